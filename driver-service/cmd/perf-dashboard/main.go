@@ -0,0 +1,53 @@
+// Команда perf-dashboard строит дашборд Grafana с трендами производительности по релизам
+// из результатов бенчмарков, сохраненных make bench-db в bench-results.
+//
+//	go run ./cmd/perf-dashboard -results bench-results -output bench-results/dashboard.json
+//
+// Результаты встраиваются в дашборд (источник данных TestData), поэтому JSON импортируется
+// в Grafana через Dashboards -> Import без подключения к файлам с результатами.
+package main
+
+import (
+	"encoding/json"
+	"flag"
+	"fmt"
+	"os"
+
+	"driver-service/internal/perfdash"
+)
+
+func main() {
+	results := flag.String("results", "bench-results", "directory with go test -bench outputs (*.txt)")
+	output := flag.String("output", "bench-results/dashboard.json", "path of the generated dashboard JSON")
+	title := flag.String("title", "Driver Service Performance", "dashboard title")
+	uid := flag.String("uid", "driver-service-perf", "dashboard UID (importing again replaces the dashboard)")
+	flag.Parse()
+
+	if err := run(*results, *output, *title, *uid); err != nil {
+		fmt.Fprintf(os.Stderr, "Failed to generate dashboard: %v\n", err)
+		os.Exit(1)
+	}
+}
+
+// run загружает результаты и сохраняет дашборд
+func run(results, output, title, uid string) error {
+	runs, err := perfdash.LoadDir(results)
+	if err != nil {
+		return err
+	}
+	if len(runs) == 0 {
+		return fmt.Errorf("no benchmark results in %s", results)
+	}
+
+	data, err := json.MarshalIndent(perfdash.BuildDashboard(title, uid, runs), "", "  ")
+	if err != nil {
+		return err
+	}
+	if err := os.WriteFile(output, append(data, '\n'), 0o644); err != nil {
+		return err
+	}
+
+	fmt.Printf("Dashboard with %d benchmarks over %d releases written to %s\n",
+		len(perfdash.Benchmarks(runs)), len(runs), output)
+	return nil
+}
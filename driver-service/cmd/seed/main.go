@@ -0,0 +1,83 @@
+// Команда seed заполняет базу из config.yaml историей за месяцы работы парка через COPY,
+// чтобы нагрузочные тесты и проверки планов запросов шли на объемах, близких к боевым.
+//
+//	go run ./cmd/seed -drivers 1000 -days 90 -interval 5m
+//
+// Водители получают телефоны с префиксом -phone-prefix; -reset удаляет данные прошлой загрузки
+// с тем же префиксом. Задача очистки местоположений удаляет историю старше jobs.location_retention,
+// поэтому на стенде с загруженной историей срок хранения увеличивают.
+package main
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"os"
+	"os/signal"
+	"syscall"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/infrastructure/database"
+	"driver-service/internal/seed"
+
+	"go.uber.org/zap"
+)
+
+func main() {
+	defaults := seed.DefaultOptions()
+	opts := defaults
+	flag.IntVar(&opts.Drivers, "drivers", defaults.Drivers, "number of drivers")
+	flag.IntVar(&opts.Days, "days", defaults.Days, "days of history before now")
+	flag.DurationVar(&opts.LocationInterval, "interval", defaults.LocationInterval, "interval between track points during a shift")
+	flag.StringVar(&opts.PhonePrefix, "phone-prefix", defaults.PhonePrefix, "phone prefix of seeded drivers")
+	flag.Int64Var(&opts.Seed, "seed", defaults.Seed, "random seed, the same seed produces the same data")
+	reset := flag.Bool("reset", false, "delete drivers seeded earlier with the same phone prefix")
+	flag.Parse()
+
+	if err := run(opts, *reset); err != nil {
+		fmt.Fprintf(os.Stderr, "Failed to seed database: %v\n", err)
+		os.Exit(1)
+	}
+}
+
+// run подключается к базе и загружает историю
+func run(opts seed.Options, reset bool) error {
+	cfg, err := config.LoadConfig()
+	if err != nil {
+		return fmt.Errorf("failed to load config: %w", err)
+	}
+
+	logger, err := zap.NewDevelopment()
+	if err != nil {
+		return err
+	}
+	defer logger.Sync()
+
+	db, err := database.NewPostgresDB(&cfg.Database, logger)
+	if err != nil {
+		return err
+	}
+	defer db.Close()
+
+	ctx, stop := signal.NotifyContext(context.Background(), os.Interrupt, syscall.SIGTERM)
+	defer stop()
+
+	seeder := seed.NewSeeder(db.DB, logger)
+	if reset {
+		deleted, err := seeder.Clear(ctx, opts.PhonePrefix)
+		if err != nil {
+			return err
+		}
+		logger.Info("Previously seeded drivers deleted", zap.Int64("drivers", deleted))
+	}
+
+	result, err := seeder.Seed(ctx, opts)
+	if err != nil {
+		return err
+	}
+
+	fmt.Printf("Seeded %d drivers, %d shifts, %d locations and %d ratings over %d days in %s\n",
+		result.Drivers, result.Shifts, result.Locations, result.Ratings, opts.Days, result.Duration.Round(time.Second))
+	return nil
+}
@@ -11,10 +11,19 @@ import (
 	"time"
 
 	"driver-service/internal/config"
+	"driver-service/internal/consistency"
 	"driver-service/internal/domain/services"
+	"driver-service/internal/features"
 	httpHandlers "driver-service/internal/interfaces/http/handlers"
 	httpServer "driver-service/internal/interfaces/http"
 	"driver-service/internal/infrastructure/database"
+	"driver-service/internal/infrastructure/messaging"
+	"driver-service/internal/infrastructure/nats"
+	"driver-service/internal/infrastructure/push"
+	"driver-service/internal/infrastructure/redis"
+	"driver-service/internal/infrastructure/storage"
+	"driver-service/internal/infrastructure/webhooks"
+	"driver-service/internal/limits"
 	"driver-service/internal/repositories"
 
 	"github.com/google/uuid"
@@ -29,13 +38,63 @@ type Application struct {
 	db       *database.DB
 	
 	// Repositories
-	driverRepo   repositories.DriverRepository
-	documentRepo repositories.DocumentRepository
-	locationRepo repositories.LocationRepository
+	driverRepo     repositories.DriverRepository
+	documentRepo   repositories.DocumentRepository
+	locationRepo   repositories.LocationRepository
+	shiftRepo      repositories.ShiftRepository
+	heartbeatRepo  repositories.HeartbeatRepository
+	paymentRepo    repositories.PaymentRepository
+	connectionRepo repositories.ConnectionRepository
+
+	// eventInboxRepo обработанные входящие события: эффект события применяется ровно один раз
+	eventInboxRepo repositories.EventInboxRepository
 	
 	// Services
 	driverService   services.DriverService
 	locationService services.LocationService
+	webhooks        *webhooks.Publisher
+
+	// documentUploadService и avatarService nil, если объектное хранилище не настроено
+	documentUploadService services.DocumentUploadService
+	avatarService         services.AvatarService
+
+	// notificationService обрабатывает order.assigned и напоминает об истекающих документах
+	notificationService services.NotificationService
+
+	// jobService фоновые задачи: автозакрытие смен, очистка местоположений, истечение документов
+	jobService services.JobService
+
+	// features флаги функциональности, переключаемые на лету
+	features *features.Flags
+
+	// dispatchFeed рассылает сохраненные местоположения диспетчерам, следящим за областью карты
+	dispatchFeed *services.DispatchFeed
+	// locationRelay рассылает местоположения потоку позиций остальных реплик (nats.dispatch_feed)
+	locationRelay *nats.LocationRelay
+
+	// heartbeatService отмечает, что приложение водителя на связи
+	heartbeatService services.HeartbeatService
+
+	// earningsService заработок водителей по оплатам поездок
+	earningsService services.EarningsService
+
+	// statsService статистика водителей: поездки, рейтинг и пройденное расстояние
+	statsService services.StatsService
+
+	// shiftService смены водителей: начало, перерывы и окончание
+	shiftService services.ShiftService
+
+	// analyticsService агрегаты по парку водителей (тепловая карта)
+	analyticsService services.AnalyticsService
+
+	// natsPublisher публикует события водителей в NATS; nil, если nats.publish_events выключен
+	natsPublisher *nats.Publisher
+
+	// orderEventService учитывает поездки и оплаты по событиям сервисов заказов и платежей
+	orderEventService services.OrderEventService
+
+	// limits ограничения API, перечитываемые по SIGHUP
+	limits *limits.Limits
 	
 	// Servers
 	httpServer *httpServer.Server
@@ -84,8 +143,15 @@ func NewApplication() (*Application, error) {
 		zap.Int("http_port", cfg.Server.HTTPPort),
 	)
 
-	// Инициализируем базу данных
-	db, err := database.NewPostgresDB(&cfg.Database, logger)
+	// Инициализируем базу данных: сервис может запуститься раньше БД, поэтому подключение повторяется
+	// до database.startup_timeout. Redis и NATS переподключаются в фоне и на запуск не влияют.
+	startupCtx := context.Background()
+	if cfg.Database.StartupTimeout > 0 {
+		var cancel context.CancelFunc
+		startupCtx, cancel = context.WithTimeout(startupCtx, cfg.Database.StartupTimeout)
+		defer cancel()
+	}
+	db, err := database.ConnectWithRetry(startupCtx, &cfg.Database, logger)
 	if err != nil {
 		return nil, fmt.Errorf("failed to initialize database: %w", err)
 	}
@@ -155,6 +221,11 @@ func (app *Application) initRepositories() error {
 	app.driverRepo = repositories.NewDriverRepository(app.db, app.logger)
 	app.documentRepo = repositories.NewDocumentRepository(app.db, app.logger)
 	app.locationRepo = repositories.NewLocationRepository(app.db, app.logger)
+	app.shiftRepo = repositories.NewShiftRepository(app.db, app.logger)
+	app.heartbeatRepo = repositories.NewHeartbeatRepository(app.db, app.logger)
+	app.eventInboxRepo = repositories.NewEventInboxRepository(app.db, app.logger)
+	app.paymentRepo = repositories.NewPaymentRepository(app.db, app.logger)
+	app.connectionRepo = repositories.NewConnectionRepository(app.db, app.logger)
 
 	app.logger.Info("Repositories initialized")
 	return nil
@@ -162,20 +233,147 @@ func (app *Application) initRepositories() error {
 
 // initServices инициализирует сервисы
 func (app *Application) initServices() error {
-	// Создаем заглушку для EventPublisher
-	eventBus := &mockEventPublisher{logger: app.logger}
+	// Публикуем события водителей в NATS (или только логируем) и доставляем их партнерам через webhooks
+	var publisher services.EventPublisher = &loggingEventPublisher{logger: app.logger}
+	if app.config.NATS.PublishEvents {
+		natsPublisher, err := nats.NewPublisher(app.config.NATS, app.logger)
+		if err != nil {
+			return err
+		}
+		app.natsPublisher = natsPublisher
+		publisher = natsPublisher
+	}
+	app.webhooks = webhooks.NewPublisherFromConfig(publisher, app.config.Webhooks, app.logger)
+	var eventBus services.EventPublisher = app.webhooks
+
+	// Письма и SMS водителям при регистрации, проверке документов и блокировке
+	var emailSender messaging.EmailSender
+	if app.config.External.SMTP.Host != "" {
+		emailSender = messaging.NewSMTPSender(app.config.External.SMTP)
+	}
+	var smsSender messaging.SMSSender
+	if app.config.External.SMSAPI.BaseURL != "" {
+		smsSender = messaging.NewSMSClient(app.config.External.SMSAPI)
+	}
+	if emailSender != nil || smsSender != nil {
+		eventBus = messaging.NewNotifier(eventBus, app.driverRepo, emailSender, smsSender, app.logger)
+	} else {
+		app.logger.Warn("Email and SMS providers are not configured, driver messages are disabled")
+	}
 
-	app.driverService = services.NewDriverService(
+	app.features = features.FromConfig(app.config.Features)
+	app.limits = limits.New(app.config.Limits)
+
+	geoCache := services.NewCachedLocationService(
+		services.NewAvailabilityLocationService(
+			services.NewGeofenceLocationService(
+				services.NewLocationService(
+					app.locationRepo,
+					app.driverRepo,
+					eventBus,
+					app.logger,
+				),
+				app.locationRepo,
+				geofenceZones(app.config.Geofence),
+				eventBus,
+				app.logger,
+			),
+			app.connectionRepo,
+			eventBus,
+			app.logger,
+		),
+		app.features,
+		app.config.Features.GeoCacheTTL,
+	)
+	app.dispatchFeed = services.NewDispatchFeed(geoCache, app.logger)
+	app.locationService = app.dispatchFeed
+
+	// Смена статуса и удаление водителя сбрасывают закэшированные результаты поиска поблизости с ним
+	eventBus = geoCache.EvictingPublisher(eventBus)
+
+	app.driverService = services.NewStrictDriverService(
+		services.NewDriverService(
+			app.driverRepo,
+			app.documentRepo,
+			eventBus,
+			app.logger,
+		),
+		app.features,
+	)
+
+	// Поток позиций диспетчеров получает местоположения, сохраненные любой репликой
+	if app.config.NATS.DispatchFeed {
+		relay, err := nats.NewLocationRelay(app.config.NATS, app.dispatchFeed.Deliver, app.logger)
+		if err != nil {
+			return err
+		}
+		app.locationRelay = relay
+		app.dispatchFeed.UseRelay(relay)
+	}
+
+	app.heartbeatService = services.NewHeartbeatService(
 		app.driverRepo,
-		app.documentRepo,
+		app.heartbeatRepo,
+		app.locationService,
 		eventBus,
+		app.config.Heartbeat.MinInterval,
 		app.logger,
 	)
 
-	app.locationService = services.NewLocationService(
-		app.locationRepo,
+	app.earningsService = services.NewEarningsService(app.driverRepo, app.paymentRepo, app.logger)
+	app.statsService = services.NewStatsService(app.driverRepo, app.logger)
+	app.shiftService = services.NewShiftService(app.shiftRepo, app.driverRepo, eventBus, app.logger)
+	app.analyticsService = services.NewAnalyticsService(app.locationRepo, app.logger)
+
+	// Файлы документов загружаются в S3-совместимое хранилище, если оно настроено
+	if app.config.External.S3.Endpoint != "" {
+		documentStorage, err := storage.NewS3Client(app.config.External.S3)
+		if err != nil {
+			return fmt.Errorf("failed to create document storage client: %w", err)
+		}
+
+		app.documentUploadService = services.NewDocumentUploadService(
+			app.driverRepo,
+			app.documentRepo,
+			documentStorage,
+			app.config.External.S3.UploadURLExpiry,
+			app.logger,
+		)
+		app.avatarService = services.NewAvatarService(
+			app.driverRepo,
+			documentStorage,
+			app.config.External.S3.CDNBaseURL,
+			app.logger,
+		)
+	} else {
+		app.logger.Warn("Document storage is not configured, document and avatar uploads are disabled")
+	}
+
+	app.notificationService = services.NewNotificationService(
 		app.driverRepo,
+		app.documentRepo,
+		push.NewGateway(app.config.Push, app.logger),
+		app.logger,
+	)
+
+	app.orderEventService = services.NewOrderEventService(
+		app.eventInboxRepo,
+		app.driverService,
+		app.logger,
+	)
+
+	app.jobService = services.NewJobService(
+		app.driverRepo,
+		app.documentRepo,
+		app.locationRepo,
+		app.shiftRepo,
+		app.heartbeatRepo,
+		app.connectionRepo,
 		eventBus,
+		app.config.Jobs.MaxShiftDuration,
+		app.config.Jobs.LocationRetention,
+		app.config.Heartbeat.Timeout,
+		app.config.Jobs.VerificationTimeout,
 		app.logger,
 	)
 
@@ -188,6 +386,7 @@ func (app *Application) initServers() error {
 	// HTTP handlers
 	driverHandler := httpHandlers.NewDriverHandler(app.driverService, app.logger)
 	locationHandler := httpHandlers.NewLocationHandler(app.locationService, app.logger)
+	locationHandler.SetLimits(app.limits)
 
 	// HTTP server
 	app.httpServer = httpServer.NewServer(
@@ -196,17 +395,100 @@ func (app *Application) initServers() error {
 		driverHandler,
 		locationHandler,
 	)
+	app.httpServer.SetLimits(app.limits)
+
+	// Проверки живости и готовности для оркестратора и балансировщика
+	app.httpServer.RegisterHealthRoutes(httpHandlers.NewHealthHandler(
+		app.dependencyChecks(), app.config.Server.ReadinessTimeout, app.logger))
+
+	// Поток позиций водителей для диспетчерских панелей
+	app.httpServer.RegisterDispatchFeedRoutes(httpHandlers.NewDispatchFeedHandler(app.dispatchFeed, app.logger))
+
+	// Heartbeat приложения водителя
+	app.httpServer.RegisterHeartbeatRoutes(httpHandlers.NewHeartbeatHandler(
+		app.heartbeatService, app.config.Heartbeat.MinInterval, app.logger))
+
+	// Заработок водителей
+	app.httpServer.RegisterEarningsRoutes(httpHandlers.NewEarningsHandler(app.earningsService, app.logger))
+
+	// Статистика водителей
+	app.httpServer.RegisterStatsRoutes(httpHandlers.NewStatsHandler(app.statsService, app.logger))
+
+	// Смены водителей
+	app.httpServer.RegisterShiftRoutes(httpHandlers.NewShiftHandler(app.shiftService, app.logger))
+
+	// Аналитика по парку водителей
+	app.httpServer.RegisterAnalyticsRoutes(httpHandlers.NewAnalyticsHandler(app.analyticsService, app.logger))
+
+	// Фотографии водителей
+	if app.avatarService != nil {
+		app.httpServer.RegisterAvatarRoutes(httpHandlers.NewAvatarHandler(app.avatarService, app.logger))
+	}
+
+	// Ручной запуск фоновых задач для тестовых стендов
+	if app.config.Jobs.AdminAPIEnabled {
+		app.httpServer.RegisterJobRoutes(httpHandlers.NewJobsHandler(app.jobService, app.logger))
+		app.httpServer.RegisterConsistencyRoutes(httpHandlers.NewConsistencyHandler(
+			consistency.NewChecker(app.db, app.logger, consistency.DefaultRules(consistency.DefaultLocationStaleAfter)...),
+			app.logger,
+		))
+		app.httpServer.RegisterAdminDriverRoutes(httpHandlers.NewDriverHandler(app.driverService, app.logger))
+		app.logger.Warn("Jobs admin API is enabled")
+	}
+
+	// Переключение флагов функциональности для тестовых стендов
+	if app.config.Features.AdminAPIEnabled {
+		app.httpServer.RegisterFeatureRoutes(httpHandlers.NewFeaturesHandler(app.features, app.logger))
+		app.logger.Warn("Features admin API is enabled", zap.String("features", app.features.String()))
+	}
+
+	// Перечитывание ограничений API через HTTP для тестовых стендов
+	if app.config.Limits.AdminAPIEnabled {
+		app.httpServer.RegisterLimitsRoutes(httpHandlers.NewLimitsHandler(app.limits, config.ReloadLimits, app.logger))
+		app.logger.Warn("Limits admin API is enabled")
+	}
 
 	app.logger.Info("Servers initialized")
 	return nil
 }
 
+// dependencyChecks возвращает проверки зависимостей для /health/ready: БД всегда, Redis и NATS - если
+// сервис к ним подписан или публикует в NATS события
+func (app *Application) dependencyChecks() []httpHandlers.DependencyCheck {
+	checks := []httpHandlers.DependencyCheck{
+		{Name: "database", Check: app.db.PingContext},
+	}
+	if app.config.Redis.SessionKeyPrefix != "" {
+		checks = append(checks, httpHandlers.DependencyCheck{Name: "redis", Check: func(ctx context.Context) error {
+			return redis.Ping(ctx, app.config.Redis)
+		}})
+	}
+	if app.config.NATS.ConsumeEvents || app.config.NATS.PublishEvents {
+		checks = append(checks, httpHandlers.DependencyCheck{Name: "nats", Check: func(ctx context.Context) error {
+			return nats.Ping(ctx, app.config.NATS)
+		}})
+	}
+	return checks
+}
+
 // Run запускает приложение
 func (app *Application) Run() error {
 	// Запускаем background задачи
 	app.wg.Add(1)
 	go app.runBackgroundTasks()
 
+	// Истечение сессий приложения водителя в Redis переводит водителя в offline
+	if app.config.Redis.SessionKeyPrefix != "" {
+		app.wg.Add(1)
+		go app.runSessionExpiryListener()
+	}
+
+	// События сервисов заказов и платежей: уведомление о заказе, учет поездок и оплат, возврат водителя на смену
+	if app.config.NATS.ConsumeEvents {
+		app.wg.Add(1)
+		go app.runEventSubscriber()
+	}
+
 	// Запускаем HTTP сервер
 	app.wg.Add(1)
 	go func() {
@@ -216,42 +498,156 @@ func (app *Application) Run() error {
 		}
 	}()
 
-	// Ждем сигнал для завершения
+	// Ждем сигнал для завершения; SIGHUP перечитывает ограничения API без перезапуска
 	sigChan := make(chan os.Signal, 1)
-	signal.Notify(sigChan, syscall.SIGINT, syscall.SIGTERM)
+	signal.Notify(sigChan, syscall.SIGINT, syscall.SIGTERM, syscall.SIGHUP)
 
-	select {
-	case sig := <-sigChan:
-		app.logger.Info("Received shutdown signal", zap.String("signal", sig.String()))
-	case <-app.shutdown:
-		app.logger.Info("Received shutdown from internal source")
+	for {
+		select {
+		case sig := <-sigChan:
+			if sig == syscall.SIGHUP {
+				app.reloadLimits()
+				continue
+			}
+			app.logger.Info("Received shutdown signal", zap.String("signal", sig.String()))
+		case <-app.shutdown:
+			app.logger.Info("Received shutdown from internal source")
+		}
+		break
 	}
 
 	// Graceful shutdown
 	return app.gracefulShutdown()
 }
 
-// runBackgroundTasks запускает фоновые задачи
+// reloadLimits перечитывает ограничения API из файла конфигурации; при ошибке действуют прежние
+func (app *Application) reloadLimits() {
+	cfg, err := app.limits.Reload(config.ReloadLimits)
+	if err != nil {
+		app.logger.Error("Failed to reload limits", zap.Error(err))
+		return
+	}
+
+	app.logger.Info("Limits reloaded",
+		zap.Float64("requests_per_second", cfg.RequestsPerSecond),
+		zap.Int("burst", cfg.Burst),
+		zap.Float64("nearby_radius_km", cfg.NearbyRadiusKm),
+	)
+}
+
+// backgroundTask задачи JobService, которые runBackgroundTasks запускает каждые interval с таймаутом timeout
+type backgroundTask struct {
+	interval time.Duration
+	timeout  time.Duration
+	jobs     []string
+	// expiringDocuments после задач уведомляет водителей об истекающих документах
+	expiringDocuments bool
+}
+
+// backgroundTasks расписание фоновых задач: cleanup старых местоположений, истечение документов и верификации
+// с уведомлениями об истекающих документах, закрытие брошенных смен, потеря связи с водителями и перевод
+// в offline водителей без heartbeat
+func backgroundTasks(cfg *config.Config) []backgroundTask {
+	return []backgroundTask{
+		{interval: 24 * time.Hour, timeout: 30 * time.Minute, jobs: []string{services.JobPruneLocations}},
+		{interval: time.Hour, timeout: 5 * time.Minute, jobs: []string{services.JobExpireDocuments, services.JobExpireVerification},
+			expiringDocuments: true},
+		{interval: 15 * time.Minute, timeout: 5 * time.Minute, jobs: []string{services.JobAutoCloseShifts}},
+		{interval: time.Minute, timeout: 30 * time.Second, jobs: []string{services.JobDetectConnectionLoss}},
+		// Водитель уходит в offline не позже чем через полтора heartbeat.timeout после последнего heartbeat
+		{interval: cfg.Heartbeat.Timeout / 2, timeout: 30 * time.Second, jobs: []string{services.JobMarkOffline}},
+	}
+}
+
+// runBackgroundTasks запускает фоновые задачи по расписанию backgroundTasks
 func (app *Application) runBackgroundTasks() {
 	defer app.wg.Done()
 
-	// Cleanup старых местоположений
-	cleanupTicker := time.NewTicker(24 * time.Hour)
-	defer cleanupTicker.Stop()
-
-	for {
-		select {
-		case <-cleanupTicker.C:
-			ctx, cancel := context.WithTimeout(context.Background(), 30*time.Minute)
-			if err := app.locationService.CleanupOldLocations(ctx); err != nil {
-				app.logger.Error("Failed to cleanup old locations", zap.Error(err))
+	var wg sync.WaitGroup
+	for _, task := range backgroundTasks(app.config) {
+		wg.Add(1)
+		go func(task backgroundTask) {
+			defer wg.Done()
+
+			ticker := time.NewTicker(task.interval)
+			defer ticker.Stop()
+
+			for {
+				select {
+				case <-ticker.C:
+					for _, job := range task.jobs {
+						app.runJob(job, task.timeout)
+					}
+					if task.expiringDocuments {
+						app.notifyExpiringDocuments(task.timeout)
+					}
+
+				case <-app.shutdown:
+					return
+				}
 			}
-			cancel()
+		}(task)
+	}
 
-		case <-app.shutdown:
-			app.logger.Info("Stopping background tasks")
-			return
+	<-app.shutdown
+	app.logger.Info("Stopping background tasks")
+	wg.Wait()
+}
+
+// runSessionExpiryListener переводит в offline водителей, ключи сессий которых истекли в Redis
+func (app *Application) runSessionExpiryListener() {
+	defer app.wg.Done()
+
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+	go func() {
+		<-app.shutdown
+		cancel()
+	}()
+
+	listener := redis.NewSessionExpiryListener(app.config.Redis, func(ctx context.Context, driverID uuid.UUID) {
+		if err := app.heartbeatService.ExpireSession(ctx, driverID); err != nil {
+			app.logger.Error("Failed to expire driver session",
+				zap.Error(err),
+				zap.String("driver_id", driverID.String()),
+			)
 		}
+	}, app.logger)
+	listener.Run(ctx)
+}
+
+// runEventSubscriber обрабатывает события сервисов заказов и платежей из NATS
+func (app *Application) runEventSubscriber() {
+	defer app.wg.Done()
+
+	ctx, cancel := context.WithCancel(context.Background())
+	defer cancel()
+	go func() {
+		<-app.shutdown
+		cancel()
+	}()
+
+	subscriber := nats.NewSubscriber(app.config.NATS, app.logger)
+	nats.RegisterOrderHandlers(subscriber, app.orderEventService, app.notificationService)
+	subscriber.Run(ctx)
+}
+
+// runJob выполняет фоновую задачу с таймаутом; ошибки логируются JobService
+func (app *Application) runJob(name string, timeout time.Duration) {
+	ctx, cancel := context.WithTimeout(context.Background(), timeout)
+	defer cancel()
+
+	_, _ = app.jobService.RunJob(ctx, name)
+}
+
+// notifyExpiringDocuments уведомляет водителей о документах, истекающих в течение jobs.document_expiry_notice_days;
+// о каждом сроке действия документа водитель уведомляется один раз
+func (app *Application) notifyExpiringDocuments(timeout time.Duration) {
+	ctx, cancel := context.WithTimeout(context.Background(), timeout)
+	defer cancel()
+
+	if _, err := app.notificationService.NotifyExpiringDocuments(ctx, app.config.Jobs.DocumentExpiryNotice); err != nil {
+		app.logger.Error("Failed to notify drivers about expiring documents", zap.Error(err))
 	}
 }
 
@@ -271,10 +667,17 @@ func (app *Application) gracefulShutdown() error {
 		app.logger.Error("Failed to stop HTTP server", zap.Error(err))
 	}
 
-	// Ждем завершения background задач
+	// Ждем завершения background задач и начатых доставок webhooks, затем отправки событий в NATS
 	done := make(chan struct{})
 	go func() {
 		app.wg.Wait()
+		app.webhooks.Close()
+		if app.locationRelay != nil {
+			app.locationRelay.Close()
+		}
+		if app.natsPublisher != nil {
+			app.natsPublisher.Close()
+		}
 		close(done)
 	}()
 
@@ -294,16 +697,31 @@ func (app *Application) gracefulShutdown() error {
 	return nil
 }
 
-// mockEventPublisher заглушка для EventPublisher
-// В реальном приложении здесь должна быть реализация с NATS
-type mockEventPublisher struct {
+// geofenceZones возвращает геозоны из конфигурации
+func geofenceZones(cfg config.GeofenceConfig) []services.Zone {
+	zones := make([]services.Zone, 0, len(cfg.Zones))
+	for _, zone := range cfg.Zones {
+		zones = append(zones, services.Zone{
+			ID:        zone.ID,
+			Name:      zone.Name,
+			Latitude:  zone.Latitude,
+			Longitude: zone.Longitude,
+			RadiusKm:  zone.RadiusKm,
+		})
+	}
+	return zones
+}
+
+// loggingEventPublisher логирует события водителей, когда публикация в NATS выключена
+type loggingEventPublisher struct {
 	logger *zap.Logger
 }
 
-func (m *mockEventPublisher) PublishDriverEvent(ctx context.Context, eventType string, driverID uuid.UUID, data interface{}) error {
+func (m *loggingEventPublisher) PublishDriverEvent(ctx context.Context, eventType string, driverID uuid.UUID, data interface{}) error {
 	m.logger.Info("Publishing driver event",
 		zap.String("event_type", eventType),
 		zap.String("driver_id", driverID.String()),
+		zap.String("traceparent", services.TraceparentFromContext(ctx)),
 		zap.Any("data", data),
 	)
 	return nil
@@ -0,0 +1,44 @@
+package main
+
+import (
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/services"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+func TestBackgroundTasks_ScheduleEveryJob(t *testing.T) {
+	// Arrange
+	cfg := &config.Config{Heartbeat: config.HeartbeatConfig{Timeout: 90 * time.Second}}
+	jobService := services.NewJobService(nil, nil, nil, nil, nil, nil, nil, 0, 0, 0, 0, zap.NewNop())
+
+	// Act
+	scheduled := make(map[string]backgroundTask)
+	var expiringDocuments []backgroundTask
+	for _, task := range backgroundTasks(cfg) {
+		require.Positive(t, task.interval)
+		require.Positive(t, task.timeout)
+		for _, job := range task.jobs {
+			scheduled[job] = task
+		}
+		if task.expiringDocuments {
+			expiringDocuments = append(expiringDocuments, task)
+		}
+	}
+
+	// Assert
+	for _, job := range jobService.Jobs() {
+		assert.Contains(t, scheduled, job, "Задача %s не запускается по расписанию", job)
+	}
+	assert.Equal(t, 45*time.Second, scheduled[services.JobMarkOffline].interval)
+
+	// Уведомления об истекающих документах отправляются раз в час, после пометки истекших
+	require.Len(t, expiringDocuments, 1)
+	assert.Equal(t, time.Hour, expiringDocuments[0].interval)
+	assert.Contains(t, expiringDocuments[0].jobs, services.JobExpireDocuments)
+}
@@ -2,6 +2,7 @@ package config
 
 import (
 	"fmt"
+	"net"
 	"strings"
 	"time"
 
@@ -17,6 +18,13 @@ type Config struct {
 	Logger   LoggerConfig   `mapstructure:"logger"`
 	External ExternalConfig `mapstructure:"external"`
 	Metrics  MetricsConfig  `mapstructure:"metrics"`
+	Webhooks WebhooksConfig `mapstructure:"webhooks"`
+	Push     PushConfig     `mapstructure:"push"`
+	Jobs      JobsConfig      `mapstructure:"jobs"`
+	Features  FeaturesConfig  `mapstructure:"features"`
+	Heartbeat HeartbeatConfig `mapstructure:"heartbeat"`
+	Limits    LimitsConfig    `mapstructure:"limits"`
+	Geofence  GeofenceConfig  `mapstructure:"geofence"`
 }
 
 // ServerConfig конфигурация HTTP и gRPC серверов
@@ -26,6 +34,11 @@ type ServerConfig struct {
 	MetricsPort int           `mapstructure:"metrics_port"`
 	Timeout     time.Duration `mapstructure:"timeout"`
 	Environment string        `mapstructure:"environment"`
+	// ReadinessTimeout сколько /health/ready ждет ответа БД, Redis и NATS
+	ReadinessTimeout time.Duration `mapstructure:"readiness_timeout"`
+	// TrustedProxies адреса и подсети балансировщиков, чьим заголовкам X-Forwarded-For и X-Real-IP доверяет
+	// сервис при определении адреса клиента (лимит запросов); пусто - адрес клиента берется из соединения
+	TrustedProxies []string `mapstructure:"trusted_proxies"`
 }
 
 // DatabaseConfig конфигурация PostgreSQL
@@ -39,6 +52,10 @@ type DatabaseConfig struct {
 	MaxOpenConns    int           `mapstructure:"max_open_conns"`
 	MaxIdleConns    int           `mapstructure:"max_idle_conns"`
 	ConnMaxLifetime time.Duration `mapstructure:"conn_max_lifetime"`
+	// ConnectRetryInterval пауза между попытками подключения при запуске, пока БД недоступна
+	ConnectRetryInterval time.Duration `mapstructure:"connect_retry_interval"`
+	// StartupTimeout сколько сервис ждет БД при запуске, прежде чем завершиться с ошибкой; 0 - без ограничения
+	StartupTimeout time.Duration `mapstructure:"startup_timeout"`
 }
 
 // RedisConfig конфигурация Redis
@@ -50,6 +67,9 @@ type RedisConfig struct {
 	MaxRetries  int           `mapstructure:"max_retries"`
 	PoolSize    int           `mapstructure:"pool_size"`
 	IdleTimeout time.Duration `mapstructure:"idle_timeout"`
+	// SessionKeyPrefix префикс ключей сессий приложения водителя ("<prefix><driver_id>"): истечение ключа
+	// переводит водителя в offline. Пустой - сессии не отслеживаются. Требует notify-keyspace-events с классом Ex.
+	SessionKeyPrefix string `mapstructure:"session_key_prefix"`
 }
 
 // NATSConfig конфигурация NATS
@@ -62,6 +82,32 @@ type NATSConfig struct {
 	MaxReconnect    int           `mapstructure:"max_reconnect"`
 	PingInterval    time.Duration `mapstructure:"ping_interval"`
 	MaxPingsOut     int           `mapstructure:"max_pings_out"`
+	// Аутентификация: файл учетных данных (JWT и NKey), токен или пользователь и пароль; пусто - без аутентификации
+	CredentialsFile string `mapstructure:"credentials_file"`
+	Token           string `mapstructure:"token"`
+	Username        string `mapstructure:"username"`
+	Password        string `mapstructure:"password"`
+	// TLS: корневой сертификат сервера и сертификат клиента; TLS без проверки своим CA включается схемой tls:// в URL
+	TLSCAFile   string `mapstructure:"tls_ca_file"`
+	TLSCertFile string `mapstructure:"tls_cert_file"`
+	TLSKeyFile  string `mapstructure:"tls_key_file"`
+	// PublishEvents публикация событий водителей в NATS (тема - тип события); выключено - события только логируются
+	PublishEvents bool `mapstructure:"publish_events"`
+	// DispatchFeed рассылка сохраненных местоположений потоку позиций диспетчеров всех реплик через NATS;
+	// выключено - диспетчер получает только местоположения, сохраненные репликой, к которой он подключен
+	DispatchFeed bool `mapstructure:"dispatch_feed"`
+	// ConsumeEvents подписка на события сервисов заказов и платежей (order.assigned, order.completed, payment.processed)
+	ConsumeEvents bool `mapstructure:"consume_events"`
+	// Stream поток JetStream с событиями: сервис читает его durable pull-консьюмером Durable с подтверждениями
+	// (повторная доставка необработанных событий после перезапуска); пусто - подписка core NATS на темы событий
+	Stream  string `mapstructure:"stream"`
+	Durable string `mapstructure:"durable"`
+	// AckWait через сколько JetStream доставляет неподтвержденное событие повторно
+	AckWait time.Duration `mapstructure:"ack_wait"`
+	// MaxDeliver сколько раз JetStream доставляет событие; 0 - без ограничения
+	MaxDeliver int `mapstructure:"max_deliver"`
+	// Workers количество параллельных обработчиков событий; события одного водителя обрабатываются по порядку
+	Workers int `mapstructure:"workers"`
 }
 
 // LoggerConfig конфигурация логгера
@@ -76,6 +122,7 @@ type ExternalConfig struct {
 	GIBDDAPI GIBDDAPIConfig `mapstructure:"gibdd_api"`
 	MapsAPI  MapsAPIConfig  `mapstructure:"maps_api"`
 	SMSAPI   SMSAPIConfig   `mapstructure:"sms_api"`
+	SMTP     SMTPConfig     `mapstructure:"smtp"`
 	S3       S3Config       `mapstructure:"s3"`
 }
 
@@ -101,14 +148,26 @@ type SMSAPIConfig struct {
 	Timeout  time.Duration `mapstructure:"timeout"`
 }
 
+// SMTPConfig конфигурация почтового сервера для писем водителям
+type SMTPConfig struct {
+	Host     string        `mapstructure:"host"`
+	Port     int           `mapstructure:"port"`
+	Username string        `mapstructure:"username"`
+	Password string        `mapstructure:"password"`
+	From     string        `mapstructure:"from"`
+	Timeout  time.Duration `mapstructure:"timeout"`
+}
+
 // S3Config конфигурация S3
 type S3Config struct {
-	Endpoint        string `mapstructure:"endpoint"`
-	AccessKeyID     string `mapstructure:"access_key_id"`
-	SecretAccessKey string `mapstructure:"secret_access_key"`
-	BucketName      string `mapstructure:"bucket_name"`
-	Region          string `mapstructure:"region"`
-	UseSSL          bool   `mapstructure:"use_ssl"`
+	Endpoint        string        `mapstructure:"endpoint"`
+	AccessKeyID     string        `mapstructure:"access_key_id"`
+	SecretAccessKey string        `mapstructure:"secret_access_key"`
+	BucketName      string        `mapstructure:"bucket_name"`
+	Region          string        `mapstructure:"region"`
+	UseSSL          bool          `mapstructure:"use_ssl"`
+	UploadURLExpiry time.Duration `mapstructure:"upload_url_expiry"` // срок действия ссылки на загрузку файла
+	CDNBaseURL      string        `mapstructure:"cdn_base_url"`      // адрес CDN перед бакетом для фотографий водителей
 }
 
 // MetricsConfig конфигурация метрик
@@ -117,6 +176,94 @@ type MetricsConfig struct {
 	Path    string `mapstructure:"path"`
 }
 
+// WebhooksConfig конфигурация доставки событий партнерам
+type WebhooksConfig struct {
+	Targets        []WebhookTargetConfig `mapstructure:"targets"`
+	MaxAttempts    int                   `mapstructure:"max_attempts"`
+	InitialBackoff time.Duration         `mapstructure:"initial_backoff"`
+	MaxBackoff     time.Duration         `mapstructure:"max_backoff"`
+	Timeout        time.Duration         `mapstructure:"timeout"`
+	Workers        int                   `mapstructure:"workers"`
+	QueueSize      int                   `mapstructure:"queue_size"`
+	MaxDeadLetters int                   `mapstructure:"max_dead_letters"`
+}
+
+// WebhookTargetConfig адрес партнера, на который доставляются события
+type WebhookTargetConfig struct {
+	URL    string   `mapstructure:"url"`
+	Secret string   `mapstructure:"secret"`
+	Events []string `mapstructure:"events"`
+}
+
+// PushConfig конфигурация шлюзов push-уведомлений водителям
+type PushConfig struct {
+	FCM            FCMConfig     `mapstructure:"fcm"`
+	APNS           APNSConfig    `mapstructure:"apns"`
+	MaxAttempts    int           `mapstructure:"max_attempts"`
+	InitialBackoff time.Duration `mapstructure:"initial_backoff"`
+	Timeout        time.Duration `mapstructure:"timeout"`
+}
+
+// FCMConfig конфигурация Firebase Cloud Messaging (HTTP v1 API)
+type FCMConfig struct {
+	BaseURL     string `mapstructure:"base_url"`
+	ProjectID   string `mapstructure:"project_id"`
+	AccessToken string `mapstructure:"access_token"`
+}
+
+// APNSConfig конфигурация Apple Push Notification service
+type APNSConfig struct {
+	BaseURL   string `mapstructure:"base_url"`
+	Topic     string `mapstructure:"topic"`
+	AuthToken string `mapstructure:"auth_token"`
+}
+
+// JobsConfig конфигурация фоновых задач
+type JobsConfig struct {
+	AdminAPIEnabled      bool          `mapstructure:"admin_api_enabled"`           // /api/v1/admin/jobs, /api/v1/admin/consistency и /api/v1/admin/drivers (не для production)
+	MaxShiftDuration     time.Duration `mapstructure:"max_shift_duration"`          // смена дольше закрывается автоматически
+	LocationRetention    time.Duration `mapstructure:"location_retention"`          // срок хранения истории местоположений
+	VerificationTimeout  time.Duration `mapstructure:"verification_timeout"`        // водитель в pending_verification дольше отклоняется
+	DocumentExpiryNotice int           `mapstructure:"document_expiry_notice_days"` // за сколько дней до истечения документа водитель получает push
+}
+
+// HeartbeatConfig конфигурация сигналов присутствия приложения водителя
+type HeartbeatConfig struct {
+	Timeout     time.Duration `mapstructure:"timeout"`      // водитель без heartbeat дольше timeout переводится в offline
+	MinInterval time.Duration `mapstructure:"min_interval"` // heartbeat водителя чаще min_interval отклоняются
+}
+
+// FeaturesConfig начальные значения флагов функциональности
+type FeaturesConfig struct {
+	GeoCache         bool          `mapstructure:"geo_cache"`         // кэширование поиска водителей поблизости
+	GeoCacheTTL      time.Duration `mapstructure:"geo_cache_ttl"`     // время жизни закэшированного результата
+	StrictValidation bool          `mapstructure:"strict_validation"` // строгая проверка телефона, email и ВУ при регистрации
+	AdminAPIEnabled  bool          `mapstructure:"admin_api_enabled"` // переключение флагов через /api/v1/admin/features (не для production)
+}
+
+// LimitsConfig ограничения API, которые перечитываются без перезапуска: по SIGHUP или через
+// /api/v1/admin/config/reload
+type LimitsConfig struct {
+	RequestsPerSecond float64 `mapstructure:"requests_per_second"` // запросов в секунду к /api/v1 с одного адреса; 0 - без ограничения
+	Burst             int     `mapstructure:"burst"`               // запросов подряд сверх requests_per_second
+	NearbyRadiusKm    float64 `mapstructure:"nearby_radius_km"`    // радиус поиска водителей поблизости без radius_km
+	AdminAPIEnabled   bool    `mapstructure:"admin_api_enabled"`   // перечитывание через /api/v1/admin/config/reload (не для production)
+}
+
+// GeofenceConfig геозоны, при пересечении границ которых публикуются driver.zone.entered и driver.zone.exited
+type GeofenceConfig struct {
+	Zones []GeofenceZoneConfig `mapstructure:"zones"`
+}
+
+// GeofenceZoneConfig геозона: круг с центром и радиусом
+type GeofenceZoneConfig struct {
+	ID        string  `mapstructure:"id"`
+	Name      string  `mapstructure:"name"`
+	Latitude  float64 `mapstructure:"latitude"`
+	Longitude float64 `mapstructure:"longitude"`
+	RadiusKm  float64 `mapstructure:"radius_km"`
+}
+
 // LoadConfig загружает конфигурацию из переменных окружения и файлов
 func LoadConfig() (*Config, error) {
 	viper.SetConfigName("config")
@@ -156,6 +303,7 @@ func setDefaults() {
 	viper.SetDefault("server.metrics_port", 9002)
 	viper.SetDefault("server.timeout", "30s")
 	viper.SetDefault("server.environment", "development")
+	viper.SetDefault("server.readiness_timeout", "2s")
 
 	// Database
 	viper.SetDefault("database.host", "localhost")
@@ -167,6 +315,8 @@ func setDefaults() {
 	viper.SetDefault("database.max_open_conns", 25)
 	viper.SetDefault("database.max_idle_conns", 25)
 	viper.SetDefault("database.conn_max_lifetime", "5m")
+	viper.SetDefault("database.connect_retry_interval", "2s")
+	viper.SetDefault("database.startup_timeout", "5m")
 
 	// Redis
 	viper.SetDefault("redis.host", "localhost")
@@ -176,6 +326,7 @@ func setDefaults() {
 	viper.SetDefault("redis.max_retries", 3)
 	viper.SetDefault("redis.pool_size", 10)
 	viper.SetDefault("redis.idle_timeout", "5m")
+	viper.SetDefault("redis.session_key_prefix", "")
 
 	// NATS
 	viper.SetDefault("nats.url", "nats://localhost:4222")
@@ -186,6 +337,14 @@ func setDefaults() {
 	viper.SetDefault("nats.max_reconnect", -1)
 	viper.SetDefault("nats.ping_interval", "20s")
 	viper.SetDefault("nats.max_pings_out", 2)
+	viper.SetDefault("nats.publish_events", false)
+	viper.SetDefault("nats.dispatch_feed", false)
+	viper.SetDefault("nats.consume_events", false)
+	viper.SetDefault("nats.stream", "")
+	viper.SetDefault("nats.durable", "driver-service")
+	viper.SetDefault("nats.ack_wait", "30s")
+	viper.SetDefault("nats.max_deliver", 0)
+	viper.SetDefault("nats.workers", 8)
 
 	// Logger
 	viper.SetDefault("logger.level", "info")
@@ -196,14 +355,93 @@ func setDefaults() {
 	viper.SetDefault("external.gibdd_api.timeout", "30s")
 	viper.SetDefault("external.maps_api.timeout", "10s")
 	viper.SetDefault("external.sms_api.timeout", "15s")
+	viper.SetDefault("external.smtp.port", 587)
+	viper.SetDefault("external.smtp.timeout", "10s")
 
 	// S3
 	viper.SetDefault("external.s3.region", "us-east-1")
 	viper.SetDefault("external.s3.use_ssl", true)
+	viper.SetDefault("external.s3.upload_url_expiry", "15m")
 
 	// Metrics
 	viper.SetDefault("metrics.enabled", true)
 	viper.SetDefault("metrics.path", "/metrics")
+
+	// Webhooks
+	viper.SetDefault("webhooks.max_attempts", 5)
+	viper.SetDefault("webhooks.initial_backoff", "1s")
+	viper.SetDefault("webhooks.max_backoff", "1m")
+	viper.SetDefault("webhooks.timeout", "10s")
+	viper.SetDefault("webhooks.workers", 8)
+	viper.SetDefault("webhooks.queue_size", 1000)
+	viper.SetDefault("webhooks.max_dead_letters", 1000)
+
+	// Push
+	viper.SetDefault("push.fcm.base_url", "https://fcm.googleapis.com")
+	viper.SetDefault("push.apns.base_url", "https://api.push.apple.com")
+	viper.SetDefault("push.max_attempts", 3)
+	viper.SetDefault("push.initial_backoff", "500ms")
+	viper.SetDefault("push.timeout", "10s")
+
+	// Jobs
+	viper.SetDefault("jobs.admin_api_enabled", false)
+	viper.SetDefault("jobs.max_shift_duration", "12h")
+	viper.SetDefault("jobs.location_retention", "720h")
+	viper.SetDefault("jobs.verification_timeout", "72h")
+	viper.SetDefault("jobs.document_expiry_notice_days", 30)
+
+	// Features
+	viper.SetDefault("features.geo_cache", false)
+	viper.SetDefault("features.geo_cache_ttl", "30s")
+	viper.SetDefault("features.strict_validation", false)
+	viper.SetDefault("features.admin_api_enabled", false)
+
+	// Heartbeat
+	viper.SetDefault("heartbeat.timeout", "90s")
+	viper.SetDefault("heartbeat.min_interval", "5s")
+
+	// Limits
+	setLimitsDefaults(viper.GetViper())
+}
+
+// setLimitsDefaults устанавливает значения по умолчанию секции limits
+func setLimitsDefaults(v *viper.Viper) {
+	v.SetDefault("limits.requests_per_second", 0)
+	v.SetDefault("limits.burst", 20)
+	v.SetDefault("limits.nearby_radius_km", 5.0)
+	v.SetDefault("limits.admin_api_enabled", false)
+}
+
+// ReloadLimits перечитывает секцию limits из файла конфигурации, загруженного LoadConfig,
+// и переменных окружения DRIVER_SERVICE_LIMITS_*
+func ReloadLimits() (LimitsConfig, error) {
+	return LoadLimits(viper.ConfigFileUsed())
+}
+
+// LoadLimits читает секцию limits из файла path и переменных окружения DRIVER_SERVICE_LIMITS_*;
+// пустой path - только переменные окружения и значения по умолчанию
+func LoadLimits(path string) (LimitsConfig, error) {
+	v := viper.New()
+	setLimitsDefaults(v)
+	v.AutomaticEnv()
+	v.SetEnvKeyReplacer(strings.NewReplacer(".", "_"))
+	v.SetEnvPrefix("DRIVER_SERVICE")
+
+	if path != "" {
+		v.SetConfigFile(path)
+		if err := v.ReadInConfig(); err != nil {
+			return LimitsConfig{}, fmt.Errorf("error reading config file: %w", err)
+		}
+	}
+
+	var limits LimitsConfig
+	if err := v.UnmarshalKey("limits", &limits); err != nil {
+		return LimitsConfig{}, fmt.Errorf("error unmarshaling limits: %w", err)
+	}
+	if err := limits.Validate(); err != nil {
+		return LimitsConfig{}, err
+	}
+	return limits, nil
 }
 
 // GetDSN возвращает строку подключения к базе данных
@@ -243,5 +481,80 @@ func (c *Config) Validate() error {
 		return fmt.Errorf("NATS URL is required")
 	}
 
+	if c.NATS.Stream != "" && c.NATS.Durable == "" {
+		return fmt.Errorf("NATS durable is required when stream is set")
+	}
+
+	if c.Jobs.AdminAPIEnabled && c.Server.Environment == "production" {
+		return fmt.Errorf("jobs admin API must not be enabled in production")
+	}
+
+	if c.Jobs.DocumentExpiryNotice <= 0 {
+		return fmt.Errorf("jobs document expiry notice days must be positive")
+	}
+
+	if c.Features.AdminAPIEnabled && c.Server.Environment == "production" {
+		return fmt.Errorf("features admin API must not be enabled in production")
+	}
+
+	if c.Heartbeat.Timeout <= 0 || c.Heartbeat.MinInterval <= 0 {
+		return fmt.Errorf("heartbeat timeout and min interval must be positive")
+	}
+
+	if c.Heartbeat.MinInterval >= c.Heartbeat.Timeout {
+		return fmt.Errorf("heartbeat min interval must be less than timeout")
+	}
+
+	for _, proxy := range c.Server.TrustedProxies {
+		if net.ParseIP(proxy) == nil {
+			if _, _, err := net.ParseCIDR(proxy); err != nil {
+				return fmt.Errorf("invalid trusted proxy %q: must be an IP address or CIDR", proxy)
+			}
+		}
+	}
+
+	if c.Limits.AdminAPIEnabled && c.Server.Environment == "production" {
+		return fmt.Errorf("limits admin API must not be enabled in production")
+	}
+
+	if err := c.Geofence.Validate(); err != nil {
+		return err
+	}
+
+	return c.Limits.Validate()
+}
+
+// Validate проверяет геозоны: уникальные ID, координаты центра и положительный радиус
+func (c GeofenceConfig) Validate() error {
+	ids := make(map[string]bool, len(c.Zones))
+	for _, zone := range c.Zones {
+		if zone.ID == "" {
+			return fmt.Errorf("geofence zone id is required")
+		}
+		if ids[zone.ID] {
+			return fmt.Errorf("duplicate geofence zone id: %s", zone.ID)
+		}
+		ids[zone.ID] = true
+
+		if zone.Latitude < -90 || zone.Latitude > 90 || zone.Longitude < -180 || zone.Longitude > 180 {
+			return fmt.Errorf("invalid geofence zone %s center: %f, %f", zone.ID, zone.Latitude, zone.Longitude)
+		}
+		if zone.RadiusKm <= 0 {
+			return fmt.Errorf("geofence zone %s radius must be positive", zone.ID)
+		}
+	}
+	return nil
+}
+
+// Validate проверяет ограничения API; вызывается и при перечитывании, чтобы ошибка в файле не сбросила лимиты
+func (c LimitsConfig) Validate() error {
+	if c.RequestsPerSecond < 0 || c.Burst < 0 {
+		return fmt.Errorf("limits requests per second and burst must not be negative")
+	}
+
+	if c.NearbyRadiusKm <= 0 {
+		return fmt.Errorf("limits nearby radius must be positive")
+	}
+
 	return nil
 }
\ No newline at end of file
@@ -0,0 +1,146 @@
+package consistency
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/database"
+
+	"go.uber.org/zap"
+)
+
+// Rule правило проверки согласованности данных. Правило только читает данные:
+// исправления возвращаются в отчете как SQL для ручного применения.
+type Rule interface {
+	Name() string
+	Description() string
+	Check(ctx context.Context, db *database.DB) ([]Violation, error)
+}
+
+// Violation нарушение, найденное правилом
+type Violation struct {
+	Entity    string `json:"entity"`               // тип записи: driver, shift
+	EntityID  string `json:"entity_id"`            // идентификатор записи
+	Details   string `json:"details"`              // описание нарушения
+	RepairSQL string `json:"repair_sql,omitempty"` // предлагаемое исправление; пусто, если нужен разбор вручную
+}
+
+// RuleResult результат проверки одного правила
+type RuleResult struct {
+	Rule        string      `json:"rule"`
+	Description string      `json:"description"`
+	Violations  []Violation `json:"violations"`
+	Error       string      `json:"error,omitempty"` // правило не удалось выполнить
+}
+
+// Report отчет проверки согласованности
+type Report struct {
+	StartedAt  time.Time    `json:"started_at"`
+	FinishedAt time.Time    `json:"finished_at"`
+	Consistent bool         `json:"consistent"` // все правила выполнены и нарушений нет
+	Violations int          `json:"violations"` // общее количество нарушений
+	Rules      []RuleResult `json:"rules"`
+}
+
+// RepairStatements возвращает предложенные исправления в порядке правил
+func (r *Report) RepairStatements() []string {
+	var statements []string
+	for _, result := range r.Rules {
+		for _, violation := range result.Violations {
+			if violation.RepairSQL != "" {
+				statements = append(statements, violation.RepairSQL)
+			}
+		}
+	}
+	return statements
+}
+
+// Checker выполняет набор правил и собирает отчет
+type Checker struct {
+	db     *database.DB
+	rules  []Rule
+	logger *zap.Logger
+}
+
+// NewChecker создает Checker с правилами rules (см. DefaultRules)
+func NewChecker(db *database.DB, logger *zap.Logger, rules ...Rule) *Checker {
+	return &Checker{
+		db:     db,
+		rules:  rules,
+		logger: logger,
+	}
+}
+
+// Rules возвращает имена правил в порядке выполнения
+func (c *Checker) Rules() []string {
+	names := make([]string, 0, len(c.rules))
+	for _, rule := range c.rules {
+		names = append(names, rule.Name())
+	}
+	return names
+}
+
+// Run выполняет правила names (все, если names пуст). Ошибка одного правила
+// не останавливает проверку: она попадает в отчет, и отчет считается несогласованным.
+func (c *Checker) Run(ctx context.Context, names ...string) (*Report, error) {
+	rules, err := c.selectRules(names)
+	if err != nil {
+		return nil, err
+	}
+
+	report := &Report{StartedAt: time.Now(), Consistent: true, Rules: make([]RuleResult, 0, len(rules))}
+
+	for _, rule := range rules {
+		result := RuleResult{Rule: rule.Name(), Description: rule.Description(), Violations: []Violation{}}
+
+		violations, err := rule.Check(ctx, c.db)
+		if err != nil {
+			c.logger.Error("Consistency rule failed", zap.Error(err), zap.String("rule", rule.Name()))
+			result.Error = err.Error()
+			report.Consistent = false
+		} else if len(violations) > 0 {
+			c.logger.Warn("Consistency violations found",
+				zap.String("rule", rule.Name()),
+				zap.Int("violations", len(violations)),
+			)
+			result.Violations = violations
+			report.Violations += len(violations)
+			report.Consistent = false
+		}
+
+		report.Rules = append(report.Rules, result)
+	}
+
+	report.FinishedAt = time.Now()
+	return report, nil
+}
+
+// selectRules возвращает правила по именам в порядке регистрации
+func (c *Checker) selectRules(names []string) ([]Rule, error) {
+	if len(names) == 0 {
+		return c.rules, nil
+	}
+
+	known := make(map[string]bool, len(c.rules))
+	for _, rule := range c.rules {
+		known[rule.Name()] = true
+	}
+
+	requested := make(map[string]bool, len(names))
+	for _, name := range names {
+		if !known[name] {
+			return nil, fmt.Errorf("%w: %s", entities.ErrConsistencyRuleNotFound, name)
+		}
+		requested[name] = true
+	}
+
+	selected := make([]Rule, 0, len(requested))
+	for _, rule := range c.rules {
+		if requested[rule.Name()] {
+			selected = append(selected, rule)
+		}
+	}
+	return selected, nil
+}
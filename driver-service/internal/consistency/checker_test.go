@@ -0,0 +1,107 @@
+package consistency
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/database"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// stubRule правило с заранее заданным результатом
+type stubRule struct {
+	name       string
+	violations []Violation
+	err        error
+}
+
+func (r stubRule) Name() string        { return r.name }
+func (r stubRule) Description() string { return r.name + " description" }
+
+func (r stubRule) Check(ctx context.Context, db *database.DB) ([]Violation, error) {
+	return r.violations, r.err
+}
+
+func TestCheckerRun(t *testing.T) {
+	// Arrange
+	checker := NewChecker(nil, zap.NewNop(),
+		stubRule{name: "clean"},
+		stubRule{name: "broken", err: errors.New("relation does not exist")},
+		stubRule{name: "dirty", violations: []Violation{
+			{Entity: "shift", EntityID: "1", Details: "first", RepairSQL: "UPDATE one"},
+			{Entity: "shift", EntityID: "2", Details: "manual review"},
+		}},
+	)
+
+	// Act
+	report, err := checker.Run(context.Background())
+
+	// Assert
+	require.NoError(t, err)
+	assert.False(t, report.Consistent)
+	assert.Equal(t, 2, report.Violations)
+	assert.False(t, report.FinishedAt.Before(report.StartedAt))
+
+	require.Len(t, report.Rules, 3)
+	assert.Equal(t, "clean", report.Rules[0].Rule)
+	assert.NotNil(t, report.Rules[0].Violations, "Пустой список нарушений сериализуется как []")
+	assert.Empty(t, report.Rules[0].Violations)
+	assert.Equal(t, "relation does not exist", report.Rules[1].Error, "Ошибка правила попадает в отчет")
+	assert.Len(t, report.Rules[2].Violations, 2, "Правила после упавшего выполняются")
+
+	assert.Equal(t, []string{"UPDATE one"}, report.RepairStatements())
+
+	t.Run("failed rule alone makes report inconsistent", func(t *testing.T) {
+		report, err := checker.Run(context.Background(), "broken")
+
+		require.NoError(t, err)
+		assert.False(t, report.Consistent)
+		assert.Zero(t, report.Violations)
+	})
+}
+
+func TestCheckerSelectRules(t *testing.T) {
+	// Arrange
+	checker := NewChecker(nil, zap.NewNop(), stubRule{name: "first"}, stubRule{name: "second"}, stubRule{name: "third"})
+
+	t.Run("registration order", func(t *testing.T) {
+		report, err := checker.Run(context.Background(), "third", "first", "third")
+
+		require.NoError(t, err)
+		assert.True(t, report.Consistent)
+		require.Len(t, report.Rules, 2)
+		assert.Equal(t, "first", report.Rules[0].Rule)
+		assert.Equal(t, "third", report.Rules[1].Rule)
+	})
+
+	t.Run("unknown rule", func(t *testing.T) {
+		_, err := checker.Run(context.Background(), "first", "cache_orphans")
+		assert.ErrorIs(t, err, entities.ErrConsistencyRuleNotFound)
+	})
+
+	assert.Equal(t, []string{"first", "second", "third"}, checker.Rules())
+}
+
+func TestDefaultRules(t *testing.T) {
+	// Act
+	rules := DefaultRules(DefaultLocationStaleAfter)
+
+	// Assert
+	names := make(map[string]bool, len(rules))
+	for _, rule := range rules {
+		assert.False(t, names[rule.Name()], "Имя правила %s повторяется", rule.Name())
+		names[rule.Name()] = true
+		assert.NotEmpty(t, rule.Description())
+	}
+
+	for _, name := range []string{
+		RuleOrphanedLocations, RuleNegativeEarnings, RuleOverlappingShifts, RuleStaleActiveStatus, RuleRatingStatsCache,
+	} {
+		assert.True(t, names[name], "Нет правила %s", name)
+	}
+}
@@ -0,0 +1,177 @@
+package consistency
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"time"
+
+	"driver-service/internal/infrastructure/database"
+)
+
+// Имена правил DefaultRules
+const (
+	RuleOrphanedLocations = "orphaned_locations"  // история местоположений без водителя
+	RuleNegativeEarnings  = "negative_earnings"   // смены с отрицательным заработком
+	RuleOverlappingShifts = "overlapping_shifts"  // пересекающиеся смены одного водителя
+	RuleStaleActiveStatus = "stale_active_status" // водитель на линии без свежего местоположения
+	RuleRatingStatsCache  = "rating_stats_cache"  // агрегаты оценок расходятся с оценками
+)
+
+// DefaultLocationStaleAfter местоположение старше считается устаревшим
+// (тот же порог, после которого LocationService.GetCurrentLocation возвращает ErrLocationTooOld)
+const DefaultLocationStaleAfter = 10 * time.Minute
+
+// SQLRule правило, нарушения которого выбираются одним запросом. Запрос возвращает колонки
+// entity_id, details и repair_sql; repair_sql равен NULL, если исправление нужно выбирать вручную.
+// Литералы в repair_sql экранируются в запросе через format('%L').
+type SQLRule struct {
+	RuleName        string
+	RuleDescription string
+	Entity          string
+	Query           string
+	Args            []interface{}
+}
+
+// Name возвращает имя правила
+func (r SQLRule) Name() string {
+	return r.RuleName
+}
+
+// Description возвращает описание правила
+func (r SQLRule) Description() string {
+	return r.RuleDescription
+}
+
+// Check выполняет запрос правила
+func (r SQLRule) Check(ctx context.Context, db *database.DB) ([]Violation, error) {
+	var rows []struct {
+		EntityID  string         `db:"entity_id"`
+		Details   string         `db:"details"`
+		RepairSQL sql.NullString `db:"repair_sql"`
+	}
+	if err := db.SelectContext(ctx, &rows, r.Query, r.Args...); err != nil {
+		return nil, fmt.Errorf("failed to check %s: %w", r.RuleName, err)
+	}
+
+	violations := make([]Violation, 0, len(rows))
+	for _, row := range rows {
+		violations = append(violations, Violation{
+			Entity:    r.Entity,
+			EntityID:  row.EntityID,
+			Details:   row.Details,
+			RepairSQL: row.RepairSQL.String,
+		})
+	}
+	return violations, nil
+}
+
+// DefaultRules возвращает правила проверки данных сервиса. Часть нарушений схема уже запрещает
+// (внешние ключи, CHECK); правила все равно их ищут, потому что такие данные появляются
+// при восстановлении дампов с отключенными триггерами и при ручных правках.
+//
+// Redis хранит только сессии приложения водителя, поэтому кэшем здесь считается driver_rating_stats: таблицу
+// агрегатов, которую триггер пересчитывает по driver_ratings.
+func DefaultRules(locationStaleAfter time.Duration) []Rule {
+	return []Rule{
+		SQLRule{
+			RuleName:        RuleOrphanedLocations,
+			RuleDescription: "driver_locations rows referencing a driver that does not exist",
+			Entity:          "driver",
+			Query: `
+				SELECT
+					l.driver_id::text AS entity_id,
+					format('%s location records reference a missing driver', COUNT(*)) AS details,
+					format('DELETE FROM driver_locations WHERE driver_id = %L', l.driver_id) AS repair_sql
+				FROM driver_locations l
+				LEFT JOIN drivers d ON d.id = l.driver_id
+				WHERE d.id IS NULL
+				GROUP BY l.driver_id
+				ORDER BY l.driver_id`,
+		},
+		SQLRule{
+			RuleName:        RuleNegativeEarnings,
+			RuleDescription: "driver_shifts with negative total_earnings",
+			Entity:          "shift",
+			Query: `
+				SELECT
+					id::text AS entity_id,
+					format('shift total_earnings is negative: %s', total_earnings) AS details,
+					format('UPDATE driver_shifts SET total_earnings = 0 WHERE id = %L AND total_earnings < 0', id) AS repair_sql
+				FROM driver_shifts
+				WHERE total_earnings < 0
+				ORDER BY id`,
+		},
+		SQLRule{
+			// Для каждой смены берется ближайшая пересекающаяся с ней более поздняя смена;
+			// исправление завершает раннюю смену началом поздней. Смены с одинаковым началом
+			// так не исправить, их нужно разобрать вручную.
+			RuleName:        RuleOverlappingShifts,
+			RuleDescription: "driver_shifts of the same driver overlapping in time (cancelled shifts are ignored)",
+			Entity:          "shift",
+			Query: `
+				SELECT DISTINCT ON (a.id)
+					a.id::text AS entity_id,
+					format('shift overlaps shift %s of driver %s', b.id, a.driver_id) AS details,
+					CASE WHEN b.start_time > a.start_time THEN
+						format('UPDATE driver_shifts SET end_time = %L, status = CASE WHEN status = %L THEN %L ELSE status END WHERE id = %L',
+							b.start_time, 'active', 'completed', a.id)
+					END AS repair_sql
+				FROM driver_shifts a
+				JOIN driver_shifts b
+					ON b.driver_id = a.driver_id
+					AND (b.start_time, b.id) > (a.start_time, a.id)
+				WHERE a.status <> 'cancelled' AND b.status <> 'cancelled'
+					AND tstzrange(a.start_time, a.end_time) && tstzrange(b.start_time, b.end_time)
+				ORDER BY a.id, b.start_time, b.id`,
+		},
+		SQLRule{
+			// Статусы на линии совпадают с Driver.IsActive
+			RuleName:        RuleStaleActiveStatus,
+			RuleDescription: "drivers on the line whose last location is missing or stale",
+			Entity:          "driver",
+			Query: `
+				SELECT
+					d.id::text AS entity_id,
+					CASE WHEN l.recorded_at IS NULL
+						THEN format('driver is %s but has never reported a location', d.status)
+						ELSE format('driver is %s but last location is from %s', d.status, l.recorded_at)
+					END AS details,
+					format('UPDATE drivers SET status = %L WHERE id = %L AND status = %L', 'offline', d.id, d.status) AS repair_sql
+				FROM drivers d
+				LEFT JOIN LATERAL (
+					SELECT recorded_at FROM driver_locations
+					WHERE driver_id = d.id
+					ORDER BY recorded_at DESC
+					LIMIT 1
+				) l ON TRUE
+				WHERE d.deleted_at IS NULL
+					AND d.status IN ('available', 'on_shift', 'busy')
+					AND (l.recorded_at IS NULL OR l.recorded_at < NOW() - make_interval(secs => $1))
+				ORDER BY d.id`,
+			Args: []interface{}{locationStaleAfter.Seconds()},
+		},
+		SQLRule{
+			RuleName:        RuleRatingStatsCache,
+			RuleDescription: "driver_rating_stats rows missing, orphaned or disagreeing with driver_ratings",
+			Entity:          "driver",
+			Query: `
+				SELECT
+					COALESCE(s.driver_id, r.driver_id)::text AS entity_id,
+					format('rating stats have %s ratings averaging %s, driver_ratings have %s averaging %s',
+						COALESCE(s.total_ratings, 0), COALESCE(s.average_rating, 0),
+						COALESCE(r.total, 0), COALESCE(r.average, 0)) AS details,
+					format('SELECT update_driver_rating_stats(%L)', COALESCE(s.driver_id, r.driver_id)) AS repair_sql
+				FROM driver_rating_stats s
+				FULL JOIN (
+					SELECT driver_id, COUNT(*) AS total, ROUND(AVG(rating), 2) AS average
+					FROM driver_ratings
+					GROUP BY driver_id
+				) r ON r.driver_id = s.driver_id
+				WHERE s.driver_id IS NULL
+					OR s.total_ratings <> COALESCE(r.total, 0)
+					OR s.average_rating <> COALESCE(r.average, 0)
+				ORDER BY 1`,
+		},
+	}
+}
@@ -0,0 +1,14 @@
+package entities
+
+import (
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// ConnectionLoss потеря связи с активным водителем: его последнее местоположение устарело
+type ConnectionLoss struct {
+	DriverID       uuid.UUID `json:"driver_id" db:"driver_id"`
+	LastLocationAt time.Time `json:"last_location_at" db:"last_location_at"` // время последнего местоположения до потери связи
+	LostAt         time.Time `json:"lost_at" db:"lost_at"`
+}
@@ -0,0 +1,125 @@
+package entities
+
+import (
+	"regexp"
+	"strings"
+	"time"
+)
+
+// Country страна выдачи паспорта и водительского удостоверения водителя (ISO 3166-1 alpha-2)
+type Country string
+
+const (
+	CountryRU Country = "RU" // Россия
+	CountryBY Country = "BY" // Беларусь
+	CountryKZ Country = "KZ" // Казахстан
+	CountryUZ Country = "UZ" // Узбекистан
+	CountryKG Country = "KG" // Кыргызстан
+
+	// DefaultCountry страна документов, если водитель ее не указал
+	DefaultCountry = CountryRU
+)
+
+// driverMetadataDocumentCountry ключ метаданных водителя со страной выдачи документов
+const driverMetadataDocumentCountry = "document_country"
+
+// documentFormat форматы паспорта и водительского удостоверения страны
+type documentFormat struct {
+	passportSeries *regexp.Regexp
+	passportNumber *regexp.Regexp
+	licenseNumber  *regexp.Regexp
+}
+
+// documentFormats форматы документов поддерживаемых стран. Пробелы в номерах не учитываются.
+var documentFormats = map[Country]documentFormat{
+	// Паспорт: серия 4 цифры, номер 6 цифр; ВУ: 10 знаков (серия и номер)
+	CountryRU: {
+		passportSeries: regexp.MustCompile(`^\d{4}$`),
+		passportNumber: regexp.MustCompile(`^\d{6}$`),
+		licenseNumber:  regexp.MustCompile(`^[0-9A-Z]{10}$`),
+	},
+	// Паспорт: серия 2 латинские буквы (MP, AB), номер 7 цифр; ВУ: цифра, 2 буквы, 6 цифр
+	CountryBY: {
+		passportSeries: regexp.MustCompile(`^[A-Z]{2}$`),
+		passportNumber: regexp.MustCompile(`^\d{7}$`),
+		licenseNumber:  regexp.MustCompile(`^\d[A-Z]{2}\d{6}$`),
+	},
+	// Паспорт: серия N, номер 8 цифр; ВУ: 2 буквы, 6 цифр
+	CountryKZ: {
+		passportSeries: regexp.MustCompile(`^N$`),
+		passportNumber: regexp.MustCompile(`^\d{8}$`),
+		licenseNumber:  regexp.MustCompile(`^[A-Z]{2}\d{6}$`),
+	},
+	// Паспорт: серия 2 латинские буквы (AA, FA), номер 7 цифр; ВУ: 2 буквы, 7 цифр
+	CountryUZ: {
+		passportSeries: regexp.MustCompile(`^[A-Z]{2}$`),
+		passportNumber: regexp.MustCompile(`^\d{7}$`),
+		licenseNumber:  regexp.MustCompile(`^[A-Z]{2}\d{7}$`),
+	},
+	// Паспорт: серия ID или AC, номер 7 цифр; ВУ: 3 буквы, 6 цифр
+	CountryKG: {
+		passportSeries: regexp.MustCompile(`^(ID|AC)$`),
+		passportNumber: regexp.MustCompile(`^\d{7}$`),
+		licenseNumber:  regexp.MustCompile(`^[A-Z]{3}\d{6}$`),
+	},
+}
+
+// SupportedCountries возвращает страны, документы которых принимаются при регистрации, в стабильном порядке
+func SupportedCountries() []Country {
+	return []Country{CountryRU, CountryBY, CountryKZ, CountryUZ, CountryKG}
+}
+
+// ParseCountry разбирает код страны без учета регистра
+func ParseCountry(value string) (Country, error) {
+	country := Country(strings.ToUpper(strings.TrimSpace(value)))
+	if _, ok := documentFormats[country]; !ok {
+		return "", ErrUnsupportedCountry
+	}
+	return country, nil
+}
+
+// validate проверяет серию и номер паспорта и номер ВУ по форматам страны
+func (f documentFormat) validate(passportSeries, passportNumber, licenseNumber string) error {
+	if !f.passportSeries.MatchString(compactDocumentNumber(passportSeries)) ||
+		!f.passportNumber.MatchString(compactDocumentNumber(passportNumber)) {
+		return ErrInvalidPassport
+	}
+
+	if !f.licenseNumber.MatchString(compactDocumentNumber(licenseNumber)) {
+		return ErrInvalidLicense
+	}
+
+	return nil
+}
+
+// compactDocumentNumber убирает пробелы из серии или номера документа ("12 34" -> "1234")
+func compactDocumentNumber(value string) string {
+	return strings.ReplaceAll(value, " ", "")
+}
+
+// SetDocumentCountry сохраняет страну выдачи паспорта и ВУ водителя
+func (d *Driver) SetDocumentCountry(country Country) error {
+	if _, ok := documentFormats[country]; !ok {
+		return ErrUnsupportedCountry
+	}
+	if d.Metadata == nil {
+		d.Metadata = make(Metadata)
+	}
+	d.Metadata[driverMetadataDocumentCountry] = string(country)
+	d.UpdatedAt = time.Now()
+	return nil
+}
+
+// DocumentCountry возвращает страну выдачи документов водителя (DefaultCountry, если не указана или не поддерживается)
+func (d *Driver) DocumentCountry() Country {
+	country, _ := d.Metadata[driverMetadataDocumentCountry].(string)
+	if _, ok := documentFormats[Country(country)]; !ok {
+		return DefaultCountry
+	}
+	return Country(country)
+}
+
+// ValidateDocuments проверяет паспорт и ВУ водителя по форматам страны выдачи
+func (d *Driver) ValidateDocuments() error {
+	return documentFormats[d.DocumentCountry()].validate(d.PassportSeries, d.PassportNumber, d.LicenseNumber)
+}
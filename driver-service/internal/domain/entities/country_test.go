@@ -0,0 +1,101 @@
+package entities
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestParseCountry(t *testing.T) {
+	testCases := []struct {
+		value       string
+		expected    Country
+		expectedErr error
+	}{
+		{value: "RU", expected: CountryRU},
+		{value: " kz ", expected: CountryKZ},
+		{value: "by", expected: CountryBY},
+		{value: "DE", expectedErr: ErrUnsupportedCountry},
+		{value: "", expectedErr: ErrUnsupportedCountry},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.value, func(t *testing.T) {
+			// Act
+			country, err := ParseCountry(tc.value)
+
+			// Assert
+			assert.ErrorIs(t, err, tc.expectedErr)
+			assert.Equal(t, tc.expected, country)
+		})
+	}
+}
+
+func TestDriver_DocumentCountry(t *testing.T) {
+	// Arrange
+	driver := &Driver{}
+	assert.Equal(t, DefaultCountry, driver.DocumentCountry())
+
+	// Act & Assert
+	require.NoError(t, driver.SetDocumentCountry(CountryUZ))
+	assert.Equal(t, CountryUZ, driver.DocumentCountry())
+
+	assert.ErrorIs(t, driver.SetDocumentCountry("DE"), ErrUnsupportedCountry)
+	assert.Equal(t, CountryUZ, driver.DocumentCountry(), "Неподдерживаемая страна не сохраняется")
+
+	for _, country := range SupportedCountries() {
+		_, ok := documentFormats[country]
+		assert.True(t, ok, "Для %s заданы форматы документов", country)
+	}
+}
+
+func TestDriver_ValidateDocuments(t *testing.T) {
+	testCases := []struct {
+		name           string
+		country        Country
+		passportSeries string
+		passportNumber string
+		licenseNumber  string
+		expectedErr    error
+	}{
+		{name: "Russia", country: CountryRU, passportSeries: "45 08", passportNumber: "123456", licenseNumber: "77 AB 123456"},
+		{name: "Belarus", country: CountryBY, passportSeries: "MP", passportNumber: "1234567", licenseNumber: "5AB123456"},
+		{name: "Kazakhstan", country: CountryKZ, passportSeries: "N", passportNumber: "12345678", licenseNumber: "AB123456"},
+		{name: "Uzbekistan", country: CountryUZ, passportSeries: "AA", passportNumber: "1234567", licenseNumber: "AF1234567"},
+		{name: "Kyrgyzstan", country: CountryKG, passportSeries: "ID", passportNumber: "1234567", licenseNumber: "KGZ123456"},
+		{
+			name: "Russian passport as Belarusian", country: CountryBY,
+			passportSeries: "4508", passportNumber: "123456", licenseNumber: "5AB123456",
+			expectedErr: ErrInvalidPassport,
+		},
+		{
+			name: "Kazakh license with Russian passport", country: CountryRU,
+			passportSeries: "4508", passportNumber: "123456", licenseNumber: "AB123456",
+			expectedErr: ErrInvalidLicense,
+		},
+		{
+			name: "lowercase Uzbek series", country: CountryUZ,
+			passportSeries: "aa", passportNumber: "1234567", licenseNumber: "AF1234567",
+			expectedErr: ErrInvalidPassport,
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			// Arrange
+			driver := &Driver{
+				PassportSeries: tc.passportSeries,
+				PassportNumber: tc.passportNumber,
+				LicenseNumber:  tc.licenseNumber,
+			}
+			require.NoError(t, driver.SetDocumentCountry(tc.country))
+
+			// Act
+			err := driver.ValidateDocuments()
+
+			// Assert
+			assert.ErrorIs(t, err, tc.expectedErr)
+		})
+	}
+}
@@ -0,0 +1,118 @@
+package entities
+
+import (
+	"encoding/base64"
+	"encoding/json"
+	"strconv"
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// DriverCursor позиция в списке водителей: значение поля сортировки и id последнего водителя страницы.
+// Следующая страница начинается строго после этой позиции, поэтому водители, добавленные или удаленные
+// между запросами, не сдвигают ее, как сдвигают offset.
+type DriverCursor struct {
+	SortBy    string    `json:"sort_by"`
+	Direction string    `json:"direction"`
+	Value     string    `json:"value"`
+	ID        uuid.UUID `json:"id"`
+}
+
+// ParseDriverCursor разбирает курсор из параметра cursor
+func ParseDriverCursor(encoded string) (*DriverCursor, error) {
+	data, err := base64.RawURLEncoding.DecodeString(encoded)
+	if err != nil {
+		return nil, ErrInvalidCursor
+	}
+
+	var cursor DriverCursor
+	if err := json.Unmarshal(data, &cursor); err != nil || cursor.ID == uuid.Nil {
+		return nil, ErrInvalidCursor
+	}
+
+	// Значение подставляется в запрос с приведением к типу столбца: проверяем его заранее
+	switch cursor.SortBy {
+	case "created_at":
+		_, err = time.Parse(time.RFC3339Nano, cursor.Value)
+	case "current_rating":
+		_, err = strconv.ParseFloat(cursor.Value, 64)
+	case "total_trips":
+		_, err = strconv.Atoi(cursor.Value)
+	default:
+		return nil, ErrInvalidCursor
+	}
+	if err != nil || (cursor.Direction != "asc" && cursor.Direction != "desc") {
+		return nil, ErrInvalidCursor
+	}
+
+	return &cursor, nil
+}
+
+// Encode возвращает курсор в виде непрозрачной строки для параметра cursor
+func (c *DriverCursor) Encode() string {
+	data, _ := json.Marshal(c)
+	return base64.RawURLEncoding.EncodeToString(data)
+}
+
+// LocationCursor позиция в истории местоположений водителя: время и id последней точки страницы.
+// Точки, записанные между запросами страниц, не сдвигают следующую страницу.
+type LocationCursor struct {
+	RecordedAt time.Time `json:"recorded_at"`
+	ID         uuid.UUID `json:"id"`
+}
+
+// ParseLocationCursor разбирает курсор истории местоположений из параметра cursor
+func ParseLocationCursor(encoded string) (*LocationCursor, error) {
+	data, err := base64.RawURLEncoding.DecodeString(encoded)
+	if err != nil {
+		return nil, ErrInvalidCursor
+	}
+
+	var cursor LocationCursor
+	if err := json.Unmarshal(data, &cursor); err != nil || cursor.ID == uuid.Nil || cursor.RecordedAt.IsZero() {
+		return nil, ErrInvalidCursor
+	}
+	return &cursor, nil
+}
+
+// Encode возвращает курсор в виде непрозрачной строки для параметра cursor
+func (c *LocationCursor) Encode() string {
+	data, _ := json.Marshal(c)
+	return base64.RawURLEncoding.EncodeToString(data)
+}
+
+// LocationCursorAfter возвращает курсор, с которого начинается страница истории после точки location
+func LocationCursorAfter(location *DriverLocation) *LocationCursor {
+	return &LocationCursor{RecordedAt: location.RecordedAt, ID: location.ID}
+}
+
+// SortField возвращает поле сортировки списка (created_at, если не задано)
+func (f *DriverFilters) SortField() string {
+	if f.SortBy == "" {
+		return "created_at"
+	}
+	return f.SortBy
+}
+
+// SortOrder возвращает направление сортировки списка: без sort_by - desc, с sort_by - asc, если не задано
+func (f *DriverFilters) SortOrder() string {
+	if f.SortBy == "" || f.SortDirection == "desc" {
+		return "desc"
+	}
+	return "asc"
+}
+
+// CursorAfter возвращает курсор, с которого начинается страница после водителя driver
+func (f *DriverFilters) CursorAfter(driver *Driver) *DriverCursor {
+	cursor := &DriverCursor{SortBy: f.SortField(), Direction: f.SortOrder(), ID: driver.ID}
+	switch cursor.SortBy {
+	case "current_rating":
+		cursor.Value = strconv.FormatFloat(driver.CurrentRating, 'f', -1, 64)
+	case "total_trips":
+		cursor.Value = strconv.Itoa(driver.TotalTrips)
+	default:
+		cursor.Value = driver.CreatedAt.UTC().Format(time.RFC3339Nano)
+	}
+	return cursor
+}
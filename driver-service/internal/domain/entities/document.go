@@ -30,6 +30,47 @@ const (
 	VerificationStatusProcessing VerificationStatus = "processing"
 )
 
+// ScanStatus статус антивирусной проверки файла документа
+type ScanStatus string
+
+const (
+	ScanStatusPending  ScanStatus = "pending"
+	ScanStatusClean    ScanStatus = "clean"
+	ScanStatusInfected ScanStatus = "infected"
+	ScanStatusFailed   ScanStatus = "failed"
+)
+
+// ScanRejectionReason причина отклонения документа с зараженным файлом
+const ScanRejectionReason = "file failed antivirus scan"
+
+// scanVerifierID проверяющий, от имени которого документ отклоняется по результату антивирусной проверки
+const scanVerifierID = "antivirus"
+
+// Ключи метаданных документа для файла в объектном хранилище
+const (
+	documentMetadataBucket      = "storage_bucket"
+	documentMetadataObjectKey   = "storage_key"
+	documentMetadataContentType = "content_type"
+	documentMetadataSize        = "size_bytes"
+	documentMetadataETag        = "etag"
+	documentMetadataScanStatus  = "scan_status"
+)
+
+// scanTransitions допустимые переходы статуса антивирусной проверки
+var scanTransitions = map[ScanStatus][]ScanStatus{
+	ScanStatusPending: {ScanStatusClean, ScanStatusInfected, ScanStatusFailed},
+	ScanStatusFailed:  {ScanStatusPending, ScanStatusClean, ScanStatusInfected},
+}
+
+// DocumentObject файл документа в объектном хранилище
+type DocumentObject struct {
+	Bucket      string `json:"bucket"`
+	Key         string `json:"key"`
+	ContentType string `json:"content_type"`
+	Size        int64  `json:"size"`
+	ETag        string `json:"etag"`
+}
+
 // DriverDocument представляет документ водителя
 type DriverDocument struct {
 	ID             uuid.UUID          `json:"id" db:"id"`
@@ -43,6 +84,8 @@ type DriverDocument struct {
 	VerifiedBy     *string            `json:"verified_by,omitempty" db:"verified_by"`
 	VerifiedAt     *time.Time         `json:"verified_at,omitempty" db:"verified_at"`
 	RejectionReason *string           `json:"rejection_reason,omitempty" db:"rejection_reason"`
+	// ExpiryNotifiedFor срок действия, о котором водитель уже получил push (NotifyExpiringDocuments)
+	ExpiryNotifiedFor *time.Time      `json:"-" db:"expiry_notified_for"`
 	Metadata       Metadata           `json:"metadata" db:"metadata"`
 	CreatedAt      time.Time          `json:"created_at" db:"created_at"`
 	UpdatedAt      time.Time          `json:"updated_at" db:"updated_at"`
@@ -90,6 +133,79 @@ func (d *DriverDocument) MarkExpired() {
 	d.UpdatedAt = time.Now()
 }
 
+// AttachObject привязывает к документу загруженный файл и ставит его на антивирусную проверку.
+// До окончания проверки документ находится в статусе processing.
+func (d *DriverDocument) AttachObject(object DocumentObject) {
+	if d.Metadata == nil {
+		d.Metadata = make(Metadata)
+	}
+	d.Metadata[documentMetadataBucket] = object.Bucket
+	d.Metadata[documentMetadataObjectKey] = object.Key
+	d.Metadata[documentMetadataContentType] = object.ContentType
+	d.Metadata[documentMetadataSize] = float64(object.Size)
+	d.Metadata[documentMetadataETag] = object.ETag
+	d.Metadata[documentMetadataScanStatus] = string(ScanStatusPending)
+
+	d.Status = VerificationStatusProcessing
+	d.UpdatedAt = time.Now()
+}
+
+// Object возвращает файл документа в объектном хранилище
+func (d *DriverDocument) Object() (DocumentObject, bool) {
+	key, ok := d.Metadata[documentMetadataObjectKey].(string)
+	if !ok || key == "" {
+		return DocumentObject{}, false
+	}
+
+	bucket, _ := d.Metadata[documentMetadataBucket].(string)
+	contentType, _ := d.Metadata[documentMetadataContentType].(string)
+	size, _ := d.Metadata[documentMetadataSize].(float64)
+	etag, _ := d.Metadata[documentMetadataETag].(string)
+
+	return DocumentObject{
+		Bucket:      bucket,
+		Key:         key,
+		ContentType: contentType,
+		Size:        int64(size),
+		ETag:        etag,
+	}, true
+}
+
+// ScanStatus возвращает статус антивирусной проверки файла (пустой, если файл не загружался)
+func (d *DriverDocument) ScanStatus() ScanStatus {
+	status, _ := d.Metadata[documentMetadataScanStatus].(string)
+	return ScanStatus(status)
+}
+
+// SetScanStatus применяет результат антивирусной проверки: чистый файл передается на ручную
+// верификацию, зараженный документ отклоняется, после сбоя проверку можно повторить
+func (d *DriverDocument) SetScanStatus(status ScanStatus) error {
+	allowed := false
+	for _, next := range scanTransitions[d.ScanStatus()] {
+		if next == status {
+			allowed = true
+			break
+		}
+	}
+	if !allowed {
+		return ErrInvalidScanTransition
+	}
+
+	d.Metadata[documentMetadataScanStatus] = string(status)
+
+	switch status {
+	case ScanStatusClean:
+		d.Status = VerificationStatusPending
+		d.UpdatedAt = time.Now()
+	case ScanStatusInfected:
+		d.Reject(scanVerifierID, ScanRejectionReason)
+	default:
+		d.Status = VerificationStatusProcessing
+		d.UpdatedAt = time.Now()
+	}
+	return nil
+}
+
 // Validate проверяет валидность данных документа
 func (d *DriverDocument) Validate() error {
 	if d.DriverID == uuid.Nil {
@@ -0,0 +1,100 @@
+package entities
+
+import (
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// newUploadedDocument создает документ с привязанным файлом
+func newUploadedDocument() *DriverDocument {
+	document := NewDriverDocument(uuid.New(), DocumentTypeDriverLicense, "77AA123456",
+		time.Now().AddDate(-1, 0, 0), time.Now().AddDate(9, 0, 0), "http://storage/documents/license.pdf")
+	document.AttachObject(DocumentObject{
+		Bucket:      "documents",
+		Key:         "drivers/license.pdf",
+		ContentType: "application/pdf",
+		Size:        1024,
+		ETag:        "9e107d9d372bb6826bd81d3542a419d6",
+	})
+	return document
+}
+
+func TestDriverDocument_AttachObject(t *testing.T) {
+	// Arrange
+	document := NewDriverDocument(uuid.New(), DocumentTypeDriverLicense, "77AA123456",
+		time.Now().AddDate(-1, 0, 0), time.Now().AddDate(9, 0, 0), "http://storage/documents/license.pdf")
+	_, ok := document.Object()
+	require.False(t, ok)
+	assert.Empty(t, document.ScanStatus())
+
+	// Act
+	document = newUploadedDocument()
+
+	// Assert
+	object, ok := document.Object()
+	require.True(t, ok)
+	assert.Equal(t, "documents", object.Bucket)
+	assert.Equal(t, "drivers/license.pdf", object.Key)
+	assert.Equal(t, "application/pdf", object.ContentType)
+	assert.Equal(t, int64(1024), object.Size)
+	assert.Equal(t, ScanStatusPending, document.ScanStatus())
+	assert.Equal(t, VerificationStatusProcessing, document.Status)
+}
+
+func TestDriverDocument_SetScanStatus(t *testing.T) {
+	testCases := []struct {
+		name           string
+		steps          []ScanStatus
+		expectedStatus VerificationStatus
+	}{
+		{name: "Clean", steps: []ScanStatus{ScanStatusClean}, expectedStatus: VerificationStatusPending},
+		{name: "Infected", steps: []ScanStatus{ScanStatusInfected}, expectedStatus: VerificationStatusRejected},
+		{name: "Failed", steps: []ScanStatus{ScanStatusFailed}, expectedStatus: VerificationStatusProcessing},
+		{name: "RescanAfterFailure", steps: []ScanStatus{ScanStatusFailed, ScanStatusPending, ScanStatusClean}, expectedStatus: VerificationStatusPending},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			// Arrange
+			document := newUploadedDocument()
+
+			// Act
+			for _, step := range tc.steps {
+				require.NoError(t, document.SetScanStatus(step))
+			}
+
+			// Assert
+			assert.Equal(t, tc.steps[len(tc.steps)-1], document.ScanStatus())
+			assert.Equal(t, tc.expectedStatus, document.Status)
+		})
+	}
+
+	t.Run("InfectedDocumentRejected", func(t *testing.T) {
+		document := newUploadedDocument()
+		require.NoError(t, document.SetScanStatus(ScanStatusInfected))
+
+		require.NotNil(t, document.RejectionReason)
+		assert.Equal(t, ScanRejectionReason, *document.RejectionReason)
+	})
+
+	t.Run("InvalidTransitions", func(t *testing.T) {
+		// Итоговые статусы не меняются
+		for _, final := range []ScanStatus{ScanStatusClean, ScanStatusInfected} {
+			document := newUploadedDocument()
+			require.NoError(t, document.SetScanStatus(final))
+
+			for _, next := range []ScanStatus{ScanStatusPending, ScanStatusClean, ScanStatusInfected, ScanStatusFailed} {
+				assert.ErrorIs(t, document.SetScanStatus(next), ErrInvalidScanTransition, "%s -> %s", final, next)
+			}
+		}
+
+		// Документ без файла нельзя проверять
+		document := NewDriverDocument(uuid.New(), DocumentTypePassport, "4510123456",
+			time.Now().AddDate(-1, 0, 0), time.Now().AddDate(9, 0, 0), "http://storage/documents/passport.pdf")
+		assert.ErrorIs(t, document.SetScanStatus(ScanStatusClean), ErrInvalidScanTransition)
+	})
+}
@@ -5,6 +5,7 @@ import (
 	"encoding/json"
 	"fmt"
 	"time"
+	"unicode/utf8"
 
 	"github.com/google/uuid"
 )
@@ -21,10 +22,51 @@ const (
 	StatusOnShift             Status = "on_shift"
 	StatusBusy                Status = "busy"
 	StatusInactive            Status = "inactive"
+	StatusOffline             Status = "offline" // пропущены heartbeat приложения водителя
 	StatusSuspended           Status = "suspended"
 	StatusBlocked             Status = "blocked"
 )
 
+// PushPlatform платформа push-уведомлений устройства водителя
+type PushPlatform string
+
+const (
+	PushPlatformFCM  PushPlatform = "fcm"  // Android, Firebase Cloud Messaging
+	PushPlatformAPNS PushPlatform = "apns" // iOS, Apple Push Notification service
+)
+
+// Ключи метаданных водителя для push-уведомлений
+const (
+	driverMetadataPushPlatform = "push_platform"
+	driverMetadataPushToken    = "push_token"
+)
+
+// Locale язык сообщений водителю (email, SMS)
+type Locale string
+
+const (
+	LocaleRU Locale = "ru"
+	LocaleEN Locale = "en"
+
+	// DefaultLocale язык сообщений, если водитель его не выбрал
+	DefaultLocale = LocaleRU
+)
+
+// MinDriverAge минимальный возраст водителя на момент регистрации, лет
+const MinDriverAge = 18
+
+// MaxNameLength максимальная длина имени, фамилии и отчества в символах (VARCHAR(100))
+const MaxNameLength = 100
+
+// driverMetadataLocale ключ метаданных водителя с языком сообщений
+const driverMetadataLocale = "locale"
+
+// driverMetadataAvatarURL ключ метаданных водителя с адресом фотографии
+const driverMetadataAvatarURL = "avatar_url"
+
+// driverMetadataCity ключ метаданных водителя с городом работы (по нему фильтруется список водителей)
+const driverMetadataCity = "city"
+
 // Metadata дополнительные данные в формате JSON
 type Metadata map[string]interface{}
 
@@ -83,9 +125,10 @@ func (d *Driver) IsActive() bool {
 	return d.Status == StatusAvailable || d.Status == StatusOnShift || d.Status == StatusBusy
 }
 
-// CanReceiveOrders проверяет, может ли водитель получать заказы
+// CanReceiveOrders проверяет, может ли водитель получать заказы: свободен (available) или на смене
+// без заказа (on_shift)
 func (d *Driver) CanReceiveOrders() bool {
-	return d.Status == StatusAvailable && d.DeletedAt == nil
+	return (d.Status == StatusAvailable || d.Status == StatusOnShift) && d.DeletedAt == nil
 }
 
 // UpdateRating обновляет рейтинг водителя
@@ -106,11 +149,96 @@ func (d *Driver) ChangeStatus(newStatus Status) {
 	d.UpdatedAt = time.Now()
 }
 
+// SetPushToken сохраняет токен устройства водителя для push-уведомлений
+func (d *Driver) SetPushToken(platform PushPlatform, token string) error {
+	if (platform != PushPlatformFCM && platform != PushPlatformAPNS) || token == "" {
+		return ErrPushTokenInvalid
+	}
+	if d.Metadata == nil {
+		d.Metadata = make(Metadata)
+	}
+	d.Metadata[driverMetadataPushPlatform] = string(platform)
+	d.Metadata[driverMetadataPushToken] = token
+	d.UpdatedAt = time.Now()
+	return nil
+}
+
+// PushToken возвращает платформу и токен устройства водителя
+func (d *Driver) PushToken() (PushPlatform, string, bool) {
+	platform, _ := d.Metadata[driverMetadataPushPlatform].(string)
+	token, _ := d.Metadata[driverMetadataPushToken].(string)
+	if platform == "" || token == "" {
+		return "", "", false
+	}
+	return PushPlatform(platform), token, true
+}
+
+// SetLocale сохраняет язык сообщений водителю
+func (d *Driver) SetLocale(locale Locale) error {
+	if locale != LocaleRU && locale != LocaleEN {
+		return ErrUnsupportedLocale
+	}
+	if d.Metadata == nil {
+		d.Metadata = make(Metadata)
+	}
+	d.Metadata[driverMetadataLocale] = string(locale)
+	d.UpdatedAt = time.Now()
+	return nil
+}
+
+// Locale возвращает язык сообщений водителю (DefaultLocale, если не выбран или не поддерживается)
+func (d *Driver) Locale() Locale {
+	locale, _ := d.Metadata[driverMetadataLocale].(string)
+	if Locale(locale) != LocaleRU && Locale(locale) != LocaleEN {
+		return DefaultLocale
+	}
+	return Locale(locale)
+}
+
+// SetAvatarURL сохраняет адрес фотографии водителя
+func (d *Driver) SetAvatarURL(url string) {
+	if d.Metadata == nil {
+		d.Metadata = make(Metadata)
+	}
+	d.Metadata[driverMetadataAvatarURL] = url
+	d.UpdatedAt = time.Now()
+}
+
+// AvatarURL возвращает адрес фотографии водителя, если она загружена
+func (d *Driver) AvatarURL() (string, bool) {
+	url, _ := d.Metadata[driverMetadataAvatarURL].(string)
+	return url, url != ""
+}
+
+// SetCity сохраняет город работы водителя; пустая строка удаляет город
+func (d *Driver) SetCity(city string) {
+	if d.Metadata == nil {
+		d.Metadata = make(Metadata)
+	}
+	if city == "" {
+		delete(d.Metadata, driverMetadataCity)
+	} else {
+		d.Metadata[driverMetadataCity] = city
+	}
+	d.UpdatedAt = time.Now()
+}
+
+// City возвращает город работы водителя, если он указан
+func (d *Driver) City() (string, bool) {
+	city, _ := d.Metadata[driverMetadataCity].(string)
+	return city, city != ""
+}
+
 // IsLicenseExpired проверяет, не истекло ли водительское удостоверение
 func (d *Driver) IsLicenseExpired() bool {
 	return time.Now().After(d.LicenseExpiry)
 }
 
+// IsUnderage проверяет, что водителю еще не исполнилось MinDriverAge лет
+func (d *Driver) IsUnderage() bool {
+	return d.BirthDate.AddDate(MinDriverAge, 0, 0).After(time.Now())
+}
+
 // GetFullName возвращает полное имя водителя
 func (d *Driver) GetFullName() string {
 	if d.MiddleName != nil && *d.MiddleName != "" {
@@ -133,6 +261,12 @@ func (d *Driver) Validate() error {
 		return ErrInvalidName
 	}
 
+	// Длина считается в символах, как в VARCHAR: 100 эмодзи - 400 байт, но допустимое имя
+	if utf8.RuneCountInString(d.FirstName) > MaxNameLength || utf8.RuneCountInString(d.LastName) > MaxNameLength ||
+		(d.MiddleName != nil && utf8.RuneCountInString(*d.MiddleName) > MaxNameLength) {
+		return ErrInvalidName
+	}
+
 	if d.LicenseNumber == "" {
 		return ErrInvalidLicense
 	}
@@ -141,6 +275,10 @@ func (d *Driver) Validate() error {
 		return ErrInvalidPassport
 	}
 
+	if d.IsUnderage() {
+		return ErrDriverUnderage
+	}
+
 	return nil
 }
 
@@ -171,10 +309,42 @@ type DriverFilters struct {
 	City          *string    `json:"city,omitempty"`
 	CreatedAfter  *time.Time `json:"created_after,omitempty"`
 	CreatedBefore *time.Time `json:"created_before,omitempty"`
-	Limit         int        `json:"limit,omitempty"`
-	Offset        int        `json:"offset,omitempty"`
-	SortBy        string     `json:"sort_by,omitempty"`
-	SortDirection string     `json:"sort_direction,omitempty"`
+	// LicenseExpiringBefore водители, у которых ВУ истекает раньше этой даты (учитывается только дата)
+	LicenseExpiringBefore *time.Time `json:"license_expiring_before,omitempty"`
+	// Search полнотекстовый поиск по имени, фамилии и отчеству (словоформы русского языка)
+	Search        *string `json:"search,omitempty"`
+	Limit         int     `json:"limit,omitempty"`
+	Offset        int     `json:"offset,omitempty"`
+	SortBy        string  `json:"sort_by,omitempty"`
+	SortDirection string  `json:"sort_direction,omitempty"`
+	// After курсор: страница начинается после этой позиции (вместо Offset)
+	After *DriverCursor `json:"-"`
+}
+
+// DriverSortFields поля, по которым сортируется список водителей (значения sort_by)
+var DriverSortFields = []string{"created_at", "current_rating", "total_trips"}
+
+// ValidateSort проверяет поле и направление сортировки: sort_by подставляется в запрос как имя столбца
+func (f *DriverFilters) ValidateSort() error {
+	if f.SortBy != "" {
+		known := false
+		for _, field := range DriverSortFields {
+			known = known || f.SortBy == field
+		}
+		if !known {
+			return ErrInvalidSortField
+		}
+	}
+
+	if f.SortDirection != "" && f.SortDirection != "asc" && f.SortDirection != "desc" {
+		return ErrInvalidSortDirection
+	}
+
+	// Курсор выдается для конкретной сортировки и с другой не имеет смысла
+	if f.After != nil && (f.After.SortBy != f.SortField() || f.After.Direction != f.SortOrder()) {
+		return ErrInvalidCursor
+	}
+	return nil
 }
 
 // DriverSummary краткая информация о водителе
@@ -198,3 +368,21 @@ func (d *Driver) ToSummary() *DriverSummary {
 		TotalTrips:    d.TotalTrips,
 	}
 }
+
+// DriverStats статистика водителя: поездки и рейтинг за все время, пройденное расстояние за период [From, To]
+type DriverStats struct {
+	DriverID      uuid.UUID `json:"driver_id" db:"driver_id"`
+	From          time.Time `json:"from" db:"-"`
+	To            time.Time `json:"to" db:"-"`
+	TotalTrips    int       `json:"total_trips" db:"total_trips"`
+	AverageRating float64   `json:"average_rating" db:"average_rating"`
+	TotalRatings  int       `json:"total_ratings" db:"total_ratings"`
+	DistanceKm    float64   `json:"distance_km" db:"distance_km"`
+}
+
+// DriverStatusInfo статус водителя в ответе на пакетный запрос статусов
+type DriverStatusInfo struct {
+	DriverID  uuid.UUID `json:"driver_id" db:"id"`
+	Status    Status    `json:"status" db:"status"`
+	UpdatedAt time.Time `json:"updated_at" db:"updated_at"`
+}
@@ -1,6 +1,7 @@
 package entities
 
 import (
+	"strings"
 	"testing"
 	"time"
 
@@ -63,6 +64,7 @@ func TestDriver_CanReceiveOrders(t *testing.T) {
 		expected  bool
 	}{
 		{"Available driver", StatusAvailable, nil, true},
+		{"On shift driver", StatusOnShift, nil, true},
 		{"Busy driver", StatusBusy, nil, false},
 		{"Available but deleted", StatusAvailable, timePtr(time.Now()), false},
 		{"Inactive driver", StatusInactive, nil, false},
@@ -197,6 +199,76 @@ func TestDriver_ChangeStatus(t *testing.T) {
 	assert.True(t, driver.UpdatedAt.After(oldUpdatedAt))
 }
 
+func TestDriver_PushToken(t *testing.T) {
+	testCases := []struct {
+		name        string
+		platform    PushPlatform
+		token       string
+		expectedErr error
+	}{
+		{name: "FCM", platform: PushPlatformFCM, token: "fcm-token"},
+		{name: "APNS", platform: PushPlatformAPNS, token: "apns-token"},
+		{name: "UnknownPlatform", platform: "sms", token: "token", expectedErr: ErrPushTokenInvalid},
+		{name: "EmptyToken", platform: PushPlatformFCM, token: "", expectedErr: ErrPushTokenInvalid},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			// Arrange
+			driver := &Driver{}
+			_, _, ok := driver.PushToken()
+			assert.False(t, ok)
+
+			// Act
+			err := driver.SetPushToken(tc.platform, tc.token)
+
+			// Assert
+			platform, token, ok := driver.PushToken()
+			if tc.expectedErr != nil {
+				assert.ErrorIs(t, err, tc.expectedErr)
+				assert.False(t, ok)
+				return
+			}
+			assert.NoError(t, err)
+			assert.True(t, ok)
+			assert.Equal(t, tc.platform, platform)
+			assert.Equal(t, tc.token, token)
+		})
+	}
+}
+
+func TestDriver_Locale(t *testing.T) {
+	testCases := []struct {
+		name        string
+		locale      Locale
+		expected    Locale
+		expectedErr error
+	}{
+		{name: "Russian", locale: LocaleRU, expected: LocaleRU},
+		{name: "English", locale: LocaleEN, expected: LocaleEN},
+		{name: "Unsupported", locale: "de", expected: DefaultLocale, expectedErr: ErrUnsupportedLocale},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			// Arrange
+			driver := &Driver{}
+			assert.Equal(t, DefaultLocale, driver.Locale())
+
+			// Act
+			err := driver.SetLocale(tc.locale)
+
+			// Assert
+			if tc.expectedErr != nil {
+				assert.ErrorIs(t, err, tc.expectedErr)
+			} else {
+				assert.NoError(t, err)
+			}
+			assert.Equal(t, tc.expected, driver.Locale())
+		})
+	}
+}
+
 func TestDriver_Validate(t *testing.T) {
 	tests := []struct {
 		name        string
@@ -231,6 +303,33 @@ func TestDriver_Validate(t *testing.T) {
 			expectError: true,
 			expectedErr: ErrInvalidPhone,
 		},
+		{
+			name: "Name of 100 four-byte characters",
+			driver: &Driver{
+				Phone:          "+79001234567",
+				Email:          "test@example.com",
+				FirstName:      strings.Repeat("😀", MaxNameLength),
+				LastName:       "Иванов",
+				LicenseNumber:  "TEST123456",
+				PassportSeries: "1234",
+				PassportNumber: "567890",
+			},
+			expectError: false,
+		},
+		{
+			name: "Name longer than 100 characters",
+			driver: &Driver{
+				Phone:          "+79001234567",
+				Email:          "test@example.com",
+				FirstName:      "Иван",
+				LastName:       strings.Repeat("ж", MaxNameLength+1),
+				LicenseNumber:  "TEST123456",
+				PassportSeries: "1234",
+				PassportNumber: "567890",
+			},
+			expectError: true,
+			expectedErr: ErrInvalidName,
+		},
 		{
 			name: "Empty email",
 			driver: &Driver{
@@ -272,6 +371,34 @@ func TestDriver_Validate(t *testing.T) {
 			},
 			expectError: true,
 			expectedErr: ErrInvalidLicense,
+		},		{
+			name: "Underage driver",
+			driver: &Driver{
+				Phone:          "+79001234567",
+				Email:          "test@example.com",
+				FirstName:      "Иван",
+				LastName:       "Иванов",
+				BirthDate:      time.Now().AddDate(-MinDriverAge, 0, 1),
+				LicenseNumber:  "TEST123456",
+				PassportSeries: "1234",
+				PassportNumber: "567890",
+			},
+			expectError: true,
+			expectedErr: ErrDriverUnderage,
+		},
+		{
+			name: "Driver turned minimum age today",
+			driver: &Driver{
+				Phone:          "+79001234567",
+				Email:          "test@example.com",
+				FirstName:      "Иван",
+				LastName:       "Иванов",
+				BirthDate:      time.Now().AddDate(-MinDriverAge, 0, 0).Add(-time.Minute),
+				LicenseNumber:  "TEST123456",
+				PassportSeries: "1234",
+				PassportNumber: "567890",
+			},
+			expectError: false,
 		},
 	}
 
@@ -15,6 +15,18 @@ var (
 	ErrInvalidStatus     = errors.New("invalid driver status")
 	ErrInvalidDriverID   = errors.New("invalid driver ID")
 
+	// List errors
+	ErrInvalidSortField     = errors.New("invalid sort field")
+	ErrInvalidSortDirection = errors.New("invalid sort direction")
+	ErrInvalidCursor        = errors.New("invalid pagination cursor")
+
+	// Lookup errors
+	ErrInvalidLookup = errors.New("lookup requires exactly one of phone or email")
+
+	// Batch status errors
+	ErrEmptyStatusBatch    = errors.New("status batch has no driver IDs")
+	ErrStatusBatchTooLarge = errors.New("status batch has too many driver IDs")
+
 	// Document errors
 	ErrDocumentNotFound      = errors.New("document not found")
 	ErrDocumentExists        = errors.New("document already exists")
@@ -24,12 +36,16 @@ var (
 	ErrInvalidExpiryDate     = errors.New("invalid expiry date")
 	ErrDocumentExpired       = errors.New("document expired")
 	ErrDocumentNotVerified   = errors.New("document not verified")
+	ErrInvalidObjectKey      = errors.New("invalid document object key")
+	ErrObjectNotFound        = errors.New("document object not found in storage")
+	ErrInvalidScanTransition = errors.New("invalid document scan status transition")
 
 	// Location errors
 	ErrLocationNotFound  = errors.New("location not found")
 	ErrInvalidLocation   = errors.New("invalid location coordinates")
 	ErrInvalidTimestamp  = errors.New("invalid timestamp")
 	ErrLocationTooOld    = errors.New("location data is too old")
+	ErrInvalidRadius     = errors.New("invalid search radius")
 
 	// Shift errors
 	ErrShiftNotFound     = errors.New("shift not found")
@@ -38,6 +54,10 @@ var (
 	ErrInvalidEndTime    = errors.New("invalid end time")
 	ErrShiftNotActive    = errors.New("shift is not active")
 	ErrShiftAlreadyEnded = errors.New("shift already ended")
+	ErrShiftNotPaused    = errors.New("shift is not paused")
+
+	// Payment errors
+	ErrInvalidPayment = errors.New("invalid payment amounts")
 
 	// Rating errors
 	ErrRatingNotFound       = errors.New("rating not found")
@@ -50,8 +70,35 @@ var (
 	ErrDriverBlocked          = errors.New("driver is blocked")
 	ErrDriverSuspended        = errors.New("driver is suspended")
 	ErrLicenseExpired         = errors.New("driver license expired")
+	ErrDriverUnderage         = errors.New("driver is under minimum age")
 	ErrUnauthorized           = errors.New("unauthorized access")
 	ErrPermissionDenied       = errors.New("permission denied")
 	ErrInvalidOperation       = errors.New("invalid operation")
 	ErrConcurrentModification = errors.New("concurrent modification detected")
+
+	// Notification errors
+	ErrPushTokenMissing  = errors.New("driver has no push token")
+	ErrPushTokenInvalid  = errors.New("invalid push token")
+	ErrUnsupportedLocale = errors.New("unsupported locale")
+
+	// Avatar errors
+	ErrAvatarTooLarge        = errors.New("avatar file too large")
+	ErrUnsupportedAvatarType = errors.New("unsupported avatar image type")
+	ErrInvalidAvatar         = errors.New("invalid avatar image")
+
+	// Document country errors
+	ErrUnsupportedCountry = errors.New("unsupported document country")
+
+	// Job errors
+	ErrJobNotFound = errors.New("job not found")
+
+	// Feature flag errors
+	ErrFeatureNotFound = errors.New("feature flag not found")
+
+	// Consistency check errors
+	ErrConsistencyRuleNotFound = errors.New("consistency rule not found")
+
+	// Heartbeat errors
+	ErrHeartbeatNotFound    = errors.New("heartbeat not found")
+	ErrHeartbeatRateLimited = errors.New("heartbeat rate limit exceeded")
 )
\ No newline at end of file
@@ -0,0 +1,13 @@
+package entities
+
+import (
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// DriverHeartbeat последний сигнал присутствия приложения водителя
+type DriverHeartbeat struct {
+	DriverID   uuid.UUID `json:"driver_id" db:"driver_id"`
+	LastSeenAt time.Time `json:"last_seen_at" db:"last_seen_at"`
+}
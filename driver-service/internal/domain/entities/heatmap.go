@@ -0,0 +1,89 @@
+package entities
+
+import (
+	"errors"
+	"sort"
+)
+
+// Допустимая точность geohash ячеек карты плотности: от ~5000 км (1 символ) до ~5 м (9 символов)
+const (
+	MinGeohashPrecision = 1
+	MaxGeohashPrecision = 9
+)
+
+// ErrInvalidPrecision точность geohash вне [MinGeohashPrecision, MaxGeohashPrecision]
+var ErrInvalidPrecision = errors.New("invalid geohash precision")
+
+// geohashAlphabet алфавит base32 для geohash
+const geohashAlphabet = "0123456789bcdefghjkmnpqrstuvwxyz"
+
+// Geohash кодирует координаты в geohash длиной precision символов. Точка на границе ячеек
+// относится к северной и восточной ячейке.
+func Geohash(lat, lon float64, precision int) string {
+	latRange := [2]float64{-90, 90}
+	lonRange := [2]float64{-180, 180}
+
+	hash := make([]byte, 0, precision)
+	bit, ch := 0, 0
+	even := true
+	for len(hash) < precision {
+		value, bounds := lat, &latRange
+		if even {
+			value, bounds = lon, &lonRange
+		}
+
+		mid := (bounds[0] + bounds[1]) / 2
+		ch <<= 1
+		if value >= mid {
+			ch |= 1
+			bounds[0] = mid
+		} else {
+			bounds[1] = mid
+		}
+		even = !even
+
+		if bit++; bit == 5 {
+			hash = append(hash, geohashAlphabet[ch])
+			bit, ch = 0, 0
+		}
+	}
+
+	return string(hash)
+}
+
+// HeatmapCell количество водителей в ячейке geohash
+type HeatmapCell struct {
+	Geohash string `json:"geohash"`
+	Count   int    `json:"count"`
+}
+
+// DriverHeatmap плотность водителей по ячейкам geohash: только непустые ячейки, по возрастанию geohash
+type DriverHeatmap struct {
+	Precision int           `json:"precision"`
+	Cells     []HeatmapCell `json:"cells"`
+	Total     int           `json:"total"`
+}
+
+// NewDriverHeatmap считает водителей по ячейкам geohash точности precision по их последним местоположениям
+func NewDriverHeatmap(precision int, locations []*DriverLocation) (*DriverHeatmap, error) {
+	if precision < MinGeohashPrecision || precision > MaxGeohashPrecision {
+		return nil, ErrInvalidPrecision
+	}
+
+	counts := make(map[string]int)
+	for _, location := range locations {
+		counts[Geohash(location.Latitude, location.Longitude, precision)]++
+	}
+
+	heatmap := &DriverHeatmap{
+		Precision: precision,
+		Cells:     make([]HeatmapCell, 0, len(counts)),
+		Total:     len(locations),
+	}
+	for geohash, count := range counts {
+		heatmap.Cells = append(heatmap.Cells, HeatmapCell{Geohash: geohash, Count: count})
+	}
+	sort.Slice(heatmap.Cells, func(i, j int) bool { return heatmap.Cells[i].Geohash < heatmap.Cells[j].Geohash })
+
+	return heatmap, nil
+}
@@ -0,0 +1,46 @@
+package entities
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestGeohash(t *testing.T) {
+	tests := []struct {
+		name      string
+		lat, lon  float64
+		precision int
+		expected  string
+	}{
+		{"Jutland", 57.64911, 10.40744, 11, "u4pruydqqvj"},
+		{"Spain", 42.6, -5.6, 5, "ezs42"},
+		{"Sydney", -33.8688, 151.2093, 6, "r3gx2f"},
+		{"Moscow", 55.7558, 37.6173, 6, "ucfv0n"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			assert.Equal(t, tt.expected, Geohash(tt.lat, tt.lon, tt.precision))
+		})
+	}
+}
+
+func TestNewDriverHeatmap(t *testing.T) {
+	locations := []*DriverLocation{
+		{Latitude: 55.7558, Longitude: 37.6173},
+		{Latitude: 55.7560, Longitude: 37.6175},
+		{Latitude: -33.8688, Longitude: 151.2093},
+	}
+
+	heatmap, err := NewDriverHeatmap(4, locations)
+	require.NoError(t, err)
+	assert.Equal(t, 3, heatmap.Total)
+	assert.Equal(t, []HeatmapCell{{Geohash: "r3gx", Count: 1}, {Geohash: "ucfv", Count: 2}}, heatmap.Cells)
+
+	_, err = NewDriverHeatmap(0, locations)
+	assert.ErrorIs(t, err, ErrInvalidPrecision)
+	_, err = NewDriverHeatmap(MaxGeohashPrecision+1, locations)
+	assert.ErrorIs(t, err, ErrInvalidPrecision)
+}
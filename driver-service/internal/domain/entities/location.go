@@ -7,6 +7,9 @@ import (
 	"github.com/google/uuid"
 )
 
+// LocationStaleAfter местоположение старше считается устаревшим: связь с водителем потеряна
+const LocationStaleAfter = 10 * time.Minute
+
 // DriverLocation представляет местоположение водителя
 type DriverLocation struct {
 	ID         uuid.UUID `json:"id" db:"id"`
@@ -134,12 +137,50 @@ type LocationFilters struct {
 	Offset    int        `json:"offset,omitempty"`
 }
 
+// LocationHistoryQuery выборка истории местоположений водителя за период [From, To] по возрастанию
+// recorded_at. Interval оставляет по одной точке на интервал - последнюю точку интервала; After и Limit
+// задают страницу, Limit 0 - весь период.
+type LocationHistoryQuery struct {
+	DriverID uuid.UUID
+	From     time.Time
+	To       time.Time
+	Interval time.Duration
+	After    *LocationCursor
+	Limit    int
+}
+
 // GeoBounds географические границы
 type GeoBounds struct {
 	NorthEast Location `json:"north_east"`
 	SouthWest Location `json:"south_west"`
 }
 
+// Validate проверяет, что углы границ - корректные координаты и юго-западный угол не севернее северо-восточного.
+// Границы, пересекающие антимеридиан, задаются долготой юго-западного угла больше северо-восточного.
+func (b GeoBounds) Validate() error {
+	for _, corner := range []Location{b.NorthEast, b.SouthWest} {
+		if corner.Latitude < -90 || corner.Latitude > 90 || corner.Longitude < -180 || corner.Longitude > 180 {
+			return ErrInvalidLocation
+		}
+	}
+	if b.SouthWest.Latitude > b.NorthEast.Latitude {
+		return ErrInvalidLocation
+	}
+	return nil
+}
+
+// Contains проверяет, находится ли точка внутри границ (включая сами границы)
+func (b GeoBounds) Contains(lat, lon float64) bool {
+	if lat < b.SouthWest.Latitude || lat > b.NorthEast.Latitude {
+		return false
+	}
+	if b.SouthWest.Longitude <= b.NorthEast.Longitude {
+		return lon >= b.SouthWest.Longitude && lon <= b.NorthEast.Longitude
+	}
+	// Границы пересекают антимеридиан
+	return lon >= b.SouthWest.Longitude || lon <= b.NorthEast.Longitude
+}
+
 // LocationUpdateRequest запрос на обновление местоположения
 type LocationUpdateRequest struct {
 	Latitude  float64  `json:"latitude" binding:"required"`
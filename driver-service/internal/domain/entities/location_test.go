@@ -0,0 +1,56 @@
+package entities
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestGeoBounds_Contains(t *testing.T) {
+	moscow := GeoBounds{
+		SouthWest: Location{Latitude: 55.70, Longitude: 37.50},
+		NorthEast: Location{Latitude: 55.80, Longitude: 37.70},
+	}
+	// Границы через антимеридиан: от 170° в.д. до 170° з.д.
+	chukotka := GeoBounds{
+		SouthWest: Location{Latitude: 60, Longitude: 170},
+		NorthEast: Location{Latitude: 70, Longitude: -170},
+	}
+
+	tests := []struct {
+		name     string
+		bounds   GeoBounds
+		lat, lon float64
+		expected bool
+	}{
+		{"inside", moscow, 55.75, 37.60, true},
+		{"on south-west corner", moscow, 55.70, 37.50, true},
+		{"on north-east corner", moscow, 55.80, 37.70, true},
+		{"north of bounds", moscow, 55.81, 37.60, false},
+		{"west of bounds", moscow, 55.75, 37.49, false},
+		{"antimeridian east side", chukotka, 65, 175, true},
+		{"antimeridian west side", chukotka, 65, -175, true},
+		{"antimeridian outside", chukotka, 65, 0, false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			assert.Equal(t, tt.expected, tt.bounds.Contains(tt.lat, tt.lon))
+		})
+	}
+}
+
+func TestGeoBounds_Validate(t *testing.T) {
+	valid := GeoBounds{
+		SouthWest: Location{Latitude: 55.70, Longitude: 37.50},
+		NorthEast: Location{Latitude: 55.80, Longitude: 37.70},
+	}
+	assert.NoError(t, valid.Validate())
+
+	inverted := GeoBounds{SouthWest: valid.NorthEast, NorthEast: valid.SouthWest}
+	assert.ErrorIs(t, inverted.Validate(), ErrInvalidLocation)
+
+	outOfRange := valid
+	outOfRange.NorthEast.Longitude = 181
+	assert.ErrorIs(t, outOfRange.Validate(), ErrInvalidLocation)
+}
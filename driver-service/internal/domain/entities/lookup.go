@@ -0,0 +1,69 @@
+package entities
+
+import "strings"
+
+// Допустимое число цифр номера в E.164: код страны и национальный номер, не больше 15 цифр
+const (
+	MinPhoneDigits = 10
+	MaxPhoneDigits = 15
+)
+
+// DriverLookup параметры поиска водителя службой поддержки: задается ровно одно из Phone и Email
+type DriverLookup struct {
+	Phone          string // в любом формате записи, сравнивается после NormalizePhone
+	Email          string // без учета регистра
+	IncludeDeleted bool   // искать также среди мягко удаленных водителей
+}
+
+// Validate проверяет, что задан ровно один контакт для поиска
+func (l *DriverLookup) Validate() error {
+	phone := strings.TrimSpace(l.Phone)
+	email := strings.TrimSpace(l.Email)
+	if (phone == "") == (email == "") {
+		return ErrInvalidLookup
+	}
+	if phone != "" && NormalizePhone(phone) == "" {
+		return ErrInvalidPhone
+	}
+	return nil
+}
+
+// NormalizePhone возвращает номер телефона без форматирования - только цифры; номер в российском
+// национальном формате 8XXXXXXXXXX приводится к 7XXXXXXXXXX. Так "+7 (916) 123-45-67",
+// "8 916 123 45 67" и "79161234567" дают один и тот же номер. Правило совпадает с SQL функцией
+// normalize_phone, по которой построен индекс поиска.
+func NormalizePhone(phone string) string {
+	digits := strings.Map(func(r rune) rune {
+		if r >= '0' && r <= '9' {
+			return r
+		}
+		return -1
+	}, phone)
+
+	if !strings.HasPrefix(strings.TrimSpace(phone), "+") && len(digits) == 11 && digits[0] == '8' {
+		return "7" + digits[1:]
+	}
+	return digits
+}
+
+// FormatPhoneE164 приводит номер в любом формате записи к E.164: "+" и цифры номера по правилу
+// NormalizePhone, так "8 (916) 123-45-67" и "+7 916 123 45 67" дают "+79161234567". Кроме цифр номер
+// может содержать только пробелы, дефисы, точки, скобки и "+" в начале; в номере должно быть от
+// MinPhoneDigits до MaxPhoneDigits цифр, иначе возвращается ErrInvalidPhone.
+func FormatPhoneE164(phone string) (string, error) {
+	trimmed := strings.TrimSpace(phone)
+	for i, r := range trimmed {
+		switch {
+		case r >= '0' && r <= '9', r == ' ', r == '-', r == '.', r == '(', r == ')':
+		case r == '+' && i == 0:
+		default:
+			return "", ErrInvalidPhone
+		}
+	}
+
+	digits := NormalizePhone(trimmed)
+	if len(digits) < MinPhoneDigits || len(digits) > MaxPhoneDigits || digits[0] == '0' {
+		return "", ErrInvalidPhone
+	}
+	return "+" + digits, nil
+}
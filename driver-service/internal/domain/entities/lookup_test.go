@@ -0,0 +1,38 @@
+package entities
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestFormatPhoneE164(t *testing.T) {
+	tests := []struct {
+		name     string
+		phone    string
+		expected string
+		err      error
+	}{
+		{name: "E.164", phone: "+79161234567", expected: "+79161234567"},
+		{name: "Spaces and brackets", phone: "+7 (916) 123-45-67", expected: "+79161234567"},
+		{name: "Russian national format", phone: "8 (916) 123-45-67", expected: "+79161234567"},
+		{name: "Without plus", phone: "79161234567", expected: "+79161234567"},
+		{name: "Foreign number with 8", phone: "+8 916 123 45 67", expected: "+89161234567"},
+		{name: "UK", phone: " +44 20 7946 0958 ", expected: "+442079460958"},
+		{name: "Empty", phone: "", err: ErrInvalidPhone},
+		{name: "Too short", phone: "+7 916 123", err: ErrInvalidPhone},
+		{name: "Too long", phone: "+7916123456789012345678", err: ErrInvalidPhone},
+		{name: "Letters", phone: "+7 916 ABC-45-67", err: ErrInvalidPhone},
+		{name: "Plus inside", phone: "7+9161234567", err: ErrInvalidPhone},
+		{name: "Leading zero", phone: "+0 916 123 45 67", err: ErrInvalidPhone},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			phone, err := FormatPhoneE164(tt.phone)
+
+			assert.Equal(t, tt.err, err)
+			assert.Equal(t, tt.expected, phone)
+		})
+	}
+}
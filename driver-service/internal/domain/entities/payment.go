@@ -0,0 +1,46 @@
+package entities
+
+import (
+	"math"
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// TripPayment оплата поездки водителя (событие payment.processed): net_amount - заработок водителя,
+// gross_amount - оплата пассажира, commission - комиссия платформы
+type TripPayment struct {
+	PaymentID   uuid.UUID  `json:"payment_id" db:"payment_id"`
+	DriverID    uuid.UUID  `json:"driver_id" db:"driver_id"`
+	ShiftID     *uuid.UUID `json:"shift_id,omitempty" db:"shift_id"`
+	GrossAmount float64    `json:"gross_amount" db:"gross_amount"`
+	Commission  float64    `json:"commission" db:"commission"`
+	NetAmount   float64    `json:"net_amount" db:"net_amount"`
+	ProcessedAt time.Time  `json:"processed_at" db:"processed_at"`
+}
+
+// Validate проверяет суммы оплаты: суммы неотрицательны, gross_amount = net_amount + commission с точностью до копейки
+func (p *TripPayment) Validate() error {
+	if p.DriverID == uuid.Nil {
+		return ErrInvalidDriverID
+	}
+	if p.GrossAmount < 0 || p.Commission < 0 || p.NetAmount < 0 {
+		return ErrInvalidPayment
+	}
+	if math.Abs(p.GrossAmount-p.Commission-p.NetAmount) > 0.005 {
+		return ErrInvalidPayment
+	}
+	return nil
+}
+
+// EarningsSummary заработок водителя за период по оплатам поездок
+type EarningsSummary struct {
+	DriverID      uuid.UUID   `json:"driver_id"`
+	From          time.Time   `json:"from"`
+	To            time.Time   `json:"to"`
+	TotalTrips    int         `json:"total_trips"`
+	GrossEarnings float64     `json:"gross_earnings"`
+	Commission    float64     `json:"commission"`
+	NetEarnings   float64     `json:"net_earnings"`
+	PaymentIDs    []uuid.UUID `json:"payment_ids"`
+}
@@ -16,6 +16,12 @@ const (
 	ShiftStatusCancelled ShiftStatus = "cancelled"
 )
 
+// Ключи метаданных смены для учета перерывов
+const (
+	shiftMetadataPausedAt     = "paused_at"     // начало текущего перерыва (RFC3339)
+	shiftMetadataBreakSeconds = "break_seconds" // суммарная длительность завершенных перерывов
+)
+
 // DriverShift представляет рабочую смену водителя
 type DriverShift struct {
 	ID              uuid.UUID  `json:"id" db:"id"`
@@ -45,6 +51,29 @@ func (s *DriverShift) GetDuration() int64 {
 	return int64(s.EndTime.Sub(s.StartTime).Minutes())
 }
 
+// GetBreakDuration возвращает суммарную длительность перерывов в минутах, включая текущий
+func (s *DriverShift) GetBreakDuration() int64 {
+	total := s.completedBreaks()
+	if pausedAt, ok := s.pausedAt(); ok && s.Status == ShiftStatusSuspended {
+		end := time.Now()
+		if s.EndTime != nil {
+			end = *s.EndTime
+		}
+		total += end.Sub(pausedAt)
+	}
+	return int64(total.Minutes())
+}
+
+// GetWorkingDuration возвращает продолжительность смены без перерывов в минутах
+func (s *DriverShift) GetWorkingDuration() int64 {
+	return s.GetDuration() - s.GetBreakDuration()
+}
+
+// IsPaused проверяет, находится ли водитель на перерыве
+func (s *DriverShift) IsPaused() bool {
+	return s.Status == ShiftStatusSuspended && s.EndTime == nil
+}
+
 // IsActive проверяет, активна ли смена
 func (s *DriverShift) IsActive() bool {
 	return s.Status == ShiftStatusActive && s.EndTime == nil
@@ -53,6 +82,7 @@ func (s *DriverShift) IsActive() bool {
 // End завершает смену
 func (s *DriverShift) End(location *DriverLocation) {
 	now := time.Now()
+	s.closeBreak(now)
 	s.EndTime = &now
 	s.Status = ShiftStatusCompleted
 	s.UpdatedAt = now
@@ -63,18 +93,58 @@ func (s *DriverShift) End(location *DriverLocation) {
 	}
 }
 
-// Suspend приостанавливает смену
+// Suspend приостанавливает смену (перерыв водителя)
 func (s *DriverShift) Suspend() {
+	now := time.Now()
+	if s.Status != ShiftStatusSuspended {
+		if s.Metadata == nil {
+			s.Metadata = make(Metadata)
+		}
+		s.Metadata[shiftMetadataPausedAt] = now.Format(time.RFC3339Nano)
+	}
 	s.Status = ShiftStatusSuspended
-	s.UpdatedAt = time.Now()
+	s.UpdatedAt = now
 }
 
 // Resume возобновляет смену
 func (s *DriverShift) Resume() {
 	if s.Status == ShiftStatusSuspended {
+		now := time.Now()
+		s.closeBreak(now)
 		s.Status = ShiftStatusActive
-		s.UpdatedAt = time.Now()
+		s.UpdatedAt = now
+	}
+}
+
+// closeBreak завершает текущий перерыв и добавляет его к сумме перерывов
+func (s *DriverShift) closeBreak(now time.Time) {
+	pausedAt, ok := s.pausedAt()
+	if !ok || s.Status != ShiftStatusSuspended {
+		return
+	}
+
+	total := s.completedBreaks() + now.Sub(pausedAt)
+	s.Metadata[shiftMetadataBreakSeconds] = total.Seconds()
+	delete(s.Metadata, shiftMetadataPausedAt)
+}
+
+// pausedAt возвращает начало текущего перерыва
+func (s *DriverShift) pausedAt() (time.Time, bool) {
+	value, ok := s.Metadata[shiftMetadataPausedAt].(string)
+	if !ok {
+		return time.Time{}, false
 	}
+	pausedAt, err := time.Parse(time.RFC3339Nano, value)
+	if err != nil {
+		return time.Time{}, false
+	}
+	return pausedAt, true
+}
+
+// completedBreaks возвращает суммарную длительность завершенных перерывов
+func (s *DriverShift) completedBreaks() time.Duration {
+	seconds, _ := s.Metadata[shiftMetadataBreakSeconds].(float64)
+	return time.Duration(seconds * float64(time.Second))
 }
 
 // Cancel отменяет смену
@@ -111,10 +181,10 @@ func (s *DriverShift) GetAverageDistancePerTrip() float64 {
 	return s.TotalDistance / float64(s.TotalTrips)
 }
 
-// GetEarningsPerHour возвращает заработок в час
+// GetEarningsPerHour возвращает заработок в час (время перерывов не учитывается)
 func (s *DriverShift) GetEarningsPerHour() float64 {
-	duration := s.GetDuration()
-	if duration == 0 {
+	duration := s.GetWorkingDuration()
+	if duration <= 0 {
 		return 0
 	}
 	hours := float64(duration) / 60.0
@@ -226,6 +296,7 @@ type ShiftResponse struct {
 	StartTime       time.Time    `json:"start_time"`
 	EndTime         *time.Time   `json:"end_time,omitempty"`
 	Duration        int64        `json:"duration_minutes"`
+	BreakDuration   int64        `json:"break_minutes"`
 	StartLocation   *Location    `json:"start_location,omitempty"`
 	EndLocation     *Location    `json:"end_location,omitempty"`
 	TotalTrips      int          `json:"total_trips"`
@@ -247,6 +318,7 @@ func (s *DriverShift) ToResponse() *ShiftResponse {
 		StartTime:       s.StartTime,
 		EndTime:         s.EndTime,
 		Duration:        s.GetDuration(),
+		BreakDuration:   s.GetBreakDuration(),
 		StartLocation:   s.GetStartLocation(),
 		EndLocation:     s.GetEndLocation(),
 		TotalTrips:      s.TotalTrips,
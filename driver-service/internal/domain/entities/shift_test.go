@@ -0,0 +1,101 @@
+package entities
+
+import (
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestDriverShift_SuspendResume(t *testing.T) {
+	// Arrange
+	shift := NewDriverShift(uuid.New(), nil, nil)
+
+	// Act
+	shift.Suspend()
+
+	// Assert
+	assert.Equal(t, ShiftStatusSuspended, shift.Status)
+	assert.True(t, shift.IsPaused())
+	assert.False(t, shift.IsActive())
+	require.Contains(t, shift.Metadata, shiftMetadataPausedAt)
+
+	// Повторная приостановка не сбрасывает начало перерыва
+	pausedAt := shift.Metadata[shiftMetadataPausedAt]
+	shift.Suspend()
+	assert.Equal(t, pausedAt, shift.Metadata[shiftMetadataPausedAt])
+
+	// Act
+	shift.Resume()
+
+	// Assert
+	assert.Equal(t, ShiftStatusActive, shift.Status)
+	assert.True(t, shift.IsActive())
+	assert.False(t, shift.IsPaused())
+	assert.NotContains(t, shift.Metadata, shiftMetadataPausedAt)
+	assert.Contains(t, shift.Metadata, shiftMetadataBreakSeconds)
+}
+
+func TestDriverShift_GetBreakDuration(t *testing.T) {
+	tests := []struct {
+		name            string
+		status          ShiftStatus
+		completedBreaks time.Duration
+		currentBreak    time.Duration
+		expected        int64
+	}{
+		{"No breaks", ShiftStatusActive, 0, 0, 0},
+		{"Completed breaks", ShiftStatusActive, 45 * time.Minute, 0, 45},
+		{"Current break", ShiftStatusSuspended, 0, 30 * time.Minute, 30},
+		{"Completed and current breaks", ShiftStatusSuspended, 45 * time.Minute, 30 * time.Minute, 75},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			shift := NewDriverShift(uuid.New(), nil, nil)
+			shift.StartTime = time.Now().Add(-3 * time.Hour)
+			shift.Status = tt.status
+			shift.Metadata[shiftMetadataBreakSeconds] = tt.completedBreaks.Seconds()
+			if tt.currentBreak > 0 {
+				shift.Metadata[shiftMetadataPausedAt] = time.Now().Add(-tt.currentBreak).Format(time.RFC3339Nano)
+			}
+
+			assert.Equal(t, tt.expected, shift.GetBreakDuration())
+			assert.Equal(t, 180-tt.expected, shift.GetWorkingDuration())
+		})
+	}
+}
+
+func TestDriverShift_GetEarningsPerHourExcludesBreaks(t *testing.T) {
+	// Arrange
+	shift := NewDriverShift(uuid.New(), nil, nil)
+	shift.StartTime = time.Now().Add(-3 * time.Hour)
+	shift.TotalEarnings = 1200
+	shift.Metadata[shiftMetadataBreakSeconds] = time.Hour.Seconds()
+
+	// Act
+	earningsPerHour := shift.GetEarningsPerHour()
+
+	// Assert
+	assert.InDelta(t, 600.0, earningsPerHour, 0.01, "Час перерыва не должен учитываться в заработке в час")
+	assert.Equal(t, int64(60), shift.ToResponse().BreakDuration)
+}
+
+func TestDriverShift_EndClosesCurrentBreak(t *testing.T) {
+	// Arrange
+	shift := NewDriverShift(uuid.New(), nil, nil)
+	shift.StartTime = time.Now().Add(-2 * time.Hour)
+	shift.Suspend()
+	shift.Metadata[shiftMetadataPausedAt] = time.Now().Add(-20 * time.Minute).Format(time.RFC3339Nano)
+
+	// Act
+	shift.End(nil)
+
+	// Assert
+	assert.Equal(t, ShiftStatusCompleted, shift.Status)
+	assert.NotContains(t, shift.Metadata, shiftMetadataPausedAt)
+	assert.Equal(t, int64(20), shift.GetBreakDuration())
+	assert.Equal(t, int64(100), shift.GetWorkingDuration())
+}
@@ -0,0 +1,44 @@
+package services
+
+import (
+	"context"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"go.uber.org/zap"
+)
+
+// AnalyticsService интерфейс для агрегатов по парку водителей
+type AnalyticsService interface {
+	GetDriverHeatmap(ctx context.Context, precision int) (*entities.DriverHeatmap, error)
+}
+
+// analyticsService реализация AnalyticsService
+type analyticsService struct {
+	locationRepo repositories.LocationRepository
+	logger       *zap.Logger
+}
+
+// NewAnalyticsService создает новый AnalyticsService
+func NewAnalyticsService(locationRepo repositories.LocationRepository, logger *zap.Logger) AnalyticsService {
+	return &analyticsService{
+		locationRepo: locationRepo,
+		logger:       logger,
+	}
+}
+
+// GetDriverHeatmap считает водителей на линии по ячейкам geohash точности precision
+// по их последним свежим местоположениям
+func (s *analyticsService) GetDriverHeatmap(ctx context.Context, precision int) (*entities.DriverHeatmap, error) {
+	if precision < entities.MinGeohashPrecision || precision > entities.MaxGeohashPrecision {
+		return nil, entities.ErrInvalidPrecision
+	}
+
+	locations, err := s.locationRepo.GetLatestOfActiveDrivers(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	return entities.NewDriverHeatmap(precision, locations)
+}
@@ -0,0 +1,114 @@
+package services
+
+import (
+	"context"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// EventAvailabilityChanged событие потери и восстановления связи с активным водителем
+const EventAvailabilityChanged = "driver.availability.changed"
+
+// AvailabilityLocationService декоратор LocationService, который публикует driver.availability.changed
+// с available=true, когда от водителя, связь с которым отмечена потерянной (задача detect_connection_loss),
+// приходит свежее местоположение. Досланные точки старше LocationStaleAfter связь не восстанавливают.
+type AvailabilityLocationService struct {
+	LocationService
+	connectionRepo repositories.ConnectionRepository
+	eventBus       EventPublisher
+	logger         *zap.Logger
+}
+
+// NewAvailabilityLocationService оборачивает next событиями восстановления связи
+func NewAvailabilityLocationService(
+	next LocationService,
+	connectionRepo repositories.ConnectionRepository,
+	eventBus EventPublisher,
+	logger *zap.Logger,
+) *AvailabilityLocationService {
+	return &AvailabilityLocationService{
+		LocationService: next,
+		connectionRepo:  connectionRepo,
+		eventBus:        eventBus,
+		logger:          logger,
+	}
+}
+
+// UpdateLocation обновляет местоположение и публикует восстановление связи, если точка свежая
+func (s *AvailabilityLocationService) UpdateLocation(ctx context.Context, location *entities.DriverLocation) error {
+	if err := s.LocationService.UpdateLocation(ctx, location); err != nil {
+		return err
+	}
+
+	if isFreshLocation(location) {
+		s.restore(ctx, location.DriverID, location)
+	}
+	return nil
+}
+
+// BatchUpdateLocations обновляет местоположения пакетом и публикует восстановление связи водителей,
+// у которых в пакете есть свежая точка
+func (s *AvailabilityLocationService) BatchUpdateLocations(ctx context.Context, locations []*entities.DriverLocation) error {
+	if err := s.LocationService.BatchUpdateLocations(ctx, locations); err != nil {
+		return err
+	}
+
+	latest := make(map[uuid.UUID]*entities.DriverLocation)
+	var drivers []uuid.UUID
+	for _, location := range locations {
+		if !isFreshLocation(location) {
+			continue
+		}
+		previous, ok := latest[location.DriverID]
+		if !ok {
+			drivers = append(drivers, location.DriverID)
+		}
+		if !ok || location.RecordedAt.After(previous.RecordedAt) {
+			latest[location.DriverID] = location
+		}
+	}
+
+	for _, driverID := range drivers {
+		s.restore(ctx, driverID, latest[driverID])
+	}
+	return nil
+}
+
+// restore снимает отметку о потере связи и публикует событие. Ошибки логируются: местоположения уже
+// сохранены, а отметка снимется следующей свежей точкой.
+func (s *AvailabilityLocationService) restore(ctx context.Context, driverID uuid.UUID, location *entities.DriverLocation) {
+	loss, err := s.connectionRepo.MarkRestored(ctx, driverID)
+	if err != nil {
+		s.logger.Error("Failed to restore driver connection",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+		return
+	}
+	if loss == nil {
+		return
+	}
+
+	eventData := map[string]interface{}{
+		"available": true,
+		"reason":    "connection_restored",
+		"lost_at":   loss.LostAt,
+		"location":  location.ToLocation(),
+	}
+	if err := s.eventBus.PublishDriverEvent(ctx, EventAvailabilityChanged, driverID, eventData); err != nil {
+		s.logger.Error("Failed to publish driver availability changed event",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+	}
+}
+
+// isFreshLocation проверяет, что точка записана не раньше LocationStaleAfter назад
+func isFreshLocation(location *entities.DriverLocation) bool {
+	return time.Since(location.RecordedAt) <= entities.LocationStaleAfter
+}
@@ -0,0 +1,135 @@
+package services
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"image"
+	_ "image/jpeg" // декодер для проверки JPEG
+	_ "image/png"  // декодер для проверки PNG
+	"strings"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/imaging"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// MaxAvatarSize максимальный размер файла фотографии водителя
+const MaxAvatarSize = 5 << 20
+
+// avatarExtensions типы фотографий водителя и расширения их объектов в хранилище
+var avatarExtensions = map[string]string{
+	imaging.TypeJPEG: "jpg",
+	imaging.TypePNG:  "png",
+}
+
+// AvatarService загрузка фотографий водителей
+type AvatarService interface {
+	UploadAvatar(ctx context.Context, driverID uuid.UUID, content []byte) (*Avatar, error)
+}
+
+// AvatarStorage объектное хранилище фотографий водителей
+type AvatarStorage interface {
+	ObjectURL(key string) string
+	PutObject(ctx context.Context, key, contentType string, content []byte) error
+}
+
+// Avatar загруженная фотография водителя
+type Avatar struct {
+	URL         string `json:"url"`
+	ContentType string `json:"content_type"`
+	Size        int    `json:"size"`
+}
+
+// avatarService реализация AvatarService
+type avatarService struct {
+	driverRepo repositories.DriverRepository
+	storage    AvatarStorage
+	cdnBaseURL string
+	logger     *zap.Logger
+}
+
+// NewAvatarService создает новый AvatarService. Фотографии отдаются по адресу cdnBaseURL + ключ объекта;
+// без CDN - по адресу объекта в хранилище.
+func NewAvatarService(
+	driverRepo repositories.DriverRepository,
+	storage AvatarStorage,
+	cdnBaseURL string,
+	logger *zap.Logger,
+) AvatarService {
+	return &avatarService{
+		driverRepo: driverRepo,
+		storage:    storage,
+		cdnBaseURL: strings.TrimRight(cdnBaseURL, "/"),
+		logger:     logger,
+	}
+}
+
+// UploadAvatar проверяет фотографию водителя, удаляет из нее метаданные (EXIF с координатами съемки)
+// и сохраняет в хранилище под новым ключом
+func (s *avatarService) UploadAvatar(ctx context.Context, driverID uuid.UUID, content []byte) (*Avatar, error) {
+	if len(content) > MaxAvatarSize {
+		return nil, entities.ErrAvatarTooLarge
+	}
+
+	// Тип определяется по содержимому: заголовку клиента не доверяем
+	contentType := imaging.DetectType(content)
+	extension, ok := avatarExtensions[contentType]
+	if !ok {
+		return nil, entities.ErrUnsupportedAvatarType
+	}
+
+	driver, err := s.driverRepo.GetByID(ctx, driverID)
+	if err != nil {
+		return nil, err
+	}
+
+	stripped, err := imaging.StripMetadata(content, contentType)
+	if err != nil {
+		if errors.Is(err, imaging.ErrMalformedImage) {
+			return nil, entities.ErrInvalidAvatar
+		}
+		return nil, err
+	}
+	if _, _, err := image.DecodeConfig(bytes.NewReader(stripped)); err != nil {
+		return nil, entities.ErrInvalidAvatar
+	}
+
+	// Новый ключ на каждую загрузку: CDN не отдаст закэшированную прежнюю фотографию
+	objectKey := fmt.Sprintf("%savatar/%s.%s", driverObjectPrefix(driverID), uuid.New(), extension)
+	if err := s.storage.PutObject(ctx, objectKey, contentType, stripped); err != nil {
+		s.logger.Error("Failed to store avatar",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+			zap.String("object_key", objectKey),
+		)
+		return nil, fmt.Errorf("failed to store avatar: %w", err)
+	}
+
+	avatarURL := s.storage.ObjectURL(objectKey)
+	if s.cdnBaseURL != "" {
+		avatarURL = s.cdnBaseURL + "/" + objectKey
+	}
+
+	driver.SetAvatarURL(avatarURL)
+	if err := s.driverRepo.Update(ctx, driver); err != nil {
+		return nil, err
+	}
+
+	s.logger.Info("Avatar uploaded",
+		zap.String("driver_id", driverID.String()),
+		zap.String("object_key", objectKey),
+		zap.Int("size", len(stripped)),
+		zap.Int("metadata_bytes_removed", len(content)-len(stripped)),
+	)
+
+	return &Avatar{
+		URL:         avatarURL,
+		ContentType: contentType,
+		Size:        len(stripped),
+	}, nil
+}
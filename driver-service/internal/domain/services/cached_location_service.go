@@ -0,0 +1,248 @@
+package services
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"sync"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+
+	"github.com/google/uuid"
+)
+
+// nearbyCacheEntry закэшированный результат поиска водителей поблизости в радиусе radiusKm от center
+type nearbyCacheEntry struct {
+	center    entities.DriverLocation
+	radiusKm  float64
+	locations []*entities.DriverLocation
+	expiresAt time.Time
+}
+
+// contains проверяет, есть ли водитель в закэшированном результате
+func (e *nearbyCacheEntry) contains(driverID uuid.UUID) bool {
+	for _, cached := range e.locations {
+		if cached.DriverID == driverID {
+			return true
+		}
+	}
+	return false
+}
+
+// affectedBy проверяет, может ли местоположение изменить результат поиска: точка попадает в область поиска
+// или водитель уже есть в результате (он мог покинуть область)
+func (e *nearbyCacheEntry) affectedBy(location *entities.DriverLocation) bool {
+	return e.center.DistanceTo(location) <= e.radiusKm || e.contains(location.DriverID)
+}
+
+// NearbyCacheStats счетчики обращений к кэшу поиска поблизости с момента создания CachedLocationService
+type NearbyCacheStats struct {
+	Hits   uint64
+	Misses uint64
+}
+
+// CacheEntry ключ кэша и оставшееся время его жизни (отрицательное - запись истекла, но еще хранится)
+type CacheEntry struct {
+	Key       string
+	ExpiresIn time.Duration
+}
+
+// CachedLocationService декоратор LocationService, который при включенном флаге
+// geo_cache кэширует результаты поиска водителей поблизости
+type CachedLocationService struct {
+	LocationService
+	flags *features.Flags
+	ttl   time.Duration
+
+	mu      sync.Mutex
+	entries map[string]nearbyCacheEntry
+	stats   NearbyCacheStats
+}
+
+// NewCachedLocationService оборачивает next кэшем поиска поблизости под флагом geo_cache.
+// Обновление местоположения через сервис сбрасывает только записи, на результат которых оно может повлиять:
+// точка внутри области поиска или водитель уже в результате. Смена статуса и удаление водителя сбрасывают
+// записи с ним (см. EvictingPublisher). Остальные записи живут до истечения ttl, истекшие записи удаляются
+// при следующем промахе кэша.
+func NewCachedLocationService(next LocationService, flags *features.Flags, ttl time.Duration) *CachedLocationService {
+	return &CachedLocationService{
+		LocationService: next,
+		flags:           flags,
+		ttl:             ttl,
+		entries:         make(map[string]nearbyCacheEntry),
+	}
+}
+
+// GetNearbyDrivers возвращает водителей поблизости, используя кэш при включенном флаге
+func (s *CachedLocationService) GetNearbyDrivers(ctx context.Context, lat, lon, radiusKm float64, limit int) ([]*entities.DriverLocation, error) {
+	if !s.flags.Enabled(features.GeoCache) {
+		return s.LocationService.GetNearbyDrivers(ctx, lat, lon, radiusKm, limit)
+	}
+
+	// Координаты округляются до ~10 м, чтобы близкие запросы попадали в одну запись
+	key := fmt.Sprintf("%.4f:%.4f:%.3f:%d", lat, lon, radiusKm, limit)
+	now := time.Now()
+
+	s.mu.Lock()
+	entry, ok := s.entries[key]
+	ttl := s.ttl
+	hit := ok && now.Before(entry.expiresAt)
+	if hit {
+		s.stats.Hits++
+	} else {
+		s.stats.Misses++
+	}
+	s.mu.Unlock()
+	if hit {
+		return entry.locations, nil
+	}
+
+	locations, err := s.LocationService.GetNearbyDrivers(ctx, lat, lon, radiusKm, limit)
+	if err != nil {
+		return nil, err
+	}
+
+	s.mu.Lock()
+	s.evictExpired(now)
+	s.entries[key] = nearbyCacheEntry{
+		center:    entities.DriverLocation{Latitude: lat, Longitude: lon},
+		radiusKm:  radiusKm,
+		locations: locations,
+		expiresAt: now.Add(ttl),
+	}
+	s.mu.Unlock()
+
+	return locations, nil
+}
+
+// evictExpired удаляет истекшие записи, чтобы кэш не рос за счет запросов, которые больше не повторяются.
+// Вызывается под s.mu.
+func (s *CachedLocationService) evictExpired(now time.Time) {
+	for key, entry := range s.entries {
+		if !now.Before(entry.expiresAt) {
+			delete(s.entries, key)
+		}
+	}
+}
+
+// UpdateLocation обновляет местоположение и сбрасывает записи кэша, на которые оно влияет
+func (s *CachedLocationService) UpdateLocation(ctx context.Context, location *entities.DriverLocation) error {
+	defer s.invalidateFor(location)
+	return s.LocationService.UpdateLocation(ctx, location)
+}
+
+// BatchUpdateLocations обновляет местоположения пакетом и сбрасывает записи кэша, на которые они влияют
+func (s *CachedLocationService) BatchUpdateLocations(ctx context.Context, locations []*entities.DriverLocation) error {
+	defer s.invalidateFor(locations...)
+	return s.LocationService.BatchUpdateLocations(ctx, locations)
+}
+
+// CleanupOldLocations удаляет старые местоположения и сбрасывает кэш
+func (s *CachedLocationService) CleanupOldLocations(ctx context.Context) error {
+	defer s.Invalidate()
+	return s.LocationService.CleanupOldLocations(ctx)
+}
+
+// SetTTL задает время жизни новых записей; уже закэшированные записи сохраняют свой срок
+func (s *CachedLocationService) SetTTL(ttl time.Duration) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.ttl = ttl
+}
+
+// TTL возвращает время жизни новых записей
+func (s *CachedLocationService) TTL() time.Duration {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	return s.ttl
+}
+
+// Entries возвращает ключи кэша, упорядоченные по ключу, с оставшимся временем жизни
+func (s *CachedLocationService) Entries() []CacheEntry {
+	now := time.Now()
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	entries := make([]CacheEntry, 0, len(s.entries))
+	for key, entry := range s.entries {
+		entries = append(entries, CacheEntry{Key: key, ExpiresIn: entry.expiresAt.Sub(now)})
+	}
+	sort.Slice(entries, func(i, j int) bool { return entries[i].Key < entries[j].Key })
+	return entries
+}
+
+// Stats возвращает счетчики попаданий в кэш и промахов
+func (s *CachedLocationService) Stats() NearbyCacheStats {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	return s.stats
+}
+
+// invalidateFor сбрасывает записи кэша, на результат которых могут повлиять местоположения
+func (s *CachedLocationService) invalidateFor(locations ...*entities.DriverLocation) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	for key, entry := range s.entries {
+		for _, location := range locations {
+			if entry.affectedBy(location) {
+				delete(s.entries, key)
+				break
+			}
+		}
+	}
+}
+
+// InvalidateDriver сбрасывает записи кэша, в результате которых есть водитель driverID
+func (s *CachedLocationService) InvalidateDriver(driverID uuid.UUID) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	for key, entry := range s.entries {
+		if entry.contains(driverID) {
+			delete(s.entries, key)
+		}
+	}
+}
+
+// EvictingPublisher оборачивает next: события смены статуса и удаления водителя сбрасывают записи кэша
+// с этим водителем, чтобы водитель на перерыве, offline, заблокированный или удаленный не оставался в поиске
+// поблизости до истечения ttl. Сервисы, меняющие статус водителя, публикуют события через него.
+func (s *CachedLocationService) EvictingPublisher(next EventPublisher) EventPublisher {
+	return &evictingPublisher{EventPublisher: next, cache: s}
+}
+
+// nearbyEvictingEvents события водителя, после которых он может выпасть из поиска поблизости
+// (удаление водителя публикует driver.blocked)
+var nearbyEvictingEvents = map[string]bool{
+	"driver.status.changed": true,
+	"driver.blocked":        true,
+}
+
+// evictingPublisher EventPublisher, сбрасывающий записи кэша поиска поблизости по событиям водителя
+type evictingPublisher struct {
+	EventPublisher
+	cache *CachedLocationService
+}
+
+// PublishDriverEvent сбрасывает записи кэша с водителем и публикует событие
+func (p *evictingPublisher) PublishDriverEvent(ctx context.Context, eventType string, driverID uuid.UUID, data interface{}) error {
+	if nearbyEvictingEvents[eventType] {
+		p.cache.InvalidateDriver(driverID)
+	}
+	return p.EventPublisher.PublishDriverEvent(ctx, eventType, driverID, data)
+}
+
+// Invalidate сбрасывает все записи кэша. Нужен, если местоположения меняются в обход сервиса.
+func (s *CachedLocationService) Invalidate() {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.entries = make(map[string]nearbyCacheEntry)
+}
@@ -0,0 +1,142 @@
+package services
+
+import (
+	"context"
+	"sync"
+
+	"driver-service/internal/domain/entities"
+
+	"go.uber.org/zap"
+)
+
+// dispatchFeedBuffer размер буфера подписки; обновления для переполненной подписки отбрасываются,
+// чтобы медленный клиент не задерживал запись местоположений
+const dispatchFeedBuffer = 100
+
+// areaSubscription подписка диспетчера на позиции водителей в границах
+type areaSubscription struct {
+	bounds  entities.GeoBounds
+	updates chan *entities.DriverLocation
+}
+
+// LocationRelay рассылает сохраненные местоположения всем репликам сервиса, включая отправившую:
+// каждая реплика передает полученные местоположения своим подписчикам через DispatchFeed.Deliver
+type LocationRelay interface {
+	RelayLocations(ctx context.Context, locations []*entities.DriverLocation) error
+}
+
+// DispatchFeed декоратор LocationService, который рассылает сохраненные местоположения
+// подписчикам, следящим за позициями водителей в заданных границах.
+// Это источник потока позиций GET /api/v1/locations/feed (Server-Sent Events). Без LocationRelay
+// подписчики получают только местоположения, сохраненные той же репликой.
+type DispatchFeed struct {
+	LocationService
+	relay  LocationRelay
+	logger *zap.Logger
+
+	mu            sync.Mutex
+	subscriptions map[*areaSubscription]struct{}
+}
+
+// NewDispatchFeed оборачивает next рассылкой обновлений местоположений подписчикам
+func NewDispatchFeed(next LocationService, logger *zap.Logger) *DispatchFeed {
+	return &DispatchFeed{
+		LocationService: next,
+		logger:          logger,
+		subscriptions:   make(map[*areaSubscription]struct{}),
+	}
+}
+
+// UseRelay рассылает сохраненные местоположения подписчикам всех реплик через relay;
+// вызывается до начала обработки запросов
+func (f *DispatchFeed) UseRelay(relay LocationRelay) {
+	f.relay = relay
+}
+
+// SubscribeArea подписывает на местоположения водителей внутри bounds, сохраненные после подписки.
+// Подписка действует до отмены ctx, после чего канал закрывается.
+func (f *DispatchFeed) SubscribeArea(ctx context.Context, bounds entities.GeoBounds) (<-chan *entities.DriverLocation, error) {
+	if err := bounds.Validate(); err != nil {
+		return nil, err
+	}
+
+	subscription := &areaSubscription{
+		bounds:  bounds,
+		updates: make(chan *entities.DriverLocation, dispatchFeedBuffer),
+	}
+
+	f.mu.Lock()
+	f.subscriptions[subscription] = struct{}{}
+	f.mu.Unlock()
+
+	go func() {
+		<-ctx.Done()
+
+		f.mu.Lock()
+		defer f.mu.Unlock()
+		delete(f.subscriptions, subscription)
+		close(subscription.updates)
+	}()
+
+	return subscription.updates, nil
+}
+
+// Subscribers возвращает количество активных подписок
+func (f *DispatchFeed) Subscribers() int {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	return len(f.subscriptions)
+}
+
+// UpdateLocation обновляет местоположение и рассылает его подписчикам
+func (f *DispatchFeed) UpdateLocation(ctx context.Context, location *entities.DriverLocation) error {
+	if err := f.LocationService.UpdateLocation(ctx, location); err != nil {
+		return err
+	}
+
+	f.publish(ctx, location)
+	return nil
+}
+
+// BatchUpdateLocations обновляет местоположения пакетом и рассылает их подписчикам
+func (f *DispatchFeed) BatchUpdateLocations(ctx context.Context, locations []*entities.DriverLocation) error {
+	if err := f.LocationService.BatchUpdateLocations(ctx, locations); err != nil {
+		return err
+	}
+
+	f.publish(ctx, locations...)
+	return nil
+}
+
+// publish передает местоположения подписчикам всех реплик через relay, а без него - подписчикам этой реплики.
+// Если relay недоступен, местоположения получают хотя бы подписчики этой реплики.
+func (f *DispatchFeed) publish(ctx context.Context, locations ...*entities.DriverLocation) {
+	if f.relay == nil {
+		f.Deliver(locations...)
+		return
+	}
+
+	if err := f.relay.RelayLocations(ctx, locations); err != nil {
+		f.logger.Error("Failed to relay locations to dispatch feed", zap.Error(err))
+		f.Deliver(locations...)
+	}
+}
+
+// Deliver отправляет местоположения подпискам этой реплики, в границы которых они попадают
+func (f *DispatchFeed) Deliver(locations ...*entities.DriverLocation) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	for subscription := range f.subscriptions {
+		for _, location := range locations {
+			if !subscription.bounds.Contains(location.Latitude, location.Longitude) {
+				continue
+			}
+
+			select {
+			case subscription.updates <- location:
+			default:
+			}
+		}
+	}
+}
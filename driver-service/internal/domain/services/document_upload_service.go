@@ -0,0 +1,194 @@
+package services
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// DocumentUploadService загрузка файлов документов в объектное хранилище и учет антивирусной проверки
+type DocumentUploadService interface {
+	RequestUpload(ctx context.Context, driverID uuid.UUID, docType entities.DocumentType) (*UploadTicket, error)
+	CompleteUpload(ctx context.Context, document *entities.DriverDocument, objectKey string) (*entities.DriverDocument, error)
+	RecordScanResult(ctx context.Context, documentID uuid.UUID, status entities.ScanStatus) (*entities.DriverDocument, error)
+}
+
+// ObjectStorage интерфейс объектного хранилища файлов документов
+type ObjectStorage interface {
+	Bucket() string
+	ObjectURL(key string) string
+	PresignPut(key string, expires time.Duration) (string, error)
+	StatObject(ctx context.Context, key string) (*StoredObject, error)
+}
+
+// StoredObject сведения об объекте в хранилище
+type StoredObject struct {
+	Key         string
+	Size        int64
+	ContentType string
+	ETag        string
+}
+
+// UploadTicket ссылка, по которой водитель загружает файл документа напрямую в хранилище
+type UploadTicket struct {
+	ObjectKey string    `json:"object_key"`
+	UploadURL string    `json:"upload_url"`
+	ExpiresAt time.Time `json:"expires_at"`
+}
+
+// validDocumentTypes типы документов, для которых выдаются ссылки на загрузку
+var validDocumentTypes = map[entities.DocumentType]bool{
+	entities.DocumentTypeDriverLicense: true,
+	entities.DocumentTypeMedicalCert:   true,
+	entities.DocumentTypeVehicleReg:    true,
+	entities.DocumentTypeInsurance:     true,
+	entities.DocumentTypePassport:      true,
+	entities.DocumentTypeTaxiPermit:    true,
+	entities.DocumentTypeWorkPermit:    true,
+}
+
+// documentUploadService реализация DocumentUploadService
+type documentUploadService struct {
+	driverRepo      repositories.DriverRepository
+	documentRepo    repositories.DocumentRepository
+	storage         ObjectStorage
+	uploadURLExpiry time.Duration
+	logger          *zap.Logger
+}
+
+// NewDocumentUploadService создает новый DocumentUploadService
+func NewDocumentUploadService(
+	driverRepo repositories.DriverRepository,
+	documentRepo repositories.DocumentRepository,
+	storage ObjectStorage,
+	uploadURLExpiry time.Duration,
+	logger *zap.Logger,
+) DocumentUploadService {
+	return &documentUploadService{
+		driverRepo:      driverRepo,
+		documentRepo:    documentRepo,
+		storage:         storage,
+		uploadURLExpiry: uploadURLExpiry,
+		logger:          logger,
+	}
+}
+
+// RequestUpload выдает ссылку на загрузку файла документа водителя
+func (s *documentUploadService) RequestUpload(ctx context.Context, driverID uuid.UUID, docType entities.DocumentType) (*UploadTicket, error) {
+	if !validDocumentTypes[docType] {
+		return nil, entities.ErrInvalidDocumentType
+	}
+
+	if _, err := s.driverRepo.GetByID(ctx, driverID); err != nil {
+		return nil, err
+	}
+
+	objectKey := fmt.Sprintf("%s%s/%s", driverObjectPrefix(driverID), docType, uuid.New())
+	uploadURL, err := s.storage.PresignPut(objectKey, s.uploadURLExpiry)
+	if err != nil {
+		s.logger.Error("Failed to presign document upload",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+		return nil, fmt.Errorf("failed to presign document upload: %w", err)
+	}
+
+	s.logger.Info("Document upload URL issued",
+		zap.String("driver_id", driverID.String()),
+		zap.String("document_type", string(docType)),
+		zap.String("object_key", objectKey),
+	)
+
+	return &UploadTicket{
+		ObjectKey: objectKey,
+		UploadURL: uploadURL,
+		ExpiresAt: time.Now().Add(s.uploadURLExpiry),
+	}, nil
+}
+
+// CompleteUpload сохраняет документ с загруженным файлом и ставит файл на антивирусную проверку
+func (s *documentUploadService) CompleteUpload(ctx context.Context, document *entities.DriverDocument, objectKey string) (*entities.DriverDocument, error) {
+	// Водитель может привязать только файл, загруженный по выданной ему ссылке
+	if !strings.HasPrefix(objectKey, driverObjectPrefix(document.DriverID)+string(document.DocumentType)+"/") {
+		return nil, entities.ErrInvalidObjectKey
+	}
+
+	object, err := s.storage.StatObject(ctx, objectKey)
+	if err != nil {
+		s.logger.Warn("Uploaded document object not available",
+			zap.Error(err),
+			zap.String("driver_id", document.DriverID.String()),
+			zap.String("object_key", objectKey),
+		)
+		return nil, err
+	}
+
+	document.FileURL = s.storage.ObjectURL(objectKey)
+	document.AttachObject(entities.DocumentObject{
+		Bucket:      s.storage.Bucket(),
+		Key:         object.Key,
+		ContentType: object.ContentType,
+		Size:        object.Size,
+		ETag:        object.ETag,
+	})
+
+	if err := document.Validate(); err != nil {
+		return nil, err
+	}
+
+	if err := s.documentRepo.Create(ctx, document); err != nil {
+		return nil, err
+	}
+
+	s.logger.Info("Document uploaded",
+		zap.String("document_id", document.ID.String()),
+		zap.String("driver_id", document.DriverID.String()),
+		zap.String("object_key", objectKey),
+		zap.Int64("size", object.Size),
+	)
+
+	return document, nil
+}
+
+// RecordScanResult сохраняет результат антивирусной проверки файла документа
+func (s *documentUploadService) RecordScanResult(ctx context.Context, documentID uuid.UUID, status entities.ScanStatus) (*entities.DriverDocument, error) {
+	document, err := s.documentRepo.GetByID(ctx, documentID)
+	if err != nil {
+		return nil, err
+	}
+
+	previous := document.ScanStatus()
+	if err := document.SetScanStatus(status); err != nil {
+		s.logger.Warn("Invalid document scan status transition",
+			zap.String("document_id", documentID.String()),
+			zap.String("from_status", string(previous)),
+			zap.String("to_status", string(status)),
+		)
+		return nil, err
+	}
+
+	if err := s.documentRepo.Update(ctx, document); err != nil {
+		return nil, err
+	}
+
+	s.logger.Info("Document scan result recorded",
+		zap.String("document_id", documentID.String()),
+		zap.String("from_status", string(previous)),
+		zap.String("to_status", string(status)),
+		zap.String("document_status", string(document.Status)),
+	)
+
+	return document, nil
+}
+
+// driverObjectPrefix префикс ключей файлов документов водителя
+func driverObjectPrefix(driverID uuid.UUID) string {
+	return fmt.Sprintf("drivers/%s/", driverID)
+}
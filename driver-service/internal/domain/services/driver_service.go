@@ -12,20 +12,26 @@ import (
 	"go.uber.org/zap"
 )
 
+// MaxStatusBatchSize максимальное число водителей в пакетном запросе статусов
+const MaxStatusBatchSize = 1000
+
 // DriverService интерфейс для управления водителями
 type DriverService interface {
 	CreateDriver(ctx context.Context, driver *entities.Driver) (*entities.Driver, error)
 	GetDriverByID(ctx context.Context, id uuid.UUID) (*entities.Driver, error)
 	GetDriverByPhone(ctx context.Context, phone string) (*entities.Driver, error)
 	GetDriverByEmail(ctx context.Context, email string) (*entities.Driver, error)
+	LookupDrivers(ctx context.Context, lookup *entities.DriverLookup) ([]*entities.Driver, error)
 	UpdateDriver(ctx context.Context, driver *entities.Driver) (*entities.Driver, error)
 	DeleteDriver(ctx context.Context, id uuid.UUID) error
+	RestoreDriver(ctx context.Context, id uuid.UUID) (*entities.Driver, error)
 	ListDrivers(ctx context.Context, filters *entities.DriverFilters) ([]*entities.Driver, error)
 	CountDrivers(ctx context.Context, filters *entities.DriverFilters) (int, error)
 	ChangeDriverStatus(ctx context.Context, id uuid.UUID, status entities.Status) error
 	UpdateDriverRating(ctx context.Context, id uuid.UUID, rating float64) error
 	IncrementTripCount(ctx context.Context, id uuid.UUID) error
 	GetActiveDrivers(ctx context.Context) ([]*entities.Driver, error)
+	GetDriverStatuses(ctx context.Context, ids []uuid.UUID) ([]*entities.DriverStatusInfo, []uuid.UUID, error)
 	IsDriverAvailable(ctx context.Context, id uuid.UUID) (bool, error)
 	ValidateDriverForOrder(ctx context.Context, id uuid.UUID) error
 }
@@ -71,8 +77,19 @@ func (s *driverService) CreateDriver(ctx context.Context, driver *entities.Drive
 			zap.Error(err),
 			zap.String("phone", driver.Phone),
 		)
-		return nil, fmt.Errorf("driver validation failed: %w", err)
+		return nil, err
+	}
+
+	// Телефон хранится в E.164, чтобы один номер в разных форматах записи был одним водителем
+	phone, err := entities.FormatPhoneE164(driver.Phone)
+	if err != nil {
+		s.logger.Error("Driver validation failed",
+			zap.Error(err),
+			zap.String("phone", driver.Phone),
+		)
+		return nil, err
 	}
+	driver.Phone = phone
 
 	// Проверяем, не существует ли уже водитель с таким телефоном или лицензией
 	exists, err := s.driverRepo.Exists(ctx, driver.Phone, driver.LicenseNumber)
@@ -105,8 +122,11 @@ func (s *driverService) CreateDriver(ctx context.Context, driver *entities.Drive
 		driver.Metadata = make(entities.Metadata)
 	}
 
-	// Создаем водителя в базе данных
+	// Создаем водителя в базе данных; параллельная регистрация того же номера или ВУ дает ErrDriverExists
 	if err := s.driverRepo.Create(ctx, driver); err != nil {
+		if err == entities.ErrDriverExists {
+			return nil, err
+		}
 		s.logger.Error("Failed to create driver",
 			zap.Error(err),
 			zap.String("driver_id", driver.ID.String()),
@@ -180,6 +200,23 @@ func (s *driverService) GetDriverByEmail(ctx context.Context, email string) (*en
 	return driver, nil
 }
 
+// LookupDrivers ищет водителей по телефону или email для службы поддержки
+func (s *driverService) LookupDrivers(ctx context.Context, lookup *entities.DriverLookup) ([]*entities.Driver, error) {
+	if err := lookup.Validate(); err != nil {
+		return nil, err
+	}
+
+	drivers, err := s.driverRepo.Lookup(ctx, lookup)
+	if err != nil {
+		s.logger.Error("Failed to lookup drivers",
+			zap.Error(err),
+		)
+		return nil, err
+	}
+
+	return drivers, nil
+}
+
 // UpdateDriver обновляет данные водителя
 func (s *driverService) UpdateDriver(ctx context.Context, driver *entities.Driver) (*entities.Driver, error) {
 	s.logger.Info("Updating driver",
@@ -192,7 +229,7 @@ func (s *driverService) UpdateDriver(ctx context.Context, driver *entities.Drive
 			zap.Error(err),
 			zap.String("driver_id", driver.ID.String()),
 		)
-		return nil, fmt.Errorf("driver validation failed: %w", err)
+		return nil, err
 	}
 
 	// Проверяем, существует ли водитель
@@ -261,8 +298,56 @@ func (s *driverService) DeleteDriver(ctx context.Context, id uuid.UUID) error {
 	return nil
 }
 
+// RestoreDriver восстанавливает удаленного водителя. Документы, смены, оценки и история
+// местоположений при удалении не затрагиваются, поэтому снова становятся доступны.
+func (s *driverService) RestoreDriver(ctx context.Context, id uuid.UUID) (*entities.Driver, error) {
+	s.logger.Info("Restoring driver",
+		zap.String("driver_id", id.String()),
+	)
+
+	if err := s.driverRepo.Restore(ctx, id); err != nil {
+		if err == entities.ErrDriverNotFound || err == entities.ErrDriverExists {
+			return nil, err
+		}
+		s.logger.Error("Failed to restore driver",
+			zap.Error(err),
+			zap.String("driver_id", id.String()),
+		)
+		return nil, fmt.Errorf("failed to restore driver: %w", err)
+	}
+
+	driver, err := s.driverRepo.GetByID(ctx, id)
+	if err != nil {
+		return nil, err
+	}
+
+	// Публикуем событие о восстановлении водителя
+	eventData := map[string]interface{}{
+		"status": string(driver.Status),
+	}
+
+	if err := s.eventBus.PublishDriverEvent(ctx, "driver.restored", driver.ID, eventData); err != nil {
+		s.logger.Error("Failed to publish driver restored event",
+			zap.Error(err),
+			zap.String("driver_id", driver.ID.String()),
+		)
+	}
+
+	s.logger.Info("Driver restored successfully",
+		zap.String("driver_id", id.String()),
+	)
+
+	return driver, nil
+}
+
 // ListDrivers получает список водителей с фильтрами
 func (s *driverService) ListDrivers(ctx context.Context, filters *entities.DriverFilters) ([]*entities.Driver, error) {
+	if filters != nil {
+		if err := filters.ValidateSort(); err != nil {
+			return nil, err
+		}
+	}
+
 	drivers, err := s.driverRepo.List(ctx, filters)
 	if err != nil {
 		s.logger.Error("Failed to list drivers",
@@ -313,8 +398,16 @@ func (s *driverService) ChangeDriverStatus(ctx context.Context, id uuid.UUID, st
 		return err
 	}
 
-	// Обновляем статус
-	if err := s.driverRepo.UpdateStatus(ctx, id, status); err != nil {
+	// Обновляем статус, только если его не изменили с момента чтения
+	if err := s.driverRepo.UpdateStatusFrom(ctx, id, oldStatus, status); err != nil {
+		if err == entities.ErrConcurrentModification {
+			s.logger.Warn("Driver status changed concurrently",
+				zap.String("driver_id", id.String()),
+				zap.String("from_status", string(oldStatus)),
+				zap.String("to_status", string(status)),
+			)
+			return entities.ErrConcurrentModification
+		}
 		s.logger.Error("Failed to update driver status",
 			zap.Error(err),
 			zap.String("driver_id", id.String()),
@@ -394,6 +487,45 @@ func (s *driverService) GetActiveDrivers(ctx context.Context) ([]*entities.Drive
 	return s.driverRepo.GetActiveDrivers(ctx)
 }
 
+// GetDriverStatuses получает статусы водителей одним запросом. Статусы и ID неизвестных (в том числе
+// удаленных) водителей возвращаются в порядке ids, повторяющиеся ID - один раз.
+func (s *driverService) GetDriverStatuses(ctx context.Context, ids []uuid.UUID) ([]*entities.DriverStatusInfo, []uuid.UUID, error) {
+	if len(ids) == 0 {
+		return nil, nil, entities.ErrEmptyStatusBatch
+	}
+	if len(ids) > MaxStatusBatchSize {
+		return nil, nil, entities.ErrStatusBatchTooLarge
+	}
+
+	rows, err := s.driverRepo.GetStatuses(ctx, ids)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	byID := make(map[uuid.UUID]*entities.DriverStatusInfo, len(rows))
+	for _, row := range rows {
+		byID[row.DriverID] = row
+	}
+
+	statuses := make([]*entities.DriverStatusInfo, 0, len(rows))
+	notFound := make([]uuid.UUID, 0, len(ids)-len(rows))
+	seen := make(map[uuid.UUID]bool, len(ids))
+	for _, id := range ids {
+		if seen[id] {
+			continue
+		}
+		seen[id] = true
+
+		if status, ok := byID[id]; ok {
+			statuses = append(statuses, status)
+		} else {
+			notFound = append(notFound, id)
+		}
+	}
+
+	return statuses, notFound, nil
+}
+
 // IsDriverAvailable проверяет доступность водителя
 func (s *driverService) IsDriverAvailable(ctx context.Context, id uuid.UUID) (bool, error) {
 	driver, err := s.driverRepo.GetByID(ctx, id)
@@ -468,6 +600,7 @@ func (s *driverService) validateStatusTransition(from, to entities.Status) error
 		entities.StatusAvailable: {
 			entities.StatusOnShift,
 			entities.StatusInactive,
+			entities.StatusOffline,
 			entities.StatusSuspended,
 			entities.StatusBlocked,
 		},
@@ -475,18 +608,26 @@ func (s *driverService) validateStatusTransition(from, to entities.Status) error
 			entities.StatusBusy,
 			entities.StatusAvailable,
 			entities.StatusInactive,
+			entities.StatusOffline,
 			entities.StatusSuspended,
 		},
 		entities.StatusBusy: {
 			entities.StatusOnShift,
 			entities.StatusAvailable,
 			entities.StatusInactive,
+			entities.StatusOffline,
 		},
 		entities.StatusInactive: {
 			entities.StatusAvailable,
 			entities.StatusSuspended,
 			entities.StatusBlocked,
 		},
+		entities.StatusOffline: {
+			entities.StatusAvailable,
+			entities.StatusInactive,
+			entities.StatusSuspended,
+			entities.StatusBlocked,
+		},
 		entities.StatusSuspended: {
 			entities.StatusAvailable,
 			entities.StatusBlocked,
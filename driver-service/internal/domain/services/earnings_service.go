@@ -0,0 +1,75 @@
+package services
+
+import (
+	"context"
+	"math"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// EarningsService интерфейс для заработка водителей по оплатам поездок
+type EarningsService interface {
+	GetEarnings(ctx context.Context, driverID uuid.UUID, from, to time.Time) (*entities.EarningsSummary, error)
+}
+
+// earningsService реализация EarningsService
+type earningsService struct {
+	driverRepo  repositories.DriverRepository
+	paymentRepo repositories.PaymentRepository
+	logger      *zap.Logger
+}
+
+// NewEarningsService создает новый EarningsService
+func NewEarningsService(
+	driverRepo repositories.DriverRepository,
+	paymentRepo repositories.PaymentRepository,
+	logger *zap.Logger,
+) EarningsService {
+	return &earningsService{
+		driverRepo:  driverRepo,
+		paymentRepo: paymentRepo,
+		logger:      logger,
+	}
+}
+
+// GetEarnings суммирует оплаты водителя, обработанные в периоде [from, to]: каждая оплата - одна поездка,
+// суммы округляются до копеек
+func (s *earningsService) GetEarnings(ctx context.Context, driverID uuid.UUID, from, to time.Time) (*entities.EarningsSummary, error) {
+	if _, err := s.driverRepo.GetByID(ctx, driverID); err != nil {
+		return nil, err
+	}
+
+	payments, err := s.paymentRepo.GetByDriverID(ctx, driverID, from, to)
+	if err != nil {
+		return nil, err
+	}
+
+	summary := &entities.EarningsSummary{
+		DriverID:   driverID,
+		From:       from,
+		To:         to,
+		TotalTrips: len(payments),
+		PaymentIDs: make([]uuid.UUID, 0, len(payments)),
+	}
+	for _, payment := range payments {
+		summary.GrossEarnings += payment.GrossAmount
+		summary.Commission += payment.Commission
+		summary.NetEarnings += payment.NetAmount
+		summary.PaymentIDs = append(summary.PaymentIDs, payment.PaymentID)
+	}
+	summary.GrossEarnings = roundToCents(summary.GrossEarnings)
+	summary.Commission = roundToCents(summary.Commission)
+	summary.NetEarnings = roundToCents(summary.NetEarnings)
+
+	return summary, nil
+}
+
+// roundToCents округляет сумму до копеек
+func roundToCents(amount float64) float64 {
+	return math.Round(amount*100) / 100
+}
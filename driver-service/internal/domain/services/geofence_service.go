@@ -0,0 +1,164 @@
+package services
+
+import (
+	"context"
+	"errors"
+	"sort"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// События пересечения границы геозоны
+const (
+	EventZoneEntered = "driver.zone.entered"
+	EventZoneExited  = "driver.zone.exited"
+)
+
+// Zone геозона: круг с центром и радиусом в километрах
+type Zone struct {
+	ID        string
+	Name      string
+	Latitude  float64
+	Longitude float64
+	RadiusKm  float64
+}
+
+// Contains проверяет, находится ли местоположение внутри зоны (граница относится к зоне)
+func (z Zone) Contains(location *entities.DriverLocation) bool {
+	return location.IsInRadius(&entities.DriverLocation{Latitude: z.Latitude, Longitude: z.Longitude}, z.RadiusKm)
+}
+
+// GeofenceLocationService декоратор LocationService, который публикует driver.zone.entered и
+// driver.zone.exited, когда новое местоположение водителя пересекает границу геозоны. Переход считается
+// от последнего сохраненного местоположения водителя; первая точка внутри зоны - вход в нее. Точки старше
+// последнего местоположения сохраняются без событий: текущее положение водителя они не меняют.
+type GeofenceLocationService struct {
+	LocationService
+	locationRepo repositories.LocationRepository
+	zones        []Zone
+	eventBus     EventPublisher
+	logger       *zap.Logger
+}
+
+// NewGeofenceLocationService оборачивает next событиями геозон zones; события зон публикуются
+// в порядке zones
+func NewGeofenceLocationService(
+	next LocationService,
+	locationRepo repositories.LocationRepository,
+	zones []Zone,
+	eventBus EventPublisher,
+	logger *zap.Logger,
+) *GeofenceLocationService {
+	return &GeofenceLocationService{
+		LocationService: next,
+		locationRepo:    locationRepo,
+		zones:           zones,
+		eventBus:        eventBus,
+		logger:          logger,
+	}
+}
+
+// UpdateLocation обновляет местоположение и публикует события пересеченных границ зон
+func (s *GeofenceLocationService) UpdateLocation(ctx context.Context, location *entities.DriverLocation) error {
+	if len(s.zones) == 0 {
+		return s.LocationService.UpdateLocation(ctx, location)
+	}
+
+	previous, err := s.latestLocation(ctx, location.DriverID)
+	if err != nil {
+		return err
+	}
+	if err := s.LocationService.UpdateLocation(ctx, location); err != nil {
+		return err
+	}
+
+	s.publishTransitions(ctx, previous, []*entities.DriverLocation{location})
+	return nil
+}
+
+// BatchUpdateLocations обновляет местоположения пакетом и публикует события пересеченных границ зон
+// по точкам каждого водителя в порядке recorded_at
+func (s *GeofenceLocationService) BatchUpdateLocations(ctx context.Context, locations []*entities.DriverLocation) error {
+	if len(s.zones) == 0 {
+		return s.LocationService.BatchUpdateLocations(ctx, locations)
+	}
+
+	tracks := make(map[uuid.UUID][]*entities.DriverLocation)
+	var drivers []uuid.UUID
+	for _, location := range locations {
+		if _, ok := tracks[location.DriverID]; !ok {
+			drivers = append(drivers, location.DriverID)
+		}
+		tracks[location.DriverID] = append(tracks[location.DriverID], location)
+	}
+
+	previous := make(map[uuid.UUID]*entities.DriverLocation, len(drivers))
+	for _, driverID := range drivers {
+		location, err := s.latestLocation(ctx, driverID)
+		if err != nil {
+			return err
+		}
+		previous[driverID] = location
+	}
+
+	if err := s.LocationService.BatchUpdateLocations(ctx, locations); err != nil {
+		return err
+	}
+
+	for _, driverID := range drivers {
+		track := tracks[driverID]
+		sort.SliceStable(track, func(i, j int) bool { return track[i].RecordedAt.Before(track[j].RecordedAt) })
+		s.publishTransitions(ctx, previous[driverID], track)
+	}
+	return nil
+}
+
+// latestLocation возвращает последнее сохраненное местоположение водителя или nil, если его нет
+func (s *GeofenceLocationService) latestLocation(ctx context.Context, driverID uuid.UUID) (*entities.DriverLocation, error) {
+	location, err := s.locationRepo.GetLatestByDriverID(ctx, driverID)
+	if errors.Is(err, entities.ErrLocationNotFound) {
+		return nil, nil
+	}
+	return location, err
+}
+
+// publishTransitions публикует события зон при переходе от previous по точкам track (по возрастанию
+// recorded_at). Ошибки публикации логируются: местоположения уже сохранены.
+func (s *GeofenceLocationService) publishTransitions(ctx context.Context, previous *entities.DriverLocation, track []*entities.DriverLocation) {
+	for _, location := range track {
+		if previous != nil && location.RecordedAt.Before(previous.RecordedAt) {
+			continue
+		}
+
+		for _, zone := range s.zones {
+			wasInside := previous != nil && zone.Contains(previous)
+			isInside := zone.Contains(location)
+			if wasInside == isInside {
+				continue
+			}
+
+			eventType := EventZoneEntered
+			if wasInside {
+				eventType = EventZoneExited
+			}
+			eventData := map[string]interface{}{
+				"zone_id":   zone.ID,
+				"zone_name": zone.Name,
+				"location":  location.ToLocation(),
+			}
+			if err := s.eventBus.PublishDriverEvent(ctx, eventType, location.DriverID, eventData); err != nil {
+				s.logger.Error("Failed to publish zone event",
+					zap.Error(err),
+					zap.String("event_type", eventType),
+					zap.String("zone_id", zone.ID),
+					zap.String("driver_id", location.DriverID.String()),
+				)
+			}
+		}
+		previous = location
+	}
+}
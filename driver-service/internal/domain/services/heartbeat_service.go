@@ -0,0 +1,159 @@
+package services
+
+import (
+	"context"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// HeartbeatService интерфейс для сигналов присутствия приложения водителя
+type HeartbeatService interface {
+	Heartbeat(ctx context.Context, driverID uuid.UUID, location *entities.DriverLocation) (*entities.DriverHeartbeat, error)
+	ExpireSession(ctx context.Context, driverID uuid.UUID) error
+}
+
+// heartbeatService реализация HeartbeatService
+type heartbeatService struct {
+	driverRepo      repositories.DriverRepository
+	heartbeatRepo   repositories.HeartbeatRepository
+	locationService LocationService
+	eventBus        EventPublisher
+	minInterval     time.Duration
+	logger          *zap.Logger
+}
+
+// NewHeartbeatService создает новый HeartbeatService. Heartbeat водителя чаще minInterval отклоняются.
+func NewHeartbeatService(
+	driverRepo repositories.DriverRepository,
+	heartbeatRepo repositories.HeartbeatRepository,
+	locationService LocationService,
+	eventBus EventPublisher,
+	minInterval time.Duration,
+	logger *zap.Logger,
+) HeartbeatService {
+	return &heartbeatService{
+		driverRepo:      driverRepo,
+		heartbeatRepo:   heartbeatRepo,
+		locationService: locationService,
+		eventBus:        eventBus,
+		minInterval:     minInterval,
+		logger:          logger,
+	}
+}
+
+// Heartbeat отмечает, что приложение водителя на связи. Местоположение необязательно: без него
+// последнее известное местоположение водителя не меняется. Водитель, переведенный в offline
+// из-за пропущенных heartbeat, возвращается в статус available.
+func (s *heartbeatService) Heartbeat(ctx context.Context, driverID uuid.UUID, location *entities.DriverLocation) (*entities.DriverHeartbeat, error) {
+	driver, err := s.driverRepo.GetByID(ctx, driverID)
+	if err != nil {
+		return nil, err
+	}
+
+	// Некорректное местоположение не должно расходовать лимит heartbeat
+	if location != nil {
+		if err := location.Validate(); err != nil {
+			return nil, err
+		}
+	}
+
+	heartbeat := &entities.DriverHeartbeat{DriverID: driverID, LastSeenAt: time.Now()}
+	if err := s.heartbeatRepo.Record(ctx, driverID, heartbeat.LastSeenAt, s.minInterval); err != nil {
+		if err == entities.ErrHeartbeatRateLimited {
+			s.logger.Warn("Heartbeat rate limit exceeded",
+				zap.String("driver_id", driverID.String()),
+				zap.Duration("min_interval", s.minInterval),
+			)
+		}
+		return nil, err
+	}
+
+	if location != nil {
+		if err := s.locationService.UpdateLocation(ctx, location); err != nil {
+			return nil, err
+		}
+	}
+
+	if driver.Status == entities.StatusOffline {
+		s.bringOnline(ctx, driverID)
+	}
+
+	return heartbeat, nil
+}
+
+// bringOnline возвращает водителя из offline в available после возобновления heartbeat
+func (s *heartbeatService) bringOnline(ctx context.Context, driverID uuid.UUID) {
+	err := s.driverRepo.UpdateStatusFrom(ctx, driverID, entities.StatusOffline, entities.StatusAvailable)
+	if err == entities.ErrConcurrentModification {
+		// Статус водителя уже изменили, его не трогаем
+		return
+	}
+	if err != nil {
+		s.logger.Error("Failed to bring driver online after heartbeat",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+		return
+	}
+
+	eventData := map[string]interface{}{
+		"old_status": string(entities.StatusOffline),
+		"new_status": string(entities.StatusAvailable),
+		"changed_by": "heartbeat",
+	}
+
+	if err := s.eventBus.PublishDriverEvent(ctx, "driver.status.changed", driverID, eventData); err != nil {
+		s.logger.Error("Failed to publish driver status changed event",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+	}
+}
+
+// ExpireSession переводит в offline активного водителя, сессия приложения которого истекла
+// (ключ сессии в Redis удален по TTL). Неактивный или уже удаленный водитель не затрагивается.
+func (s *heartbeatService) ExpireSession(ctx context.Context, driverID uuid.UUID) error {
+	driver, err := s.driverRepo.GetByID(ctx, driverID)
+	if err == entities.ErrDriverNotFound {
+		return nil
+	}
+	if err != nil {
+		return err
+	}
+	if !driver.IsActive() {
+		return nil
+	}
+
+	err = s.driverRepo.UpdateStatusFrom(ctx, driverID, driver.Status, entities.StatusOffline)
+	if err == entities.ErrConcurrentModification {
+		// Статус водителя уже изменили, его не трогаем
+		return nil
+	}
+	if err != nil {
+		return err
+	}
+
+	s.logger.Info("Driver session expired, driver is offline",
+		zap.String("driver_id", driverID.String()),
+		zap.String("old_status", string(driver.Status)),
+	)
+
+	eventData := map[string]interface{}{
+		"old_status": string(driver.Status),
+		"new_status": string(entities.StatusOffline),
+		"changed_by": "session_expired",
+	}
+
+	if err := s.eventBus.PublishDriverEvent(ctx, "driver.status.changed", driverID, eventData); err != nil {
+		s.logger.Error("Failed to publish driver status changed event",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+	}
+	return nil
+}
@@ -0,0 +1,342 @@
+package services
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// Фоновые задачи сервиса
+const (
+	JobAutoCloseShifts      = "auto_close_shifts"      // закрытие брошенных смен
+	JobPruneLocations       = "prune_locations"        // удаление старой истории местоположений
+	JobExpireDocuments      = "expire_documents"       // пометка истекших документов
+	JobMarkOffline          = "mark_offline"           // перевод в offline водителей с пропущенными heartbeat
+	JobExpireVerification   = "expire_verification"    // отклонение водителей, не прошедших верификацию в срок
+	JobDetectConnectionLoss = "detect_connection_loss" // потеря связи с активными водителями с устаревшим местоположением
+)
+
+// JobService запуск фоновых задач по расписанию или вручную.
+// Задачи идемпотентны: повторный запуск не меняет уже обработанные данные.
+type JobService interface {
+	Jobs() []string
+	RunJob(ctx context.Context, name string) (*JobResult, error)
+}
+
+// JobResult результат запуска фоновой задачи
+type JobResult struct {
+	Job        string    `json:"job"`
+	Affected   int       `json:"affected"` // количество обработанных записей
+	StartedAt  time.Time `json:"started_at"`
+	FinishedAt time.Time `json:"finished_at"`
+}
+
+// jobService реализация JobService
+type jobService struct {
+	driverRepo          repositories.DriverRepository
+	documentRepo        repositories.DocumentRepository
+	locationRepo        repositories.LocationRepository
+	shiftRepo           repositories.ShiftRepository
+	heartbeatRepo       repositories.HeartbeatRepository
+	connectionRepo      repositories.ConnectionRepository
+	eventBus            EventPublisher
+	maxShiftDuration    time.Duration
+	locationRetention   time.Duration
+	heartbeatTimeout    time.Duration
+	verificationTimeout time.Duration
+	logger              *zap.Logger
+}
+
+// NewJobService создает новый JobService
+func NewJobService(
+	driverRepo repositories.DriverRepository,
+	documentRepo repositories.DocumentRepository,
+	locationRepo repositories.LocationRepository,
+	shiftRepo repositories.ShiftRepository,
+	heartbeatRepo repositories.HeartbeatRepository,
+	connectionRepo repositories.ConnectionRepository,
+	eventBus EventPublisher,
+	maxShiftDuration time.Duration,
+	locationRetention time.Duration,
+	heartbeatTimeout time.Duration,
+	verificationTimeout time.Duration,
+	logger *zap.Logger,
+) JobService {
+	return &jobService{
+		driverRepo:          driverRepo,
+		documentRepo:        documentRepo,
+		locationRepo:        locationRepo,
+		shiftRepo:           shiftRepo,
+		heartbeatRepo:       heartbeatRepo,
+		connectionRepo:      connectionRepo,
+		eventBus:            eventBus,
+		maxShiftDuration:    maxShiftDuration,
+		locationRetention:   locationRetention,
+		heartbeatTimeout:    heartbeatTimeout,
+		verificationTimeout: verificationTimeout,
+		logger:              logger,
+	}
+}
+
+// Jobs возвращает имена доступных задач
+func (s *jobService) Jobs() []string {
+	return []string{JobAutoCloseShifts, JobPruneLocations, JobExpireDocuments, JobMarkOffline, JobExpireVerification,
+		JobDetectConnectionLoss}
+}
+
+// RunJob выполняет задачу name
+func (s *jobService) RunJob(ctx context.Context, name string) (*JobResult, error) {
+	var run func(ctx context.Context) (int, error)
+	switch name {
+	case JobAutoCloseShifts:
+		run = s.autoCloseShifts
+	case JobPruneLocations:
+		run = s.pruneLocations
+	case JobExpireDocuments:
+		run = s.expireDocuments
+	case JobMarkOffline:
+		run = s.markOffline
+	case JobExpireVerification:
+		run = s.expireVerification
+	case JobDetectConnectionLoss:
+		run = s.detectConnectionLoss
+	default:
+		return nil, entities.ErrJobNotFound
+	}
+
+	result := &JobResult{Job: name, StartedAt: time.Now()}
+	s.logger.Info("Starting job", zap.String("job", name))
+
+	affected, err := run(ctx)
+	result.Affected = affected
+	result.FinishedAt = time.Now()
+	if err != nil {
+		s.logger.Error("Job failed",
+			zap.Error(err),
+			zap.String("job", name),
+			zap.Int("affected", affected),
+		)
+		return result, fmt.Errorf("job %s failed: %w", name, err)
+	}
+
+	s.logger.Info("Job completed",
+		zap.String("job", name),
+		zap.Int("affected", affected),
+		zap.Duration("duration", result.FinishedAt.Sub(result.StartedAt)),
+	)
+	return result, nil
+}
+
+// autoCloseShifts закрывает смены, открытые дольше максимальной длительности, в том числе оставленные
+// на перерыве: смена и ее текущий перерыв завершаются моментом истечения максимальной длительности,
+// водитель на смене или на перерыве возвращается в статус available
+func (s *jobService) autoCloseShifts(ctx context.Context) (int, error) {
+	shifts, err := s.shiftRepo.GetStaleActive(ctx, time.Now().Add(-s.maxShiftDuration))
+	if err != nil {
+		return 0, err
+	}
+
+	closed := 0
+	for _, shift := range shifts {
+		endTime := shift.StartTime.Add(s.maxShiftDuration)
+		if err := s.shiftRepo.Complete(ctx, shift.ID, endTime); err != nil {
+			if errors.Is(err, entities.ErrShiftNotActive) {
+				// Смену успели закрыть после выборки
+				continue
+			}
+			return closed, err
+		}
+		closed++
+
+		if err := s.releaseDriver(ctx, shift); err != nil {
+			return closed, err
+		}
+
+		eventData := map[string]interface{}{
+			"shift_id":       shift.ID.String(),
+			"start_time":     shift.StartTime,
+			"end_time":       endTime,
+			"total_trips":    shift.TotalTrips,
+			"total_earnings": shift.TotalEarnings,
+		}
+
+		if err := s.eventBus.PublishDriverEvent(ctx, "shift.auto_closed", shift.DriverID, eventData); err != nil {
+			s.logger.Error("Failed to publish shift auto closed event",
+				zap.Error(err),
+				zap.String("shift_id", shift.ID.String()),
+			)
+		}
+	}
+
+	return closed, nil
+}
+
+// releaseDriver возвращает водителя с закрытой смены в статус available: со смены (on_shift, busy)
+// и с перерыва (inactive, если смена была приостановлена)
+func (s *jobService) releaseDriver(ctx context.Context, shift *entities.DriverShift) error {
+	driver, err := s.driverRepo.GetByID(ctx, shift.DriverID)
+	if errors.Is(err, entities.ErrDriverNotFound) {
+		return nil
+	}
+	if err != nil {
+		return err
+	}
+
+	onShift := driver.Status == entities.StatusOnShift || driver.Status == entities.StatusBusy
+	onBreak := driver.Status == entities.StatusInactive && shift.Status == entities.ShiftStatusSuspended
+	if !onShift && !onBreak {
+		return nil
+	}
+
+	err = s.driverRepo.UpdateStatusFrom(ctx, shift.DriverID, driver.Status, entities.StatusAvailable)
+	if errors.Is(err, entities.ErrConcurrentModification) {
+		// Статус водителя уже изменили, его не трогаем
+		return nil
+	}
+	return err
+}
+
+// pruneLocations удаляет историю местоположений старше срока хранения
+func (s *jobService) pruneLocations(ctx context.Context) (int, error) {
+	deleted, err := s.locationRepo.DeleteOld(ctx, time.Now().Add(-s.locationRetention))
+	return int(deleted), err
+}
+
+// expireDocuments помечает истекшими документы, срок действия которых прошел
+func (s *jobService) expireDocuments(ctx context.Context) (int, error) {
+	documents, err := s.documentRepo.GetExpired(ctx)
+	if err != nil {
+		return 0, err
+	}
+
+	ids := make([]uuid.UUID, 0, len(documents))
+	for _, document := range documents {
+		ids = append(ids, document.ID)
+	}
+
+	if err := s.documentRepo.MarkExpired(ctx, ids); err != nil {
+		return 0, err
+	}
+	return len(ids), nil
+}
+
+// markOffline переводит в offline активных водителей, от приложения которых не было heartbeat
+// дольше heartbeatTimeout. Водители, ни разу не отправлявшие heartbeat, не затрагиваются.
+func (s *jobService) markOffline(ctx context.Context) (int, error) {
+	heartbeats, err := s.heartbeatRepo.GetMissed(ctx, time.Now().Add(-s.heartbeatTimeout))
+	if err != nil {
+		return 0, err
+	}
+
+	marked := 0
+	for _, heartbeat := range heartbeats {
+		driver, err := s.driverRepo.GetByID(ctx, heartbeat.DriverID)
+		if errors.Is(err, entities.ErrDriverNotFound) {
+			continue
+		}
+		if err != nil {
+			return marked, err
+		}
+		if !driver.IsActive() {
+			continue
+		}
+
+		err = s.driverRepo.UpdateStatusFrom(ctx, driver.ID, driver.Status, entities.StatusOffline)
+		if errors.Is(err, entities.ErrConcurrentModification) {
+			// Статус водителя уже изменили, его не трогаем
+			continue
+		}
+		if err != nil {
+			return marked, err
+		}
+		marked++
+
+		eventData := map[string]interface{}{
+			"old_status":   string(driver.Status),
+			"new_status":   string(entities.StatusOffline),
+			"changed_by":   "heartbeat_timeout",
+			"last_seen_at": heartbeat.LastSeenAt,
+		}
+
+		if err := s.eventBus.PublishDriverEvent(ctx, "driver.status.changed", driver.ID, eventData); err != nil {
+			s.logger.Error("Failed to publish driver status changed event",
+				zap.Error(err),
+				zap.String("driver_id", driver.ID.String()),
+			)
+		}
+	}
+
+	return marked, nil
+}
+
+// expireVerification переводит в rejected водителей, которые находятся в статусе pending_verification
+// дольше verificationTimeout (по времени последнего изменения водителя)
+func (s *jobService) expireVerification(ctx context.Context) (int, error) {
+	drivers, err := s.driverRepo.GetStalePendingVerification(ctx, time.Now().Add(-s.verificationTimeout))
+	if err != nil {
+		return 0, err
+	}
+
+	rejected := 0
+	for _, driver := range drivers {
+		err := s.driverRepo.UpdateStatusFrom(ctx, driver.ID, entities.StatusPendingVerification, entities.StatusRejected)
+		if errors.Is(err, entities.ErrConcurrentModification) {
+			// Водителя успели проверить или перевести в другой статус после выборки
+			continue
+		}
+		if err != nil {
+			return rejected, err
+		}
+		rejected++
+
+		eventData := map[string]interface{}{
+			"old_status":    string(entities.StatusPendingVerification),
+			"new_status":    string(entities.StatusRejected),
+			"changed_by":    "verification_timeout",
+			"pending_since": driver.UpdatedAt,
+		}
+
+		if err := s.eventBus.PublishDriverEvent(ctx, "driver.status.changed", driver.ID, eventData); err != nil {
+			s.logger.Error("Failed to publish driver status changed event",
+				zap.Error(err),
+				zap.String("driver_id", driver.ID.String()),
+			)
+		}
+	}
+
+	return rejected, nil
+}
+
+// detectConnectionLoss отмечает потерю связи с активными водителями, последнее местоположение которых
+// старше LocationStaleAfter, и публикует driver.availability.changed с available=false. Статус водителя
+// не меняется: потеря связи не отменяет поездку. Связь восстанавливает AvailabilityLocationService.
+func (s *jobService) detectConnectionLoss(ctx context.Context) (int, error) {
+	losses, err := s.connectionRepo.MarkLost(ctx, time.Now().Add(-entities.LocationStaleAfter))
+	if err != nil {
+		return 0, err
+	}
+
+	for _, loss := range losses {
+		eventData := map[string]interface{}{
+			"available":        false,
+			"reason":           "connection_lost",
+			"last_location_at": loss.LastLocationAt,
+		}
+
+		if err := s.eventBus.PublishDriverEvent(ctx, EventAvailabilityChanged, loss.DriverID, eventData); err != nil {
+			s.logger.Error("Failed to publish driver availability changed event",
+				zap.Error(err),
+				zap.String("driver_id", loss.DriverID.String()),
+			)
+		}
+	}
+
+	return len(losses), nil
+}
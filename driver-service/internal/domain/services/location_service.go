@@ -16,7 +16,9 @@ import (
 type LocationService interface {
 	UpdateLocation(ctx context.Context, location *entities.DriverLocation) error
 	GetCurrentLocation(ctx context.Context, driverID uuid.UUID) (*entities.DriverLocation, error)
+	GetLocationAt(ctx context.Context, driverID uuid.UUID, at time.Time) (*entities.DriverLocation, error)
 	GetLocationHistory(ctx context.Context, driverID uuid.UUID, from, to time.Time) ([]*entities.DriverLocation, error)
+	GetLocationHistoryPage(ctx context.Context, query *entities.LocationHistoryQuery) ([]*entities.DriverLocation, error)
 	GetLocationStats(ctx context.Context, driverID uuid.UUID, from, to time.Time) (*entities.LocationStats, error)
 	StreamLocations(ctx context.Context, driverID uuid.UUID) (<-chan *entities.DriverLocation, error)
 	StartOrderTracking(ctx context.Context, driverID, orderID uuid.UUID) error
@@ -126,8 +128,8 @@ func (s *locationService) GetCurrentLocation(ctx context.Context, driverID uuid.
 		return nil, err
 	}
 
-	// Проверяем, не слишком ли старые данные (больше 10 минут)
-	if time.Since(location.RecordedAt) > 10*time.Minute {
+	// Проверяем, не слишком ли старые данные
+	if time.Since(location.RecordedAt) > entities.LocationStaleAfter {
 		s.logger.Warn("Location data is too old",
 			zap.String("driver_id", driverID.String()),
 			zap.Time("recorded_at", location.RecordedAt),
@@ -138,12 +140,39 @@ func (s *locationService) GetCurrentLocation(ctx context.Context, driverID uuid.
 	return location, nil
 }
 
+// GetLocationAt получает местоположение водителя на момент at: последнюю точку, записанную не позже at,
+// без интерполяции между точками. До первой точки трека местоположение неизвестно.
+func (s *locationService) GetLocationAt(ctx context.Context, driverID uuid.UUID, at time.Time) (*entities.DriverLocation, error) {
+	if _, err := s.driverRepo.GetByID(ctx, driverID); err != nil {
+		return nil, err
+	}
+
+	location, err := s.locationRepo.GetLatestAt(ctx, driverID, at)
+	if err != nil {
+		if err != entities.ErrLocationNotFound {
+			s.logger.Error("Failed to get location at time",
+				zap.Error(err),
+				zap.String("driver_id", driverID.String()),
+				zap.Time("at", at),
+			)
+		}
+		return nil, err
+	}
+
+	return location, nil
+}
+
 // GetLocationHistory получает историю местоположений водителя
 func (s *locationService) GetLocationHistory(ctx context.Context, driverID uuid.UUID, from, to time.Time) ([]*entities.DriverLocation, error) {
 	if to.Before(from) {
 		return nil, fmt.Errorf("invalid time range: 'to' time is before 'from' time")
 	}
 
+	// История удаленного водителя недоступна, но сохраняется до его восстановления
+	if _, err := s.driverRepo.GetByID(ctx, driverID); err != nil {
+		return nil, err
+	}
+
 	locations, err := s.locationRepo.GetByDriverIDInTimeRange(ctx, driverID, from, to)
 	if err != nil {
 		s.logger.Error("Failed to get location history",
@@ -158,6 +187,31 @@ func (s *locationService) GetLocationHistory(ctx context.Context, driverID uuid.
 	return locations, nil
 }
 
+// GetLocationHistoryPage получает страницу истории местоположений, прореженную до query.Interval
+func (s *locationService) GetLocationHistoryPage(ctx context.Context, query *entities.LocationHistoryQuery) ([]*entities.DriverLocation, error) {
+	if query.To.Before(query.From) {
+		return nil, fmt.Errorf("invalid time range: 'to' time is before 'from' time")
+	}
+
+	if _, err := s.driverRepo.GetByID(ctx, query.DriverID); err != nil {
+		return nil, err
+	}
+
+	locations, err := s.locationRepo.GetHistory(ctx, query)
+	if err != nil {
+		s.logger.Error("Failed to get location history page",
+			zap.Error(err),
+			zap.String("driver_id", query.DriverID.String()),
+			zap.Time("from", query.From),
+			zap.Time("to", query.To),
+			zap.Duration("interval", query.Interval),
+		)
+		return nil, err
+	}
+
+	return locations, nil
+}
+
 // GetLocationStats вычисляет статистику по местоположениям
 func (s *locationService) GetLocationStats(ctx context.Context, driverID uuid.UUID, from, to time.Time) (*entities.LocationStats, error) {
 	locations, err := s.GetLocationHistory(ctx, driverID, from, to)
@@ -282,10 +336,15 @@ func (s *locationService) StopOrderTracking(ctx context.Context, driverID, order
 	return nil
 }
 
-// GetNearbyDrivers получает водителей поблизости от указанной точки
+// GetNearbyDrivers получает активных водителей поблизости от указанной точки
 func (s *locationService) GetNearbyDrivers(ctx context.Context, lat, lon, radiusKm float64, limit int) ([]*entities.DriverLocation, error) {
-	if radiusKm <= 0 {
-		return nil, fmt.Errorf("radius must be positive")
+	center := &entities.DriverLocation{Latitude: lat, Longitude: lon}
+	if !center.IsValidLocation() {
+		return nil, entities.ErrInvalidLocation
+	}
+
+	if !(radiusKm > 0) {
+		return nil, entities.ErrInvalidRadius
 	}
 
 	if limit <= 0 {
@@ -303,20 +362,7 @@ func (s *locationService) GetNearbyDrivers(ctx context.Context, lat, lon, radius
 		return nil, err
 	}
 
-	// Фильтруем только активных водителей
-	var activeDriverLocations []*entities.DriverLocation
-	for _, location := range locations {
-		driver, err := s.driverRepo.GetByID(ctx, location.DriverID)
-		if err != nil {
-			continue
-		}
-
-		if driver.IsActive() {
-			activeDriverLocations = append(activeDriverLocations, location)
-		}
-	}
-
-	return activeDriverLocations, nil
+	return locations, nil
 }
 
 // BatchUpdateLocations обновляет множество местоположений за один запрос
@@ -377,7 +423,7 @@ func (s *locationService) CleanupOldLocations(ctx context.Context) error {
 		zap.Time("cutoff_time", cutoffTime),
 	)
 
-	if err := s.locationRepo.DeleteOld(ctx, cutoffTime); err != nil {
+	if _, err := s.locationRepo.DeleteOld(ctx, cutoffTime); err != nil {
 		s.logger.Error("Failed to cleanup old locations",
 			zap.Error(err),
 		)
@@ -0,0 +1,175 @@
+package services
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strconv"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// Типы push-уведомлений водителям (поле type в данных уведомления)
+const (
+	NotificationOrderAssigned    = "order_assigned"
+	NotificationDocumentExpiring = "document_expiring"
+)
+
+// NotificationService push-уведомления водителям
+type NotificationService interface {
+	HandleOrderAssigned(ctx context.Context, driverID, orderID uuid.UUID) error
+	NotifyExpiringDocuments(ctx context.Context, days int) (int, error)
+}
+
+// PushGateway интерфейс шлюза push-уведомлений (FCM, APNS)
+type PushGateway interface {
+	Send(ctx context.Context, message *PushMessage) error
+}
+
+// PushMessage push-уведомление на устройство водителя
+type PushMessage struct {
+	Platform entities.PushPlatform
+	Token    string
+	Title    string
+	Body     string
+	Data     map[string]string
+}
+
+// notificationService реализация NotificationService
+type notificationService struct {
+	driverRepo   repositories.DriverRepository
+	documentRepo repositories.DocumentRepository
+	gateway      PushGateway
+	logger       *zap.Logger
+}
+
+// NewNotificationService создает новый NotificationService
+func NewNotificationService(
+	driverRepo repositories.DriverRepository,
+	documentRepo repositories.DocumentRepository,
+	gateway PushGateway,
+	logger *zap.Logger,
+) NotificationService {
+	return &notificationService{
+		driverRepo:   driverRepo,
+		documentRepo: documentRepo,
+		gateway:      gateway,
+		logger:       logger,
+	}
+}
+
+// HandleOrderAssigned обрабатывает входящее событие order.assigned: уведомляет водителя о назначенном заказе
+func (s *notificationService) HandleOrderAssigned(ctx context.Context, driverID, orderID uuid.UUID) error {
+	driver, err := s.driverRepo.GetByID(ctx, driverID)
+	if err != nil {
+		return err
+	}
+
+	return s.notify(ctx, driver, "Новый заказ", "Вам назначен заказ, откройте приложение для деталей", map[string]string{
+		"type":      NotificationOrderAssigned,
+		"order_id":  orderID.String(),
+		"driver_id": driverID.String(),
+	})
+}
+
+// NotifyExpiringDocuments уведомляет водителей о верифицированных документах, истекающих в течение days дней.
+// О каждом сроке действия документа водитель уведомляется один раз: отправленное уведомление отмечается
+// в документе, и повторный запуск его пропускает. Возвращает количество отправленных уведомлений; водители
+// без токена устройства пропускаются, ошибки отправки не прерывают обработку остальных документов
+// (документ с ошибкой уведомляется при следующем запуске).
+func (s *notificationService) NotifyExpiringDocuments(ctx context.Context, days int) (int, error) {
+	documents, err := s.documentRepo.GetExpiringNotNotified(ctx, days)
+	if err != nil {
+		return 0, err
+	}
+
+	sent := 0
+	var errs []error
+	for _, document := range documents {
+		driver, err := s.driverRepo.GetByID(ctx, document.DriverID)
+		if err != nil {
+			errs = append(errs, err)
+			continue
+		}
+
+		expiryDate := document.ExpiryDate.Format("02.01.2006")
+		err = s.notify(ctx, driver, "Истекает срок документа",
+			fmt.Sprintf("Срок действия документа %s истекает %s, загрузите новый документ", document.DocumentNumber, expiryDate),
+			map[string]string{
+				"type":          NotificationDocumentExpiring,
+				"driver_id":     driver.ID.String(),
+				"document_id":   document.ID.String(),
+				"document_type": string(document.DocumentType),
+				"expiry_date":   document.ExpiryDate.Format("2006-01-02"),
+				"days_left":     strconv.Itoa(daysUntil(document.ExpiryDate)),
+			})
+
+		switch {
+		case err == nil:
+			sent++
+			if err := s.documentRepo.MarkExpiryNotified(ctx, document.ID, document.ExpiryDate); err != nil {
+				errs = append(errs, fmt.Errorf("document %s: %w", document.ID, err))
+			}
+		case errors.Is(err, entities.ErrPushTokenMissing):
+			continue
+		default:
+			errs = append(errs, fmt.Errorf("document %s: %w", document.ID, err))
+		}
+	}
+
+	s.logger.Info("Expiring document notifications sent",
+		zap.Int("days", days),
+		zap.Int("documents", len(documents)),
+		zap.Int("sent", sent),
+		zap.Int("failed", len(errs)),
+	)
+
+	return sent, errors.Join(errs...)
+}
+
+// notify отправляет уведомление на устройство водителя
+func (s *notificationService) notify(ctx context.Context, driver *entities.Driver, title, body string, data map[string]string) error {
+	platform, token, ok := driver.PushToken()
+	if !ok {
+		s.logger.Warn("Driver has no push token, notification skipped",
+			zap.String("driver_id", driver.ID.String()),
+			zap.String("type", data["type"]),
+		)
+		return entities.ErrPushTokenMissing
+	}
+
+	message := &PushMessage{
+		Platform: platform,
+		Token:    token,
+		Title:    title,
+		Body:     body,
+		Data:     data,
+	}
+
+	if err := s.gateway.Send(ctx, message); err != nil {
+		s.logger.Error("Failed to send push notification",
+			zap.Error(err),
+			zap.String("driver_id", driver.ID.String()),
+			zap.String("platform", string(platform)),
+			zap.String("type", data["type"]),
+		)
+		return err
+	}
+
+	s.logger.Info("Push notification sent",
+		zap.String("driver_id", driver.ID.String()),
+		zap.String("platform", string(platform)),
+		zap.String("type", data["type"]),
+	)
+	return nil
+}
+
+// daysUntil возвращает количество полных дней до даты
+func daysUntil(date time.Time) int {
+	return int(time.Until(date).Hours() / 24)
+}
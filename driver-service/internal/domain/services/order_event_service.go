@@ -0,0 +1,124 @@
+package services
+
+import (
+	"context"
+	"errors"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// Темы событий сервисов заказов и платежей, эффекты которых применяет OrderEventService
+const (
+	SubjectOrderAssigned    = "order.assigned"
+	SubjectOrderCompleted   = "order.completed"
+	SubjectPaymentProcessed = "payment.processed"
+)
+
+// OrderEventService применяет эффекты событий сервисов заказов и платежей. eventID - идентификатор
+// логического события (заголовок Nats-Msg-Id): повторная доставка события с тем же eventID эффект не повторяет.
+type OrderEventService interface {
+	HandleOrderAssigned(ctx context.Context, eventID string, driverID, orderID uuid.UUID) error
+	HandleOrderCompleted(ctx context.Context, eventID string, driverID, orderID uuid.UUID, distanceKm float64) error
+	HandlePaymentProcessed(ctx context.Context, eventID string, payment *entities.TripPayment) error
+}
+
+// orderEventService реализация OrderEventService
+type orderEventService struct {
+	inbox         repositories.EventInboxRepository
+	driverService DriverService
+	logger        *zap.Logger
+}
+
+// NewOrderEventService создает новый OrderEventService
+func NewOrderEventService(
+	inbox repositories.EventInboxRepository,
+	driverService DriverService,
+	logger *zap.Logger,
+) OrderEventService {
+	return &orderEventService{
+		inbox:         inbox,
+		driverService: driverService,
+		logger:        logger,
+	}
+}
+
+// HandleOrderAssigned переводит водителя на смене в статус busy (событие driver.status.changed).
+// Статус водителя не на смене не меняется, поэтому повторная доставка уже примененного назначения
+// занятого водителя не трогает.
+func (s *orderEventService) HandleOrderAssigned(ctx context.Context, eventID string, driverID, orderID uuid.UUID) error {
+	driver, err := s.driverService.GetDriverByID(ctx, driverID)
+	if err != nil {
+		return err
+	}
+	if driver.Status != entities.StatusOnShift {
+		s.logger.Info("Order assigned to driver not on shift, status unchanged",
+			zap.String("event_id", eventID),
+			zap.String("driver_id", driverID.String()),
+			zap.String("order_id", orderID.String()),
+			zap.String("status", string(driver.Status)),
+		)
+		return nil
+	}
+
+	err = s.driverService.ChangeDriverStatus(ctx, driverID, entities.StatusBusy)
+	if errors.Is(err, entities.ErrConcurrentModification) {
+		// Статус водителя уже изменили, его не трогаем
+		return nil
+	}
+	return err
+}
+
+// HandleOrderCompleted учитывает поездку у водителя и в его активной смене и возвращает занятого водителя
+// на смену (событие driver.status.changed). Возврат на смену выполняется и при повторной доставке:
+// он не меняет статус водителя, который уже не занят.
+func (s *orderEventService) HandleOrderCompleted(ctx context.Context, eventID string, driverID, orderID uuid.UUID, distanceKm float64) error {
+	applied, err := s.inbox.ApplyTrip(ctx, eventID, SubjectOrderCompleted, driverID, distanceKm)
+	if err != nil {
+		return err
+	}
+	s.logApplied(applied, eventID, SubjectOrderCompleted, driverID, zap.String("order_id", orderID.String()))
+
+	driver, err := s.driverService.GetDriverByID(ctx, driverID)
+	if err != nil {
+		return err
+	}
+	if driver.Status != entities.StatusBusy {
+		return nil
+	}
+
+	err = s.driverService.ChangeDriverStatus(ctx, driverID, entities.StatusOnShift)
+	if errors.Is(err, entities.ErrConcurrentModification) {
+		// Статус водителя уже изменили, его не трогаем
+		return nil
+	}
+	return err
+}
+
+// HandlePaymentProcessed записывает оплату поездки и добавляет ее к заработку активной смены водителя
+func (s *orderEventService) HandlePaymentProcessed(ctx context.Context, eventID string, payment *entities.TripPayment) error {
+	applied, err := s.inbox.ApplyPayment(ctx, eventID, SubjectPaymentProcessed, payment)
+	if err != nil {
+		return err
+	}
+	s.logApplied(applied, eventID, SubjectPaymentProcessed, payment.DriverID, zap.String("payment_id", payment.PaymentID.String()))
+	return nil
+}
+
+// logApplied логирует применение события или пропуск повторной доставки
+func (s *orderEventService) logApplied(applied bool, eventID, subject string, driverID uuid.UUID, field zap.Field) {
+	fields := []zap.Field{
+		zap.String("event_id", eventID),
+		zap.String("subject", subject),
+		zap.String("driver_id", driverID.String()),
+		field,
+	}
+	if !applied {
+		s.logger.Info("Event already applied, redelivery skipped", fields...)
+		return
+	}
+	s.logger.Info("Event applied", fields...)
+}
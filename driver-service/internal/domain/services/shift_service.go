@@ -0,0 +1,240 @@
+package services
+
+import (
+	"context"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// ShiftService интерфейс для смен водителей: начало, перерыв, возобновление и окончание.
+// Смена меняет и статус водителя: на смене он on_shift, на перерыве inactive (не получает заказы
+// и не находится поиском поблизости), после смены available.
+type ShiftService interface {
+	StartShift(ctx context.Context, driverID uuid.UUID, req *entities.ShiftStartRequest) (*entities.DriverShift, error)
+	PauseShift(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, error)
+	ResumeShift(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, error)
+	EndShift(ctx context.Context, driverID uuid.UUID, req *entities.ShiftEndRequest) (*entities.DriverShift, error)
+	GetCurrentShift(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, error)
+}
+
+// shiftService реализация ShiftService
+type shiftService struct {
+	shiftRepo  repositories.ShiftRepository
+	driverRepo repositories.DriverRepository
+	eventBus   EventPublisher
+	logger     *zap.Logger
+}
+
+// NewShiftService создает новый ShiftService
+func NewShiftService(
+	shiftRepo repositories.ShiftRepository,
+	driverRepo repositories.DriverRepository,
+	eventBus EventPublisher,
+	logger *zap.Logger,
+) ShiftService {
+	return &shiftService{
+		shiftRepo:  shiftRepo,
+		driverRepo: driverRepo,
+		eventBus:   eventBus,
+		logger:     logger,
+	}
+}
+
+// StartShift начинает смену доступного водителя (или водителя on_shift без открытой смены).
+// Если у водителя уже есть незавершенная смена, возвращает ErrShiftExists.
+func (s *shiftService) StartShift(ctx context.Context, driverID uuid.UUID, req *entities.ShiftStartRequest) (*entities.DriverShift, error) {
+	driver, err := s.driverRepo.GetByID(ctx, driverID)
+	if err != nil {
+		return nil, err
+	}
+	if driver.Status != entities.StatusAvailable && driver.Status != entities.StatusOnShift {
+		return nil, entities.ErrDriverNotAvailable
+	}
+
+	var vehicleID *uuid.UUID
+	var startLocation *entities.DriverLocation
+	if req != nil {
+		vehicleID = req.VehicleID
+		if startLocation, err = shiftLocation(driverID, req.Latitude, req.Longitude); err != nil {
+			return nil, err
+		}
+	}
+
+	oldStatus := driver.Status
+	if err := s.changeDriverStatus(ctx, driver, entities.StatusOnShift); err != nil {
+		return nil, err
+	}
+
+	shift := entities.NewDriverShift(driverID, vehicleID, startLocation)
+	if err := s.shiftRepo.Create(ctx, shift); err != nil {
+		s.revertDriverStatus(ctx, driver, oldStatus)
+		return nil, err
+	}
+
+	s.logger.Info("Shift started",
+		zap.String("driver_id", driverID.String()),
+		zap.String("shift_id", shift.ID.String()),
+	)
+	return shift, nil
+}
+
+// PauseShift приостанавливает активную смену (перерыв). Водитель, выполняющий заказ (busy),
+// уйти на перерыв не может: возвращается ErrDriverNotAvailable.
+func (s *shiftService) PauseShift(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, error) {
+	shift, driver, err := s.currentShift(ctx, driverID)
+	if err != nil {
+		return nil, err
+	}
+	if !shift.IsActive() {
+		return nil, entities.ErrShiftNotActive
+	}
+	if driver.Status != entities.StatusOnShift {
+		return nil, entities.ErrDriverNotAvailable
+	}
+
+	shift.Suspend()
+	return shift, s.saveShift(ctx, shift, driver, entities.StatusInactive)
+}
+
+// ResumeShift возобновляет смену после перерыва и возвращает водителя в статус on_shift
+func (s *shiftService) ResumeShift(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, error) {
+	shift, driver, err := s.currentShift(ctx, driverID)
+	if err != nil {
+		return nil, err
+	}
+	if !shift.IsPaused() {
+		return nil, entities.ErrShiftNotPaused
+	}
+	if driver.Status != entities.StatusInactive {
+		// Статус водителя изменили во время перерыва (например, заблокировали)
+		return nil, entities.ErrDriverNotAvailable
+	}
+
+	shift.Resume()
+	return shift, s.saveShift(ctx, shift, driver, entities.StatusOnShift)
+}
+
+// EndShift завершает активную или приостановленную смену (текущий перерыв закрывается моментом
+// окончания) и возвращает водителя в статус available. Водитель, выполняющий заказ, смену не завершает.
+func (s *shiftService) EndShift(ctx context.Context, driverID uuid.UUID, req *entities.ShiftEndRequest) (*entities.DriverShift, error) {
+	shift, driver, err := s.currentShift(ctx, driverID)
+	if err != nil {
+		return nil, err
+	}
+	if driver.Status == entities.StatusBusy {
+		return nil, entities.ErrDriverNotAvailable
+	}
+
+	var endLocation *entities.DriverLocation
+	if req != nil {
+		if endLocation, err = shiftLocation(driverID, req.Latitude, req.Longitude); err != nil {
+			return nil, err
+		}
+	}
+
+	shift.End(endLocation)
+	return shift, s.saveShift(ctx, shift, driver, entities.StatusAvailable)
+}
+
+// GetCurrentShift получает незавершенную смену водителя; если ее нет, возвращает ErrShiftNotFound
+func (s *shiftService) GetCurrentShift(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, error) {
+	shift, _, err := s.currentShift(ctx, driverID)
+	return shift, err
+}
+
+// currentShift получает водителя и его незавершенную смену
+func (s *shiftService) currentShift(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, *entities.Driver, error) {
+	driver, err := s.driverRepo.GetByID(ctx, driverID)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	shift, err := s.shiftRepo.GetCurrent(ctx, driverID)
+	if err != nil {
+		return nil, nil, err
+	}
+	return shift, driver, nil
+}
+
+// saveShift меняет статус водителя на status и сохраняет смену. Если смену сохранить не удалось
+// (ее завершили параллельно), статус водителя возвращается прежним.
+func (s *shiftService) saveShift(ctx context.Context, shift *entities.DriverShift, driver *entities.Driver, status entities.Status) error {
+	oldStatus := driver.Status
+	if err := s.changeDriverStatus(ctx, driver, status); err != nil {
+		return err
+	}
+
+	if err := s.shiftRepo.Update(ctx, shift); err != nil {
+		s.revertDriverStatus(ctx, driver, oldStatus)
+		return err
+	}
+
+	s.logger.Info("Shift updated",
+		zap.String("driver_id", driver.ID.String()),
+		zap.String("shift_id", shift.ID.String()),
+		zap.String("status", string(shift.Status)),
+	)
+	return nil
+}
+
+// changeDriverStatus переводит водителя в статус status, если он еще не в нем, и публикует
+// driver.status.changed. Если статус водителя успели изменить, возвращает ErrConcurrentModification.
+func (s *shiftService) changeDriverStatus(ctx context.Context, driver *entities.Driver, status entities.Status) error {
+	if driver.Status == status {
+		return nil
+	}
+
+	if err := s.driverRepo.UpdateStatusFrom(ctx, driver.ID, driver.Status, status); err != nil {
+		return err
+	}
+
+	eventData := map[string]interface{}{
+		"old_status": string(driver.Status),
+		"new_status": string(status),
+		"changed_by": "shift",
+	}
+	if err := s.eventBus.PublishDriverEvent(ctx, "driver.status.changed", driver.ID, eventData); err != nil {
+		s.logger.Error("Failed to publish driver status changed event",
+			zap.Error(err),
+			zap.String("driver_id", driver.ID.String()),
+		)
+	}
+
+	driver.Status = status
+	return nil
+}
+
+// revertDriverStatus возвращает водителю статус oldStatus после неудачной записи смены. Ошибка логируется:
+// исходная ошибка записи важнее, а статус мог уже измениться снова.
+func (s *shiftService) revertDriverStatus(ctx context.Context, driver *entities.Driver, oldStatus entities.Status) {
+	if driver.Status == oldStatus {
+		return
+	}
+
+	if err := s.driverRepo.UpdateStatusFrom(ctx, driver.ID, driver.Status, oldStatus); err != nil {
+		s.logger.Error("Failed to revert driver status after shift update failure",
+			zap.Error(err),
+			zap.String("driver_id", driver.ID.String()),
+		)
+		return
+	}
+	driver.Status = oldStatus
+}
+
+// shiftLocation возвращает точку начала или окончания смены из запроса; без координат - nil
+func shiftLocation(driverID uuid.UUID, latitude, longitude *float64) (*entities.DriverLocation, error) {
+	if latitude == nil || longitude == nil {
+		return nil, nil
+	}
+
+	location := entities.NewDriverLocation(driverID, *latitude, *longitude, time.Now())
+	if err := location.Validate(); err != nil {
+		return nil, err
+	}
+	return location, nil
+}
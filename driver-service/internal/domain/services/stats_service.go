@@ -0,0 +1,37 @@
+package services
+
+import (
+	"context"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// StatsService интерфейс для статистики водителей
+type StatsService interface {
+	GetDriverStats(ctx context.Context, driverID uuid.UUID, from, to time.Time) (*entities.DriverStats, error)
+}
+
+// statsService реализация StatsService
+type statsService struct {
+	driverRepo repositories.DriverRepository
+	logger     *zap.Logger
+}
+
+// NewStatsService создает новый StatsService
+func NewStatsService(driverRepo repositories.DriverRepository, logger *zap.Logger) StatsService {
+	return &statsService{
+		driverRepo: driverRepo,
+		logger:     logger,
+	}
+}
+
+// GetDriverStats получает поездки и рейтинг водителя и расстояние, пройденное в периоде [from, to].
+// Для удаленного или неизвестного водителя возвращает ErrDriverNotFound.
+func (s *statsService) GetDriverStats(ctx context.Context, driverID uuid.UUID, from, to time.Time) (*entities.DriverStats, error) {
+	return s.driverRepo.GetStats(ctx, driverID, from, to)
+}
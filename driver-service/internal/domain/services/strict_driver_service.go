@@ -0,0 +1,71 @@
+package services
+
+import (
+	"context"
+	"net/mail"
+	"regexp"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+)
+
+// e164Phone телефон в международном формате E.164
+var e164Phone = regexp.MustCompile(`^\+[1-9]\d{10,14}$`)
+
+// strictDriverService декоратор DriverService, который при включенном флаге
+// strict_validation дополнительно проверяет данные водителя перед сохранением
+type strictDriverService struct {
+	DriverService
+	flags *features.Flags
+}
+
+// NewStrictDriverService оборачивает next строгой валидацией под флагом strict_validation.
+// Флаг проверяется при каждом вызове, поэтому его можно переключать без перезапуска.
+func NewStrictDriverService(next DriverService, flags *features.Flags) DriverService {
+	return &strictDriverService{
+		DriverService: next,
+		flags:         flags,
+	}
+}
+
+// CreateDriver создает водителя, предварительно проверяя данные в строгом режиме
+func (s *strictDriverService) CreateDriver(ctx context.Context, driver *entities.Driver) (*entities.Driver, error) {
+	if err := s.validate(driver); err != nil {
+		return nil, err
+	}
+	return s.DriverService.CreateDriver(ctx, driver)
+}
+
+// UpdateDriver обновляет водителя, предварительно проверяя данные в строгом режиме
+func (s *strictDriverService) UpdateDriver(ctx context.Context, driver *entities.Driver) (*entities.Driver, error) {
+	if err := s.validate(driver); err != nil {
+		return nil, err
+	}
+	return s.DriverService.UpdateDriver(ctx, driver)
+}
+
+// validate проверяет формат телефона и email, форматы паспорта и ВУ страны выдачи и срок действия ВУ,
+// если флаг включен
+func (s *strictDriverService) validate(driver *entities.Driver) error {
+	if !s.flags.Enabled(features.StrictValidation) {
+		return nil
+	}
+
+	if !e164Phone.MatchString(driver.Phone) {
+		return entities.ErrInvalidPhone
+	}
+
+	if address, err := mail.ParseAddress(driver.Email); err != nil || address.Address != driver.Email {
+		return entities.ErrInvalidEmail
+	}
+
+	if err := driver.ValidateDocuments(); err != nil {
+		return err
+	}
+
+	if driver.IsLicenseExpired() {
+		return entities.ErrLicenseExpired
+	}
+
+	return nil
+}
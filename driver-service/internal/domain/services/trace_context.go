@@ -0,0 +1,70 @@
+package services
+
+import (
+	"context"
+	"crypto/rand"
+	"encoding/hex"
+	"strings"
+)
+
+// Заголовки сообщений NATS: контекст трассировки W3C и идентификатор сообщения (дедупликация JetStream)
+const (
+	TraceparentHeader = "traceparent"
+	MessageIDHeader   = "Nats-Msg-Id"
+)
+
+// traceparentKey ключ traceparent публикуемых событий в context.Context
+type traceparentKey struct{}
+
+// ContextWithTraceparent продолжает в ctx трассу входящего события с заголовком header: события, опубликованные
+// при его обработке, получают traceparent с тем же trace id и флагами и новым span id обработки.
+// Некорректный заголовок игнорируется, ctx возвращается без изменений.
+func ContextWithTraceparent(ctx context.Context, header string) context.Context {
+	traceID, flags, ok := parseTraceparent(header)
+	if !ok {
+		return ctx
+	}
+
+	spanID := make([]byte, 8)
+	_, _ = rand.Read(spanID)
+	return context.WithValue(ctx, traceparentKey{}, "00-"+traceID+"-"+hex.EncodeToString(spanID)+"-"+flags)
+}
+
+// TraceparentFromContext возвращает traceparent событий, публикуемых в ctx, или пустую строку вне трассы
+func TraceparentFromContext(ctx context.Context) string {
+	header, _ := ctx.Value(traceparentKey{}).(string)
+	return header
+}
+
+// parseTraceparent разбирает заголовок "<version>-<trace id>-<parent id>-<flags>" и возвращает trace id и флаги.
+// Версии новее 00 могут добавлять поля после флагов, они отбрасываются.
+func parseTraceparent(header string) (traceID, flags string, ok bool) {
+	parts := strings.Split(strings.TrimSpace(header), "-")
+	if len(parts) < 4 {
+		return "", "", false
+	}
+	version, traceID, parentID, flags := parts[0], parts[1], parts[2], parts[3]
+	if (version == "00" && len(parts) != 4) || version == "ff" {
+		return "", "", false
+	}
+	if !isHex(version, 2) || !isHex(traceID, 32) || !isHex(parentID, 16) || !isHex(flags, 2) {
+		return "", "", false
+	}
+	if strings.Trim(traceID, "0") == "" || strings.Trim(parentID, "0") == "" {
+		return "", "", false
+	}
+	return traceID, flags, true
+}
+
+// isHex проверяет, что s состоит из size символов в нижнем регистре hex
+func isHex(s string, size int) bool {
+	if len(s) != size {
+		return false
+	}
+	for _, c := range s {
+		if (c < '0' || c > '9') && (c < 'a' || c > 'f') {
+			return false
+		}
+	}
+	return true
+}
@@ -0,0 +1,150 @@
+package features
+
+import (
+	"fmt"
+	"strings"
+	"sync"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/entities"
+)
+
+// Flag имя флага функциональности
+type Flag string
+
+// Флаги функциональности сервиса
+const (
+	GeoCache         Flag = "geo_cache"         // кэширование поиска водителей поблизости
+	StrictValidation Flag = "strict_validation" // строгая проверка телефона, email и ВУ при регистрации
+)
+
+// All возвращает все известные флаги в стабильном порядке
+func All() []Flag {
+	return []Flag{GeoCache, StrictValidation}
+}
+
+// IsKnown проверяет, что флаг известен сервису
+func IsKnown(flag Flag) bool {
+	for _, known := range All() {
+		if known == flag {
+			return true
+		}
+	}
+	return false
+}
+
+// Flags текущие значения флагов. Значения можно менять на лету,
+// компоненты сервиса читают флаг при каждом обращении.
+type Flags struct {
+	mu     sync.RWMutex
+	values map[Flag]bool
+}
+
+// New создает набор флагов; неуказанные флаги выключены
+func New(values map[Flag]bool) *Flags {
+	flags := &Flags{values: make(map[Flag]bool, len(All()))}
+	for _, flag := range All() {
+		flags.values[flag] = values[flag]
+	}
+	return flags
+}
+
+// FromConfig создает набор флагов из конфигурации сервиса
+func FromConfig(cfg config.FeaturesConfig) *Flags {
+	return New(map[Flag]bool{
+		GeoCache:         cfg.GeoCache,
+		StrictValidation: cfg.StrictValidation,
+	})
+}
+
+// Enabled проверяет, включен ли флаг. Для nil возвращает false.
+func (f *Flags) Enabled(flag Flag) bool {
+	if f == nil {
+		return false
+	}
+
+	f.mu.RLock()
+	defer f.mu.RUnlock()
+	return f.values[flag]
+}
+
+// Set включает или выключает флаг
+func (f *Flags) Set(flag Flag, enabled bool) error {
+	if !IsKnown(flag) {
+		return fmt.Errorf("%w: %s", entities.ErrFeatureNotFound, flag)
+	}
+
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	f.values[flag] = enabled
+	return nil
+}
+
+// Snapshot возвращает копию текущих значений флагов
+func (f *Flags) Snapshot() map[Flag]bool {
+	f.mu.RLock()
+	defer f.mu.RUnlock()
+
+	snapshot := make(map[Flag]bool, len(f.values))
+	for flag, enabled := range f.values {
+		snapshot[flag] = enabled
+	}
+	return snapshot
+}
+
+// Restore заменяет значения флагов на snapshot
+func (f *Flags) Restore(snapshot map[Flag]bool) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	for _, flag := range All() {
+		f.values[flag] = snapshot[flag]
+	}
+}
+
+// String возвращает флаги в формате Parse, например "geo_cache=on,strict_validation=off"
+func (f *Flags) String() string {
+	snapshot := f.Snapshot()
+
+	parts := make([]string, 0, len(snapshot))
+	for _, flag := range All() {
+		state := "off"
+		if snapshot[flag] {
+			state = "on"
+		}
+		parts = append(parts, fmt.Sprintf("%s=%s", flag, state))
+	}
+	return strings.Join(parts, ",")
+}
+
+// Parse разбирает список флагов вида "geo_cache,strict_validation=off".
+// Флаг без значения считается включенным; допустимые значения: on/off, true/false, 1/0.
+func Parse(value string) (map[Flag]bool, error) {
+	values := make(map[Flag]bool)
+	for _, part := range strings.Split(value, ",") {
+		part = strings.TrimSpace(part)
+		if part == "" {
+			continue
+		}
+
+		name, state, hasState := strings.Cut(part, "=")
+		flag := Flag(strings.TrimSpace(name))
+		if !IsKnown(flag) {
+			return nil, fmt.Errorf("%w: %s", entities.ErrFeatureNotFound, flag)
+		}
+
+		enabled := true
+		if hasState {
+			switch strings.ToLower(strings.TrimSpace(state)) {
+			case "on", "true", "1":
+				enabled = true
+			case "off", "false", "0":
+				enabled = false
+			default:
+				return nil, fmt.Errorf("invalid value %q for feature %s", state, flag)
+			}
+		}
+		values[flag] = enabled
+	}
+	return values, nil
+}
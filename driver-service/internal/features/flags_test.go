@@ -0,0 +1,68 @@
+package features
+
+import (
+	"testing"
+
+	"driver-service/internal/domain/entities"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestParse(t *testing.T) {
+	testCases := []struct {
+		name     string
+		value    string
+		expected map[Flag]bool
+	}{
+		{name: "empty", value: "", expected: map[Flag]bool{}},
+		{name: "flag without value", value: "geo_cache", expected: map[Flag]bool{GeoCache: true}},
+		{
+			name:     "explicit values",
+			value:    " geo_cache=off, strict_validation=true ",
+			expected: map[Flag]bool{GeoCache: false, StrictValidation: true},
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			// Act
+			values, err := Parse(tc.value)
+
+			// Assert
+			require.NoError(t, err)
+			assert.Equal(t, tc.expected, values)
+		})
+	}
+
+	t.Run("unknown flag", func(t *testing.T) {
+		_, err := Parse("geo_cache,surge_pricing")
+		assert.ErrorIs(t, err, entities.ErrFeatureNotFound)
+	})
+
+	t.Run("invalid value", func(t *testing.T) {
+		_, err := Parse("geo_cache=maybe")
+		assert.Error(t, err)
+	})
+}
+
+func TestFlags(t *testing.T) {
+	// Arrange
+	flags := New(map[Flag]bool{StrictValidation: true})
+	snapshot := flags.Snapshot()
+
+	// Act
+	require.NoError(t, flags.Set(GeoCache, true))
+	err := flags.Set("surge_pricing", true)
+
+	// Assert
+	assert.ErrorIs(t, err, entities.ErrFeatureNotFound)
+	assert.Equal(t, "geo_cache=on,strict_validation=on", flags.String())
+
+	flags.Restore(snapshot)
+	assert.False(t, flags.Enabled(GeoCache))
+	assert.True(t, flags.Enabled(StrictValidation))
+
+	var disabled *Flags
+	assert.False(t, disabled.Enabled(GeoCache), "nil флаги считаются выключенными")
+}
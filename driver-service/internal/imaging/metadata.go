@@ -0,0 +1,148 @@
+// Package imaging обработка изображений, которые загружают водители (фотографии профиля)
+package imaging
+
+import (
+	"bytes"
+	"encoding/binary"
+	"errors"
+	"fmt"
+	"net/http"
+)
+
+// Типы изображений, из которых StripMetadata умеет удалять метаданные
+const (
+	TypeJPEG = "image/jpeg"
+	TypePNG  = "image/png"
+)
+
+// ErrMalformedImage структура файла не соответствует формату изображения
+var ErrMalformedImage = errors.New("malformed image")
+
+// Маркеры сегментов JPEG
+const (
+	jpegMarkerSOI  = 0xD8 // начало файла
+	jpegMarkerEOI  = 0xD9 // конец файла
+	jpegMarkerSOS  = 0xDA // начало сжатых данных
+	jpegMarkerAPP1 = 0xE1 // EXIF, XMP
+	jpegMarkerAPPD = 0xED // IPTC (Photoshop)
+	jpegMarkerCOM  = 0xFE // комментарий
+)
+
+// pngSignature первые 8 байт PNG файла
+var pngSignature = []byte("\x89PNG\r\n\x1a\n")
+
+// pngMetadataChunks чанки PNG с EXIF, текстовыми полями и временем изменения
+var pngMetadataChunks = map[string]bool{
+	"eXIf": true,
+	"tEXt": true,
+	"zTXt": true,
+	"iTXt": true,
+	"tIME": true,
+}
+
+// DetectType определяет тип изображения по содержимому, а не по заголовку Content-Type клиента
+func DetectType(content []byte) string {
+	return http.DetectContentType(content)
+}
+
+// StripMetadata удаляет из изображения метаданные (EXIF с координатами съемки и моделью устройства, XMP,
+// IPTC, комментарии), не перекодируя само изображение
+func StripMetadata(content []byte, contentType string) ([]byte, error) {
+	switch contentType {
+	case TypeJPEG:
+		return stripJPEG(content)
+	case TypePNG:
+		return stripPNG(content)
+	default:
+		return nil, fmt.Errorf("metadata stripping is not supported for %s", contentType)
+	}
+}
+
+// stripJPEG копирует сегменты JPEG до начала сжатых данных, пропуская APP1, APP13 и COM
+func stripJPEG(content []byte) ([]byte, error) {
+	if len(content) < 4 || content[0] != 0xFF || content[1] != jpegMarkerSOI {
+		return nil, ErrMalformedImage
+	}
+
+	stripped := bytes.NewBuffer(make([]byte, 0, len(content)))
+	stripped.Write(content[:2])
+
+	pos := 2
+	for {
+		if pos >= len(content) || content[pos] != 0xFF {
+			return nil, fmt.Errorf("%w: expected jpeg marker at offset %d", ErrMalformedImage, pos)
+		}
+		// Перед маркером допускаются байты заполнения 0xFF
+		for pos < len(content) && content[pos] == 0xFF {
+			pos++
+		}
+		if pos >= len(content) {
+			return nil, ErrMalformedImage
+		}
+
+		marker := content[pos]
+		pos++
+		if marker == jpegMarkerEOI {
+			stripped.Write([]byte{0xFF, marker})
+			return stripped.Bytes(), nil
+		}
+
+		if pos+2 > len(content) {
+			return nil, ErrMalformedImage
+		}
+		length := int(binary.BigEndian.Uint16(content[pos:]))
+		if length < 2 || pos+length > len(content) {
+			return nil, fmt.Errorf("%w: jpeg segment 0x%X overflows file", ErrMalformedImage, marker)
+		}
+
+		segment := content[pos : pos+length]
+		pos += length
+
+		switch marker {
+		case jpegMarkerAPP1, jpegMarkerAPPD, jpegMarkerCOM:
+			continue
+		case jpegMarkerSOS:
+			// Дальше сжатые данные и EOI: метаданных в них нет
+			stripped.Write([]byte{0xFF, marker})
+			stripped.Write(segment)
+			stripped.Write(content[pos:])
+			return stripped.Bytes(), nil
+		}
+
+		stripped.Write([]byte{0xFF, marker})
+		stripped.Write(segment)
+	}
+}
+
+// stripPNG копирует чанки PNG до IEND, пропуская pngMetadataChunks
+func stripPNG(content []byte) ([]byte, error) {
+	if !bytes.HasPrefix(content, pngSignature) {
+		return nil, ErrMalformedImage
+	}
+
+	stripped := bytes.NewBuffer(make([]byte, 0, len(content)))
+	stripped.Write(pngSignature)
+
+	pos := len(pngSignature)
+	for {
+		// Длина, тип, данные и CRC чанка
+		if pos+8 > len(content) {
+			return nil, fmt.Errorf("%w: png ends without IEND", ErrMalformedImage)
+		}
+		length := int(binary.BigEndian.Uint32(content[pos:]))
+		chunkType := string(content[pos+4 : pos+8])
+		end := pos + 12 + length
+		if length < 0 || end > len(content) || end < pos {
+			return nil, fmt.Errorf("%w: png chunk %q overflows file", ErrMalformedImage, chunkType)
+		}
+
+		if !pngMetadataChunks[chunkType] {
+			stripped.Write(content[pos:end])
+		}
+		pos = end
+
+		if chunkType == "IEND" {
+			return stripped.Bytes(), nil
+		}
+	}
+}
@@ -0,0 +1,145 @@
+package imaging
+
+import (
+	"bytes"
+	"encoding/binary"
+	"hash/crc32"
+	"image"
+	"image/color"
+	"image/jpeg"
+	"image/png"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// exifPayload EXIF с координатами съемки, которые не должны попасть в публичную фотографию
+const exifPayload = "Exif\x00\x00GPS 55.7558N 37.6173E"
+
+// testImage возвращает однотонное изображение 16x16
+func testImage() image.Image {
+	img := image.NewRGBA(image.Rect(0, 0, 16, 16))
+	for x := 0; x < 16; x++ {
+		for y := 0; y < 16; y++ {
+			img.Set(x, y, color.RGBA{R: 200, G: 100, B: 50, A: 255})
+		}
+	}
+	return img
+}
+
+// jpegWithMetadata кодирует JPEG и вставляет после SOI сегменты APP1 (EXIF) и COM
+func jpegWithMetadata(t *testing.T) []byte {
+	var encoded bytes.Buffer
+	require.NoError(t, jpeg.Encode(&encoded, testImage(), nil))
+
+	segment := func(marker byte, payload string) []byte {
+		header := []byte{0xFF, marker, 0, 0}
+		binary.BigEndian.PutUint16(header[2:], uint16(len(payload)+2))
+		return append(header, payload...)
+	}
+
+	content := append([]byte{}, encoded.Bytes()[:2]...)
+	content = append(content, segment(jpegMarkerAPP1, exifPayload)...)
+	content = append(content, segment(jpegMarkerCOM, "taken on driver phone")...)
+	return append(content, encoded.Bytes()[2:]...)
+}
+
+// pngWithMetadata кодирует PNG и вставляет после IHDR чанки eXIf и tEXt
+func pngWithMetadata(t *testing.T) []byte {
+	var encoded bytes.Buffer
+	require.NoError(t, png.Encode(&encoded, testImage()))
+
+	chunk := func(chunkType, data string) []byte {
+		result := make([]byte, 8, 12+len(data))
+		binary.BigEndian.PutUint32(result, uint32(len(data)))
+		copy(result[4:], chunkType)
+		result = append(result, data...)
+		return binary.BigEndian.AppendUint32(result, crc32.ChecksumIEEE(result[4:]))
+	}
+
+	// Сигнатура 8 байт и IHDR 25 байт
+	ihdrEnd := len(pngSignature) + 25
+	content := append([]byte{}, encoded.Bytes()[:ihdrEnd]...)
+	content = append(content, chunk("eXIf", exifPayload)...)
+	content = append(content, chunk("tEXt", "Comment\x00taken on driver phone")...)
+	return append(content, encoded.Bytes()[ihdrEnd:]...)
+}
+
+func TestStripMetadata(t *testing.T) {
+	testCases := []struct {
+		name        string
+		content     []byte
+		contentType string
+	}{
+		{name: "JPEG", content: jpegWithMetadata(t), contentType: TypeJPEG},
+		{name: "PNG", content: pngWithMetadata(t), contentType: TypePNG},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			// Arrange
+			require.Equal(t, tc.contentType, DetectType(tc.content))
+			require.Contains(t, string(tc.content), "GPS")
+
+			// Act
+			stripped, err := StripMetadata(tc.content, tc.contentType)
+
+			// Assert
+			require.NoError(t, err)
+			assert.NotContains(t, string(stripped), "GPS")
+			assert.NotContains(t, string(stripped), "taken on driver phone")
+			assert.Less(t, len(stripped), len(tc.content))
+
+			decoded, format, err := image.Decode(bytes.NewReader(stripped))
+			require.NoError(t, err, "Изображение без метаданных декодируется")
+			assert.Equal(t, tc.name, strings.ToUpper(format))
+			assert.Equal(t, image.Rect(0, 0, 16, 16), decoded.Bounds())
+		})
+	}
+}
+
+func TestStripMetadata_WithoutMetadata(t *testing.T) {
+	// Arrange
+	var encoded bytes.Buffer
+	require.NoError(t, png.Encode(&encoded, testImage()))
+
+	// Act
+	stripped, err := StripMetadata(encoded.Bytes(), TypePNG)
+
+	// Assert
+	require.NoError(t, err)
+	assert.Equal(t, encoded.Bytes(), stripped, "Изображение без метаданных не меняется")
+}
+
+func TestStripMetadata_Malformed(t *testing.T) {
+	jpegContent := jpegWithMetadata(t)
+	pngContent := pngWithMetadata(t)
+
+	testCases := []struct {
+		name        string
+		content     []byte
+		contentType string
+	}{
+		{name: "JPEG without SOI", content: jpegContent[2:], contentType: TypeJPEG},
+		{name: "truncated JPEG segment", content: jpegContent[:10], contentType: TypeJPEG},
+		{name: "PNG without signature", content: pngContent[8:], contentType: TypePNG},
+		{name: "PNG without IEND", content: pngContent[:len(pngContent)-12], contentType: TypePNG},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			// Act
+			_, err := StripMetadata(tc.content, tc.contentType)
+
+			// Assert
+			assert.ErrorIs(t, err, ErrMalformedImage)
+		})
+	}
+
+	t.Run("unsupported type", func(t *testing.T) {
+		_, err := StripMetadata([]byte("GIF89a"), "image/gif")
+		assert.Error(t, err)
+	})
+}
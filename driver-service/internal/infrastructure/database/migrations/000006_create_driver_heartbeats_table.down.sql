@@ -0,0 +1,10 @@
+-- Restore status constraint without offline status
+UPDATE drivers SET status = 'inactive' WHERE status = 'offline';
+ALTER TABLE drivers DROP CONSTRAINT check_drivers_status;
+ALTER TABLE drivers ADD CONSTRAINT check_drivers_status CHECK (status IN ('registered', 'pending_verification', 'verified', 'rejected', 'available', 'on_shift', 'busy', 'inactive', 'suspended', 'blocked'));
+
+-- Drop indexes for heartbeats
+DROP INDEX IF EXISTS idx_driver_heartbeats_last_seen_at;
+
+-- Drop table
+DROP TABLE IF EXISTS driver_heartbeats;
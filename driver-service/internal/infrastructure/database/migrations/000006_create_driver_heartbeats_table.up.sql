@@ -0,0 +1,13 @@
+-- Create driver heartbeats table
+-- Отдельная таблица, чтобы частые heartbeat не меняли drivers.updated_at
+CREATE TABLE driver_heartbeats (
+    driver_id UUID PRIMARY KEY REFERENCES drivers(id) ON DELETE CASCADE,
+    last_seen_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Create index for missed heartbeat lookups
+CREATE INDEX idx_driver_heartbeats_last_seen_at ON driver_heartbeats(last_seen_at);
+
+-- Allow offline status for drivers with missed heartbeats
+ALTER TABLE drivers DROP CONSTRAINT check_drivers_status;
+ALTER TABLE drivers ADD CONSTRAINT check_drivers_status CHECK (status IN ('registered', 'pending_verification', 'verified', 'rejected', 'available', 'on_shift', 'busy', 'inactive', 'offline', 'suspended', 'blocked'));
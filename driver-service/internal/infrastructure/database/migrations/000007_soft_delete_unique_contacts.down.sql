@@ -0,0 +1,9 @@
+-- Drop partial unique indexes
+DROP INDEX IF EXISTS idx_drivers_phone_active;
+DROP INDEX IF EXISTS idx_drivers_email_active;
+DROP INDEX IF EXISTS idx_drivers_license_number_active;
+
+-- Restore table-wide uniqueness (fails if a deleted driver's contacts were registered again)
+ALTER TABLE drivers ADD CONSTRAINT drivers_phone_key UNIQUE (phone);
+ALTER TABLE drivers ADD CONSTRAINT drivers_email_key UNIQUE (email);
+ALTER TABLE drivers ADD CONSTRAINT drivers_license_number_key UNIQUE (license_number);
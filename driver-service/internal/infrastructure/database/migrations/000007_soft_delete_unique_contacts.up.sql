@@ -0,0 +1,9 @@
+-- Phone, email and license of a soft-deleted driver can be used for a new registration:
+-- uniqueness is enforced only among drivers that are not deleted
+ALTER TABLE drivers DROP CONSTRAINT drivers_phone_key;
+ALTER TABLE drivers DROP CONSTRAINT drivers_email_key;
+ALTER TABLE drivers DROP CONSTRAINT drivers_license_number_key;
+
+CREATE UNIQUE INDEX idx_drivers_phone_active ON drivers(phone) WHERE deleted_at IS NULL;
+CREATE UNIQUE INDEX idx_drivers_email_active ON drivers(email) WHERE deleted_at IS NULL;
+CREATE UNIQUE INDEX idx_drivers_license_number_active ON drivers(license_number) WHERE deleted_at IS NULL;
@@ -0,0 +1,8 @@
+-- Create processed events table
+-- Идентификаторы обработанных входящих событий: эффект события и запись о нем фиксируются одной транзакцией,
+-- поэтому повторная доставка (JetStream redelivery) не применяет эффект второй раз
+CREATE TABLE processed_events (
+    event_id VARCHAR(255) PRIMARY KEY,
+    subject VARCHAR(255) NOT NULL,
+    processed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,6 @@
+-- Drop lookup indexes
+DROP INDEX IF EXISTS idx_drivers_phone_normalized;
+DROP INDEX IF EXISTS idx_drivers_email_lower;
+
+-- Drop function
+DROP FUNCTION IF EXISTS normalize_phone(TEXT);
@@ -0,0 +1,13 @@
+-- Normalized phone for support lookup: digits only, national Russian format 8XXXXXXXXXX as 7XXXXXXXXXX
+-- (same rule as entities.NormalizePhone)
+CREATE OR REPLACE FUNCTION normalize_phone(phone TEXT) RETURNS TEXT AS $$
+    SELECT CASE
+        WHEN phone !~ '^\s*\+' AND regexp_replace(phone, '\D', '', 'g') ~ '^8\d{10}$'
+            THEN '7' || substr(regexp_replace(phone, '\D', '', 'g'), 2)
+        ELSE regexp_replace(phone, '\D', '', 'g')
+    END
+$$ LANGUAGE SQL IMMUTABLE STRICT;
+
+-- Lookup indexes cover soft-deleted drivers too (include_deleted)
+CREATE INDEX idx_drivers_phone_normalized ON drivers(normalize_phone(phone));
+CREATE INDEX idx_drivers_email_lower ON drivers(lower(email));
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_driver_locations_driver_time;
+CREATE INDEX idx_driver_locations_driver_time ON driver_locations(driver_id, recorded_at DESC);
@@ -0,0 +1,14 @@
+-- A resent point (client retry, repeated item in a batch) matches a stored point of the driver
+-- by recorded_at and coordinates: keep the earliest stored copy and reject further ones
+DELETE FROM driver_locations duplicate
+USING driver_locations kept
+WHERE duplicate.driver_id = kept.driver_id
+  AND duplicate.recorded_at = kept.recorded_at
+  AND duplicate.latitude = kept.latitude
+  AND duplicate.longitude = kept.longitude
+  AND (duplicate.created_at, duplicate.id) > (kept.created_at, kept.id);
+
+-- Same leading columns as before, so history and latest-location queries keep using the index
+DROP INDEX idx_driver_locations_driver_time;
+CREATE UNIQUE INDEX idx_driver_locations_driver_time
+    ON driver_locations(driver_id, recorded_at DESC, latitude, longitude);
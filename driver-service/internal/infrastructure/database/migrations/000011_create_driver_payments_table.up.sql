@@ -0,0 +1,18 @@
+-- Create driver_payments table
+-- Trip payments from payment.processed events: the earnings API sums them per driver and period,
+-- payment_id keeps a payment counted once even if it arrives under another event id
+CREATE TABLE driver_payments (
+    payment_id UUID PRIMARY KEY,
+    driver_id UUID NOT NULL REFERENCES drivers(id) ON DELETE CASCADE,
+    shift_id UUID, -- Shift the payment was added to (shifts are deleted only with the driver)
+    gross_amount DECIMAL(10, 2) NOT NULL,
+    commission DECIMAL(10, 2) NOT NULL DEFAULT 0.0,
+    net_amount DECIMAL(10, 2) NOT NULL,
+    processed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_driver_payments_driver_time ON driver_payments(driver_id, processed_at DESC);
+CREATE INDEX idx_driver_payments_shift_id ON driver_payments(shift_id);
+
+ALTER TABLE driver_payments ADD CONSTRAINT check_driver_payments_amounts
+    CHECK (gross_amount >= 0.0 AND commission >= 0.0 AND net_amount >= 0.0);
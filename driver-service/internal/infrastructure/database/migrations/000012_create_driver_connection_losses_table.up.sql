@@ -0,0 +1,8 @@
+-- Create driver_connection_losses table
+-- Active drivers whose latest location went stale: a row is inserted once when the connection is lost
+-- and deleted by the next fresh location, so driver.availability.changed is published once per transition
+CREATE TABLE driver_connection_losses (
+    driver_id UUID PRIMARY KEY REFERENCES drivers(id) ON DELETE CASCADE,
+    last_location_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    lost_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+-- The original formatting of converted phones is not kept: E.164 numbers stay as they are
+SELECT 1;
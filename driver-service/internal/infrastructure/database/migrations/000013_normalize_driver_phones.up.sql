@@ -0,0 +1,15 @@
+-- Phones are stored in E.164 ('+' and the digits of normalize_phone), so one number written in different
+-- formats is one driver. When several active drivers share a number, the one already in E.164 or else the
+-- earliest registration is converted; the others keep their formatting and are still found by lookup.
+UPDATE drivers d
+SET phone = '+' || normalize_phone(d.phone)
+WHERE d.phone <> '+' || normalize_phone(d.phone)
+  AND normalize_phone(d.phone) ~ '^[1-9]\d{9,14}$'
+  AND (d.deleted_at IS NOT NULL OR NOT EXISTS (
+      SELECT 1 FROM drivers other
+      WHERE other.id <> d.id
+        AND other.deleted_at IS NULL
+        AND normalize_phone(other.phone) = normalize_phone(d.phone)
+        AND (other.phone = '+' || normalize_phone(other.phone)
+             OR (other.created_at, other.id) < (d.created_at, d.id))
+  ));
@@ -0,0 +1,2 @@
+-- Drop column
+ALTER TABLE driver_documents DROP COLUMN IF EXISTS expiry_notified_for;
@@ -0,0 +1,3 @@
+-- Expiry date of the document for which the driver already got an expiring-document push: the hourly
+-- notification skips the document until its expiry date changes (a renewed document is notified again)
+ALTER TABLE driver_documents ADD COLUMN expiry_notified_for DATE;
@@ -44,6 +44,7 @@ func NewPostgresDB(cfg *config.DatabaseConfig, logger *zap.Logger) (*DB, error)
 
 	// Проверка подключения
 	if err := db.Ping(); err != nil {
+		db.Close()
 		return nil, fmt.Errorf("failed to ping database: %w", err)
 	}
 
@@ -55,6 +56,37 @@ func NewPostgresDB(cfg *config.DatabaseConfig, logger *zap.Logger) (*DB, error)
 	}, nil
 }
 
+// defaultConnectRetryInterval пауза между попытками подключения, если connect_retry_interval не задан
+const defaultConnectRetryInterval = 2 * time.Second
+
+// ConnectWithRetry подключается к PostgreSQL, повторяя попытки каждые cfg.ConnectRetryInterval, пока БД
+// не станет доступна или не отменится ctx. Сервис можно запускать раньше БД: после ее запуска
+// он подключится без перезапуска.
+func ConnectWithRetry(ctx context.Context, cfg *config.DatabaseConfig, logger *zap.Logger) (*DB, error) {
+	interval := cfg.ConnectRetryInterval
+	if interval <= 0 {
+		interval = defaultConnectRetryInterval
+	}
+
+	for attempt := 1; ; attempt++ {
+		db, err := NewPostgresDB(cfg, logger)
+		if err == nil {
+			return db, nil
+		}
+
+		logger.Warn("Database is not available yet, retrying",
+			zap.Error(err),
+			zap.Int("attempt", attempt),
+			zap.Duration("delay", interval),
+		)
+		select {
+		case <-ctx.Done():
+			return nil, fmt.Errorf("database is not available after %d attempts: %w", attempt, err)
+		case <-time.After(interval):
+		}
+	}
+}
+
 // RunMigrations выполняет миграции базы данных
 func (db *DB) RunMigrations(migrationsPath string) error {
 	driver, err := postgres.WithInstance(db.DB.DB, &postgres.Config{})
@@ -0,0 +1,133 @@
+package messaging
+
+import (
+	"context"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/repositories"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// EmailSender отправляет письма водителям
+type EmailSender interface {
+	SendEmail(ctx context.Context, to string, message *Message) error
+}
+
+// SMSSender отправляет SMS водителям
+type SMSSender interface {
+	SendSMS(ctx context.Context, phone string, message *Message) error
+}
+
+// Notifier EventPublisher, который передает события следующему издателю и отправляет водителю
+// письмо и SMS при регистрации, проверке документов и блокировке.
+// Ошибки отправки сообщений логируются и не влияют на публикацию события.
+type Notifier struct {
+	next       services.EventPublisher
+	driverRepo repositories.DriverRepository
+	email      EmailSender // nil - письма не отправляются
+	sms        SMSSender   // nil - SMS не отправляются
+	logger     *zap.Logger
+}
+
+// NewNotifier создает Notifier поверх next (может быть nil)
+func NewNotifier(
+	next services.EventPublisher,
+	driverRepo repositories.DriverRepository,
+	email EmailSender,
+	sms SMSSender,
+	logger *zap.Logger,
+) *Notifier {
+	return &Notifier{
+		next:       next,
+		driverRepo: driverRepo,
+		email:      email,
+		sms:        sms,
+		logger:     logger,
+	}
+}
+
+// PublishDriverEvent публикует событие и отправляет водителю сообщение, если событию соответствует шаблон
+func (n *Notifier) PublishDriverEvent(ctx context.Context, eventType string, driverID uuid.UUID, data interface{}) error {
+	var err error
+	if n.next != nil {
+		err = n.next.PublishDriverEvent(ctx, eventType, driverID, data)
+	}
+
+	if name, ok := templateFor(eventType, data); ok {
+		n.notify(ctx, name, driverID)
+	}
+	return err
+}
+
+// templateFor возвращает шаблон сообщения для события
+func templateFor(eventType string, data interface{}) (string, bool) {
+	switch eventType {
+	case "driver.registered":
+		return TemplateWelcome, true
+	case "driver.status.changed":
+		fields, _ := data.(map[string]interface{})
+		newStatus, _ := fields["new_status"].(string)
+
+		switch entities.Status(newStatus) {
+		case entities.StatusVerified:
+			return TemplateApproved, true
+		case entities.StatusRejected:
+			return TemplateRejected, true
+		case entities.StatusBlocked:
+			return TemplateBlocked, true
+		}
+	}
+	return "", false
+}
+
+// notify отрисовывает шаблон на языке водителя и отправляет письмо и SMS
+func (n *Notifier) notify(ctx context.Context, name string, driverID uuid.UUID) {
+	driver, err := n.driverRepo.GetByID(ctx, driverID)
+	if err != nil {
+		n.logger.Error("Failed to load driver for message",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+			zap.String("template", name),
+		)
+		return
+	}
+
+	message, err := Render(name, driver)
+	if err != nil {
+		n.logger.Error("Failed to render message",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+			zap.String("template", name),
+		)
+		return
+	}
+
+	if n.email != nil && driver.Email != "" {
+		if err := n.email.SendEmail(ctx, driver.Email, message); err != nil {
+			n.logger.Error("Failed to send email",
+				zap.Error(err),
+				zap.String("driver_id", driverID.String()),
+				zap.String("template", name),
+			)
+		}
+	}
+
+	if n.sms != nil && driver.Phone != "" {
+		if err := n.sms.SendSMS(ctx, driver.Phone, message); err != nil {
+			n.logger.Error("Failed to send SMS",
+				zap.Error(err),
+				zap.String("driver_id", driverID.String()),
+				zap.String("template", name),
+			)
+		}
+	}
+
+	n.logger.Info("Driver message processed",
+		zap.String("driver_id", driverID.String()),
+		zap.String("template", name),
+		zap.String("locale", string(message.Locale)),
+	)
+}
@@ -0,0 +1,66 @@
+package messaging
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+	"time"
+
+	"driver-service/internal/config"
+)
+
+// SMSRequest тело запроса отправки SMS к API провайдера
+type SMSRequest struct {
+	From string `json:"from"`
+	To   string `json:"to"`
+	Text string `json:"text"`
+}
+
+// SMSClient отправляет SMS водителям через HTTP API провайдера (POST {base_url}/messages)
+type SMSClient struct {
+	config config.SMSAPIConfig
+	client *http.Client
+}
+
+// NewSMSClient создает SMSClient
+func NewSMSClient(cfg config.SMSAPIConfig) *SMSClient {
+	if cfg.Timeout <= 0 {
+		cfg.Timeout = 15 * time.Second
+	}
+	return &SMSClient{
+		config: cfg,
+		client: &http.Client{Timeout: cfg.Timeout},
+	}
+}
+
+// SendSMS отправляет SMS на номер phone
+func (c *SMSClient) SendSMS(ctx context.Context, phone string, message *Message) error {
+	body, err := json.Marshal(SMSRequest{From: c.config.From, To: phone, Text: message.SMSText})
+	if err != nil {
+		return fmt.Errorf("failed to marshal SMS request: %w", err)
+	}
+
+	endpoint := strings.TrimRight(c.config.BaseURL, "/") + "/messages"
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, endpoint, bytes.NewReader(body))
+	if err != nil {
+		return fmt.Errorf("failed to create SMS request: %w", err)
+	}
+	req.Header.Set("Content-Type", "application/json")
+	req.Header.Set("Authorization", "Bearer "+c.config.APIKey)
+
+	resp, err := c.client.Do(req)
+	if err != nil {
+		return fmt.Errorf("failed to send SMS: %w", err)
+	}
+	defer resp.Body.Close()
+	_, _ = io.Copy(io.Discard, resp.Body)
+
+	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		return fmt.Errorf("SMS provider rejected message: status %d", resp.StatusCode)
+	}
+	return nil
+}
@@ -0,0 +1,139 @@
+package messaging
+
+import (
+	"bytes"
+	"fmt"
+	"text/template"
+
+	"driver-service/internal/domain/entities"
+)
+
+// Шаблоны сообщений водителю
+const (
+	TemplateWelcome  = "driver_welcome"  // регистрация водителя
+	TemplateApproved = "driver_approved" // документы проверены
+	TemplateRejected = "driver_rejected" // документы отклонены
+	TemplateBlocked  = "driver_blocked"  // аккаунт заблокирован
+)
+
+// Message сообщение водителю, отрисованное по шаблону на языке водителя
+type Message struct {
+	Template  string
+	Locale    entities.Locale
+	Subject   string
+	EmailBody string
+	SMSText   string
+}
+
+// messageTemplate шаблон письма и SMS на одном языке
+type messageTemplate struct {
+	subject *template.Template
+	email   *template.Template
+	sms     *template.Template
+}
+
+// templateData данные водителя, доступные в шаблонах
+type templateData struct {
+	FirstName string
+	FullName  string
+}
+
+// templates шаблоны сообщений по имени и языку
+var templates = map[string]map[entities.Locale]messageTemplate{
+	TemplateWelcome: {
+		entities.LocaleRU: newMessageTemplate(
+			"Добро пожаловать в сервис такси",
+			"Здравствуйте, {{.FirstName}}!\n\nВы зарегистрированы как водитель. Загрузите водительское удостоверение и медицинскую справку в приложении, чтобы пройти проверку.\n",
+			"{{.FirstName}}, вы зарегистрированы как водитель. Загрузите документы в приложении для проверки.",
+		),
+		entities.LocaleEN: newMessageTemplate(
+			"Welcome to the taxi service",
+			"Hello, {{.FirstName}}!\n\nYou are registered as a driver. Upload your driver license and medical certificate in the app to get verified.\n",
+			"{{.FirstName}}, you are registered as a driver. Upload your documents in the app to get verified.",
+		),
+	},
+	TemplateApproved: {
+		entities.LocaleRU: newMessageTemplate(
+			"Документы проверены",
+			"Здравствуйте, {{.FirstName}}!\n\nВаши документы проверены, вы можете выходить на линию.\n",
+			"{{.FirstName}}, документы проверены. Можно выходить на линию.",
+		),
+		entities.LocaleEN: newMessageTemplate(
+			"Your documents are verified",
+			"Hello, {{.FirstName}}!\n\nYour documents have been verified, you can start taking orders.\n",
+			"{{.FirstName}}, your documents are verified. You can start taking orders.",
+		),
+	},
+	TemplateRejected: {
+		entities.LocaleRU: newMessageTemplate(
+			"Документы отклонены",
+			"Здравствуйте, {{.FirstName}}!\n\nВаши документы не прошли проверку. Загрузите исправленные документы в приложении.\n",
+			"{{.FirstName}}, документы не прошли проверку. Загрузите исправленные документы в приложении.",
+		),
+		entities.LocaleEN: newMessageTemplate(
+			"Your documents were rejected",
+			"Hello, {{.FirstName}}!\n\nYour documents did not pass verification. Upload corrected documents in the app.\n",
+			"{{.FirstName}}, your documents did not pass verification. Upload corrected documents in the app.",
+		),
+	},
+	TemplateBlocked: {
+		entities.LocaleRU: newMessageTemplate(
+			"Аккаунт заблокирован",
+			"Здравствуйте, {{.FirstName}}!\n\nВаш аккаунт водителя заблокирован. Если вы считаете это ошибкой, обратитесь в службу поддержки.\n",
+			"{{.FirstName}}, ваш аккаунт водителя заблокирован. Обратитесь в службу поддержки.",
+		),
+		entities.LocaleEN: newMessageTemplate(
+			"Your account is blocked",
+			"Hello, {{.FirstName}}!\n\nYour driver account has been blocked. If you believe this is a mistake, please contact support.\n",
+			"{{.FirstName}}, your driver account has been blocked. Please contact support.",
+		),
+	},
+}
+
+// newMessageTemplate разбирает тексты шаблона; ошибка в шаблоне - ошибка программы
+func newMessageTemplate(subject, email, sms string) messageTemplate {
+	return messageTemplate{
+		subject: template.Must(template.New("subject").Parse(subject)),
+		email:   template.Must(template.New("email").Parse(email)),
+		sms:     template.Must(template.New("sms").Parse(sms)),
+	}
+}
+
+// Render отрисовывает шаблон name на языке водителя
+func Render(name string, driver *entities.Driver) (*Message, error) {
+	localized, ok := templates[name]
+	if !ok {
+		return nil, fmt.Errorf("unknown message template: %s", name)
+	}
+
+	locale := driver.Locale()
+	tmpl, ok := localized[locale]
+	if !ok {
+		locale = entities.DefaultLocale
+		tmpl = localized[locale]
+	}
+
+	data := templateData{FirstName: driver.FirstName, FullName: driver.GetFullName()}
+	message := &Message{Template: name, Locale: locale}
+
+	var err error
+	if message.Subject, err = execute(tmpl.subject, data); err != nil {
+		return nil, err
+	}
+	if message.EmailBody, err = execute(tmpl.email, data); err != nil {
+		return nil, err
+	}
+	if message.SMSText, err = execute(tmpl.sms, data); err != nil {
+		return nil, err
+	}
+	return message, nil
+}
+
+// execute выполняет шаблон и возвращает результат строкой
+func execute(tmpl *template.Template, data templateData) (string, error) {
+	var buf bytes.Buffer
+	if err := tmpl.Execute(&buf, data); err != nil {
+		return "", fmt.Errorf("failed to render message template %s: %w", tmpl.Name(), err)
+	}
+	return buf.String(), nil
+}
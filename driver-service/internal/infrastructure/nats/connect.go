@@ -0,0 +1,106 @@
+package nats
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"driver-service/internal/config"
+
+	natsgo "github.com/nats-io/nats.go"
+	"go.uber.org/zap"
+)
+
+// Connect подключается к NATS по cfg: аутентификация (пользователь и пароль, токен или файл учетных данных),
+// TLS, пинги и переподключение после обрыва соединения берет на себя клиент. extra дополняет параметры подключения.
+func Connect(cfg config.NATSConfig, logger *zap.Logger, extra ...natsgo.Option) (*natsgo.Conn, error) {
+	nc, err := natsgo.Connect(cfg.URL, append(connectOptions(cfg, logger), extra...)...)
+	if err != nil {
+		return nil, fmt.Errorf("failed to connect to NATS %s: %w", cfg.URL, err)
+	}
+	return nc, nil
+}
+
+// connectOptions параметры подключения из cfg; нулевые значения оставляют умолчания клиента
+func connectOptions(cfg config.NATSConfig, logger *zap.Logger) []natsgo.Option {
+	options := []natsgo.Option{
+		natsgo.Name(cfg.ClientID),
+		natsgo.DisconnectErrHandler(func(_ *natsgo.Conn, err error) {
+			if err != nil {
+				logger.Warn("NATS connection lost, reconnecting", zap.Error(err))
+			}
+		}),
+		natsgo.ReconnectHandler(func(nc *natsgo.Conn) {
+			logger.Info("NATS connection restored", zap.String("server", nc.ConnectedUrlRedacted()))
+		}),
+		natsgo.ErrorHandler(func(_ *natsgo.Conn, sub *natsgo.Subscription, err error) {
+			fields := []zap.Field{zap.Error(err)}
+			if sub != nil {
+				fields = append(fields, zap.String("subject", sub.Subject))
+			}
+			logger.Error("NATS asynchronous error", fields...)
+		}),
+	}
+
+	if cfg.ConnectTimeout > 0 {
+		options = append(options, natsgo.Timeout(cfg.ConnectTimeout))
+	}
+	if cfg.MaxReconnect != 0 {
+		options = append(options, natsgo.MaxReconnects(cfg.MaxReconnect))
+	}
+	if cfg.ReconnectDelay > 0 {
+		options = append(options, natsgo.ReconnectWait(cfg.ReconnectDelay))
+	}
+	if cfg.PingInterval > 0 {
+		options = append(options, natsgo.PingInterval(cfg.PingInterval))
+	}
+	if cfg.MaxPingsOut > 0 {
+		options = append(options, natsgo.MaxPingsOutstanding(cfg.MaxPingsOut))
+	}
+
+	switch {
+	case cfg.CredentialsFile != "":
+		options = append(options, natsgo.UserCredentials(cfg.CredentialsFile))
+	case cfg.Token != "":
+		options = append(options, natsgo.Token(cfg.Token))
+	case cfg.Username != "":
+		options = append(options, natsgo.UserInfo(cfg.Username, cfg.Password))
+	}
+
+	if cfg.TLSCAFile != "" {
+		options = append(options, natsgo.RootCAs(cfg.TLSCAFile))
+	}
+	if cfg.TLSCertFile != "" || cfg.TLSKeyFile != "" {
+		options = append(options, natsgo.ClientCert(cfg.TLSCertFile, cfg.TLSKeyFile))
+	}
+
+	return options
+}
+
+// Ping проверяет доступность NATS в отдельном соединении (проверка готовности сервиса): подключение
+// с параметрами cfg и ответ сервера на PING. Ожидание ограничено ctx.
+func Ping(ctx context.Context, cfg config.NATSConfig) error {
+	if deadline, ok := ctx.Deadline(); ok {
+		cfg.ConnectTimeout = time.Until(deadline)
+		if cfg.ConnectTimeout <= 0 {
+			return ctx.Err()
+		}
+	}
+
+	nc, err := Connect(cfg, zap.NewNop(), natsgo.NoReconnect())
+	if err != nil {
+		return err
+	}
+	defer nc.Close()
+
+	// FlushWithContext требует срок у ctx
+	if _, ok := ctx.Deadline(); ok {
+		err = nc.FlushWithContext(ctx)
+	} else {
+		err = nc.Flush()
+	}
+	if err != nil {
+		return fmt.Errorf("nats ping failed: %w", err)
+	}
+	return nil
+}
@@ -0,0 +1,83 @@
+package nats
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/entities"
+
+	natsgo "github.com/nats-io/nats.go"
+	"go.uber.org/zap"
+)
+
+// SubjectDispatchFeed тема, через которую реплики сервиса обмениваются сохраненными местоположениями для потока
+// позиций диспетчеров. Тема внутренняя: это не событие водителя из api/events/catalog.yaml.
+const SubjectDispatchFeed = "driver-service.dispatch.feed"
+
+// LocationRelay рассылает сохраненные местоположения всем репликам сервиса через core NATS (services.LocationRelay).
+// Каждая реплика подписана на SubjectDispatchFeed без группы очередей, поэтому получает и свои, и чужие
+// местоположения и передает их deliver. Доставка без подтверждений, как и сам поток: пропущенную позицию
+// заменит следующее обновление водителя.
+type LocationRelay struct {
+	nc     *natsgo.Conn
+	sub    *natsgo.Subscription
+	logger *zap.Logger
+}
+
+// NewLocationRelay подключается к NATS по cfg и подписывается на SubjectDispatchFeed. Если сервер доступен,
+// подписка действует к возврату; как и Publisher, недоступный при запуске сервер не мешает запуску:
+// подписка создается после подключения.
+func NewLocationRelay(
+	cfg config.NATSConfig,
+	deliver func(locations ...*entities.DriverLocation),
+	logger *zap.Logger,
+) (*LocationRelay, error) {
+	nc, err := Connect(cfg, logger, natsgo.RetryOnFailedConnect(true))
+	if err != nil {
+		return nil, err
+	}
+
+	sub, err := nc.Subscribe(SubjectDispatchFeed, func(msg *natsgo.Msg) {
+		var locations []*entities.DriverLocation
+		if err := json.Unmarshal(msg.Data, &locations); err != nil {
+			logger.Warn("Failed to decode dispatch feed locations", zap.Error(err))
+			return
+		}
+		deliver(locations...)
+	})
+	if err != nil {
+		nc.Close()
+		return nil, fmt.Errorf("failed to subscribe to %s: %w", SubjectDispatchFeed, err)
+	}
+	if nc.IsConnected() {
+		// Ответ на PING означает, что сервер обработал подписку
+		if err := nc.Flush(); err != nil {
+			logger.Warn("Failed to flush dispatch feed subscription", zap.Error(err))
+		}
+	}
+
+	return &LocationRelay{nc: nc, sub: sub, logger: logger}, nil
+}
+
+// RelayLocations публикует местоположения для подписчиков потока всех реплик
+func (r *LocationRelay) RelayLocations(ctx context.Context, locations []*entities.DriverLocation) error {
+	payload, err := json.Marshal(locations)
+	if err != nil {
+		return fmt.Errorf("failed to marshal dispatch feed locations: %w", err)
+	}
+
+	if err := r.nc.Publish(SubjectDispatchFeed, payload); err != nil {
+		return fmt.Errorf("failed to publish dispatch feed locations: %w", err)
+	}
+	return nil
+}
+
+// Close снимает подписку и закрывает соединение
+func (r *LocationRelay) Close() {
+	if err := r.sub.Unsubscribe(); err != nil {
+		r.logger.Warn("Failed to unsubscribe from dispatch feed", zap.Error(err))
+	}
+	r.nc.Close()
+}
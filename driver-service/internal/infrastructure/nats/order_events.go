@@ -0,0 +1,143 @@
+package nats
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/google/uuid"
+)
+
+// Темы событий сервисов заказов и платежей, на которые подписан сервис
+const (
+	SubjectOrderAssigned    = services.SubjectOrderAssigned
+	SubjectOrderCompleted   = services.SubjectOrderCompleted
+	SubjectPaymentProcessed = services.SubjectPaymentProcessed
+)
+
+// orderEvent данные событий order.* и payment.* (см. api/events/catalog.yaml)
+type orderEvent struct {
+	OrderID    uuid.UUID `json:"order_id"`
+	PaymentID  uuid.UUID `json:"payment_id"`
+	DriverID   uuid.UUID `json:"driver_id"`
+	DistanceKm float64   `json:"distance_km"`
+	Amount     float64   `json:"amount"`
+
+	// Разбивка оплаты: amount - заработок водителя, gross_amount - оплата пассажира, commission - комиссия.
+	// Без gross_amount оплата пассажира считается равной amount + commission.
+	GrossAmount *float64 `json:"gross_amount"`
+	Commission  float64  `json:"commission"`
+}
+
+// RegisterOrderHandlers подписывает s на события сервисов заказов и платежей: order.assigned переводит водителя
+// на смене в статус busy и отправляет ему push-уведомление, order.completed учитывает поездку и возвращает занятого водителя на смену,
+// payment.processed записывает оплату и добавляет ее к заработку смены. События одного водителя обрабатываются по порядку поступления.
+func RegisterOrderHandlers(s *Subscriber, orders services.OrderEventService, notifications services.NotificationService) {
+	s.PartitionBy(driverKey)
+
+	s.Handle(SubjectOrderAssigned, func(ctx context.Context, message *Message) error {
+		event, err := decodeOrderEvent(message)
+		if err != nil {
+			return err
+		}
+
+		err = orders.HandleOrderAssigned(ctx, eventID(message, event.OrderID), event.DriverID, event.OrderID)
+		if err != nil {
+			return unprocessable(err)
+		}
+
+		err = notifications.HandleOrderAssigned(ctx, event.DriverID, event.OrderID)
+		if errors.Is(err, entities.ErrPushTokenMissing) {
+			return nil
+		}
+		return unprocessable(err)
+	})
+
+	s.Handle(SubjectOrderCompleted, func(ctx context.Context, message *Message) error {
+		event, err := decodeOrderEvent(message)
+		if err != nil {
+			return err
+		}
+		if event.DistanceKm < 0 {
+			return fmt.Errorf("%w: %s distance_km is negative", ErrUnprocessable, message.Subject)
+		}
+
+		err = orders.HandleOrderCompleted(ctx, eventID(message, event.OrderID), event.DriverID, event.OrderID, event.DistanceKm)
+		return unprocessable(err)
+	})
+
+	s.Handle(SubjectPaymentProcessed, func(ctx context.Context, message *Message) error {
+		event, err := decodeOrderEvent(message)
+		if err != nil {
+			return err
+		}
+		if event.Amount < 0 {
+			return fmt.Errorf("%w: %s amount is negative", ErrUnprocessable, message.Subject)
+		}
+		// payment_id появился позже: оплата в событиях предыдущего поколения идентифицируется заказом
+		paymentID := event.PaymentID
+		if paymentID == uuid.Nil {
+			paymentID = event.OrderID
+		}
+		payment := &entities.TripPayment{
+			PaymentID:   paymentID,
+			DriverID:    event.DriverID,
+			GrossAmount: event.Amount + event.Commission,
+			Commission:  event.Commission,
+			NetAmount:   event.Amount,
+		}
+		if event.GrossAmount != nil {
+			payment.GrossAmount = *event.GrossAmount
+		}
+		if err := payment.Validate(); err != nil {
+			return fmt.Errorf("%w: invalid %s event: %v", ErrUnprocessable, message.Subject, err)
+		}
+
+		err = orders.HandlePaymentProcessed(ctx, eventID(message, paymentID), payment)
+		return unprocessable(err)
+	})
+}
+
+// decodeOrderEvent разбирает данные события order.* или payment.*
+func decodeOrderEvent(message *Message) (*orderEvent, error) {
+	var event orderEvent
+	if err := json.Unmarshal(message.Data, &event); err != nil {
+		return nil, fmt.Errorf("%w: invalid %s event: %v", ErrUnprocessable, message.Subject, err)
+	}
+	if event.DriverID == uuid.Nil {
+		return nil, fmt.Errorf("%w: invalid %s event: driver_id is required", ErrUnprocessable, message.Subject)
+	}
+	return &event, nil
+}
+
+// driverKey возвращает driver_id события или пустую строку, если данные не разбираются
+func driverKey(message *Message) string {
+	var event struct {
+		DriverID string `json:"driver_id"`
+	}
+	if json.Unmarshal(message.Data, &event) != nil {
+		return ""
+	}
+	return event.DriverID
+}
+
+// eventID возвращает идентификатор логического события: заголовок Nats-Msg-Id, который издатель задает
+// для дедупликации, или, если его нет, тему и ID заказа или платежа
+func eventID(message *Message, entityID uuid.UUID) string {
+	if id := message.Header.Get(services.MessageIDHeader); id != "" {
+		return id
+	}
+	return message.Subject + ":" + entityID.String()
+}
+
+// unprocessable помечает ошибки, которые не исчезнут при повторной доставке (водителя нет)
+func unprocessable(err error) error {
+	if errors.Is(err, entities.ErrDriverNotFound) {
+		return fmt.Errorf("%w: %v", ErrUnprocessable, err)
+	}
+	return err
+}
@@ -0,0 +1,81 @@
+package nats
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/services"
+
+	"github.com/google/uuid"
+	natsgo "github.com/nats-io/nats.go"
+	"go.uber.org/zap"
+)
+
+// closeFlushTimeout сколько Close ждет отправки опубликованных событий
+const closeFlushTimeout = 5 * time.Second
+
+// Заголовки публикуемых событий: данные части событий (driver.location.updated) не содержат ID водителя,
+// а время публикации нужно подписчикам, чтобы считать задержку доставки
+const (
+	DriverIDHeader    = "Driver-Id"
+	PublishedAtHeader = "Published-At" // RFC 3339 с наносекундами, UTC
+)
+
+// Publisher публикует события водителей в NATS (services.EventPublisher): тема - тип события, данные события
+// в JSON с заголовками Nats-Msg-Id, Driver-Id, Published-At и traceparent трассы запроса.
+// Пока соединение восстанавливается, клиент копит публикации в буфере переподключения.
+type Publisher struct {
+	nc     *natsgo.Conn
+	logger *zap.Logger
+}
+
+// NewPublisher подключается к NATS по cfg. Недоступный при запуске сервер не мешает запуску:
+// клиент подключается в фоне, события до подключения ждут в буфере переподключения.
+func NewPublisher(cfg config.NATSConfig, logger *zap.Logger) (*Publisher, error) {
+	nc, err := Connect(cfg, logger, natsgo.RetryOnFailedConnect(true))
+	if err != nil {
+		return nil, err
+	}
+	return &Publisher{nc: nc, logger: logger}, nil
+}
+
+// PublishDriverEvent публикует событие eventType водителя driverID
+func (p *Publisher) PublishDriverEvent(ctx context.Context, eventType string, driverID uuid.UUID, data interface{}) error {
+	payload, err := json.Marshal(data)
+	if err != nil {
+		return fmt.Errorf("failed to marshal %s event: %w", eventType, err)
+	}
+
+	msg := natsgo.NewMsg(eventType)
+	msg.Data = payload
+	msg.Header.Set(services.MessageIDHeader, uuid.NewString())
+	msg.Header.Set(DriverIDHeader, driverID.String())
+	msg.Header.Set(PublishedAtHeader, time.Now().UTC().Format(time.RFC3339Nano))
+	if traceparent := services.TraceparentFromContext(ctx); traceparent != "" {
+		msg.Header.Set(services.TraceparentHeader, traceparent)
+	}
+
+	if err := p.nc.PublishMsg(msg); err != nil {
+		return fmt.Errorf("failed to publish %s event: %w", eventType, err)
+	}
+	return nil
+}
+
+// Flush ждет, пока сервер NATS примет опубликованные события; ожидание ограничено ctx
+func (p *Publisher) Flush(ctx context.Context) error {
+	if _, ok := ctx.Deadline(); !ok {
+		return p.nc.Flush()
+	}
+	return p.nc.FlushWithContext(ctx)
+}
+
+// Close ждет до closeFlushTimeout, пока сервер примет опубликованные события, и закрывает соединение
+func (p *Publisher) Close() {
+	if err := p.nc.FlushTimeout(closeFlushTimeout); err != nil {
+		p.logger.Warn("Failed to flush NATS events before close", zap.Error(err))
+	}
+	p.nc.Close()
+}
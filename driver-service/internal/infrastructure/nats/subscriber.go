@@ -0,0 +1,363 @@
+package nats
+
+import (
+	"context"
+	"errors"
+	"hash/fnv"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/services"
+
+	natsgo "github.com/nats-io/nats.go"
+	"github.com/nats-io/nats.go/jetstream"
+	"go.uber.org/zap"
+)
+
+// defaultReconnectDelay пауза перед повторной подпиской, если reconnect_delay не задан
+const defaultReconnectDelay = time.Second
+
+// defaultWorkers количество обработчиков сообщений, если workers не задан
+const defaultWorkers = 8
+
+// ErrUnprocessable ошибка обработки, которая не исчезнет при повторной доставке (некорректные данные события,
+// неизвестный водитель): такое сообщение JetStream больше не доставляет
+var ErrUnprocessable = errors.New("unprocessable message")
+
+// errConnectionClosed соединение закрыто клиентом после исчерпания max_reconnect
+var errConnectionClosed = errors.New("nats connection closed")
+
+// Header заголовки сообщения NATS; имена сравниваются без учета регистра
+type Header map[string][]string
+
+// Get возвращает первое значение заголовка key или пустую строку
+func (h Header) Get(key string) string {
+	for name, values := range h {
+		if strings.EqualFold(name, key) && len(values) > 0 {
+			return values[0]
+		}
+	}
+	return ""
+}
+
+// Message входящее сообщение NATS
+type Message struct {
+	Subject string
+	Reply   string
+	Header  Header
+	Data    []byte
+}
+
+// Handler обрабатывает входящее сообщение; ошибка логируется. Сообщение JetStream после ошибки доставляется
+// повторно, если это не ErrUnprocessable, поэтому эффекты обработчика должны переносить повторную доставку.
+type Handler func(ctx context.Context, message *Message) error
+
+// acknowledger подтверждение доставки JetStream (jetstream.Msg)
+type acknowledger interface {
+	Ack() error
+	Nak() error
+	Term() error
+}
+
+// delivery сообщение, ожидающее обработчика; ack пустой у сообщений core NATS
+type delivery struct {
+	message *Message
+	ack     acknowledger
+}
+
+// Subscriber передает сообщения NATS обработчикам тем в пуле из workers обработчиков. Сообщения с одинаковым
+// ключом PartitionBy обрабатываются одним обработчиком по порядку поступления; клиент получает новые сообщения,
+// только пока очередь пула не заполнена.
+// Если задан stream, сервис создает или обновляет durable pull-консьюмер JetStream этого потока с явными
+// подтверждениями: сообщение подтверждается после обработки, неподтвержденные (в том числе из-за перезапуска
+// сервиса) JetStream доставляет повторно через ack_wait. Иначе сервис подписывается на темы обработчиков в core NATS.
+// Трасса входящего события (заголовок traceparent) продолжается в контексте обработчика, поэтому события,
+// опубликованные при обработке, связаны с ней.
+type Subscriber struct {
+	config    config.NATSConfig
+	subjects  []string
+	handlers  map[string]Handler
+	partition func(message *Message) string
+	logger    *zap.Logger
+
+	subscribed     chan struct{}
+	subscribedOnce sync.Once
+
+	next          atomic.Uint64 // очередь для сообщений без ключа
+	processed     atomic.Uint64
+	failed        atomic.Uint64
+	unprocessable atomic.Uint64
+}
+
+// SubscriberStats счетчики результатов обработки сообщений с момента создания Subscriber;
+// сообщения тем без обработчика не учитываются
+type SubscriberStats struct {
+	Processed     uint64 // обработаны без ошибки
+	Failed        uint64 // ошибка обработки; сообщение JetStream доставляется повторно
+	Unprocessable uint64 // ErrUnprocessable: некорректные данные или неприменимое событие
+}
+
+// NewSubscriber создает Subscriber для сервера cfg.URL
+func NewSubscriber(cfg config.NATSConfig, logger *zap.Logger) *Subscriber {
+	return &Subscriber{
+		config:     cfg,
+		handlers:   make(map[string]Handler),
+		logger:     logger,
+		subscribed: make(chan struct{}),
+	}
+}
+
+// Handle регистрирует обработчик темы subject; вызывается до Run
+func (s *Subscriber) Handle(subject string, handler Handler) {
+	if _, ok := s.handlers[subject]; !ok {
+		s.subjects = append(s.subjects, subject)
+	}
+	s.handlers[subject] = handler
+}
+
+// PartitionBy задает ключ порядка обработки: сообщения с одинаковым непустым ключом обрабатываются
+// по порядку поступления, остальные распределяются по обработчикам по очереди; вызывается до Run
+func (s *Subscriber) PartitionBy(key func(message *Message) string) {
+	s.partition = key
+}
+
+// Stats возвращает счетчики результатов обработки сообщений
+func (s *Subscriber) Stats() SubscriberStats {
+	return SubscriberStats{
+		Processed:     s.processed.Load(),
+		Failed:        s.failed.Load(),
+		Unprocessable: s.unprocessable.Load(),
+	}
+}
+
+// Subscribed закрывается после первой успешной подписки; сообщения core NATS, опубликованные раньше, не обрабатываются
+func (s *Subscriber) Subscribed() <-chan struct{} {
+	return s.subscribed
+}
+
+// Run обрабатывает сообщения до отмены ctx. Обрывы соединения клиент переживает сам; подписка создается
+// заново, если подключиться не удалось или клиент закрыл соединение после max_reconnect попыток.
+func (s *Subscriber) Run(ctx context.Context) {
+	delay := s.config.ReconnectDelay
+	if delay <= 0 {
+		delay = defaultReconnectDelay
+	}
+
+	for {
+		err := s.listen(ctx)
+		if ctx.Err() != nil {
+			return
+		}
+
+		s.logger.Warn("NATS subscription lost, resubscribing",
+			zap.Error(err),
+			zap.Duration("delay", delay),
+		)
+		select {
+		case <-ctx.Done():
+			return
+		case <-time.After(delay):
+		}
+	}
+}
+
+// listen подключается к NATS, подписывается и передает сообщения пулу обработчиков до отмены ctx
+// или закрытия соединения. Перед возвратом подписка снимается, а обработка начатых сообщений завершается.
+func (s *Subscriber) listen(ctx context.Context) error {
+	closed := make(chan struct{})
+	var closedOnce sync.Once
+	nc, err := Connect(s.config, s.logger, natsgo.ClosedHandler(func(*natsgo.Conn) {
+		closedOnce.Do(func() { close(closed) })
+	}))
+	if err != nil {
+		return err
+	}
+	defer nc.Close()
+
+	workers := s.config.Workers
+	if workers <= 0 {
+		workers = defaultWorkers
+	}
+	queues := make([]chan delivery, workers)
+	stop := make(chan struct{})
+	var wg sync.WaitGroup
+	for i := range queues {
+		queues[i] = make(chan delivery, 1)
+		wg.Add(1)
+		go func(queue <-chan delivery) {
+			defer wg.Done()
+			for {
+				select {
+				case <-stop:
+					return
+				case d := <-queue:
+					s.process(ctx, d)
+				}
+			}
+		}(queues[i])
+	}
+	defer func() {
+		close(stop)
+		wg.Wait()
+	}()
+
+	// Пока очередь обработчика занята, клиент не получает новые сообщения подписки
+	dispatch := func(d delivery) {
+		select {
+		case queues[s.queueIndex(d.message, len(queues))] <- d:
+		case <-stop:
+		}
+	}
+
+	if s.config.Stream != "" {
+		consume, err := s.consumeStream(ctx, nc, workers, dispatch)
+		if err != nil {
+			return err
+		}
+		defer consume.Stop()
+	} else {
+		for _, subject := range s.subjects {
+			sub, err := nc.Subscribe(subject, func(msg *natsgo.Msg) {
+				dispatch(delivery{message: newMessage(msg.Subject, msg.Reply, msg.Header, msg.Data)})
+			})
+			if err != nil {
+				return err
+			}
+			defer sub.Unsubscribe()
+		}
+	}
+
+	// Ответ на PING означает, что сервер обработал подписки
+	if err := nc.Flush(); err != nil {
+		return err
+	}
+	s.subscribedOnce.Do(func() {
+		s.logger.Info("Subscribed to NATS subjects",
+			zap.Strings("subjects", s.subjects),
+			zap.String("stream", s.config.Stream),
+			zap.String("durable", s.config.Durable),
+			zap.Int("workers", workers),
+		)
+		close(s.subscribed)
+	})
+
+	select {
+	case <-ctx.Done():
+		return ctx.Err()
+	case <-closed:
+		return errConnectionClosed
+	}
+}
+
+// consumeStream создает или обновляет durable-консьюмер потока stream и передает его сообщения dispatch.
+// Клиент держит не больше двух сообщений на обработчик, чтобы сообщения не ждали в клиенте дольше ack_wait.
+func (s *Subscriber) consumeStream(
+	ctx context.Context,
+	nc *natsgo.Conn,
+	workers int,
+	dispatch func(delivery),
+) (jetstream.ConsumeContext, error) {
+	js, err := jetstream.New(nc)
+	if err != nil {
+		return nil, err
+	}
+
+	consumer, err := js.CreateOrUpdateConsumer(ctx, s.config.Stream, jetstream.ConsumerConfig{
+		Durable:    s.config.Durable,
+		AckPolicy:  jetstream.AckExplicitPolicy,
+		AckWait:    s.config.AckWait,
+		MaxDeliver: s.config.MaxDeliver,
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	return consumer.Consume(func(msg jetstream.Msg) {
+		dispatch(delivery{message: newMessage(msg.Subject(), msg.Reply(), msg.Headers(), msg.Data()), ack: msg})
+	},
+		jetstream.PullMaxMessages(2*workers),
+		jetstream.ConsumeErrHandler(func(_ jetstream.ConsumeContext, err error) {
+			s.logger.Warn("JetStream consumer error", zap.Error(err), zap.String("stream", s.config.Stream))
+		}),
+	)
+}
+
+// queueIndex выбирает очередь обработчика для сообщения
+func (s *Subscriber) queueIndex(message *Message, queues int) int {
+	key := ""
+	if s.partition != nil {
+		key = s.partition(message)
+	}
+	if key == "" {
+		return int(s.next.Add(1) % uint64(queues))
+	}
+
+	hash := fnv.New32a()
+	hash.Write([]byte(key))
+	return int(hash.Sum32() % uint32(queues))
+}
+
+// process обрабатывает сообщение и отвечает JetStream на его доставку
+func (s *Subscriber) process(ctx context.Context, d delivery) {
+	err := s.handle(ctx, d.message)
+	if d.ack == nil {
+		return
+	}
+	if ackErr := acknowledge(d.ack, err); ackErr != nil {
+		s.logger.Warn("Failed to acknowledge NATS message",
+			zap.Error(ackErr),
+			zap.String("subject", d.message.Subject),
+			zap.String("message_id", d.message.Header.Get(services.MessageIDHeader)),
+		)
+	}
+}
+
+// handle передает сообщение обработчику темы в контексте трассы входящего события, учитывает результат
+// в Stats и возвращает ошибку обработки. Сообщения тем без обработчика считаются обработанными.
+func (s *Subscriber) handle(ctx context.Context, message *Message) error {
+	handler, ok := s.handlers[message.Subject]
+	if !ok {
+		return nil
+	}
+
+	ctx = services.ContextWithTraceparent(ctx, message.Header.Get(services.TraceparentHeader))
+	err := handler(ctx, message)
+	switch {
+	case err == nil:
+		s.processed.Add(1)
+	case errors.Is(err, ErrUnprocessable):
+		s.unprocessable.Add(1)
+	default:
+		s.failed.Add(1)
+	}
+	if err != nil {
+		s.logger.Error("Failed to handle NATS message",
+			zap.Error(err),
+			zap.String("subject", message.Subject),
+			zap.String("message_id", message.Header.Get(services.MessageIDHeader)),
+			zap.String("traceparent", message.Header.Get(services.TraceparentHeader)),
+		)
+	}
+	return err
+}
+
+// acknowledge отвечает JetStream на доставку по результату обработки: обработано, повторить доставку
+// или больше не доставлять. Если сервис остановлен до ответа, JetStream доставит сообщение повторно через ack_wait.
+func acknowledge(ack acknowledger, handleErr error) error {
+	switch {
+	case errors.Is(handleErr, ErrUnprocessable):
+		return ack.Term()
+	case handleErr != nil:
+		return ack.Nak()
+	default:
+		return ack.Ack()
+	}
+}
+
+// newMessage создает Message из полей сообщения клиента
+func newMessage(subject, reply string, header natsgo.Header, data []byte) *Message {
+	return &Message{Subject: subject, Reply: reply, Header: Header(header), Data: data}
+}
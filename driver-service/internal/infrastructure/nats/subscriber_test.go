@@ -0,0 +1,178 @@
+package nats
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/services"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// fakeAck записывает ответы на доставку JetStream
+type fakeAck struct {
+	responses []string
+}
+
+func (a *fakeAck) Ack() error { a.responses = append(a.responses, "ack"); return nil }
+func (a *fakeAck) Nak() error { a.responses = append(a.responses, "nak"); return nil }
+func (a *fakeAck) Term() error { a.responses = append(a.responses, "term"); return nil }
+
+func TestSubscriber_PropagatesTraceContext(t *testing.T) {
+	// Arrange
+	subscriber := NewSubscriber(config.NATSConfig{}, zap.NewNop())
+	var traceparent string
+	subscriber.Handle(SubjectOrderCompleted, func(ctx context.Context, message *Message) error {
+		traceparent = services.TraceparentFromContext(ctx)
+		return nil
+	})
+	incoming := "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+
+	// Act
+	err := subscriber.handle(context.Background(), &Message{
+		Subject: SubjectOrderCompleted,
+		Header:  Header{"Traceparent": {incoming}, "Nats-Msg-Id": {"order-1"}},
+		Data:    []byte(`{"order_id":"1"}`),
+	})
+
+	// Assert
+	require.NoError(t, err)
+	parts := strings.Split(traceparent, "-")
+	require.Len(t, parts, 4, traceparent)
+	assert.Equal(t, []string{"00", "0af7651916cd43dd8448eb211c80319c"}, parts[:2])
+	assert.NotEqual(t, "b7ad6b7169203331", parts[2], "Новый span id обработки")
+	assert.Len(t, parts[2], 16)
+	assert.Equal(t, "01", parts[3])
+}
+
+func TestSubscriber_MessagesWithoutTraceContext(t *testing.T) {
+	// Arrange
+	subscriber := NewSubscriber(config.NATSConfig{}, zap.NewNop())
+	var traceparents []string
+	subscriber.Handle(SubjectOrderCompleted, func(ctx context.Context, message *Message) error {
+		traceparents = append(traceparents, services.TraceparentFromContext(ctx))
+		return nil
+	})
+
+	// Act
+	for _, header := range []Header{nil, {"traceparent": {"00-0af7651916cd43dd8448eb211c80319C-b7ad6b7169203331-01"}}} {
+		require.NoError(t, subscriber.handle(context.Background(), &Message{Subject: SubjectOrderCompleted, Header: header}))
+	}
+	require.NoError(t, subscriber.handle(context.Background(), &Message{Subject: SubjectOrderAssigned}))
+
+	// Assert
+	assert.Equal(t, []string{"", ""}, traceparents, "Темы без обработчика пропускаются")
+}
+
+func TestSubscriber_AcknowledgesJetStreamMessages(t *testing.T) {
+	tests := []struct {
+		name      string
+		handleErr error
+		expected  string
+	}{
+		{"Processed", nil, "ack"},
+		{"Retry", errors.New("database unavailable"), "nak"},
+		{"Unprocessable", fmt.Errorf("%w: driver not found", ErrUnprocessable), "term"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			// Arrange
+			subscriber := NewSubscriber(config.NATSConfig{}, zap.NewNop())
+			subscriber.Handle(SubjectOrderCompleted, func(ctx context.Context, message *Message) error {
+				return tt.handleErr
+			})
+			ack := &fakeAck{}
+
+			// Act
+			subscriber.process(context.Background(), delivery{message: &Message{Subject: SubjectOrderCompleted}, ack: ack})
+
+			// Assert
+			assert.Equal(t, []string{tt.expected}, ack.responses)
+		})
+	}
+}
+
+func TestSubscriber_CountsHandlingResults(t *testing.T) {
+	// Arrange
+	subscriber := NewSubscriber(config.NATSConfig{}, zap.NewNop())
+	subscriber.Handle(SubjectOrderCompleted, func(ctx context.Context, message *Message) error {
+		switch string(message.Data) {
+		case "retry":
+			return errors.New("database unavailable")
+		case "garbled":
+			return fmt.Errorf("%w: invalid event", ErrUnprocessable)
+		}
+		return nil
+	})
+
+	// Act
+	for _, message := range []*Message{
+		{Subject: SubjectOrderCompleted, Data: []byte(`{}`)},
+		{Subject: SubjectOrderCompleted, Data: []byte("garbled")},
+		{Subject: SubjectOrderCompleted, Data: []byte("retry")},
+		{Subject: SubjectOrderCompleted, Data: []byte("garbled")},
+		{Subject: SubjectOrderCompleted, Data: []byte(`{}`)},
+		{Subject: SubjectPaymentProcessed, Data: []byte("garbled")},
+	} {
+		subscriber.handle(context.Background(), message)
+	}
+
+	// Assert
+	assert.Equal(t, SubscriberStats{Processed: 2, Failed: 1, Unprocessable: 2}, subscriber.Stats(),
+		"Сообщения тем без обработчика не учитываются")
+}
+
+func TestSubscriber_PartitionsByDriver(t *testing.T) {
+	// Arrange
+	subscriber := NewSubscriber(config.NATSConfig{}, zap.NewNop())
+	RegisterOrderHandlers(subscriber, nil, nil)
+	driverEvent := func(driverID string) *Message {
+		return &Message{Subject: SubjectOrderCompleted, Data: []byte(`{"driver_id":"` + driverID + `"}`)}
+	}
+
+	// Act
+	first := subscriber.queueIndex(driverEvent("9f1c3a52-3d4e-4b8a-9c61-0b7e2f4d5a10"), 8)
+	second := subscriber.queueIndex(driverEvent("9f1c3a52-3d4e-4b8a-9c61-0b7e2f4d5a10"), 8)
+	garbled := make(map[int]bool)
+	for i := 0; i < 8; i++ {
+		garbled[subscriber.queueIndex(&Message{Subject: SubjectOrderCompleted, Data: []byte("garbled")}, 8)] = true
+	}
+
+	// Assert
+	assert.Equal(t, first, second, "События одного водителя обрабатываются по порядку")
+	assert.Len(t, garbled, 8, "Сообщения без ключа распределяются по всем обработчикам")
+}
+
+func TestPing_Unreachable(t *testing.T) {
+	// Arrange
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+	addr := listener.Addr().String()
+	require.NoError(t, listener.Close())
+
+	ctx, cancel := context.WithTimeout(context.Background(), time.Second)
+	defer cancel()
+
+	// Act
+	err = Ping(ctx, config.NATSConfig{URL: "nats://" + addr, ClientID: "driver-service"})
+
+	// Assert
+	assert.Error(t, err)
+}
+
+func TestHeaderGet(t *testing.T) {
+	header := Header{"Nats-Msg-Id": {"1"}, "X-Tag": {"a", "b"}}
+	assert.Equal(t, "1", header.Get("nats-msg-id"))
+	assert.Equal(t, "a", header.Get("X-Tag"))
+	assert.Empty(t, header.Get("Traceparent"))
+	assert.Empty(t, Header(nil).Get("Traceparent"))
+}
@@ -0,0 +1,179 @@
+package push
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"go.uber.org/zap"
+)
+
+// Gateway отправляет push-уведомления через FCM HTTP v1 API и APNS.
+// Ответы 5xx, 429 и сетевые ошибки повторяются с экспоненциальной паузой; 404 и 410 означают,
+// что токен устройства больше не действует, остальные 4xx не повторяются.
+type Gateway struct {
+	config config.PushConfig
+	client *http.Client
+	logger *zap.Logger
+}
+
+// NewGateway создает шлюз push-уведомлений
+func NewGateway(cfg config.PushConfig, logger *zap.Logger) *Gateway {
+	if cfg.MaxAttempts <= 0 {
+		cfg.MaxAttempts = 1
+	}
+	if cfg.InitialBackoff <= 0 {
+		cfg.InitialBackoff = 500 * time.Millisecond
+	}
+	if cfg.Timeout <= 0 {
+		cfg.Timeout = 10 * time.Second
+	}
+
+	return &Gateway{
+		config: cfg,
+		client: &http.Client{},
+		logger: logger,
+	}
+}
+
+// Send отправляет уведомление на устройство с повторами
+func (g *Gateway) Send(ctx context.Context, message *services.PushMessage) error {
+	endpoint, headers, body, err := g.buildRequest(message)
+	if err != nil {
+		return err
+	}
+
+	backoff := g.config.InitialBackoff
+	var lastErr error
+	for attempt := 1; attempt <= g.config.MaxAttempts; attempt++ {
+		status, err := g.send(ctx, endpoint, headers, body)
+		switch {
+		case err == nil && status >= 200 && status < 300:
+			return nil
+		case err == nil && (status == http.StatusNotFound || status == http.StatusGone):
+			return entities.ErrPushTokenInvalid
+		case err == nil && status < 500 && status != http.StatusTooManyRequests:
+			return fmt.Errorf("push gateway rejected notification: status %d", status)
+		case err == nil:
+			lastErr = fmt.Errorf("push gateway unavailable: status %d", status)
+		default:
+			lastErr = err
+		}
+
+		g.logger.Warn("Push notification attempt failed",
+			zap.String("platform", string(message.Platform)),
+			zap.Int("attempt", attempt),
+			zap.Error(lastErr),
+		)
+
+		if attempt == g.config.MaxAttempts {
+			break
+		}
+
+		select {
+		case <-time.After(backoff):
+		case <-ctx.Done():
+			return ctx.Err()
+		}
+		backoff *= 2
+	}
+
+	return fmt.Errorf("push notification failed after %d attempts: %w", g.config.MaxAttempts, lastErr)
+}
+
+// buildRequest формирует адрес, заголовки и тело запроса для платформы устройства
+func (g *Gateway) buildRequest(message *services.PushMessage) (string, map[string]string, []byte, error) {
+	var (
+		endpoint string
+		headers  map[string]string
+		payload  interface{}
+	)
+
+	switch message.Platform {
+	case entities.PushPlatformFCM:
+		endpoint = fmt.Sprintf("%s/v1/projects/%s/messages:send",
+			strings.TrimRight(g.config.FCM.BaseURL, "/"), url.PathEscape(g.config.FCM.ProjectID))
+		headers = map[string]string{
+			"Authorization": "Bearer " + g.config.FCM.AccessToken,
+		}
+		payload = map[string]interface{}{
+			"message": map[string]interface{}{
+				"token": message.Token,
+				"notification": map[string]string{
+					"title": message.Title,
+					"body":  message.Body,
+				},
+				"data": message.Data,
+			},
+		}
+
+	case entities.PushPlatformAPNS:
+		endpoint = fmt.Sprintf("%s/3/device/%s",
+			strings.TrimRight(g.config.APNS.BaseURL, "/"), url.PathEscape(message.Token))
+		headers = map[string]string{
+			"Authorization":   "bearer " + g.config.APNS.AuthToken,
+			"apns-topic":      g.config.APNS.Topic,
+			"apns-push-type":  "alert",
+			"apns-priority":   "10",
+			"apns-expiration": "0",
+		}
+
+		// Пользовательские данные APNS передаются ключами верхнего уровня рядом с aps
+		apnsPayload := map[string]interface{}{
+			"aps": map[string]interface{}{
+				"alert": map[string]string{
+					"title": message.Title,
+					"body":  message.Body,
+				},
+				"sound": "default",
+			},
+		}
+		for key, value := range message.Data {
+			apnsPayload[key] = value
+		}
+		payload = apnsPayload
+
+	default:
+		return "", nil, nil, entities.ErrPushTokenInvalid
+	}
+
+	body, err := json.Marshal(payload)
+	if err != nil {
+		return "", nil, nil, fmt.Errorf("failed to marshal push payload: %w", err)
+	}
+	return endpoint, headers, body, nil
+}
+
+// send выполняет одну попытку отправки и возвращает код ответа
+func (g *Gateway) send(ctx context.Context, endpoint string, headers map[string]string, body []byte) (int, error) {
+	ctx, cancel := context.WithTimeout(ctx, g.config.Timeout)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, endpoint, bytes.NewReader(body))
+	if err != nil {
+		return 0, fmt.Errorf("failed to create push request: %w", err)
+	}
+	req.Header.Set("Content-Type", "application/json")
+	for key, value := range headers {
+		req.Header.Set(key, value)
+	}
+
+	resp, err := g.client.Do(req)
+	if err != nil {
+		return 0, err
+	}
+	defer resp.Body.Close()
+	_, _ = io.Copy(io.Discard, resp.Body)
+
+	return resp.StatusCode, nil
+}
@@ -0,0 +1,51 @@
+package redis
+
+import (
+	"bufio"
+	"context"
+	"fmt"
+	"net"
+	"time"
+
+	"driver-service/internal/config"
+)
+
+// Ping проверяет доступность Redis командой PING в отдельном соединении (проверка готовности сервиса).
+// Ожидание ответа ограничено ctx.
+func Ping(ctx context.Context, cfg config.RedisConfig) error {
+	addr := cfg.GetRedisAddr()
+	var dialer net.Dialer
+	conn, err := dialer.DialContext(ctx, "tcp", addr)
+	if err != nil {
+		return fmt.Errorf("failed to connect to Redis %s: %w", addr, err)
+	}
+	defer conn.Close()
+
+	if deadline, ok := ctx.Deadline(); ok {
+		conn.SetDeadline(deadline)
+	}
+	stop := context.AfterFunc(ctx, func() { conn.SetDeadline(time.Now()) })
+	defer stop()
+
+	reader := bufio.NewReader(conn)
+	if cfg.Password != "" {
+		if err := writeCommand(conn, "AUTH", cfg.Password); err != nil {
+			return err
+		}
+		if _, err := readReply(reader); err != nil {
+			return fmt.Errorf("failed to authenticate to Redis: %w", err)
+		}
+	}
+
+	if err := writeCommand(conn, "PING"); err != nil {
+		return err
+	}
+	reply, err := readReply(reader)
+	if err != nil {
+		return fmt.Errorf("redis ping failed: %w", err)
+	}
+	if reply != "PONG" {
+		return fmt.Errorf("unexpected redis ping reply %q", reply)
+	}
+	return nil
+}
@@ -0,0 +1,210 @@
+package redis
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"fmt"
+	"io"
+	"net"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"driver-service/internal/config"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// reconnectDelay пауза перед повторным подключением после обрыва соединения с Redis
+const reconnectDelay = time.Second
+
+// SessionExpiryListener подписывается на уведомления Redis об истечении ключей (keyspace notifications,
+// канал __keyevent@<db>__:expired) и для ключей сессий "<prefix><driver_id>" вызывает onExpired.
+// Сервису нужна только подписка, поэтому клиент работает по протоколу RESP напрямую.
+type SessionExpiryListener struct {
+	config    config.RedisConfig
+	onExpired func(ctx context.Context, driverID uuid.UUID)
+	logger    *zap.Logger
+
+	subscribed     chan struct{}
+	subscribedOnce sync.Once
+}
+
+// NewSessionExpiryListener создает SessionExpiryListener для ключей с префиксом cfg.SessionKeyPrefix
+func NewSessionExpiryListener(
+	cfg config.RedisConfig,
+	onExpired func(ctx context.Context, driverID uuid.UUID),
+	logger *zap.Logger,
+) *SessionExpiryListener {
+	return &SessionExpiryListener{
+		config:     cfg,
+		onExpired:  onExpired,
+		logger:     logger,
+		subscribed: make(chan struct{}),
+	}
+}
+
+// Subscribed закрывается после первой успешной подписки; ключи, истекшие раньше, не обрабатываются
+func (l *SessionExpiryListener) Subscribed() <-chan struct{} {
+	return l.subscribed
+}
+
+// Run обрабатывает уведомления до отмены ctx, переподключаясь после обрыва соединения
+func (l *SessionExpiryListener) Run(ctx context.Context) {
+	for {
+		err := l.listen(ctx)
+		if ctx.Err() != nil {
+			return
+		}
+
+		l.logger.Warn("Redis session expiry subscription lost, reconnecting",
+			zap.Error(err),
+			zap.Duration("delay", reconnectDelay),
+		)
+		select {
+		case <-ctx.Done():
+			return
+		case <-time.After(reconnectDelay):
+		}
+	}
+}
+
+// listen подключается к Redis, подписывается на истечение ключей и обрабатывает уведомления
+// до ошибки соединения или отмены ctx
+func (l *SessionExpiryListener) listen(ctx context.Context) error {
+	addr := l.config.GetRedisAddr()
+	var dialer net.Dialer
+	conn, err := dialer.DialContext(ctx, "tcp", addr)
+	if err != nil {
+		return fmt.Errorf("failed to connect to Redis %s: %w", addr, err)
+	}
+	defer conn.Close()
+
+	// Отмена ctx прерывает блокирующее чтение уведомлений
+	stop := context.AfterFunc(ctx, func() { conn.Close() })
+	defer stop()
+
+	reader := bufio.NewReader(conn)
+	if l.config.Password != "" {
+		if err := writeCommand(conn, "AUTH", l.config.Password); err != nil {
+			return err
+		}
+		if _, err := readReply(reader); err != nil {
+			return fmt.Errorf("failed to authenticate to Redis: %w", err)
+		}
+	}
+
+	channel := fmt.Sprintf("__keyevent@%d__:expired", l.config.Database)
+	if err := writeCommand(conn, "SUBSCRIBE", channel); err != nil {
+		return err
+	}
+
+	for {
+		reply, err := readReply(reader)
+		if err != nil {
+			return err
+		}
+
+		message, ok := reply.([]interface{})
+		if !ok || len(message) != 3 {
+			continue
+		}
+		switch kind, _ := message[0].(string); kind {
+		case "subscribe":
+			l.logger.Info("Subscribed to Redis session expiry",
+				zap.String("channel", channel),
+				zap.String("key_prefix", l.config.SessionKeyPrefix),
+			)
+			l.subscribedOnce.Do(func() { close(l.subscribed) })
+		case "message":
+			key, _ := message[2].(string)
+			l.handle(ctx, key)
+		}
+	}
+}
+
+// handle вызывает onExpired для истекшего ключа сессии; остальные ключи пропускаются
+func (l *SessionExpiryListener) handle(ctx context.Context, key string) {
+	suffix, ok := strings.CutPrefix(key, l.config.SessionKeyPrefix)
+	if !ok {
+		return
+	}
+
+	driverID, err := uuid.Parse(suffix)
+	if err != nil {
+		l.logger.Warn("Ignoring expired session key without driver ID", zap.String("key", key))
+		return
+	}
+	l.onExpired(ctx, driverID)
+}
+
+// writeCommand отправляет команду массивом bulk-строк RESP
+func writeCommand(w io.Writer, args ...string) error {
+	var buf bytes.Buffer
+	fmt.Fprintf(&buf, "*%d\r\n", len(args))
+	for _, arg := range args {
+		fmt.Fprintf(&buf, "$%d\r\n%s\r\n", len(arg), arg)
+	}
+
+	_, err := w.Write(buf.Bytes())
+	return err
+}
+
+// readReply читает ответ RESP2: простую строку, ошибку, целое, bulk-строку или массив.
+// Ошибка Redis возвращается как error, nil bulk-строка и nil массив - как nil.
+func readReply(r *bufio.Reader) (interface{}, error) {
+	line, err := r.ReadString('\n')
+	if err != nil {
+		return nil, err
+	}
+	line = strings.TrimSuffix(line, "\r\n")
+	if line == "" {
+		return nil, fmt.Errorf("empty RESP reply")
+	}
+
+	switch line[0] {
+	case '+':
+		return line[1:], nil
+	case '-':
+		return nil, fmt.Errorf("redis: %s", line[1:])
+	case ':':
+		value, err := strconv.ParseInt(line[1:], 10, 64)
+		if err != nil {
+			return nil, fmt.Errorf("invalid RESP integer %q: %w", line, err)
+		}
+		return value, nil
+	case '$':
+		size, err := strconv.Atoi(line[1:])
+		if err != nil {
+			return nil, fmt.Errorf("invalid RESP bulk string length %q: %w", line, err)
+		}
+		if size < 0 {
+			return nil, nil
+		}
+		buf := make([]byte, size+2)
+		if _, err := io.ReadFull(r, buf); err != nil {
+			return nil, err
+		}
+		return string(buf[:size]), nil
+	case '*':
+		count, err := strconv.Atoi(line[1:])
+		if err != nil {
+			return nil, fmt.Errorf("invalid RESP array length %q: %w", line, err)
+		}
+		if count < 0 {
+			return nil, nil
+		}
+		items := make([]interface{}, count)
+		for i := range items {
+			if items[i], err = readReply(r); err != nil {
+				return nil, err
+			}
+		}
+		return items, nil
+	default:
+		return nil, fmt.Errorf("unexpected RESP reply %q", line)
+	}
+}
@@ -0,0 +1,171 @@
+package redis
+
+import (
+	"bufio"
+	"context"
+	"net"
+	"strconv"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// fakeRedis принимает одно подключение и отдает его тесту вместе с читателем команд
+func fakeRedis(t *testing.T) (config.RedisConfig, <-chan net.Conn) {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+	t.Cleanup(func() { listener.Close() })
+
+	host, port, err := net.SplitHostPort(listener.Addr().String())
+	require.NoError(t, err)
+	portNumber, err := strconv.Atoi(port)
+	require.NoError(t, err)
+
+	conns := make(chan net.Conn, 1)
+	go func() {
+		conn, err := listener.Accept()
+		if err != nil {
+			return
+		}
+		t.Cleanup(func() { conn.Close() })
+		conns <- conn
+	}()
+
+	return config.RedisConfig{Host: host, Port: portNumber, Database: 2, SessionKeyPrefix: "driver:session:"}, conns
+}
+
+// startListener запускает слушателя до конца теста и возвращает канал ID водителей истекших сессий
+func startListener(t *testing.T, cfg config.RedisConfig) (*SessionExpiryListener, <-chan uuid.UUID) {
+	expired := make(chan uuid.UUID, 10)
+	listener := NewSessionExpiryListener(cfg, func(_ context.Context, driverID uuid.UUID) {
+		expired <- driverID
+	}, zap.NewNop())
+
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan struct{})
+	go func() {
+		defer close(done)
+		listener.Run(ctx)
+	}()
+	t.Cleanup(func() {
+		cancel()
+		<-done
+	})
+
+	return listener, expired
+}
+
+// expectCommand читает команду, отправленную слушателем, и сравнивает ее с ожидаемой
+func expectCommand(t *testing.T, reader *bufio.Reader, expected ...string) {
+	reply, err := readReply(reader)
+	require.NoError(t, err)
+
+	args := make([]string, 0, len(expected))
+	for _, arg := range reply.([]interface{}) {
+		args = append(args, arg.(string))
+	}
+	assert.Equal(t, expected, args)
+}
+
+func TestSessionExpiryListener_HandlesExpiredSessionKeys(t *testing.T) {
+	// Arrange
+	cfg, conns := fakeRedis(t)
+	listener, expired := startListener(t, cfg)
+	conn := <-conns
+	reader := bufio.NewReader(conn)
+	driverID := uuid.New()
+
+	// Act
+	expectCommand(t, reader, "SUBSCRIBE", "__keyevent@2__:expired")
+	require.NoError(t, writeCommand(conn, "subscribe", "__keyevent@2__:expired", "1"))
+	<-listener.Subscribed()
+
+	for _, key := range []string{"geo:nearby:55.7558", "driver:session:not-a-uuid", "driver:session:" + driverID.String()} {
+		require.NoError(t, writeCommand(conn, "message", "__keyevent@2__:expired", key))
+	}
+
+	// Assert
+	select {
+	case id := <-expired:
+		assert.Equal(t, driverID, id)
+	case <-time.After(5 * time.Second):
+		t.Fatal("Истечение ключа сессии не обработано")
+	}
+	assert.Empty(t, expired, "Остальные ключи пропускаются")
+}
+
+func TestSessionExpiryListener_Authenticates(t *testing.T) {
+	// Arrange
+	cfg, conns := fakeRedis(t)
+	cfg.Password = "secret"
+	startListener(t, cfg)
+	conn := <-conns
+	reader := bufio.NewReader(conn)
+
+	// Act & Assert
+	expectCommand(t, reader, "AUTH", "secret")
+	_, err := conn.Write([]byte("+OK\r\n"))
+	require.NoError(t, err)
+	expectCommand(t, reader, "SUBSCRIBE", "__keyevent@2__:expired")
+}
+
+func TestPing(t *testing.T) {
+	// Arrange
+	cfg, conns := fakeRedis(t)
+	pinged := make(chan error, 1)
+	go func() { pinged <- Ping(context.Background(), cfg) }()
+	conn := <-conns
+	reader := bufio.NewReader(conn)
+
+	// Act
+	expectCommand(t, reader, "PING")
+	_, err := conn.Write([]byte("+PONG\r\n"))
+	require.NoError(t, err)
+
+	// Assert
+	assert.NoError(t, <-pinged)
+}
+
+func TestPing_Timeout(t *testing.T) {
+	// Arrange - Redis принимает соединение, но не отвечает
+	cfg, _ := fakeRedis(t)
+	ctx, cancel := context.WithTimeout(context.Background(), 100*time.Millisecond)
+	defer cancel()
+
+	// Act
+	err := Ping(ctx, cfg)
+
+	// Assert
+	assert.ErrorContains(t, err, "redis ping failed")
+}
+
+func TestReadReply(t *testing.T) {
+	reader := bufio.NewReader(strings.NewReader(
+		"+OK\r\n:42\r\n$-1\r\n*2\r\n$3\r\nfoo\r\n:1\r\n-ERR unknown command\r\n"))
+
+	reply, err := readReply(reader)
+	require.NoError(t, err)
+	assert.Equal(t, "OK", reply)
+
+	reply, err = readReply(reader)
+	require.NoError(t, err)
+	assert.Equal(t, int64(42), reply)
+
+	reply, err = readReply(reader)
+	require.NoError(t, err)
+	assert.Nil(t, reply)
+
+	reply, err = readReply(reader)
+	require.NoError(t, err)
+	assert.Equal(t, []interface{}{"foo", int64(1)}, reply)
+
+	_, err = readReply(reader)
+	assert.EqualError(t, err, "redis: ERR unknown command")
+}
@@ -0,0 +1,311 @@
+package storage
+
+import (
+	"bytes"
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+)
+
+// Параметры подписи AWS Signature Version 4
+const (
+	signingAlgorithm = "AWS4-HMAC-SHA256"
+	signingService   = "s3"
+	unsignedPayload  = "UNSIGNED-PAYLOAD"
+	amzDateFormat    = "20060102T150405Z"
+
+	// maxPresignExpiry максимальный срок действия подписанной ссылки в S3
+	maxPresignExpiry = 7 * 24 * time.Hour
+
+	// requestExpiry срок подписи запросов, которые сервис выполняет сам
+	requestExpiry = time.Minute
+)
+
+// S3Client клиент S3-совместимого хранилища (AWS S3, MinIO) для файлов документов.
+// Запросы подписываются в query-параметрах (presigned URL), поэтому одна схема подписи используется
+// и для ссылок, выдаваемых водителям, и для собственных запросов сервиса. Объекты адресуются в path-style.
+type S3Client struct {
+	endpoint  *url.URL
+	bucket    string
+	region    string
+	accessKey string
+	secretKey string
+	client    *http.Client
+	now       func() time.Time
+}
+
+// NewS3Client создает клиента по конфигурации S3
+func NewS3Client(cfg config.S3Config) (*S3Client, error) {
+	if cfg.Endpoint == "" || cfg.BucketName == "" {
+		return nil, fmt.Errorf("s3 endpoint and bucket name are required")
+	}
+
+	endpoint := cfg.Endpoint
+	if !strings.Contains(endpoint, "://") {
+		scheme := "http"
+		if cfg.UseSSL {
+			scheme = "https"
+		}
+		endpoint = scheme + "://" + endpoint
+	}
+
+	endpointURL, err := url.Parse(strings.TrimRight(endpoint, "/"))
+	if err != nil {
+		return nil, fmt.Errorf("invalid s3 endpoint: %w", err)
+	}
+
+	region := cfg.Region
+	if region == "" {
+		region = "us-east-1"
+	}
+
+	return &S3Client{
+		endpoint:  endpointURL,
+		bucket:    cfg.BucketName,
+		region:    region,
+		accessKey: cfg.AccessKeyID,
+		secretKey: cfg.SecretAccessKey,
+		client:    &http.Client{Timeout: 30 * time.Second},
+		now:       time.Now,
+	}, nil
+}
+
+// Bucket возвращает имя бакета документов
+func (c *S3Client) Bucket() string {
+	return c.bucket
+}
+
+// ObjectURL возвращает постоянный адрес объекта без подписи
+func (c *S3Client) ObjectURL(key string) string {
+	return c.objectURL(key).String()
+}
+
+// PresignPut возвращает ссылку для загрузки объекта PUT запросом без дополнительной авторизации
+func (c *S3Client) PresignPut(key string, expires time.Duration) (string, error) {
+	return c.presign(http.MethodPut, c.objectURL(key), expires)
+}
+
+// PresignGet возвращает ссылку для скачивания объекта
+func (c *S3Client) PresignGet(key string, expires time.Duration) (string, error) {
+	return c.presign(http.MethodGet, c.objectURL(key), expires)
+}
+
+// StatObject возвращает сведения о загруженном объекте
+func (c *S3Client) StatObject(ctx context.Context, key string) (*services.StoredObject, error) {
+	resp, err := c.do(ctx, http.MethodHead, c.objectURL(key), nil, nil)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	switch {
+	case resp.StatusCode == http.StatusNotFound:
+		return nil, entities.ErrObjectNotFound
+	case resp.StatusCode != http.StatusOK:
+		return nil, fmt.Errorf("failed to stat object %s: status %d", key, resp.StatusCode)
+	}
+
+	size, _ := strconv.ParseInt(resp.Header.Get("Content-Length"), 10, 64)
+	return &services.StoredObject{
+		Key:         key,
+		Size:        size,
+		ContentType: resp.Header.Get("Content-Type"),
+		ETag:        strings.Trim(resp.Header.Get("ETag"), `"`),
+	}, nil
+}
+
+// GetObject скачивает объект целиком
+func (c *S3Client) GetObject(ctx context.Context, key string) ([]byte, error) {
+	resp, err := c.do(ctx, http.MethodGet, c.objectURL(key), nil, nil)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	switch {
+	case resp.StatusCode == http.StatusNotFound:
+		return nil, entities.ErrObjectNotFound
+	case resp.StatusCode != http.StatusOK:
+		return nil, fmt.Errorf("failed to get object %s: status %d", key, resp.StatusCode)
+	}
+
+	return io.ReadAll(resp.Body)
+}
+
+// EnsureBucket создает бакет документов, если его еще нет
+func (c *S3Client) EnsureBucket(ctx context.Context) error {
+	bucketURL := *c.endpoint
+	bucketURL.Path = c.endpoint.Path + "/" + c.bucket
+
+	resp, err := c.do(ctx, http.MethodPut, &bucketURL, nil, nil)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	// 409 - бакет уже существует и принадлежит нам
+	if resp.StatusCode != http.StatusOK && resp.StatusCode != http.StatusConflict {
+		body, _ := io.ReadAll(resp.Body)
+		return fmt.Errorf("failed to create bucket %s: status %d: %s", c.bucket, resp.StatusCode, body)
+	}
+	return nil
+}
+
+// PutObject загружает объект целиком. Используется для файлов, которые сервис обрабатывает сам
+// (фотографии водителей); файлы документов водитель загружает напрямую по PresignPut.
+func (c *S3Client) PutObject(ctx context.Context, key, contentType string, content []byte) error {
+	resp, err := c.do(ctx, http.MethodPut, c.objectURL(key), content, http.Header{"Content-Type": {contentType}})
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		body, _ := io.ReadAll(resp.Body)
+		return fmt.Errorf("failed to put object %s: status %d: %s", key, resp.StatusCode, body)
+	}
+	return nil
+}
+
+// do выполняет подписанный запрос к хранилищу. Заголовки header не входят в подпись.
+func (c *S3Client) do(ctx context.Context, method string, target *url.URL, body []byte, header http.Header) (*http.Response, error) {
+	signedURL, err := c.presign(method, target, requestExpiry)
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequestWithContext(ctx, method, signedURL, bytes.NewReader(body))
+	if err != nil {
+		return nil, fmt.Errorf("failed to create storage request: %w", err)
+	}
+	for name, values := range header {
+		req.Header[name] = values
+	}
+
+	resp, err := c.client.Do(req)
+	if err != nil {
+		return nil, fmt.Errorf("storage request failed: %w", err)
+	}
+	return resp, nil
+}
+
+// objectURL возвращает path-style адрес объекта
+func (c *S3Client) objectURL(key string) *url.URL {
+	objectURL := *c.endpoint
+	objectURL.Path = c.endpoint.Path + "/" + c.bucket + "/" + strings.TrimLeft(key, "/")
+	return &objectURL
+}
+
+// presign подписывает запрос method к target по схеме AWS Signature Version 4 (query-параметры)
+func (c *S3Client) presign(method string, target *url.URL, expires time.Duration) (string, error) {
+	if expires <= 0 || expires > maxPresignExpiry {
+		return "", fmt.Errorf("presign expiry must be between 1s and %v, got %v", maxPresignExpiry, expires)
+	}
+
+	now := c.now().UTC()
+	amzDate := now.Format(amzDateFormat)
+	scope := strings.Join([]string{amzDate[:8], c.region, signingService, "aws4_request"}, "/")
+
+	query := map[string]string{
+		"X-Amz-Algorithm":     signingAlgorithm,
+		"X-Amz-Credential":    c.accessKey + "/" + scope,
+		"X-Amz-Date":          amzDate,
+		"X-Amz-Expires":       strconv.Itoa(int(expires.Seconds())),
+		"X-Amz-SignedHeaders": "host",
+	}
+	canonicalQuery := canonicalQueryString(query)
+	canonicalPath := encodePath(target.Path)
+
+	canonicalRequest := strings.Join([]string{
+		method,
+		canonicalPath,
+		canonicalQuery,
+		"host:" + target.Host + "\n",
+		"host",
+		unsignedPayload,
+	}, "\n")
+
+	stringToSign := strings.Join([]string{
+		signingAlgorithm,
+		amzDate,
+		scope,
+		hexSHA256([]byte(canonicalRequest)),
+	}, "\n")
+
+	signingKey := hmacSHA256([]byte("AWS4"+c.secretKey), amzDate[:8])
+	signingKey = hmacSHA256(signingKey, c.region)
+	signingKey = hmacSHA256(signingKey, signingService)
+	signingKey = hmacSHA256(signingKey, "aws4_request")
+	signature := hex.EncodeToString(hmacSHA256(signingKey, stringToSign))
+
+	return fmt.Sprintf("%s://%s%s?%s&X-Amz-Signature=%s",
+		target.Scheme, target.Host, canonicalPath, canonicalQuery, signature), nil
+}
+
+// canonicalQueryString сортирует параметры по имени и кодирует их по RFC 3986
+func canonicalQueryString(params map[string]string) string {
+	keys := make([]string, 0, len(params))
+	for key := range params {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	pairs := make([]string, 0, len(keys))
+	for _, key := range keys {
+		pairs = append(pairs, uriEncode(key, true)+"="+uriEncode(params[key], true))
+	}
+	return strings.Join(pairs, "&")
+}
+
+// encodePath кодирует путь объекта, сохраняя разделители сегментов
+func encodePath(path string) string {
+	if path == "" {
+		return "/"
+	}
+	return uriEncode(path, false)
+}
+
+// uriEncode кодирует строку по правилам SigV4: незарезервированные символы RFC 3986 не кодируются,
+// остальные байты - в %XX с заглавными hex-цифрами. Слэш кодируется только в query-параметрах.
+func uriEncode(value string, encodeSlash bool) string {
+	var builder strings.Builder
+	for i := 0; i < len(value); i++ {
+		b := value[i]
+		switch {
+		case (b >= 'A' && b <= 'Z') || (b >= 'a' && b <= 'z') || (b >= '0' && b <= '9'),
+			b == '-', b == '_', b == '.', b == '~':
+			builder.WriteByte(b)
+		case b == '/' && !encodeSlash:
+			builder.WriteByte(b)
+		default:
+			fmt.Fprintf(&builder, "%%%02X", b)
+		}
+	}
+	return builder.String()
+}
+
+// hmacSHA256 вычисляет HMAC-SHA256
+func hmacSHA256(key []byte, data string) []byte {
+	mac := hmac.New(sha256.New, key)
+	mac.Write([]byte(data))
+	return mac.Sum(nil)
+}
+
+// hexSHA256 вычисляет SHA-256 в hex
+func hexSHA256(data []byte) string {
+	sum := sha256.Sum256(data)
+	return hex.EncodeToString(sum[:])
+}
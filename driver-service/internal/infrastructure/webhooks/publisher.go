@@ -0,0 +1,459 @@
+package webhooks
+
+import (
+	"bytes"
+	"context"
+	"crypto/hmac"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"strconv"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/services"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// Заголовки запроса доставки события
+const (
+	SignatureHeader = "X-Webhook-Signature" // подпись тела: sha256=<hex HMAC-SHA256 секретом адресата>
+	EventHeader     = "X-Webhook-Event"
+	DeliveryHeader  = "X-Webhook-Delivery"
+	AttemptHeader   = "X-Webhook-Attempt"
+)
+
+// signaturePrefix префикс алгоритма в заголовке подписи
+const signaturePrefix = "sha256="
+
+// Значения по умолчанию для незаданных параметров пула доставки
+const (
+	defaultWorkers        = 8
+	defaultQueueSize      = 1000
+	defaultMaxDeadLetters = 1000
+)
+
+// Target адрес партнера, на который доставляются события
+type Target struct {
+	ID     uuid.UUID
+	URL    string
+	Secret string
+	Events []string // типы событий; пустой список - все события
+}
+
+// accepts проверяет, подписан ли адресат на событие
+func (t *Target) accepts(eventType string) bool {
+	if len(t.Events) == 0 {
+		return true
+	}
+	for _, event := range t.Events {
+		if event == eventType {
+			return true
+		}
+	}
+	return false
+}
+
+// Payload тело запроса доставки события
+type Payload struct {
+	DeliveryID uuid.UUID   `json:"delivery_id"`
+	EventType  string      `json:"event_type"`
+	DriverID   uuid.UUID   `json:"driver_id"`
+	Data       interface{} `json:"data"`
+	OccurredAt time.Time   `json:"occurred_at"`
+}
+
+// DeadLetter доставка, от которой отказались после исчерпания попыток или ответа 4xx
+type DeadLetter struct {
+	TargetID   uuid.UUID
+	Payload    Payload
+	Attempts   int
+	LastStatus int // 0, если ответа не было
+	LastError  string
+	FailedAt   time.Time
+}
+
+// Config параметры доставки
+type Config struct {
+	MaxAttempts    int
+	InitialBackoff time.Duration // пауза перед второй попыткой, дальше удваивается
+	MaxBackoff     time.Duration
+	Timeout        time.Duration // таймаут одной попытки
+	Workers        int           // количество параллельных доставок
+	QueueSize      int           // доставки, ожидающие свободного обработчика; при заполненной очереди - dead letter
+	MaxDeadLetters int           // сколько последних dead letter хранится; более старые вытесняются
+}
+
+// Stats счетчики доставок с момента создания Publisher
+type Stats struct {
+	Delivered    uint64 // доставлены с ответом 2xx
+	DeadLettered uint64 // попали в dead letter, в том числе вытесненные из буфера
+	Dropped      uint64 // не поставлены в очередь: очередь заполнена или Publisher закрыт (входят в DeadLettered)
+	Evicted      uint64 // вытеснены из буфера dead letter более новыми
+}
+
+// job доставка события адресату, ожидающая обработчика
+type job struct {
+	target  Target
+	payload Payload
+}
+
+// Publisher EventPublisher, который передает события следующему издателю и доставляет их партнерам.
+// Доставка асинхронная: пул из Workers обработчиков берет доставки из очереди на QueueSize доставок;
+// ошибки 5xx, 429 и сетевые ошибки повторяются с экспоненциальной паузой, после последней неудачной попытки,
+// ответа 4xx или при заполненной очереди доставка попадает в dead letter. Dead letter хранятся в кольцевом
+// буфере из MaxDeadLetters последних доставок, их количество доступно в Stats.
+type Publisher struct {
+	next   services.EventPublisher
+	config Config
+	client *http.Client
+	logger *zap.Logger
+	queue  chan job
+
+	mu          sync.RWMutex
+	targets     map[uuid.UUID]Target
+	deadLetters []DeadLetter // кольцевой буфер: при заполнении запись идет на место oldestDead
+	oldestDead  int
+
+	delivered    atomic.Uint64
+	deadLettered atomic.Uint64
+	dropped      atomic.Uint64
+	evicted      atomic.Uint64
+
+	wg        sync.WaitGroup
+	done      chan struct{}
+	closeOnce sync.Once
+}
+
+// NewPublisher создает Publisher поверх next (может быть nil)
+func NewPublisher(next services.EventPublisher, cfg Config, logger *zap.Logger) *Publisher {
+	if cfg.MaxAttempts <= 0 {
+		cfg.MaxAttempts = 1
+	}
+	if cfg.InitialBackoff <= 0 {
+		cfg.InitialBackoff = time.Second
+	}
+	if cfg.MaxBackoff < cfg.InitialBackoff {
+		cfg.MaxBackoff = cfg.InitialBackoff
+	}
+	if cfg.Timeout <= 0 {
+		cfg.Timeout = 10 * time.Second
+	}
+	if cfg.Workers <= 0 {
+		cfg.Workers = defaultWorkers
+	}
+	if cfg.QueueSize <= 0 {
+		cfg.QueueSize = defaultQueueSize
+	}
+	if cfg.MaxDeadLetters <= 0 {
+		cfg.MaxDeadLetters = defaultMaxDeadLetters
+	}
+
+	p := &Publisher{
+		next:    next,
+		config:  cfg,
+		client:  &http.Client{},
+		logger:  logger,
+		queue:   make(chan job, cfg.QueueSize),
+		targets: make(map[uuid.UUID]Target),
+		done:    make(chan struct{}),
+	}
+
+	for i := 0; i < cfg.Workers; i++ {
+		p.wg.Add(1)
+		go p.work()
+	}
+	return p
+}
+
+// NewPublisherFromConfig создает Publisher с параметрами и адресатами из конфигурации приложения
+func NewPublisherFromConfig(next services.EventPublisher, cfg config.WebhooksConfig, logger *zap.Logger) *Publisher {
+	publisher := NewPublisher(next, Config{
+		MaxAttempts:    cfg.MaxAttempts,
+		InitialBackoff: cfg.InitialBackoff,
+		MaxBackoff:     cfg.MaxBackoff,
+		Timeout:        cfg.Timeout,
+		Workers:        cfg.Workers,
+		QueueSize:      cfg.QueueSize,
+		MaxDeadLetters: cfg.MaxDeadLetters,
+	}, logger)
+
+	for _, target := range cfg.Targets {
+		publisher.Register(Target{URL: target.URL, Secret: target.Secret, Events: target.Events})
+	}
+	return publisher
+}
+
+// Register добавляет адресата и возвращает его ID
+func (p *Publisher) Register(target Target) uuid.UUID {
+	if target.ID == uuid.Nil {
+		target.ID = uuid.New()
+	}
+
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	p.targets[target.ID] = target
+
+	p.logger.Info("Webhook target registered",
+		zap.String("target_id", target.ID.String()),
+		zap.String("url", target.URL),
+		zap.Strings("events", target.Events),
+	)
+	return target.ID
+}
+
+// Unregister удаляет адресата; уже начатые доставки завершаются
+func (p *Publisher) Unregister(targetID uuid.UUID) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	delete(p.targets, targetID)
+}
+
+// DeadLetters возвращает копию последних MaxDeadLetters доставок, от которых отказались, от старых к новым
+func (p *Publisher) DeadLetters() []DeadLetter {
+	p.mu.RLock()
+	defer p.mu.RUnlock()
+
+	deadLetters := make([]DeadLetter, 0, len(p.deadLetters))
+	deadLetters = append(deadLetters, p.deadLetters[p.oldestDead:]...)
+	deadLetters = append(deadLetters, p.deadLetters[:p.oldestDead]...)
+	return deadLetters
+}
+
+// Stats возвращает счетчики доставок
+func (p *Publisher) Stats() Stats {
+	return Stats{
+		Delivered:    p.delivered.Load(),
+		DeadLettered: p.deadLettered.Load(),
+		Dropped:      p.dropped.Load(),
+		Evicted:      p.evicted.Load(),
+	}
+}
+
+// PublishDriverEvent публикует событие в следующем издателе и ставит доставки подписанным адресатам в очередь.
+// Если очередь заполнена, доставка сразу попадает в dead letter: издатель не ждет партнеров.
+func (p *Publisher) PublishDriverEvent(ctx context.Context, eventType string, driverID uuid.UUID, data interface{}) error {
+	if p.next != nil {
+		if err := p.next.PublishDriverEvent(ctx, eventType, driverID, data); err != nil {
+			return err
+		}
+	}
+
+	p.mu.RLock()
+	var targets []Target
+	for _, target := range p.targets {
+		if target.accepts(eventType) {
+			targets = append(targets, target)
+		}
+	}
+	p.mu.RUnlock()
+
+	occurredAt := time.Now().UTC()
+	for _, target := range targets {
+		payload := Payload{
+			DeliveryID: uuid.New(),
+			EventType:  eventType,
+			DriverID:   driverID,
+			Data:       data,
+			OccurredAt: occurredAt,
+		}
+
+		// Доставка не привязана к контексту запроса: она продолжается после ответа клиенту
+		p.enqueue(job{target: target, payload: payload})
+	}
+
+	return nil
+}
+
+// Close прекращает повторные попытки, ждет завершения начатых доставок и переносит
+// не начатые в dead letter
+func (p *Publisher) Close() {
+	p.closeOnce.Do(func() { close(p.done) })
+	p.wg.Wait()
+	p.drain()
+}
+
+// enqueue ставит доставку в очередь пула; если очередь заполнена или Publisher закрыт, доставка попадает в dead letter
+func (p *Publisher) enqueue(j job) {
+	select {
+	case <-p.done:
+		p.drop(j, "publisher closed")
+		return
+	default:
+	}
+
+	select {
+	case p.queue <- j:
+	default:
+		p.drop(j, "delivery queue is full")
+	}
+}
+
+// drop сохраняет в dead letter доставку, которая не была начата
+func (p *Publisher) drop(j job, reason string) {
+	p.dropped.Add(1)
+	p.addDeadLetter(DeadLetter{TargetID: j.target.ID, Payload: j.payload, LastError: reason})
+}
+
+// work обработчик пула: доставляет события из очереди до закрытия Publisher
+func (p *Publisher) work() {
+	defer p.wg.Done()
+	for {
+		select {
+		case <-p.done:
+			return
+		case j := <-p.queue:
+			p.deliver(j.target, j.payload)
+		}
+	}
+}
+
+// drain переносит в dead letter доставки, оставшиеся в очереди после остановки пула
+func (p *Publisher) drain() {
+	for {
+		select {
+		case j := <-p.queue:
+			p.drop(j, "delivery stopped on shutdown")
+		default:
+			return
+		}
+	}
+}
+
+// deliver отправляет событие адресату с повторами и при неудаче сохраняет его в dead letter
+func (p *Publisher) deliver(target Target, payload Payload) {
+	deadLetter := DeadLetter{TargetID: target.ID, Payload: payload}
+
+	body, err := json.Marshal(payload)
+	if err != nil {
+		deadLetter.LastError = fmt.Sprintf("failed to marshal payload: %v", err)
+		p.addDeadLetter(deadLetter)
+		return
+	}
+
+	backoff := p.config.InitialBackoff
+	for attempt := 1; attempt <= p.config.MaxAttempts; attempt++ {
+		deadLetter.Attempts = attempt
+
+		status, err := p.send(target, payload, body, attempt)
+		if err == nil && status >= 200 && status < 300 {
+			p.delivered.Add(1)
+			p.logger.Debug("Webhook delivered",
+				zap.String("target_id", target.ID.String()),
+				zap.String("delivery_id", payload.DeliveryID.String()),
+				zap.Int("attempt", attempt),
+			)
+			return
+		}
+
+		deadLetter.LastStatus = status
+		deadLetter.LastError = ""
+		if err != nil {
+			deadLetter.LastError = err.Error()
+		}
+
+		p.logger.Warn("Webhook delivery attempt failed",
+			zap.String("target_id", target.ID.String()),
+			zap.String("delivery_id", payload.DeliveryID.String()),
+			zap.Int("attempt", attempt),
+			zap.Int("status_code", status),
+			zap.Error(err),
+		)
+
+		if !retryable(status, err) || attempt == p.config.MaxAttempts {
+			break
+		}
+
+		select {
+		case <-time.After(backoff):
+		case <-p.done:
+			deadLetter.LastError = "delivery stopped on shutdown"
+			p.addDeadLetter(deadLetter)
+			return
+		}
+
+		backoff *= 2
+		if backoff > p.config.MaxBackoff {
+			backoff = p.config.MaxBackoff
+		}
+	}
+
+	p.addDeadLetter(deadLetter)
+}
+
+// send выполняет одну попытку доставки и возвращает код ответа
+func (p *Publisher) send(target Target, payload Payload, body []byte, attempt int) (int, error) {
+	ctx, cancel := context.WithTimeout(context.Background(), p.config.Timeout)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, target.URL, bytes.NewReader(body))
+	if err != nil {
+		return 0, fmt.Errorf("failed to create webhook request: %w", err)
+	}
+	req.Header.Set("Content-Type", "application/json")
+	req.Header.Set(SignatureHeader, Sign(target.Secret, body))
+	req.Header.Set(EventHeader, payload.EventType)
+	req.Header.Set(DeliveryHeader, payload.DeliveryID.String())
+	req.Header.Set(AttemptHeader, strconv.Itoa(attempt))
+
+	resp, err := p.client.Do(req)
+	if err != nil {
+		return 0, err
+	}
+	defer resp.Body.Close()
+	_, _ = io.Copy(io.Discard, resp.Body)
+
+	return resp.StatusCode, nil
+}
+
+// addDeadLetter сохраняет доставку, от которой отказались, вытесняя самую старую при заполненном буфере
+func (p *Publisher) addDeadLetter(deadLetter DeadLetter) {
+	deadLetter.FailedAt = time.Now()
+	p.deadLettered.Add(1)
+
+	p.logger.Error("Webhook delivery dead-lettered",
+		zap.String("target_id", deadLetter.TargetID.String()),
+		zap.String("delivery_id", deadLetter.Payload.DeliveryID.String()),
+		zap.String("event_type", deadLetter.Payload.EventType),
+		zap.Int("attempts", deadLetter.Attempts),
+		zap.Int("last_status", deadLetter.LastStatus),
+		zap.String("last_error", deadLetter.LastError),
+	)
+
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if len(p.deadLetters) < p.config.MaxDeadLetters {
+		p.deadLetters = append(p.deadLetters, deadLetter)
+		return
+	}
+	p.deadLetters[p.oldestDead] = deadLetter
+	p.oldestDead = (p.oldestDead + 1) % len(p.deadLetters)
+	p.evicted.Add(1)
+}
+
+// retryable проверяет, имеет ли смысл повторять доставку
+func retryable(status int, err error) bool {
+	if err != nil {
+		return true
+	}
+	return status >= 500 || status == http.StatusTooManyRequests
+}
+
+// Sign возвращает подпись тела для заголовка SignatureHeader
+func Sign(secret string, body []byte) string {
+	mac := hmac.New(sha256.New, []byte(secret))
+	mac.Write(body)
+	return signaturePrefix + hex.EncodeToString(mac.Sum(nil))
+}
+
+// VerifySignature проверяет подпись тела, полученную в заголовке SignatureHeader
+func VerifySignature(secret string, body []byte, signature string) bool {
+	return hmac.Equal([]byte(Sign(secret, body)), []byte(signature))
+}
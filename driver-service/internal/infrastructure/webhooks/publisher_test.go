@@ -0,0 +1,74 @@
+package webhooks
+
+import (
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+func TestPublisher_DeadLettersRingBuffer(t *testing.T) {
+	// Arrange
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusBadRequest)
+	}))
+	defer server.Close()
+
+	publisher := NewPublisher(nil, Config{MaxAttempts: 1, MaxDeadLetters: 2}, zap.NewNop())
+	defer publisher.Close()
+	publisher.Register(Target{URL: server.URL, Secret: "secret"})
+
+	// Act
+	for _, eventType := range []string{"event.1", "event.2", "event.3"} {
+		expected := publisher.Stats().DeadLettered + 1
+		require.NoError(t, publisher.PublishDriverEvent(context.Background(), eventType, uuid.New(), nil))
+		require.Eventually(t, func() bool { return publisher.Stats().DeadLettered == expected }, time.Second, 5*time.Millisecond)
+	}
+
+	// Assert
+	deadLetters := publisher.DeadLetters()
+	require.Len(t, deadLetters, 2, "Буфер хранит только последние MaxDeadLetters доставок")
+	assert.Equal(t, "event.2", deadLetters[0].Payload.EventType)
+	assert.Equal(t, "event.3", deadLetters[1].Payload.EventType)
+	assert.Equal(t, Stats{DeadLettered: 3, Evicted: 1}, publisher.Stats())
+}
+
+func TestPublisher_QueueFullDeadLetters(t *testing.T) {
+	// Arrange
+	received := make(chan struct{}, 3)
+	release := make(chan struct{})
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		received <- struct{}{}
+		<-release
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer server.Close()
+
+	publisher := NewPublisher(nil, Config{MaxAttempts: 1, Workers: 1, QueueSize: 1}, zap.NewNop())
+	targetID := publisher.Register(Target{URL: server.URL, Secret: "secret"})
+	ctx := context.Background()
+
+	// Act
+	require.NoError(t, publisher.PublishDriverEvent(ctx, "event.1", uuid.New(), nil))
+	<-received // единственный обработчик занят первой доставкой
+	require.NoError(t, publisher.PublishDriverEvent(ctx, "event.2", uuid.New(), nil))
+	require.NoError(t, publisher.PublishDriverEvent(ctx, "event.3", uuid.New(), nil))
+	close(release)
+	require.Eventually(t, func() bool { return publisher.Stats().Delivered == 2 }, time.Second, 5*time.Millisecond)
+	publisher.Close()
+
+	// Assert
+	assert.Equal(t, Stats{Delivered: 2, DeadLettered: 1, Dropped: 1}, publisher.Stats())
+	deadLetters := publisher.DeadLetters()
+	require.Len(t, deadLetters, 1)
+	assert.Equal(t, targetID, deadLetters[0].TargetID)
+	assert.Equal(t, "event.3", deadLetters[0].Payload.EventType)
+	assert.Equal(t, "delivery queue is full", deadLetters[0].LastError)
+	assert.Zero(t, deadLetters[0].Attempts)
+}
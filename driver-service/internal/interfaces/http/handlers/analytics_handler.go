@@ -0,0 +1,53 @@
+package handlers
+
+import (
+	"fmt"
+	"net/http"
+	"strconv"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/gin-gonic/gin"
+	"go.uber.org/zap"
+)
+
+// AnalyticsHandler обработчик HTTP запросов аналитики по парку водителей
+type AnalyticsHandler struct {
+	analyticsService services.AnalyticsService
+	logger           *zap.Logger
+}
+
+// NewAnalyticsHandler создает новый AnalyticsHandler
+func NewAnalyticsHandler(analyticsService services.AnalyticsService, logger *zap.Logger) *AnalyticsHandler {
+	return &AnalyticsHandler{
+		analyticsService: analyticsService,
+		logger:           logger,
+	}
+}
+
+// GetDriverHeatmap возвращает плотность водителей на линии по ячейкам geohash точности precision
+func (h *AnalyticsHandler) GetDriverHeatmap(c *gin.Context) {
+	precision, err := strconv.Atoi(c.Query("precision"))
+	if err != nil || precision < entities.MinGeohashPrecision || precision > entities.MaxGeohashPrecision {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid precision",
+			Code:  "INVALID_REQUEST",
+			Details: fmt.Sprintf("precision must be an integer from %d to %d",
+				entities.MinGeohashPrecision, entities.MaxGeohashPrecision),
+		})
+		return
+	}
+
+	heatmap, err := h.analyticsService.GetDriverHeatmap(c.Request.Context(), precision)
+	if err != nil {
+		h.logger.Error("Failed to get driver heatmap", zap.Error(err))
+		c.JSON(http.StatusInternalServerError, ErrorResponse{
+			Error: "Internal server error",
+			Code:  "INTERNAL_ERROR",
+		})
+		return
+	}
+
+	c.JSON(http.StatusOK, heatmap)
+}
@@ -0,0 +1,117 @@
+package handlers
+
+import (
+	"errors"
+	"io"
+	"net/http"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/gin-gonic/gin"
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// AvatarFormField поле multipart формы с файлом фотографии
+const AvatarFormField = "avatar"
+
+// avatarFormOverhead запас на заголовки и границы multipart формы сверх размера файла
+const avatarFormOverhead = 64 << 10
+
+// AvatarHandler обработчик загрузки фотографий водителей
+type AvatarHandler struct {
+	avatarService services.AvatarService
+	logger        *zap.Logger
+}
+
+// NewAvatarHandler создает новый AvatarHandler
+func NewAvatarHandler(avatarService services.AvatarService, logger *zap.Logger) *AvatarHandler {
+	return &AvatarHandler{
+		avatarService: avatarService,
+		logger:        logger,
+	}
+}
+
+// UploadAvatar загружает фотографию водителя (multipart/form-data, поле avatar, JPEG или PNG)
+func (h *AvatarHandler) UploadAvatar(c *gin.Context) {
+	driverID, err := uuid.Parse(c.Param("id"))
+	if err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid driver ID format",
+		})
+		return
+	}
+
+	// Слишком большое тело отклоняется, не дочитываясь до конца
+	c.Request.Body = http.MaxBytesReader(c.Writer, c.Request.Body, services.MaxAvatarSize+avatarFormOverhead)
+
+	fileHeader, err := c.FormFile(AvatarFormField)
+	if err != nil {
+		var maxBytesErr *http.MaxBytesError
+		if errors.As(err, &maxBytesErr) {
+			h.handleAvatarError(c, entities.ErrAvatarTooLarge)
+			return
+		}
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error:   "Avatar file is required",
+			Code:    "INVALID_REQUEST",
+			Details: err.Error(),
+		})
+		return
+	}
+
+	file, err := fileHeader.Open()
+	if err != nil {
+		h.handleAvatarError(c, err)
+		return
+	}
+	defer file.Close()
+
+	// Лишний байт сверх лимита отличает слишком большой файл от файла ровно MaxAvatarSize
+	content, err := io.ReadAll(io.LimitReader(file, services.MaxAvatarSize+1))
+	if err != nil {
+		h.handleAvatarError(c, err)
+		return
+	}
+
+	avatar, err := h.avatarService.UploadAvatar(c.Request.Context(), driverID, content)
+	if err != nil {
+		h.handleAvatarError(c, err)
+		return
+	}
+
+	c.JSON(http.StatusOK, avatar)
+}
+
+// handleAvatarError преобразует ошибки сервиса в HTTP ответы
+func (h *AvatarHandler) handleAvatarError(c *gin.Context, err error) {
+	switch err {
+	case entities.ErrAvatarTooLarge:
+		c.JSON(http.StatusRequestEntityTooLarge, ErrorResponse{
+			Error: "Avatar file too large",
+			Code:  "AVATAR_TOO_LARGE",
+		})
+	case entities.ErrUnsupportedAvatarType:
+		c.JSON(http.StatusUnsupportedMediaType, ErrorResponse{
+			Error: "Avatar must be a JPEG or PNG image",
+			Code:  "UNSUPPORTED_MEDIA_TYPE",
+		})
+	case entities.ErrInvalidAvatar:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid avatar image",
+			Code:  "INVALID_IMAGE",
+		})
+	case entities.ErrDriverNotFound:
+		c.JSON(http.StatusNotFound, ErrorResponse{
+			Error: "Driver not found",
+			Code:  "DRIVER_NOT_FOUND",
+		})
+	default:
+		h.logger.Error("Failed to upload avatar", zap.Error(err))
+		c.JSON(http.StatusInternalServerError, ErrorResponse{
+			Error: "Internal server error",
+			Code:  "INTERNAL_ERROR",
+		})
+	}
+}
@@ -0,0 +1,59 @@
+package handlers
+
+import (
+	"errors"
+	"net/http"
+	"strings"
+
+	"driver-service/internal/consistency"
+	"driver-service/internal/domain/entities"
+
+	"github.com/gin-gonic/gin"
+	"go.uber.org/zap"
+)
+
+// ConsistencyHandler обработчик HTTP запросов проверки согласованности данных (только для тестовых стендов)
+type ConsistencyHandler struct {
+	checker *consistency.Checker
+	logger  *zap.Logger
+}
+
+// NewConsistencyHandler создает новый ConsistencyHandler
+func NewConsistencyHandler(checker *consistency.Checker, logger *zap.Logger) *ConsistencyHandler {
+	return &ConsistencyHandler{
+		checker: checker,
+		logger:  logger,
+	}
+}
+
+// CheckConsistency выполняет правила проверки и возвращает отчет с предложенными исправлениями.
+// Параметр rules ограничивает проверку перечисленными через запятую правилами.
+func (h *ConsistencyHandler) CheckConsistency(c *gin.Context) {
+	var names []string
+	for _, name := range strings.Split(c.Query("rules"), ",") {
+		if name = strings.TrimSpace(name); name != "" {
+			names = append(names, name)
+		}
+	}
+
+	report, err := h.checker.Run(c.Request.Context(), names...)
+	if err != nil {
+		if errors.Is(err, entities.ErrConsistencyRuleNotFound) {
+			c.JSON(http.StatusNotFound, ErrorResponse{
+				Error:   "Consistency rule not found",
+				Code:    "RULE_NOT_FOUND",
+				Details: err.Error(),
+			})
+			return
+		}
+
+		h.logger.Error("Failed to check consistency", zap.Error(err))
+		c.JSON(http.StatusInternalServerError, ErrorResponse{
+			Error: "Internal server error",
+			Code:  "INTERNAL_ERROR",
+		})
+		return
+	}
+
+	c.JSON(http.StatusOK, report)
+}
@@ -0,0 +1,84 @@
+package handlers
+
+import (
+	"net/http"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/gin-gonic/gin"
+	"go.uber.org/zap"
+)
+
+// Имена событий потока позиций (text/event-stream)
+const (
+	FeedEventSubscribed = "subscribed"
+	FeedEventLocation   = "location"
+)
+
+// DispatchFeedHandler обработчик потока позиций водителей для диспетчеров
+type DispatchFeedHandler struct {
+	feed   *services.DispatchFeed
+	logger *zap.Logger
+}
+
+// NewDispatchFeedHandler создает новый DispatchFeedHandler
+func NewDispatchFeedHandler(feed *services.DispatchFeed, logger *zap.Logger) *DispatchFeedHandler {
+	return &DispatchFeedHandler{
+		feed:   feed,
+		logger: logger,
+	}
+}
+
+// WatchAreaRequest границы области, за которой следит диспетчер
+type WatchAreaRequest struct {
+	SouthWestLat *float64 `form:"sw_lat" binding:"required"`
+	SouthWestLon *float64 `form:"sw_lon" binding:"required"`
+	NorthEastLat *float64 `form:"ne_lat" binding:"required"`
+	NorthEastLon *float64 `form:"ne_lon" binding:"required"`
+}
+
+// WatchArea передает позиции водителей внутри области в формате Server-Sent Events:
+// сначала событие subscribed, затем location на каждое сохраненное местоположение.
+// Поток завершается, когда клиент закрывает соединение.
+func (h *DispatchFeedHandler) WatchArea(c *gin.Context) {
+	var req WatchAreaRequest
+	if err := c.ShouldBindQuery(&req); err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error:   "Invalid request data",
+			Details: err.Error(),
+		})
+		return
+	}
+
+	bounds := entities.GeoBounds{
+		SouthWest: entities.Location{Latitude: *req.SouthWestLat, Longitude: *req.SouthWestLon},
+		NorthEast: entities.Location{Latitude: *req.NorthEastLat, Longitude: *req.NorthEastLon},
+	}
+
+	ctx := c.Request.Context()
+	updates, err := h.feed.SubscribeArea(ctx, bounds)
+	if err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid location coordinates",
+			Code:  "INVALID_LOCATION",
+		})
+		return
+	}
+
+	// Поток живет дольше WriteTimeout сервера
+	if err := http.NewResponseController(c.Writer).SetWriteDeadline(time.Time{}); err != nil {
+		h.logger.Debug("Failed to reset write deadline for dispatch feed", zap.Error(err))
+	}
+
+	c.Header("Cache-Control", "no-cache")
+	c.Header("X-Accel-Buffering", "no")
+	c.SSEvent(FeedEventSubscribed, bounds)
+	c.Writer.Flush()
+
+	for location := range updates {
+		c.SSEvent(FeedEventLocation, location.ToResponse())
+		c.Writer.Flush()
+	}
+}
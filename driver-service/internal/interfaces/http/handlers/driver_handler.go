@@ -1,8 +1,10 @@
 package handlers
 
 import (
+	"fmt"
 	"net/http"
 	"strconv"
+	"strings"
 	"time"
 
 	"driver-service/internal/domain/entities"
@@ -39,6 +41,8 @@ type CreateDriverRequest struct {
 	PassportNumber string    `json:"passport_number" binding:"required"`
 	LicenseNumber  string    `json:"license_number" binding:"required"`
 	LicenseExpiry  time.Time `json:"license_expiry" binding:"required"`
+	Country        string    `json:"country,omitempty"` // страна выдачи паспорта и ВУ (ISO 3166-1 alpha-2), по умолчанию RU
+	City           string    `json:"city,omitempty"`    // город работы водителя
 }
 
 // UpdateDriverRequest запрос на обновление водителя
@@ -51,6 +55,8 @@ type UpdateDriverRequest struct {
 	PassportSeries *string    `json:"passport_series,omitempty"`
 	PassportNumber *string    `json:"passport_number,omitempty"`
 	LicenseExpiry  *time.Time `json:"license_expiry,omitempty"`
+	Country        *string    `json:"country,omitempty"`
+	City           *string    `json:"city,omitempty"` // пустая строка удаляет город
 }
 
 // ChangeStatusRequest запрос на изменение статуса
@@ -58,6 +64,17 @@ type ChangeStatusRequest struct {
 	Status string `json:"status" binding:"required"`
 }
 
+// DriverStatusesRequest пакетный запрос статусов водителей (не больше services.MaxStatusBatchSize ID)
+type DriverStatusesRequest struct {
+	DriverIDs []uuid.UUID `json:"driver_ids" binding:"required"`
+}
+
+// DriverStatusesResponse статусы водителей в порядке driver_ids запроса и ID неизвестных водителей
+type DriverStatusesResponse struct {
+	Statuses []*entities.DriverStatusInfo `json:"statuses"`
+	NotFound []uuid.UUID                  `json:"not_found"`
+}
+
 // DriverResponse ответ с информацией о водителе
 type DriverResponse struct {
 	ID              uuid.UUID         `json:"id"`
@@ -77,6 +94,7 @@ type DriverResponse struct {
 	Metadata        entities.Metadata `json:"metadata,omitempty"`
 	CreatedAt       time.Time         `json:"created_at"`
 	UpdatedAt       time.Time         `json:"updated_at"`
+	DeletedAt       *time.Time        `json:"deleted_at,omitempty"`
 }
 
 // ListDriversResponse ответ со списком водителей
@@ -86,6 +104,12 @@ type ListDriversResponse struct {
 	Limit      int               `json:"limit"`
 	Offset     int               `json:"offset"`
 	HasMore    bool              `json:"has_more"`
+	NextCursor string            `json:"next_cursor,omitempty"` // курсор следующей страницы, если она есть
+}
+
+// LookupDriversResponse водители, найденные по телефону или email: активный и, с include_deleted, удаленные
+type LookupDriversResponse struct {
+	Drivers []*DriverResponse `json:"drivers"`
 }
 
 // ErrorResponse стандартный ответ с ошибкой
@@ -122,6 +146,15 @@ func (h *DriverHandler) CreateDriver(c *gin.Context) {
 		LicenseNumber:  req.LicenseNumber,
 		LicenseExpiry:  req.LicenseExpiry,
 	}
+	if req.Country != "" {
+		if err := h.setDocumentCountry(driver, req.Country); err != nil {
+			h.handleServiceError(c, err, "Invalid document country")
+			return
+		}
+	}
+	if city := strings.TrimSpace(req.City); city != "" {
+		driver.SetCity(city)
+	}
 
 	// Создаем водителя через сервис
 	createdDriver, err := h.driverService.CreateDriver(c.Request.Context(), driver)
@@ -151,6 +184,9 @@ func (h *DriverHandler) GetDriver(c *gin.Context) {
 		return
 	}
 
+	// Время изменения для условных запросов с If-Modified-Since
+	c.Header("Last-Modified", driver.UpdatedAt.UTC().Format(http.TimeFormat))
+
 	response := h.toDriverResponse(driver)
 	c.JSON(http.StatusOK, response)
 }
@@ -210,6 +246,15 @@ func (h *DriverHandler) UpdateDriver(c *gin.Context) {
 	if req.LicenseExpiry != nil {
 		driver.LicenseExpiry = *req.LicenseExpiry
 	}
+	if req.Country != nil {
+		if err := h.setDocumentCountry(driver, *req.Country); err != nil {
+			h.handleServiceError(c, err, "Invalid document country")
+			return
+		}
+	}
+	if req.City != nil {
+		driver.SetCity(strings.TrimSpace(*req.City))
+	}
 
 	// Обновляем водителя через сервис
 	updatedDriver, err := h.driverService.UpdateDriver(c.Request.Context(), driver)
@@ -242,6 +287,59 @@ func (h *DriverHandler) DeleteDriver(c *gin.Context) {
 	c.JSON(http.StatusNoContent, nil)
 }
 
+// RestoreDriver восстанавливает удаленного водителя
+func (h *DriverHandler) RestoreDriver(c *gin.Context) {
+	driverIDStr := c.Param("id")
+	driverID, err := uuid.Parse(driverIDStr)
+	if err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid driver ID format",
+		})
+		return
+	}
+
+	driver, err := h.driverService.RestoreDriver(c.Request.Context(), driverID)
+	if err != nil {
+		h.handleServiceError(c, err, "Failed to restore driver")
+		return
+	}
+
+	response := h.toDriverResponse(driver)
+	c.JSON(http.StatusOK, response)
+}
+
+// LookupDrivers ищет водителей по телефону или email для службы поддержки
+func (h *DriverHandler) LookupDrivers(c *gin.Context) {
+	lookup := &entities.DriverLookup{
+		Phone: c.Query("phone"),
+		Email: c.Query("email"),
+	}
+
+	if includeDeleted := c.Query("include_deleted"); includeDeleted != "" {
+		value, err := strconv.ParseBool(includeDeleted)
+		if err != nil {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error: "Invalid include_deleted, expected true or false",
+				Code:  "INVALID_REQUEST",
+			})
+			return
+		}
+		lookup.IncludeDeleted = value
+	}
+
+	drivers, err := h.driverService.LookupDrivers(c.Request.Context(), lookup)
+	if err != nil {
+		h.handleServiceError(c, err, "Failed to lookup drivers")
+		return
+	}
+
+	response := LookupDriversResponse{Drivers: make([]*DriverResponse, len(drivers))}
+	for i, driver := range drivers {
+		response.Drivers[i] = h.toDriverResponse(driver)
+	}
+	c.JSON(http.StatusOK, response)
+}
+
 // ListDrivers получает список водителей с фильтрами
 func (h *DriverHandler) ListDrivers(c *gin.Context) {
 	filters := &entities.DriverFilters{}
@@ -263,6 +361,40 @@ func (h *DriverHandler) ListDrivers(c *gin.Context) {
 		}
 	}
 
+	if city := strings.TrimSpace(c.Query("city")); city != "" {
+		filters.City = &city
+	}
+
+	if createdAfterStr := c.Query("created_after"); createdAfterStr != "" {
+		createdAfter, err := time.Parse(time.RFC3339, createdAfterStr)
+		if err != nil {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error:   "Invalid created_after format, expected RFC3339",
+				Code:    "INVALID_REQUEST",
+				Details: err.Error(),
+			})
+			return
+		}
+		filters.CreatedAfter = &createdAfter
+	}
+
+	if expiringStr := c.Query("license_expiring_before"); expiringStr != "" {
+		expiring, err := time.Parse(time.DateOnly, expiringStr)
+		if err != nil {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error:   "Invalid license_expiring_before format, expected YYYY-MM-DD",
+				Code:    "INVALID_REQUEST",
+				Details: err.Error(),
+			})
+			return
+		}
+		filters.LicenseExpiringBefore = &expiring
+	}
+
+	if search := strings.TrimSpace(c.Query("search")); search != "" {
+		filters.Search = &search
+	}
+
 	if limitStr := c.Query("limit"); limitStr != "" {
 		if limit, err := strconv.Atoi(limitStr); err == nil && limit > 0 {
 			filters.Limit = limit
@@ -287,8 +419,31 @@ func (h *DriverHandler) ListDrivers(c *gin.Context) {
 		filters.SortDirection = sortDirection
 	}
 
+	if cursorStr := c.Query("cursor"); cursorStr != "" {
+		if filters.Offset > 0 {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error: "cursor cannot be combined with offset",
+				Code:  "INVALID_REQUEST",
+			})
+			return
+		}
+		cursor, err := entities.ParseDriverCursor(cursorStr)
+		if err != nil {
+			h.handleServiceError(c, err, "Invalid cursor")
+			return
+		}
+		filters.After = cursor
+	}
+
+	// С курсором запрашиваем на одного водителя больше: так видно, есть ли следующая страница
+	limit := filters.Limit
+	if filters.After != nil {
+		filters.Limit++
+	}
+
 	// Получаем список водителей
 	drivers, err := h.driverService.ListDrivers(c.Request.Context(), filters)
+	filters.Limit = limit
 	if err != nil {
 		h.handleServiceError(c, err, "Failed to list drivers")
 		return
@@ -304,6 +459,12 @@ func (h *DriverHandler) ListDrivers(c *gin.Context) {
 		total = len(drivers)
 	}
 
+	hasMore := filters.Offset+len(drivers) < total
+	if filters.After != nil {
+		hasMore = len(drivers) > limit
+		drivers = drivers[:min(len(drivers), limit)]
+	}
+
 	// Преобразуем в ответ
 	driverResponses := make([]*DriverResponse, len(drivers))
 	for i, driver := range drivers {
@@ -315,7 +476,10 @@ func (h *DriverHandler) ListDrivers(c *gin.Context) {
 		Total:   total,
 		Limit:   filters.Limit,
 		Offset:  filters.Offset,
-		HasMore: filters.Offset+len(drivers) < total,
+		HasMore: hasMore,
+	}
+	if hasMore && len(drivers) > 0 {
+		response.NextCursor = filters.CursorAfter(drivers[len(drivers)-1]).Encode()
 	}
 
 	c.JSON(http.StatusOK, response)
@@ -379,6 +543,29 @@ func (h *DriverHandler) GetActiveDrivers(c *gin.Context) {
 	})
 }
 
+// GetDriverStatuses получает статусы нескольких водителей одним запросом (для диспетчерской)
+func (h *DriverHandler) GetDriverStatuses(c *gin.Context) {
+	var req DriverStatusesRequest
+	if err := c.ShouldBindJSON(&req); err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error:   "Invalid request data",
+			Details: err.Error(),
+		})
+		return
+	}
+
+	statuses, notFound, err := h.driverService.GetDriverStatuses(c.Request.Context(), req.DriverIDs)
+	if err != nil {
+		h.handleServiceError(c, err, "Failed to get driver statuses")
+		return
+	}
+
+	c.JSON(http.StatusOK, DriverStatusesResponse{
+		Statuses: statuses,
+		NotFound: notFound,
+	})
+}
+
 // toDriverResponse преобразует Driver entity в DriverResponse
 func (h *DriverHandler) toDriverResponse(driver *entities.Driver) *DriverResponse {
 	return &DriverResponse{
@@ -399,9 +586,19 @@ func (h *DriverHandler) toDriverResponse(driver *entities.Driver) *DriverRespons
 		Metadata:        driver.Metadata,
 		CreatedAt:       driver.CreatedAt,
 		UpdatedAt:       driver.UpdatedAt,
+		DeletedAt:       driver.DeletedAt,
 	}
 }
 
+// setDocumentCountry сохраняет страну выдачи документов водителя из кода в запросе
+func (h *DriverHandler) setDocumentCountry(driver *entities.Driver, code string) error {
+	country, err := entities.ParseCountry(code)
+	if err != nil {
+		return err
+	}
+	return driver.SetDocumentCountry(country)
+}
+
 // handleServiceError обрабатывает ошибки из сервисного слоя
 func (h *DriverHandler) handleServiceError(c *gin.Context, err error, message string) {
 	h.logger.Error(message, zap.Error(err))
@@ -418,17 +615,53 @@ func (h *DriverHandler) handleServiceError(c *gin.Context, err error, message st
 			Code:  "DRIVER_EXISTS",
 		})
 	case entities.ErrInvalidPhone, entities.ErrInvalidEmail, entities.ErrInvalidName,
-		 entities.ErrInvalidLicense, entities.ErrInvalidPassport:
+		 entities.ErrInvalidLicense, entities.ErrInvalidPassport, entities.ErrLicenseExpired,
+		 entities.ErrUnsupportedCountry, entities.ErrDriverUnderage:
 		c.JSON(http.StatusBadRequest, ErrorResponse{
 			Error: "Invalid driver data",
 			Code:  "INVALID_DATA",
 			Details: err.Error(),
 		})
+	case entities.ErrInvalidSortField:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid sort_by, expected one of: " + strings.Join(entities.DriverSortFields, ", "),
+			Code:  "INVALID_REQUEST",
+		})
+	case entities.ErrInvalidSortDirection:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid sort_direction, expected asc or desc",
+			Code:  "INVALID_REQUEST",
+		})
+	case entities.ErrInvalidCursor:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid cursor, expected next_cursor of the same list and sort order",
+			Code:  "INVALID_REQUEST",
+		})
+	case entities.ErrInvalidLookup:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Exactly one of phone or email is required",
+			Code:  "INVALID_REQUEST",
+		})
+	case entities.ErrEmptyStatusBatch:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "driver_ids must not be empty",
+			Code:  "INVALID_REQUEST",
+		})
+	case entities.ErrStatusBatchTooLarge:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: fmt.Sprintf("driver_ids must not contain more than %d IDs", services.MaxStatusBatchSize),
+			Code:  "BATCH_TOO_LARGE",
+		})
 	case entities.ErrDriverNotAvailable:
 		c.JSON(http.StatusConflict, ErrorResponse{
 			Error: "Driver is not available",
 			Code:  "DRIVER_NOT_AVAILABLE",
 		})
+	case entities.ErrConcurrentModification:
+		c.JSON(http.StatusConflict, ErrorResponse{
+			Error: "Driver was modified concurrently",
+			Code:  "CONCURRENT_MODIFICATION",
+		})
 	case entities.ErrDriverBlocked, entities.ErrDriverSuspended:
 		c.JSON(http.StatusForbidden, ErrorResponse{
 			Error: "Driver is blocked or suspended",
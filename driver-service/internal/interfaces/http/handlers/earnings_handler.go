@@ -0,0 +1,113 @@
+package handlers
+
+import (
+	"net/http"
+	"strconv"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/gin-gonic/gin"
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// EarningsHandler обработчик HTTP запросов заработка водителей
+type EarningsHandler struct {
+	earningsService services.EarningsService
+	logger          *zap.Logger
+}
+
+// NewEarningsHandler создает новый EarningsHandler
+func NewEarningsHandler(earningsService services.EarningsService, logger *zap.Logger) *EarningsHandler {
+	return &EarningsHandler{
+		earningsService: earningsService,
+		logger:          logger,
+	}
+}
+
+// GetEarnings возвращает заработок водителя за период from-to (Unix или RFC3339, по умолчанию последние 24 часа)
+// по оплатам поездок
+func (h *EarningsHandler) GetEarnings(c *gin.Context) {
+	driverID, err := uuid.Parse(c.Param("id"))
+	if err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid driver ID format",
+		})
+		return
+	}
+
+	from, to, ok := parsePeriodParams(c)
+	if !ok {
+		return
+	}
+
+	summary, err := h.earningsService.GetEarnings(c.Request.Context(), driverID, from, to)
+	if err != nil {
+		switch err {
+		case entities.ErrDriverNotFound:
+			c.JSON(http.StatusNotFound, ErrorResponse{
+				Error: "Driver not found",
+				Code:  "DRIVER_NOT_FOUND",
+			})
+		default:
+			h.logger.Error("Failed to get driver earnings", zap.Error(err))
+			c.JSON(http.StatusInternalServerError, ErrorResponse{
+				Error: "Internal server error",
+				Code:  "INTERNAL_ERROR",
+			})
+		}
+		return
+	}
+
+	c.JSON(http.StatusOK, summary)
+}
+
+// parsePeriodParams разбирает период from-to запроса (Unix или RFC3339, по умолчанию последние 24 часа);
+// при ошибке отвечает 400
+func parsePeriodParams(c *gin.Context) (time.Time, time.Time, bool) {
+	var err error
+
+	to := time.Now()
+	if toStr := c.Query("to"); toStr != "" {
+		if to, err = parseTimeParam(toStr); err != nil {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error:   "Invalid 'to' time format",
+				Code:    "INVALID_REQUEST",
+				Details: "Use Unix timestamp or RFC3339 format",
+			})
+			return time.Time{}, time.Time{}, false
+		}
+	}
+
+	from := to.Add(-24 * time.Hour)
+	if fromStr := c.Query("from"); fromStr != "" {
+		if from, err = parseTimeParam(fromStr); err != nil {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error:   "Invalid 'from' time format",
+				Code:    "INVALID_REQUEST",
+				Details: "Use Unix timestamp or RFC3339 format",
+			})
+			return time.Time{}, time.Time{}, false
+		}
+	}
+
+	if to.Before(from) {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "'to' must not be before 'from'",
+			Code:  "INVALID_REQUEST",
+		})
+		return time.Time{}, time.Time{}, false
+	}
+
+	return from, to, true
+}
+
+// parseTimeParam разбирает время запроса: Unix-время или RFC3339
+func parseTimeParam(value string) (time.Time, error) {
+	if unix, err := strconv.ParseInt(value, 10, 64); err == nil {
+		return time.Unix(unix, 0), nil
+	}
+	return time.Parse(time.RFC3339, value)
+}
@@ -0,0 +1,75 @@
+package handlers
+
+import (
+	"errors"
+	"net/http"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+
+	"github.com/gin-gonic/gin"
+	"go.uber.org/zap"
+)
+
+// FeaturesHandler обработчик HTTP запросов переключения флагов функциональности (только для тестовых стендов)
+type FeaturesHandler struct {
+	flags  *features.Flags
+	logger *zap.Logger
+}
+
+// NewFeaturesHandler создает новый FeaturesHandler
+func NewFeaturesHandler(flags *features.Flags, logger *zap.Logger) *FeaturesHandler {
+	return &FeaturesHandler{
+		flags:  flags,
+		logger: logger,
+	}
+}
+
+// FeaturesResponse ответ с текущими значениями флагов
+type FeaturesResponse struct {
+	Features map[features.Flag]bool `json:"features"`
+}
+
+// SetFeatureRequest запрос на переключение флага
+type SetFeatureRequest struct {
+	Enabled *bool `json:"enabled" binding:"required"`
+}
+
+// ListFeatures возвращает текущие значения флагов
+func (h *FeaturesHandler) ListFeatures(c *gin.Context) {
+	c.JSON(http.StatusOK, FeaturesResponse{Features: h.flags.Snapshot()})
+}
+
+// SetFeature включает или выключает флаг
+func (h *FeaturesHandler) SetFeature(c *gin.Context) {
+	name := features.Flag(c.Param("name"))
+
+	var req SetFeatureRequest
+	if err := c.ShouldBindJSON(&req); err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error:   "Invalid request data",
+			Details: err.Error(),
+		})
+		return
+	}
+
+	if err := h.flags.Set(name, *req.Enabled); err != nil {
+		if errors.Is(err, entities.ErrFeatureNotFound) {
+			c.JSON(http.StatusNotFound, ErrorResponse{
+				Error: "Feature flag not found",
+				Code:  "FEATURE_NOT_FOUND",
+			})
+			return
+		}
+
+		h.logger.Error("Failed to set feature flag", zap.Error(err), zap.String("feature", string(name)))
+		c.JSON(http.StatusInternalServerError, ErrorResponse{
+			Error: "Internal server error",
+			Code:  "INTERNAL_ERROR",
+		})
+		return
+	}
+
+	h.logger.Info("Feature flag changed", zap.String("feature", string(name)), zap.Bool("enabled", *req.Enabled))
+	c.JSON(http.StatusOK, FeaturesResponse{Features: h.flags.Snapshot()})
+}
@@ -0,0 +1,135 @@
+package handlers
+
+import (
+	"context"
+	"net/http"
+	"sync"
+	"time"
+
+	"github.com/gin-gonic/gin"
+	"go.uber.org/zap"
+)
+
+// Состояния в ответах /health/live и /health/ready
+const (
+	HealthStatusAlive    = "alive"
+	HealthStatusReady    = "ready"
+	HealthStatusNotReady = "not_ready"
+
+	DependencyStatusUp   = "up"
+	DependencyStatusDown = "down"
+)
+
+// DefaultReadinessTimeout сколько ждать ответа всех зависимостей, если ReadinessTimeout не задан
+const DefaultReadinessTimeout = 2 * time.Second
+
+// DependencyCheck проверка доступности зависимости сервиса (БД, Redis, NATS) для /health/ready
+type DependencyCheck struct {
+	Name  string
+	Check func(ctx context.Context) error
+}
+
+// HealthHandler обработчик проверок живости и готовности сервиса.
+// Живость (/health/live) не зависит от внешних систем: перезапуск не поможет при отказе БД.
+// Готовность (/health/ready) проверяет все зависимости параллельно и возвращает 503, если хотя бы одна
+// недоступна, чтобы балансировщик перестал направлять запросы в экземпляр.
+type HealthHandler struct {
+	checks  []DependencyCheck
+	timeout time.Duration
+	logger  *zap.Logger
+}
+
+// NewHealthHandler создает новый HealthHandler; timeout ограничивает ожидание всех проверок
+func NewHealthHandler(checks []DependencyCheck, timeout time.Duration, logger *zap.Logger) *HealthHandler {
+	if timeout <= 0 {
+		timeout = DefaultReadinessTimeout
+	}
+	return &HealthHandler{
+		checks:  checks,
+		timeout: timeout,
+		logger:  logger,
+	}
+}
+
+// LivenessResponse ответ проверки живости
+type LivenessResponse struct {
+	Status    string    `json:"status"`
+	Service   string    `json:"service"`
+	Timestamp time.Time `json:"timestamp"`
+}
+
+// DependencyHealth состояние зависимости
+type DependencyHealth struct {
+	Status    string  `json:"status"`
+	LatencyMs float64 `json:"latency_ms"`
+	Error     string  `json:"error,omitempty"`
+}
+
+// ReadinessResponse ответ проверки готовности с состоянием каждой зависимости
+type ReadinessResponse struct {
+	Status       string                      `json:"status"`
+	Service      string                      `json:"service"`
+	Timestamp    time.Time                   `json:"timestamp"`
+	Dependencies map[string]DependencyHealth `json:"dependencies"`
+}
+
+// Live отвечает 200, пока процесс обслуживает запросы
+func (h *HealthHandler) Live(c *gin.Context) {
+	c.JSON(http.StatusOK, LivenessResponse{
+		Status:    HealthStatusAlive,
+		Service:   "driver-service",
+		Timestamp: time.Now().UTC(),
+	})
+}
+
+// Ready проверяет зависимости и отвечает 200, если все доступны, иначе 503
+func (h *HealthHandler) Ready(c *gin.Context) {
+	ctx, cancel := context.WithTimeout(c.Request.Context(), h.timeout)
+	defer cancel()
+
+	response := ReadinessResponse{
+		Status:       HealthStatusReady,
+		Service:      "driver-service",
+		Timestamp:    time.Now().UTC(),
+		Dependencies: make(map[string]DependencyHealth, len(h.checks)),
+	}
+
+	var mu sync.Mutex
+	var wg sync.WaitGroup
+	for _, check := range h.checks {
+		wg.Add(1)
+		go func(check DependencyCheck) {
+			defer wg.Done()
+
+			started := time.Now()
+			err := check.Check(ctx)
+			health := DependencyHealth{
+				Status:    DependencyStatusUp,
+				LatencyMs: float64(time.Since(started).Microseconds()) / 1000,
+			}
+			if err != nil {
+				health.Status = DependencyStatusDown
+				health.Error = err.Error()
+			}
+
+			mu.Lock()
+			defer mu.Unlock()
+			response.Dependencies[check.Name] = health
+		}(check)
+	}
+	wg.Wait()
+
+	status := http.StatusOK
+	for name, dependency := range response.Dependencies {
+		if dependency.Status == DependencyStatusDown {
+			response.Status = HealthStatusNotReady
+			status = http.StatusServiceUnavailable
+			h.logger.Warn("Dependency is not ready",
+				zap.String("dependency", name),
+				zap.String("error", dependency.Error),
+			)
+		}
+	}
+
+	c.JSON(status, response)
+}
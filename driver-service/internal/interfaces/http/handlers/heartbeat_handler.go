@@ -0,0 +1,122 @@
+package handlers
+
+import (
+	"errors"
+	"io"
+	"math"
+	"net/http"
+	"strconv"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/gin-gonic/gin"
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// HeartbeatHandler обработчик heartbeat приложения водителя
+type HeartbeatHandler struct {
+	heartbeatService services.HeartbeatService
+	minInterval      time.Duration
+	logger           *zap.Logger
+}
+
+// NewHeartbeatHandler создает новый HeartbeatHandler. minInterval возвращается клиенту в Retry-After.
+func NewHeartbeatHandler(heartbeatService services.HeartbeatService, minInterval time.Duration, logger *zap.Logger) *HeartbeatHandler {
+	return &HeartbeatHandler{
+		heartbeatService: heartbeatService,
+		minInterval:      minInterval,
+		logger:           logger,
+	}
+}
+
+// HeartbeatRequest запрос heartbeat, тело необязательно
+type HeartbeatRequest struct {
+	Location *UpdateLocationRequest `json:"location,omitempty"`
+}
+
+// HeartbeatResponse ответ на heartbeat
+type HeartbeatResponse struct {
+	DriverID   uuid.UUID `json:"driver_id"`
+	LastSeenAt time.Time `json:"last_seen_at"`
+}
+
+// Heartbeat отмечает, что приложение водителя на связи, и при наличии сохраняет местоположение
+func (h *HeartbeatHandler) Heartbeat(c *gin.Context) {
+	driverID, err := uuid.Parse(c.Param("id"))
+	if err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid driver ID format",
+		})
+		return
+	}
+
+	var req HeartbeatRequest
+	if err := c.ShouldBindJSON(&req); err != nil && !errors.Is(err, io.EOF) {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error:   "Invalid request data",
+			Details: err.Error(),
+		})
+		return
+	}
+
+	var location *entities.DriverLocation
+	if req.Location != nil {
+		recordedAt := time.Now()
+		if req.Location.Timestamp != nil {
+			recordedAt = time.Unix(*req.Location.Timestamp, 0)
+		}
+
+		location = entities.NewDriverLocation(driverID, req.Location.Latitude, req.Location.Longitude, recordedAt)
+		location.Altitude = req.Location.Altitude
+		location.Accuracy = req.Location.Accuracy
+		location.Speed = req.Location.Speed
+		location.Bearing = req.Location.Bearing
+	}
+
+	heartbeat, err := h.heartbeatService.Heartbeat(c.Request.Context(), driverID, location)
+	if err != nil {
+		h.handleHeartbeatError(c, err)
+		return
+	}
+
+	c.JSON(http.StatusOK, HeartbeatResponse{
+		DriverID:   heartbeat.DriverID,
+		LastSeenAt: heartbeat.LastSeenAt,
+	})
+}
+
+// handleHeartbeatError преобразует ошибки сервиса в HTTP ответы
+func (h *HeartbeatHandler) handleHeartbeatError(c *gin.Context, err error) {
+	switch err {
+	case entities.ErrHeartbeatRateLimited:
+		c.Header("Retry-After", strconv.Itoa(int(math.Ceil(h.minInterval.Seconds()))))
+		c.JSON(http.StatusTooManyRequests, ErrorResponse{
+			Error: "Heartbeat rate limit exceeded",
+			Code:  "RATE_LIMITED",
+		})
+	case entities.ErrDriverNotFound:
+		c.JSON(http.StatusNotFound, ErrorResponse{
+			Error: "Driver not found",
+			Code:  "DRIVER_NOT_FOUND",
+		})
+	case entities.ErrInvalidLocation:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid location coordinates",
+			Code:  "INVALID_LOCATION",
+		})
+	case entities.ErrInvalidTimestamp:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid timestamp",
+			Code:  "INVALID_TIMESTAMP",
+		})
+	default:
+		h.logger.Error("Failed to record heartbeat", zap.Error(err))
+		c.JSON(http.StatusInternalServerError, ErrorResponse{
+			Error: "Internal server error",
+			Code:  "INTERNAL_ERROR",
+		})
+	}
+}
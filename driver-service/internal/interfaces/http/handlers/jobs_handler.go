@@ -0,0 +1,63 @@
+package handlers
+
+import (
+	"errors"
+	"net/http"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/gin-gonic/gin"
+	"go.uber.org/zap"
+)
+
+// JobsHandler обработчик HTTP запросов ручного запуска фоновых задач (только для тестовых стендов)
+type JobsHandler struct {
+	jobService services.JobService
+	logger     *zap.Logger
+}
+
+// NewJobsHandler создает новый JobsHandler
+func NewJobsHandler(jobService services.JobService, logger *zap.Logger) *JobsHandler {
+	return &JobsHandler{
+		jobService: jobService,
+		logger:     logger,
+	}
+}
+
+// ListJobsResponse ответ со списком фоновых задач
+type ListJobsResponse struct {
+	Jobs []string `json:"jobs"`
+}
+
+// ListJobs возвращает имена доступных задач
+func (h *JobsHandler) ListJobs(c *gin.Context) {
+	c.JSON(http.StatusOK, ListJobsResponse{Jobs: h.jobService.Jobs()})
+}
+
+// RunJob запускает задачу и возвращает результат после ее завершения
+func (h *JobsHandler) RunJob(c *gin.Context) {
+	name := c.Param("name")
+
+	result, err := h.jobService.RunJob(c.Request.Context(), name)
+	if err != nil {
+		h.logger.Error("Failed to run job", zap.Error(err), zap.String("job", name))
+
+		if errors.Is(err, entities.ErrJobNotFound) {
+			c.JSON(http.StatusNotFound, ErrorResponse{
+				Error: "Job not found",
+				Code:  "JOB_NOT_FOUND",
+			})
+			return
+		}
+
+		c.JSON(http.StatusInternalServerError, ErrorResponse{
+			Error:   "Job failed",
+			Code:    "JOB_FAILED",
+			Details: err.Error(),
+		})
+		return
+	}
+
+	c.JSON(http.StatusOK, result)
+}
@@ -0,0 +1,70 @@
+package handlers
+
+import (
+	"net/http"
+
+	"driver-service/internal/config"
+	"driver-service/internal/limits"
+
+	"github.com/gin-gonic/gin"
+	"go.uber.org/zap"
+)
+
+// LimitsHandler обработчик HTTP запросов просмотра и перечитывания ограничений API (только для тестовых стендов)
+type LimitsHandler struct {
+	limits *limits.Limits
+	load   func() (config.LimitsConfig, error)
+	logger *zap.Logger
+}
+
+// NewLimitsHandler создает новый LimitsHandler; load читает ограничения из конфигурации (config.ReloadLimits)
+func NewLimitsHandler(l *limits.Limits, load func() (config.LimitsConfig, error), logger *zap.Logger) *LimitsHandler {
+	return &LimitsHandler{
+		limits: l,
+		load:   load,
+		logger: logger,
+	}
+}
+
+// LimitsResponse ответ с текущими ограничениями API
+type LimitsResponse struct {
+	RequestsPerSecond float64 `json:"requests_per_second"`
+	Burst             int     `json:"burst"`
+	NearbyRadiusKm    float64 `json:"nearby_radius_km"`
+}
+
+// GetLimits возвращает текущие ограничения
+func (h *LimitsHandler) GetLimits(c *gin.Context) {
+	c.JSON(http.StatusOK, newLimitsResponse(h.limits.Config()))
+}
+
+// Reload перечитывает ограничения из конфигурации, как по SIGHUP. Ошибочная конфигурация
+// отклоняется с 422, а действующие ограничения остаются прежними.
+func (h *LimitsHandler) Reload(c *gin.Context) {
+	cfg, err := h.limits.Reload(h.load)
+	if err != nil {
+		h.logger.Warn("Failed to reload limits", zap.Error(err))
+		c.JSON(http.StatusUnprocessableEntity, ErrorResponse{
+			Error:   "Invalid limits configuration",
+			Code:    "INVALID_CONFIG",
+			Details: err.Error(),
+		})
+		return
+	}
+
+	h.logger.Info("Limits reloaded",
+		zap.Float64("requests_per_second", cfg.RequestsPerSecond),
+		zap.Int("burst", cfg.Burst),
+		zap.Float64("nearby_radius_km", cfg.NearbyRadiusKm),
+	)
+	c.JSON(http.StatusOK, newLimitsResponse(cfg))
+}
+
+// newLimitsResponse преобразует ограничения в ответ
+func newLimitsResponse(cfg config.LimitsConfig) LimitsResponse {
+	return LimitsResponse{
+		RequestsPerSecond: cfg.RequestsPerSecond,
+		Burst:             cfg.Burst,
+		NearbyRadiusKm:    cfg.NearbyRadiusKm,
+	}
+}
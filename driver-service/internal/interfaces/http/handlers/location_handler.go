@@ -7,16 +7,33 @@ import (
 
 	"driver-service/internal/domain/entities"
 	"driver-service/internal/domain/services"
+	"driver-service/internal/limits"
 
 	"github.com/gin-gonic/gin"
+	"github.com/gin-gonic/gin/binding"
 	"github.com/google/uuid"
 	"go.uber.org/zap"
 )
 
+// Размер страницы истории местоположений
+const (
+	defaultHistoryPageSize = 100  // с cursor без limit
+	maxHistoryPageSize     = 1000 // больший limit отклоняется
+)
+
+// Размер выдачи поиска поблизости
+const (
+	defaultNearbyLimit = 20
+	maxNearbyLimit     = 100 // больший limit отклоняется
+)
+
 // LocationHandler обработчик HTTP запросов для местоположений
 type LocationHandler struct {
 	locationService services.LocationService
 	logger          *zap.Logger
+
+	// limits радиус поиска поблизости по умолчанию (см. SetLimits); nil - limits.DefaultNearbyRadiusKm
+	limits *limits.Limits
 }
 
 // NewLocationHandler создает новый LocationHandler
@@ -27,6 +44,11 @@ func NewLocationHandler(locationService services.LocationService, logger *zap.Lo
 	}
 }
 
+// SetLimits задает радиус поиска водителей поблизости по умолчанию из перечитываемых ограничений API
+func (h *LocationHandler) SetLimits(l *limits.Limits) {
+	h.limits = l
+}
+
 // UpdateLocationRequest запрос на обновление местоположения
 type UpdateLocationRequest struct {
 	Latitude  float64  `json:"latitude" binding:"required"`
@@ -60,9 +82,11 @@ type LocationResponse struct {
 
 // LocationHistoryResponse ответ с историей местоположений
 type LocationHistoryResponse struct {
-	Locations []*LocationResponse    `json:"locations"`
-	Stats     *entities.LocationStats `json:"stats"`
-	Count     int                    `json:"count"`
+	Locations  []*LocationResponse     `json:"locations"`
+	Stats      *entities.LocationStats `json:"stats"`
+	Count      int                     `json:"count"`
+	Interval   string                  `json:"interval,omitempty"`    // интервал прореживания из запроса
+	NextCursor string                  `json:"next_cursor,omitempty"` // курсор следующей страницы, если она есть
 }
 
 // NearbyDriversResponse ответ с водителями поблизости
@@ -138,7 +162,7 @@ func (h *LocationHandler) BatchUpdateLocations(c *gin.Context) {
 	}
 
 	var req BatchLocationRequest
-	if err := c.ShouldBindJSON(&req); err != nil {
+	if err := c.ShouldBindWith(&req, batchRequestBinding(c)); err != nil {
 		h.logger.Error("Invalid batch update request",
 			zap.Error(err),
 		)
@@ -200,6 +224,40 @@ func (h *LocationHandler) GetCurrentLocation(c *gin.Context) {
 	c.JSON(http.StatusOK, response)
 }
 
+// GetLocationAt получает местоположение водителя на момент timestamp (Unix или RFC3339)
+func (h *LocationHandler) GetLocationAt(c *gin.Context) {
+	driverID, err := uuid.Parse(c.Param("id"))
+	if err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid driver ID format",
+		})
+		return
+	}
+
+	timestampStr := c.Query("timestamp")
+	var at time.Time
+	if timestampUnix, err := strconv.ParseInt(timestampStr, 10, 64); err == nil {
+		at = time.Unix(timestampUnix, 0)
+	} else if parsedTime, err := time.Parse(time.RFC3339, timestampStr); err == nil {
+		at = parsedTime
+	} else {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error:   "Invalid 'timestamp'",
+			Code:    "INVALID_TIMESTAMP",
+			Details: "Use Unix timestamp or RFC3339 format",
+		})
+		return
+	}
+
+	location, err := h.locationService.GetLocationAt(c.Request.Context(), driverID, at)
+	if err != nil {
+		h.handleLocationServiceError(c, err, "Failed to get location at time")
+		return
+	}
+
+	c.JSON(http.StatusOK, h.toLocationResponse(location))
+}
+
 // GetLocationHistory получает историю местоположений водителя
 func (h *LocationHandler) GetLocationHistory(c *gin.Context) {
 	driverIDStr := c.Param("id")
@@ -246,13 +304,66 @@ func (h *LocationHandler) GetLocationHistory(c *gin.Context) {
 		to = time.Now()
 	}
 
+	query := &entities.LocationHistoryQuery{DriverID: driverID, From: from, To: to}
+
+	// interval оставляет по одной точке на интервал
+	if intervalStr := c.Query("interval"); intervalStr != "" {
+		interval, err := time.ParseDuration(intervalStr)
+		if err != nil || interval <= 0 {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error:   "Invalid 'interval'",
+				Code:    "INVALID_REQUEST",
+				Details: "Use a positive duration, e.g. 30s or 5m",
+			})
+			return
+		}
+		query.Interval = interval
+	}
+
+	// limit или cursor включают постраничную выдачу; без них возвращается весь период
+	pageSize := 0
+	if limitStr := c.Query("limit"); limitStr != "" {
+		limit, err := strconv.Atoi(limitStr)
+		if err != nil || limit <= 0 || limit > maxHistoryPageSize {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error:   "Invalid 'limit'",
+				Code:    "INVALID_REQUEST",
+				Details: "Use an integer from 1 to " + strconv.Itoa(maxHistoryPageSize),
+			})
+			return
+		}
+		pageSize = limit
+	}
+	if cursorStr := c.Query("cursor"); cursorStr != "" {
+		cursor, err := entities.ParseLocationCursor(cursorStr)
+		if err != nil {
+			h.handleLocationServiceError(c, err, "Invalid cursor")
+			return
+		}
+		query.After = cursor
+		if pageSize == 0 {
+			pageSize = defaultHistoryPageSize
+		}
+	}
+
+	// Запрашиваем на одну точку больше страницы: так видно, есть ли следующая страница
+	if pageSize > 0 {
+		query.Limit = pageSize + 1
+	}
+
 	// Получаем историю местоположений
-	locations, err := h.locationService.GetLocationHistory(c.Request.Context(), driverID, from, to)
+	locations, err := h.locationService.GetLocationHistoryPage(c.Request.Context(), query)
 	if err != nil {
 		h.handleLocationServiceError(c, err, "Failed to get location history")
 		return
 	}
 
+	var nextCursor string
+	if pageSize > 0 && len(locations) > pageSize {
+		locations = locations[:pageSize]
+		nextCursor = entities.LocationCursorAfter(locations[pageSize-1]).Encode()
+	}
+
 	// Получаем статистику
 	stats, err := h.locationService.GetLocationStats(c.Request.Context(), driverID, from, to)
 	if err != nil {
@@ -270,9 +381,11 @@ func (h *LocationHandler) GetLocationHistory(c *gin.Context) {
 	}
 
 	response := &LocationHistoryResponse{
-		Locations: locationResponses,
-		Stats:     stats,
-		Count:     len(locationResponses),
+		Locations:  locationResponses,
+		Stats:      stats,
+		Count:      len(locationResponses),
+		Interval:   c.Query("interval"),
+		NextCursor: nextCursor,
 	}
 
 	c.JSON(http.StatusOK, response)
@@ -306,20 +419,31 @@ func (h *LocationHandler) GetNearbyDrivers(c *gin.Context) {
 		return
 	}
 
-	// Парсим радиус
-	radiusKm := 5.0 // По умолчанию 5 км
+	// Парсим радиус; нулевой и отрицательный радиус отклоняет сервис
+	radiusKm := h.limits.NearbyRadiusKm() // По умолчанию nearby_radius_km
 	if radiusStr := c.Query("radius_km"); radiusStr != "" {
-		if parsed, err := strconv.ParseFloat(radiusStr, 64); err == nil && parsed > 0 {
-			radiusKm = parsed
+		if radiusKm, err = strconv.ParseFloat(radiusStr, 64); err != nil {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error: "Invalid radius_km format",
+				Code:  "INVALID_RADIUS",
+			})
+			return
 		}
 	}
 
 	// Парсим лимит
-	limit := 20 // По умолчанию 20
+	limit := defaultNearbyLimit
 	if limitStr := c.Query("limit"); limitStr != "" {
-		if parsed, err := strconv.Atoi(limitStr); err == nil && parsed > 0 {
-			limit = parsed
+		parsed, err := strconv.Atoi(limitStr)
+		if err != nil || parsed <= 0 || parsed > maxNearbyLimit {
+			c.JSON(http.StatusBadRequest, ErrorResponse{
+				Error:   "Invalid 'limit'",
+				Code:    "INVALID_REQUEST",
+				Details: "Use an integer from 1 to " + strconv.Itoa(maxNearbyLimit),
+			})
+			return
 		}
+		limit = parsed
 	}
 
 	// Получаем водителей поблизости
@@ -372,6 +496,17 @@ func (h *LocationHandler) toLocationResponse(location *entities.DriverLocation)
 	}
 }
 
+// batchRequestBinding выбирает формат тела пакетного обновления по Content-Type.
+// Мобильное приложение отправляет пакеты в MessagePack, остальные клиенты - в JSON.
+func batchRequestBinding(c *gin.Context) binding.Binding {
+	switch c.ContentType() {
+	case binding.MIMEMSGPACK, binding.MIMEMSGPACK2:
+		return binding.MsgPack
+	default:
+		return binding.JSON
+	}
+}
+
 // handleLocationServiceError обрабатывает ошибки из LocationService
 func (h *LocationHandler) handleLocationServiceError(c *gin.Context, err error, message string) {
 	h.logger.Error(message, zap.Error(err))
@@ -392,6 +527,11 @@ func (h *LocationHandler) handleLocationServiceError(c *gin.Context, err error,
 			Error: "Invalid timestamp",
 			Code:  "INVALID_TIMESTAMP",
 		})
+	case entities.ErrInvalidRadius:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "radius_km must be a positive number",
+			Code:  "INVALID_RADIUS",
+		})
 	case entities.ErrLocationTooOld:
 		c.JSON(http.StatusNotFound, ErrorResponse{
 			Error: "Location data is too old",
@@ -402,6 +542,11 @@ func (h *LocationHandler) handleLocationServiceError(c *gin.Context, err error,
 			Error: "Driver not found",
 			Code:  "DRIVER_NOT_FOUND",
 		})
+	case entities.ErrInvalidCursor:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid cursor, expected next_cursor of the same history",
+			Code:  "INVALID_REQUEST",
+		})
 	default:
 		c.JSON(http.StatusInternalServerError, ErrorResponse{
 			Error: "Internal server error",
@@ -0,0 +1,190 @@
+package handlers
+
+import (
+	"errors"
+	"io"
+	"net/http"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/gin-gonic/gin"
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// ShiftHandler обработчик HTTP запросов смен водителей
+type ShiftHandler struct {
+	shiftService services.ShiftService
+	logger       *zap.Logger
+}
+
+// NewShiftHandler создает новый ShiftHandler
+func NewShiftHandler(shiftService services.ShiftService, logger *zap.Logger) *ShiftHandler {
+	return &ShiftHandler{
+		shiftService: shiftService,
+		logger:       logger,
+	}
+}
+
+// StartShift начинает смену водителя (тело необязательно: транспорт и точка начала смены)
+func (h *ShiftHandler) StartShift(c *gin.Context) {
+	driverID, ok := h.parseDriverID(c)
+	if !ok {
+		return
+	}
+
+	var req entities.ShiftStartRequest
+	if !h.bindOptionalJSON(c, &req) {
+		return
+	}
+
+	shift, err := h.shiftService.StartShift(c.Request.Context(), driverID, &req)
+	if err != nil {
+		h.handleShiftError(c, err, "Failed to start shift")
+		return
+	}
+
+	c.JSON(http.StatusCreated, shift.ToResponse())
+}
+
+// PauseShift приостанавливает смену водителя (перерыв)
+func (h *ShiftHandler) PauseShift(c *gin.Context) {
+	driverID, ok := h.parseDriverID(c)
+	if !ok {
+		return
+	}
+
+	shift, err := h.shiftService.PauseShift(c.Request.Context(), driverID)
+	if err != nil {
+		h.handleShiftError(c, err, "Failed to pause shift")
+		return
+	}
+
+	c.JSON(http.StatusOK, shift.ToResponse())
+}
+
+// ResumeShift возобновляет смену водителя после перерыва
+func (h *ShiftHandler) ResumeShift(c *gin.Context) {
+	driverID, ok := h.parseDriverID(c)
+	if !ok {
+		return
+	}
+
+	shift, err := h.shiftService.ResumeShift(c.Request.Context(), driverID)
+	if err != nil {
+		h.handleShiftError(c, err, "Failed to resume shift")
+		return
+	}
+
+	c.JSON(http.StatusOK, shift.ToResponse())
+}
+
+// EndShift завершает смену водителя (тело необязательно: точка окончания смены)
+func (h *ShiftHandler) EndShift(c *gin.Context) {
+	driverID, ok := h.parseDriverID(c)
+	if !ok {
+		return
+	}
+
+	var req entities.ShiftEndRequest
+	if !h.bindOptionalJSON(c, &req) {
+		return
+	}
+
+	shift, err := h.shiftService.EndShift(c.Request.Context(), driverID, &req)
+	if err != nil {
+		h.handleShiftError(c, err, "Failed to end shift")
+		return
+	}
+
+	c.JSON(http.StatusOK, shift.ToResponse())
+}
+
+// GetCurrentShift возвращает незавершенную смену водителя
+func (h *ShiftHandler) GetCurrentShift(c *gin.Context) {
+	driverID, ok := h.parseDriverID(c)
+	if !ok {
+		return
+	}
+
+	shift, err := h.shiftService.GetCurrentShift(c.Request.Context(), driverID)
+	if err != nil {
+		h.handleShiftError(c, err, "Failed to get current shift")
+		return
+	}
+
+	c.JSON(http.StatusOK, shift.ToResponse())
+}
+
+// parseDriverID разбирает ID водителя из пути; при ошибке отвечает 400
+func (h *ShiftHandler) parseDriverID(c *gin.Context) (uuid.UUID, bool) {
+	driverID, err := uuid.Parse(c.Param("id"))
+	if err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid driver ID format",
+		})
+		return uuid.Nil, false
+	}
+	return driverID, true
+}
+
+// bindOptionalJSON разбирает необязательное тело запроса; при ошибке отвечает 400
+func (h *ShiftHandler) bindOptionalJSON(c *gin.Context, req interface{}) bool {
+	if err := c.ShouldBindJSON(req); err != nil && !errors.Is(err, io.EOF) {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error:   "Invalid request data",
+			Details: err.Error(),
+		})
+		return false
+	}
+	return true
+}
+
+// handleShiftError преобразует ошибки сервиса смен в HTTP ответы
+func (h *ShiftHandler) handleShiftError(c *gin.Context, err error, message string) {
+	switch err {
+	case entities.ErrDriverNotFound:
+		c.JSON(http.StatusNotFound, ErrorResponse{
+			Error: "Driver not found",
+			Code:  "DRIVER_NOT_FOUND",
+		})
+	case entities.ErrShiftNotFound:
+		c.JSON(http.StatusNotFound, ErrorResponse{
+			Error: "Driver has no current shift",
+			Code:  "SHIFT_NOT_FOUND",
+		})
+	case entities.ErrShiftExists:
+		c.JSON(http.StatusConflict, ErrorResponse{
+			Error: "Driver already has a current shift",
+			Code:  "SHIFT_EXISTS",
+		})
+	case entities.ErrShiftNotActive, entities.ErrShiftNotPaused, entities.ErrShiftAlreadyEnded:
+		c.JSON(http.StatusConflict, ErrorResponse{
+			Error:   "Invalid shift state",
+			Code:    "INVALID_SHIFT_STATE",
+			Details: err.Error(),
+		})
+	case entities.ErrDriverNotAvailable:
+		c.JSON(http.StatusConflict, ErrorResponse{
+			Error: "Driver is not available",
+			Code:  "DRIVER_NOT_AVAILABLE",
+		})
+	case entities.ErrConcurrentModification:
+		c.JSON(http.StatusConflict, ErrorResponse{
+			Error: "Driver was modified concurrently",
+			Code:  "CONCURRENT_MODIFICATION",
+		})
+	case entities.ErrInvalidLocation:
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid location coordinates",
+			Code:  "INVALID_LOCATION",
+		})
+	default:
+		h.logger.Error(message, zap.Error(err))
+		c.JSON(http.StatusInternalServerError, ErrorResponse{
+			Error: "Internal server error",
+			Code:  "INTERNAL_ERROR",
+		})
+	}
+}
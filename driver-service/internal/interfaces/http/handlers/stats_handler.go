@@ -0,0 +1,63 @@
+package handlers
+
+import (
+	"net/http"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+
+	"github.com/gin-gonic/gin"
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// StatsHandler обработчик HTTP запросов статистики водителей
+type StatsHandler struct {
+	statsService services.StatsService
+	logger       *zap.Logger
+}
+
+// NewStatsHandler создает новый StatsHandler
+func NewStatsHandler(statsService services.StatsService, logger *zap.Logger) *StatsHandler {
+	return &StatsHandler{
+		statsService: statsService,
+		logger:       logger,
+	}
+}
+
+// GetDriverStats возвращает поездки и рейтинг водителя и расстояние, пройденное за период from-to
+// (Unix или RFC3339, по умолчанию последние 24 часа)
+func (h *StatsHandler) GetDriverStats(c *gin.Context) {
+	driverID, err := uuid.Parse(c.Param("id"))
+	if err != nil {
+		c.JSON(http.StatusBadRequest, ErrorResponse{
+			Error: "Invalid driver ID format",
+		})
+		return
+	}
+
+	from, to, ok := parsePeriodParams(c)
+	if !ok {
+		return
+	}
+
+	stats, err := h.statsService.GetDriverStats(c.Request.Context(), driverID, from, to)
+	if err != nil {
+		switch err {
+		case entities.ErrDriverNotFound:
+			c.JSON(http.StatusNotFound, ErrorResponse{
+				Error: "Driver not found",
+				Code:  "DRIVER_NOT_FOUND",
+			})
+		default:
+			h.logger.Error("Failed to get driver stats", zap.Error(err))
+			c.JSON(http.StatusInternalServerError, ErrorResponse{
+				Error: "Internal server error",
+				Code:  "INTERNAL_ERROR",
+			})
+		}
+		return
+	}
+
+	c.JSON(http.StatusOK, stats)
+}
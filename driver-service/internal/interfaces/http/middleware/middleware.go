@@ -1,7 +1,14 @@
 package middleware
 
 import (
+	"bytes"
+	"compress/gzip"
 	"context"
+	"crypto/sha256"
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
 	"time"
 
 	"github.com/gin-gonic/gin"
@@ -51,7 +58,7 @@ func CORS() gin.HandlerFunc {
 		// В production среде здесь должны быть проверки разрешенных доменов
 		c.Header("Access-Control-Allow-Origin", origin)
 		c.Header("Access-Control-Allow-Credentials", "true")
-		c.Header("Access-Control-Allow-Headers", "Content-Type, Content-Length, Accept-Encoding, X-CSRF-Token, Authorization, accept, origin, Cache-Control, X-Requested-With, X-Request-ID")
+		c.Header("Access-Control-Allow-Headers", "Content-Type, Content-Length, Accept-Encoding, X-CSRF-Token, Authorization, accept, origin, Cache-Control, X-Requested-With, X-Request-ID, If-None-Match, If-Modified-Since")
 		c.Header("Access-Control-Allow-Methods", "POST, OPTIONS, GET, PUT, DELETE, PATCH")
 
 		if c.Request.Method == "OPTIONS" {
@@ -63,11 +70,156 @@ func CORS() gin.HandlerFunc {
 	}
 }
 
-// RateLimit middleware для ограничения частоты запросов
-func RateLimit() gin.HandlerFunc {
-	// В реальном приложении здесь должна быть реализация rate limiting
-	// с использованием Redis или in-memory cache
+// ConditionalGet middleware для условных GET запросов: вычисляет ETag по телу успешного ответа и отвечает
+// 304 Not Modified, если ETag совпадает с If-None-Match или ресурс не изменялся после If-Modified-Since
+// (сравнивается с заголовком Last-Modified, который выставляет обработчик). Тело ответа буферизуется целиком,
+// поэтому middleware подключается только к маршрутам одного ресурса, а не к спискам.
+func ConditionalGet() gin.HandlerFunc {
 	return func(c *gin.Context) {
+		if c.Request.Method != http.MethodGet {
+			c.Next()
+			return
+		}
+
+		writer := &bufferedWriter{ResponseWriter: c.Writer}
+		c.Writer = writer
+		c.Next()
+		c.Writer = writer.ResponseWriter
+
+		if c.Writer.Status() != http.StatusOK {
+			writer.flush()
+			return
+		}
+
+		sum := sha256.Sum256(writer.body.Bytes())
+		etag := fmt.Sprintf("\"%x\"", sum[:16])
+		c.Header("ETag", etag)
+
+		if notModified(c.Request, etag, c.Writer.Header().Get("Last-Modified")) {
+			c.Writer.Header().Del("Content-Type")
+			c.Writer.Header().Del("Content-Length")
+			c.Status(http.StatusNotModified)
+			c.Writer.WriteHeaderNow()
+			return
+		}
+
+		writer.flush()
+	}
+}
+
+// bufferedWriter накапливает тело ответа, чтобы вычислить ETag до отправки
+type bufferedWriter struct {
+	gin.ResponseWriter
+	body bytes.Buffer
+}
+
+// Write записывает тело ответа в буфер
+func (w *bufferedWriter) Write(data []byte) (int, error) {
+	return w.body.Write(data)
+}
+
+// WriteString записывает тело ответа в буфер
+func (w *bufferedWriter) WriteString(s string) (int, error) {
+	return w.body.WriteString(s)
+}
+
+// flush отправляет накопленный ответ клиенту
+func (w *bufferedWriter) flush() {
+	w.ResponseWriter.WriteHeaderNow()
+	_, _ = w.ResponseWriter.Write(w.body.Bytes())
+}
+
+// notModified проверяет условия запроса. If-None-Match имеет приоритет над If-Modified-Since (RFC 7232).
+func notModified(r *http.Request, etag, lastModified string) bool {
+	if ifNoneMatch := r.Header.Get("If-None-Match"); ifNoneMatch != "" {
+		for _, candidate := range strings.Split(ifNoneMatch, ",") {
+			candidate = strings.TrimPrefix(strings.TrimSpace(candidate), "W/")
+			if candidate == "*" || candidate == etag {
+				return true
+			}
+		}
+		return false
+	}
+
+	ifModifiedSince := r.Header.Get("If-Modified-Since")
+	if ifModifiedSince == "" || lastModified == "" {
+		return false
+	}
+
+	since, err := http.ParseTime(ifModifiedSince)
+	if err != nil {
+		return false
+	}
+	modified, err := http.ParseTime(lastModified)
+	if err != nil {
+		return false
+	}
+	return !modified.After(since)
+}
+
+// RateLimit middleware для ограничения частоты запросов с одного адреса: allow решает по адресу клиента,
+// пропустить ли запрос. Отклоненные запросы получают 429.
+func RateLimit(allow func(key string) bool) gin.HandlerFunc {
+	return func(c *gin.Context) {
+		if !allow(c.ClientIP()) {
+			c.AbortWithStatusJSON(http.StatusTooManyRequests, gin.H{
+				"error": "Too many requests",
+				"code":  "RATE_LIMITED",
+			})
+			return
+		}
+		c.Next()
+	}
+}
+
+// DecodeBody middleware для тела запроса: распаковывает тело с Content-Encoding: gzip и ограничивает размер
+// распакованного тела maxBytes, чтобы сжатый запрос не обходил лимит. Больший запрос получает 413,
+// другие кодировки - 415. Обработчик получает распакованное тело без Content-Encoding.
+func DecodeBody(maxBytes int64) gin.HandlerFunc {
+	return func(c *gin.Context) {
+		var body io.Reader = c.Request.Body
+		switch encoding := strings.ToLower(strings.TrimSpace(c.GetHeader("Content-Encoding"))); encoding {
+		case "", "identity":
+		case "gzip":
+			reader, err := gzip.NewReader(c.Request.Body)
+			if err != nil {
+				c.AbortWithStatusJSON(http.StatusBadRequest, gin.H{
+					"error":   "Invalid gzip request body",
+					"code":    "INVALID_REQUEST",
+					"details": err.Error(),
+				})
+				return
+			}
+			defer reader.Close()
+			body = reader
+		default:
+			c.AbortWithStatusJSON(http.StatusUnsupportedMediaType, gin.H{
+				"error": fmt.Sprintf("Unsupported Content-Encoding %q, use gzip or none", encoding),
+				"code":  "UNSUPPORTED_ENCODING",
+			})
+			return
+		}
+
+		data, err := io.ReadAll(io.LimitReader(body, maxBytes+1))
+		if err != nil {
+			c.AbortWithStatusJSON(http.StatusBadRequest, gin.H{
+				"error":   "Failed to read request body",
+				"code":    "INVALID_REQUEST",
+				"details": err.Error(),
+			})
+			return
+		}
+		if int64(len(data)) > maxBytes {
+			c.AbortWithStatusJSON(http.StatusRequestEntityTooLarge, gin.H{
+				"error": fmt.Sprintf("Request body exceeds %d bytes", maxBytes),
+				"code":  "PAYLOAD_TOO_LARGE",
+			})
+			return
+		}
+
+		c.Request.Body = io.NopCloser(bytes.NewReader(data))
+		c.Request.ContentLength = int64(len(data))
+		c.Request.Header.Del("Content-Encoding")
 		c.Next()
 	}
 }
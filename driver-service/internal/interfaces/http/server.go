@@ -9,17 +9,25 @@ import (
 	"driver-service/internal/config"
 	"driver-service/internal/interfaces/http/handlers"
 	"driver-service/internal/interfaces/http/middleware"
+	"driver-service/internal/limits"
 
 	"github.com/gin-gonic/gin"
 	"go.uber.org/zap"
 )
 
+// MaxBatchBodyBytes лимит распакованного тела пакетного обновления местоположений (x-max-body-bytes
+// в api/openapi/driver-api.yaml): пакет из 10 000 точек, накопленных приложением без связи, укладывается с запасом
+const MaxBatchBodyBytes = 4 << 20
+
 // Server HTTP сервер
 type Server struct {
 	config     *config.Config
 	logger     *zap.Logger
 	httpServer *http.Server
 	router     *gin.Engine
+
+	// limits ограничения частоты запросов к /api/v1 (см. SetLimits); nil - без ограничения
+	limits *limits.Limits
 }
 
 // NewServer создает новый HTTP сервер
@@ -35,6 +43,18 @@ func NewServer(
 	}
 
 	router := gin.New()
+	// Заголовкам X-Forwarded-For и X-Real-IP верим только от балансировщиков: иначе клиент с подделанным
+	// заголовком получал бы новую корзину лимита запросов на каждый запрос. Адреса проверены в Config.Validate.
+	if err := router.SetTrustedProxies(cfg.Server.TrustedProxies); err != nil {
+		logger.Error("Invalid trusted proxies, forwarded headers are ignored", zap.Error(err))
+		_ = router.SetTrustedProxies(nil)
+	}
+
+	server := &Server{
+		config: cfg,
+		logger: logger,
+		router: router,
+	}
 	
 	// Middleware
 	router.Use(gin.Recovery())
@@ -53,6 +73,7 @@ func NewServer(
 
 	// API routes
 	api := router.Group("/api/v1")
+	api.Use(middleware.RateLimit(server.allowRequest))
 	
 	// Driver routes
 	drivers := api.Group("/drivers")
@@ -60,15 +81,19 @@ func NewServer(
 		drivers.POST("", driverHandler.CreateDriver)
 		drivers.GET("", driverHandler.ListDrivers)
 		drivers.GET("/active", driverHandler.GetActiveDrivers)
-		drivers.GET("/:id", driverHandler.GetDriver)
+		drivers.POST("/statuses", driverHandler.GetDriverStatuses)
+		// Условные запросы только для карточки водителя: ConditionalGet буферизует тело ради ETag,
+		// а списки могут быть большими
+		drivers.GET("/:id", middleware.ConditionalGet(), driverHandler.GetDriver)
 		drivers.PUT("/:id", driverHandler.UpdateDriver)
 		drivers.DELETE("/:id", driverHandler.DeleteDriver)
 		drivers.PATCH("/:id/status", driverHandler.ChangeStatus)
 		
 		// Location routes for specific driver
 		drivers.POST("/:id/locations", locationHandler.UpdateLocation)
-		drivers.POST("/:id/locations/batch", locationHandler.BatchUpdateLocations)
+		drivers.POST("/:id/locations/batch", middleware.DecodeBody(MaxBatchBodyBytes), locationHandler.BatchUpdateLocations)
 		drivers.GET("/:id/locations/current", locationHandler.GetCurrentLocation)
+		drivers.GET("/:id/locations/at", locationHandler.GetLocationAt)
 		drivers.GET("/:id/locations/history", locationHandler.GetLocationHistory)
 	}
 
@@ -78,18 +103,13 @@ func NewServer(
 		locations.GET("/nearby", locationHandler.GetNearbyDrivers)
 	}
 
-	server := &Server{
-		config: cfg,
-		logger: logger,
-		router: router,
-		httpServer: &http.Server{
-			Addr:           fmt.Sprintf(":%d", cfg.Server.HTTPPort),
-			Handler:        router,
-			ReadTimeout:    cfg.Server.Timeout,
-			WriteTimeout:   cfg.Server.Timeout,
-			IdleTimeout:    2 * cfg.Server.Timeout,
-			MaxHeaderBytes: 1 << 20, // 1 MB
-		},
+	server.httpServer = &http.Server{
+		Addr:           fmt.Sprintf(":%d", cfg.Server.HTTPPort),
+		Handler:        router,
+		ReadTimeout:    cfg.Server.Timeout,
+		WriteTimeout:   cfg.Server.Timeout,
+		IdleTimeout:    2 * cfg.Server.Timeout,
+		MaxHeaderBytes: 1 << 20, // 1 MB
 	}
 
 	return server
@@ -116,6 +136,114 @@ func (s *Server) Stop(ctx context.Context) error {
 	return s.httpServer.Shutdown(ctx)
 }
 
+// RegisterJobRoutes подключает ручной запуск фоновых задач (/api/v1/admin/jobs).
+// Вызывается до Start и только вне production (см. JobsConfig.AdminAPIEnabled).
+func (s *Server) RegisterJobRoutes(jobsHandler *handlers.JobsHandler) {
+	jobs := s.router.Group("/api/v1/admin/jobs")
+	{
+		jobs.GET("", jobsHandler.ListJobs)
+		jobs.POST("/:name/run", jobsHandler.RunJob)
+	}
+}
+
+// RegisterConsistencyRoutes подключает проверку согласованности данных (/api/v1/admin/consistency).
+// Вызывается до Start и только вне production (см. JobsConfig.AdminAPIEnabled).
+func (s *Server) RegisterConsistencyRoutes(consistencyHandler *handlers.ConsistencyHandler) {
+	s.router.GET("/api/v1/admin/consistency", consistencyHandler.CheckConsistency)
+}
+
+// RegisterAdminDriverRoutes подключает поиск водителей службой поддержки и восстановление удаленных
+// водителей (/api/v1/admin/drivers). Вызывается до Start и только вне production (см. JobsConfig.AdminAPIEnabled).
+func (s *Server) RegisterAdminDriverRoutes(driverHandler *handlers.DriverHandler) {
+	adminDrivers := s.router.Group("/api/v1/admin/drivers")
+	{
+		adminDrivers.GET("/lookup", driverHandler.LookupDrivers)
+		adminDrivers.POST("/:id/restore", driverHandler.RestoreDriver)
+	}
+}
+
+// RegisterFeatureRoutes подключает переключение флагов функциональности (/api/v1/admin/features).
+// Вызывается до Start и только вне production (см. FeaturesConfig.AdminAPIEnabled).
+func (s *Server) RegisterFeatureRoutes(featuresHandler *handlers.FeaturesHandler) {
+	featureFlags := s.router.Group("/api/v1/admin/features")
+	{
+		featureFlags.GET("", featuresHandler.ListFeatures)
+		featureFlags.PUT("/:name", featuresHandler.SetFeature)
+	}
+}
+
+// RegisterDispatchFeedRoutes подключает поток позиций водителей для диспетчеров (/api/v1/locations/feed)
+func (s *Server) RegisterDispatchFeedRoutes(feedHandler *handlers.DispatchFeedHandler) {
+	s.router.GET("/api/v1/locations/feed", feedHandler.WatchArea)
+}
+
+// RegisterHeartbeatRoutes подключает heartbeat приложения водителя (/api/v1/drivers/:id/heartbeat)
+func (s *Server) RegisterHeartbeatRoutes(heartbeatHandler *handlers.HeartbeatHandler) {
+	s.router.POST("/api/v1/drivers/:id/heartbeat", heartbeatHandler.Heartbeat)
+}
+
+// RegisterEarningsRoutes подключает заработок водителя за период (/api/v1/drivers/:id/earnings)
+func (s *Server) RegisterEarningsRoutes(earningsHandler *handlers.EarningsHandler) {
+	s.router.GET("/api/v1/drivers/:id/earnings", middleware.RateLimit(s.allowRequest), earningsHandler.GetEarnings)
+}
+
+// RegisterStatsRoutes подключает статистику водителя (/api/v1/drivers/:id/stats)
+func (s *Server) RegisterStatsRoutes(statsHandler *handlers.StatsHandler) {
+	s.router.GET("/api/v1/drivers/:id/stats", middleware.RateLimit(s.allowRequest), statsHandler.GetDriverStats)
+}
+
+// RegisterShiftRoutes подключает смены водителя (/api/v1/drivers/:id/shifts)
+func (s *Server) RegisterShiftRoutes(shiftHandler *handlers.ShiftHandler) {
+	shifts := s.router.Group("/api/v1/drivers/:id/shifts")
+	shifts.Use(middleware.RateLimit(s.allowRequest))
+	{
+		shifts.POST("/start", shiftHandler.StartShift)
+		shifts.POST("/pause", shiftHandler.PauseShift)
+		shifts.POST("/resume", shiftHandler.ResumeShift)
+		shifts.POST("/end", shiftHandler.EndShift)
+		shifts.GET("/current", shiftHandler.GetCurrentShift)
+	}
+}
+
+// RegisterAnalyticsRoutes подключает аналитику по парку водителей (/api/v1/analytics)
+func (s *Server) RegisterAnalyticsRoutes(analyticsHandler *handlers.AnalyticsHandler) {
+	s.router.GET("/api/v1/analytics/heatmap", middleware.RateLimit(s.allowRequest), analyticsHandler.GetDriverHeatmap)
+}
+
+// RegisterAvatarRoutes подключает загрузку фотографий водителей (/api/v1/drivers/:id/avatar).
+// Вызывается до Start, если настроено объектное хранилище.
+func (s *Server) RegisterAvatarRoutes(avatarHandler *handlers.AvatarHandler) {
+	s.router.POST("/api/v1/drivers/:id/avatar", avatarHandler.UploadAvatar)
+}
+
+// RegisterHealthRoutes подключает проверки живости (/health/live) и готовности (/health/ready).
+// /health остается прежним: он не проверяет зависимости.
+func (s *Server) RegisterHealthRoutes(healthHandler *handlers.HealthHandler) {
+	s.router.GET("/health/live", healthHandler.Live)
+	s.router.GET("/health/ready", healthHandler.Ready)
+}
+
+// SetLimits включает ограничение частоты запросов к /api/v1 по limits. Вызывается до Start;
+// новые значения limits действуют без перезапуска.
+func (s *Server) SetLimits(l *limits.Limits) {
+	s.limits = l
+}
+
+// RegisterLimitsRoutes подключает просмотр и перечитывание ограничений API (/api/v1/admin/config).
+// Вызывается до Start и только вне production (см. LimitsConfig.AdminAPIEnabled).
+func (s *Server) RegisterLimitsRoutes(limitsHandler *handlers.LimitsHandler) {
+	adminConfig := s.router.Group("/api/v1/admin/config")
+	{
+		adminConfig.GET("/limits", limitsHandler.GetLimits)
+		adminConfig.POST("/reload", limitsHandler.Reload)
+	}
+}
+
+// allowRequest проверяет запрос с адреса key по ограничениям SetLimits
+func (s *Server) allowRequest(key string) bool {
+	return s.limits.Allow(key)
+}
+
 // GetRouter возвращает router для тестирования
 func (s *Server) GetRouter() *gin.Engine {
 	return s.router
@@ -0,0 +1,138 @@
+package limits
+
+import (
+	"container/list"
+	"fmt"
+	"sync"
+	"time"
+
+	"driver-service/internal/config"
+)
+
+// DefaultNearbyRadiusKm радиус поиска водителей поблизости, если nearby_radius_km не задан
+const DefaultNearbyRadiusKm = 5.0
+
+// defaultMaxBuckets сколько адресов запоминается; при превышении забывается адрес, дольше всех не делавший запросов
+const defaultMaxBuckets = 10000
+
+// Limits текущие ограничения API. Значения заменяются на лету при перечитывании конфигурации,
+// компоненты сервиса читают их при каждом запросе.
+type Limits struct {
+	mu         sync.Mutex
+	config     config.LimitsConfig
+	buckets    map[string]*list.Element // значения - *bucket
+	recent     *list.List               // корзины от последнего запроса к самому давнему
+	maxBuckets int
+	now        func() time.Time
+}
+
+// bucket корзина токенов адреса: запрос забирает токен, токены пополняются со скоростью requests_per_second
+type bucket struct {
+	key     string
+	tokens  float64
+	updated time.Time
+}
+
+// New создает ограничения из конфигурации сервиса
+func New(cfg config.LimitsConfig) *Limits {
+	return &Limits{
+		config:     cfg,
+		buckets:    make(map[string]*list.Element),
+		recent:     list.New(),
+		maxBuckets: defaultMaxBuckets,
+		now:        time.Now,
+	}
+}
+
+// Config возвращает текущие ограничения
+func (l *Limits) Config() config.LimitsConfig {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	return l.config
+}
+
+// Update заменяет ограничения. Корзины адресов сбрасываются: новый лимит действует с полной корзины.
+func (l *Limits) Update(cfg config.LimitsConfig) {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	l.config = cfg
+	l.buckets = make(map[string]*list.Element)
+	l.recent.Init()
+}
+
+// Reload читает ограничения через load и применяет их. При ошибке текущие ограничения не меняются.
+func (l *Limits) Reload(load func() (config.LimitsConfig, error)) (config.LimitsConfig, error) {
+	cfg, err := load()
+	if err != nil {
+		return l.Config(), fmt.Errorf("failed to reload limits: %w", err)
+	}
+	if err := cfg.Validate(); err != nil {
+		return l.Config(), fmt.Errorf("failed to reload limits: %w", err)
+	}
+
+	l.Update(cfg)
+	return cfg, nil
+}
+
+// NearbyRadiusKm возвращает радиус поиска водителей поблизости по умолчанию. Для nil - DefaultNearbyRadiusKm.
+func (l *Limits) NearbyRadiusKm() float64 {
+	if l == nil {
+		return DefaultNearbyRadiusKm
+	}
+
+	radiusKm := l.Config().NearbyRadiusKm
+	if radiusKm <= 0 {
+		return DefaultNearbyRadiusKm
+	}
+	return radiusKm
+}
+
+// Allow проверяет, что запрос с адреса key укладывается в лимит, и учитывает его.
+// Для nil и requests_per_second = 0 запросы не ограничиваются. Запоминается не больше maxBuckets адресов:
+// адрес, дольше всех не делавший запросов, забывается и при следующем запросе получает полную корзину.
+func (l *Limits) Allow(key string) bool {
+	if l == nil {
+		return true
+	}
+
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	if l.config.RequestsPerSecond <= 0 {
+		return true
+	}
+
+	capacity := float64(max(l.config.Burst, 1))
+	now := l.now()
+
+	var b *bucket
+	if element, ok := l.buckets[key]; ok {
+		l.recent.MoveToFront(element)
+		b = element.Value.(*bucket)
+	} else {
+		if len(l.buckets) >= l.maxBuckets {
+			l.evictOldest()
+		}
+		b = &bucket{key: key, tokens: capacity, updated: now}
+		l.buckets[key] = l.recent.PushFront(b)
+	}
+
+	b.tokens = min(capacity, b.tokens+now.Sub(b.updated).Seconds()*l.config.RequestsPerSecond)
+	b.updated = now
+	if b.tokens < 1 {
+		return false
+	}
+	b.tokens--
+	return true
+}
+
+// evictOldest забывает адрес, дольше всех не делавший запросов
+func (l *Limits) evictOldest() {
+	oldest := l.recent.Back()
+	if oldest == nil {
+		return
+	}
+	l.recent.Remove(oldest)
+	delete(l.buckets, oldest.Value.(*bucket).key)
+}
@@ -0,0 +1,102 @@
+package limits
+
+import (
+	"errors"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestAllow(t *testing.T) {
+	// Arrange
+	now := time.Date(2024, 1, 1, 12, 0, 0, 0, time.UTC)
+	limits := New(config.LimitsConfig{RequestsPerSecond: 2, Burst: 3})
+	limits.now = func() time.Time { return now }
+
+	// Act & Assert - корзина на burst запросов
+	for i := 0; i < 3; i++ {
+		assert.True(t, limits.Allow("10.0.0.1"), "запрос %d", i+1)
+	}
+	assert.False(t, limits.Allow("10.0.0.1"))
+	assert.True(t, limits.Allow("10.0.0.2"), "Лимит считается для каждого адреса отдельно")
+
+	// Act & Assert - за полсекунды пополняется один токен
+	now = now.Add(500 * time.Millisecond)
+	assert.True(t, limits.Allow("10.0.0.1"))
+	assert.False(t, limits.Allow("10.0.0.1"))
+}
+
+func TestAllow_EvictsLeastRecentlyUsed(t *testing.T) {
+	// Arrange
+	now := time.Date(2024, 1, 1, 12, 0, 0, 0, time.UTC)
+	limits := New(config.LimitsConfig{RequestsPerSecond: 1, Burst: 1})
+	limits.now = func() time.Time { return now }
+	limits.maxBuckets = 2
+
+	require.True(t, limits.Allow("10.0.0.1"))
+	require.True(t, limits.Allow("10.0.0.2"))
+	require.False(t, limits.Allow("10.0.0.1"), "10.0.0.1 становится последним адресом с запросом")
+
+	// Act - корзины обоих адресов не пополнились, но новый адрес вытесняет давний
+	assert.True(t, limits.Allow("10.0.0.3"))
+
+	// Assert
+	assert.Len(t, limits.buckets, 2, "Адресов не больше maxBuckets")
+	assert.Equal(t, 2, limits.recent.Len())
+	assert.False(t, limits.Allow("10.0.0.1"), "Недавний адрес остается ограниченным")
+	assert.True(t, limits.Allow("10.0.0.2"), "Давний адрес забыт и получает полную корзину")
+}
+
+func TestAllow_Unlimited(t *testing.T) {
+	var disabled *Limits
+	assert.True(t, disabled.Allow("10.0.0.1"))
+
+	limits := New(config.LimitsConfig{Burst: 1})
+	for i := 0; i < 100; i++ {
+		require.True(t, limits.Allow("10.0.0.1"))
+	}
+}
+
+func TestReload(t *testing.T) {
+	// Arrange
+	limits := New(config.LimitsConfig{RequestsPerSecond: 1, Burst: 1, NearbyRadiusKm: 5})
+	require.True(t, limits.Allow("10.0.0.1"))
+	require.False(t, limits.Allow("10.0.0.1"))
+
+	// Act
+	reloaded, err := limits.Reload(func() (config.LimitsConfig, error) {
+		return config.LimitsConfig{RequestsPerSecond: 100, Burst: 10, NearbyRadiusKm: 12}, nil
+	})
+
+	// Assert
+	require.NoError(t, err)
+	assert.Equal(t, 12.0, reloaded.NearbyRadiusKm)
+	assert.Equal(t, 12.0, limits.NearbyRadiusKm())
+	assert.True(t, limits.Allow("10.0.0.1"), "Новый лимит действует с полной корзины")
+
+	t.Run("load error keeps limits", func(t *testing.T) {
+		_, err := limits.Reload(func() (config.LimitsConfig, error) {
+			return config.LimitsConfig{}, errors.New("broken yaml")
+		})
+		assert.Error(t, err)
+		assert.Equal(t, 12.0, limits.NearbyRadiusKm())
+	})
+
+	t.Run("invalid limits are rejected", func(t *testing.T) {
+		_, err := limits.Reload(func() (config.LimitsConfig, error) {
+			return config.LimitsConfig{RequestsPerSecond: -1, NearbyRadiusKm: 1}, nil
+		})
+		assert.Error(t, err)
+		assert.Equal(t, 100.0, limits.Config().RequestsPerSecond)
+	})
+}
+
+func TestNearbyRadiusKm_Default(t *testing.T) {
+	var disabled *Limits
+	assert.Equal(t, DefaultNearbyRadiusKm, disabled.NearbyRadiusKm())
+	assert.Equal(t, DefaultNearbyRadiusKm, New(config.LimitsConfig{}).NearbyRadiusKm())
+}
@@ -0,0 +1,194 @@
+package perfdash
+
+import (
+	"fmt"
+	"math"
+	"strconv"
+	"strings"
+)
+
+// testDataSource источник данных Grafana TestData: результаты встраиваются в дашборд как CSV,
+// поэтому дашборд импортируется в любой Grafana без доступа к файлам с результатами
+var testDataSource = map[string]string{"type": "grafana-testdata-datasource"}
+
+// Размеры панелей в сетке Grafana (24 колонки)
+const (
+	panelWidth  = 12
+	panelHeight = 8
+)
+
+// Dashboard модель дашборда Grafana (подмножество полей, нужное для импорта)
+type Dashboard struct {
+	Title         string   `json:"title"`
+	UID           string   `json:"uid"`
+	Tags          []string `json:"tags"`
+	SchemaVersion int      `json:"schemaVersion"`
+	Editable      bool     `json:"editable"`
+	Panels        []Panel  `json:"panels"`
+}
+
+// Panel панель дашборда
+type Panel struct {
+	ID          int                    `json:"id"`
+	Type        string                 `json:"type"`
+	Title       string                 `json:"title"`
+	Description string                 `json:"description,omitempty"`
+	GridPos     GridPos                `json:"gridPos"`
+	Datasource  map[string]string      `json:"datasource"`
+	Targets     []Target               `json:"targets"`
+	FieldConfig map[string]interface{} `json:"fieldConfig"`
+	Options     map[string]interface{} `json:"options"`
+}
+
+// GridPos положение панели в сетке
+type GridPos struct {
+	H int `json:"h"`
+	W int `json:"w"`
+	X int `json:"x"`
+	Y int `json:"y"`
+}
+
+// Target запрос панели к TestData со встроенным CSV
+type Target struct {
+	RefID      string `json:"refId"`
+	ScenarioID string `json:"scenarioId"`
+	CSVContent string `json:"csvContent"`
+}
+
+// BuildDashboard строит дашборд трендов по релизам: сводная таблица последнего релиза относительно предыдущего
+// и по панели на каждый бенчмарк со временем операции по релизам
+func BuildDashboard(title, uid string, runs []Run) Dashboard {
+	dashboard := Dashboard{
+		Title:         title,
+		UID:           uid,
+		Tags:          []string{"driver-service", "performance"},
+		SchemaVersion: 39,
+		Editable:      true,
+	}
+
+	dashboard.Panels = append(dashboard.Panels, summaryPanel(runs))
+	for i, benchmark := range Benchmarks(runs) {
+		dashboard.Panels = append(dashboard.Panels, trendPanel(benchmark, runs, len(dashboard.Panels)+1, i))
+	}
+	return dashboard
+}
+
+// summaryPanel таблица последнего релиза: время, память и аллокации на операцию и изменение времени
+// относительно предыдущего релиза
+func summaryPanel(runs []Run) Panel {
+	var csv strings.Builder
+	csv.WriteString("benchmark,time/op,B/op,allocs/op,time change %\n")
+
+	title := "Последний релиз"
+	if len(runs) > 0 {
+		latest := runs[len(runs)-1]
+		title = "Релиз " + latest.Release
+		if len(runs) > 1 {
+			title += " относительно " + runs[len(runs)-2].Release
+		}
+
+		for _, benchmark := range Benchmarks(runs) {
+			metrics, ok := latest.Medians[benchmark]
+			if !ok {
+				continue
+			}
+
+			change := ""
+			if len(runs) > 1 {
+				if previous, ok := runs[len(runs)-2].Medians[benchmark][TimePerOp]; ok && previous > 0 {
+					change = formatValue(math.Round((metrics[TimePerOp]-previous)/previous*1000) / 10)
+				}
+			}
+			fmt.Fprintf(&csv, "%s,%s,%s,%s,%s\n", csvField(benchmark),
+				metricValue(metrics, TimePerOp), metricValue(metrics, BytesPerOp), metricValue(metrics, AllocsPerOp), change)
+		}
+	}
+
+	return Panel{
+		ID:         1,
+		Type:       "table",
+		Title:      title,
+		GridPos:    GridPos{H: panelHeight, W: 2 * panelWidth, X: 0, Y: 0},
+		Datasource: testDataSource,
+		Targets:    []Target{csvTarget(csv.String())},
+		FieldConfig: map[string]interface{}{
+			"defaults": map[string]interface{}{},
+			"overrides": []interface{}{
+				fieldUnit("time/op", "ns"),
+				fieldUnit("B/op", "decbytes"),
+				fieldUnit("time change %", "percent"),
+			},
+		},
+		Options: map[string]interface{}{"showHeader": true},
+	}
+}
+
+// trendPanel столбчатая диаграмма времени операции бенчмарка по релизам
+func trendPanel(benchmark string, runs []Run, id, index int) Panel {
+	var csv strings.Builder
+	csv.WriteString("release,time/op\n")
+	for _, run := range runs {
+		if metrics, ok := run.Medians[benchmark]; ok {
+			fmt.Fprintf(&csv, "%s,%s\n", csvField(run.Release), metricValue(metrics, TimePerOp))
+		}
+	}
+
+	return Panel{
+		ID:          id,
+		Type:        "barchart",
+		Title:       benchmark,
+		Description: "Медиана времени операции по релизам",
+		GridPos: GridPos{
+			H: panelHeight,
+			W: panelWidth,
+			X: (index % 2) * panelWidth,
+			Y: panelHeight * (1 + index/2),
+		},
+		Datasource: testDataSource,
+		Targets:    []Target{csvTarget(csv.String())},
+		FieldConfig: map[string]interface{}{
+			"defaults":  map[string]interface{}{"unit": "ns"},
+			"overrides": []interface{}{},
+		},
+		Options: map[string]interface{}{
+			"xField":    "release",
+			"showValue": "auto",
+			"legend":    map[string]interface{}{"showLegend": false},
+		},
+	}
+}
+
+// csvTarget запрос TestData со встроенным CSV
+func csvTarget(content string) Target {
+	return Target{RefID: "A", ScenarioID: "csv_content", CSVContent: content}
+}
+
+// fieldUnit переопределение единицы измерения поля таблицы
+func fieldUnit(field, unit string) map[string]interface{} {
+	return map[string]interface{}{
+		"matcher":    map[string]string{"id": "byName", "options": field},
+		"properties": []map[string]string{{"id": "unit", "value": unit}},
+	}
+}
+
+// metricValue возвращает значение метрики для CSV или пустую строку, если бенчмарк ее не сообщает
+func metricValue(metrics map[Metric]float64, metric Metric) string {
+	value, ok := metrics[metric]
+	if !ok {
+		return ""
+	}
+	return formatValue(value)
+}
+
+// formatValue форматирует число без экспоненты
+func formatValue(value float64) string {
+	return strconv.FormatFloat(value, 'f', -1, 64)
+}
+
+// csvField экранирует значение CSV: имена бенчмарков могут содержать запятые ("Nearby/lat=1,lon=2")
+func csvField(value string) string {
+	if !strings.ContainsAny(value, ",\"\n") {
+		return value
+	}
+	return `"` + strings.ReplaceAll(value, `"`, `""`) + `"`
+}
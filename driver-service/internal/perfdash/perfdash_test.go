@@ -0,0 +1,104 @@
+package perfdash
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+const benchOutput = `release: v1.3.0
+goos: linux
+goarch: amd64
+pkg: driver-service/tests/benchmarks
+cpu: Intel(R) Xeon(R) CPU @ 2.20GHz
+BenchmarkDatabase/CreateDriver-8         	    1000	   1200000 ns/op	    2048 B/op	      30 allocs/op
+BenchmarkDatabase/CreateDriver-8         	    1000	   1000000 ns/op	    2048 B/op	      30 allocs/op
+BenchmarkDatabase/CreateDriver-8         	    1000	   1100000 ns/op	    2048 B/op	      30 allocs/op
+BenchmarkDatabase/Nearby/radius=1km-8    	     500	   2000000 ns/op	    4096 B/op	      55 allocs/op	         7.5 drivers/op
+release: v1.4.0
+BenchmarkDatabase/CreateDriver-8         	    1000	    880000 ns/op	    1024 B/op	      20 allocs/op
+PASS
+ok  	driver-service/tests/benchmarks	12.345s
+`
+
+func TestParseResults(t *testing.T) {
+	// Act
+	samples, err := ParseResults(strings.NewReader(benchOutput), "unknown")
+
+	// Assert
+	require.NoError(t, err)
+	require.Len(t, samples, 5)
+
+	assert.Equal(t, "v1.3.0", samples[0].Release)
+	assert.Equal(t, "Database/CreateDriver", samples[0].Benchmark)
+	assert.Equal(t, map[Metric]float64{TimePerOp: 1200000, BytesPerOp: 2048, AllocsPerOp: 30}, samples[0].Values)
+	assert.Equal(t, "Database/Nearby/radius=1km", samples[3].Benchmark)
+	assert.Equal(t, 7.5, samples[3].Values["drivers/op"])
+	assert.Equal(t, "v1.4.0", samples[4].Release)
+
+	t.Run("default release", func(t *testing.T) {
+		samples, err := ParseResults(strings.NewReader("BenchmarkX-4 10 100 ns/op\n"), "database-20260918")
+		require.NoError(t, err)
+		require.Len(t, samples, 1)
+		assert.Equal(t, "database-20260918", samples[0].Release)
+		assert.Equal(t, "X", samples[0].Benchmark)
+	})
+
+	t.Run("malformed line", func(t *testing.T) {
+		_, err := ParseResults(strings.NewReader("BenchmarkX-4 10 100\n"), "")
+		assert.Error(t, err)
+	})
+}
+
+func TestAggregate(t *testing.T) {
+	// Arrange
+	samples, err := ParseResults(strings.NewReader(benchOutput), "unknown")
+	require.NoError(t, err)
+
+	// Act
+	runs := Aggregate(samples)
+
+	// Assert
+	require.Len(t, runs, 2)
+	assert.Equal(t, "v1.3.0", runs[0].Release)
+	assert.Equal(t, 1100000.0, runs[0].Medians["Database/CreateDriver"][TimePerOp], "Медиана трех прогонов")
+	assert.Equal(t, "v1.4.0", runs[1].Release)
+	assert.NotContains(t, runs[1].Medians, "Database/Nearby/radius=1km")
+	assert.Equal(t, []string{"Database/CreateDriver", "Database/Nearby/radius=1km"}, Benchmarks(runs))
+}
+
+func TestBuildDashboard(t *testing.T) {
+	// Arrange
+	samples, err := ParseResults(strings.NewReader(benchOutput), "unknown")
+	require.NoError(t, err)
+
+	// Act
+	dashboard := BuildDashboard("Perf", "perf", Aggregate(samples))
+
+	// Assert
+	require.Len(t, dashboard.Panels, 3)
+
+	summary := dashboard.Panels[0]
+	assert.Equal(t, "table", summary.Type)
+	assert.Equal(t, "Релиз v1.4.0 относительно v1.3.0", summary.Title)
+	assert.Equal(t, "benchmark,time/op,B/op,allocs/op,time change %\n"+
+		"Database/CreateDriver,880000,1024,20,-20\n", summary.Targets[0].CSVContent)
+
+	createDriver := dashboard.Panels[1]
+	assert.Equal(t, "barchart", createDriver.Type)
+	assert.Equal(t, "Database/CreateDriver", createDriver.Title)
+	assert.Equal(t, "release,time/op\nv1.3.0,1100000\nv1.4.0,880000\n", createDriver.Targets[0].CSVContent)
+	assert.Equal(t, GridPos{H: panelHeight, W: panelWidth, X: 0, Y: panelHeight}, createDriver.GridPos)
+
+	nearby := dashboard.Panels[2]
+	assert.Equal(t, "release,time/op\nv1.3.0,2000000\n", nearby.Targets[0].CSVContent)
+	assert.Equal(t, panelWidth, nearby.GridPos.X)
+
+	ids := make(map[int]bool)
+	for _, panel := range dashboard.Panels {
+		assert.False(t, ids[panel.ID], "ID панелей уникальны")
+		ids[panel.ID] = true
+	}
+}
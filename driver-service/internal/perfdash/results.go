@@ -0,0 +1,189 @@
+package perfdash
+
+import (
+	"bufio"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+)
+
+// ReleaseKey строка конфигурации в выводе go test -bench, задающая релиз последующих результатов
+// (make bench-db записывает "release: <git describe>" в начало файла)
+const ReleaseKey = "release"
+
+// Metric единица измерения результата бенчмарка, например "ns/op", "B/op" или "drivers/op"
+type Metric string
+
+// Стандартные метрики go test -bench -benchmem
+const (
+	TimePerOp   Metric = "ns/op"
+	BytesPerOp  Metric = "B/op"
+	AllocsPerOp Metric = "allocs/op"
+)
+
+// Sample один результат бенчмарка (одна строка вывода go test -bench)
+type Sample struct {
+	Release   string
+	Benchmark string
+	Values    map[Metric]float64
+}
+
+// Run результаты бенчмарков одного релиза: медианы по всем прогонам (-count) этого релиза
+type Run struct {
+	Release string
+	Medians map[string]map[Metric]float64 // бенчмарк -> метрика -> медиана
+}
+
+// ParseResults разбирает вывод go test -bench. Строки "ключ: значение" задают конфигурацию,
+// из которой берется release; до первой такой строки используется defaultRelease.
+// Суффикс GOMAXPROCS ("-8") и префикс "Benchmark" из имени бенчмарка отбрасываются.
+func ParseResults(r io.Reader, defaultRelease string) ([]Sample, error) {
+	release := defaultRelease
+	var samples []Sample
+
+	scanner := bufio.NewScanner(r)
+	for line := 1; scanner.Scan(); line++ {
+		text := strings.TrimSpace(scanner.Text())
+
+		if key, value, ok := strings.Cut(text, ":"); ok && key == ReleaseKey {
+			release = strings.TrimSpace(value)
+			continue
+		}
+		if !strings.HasPrefix(text, "Benchmark") {
+			continue
+		}
+
+		// Строка результата: имя, число итераций и пары "значение единица". Остальные строки с именем
+		// бенчмарка (заголовок перед выводом b.Log, "--- FAIL") пропускаются.
+		fields := strings.Fields(text)
+		if len(fields) < 2 {
+			continue
+		}
+		if _, err := strconv.Atoi(fields[1]); err != nil {
+			continue
+		}
+		if len(fields) < 4 || len(fields)%2 != 0 {
+			return nil, fmt.Errorf("line %d: malformed benchmark result %q", line, text)
+		}
+
+		sample := Sample{
+			Release:   release,
+			Benchmark: benchmarkName(fields[0]),
+			Values:    make(map[Metric]float64, (len(fields)-2)/2),
+		}
+		for i := 2; i < len(fields); i += 2 {
+			value, err := strconv.ParseFloat(fields[i], 64)
+			if err != nil {
+				return nil, fmt.Errorf("line %d: invalid value %q: %w", line, fields[i], err)
+			}
+			sample.Values[Metric(fields[i+1])] = value
+		}
+		samples = append(samples, sample)
+	}
+
+	return samples, scanner.Err()
+}
+
+// benchmarkName убирает из имени бенчмарка префикс "Benchmark" и суффикс GOMAXPROCS
+func benchmarkName(name string) string {
+	name = strings.TrimPrefix(name, "Benchmark")
+	if i := strings.LastIndex(name, "-"); i > 0 {
+		if _, err := strconv.Atoi(name[i+1:]); err == nil {
+			name = name[:i]
+		}
+	}
+	return name
+}
+
+// LoadDir загружает все файлы *.txt каталога с результатами (bench-results) в порядке имен:
+// make bench-db называет файлы по времени прогона. Файл без строки release относится к релизу,
+// совпадающему с именем файла. Прогоны одного релиза объединяются, релизы идут в порядке первого появления.
+func LoadDir(dir string) ([]Run, error) {
+	files, err := filepath.Glob(filepath.Join(dir, "*.txt"))
+	if err != nil {
+		return nil, err
+	}
+	sort.Strings(files)
+
+	var samples []Sample
+	for _, path := range files {
+		file, err := os.Open(path)
+		if err != nil {
+			return nil, err
+		}
+
+		parsed, err := ParseResults(file, strings.TrimSuffix(filepath.Base(path), ".txt"))
+		file.Close()
+		if err != nil {
+			return nil, fmt.Errorf("%s: %w", path, err)
+		}
+		samples = append(samples, parsed...)
+	}
+
+	return Aggregate(samples), nil
+}
+
+// Aggregate группирует результаты по релизам и считает медиану каждой метрики каждого бенчмарка
+func Aggregate(samples []Sample) []Run {
+	var releases []string
+	values := make(map[string]map[string]map[Metric][]float64)
+	for _, sample := range samples {
+		byBenchmark, ok := values[sample.Release]
+		if !ok {
+			byBenchmark = make(map[string]map[Metric][]float64)
+			values[sample.Release] = byBenchmark
+			releases = append(releases, sample.Release)
+		}
+		if byBenchmark[sample.Benchmark] == nil {
+			byBenchmark[sample.Benchmark] = make(map[Metric][]float64)
+		}
+		for metric, value := range sample.Values {
+			byBenchmark[sample.Benchmark][metric] = append(byBenchmark[sample.Benchmark][metric], value)
+		}
+	}
+
+	runs := make([]Run, 0, len(releases))
+	for _, release := range releases {
+		run := Run{Release: release, Medians: make(map[string]map[Metric]float64)}
+		for benchmark, metrics := range values[release] {
+			run.Medians[benchmark] = make(map[Metric]float64, len(metrics))
+			for metric, measured := range metrics {
+				run.Medians[benchmark][metric] = median(measured)
+			}
+		}
+		runs = append(runs, run)
+	}
+	return runs
+}
+
+// median возвращает медиану значений
+func median(values []float64) float64 {
+	sorted := append([]float64(nil), values...)
+	sort.Float64s(sorted)
+
+	middle := len(sorted) / 2
+	if len(sorted)%2 == 0 {
+		return (sorted[middle-1] + sorted[middle]) / 2
+	}
+	return sorted[middle]
+}
+
+// Benchmarks возвращает имена бенчмарков всех релизов в алфавитном порядке
+func Benchmarks(runs []Run) []string {
+	seen := make(map[string]bool)
+	var names []string
+	for _, run := range runs {
+		for name := range run.Medians {
+			if !seen[name] {
+				seen[name] = true
+				names = append(names, name)
+			}
+		}
+	}
+	sort.Strings(names)
+	return names
+}
@@ -0,0 +1,91 @@
+package repositories
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/database"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// ConnectionRepository интерфейс для учета потери связи с водителями
+type ConnectionRepository interface {
+	MarkLost(ctx context.Context, locatedBefore time.Time) ([]*entities.ConnectionLoss, error)
+	MarkRestored(ctx context.Context, driverID uuid.UUID) (*entities.ConnectionLoss, error)
+}
+
+// connectionRepository реализация ConnectionRepository
+type connectionRepository struct {
+	db     *database.DB
+	logger *zap.Logger
+}
+
+// NewConnectionRepository создает новый репозиторий потерь связи
+func NewConnectionRepository(db *database.DB, logger *zap.Logger) ConnectionRepository {
+	return &connectionRepository{
+		db:     db,
+		logger: logger,
+	}
+}
+
+// MarkLost отмечает потерю связи с активными водителями (available, on_shift, busy), последнее местоположение
+// которых записано раньше locatedBefore, и возвращает только новые потери: водитель, связь с которым уже
+// отмечена потерянной, повторно не возвращается. Водители без истории местоположений не затрагиваются.
+func (r *connectionRepository) MarkLost(ctx context.Context, locatedBefore time.Time) ([]*entities.ConnectionLoss, error) {
+	query := `
+		INSERT INTO driver_connection_losses (driver_id, last_location_at, lost_at)
+		SELECT d.id, l.recorded_at, NOW()
+		FROM drivers d
+		JOIN LATERAL (
+			SELECT recorded_at FROM driver_locations
+			WHERE driver_id = d.id
+			ORDER BY recorded_at DESC
+			LIMIT 1
+		) l ON true
+		WHERE d.status IN ('available', 'on_shift', 'busy')
+			AND d.deleted_at IS NULL
+			AND l.recorded_at < $1
+		ON CONFLICT (driver_id) DO NOTHING
+		RETURNING driver_id, last_location_at, lost_at`
+
+	var losses []*entities.ConnectionLoss
+	err := r.db.SelectContext(ctx, &losses, query, locatedBefore)
+	if err != nil {
+		r.logger.Error("Failed to mark lost connections",
+			zap.Error(err),
+			zap.Time("located_before", locatedBefore),
+		)
+		return nil, fmt.Errorf("failed to mark lost connections: %w", err)
+	}
+
+	return losses, nil
+}
+
+// MarkRestored снимает отметку о потере связи с водителем и возвращает ее; если связь не была
+// отмечена потерянной, возвращает nil
+func (r *connectionRepository) MarkRestored(ctx context.Context, driverID uuid.UUID) (*entities.ConnectionLoss, error) {
+	query := `
+		DELETE FROM driver_connection_losses
+		WHERE driver_id = $1
+		RETURNING driver_id, last_location_at, lost_at`
+
+	var loss entities.ConnectionLoss
+	err := r.db.GetContext(ctx, &loss, query, driverID)
+	if err != nil {
+		if err == sql.ErrNoRows {
+			return nil, nil
+		}
+		r.logger.Error("Failed to mark restored connection",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+		return nil, fmt.Errorf("failed to mark restored connection: %w", err)
+	}
+
+	return &loss, nil
+}
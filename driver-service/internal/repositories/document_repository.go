@@ -26,6 +26,8 @@ type DocumentRepository interface {
 	Count(ctx context.Context, filters *entities.DocumentFilters) (int, error)
 	UpdateStatus(ctx context.Context, id uuid.UUID, status entities.VerificationStatus, verifierID, reason *string) error
 	GetExpiring(ctx context.Context, days int) ([]*entities.DriverDocument, error)
+	GetExpiringNotNotified(ctx context.Context, days int) ([]*entities.DriverDocument, error)
+	MarkExpiryNotified(ctx context.Context, id uuid.UUID, expiryDate time.Time) error
 	GetExpired(ctx context.Context) ([]*entities.DriverDocument, error)
 	MarkExpired(ctx context.Context, documentIDs []uuid.UUID) error
 }
@@ -305,6 +307,47 @@ func (r *documentRepository) GetExpiring(ctx context.Context, days int) ([]*enti
 	return documents, nil
 }
 
+// GetExpiringNotNotified получает документы, истекающие через указанное количество дней, о сроке действия
+// которых водитель еще не уведомлен (см. MarkExpiryNotified)
+func (r *documentRepository) GetExpiringNotNotified(ctx context.Context, days int) ([]*entities.DriverDocument, error) {
+	query := `
+		SELECT * FROM driver_documents
+		WHERE expiry_date <= $1
+		AND expiry_date > NOW()
+		AND status = 'verified'
+		AND expiry_notified_for IS DISTINCT FROM expiry_date
+		ORDER BY expiry_date ASC`
+
+	var documents []*entities.DriverDocument
+	err := r.db.SelectContext(ctx, &documents, query, time.Now().AddDate(0, 0, days))
+	if err != nil {
+		r.logger.Error("Failed to get expiring documents to notify",
+			zap.Error(err),
+			zap.Int("days", days),
+		)
+		return nil, fmt.Errorf("failed to get expiring documents to notify: %w", err)
+	}
+
+	return documents, nil
+}
+
+// MarkExpiryNotified отмечает, что водитель уведомлен об истечении документа expiryDate. Документ с новым
+// сроком действия снова попадает в GetExpiringNotNotified
+func (r *documentRepository) MarkExpiryNotified(ctx context.Context, id uuid.UUID, expiryDate time.Time) error {
+	query := `UPDATE driver_documents SET expiry_notified_for = $1 WHERE id = $2`
+
+	_, err := r.db.ExecContext(ctx, query, expiryDate, id)
+	if err != nil {
+		r.logger.Error("Failed to mark document expiry notified",
+			zap.Error(err),
+			zap.String("document_id", id.String()),
+		)
+		return fmt.Errorf("failed to mark document expiry notified: %w", err)
+	}
+
+	return nil
+}
+
 // GetExpired получает истекшие документы
 func (r *documentRepository) GetExpired(ctx context.Context) ([]*entities.DriverDocument, error) {
 	query := `
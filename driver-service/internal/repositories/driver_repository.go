@@ -4,6 +4,7 @@ import (
 	"context"
 	"database/sql"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"strings"
 	"time"
@@ -12,6 +13,7 @@ import (
 	"driver-service/internal/infrastructure/database"
 
 	"github.com/google/uuid"
+	"github.com/lib/pq"
 	"go.uber.org/zap"
 )
 
@@ -22,16 +24,22 @@ type DriverRepository interface {
 	GetByPhone(ctx context.Context, phone string) (*entities.Driver, error)
 	GetByEmail(ctx context.Context, email string) (*entities.Driver, error)
 	GetByLicenseNumber(ctx context.Context, licenseNumber string) (*entities.Driver, error)
+	Lookup(ctx context.Context, lookup *entities.DriverLookup) ([]*entities.Driver, error)
 	Update(ctx context.Context, driver *entities.Driver) error
 	Delete(ctx context.Context, id uuid.UUID) error
 	SoftDelete(ctx context.Context, id uuid.UUID) error
+	Restore(ctx context.Context, id uuid.UUID) error
 	List(ctx context.Context, filters *entities.DriverFilters) ([]*entities.Driver, error)
 	Count(ctx context.Context, filters *entities.DriverFilters) (int, error)
 	Exists(ctx context.Context, phone, licenseNumber string) (bool, error)
 	UpdateStatus(ctx context.Context, id uuid.UUID, status entities.Status) error
+	UpdateStatusFrom(ctx context.Context, id uuid.UUID, from, to entities.Status) error
 	UpdateRating(ctx context.Context, id uuid.UUID, rating float64) error
 	IncrementTripCount(ctx context.Context, id uuid.UUID) error
 	GetActiveDrivers(ctx context.Context) ([]*entities.Driver, error)
+	GetStalePendingVerification(ctx context.Context, updatedBefore time.Time) ([]*entities.Driver, error)
+	GetStatuses(ctx context.Context, ids []uuid.UUID) ([]*entities.DriverStatusInfo, error)
+	GetStats(ctx context.Context, id uuid.UUID, from, to time.Time) (*entities.DriverStats, error)
 }
 
 // driverRepository реализация DriverRepository
@@ -92,6 +100,10 @@ func (r *driverRepository) Create(ctx context.Context, driver *entities.Driver)
 
 	_, err = r.db.NamedExecContext(ctx, query, params)
 	if err != nil {
+		var pqErr *pq.Error
+		if errors.As(err, &pqErr) && pqErr.Code == "23505" {
+			return entities.ErrDriverExists
+		}
 		r.logger.Error("Failed to create driver",
 			zap.Error(err),
 			zap.String("driver_id", driver.ID.String()),
@@ -130,14 +142,16 @@ func (r *driverRepository) GetByID(ctx context.Context, id uuid.UUID) (*entities
 	return &driver, nil
 }
 
-// GetByPhone получает водителя по номеру телефона
+// GetByPhone получает водителя по номеру телефона в любом формате записи (см. entities.NormalizePhone)
 func (r *driverRepository) GetByPhone(ctx context.Context, phone string) (*entities.Driver, error) {
 	var driver entities.Driver
 	query := `
 		SELECT * FROM drivers 
-		WHERE phone = $1 AND deleted_at IS NULL`
+		WHERE normalize_phone(phone) = $1 AND deleted_at IS NULL
+		ORDER BY created_at
+		LIMIT 1`
 
-	err := r.db.GetContext(ctx, &driver, query, phone)
+	err := r.db.GetContext(ctx, &driver, query, entities.NormalizePhone(phone))
 	if err != nil {
 		if err == sql.ErrNoRows {
 			return nil, entities.ErrDriverNotFound
@@ -196,6 +210,38 @@ func (r *driverRepository) GetByLicenseNumber(ctx context.Context, licenseNumber
 	return &driver, nil
 }
 
+// Lookup ищет водителей по телефону в любом формате записи или по email без учета регистра.
+// С IncludeDeleted в выдачу попадают и мягко удаленные водители (после удаления контакт может
+// быть занят новой регистрацией, поэтому водителей может быть несколько): сначала активный,
+// затем удаленные, от недавно удаленных к давно удаленным.
+func (r *driverRepository) Lookup(ctx context.Context, lookup *entities.DriverLookup) ([]*entities.Driver, error) {
+	query := "SELECT * FROM drivers WHERE "
+	var arg string
+	if phone := strings.TrimSpace(lookup.Phone); phone != "" {
+		query += "normalize_phone(phone) = $1"
+		arg = entities.NormalizePhone(phone)
+	} else {
+		query += "lower(email) = lower($1)"
+		arg = strings.TrimSpace(lookup.Email)
+	}
+	if !lookup.IncludeDeleted {
+		query += " AND deleted_at IS NULL"
+	}
+	query += " ORDER BY deleted_at DESC NULLS FIRST, created_at DESC, id"
+
+	var drivers []*entities.Driver
+	err := r.db.SelectContext(ctx, &drivers, query, arg)
+	if err != nil {
+		r.logger.Error("Failed to lookup drivers",
+			zap.Error(err),
+			zap.Bool("include_deleted", lookup.IncludeDeleted),
+		)
+		return nil, fmt.Errorf("failed to lookup drivers: %w", err)
+	}
+
+	return drivers, nil
+}
+
 // Update обновляет данные водителя
 func (r *driverRepository) Update(ctx context.Context, driver *entities.Driver) error {
 	driver.UpdatedAt = time.Now()
@@ -298,6 +344,43 @@ func (r *driverRepository) SoftDelete(ctx context.Context, id uuid.UUID) error {
 	return nil
 }
 
+// Restore восстанавливает мягко удаленного водителя. Если телефон, email или ВУ водителя
+// после удаления заняты новой регистрацией, возвращается ErrDriverExists.
+func (r *driverRepository) Restore(ctx context.Context, id uuid.UUID) error {
+	query := `
+		UPDATE drivers 
+		SET deleted_at = NULL, updated_at = $1 
+		WHERE id = $2 AND deleted_at IS NOT NULL`
+
+	result, err := r.db.ExecContext(ctx, query, time.Now(), id)
+	if err != nil {
+		var pqErr *pq.Error
+		if errors.As(err, &pqErr) && pqErr.Code == "23505" {
+			return entities.ErrDriverExists
+		}
+		r.logger.Error("Failed to restore driver",
+			zap.Error(err),
+			zap.String("driver_id", id.String()),
+		)
+		return fmt.Errorf("failed to restore driver: %w", err)
+	}
+
+	rowsAffected, err := result.RowsAffected()
+	if err != nil {
+		return fmt.Errorf("failed to get rows affected: %w", err)
+	}
+
+	if rowsAffected == 0 {
+		return entities.ErrDriverNotFound
+	}
+
+	r.logger.Info("Driver restored successfully",
+		zap.String("driver_id", id.String()),
+	)
+
+	return nil
+}
+
 // List получает список водителей с фильтрами
 func (r *driverRepository) List(ctx context.Context, filters *entities.DriverFilters) ([]*entities.Driver, error) {
 	query, args, err := r.buildListQuery(filters, false)
@@ -336,17 +419,17 @@ func (r *driverRepository) Count(ctx context.Context, filters *entities.DriverFi
 	return count, nil
 }
 
-// Exists проверяет существование водителя по телефону или номеру лицензии
+// Exists проверяет существование водителя по телефону в любом формате записи или номеру лицензии
 func (r *driverRepository) Exists(ctx context.Context, phone, licenseNumber string) (bool, error) {
 	query := `
 		SELECT EXISTS(
 			SELECT 1 FROM drivers 
-			WHERE (phone = $1 OR license_number = $2) 
+			WHERE (normalize_phone(phone) = $1 OR license_number = $2) 
 			AND deleted_at IS NULL
 		)`
 
 	var exists bool
-	err := r.db.GetContext(ctx, &exists, query, phone, licenseNumber)
+	err := r.db.GetContext(ctx, &exists, query, entities.NormalizePhone(phone), licenseNumber)
 	if err != nil {
 		r.logger.Error("Failed to check driver existence",
 			zap.Error(err),
@@ -393,6 +476,44 @@ func (r *driverRepository) UpdateStatus(ctx context.Context, id uuid.UUID, statu
 	return nil
 }
 
+// UpdateStatusFrom обновляет статус водителя, только если текущий статус равен from.
+// Если статус успел измениться (например, водителя одновременно назначили на другой заказ),
+// возвращает ErrConcurrentModification.
+func (r *driverRepository) UpdateStatusFrom(ctx context.Context, id uuid.UUID, from, to entities.Status) error {
+	query := `
+		UPDATE drivers 
+		SET status = $1, updated_at = $2 
+		WHERE id = $3 AND status = $4 AND deleted_at IS NULL`
+
+	result, err := r.db.ExecContext(ctx, query, to, time.Now(), id, from)
+	if err != nil {
+		r.logger.Error("Failed to update driver status",
+			zap.Error(err),
+			zap.String("driver_id", id.String()),
+			zap.String("from_status", string(from)),
+			zap.String("to_status", string(to)),
+		)
+		return fmt.Errorf("failed to update driver status: %w", err)
+	}
+
+	rowsAffected, err := result.RowsAffected()
+	if err != nil {
+		return fmt.Errorf("failed to get rows affected: %w", err)
+	}
+
+	if rowsAffected == 0 {
+		return entities.ErrConcurrentModification
+	}
+
+	r.logger.Info("Driver status updated successfully",
+		zap.String("driver_id", id.String()),
+		zap.String("from_status", string(from)),
+		zap.String("to_status", string(to)),
+	)
+
+	return nil
+}
+
 // UpdateRating обновляет рейтинг водителя
 func (r *driverRepository) UpdateRating(ctx context.Context, id uuid.UUID, rating float64) error {
 	query := `
@@ -450,16 +571,24 @@ func (r *driverRepository) IncrementTripCount(ctx context.Context, id uuid.UUID)
 	return nil
 }
 
-// GetActiveDrivers получает список активных водителей
+// GetActiveDrivers получает список активных водителей. Водители, последнее местоположение которых старше
+// entities.LocationStaleAfter, не включаются: связь с ними потеряна. Водители без местоположений остаются в списке.
 func (r *driverRepository) GetActiveDrivers(ctx context.Context) ([]*entities.Driver, error) {
 	query := `
-		SELECT * FROM drivers 
-		WHERE status IN ('available', 'on_shift', 'busy') 
-		AND deleted_at IS NULL
-		ORDER BY current_rating DESC`
+		SELECT d.* FROM drivers d
+		LEFT JOIN LATERAL (
+			SELECT recorded_at FROM driver_locations
+			WHERE driver_id = d.id
+			ORDER BY recorded_at DESC
+			LIMIT 1
+		) latest ON true
+		WHERE d.status IN ('available', 'on_shift', 'busy')
+		AND d.deleted_at IS NULL
+		AND (latest.recorded_at IS NULL OR latest.recorded_at >= $1)
+		ORDER BY d.current_rating DESC`
 
 	var drivers []*entities.Driver
-	err := r.db.SelectContext(ctx, &drivers, query)
+	err := r.db.SelectContext(ctx, &drivers, query, time.Now().Add(-entities.LocationStaleAfter))
 	if err != nil {
 		r.logger.Error("Failed to get active drivers",
 			zap.Error(err),
@@ -470,6 +599,107 @@ func (r *driverRepository) GetActiveDrivers(ctx context.Context) ([]*entities.Dr
 	return drivers, nil
 }
 
+// GetStalePendingVerification получает водителей в статусе pending_verification, которые не изменялись
+// с момента updatedBefore
+func (r *driverRepository) GetStalePendingVerification(ctx context.Context, updatedBefore time.Time) ([]*entities.Driver, error) {
+	query := `
+		SELECT * FROM drivers
+		WHERE status = 'pending_verification' AND updated_at < $1
+		AND deleted_at IS NULL
+		ORDER BY updated_at`
+
+	var drivers []*entities.Driver
+	err := r.db.SelectContext(ctx, &drivers, query, updatedBefore)
+	if err != nil {
+		r.logger.Error("Failed to get stale pending verification drivers",
+			zap.Error(err),
+			zap.Time("updated_before", updatedBefore),
+		)
+		return nil, fmt.Errorf("failed to get stale pending verification drivers: %w", err)
+	}
+
+	return drivers, nil
+}
+
+// GetStatuses получает статусы водителей по списку ID одним запросом. Порядок строк не определен,
+// удаленные и неизвестные водители пропускаются.
+func (r *driverRepository) GetStatuses(ctx context.Context, ids []uuid.UUID) ([]*entities.DriverStatusInfo, error) {
+	idStrings := make([]string, len(ids))
+	for i, id := range ids {
+		idStrings[i] = id.String()
+	}
+
+	query := `
+		SELECT id, status, updated_at FROM drivers
+		WHERE id = ANY($1::uuid[]) AND deleted_at IS NULL`
+
+	var statuses []*entities.DriverStatusInfo
+	err := r.db.SelectContext(ctx, &statuses, query, pq.Array(idStrings))
+	if err != nil {
+		r.logger.Error("Failed to get driver statuses",
+			zap.Error(err),
+			zap.Int("count", len(ids)),
+		)
+		return nil, fmt.Errorf("failed to get driver statuses: %w", err)
+	}
+
+	return statuses, nil
+}
+
+// GetStats получает статистику водителя одним запросом: поездки - счетчик drivers.total_trips, рейтинг -
+// агрегат driver_rating_stats (его пересчитывает триггер на driver_ratings), расстояние - сумма расстояний
+// между соседними точками трека за период [from, to] по формуле гаверсинуса, как в GetNearby
+func (r *driverRepository) GetStats(ctx context.Context, id uuid.UUID, from, to time.Time) (*entities.DriverStats, error) {
+	query := `
+		SELECT d.id AS driver_id, d.total_trips,
+			COALESCE(rs.average_rating, 0) AS average_rating,
+			COALESCE(rs.total_ratings, 0) AS total_ratings,
+			COALESCE(track.km, 0) AS distance_km
+		FROM drivers d
+		LEFT JOIN driver_rating_stats rs ON rs.driver_id = d.id
+		CROSS JOIN LATERAL (
+			SELECT SUM(6371.0 * 2 * asin(least(1.0, sqrt(
+				power(sin(radians(latitude - prev_latitude) / 2), 2) +
+				cos(radians(prev_latitude)) * cos(radians(latitude)) *
+				power(sin(radians(longitude - prev_longitude) / 2), 2)
+			)))) AS km
+			FROM (
+				SELECT latitude, longitude,
+					LAG(latitude) OVER w AS prev_latitude,
+					LAG(longitude) OVER w AS prev_longitude
+				FROM driver_locations
+				WHERE driver_id = d.id AND recorded_at BETWEEN $2 AND $3
+				WINDOW w AS (ORDER BY recorded_at)
+			) points
+			WHERE prev_latitude IS NOT NULL
+		) track
+		WHERE d.id = $1 AND d.deleted_at IS NULL`
+
+	var stats entities.DriverStats
+	err := r.db.GetContext(ctx, &stats, query, id, from, to)
+	if err != nil {
+		if err == sql.ErrNoRows {
+			return nil, entities.ErrDriverNotFound
+		}
+		r.logger.Error("Failed to get driver stats",
+			zap.Error(err),
+			zap.String("driver_id", id.String()),
+		)
+		return nil, fmt.Errorf("failed to get driver stats: %w", err)
+	}
+
+	stats.From = from
+	stats.To = to
+	return &stats, nil
+}
+
+// driverSortTypes типы столбцов сортировки списка водителей для значения курсора
+var driverSortTypes = map[string]string{
+	"created_at":     "timestamptz",
+	"current_rating": "numeric",
+	"total_trips":    "integer",
+}
+
 // buildListQuery строит SQL запрос для получения списка водителей
 func (r *driverRepository) buildListQuery(filters *entities.DriverFilters, isCount bool) (string, []interface{}, error) {
 	var conditions []string
@@ -488,6 +718,10 @@ func (r *driverRepository) buildListQuery(filters *entities.DriverFilters, isCou
 
 	// Добавляем условия фильтрации
 	if filters != nil {
+		if err := filters.ValidateSort(); err != nil {
+			return "", nil, err
+		}
+
 		if len(filters.Status) > 0 {
 			placeholders := make([]string, len(filters.Status))
 			for i, status := range filters.Status {
@@ -521,6 +755,39 @@ func (r *driverRepository) buildListQuery(filters *entities.DriverFilters, isCou
 			conditions = append(conditions, fmt.Sprintf("created_at <= $%d", argCount))
 			args = append(args, *filters.CreatedBefore)
 		}
+
+		if filters.City != nil {
+			argCount++
+			conditions = append(conditions, fmt.Sprintf("lower(metadata->>'city') = lower($%d)", argCount))
+			args = append(args, *filters.City)
+		}
+
+		if filters.LicenseExpiringBefore != nil {
+			argCount++
+			conditions = append(conditions, fmt.Sprintf("license_expiry < $%d::date", argCount))
+			args = append(args, filters.LicenseExpiringBefore.Format("2006-01-02"))
+		}
+
+		if filters.Search != nil {
+			// Выражение совпадает с индексом idx_drivers_names
+			argCount++
+			conditions = append(conditions, fmt.Sprintf(
+				"to_tsvector('russian', first_name || ' ' || last_name || ' ' || COALESCE(middle_name, '')) @@ plainto_tsquery('russian', $%d)",
+				argCount))
+			args = append(args, *filters.Search)
+		}
+
+		if filters.After != nil && !isCount {
+			// Строки строго после курсора в порядке сортировки; id различает водителей с равным значением поля
+			operator := ">"
+			if filters.SortOrder() == "desc" {
+				operator = "<"
+			}
+			argCount += 2
+			conditions = append(conditions, fmt.Sprintf("(%s, id) %s ($%d::%s, $%d::uuid)",
+				filters.SortField(), operator, argCount-1, driverSortTypes[filters.SortField()], argCount))
+			args = append(args, filters.After.Value, filters.After.ID)
+		}
 	}
 
 	// Собираем WHERE условия
@@ -533,16 +800,10 @@ func (r *driverRepository) buildListQuery(filters *entities.DriverFilters, isCou
 
 	// Для запросов списка добавляем сортировку и пагинацию
 	if !isCount && filters != nil {
-		// Сортировка
-		orderBy := "ORDER BY created_at DESC"
-		if filters.SortBy != "" {
-			direction := "ASC"
-			if filters.SortDirection == "desc" {
-				direction = "DESC"
-			}
-			orderBy = fmt.Sprintf("ORDER BY %s %s", filters.SortBy, direction)
-		}
-		query += " " + orderBy
+		// Сортировка; id в конце делает порядок полным: водители с одинаковым значением поля
+		// не переставляются между запросами и не теряются и не повторяются на границах страниц
+		direction := strings.ToUpper(filters.SortOrder())
+		query += fmt.Sprintf(" ORDER BY %s %s, id %s", filters.SortField(), direction, direction)
 
 		// Пагинация
 		if filters.Limit > 0 {
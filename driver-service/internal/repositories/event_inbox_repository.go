@@ -0,0 +1,176 @@
+package repositories
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/database"
+
+	"github.com/google/uuid"
+	"github.com/jmoiron/sqlx"
+	"go.uber.org/zap"
+)
+
+// EventInboxRepository применяет эффекты входящих событий ровно один раз: эффект и идентификатор события
+// записываются в processed_events одной транзакцией. Методы возвращают false без изменений,
+// если событие с таким идентификатором уже применено (повторная доставка).
+type EventInboxRepository interface {
+	ApplyTrip(ctx context.Context, eventID, subject string, driverID uuid.UUID, distanceKm float64) (bool, error)
+	ApplyPayment(ctx context.Context, eventID, subject string, payment *entities.TripPayment) (bool, error)
+}
+
+// eventInboxRepository реализация EventInboxRepository
+type eventInboxRepository struct {
+	db     *database.DB
+	logger *zap.Logger
+}
+
+// NewEventInboxRepository создает новый репозиторий обработанных событий
+func NewEventInboxRepository(db *database.DB, logger *zap.Logger) EventInboxRepository {
+	return &eventInboxRepository{
+		db:     db,
+		logger: logger,
+	}
+}
+
+// ApplyTrip учитывает завершенную поездку в счетчике поездок водителя и в итогах его активной смены.
+// Если водителя нет, возвращает ErrDriverNotFound; поездка вне смены учитывается только у водителя.
+func (r *eventInboxRepository) ApplyTrip(ctx context.Context, eventID, subject string, driverID uuid.UUID, distanceKm float64) (bool, error) {
+	return r.apply(ctx, eventID, subject, func(tx *sqlx.Tx) error {
+		now := time.Now()
+		result, err := tx.ExecContext(ctx, `
+			UPDATE drivers
+			SET total_trips = total_trips + 1, updated_at = $1
+			WHERE id = $2 AND deleted_at IS NULL`, now, driverID)
+		if err != nil {
+			return fmt.Errorf("failed to increment trip count: %w", err)
+		}
+		if err := requireRow(result, entities.ErrDriverNotFound); err != nil {
+			return err
+		}
+
+		_, err = tx.ExecContext(ctx, `
+			UPDATE driver_shifts
+			SET total_trips = total_trips + 1, total_distance = total_distance + $1, updated_at = $2
+			WHERE driver_id = $3 AND status = 'active' AND end_time IS NULL`, distanceKm, now, driverID)
+		if err != nil {
+			return fmt.Errorf("failed to add trip to shift: %w", err)
+		}
+		return nil
+	})
+}
+
+// ApplyPayment записывает оплату поездки в driver_payments и добавляет ее net_amount к заработку незавершенной
+// (активной или приостановленной) смены водителя. Если водителя нет, возвращает ErrDriverNotFound. Оплата,
+// пришедшая после окончания смены, записывается без смены (shift_id NULL) и учитывается только в заработке
+// водителя. Оплата с уже записанным payment_id (повтор под другим идентификатором события) заработок не меняет.
+func (r *eventInboxRepository) ApplyPayment(ctx context.Context, eventID, subject string, payment *entities.TripPayment) (bool, error) {
+	return r.apply(ctx, eventID, subject, func(tx *sqlx.Tx) error {
+		var driverExists bool
+		err := tx.QueryRowxContext(ctx, `
+			SELECT EXISTS(SELECT 1 FROM drivers WHERE id = $1 AND deleted_at IS NULL)`, payment.DriverID).Scan(&driverExists)
+		if err != nil {
+			return fmt.Errorf("failed to check driver: %w", err)
+		}
+		if !driverExists {
+			return entities.ErrDriverNotFound
+		}
+
+		var shiftID *uuid.UUID
+		var openShiftID uuid.UUID
+		err = tx.QueryRowxContext(ctx, `
+			SELECT id FROM driver_shifts
+			WHERE driver_id = $1 AND status IN ('active', 'suspended') AND end_time IS NULL
+			FOR UPDATE`, payment.DriverID).Scan(&openShiftID)
+		switch {
+		case err == nil:
+			shiftID = &openShiftID
+		case err != sql.ErrNoRows:
+			return fmt.Errorf("failed to get current shift: %w", err)
+		}
+
+		now := time.Now()
+		payment.ShiftID = shiftID
+		payment.ProcessedAt = now
+		result, err := tx.NamedExecContext(ctx, `
+			INSERT INTO driver_payments (
+				payment_id, driver_id, shift_id, gross_amount, commission, net_amount, processed_at
+			) VALUES (
+				:payment_id, :driver_id, :shift_id, :gross_amount, :commission, :net_amount, :processed_at
+			)
+			ON CONFLICT (payment_id) DO NOTHING`, payment)
+		if err != nil {
+			return fmt.Errorf("failed to record payment: %w", err)
+		}
+		rowsAffected, err := result.RowsAffected()
+		if err != nil {
+			return fmt.Errorf("failed to get rows affected: %w", err)
+		}
+		if rowsAffected == 0 || shiftID == nil {
+			return nil
+		}
+
+		_, err = tx.ExecContext(ctx, `
+			UPDATE driver_shifts
+			SET total_earnings = total_earnings + $1, updated_at = $2
+			WHERE id = $3`, payment.NetAmount, now, *shiftID)
+		if err != nil {
+			return fmt.Errorf("failed to add payment to shift: %w", err)
+		}
+		return nil
+	})
+}
+
+// apply записывает идентификатор события и, если его еще не было, применяет эффект в той же транзакции.
+// Параллельная доставка того же события ждет фиксации первой транзакции на вставке и пропускает эффект.
+func (r *eventInboxRepository) apply(ctx context.Context, eventID, subject string, effect func(tx *sqlx.Tx) error) (bool, error) {
+	applied := false
+	err := r.db.TransactionWithContext(ctx, func(tx *sqlx.Tx) error {
+		result, err := tx.ExecContext(ctx, `
+			INSERT INTO processed_events (event_id, subject, processed_at)
+			VALUES ($1, $2, $3)
+			ON CONFLICT (event_id) DO NOTHING`, eventID, subject, time.Now())
+		if err != nil {
+			return fmt.Errorf("failed to record processed event: %w", err)
+		}
+
+		rowsAffected, err := result.RowsAffected()
+		if err != nil {
+			return fmt.Errorf("failed to get rows affected: %w", err)
+		}
+		if rowsAffected == 0 {
+			return nil
+		}
+
+		if err := effect(tx); err != nil {
+			return err
+		}
+		applied = true
+		return nil
+	})
+	if err != nil {
+		r.logger.Error("Failed to apply event",
+			zap.Error(err),
+			zap.String("event_id", eventID),
+			zap.String("subject", subject),
+		)
+		return false, err
+	}
+
+	return applied, nil
+}
+
+// requireRow возвращает notFound, если запрос не изменил ни одной строки
+func requireRow(result sql.Result, notFound error) error {
+	rowsAffected, err := result.RowsAffected()
+	if err != nil {
+		return fmt.Errorf("failed to get rows affected: %w", err)
+	}
+	if rowsAffected == 0 {
+		return notFound
+	}
+	return nil
+}
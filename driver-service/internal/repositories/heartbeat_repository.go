@@ -0,0 +1,112 @@
+package repositories
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/database"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// HeartbeatRepository интерфейс для работы с heartbeat приложений водителей
+type HeartbeatRepository interface {
+	Record(ctx context.Context, driverID uuid.UUID, seenAt time.Time, minInterval time.Duration) error
+	GetByDriverID(ctx context.Context, driverID uuid.UUID) (*entities.DriverHeartbeat, error)
+	GetMissed(ctx context.Context, seenBefore time.Time) ([]*entities.DriverHeartbeat, error)
+}
+
+// heartbeatRepository реализация HeartbeatRepository
+type heartbeatRepository struct {
+	db     *database.DB
+	logger *zap.Logger
+}
+
+// NewHeartbeatRepository создает новый репозиторий heartbeat
+func NewHeartbeatRepository(db *database.DB, logger *zap.Logger) HeartbeatRepository {
+	return &heartbeatRepository{
+		db:     db,
+		logger: logger,
+	}
+}
+
+// Record сохраняет время heartbeat водителя. Если предыдущий heartbeat был меньше minInterval назад,
+// время не меняется и возвращается ErrHeartbeatRateLimited. Проверка и запись выполняются одним запросом,
+// поэтому ограничение соблюдается и при параллельных запросах к нескольким экземплярам сервиса.
+func (r *heartbeatRepository) Record(ctx context.Context, driverID uuid.UUID, seenAt time.Time, minInterval time.Duration) error {
+	query := `
+		INSERT INTO driver_heartbeats (driver_id, last_seen_at)
+		VALUES ($1, $2)
+		ON CONFLICT (driver_id) DO UPDATE
+		SET last_seen_at = EXCLUDED.last_seen_at
+		WHERE driver_heartbeats.last_seen_at <= $3`
+
+	result, err := r.db.ExecContext(ctx, query, driverID, seenAt, seenAt.Add(-minInterval))
+	if err != nil {
+		r.logger.Error("Failed to record heartbeat",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+		return fmt.Errorf("failed to record heartbeat: %w", err)
+	}
+
+	rowsAffected, err := result.RowsAffected()
+	if err != nil {
+		return fmt.Errorf("failed to get rows affected: %w", err)
+	}
+
+	if rowsAffected == 0 {
+		return entities.ErrHeartbeatRateLimited
+	}
+
+	return nil
+}
+
+// GetByDriverID получает последний heartbeat водителя
+func (r *heartbeatRepository) GetByDriverID(ctx context.Context, driverID uuid.UUID) (*entities.DriverHeartbeat, error) {
+	query := `SELECT * FROM driver_heartbeats WHERE driver_id = $1`
+
+	var heartbeat entities.DriverHeartbeat
+	err := r.db.GetContext(ctx, &heartbeat, query, driverID)
+	if err != nil {
+		if err == sql.ErrNoRows {
+			return nil, entities.ErrHeartbeatNotFound
+		}
+		r.logger.Error("Failed to get heartbeat",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+		return nil, fmt.Errorf("failed to get heartbeat: %w", err)
+	}
+
+	return &heartbeat, nil
+}
+
+// GetMissed получает heartbeat активных водителей (available, on_shift, busy), последний сигнал
+// от которых был раньше seenBefore
+func (r *heartbeatRepository) GetMissed(ctx context.Context, seenBefore time.Time) ([]*entities.DriverHeartbeat, error) {
+	query := `
+		SELECT h.driver_id, h.last_seen_at
+		FROM driver_heartbeats h
+		JOIN drivers d ON d.id = h.driver_id
+		WHERE h.last_seen_at < $1
+			AND d.status IN ('available', 'on_shift', 'busy')
+			AND d.deleted_at IS NULL
+		ORDER BY h.last_seen_at`
+
+	var heartbeats []*entities.DriverHeartbeat
+	err := r.db.SelectContext(ctx, &heartbeats, query, seenBefore)
+	if err != nil {
+		r.logger.Error("Failed to get missed heartbeats",
+			zap.Error(err),
+			zap.Time("seen_before", seenBefore),
+		)
+		return nil, fmt.Errorf("failed to get missed heartbeats: %w", err)
+	}
+
+	return heartbeats, nil
+}
@@ -10,6 +10,7 @@ import (
 	"driver-service/internal/infrastructure/database"
 
 	"github.com/google/uuid"
+	"github.com/jmoiron/sqlx"
 	"go.uber.org/zap"
 )
 
@@ -18,13 +19,19 @@ type LocationRepository interface {
 	Create(ctx context.Context, location *entities.DriverLocation) error
 	GetByID(ctx context.Context, id uuid.UUID) (*entities.DriverLocation, error)
 	GetLatestByDriverID(ctx context.Context, driverID uuid.UUID) (*entities.DriverLocation, error)
+	GetLatestAt(ctx context.Context, driverID uuid.UUID, at time.Time) (*entities.DriverLocation, error)
 	GetByDriverIDInTimeRange(ctx context.Context, driverID uuid.UUID, from, to time.Time) ([]*entities.DriverLocation, error)
+	GetHistory(ctx context.Context, query *entities.LocationHistoryQuery) ([]*entities.DriverLocation, error)
 	List(ctx context.Context, filters *entities.LocationFilters) ([]*entities.DriverLocation, error)
 	CreateBatch(ctx context.Context, locations []*entities.DriverLocation) error
-	DeleteOld(ctx context.Context, olderThan time.Time) error
+	DeleteOld(ctx context.Context, olderThan time.Time) (int64, error)
 	GetNearby(ctx context.Context, lat, lon, radiusKm float64, limit int) ([]*entities.DriverLocation, error)
+	GetLatestOfActiveDrivers(ctx context.Context) ([]*entities.DriverLocation, error)
 }
 
+// batchInsertSize количество местоположений в одном INSERT пакетной вставки (12 параметров на строку)
+const batchInsertSize = 1000
+
 type locationRepository struct {
 	db     *database.DB
 	logger *zap.Logger
@@ -37,6 +44,8 @@ func NewLocationRepository(db *database.DB, logger *zap.Logger) LocationReposito
 	}
 }
 
+// Create сохраняет местоположение; повтор сохраненной точки водителя (то же время записи и координаты)
+// пропускается
 func (r *locationRepository) Create(ctx context.Context, location *entities.DriverLocation) error {
 	query := `
 		INSERT INTO driver_locations (
@@ -45,7 +54,8 @@ func (r *locationRepository) Create(ctx context.Context, location *entities.Driv
 		) VALUES (
 			:id, :driver_id, :latitude, :longitude, :altitude, :accuracy,
 			:speed, :bearing, :address, :metadata, :recorded_at, :created_at
-		)`
+		)
+		ON CONFLICT DO NOTHING`
 
 	_, err := r.db.NamedExecContext(ctx, query, location)
 	return err
@@ -83,6 +93,25 @@ func (r *locationRepository) GetLatestByDriverID(ctx context.Context, driverID u
 	return &location, nil
 }
 
+// GetLatestAt возвращает последнее местоположение водителя, записанное не позже at
+func (r *locationRepository) GetLatestAt(ctx context.Context, driverID uuid.UUID, at time.Time) (*entities.DriverLocation, error) {
+	var location entities.DriverLocation
+	query := `
+		SELECT * FROM driver_locations
+		WHERE driver_id = $1 AND recorded_at <= $2
+		ORDER BY recorded_at DESC
+		LIMIT 1`
+
+	err := r.db.GetContext(ctx, &location, query, driverID, at)
+	if err != nil {
+		if err == sql.ErrNoRows {
+			return nil, entities.ErrLocationNotFound
+		}
+		return nil, err
+	}
+	return &location, nil
+}
+
 func (r *locationRepository) GetByDriverIDInTimeRange(ctx context.Context, driverID uuid.UUID, from, to time.Time) ([]*entities.DriverLocation, error) {
 	var locations []*entities.DriverLocation
 	query := `
@@ -94,6 +123,37 @@ func (r *locationRepository) GetByDriverIDInTimeRange(ctx context.Context, drive
 	return locations, err
 }
 
+// GetHistory возвращает историю по query. С интервалом точки группируются по интервалам от начала эпохи
+// и от каждого интервала остается последняя точка; курсор и лимит применяются после прореживания.
+func (r *locationRepository) GetHistory(ctx context.Context, query *entities.LocationHistoryQuery) ([]*entities.DriverLocation, error) {
+	args := []interface{}{query.DriverID, query.From, query.To}
+	source := "driver_locations WHERE driver_id = $1 AND recorded_at BETWEEN $2 AND $3"
+	if query.Interval > 0 {
+		args = append(args, query.Interval.Seconds())
+		source = `(
+			SELECT DISTINCT ON (floor(extract(epoch FROM recorded_at) / $4)) *
+			FROM driver_locations
+			WHERE driver_id = $1 AND recorded_at BETWEEN $2 AND $3
+			ORDER BY floor(extract(epoch FROM recorded_at) / $4), recorded_at DESC, id DESC
+		) sampled WHERE true`
+	}
+
+	statement := "SELECT * FROM " + source
+	if query.After != nil {
+		args = append(args, query.After.RecordedAt, query.After.ID)
+		statement += fmt.Sprintf(" AND (recorded_at, id) > ($%d, $%d)", len(args)-1, len(args))
+	}
+	statement += " ORDER BY recorded_at ASC, id ASC"
+	if query.Limit > 0 {
+		args = append(args, query.Limit)
+		statement += fmt.Sprintf(" LIMIT $%d", len(args))
+	}
+
+	var locations []*entities.DriverLocation
+	err := r.db.SelectContext(ctx, &locations, statement, args...)
+	return locations, err
+}
+
 func (r *locationRepository) List(ctx context.Context, filters *entities.LocationFilters) ([]*entities.DriverLocation, error) {
 	query, args := r.buildListQuery(filters)
 
@@ -114,37 +174,99 @@ func (r *locationRepository) CreateBatch(ctx context.Context, locations []*entit
 		) VALUES (
 			:id, :driver_id, :latitude, :longitude, :altitude, :accuracy,
 			:speed, :bearing, :address, :metadata, :recorded_at, :created_at
-		)`
+		)
+		ON CONFLICT DO NOTHING`
 
-	_, err := r.db.NamedExecContext(ctx, query, locations)
-	return err
+	// Повторы точек в пакете и уже сохраненные точки пропускаются (уникальный индекс по водителю, времени
+	// и координатам). Пакет вставляется частями в одной транзакции: PostgreSQL принимает не более 65535 параметров в запросе
+	return r.db.TransactionWithContext(ctx, func(tx *sqlx.Tx) error {
+		for start := 0; start < len(locations); start += batchInsertSize {
+			end := start + batchInsertSize
+			if end > len(locations) {
+				end = len(locations)
+			}
+
+			if _, err := tx.NamedExecContext(ctx, query, locations[start:end]); err != nil {
+				return err
+			}
+		}
+		return nil
+	})
 }
 
-func (r *locationRepository) DeleteOld(ctx context.Context, olderThan time.Time) error {
+func (r *locationRepository) DeleteOld(ctx context.Context, olderThan time.Time) (int64, error) {
 	query := `DELETE FROM driver_locations WHERE recorded_at < $1`
 	result, err := r.db.ExecContext(ctx, query, olderThan)
 	if err != nil {
-		return err
+		return 0, err
 	}
 
 	rowsAffected, _ := result.RowsAffected()
 	r.logger.Info("Deleted old locations", zap.Int64("rows_affected", rowsAffected))
-	return nil
+	return rowsAffected, nil
 }
 
 func (r *locationRepository) GetNearby(ctx context.Context, lat, lon, radiusKm float64, limit int) ([]*entities.DriverLocation, error) {
+	// Сначала последняя точка каждого водителя (по индексу driver_id, recorded_at), затем фильтр по радиусу:
+	// водитель, уехавший из радиуса, не находится по старой точке внутри него. Расстояние в километрах
+	// по формуле гаверсинуса, как entities.DriverLocation.DistanceTo. Водители, последняя точка которых
+	// старше entities.LocationStaleAfter, не находятся: связь с ними потеряна. Как и в GetLatestOfActiveDrivers,
+	// учитываются только водители на линии (available, on_shift, busy), не удаленные - до LIMIT, чтобы неактивные
+	// водители не занимали места в выдаче. Ближайшие первыми, при равном расстоянии - по driver_id, чтобы меньший
+	// limit всегда возвращал начало списка большего
 	query := `
-		SELECT DISTINCT ON (driver_id) *
-		FROM driver_locations 
-		WHERE point(longitude, latitude) <@> point($1, $2) <= $3
-		ORDER BY driver_id, recorded_at DESC
+		SELECT latest.*
+		FROM drivers d
+		CROSS JOIN LATERAL (
+			SELECT * FROM driver_locations
+			WHERE driver_id = d.id
+			ORDER BY recorded_at DESC
+			LIMIT 1
+		) latest
+		CROSS JOIN LATERAL (
+			SELECT 6371.0 * 2 * asin(least(1.0, sqrt(
+				power(sin(radians(latest.latitude - $2::float8) / 2), 2) +
+				cos(radians($2::float8)) * cos(radians(latest.latitude)) *
+				power(sin(radians(latest.longitude - $1::float8) / 2), 2)
+			))) AS km
+		) distance
+		WHERE d.status IN ('available', 'on_shift', 'busy')
+		AND d.deleted_at IS NULL
+		AND distance.km <= $3 AND latest.recorded_at >= $5
+		ORDER BY distance.km, latest.driver_id
 		LIMIT $4`
 
 	var locations []*entities.DriverLocation
-	err := r.db.SelectContext(ctx, &locations, query, lon, lat, radiusKm, limit)
+	err := r.db.SelectContext(ctx, &locations, query, lon, lat, radiusKm, limit, time.Now().Add(-entities.LocationStaleAfter))
 	return locations, err
 }
 
+// GetLatestOfActiveDrivers получает последнее местоположение каждого водителя на линии (available, on_shift,
+// busy). Как и в GetNearby, водители с местоположением старше entities.LocationStaleAfter не учитываются.
+func (r *locationRepository) GetLatestOfActiveDrivers(ctx context.Context) ([]*entities.DriverLocation, error) {
+	query := `
+		SELECT latest.*
+		FROM drivers d
+		CROSS JOIN LATERAL (
+			SELECT * FROM driver_locations
+			WHERE driver_id = d.id
+			ORDER BY recorded_at DESC
+			LIMIT 1
+		) latest
+		WHERE d.status IN ('available', 'on_shift', 'busy')
+		AND d.deleted_at IS NULL
+		AND latest.recorded_at >= $1`
+
+	var locations []*entities.DriverLocation
+	err := r.db.SelectContext(ctx, &locations, query, time.Now().Add(-entities.LocationStaleAfter))
+	if err != nil {
+		r.logger.Error("Failed to get latest locations of active drivers", zap.Error(err))
+		return nil, fmt.Errorf("failed to get latest locations of active drivers: %w", err)
+	}
+
+	return locations, nil
+}
+
 func (r *locationRepository) buildListQuery(filters *entities.LocationFilters) (string, []interface{}) {
 	query := "SELECT * FROM driver_locations WHERE 1=1"
 	var args []interface{}
@@ -0,0 +1,52 @@
+package repositories
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/database"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// PaymentRepository интерфейс для чтения оплат поездок водителей (записывает их EventInboxRepository)
+type PaymentRepository interface {
+	GetByDriverID(ctx context.Context, driverID uuid.UUID, from, to time.Time) ([]*entities.TripPayment, error)
+}
+
+// paymentRepository реализация PaymentRepository
+type paymentRepository struct {
+	db     *database.DB
+	logger *zap.Logger
+}
+
+// NewPaymentRepository создает новый репозиторий оплат
+func NewPaymentRepository(db *database.DB, logger *zap.Logger) PaymentRepository {
+	return &paymentRepository{
+		db:     db,
+		logger: logger,
+	}
+}
+
+// GetByDriverID получает оплаты водителя, обработанные в периоде [from, to], по времени обработки
+func (r *paymentRepository) GetByDriverID(ctx context.Context, driverID uuid.UUID, from, to time.Time) ([]*entities.TripPayment, error) {
+	query := `
+		SELECT * FROM driver_payments
+		WHERE driver_id = $1 AND processed_at BETWEEN $2 AND $3
+		ORDER BY processed_at, payment_id`
+
+	var payments []*entities.TripPayment
+	err := r.db.SelectContext(ctx, &payments, query, driverID, from, to)
+	if err != nil {
+		r.logger.Error("Failed to get driver payments",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+		return nil, fmt.Errorf("failed to get driver payments: %w", err)
+	}
+
+	return payments, nil
+}
@@ -0,0 +1,184 @@
+package repositories
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/database"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+)
+
+// ShiftRepository интерфейс для работы со сменами водителей
+type ShiftRepository interface {
+	Create(ctx context.Context, shift *entities.DriverShift) error
+	GetCurrent(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, error)
+	Update(ctx context.Context, shift *entities.DriverShift) error
+	GetStaleActive(ctx context.Context, startedBefore time.Time) ([]*entities.DriverShift, error)
+	Complete(ctx context.Context, id uuid.UUID, endTime time.Time) error
+}
+
+// shiftRepository реализация ShiftRepository
+type shiftRepository struct {
+	db     *database.DB
+	logger *zap.Logger
+}
+
+// NewShiftRepository создает новый репозиторий смен
+func NewShiftRepository(db *database.DB, logger *zap.Logger) ShiftRepository {
+	return &shiftRepository{
+		db:     db,
+		logger: logger,
+	}
+}
+
+// Create создает смену, если у водителя нет незавершенной смены; иначе возвращает ErrShiftExists.
+// Проверка и запись выполняются одним запросом.
+func (r *shiftRepository) Create(ctx context.Context, shift *entities.DriverShift) error {
+	query := `
+		INSERT INTO driver_shifts (
+			id, driver_id, vehicle_id, start_time, status, start_latitude, start_longitude,
+			total_trips, total_distance, total_earnings, metadata, created_at, updated_at
+		)
+		SELECT
+			:id, :driver_id, :vehicle_id, :start_time, :status, :start_latitude, :start_longitude,
+			:total_trips, :total_distance, :total_earnings, :metadata, :created_at, :updated_at
+		WHERE NOT EXISTS (
+			SELECT 1 FROM driver_shifts WHERE driver_id = :driver_id AND end_time IS NULL
+		)`
+
+	result, err := r.db.NamedExecContext(ctx, query, shift)
+	if err != nil {
+		r.logger.Error("Failed to create shift",
+			zap.Error(err),
+			zap.String("driver_id", shift.DriverID.String()),
+		)
+		return fmt.Errorf("failed to create shift: %w", err)
+	}
+
+	rowsAffected, err := result.RowsAffected()
+	if err != nil {
+		return fmt.Errorf("failed to get rows affected: %w", err)
+	}
+
+	if rowsAffected == 0 {
+		return entities.ErrShiftExists
+	}
+
+	return nil
+}
+
+// GetCurrent получает незавершенную (активную или приостановленную) смену водителя.
+// Если ее нет, возвращает ErrShiftNotFound.
+func (r *shiftRepository) GetCurrent(ctx context.Context, driverID uuid.UUID) (*entities.DriverShift, error) {
+	query := `
+		SELECT * FROM driver_shifts
+		WHERE driver_id = $1 AND end_time IS NULL AND status IN ('active', 'suspended')
+		ORDER BY start_time DESC
+		LIMIT 1`
+
+	var shift entities.DriverShift
+	err := r.db.GetContext(ctx, &shift, query, driverID)
+	if err != nil {
+		if err == sql.ErrNoRows {
+			return nil, entities.ErrShiftNotFound
+		}
+		r.logger.Error("Failed to get current shift",
+			zap.Error(err),
+			zap.String("driver_id", driverID.String()),
+		)
+		return nil, fmt.Errorf("failed to get current shift: %w", err)
+	}
+
+	return &shift, nil
+}
+
+// Update сохраняет статус, окончание и метаданные (перерывы) незавершенной смены.
+// Если смена уже завершена, возвращает ErrShiftAlreadyEnded.
+func (r *shiftRepository) Update(ctx context.Context, shift *entities.DriverShift) error {
+	query := `
+		UPDATE driver_shifts SET
+			status = :status, end_time = :end_time, end_latitude = :end_latitude, end_longitude = :end_longitude,
+			metadata = :metadata, updated_at = :updated_at
+		WHERE id = :id AND end_time IS NULL`
+
+	result, err := r.db.NamedExecContext(ctx, query, shift)
+	if err != nil {
+		r.logger.Error("Failed to update shift",
+			zap.Error(err),
+			zap.String("shift_id", shift.ID.String()),
+		)
+		return fmt.Errorf("failed to update shift: %w", err)
+	}
+
+	rowsAffected, err := result.RowsAffected()
+	if err != nil {
+		return fmt.Errorf("failed to get rows affected: %w", err)
+	}
+
+	if rowsAffected == 0 {
+		return entities.ErrShiftAlreadyEnded
+	}
+
+	return nil
+}
+
+// GetStaleActive получает незакрытые смены (активные и приостановленные), начатые раньше startedBefore
+func (r *shiftRepository) GetStaleActive(ctx context.Context, startedBefore time.Time) ([]*entities.DriverShift, error) {
+	query := `
+		SELECT * FROM driver_shifts
+		WHERE status IN ('active', 'suspended') AND end_time IS NULL AND start_time < $1
+		ORDER BY start_time`
+
+	var shifts []*entities.DriverShift
+	err := r.db.SelectContext(ctx, &shifts, query, startedBefore)
+	if err != nil {
+		r.logger.Error("Failed to get stale shifts",
+			zap.Error(err),
+			zap.Time("started_before", startedBefore),
+		)
+		return nil, fmt.Errorf("failed to get stale shifts: %w", err)
+	}
+
+	return shifts, nil
+}
+
+// Complete закрывает активную или приостановленную смену временем endTime, сохраняя итоги смены. Текущий
+// перерыв закрывается моментом endTime, как в DriverShift.End. Если смена уже закрыта, возвращает ErrShiftNotActive.
+func (r *shiftRepository) Complete(ctx context.Context, id uuid.UUID, endTime time.Time) error {
+	query := `
+		UPDATE driver_shifts
+		SET status = 'completed', end_time = $1, updated_at = $2,
+			metadata = CASE
+				WHEN status = 'suspended' AND metadata->>'paused_at' IS NOT NULL THEN
+					(metadata - 'paused_at') || jsonb_build_object('break_seconds',
+						COALESCE((metadata->>'break_seconds')::float8, 0) +
+						GREATEST(0, EXTRACT(EPOCH FROM $1::timestamptz - (metadata->>'paused_at')::timestamptz)::float8))
+				ELSE metadata
+			END
+		WHERE id = $3 AND status IN ('active', 'suspended') AND end_time IS NULL`
+
+	result, err := r.db.ExecContext(ctx, query, endTime, time.Now(), id)
+	if err != nil {
+		r.logger.Error("Failed to complete shift",
+			zap.Error(err),
+			zap.String("shift_id", id.String()),
+		)
+		return fmt.Errorf("failed to complete shift: %w", err)
+	}
+
+	rowsAffected, err := result.RowsAffected()
+	if err != nil {
+		return fmt.Errorf("failed to get rows affected: %w", err)
+	}
+
+	if rowsAffected == 0 {
+		return entities.ErrShiftNotActive
+	}
+
+	return nil
+}
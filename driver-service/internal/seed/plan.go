@@ -0,0 +1,241 @@
+// Package seed заполняет базу историей за месяцы работы парка: водители, смены, треки
+// местоположений и оценки. Строки загружаются через COPY, поэтому миллионы местоположений
+// загружаются за минуты, а не за часы построчных INSERT.
+package seed
+
+import (
+	"fmt"
+	"math"
+	"math/rand"
+	"time"
+
+	"driver-service/internal/domain/entities"
+
+	"github.com/google/uuid"
+)
+
+// Центр города, вокруг которого строятся треки, и радиус, за который водители не уезжают
+const (
+	centerLatitude  = 55.7558
+	centerLongitude = 37.6173
+	cityRadiusKm    = 30.0
+)
+
+// Options параметры генерируемой истории
+type Options struct {
+	Drivers          int           // количество водителей
+	Days             int           // глубина истории в днях до Now
+	LocationInterval time.Duration // интервал между точками трека во время смены
+	PhonePrefix      string        // префикс телефонов водителей, по нему же удаляются прошлые данные
+	Seed             int64         // одинаковый Seed дает одинаковые данные
+	Now              time.Time     // конец истории; смены, не закончившиеся к Now, не создаются
+}
+
+// DefaultOptions около 1000 водителей за 90 дней: ~60 тысяч смен, ~6 миллионов местоположений
+// и ~250 тысяч оценок
+func DefaultOptions() Options {
+	return Options{
+		Drivers:          1000,
+		Days:             90,
+		LocationInterval: 5 * time.Minute,
+		PhonePrefix:      "+7950",
+		Seed:             1,
+		Now:              time.Now().UTC(),
+	}
+}
+
+// Validate проверяет параметры
+func (o Options) Validate() error {
+	switch {
+	case o.Drivers <= 0 || o.Drivers > 9999999:
+		return fmt.Errorf("drivers must be between 1 and 9999999, got %d", o.Drivers)
+	case o.Days <= 0:
+		return fmt.Errorf("days must be positive, got %d", o.Days)
+	case o.LocationInterval < time.Second:
+		return fmt.Errorf("location interval must be at least 1s, got %s", o.LocationInterval)
+	case len(o.PhonePrefix)+7 > 20:
+		return fmt.Errorf("phone prefix %q is too long", o.PhonePrefix)
+	case o.Now.IsZero():
+		return fmt.Errorf("now is not set")
+	}
+	return nil
+}
+
+// driverRow водитель; total_trips совпадает с суммой поездок его смен
+type driverRow struct {
+	id         uuid.UUID
+	index      int
+	homeLat    float64
+	homeLon    float64
+	totalTrips int
+	createdAt  time.Time
+}
+
+// shiftRow закрытая смена водителя
+type shiftRow struct {
+	id        uuid.UUID
+	driverID  uuid.UUID
+	seed      int64 // seed трека и оценок смены
+	startTime time.Time
+	endTime   time.Time
+	startLat  float64
+	startLon  float64
+	trips     int
+	earnings  float64
+}
+
+// locationRow точка трека
+type locationRow struct {
+	latitude   float64
+	longitude  float64
+	accuracy   float64
+	speed      float64 // км/ч
+	bearing    float64
+	recordedAt time.Time
+}
+
+// ratingRow оценка поездки смены
+type ratingRow struct {
+	orderID    uuid.UUID
+	customerID uuid.UUID
+	rating     int
+	createdAt  time.Time
+}
+
+// plan детерминированно строит данные по Options. Каждый проход загрузки строит строки своей таблицы
+// заново, поэтому в памяти одновременно находятся только смены одного водителя.
+type plan struct {
+	opts Options
+}
+
+// driver строит водителя index вместе с его сменами
+func (p plan) driver(index int) (driverRow, []shiftRow) {
+	rng := rand.New(rand.NewSource(p.opts.Seed*1000003 + int64(index)))
+
+	driver := driverRow{index: index, id: newUUID(rng)}
+	driver.homeLat, driver.homeLon = offset(centerLatitude, centerLongitude, rng.Float64()*360, rng.Float64()*cityRadiusKm/2)
+
+	firstDay := p.opts.Now.Truncate(24*time.Hour).AddDate(0, 0, -p.opts.Days)
+	driver.createdAt = firstDay.Add(-time.Duration(rng.Intn(30*24)) * time.Hour)
+
+	var shifts []shiftRow
+	for day := 0; day <= p.opts.Days; day++ {
+		// Примерно два выходных в неделю
+		if rng.Intn(7) < 2 {
+			continue
+		}
+
+		start := firstDay.AddDate(0, 0, day).
+			Add(time.Duration(6*60+rng.Intn(8*60)) * time.Minute)
+		end := start.Add(time.Duration(6*60+rng.Intn(4*60)) * time.Minute)
+		if end.After(p.opts.Now) {
+			break
+		}
+
+		shift := shiftRow{
+			id:        newUUID(rng),
+			driverID:  driver.id,
+			seed:      rng.Int63(),
+			startTime: start,
+			endTime:   end,
+			trips:     5 + rng.Intn(16),
+		}
+		shift.startLat, shift.startLon = offset(driver.homeLat, driver.homeLon, rng.Float64()*360, rng.Float64()*2)
+		shift.earnings = float64(shift.trips) * (300 + float64(rng.Intn(400)))
+		driver.totalTrips += shift.trips
+		shifts = append(shifts, shift)
+	}
+
+	return driver, shifts
+}
+
+// track передает visit точки трека смены и возвращает последнюю точку и пройденное расстояние в км
+func (p plan) track(shift shiftRow, visit func(locationRow)) (locationRow, float64) {
+	rng := rand.New(rand.NewSource(shift.seed))
+
+	point := locationRow{latitude: shift.startLat, longitude: shift.startLon, bearing: rng.Float64() * 360}
+	distance := 0.0
+	for at := shift.startTime; !at.After(shift.endTime); at = at.Add(p.opts.LocationInterval) {
+		// Водитель то стоит в пробке или ждет пассажира, то едет, плавно поворачивая
+		if rng.Intn(4) == 0 {
+			point.speed = 0
+		} else {
+			point.speed = 10 + rng.Float64()*60
+		}
+		point.bearing = math.Mod(point.bearing+rng.NormFloat64()*30+360, 360)
+
+		// У границы города разворачиваемся к центру
+		here := &entities.DriverLocation{Latitude: point.latitude, Longitude: point.longitude}
+		if here.DistanceTo(&entities.DriverLocation{Latitude: centerLatitude, Longitude: centerLongitude}) > cityRadiusKm {
+			point.bearing = bearingTo(point.latitude, point.longitude, centerLatitude, centerLongitude)
+		}
+
+		if at.After(shift.startTime) {
+			step := point.speed * p.opts.LocationInterval.Hours()
+			point.latitude, point.longitude = offset(point.latitude, point.longitude, point.bearing, step)
+			distance += step
+		}
+		point.accuracy = 3 + rng.Float64()*17
+		point.recordedAt = at.Add(time.Duration(rng.Intn(10)) * time.Second)
+		visit(point)
+	}
+
+	return point, distance
+}
+
+// ratings передает visit оценки поездок смены: оценивают примерно треть поездок
+func (p plan) ratings(shift shiftRow, visit func(ratingRow)) {
+	rng := rand.New(rand.NewSource(shift.seed ^ 0x5eed))
+
+	duration := shift.endTime.Sub(shift.startTime)
+	for trip := 0; trip < shift.trips; trip++ {
+		if rng.Intn(3) != 0 {
+			continue
+		}
+
+		rating := 5
+		switch roll := rng.Intn(100); {
+		case roll < 3:
+			rating = 1 + rng.Intn(2)
+		case roll < 15:
+			rating = 3
+		case roll < 40:
+			rating = 4
+		}
+
+		visit(ratingRow{
+			orderID:    newUUID(rng),
+			customerID: newUUID(rng),
+			rating:     rating,
+			createdAt:  shift.startTime.Add(duration * time.Duration(trip+1) / time.Duration(shift.trips+1)),
+		})
+	}
+}
+
+// phone телефон водителя index
+func (p plan) phone(index int) string {
+	return fmt.Sprintf("%s%07d", p.opts.PhonePrefix, index)
+}
+
+// newUUID UUID из генератора rng, чтобы данные повторялись при одинаковом Seed
+func newUUID(rng *rand.Rand) uuid.UUID {
+	id, _ := uuid.NewRandomFromReader(rng)
+	return id
+}
+
+// offset смещает точку на distanceKm в направлении bearing (градусы от севера)
+func offset(lat, lon, bearing, distanceKm float64) (float64, float64) {
+	const kmPerDegree = 111.32
+
+	rad := bearing * math.Pi / 180
+	lat += distanceKm * math.Cos(rad) / kmPerDegree
+	lon += distanceKm * math.Sin(rad) / (kmPerDegree * math.Cos(lat*math.Pi/180))
+	return lat, lon
+}
+
+// bearingTo направление из точки (lat, lon) на (toLat, toLon) в градусах от севера
+func bearingTo(lat, lon, toLat, toLon float64) float64 {
+	dy := toLat - lat
+	dx := (toLon - lon) * math.Cos(lat*math.Pi/180)
+	return math.Mod(math.Atan2(dx, dy)*180/math.Pi+360, 360)
+}
@@ -0,0 +1,131 @@
+package seed
+
+import (
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func testOptions() Options {
+	opts := DefaultOptions()
+	opts.Drivers = 20
+	opts.Days = 60
+	opts.Now = time.Date(2024, 3, 15, 12, 0, 0, 0, time.UTC)
+	return opts
+}
+
+func TestPlanIsDeterministic(t *testing.T) {
+	// Arrange
+	p := plan{opts: testOptions()}
+
+	// Act
+	first, firstShifts := p.driver(7)
+	second, secondShifts := p.driver(7)
+	other, _ := p.driver(8)
+
+	// Assert
+	assert.Equal(t, first, second)
+	assert.Equal(t, firstShifts, secondShifts)
+	assert.NotEqual(t, first.id, other.id)
+}
+
+func TestPlanCoversHistory(t *testing.T) {
+	// Arrange
+	opts := testOptions()
+	p := plan{opts: opts}
+
+	for i := 0; i < opts.Drivers; i++ {
+		// Act
+		driver, shifts := p.driver(i)
+
+		// Assert
+		require.NotEmpty(t, shifts)
+		assert.True(t, shifts[0].startTime.Before(opts.Now.AddDate(0, 0, -opts.Days+7)), "История должна начинаться в первую неделю")
+		trips := 0
+		for j, shift := range shifts {
+			assert.True(t, shift.endTime.After(shift.startTime))
+			assert.False(t, shift.endTime.After(opts.Now), "Смена не должна заканчиваться после Now")
+			if j > 0 {
+				assert.True(t, shift.startTime.After(shifts[j-1].endTime), "Смены не должны пересекаться")
+			}
+			trips += shift.trips
+		}
+		assert.Equal(t, trips, driver.totalTrips)
+	}
+}
+
+func TestTrackStaysInCity(t *testing.T) {
+	// Arrange
+	opts := testOptions()
+	p := plan{opts: opts}
+	_, shifts := p.driver(3)
+	center := &entities.DriverLocation{Latitude: centerLatitude, Longitude: centerLongitude}
+
+	for _, shift := range shifts {
+		points := 0
+		var previous time.Time
+
+		// Act
+		last, distance := p.track(shift, func(point locationRow) {
+			points++
+			location := &entities.DriverLocation{Latitude: point.latitude, Longitude: point.longitude}
+			assert.Less(t, location.DistanceTo(center), cityRadiusKm+10)
+			assert.GreaterOrEqual(t, point.bearing, 0.0)
+			assert.Less(t, point.bearing, 360.0)
+			assert.True(t, point.recordedAt.After(previous))
+			previous = point.recordedAt
+		})
+
+		// Assert
+		expected := int(shift.endTime.Sub(shift.startTime)/opts.LocationInterval) + 1
+		assert.Equal(t, expected, points)
+		assert.Greater(t, distance, 0.0)
+		assert.False(t, last.recordedAt.Before(shift.endTime.Add(-opts.LocationInterval)))
+	}
+}
+
+func TestRatingsWithinShift(t *testing.T) {
+	// Arrange
+	p := plan{opts: testOptions()}
+	_, shifts := p.driver(5)
+
+	total := 0
+	for _, shift := range shifts {
+		// Act
+		p.ratings(shift, func(rating ratingRow) {
+			total++
+
+			// Assert
+			assert.GreaterOrEqual(t, rating.rating, 1)
+			assert.LessOrEqual(t, rating.rating, 5)
+			assert.True(t, rating.createdAt.After(shift.startTime))
+			assert.True(t, rating.createdAt.Before(shift.endTime))
+		})
+	}
+	assert.Positive(t, total)
+}
+
+func TestOptionsValidate(t *testing.T) {
+	tests := []struct {
+		name   string
+		modify func(*Options)
+	}{
+		{"no drivers", func(o *Options) { o.Drivers = 0 }},
+		{"no days", func(o *Options) { o.Days = 0 }},
+		{"too frequent locations", func(o *Options) { o.LocationInterval = time.Millisecond }},
+		{"long phone prefix", func(o *Options) { o.PhonePrefix = "+7950123456789" }},
+	}
+
+	assert.NoError(t, testOptions().Validate())
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			opts := testOptions()
+			tt.modify(&opts)
+			assert.Error(t, opts.Validate())
+		})
+	}
+}
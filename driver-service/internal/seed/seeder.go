@@ -0,0 +1,249 @@
+package seed
+
+import (
+	"context"
+	"database/sql"
+	"fmt"
+	"math"
+	"strings"
+	"time"
+
+	"github.com/jmoiron/sqlx"
+	"github.com/lib/pq"
+	"go.uber.org/zap"
+)
+
+// Result количество загруженных строк
+type Result struct {
+	Drivers   int
+	Shifts    int
+	Locations int
+	Ratings   int
+	Duration  time.Duration
+}
+
+// Seeder загружает историю, построенную по Options, через COPY
+type Seeder struct {
+	db     *sqlx.DB
+	logger *zap.Logger
+}
+
+// NewSeeder создает загрузчик
+func NewSeeder(db *sqlx.DB, logger *zap.Logger) *Seeder {
+	return &Seeder{db: db, logger: logger}
+}
+
+// Clear удаляет водителей с телефонами prefix вместе с их сменами, местоположениями и оценками
+func (s *Seeder) Clear(ctx context.Context, prefix string) (int64, error) {
+	result, err := s.db.ExecContext(ctx, `DELETE FROM drivers WHERE phone LIKE $1 || '%'`, prefix)
+	if err != nil {
+		return 0, fmt.Errorf("failed to clear seeded drivers: %w", err)
+	}
+	return result.RowsAffected()
+}
+
+// Seed загружает водителей, смены, местоположения и оценки и обновляет статистику планировщика.
+// Каждая таблица загружается в своей транзакции: прерванная загрузка оставляет водителей без части
+// истории, поэтому перед повторной загрузкой их удаляют через Clear.
+func (s *Seeder) Seed(ctx context.Context, opts Options) (Result, error) {
+	if err := opts.Validate(); err != nil {
+		return Result{}, err
+	}
+
+	started := time.Now()
+	p := plan{opts: opts}
+	var result Result
+
+	steps := []struct {
+		table string
+		count *int
+		load  func(stmt *sql.Stmt) (int, error)
+	}{
+		{"drivers", &result.Drivers, p.copyDrivers},
+		{"driver_shifts", &result.Shifts, p.copyShifts},
+		{"driver_locations", &result.Locations, p.copyLocations},
+		{"driver_ratings", &result.Ratings, p.copyRatings},
+	}
+	for _, step := range steps {
+		stepStarted := time.Now()
+		count, err := s.copy(ctx, step.table, opts.PhonePrefix, step.load)
+		if err != nil {
+			return result, fmt.Errorf("failed to seed %s: %w", step.table, err)
+		}
+		*step.count = count
+		s.logger.Info("Table seeded",
+			zap.String("table", step.table),
+			zap.Int("rows", count),
+			zap.Duration("duration", time.Since(stepStarted)),
+		)
+	}
+
+	// Статистика планировщика нужна сразу: тесты планов запросов запускаются после загрузки
+	for _, step := range steps {
+		if _, err := s.db.ExecContext(ctx, "ANALYZE "+step.table); err != nil {
+			return result, fmt.Errorf("failed to analyze %s: %w", step.table, err)
+		}
+	}
+
+	result.Duration = time.Since(started)
+	return result, nil
+}
+
+// copy загружает строки load в table одной командой COPY. Пересчет статистики оценок триггером
+// на каждую строку отключается на время загрузки, статистика пересчитывается один раз
+// на водителя с телефоном prefix.
+func (s *Seeder) copy(ctx context.Context, table, prefix string, load func(stmt *sql.Stmt) (int, error)) (int, error) {
+	tx, err := s.db.BeginTx(ctx, nil)
+	if err != nil {
+		return 0, err
+	}
+	defer tx.Rollback()
+
+	if table == "driver_ratings" {
+		if _, err := tx.ExecContext(ctx, `ALTER TABLE driver_ratings DISABLE TRIGGER trigger_driver_rating_stats_update`); err != nil {
+			return 0, err
+		}
+	}
+
+	stmt, err := tx.PrepareContext(ctx, pq.CopyIn(table, copyColumns[table]...))
+	if err != nil {
+		return 0, err
+	}
+
+	count, err := load(stmt)
+	if err != nil {
+		stmt.Close()
+		return 0, err
+	}
+	// Пустой Exec завершает COPY
+	if _, err := stmt.ExecContext(ctx); err != nil {
+		stmt.Close()
+		return 0, err
+	}
+	if err := stmt.Close(); err != nil {
+		return 0, err
+	}
+
+	if table == "driver_ratings" {
+		if _, err := tx.ExecContext(ctx, `ALTER TABLE driver_ratings ENABLE TRIGGER trigger_driver_rating_stats_update`); err != nil {
+			return 0, err
+		}
+		if _, err := tx.ExecContext(ctx, `
+			SELECT update_driver_rating_stats(id) FROM drivers WHERE phone LIKE $1 || '%'`, prefix); err != nil {
+			return 0, err
+		}
+	}
+
+	return count, tx.Commit()
+}
+
+// copyColumns столбцы, загружаемые в каждую таблицу
+var copyColumns = map[string][]string{
+	"drivers": {
+		"id", "phone", "email", "first_name", "last_name", "birth_date",
+		"passport_series", "passport_number", "license_number", "license_expiry",
+		"status", "total_trips", "created_at", "updated_at",
+	},
+	"driver_shifts": {
+		"id", "driver_id", "start_time", "end_time", "status",
+		"start_latitude", "start_longitude", "end_latitude", "end_longitude",
+		"total_trips", "total_distance", "total_earnings", "created_at", "updated_at",
+	},
+	"driver_locations": {
+		"driver_id", "latitude", "longitude", "accuracy", "speed", "bearing", "recorded_at", "created_at",
+	},
+	"driver_ratings": {
+		"driver_id", "order_id", "customer_id", "rating", "rating_type", "is_verified", "created_at", "updated_at",
+	},
+}
+
+func (p plan) copyDrivers(stmt *sql.Stmt) (int, error) {
+	for i := 0; i < p.opts.Drivers; i++ {
+		driver, _ := p.driver(i)
+		phone := p.phone(i)
+		// Почта и права выводятся из телефона, чтобы загрузки с разными префиксами не пересекались
+		digits := strings.TrimPrefix(phone, "+")
+		if _, err := stmt.Exec(
+			driver.id, phone, "seed"+digits+"@example.com",
+			firstNames[i%len(firstNames)], lastNames[(i/len(firstNames))%len(lastNames)],
+			driver.createdAt.AddDate(-25-i%30, 0, 0).Format("2006-01-02"),
+			fmt.Sprintf("%04d", i%10000), fmt.Sprintf("%06d", i),
+			"SEED"+digits, p.opts.Now.AddDate(5, 0, 0).Format("2006-01-02"),
+			"available", driver.totalTrips, driver.createdAt, driver.createdAt,
+		); err != nil {
+			return 0, err
+		}
+	}
+	return p.opts.Drivers, nil
+}
+
+func (p plan) copyShifts(stmt *sql.Stmt) (int, error) {
+	count := 0
+	for i := 0; i < p.opts.Drivers; i++ {
+		_, shifts := p.driver(i)
+		for _, shift := range shifts {
+			end, distance := p.track(shift, func(locationRow) {})
+			if _, err := stmt.Exec(
+				shift.id, shift.driverID, shift.startTime, shift.endTime, "completed",
+				shift.startLat, shift.startLon, end.latitude, end.longitude,
+				shift.trips, round2(distance), round2(shift.earnings), shift.startTime, shift.endTime,
+			); err != nil {
+				return 0, err
+			}
+			count++
+		}
+	}
+	return count, nil
+}
+
+func (p plan) copyLocations(stmt *sql.Stmt) (int, error) {
+	count := 0
+	var err error
+	for i := 0; i < p.opts.Drivers && err == nil; i++ {
+		_, shifts := p.driver(i)
+		for _, shift := range shifts {
+			p.track(shift, func(point locationRow) {
+				if err != nil {
+					return
+				}
+				_, err = stmt.Exec(
+					shift.driverID, point.latitude, point.longitude,
+					round2(point.accuracy), round2(point.speed), math.Floor(point.bearing*100)/100,
+					point.recordedAt, point.recordedAt,
+				)
+				count++
+			})
+		}
+	}
+	return count, err
+}
+
+func (p plan) copyRatings(stmt *sql.Stmt) (int, error) {
+	count := 0
+	var err error
+	for i := 0; i < p.opts.Drivers && err == nil; i++ {
+		_, shifts := p.driver(i)
+		for _, shift := range shifts {
+			p.ratings(shift, func(rating ratingRow) {
+				if err != nil {
+					return
+				}
+				_, err = stmt.Exec(
+					shift.driverID, rating.orderID, rating.customerID, rating.rating,
+					"customer", true, rating.createdAt, rating.createdAt,
+				)
+				count++
+			})
+		}
+	}
+	return count, err
+}
+
+func round2(value float64) float64 {
+	return math.Round(value*100) / 100
+}
+
+var (
+	firstNames = []string{"Алексей", "Дмитрий", "Иван", "Сергей", "Андрей", "Михаил", "Николай", "Павел", "Ольга", "Анна"}
+	lastNames  = []string{"Иванов", "Смирнов", "Кузнецов", "Попов", "Васильев", "Петров", "Соколов", "Морозов", "Волков", "Лебедев"}
+)
@@ -1,6 +1,44 @@
 #!/bin/bash
 
 # Скрипт для запуска интеграционных тестов
+#
+# Использование:
+#   ./scripts/run-tests.sh                # полный прогон
+#   ./scripts/run-tests.sh --mode smoke   # smoke-проверка после деплоя (~60 секунд)
+#   ./scripts/run-tests.sh --mode flake-hunt --iterations 50 [--run REGEX] [--shuffle] [--seed N]
+#                                         # поиск нестабильных тестов повторными прогонами
+#   ./scripts/run-tests.sh --mode dr-drill --iterations 5 [--seed N]
+#                                         # учения по аварийному восстановлению с замером RPO/RTO
+#   ./scripts/run-tests.sh --resume       # продолжение прерванного полного прогона
+#
+# Полный прогон записывает прошедшие тесты и завершенные этапы в CHECKPOINT_FILE
+# (по умолчанию test-checkpoint/checkpoint.txt). С --resume завершенные этапы пропускаются, а в
+# прерванном этапе повторно запускаются только непрошедшие тесты верхнего уровня; итог объединяет
+# результаты обоих запусков. После успешного полного прогона checkpoint удаляется.
+#
+# В режиме smoke при заданном SMOKE_BASE_URL проверяется развернутый сервис (без Docker).
+# Заголовки для защищенных окружений задаются без изменения кода: TEST_DEFAULT_HEADERS="Имя: значение; ..."
+# добавляется ко всем запросам тестов, TEST_USER_AGENT переопределяет User-Agent.
+# Коды выхода режима smoke:
+#   0 - проверки пройдены
+#   1 - ошибка окружения или аргументов
+#   2 - функциональная ошибка
+#   3 - нарушены пороги SLO
+#   4 - превышен общий лимит времени
+#
+# В режиме flake-hunt выбранные тесты (--run, по умолчанию все интеграционные) прогоняются --iterations раз.
+# Каждая итерация получает свой seed (TEST_SEED, последовательно от --seed или случайный), с --shuffle
+# он же задает случайный порядок тестов (go test -shuffle). Отчет сохраняется в FLAKE_REPORT_DIR
+# (по умолчанию flake-report/<время>): доля падений по тестам, seed и снимок окружения упавших итераций,
+# логи упавших тестов (TEST_ARTIFACTS_DIR). Коды выхода: 0 - падений нет, 1 - ошибка окружения
+# или аргументов, 2 - есть упавшие итерации.
+#
+# В режиме dr-drill сценарий TestDisasterRecoveryTestSuite (отказ БД в случайный момент под нагрузкой и
+# восстановление из последнего дампа) прогоняется --iterations раз с разными TEST_SEED. Метрики каждой
+# итерации (rpo_ms, rto_ms, lost_writes) дописываются в DR_REPORT_DIR/metrics.txt (по умолчанию
+# dr-report/<время>), сводка с максимальными и средними RPO/RTO - в summary.txt. Требуются pg_dump
+# и pg_restore (TEST_PG_BIN_DIR). Коды выхода: 0 - все итерации прошли, 1 - ошибка окружения
+# или аргументов, 2 - есть упавшие итерации.
 
 set -e
 
@@ -23,6 +61,321 @@ error() {
     echo -e "${RED}[$(date +'%Y-%m-%d %H:%M:%S')] ERROR: $1${NC}"
 }
 
+# Разбираем аргументы
+MODE="full"
+ITERATIONS=20
+RUN_PATTERN="."
+SHUFFLE="false"
+BASE_SEED=""
+RESUME="false"
+CHECKPOINT_FILE="${CHECKPOINT_FILE:-test-checkpoint/checkpoint.txt}"
+while [ $# -gt 0 ]; do
+    case "$1" in
+        --mode)
+            MODE="$2"
+            shift 2
+            ;;
+        --mode=*)
+            MODE="${1#*=}"
+            shift
+            ;;
+        --iterations)
+            ITERATIONS="$2"
+            shift 2
+            ;;
+        --run)
+            RUN_PATTERN="$2"
+            shift 2
+            ;;
+        --shuffle)
+            SHUFFLE="true"
+            shift
+            ;;
+        --seed)
+            BASE_SEED="$2"
+            shift 2
+            ;;
+        --resume)
+            RESUME="true"
+            shift
+            ;;
+        *)
+            error "Unknown argument: $1"
+            exit 1
+            ;;
+    esac
+done
+
+case "$MODE" in
+    full|smoke|flake-hunt|dr-drill) ;;
+    *)
+        error "Unknown mode: $MODE (expected full, smoke, flake-hunt or dr-drill)"
+        exit 1
+        ;;
+esac
+
+if ! [[ "$ITERATIONS" =~ ^[1-9][0-9]*$ ]]; then
+    error "--iterations must be a positive integer, got: $ITERATIONS"
+    exit 1
+fi
+
+if [ -n "$BASE_SEED" ] && ! [[ "$BASE_SEED" =~ ^[0-9]+$ ]]; then
+    error "--seed must be a non-negative integer, got: $BASE_SEED"
+    exit 1
+fi
+
+if [ "$RESUME" == "true" ] && [ "$MODE" != "full" ]; then
+    error "--resume is only supported in full mode"
+    exit 1
+fi
+
+# Функция запуска этапа полного прогона: прошедшие тесты верхнего уровня и завершение этапа записываются
+# в checkpoint, при продолжении прогона они пропускаются
+run_stage() {
+    local stage="$1"
+    shift
+
+    if grep -qx "done $stage" "$CHECKPOINT_FILE" 2>/dev/null; then
+        log "Stage $stage already completed before the interruption, skipping"
+        return 0
+    fi
+
+    local skip_args=()
+    local passed
+    passed=$(awk -v stage="$stage" '$1 == "pass" && $2 == stage { print $3 }' "$CHECKPOINT_FILE" 2>/dev/null | sort -u | paste -sd '|' -)
+    if [ -n "$passed" ]; then
+        log "Stage $stage: skipping tests passed before the interruption: ${passed//|/, }"
+        skip_args=(-skip "^(${passed})\$")
+    fi
+
+    set +e
+    go test "$@" "${skip_args[@]}" 2>&1 | awk -v stage="$stage" -v checkpoint="$CHECKPOINT_FILE" '
+        { print; fflush() }
+        /^--- PASS: / { print "pass " stage " " $3 >> checkpoint; fflush(checkpoint) }
+    '
+    local status=${PIPESTATUS[0]}
+    set -e
+
+    if [ $status -ne 0 ]; then
+        error "Stage $stage failed, rerun with --resume to continue from $CHECKPOINT_FILE"
+        exit $status
+    fi
+    echo "done $stage" >> "$CHECKPOINT_FILE"
+}
+
+# Функция вывода объединенного итога прогона по checkpoint
+print_checkpoint_summary() {
+    awk '
+        $1 == "pass" && !seen[$2 " " $3]++ { passed[$2]++ }
+        $1 == "done" { stages++ }
+        END {
+            for (stage in passed) printf "  %s: %d tests passed\n", stage, passed[stage]
+            printf "  stages completed: %d\n", stages
+        }
+    ' "$CHECKPOINT_FILE"
+}
+
+# Функция запуска smoke-проверки, возвращает код выхода режима smoke
+run_smoke() {
+    log "Running smoke tests..."
+
+    local output
+    output=$(mktemp)
+
+    set +e
+    go test -v -count=1 -tags=integration -timeout="${SMOKE_TIMEOUT:-90s}" \
+        -run='^TestSmokeTestSuite$' ./tests/integration/... 2>&1 | tee "$output"
+    local status=${PIPESTATUS[0]}
+    set -e
+
+    local code=0
+    if [ $status -ne 0 ]; then
+        if grep -q "panic: test timed out" "$output"; then
+            error "Smoke tests exceeded the time limit"
+            code=4
+        elif grep -q "SLO breach" "$output"; then
+            error "Smoke tests breached SLO thresholds"
+            code=3
+        else
+            error "Smoke tests failed"
+            code=2
+        fi
+    else
+        log "Smoke tests passed"
+    fi
+
+    rm -f "$output"
+    return $code
+}
+
+# Функция сохранения снимка окружения упавшей итерации flake-hunt
+write_env_snapshot() {
+    local file="$1" iteration="$2" seed="$3" shuffle_flag="$4"
+
+    {
+        echo "iteration=$iteration"
+        echo "seed=$seed"
+        echo "shuffle=$SHUFFLE"
+        echo "run=$RUN_PATTERN"
+        echo "date=$(date -u +'%Y-%m-%dT%H:%M:%SZ')"
+        echo "git_commit=$(git rev-parse HEAD 2>/dev/null || echo unknown)"
+        echo "go_version=$(go version)"
+        echo "reproduce=TEST_SEED=$seed go test -v -count=1 -tags=integration $shuffle_flag -run='$RUN_PATTERN' ./tests/integration/..."
+        # Значения TEST_DEFAULT_HEADERS могут содержать токены доступа, в снимок попадают только имена заголовков
+        env | grep -E '^(TEST_|GO)' | grep -v '^TEST_DEFAULT_HEADERS=' | sort
+        if [ -n "${TEST_DEFAULT_HEADERS}" ]; then
+            echo "TEST_DEFAULT_HEADERS=$(echo "$TEST_DEFAULT_HEADERS" | sed -E 's/:[^;]*/: <redacted>/g')"
+        fi
+    } > "$file"
+}
+
+# Функция поиска нестабильных тестов, возвращает код выхода режима flake-hunt
+run_flake_hunt() {
+    local report_dir="${FLAKE_REPORT_DIR:-flake-report/$(date +'%Y%m%d-%H%M%S')}"
+    mkdir -p "$report_dir"
+    : > "$report_dir/seeds.txt"
+
+    log "Flake hunt: $ITERATIONS iterations of '$RUN_PATTERN' (shuffle: $SHUFFLE), report in $report_dir"
+
+    local failed=0
+    local i
+    for i in $(seq 1 "$ITERATIONS"); do
+        local seed
+        if [ -n "$BASE_SEED" ]; then
+            seed=$((BASE_SEED + i - 1))
+        else
+            seed=$(( (RANDOM << 15) | RANDOM ))
+        fi
+        echo "$i $seed" >> "$report_dir/seeds.txt"
+
+        local shuffle_flag=""
+        if [ "$SHUFFLE" == "true" ]; then
+            shuffle_flag="-shuffle=$seed"
+        fi
+
+        set +e
+        TEST_SEED="$seed" TEST_RUN_ID="flake-$i-$seed" TEST_ARTIFACTS_DIR="$report_dir/artifacts" \
+            go test -json -count=1 -tags=integration -timeout="${FLAKE_TIMEOUT:-10m}" $shuffle_flag \
+            -run="$RUN_PATTERN" ./tests/integration/... > "$report_dir/iteration-$i.json" 2>&1
+        local status=$?
+        set -e
+
+        if [ $status -ne 0 ]; then
+            failed=$((failed + 1))
+            warn "Iteration $i/$ITERATIONS failed (seed $seed)"
+            write_env_snapshot "$report_dir/iteration-$i.env" "$i" "$seed" "$shuffle_flag"
+        else
+            log "Iteration $i/$ITERATIONS passed (seed $seed)"
+        fi
+    done
+
+    # Результаты тестов из go test -json: "<тест> <pass|fail> <итерация>"
+    local i_file
+    for i_file in "$report_dir"/iteration-*.json; do
+        local iteration="${i_file##*iteration-}"
+        iteration="${iteration%.json}"
+        grep -E '"Action":"(pass|fail)".*"Test":' "$i_file" \
+            | sed -E 's/.*"Action":"(pass|fail)".*"Test":"([^"]*)".*/\2 \1/' \
+            | sed "s/\$/ $iteration/" || true
+    done | awk '
+        NR == FNR { seed[$1] = $2; next }
+        {
+            runs[$1]++
+            if ($2 == "fail") {
+                fails[$1]++
+                seeds[$1] = seeds[$1] " " seed[$3]
+            }
+        }
+        END {
+            for (test in fails) {
+                printf "%6.1f%%  %d/%d  %s  seeds:%s\n", 100 * fails[test] / runs[test], fails[test], runs[test], test, seeds[test]
+            }
+        }
+    ' "$report_dir/seeds.txt" - | sort -rn > "$report_dir/summary.txt"
+
+    if [ $failed -eq 0 ]; then
+        log "Flake hunt finished: all $ITERATIONS iterations passed"
+        return 0
+    fi
+
+    error "Flake hunt finished: $failed of $ITERATIONS iterations failed"
+    echo "Failure rate  failed/runs  test  seeds"
+    cat "$report_dir/summary.txt"
+    log "Summary, environment snapshots and logs of failed tests: $report_dir"
+    return 2
+}
+
+# Функция учений по аварийному восстановлению: повторные прогоны сценария отказа и сводка RPO/RTO
+run_dr_drill() {
+    local report_dir="${DR_REPORT_DIR:-dr-report/$(date +'%Y%m%d-%H%M%S')}"
+    mkdir -p "$report_dir"
+    : > "$report_dir/metrics.txt"
+
+    log "DR drill: $ITERATIONS iterations, report in $report_dir"
+
+    local failed=0
+    local i
+    for i in $(seq 1 "$ITERATIONS"); do
+        local seed
+        if [ -n "$BASE_SEED" ]; then
+            seed=$((BASE_SEED + i - 1))
+        else
+            seed=$(( (RANDOM << 15) | RANDOM ))
+        fi
+
+        set +e
+        TEST_SEED="$seed" DR_REPORT="$report_dir/metrics.txt" \
+            go test -v -count=1 -tags=integration -timeout=5m \
+            -run='^TestDisasterRecoveryTestSuite$' ./tests/integration/... > "$report_dir/iteration-$i.log" 2>&1
+        local status=$?
+        set -e
+
+        if [ $status -ne 0 ]; then
+            failed=$((failed + 1))
+            warn "Iteration $i/$ITERATIONS failed (seed $seed), see $report_dir/iteration-$i.log"
+        else
+            log "Iteration $i/$ITERATIONS passed (seed $seed)"
+        fi
+    done
+
+    # Строки metrics.txt в формате logfmt: "... rpo_ms=<N> rto_ms=<N> ... lost_writes=<N>"
+    awk '
+        {
+            for (f = 1; f <= NF; f++) {
+                split($f, kv, "=")
+                value[kv[1]] = kv[2]
+            }
+            runs++
+            rpo_sum += value["rpo_ms"]; rto_sum += value["rto_ms"]; lost += value["lost_writes"]
+            if (value["rpo_ms"] + 0 > rpo_max) rpo_max = value["rpo_ms"] + 0
+            if (value["rto_ms"] + 0 > rto_max) rto_max = value["rto_ms"] + 0
+        }
+        END {
+            if (runs == 0) { print "no metrics recorded"; exit }
+            printf "runs=%d rpo_avg_ms=%d rpo_max_ms=%d rto_avg_ms=%d rto_max_ms=%d lost_writes=%d\n",
+                runs, rpo_sum / runs, rpo_max, rto_sum / runs, rto_max, lost
+        }
+    ' "$report_dir/metrics.txt" > "$report_dir/summary.txt"
+    log "DR drill metrics: $(cat "$report_dir/summary.txt")"
+
+    if [ $failed -eq 0 ]; then
+        log "DR drill finished: all $ITERATIONS iterations passed"
+        return 0
+    fi
+
+    error "DR drill finished: $failed of $ITERATIONS iterations failed"
+    return 2
+}
+
+# Smoke-проверка развернутого сервиса не требует тестового окружения
+if [ "$MODE" == "smoke" ] && [ -n "${SMOKE_BASE_URL}" ]; then
+    cd "$(dirname "$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)")"
+    log "Running smoke tests against ${SMOKE_BASE_URL}"
+    exit_code=0
+    run_smoke || exit_code=$?
+    exit $exit_code
+fi
+
 # Проверяем, что Docker доступен
 if ! command -v docker &> /dev/null; then
     error "Docker is not installed or not in PATH"
@@ -93,6 +446,37 @@ export TEST_DB_NAME=driver_service_test
 export TEST_REDIS_HOST=localhost
 export TEST_REDIS_PORT=6380
 
+if [ "$MODE" == "smoke" ]; then
+    exit_code=0
+    run_smoke || exit_code=$?
+    exit $exit_code
+fi
+
+if [ "$MODE" == "flake-hunt" ]; then
+    exit_code=0
+    run_flake_hunt || exit_code=$?
+    exit $exit_code
+fi
+
+if [ "$MODE" == "dr-drill" ]; then
+    exit_code=0
+    run_dr_drill || exit_code=$?
+    exit $exit_code
+fi
+
+# Начинаем новый checkpoint или продолжаем прерванный прогон
+mkdir -p "$(dirname "$CHECKPOINT_FILE")"
+if [ "$RESUME" == "true" ]; then
+    if [ -f "$CHECKPOINT_FILE" ]; then
+        log "Resuming interrupted run from $CHECKPOINT_FILE"
+    else
+        warn "Checkpoint $CHECKPOINT_FILE not found, starting a full run"
+    fi
+else
+    rm -f "$CHECKPOINT_FILE"
+fi
+touch "$CHECKPOINT_FILE"
+
 # Проверяем зависимости Go
 log "Checking Go dependencies..."
 go mod tidy
@@ -107,16 +491,20 @@ fi
 
 # Запускаем unit тесты
 log "Running unit tests..."
-go test -v -race -coverprofile=coverage.out ./internal/...
+# Примечание: при продолжении прогона coverage.out содержит покрытие только перезапущенных тестов
+run_stage unit -v -race -coverprofile=coverage.out ./internal/...
 
-# Запускаем интеграционные тесты
+# Запускаем интеграционные тесты (с отчетами о покрытии эндпоинтов из api/openapi/driver-api.yaml
+# и типов событий из api/events/catalog.yaml)
 log "Running integration tests..."
-go test -v -race -tags=integration -timeout=10m ./tests/integration/...
+ENDPOINT_COVERAGE=endpoint-coverage.txt EVENT_COVERAGE=event-coverage.txt \
+    run_stage integration -v -race -tags=integration -timeout=10m ./tests/integration/...
+log "Coverage reports: endpoint-coverage.txt, event-coverage.txt"
 
 # Запускаем performance тесты (если не в быстром режиме)
 if [ "${SKIP_PERFORMANCE_TESTS}" != "true" ]; then
     log "Running performance tests..."
-    go test -v -tags=integration -timeout=15m -run="Performance" ./tests/integration/...
+    run_stage performance -v -tags=integration -timeout=15m -run="Performance" ./tests/integration/...
 else
     warn "Skipping performance tests (SKIP_PERFORMANCE_TESTS=true)"
 fi
@@ -163,6 +551,8 @@ ORDER BY tablename, attname;
 " || warn "Could not get database statistics"
 
 log "All tests completed successfully!"
+print_checkpoint_summary
+rm -f "$CHECKPOINT_FILE"
 
 # Опционально: отправляем результаты в систему мониторинга
 if [ "${SEND_TEST_RESULTS}" == "true" ]; then
@@ -0,0 +1,98 @@
+//go:build integration
+
+// Package benchmarks содержит микробенчмарки горячих путей работы с БД на тестовой базе данных.
+// Результаты в формате go test -bench сравниваются между прогонами утилитой benchstat (make bench-db).
+package benchmarks
+
+import (
+	"context"
+	"fmt"
+	"math/rand"
+	"testing"
+
+	"driver-service/internal/repositories"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"go.uber.org/zap"
+)
+
+// nearbyDataset набор данных для поиска поблизости: 200 водителей по 250 точек
+func nearbyDataset() helpers.LargeDataset {
+	dataset := helpers.DefaultLargeDataset()
+	dataset.Drivers = 200
+	return dataset
+}
+
+// BenchmarkDatabase измеряет создание водителя, запись местоположения и поиск поблизости через репозитории.
+// Подбенчмарки используют одну тестовую БД: функция верхнего уровня выполняется один раз.
+func BenchmarkDatabase(b *testing.B) {
+	tdb := helpers.SetupTestDB(b)
+	defer tdb.TeardownTestDB(b)
+
+	ctx := context.Background()
+	// Логи репозиториев не должны влиять на замеры
+	logger := zap.NewNop()
+	driverRepo := repositories.NewDriverRepository(tdb.DB, logger)
+	locationRepo := repositories.NewLocationRepository(tdb.DB, logger)
+
+	b.Run("CreateDriver", func(b *testing.B) {
+		tdb.CleanupTables(b)
+		b.ReportAllocs()
+		b.ResetTimer()
+
+		for i := 0; i < b.N; i++ {
+			driver := fixtures.CreateTestDriver()
+			driver.Phone = fmt.Sprintf("+7902%07d", i)
+			driver.Email = fmt.Sprintf("bench.driver%d@example.com", i)
+			driver.LicenseNumber = fmt.Sprintf("BENCH%07d", i)
+
+			if err := driverRepo.Create(ctx, driver); err != nil {
+				b.Fatalf("Failed to create driver: %v", err)
+			}
+		}
+	})
+
+	b.Run("InsertLocation", func(b *testing.B) {
+		tdb.CleanupTables(b)
+		driver := fixtures.CreateTestDriver()
+		if err := driverRepo.Create(ctx, driver); err != nil {
+			b.Fatalf("Failed to create driver: %v", err)
+		}
+		b.ReportAllocs()
+		b.ResetTimer()
+
+		for i := 0; i < b.N; i++ {
+			location := fixtures.CreateTestLocationWithCoords(driver.ID,
+				55.7558+float64(i%1000)*0.0001, 37.6173+float64(i%1000)*0.0001)
+
+			if err := locationRepo.Create(ctx, location); err != nil {
+				b.Fatalf("Failed to insert location: %v", err)
+			}
+		}
+	})
+
+	tdb.CleanupTables(b)
+	dataset := tdb.SeedLargeDataset(b, nearbyDataset(), 1712)
+
+	for _, radiusKm := range []float64{1, 3, 10} {
+		b.Run(fmt.Sprintf("Nearby/radius=%gkm", radiusKm), func(b *testing.B) {
+			rng := rand.New(rand.NewSource(1712))
+			b.ReportAllocs()
+			b.ResetTimer()
+
+			found := 0
+			for i := 0; i < b.N; i++ {
+				latitude := dataset.Latitude + (rng.Float64()-0.5)*0.05
+				longitude := dataset.Longitude + (rng.Float64()-0.5)*0.08
+
+				locations, err := locationRepo.GetNearby(ctx, latitude, longitude, radiusKm, 50)
+				if err != nil {
+					b.Fatalf("Failed to search nearby drivers: %v", err)
+				}
+				found += len(locations)
+			}
+			b.ReportMetric(float64(found)/float64(b.N), "drivers/op")
+		})
+	}
+}
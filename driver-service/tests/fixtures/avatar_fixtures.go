@@ -0,0 +1,108 @@
+//go:build integration
+
+package fixtures
+
+import (
+	"bytes"
+	"encoding/binary"
+	"hash/crc32"
+	"image"
+	"image/color"
+	"image/jpeg"
+	"image/png"
+)
+
+// AvatarGPSTag координаты съемки в EXIF фотографий из фикстур: после загрузки их не должно быть в файле
+const AvatarGPSTag = "GPSLatitude=55.7558N GPSLongitude=37.6173E"
+
+// AvatarDeviceTag модель телефона в EXIF фотографий из фикстур
+const AvatarDeviceTag = "Model=Pixel 7 Pro"
+
+// avatarEXIF содержимое сегмента APP1 / чанка eXIf фотографий из фикстур
+const avatarEXIF = "Exif\x00\x00" + AvatarGPSTag + "\x00" + AvatarDeviceTag
+
+// jpegMaxSegment максимальный размер сегмента JPEG вместе с маркером (2 байта) и длиной (до 65535)
+const jpegMaxSegment = 2 + 0xFFFF
+
+// avatarImage возвращает градиент width x height: фотография, которую водитель снял на телефон
+func avatarImage(width, height int) image.Image {
+	img := image.NewRGBA(image.Rect(0, 0, width, height))
+	for x := 0; x < width; x++ {
+		for y := 0; y < height; y++ {
+			img.Set(x, y, color.RGBA{R: uint8(x * 255 / width), G: uint8(y * 255 / height), B: 128, A: 255})
+		}
+	}
+	return img
+}
+
+// CreateAvatarJPEG создает JPEG фотографию с EXIF (координаты съемки и модель телефона)
+func CreateAvatarJPEG(width, height int) []byte {
+	var encoded bytes.Buffer
+	if err := jpeg.Encode(&encoded, avatarImage(width, height), &jpeg.Options{Quality: 90}); err != nil {
+		panic(err)
+	}
+
+	content := append([]byte{}, encoded.Bytes()[:2]...)
+	content = append(content, jpegSegment(0xE1, []byte(avatarEXIF))...)
+	return append(content, encoded.Bytes()[2:]...)
+}
+
+// CreateAvatarPNG создает PNG фотографию с чанком eXIf
+func CreateAvatarPNG(width, height int) []byte {
+	var encoded bytes.Buffer
+	if err := png.Encode(&encoded, avatarImage(width, height)); err != nil {
+		panic(err)
+	}
+
+	// Сигнатура (8 байт) и IHDR (25 байт), за ними метаданные
+	ihdrEnd := 8 + 25
+	content := append([]byte{}, encoded.Bytes()[:ihdrEnd]...)
+	content = append(content, pngChunk("eXIf", []byte(avatarEXIF))...)
+	return append(content, encoded.Bytes()[ihdrEnd:]...)
+}
+
+// PadAvatarJPEG дополняет JPEG комментариями (сегменты COM) до размера size байт, не меняя изображение.
+// size должен быть хотя бы на 4 байта больше исходного файла.
+func PadAvatarJPEG(content []byte, size int) []byte {
+	padding := make([]byte, 0, size-len(content))
+	for remaining := size - len(content); remaining > 0; {
+		segment := min(remaining, jpegMaxSegment)
+		// Остаток меньше пустого сегмента (4 байта) не уместить: переносим его в следующий сегмент
+		if rest := remaining - segment; rest > 0 && rest < 4 {
+			segment -= 4
+		}
+		padding = append(padding, jpegSegment(0xFE, bytes.Repeat([]byte{'x'}, segment-4))...)
+		remaining -= segment
+	}
+
+	padded := append([]byte{}, content[:2]...)
+	padded = append(padded, padding...)
+	return append(padded, content[2:]...)
+}
+
+// CreateTruncatedJPEG создает файл, который начинается как JPEG, но обрывается в первом сегменте
+func CreateTruncatedJPEG() []byte {
+	return CreateAvatarJPEG(32, 32)[:64]
+}
+
+// CreateAvatarGIF создает минимальный GIF 1x1: тип изображения, который сервис не принимает
+func CreateAvatarGIF() []byte {
+	return []byte("GIF89a\x01\x00\x01\x00\x80\x00\x00\xff\xff\xff\x00\x00\x00!\xf9\x04\x01\x00\x00\x00\x00" +
+		",\x00\x00\x00\x00\x01\x00\x01\x00\x00\x02\x02D\x01\x00;")
+}
+
+// jpegSegment возвращает сегмент JPEG с маркером 0xFF marker
+func jpegSegment(marker byte, payload []byte) []byte {
+	segment := []byte{0xFF, marker, 0, 0}
+	binary.BigEndian.PutUint16(segment[2:], uint16(len(payload)+2))
+	return append(segment, payload...)
+}
+
+// pngChunk возвращает чанк PNG с контрольной суммой
+func pngChunk(chunkType string, data []byte) []byte {
+	chunk := make([]byte, 8, 12+len(data))
+	binary.BigEndian.PutUint32(chunk, uint32(len(data)))
+	copy(chunk[4:], chunkType)
+	chunk = append(chunk, data...)
+	return binary.BigEndian.AppendUint32(chunk, crc32.ChecksumIEEE(chunk[4:]))
+}
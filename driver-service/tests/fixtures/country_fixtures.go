@@ -0,0 +1,99 @@
+//go:build integration
+
+package fixtures
+
+import (
+	"driver-service/internal/domain/entities"
+)
+
+// CountryDocuments образцы документов водителя из страны: телефон в формате страны
+// (к PhonePrefix дописываются цифры до длины номера) и паспорт и ВУ, проходящие проверку формата
+type CountryDocuments struct {
+	Country        entities.Country
+	PhonePrefix    string
+	PhoneDigits    int // цифр после PhonePrefix
+	PassportSeries string
+	PassportNumber string
+	LicenseNumber  string
+
+	// Invalid документы, нарушающие формат страны
+	Invalid []InvalidDocument
+}
+
+// InvalidDocument документ с нарушенным форматом и ожидаемая ошибка проверки
+type InvalidDocument struct {
+	Name  string
+	Field string // поле запроса регистрации: passport_series, passport_number или license_number
+	Value string
+	Err   error
+}
+
+// InternationalDocuments возвращает образцы документов всех поддерживаемых стран
+func InternationalDocuments() []CountryDocuments {
+	return []CountryDocuments{
+		{
+			Country:        entities.CountryRU,
+			PhonePrefix:    "+7916",
+			PhoneDigits:    7,
+			PassportSeries: "45 08",
+			PassportNumber: "123456",
+			LicenseNumber:  "77 AB 123456",
+			Invalid: []InvalidDocument{
+				{Name: "short passport number", Field: "passport_number", Value: "12345", Err: entities.ErrInvalidPassport},
+				{Name: "letters in series", Field: "passport_series", Value: "MP", Err: entities.ErrInvalidPassport},
+				{Name: "short license", Field: "license_number", Value: "77AB1234", Err: entities.ErrInvalidLicense},
+			},
+		},
+		{
+			Country:        entities.CountryBY,
+			PhonePrefix:    "+37529",
+			PhoneDigits:    7,
+			PassportSeries: "MP",
+			PassportNumber: "1234567",
+			LicenseNumber:  "5AB 123456",
+			Invalid: []InvalidDocument{
+				{Name: "russian series", Field: "passport_series", Value: "4508", Err: entities.ErrInvalidPassport},
+				{Name: "six digit number", Field: "passport_number", Value: "123456", Err: entities.ErrInvalidPassport},
+				{Name: "license without category digit", Field: "license_number", Value: "AB123456", Err: entities.ErrInvalidLicense},
+			},
+		},
+		{
+			Country:        entities.CountryKZ,
+			PhonePrefix:    "+7701",
+			PhoneDigits:    7,
+			PassportSeries: "N",
+			PassportNumber: "12345678",
+			LicenseNumber:  "AB123456",
+			Invalid: []InvalidDocument{
+				{Name: "unknown series", Field: "passport_series", Value: "K", Err: entities.ErrInvalidPassport},
+				{Name: "seven digit number", Field: "passport_number", Value: "1234567", Err: entities.ErrInvalidPassport},
+				{Name: "digits only license", Field: "license_number", Value: "12345678", Err: entities.ErrInvalidLicense},
+			},
+		},
+		{
+			Country:        entities.CountryUZ,
+			PhonePrefix:    "+99890",
+			PhoneDigits:    7,
+			PassportSeries: "AA",
+			PassportNumber: "1234567",
+			LicenseNumber:  "AF1234567",
+			Invalid: []InvalidDocument{
+				{Name: "cyrillic series", Field: "passport_series", Value: "АА", Err: entities.ErrInvalidPassport},
+				{Name: "eight digit number", Field: "passport_number", Value: "12345678", Err: entities.ErrInvalidPassport},
+				{Name: "six digit license", Field: "license_number", Value: "AF123456", Err: entities.ErrInvalidLicense},
+			},
+		},
+		{
+			Country:        entities.CountryKG,
+			PhonePrefix:    "+996555",
+			PhoneDigits:    6,
+			PassportSeries: "ID",
+			PassportNumber: "1234567",
+			LicenseNumber:  "KGZ123456",
+			Invalid: []InvalidDocument{
+				{Name: "unknown series", Field: "passport_series", Value: "AN", Err: entities.ErrInvalidPassport},
+				{Name: "two letter license", Field: "license_number", Value: "KG1234567", Err: entities.ErrInvalidLicense},
+			},
+		},
+	}
+}
@@ -0,0 +1,21 @@
+//go:build integration
+
+package fixtures
+
+import "fmt"
+
+// EICARSignature стандартная тестовая сигнатура EICAR: безопасна, но любой антивирус считает ее вирусом
+const EICARSignature = `X5O!P%@AP[4\PZX54(P^)7CC)7}$EICAR-STANDARD-ANTIVIRUS-TEST-FILE!$H+H*`
+
+// DocumentContentType тип содержимого файлов документов в тестах
+const DocumentContentType = "application/pdf"
+
+// CreateTestDocumentFile создает минимальный PDF файл скана документа с заданным номером
+func CreateTestDocumentFile(documentNumber string) []byte {
+	return []byte(fmt.Sprintf("%%PDF-1.4\n%% Скан документа %s\n1 0 obj << /Type /Catalog >> endobj\ntrailer << /Root 1 0 R >>\n%%%%EOF\n", documentNumber))
+}
+
+// CreateInfectedDocumentFile создает файл документа, содержащий сигнатуру EICAR
+func CreateInfectedDocumentFile() []byte {
+	return []byte(EICARSignature)
+}
@@ -0,0 +1,68 @@
+//go:build integration
+
+package fixtures
+
+import (
+	"math"
+
+	"driver-service/internal/domain/entities"
+
+	"github.com/google/uuid"
+)
+
+// DefaultCommissionRate комиссия платформы по умолчанию
+const DefaultCommissionRate = 0.2
+
+// PaymentProcessed данные события payment.processed об оплате поездки
+type PaymentProcessed struct {
+	PaymentID      uuid.UUID
+	DriverID       uuid.UUID
+	ShiftID        uuid.UUID
+	Distance       float64
+	GrossAmount    float64
+	CommissionRate float64
+	Commission     float64
+	NetAmount      float64
+}
+
+// CreatePaymentProcessed создает оплату поездки с комиссией, округленной до копеек
+func CreatePaymentProcessed(driverID, shiftID uuid.UUID, gross, commissionRate, distance float64) *PaymentProcessed {
+	commission := math.Round(gross*commissionRate*100) / 100
+
+	return &PaymentProcessed{
+		PaymentID:      uuid.New(),
+		DriverID:       driverID,
+		ShiftID:        shiftID,
+		Distance:       distance,
+		GrossAmount:    gross,
+		CommissionRate: commissionRate,
+		Commission:     commission,
+		NetAmount:      math.Round((gross-commission)*100) / 100,
+	}
+}
+
+// EventData возвращает данные для публикации события payment.processed: amount - заработок водителя
+func (p *PaymentProcessed) EventData() map[string]interface{} {
+	return map[string]interface{}{
+		"payment_id":      p.PaymentID.String(),
+		"driver_id":       p.DriverID.String(),
+		"amount":          p.NetAmount,
+		"shift_id":        p.ShiftID.String(),
+		"distance":        p.Distance,
+		"gross_amount":    p.GrossAmount,
+		"commission_rate": p.CommissionRate,
+		"commission":      p.Commission,
+		"net_amount":      p.NetAmount,
+	}
+}
+
+// Payment возвращает оплату в том виде, в каком ее получает сервис из события payment.processed
+func (p *PaymentProcessed) Payment() *entities.TripPayment {
+	return &entities.TripPayment{
+		PaymentID:   p.PaymentID,
+		DriverID:    p.DriverID,
+		GrossAmount: p.GrossAmount,
+		Commission:  p.Commission,
+		NetAmount:   p.NetAmount,
+	}
+}
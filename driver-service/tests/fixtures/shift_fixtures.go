@@ -0,0 +1,29 @@
+//go:build integration
+
+package fixtures
+
+import (
+	"time"
+
+	"driver-service/internal/domain/entities"
+
+	"github.com/google/uuid"
+)
+
+// CreateCompletedShiftInZone создает завершенную смену, время которой задано в локальной зоне города.
+// Часовой пояс города сохраняется в metadata["timezone"].
+func CreateCompletedShiftInZone(driverID uuid.UUID, location *time.Location, start, end time.Time, trips int, earnings float64) *entities.DriverShift {
+	shift := CreateTestShift(driverID)
+
+	startTime := start.In(location)
+	endTime := end.In(location)
+
+	shift.StartTime = startTime
+	shift.EndTime = &endTime
+	shift.Status = entities.ShiftStatusCompleted
+	shift.TotalTrips = trips
+	shift.TotalEarnings = earnings
+	shift.Metadata["timezone"] = location.String()
+
+	return shift
+}
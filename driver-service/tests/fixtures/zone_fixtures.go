@@ -0,0 +1,126 @@
+//go:build integration
+
+package fixtures
+
+import (
+	"math"
+	"math/rand"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+)
+
+// Zone именованная геозона (круг с центром и радиусом)
+type Zone struct {
+	ID        string
+	Name      string
+	CenterLat float64
+	CenterLon float64
+	RadiusKm  float64
+}
+
+// Contains проверяет, находится ли точка внутри зоны
+func (z Zone) Contains(lat, lon float64) bool {
+	center := &entities.DriverLocation{Latitude: z.CenterLat, Longitude: z.CenterLon}
+	point := &entities.DriverLocation{Latitude: lat, Longitude: lon}
+	return point.IsInRadius(center, z.RadiusKm)
+}
+
+// ServiceZones возвращает зоны в виде геозон сервиса
+func ServiceZones(zones []Zone) []services.Zone {
+	result := make([]services.Zone, len(zones))
+	for i, zone := range zones {
+		result[i] = services.Zone{
+			ID:        zone.ID,
+			Name:      zone.Name,
+			Latitude:  zone.CenterLat,
+			Longitude: zone.CenterLon,
+			RadiusKm:  zone.RadiusKm,
+		}
+	}
+	return result
+}
+
+// RoutePoint точка маршрута
+type RoutePoint struct {
+	Latitude  float64
+	Longitude float64
+}
+
+// CreateAirportZone создает зону аэропорта (Шереметьево)
+func CreateAirportZone() Zone {
+	return Zone{
+		ID:        "zone-airport-svo",
+		Name:      "Аэропорт Шереметьево",
+		CenterLat: 55.9726,
+		CenterLon: 37.4146,
+		RadiusKm:  3.0,
+	}
+}
+
+// CreateCityCenterZone создает зону центра города (в пределах Садового кольца)
+func CreateCityCenterZone() Zone {
+	return Zone{
+		ID:        "zone-city-center",
+		Name:      "Центр Москвы",
+		CenterLat: 55.7558,
+		CenterLon: 37.6173,
+		RadiusKm:  2.5,
+	}
+}
+
+// CreateTestZones возвращает набор именованных зон для тестов
+func CreateTestZones() []Zone {
+	return []Zone{
+		CreateCityCenterZone(),
+		CreateAirportZone(),
+	}
+}
+
+// CreateStraightRoute создает маршрут из steps+1 точек по прямой между двумя точками
+func CreateStraightRoute(from, to RoutePoint, steps int) []RoutePoint {
+	if steps < 1 {
+		steps = 1
+	}
+
+	route := make([]RoutePoint, steps+1)
+	for i := 0; i <= steps; i++ {
+		fraction := float64(i) / float64(steps)
+		route[i] = RoutePoint{
+			Latitude:  from.Latitude + (to.Latitude-from.Latitude)*fraction,
+			Longitude: from.Longitude + (to.Longitude-from.Longitude)*fraction,
+		}
+	}
+
+	return route
+}
+
+// CreateCenterToAirportRoute создает маршрут из центра города в аэропорт
+func CreateCenterToAirportRoute(steps int) []RoutePoint {
+	center := CreateCityCenterZone()
+	airport := CreateAirportZone()
+
+	return CreateStraightRoute(
+		RoutePoint{Latitude: center.CenterLat, Longitude: center.CenterLon},
+		RoutePoint{Latitude: airport.CenterLat, Longitude: airport.CenterLon},
+		steps,
+	)
+}
+
+// CreateRandomPointsAround создает count случайных точек, равномерно распределенных в круге радиусом maxDistanceKm
+func CreateRandomPointsAround(rng *rand.Rand, center RoutePoint, maxDistanceKm float64, count int) []RoutePoint {
+	const kmPerDegree = 111.32
+
+	points := make([]RoutePoint, count)
+	for i := 0; i < count; i++ {
+		distance := maxDistanceKm * math.Sqrt(rng.Float64())
+		angle := rng.Float64() * 2 * math.Pi
+
+		points[i] = RoutePoint{
+			Latitude:  center.Latitude + distance*math.Cos(angle)/kmPerDegree,
+			Longitude: center.Longitude + distance*math.Sin(angle)/(kmPerDegree*math.Cos(center.Latitude*math.Pi/180)),
+		}
+	}
+
+	return points
+}
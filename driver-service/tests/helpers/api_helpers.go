@@ -4,29 +4,192 @@ package helpers
 
 import (
 	"bytes"
+	"compress/gzip"
+	"context"
 	"encoding/json"
+	"fmt"
 	"io"
 	"net/http"
 	"net/http/httptest"
+	"net/url"
+	"os"
+	"runtime"
+	"strings"
 	"testing"
 	"time"
 
 	"github.com/gin-gonic/gin"
 	"github.com/stretchr/testify/require"
+	"github.com/ugorji/go/codec"
 )
 
+// DefaultAPIVersion версия API, к которой по умолчанию обращается помощник
+const DefaultAPIVersion = "v1"
+
+// MIMEMsgPack Content-Type тела запроса в формате MessagePack (так пакеты координат отправляет мобильное приложение)
+const MIMEMsgPack = "application/x-msgpack"
+
+// DefaultHeadersEnv переменная окружения с заголовками для всех запросов помощника в формате
+// "Имя: значение; Имя2: значение2" (например, токен доступа к staging или X-Env: test)
+const DefaultHeadersEnv = "TEST_DEFAULT_HEADERS"
+
+// UserAgentEnv переменная окружения, переопределяющая User-Agent запросов помощника
+const UserAgentEnv = "TEST_USER_AGENT"
+
+// DefaultUserAgent User-Agent запросов помощника, если UserAgentEnv не задана
+const DefaultUserAgent = "driver-service-tests"
+
 // APITestHelper помощник для тестирования HTTP API
 type APITestHelper struct {
-	router *gin.Engine
-	t      *testing.T
+	router     *gin.Engine
+	t          *testing.T
+	apiVersion string
+
+	// baseURL и client задаются для запросов к развернутому сервису вместо роутера в памяти
+	baseURL string
+	client  *http.Client
+
+	// span собирает историю запросов текущего теста, history - последние запросы клиента
+	span    *TestSpan
+	history *RequestHistory
+
+	// onRequest и onResponse вызываются для каждого запроса в порядке добавления
+	onRequest  []RequestInterceptor
+	onResponse []ResponseInterceptor
 }
 
+// RequestInterceptor вызывается перед отправкой запроса, когда заголовки и query параметры уже заполнены.
+// Может добавлять заголовки или менять запрос.
+type RequestInterceptor func(req *http.Request)
+
+// ResponseInterceptor вызывается после получения ответа с исходным запросом и длительностью его выполнения
+type ResponseInterceptor func(req *http.Request, resp *APIResponse, duration time.Duration)
+
 // NewAPITestHelper создает новый APITestHelper
 func NewAPITestHelper(router *gin.Engine, t *testing.T) *APITestHelper {
-	return &APITestHelper{
-		router: router,
-		t:      t,
+	helper := &APITestHelper{
+		router:     router,
+		t:          t,
+		apiVersion: DefaultAPIVersion,
+		history:    LoadRequestHistory(t),
+	}
+	t.Cleanup(func() { helper.history.finish(t) })
+	return helper.withDefaultHeaders()
+}
+
+// NewRemoteAPITestHelper создает APITestHelper, выполняющий запросы к развернутому сервису по baseURL
+func NewRemoteAPITestHelper(baseURL string, t *testing.T) *APITestHelper {
+	helper := &APITestHelper{
+		t:          t,
+		apiVersion: DefaultAPIVersion,
+		baseURL:    strings.TrimRight(baseURL, "/"),
+		client:     NewHTTPClient(LoadHTTPClientConfig(t)),
+		history:    LoadRequestHistory(t),
+	}
+	t.Cleanup(func() { helper.history.finish(t) })
+	return helper.withDefaultHeaders()
+}
+
+// WithHTTPClient возвращает копию помощника, выполняющую запросы к развернутому сервису клиентом
+// с собственным пулом соединений по cfg. Для роутера в памяти настройки не действуют.
+func (h *APITestHelper) WithHTTPClient(cfg HTTPClientConfig) *APITestHelper {
+	clientHelper := *h
+	clientHelper.client = NewHTTPClient(cfg)
+	return &clientHelper
+}
+
+// DefaultHeaders возвращает заголовки из DefaultHeadersEnv и User-Agent для текущего окружения
+func DefaultHeaders() (map[string]string, error) {
+	headers := map[string]string{"User-Agent": DefaultUserAgent}
+	if userAgent := os.Getenv(UserAgentEnv); userAgent != "" {
+		headers["User-Agent"] = userAgent
+	}
+
+	for _, entry := range strings.Split(os.Getenv(DefaultHeadersEnv), ";") {
+		if strings.TrimSpace(entry) == "" {
+			continue
+		}
+
+		name, value, ok := strings.Cut(entry, ":")
+		name = strings.TrimSpace(name)
+		if !ok || name == "" {
+			return nil, fmt.Errorf("%s: expected \"Name: value\", got %q", DefaultHeadersEnv, entry)
+		}
+		headers[http.CanonicalHeaderKey(name)] = strings.TrimSpace(value)
+	}
+
+	return headers, nil
+}
+
+// withDefaultHeaders добавляет заголовки окружения ко всем запросам помощника
+func (h *APITestHelper) withDefaultHeaders() *APITestHelper {
+	headers, err := DefaultHeaders()
+	require.NoError(h.t, err)
+
+	helper := h
+	for name, value := range headers {
+		helper = helper.WithHeader(name, value)
 	}
+	return helper
+}
+
+// IsRemote проверяет, выполняются ли запросы к развернутому сервису
+func (h *APITestHelper) IsRemote() bool {
+	return h.baseURL != ""
+}
+
+// WithAPIVersion возвращает копию помощника, обращающуюся к указанной версии API (например, "v2")
+func (h *APITestHelper) WithAPIVersion(version string) *APITestHelper {
+	versionHelper := *h
+	versionHelper.apiVersion = version
+	return &versionHelper
+}
+
+// APIVersion возвращает версию API, к которой обращается помощник
+func (h *APITestHelper) APIVersion() string {
+	return h.apiVersion
+}
+
+// WithSpan возвращает копию помощника, записывающую запросы и ответы в span теста.
+// Каждому запросу без явного X-Request-ID присваивается ID, по которому его можно найти в логах сервиса.
+func (h *APITestHelper) WithSpan(span *TestSpan) *APITestHelper {
+	spanHelper := *h
+	spanHelper.span = span
+	h.history.attachToSpan()
+	return &spanHelper
+}
+
+// History возвращает последние запросы клиента (общие для всех копий помощника)
+func (h *APITestHelper) History() *RequestHistory {
+	return h.history
+}
+
+// WithRequestInterceptor возвращает копию помощника, вызывающую interceptor перед каждым запросом
+func (h *APITestHelper) WithRequestInterceptor(interceptor RequestInterceptor) *APITestHelper {
+	interceptorHelper := *h
+	interceptorHelper.onRequest = append(append([]RequestInterceptor(nil), h.onRequest...), interceptor)
+	return &interceptorHelper
+}
+
+// WithResponseInterceptor возвращает копию помощника, вызывающую interceptor после каждого ответа
+func (h *APITestHelper) WithResponseInterceptor(interceptor ResponseInterceptor) *APITestHelper {
+	interceptorHelper := *h
+	interceptorHelper.onResponse = append(append([]ResponseInterceptor(nil), h.onResponse...), interceptor)
+	return &interceptorHelper
+}
+
+// WithHeader возвращает копию помощника, добавляющую заголовок ко всем запросам (явно переданный заголовок имеет приоритет)
+func (h *APITestHelper) WithHeader(key, value string) *APITestHelper {
+	return h.WithRequestInterceptor(func(req *http.Request) {
+		if req.Header.Get(key) == "" {
+			req.Header.Set(key, value)
+		}
+	})
+}
+
+// APIPath возвращает путь к ресурсу в текущей версии API, например APIPath("/drivers") -> "/api/v1/drivers"
+func (h *APITestHelper) APIPath(path string) string {
+	return "/api/" + h.apiVersion + path
 }
 
 // APIRequest структура для HTTP запроса
@@ -36,6 +199,13 @@ type APIRequest struct {
 	Body        interface{}
 	Headers     map[string]string
 	QueryParams map[string]string
+	// ContentType формат, в котором сериализуется Body: application/json (по умолчанию) или MIMEMsgPack
+	ContentType string
+	// ContentEncoding сжатие тела: "" или "gzip"
+	ContentEncoding string
+	// RawBody тело запроса как есть, без сериализации и Content-Type по умолчанию
+	// (например, записанный трафик, в том числе с некорректным JSON). Имеет приоритет над Body.
+	RawBody []byte
 }
 
 // APIResponse структура для HTTP ответа
@@ -43,15 +213,26 @@ type APIResponse struct {
 	StatusCode int
 	Body       []byte
 	Headers    http.Header
+	// Connection соединение запроса к развернутому сервису; nil для роутера в памяти
+	Connection *ConnectionInfo
 }
 
 // MakeRequest выполняет HTTP запрос и возвращает ответ
 func (h *APITestHelper) MakeRequest(req APIRequest) *APIResponse {
 	var bodyReader io.Reader
 
+	contentType := req.ContentType
+	if contentType == "" {
+		contentType = "application/json"
+	}
+
 	// Подготавливаем тело запроса
-	if req.Body != nil {
-		bodyBytes, err := json.Marshal(req.Body)
+	if req.RawBody != nil {
+		bodyReader = bytes.NewReader(req.RawBody)
+	} else if req.Body != nil {
+		bodyBytes, err := encodeBody(contentType, req.Body)
+		require.NoError(h.t, err)
+		bodyBytes, err = compressBody(req.ContentEncoding, bodyBytes)
 		require.NoError(h.t, err)
 		bodyReader = bytes.NewBuffer(bodyBytes)
 	}
@@ -66,9 +247,12 @@ func (h *APITestHelper) MakeRequest(req APIRequest) *APIResponse {
 		}
 	}
 
-	// Добавляем Content-Type для JSON
+	// Добавляем Content-Type и Content-Encoding тела
 	if req.Body != nil && httpReq.Header.Get("Content-Type") == "" {
-		httpReq.Header.Set("Content-Type", "application/json")
+		httpReq.Header.Set("Content-Type", contentType)
+	}
+	if req.Body != nil && req.ContentEncoding != "" && httpReq.Header.Get("Content-Encoding") == "" {
+		httpReq.Header.Set("Content-Encoding", req.ContentEncoding)
 	}
 
 	// Добавляем query параметры
@@ -80,6 +264,99 @@ func (h *APITestHelper) MakeRequest(req APIRequest) *APIResponse {
 		httpReq.URL.RawQuery = q.Encode()
 	}
 
+	if h.span != nil && httpReq.Header.Get("X-Request-ID") == "" {
+		httpReq.Header.Set("X-Request-ID", h.span.nextRequestID())
+	}
+
+	// При экспорте в OpenTelemetry запрос становится span'ом теста, а traceparent связывает с ним трассу сервиса
+	var traceSpanID, parentSpanID string
+	if h.span != nil && OTLPEnabled() {
+		var header string
+		traceSpanID, parentSpanID, header = h.span.startClientCall()
+		if httpReq.Header.Get("traceparent") == "" {
+			httpReq.Header.Set("traceparent", header)
+		}
+	}
+
+	for _, interceptor := range h.onRequest {
+		interceptor(httpReq)
+	}
+
+	started := time.Now()
+	response := h.execute(httpReq)
+	duration := time.Since(started)
+	recordClientRequest(req.Method, httpReq.URL.Path, response.StatusCode, duration)
+	recordRunLatency(req.Method, httpReq.URL.Path, duration)
+
+	for _, interceptor := range h.onResponse {
+		interceptor(httpReq, response, duration)
+	}
+
+	exchange := RecordedExchange{
+		RequestID:    httpReq.Header.Get("X-Request-ID"),
+		Method:       req.Method,
+		URL:          httpReq.URL.RequestURI(),
+		StatusCode:   response.StatusCode,
+		ResponseBody: response.Body,
+		Started:      started,
+		Duration:     duration,
+
+		traceSpanID:  traceSpanID,
+		parentSpanID: parentSpanID,
+	}
+	if req.RawBody != nil {
+		exchange.RequestBody = req.RawBody
+	} else if req.Body != nil {
+		exchange.RequestBody, _ = json.Marshal(req.Body)
+	}
+	h.history.record(exchange)
+	if h.span != nil {
+		h.span.record(exchange)
+	}
+
+	return response
+}
+
+// encodeBody сериализует тело запроса в формате contentType
+func encodeBody(contentType string, body interface{}) ([]byte, error) {
+	switch contentType {
+	case "application/json":
+		return json.Marshal(body)
+	case MIMEMsgPack:
+		var encoded []byte
+		err := codec.NewEncoderBytes(&encoded, new(codec.MsgpackHandle)).Encode(body)
+		return encoded, err
+	default:
+		return nil, fmt.Errorf("unsupported request content type %q", contentType)
+	}
+}
+
+// compressBody сжимает сериализованное тело запроса в соответствии с contentEncoding
+func compressBody(contentEncoding string, body []byte) ([]byte, error) {
+	switch contentEncoding {
+	case "":
+		return body, nil
+	case "gzip":
+		var compressed bytes.Buffer
+		writer := gzip.NewWriter(&compressed)
+		if _, err := writer.Write(body); err != nil {
+			return nil, err
+		}
+		if err := writer.Close(); err != nil {
+			return nil, err
+		}
+		return compressed.Bytes(), nil
+	default:
+		return nil, fmt.Errorf("unsupported request content encoding %q", contentEncoding)
+	}
+}
+
+// execute выполняет подготовленный запрос через роутер в памяти или к развернутому сервису
+func (h *APITestHelper) execute(httpReq *http.Request) *APIResponse {
+	if h.IsRemote() {
+		return h.doRemoteRequest(httpReq)
+	}
+
 	// Выполняем запрос
 	w := httptest.NewRecorder()
 	h.router.ServeHTTP(w, httpReq)
@@ -91,6 +368,34 @@ func (h *APITestHelper) MakeRequest(req APIRequest) *APIResponse {
 	}
 }
 
+// doRemoteRequest отправляет подготовленный запрос развернутому сервису
+func (h *APITestHelper) doRemoteRequest(httpReq *http.Request) *APIResponse {
+	target, err := url.Parse(h.baseURL + httpReq.URL.RequestURI())
+	require.NoError(h.t, err)
+
+	// Запрос, созданный httptest, является серверным: для клиента RequestURI должен быть пустым
+	httpReq.RequestURI = ""
+	httpReq.URL = target
+	httpReq.Host = target.Host
+
+	httpReq, connection := connectionTrace(httpReq)
+	resp, err := h.client.Do(httpReq)
+	require.NoError(h.t, err, "Request to %s failed", target)
+	defer resp.Body.Close()
+
+	body, err := io.ReadAll(resp.Body)
+	require.NoError(h.t, err)
+
+	connection.Protocol = resp.Proto
+	recordClientConnection(connection)
+	return &APIResponse{
+		StatusCode: resp.StatusCode,
+		Body:       body,
+		Headers:    resp.Header,
+		Connection: connection,
+	}
+}
+
 // UnmarshalResponse парсит JSON ответ в структуру
 func (h *APITestHelper) UnmarshalResponse(response *APIResponse, target interface{}) {
 	err := json.Unmarshal(response.Body, target)
@@ -181,25 +486,102 @@ func (h *APITestHelper) AssertValidationError(response *APIResponse, field strin
 	// Это может потребовать доработки в зависимости от используемой библиотеки валидации
 }
 
+// conditionPollInterval интервал между проверками условия в WaitForCondition
+const conditionPollInterval = 100 * time.Millisecond
+
 // WaitForCondition ждет выполнения условия с таймаутом
 func WaitForCondition(t *testing.T, condition func() bool, timeout time.Duration, message string) {
-	ticker := time.NewTicker(100 * time.Millisecond)
-	defer ticker.Stop()
+	t.Helper()
+
+	WaitForConditionContext(t, func(context.Context) (bool, error) {
+		return condition(), nil
+	}, timeout, message)
+}
+
+// WaitForConditionContext ждет выполнения условия, которое само обращается к БД, API или внешнему сервису.
+// Условие проверяется сразу и затем с интервалом; контекст отменяется по истечении таймаута, поэтому
+// зависший запрос внутри условия не задерживает тест. Ошибка условия не прерывает ожидание, а
+// последняя из них выводится при таймауте.
+func WaitForConditionContext(t testing.TB, condition func(ctx context.Context) (bool, error), timeout time.Duration, message string) {
+	t.Helper()
 
-	timeoutChan := time.After(timeout)
+	ctx, cancel := context.WithTimeout(context.Background(), timeout)
+	defer cancel()
 
+	ticker := time.NewTicker(conditionPollInterval)
+	defer ticker.Stop()
+
+	var lastErr error
 	for {
+		ok, err := condition(ctx)
+		if err == nil && ok {
+			return
+		}
+		if err != nil {
+			lastErr = err
+		}
+
 		select {
 		case <-ticker.C:
-			if condition() {
-				return
+		case <-ctx.Done():
+			if lastErr != nil {
+				t.Fatalf("Timeout waiting for condition: %s (last error: %v)", message, lastErr)
 			}
-		case <-timeoutChan:
 			t.Fatalf("Timeout waiting for condition: %s", message)
 		}
 	}
 }
 
+// EventuallyT собирает ошибки одной попытки Eventually. Передается в assert/require вместо *testing.T:
+// неудачная проверка не проваливает тест, а только отмечает попытку неудачной.
+type EventuallyT struct {
+	errors []string
+	failed bool
+}
+
+// Errorf запоминает ошибку проверки
+func (c *EventuallyT) Errorf(format string, args ...interface{}) {
+	c.errors = append(c.errors, fmt.Sprintf(format, args...))
+	c.failed = true
+}
+
+// FailNow завершает попытку, как require завершает тест
+func (c *EventuallyT) FailNow() {
+	c.failed = true
+	runtime.Goexit()
+}
+
+// Helper нужен для совместимости с assert.TestingT
+func (c *EventuallyT) Helper() {}
+
+// Eventually повторяет проверку с интервалом, пока в ней не останется ошибок, вместо фиксированного
+// ожидания асинхронной обработки. При таймауте тест проваливается с ошибками последней попытки.
+func Eventually(t *testing.T, assertion func(c *EventuallyT), timeout, interval time.Duration) {
+	t.Helper()
+
+	deadline := time.Now().Add(timeout)
+	for attempt := 1; ; attempt++ {
+		collect := &EventuallyT{}
+
+		// Отдельная горутина, чтобы FailNow (runtime.Goexit) завершал только попытку
+		done := make(chan struct{})
+		go func() {
+			defer close(done)
+			assertion(collect)
+		}()
+		<-done
+
+		if !collect.failed {
+			return
+		}
+		if time.Now().Add(interval).After(deadline) {
+			t.Fatalf("Condition not satisfied within %v (%d attempts), last attempt:\n%s",
+				timeout, attempt, strings.Join(collect.errors, "\n"))
+		}
+		time.Sleep(interval)
+	}
+}
+
 // AssertLocationInBounds проверяет, что местоположение находится в заданных границах
 func AssertLocationInBounds(t *testing.T, lat, lon, centerLat, centerLon, radiusKm float64) {
 	// Простая проверка через прямоугольные границы
@@ -216,11 +598,13 @@ func AssertLocationInBounds(t *testing.T, lat, lon, centerLat, centerLon, radius
 	}
 }
 
-// LoadTestData загружает тестовые данные из JSON файла
+// LoadTestData загружает тестовые данные из JSON файла в tests/testdata
 func LoadTestData(t *testing.T, filename string, target interface{}) {
-	// В реальном проекте здесь можно загружать данные из fixtures файлов
-	// Пока оставляем заглушку
 	t.Logf("Loading test data from %s", filename)
+
+	data, err := os.ReadFile(TestDataPath(filename))
+	require.NoError(t, err, "Не удалось прочитать %s", filename)
+	require.NoError(t, json.Unmarshal(data, target), "Некорректный JSON в %s", filename)
 }
 
 // CompareJSONObjects сравнивает два JSON объекта
@@ -0,0 +1,87 @@
+//go:build integration
+
+package helpers
+
+import (
+	"os"
+	"sort"
+	"strconv"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения SLO назначения заказа: длительности (например, "250ms")
+const (
+	DispatchSLOP95Env = "DISPATCH_SLO_P95"
+	DispatchSLOP99Env = "DISPATCH_SLO_P99"
+)
+
+// DispatchSLO пороги задержки от публикации order.assigned до перевода водителя в busy
+type DispatchSLO struct {
+	P95 time.Duration
+	P99 time.Duration
+}
+
+// DefaultDispatchSLO возвращает пороги по умолчанию для NATS и БД из docker-compose.test.yml
+func DefaultDispatchSLO() DispatchSLO {
+	return DispatchSLO{
+		P95: 250 * time.Millisecond,
+		P99: 500 * time.Millisecond,
+	}
+}
+
+// LoadDispatchSLO возвращает пороги по умолчанию с переопределениями из DISPATCH_SLO_P95 и DISPATCH_SLO_P99,
+// умноженные на LATENCY_BUDGET_MULTIPLIER (для медленных окружений)
+func LoadDispatchSLO(t *testing.T) DispatchSLO {
+	slo := DefaultDispatchSLO()
+
+	for env, target := range map[string]*time.Duration{DispatchSLOP95Env: &slo.P95, DispatchSLOP99Env: &slo.P99} {
+		if value := os.Getenv(env); value != "" {
+			duration, err := time.ParseDuration(value)
+			require.NoError(t, err, "Некорректный %s: %q", env, value)
+			*target = duration
+		}
+	}
+
+	if multiplier, err := strconv.ParseFloat(os.Getenv(LatencyBudgetMultiplierEnv), 64); err == nil && multiplier > 0 {
+		slo.P95 = time.Duration(float64(slo.P95) * multiplier)
+		slo.P99 = time.Duration(float64(slo.P99) * multiplier)
+	}
+
+	return slo
+}
+
+// LatencyPercentiles перцентили задержки по итерациям замера
+type LatencyPercentiles struct {
+	Samples int
+	P50     time.Duration
+	P95     time.Duration
+	P99     time.Duration
+	Max     time.Duration
+}
+
+// NewLatencyPercentiles считает перцентили задержек samples
+func NewLatencyPercentiles(samples []time.Duration) LatencyPercentiles {
+	sorted := append([]time.Duration(nil), samples...)
+	sort.Slice(sorted, func(i, j int) bool { return sorted[i] < sorted[j] })
+
+	return LatencyPercentiles{
+		Samples: len(sorted),
+		P50:     percentile(sorted, 50),
+		P95:     percentile(sorted, 95),
+		P99:     percentile(sorted, 99),
+		Max:     percentile(sorted, 100),
+	}
+}
+
+// AssertDispatchSLO выводит перцентили задержки observed и проверяет их по порогам назначения заказа
+func AssertDispatchSLO(t *testing.T, observed string, latency LatencyPercentiles, slo DispatchSLO) {
+	t.Helper()
+
+	t.Logf("%s over %d iterations: p50 %v, p95 %v, p99 %v, max %v",
+		observed, latency.Samples, latency.P50, latency.P95, latency.P99, latency.Max)
+	AssertWithinSLO(t, observed+" p95", latency.P95, slo.P95)
+	AssertWithinSLO(t, observed+" p99", latency.P99, slo.P99)
+}
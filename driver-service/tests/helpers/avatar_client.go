@@ -0,0 +1,115 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"errors"
+	"fmt"
+	"io"
+	"mime/multipart"
+	"net/http"
+	"net/http/httptest"
+	"net/textproto"
+	"strings"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// avatarCacheControl заголовок кэширования тестового CDN: ключ фотографии меняется при каждой загрузке
+const avatarCacheControl = "public, max-age=31536000, immutable"
+
+// EnableAvatars подключает загрузку фотографий водителей (POST /api/v1/drivers/:id/avatar) поверх тестового
+// MinIO (см. EnableStorage) и поднимает тестовый CDN: он, как CDN с доступом к закрытому бакету, отдает
+// объекты хранилища по GET {AvatarCDNURL}/{ключ}. Адреса фотографий в ответах сервиса ведут на него.
+func (env *TestEnvironment) EnableAvatars(t *testing.T) {
+	t.Helper()
+
+	if env.Storage == nil {
+		env.EnableStorage(t)
+	}
+
+	cdn := httptest.NewServer(http.HandlerFunc(env.serveAvatar))
+	t.Cleanup(cdn.Close)
+
+	env.AvatarCDNURL = cdn.URL
+	env.Avatars = services.NewAvatarService(env.DriverRepo, env.Storage, cdn.URL, env.Logger)
+	env.server.RegisterAvatarRoutes(httpHandlers.NewAvatarHandler(env.Avatars, env.Logger))
+}
+
+// serveAvatar отдает объект хранилища по пути запроса к тестовому CDN
+func (env *TestEnvironment) serveAvatar(w http.ResponseWriter, r *http.Request) {
+	if r.Method != http.MethodGet {
+		w.WriteHeader(http.StatusMethodNotAllowed)
+		return
+	}
+
+	key := strings.TrimPrefix(r.URL.Path, "/")
+	object, err := env.Storage.StatObject(r.Context(), key)
+	if err == nil {
+		var content []byte
+		if content, err = env.Storage.GetObject(r.Context(), key); err == nil {
+			w.Header().Set("Content-Type", object.ContentType)
+			w.Header().Set("Cache-Control", avatarCacheControl)
+			w.Header().Set("ETag", `"`+object.ETag+`"`)
+			_, _ = w.Write(content)
+			return
+		}
+	}
+
+	if errors.Is(err, entities.ErrObjectNotFound) {
+		w.WriteHeader(http.StatusNotFound)
+		return
+	}
+	http.Error(w, err.Error(), http.StatusBadGateway)
+}
+
+// UploadAvatar загружает фотографию водителя multipart формой, как мобильное приложение.
+// contentType - тип файла, который указывает клиент (сервис определяет тип по содержимому).
+func (h *APITestHelper) UploadAvatar(driverID uuid.UUID, filename, contentType string, content []byte) *APIResponse {
+	var body bytes.Buffer
+	form := multipart.NewWriter(&body)
+
+	header := textproto.MIMEHeader{}
+	header.Set("Content-Disposition", fmt.Sprintf(`form-data; name=%q; filename=%q`, httpHandlers.AvatarFormField, filename))
+	header.Set("Content-Type", contentType)
+	part, err := form.CreatePart(header)
+	require.NoError(h.t, err)
+	_, err = part.Write(content)
+	require.NoError(h.t, err)
+	require.NoError(h.t, form.Close())
+
+	return h.MakeRequest(APIRequest{
+		Method:  http.MethodPost,
+		URL:     fmt.Sprintf("/api/v1/drivers/%s/avatar", driverID),
+		RawBody: body.Bytes(),
+		Headers: map[string]string{"Content-Type": form.FormDataContentType()},
+	})
+}
+
+// FetchAvatar скачивает фотографию по адресу из ответа сервиса поверх HTTP, как клиент CDN
+func FetchAvatar(t *testing.T, avatarURL string) *http.Response {
+	t.Helper()
+
+	resp, err := http.Get(avatarURL)
+	require.NoError(t, err)
+	t.Cleanup(func() { resp.Body.Close() })
+	return resp
+}
+
+// ReadAvatar скачивает фотографию и возвращает ее содержимое; ответ должен быть 200
+func ReadAvatar(t *testing.T, avatarURL string) []byte {
+	t.Helper()
+
+	resp := FetchAvatar(t, avatarURL)
+	require.Equal(t, http.StatusOK, resp.StatusCode, avatarURL)
+	content, err := io.ReadAll(resp.Body)
+	require.NoError(t, err)
+	return content
+}
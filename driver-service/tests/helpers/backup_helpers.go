@@ -0,0 +1,132 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strconv"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// TestPGBinDirEnv каталог с pg_dump и pg_restore, если их нет в PATH или версия в PATH
+// старше сервера PostgreSQL из docker-compose.test.yml
+const TestPGBinDirEnv = "TEST_PG_BIN_DIR"
+
+// TableChecksum количество строк и контрольная сумма содержимого таблицы
+type TableChecksum struct {
+	Rows int
+	MD5  string
+}
+
+// pgTool возвращает путь к утилите PostgreSQL или пропускает тест, если утилита недоступна
+func pgTool(t *testing.T, name string) string {
+	if dir := os.Getenv(TestPGBinDirEnv); dir != "" {
+		path := filepath.Join(dir, name)
+		if _, err := os.Stat(path); err != nil {
+			t.Skipf("%s not found in %s=%s: %v", name, TestPGBinDirEnv, dir, err)
+		}
+		return path
+	}
+
+	path, err := exec.LookPath(name)
+	if err != nil {
+		t.Skipf("%s is not available (set %s): %v", name, TestPGBinDirEnv, err)
+	}
+	return path
+}
+
+// RequireBackupTools пропускает тест, если pg_dump или pg_restore недоступны
+func RequireBackupTools(t *testing.T) {
+	pgTool(t, "pg_dump")
+	pgTool(t, "pg_restore")
+}
+
+// pgToolCommand собирает команду утилиты PostgreSQL с параметрами подключения к тестовой БД
+func (tdb *TestDB) pgToolCommand(ctx context.Context, path string, args ...string) *exec.Cmd {
+	cfg := getTestConfig().Database
+
+	connArgs := []string{
+		"--host", cfg.Host,
+		"--port", strconv.Itoa(cfg.Port),
+		"--username", cfg.User,
+		"--dbname", tdb.dbName,
+		"--no-password",
+	}
+
+	cmd := exec.CommandContext(ctx, path, append(connArgs, args...)...)
+	cmd.Env = append(os.Environ(), "PGPASSWORD="+cfg.Password)
+	return cmd
+}
+
+// runPGTool запускает утилиту PostgreSQL и проваливает тест при ошибке
+func (tdb *TestDB) runPGTool(t *testing.T, name string, args ...string) {
+	output, err := tdb.pgToolCommand(context.Background(), pgTool(t, name), args...).CombinedOutput()
+	require.NoError(t, err, "%s failed: %s", name, output)
+}
+
+// Backup снимает дамп тестовой БД через pg_dump в custom формате и возвращает путь к файлу дампа
+func (tdb *TestDB) Backup(t *testing.T) string {
+	path := filepath.Join(t.TempDir(), tdb.dbName+".dump")
+	tdb.runPGTool(t, "pg_dump", "--format=custom", "--file", path)
+
+	info, err := os.Stat(path)
+	require.NoError(t, err)
+	tdb.logger.Info("Test database backed up", zap.String("path", path), zap.Int64("bytes", info.Size()))
+	return path
+}
+
+// Wipe удаляет все объекты тестовой БД вместе со схемой public: таблицы, функции, триггеры,
+// расширения и историю миграций
+func (tdb *TestDB) Wipe(t *testing.T) {
+	_, err := tdb.ExecContext(context.Background(), "DROP SCHEMA public CASCADE; CREATE SCHEMA public")
+	require.NoError(t, err)
+	tdb.logger.Info("Test database wiped", zap.String("db_name", tdb.dbName))
+}
+
+// Restore восстанавливает тестовую БД из дампа pg_dump. Ошибка любого объекта дампа прерывает восстановление.
+func (tdb *TestDB) Restore(t *testing.T, path string) {
+	tdb.runPGTool(t, "pg_restore", "--exit-on-error", "--no-owner", "--no-privileges", path)
+	tdb.logger.Info("Test database restored", zap.String("path", path))
+}
+
+// TableChecksums возвращает количество строк и контрольную сумму содержимого каждой таблицы схемы public
+func (tdb *TestDB) TableChecksums(t *testing.T) map[string]TableChecksum {
+	ctx := context.Background()
+
+	var tables []string
+	err := tdb.SelectContext(ctx, &tables,
+		"SELECT tablename FROM pg_tables WHERE schemaname = 'public' ORDER BY tablename")
+	require.NoError(t, err)
+
+	checksums := make(map[string]TableChecksum, len(tables))
+	for _, table := range tables {
+		var checksum struct {
+			Rows int    `db:"rows"`
+			MD5  string `db:"md5"`
+		}
+		query := fmt.Sprintf(`
+			SELECT COUNT(*) AS rows, COALESCE(md5(string_agg(t::text, '|' ORDER BY t::text)), '') AS md5
+			FROM %s t`, table)
+		require.NoError(t, tdb.GetContext(ctx, &checksum, query), "Таблица %s", table)
+
+		checksums[table] = TableChecksum{Rows: checksum.Rows, MD5: checksum.MD5}
+	}
+	return checksums
+}
+
+// RestoreTestDB создает новую тестовую БД и восстанавливает ее из дампа pg_dump.
+// Миграции не выполняются: схема и история миграций берутся из дампа.
+func RestoreTestDB(t *testing.T, path string) *TestDB {
+	testDBName := fmt.Sprintf("restored_%d_%s", time.Now().UnixNano(), t.Name())
+	testDB := createTestDB(t, sanitizeDBName(testDBName))
+	testDB.Restore(t, path)
+	return testDB
+}
@@ -0,0 +1,138 @@
+//go:build integration
+
+package helpers
+
+import (
+	"net/http"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/services"
+
+	"github.com/stretchr/testify/assert"
+)
+
+// ETag возвращает ETag ответа (пустая строка, если заголовка нет)
+func (r *APIResponse) ETag() string {
+	return r.Headers.Get("ETag")
+}
+
+// LastModified возвращает время последнего изменения ресурса из заголовка Last-Modified
+func (r *APIResponse) LastModified() (time.Time, bool) {
+	lastModified, err := http.ParseTime(r.Headers.Get("Last-Modified"))
+	if err != nil {
+		return time.Time{}, false
+	}
+	return lastModified, true
+}
+
+// NotModified проверяет, что сервис ответил 304 Not Modified
+func (r *APIResponse) NotModified() bool {
+	return r.StatusCode == http.StatusNotModified
+}
+
+// IsConditional проверяет, что запрос содержит условия кэширования If-None-Match или If-Modified-Since
+func IsConditional(req *http.Request) bool {
+	return req.Header.Get("If-None-Match") != "" || req.Header.Get("If-Modified-Since") != ""
+}
+
+// CacheStats считает условные запросы и ответы 304 по операциям вида "GET /api/v1/drivers/{id}".
+// Подключается к APITestHelper через WithResponseInterceptor(stats.Record).
+type CacheStats struct {
+	mu          sync.Mutex
+	conditional map[string]int
+	hits        map[string]int
+}
+
+// NewCacheStats создает пустой счетчик попаданий в кэш
+func NewCacheStats() *CacheStats {
+	return &CacheStats{
+		conditional: make(map[string]int),
+		hits:        make(map[string]int),
+	}
+}
+
+// Record учитывает условный запрос и попадание в кэш; сигнатура совпадает с ResponseInterceptor
+func (s *CacheStats) Record(req *http.Request, resp *APIResponse, _ time.Duration) {
+	if !IsConditional(req) {
+		return
+	}
+	operation := req.Method + " " + templatePath(req.URL.Path)
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.conditional[operation]++
+	if resp.NotModified() {
+		s.hits[operation]++
+	}
+}
+
+// Conditional возвращает количество условных запросов операции
+func (s *CacheStats) Conditional(operation string) int {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.conditional[operation]
+}
+
+// Hits возвращает количество ответов 304 операции
+func (s *CacheStats) Hits(operation string) int {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.hits[operation]
+}
+
+// HitRatio возвращает долю ответов 304 среди условных запросов операции или 0, если их не было
+func (s *CacheStats) HitRatio(operation string) float64 {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if s.conditional[operation] == 0 {
+		return 0
+	}
+	return float64(s.hits[operation]) / float64(s.conditional[operation])
+}
+
+// AssertCacheTTLWithinPolicy проверяет, что у каждого ключа кэша срок жизни в пределах политики:
+// ключ с истекшим сроком, который кэш продолжает хранить, и ключ со сроком больше maxTTL - нарушения.
+// Возвращает число проверенных ключей.
+func AssertCacheTTLWithinPolicy(t *testing.T, entries []services.CacheEntry, maxTTL time.Duration) int {
+	t.Helper()
+
+	for _, entry := range entries {
+		if entry.ExpiresIn <= 0 {
+			t.Errorf("Cache key %q expired %v ago but is still stored", entry.Key, -entry.ExpiresIn)
+			continue
+		}
+		assert.LessOrEqual(t, entry.ExpiresIn, maxTTL, "TTL of cache key %q exceeds policy", entry.Key)
+	}
+	return len(entries)
+}
+
+// GeoCacheEntries возвращает ключи кэша поиска поблизости (в памяти процесса, не Redis) с оставшимся временем жизни
+func (env *TestEnvironment) GeoCacheEntries() []services.CacheEntry {
+	return env.geoCache.Entries()
+}
+
+// AuditGeoCacheTTL проверяет ключи кэша поиска поблизости по политике - текущему времени жизни кэша
+// (TestGeoCacheTTL или заданному SetGeoCacheTTL). Возвращает число проверенных ключей.
+func (env *TestEnvironment) AuditGeoCacheTTL(t *testing.T) int {
+	t.Helper()
+	return AssertCacheTTLWithinPolicy(t, env.geoCache.Entries(), env.geoCache.TTL())
+}
+
+// GeoCacheStats возвращает счетчики попаданий в кэш поиска поблизости и промахов
+func (env *TestEnvironment) GeoCacheStats() services.NearbyCacheStats {
+	return env.geoCache.Stats()
+}
+
+// InvalidateGeoCache сбрасывает кэш поиска поблизости: следующие запросы идут в БД, как после перезапуска сервиса
+func (env *TestEnvironment) InvalidateGeoCache() {
+	env.geoCache.Invalidate()
+}
+
+// SetGeoCacheTTL задает время жизни новых записей кэша поиска поблизости до конца теста t
+func (env *TestEnvironment) SetGeoCacheTTL(t *testing.T, ttl time.Duration) {
+	previous := env.geoCache.TTL()
+	env.geoCache.SetTTL(ttl)
+	t.Cleanup(func() { env.geoCache.SetTTL(previous) })
+}
@@ -0,0 +1,250 @@
+//go:build integration
+
+package helpers
+
+import (
+	"math"
+	"math/rand"
+	"net"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// Потеря пакетов в ChaosProxy по модели TCP: потерянный блок передается повторно после таймаута,
+// который удваивается с каждой потерей подряд, а после packetMaxRetransmits потерь подряд соединение сбрасывается
+const (
+	packetRetransmitTimeout = 200 * time.Millisecond // минимальный RTO TCP в Linux
+	packetMaxRetransmits    = 3
+)
+
+// ChaosProxy TCP прокси перед зависимостью сервиса (БД, Redis, NATS), через который тест имитирует ее отказ:
+// Cut обрывает открытые соединения и сбрасывает новые, Restore возвращает доступ, SetLatency замедляет ответы,
+// SetPacketLoss теряет пакеты. Сервис подключается к Addr прокси вместо адреса зависимости.
+type ChaosProxy struct {
+	target   string
+	listener net.Listener
+	latency  atomic.Int64  // задержка ответов зависимости, time.Duration
+	loss     atomic.Uint64 // доля теряемых пакетов, биты float64
+
+	mu          sync.Mutex
+	cut         bool
+	conns       map[net.Conn]struct{}
+	connections int // подключений к прокси, включая сброшенные при отказе
+	random      *rand.Rand
+	lost        int // потерянных блоков данных
+	lossResets  int // соединений, сброшенных из-за потерь подряд
+	wg          sync.WaitGroup
+}
+
+// StartChaosProxy запускает прокси к target (host:port) до конца теста
+func StartChaosProxy(t *testing.T, target string) *ChaosProxy {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+
+	p := &ChaosProxy{
+		target:   target,
+		listener: listener,
+		conns:    make(map[net.Conn]struct{}),
+		random:   rand.New(rand.NewSource(time.Now().UnixNano())),
+	}
+	p.wg.Add(1)
+	go p.accept()
+	t.Cleanup(p.close)
+
+	return p
+}
+
+// Addr возвращает адрес прокси (host:port)
+func (p *ChaosProxy) Addr() string {
+	return p.listener.Addr().String()
+}
+
+// Cut имитирует отказ зависимости: открытые соединения обрываются, новые закрываются сразу после подключения
+func (p *ChaosProxy) Cut() {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	p.cut = true
+	for conn := range p.conns {
+		conn.Close()
+	}
+}
+
+// Restore возвращает доступ к зависимости
+func (p *ChaosProxy) Restore() {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	p.cut = false
+}
+
+// SetLatency задерживает каждый блок данных от зависимости к сервису на latency (как toxic latency
+// toxiproxy на downstream): запросы сервиса доходят сразу, ответы - с задержкой. Действует и на открытые
+// соединения; 0 убирает задержку.
+func (p *ChaosProxy) SetLatency(latency time.Duration) {
+	p.latency.Store(int64(latency))
+}
+
+// SetPacketLoss теряет долю rate (0-1) блоков данных в обе стороны: TCP не теряет данные, поэтому
+// потеря видна как задержка повторной передачи от packetRetransmitTimeout с удвоением и блокирует
+// следующие данные соединения, а после packetMaxRetransmits потерь подряд соединение сбрасывается.
+// Потери выбираются генератором с seed; действует и на открытые соединения, 0 убирает потери.
+func (p *ChaosProxy) SetPacketLoss(rate float64, seed int64) {
+	p.mu.Lock()
+	p.random = rand.New(rand.NewSource(seed))
+	p.mu.Unlock()
+
+	p.loss.Store(math.Float64bits(rate))
+}
+
+// PacketLoss возвращает число потерянных блоков данных и соединений, сброшенных из-за потерь подряд
+func (p *ChaosProxy) PacketLoss() (lost, resets int) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	return p.lost, p.lossResets
+}
+
+// Connections возвращает число подключений к зависимости через прокси, включая сброшенные при отказе:
+// по нему тест видит, что сервис повторяет попытки подключения
+func (p *ChaosProxy) Connections() int {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	return p.connections
+}
+
+// accept принимает подключения до закрытия прокси
+func (p *ChaosProxy) accept() {
+	defer p.wg.Done()
+
+	for {
+		client, err := p.listener.Accept()
+		if err != nil {
+			return
+		}
+		p.wg.Add(1)
+		go p.serve(client)
+	}
+}
+
+// serve соединяет клиента с зависимостью и передает данные в обе стороны до обрыва одной из сторон
+func (p *ChaosProxy) serve(client net.Conn) {
+	defer p.wg.Done()
+	defer client.Close()
+
+	p.mu.Lock()
+	p.connections++
+	p.mu.Unlock()
+	if !p.track(client) {
+		return
+	}
+	defer p.untrack(client)
+
+	upstream, err := net.Dial("tcp", p.target)
+	if err != nil {
+		return
+	}
+	defer upstream.Close()
+	if !p.track(upstream) {
+		return
+	}
+	defer p.untrack(upstream)
+
+	done := make(chan struct{}, 2)
+	go func() {
+		p.relay(upstream, client, false)
+		done <- struct{}{}
+	}()
+	go func() {
+		p.relay(client, upstream, true)
+		done <- struct{}{}
+	}()
+
+	// Обрыв любой стороны закрывает обе
+	<-done
+	client.Close()
+	upstream.Close()
+	<-done
+}
+
+// relay передает данные из src в dst с потерями пакетов прокси, а ответы зависимости (downstream) -
+// еще и с задержкой прокси. Возвращается при обрыве одной из сторон или сбросе соединения из-за потерь.
+func (p *ChaosProxy) relay(dst, src net.Conn, downstream bool) {
+	buf := make([]byte, 32*1024)
+	for {
+		n, err := src.Read(buf)
+		if n > 0 {
+			if latency := time.Duration(p.latency.Load()); downstream && latency > 0 {
+				time.Sleep(latency)
+			}
+			delay, delivered := p.retransmitDelay()
+			if !delivered {
+				return
+			}
+			time.Sleep(delay)
+			if _, writeErr := dst.Write(buf[:n]); writeErr != nil {
+				return
+			}
+		}
+		if err != nil {
+			return
+		}
+	}
+}
+
+// retransmitDelay разыгрывает потерю блока данных и возвращает задержку его повторной передачи;
+// false, если блок потерян packetMaxRetransmits раз подряд и соединение нужно сбросить
+func (p *ChaosProxy) retransmitDelay() (time.Duration, bool) {
+	rate := math.Float64frombits(p.loss.Load())
+	if rate <= 0 {
+		return 0, true
+	}
+
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	var delay time.Duration
+	timeout := packetRetransmitTimeout
+	for attempt := 0; p.random.Float64() < rate; attempt++ {
+		p.lost++
+		if attempt == packetMaxRetransmits {
+			p.lossResets++
+			return 0, false
+		}
+		delay += timeout
+		timeout *= 2
+	}
+	return delay, true
+}
+
+// track запоминает соединение, чтобы Cut мог его оборвать; false, если зависимость сейчас недоступна
+func (p *ChaosProxy) track(conn net.Conn) bool {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	if p.cut {
+		return false
+	}
+	p.conns[conn] = struct{}{}
+	return true
+}
+
+// untrack забывает закрытое соединение
+func (p *ChaosProxy) untrack(conn net.Conn) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	delete(p.conns, conn)
+}
+
+// close останавливает прокси и обрывает все соединения
+func (p *ChaosProxy) close() {
+	p.listener.Close()
+	p.Cut()
+	p.wg.Wait()
+}
@@ -0,0 +1,78 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"sync"
+	"testing"
+
+	"driver-service/internal/config"
+	"driver-service/internal/limits"
+
+	"github.com/stretchr/testify/require"
+)
+
+// configPath путь административного API ограничений (не версионируется)
+const configPath = "/api/v1/admin/config"
+
+// TestLimits ограничения API окружения, к которым Reset возвращает сервис: частота запросов
+// не ограничена, радиус поиска поблизости по умолчанию
+var TestLimits = config.LimitsConfig{Burst: 20, NearbyRadiusKm: limits.DefaultNearbyRadiusKm}
+
+// configFile файл конфигурации сервиса окружения, из которого перечитываются ограничения API.
+// Общий для копий окружения из ForTest.
+type configFile struct {
+	mu   sync.Mutex
+	path string
+}
+
+// load читает ограничения из файла, как config.ReloadLimits; без файла - значения по умолчанию
+func (f *configFile) load() (config.LimitsConfig, error) {
+	f.mu.Lock()
+	path := f.path
+	f.mu.Unlock()
+
+	return config.LoadLimits(path)
+}
+
+// set задает путь файла; пустой - файла нет
+func (f *configFile) set(path string) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	f.path = path
+}
+
+// WriteConfig записывает файл конфигурации сервиса окружения (YAML), как правка config.yaml на стенде:
+// сервис перечитывает из него ограничения API только по POST /api/v1/admin/config/reload (API.ReloadConfig),
+// как по SIGHUP. Файл действует до конца теста t или до Reset.
+func (env *TestEnvironment) WriteConfig(t *testing.T, content string) {
+	path := filepath.Join(t.TempDir(), "config.yaml")
+	require.NoError(t, os.WriteFile(path, []byte(content), 0o600))
+	env.configFile.set(path)
+}
+
+// WriteLimits записывает файл конфигурации с секцией limits из cfg
+func (env *TestEnvironment) WriteLimits(t *testing.T, cfg config.LimitsConfig) {
+	env.WriteConfig(t, fmt.Sprintf("limits:\n  requests_per_second: %g\n  burst: %d\n  nearby_radius_km: %g\n",
+		cfg.RequestsPerSecond, cfg.Burst, cfg.NearbyRadiusKm))
+}
+
+// resetLimits возвращает ограничения окружения к TestLimits и забывает файл конфигурации
+func (env *TestEnvironment) resetLimits() {
+	env.configFile.set("")
+	env.Limits.Update(TestLimits)
+}
+
+// ReloadConfig перечитывает ограничения API из файла конфигурации через административный API
+func (h *APITestHelper) ReloadConfig() *APIResponse {
+	return h.MakeRequest(APIRequest{Method: http.MethodPost, URL: configPath + "/reload"})
+}
+
+// GetLimits возвращает действующие ограничения API через административный API
+func (h *APITestHelper) GetLimits() *APIResponse {
+	return h.MakeRequest(APIRequest{Method: http.MethodGet, URL: configPath + "/limits"})
+}
@@ -0,0 +1,77 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+	"testing"
+
+	"driver-service/internal/consistency"
+
+	"github.com/jmoiron/sqlx"
+	"github.com/stretchr/testify/require"
+)
+
+// consistencyPath путь административного API проверки согласованности данных
+const consistencyPath = "/api/v1/admin/consistency"
+
+// CheckConsistency запускает проверку согласованности через административный API.
+// rules ограничивает проверку правилами с этими именами; без них выполняются все правила.
+func (h *APITestHelper) CheckConsistency(rules ...string) (*consistency.Report, *APIResponse) {
+	path := consistencyPath
+	if len(rules) > 0 {
+		path += "?rules=" + url.QueryEscape(strings.Join(rules, ","))
+	}
+
+	response := h.MakeRequest(APIRequest{Method: http.MethodGet, URL: path})
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var report consistency.Report
+	h.UnmarshalResponse(response, &report)
+	return &report, response
+}
+
+// ApplyRepairs выполняет предложенные в отчете исправления в одной транзакции
+func (tdb *TestDB) ApplyRepairs(t *testing.T, report *consistency.Report) {
+	err := tdb.Transaction(func(tx *sqlx.Tx) error {
+		for _, statement := range report.RepairStatements() {
+			if _, err := tx.Exec(statement); err != nil {
+				return fmt.Errorf("failed to apply repair %q: %w", statement, err)
+			}
+		}
+		return nil
+	})
+	require.NoError(t, err)
+}
+
+// WithoutConstraint снимает ограничение таблицы на время insert и возвращает его как NOT VALID:
+// ограничение снова действует для новых строк, а вставленные в обход него строки остаются.
+// Так в тестовой БД появляются данные, которые схема сейчас запрещает (старые дампы, ручные правки).
+func (tdb *TestDB) WithoutConstraint(t *testing.T, table, constraint string, insert func()) {
+	ctx := context.Background()
+
+	var definition string
+	require.NoError(t, tdb.GetContext(ctx, &definition,
+		"SELECT pg_get_constraintdef(oid) FROM pg_constraint WHERE conrelid = $1::regclass AND conname = $2",
+		table, constraint), "Ограничение %s.%s", table, constraint)
+
+	_, err := tdb.ExecContext(ctx, "ALTER TABLE "+table+" DROP CONSTRAINT "+constraint)
+	require.NoError(t, err)
+
+	insert()
+
+	_, err = tdb.ExecContext(ctx, "ALTER TABLE "+table+" ADD CONSTRAINT "+constraint+" "+definition+" NOT VALID")
+	require.NoError(t, err)
+}
+
+// ValidateConstraint проверяет ограничение, возвращенное WithoutConstraint, на всех строках таблицы
+func (tdb *TestDB) ValidateConstraint(table, constraint string) error {
+	_, err := tdb.ExecContext(context.Background(), "ALTER TABLE "+table+" VALIDATE CONSTRAINT "+constraint)
+	return err
+}
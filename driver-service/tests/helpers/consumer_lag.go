@@ -0,0 +1,272 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"os"
+	"runtime"
+	"strconv"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/nats-io/nats.go/jetstream"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения харнесса задержки консьюмера: задержка подтверждения каждого сообщения (например, "5ms"),
+// размер очереди событий и минимальная скорость догоняния в сообщениях в секунду
+const (
+	ConsumerLagEnv           = "CONSUMER_LAG"
+	ConsumerLagMessagesEnv   = "CONSUMER_LAG_MESSAGES"
+	ConsumerLagMinCatchUpEnv = "CONSUMER_LAG_MIN_CATCHUP"
+)
+
+// ConsumerLagOptions параметры харнесса: консьюмер событий сервиса подтверждает каждое сообщение с задержкой Lag,
+// пока публикуются Messages событий, затем задержка снимается и замеряется, как быстро он догоняет очередь
+type ConsumerLagOptions struct {
+	Stream      string
+	Durable     string
+	Subjects    []string // темы публикуются по кругу
+	Messages    int
+	PayloadSize int // по умолчанию 512
+	Lag         time.Duration
+	// MaxAckPending ограничение доставленных без подтверждения сообщений: остальная очередь остается
+	// на сервере, поэтому память консьюмера не зависит от ее размера
+	MaxAckPending  int
+	AckWait        time.Duration // больше времени ожидания сообщения в очереди консьюмера, чтобы не было повторных доставок
+	CatchUpTimeout time.Duration
+	SampleInterval time.Duration // период опроса CONSUMER.INFO и памяти процесса
+	// MinCatchUpRate минимальная скорость догоняния, сообщений в секунду
+	MinCatchUpRate float64
+	// MaxHeapGrowth допустимый рост памяти кучи процесса относительно начала замера
+	MaxHeapGrowth uint64
+}
+
+// DefaultConsumerLagOptions возвращает параметры по умолчанию для NATS в docker-compose.test.yml
+func DefaultConsumerLagOptions() ConsumerLagOptions {
+	return ConsumerLagOptions{
+		Stream:         "DRIVER_EVENTS_LAG",
+		Durable:        "driver-events-lagging",
+		Messages:       5000,
+		PayloadSize:    512,
+		Lag:            5 * time.Millisecond,
+		MaxAckPending:  100,
+		AckWait:        30 * time.Second,
+		CatchUpTimeout: time.Minute,
+		SampleInterval: 100 * time.Millisecond,
+		MinCatchUpRate: 500,
+		MaxHeapGrowth:  32 << 20,
+	}
+}
+
+// LoadConsumerLagOptions возвращает параметры по умолчанию с переопределениями из CONSUMER_LAG,
+// CONSUMER_LAG_MESSAGES и CONSUMER_LAG_MIN_CATCHUP
+func LoadConsumerLagOptions(t *testing.T) ConsumerLagOptions {
+	options := DefaultConsumerLagOptions()
+
+	if value := os.Getenv(ConsumerLagEnv); value != "" {
+		lag, err := time.ParseDuration(value)
+		require.NoError(t, err, "Некорректный %s: %q", ConsumerLagEnv, value)
+		options.Lag = lag
+	}
+	if value := os.Getenv(ConsumerLagMessagesEnv); value != "" {
+		messages, err := strconv.Atoi(value)
+		require.NoError(t, err, "Некорректный %s: %q", ConsumerLagMessagesEnv, value)
+		options.Messages = messages
+	}
+	if value := os.Getenv(ConsumerLagMinCatchUpEnv); value != "" {
+		rate, err := strconv.ParseFloat(value, 64)
+		require.NoError(t, err, "Некорректный %s: %q", ConsumerLagMinCatchUpEnv, value)
+		options.MinCatchUpRate = rate
+	}
+
+	return options
+}
+
+// ConsumerLagSample состояние консьюмера и память процесса в момент опроса
+type ConsumerLagSample struct {
+	Elapsed   time.Duration
+	State     ConsumerState
+	Acked     int
+	HeapInuse uint64
+}
+
+// ConsumerLagResult результат харнесса
+type ConsumerLagResult struct {
+	Publish        *NATSBenchmarkResult
+	Backlog        int           // недоставленные и неподтвержденные сообщения в момент снятия задержки
+	LagPhase       time.Duration // публикация с задержкой подтверждений
+	CatchUp        time.Duration // от снятия задержки до пустой очереди
+	CatchUpRate    float64       // сообщений в секунду
+	Drained        bool
+	Acked          int
+	PeakAckPending int
+	Redelivered    int
+	HeapBaseline   uint64
+	HeapPeak       uint64
+	Samples        []ConsumerLagSample
+}
+
+// HeapGrowth возвращает рост памяти кучи процесса за замер
+func (r *ConsumerLagResult) HeapGrowth() uint64 {
+	if r.HeapPeak < r.HeapBaseline {
+		return 0
+	}
+	return r.HeapPeak - r.HeapBaseline
+}
+
+// lagConsumer подтверждает сообщения pull-консьюмера JetStream по одному, выдерживая задержку перед каждым,
+// как обработчик событий сервиса
+type lagConsumer struct {
+	lag   atomic.Int64 // time.Duration
+	acked atomic.Int64
+}
+
+// handle подтверждает сообщение после задержки
+func (c *lagConsumer) handle(msg jetstream.Msg) {
+	if lag := time.Duration(c.lag.Load()); lag > 0 {
+		time.Sleep(lag)
+	}
+	if msg.Ack() == nil {
+		c.acked.Add(1)
+	}
+}
+
+// ConsumerLag создает в потоке options.Stream durable pull-консьюмер с ограничением MaxAckPending и подтверждает
+// его сообщения с задержкой options.Lag, пока публикуются options.Messages событий. После публикации задержка снимается
+// и консьюмер догоняет очередь; состояние консьюмера и память кучи процесса опрашиваются каждые SampleInterval.
+// Поток создается тестом (EnsureStream).
+func (h *NATSHelper) ConsumerLag(options ConsumerLagOptions) *ConsumerLagResult {
+	ctx, cancel := context.WithTimeout(context.Background(), natsReplyTimeout)
+	defer cancel()
+	pull, err := h.js.CreateOrUpdateConsumer(ctx, options.Stream, jetstream.ConsumerConfig{
+		Durable:       options.Durable,
+		AckPolicy:     jetstream.AckExplicitPolicy,
+		AckWait:       options.AckWait,
+		MaxAckPending: options.MaxAckPending,
+	})
+	require.NoError(h.t, err)
+
+	consumer := &lagConsumer{}
+	consumer.lag.Store(int64(options.Lag))
+	consume, err := pull.Consume(consumer.handle, jetstream.PullMaxMessages(options.MaxAckPending))
+	require.NoError(h.t, err)
+	defer consume.Stop()
+
+	result := &ConsumerLagResult{}
+	var memStats runtime.MemStats
+	runtime.GC()
+	runtime.ReadMemStats(&memStats)
+	result.HeapBaseline = memStats.HeapInuse
+
+	start := time.Now()
+	var (
+		mu      sync.Mutex
+		stop    = make(chan struct{})
+		sampled = make(chan struct{})
+	)
+	go func() {
+		defer close(sampled)
+		ticker := time.NewTicker(options.SampleInterval)
+		defer ticker.Stop()
+		for {
+			select {
+			case <-stop:
+				return
+			case <-ticker.C:
+			}
+
+			state, err := h.ConsumerState(options.Stream, options.Durable)
+			if err != nil {
+				continue
+			}
+			var memStats runtime.MemStats
+			runtime.ReadMemStats(&memStats)
+
+			mu.Lock()
+			result.Samples = append(result.Samples, ConsumerLagSample{
+				Elapsed:   time.Since(start),
+				State:     state,
+				Acked:     int(consumer.acked.Load()),
+				HeapInuse: memStats.HeapInuse,
+			})
+			mu.Unlock()
+		}
+	}()
+
+	// Публикация с задержкой подтверждений копит очередь на сервере
+	result.Publish = h.Benchmark(NATSBenchmarkOptions{
+		Operation:   "lagging consumer backlog",
+		Subjects:    options.Subjects,
+		Messages:    options.Messages,
+		Concurrency: 4,
+		Batch:       100,
+		PayloadSize: options.PayloadSize,
+		JetStream:   true,
+	})
+	result.LagPhase = time.Since(start)
+
+	state, err := h.ConsumerState(options.Stream, options.Durable)
+	require.NoError(h.t, err)
+	result.Backlog = state.NumPending + state.NumAckPending
+
+	// Снятие задержки: консьюмер догоняет очередь
+	consumer.lag.Store(0)
+	catchUpStart := time.Now()
+	deadline := catchUpStart.Add(options.CatchUpTimeout)
+	for time.Now().Before(deadline) {
+		if state, err = h.ConsumerState(options.Stream, options.Durable); err == nil && state.Drained() {
+			result.Drained = true
+			break
+		}
+		time.Sleep(10 * time.Millisecond)
+	}
+	result.CatchUp = time.Since(catchUpStart)
+	if result.CatchUp > 0 {
+		result.CatchUpRate = float64(result.Backlog) / result.CatchUp.Seconds()
+	}
+
+	close(stop)
+	<-sampled
+
+	result.Acked = int(consumer.acked.Load())
+	result.Redelivered = state.NumRedelivered
+	result.HeapPeak = result.HeapBaseline
+	for _, sample := range result.Samples {
+		result.PeakAckPending = max(result.PeakAckPending, sample.State.NumAckPending)
+		result.HeapPeak = max(result.HeapPeak, sample.HeapInuse)
+		result.Redelivered = max(result.Redelivered, sample.State.NumRedelivered)
+	}
+
+	h.t.Logf("Consumer lag %v: backlog %d after %v, caught up in %v (%.2f msg/s), drained: %v",
+		options.Lag, result.Backlog, result.LagPhase, result.CatchUp, result.CatchUpRate, result.Drained)
+	h.t.Logf("Acked: %d, peak ack pending: %d, redelivered: %d, heap in use: %d KB -> peak %d KB",
+		result.Acked, result.PeakAckPending, result.Redelivered, result.HeapBaseline/1024, result.HeapPeak/1024)
+	return result
+}
+
+// AssertConsumerCaughtUp проверяет, что консьюмер догнал очередь не медленнее options.MinCatchUpRate,
+// сервер не доставлял больше MaxAckPending сообщений без подтверждения, повторных доставок не было,
+// а память процесса не выросла больше MaxHeapGrowth
+func AssertConsumerCaughtUp(t *testing.T, result *ConsumerLagResult, options ConsumerLagOptions) {
+	t.Helper()
+
+	require.True(t, result.Drained, "Консьюмер не догнал очередь за %v", options.CatchUpTimeout)
+	assert.Zero(t, result.Publish.Errors, "Сообщения без подтверждения JetStream")
+	assert.Positive(t, result.Backlog, "Задержка подтверждений не создала очередь")
+	assert.Equal(t, options.Messages-result.Publish.Errors, result.Acked, "Каждое сообщение подтверждено один раз")
+	assert.LessOrEqual(t, result.PeakAckPending, options.MaxAckPending, "Неподтвержденные сообщения сверх max_ack_pending")
+	assert.Zero(t, result.Redelivered, "Повторные доставки: задержка превысила ack_wait")
+
+	if result.CatchUpRate < options.MinCatchUpRate {
+		t.Errorf("%s: consumer catch-up %.2f msg/s, limit %.2f msg/s", SLOBreachMarker, result.CatchUpRate, options.MinCatchUpRate)
+	}
+	if growth := result.HeapGrowth(); growth > options.MaxHeapGrowth {
+		t.Errorf("Heap grew by %d KB while consuming backlog of %d messages, limit %d KB",
+			growth/1024, result.Backlog, options.MaxHeapGrowth/1024)
+	}
+}
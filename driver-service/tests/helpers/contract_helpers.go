@@ -0,0 +1,62 @@
+//go:build integration
+
+package helpers
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// JSONKind тип значения в JSON ответе
+type JSONKind string
+
+const (
+	JSONString JSONKind = "string"
+	JSONNumber JSONKind = "number"
+	JSONBool   JSONKind = "bool"
+	JSONObject JSONKind = "object"
+	JSONArray  JSONKind = "array"
+)
+
+// APIContract обязательные поля ответа и их типы
+type APIContract map[string]JSONKind
+
+// AssertJSONContract проверяет, что в JSON объекте есть все поля контракта с ожидаемыми типами.
+// Дополнительные поля допускаются. Возвращает разобранный объект для дальнейших проверок.
+func AssertJSONContract(t *testing.T, body []byte, contract APIContract) map[string]interface{} {
+	t.Helper()
+
+	var object map[string]interface{}
+	require.NoError(t, json.Unmarshal(body, &object), "Ответ не является JSON объектом: %s", string(body))
+
+	for field, expected := range contract {
+		value, ok := object[field]
+		if !assert.True(t, ok, "Нет обязательного поля %q", field) {
+			continue
+		}
+		assert.Equal(t, expected, jsonKindOf(value), "Тип поля %q", field)
+	}
+
+	return object
+}
+
+// jsonKindOf возвращает тип значения, полученного из encoding/json
+func jsonKindOf(value interface{}) JSONKind {
+	switch value.(type) {
+	case string:
+		return JSONString
+	case float64:
+		return JSONNumber
+	case bool:
+		return JSONBool
+	case map[string]interface{}:
+		return JSONObject
+	case []interface{}:
+		return JSONArray
+	default:
+		return "null"
+	}
+}
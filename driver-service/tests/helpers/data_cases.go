@@ -0,0 +1,164 @@
+//go:build integration
+
+package helpers
+
+import (
+	"encoding/csv"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"runtime"
+	"strconv"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// APICase случай табличного теста, описанный во внешнем файле (JSON или CSV) в tests/testdata
+type APICase struct {
+	Name           string                 `json:"name"`
+	Set            map[string]interface{} `json:"set"`             // поля, заменяемые в базовом запросе
+	Unset          []string               `json:"unset"`           // поля, удаляемые из базового запроса
+	ExpectedStatus int                    `json:"expected_status"` // ожидаемый HTTP код
+	ExpectedCode   string                 `json:"expected_code"`   // ожидаемый код ошибки (не проверяется, если пуст)
+	ExpectedFields map[string]interface{} `json:"expected_fields"` // ожидаемые значения полей ответа
+}
+
+// Apply возвращает копию базового запроса с изменениями случая
+func (c APICase) Apply(base map[string]interface{}) map[string]interface{} {
+	request := make(map[string]interface{}, len(base))
+	for key, value := range base {
+		request[key] = value
+	}
+	for key, value := range c.Set {
+		request[key] = value
+	}
+	for _, key := range c.Unset {
+		delete(request, key)
+	}
+	return request
+}
+
+// AssertResponse проверяет ответ на соответствие ожиданиям случая
+func (c APICase) AssertResponse(t *testing.T, response *APIResponse) {
+	t.Helper()
+
+	require.Equal(t, c.ExpectedStatus, response.StatusCode, string(response.Body))
+	if c.ExpectedCode == "" && len(c.ExpectedFields) == 0 {
+		return
+	}
+
+	var body map[string]interface{}
+	require.NoError(t, json.Unmarshal(response.Body, &body), string(response.Body))
+
+	if c.ExpectedCode != "" {
+		assert.Equal(t, c.ExpectedCode, body["code"])
+	}
+	for field, expected := range c.ExpectedFields {
+		assert.Equal(t, expected, body[field], "Поле %q", field)
+	}
+}
+
+// TestDataPath возвращает путь к файлу в tests/testdata
+func TestDataPath(name string) string {
+	_, currentFile, _, _ := runtime.Caller(0)
+	return filepath.Join(filepath.Dir(currentFile), "..", "testdata", name)
+}
+
+// LoadAPICases загружает случаи из всех JSON и CSV файлов каталога tests/testdata/<dir>.
+// Новые случаи и файлы подхватываются без перекомпиляции тестов.
+func LoadAPICases(t *testing.T, dir string) map[string][]APICase {
+	files, err := filepath.Glob(filepath.Join(TestDataPath(dir), "*"))
+	require.NoError(t, err)
+
+	result := make(map[string][]APICase)
+	for _, file := range files {
+		switch strings.ToLower(filepath.Ext(file)) {
+		case ".json":
+			var cases []APICase
+			LoadTestData(t, filepath.Join(dir, filepath.Base(file)), &cases)
+			result[filepath.Base(file)] = cases
+		case ".csv":
+			result[filepath.Base(file)] = loadCSVCases(t, file)
+		}
+	}
+
+	require.NotEmpty(t, result, "Нет файлов со случаями в %s", TestDataPath(dir))
+	return result
+}
+
+// loadCSVCases читает случаи из CSV с колонками name,set,unset,expected_status,expected_code,expected_fields.
+// set и expected_fields записываются как "поле=значение;поле=значение", unset - как "поле;поле".
+// Значение разбирается как JSON (числа, true/false, null), иначе используется как строка.
+func loadCSVCases(t *testing.T, path string) []APICase {
+	file, err := os.Open(path)
+	require.NoError(t, err)
+	defer file.Close()
+
+	reader := csv.NewReader(file)
+	reader.Comment = '#'
+	records, err := reader.ReadAll()
+	require.NoError(t, err, "Некорректный CSV %s", path)
+	require.NotEmpty(t, records, "Пустой CSV %s", path)
+
+	columns := make(map[string]int)
+	for i, column := range records[0] {
+		columns[strings.TrimSpace(column)] = i
+	}
+	for _, required := range []string{"name", "expected_status"} {
+		_, ok := columns[required]
+		require.True(t, ok, "В %s нет колонки %q", path, required)
+	}
+
+	cell := func(record []string, column string) string {
+		i, ok := columns[column]
+		if !ok || i >= len(record) {
+			return ""
+		}
+		return strings.TrimSpace(record[i])
+	}
+
+	var cases []APICase
+	for line, record := range records[1:] {
+		status, err := strconv.Atoi(cell(record, "expected_status"))
+		require.NoError(t, err, "%s:%d: expected_status", path, line+2)
+
+		apiCase := APICase{
+			Name:           cell(record, "name"),
+			Set:            parseCSVAssignments(cell(record, "set")),
+			ExpectedStatus: status,
+			ExpectedCode:   cell(record, "expected_code"),
+			ExpectedFields: parseCSVAssignments(cell(record, "expected_fields")),
+		}
+		for _, field := range strings.Split(cell(record, "unset"), ";") {
+			if field = strings.TrimSpace(field); field != "" {
+				apiCase.Unset = append(apiCase.Unset, field)
+			}
+		}
+
+		cases = append(cases, apiCase)
+	}
+
+	return cases
+}
+
+// parseCSVAssignments разбирает строку вида "поле=значение;поле=значение"
+func parseCSVAssignments(value string) map[string]interface{} {
+	result := make(map[string]interface{})
+	for _, assignment := range strings.Split(value, ";") {
+		key, raw, found := strings.Cut(assignment, "=")
+		key = strings.TrimSpace(key)
+		if !found || key == "" {
+			continue
+		}
+
+		var parsed interface{}
+		if err := json.Unmarshal([]byte(raw), &parsed); err != nil {
+			parsed = raw
+		}
+		result[key] = parsed
+	}
+	return result
+}
@@ -0,0 +1,163 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// DRReportEnv файл, в который сценарий аварийного восстановления дописывает строку с метриками
+const DRReportEnv = "DR_REPORT"
+
+// Backup дамп тестовой БД, снятый планировщиком резервных копий
+type Backup struct {
+	Path       string
+	StartedAt  time.Time // момент запуска pg_dump: все записи, подтвержденные раньше, попадают в дамп
+	FinishedAt time.Time
+}
+
+// BackupScheduler периодически снимает дампы тестовой БД через pg_dump, как это делает
+// резервное копирование по расписанию, пока не будет остановлен
+type BackupScheduler struct {
+	tdb      *TestDB
+	pgDump   string
+	dir      string
+	interval time.Duration
+
+	mu      sync.Mutex
+	backups []Backup
+	err     error
+
+	cancel context.CancelFunc
+	done   chan struct{}
+}
+
+// StartBackups запускает планировщик, снимающий дамп tdb раз в interval. Первый дамп снимается сразу.
+func StartBackups(t *testing.T, tdb *TestDB, interval time.Duration) *BackupScheduler {
+	ctx, cancel := context.WithCancel(context.Background())
+	scheduler := &BackupScheduler{
+		tdb:      tdb,
+		pgDump:   pgTool(t, "pg_dump"),
+		dir:      t.TempDir(),
+		interval: interval,
+		cancel:   cancel,
+		done:     make(chan struct{}),
+	}
+
+	go scheduler.run(ctx)
+	return scheduler
+}
+
+// run снимает дампы до отмены ctx или первой ошибки
+func (s *BackupScheduler) run(ctx context.Context) {
+	defer close(s.done)
+
+	ticker := time.NewTicker(s.interval)
+	defer ticker.Stop()
+
+	for n := 1; ; n++ {
+		if err := s.backup(ctx, n); err != nil {
+			if ctx.Err() == nil {
+				s.mu.Lock()
+				s.err = err
+				s.mu.Unlock()
+			}
+			return
+		}
+
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+		}
+	}
+}
+
+// backup снимает один дамп и запоминает его после успешного завершения pg_dump
+func (s *BackupScheduler) backup(ctx context.Context, n int) error {
+	path := filepath.Join(s.dir, fmt.Sprintf("%s_%04d.dump", s.tdb.dbName, n))
+	startedAt := time.Now()
+
+	output, err := s.tdb.pgToolCommand(ctx, s.pgDump, "--format=custom", "--file", path).CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("pg_dump failed: %w: %s", err, output)
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.backups = append(s.backups, Backup{Path: path, StartedAt: startedAt, FinishedAt: time.Now()})
+	return nil
+}
+
+// Count возвращает количество успешно снятых дампов
+func (s *BackupScheduler) Count() int {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return len(s.backups)
+}
+
+// Latest возвращает последний успешно снятый дамп
+func (s *BackupScheduler) Latest() (Backup, bool) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	if len(s.backups) == 0 {
+		return Backup{}, false
+	}
+	return s.backups[len(s.backups)-1], true
+}
+
+// Stop останавливает планировщик. Дамп, прерванный остановкой, не учитывается;
+// возвращается ошибка дампа, завершившегося неудачей до остановки.
+func (s *BackupScheduler) Stop() error {
+	s.cancel()
+	<-s.done
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.err
+}
+
+// DRMetrics метрики сценария аварийного восстановления
+type DRMetrics struct {
+	Seed           int64
+	KilledAfter    time.Duration // время от начала нагрузки до отказа
+	RPO            time.Duration // окно потери данных: от запуска последнего дампа до отказа
+	RTO            time.Duration // время восстановления: от отказа до прохождения проверок работоспособности
+	BackupsTaken   int
+	AcceptedWrites int
+	LostWrites     int
+}
+
+// WriteDRReport выводит метрики в лог теста и дописывает их строкой logfmt в файл DR_REPORT, если он задан.
+// Строка заканчивается метаданными прогона (коммит, миграции, версии окружения).
+func WriteDRReport(t *testing.T, metrics DRMetrics) {
+	line := fmt.Sprintf(
+		"test=%s seed=%d killed_after_ms=%d rpo_ms=%d rto_ms=%d backups=%d accepted_writes=%d lost_writes=%d %s",
+		t.Name(), metrics.Seed, metrics.KilledAfter.Milliseconds(), metrics.RPO.Milliseconds(),
+		metrics.RTO.Milliseconds(), metrics.BackupsTaken, metrics.AcceptedWrites, metrics.LostWrites,
+		CurrentRunMetadata().Logfmt(),
+	)
+	t.Log(line)
+
+	path := os.Getenv(DRReportEnv)
+	if path == "" {
+		return
+	}
+
+	require.NoError(t, os.MkdirAll(filepath.Dir(path), 0o755))
+	file, err := os.OpenFile(path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0o644)
+	require.NoError(t, err)
+	defer file.Close()
+
+	_, err = fmt.Fprintln(file, line)
+	require.NoError(t, err)
+}
@@ -0,0 +1,177 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"sort"
+	"strconv"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/google/uuid"
+)
+
+// ErrNoDriverAvailable поблизости от заказа не осталось свободных водителей
+var ErrNoDriverAvailable = errors.New("no driver available")
+
+// OrderRequest запрос на назначение заказа
+type OrderRequest struct {
+	OrderID   uuid.UUID
+	Latitude  float64
+	Longitude float64
+}
+
+// OrderAssignment результат назначения заказа водителю
+type OrderAssignment struct {
+	OrderID    uuid.UUID
+	DriverID   uuid.UUID
+	DistanceKm float64
+}
+
+// EventData возвращает данные события order.assigned
+func (a *OrderAssignment) EventData() map[string]interface{} {
+	return map[string]interface{}{
+		"order_id":    a.OrderID.String(),
+		"driver_id":   a.DriverID.String(),
+		"distance_km": a.DistanceKm,
+	}
+}
+
+// Dispatcher имитирует диспетчеризацию сервиса заказов поверх API driver-service: ищет водителей поблизости,
+// выбирает ближайшего свободного (on_shift) и занимает его переходом в busy. Назначение публикуется
+// событием order.assigned, так что исход виден и в состоянии API, и в перехваченных событиях.
+type Dispatcher struct {
+	api      *APITestHelper
+	events   *EventRecorder
+	radiusKm float64
+}
+
+// NewDispatcher создает диспетчер с радиусом поиска водителей radiusKm
+func NewDispatcher(api *APITestHelper, events *EventRecorder, radiusKm float64) *Dispatcher {
+	return &Dispatcher{api: api, events: events, radiusKm: radiusKm}
+}
+
+// Dispatch назначает заказ ближайшему свободному водителю. Если водителя одновременно занял другой заказ,
+// переходит к следующему кандидату; если свободных не осталось, возвращает ErrNoDriverAvailable.
+// Метод не использует testing.T и может вызываться из нескольких горутин.
+func (d *Dispatcher) Dispatch(ctx context.Context, order OrderRequest) (*OrderAssignment, error) {
+	candidates, err := d.candidates(order)
+	if err != nil {
+		return nil, err
+	}
+
+	for _, candidate := range candidates {
+		status, err := d.driverStatus(candidate.DriverID)
+		if err != nil {
+			return nil, err
+		}
+		if status != entities.StatusOnShift {
+			continue
+		}
+
+		claimed, err := d.claim(candidate.DriverID)
+		if err != nil {
+			return nil, err
+		}
+		if !claimed {
+			continue
+		}
+
+		assignment := &OrderAssignment{
+			OrderID:    order.OrderID,
+			DriverID:   candidate.DriverID,
+			DistanceKm: candidate.DistanceKm,
+		}
+		if err := d.events.PublishDriverEvent(ctx, "order.assigned", candidate.DriverID, assignment.EventData()); err != nil {
+			return nil, fmt.Errorf("failed to publish order.assigned: %w", err)
+		}
+		return assignment, nil
+	}
+
+	return nil, ErrNoDriverAvailable
+}
+
+// candidates возвращает водителей поблизости от заказа по возрастанию расстояния
+func (d *Dispatcher) candidates(order OrderRequest) ([]NearbyCandidate, error) {
+	response := d.api.MakeRequest(APIRequest{
+		Method: http.MethodGet,
+		URL:    d.api.APIPath("/locations/nearby"),
+		QueryParams: map[string]string{
+			"latitude":  strconv.FormatFloat(order.Latitude, 'f', -1, 64),
+			"longitude": strconv.FormatFloat(order.Longitude, 'f', -1, 64),
+			"radius_km": strconv.FormatFloat(d.radiusKm, 'f', -1, 64),
+			"limit":     "100",
+		},
+	})
+	if response.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("nearby search failed: status %d: %s", response.StatusCode, string(response.Body))
+	}
+
+	var nearby httpHandlers.NearbyDriversResponse
+	if err := json.Unmarshal(response.Body, &nearby); err != nil {
+		return nil, fmt.Errorf("failed to decode nearby drivers: %w", err)
+	}
+
+	candidates := make([]NearbyCandidate, 0, len(nearby.Drivers))
+	for _, driver := range nearby.Drivers {
+		candidates = append(candidates, NearbyCandidate{
+			DriverID:   driver.DriverID,
+			Latitude:   driver.Latitude,
+			Longitude:  driver.Longitude,
+			DistanceKm: HaversineKm(order.Latitude, order.Longitude, driver.Latitude, driver.Longitude),
+		})
+	}
+
+	sort.SliceStable(candidates, func(i, j int) bool {
+		return candidates[i].DistanceKm < candidates[j].DistanceKm
+	})
+	return candidates, nil
+}
+
+// driverStatus возвращает текущий статус водителя по данным API
+func (d *Dispatcher) driverStatus(driverID uuid.UUID) (entities.Status, error) {
+	response := d.api.MakeRequest(APIRequest{
+		Method: http.MethodGet,
+		URL:    d.api.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+	})
+	if response.StatusCode != http.StatusOK {
+		return "", fmt.Errorf("failed to get driver %s: status %d: %s", driverID, response.StatusCode, string(response.Body))
+	}
+
+	var driver httpHandlers.DriverResponse
+	if err := json.Unmarshal(response.Body, &driver); err != nil {
+		return "", fmt.Errorf("failed to decode driver %s: %w", driverID, err)
+	}
+	return driver.Status, nil
+}
+
+// claim переводит водителя в busy. Возвращает false, если водителя успел занять другой заказ.
+func (d *Dispatcher) claim(driverID uuid.UUID) (bool, error) {
+	response := d.api.MakeRequest(APIRequest{
+		Method: http.MethodPatch,
+		URL:    d.api.APIPath(fmt.Sprintf("/drivers/%s/status", driverID)),
+		Body:   map[string]string{"status": string(entities.StatusBusy)},
+	})
+	if response.StatusCode == http.StatusOK {
+		return true, nil
+	}
+	if response.StatusCode == http.StatusConflict {
+		return false, nil
+	}
+
+	// Если статус сменился между проверкой и переходом, сервис отклоняет переход busy -> busy как недопустимый
+	status, err := d.driverStatus(driverID)
+	if err != nil {
+		return false, err
+	}
+	if status != entities.StatusOnShift {
+		return false, nil
+	}
+	return false, fmt.Errorf("failed to claim driver %s: status %d: %s", driverID, response.StatusCode, string(response.Body))
+}
@@ -0,0 +1,155 @@
+//go:build integration
+
+package helpers
+
+import (
+	"net/http"
+	"strconv"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/stretchr/testify/require"
+)
+
+// DriverListFilter фильтры, сортировка и пагинация списка водителей (GET /api/v1/drivers).
+// Незаданные (нулевые) поля в запрос не попадают.
+type DriverListFilter struct {
+	Status                entities.Status
+	MinRating             *float64
+	MaxRating             *float64
+	City                  string
+	CreatedAfter          time.Time
+	LicenseExpiringBefore time.Time // учитывается только дата
+	Search                string    // полнотекстовый поиск по ФИО
+	SortBy                string
+	SortDirection         string // asc или desc
+	Limit                 int
+	Offset                int
+	Cursor                string // next_cursor предыдущей страницы
+}
+
+// QueryParams возвращает query параметры запроса списка
+func (f DriverListFilter) QueryParams() map[string]string {
+	params := map[string]string{}
+	if f.Status != "" {
+		params["status"] = string(f.Status)
+	}
+	if f.MinRating != nil {
+		params["min_rating"] = strconv.FormatFloat(*f.MinRating, 'f', -1, 64)
+	}
+	if f.MaxRating != nil {
+		params["max_rating"] = strconv.FormatFloat(*f.MaxRating, 'f', -1, 64)
+	}
+	if f.City != "" {
+		params["city"] = f.City
+	}
+	if !f.CreatedAfter.IsZero() {
+		params["created_after"] = f.CreatedAfter.UTC().Format(time.RFC3339Nano)
+	}
+	if !f.LicenseExpiringBefore.IsZero() {
+		params["license_expiring_before"] = f.LicenseExpiringBefore.Format(time.DateOnly)
+	}
+	if f.Search != "" {
+		params["search"] = f.Search
+	}
+	if f.SortBy != "" {
+		params["sort_by"] = f.SortBy
+	}
+	if f.SortDirection != "" {
+		params["sort_direction"] = f.SortDirection
+	}
+	if f.Limit > 0 {
+		params["limit"] = strconv.Itoa(f.Limit)
+	}
+	if f.Offset > 0 {
+		params["offset"] = strconv.Itoa(f.Offset)
+	}
+	if f.Cursor != "" {
+		params["cursor"] = f.Cursor
+	}
+	return params
+}
+
+// ListDrivers запрашивает одну страницу списка водителей
+func (h *APITestHelper) ListDrivers(filter DriverListFilter) (*httpHandlers.ListDriversResponse, *APIResponse) {
+	response := h.MakeRequest(APIRequest{
+		Method:      http.MethodGet,
+		URL:         h.APIPath("/drivers"),
+		QueryParams: filter.QueryParams(),
+	})
+
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var list httpHandlers.ListDriversResponse
+	h.UnmarshalResponse(response, &list)
+	return &list, response
+}
+
+// ListAllDrivers обходит список водителей страницами по pageSize (offset из filter игнорируется)
+// и возвращает водителей всех страниц по порядку и total первой страницы
+func (h *APITestHelper) ListAllDrivers(filter DriverListFilter, pageSize int) ([]*httpHandlers.DriverResponse, int) {
+	filter.Limit = pageSize
+
+	var drivers []*httpHandlers.DriverResponse
+	total := -1
+	for filter.Offset = 0; ; filter.Offset += pageSize {
+		page, response := h.ListDrivers(filter)
+		require.NotNil(h.t, page, "offset %d: %s", filter.Offset, string(response.Body))
+		if total < 0 {
+			total = page.Total
+		}
+
+		drivers = append(drivers, page.Drivers...)
+		if !page.HasMore {
+			return drivers, total
+		}
+		require.NotEmpty(h.t, page.Drivers, "has_more при пустой странице, offset %d", filter.Offset)
+	}
+}
+
+// ListDriversByCursor обходит список водителей страницами по pageSize, переходя по next_cursor
+// (offset и cursor из filter игнорируются). afterPage, если задан, вызывается после каждой страницы,
+// кроме последней - например, чтобы изменить данные между запросами.
+func (h *APITestHelper) ListDriversByCursor(filter DriverListFilter, pageSize int, afterPage func(page int)) []*httpHandlers.DriverResponse {
+	var drivers []*httpHandlers.DriverResponse
+	h.WalkDriversByCursor(filter, pageSize, func(page int, list *httpHandlers.ListDriversResponse, _ *APIResponse) {
+		drivers = append(drivers, list.Drivers...)
+		if afterPage != nil && list.HasMore {
+			afterPage(page)
+		}
+	})
+	return drivers
+}
+
+// WalkDriversByCursor обходит список водителей страницами по pageSize, переходя по next_cursor, и передает
+// каждую страницу visit (offset и cursor из filter игнорируются). Страницы не накапливаются, поэтому
+// обход подходит для списков, которые не помещаются в память теста.
+func (h *APITestHelper) WalkDriversByCursor(
+	filter DriverListFilter,
+	pageSize int,
+	visit func(page int, list *httpHandlers.ListDriversResponse, response *APIResponse),
+) {
+	filter.Limit = pageSize
+	filter.Offset = 0
+	filter.Cursor = ""
+
+	for page := 0; ; page++ {
+		list, response := h.ListDrivers(filter)
+		require.NotNil(h.t, list, "page %d: %s", page, string(response.Body))
+		require.LessOrEqual(h.t, len(list.Drivers), pageSize, "page %d", page)
+
+		if !list.HasMore {
+			require.Empty(h.t, list.NextCursor, "next_cursor на последней странице, page %d", page)
+			visit(page, list, response)
+			return
+		}
+		require.NotEmpty(h.t, list.NextCursor, "has_more без next_cursor, page %d", page)
+
+		visit(page, list, response)
+		filter.Cursor = list.NextCursor
+	}
+}
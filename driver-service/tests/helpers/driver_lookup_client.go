@@ -0,0 +1,40 @@
+//go:build integration
+
+package helpers
+
+import (
+	"net/http"
+	"strconv"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+)
+
+// LookupDrivers ищет водителей по телефону или email (GET /api/v1/admin/drivers/lookup).
+// Пустые Phone и Email в запрос не попадают, include_deleted передается только при IncludeDeleted.
+func (h *APITestHelper) LookupDrivers(lookup entities.DriverLookup) (*httpHandlers.LookupDriversResponse, *APIResponse) {
+	params := map[string]string{}
+	if lookup.Phone != "" {
+		params["phone"] = lookup.Phone
+	}
+	if lookup.Email != "" {
+		params["email"] = lookup.Email
+	}
+	if lookup.IncludeDeleted {
+		params["include_deleted"] = strconv.FormatBool(lookup.IncludeDeleted)
+	}
+
+	response := h.MakeRequest(APIRequest{
+		Method:      http.MethodGet,
+		URL:         h.APIPath("/admin/drivers/lookup"),
+		QueryParams: params,
+	})
+
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var found httpHandlers.LookupDriversResponse
+	h.UnmarshalResponse(response, &found)
+	return &found, response
+}
@@ -0,0 +1,28 @@
+//go:build integration
+
+package helpers
+
+import (
+	"net/http"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/google/uuid"
+)
+
+// GetDriverStatuses запрашивает статусы водителей одним запросом (POST /api/v1/drivers/statuses)
+func (h *APITestHelper) GetDriverStatuses(ids []uuid.UUID) (*httpHandlers.DriverStatusesResponse, *APIResponse) {
+	response := h.MakeRequest(APIRequest{
+		Method: http.MethodPost,
+		URL:    h.APIPath("/drivers/statuses"),
+		Body:   httpHandlers.DriverStatusesRequest{DriverIDs: ids},
+	})
+
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var statuses httpHandlers.DriverStatusesResponse
+	h.UnmarshalResponse(response, &statuses)
+	return &statuses, response
+}
@@ -0,0 +1,41 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"net/http"
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// EarningsSummary ответ API заработка водителя за период
+type EarningsSummary struct {
+	DriverID      uuid.UUID   `json:"driver_id"`
+	TotalTrips    int         `json:"total_trips"`
+	GrossEarnings float64     `json:"gross_earnings"`
+	Commission    float64     `json:"commission"`
+	NetEarnings   float64     `json:"net_earnings"`
+	PaymentIDs    []uuid.UUID `json:"payment_ids"`
+}
+
+// GetDriverEarnings запрашивает заработок водителя за период через API
+func (h *APITestHelper) GetDriverEarnings(driverID uuid.UUID, from, to time.Time) (*EarningsSummary, *APIResponse) {
+	response := h.MakeRequest(APIRequest{
+		Method: http.MethodGet,
+		URL:    h.APIPath(fmt.Sprintf("/drivers/%s/earnings", driverID)),
+		QueryParams: map[string]string{
+			"from": from.UTC().Format(time.RFC3339),
+			"to":   to.UTC().Format(time.RFC3339),
+		},
+	})
+
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var summary EarningsSummary
+	h.UnmarshalResponse(response, &summary)
+	return &summary, response
+}
@@ -0,0 +1,283 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"runtime"
+	"sort"
+	"strings"
+	"sync"
+
+	"go.uber.org/zap/zapcore"
+	"gopkg.in/yaml.v3"
+)
+
+// EndpointCoverageEnv путь к файлу отчета о покрытии эндпоинтов; если не задан, отчет не формируется
+const EndpointCoverageEnv = "ENDPOINT_COVERAGE"
+
+// httpRequestLogMessage сообщение, которым middleware.Logger логирует каждый HTTP запрос
+const httpRequestLogMessage = "HTTP Request"
+
+// APIOperation операция из OpenAPI спецификации сервиса
+type APIOperation struct {
+	Method      string
+	Path        string // шаблон пути, например /api/v1/drivers/{id}
+	OperationID string
+	// Implemented false для операций, помеченных в спецификации x-implemented: false
+	Implemented bool
+	// MaxBodyBytes лимит размера тела запроса из x-max-body-bytes, 0 - лимит не документирован
+	MaxBodyBytes int64
+}
+
+// OpenAPISpecPath возвращает путь к api/openapi/driver-api.yaml
+func OpenAPISpecPath() string {
+	_, currentFile, _, _ := runtime.Caller(0)
+	return filepath.Join(filepath.Dir(currentFile), "..", "..", "api", "openapi", "driver-api.yaml")
+}
+
+// LoadOpenAPIOperations читает операции из OpenAPI спецификации, отсортированные по пути и методу
+func LoadOpenAPIOperations() ([]APIOperation, error) {
+	data, err := os.ReadFile(OpenAPISpecPath())
+	if err != nil {
+		return nil, fmt.Errorf("failed to read OpenAPI spec: %w", err)
+	}
+
+	var spec struct {
+		Paths map[string]map[string]interface{} `yaml:"paths"`
+	}
+	if err := yaml.Unmarshal(data, &spec); err != nil {
+		return nil, fmt.Errorf("failed to parse OpenAPI spec: %w", err)
+	}
+
+	methods := []string{"get", "post", "put", "patch", "delete"}
+
+	var operations []APIOperation
+	for path, item := range spec.Paths {
+		for _, method := range methods {
+			raw, ok := item[method].(map[string]interface{})
+			if !ok {
+				continue
+			}
+
+			operation := APIOperation{
+				Method:      strings.ToUpper(method),
+				Path:        path,
+				Implemented: true,
+			}
+			operation.OperationID, _ = raw["operationId"].(string)
+			if implemented, ok := raw["x-implemented"].(bool); ok {
+				operation.Implemented = implemented
+			}
+			if limit, ok := raw["x-max-body-bytes"].(int); ok {
+				operation.MaxBodyBytes = int64(limit)
+			}
+			operations = append(operations, operation)
+		}
+	}
+
+	sort.Slice(operations, func(i, j int) bool {
+		if operations[i].Path != operations[j].Path {
+			return operations[i].Path < operations[j].Path
+		}
+		return operations[i].Method < operations[j].Method
+	})
+	return operations, nil
+}
+
+// MatchOperation находит операцию для метода и фактического пути запроса.
+// Литеральные сегменты приоритетнее параметров: /drivers/active не считается запросом к /drivers/{id}.
+func MatchOperation(operations []APIOperation, method, path string) (APIOperation, bool) {
+	requestSegments := strings.Split(strings.Trim(path, "/"), "/")
+
+	best, bestLiterals := -1, -1
+	for i, operation := range operations {
+		if operation.Method != method {
+			continue
+		}
+
+		templateSegments := strings.Split(strings.Trim(operation.Path, "/"), "/")
+		if len(templateSegments) != len(requestSegments) {
+			continue
+		}
+
+		literals, matched := 0, true
+		for k, segment := range templateSegments {
+			switch {
+			case strings.HasPrefix(segment, "{") && strings.HasSuffix(segment, "}"):
+			case segment == requestSegments[k]:
+				literals++
+			default:
+				matched = false
+			}
+			if !matched {
+				break
+			}
+		}
+
+		if matched && literals > bestLiterals {
+			best, bestLiterals = i, literals
+		}
+	}
+
+	if best < 0 {
+		return APIOperation{}, false
+	}
+	return operations[best], true
+}
+
+// endpointRequests счетчик выполненных за прогон HTTP запросов по "МЕТОД путь"
+var endpointRequests = struct {
+	sync.Mutex
+	counts map[string]int
+}{counts: make(map[string]int)}
+
+// recordEndpointRequest учитывает выполненный запрос
+func recordEndpointRequest(method, path string) {
+	if i := strings.Index(path, "?"); i >= 0 {
+		path = path[:i]
+	}
+
+	endpointRequests.Lock()
+	defer endpointRequests.Unlock()
+
+	endpointRequests.counts[method+" "+path]++
+}
+
+// observeRequestLog учитывает запрос по записи middleware.Logger. Запросы учитываются через лог сервиса,
+// поэтому в покрытие попадают и тесты, вызывающие router.ServeHTTP напрямую, а не через APITestHelper.
+func observeRequestLog(entry zapcore.Entry, fields []zapcore.Field) {
+	if entry.Message != httpRequestLogMessage {
+		return
+	}
+
+	var method, path string
+	for _, field := range fields {
+		switch field.Key {
+		case "method":
+			method = field.String
+		case "path":
+			path = field.String
+		}
+	}
+	if method != "" && path != "" {
+		recordEndpointRequest(method, path)
+	}
+}
+
+// endpointCoverageCore ядро zap, которое только учитывает HTTP запросы для отчета о покрытии
+type endpointCoverageCore struct {
+	fields []zapcore.Field
+}
+
+// Enabled реализует zapcore.Core
+func (c endpointCoverageCore) Enabled(level zapcore.Level) bool {
+	return zapcore.InfoLevel.Enabled(level)
+}
+
+// With реализует zapcore.Core
+func (c endpointCoverageCore) With(fields []zapcore.Field) zapcore.Core {
+	return endpointCoverageCore{fields: append(append([]zapcore.Field(nil), c.fields...), fields...)}
+}
+
+// Check реализует zapcore.Core
+func (c endpointCoverageCore) Check(entry zapcore.Entry, checked *zapcore.CheckedEntry) *zapcore.CheckedEntry {
+	if c.Enabled(entry.Level) && entry.Message == httpRequestLogMessage {
+		return checked.AddCore(entry, c)
+	}
+	return checked
+}
+
+// Write реализует zapcore.Core
+func (c endpointCoverageCore) Write(entry zapcore.Entry, fields []zapcore.Field) error {
+	observeRequestLog(entry, append(append([]zapcore.Field(nil), c.fields...), fields...))
+	return nil
+}
+
+// Sync реализует zapcore.Core
+func (c endpointCoverageCore) Sync() error {
+	return nil
+}
+
+// EndpointCoverageReport формирует отчет: покрытые и непокрытые операции спецификации и запросы вне ее
+func EndpointCoverageReport() (string, error) {
+	operations, err := LoadOpenAPIOperations()
+	if err != nil {
+		return "", err
+	}
+
+	endpointRequests.Lock()
+	counts := make(map[string]int, len(endpointRequests.counts))
+	for key, count := range endpointRequests.counts {
+		counts[key] = count
+	}
+	endpointRequests.Unlock()
+
+	hits := make(map[string]int)
+	var undocumented []string
+	for key, count := range counts {
+		method, path, _ := strings.Cut(key, " ")
+		operation, ok := MatchOperation(operations, method, path)
+		if !ok {
+			undocumented = append(undocumented, fmt.Sprintf("  %-6s %s (%d)", method, path, count))
+			continue
+		}
+		hits[operation.Method+" "+operation.Path] += count
+	}
+	sort.Strings(undocumented)
+
+	var covered, untested []string
+	for _, operation := range operations {
+		key := operation.Method + " " + operation.Path
+		if count := hits[key]; count > 0 {
+			covered = append(covered, fmt.Sprintf("  %-6s %s (%d)", operation.Method, operation.Path, count))
+			continue
+		}
+
+		line := fmt.Sprintf("  %-6s %s", operation.Method, operation.Path)
+		if !operation.Implemented {
+			line += " [не реализован]"
+		}
+		untested = append(untested, line)
+	}
+
+	var report strings.Builder
+	fmt.Fprintf(&report, "Endpoint coverage: %d/%d operations (%.1f%%)\n",
+		len(covered), len(operations), 100*float64(len(covered))/float64(len(operations)))
+
+	sections := []struct {
+		title string
+		lines []string
+	}{
+		{"Untested", untested},
+		{"Covered (requests)", covered},
+		{"Not in OpenAPI spec (requests)", undocumented},
+	}
+	for _, section := range sections {
+		if len(section.lines) == 0 {
+			continue
+		}
+		fmt.Fprintf(&report, "\n%s:\n%s\n", section.title, strings.Join(section.lines, "\n"))
+	}
+
+	return report.String(), nil
+}
+
+// WriteEndpointCoverageReport сохраняет отчет о покрытии в файл из ENDPOINT_COVERAGE и выводит его.
+// Вызывается из TestMain после прогона; без ENDPOINT_COVERAGE ничего не делает.
+func WriteEndpointCoverageReport() error {
+	path := os.Getenv(EndpointCoverageEnv)
+	if path == "" {
+		return nil
+	}
+
+	report, err := EndpointCoverageReport()
+	if err != nil {
+		return err
+	}
+
+	fmt.Print("\n" + report)
+	return os.WriteFile(path, []byte(runMetadataHeader()+report), 0o644)
+}
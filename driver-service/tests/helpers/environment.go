@@ -0,0 +1,258 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/consistency"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/features"
+	"driver-service/internal/infrastructure/messaging"
+	"driver-service/internal/infrastructure/storage"
+	"driver-service/internal/infrastructure/webhooks"
+	httpServer "driver-service/internal/interfaces/http"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/internal/limits"
+	"driver-service/internal/repositories"
+
+	"github.com/gin-gonic/gin"
+	"go.uber.org/zap"
+)
+
+// TestEnvironment полностью собранный сервис поверх тестовой БД: репозитории, сервисы, роутер и записи событий.
+// Используется внешними тестовыми пакетами (см. tests/packs), чтобы не собирать окружение самостоятельно.
+type TestEnvironment struct {
+	Ctx    context.Context
+	DB     *TestDB
+	Logger *zap.Logger
+	Events *EventRecorder
+
+	// Webhooks доставляет события сервиса поверх Events зарегистрированным адресатам (см. StartWebhookReceiver)
+	Webhooks *webhooks.Publisher
+
+	DriverRepo    repositories.DriverRepository
+	DocumentRepo  repositories.DocumentRepository
+	LocationRepo  repositories.LocationRepository
+	ShiftRepo     repositories.ShiftRepository
+	HeartbeatRepo repositories.HeartbeatRepository
+	EventInbox    repositories.EventInboxRepository
+	PaymentRepo   repositories.PaymentRepository
+
+	// ConnectionRepo потери связи с водителями (см. services.JobDetectConnectionLoss)
+	ConnectionRepo repositories.ConnectionRepository
+
+	DriverService   services.DriverService
+	LocationService services.LocationService
+
+	// DispatchFeed поток позиций водителей в границах области; LocationService записывает через него
+	DispatchFeed *services.DispatchFeed
+
+	// Heartbeats сигналы присутствия приложения водителя (см. API.Heartbeat)
+	Heartbeats services.HeartbeatService
+
+	// OrderEvents эффекты событий сервисов заказов и платежей (см. StartEventSubscriber)
+	OrderEvents services.OrderEventService
+
+	// Earnings заработок водителей по оплатам, записанным OrderEvents (см. API.GetDriverEarnings)
+	Earnings services.EarningsService
+
+	// Stats статистика водителей (см. API.GetDriverStats)
+	Stats services.StatsService
+
+	// Shifts смены водителей (см. API.StartShift)
+	Shifts services.ShiftService
+
+	// Analytics аналитика по парку водителей (см. API.GetDriverHeatmap)
+	Analytics services.AnalyticsService
+
+	// Jobs фоновые задачи; запускаются вручную через API.RunJob
+	Jobs services.JobService
+
+	// Features флаги функциональности: начальные значения из TEST_FEATURE_FLAGS,
+	// переключаются через SetFeature, API.SetFeature или RunFeatureMatrix
+	Features        *features.Flags
+	initialFeatures map[features.Flag]bool
+	geoCache        *services.CachedLocationService
+
+	// zones геозоны, события пересечения которых публикует LocationService (задаются EnableGeofences)
+	zones []services.Zone
+
+	// Limits ограничения API (TestLimits); перечитываются из файла WriteConfig через API.ReloadConfig
+	Limits     *limits.Limits
+	configFile *configFile
+
+	// Storage и DocumentUploads задаются EnableStorage, если тесту нужно объектное хранилище
+	Storage         *storage.S3Client
+	DocumentUploads services.DocumentUploadService
+
+	// Avatars и AvatarCDNURL задаются EnableAvatars: фотографии водителей и адрес тестового CDN перед хранилищем
+	Avatars      services.AvatarService
+	AvatarCDNURL string
+
+	// Notifications задается StartPushGateway поверх имитации шлюзов FCM и APNS
+	Notifications services.NotificationService
+
+	// Messaging задается EnableMessaging: письма и SMS водителям через MailHog и заглушку SMS провайдера
+	Messaging *messaging.Notifier
+
+	Router *gin.Engine
+	API    *APITestHelper
+	server *httpServer.Server
+
+	// Span текущего теста (задается ForTest), logs перенаправляет в него логи сервиса
+	Span *TestSpan
+	logs *LogCapture
+
+	// Shared данные, которые наборы тестов передают зависимым наборам (например, ID созданного водителя)
+	Shared map[string]interface{}
+}
+
+// NewTestEnvironment создает тестовую БД и собирает поверх нее сервис
+func NewTestEnvironment(t *testing.T) *TestEnvironment {
+	gin.SetMode(gin.TestMode)
+
+	env := &TestEnvironment{
+		Ctx:    context.Background(),
+		DB:     SetupTestDB(t),
+		Events: NewEventRecorder(),
+		Shared: make(map[string]interface{}),
+	}
+	env.Logger, env.logs = NewLogCapture(CreateTestLogger(t))
+	env.Webhooks = webhooks.NewPublisher(env.Events, TestWebhookConfig, env.Logger)
+
+	env.Features = featureFlagsFromEnv(t)
+	env.initialFeatures = env.Features.Snapshot()
+	env.Limits = limits.New(TestLimits)
+	env.configFile = &configFile{}
+
+	env.buildServices()
+	env.buildServer(t)
+
+	return env
+}
+
+// SwitchDB переключает окружение на другую тестовую БД (например, восстановленную из дампа):
+// репозитории, сервисы и роутер собираются заново. Сервисы, подключенные EnableStorage,
+// StartPushGateway, EnableMessaging или EnableHealthChecks, нужно подключить повторно.
+func (env *TestEnvironment) SwitchDB(t *testing.T, db *TestDB) {
+	env.DB = db
+	env.buildServices()
+	env.buildServer(t)
+}
+
+// EnableGeofences подключает события геозон zones, как geofence.zones в конфигурации сервиса:
+// сервисы и роутер собираются заново
+func (env *TestEnvironment) EnableGeofences(t *testing.T, zones []services.Zone) {
+	env.zones = zones
+	env.buildServices()
+	env.buildServer(t)
+}
+
+// buildServices собирает репозитории и сервисы поверх текущей БД окружения
+func (env *TestEnvironment) buildServices() {
+	env.DriverRepo = repositories.NewDriverRepository(env.DB.DB, env.Logger)
+	env.DocumentRepo = repositories.NewDocumentRepository(env.DB.DB, env.Logger)
+	env.LocationRepo = repositories.NewLocationRepository(env.DB.DB, env.Logger)
+	env.ShiftRepo = repositories.NewShiftRepository(env.DB.DB, env.Logger)
+	env.HeartbeatRepo = repositories.NewHeartbeatRepository(env.DB.DB, env.Logger)
+	env.EventInbox = repositories.NewEventInboxRepository(env.DB.DB, env.Logger)
+	env.PaymentRepo = repositories.NewPaymentRepository(env.DB.DB, env.Logger)
+	env.ConnectionRepo = repositories.NewConnectionRepository(env.DB.DB, env.Logger)
+
+	env.geoCache = services.NewCachedLocationService(
+		services.NewAvailabilityLocationService(
+			services.NewGeofenceLocationService(
+				services.NewLocationService(env.LocationRepo, env.DriverRepo, env.Webhooks, env.Logger),
+				env.LocationRepo, env.zones, env.Webhooks, env.Logger),
+			env.ConnectionRepo, env.Webhooks, env.Logger),
+		env.Features, TestGeoCacheTTL)
+	env.DispatchFeed = services.NewDispatchFeed(env.geoCache, env.Logger)
+	env.LocationService = env.DispatchFeed
+
+	// Как в cmd/server: события смены статуса и удаления водителя сбрасывают кэш поиска поблизости
+	statusEvents := env.geoCache.EvictingPublisher(env.Webhooks)
+	env.DriverService = services.NewStrictDriverService(
+		services.NewDriverService(env.DriverRepo, env.DocumentRepo, statusEvents, env.Logger), env.Features)
+	env.Heartbeats = services.NewHeartbeatService(env.DriverRepo, env.HeartbeatRepo, env.LocationService,
+		statusEvents, TestHeartbeatMinInterval, env.Logger)
+	env.OrderEvents = services.NewOrderEventService(env.EventInbox, env.DriverService, env.Logger)
+	env.Earnings = services.NewEarningsService(env.DriverRepo, env.PaymentRepo, env.Logger)
+	env.Stats = services.NewStatsService(env.DriverRepo, env.Logger)
+	env.Shifts = services.NewShiftService(env.ShiftRepo, env.DriverRepo, statusEvents, env.Logger)
+	env.Analytics = services.NewAnalyticsService(env.LocationRepo, env.Logger)
+	env.Jobs = services.NewJobService(env.DriverRepo, env.DocumentRepo, env.LocationRepo, env.ShiftRepo, env.HeartbeatRepo,
+		env.ConnectionRepo, statusEvents, TestMaxShiftDuration, TestLocationRetention, TestHeartbeatTimeout, TestVerificationTimeout, env.Logger)
+}
+
+// buildServer собирает роутер и APITestHelper поверх текущих сервисов окружения
+func (env *TestEnvironment) buildServer(t *testing.T) {
+	driverHandler := httpHandlers.NewDriverHandler(env.DriverService, env.Logger)
+	locationHandler := httpHandlers.NewLocationHandler(env.LocationService, env.Logger)
+	locationHandler.SetLimits(env.Limits)
+
+	cfg := &config.Config{
+		Server: config.ServerConfig{
+			HTTPPort:    8001,
+			Environment: "test",
+			Timeout:     30 * time.Second,
+		},
+	}
+
+	server := httpServer.NewServer(cfg, env.Logger, driverHandler, locationHandler)
+	server.SetLimits(env.Limits)
+	server.RegisterJobRoutes(httpHandlers.NewJobsHandler(env.Jobs, env.Logger))
+	server.RegisterConsistencyRoutes(httpHandlers.NewConsistencyHandler(
+		consistency.NewChecker(env.DB.DB, env.Logger, consistency.DefaultRules(consistency.DefaultLocationStaleAfter)...),
+		env.Logger,
+	))
+	server.RegisterAdminDriverRoutes(driverHandler)
+	server.RegisterFeatureRoutes(httpHandlers.NewFeaturesHandler(env.Features, env.Logger))
+	server.RegisterLimitsRoutes(httpHandlers.NewLimitsHandler(env.Limits, env.configFile.load, env.Logger))
+	server.RegisterDispatchFeedRoutes(httpHandlers.NewDispatchFeedHandler(env.DispatchFeed, env.Logger))
+	server.RegisterHeartbeatRoutes(httpHandlers.NewHeartbeatHandler(env.Heartbeats, TestHeartbeatMinInterval, env.Logger))
+	server.RegisterEarningsRoutes(httpHandlers.NewEarningsHandler(env.Earnings, env.Logger))
+	server.RegisterStatsRoutes(httpHandlers.NewStatsHandler(env.Stats, env.Logger))
+	server.RegisterShiftRoutes(httpHandlers.NewShiftHandler(env.Shifts, env.Logger))
+	server.RegisterAnalyticsRoutes(httpHandlers.NewAnalyticsHandler(env.Analytics, env.Logger))
+	env.server = server
+	env.Router = server.GetRouter()
+	env.API = NewAPITestHelper(env.Router, t)
+}
+
+// ForTest возвращает копию окружения для подтеста t: APITestHelper привязан к t, а логи сервиса
+// и запросы к API собираются в span теста и выводятся только при его падении
+func (env *TestEnvironment) ForTest(t *testing.T) *TestEnvironment {
+	testEnv := *env
+	testEnv.Span = StartTestSpan(t, env.logs)
+	testEnv.API = NewAPITestHelper(env.Router, t).WithSpan(testEnv.Span)
+	return &testEnv
+}
+
+// Reset очищает таблицы, записанные события, историю запросов и общие данные между тестами
+// и возвращает флаги функциональности и ограничения API к начальным значениям
+func (env *TestEnvironment) Reset(t *testing.T) {
+	TrackTest(t)
+	env.DB.CleanupTables(t)
+	env.Events.Reset()
+	env.Features.Restore(env.initialFeatures)
+	env.resetLimits()
+	env.geoCache.Invalidate()
+
+	// История запросов env.API относится к текущему тесту и прикладывается к нему при падении
+	env.API.History().Clear()
+	t.Cleanup(func() { env.API.History().finish(t) })
+
+	for key := range env.Shared {
+		delete(env.Shared, key)
+	}
+}
+
+// Close дожидается начатых доставок webhooks и удаляет тестовую БД
+func (env *TestEnvironment) Close(t *testing.T) {
+	env.Webhooks.Close()
+	env.DB.TeardownTestDB(t)
+}
@@ -0,0 +1,158 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"runtime"
+	"sort"
+	"strings"
+	"sync"
+
+	"gopkg.in/yaml.v3"
+)
+
+// EventCoverageEnv путь к файлу отчета о покрытии типов событий; если не задан, отчет не формируется
+const EventCoverageEnv = "EVENT_COVERAGE"
+
+// Направления событий в каталоге
+const (
+	EventPublished = "published"
+	EventConsumed  = "consumed"
+)
+
+// CatalogEvent тип события из каталога api/events/catalog.yaml
+type CatalogEvent struct {
+	Subject     string `yaml:"subject"`
+	Description string `yaml:"description"`
+	Implemented bool   `yaml:"implemented"`
+	Direction   string `yaml:"-"`
+}
+
+// EventCatalogPath возвращает путь к api/events/catalog.yaml
+func EventCatalogPath() string {
+	_, currentFile, _, _ := runtime.Caller(0)
+	return filepath.Join(filepath.Dir(currentFile), "..", "..", "api", "events", "catalog.yaml")
+}
+
+// LoadEventCatalog читает каталог событий: сначала публикуемые, затем потребляемые
+func LoadEventCatalog() ([]CatalogEvent, error) {
+	data, err := os.ReadFile(EventCatalogPath())
+	if err != nil {
+		return nil, fmt.Errorf("failed to read event catalog: %w", err)
+	}
+
+	var catalog struct {
+		Published []CatalogEvent `yaml:"published"`
+		Consumed  []CatalogEvent `yaml:"consumed"`
+	}
+	if err := yaml.Unmarshal(data, &catalog); err != nil {
+		return nil, fmt.Errorf("failed to parse event catalog: %w", err)
+	}
+
+	var events []CatalogEvent
+	for _, event := range catalog.Published {
+		event.Direction = EventPublished
+		events = append(events, event)
+	}
+	for _, event := range catalog.Consumed {
+		event.Direction = EventConsumed
+		events = append(events, event)
+	}
+	return events, nil
+}
+
+// observedEvents счетчик типов событий, опубликованных сервисом или переданных ему тестами за прогон
+var observedEvents = struct {
+	sync.Mutex
+	counts map[string]int
+}{counts: make(map[string]int)}
+
+// TrackEvent учитывает событие в отчете о покрытии. EventRecorder вызывает его сам;
+// собственные реализации EventPublisher в тестах должны вызывать его явно.
+func TrackEvent(eventType string) {
+	observedEvents.Lock()
+	defer observedEvents.Unlock()
+
+	observedEvents.counts[eventType]++
+}
+
+// EventCoverageReport формирует отчет: встреченные и ни разу не встреченные типы из каталога и события вне его
+func EventCoverageReport() (string, error) {
+	catalog, err := LoadEventCatalog()
+	if err != nil {
+		return "", err
+	}
+
+	observedEvents.Lock()
+	counts := make(map[string]int, len(observedEvents.counts))
+	for eventType, count := range observedEvents.counts {
+		counts[eventType] = count
+	}
+	observedEvents.Unlock()
+
+	declared := make(map[string]bool, len(catalog))
+	var seen, missing []string
+	for _, event := range catalog {
+		declared[event.Subject] = true
+
+		if count := counts[event.Subject]; count > 0 {
+			seen = append(seen, fmt.Sprintf("  %-9s %s (%d)", event.Direction, event.Subject, count))
+			continue
+		}
+
+		line := fmt.Sprintf("  %-9s %s", event.Direction, event.Subject)
+		if !event.Implemented {
+			line += " [не реализовано]"
+		}
+		missing = append(missing, line)
+	}
+
+	var undeclared []string
+	for eventType, count := range counts {
+		if !declared[eventType] {
+			undeclared = append(undeclared, fmt.Sprintf("  %s (%d)", eventType, count))
+		}
+	}
+	sort.Strings(undeclared)
+
+	var report strings.Builder
+	fmt.Fprintf(&report, "Event coverage: %d/%d event types (%.1f%%)\n",
+		len(seen), len(catalog), 100*float64(len(seen))/float64(len(catalog)))
+
+	sections := []struct {
+		title string
+		lines []string
+	}{
+		{"Never seen", missing},
+		{"Seen (events)", seen},
+		{"Not in event catalog (events)", undeclared},
+	}
+	for _, section := range sections {
+		if len(section.lines) == 0 {
+			continue
+		}
+		fmt.Fprintf(&report, "\n%s:\n%s\n", section.title, strings.Join(section.lines, "\n"))
+	}
+
+	return report.String(), nil
+}
+
+// WriteEventCoverageReport сохраняет отчет о покрытии событий в файл из EVENT_COVERAGE и выводит его.
+// Вызывается из TestMain после прогона; без EVENT_COVERAGE ничего не делает.
+func WriteEventCoverageReport() error {
+	path := os.Getenv(EventCoverageEnv)
+	if path == "" {
+		return nil
+	}
+
+	report, err := EventCoverageReport()
+	if err != nil {
+		return err
+	}
+
+	fmt.Print("\n" + report)
+	return os.WriteFile(path, []byte(runMetadataHeader()+report), 0o644)
+}
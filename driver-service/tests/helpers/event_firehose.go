@@ -0,0 +1,73 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/infrastructure/nats"
+
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// EventFirehose публикует события сервиса одного типа в тестовый NATS тем же nats.Publisher, которым
+// их публикует сервис с nats.publish_events: тема - тип события, данные в JSON, заголовки Nats-Msg-Id,
+// nats.DriverIDHeader и nats.PublishedAtHeader. Окружение собирает сервисы поверх EventRecorder,
+// поэтому события перехватываются из recorder и передаются публикатору.
+type EventFirehose struct {
+	t         *testing.T
+	mu        sync.Mutex
+	publisher *nats.Publisher
+	published int
+	err       error // первая ошибка публикации; дальше события не публикуются
+}
+
+// StartEventFirehose публикует события eventType из recorder в NATS до конца теста или recorder.Reset.
+// Опубликованные события в recorder не записываются (см. EventRecorder.Forward).
+func (h *NATSHelper) StartEventFirehose(recorder *EventRecorder, eventType string) *EventFirehose {
+	publisher, err := nats.NewPublisher(config.NATSConfig{URL: h.url, ClientID: "driver-service-firehose"}, zap.NewNop())
+	require.NoError(h.t, err)
+	h.t.Cleanup(publisher.Close)
+
+	f := &EventFirehose{t: h.t, publisher: publisher}
+	recorder.Forward(eventType, f.publish)
+	return f
+}
+
+// publish публикует событие; вызывается в горутине запроса, который его опубликовал
+func (f *EventFirehose) publish(event RecordedEvent) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	if f.err != nil {
+		return
+	}
+	if err := f.publisher.PublishDriverEvent(context.Background(), event.EventType, event.DriverID, event.Data); err != nil {
+		f.err = err
+		return
+	}
+	f.published++
+}
+
+// Drain ждет, пока сервер NATS примет опубликованные события и передаст их подписчикам,
+// и возвращает количество опубликованных событий
+func (f *EventFirehose) Drain() int {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	require.NoError(f.t, f.err, "Событие не опубликовано в NATS")
+	ctx, cancel := context.WithTimeout(context.Background(), natsReplyTimeout)
+	defer cancel()
+	require.NoError(f.t, f.publisher.Flush(ctx))
+	return f.published
+}
+
+// PublishedAt возвращает время публикации события сервисом из заголовка nats.PublishedAtHeader
+func PublishedAt(message *NATSMessage) (time.Time, error) {
+	return time.Parse(time.RFC3339Nano, message.Header[nats.PublishedAtHeader])
+}
@@ -0,0 +1,135 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"sync"
+	"time"
+
+	"driver-service/internal/domain/services"
+
+	"github.com/google/uuid"
+)
+
+// RecordedEvent событие, опубликованное сервисом во время теста
+type RecordedEvent struct {
+	EventType   string
+	DriverID    uuid.UUID
+	Data        interface{}
+	Timestamp   time.Time
+	Traceparent string // трасса входящего события, при обработке которого опубликовано событие
+}
+
+// DataMap возвращает данные события в виде map (или nil, если формат другой)
+func (e RecordedEvent) DataMap() map[string]interface{} {
+	data, _ := e.Data.(map[string]interface{})
+	return data
+}
+
+// EventRecorder реализация EventPublisher, запоминающая все опубликованные события
+type EventRecorder struct {
+	mu       sync.Mutex
+	events   []RecordedEvent
+	clock    ScenarioClock                  // источник меток времени событий; nil - реальное время
+	forwards map[string]func(RecordedEvent) // тип события -> получатель вместо записи (см. Forward)
+}
+
+// NewEventRecorder создает новый EventRecorder
+func NewEventRecorder() *EventRecorder {
+	return &EventRecorder{}
+}
+
+// PublishDriverEvent сохраняет событие вместо отправки в NATS
+func (r *EventRecorder) PublishDriverEvent(ctx context.Context, eventType string, driverID uuid.UUID, data interface{}) error {
+	TrackEvent(eventType)
+
+	r.mu.Lock()
+	event := RecordedEvent{
+		EventType:   eventType,
+		DriverID:    driverID,
+		Data:        data,
+		Timestamp:   r.now(),
+		Traceparent: services.TraceparentFromContext(ctx),
+	}
+	forward := r.forwards[eventType]
+	if forward == nil {
+		r.events = append(r.events, event)
+	}
+	r.mu.Unlock()
+
+	if forward != nil {
+		forward(event)
+	}
+	return nil
+}
+
+// Forward передает события eventType функции forward вместо записи (до Reset): поток событий большого
+// парка водителей не копится в памяти теста. forward вызывается в горутине публикующего запроса.
+func (r *EventRecorder) Forward(eventType string, forward func(RecordedEvent)) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	if r.forwards == nil {
+		r.forwards = make(map[string]func(RecordedEvent))
+	}
+	r.forwards[eventType] = forward
+}
+
+// SetClock задает часы сценария, по которым записываются метки времени событий (до Reset)
+func (r *EventRecorder) SetClock(clock ScenarioClock) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	r.clock = clock
+}
+
+// now возвращает время по часам сценария или реальное время; вызывается под r.mu
+func (r *EventRecorder) now() time.Time {
+	if r.clock != nil {
+		return r.clock.Now()
+	}
+	return time.Now()
+}
+
+// Events возвращает копию всех записанных событий в порядке публикации
+func (r *EventRecorder) Events() []RecordedEvent {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	events := make([]RecordedEvent, len(r.events))
+	copy(events, r.events)
+	return events
+}
+
+// EventsOfType возвращает события указанного типа
+func (r *EventRecorder) EventsOfType(eventType string) []RecordedEvent {
+	var result []RecordedEvent
+	for _, event := range r.Events() {
+		if event.EventType == eventType {
+			result = append(result, event)
+		}
+	}
+	return result
+}
+
+// EventsForDriver возвращает события указанного типа для конкретного водителя
+func (r *EventRecorder) EventsForDriver(driverID uuid.UUID, eventType string) []RecordedEvent {
+	var result []RecordedEvent
+	for _, event := range r.EventsOfType(eventType) {
+		if event.DriverID == driverID {
+			result = append(result, event)
+		}
+	}
+	return result
+}
+
+// Reset очищает записанные события и снимает пересылку Forward
+func (r *EventRecorder) Reset() {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	r.events = nil
+	r.clock = nil
+	r.forwards = nil
+}
@@ -0,0 +1,212 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"encoding/json"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/infrastructure/nats"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// eventSubscribeTimeout сколько ждать подписки сервиса на события в NATS
+const eventSubscribeTimeout = 5 * time.Second
+
+// StartEventSubscriber подписывает сервис окружения на события сервисов заказов и платежей в тестовом NATS
+// до конца теста t. order.assigned обрабатывает Notifications окружения, поэтому перед его публикацией
+// нужен StartPushGateway. Возвращает помощник тестового NATS; без NATS тест пропускается.
+func (env *TestEnvironment) StartEventSubscriber(t *testing.T) *NATSHelper {
+	h := NewNATSHelper(t)
+	env.StartEventConsumer(t, h, EventStream{}, env.OrderEvents)
+	return h
+}
+
+// EventStream поток JetStream, из которого читает подписчик, и его durable-консьюмер, который подписчик
+// создает сам; пустой Stream - подписка core NATS на темы событий
+type EventStream struct {
+	Stream     string
+	Durable    string
+	AckWait    time.Duration // пусто - ack_wait по умолчанию сервера
+	MaxDeliver int           // пусто - без ограничения
+}
+
+// EventConsumer подписчик сервиса окружения на события в тестовом NATS, который тест может перезапускать
+// посреди обработки, как при рестарте сервиса
+type EventConsumer struct {
+	t      *testing.T
+	config config.NATSConfig
+	env    *TestEnvironment
+	orders services.OrderEventService
+
+	cancel      context.CancelFunc
+	done        chan struct{}
+	restarts    int
+	subscribers []*nats.Subscriber // подписчики всех запусков, для Stats
+}
+
+// StartEventConsumer подписывает сервис окружения на события до конца теста t: из потока stream, если он задан
+// (см. NATSHelper.EnsureStream), иначе на темы событий. Эффекты order.completed и payment.processed
+// применяет orders (env.OrderEvents или обертка теста над ним).
+func (env *TestEnvironment) StartEventConsumer(
+	t *testing.T,
+	h *NATSHelper,
+	stream EventStream,
+	orders services.OrderEventService,
+) *EventConsumer {
+	return env.startEventConsumer(t, h.url, stream, orders)
+}
+
+// StartEventConsumerThrough как StartEventConsumer, но подписчик подключается к NATS через прокси chaos:
+// задержки, потери пакетов и отказ NATS в chaos доходят до обработки событий. Без тестового NATS тест пропускается.
+func (env *TestEnvironment) StartEventConsumerThrough(
+	t *testing.T,
+	chaos *DependencyChaos,
+	stream EventStream,
+	orders services.OrderEventService,
+) *EventConsumer {
+	if chaos.NATS == nil {
+		t.Skip("Тестовый NATS недоступен, прокси NATS не запущен")
+	}
+	return env.startEventConsumer(t, chaos.NATS.URL, stream, orders)
+}
+
+// startEventConsumer подписывает сервис окружения на события NATS по адресу url до конца теста t
+func (env *TestEnvironment) startEventConsumer(
+	t *testing.T,
+	url string,
+	stream EventStream,
+	orders services.OrderEventService,
+) *EventConsumer {
+	c := &EventConsumer{
+		t: t,
+		config: config.NATSConfig{
+			URL:            url,
+			ClientID:       "driver-service-tests",
+			ConnectTimeout: 2 * time.Second,
+			ReconnectDelay: 100 * time.Millisecond,
+			MaxReconnect:   -1,
+			Stream:         stream.Stream,
+			Durable:        stream.Durable,
+			AckWait:        stream.AckWait,
+			MaxDeliver:     stream.MaxDeliver,
+		},
+		env:    env,
+		orders: orders,
+	}
+	c.start()
+	t.Cleanup(c.stop)
+	return c
+}
+
+// Restart останавливает подписчика, прерывая обработку текущего сообщения до подтверждения, и подписывает заново
+func (c *EventConsumer) Restart() {
+	c.stop()
+	c.start()
+	c.restarts++
+}
+
+// Restarts возвращает количество перезапусков
+func (c *EventConsumer) Restarts() int {
+	return c.restarts
+}
+
+// Stats возвращает счетчики результатов обработки сообщений за все запуски подписчика
+func (c *EventConsumer) Stats() nats.SubscriberStats {
+	var total nats.SubscriberStats
+	for _, subscriber := range c.subscribers {
+		stats := subscriber.Stats()
+		total.Processed += stats.Processed
+		total.Failed += stats.Failed
+		total.Unprocessable += stats.Unprocessable
+	}
+	return total
+}
+
+// start запускает подписчика и ждет подписки
+func (c *EventConsumer) start() {
+	subscriber := nats.NewSubscriber(c.config, c.env.Logger)
+	nats.RegisterOrderHandlers(subscriber, c.orders, c.env.Notifications)
+	c.subscribers = append(c.subscribers, subscriber)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	c.cancel = cancel
+	c.done = make(chan struct{})
+	go func(done chan struct{}) {
+		defer close(done)
+		subscriber.Run(ctx)
+	}(c.done)
+
+	select {
+	case <-subscriber.Subscribed():
+	case <-time.After(eventSubscribeTimeout):
+		c.t.Fatalf("Сервис не подписался на события в NATS %s за %v", c.config.URL, eventSubscribeTimeout)
+	}
+}
+
+// stop останавливает подписчика и ждет его завершения
+func (c *EventConsumer) stop() {
+	if c.cancel == nil {
+		return
+	}
+	c.cancel()
+	<-c.done
+	c.cancel = nil
+}
+
+// PublishOrderEvent публикует событие сервиса заказов order.* с заголовками header (traceparent, Nats-Msg-Id)
+func (h *NATSHelper) PublishOrderEvent(subject string, orderID, driverID uuid.UUID, header map[string]string) {
+	payload, err := json.Marshal(map[string]string{
+		"order_id":  orderID.String(),
+		"driver_id": driverID.String(),
+	})
+	require.NoError(h.t, err)
+
+	TrackEvent(subject)
+	h.Publish(subject, header, payload)
+}
+
+// OrderCompletedPayload возвращает данные события order.completed
+func OrderCompletedPayload(orderID, driverID uuid.UUID, distanceKm float64) []byte {
+	payload, _ := json.Marshal(map[string]interface{}{
+		"order_id":    orderID.String(),
+		"driver_id":   driverID.String(),
+		"distance_km": distanceKm,
+	})
+	return payload
+}
+
+// PaymentProcessedPayload возвращает данные события payment.processed
+func PaymentProcessedPayload(paymentID, orderID, driverID uuid.UUID, amount float64) []byte {
+	payload, _ := json.Marshal(map[string]interface{}{
+		"payment_id": paymentID.String(),
+		"order_id":   orderID.String(),
+		"driver_id":  driverID.String(),
+		"amount":     amount,
+	})
+	return payload
+}
+
+// NewTraceparent возвращает заголовок W3C Trace Context с новыми trace id и span id;
+// sampled задает флаг записи трассы
+func NewTraceparent(sampled bool) string {
+	flags := "00"
+	if sampled {
+		flags = "01"
+	}
+	return "00-" + newTraceID() + "-" + newOTLPSpanID() + "-" + flags
+}
+
+// TraceHeader возвращает заголовки события с трассой traceparent и новым Nats-Msg-Id
+func TraceHeader(traceparent string) map[string]string {
+	return map[string]string{
+		services.TraceparentHeader: traceparent,
+		services.MessageIDHeader:   uuid.NewString(),
+	}
+}
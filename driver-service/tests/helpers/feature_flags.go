@@ -0,0 +1,111 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/features"
+
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения флагов функциональности
+const (
+	// TestFeatureFlagsEnv начальные значения флагов окружения, например "geo_cache,strict_validation=off"
+	TestFeatureFlagsEnv = "TEST_FEATURE_FLAGS"
+
+	// FeatureMatrixEnv включает режим матрицы в RunFeatureMatrix: "all" перебирает все флаги теста,
+	// список через запятую ("geo_cache,strict_validation") - только перечисленные
+	FeatureMatrixEnv = "FEATURE_MATRIX"
+)
+
+// TestGeoCacheTTL время жизни кэша поиска поблизости в тестовом окружении
+const TestGeoCacheTTL = time.Minute
+
+// featuresPath путь административного API флагов функциональности (не версионируется)
+const featuresPath = "/api/v1/admin/features"
+
+// featureFlagsFromEnv создает флаги окружения из TEST_FEATURE_FLAGS
+func featureFlagsFromEnv(t *testing.T) *features.Flags {
+	values, err := features.Parse(os.Getenv(TestFeatureFlagsEnv))
+	require.NoError(t, err, "invalid %s", TestFeatureFlagsEnv)
+	return features.New(values)
+}
+
+// SetFeature переключает флаг окружения и сбрасывает кэш поиска поблизости,
+// чтобы результаты, полученные до переключения, не влияли на тест
+func (env *TestEnvironment) SetFeature(t *testing.T, flag features.Flag, enabled bool) {
+	require.NoError(t, env.Features.Set(flag, enabled))
+	env.geoCache.Invalidate()
+}
+
+// RunFeatureMatrix запускает fn подтестом для каждой комбинации флагов и восстанавливает флаги после.
+// Без FEATURE_MATRIX fn выполняется один раз с текущими флагами окружения; в режиме матрицы
+// перебираются флаги из flags, выбранные FEATURE_MATRIX, остальные остаются как есть.
+// Подтесты называются по значениям всех флагов, например "geo_cache=on,strict_validation=off".
+func RunFeatureMatrix(t *testing.T, env *TestEnvironment, flags []features.Flag, fn func(t *testing.T)) {
+	snapshot := env.Features.Snapshot()
+	defer func() {
+		env.Features.Restore(snapshot)
+		env.geoCache.Invalidate()
+	}()
+
+	varied := matrixFlags(t, flags)
+	for combination := 0; combination < 1<<len(varied); combination++ {
+		for i, flag := range varied {
+			env.SetFeature(t, flag, combination&(1<<i) != 0)
+		}
+		t.Run(env.Features.String(), fn)
+	}
+}
+
+// matrixFlags возвращает флаги из flags, которые нужно перебрать в текущем режиме
+func matrixFlags(t *testing.T, flags []features.Flag) []features.Flag {
+	mode := strings.TrimSpace(os.Getenv(FeatureMatrixEnv))
+	if mode == "" {
+		return nil
+	}
+	if mode == "all" {
+		return flags
+	}
+
+	selected, err := features.Parse(mode)
+	require.NoError(t, err, "invalid %s", FeatureMatrixEnv)
+
+	varied := make([]features.Flag, 0, len(flags))
+	for _, flag := range flags {
+		if _, ok := selected[flag]; ok {
+			varied = append(varied, flag)
+		}
+	}
+	return varied
+}
+
+// ListFeatures запрашивает значения флагов через административный API
+func (h *APITestHelper) ListFeatures() (map[features.Flag]bool, *APIResponse) {
+	response := h.MakeRequest(APIRequest{Method: http.MethodGet, URL: featuresPath})
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var result struct {
+		Features map[features.Flag]bool `json:"features"`
+	}
+	h.UnmarshalResponse(response, &result)
+	return result.Features, response
+}
+
+// SetFeature переключает флаг через административный API
+func (h *APITestHelper) SetFeature(flag features.Flag, enabled bool) *APIResponse {
+	return h.MakeRequest(APIRequest{
+		Method: http.MethodPut,
+		URL:    fmt.Sprintf("%s/%s", featuresPath, flag),
+		Body:   map[string]bool{"enabled": enabled},
+	})
+}
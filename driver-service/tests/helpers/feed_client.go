@@ -0,0 +1,199 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"strconv"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/google/uuid"
+)
+
+// feedHTTPClient открывает для каждого потока отдельное соединение и закрывает его вместе с потоком,
+// чтобы открытые дескрипторы после отключения клиентов относились только к сервису
+var feedHTTPClient = &http.Client{
+	Transport: &http.Transport{DisableKeepAlives: true},
+}
+
+// StartHTTPServer запускает настоящий HTTP сервер поверх роутера окружения и возвращает его адрес.
+// Нужен потоковым клиентам: роутер в памяти (APITestHelper) не держит соединения открытыми.
+// Сервер останавливается по завершении теста.
+func (env *TestEnvironment) StartHTTPServer(t *testing.T) string {
+	server := httptest.NewServer(env.Router)
+	t.Cleanup(server.Close)
+	return server.URL
+}
+
+// FeedClient клиент потока позиций водителей /api/v1/locations/feed (Server-Sent Events)
+type FeedClient struct {
+	response *http.Response
+	cancel   context.CancelFunc
+	done     chan struct{}
+
+	mu       sync.Mutex
+	received []httpHandlers.LocationResponse
+	err      error
+}
+
+// ConnectFeed подключается к потоку позиций в границах bounds и ждет подтверждения подписки:
+// после возврата клиент получает все местоположения, сохраненные сервисом
+func ConnectFeed(baseURL string, bounds entities.GeoBounds) (*FeedClient, error) {
+	query := url.Values{}
+	query.Set("sw_lat", strconv.FormatFloat(bounds.SouthWest.Latitude, 'f', -1, 64))
+	query.Set("sw_lon", strconv.FormatFloat(bounds.SouthWest.Longitude, 'f', -1, 64))
+	query.Set("ne_lat", strconv.FormatFloat(bounds.NorthEast.Latitude, 'f', -1, 64))
+	query.Set("ne_lon", strconv.FormatFloat(bounds.NorthEast.Longitude, 'f', -1, 64))
+
+	ctx, cancel := context.WithCancel(context.Background())
+	request, err := http.NewRequestWithContext(ctx, http.MethodGet, baseURL+"/api/v1/locations/feed?"+query.Encode(), nil)
+	if err != nil {
+		cancel()
+		return nil, err
+	}
+
+	response, err := feedHTTPClient.Do(request)
+	if err != nil {
+		cancel()
+		return nil, err
+	}
+	if response.StatusCode != http.StatusOK {
+		response.Body.Close()
+		cancel()
+		return nil, fmt.Errorf("feed responded with status %d", response.StatusCode)
+	}
+
+	reader := bufio.NewReader(response.Body)
+	event, _, err := readFeedEvent(reader)
+	if err == nil && event != httpHandlers.FeedEventSubscribed {
+		err = fmt.Errorf("expected %q event, got %q", httpHandlers.FeedEventSubscribed, event)
+	}
+	if err != nil {
+		response.Body.Close()
+		cancel()
+		return nil, err
+	}
+
+	client := &FeedClient{
+		response: response,
+		cancel:   cancel,
+		done:     make(chan struct{}),
+	}
+	go client.read(ctx, reader)
+	return client, nil
+}
+
+// read разбирает события потока до закрытия соединения
+func (c *FeedClient) read(ctx context.Context, reader *bufio.Reader) {
+	defer close(c.done)
+
+	for {
+		event, data, err := readFeedEvent(reader)
+		if err != nil {
+			if ctx.Err() == nil {
+				c.setErr(err)
+			}
+			return
+		}
+		if event != httpHandlers.FeedEventLocation {
+			continue
+		}
+
+		var location httpHandlers.LocationResponse
+		if err := json.Unmarshal([]byte(data), &location); err != nil {
+			c.setErr(fmt.Errorf("invalid location event %q: %w", data, err))
+			return
+		}
+
+		c.mu.Lock()
+		c.received = append(c.received, location)
+		c.mu.Unlock()
+	}
+}
+
+// readFeedEvent читает одно событие text/event-stream: строки event и data до пустой строки
+func readFeedEvent(reader *bufio.Reader) (event, data string, err error) {
+	for {
+		line, err := reader.ReadString('\n')
+		if err != nil {
+			return "", "", err
+		}
+
+		line = strings.TrimRight(line, "\r\n")
+		switch {
+		case line == "" && event != "":
+			return event, data, nil
+		case strings.HasPrefix(line, "event:"):
+			event = strings.TrimSpace(strings.TrimPrefix(line, "event:"))
+		case strings.HasPrefix(line, "data:"):
+			data += strings.TrimSpace(strings.TrimPrefix(line, "data:"))
+		}
+	}
+}
+
+// setErr запоминает ошибку чтения потока
+func (c *FeedClient) setErr(err error) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.err = err
+}
+
+// Received возвращает ID полученных местоположений в порядке получения
+func (c *FeedClient) Received() []uuid.UUID {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	ids := make([]uuid.UUID, 0, len(c.received))
+	for _, location := range c.received {
+		ids = append(ids, location.ID)
+	}
+	return ids
+}
+
+// Err возвращает ошибку чтения потока, если он оборвался до Close
+func (c *FeedClient) Err() error {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	return c.err
+}
+
+// WaitForLocations ждет, пока клиент получит все местоположения ids
+func (c *FeedClient) WaitForLocations(t *testing.T, ids []uuid.UUID, timeout time.Duration) {
+	t.Helper()
+
+	Eventually(t, func(e *EventuallyT) {
+		received := make(map[uuid.UUID]bool)
+		for _, id := range c.Received() {
+			received[id] = true
+		}
+
+		missing := 0
+		for _, id := range ids {
+			if !received[id] {
+				missing++
+			}
+		}
+		if missing > 0 {
+			e.Errorf("feed client is missing %d of %d locations (stream error: %v)", missing, len(ids), c.Err())
+		}
+	}, timeout, 50*time.Millisecond)
+}
+
+// Close закрывает соединение и дожидается завершения чтения потока
+func (c *FeedClient) Close() {
+	c.cancel()
+	c.response.Body.Close()
+	<-c.done
+}
@@ -0,0 +1,223 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"io"
+	"os"
+	"runtime"
+	"sort"
+	"strconv"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// FleetSizeEnv количество водителей масштабного теста парка
+const FleetSizeEnv = "TEST_FLEET_SIZE"
+
+// DefaultFleetSize размер парка по умолчанию; стенд нагрузочного тестирования задает до 50 000
+const DefaultFleetSize = 10000
+
+// LoadFleetSize возвращает размер парка из TEST_FLEET_SIZE или DefaultFleetSize
+func LoadFleetSize(t *testing.T) int {
+	value := os.Getenv(FleetSizeEnv)
+	if value == "" {
+		return DefaultFleetSize
+	}
+
+	size, err := strconv.Atoi(value)
+	require.NoError(t, err, "Некорректный %s: %q", FleetSizeEnv, value)
+	require.Positive(t, size, "Некорректный %s: %q", FleetSizeEnv, value)
+	return size
+}
+
+// FleetCoverage отмечает водителей парка, встреченных при обходе ответов. ID хранятся один раз
+// отсортированными, отметки - битами, поэтому память не зависит от числа и порядка ответов.
+// Не предназначен для параллельного использования.
+type FleetCoverage struct {
+	ids        []uuid.UUID
+	seen       []uint64
+	marked     int
+	duplicates int
+	unknown    int
+}
+
+// NewFleetCoverage создает отметки для водителей ids
+func NewFleetCoverage(ids []uuid.UUID) *FleetCoverage {
+	sorted := append([]uuid.UUID(nil), ids...)
+	sort.Slice(sorted, func(i, j int) bool { return bytes.Compare(sorted[i][:], sorted[j][:]) < 0 })
+
+	return &FleetCoverage{
+		ids:  sorted,
+		seen: make([]uint64, (len(sorted)+63)/64),
+	}
+}
+
+// Mark отмечает водителя id; повторные и неизвестные водители учитываются отдельно
+func (c *FleetCoverage) Mark(id uuid.UUID) {
+	i := sort.Search(len(c.ids), func(i int) bool { return bytes.Compare(c.ids[i][:], id[:]) >= 0 })
+	if i == len(c.ids) || c.ids[i] != id {
+		c.unknown++
+		return
+	}
+
+	word, bit := i/64, uint64(1)<<(i%64)
+	if c.seen[word]&bit != 0 {
+		c.duplicates++
+		return
+	}
+	c.seen[word] |= bit
+	c.marked++
+}
+
+// Marked возвращает количество отмеченных водителей
+func (c *FleetCoverage) Marked() int {
+	return c.marked
+}
+
+// AssertFleetCovered проверяет, что в ответах what каждый водитель парка встретился ровно один раз
+// и посторонних водителей не было
+func AssertFleetCovered(t *testing.T, what string, coverage *FleetCoverage) {
+	t.Helper()
+
+	assert.Equal(t, len(coverage.ids), coverage.marked, "%s: водители парка пропущены", what)
+	assert.Zero(t, coverage.duplicates, "%s: водители встретились повторно", what)
+	assert.Zero(t, coverage.unknown, "%s: водители не из парка", what)
+}
+
+// HeapWatermark наибольший объем кучи процесса после сборки мусора относительно начала замера: то, что тест
+// и сервис в памяти удерживают, без временных выделений. Сборка мусора при каждом замере занимает время,
+// поэтому Sample вызывается между замеряемыми операциями или в отдельной горутине (SampleEvery).
+type HeapWatermark struct {
+	mu       sync.Mutex
+	baseline uint64
+	peak     uint64
+	samples  int
+}
+
+// NewHeapWatermark начинает замер от текущего объема кучи
+func NewHeapWatermark() *HeapWatermark {
+	w := &HeapWatermark{}
+	w.baseline = retainedHeap()
+	w.peak = w.baseline
+	return w
+}
+
+// Sample учитывает текущий объем кучи
+func (w *HeapWatermark) Sample() {
+	heap := retainedHeap()
+
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	w.peak = max(w.peak, heap)
+	w.samples++
+}
+
+// SampleEvery замеряет кучу каждые interval в отдельной горутине до вызова возвращенной stop (повторные
+// вызовы ничего не делают): так замеры не задерживают горутину, которая обрабатывает поток сообщений
+func (w *HeapWatermark) SampleEvery(interval time.Duration) (stop func()) {
+	done, stopped := make(chan struct{}), make(chan struct{})
+	go func() {
+		defer close(stopped)
+		ticker := time.NewTicker(interval)
+		defer ticker.Stop()
+		for {
+			select {
+			case <-done:
+				return
+			case <-ticker.C:
+				w.Sample()
+			}
+		}
+	}()
+
+	var once sync.Once
+	return func() {
+		once.Do(func() {
+			close(done)
+			<-stopped
+		})
+	}
+}
+
+// Growth возвращает рост кучи от начала замера до наибольшего значения
+func (w *HeapWatermark) Growth() uint64 {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	return w.peak - w.baseline
+}
+
+// AssertHeapGrowth проверяет, что за замер step куча выросла не больше limit
+func AssertHeapGrowth(t *testing.T, step string, watermark *HeapWatermark, limit uint64) {
+	t.Helper()
+
+	growth := watermark.Growth()
+	if growth > limit {
+		t.Errorf("%s: heap grew by %d KB over %d samples, limit %d KB", step, growth/1024, watermark.samples, limit/1024)
+		return
+	}
+	t.Logf("%s: heap grew by %d KB over %d samples (limit %d KB)", step, growth/1024, watermark.samples, limit/1024)
+}
+
+// retainedHeap собирает мусор и возвращает объем занятой кучи
+func retainedHeap() uint64 {
+	runtime.GC()
+	var memStats runtime.MemStats
+	runtime.ReadMemStats(&memStats)
+	return memStats.HeapInuse
+}
+
+// StreamJSONArray читает JSON объект из r и передает visit элементы массива field по одному, не разбирая
+// объект целиком; остальные поля пропускаются. Возвращает количество переданных элементов.
+func StreamJSONArray(r io.Reader, field string, visit func(item json.RawMessage) error) (int, error) {
+	decoder := json.NewDecoder(r)
+	if token, err := decoder.Token(); err != nil || token != json.Delim('{') {
+		return 0, fmt.Errorf("expected JSON object: %v", err)
+	}
+
+	count, found := 0, false
+	for decoder.More() {
+		token, err := decoder.Token()
+		if err != nil {
+			return count, err
+		}
+		if name, _ := token.(string); name != field {
+			var skipped json.RawMessage
+			if err := decoder.Decode(&skipped); err != nil {
+				return count, err
+			}
+			continue
+		}
+
+		found = true
+		if token, err := decoder.Token(); err != nil || token != json.Delim('[') {
+			return count, fmt.Errorf("field %q is not an array: %v", field, err)
+		}
+		for decoder.More() {
+			var item json.RawMessage
+			if err := decoder.Decode(&item); err != nil {
+				return count, err
+			}
+			if err := visit(item); err != nil {
+				return count, err
+			}
+			count++
+		}
+		if _, err := decoder.Token(); err != nil {
+			return count, err
+		}
+	}
+
+	if !found {
+		return 0, fmt.Errorf("field %q not found", field)
+	}
+	return count, nil
+}
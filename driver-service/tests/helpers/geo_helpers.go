@@ -0,0 +1,174 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"math"
+	"sort"
+	"testing"
+
+	"driver-service/tests/fixtures"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+)
+
+// earthRadiusKm средний радиус Земли в километрах
+const earthRadiusKm = 6371.0
+
+// nearbyBorderlineKm допуск у границы радиуса, внутри которого результат поиска не проверяется
+const nearbyBorderlineKm = 0.01
+
+// HaversineKm вычисляет расстояние между двумя точками по формуле гаверсинусов.
+// Реализация намеренно не зависит от entities, чтобы служить независимым эталоном.
+func HaversineKm(lat1, lon1, lat2, lon2 float64) float64 {
+	toRad := func(deg float64) float64 { return deg * math.Pi / 180 }
+
+	dLat := toRad(lat2 - lat1)
+	dLon := toRad(lon2 - lon1)
+
+	a := math.Sin(dLat/2)*math.Sin(dLat/2) +
+		math.Cos(toRad(lat1))*math.Cos(toRad(lat2))*math.Sin(dLon/2)*math.Sin(dLon/2)
+	c := 2 * math.Atan2(math.Sqrt(a), math.Sqrt(1-a))
+
+	return earthRadiusKm * c
+}
+
+// geohashAlphabet алфавит base32 для geohash
+const geohashAlphabet = "0123456789bcdefghjkmnpqrstuvwxyz"
+
+// GeohashEncode кодирует координаты в geohash длиной precision символов.
+// Реализация намеренно не зависит от сервиса, чтобы служить независимым эталоном.
+func GeohashEncode(lat, lon float64, precision int) string {
+	latRange := [2]float64{-90, 90}
+	lonRange := [2]float64{-180, 180}
+
+	hash := make([]byte, 0, precision)
+	bit, ch := 0, 0
+	even := true
+	for len(hash) < precision {
+		value, bounds := lat, &latRange
+		if even {
+			value, bounds = lon, &lonRange
+		}
+
+		mid := (bounds[0] + bounds[1]) / 2
+		ch <<= 1
+		if value >= mid {
+			ch |= 1
+			bounds[0] = mid
+		} else {
+			bounds[1] = mid
+		}
+		even = !even
+
+		if bit++; bit == 5 {
+			hash = append(hash, geohashAlphabet[ch])
+			bit, ch = 0, 0
+		}
+	}
+
+	return string(hash)
+}
+
+// ExpectedHeatmap возвращает эталонное количество водителей по ячейкам geohash
+// для последних местоположений водителей
+func ExpectedHeatmap(points []fixtures.RoutePoint, precision int) map[string]int {
+	counts := make(map[string]int)
+	for _, point := range points {
+		counts[GeohashEncode(point.Latitude, point.Longitude, precision)]++
+	}
+	return counts
+}
+
+// NearbyCandidate последнее местоположение водителя с расстоянием до точки поиска
+type NearbyCandidate struct {
+	DriverID   uuid.UUID `db:"driver_id"`
+	Latitude   float64   `db:"latitude"`
+	Longitude  float64   `db:"longitude"`
+	DistanceKm float64   `db:"-"`
+}
+
+// NearbyOracleResult эталонный результат поиска водителей поблизости
+type NearbyOracleResult struct {
+	// InRadius водители, чье последнее местоположение в радиусе (упорядочены по driver_id)
+	InRadius []NearbyCandidate
+	// Borderline водители на границе радиуса, для которых допустим любой результат
+	Borderline map[uuid.UUID]bool
+}
+
+// NearbyOracle вычисляет ожидаемый результат поиска перебором всех строк БД
+type NearbyOracle struct {
+	db *TestDB
+}
+
+// NewNearbyOracle создает эталон поиска поблизости поверх тестовой БД
+func NewNearbyOracle(db *TestDB) *NearbyOracle {
+	return &NearbyOracle{db: db}
+}
+
+// Expected возвращает водителей, чье последнее местоположение находится в радиусе radiusKm.
+// Учитываются только активные, не удаленные водители - так же, как в сервисе.
+func (o *NearbyOracle) Expected(ctx context.Context, lat, lon, radiusKm float64) (*NearbyOracleResult, error) {
+	query := `
+		SELECT DISTINCT ON (l.driver_id) l.driver_id, l.latitude, l.longitude
+		FROM driver_locations l
+		JOIN drivers d ON d.id = l.driver_id
+		WHERE d.deleted_at IS NULL
+		  AND d.status IN ('available', 'on_shift', 'busy')
+		ORDER BY l.driver_id, l.recorded_at DESC`
+
+	var candidates []NearbyCandidate
+	if err := o.db.SelectContext(ctx, &candidates, query); err != nil {
+		return nil, err
+	}
+
+	result := &NearbyOracleResult{Borderline: make(map[uuid.UUID]bool)}
+	for _, candidate := range candidates {
+		candidate.DistanceKm = HaversineKm(lat, lon, candidate.Latitude, candidate.Longitude)
+
+		if math.Abs(candidate.DistanceKm-radiusKm) <= nearbyBorderlineKm {
+			result.Borderline[candidate.DriverID] = true
+			continue
+		}
+
+		if candidate.DistanceKm < radiusKm {
+			result.InRadius = append(result.InRadius, candidate)
+		}
+	}
+
+	sort.Slice(result.InRadius, func(i, j int) bool {
+		return result.InRadius[i].DriverID.String() < result.InRadius[j].DriverID.String()
+	})
+
+	return result, nil
+}
+
+// AssertNearbyMatchesOracle сравнивает фактическую выдачу API с эталоном.
+// Без ограничения выдача должна совпадать с эталоном; с ограничением limit -
+// содержать ровно min(limit, len(InRadius)) водителей из эталона.
+func AssertNearbyMatchesOracle(t *testing.T, oracle *NearbyOracleResult, actual []uuid.UUID, limit int) {
+	inRadius := make(map[uuid.UUID]bool, len(oracle.InRadius))
+	for _, candidate := range oracle.InRadius {
+		inRadius[candidate.DriverID] = true
+	}
+
+	seen := make(map[uuid.UUID]bool, len(actual))
+	for _, driverID := range actual {
+		assert.False(t, seen[driverID], "Водитель %s встречается в выдаче несколько раз", driverID)
+		seen[driverID] = true
+
+		assert.True(t, inRadius[driverID] || oracle.Borderline[driverID],
+			"Водитель %s вне радиуса, но присутствует в выдаче", driverID)
+	}
+
+	if limit > 0 && len(oracle.InRadius) >= limit {
+		assert.Len(t, actual, limit, "Выдача должна быть заполнена ровно до limit")
+		return
+	}
+
+	for driverID := range inRadius {
+		assert.True(t, seen[driverID], "Водитель %s в радиусе, но отсутствует в выдаче", driverID)
+	}
+}
@@ -0,0 +1,160 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"database/sql"
+	"net"
+	"strconv"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/infrastructure/nats"
+	"driver-service/internal/infrastructure/redis"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/stretchr/testify/require"
+)
+
+// TestReadinessTimeout ожидание зависимостей в /health/ready окружения: отказ через ChaosProxy
+// должен переводить сервис в not_ready быстрее, чем за таймаут проверки
+const TestReadinessTimeout = 500 * time.Millisecond
+
+// dependencyProbeTimeout сколько ждать подключения к тестовым Redis и NATS при выборе проверок готовности
+const dependencyProbeTimeout = time.Second
+
+// DependencyChaos прокси перед зависимостями окружения и настройки подключения к ним через прокси
+type DependencyChaos struct {
+	Proxies map[string]*ChaosProxy // имя зависимости в ответе /health/ready -> прокси
+
+	// Database БД окружения через прокси; Redis и NATS nil, если тестовые экземпляры недоступны
+	Database config.DatabaseConfig
+	Redis    *config.RedisConfig
+	NATS     *config.NATSConfig
+}
+
+// NewDependencyChaos запускает прокси перед БД окружения и, если доступны, тестовыми Redis и NATS
+// до конца теста t
+func (env *TestEnvironment) NewDependencyChaos(t *testing.T) *DependencyChaos {
+	chaos := &DependencyChaos{Proxies: make(map[string]*ChaosProxy)}
+
+	chaos.Database = getTestConfig().Database
+	chaos.Database.Database = env.DB.dbName
+	proxy := StartChaosProxy(t, net.JoinHostPort(chaos.Database.Host, strconv.Itoa(chaos.Database.Port)))
+	chaos.Database.Host, chaos.Database.Port = splitProxyAddr(t, proxy)
+	chaos.Proxies["database"] = proxy
+
+	redisAddr := net.JoinHostPort(getEnvOrDefault(TestRedisHostEnv, "localhost"), getEnvOrDefault(TestRedisPortEnv, "6380"))
+	if reachable(redisAddr) {
+		proxy := StartChaosProxy(t, redisAddr)
+		host, port := splitProxyAddr(t, proxy)
+		chaos.Redis = &config.RedisConfig{Host: host, Port: port}
+		chaos.Proxies["redis"] = proxy
+	}
+
+	natsAddr := strings.TrimPrefix(getEnvOrDefault(TestNATSURLEnv, "nats://localhost:4223"), "nats://")
+	if reachable(natsAddr) {
+		proxy := StartChaosProxy(t, natsAddr)
+		chaos.NATS = &config.NATSConfig{URL: "nats://" + proxy.Addr(), ClientID: "driver-service-tests"}
+		chaos.Proxies["nats"] = proxy
+	}
+
+	return chaos
+}
+
+// Cut имитирует отказ зависимости name
+func (c *DependencyChaos) Cut(t *testing.T, name string) {
+	c.proxy(t, name).Cut()
+}
+
+// Restore возвращает доступ к зависимости name
+func (c *DependencyChaos) Restore(t *testing.T, name string) {
+	c.proxy(t, name).Restore()
+}
+
+// SetLatency задерживает ответы зависимости name на latency; 0 убирает задержку
+func (c *DependencyChaos) SetLatency(t *testing.T, name string, latency time.Duration) {
+	c.proxy(t, name).SetLatency(latency)
+}
+
+// SetPacketLoss теряет долю rate пакетов на соединениях с зависимостью name (см. ChaosProxy.SetPacketLoss)
+func (c *DependencyChaos) SetPacketLoss(t *testing.T, name string, rate float64, seed int64) {
+	c.proxy(t, name).SetPacketLoss(rate, seed)
+}
+
+// CutAll имитирует отказ всех зависимостей
+func (c *DependencyChaos) CutAll() {
+	for _, proxy := range c.Proxies {
+		proxy.Cut()
+	}
+}
+
+// RestoreAll возвращает доступ ко всем зависимостям и убирает задержки и потери пакетов
+func (c *DependencyChaos) RestoreAll() {
+	for _, proxy := range c.Proxies {
+		proxy.Restore()
+		proxy.SetLatency(0)
+		proxy.SetPacketLoss(0, 0)
+	}
+}
+
+// proxy возвращает прокси зависимости name
+func (c *DependencyChaos) proxy(t *testing.T, name string) *ChaosProxy {
+	proxy, ok := c.Proxies[name]
+	require.True(t, ok, "Зависимость %q не подключена", name)
+	return proxy
+}
+
+// EnableHealthChecks подключает к роутеру окружения /health/live и /health/ready. Зависимости проверяются
+// через прокси NewDependencyChaos: БД окружения всегда, тестовые Redis и NATS - если доступны.
+// Вызывается один раз для окружения (обычно в SetupSuite); прокси работают до конца t.
+func (env *TestEnvironment) EnableHealthChecks(t *testing.T) *DependencyChaos {
+	chaos := env.NewDependencyChaos(t)
+	env.registerHealthChecks(t, chaos)
+	return chaos
+}
+
+// registerHealthChecks подключает проверки готовности зависимостей chaos к роутеру окружения
+func (env *TestEnvironment) registerHealthChecks(t *testing.T, chaos *DependencyChaos) {
+	db, err := sql.Open("postgres", chaos.Database.GetDSN())
+	require.NoError(t, err)
+	// Без простаивающих соединений каждая проверка подключается заново, как после перезапуска БД
+	db.SetMaxIdleConns(0)
+	t.Cleanup(func() { db.Close() })
+	checks := []httpHandlers.DependencyCheck{{Name: "database", Check: db.PingContext}}
+
+	if redisConfig := chaos.Redis; redisConfig != nil {
+		checks = append(checks, httpHandlers.DependencyCheck{Name: "redis", Check: func(ctx context.Context) error {
+			return redis.Ping(ctx, *redisConfig)
+		}})
+	}
+	if natsConfig := chaos.NATS; natsConfig != nil {
+		checks = append(checks, httpHandlers.DependencyCheck{Name: "nats", Check: func(ctx context.Context) error {
+			return nats.Ping(ctx, *natsConfig)
+		}})
+	}
+
+	env.server.RegisterHealthRoutes(httpHandlers.NewHealthHandler(checks, TestReadinessTimeout, env.Logger))
+}
+
+// splitProxyAddr возвращает хост и порт прокси
+func splitProxyAddr(t *testing.T, proxy *ChaosProxy) (string, int) {
+	host, port, err := net.SplitHostPort(proxy.Addr())
+	require.NoError(t, err)
+	portNumber, err := strconv.Atoi(port)
+	require.NoError(t, err)
+	return host, portNumber
+}
+
+// reachable проверяет, что по адресу принимаются TCP подключения
+func reachable(addr string) bool {
+	conn, err := net.DialTimeout("tcp", addr, dependencyProbeTimeout)
+	if err != nil {
+		return false
+	}
+	conn.Close()
+	return true
+}
@@ -0,0 +1,51 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// Параметры heartbeat в тестовом окружении
+const (
+	TestHeartbeatTimeout     = 90 * time.Second
+	TestHeartbeatMinInterval = 5 * time.Second
+)
+
+// Heartbeat отправляет heartbeat водителя через API. location может быть nil: тогда запрос уходит без тела.
+func (h *APITestHelper) Heartbeat(driverID uuid.UUID, location *httpHandlers.UpdateLocationRequest) (*httpHandlers.HeartbeatResponse, *APIResponse) {
+	request := APIRequest{
+		Method: http.MethodPost,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/heartbeat", driverID),
+	}
+	if location != nil {
+		request.Body = httpHandlers.HeartbeatRequest{Location: location}
+	}
+
+	response := h.MakeRequest(request)
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var result httpHandlers.HeartbeatResponse
+	h.UnmarshalResponse(response, &result)
+	return &result, response
+}
+
+// AgeHeartbeat сдвигает время последнего heartbeat водителя на age в прошлое,
+// имитируя время без сигналов (часы в сервисе не подменяются)
+func (tdb *TestDB) AgeHeartbeat(t *testing.T, driverID uuid.UUID, age time.Duration) {
+	_, err := tdb.ExecContext(context.Background(),
+		"UPDATE driver_heartbeats SET last_seen_at = last_seen_at - make_interval(secs => $1) WHERE driver_id = $2",
+		age.Seconds(), driverID)
+	require.NoError(t, err)
+}
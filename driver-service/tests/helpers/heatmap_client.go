@@ -0,0 +1,49 @@
+//go:build integration
+
+package helpers
+
+import (
+	"net/http"
+	"strconv"
+)
+
+// HeatmapCell количество водителей в ячейке geohash
+type HeatmapCell struct {
+	Geohash string `json:"geohash"`
+	Count   int    `json:"count"`
+}
+
+// HeatmapResponse ответ API плотности водителей
+type HeatmapResponse struct {
+	Precision int           `json:"precision"`
+	Cells     []HeatmapCell `json:"cells"`
+	Total     int           `json:"total"`
+}
+
+// Counts возвращает количество водителей по ячейкам
+func (r *HeatmapResponse) Counts() map[string]int {
+	counts := make(map[string]int, len(r.Cells))
+	for _, cell := range r.Cells {
+		counts[cell.Geohash] += cell.Count
+	}
+	return counts
+}
+
+// GetDriverHeatmap запрашивает плотность активных водителей с точностью precision символов geohash
+func (h *APITestHelper) GetDriverHeatmap(precision int) (*HeatmapResponse, *APIResponse) {
+	response := h.MakeRequest(APIRequest{
+		Method: http.MethodGet,
+		URL:    h.APIPath("/analytics/heatmap"),
+		QueryParams: map[string]string{
+			"precision": strconv.Itoa(precision),
+		},
+	})
+
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var heatmap HeatmapResponse
+	h.UnmarshalResponse(response, &heatmap)
+	return &heatmap, response
+}
@@ -0,0 +1,242 @@
+//go:build integration
+
+package helpers
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"net/http"
+	"net/http/httptrace"
+	"os"
+	"sort"
+	"strconv"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения пула соединений клиента к развернутому сервису
+const (
+	// HTTPMaxIdlePerHostEnv простаивающих соединений на хост, которые пул держит для повторного использования
+	HTTPMaxIdlePerHostEnv = "TEST_HTTP_MAX_IDLE_PER_HOST"
+	// HTTPIdleTimeoutEnv через сколько простаивающее соединение закрывается (например, "90s")
+	HTTPIdleTimeoutEnv = "TEST_HTTP_IDLE_TIMEOUT"
+	// HTTP2Env "false" запрещает HTTP/2 по TLS: все запросы идут по HTTP/1.1
+	HTTP2Env = "TEST_HTTP2"
+)
+
+// HTTPClientConfig настройки HTTP клиента помощника для запросов к развернутому сервису
+type HTTPClientConfig struct {
+	// MaxIdleConnsPerHost по умолчанию в net/http всего 2: при большем числе параллельных запросов
+	// лишние соединения закрываются и открываются заново, и в замерах появляется стоимость установки соединения
+	MaxIdleConnsPerHost int
+	IdleConnTimeout     time.Duration
+	HTTP2               bool
+	Timeout             time.Duration
+	// RootCAs доверенные корневые сертификаты (например, стенда с самоподписанным сертификатом); nil - системные
+	RootCAs *x509.CertPool
+}
+
+// DefaultHTTPClientConfig возвращает настройки по умолчанию
+func DefaultHTTPClientConfig() HTTPClientConfig {
+	return HTTPClientConfig{
+		MaxIdleConnsPerHost: 100,
+		IdleConnTimeout:     90 * time.Second,
+		HTTP2:               true,
+		Timeout:             30 * time.Second,
+	}
+}
+
+// LoadHTTPClientConfig возвращает настройки по умолчанию с переопределениями из TEST_HTTP_MAX_IDLE_PER_HOST,
+// TEST_HTTP_IDLE_TIMEOUT и TEST_HTTP2
+func LoadHTTPClientConfig(t *testing.T) HTTPClientConfig {
+	cfg := DefaultHTTPClientConfig()
+
+	if value := os.Getenv(HTTPMaxIdlePerHostEnv); value != "" {
+		maxIdle, err := strconv.Atoi(value)
+		require.NoError(t, err, "Некорректный %s: %q", HTTPMaxIdlePerHostEnv, value)
+		cfg.MaxIdleConnsPerHost = maxIdle
+	}
+	if value := os.Getenv(HTTPIdleTimeoutEnv); value != "" {
+		timeout, err := time.ParseDuration(value)
+		require.NoError(t, err, "Некорректный %s: %q", HTTPIdleTimeoutEnv, value)
+		cfg.IdleConnTimeout = timeout
+	}
+	if value := os.Getenv(HTTP2Env); value != "" {
+		enabled, err := strconv.ParseBool(value)
+		require.NoError(t, err, "Некорректный %s: %q", HTTP2Env, value)
+		cfg.HTTP2 = enabled
+	}
+
+	return cfg
+}
+
+// NewHTTPClient создает HTTP клиент с пулом соединений по cfg
+func NewHTTPClient(cfg HTTPClientConfig) *http.Client {
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+	transport.MaxIdleConnsPerHost = cfg.MaxIdleConnsPerHost
+	transport.MaxIdleConns = max(transport.MaxIdleConns, cfg.MaxIdleConnsPerHost)
+	transport.IdleConnTimeout = cfg.IdleConnTimeout
+	transport.ForceAttemptHTTP2 = cfg.HTTP2
+	if cfg.RootCAs != nil {
+		transport.TLSClientConfig = &tls.Config{RootCAs: cfg.RootCAs}
+	}
+	if !cfg.HTTP2 {
+		// Непустой TLSNextProto без "h2" отключает согласование HTTP/2
+		transport.TLSNextProto = map[string]func(string, *tls.Conn) http.RoundTripper{}
+	}
+
+	return &http.Client{Transport: transport, Timeout: cfg.Timeout}
+}
+
+// ConnectionInfo соединение, по которому выполнен запрос к развернутому сервису
+type ConnectionInfo struct {
+	Reused   bool          // соединение взято из пула
+	WasIdle  bool          // соединение простаивало в пуле
+	IdleTime time.Duration // сколько простаивало
+	// Setup время получения нового соединения: DNS, TCP и TLS. У соединения из пула 0.
+	Setup    time.Duration
+	Protocol string // HTTP/1.1 или HTTP/2.0
+}
+
+// connectionTrace подключает к запросу httptrace и заполняет ConnectionInfo
+func connectionTrace(req *http.Request) (*http.Request, *ConnectionInfo) {
+	info := &ConnectionInfo{}
+	var getConn time.Time
+
+	trace := &httptrace.ClientTrace{
+		GetConn: func(string) { getConn = time.Now() },
+		GotConn: func(conn httptrace.GotConnInfo) {
+			info.Reused = conn.Reused
+			info.WasIdle = conn.WasIdle
+			info.IdleTime = conn.IdleTime
+			if !conn.Reused {
+				info.Setup = time.Since(getConn)
+			}
+		},
+	}
+	return req.WithContext(httptrace.WithClientTrace(req.Context(), trace)), info
+}
+
+// ConnectionSummary статистика соединений операции
+type ConnectionSummary struct {
+	Requests       int
+	Reused         int
+	NewConnections int
+	HTTP2          int
+	SetupTime      time.Duration // суммарное время установки новых соединений
+	ServerTime     time.Duration // суммарная длительность запросов без установки соединений
+}
+
+// ReuseRatio возвращает долю запросов по соединениям из пула или 0, если запросов не было
+func (s ConnectionSummary) ReuseRatio() float64 {
+	if s.Requests == 0 {
+		return 0
+	}
+	return float64(s.Reused) / float64(s.Requests)
+}
+
+// AvgSetup возвращает среднее время установки нового соединения
+func (s ConnectionSummary) AvgSetup() time.Duration {
+	if s.NewConnections == 0 {
+		return 0
+	}
+	return s.SetupTime / time.Duration(s.NewConnections)
+}
+
+// AvgServerTime возвращает среднюю длительность запроса без установки соединения
+func (s ConnectionSummary) AvgServerTime() time.Duration {
+	if s.Requests == 0 {
+		return 0
+	}
+	return s.ServerTime / time.Duration(s.Requests)
+}
+
+// ConnectionStats считает повторное использование соединений по операциям вида "GET /api/v1/drivers/{id}",
+// чтобы в замерах отделить стоимость установки соединения от задержки сервиса.
+// Подключается к APITestHelper через WithResponseInterceptor(stats.Record); запросы к роутеру в памяти
+// соединений не открывают и не учитываются.
+type ConnectionStats struct {
+	mu         sync.Mutex
+	operations map[string]*ConnectionSummary
+}
+
+// NewConnectionStats создает пустую статистику соединений
+func NewConnectionStats() *ConnectionStats {
+	return &ConnectionStats{operations: make(map[string]*ConnectionSummary)}
+}
+
+// Record учитывает соединение запроса; сигнатура совпадает с ResponseInterceptor
+func (s *ConnectionStats) Record(req *http.Request, resp *APIResponse, duration time.Duration) {
+	if resp.Connection == nil {
+		return
+	}
+	operation := req.Method + " " + templatePath(req.URL.Path)
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	summary, ok := s.operations[operation]
+	if !ok {
+		summary = &ConnectionSummary{}
+		s.operations[operation] = summary
+	}
+
+	summary.Requests++
+	if resp.Connection.Reused {
+		summary.Reused++
+	} else {
+		summary.NewConnections++
+		summary.SetupTime += resp.Connection.Setup
+	}
+	if resp.Connection.Protocol == "HTTP/2.0" {
+		summary.HTTP2++
+	}
+	summary.ServerTime += duration - resp.Connection.Setup
+}
+
+// Summary возвращает статистику операции
+func (s *ConnectionStats) Summary(operation string) ConnectionSummary {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if summary, ok := s.operations[operation]; ok {
+		return *summary
+	}
+	return ConnectionSummary{}
+}
+
+// Total возвращает статистику по всем операциям
+func (s *ConnectionStats) Total() ConnectionSummary {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	var total ConnectionSummary
+	for _, summary := range s.operations {
+		total.Requests += summary.Requests
+		total.Reused += summary.Reused
+		total.NewConnections += summary.NewConnections
+		total.HTTP2 += summary.HTTP2
+		total.SetupTime += summary.SetupTime
+		total.ServerTime += summary.ServerTime
+	}
+	return total
+}
+
+// Log выводит статистику соединений по операциям в лог теста
+func (s *ConnectionStats) Log(t *testing.T) {
+	s.mu.Lock()
+	operations := make([]string, 0, len(s.operations))
+	for operation := range s.operations {
+		operations = append(operations, operation)
+	}
+	s.mu.Unlock()
+	sort.Strings(operations)
+
+	for _, operation := range operations {
+		summary := s.Summary(operation)
+		t.Logf("%s: %d requests, reused %.0f%%, %d new connections (avg setup %v), avg server time %v, HTTP/2 %d",
+			operation, summary.Requests, summary.ReuseRatio()*100, summary.NewConnections,
+			summary.AvgSetup(), summary.AvgServerTime(), summary.HTTP2)
+	}
+}
@@ -0,0 +1,50 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"net/http"
+	"time"
+
+	"driver-service/internal/domain/services"
+)
+
+// Параметры фоновых задач в тестовом окружении
+const (
+	TestMaxShiftDuration    = 12 * time.Hour
+	TestLocationRetention   = 30 * 24 * time.Hour
+	TestVerificationTimeout = 72 * time.Hour
+)
+
+// jobsPath путь административного API фоновых задач (не версионируется)
+const jobsPath = "/api/v1/admin/jobs"
+
+// ListJobs запрашивает имена фоновых задач через административный API
+func (h *APITestHelper) ListJobs() ([]string, *APIResponse) {
+	response := h.MakeRequest(APIRequest{Method: http.MethodGet, URL: jobsPath})
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var result struct {
+		Jobs []string `json:"jobs"`
+	}
+	h.UnmarshalResponse(response, &result)
+	return result.Jobs, response
+}
+
+// RunJob запускает фоновую задачу через административный API и дожидается ее завершения
+func (h *APITestHelper) RunJob(name string) (*services.JobResult, *APIResponse) {
+	response := h.MakeRequest(APIRequest{
+		Method: http.MethodPost,
+		URL:    fmt.Sprintf("%s/%s/run", jobsPath, name),
+	})
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var result services.JobResult
+	h.UnmarshalResponse(response, &result)
+	return &result, response
+}
@@ -0,0 +1,347 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"os"
+	"reflect"
+	"strconv"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+// JSONPathValue значение, выбранное JSONPath, с конкретным путем до него
+type JSONPathValue struct {
+	Path  string
+	Value interface{}
+}
+
+// JSONMatcher проверка значений, выбранных JSONPath.
+// match возвращает строки с описанием несоответствий, пустой результат означает успех.
+type JSONMatcher struct {
+	description string
+	match       func(values []JSONPathValue) []string
+}
+
+// AssertJSONPath выбирает значения из JSON по пути и проверяет их матчером.
+// Поддерживается подмножество JSONPath: $, .field, [n], [*].
+// В отличие от цепочек приведения типов (m["drivers"].([]interface{})...) не паникует,
+// а сообщает конкретные пути несовпавших значений и структурный diff.
+//
+//	helpers.AssertJSONPath(t, response.Body, "$.drivers[*].distance_km", helpers.AllLTE(5.0))
+func AssertJSONPath(t *testing.T, body []byte, path string, matcher JSONMatcher) bool {
+	t.Helper()
+
+	var document interface{}
+	require.NoError(t, json.Unmarshal(body, &document), "Ответ не является JSON: %s", string(body))
+
+	values, missing, err := selectJSONPath(document, path)
+	require.NoError(t, err, "Некорректный JSONPath %q", path)
+
+	var problems []string
+	for _, p := range missing {
+		problems = append(problems, colorize(colorRed, "✗ "+p+": отсутствует"))
+	}
+	problems = append(problems, matcher.match(values)...)
+	if len(problems) == 0 {
+		return true
+	}
+
+	t.Errorf("JSONPath %s: ожидалось %s\n%s\nДокумент:\n%s",
+		path, matcher.description, strings.Join(problems, "\n"), prettyJSON(document))
+	return false
+}
+
+// Equals проверяет, что выбрано ровно одно значение и оно структурно равно expected
+func Equals(expected interface{}) JSONMatcher {
+	normalized := normalizeJSON(expected)
+	return JSONMatcher{
+		description: "значение " + compactJSON(normalized),
+		match: func(values []JSONPathValue) []string {
+			if len(values) != 1 {
+				return []string{colorize(colorRed, fmt.Sprintf("✗ выбрано значений: %d, ожидалось 1", len(values)))}
+			}
+			if reflect.DeepEqual(normalized, values[0].Value) {
+				return nil
+			}
+			return []string{colorize(colorRed, "✗ "+values[0].Path+" отличается:"), jsonDiff(normalized, values[0].Value)}
+		},
+	}
+}
+
+// HasLen проверяет длину единственного выбранного массива или объекта
+func HasLen(expected int) JSONMatcher {
+	return JSONMatcher{
+		description: fmt.Sprintf("длина %d", expected),
+		match: func(values []JSONPathValue) []string {
+			if len(values) != 1 {
+				return []string{colorize(colorRed, fmt.Sprintf("✗ выбрано значений: %d, ожидалось 1", len(values)))}
+			}
+
+			var actual int
+			switch v := values[0].Value.(type) {
+			case []interface{}:
+				actual = len(v)
+			case map[string]interface{}:
+				actual = len(v)
+			default:
+				return []string{colorize(colorRed, fmt.Sprintf("✗ %s = %s: не массив и не объект", values[0].Path, compactJSON(v)))}
+			}
+
+			if actual != expected {
+				return []string{colorize(colorRed, fmt.Sprintf("✗ %s: длина %d", values[0].Path, actual))}
+			}
+			return nil
+		},
+	}
+}
+
+// AllLTE проверяет, что все выбранные значения - числа не больше limit
+func AllLTE(limit float64) JSONMatcher {
+	return allNumbers(fmt.Sprintf("<= %v", limit), func(v float64) bool { return v <= limit })
+}
+
+// AllGTE проверяет, что все выбранные значения - числа не меньше limit
+func AllGTE(limit float64) JSONMatcher {
+	return allNumbers(fmt.Sprintf(">= %v", limit), func(v float64) bool { return v >= limit })
+}
+
+// AllBetween проверяет, что все выбранные значения - числа строго между lower и upper
+func AllBetween(lower, upper float64) JSONMatcher {
+	return allNumbers(fmt.Sprintf("в интервале (%v, %v)", lower, upper), func(v float64) bool { return v > lower && v < upper })
+}
+
+// AllEqual проверяет, что все выбранные значения структурно равны expected
+func AllEqual(expected interface{}) JSONMatcher {
+	normalized := normalizeJSON(expected)
+	return allValues("равны "+compactJSON(normalized), func(v interface{}) bool { return reflect.DeepEqual(normalized, v) })
+}
+
+// AllOfType проверяет, что все выбранные значения имеют JSON тип kind:
+// "string", "number", "boolean", "object", "array" или "null"
+func AllOfType(kind string) JSONMatcher {
+	return allValues("все значения типа "+kind, func(v interface{}) bool { return jsonType(v) == kind })
+}
+
+// jsonType возвращает JSON тип значения, разобранного encoding/json
+func jsonType(value interface{}) string {
+	switch value.(type) {
+	case nil:
+		return "null"
+	case string:
+		return "string"
+	case float64:
+		return "number"
+	case bool:
+		return "boolean"
+	case map[string]interface{}:
+		return "object"
+	case []interface{}:
+		return "array"
+	default:
+		return fmt.Sprintf("%T", value)
+	}
+}
+
+// allNumbers строит матчер для числовых значений
+func allNumbers(description string, check func(float64) bool) JSONMatcher {
+	return allValues("все значения "+description, func(v interface{}) bool {
+		number, ok := v.(float64)
+		return ok && check(number)
+	})
+}
+
+// allValues строит матчер, проверяющий каждое значение; пустая выборка считается ошибкой
+func allValues(description string, check func(interface{}) bool) JSONMatcher {
+	return JSONMatcher{
+		description: description,
+		match: func(values []JSONPathValue) []string {
+			if len(values) == 0 {
+				return []string{colorize(colorRed, "✗ путь не выбрал ни одного значения")}
+			}
+
+			var problems []string
+			for _, value := range values {
+				if !check(value.Value) {
+					problems = append(problems, colorize(colorRed, fmt.Sprintf("✗ %s = %s", value.Path, compactJSON(value.Value))))
+				}
+			}
+			return problems
+		},
+	}
+}
+
+// selectJSONPath выбирает значения по пути. Отсутствующие поля и индексы возвращаются отдельно.
+func selectJSONPath(document interface{}, path string) ([]JSONPathValue, []string, error) {
+	if !strings.HasPrefix(path, "$") {
+		return nil, nil, fmt.Errorf("путь должен начинаться с $")
+	}
+
+	current := []JSONPathValue{{Path: "$", Value: document}}
+	var missing []string
+
+	rest := path[1:]
+	for rest != "" {
+		var next []JSONPathValue
+
+		switch {
+		case strings.HasPrefix(rest, "."):
+			end := strings.IndexAny(rest[1:], ".[")
+			if end < 0 {
+				end = len(rest) - 1
+			}
+			field := rest[1 : end+1]
+			if field == "" {
+				return nil, nil, fmt.Errorf("пустое имя поля в %q", path)
+			}
+			rest = rest[end+1:]
+
+			for _, item := range current {
+				object, ok := item.Value.(map[string]interface{})
+				value, found := object[field]
+				if !ok || !found {
+					missing = append(missing, item.Path+"."+field)
+					continue
+				}
+				next = append(next, JSONPathValue{Path: item.Path + "." + field, Value: value})
+			}
+
+		case strings.HasPrefix(rest, "["):
+			end := strings.Index(rest, "]")
+			if end < 0 {
+				return nil, nil, fmt.Errorf("незакрытая скобка в %q", path)
+			}
+			selector := rest[1:end]
+			rest = rest[end+1:]
+
+			for _, item := range current {
+				array, ok := item.Value.([]interface{})
+				if !ok {
+					missing = append(missing, item.Path+"["+selector+"]")
+					continue
+				}
+
+				if selector == "*" {
+					for i, value := range array {
+						next = append(next, JSONPathValue{Path: fmt.Sprintf("%s[%d]", item.Path, i), Value: value})
+					}
+					continue
+				}
+
+				index, err := strconv.Atoi(selector)
+				if err != nil {
+					return nil, nil, fmt.Errorf("некорректный индекс %q в %q", selector, path)
+				}
+				if index < 0 || index >= len(array) {
+					missing = append(missing, fmt.Sprintf("%s[%d]", item.Path, index))
+					continue
+				}
+				next = append(next, JSONPathValue{Path: fmt.Sprintf("%s[%d]", item.Path, index), Value: array[index]})
+			}
+
+		default:
+			return nil, nil, fmt.Errorf("неожиданный фрагмент %q в %q", rest, path)
+		}
+
+		current = next
+	}
+
+	return current, missing, nil
+}
+
+// normalizeJSON приводит Go-значение к виду, который дает json.Unmarshal (числа - float64 и т.д.)
+func normalizeJSON(value interface{}) interface{} {
+	data, err := json.Marshal(value)
+	if err != nil {
+		return value
+	}
+
+	var normalized interface{}
+	if err := json.Unmarshal(data, &normalized); err != nil {
+		return value
+	}
+	return normalized
+}
+
+// compactJSON сериализует значение в одну строку для сообщений об ошибках
+func compactJSON(value interface{}) string {
+	data, err := json.Marshal(value)
+	if err != nil {
+		return fmt.Sprintf("%v", value)
+	}
+	return string(data)
+}
+
+// prettyJSON сериализует значение с отступами и отсортированными ключами
+func prettyJSON(value interface{}) string {
+	var buf bytes.Buffer
+	encoder := json.NewEncoder(&buf)
+	encoder.SetEscapeHTML(false)
+	encoder.SetIndent("", "  ")
+	if err := encoder.Encode(value); err != nil {
+		return fmt.Sprintf("%v", value)
+	}
+	return strings.TrimSuffix(buf.String(), "\n")
+}
+
+// jsonDiff строит построчный diff отформатированных JSON: "-" ожидаемое, "+" фактическое
+func jsonDiff(expected, actual interface{}) string {
+	a := strings.Split(prettyJSON(expected), "\n")
+	b := strings.Split(prettyJSON(actual), "\n")
+
+	// Наибольшая общая подпоследовательность строк
+	lcs := make([][]int, len(a)+1)
+	for i := range lcs {
+		lcs[i] = make([]int, len(b)+1)
+	}
+	for i := len(a) - 1; i >= 0; i-- {
+		for j := len(b) - 1; j >= 0; j-- {
+			if a[i] == b[j] {
+				lcs[i][j] = lcs[i+1][j+1] + 1
+			} else if lcs[i+1][j] >= lcs[i][j+1] {
+				lcs[i][j] = lcs[i+1][j]
+			} else {
+				lcs[i][j] = lcs[i][j+1]
+			}
+		}
+	}
+
+	var lines []string
+	i, j := 0, 0
+	for i < len(a) || j < len(b) {
+		switch {
+		case i < len(a) && j < len(b) && a[i] == b[j]:
+			lines = append(lines, "  "+a[i])
+			i++
+			j++
+		case j < len(b) && (i == len(a) || lcs[i][j+1] >= lcs[i+1][j]):
+			lines = append(lines, colorize(colorGreen, "+ "+b[j]))
+			j++
+		default:
+			lines = append(lines, colorize(colorRed, "- "+a[i]))
+			i++
+		}
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+// ANSI-цвета для сообщений об ошибках
+const (
+	colorRed   = "\033[31m"
+	colorGreen = "\033[32m"
+	colorReset = "\033[0m"
+)
+
+// colorize окрашивает строку, если вывод цвета не отключен переменной NO_COLOR
+func colorize(color, line string) string {
+	if os.Getenv("NO_COLOR") != "" {
+		return line
+	}
+	return color + line + colorReset
+}
@@ -0,0 +1,309 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/seed"
+
+	"github.com/google/uuid"
+	"github.com/jmoiron/sqlx"
+	"github.com/stretchr/testify/require"
+)
+
+// LargeDatasetScaleEnv множитель количества водителей большого набора данных
+// (например, 0.1 для локального прогона или 10 для стенда нагрузочного тестирования)
+const LargeDatasetScaleEnv = "TEST_DATASET_SCALE"
+
+// LargeDatasetHistoryDaysEnv глубина истории смен большого набора данных в днях
+const LargeDatasetHistoryDaysEnv = "TEST_DATASET_HISTORY_DAYS"
+
+// largeDatasetPhonePrefix префикс телефонов водителей большого набора данных
+const largeDatasetPhonePrefix = "+7901"
+
+// LatencyBudgetMultiplierEnv множитель порогов задержки для медленных окружений
+const LatencyBudgetMultiplierEnv = "LATENCY_BUDGET_MULTIPLIER"
+
+// LatencyBreachMarker префикс сообщения о превышении порога задержки
+const LatencyBreachMarker = "Latency budget breach"
+
+// LargeDataset размеры набора данных для проверок задержки под объемом
+type LargeDataset struct {
+	Drivers            int
+	LocationsPerDriver int           // точек текущего трека на водителя
+	RatingsPerDriver   int           // оценок на водителя сверх оценок истории смен
+	Interval           time.Duration // интервал между точками текущего трека водителя
+	SpreadKm           float64       // водители распределены в квадрате со стороной SpreadKm вокруг центра
+	HistoryDays        int           // дней истории смен до текущего трека (seed.Seeder), 0 - без истории
+	HistoryInterval    time.Duration // интервал между точками трека в сменах истории
+}
+
+// DefaultLargeDataset 2 000 водителей: история смен за 90 дней с точкой каждые 5 минут (около 12 миллионов
+// строк driver_locations, 120 тысяч смен и полмиллиона оценок) и текущий трек из 250 точек за ~4 часа
+func DefaultLargeDataset() LargeDataset {
+	return LargeDataset{
+		Drivers:            2000,
+		LocationsPerDriver: 250,
+		Interval:           time.Minute,
+		SpreadKm:           30,
+		HistoryDays:        90,
+		HistoryInterval:    5 * time.Minute,
+	}
+}
+
+// LoadLargeDataset возвращает набор по умолчанию, количество водителей в котором умножено на TEST_DATASET_SCALE,
+// а глубина истории задается TEST_DATASET_HISTORY_DAYS
+func LoadLargeDataset(t *testing.T) LargeDataset {
+	dataset := DefaultLargeDataset()
+
+	if value := os.Getenv(LargeDatasetHistoryDaysEnv); value != "" {
+		days, err := strconv.Atoi(value)
+		require.NoError(t, err, "Некорректный %s: %q", LargeDatasetHistoryDaysEnv, value)
+		require.GreaterOrEqual(t, days, 0, "Некорректный %s: %q", LargeDatasetHistoryDaysEnv, value)
+		dataset.HistoryDays = days
+	}
+
+	if value := os.Getenv(LargeDatasetScaleEnv); value != "" {
+		scale, err := strconv.ParseFloat(value, 64)
+		require.NoError(t, err, "Некорректный %s: %q", LargeDatasetScaleEnv, value)
+		require.Positive(t, scale, "Некорректный %s: %q", LargeDatasetScaleEnv, value)
+		dataset.Drivers = int(float64(dataset.Drivers) * scale)
+		if dataset.Drivers < 1 {
+			dataset.Drivers = 1
+		}
+	}
+
+	return dataset
+}
+
+// SeededDataset набор данных, записанный SeedLargeDataset
+type SeededDataset struct {
+	LargeDataset
+	DriverIDs []uuid.UUID
+	Latitude  float64 // центр области, в которой находятся водители
+	Longitude float64
+	From      time.Time // время самой ранней точки текущего трека
+	To        time.Time // время самой поздней точки текущего трека
+	// HistoryFrom начало истории смен; без истории совпадает с From. Смены истории заканчиваются до From.
+	HistoryFrom time.Time
+}
+
+// SeedLargeDataset записывает набор данных, минуя API. История смен за HistoryDays загружается через COPY
+// тем же загрузчиком, что и команда cmd/seed; текущий трек и дополнительные оценки записываются запросами
+// generate_series (по одному на таблицу). Водители находятся на линии вокруг центра Москвы, у каждого
+// своя область, в которой он перемещается. Координаты и оценки зависят только от rngSeed.
+// После записи собирается статистика планировщика, как после автоматического ANALYZE в production.
+func (tdb *TestDB) SeedLargeDataset(t testing.TB, dataset LargeDataset, rngSeed int64) *SeededDataset {
+	ctx := context.Background()
+	seeded := &SeededDataset{
+		LargeDataset: dataset,
+		Latitude:     55.7558,
+		Longitude:    37.6173,
+		To:           time.Now().Truncate(time.Second),
+	}
+	seeded.From = seeded.To.Add(-time.Duration(dataset.LocationsPerDriver-1) * dataset.Interval)
+	seeded.HistoryFrom = seeded.From
+
+	// Смещение в градусах, соответствующее SpreadKm по широте и долготе
+	latSpread := dataset.SpreadKm / 111.0
+	lonSpread := dataset.SpreadKm / (111.0 * 0.5628) // cos(55.75°)
+
+	started := time.Now()
+	var history seed.Result
+	if dataset.HistoryDays > 0 {
+		opts := seed.DefaultOptions()
+		opts.Drivers = dataset.Drivers
+		opts.Days = dataset.HistoryDays
+		opts.LocationInterval = dataset.HistoryInterval
+		opts.PhonePrefix = largeDatasetPhonePrefix
+		opts.Seed = rngSeed
+		opts.Now = seeded.From.Add(-dataset.Interval)
+
+		var err error
+		history, err = seed.NewSeeder(tdb.DB.DB, tdb.logger).Seed(ctx, opts)
+		require.NoError(t, err)
+		seeded.HistoryFrom = opts.Now.Truncate(24*time.Hour).AddDate(0, 0, -opts.Days)
+
+		err = tdb.SelectContext(ctx, &seeded.DriverIDs,
+			`SELECT id FROM drivers WHERE phone LIKE $1 || '%' ORDER BY phone`, largeDatasetPhonePrefix)
+		require.NoError(t, err)
+
+		// Загрузка истории занимает минуты, а поиск поблизости и /drivers/active отбрасывают водителей
+		// с местоположением старше entities.LocationStaleAfter: текущий трек заканчивается в момент записи
+		seeded.To = time.Now().Truncate(time.Second)
+		seeded.From = seeded.To.Add(-time.Duration(dataset.LocationsPerDriver-1) * dataset.Interval)
+	}
+
+	err := tdb.Transaction(func(tx *sqlx.Tx) error {
+		// setseed действует в пределах соединения, поэтому весь посев выполняется в одной транзакции
+		if _, err := tx.ExecContext(ctx, "SELECT setseed($1)", float64(rngSeed%1000)/1000); err != nil {
+			return fmt.Errorf("failed to set seed: %w", err)
+		}
+
+		// Без истории водители записываются здесь же
+		if dataset.HistoryDays == 0 {
+			err := tx.SelectContext(ctx, &seeded.DriverIDs, `
+				INSERT INTO drivers (
+					phone, email, first_name, last_name, birth_date, passport_series, passport_number,
+					license_number, license_expiry, status
+				)
+				SELECT
+					$2 || lpad(n::text, 7, '0'),
+					'load.driver' || n || '@example.com',
+					'Нагрузка', 'Водитель' || n, DATE '1985-01-01', '4500', lpad(n::text, 6, '0'),
+					'LD' || lpad(n::text, 8, '0'), CURRENT_DATE + 365, 'available'
+				FROM generate_series(1, $1) AS n
+				RETURNING id`, dataset.Drivers, largeDatasetPhonePrefix)
+			if err != nil {
+				return fmt.Errorf("failed to seed drivers: %w", err)
+			}
+		}
+
+		_, err := tx.ExecContext(ctx, `
+			INSERT INTO driver_locations (driver_id, latitude, longitude, speed, recorded_at)
+			SELECT
+				d.id,
+				d.base_latitude + (random() - 0.5) * 0.01,
+				d.base_longitude + (random() - 0.5) * 0.01,
+				random() * 60,
+				$1::timestamptz - make_interval(secs => i * $2::float8)
+			FROM (
+				SELECT id,
+					$3 + (random() - 0.5) * $4 AS base_latitude,
+					$5 + (random() - 0.5) * $6 AS base_longitude
+				FROM drivers
+				ORDER BY phone
+			) d
+			CROSS JOIN generate_series(0, $7 - 1) AS i`,
+			seeded.To, dataset.Interval.Seconds(),
+			seeded.Latitude, latSpread, seeded.Longitude, lonSpread,
+			dataset.LocationsPerDriver)
+		if err != nil {
+			return fmt.Errorf("failed to seed locations: %w", err)
+		}
+
+		// Статистика оценок пересчитывается триггером на каждую строку
+		_, err = tx.ExecContext(ctx, `
+			INSERT INTO driver_ratings (driver_id, rating, rating_type)
+			SELECT d.id, 1 + floor(random() * 5)::int, 'customer'
+			FROM drivers d
+			CROSS JOIN generate_series(1, $1)`, dataset.RatingsPerDriver)
+		if err != nil {
+			return fmt.Errorf("failed to seed ratings: %w", err)
+		}
+
+		return nil
+	})
+	require.NoError(t, err)
+
+	_, err = tdb.ExecContext(ctx, "ANALYZE drivers, driver_locations, driver_shifts, driver_ratings, driver_rating_stats")
+	require.NoError(t, err)
+
+	t.Logf("Seeded %d drivers, %d shifts, %d locations, %d ratings over %d days of history in %v",
+		dataset.Drivers, history.Shifts, history.Locations+dataset.Drivers*dataset.LocationsPerDriver,
+		history.Ratings+dataset.Drivers*dataset.RatingsPerDriver, dataset.HistoryDays, time.Since(started))
+	return seeded
+}
+
+// RefreshLargeDataset сдвигает текущий трек набора данных так, чтобы он заканчивался сейчас. Suite, которые
+// записывают набор один раз и проверяют поиск поблизости и /drivers/active дольше entities.LocationStaleAfter,
+// вызывают его перед каждым тестом. История смен не меняется.
+func (tdb *TestDB) RefreshLargeDataset(t testing.TB, seeded *SeededDataset) {
+	shift := time.Now().Truncate(time.Second).Sub(seeded.To)
+	if shift <= 0 {
+		return
+	}
+
+	_, err := tdb.ExecContext(context.Background(), `
+		UPDATE driver_locations SET recorded_at = recorded_at + make_interval(secs => $1)
+		WHERE recorded_at >= $2
+		AND driver_id IN (SELECT id FROM drivers WHERE phone LIKE $3 || '%')`,
+		shift.Seconds(), seeded.From, largeDatasetPhonePrefix)
+	require.NoError(t, err)
+
+	seeded.From = seeded.From.Add(shift)
+	seeded.To = seeded.To.Add(shift)
+}
+
+// LatencyBudget порог задержки операции API на большом наборе данных
+type LatencyBudget struct {
+	Operation string        // операция RequestTimings, например "GET /api/v1/locations/nearby"
+	P95       time.Duration // порог 95-го перцентиля
+	// Queries запросы к БД, которые выполняет операция; их планы сохраняются при превышении порога
+	Queries []PlannedQuery
+}
+
+// PlannedQuery запрос к БД с параметрами одного из вызовов операции
+type PlannedQuery struct {
+	Name  string
+	Query string
+	Args  []interface{}
+}
+
+// AssertLatencyBudget проверяет 95-й перцентиль операции (порог умножается на LATENCY_BUDGET_MULTIPLIER).
+// При превышении в лог теста выводятся планы запросов операции (EXPLAIN ANALYZE), а если задан
+// TEST_ARTIFACTS_DIR, они сохраняются в файл рядом с остальными материалами упавшего теста.
+func (tdb *TestDB) AssertLatencyBudget(t *testing.T, timings *RequestTimings, budget LatencyBudget) {
+	t.Helper()
+
+	samples := len(timings.Samples(budget.Operation))
+	require.NotZero(t, samples, "Нет запросов %s", budget.Operation)
+
+	limit := scaledLatencyBudget(budget.P95)
+
+	p95 := timings.Percentile(budget.Operation, 95)
+	if p95 <= limit {
+		t.Logf("%s: p95 %v over %d requests (limit %v)", budget.Operation, p95, samples, limit)
+		return
+	}
+
+	t.Errorf("%s: %s p95 %v over %d requests, limit %v", LatencyBreachMarker, budget.Operation, p95, samples, limit)
+
+	var plans strings.Builder
+	for _, query := range budget.Queries {
+		fmt.Fprintf(&plans, "-- %s\n%s\n\n", query.Name, tdb.ExplainAnalyze(t, query.Query, query.Args...))
+	}
+	t.Logf("Query plans for %s:\n%s", budget.Operation, plans.String())
+
+	dir := os.Getenv(TestArtifactsDirEnv)
+	if dir == "" {
+		return
+	}
+
+	path := filepath.Join(dir, RunID(), artifactName.ReplaceAllString(t.Name(), "_")+".plan.txt")
+	if err := os.MkdirAll(filepath.Dir(path), 0o755); err != nil {
+		t.Logf("Failed to save query plans: %v", err)
+		return
+	}
+	if err := os.WriteFile(path, []byte(runMetadataHeader()+plans.String()), 0o644); err != nil {
+		t.Logf("Failed to save query plans: %v", err)
+		return
+	}
+	t.Logf("Query plans saved to %s", path)
+}
+
+// ExplainAnalyze выполняет запрос под EXPLAIN (ANALYZE, BUFFERS) и возвращает план в текстовом виде
+func (tdb *TestDB) ExplainAnalyze(t *testing.T, query string, args ...interface{}) string {
+	var lines []string
+	err := tdb.SelectContext(context.Background(), &lines, "EXPLAIN (ANALYZE, BUFFERS) "+query, args...)
+	require.NoError(t, err)
+	return strings.Join(lines, "\n")
+}
+
+// CountLocationsInRange возвращает количество точек истории водителя за период напрямую из БД
+func (tdb *TestDB) CountLocationsInRange(t *testing.T, driverID uuid.UUID, from, to time.Time) int {
+	var count int
+	err := tdb.GetContext(context.Background(), &count, `
+		SELECT COUNT(*) FROM driver_locations
+		WHERE driver_id = $1 AND recorded_at BETWEEN $2 AND $3`, driverID, from, to)
+	require.NoError(t, err)
+	return count
+}
@@ -0,0 +1,131 @@
+//go:build integration
+
+package helpers
+
+import (
+	"math/rand"
+	"net/url"
+	"os"
+	"strconv"
+	"sync"
+	"time"
+)
+
+// runLatencySamples сколько длительностей операции хранится за прогон. Дальше выборка обновляется
+// резервуарным методом и остается равномерной по всему прогону, поэтому память нагрузочных тестов не растет.
+const runLatencySamples = 10000
+
+// runLatencies длительности всех запросов к API за прогон по операциям вида "GET /api/v1/drivers/{id}":
+// с ними AssertLatency сравнивает замер. В отличие от метрик прогона, собираются всегда.
+var runLatencies sync.Map // операция -> *latencyReservoir
+
+// latencyReservoir равномерная выборка длительностей одной операции
+type latencyReservoir struct {
+	mu      sync.Mutex
+	samples []time.Duration
+	total   int
+	random  *rand.Rand
+}
+
+// LatencyT часть testing.TB, которая нужна AssertLatency
+type LatencyT interface {
+	Helper()
+	Errorf(format string, args ...interface{})
+	Logf(format string, args ...interface{})
+}
+
+// recordRunLatency учитывает длительность запроса в выборке операции за прогон
+func recordRunLatency(method, path string, duration time.Duration) {
+	operation := method + " " + templatePath(path)
+	value, ok := runLatencies.Load(operation)
+	if !ok {
+		value, _ = runLatencies.LoadOrStore(operation, &latencyReservoir{random: rand.New(rand.NewSource(time.Now().UnixNano()))})
+	}
+
+	reservoir := value.(*latencyReservoir)
+	reservoir.mu.Lock()
+	defer reservoir.mu.Unlock()
+
+	reservoir.total++
+	if len(reservoir.samples) < runLatencySamples {
+		reservoir.samples = append(reservoir.samples, duration)
+		return
+	}
+	if i := reservoir.random.Intn(reservoir.total); i < runLatencySamples {
+		reservoir.samples[i] = duration
+	}
+}
+
+// RunLatency возвращает перцентили длительности операции за прогон; Samples - число запросов операции
+// (перцентили при большом числе запросов считаются по выборке из runLatencySamples)
+func RunLatency(operation string) LatencyPercentiles {
+	value, ok := runLatencies.Load(operation)
+	if !ok {
+		return LatencyPercentiles{}
+	}
+
+	reservoir := value.(*latencyReservoir)
+	reservoir.mu.Lock()
+	samples := append([]time.Duration(nil), reservoir.samples...)
+	total := reservoir.total
+	reservoir.mu.Unlock()
+
+	latency := NewLatencyPercentiles(samples)
+	latency.Samples = total
+	return latency
+}
+
+// AssertLatency выполняет call - вызов одного или нескольких методов клиента h - и проверяет, что каждый
+// выполненный в нем запрос уложился в budget (порог умножается на LATENCY_BUDGET_MULTIPLIER). Длительности
+// запросов попадают в гистограмму прогона, а сообщение о превышении содержит p50/p95 той же операции
+// за прогон: по нему видно, медленный ли это запрос или медленное все окружение.
+//
+//	var list *httpHandlers.ListDriversResponse
+//	env.API.AssertLatency(t, 200*time.Millisecond, func() { list, _ = env.API.ListDrivers(filter) })
+//
+// Запросы call определяются по истории клиента, поэтому параллельно с call тот же клиент
+// использоваться не должен.
+func (h *APITestHelper) AssertLatency(t LatencyT, budget time.Duration, call func()) {
+	t.Helper()
+
+	before := h.history.Total()
+	call()
+	made := h.history.Total() - before
+
+	if made <= 0 {
+		t.Errorf("%s: no API requests were made within the call", LatencyBreachMarker)
+		return
+	}
+	exchanges := h.history.Exchanges()
+	if made > len(exchanges) {
+		// Часть запросов уже вытеснена из кольцевой истории: проверяются сохранившиеся
+		made = len(exchanges)
+	}
+
+	limit := scaledLatencyBudget(budget)
+	for _, exchange := range exchanges[len(exchanges)-made:] {
+		path := exchange.URL
+		if parsed, err := url.ParseRequestURI(exchange.URL); err == nil {
+			path = parsed.Path
+		}
+		operation := exchange.Method + " " + templatePath(path)
+
+		if exchange.Duration <= limit {
+			t.Logf("%s: %v -> %d (limit %v)", operation, exchange.Duration, exchange.StatusCode, limit)
+			continue
+		}
+
+		run := RunLatency(operation)
+		t.Errorf("%s: %s took %v -> %d, limit %v; this run %s: p50 %v, p95 %v, max %v over %d requests",
+			LatencyBreachMarker, operation, exchange.Duration, exchange.StatusCode, limit,
+			operation, run.P50, run.P95, run.Max, run.Samples)
+	}
+}
+
+// scaledLatencyBudget возвращает порог задержки, умноженный на LATENCY_BUDGET_MULTIPLIER
+func scaledLatencyBudget(budget time.Duration) time.Duration {
+	if multiplier, err := strconv.ParseFloat(os.Getenv(LatencyBudgetMultiplierEnv), 64); err == nil && multiplier > 0 {
+		return time.Duration(float64(budget) * multiplier)
+	}
+	return budget
+}
@@ -0,0 +1,150 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// LoadFailure запрос генератора нагрузки, завершившийся ошибкой
+type LoadFailure struct {
+	DriverID   uuid.UUID
+	StatusCode int
+	Body       string
+	At         time.Time
+}
+
+// LoadReport итоги работы генератора нагрузки
+type LoadReport struct {
+	Sent       int
+	Accepted   []uuid.UUID // ID сохраненных местоположений из ответов сервиса
+	AcceptedAt []time.Time // время получения ответа для соответствующего элемента Accepted
+	Failures   []LoadFailure
+}
+
+// LocationLoadGenerator непрерывно отправляет обновления местоположений водителей через API,
+// пока не будет остановлен. Каждый принятый запрос запоминается по ID местоположения из ответа,
+// чтобы после нагрузки проверить, что ни одно принятое обновление не потеряно.
+type LocationLoadGenerator struct {
+	api       *APITestHelper
+	driverIDs []uuid.UUID
+	interval  time.Duration
+
+	mu     sync.Mutex
+	report LoadReport
+
+	cancel context.CancelFunc
+	wg     sync.WaitGroup
+}
+
+// StartLocationLoad запускает workers воркеров, каждый из которых отправляет обновление
+// местоположения одного из водителей driverIDs раз в interval
+func StartLocationLoad(api *APITestHelper, driverIDs []uuid.UUID, workers int, interval time.Duration) *LocationLoadGenerator {
+	ctx, cancel := context.WithCancel(context.Background())
+	generator := &LocationLoadGenerator{
+		api:       api,
+		driverIDs: driverIDs,
+		interval:  interval,
+		cancel:    cancel,
+	}
+
+	for worker := 0; worker < workers; worker++ {
+		generator.wg.Add(1)
+		go generator.run(ctx, worker, workers)
+	}
+	return generator
+}
+
+// run отправляет обновления водителей worker, worker+workers, ... по кругу до отмены ctx
+func (g *LocationLoadGenerator) run(ctx context.Context, worker, workers int) {
+	defer g.wg.Done()
+
+	ticker := time.NewTicker(g.interval)
+	defer ticker.Stop()
+
+	for step := 0; ; step++ {
+		driverID := g.driverIDs[(worker+step*workers)%len(g.driverIDs)]
+		g.send(driverID, step)
+
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+		}
+	}
+}
+
+// send отправляет одно обновление местоположения и учитывает результат
+func (g *LocationLoadGenerator) send(driverID uuid.UUID, step int) {
+	response := g.api.MakeRequest(APIRequest{
+		Method: http.MethodPost,
+		URL:    g.api.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body: map[string]interface{}{
+			"latitude":  55.7558 + float64(step%100)*0.0001,
+			"longitude": 37.6173 + float64(step%100)*0.0001,
+		},
+	})
+
+	var location struct {
+		ID uuid.UUID `json:"id"`
+	}
+	accepted := response.StatusCode == http.StatusOK &&
+		json.Unmarshal(response.Body, &location) == nil && location.ID != uuid.Nil
+	recordLoadOperation("location_load", accepted)
+
+	g.mu.Lock()
+	defer g.mu.Unlock()
+
+	g.report.Sent++
+	if accepted {
+		g.report.Accepted = append(g.report.Accepted, location.ID)
+		g.report.AcceptedAt = append(g.report.AcceptedAt, time.Now())
+		return
+	}
+	g.report.Failures = append(g.report.Failures, LoadFailure{
+		DriverID:   driverID,
+		StatusCode: response.StatusCode,
+		Body:       string(response.Body),
+		At:         time.Now(),
+	})
+}
+
+// Accepted возвращает количество принятых на данный момент обновлений
+func (g *LocationLoadGenerator) Accepted() int {
+	g.mu.Lock()
+	defer g.mu.Unlock()
+	return len(g.report.Accepted)
+}
+
+// WaitForAccepted ждет, пока сервис примет еще count обновлений сверх уже принятых
+func (g *LocationLoadGenerator) WaitForAccepted(t *testing.T, count int, timeout time.Duration) {
+	t.Helper()
+
+	target := g.Accepted() + count
+	WaitForCondition(t, func() bool {
+		return g.Accepted() >= target
+	}, timeout, fmt.Sprintf("load generator accepted %d location updates", target))
+}
+
+// Stop останавливает воркеров, дожидается отправленных запросов и возвращает итоги
+func (g *LocationLoadGenerator) Stop() *LoadReport {
+	g.cancel()
+	g.wg.Wait()
+
+	g.mu.Lock()
+	defer g.mu.Unlock()
+
+	report := g.report
+	report.Accepted = append([]uuid.UUID(nil), g.report.Accepted...)
+	report.AcceptedAt = append([]time.Time(nil), g.report.AcceptedAt...)
+	report.Failures = append([]LoadFailure(nil), g.report.Failures...)
+	return &report
+}
@@ -0,0 +1,88 @@
+//go:build integration
+
+package helpers
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"strconv"
+	"time"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/google/uuid"
+)
+
+// HistoryQuery параметры запроса истории местоположений
+type HistoryQuery struct {
+	From     time.Time
+	To       time.Time
+	Interval string // интервал прореживания, например "1m"
+	Cursor   string // курсор следующей страницы
+	Limit    int
+}
+
+// HistoryPage страница истории местоположений
+type HistoryPage struct {
+	httpHandlers.LocationHistoryResponse
+}
+
+// GetLocationHistory запрашивает историю местоположений водителя через API
+func (h *APITestHelper) GetLocationHistory(driverID uuid.UUID, query HistoryQuery) (*HistoryPage, *APIResponse) {
+	params := make(map[string]string)
+	if !query.From.IsZero() {
+		params["from"] = query.From.UTC().Format(time.RFC3339)
+	}
+	if !query.To.IsZero() {
+		params["to"] = query.To.UTC().Format(time.RFC3339)
+	}
+	if query.Interval != "" {
+		params["interval"] = query.Interval
+	}
+	if query.Cursor != "" {
+		params["cursor"] = query.Cursor
+	}
+	if query.Limit != 0 {
+		params["limit"] = strconv.Itoa(query.Limit)
+	}
+
+	response := h.MakeRequest(APIRequest{
+		Method:      http.MethodGet,
+		URL:         h.APIPath(fmt.Sprintf("/drivers/%s/locations/history", driverID)),
+		QueryParams: params,
+	})
+
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var page HistoryPage
+	h.UnmarshalResponse(response, &page)
+	return &page, response
+}
+
+// GetLocationAt запрашивает местоположение водителя на момент времени at
+func (h *APITestHelper) GetLocationAt(driverID uuid.UUID, at time.Time) (*httpHandlers.LocationResponse, *APIResponse) {
+	response := h.MakeRequest(APIRequest{
+		Method: http.MethodGet,
+		URL:    h.APIPath(fmt.Sprintf("/drivers/%s/locations/at", driverID)),
+		QueryParams: map[string]string{
+			"timestamp": at.UTC().Format(time.RFC3339),
+		},
+	})
+
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var location httpHandlers.LocationResponse
+	h.UnmarshalResponse(response, &location)
+	return &location, response
+}
+
+// IsUnknownRoute проверяет, что запрос не сопоставился ни с одним маршрутом роутера
+// (gin отвечает 404 с текстовым, а не JSON телом)
+func IsUnknownRoute(response *APIResponse) bool {
+	return response.StatusCode == http.StatusNotFound && !json.Valid(response.Body)
+}
@@ -0,0 +1,21 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// AgeDriverLocations сдвигает метки времени всех местоположений водителя на age в прошлое,
+// имитируя время без обновлений (часы в сервисе не подменяются)
+func (tdb *TestDB) AgeDriverLocations(t *testing.T, driverID uuid.UUID, age time.Duration) {
+	_, err := tdb.ExecContext(context.Background(),
+		"UPDATE driver_locations SET recorded_at = recorded_at - make_interval(secs => $1) WHERE driver_id = $2",
+		age.Seconds(), driverID)
+	require.NoError(t, err)
+}
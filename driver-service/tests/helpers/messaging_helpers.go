@@ -0,0 +1,289 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"mime"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"strconv"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/infrastructure/messaging"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения тестового MailHog из docker-compose.test.yml
+const (
+	TestSMTPHostEnv   = "TEST_SMTP_HOST"
+	TestSMTPPortEnv   = "TEST_SMTP_PORT"
+	TestMailHogAPIEnv = "TEST_MAILHOG_API"
+)
+
+// Отправитель писем и учетные данные заглушки SMS провайдера в тестовом окружении
+const (
+	TestEmailFrom = "Taxi Service <noreply@taxi.test>"
+	TestSMSFrom   = "TaxiTest"
+	TestSMSAPIKey = "test-sms-api-key"
+)
+
+// testMailHogReadyTimeout сколько ждать готовности MailHog, прежде чем пропустить тесты сообщений
+const testMailHogReadyTimeout = 15 * time.Second
+
+// ReceivedEmail письмо, принятое MailHog
+type ReceivedEmail struct {
+	From     string
+	To       []string
+	Subject  string // декодированная тема
+	Body     string // текст с переводами строк \n
+	Template string // заголовок X-Template
+	Locale   string // заголовок Content-Language
+}
+
+// ReceivedSMS запрос отправки SMS, полученный заглушкой провайдера
+type ReceivedSMS struct {
+	messaging.SMSRequest
+	ReceivedAt time.Time
+}
+
+// MessagingMock MailHog для писем и встроенная заглушка HTTP API SMS провайдера.
+// Заглушка проверяет ключ API (401 при несовпадении) и может отвечать ошибкой.
+type MessagingMock struct {
+	mailhogURL string
+	server     *httptest.Server
+
+	mu          sync.Mutex
+	sms         []ReceivedSMS
+	failureCode int // код ответа на все запросы; 0 - SMS принимаются
+}
+
+// EnableMessaging подключает окружение к MailHog и заглушке SMS провайдера: DriverService и роутер
+// пересобираются поверх messaging.Notifier, по окончании теста окружение восстанавливается.
+// Если MailHog недоступен, тест пропускается.
+func (env *TestEnvironment) EnableMessaging(t *testing.T) *MessagingMock {
+	t.Helper()
+
+	mock := &MessagingMock{
+		mailhogURL: strings.TrimRight(getEnvOrDefault(TestMailHogAPIEnv, "http://localhost:8026"), "/"),
+	}
+
+	// Ждем готовности MailHog: контейнер из docker-compose.test.yml может еще запускаться
+	deadline := time.Now().Add(testMailHogReadyTimeout)
+	for {
+		err := mock.deleteAll()
+		if err == nil {
+			break
+		}
+		if time.Now().After(deadline) {
+			t.Skipf("MailHog %s недоступен: %v", mock.mailhogURL, err)
+		}
+		time.Sleep(conditionPollInterval)
+	}
+
+	mock.server = httptest.NewServer(http.HandlerFunc(mock.handleSMS))
+	t.Cleanup(mock.server.Close)
+
+	port, err := strconv.Atoi(getEnvOrDefault(TestSMTPPortEnv, "1026"))
+	require.NoError(t, err)
+
+	notifier := messaging.NewNotifier(
+		env.Webhooks,
+		env.DriverRepo,
+		messaging.NewSMTPSender(config.SMTPConfig{
+			Host:    getEnvOrDefault(TestSMTPHostEnv, "localhost"),
+			Port:    port,
+			From:    TestEmailFrom,
+			Timeout: 5 * time.Second,
+		}),
+		messaging.NewSMSClient(config.SMSAPIConfig{
+			BaseURL: mock.server.URL,
+			APIKey:  TestSMSAPIKey,
+			From:    TestSMSFrom,
+			Timeout: 5 * time.Second,
+		}),
+		env.Logger,
+	)
+
+	previousService, previousRouter, previousAPI := env.DriverService, env.Router, env.API
+	t.Cleanup(func() {
+		env.Messaging = nil
+		env.DriverService, env.Router, env.API = previousService, previousRouter, previousAPI
+	})
+
+	env.Messaging = notifier
+	env.DriverService = services.NewStrictDriverService(
+		services.NewDriverService(env.DriverRepo, env.DocumentRepo, env.geoCache.EvictingPublisher(notifier), env.Logger), env.Features)
+	env.buildServer(t)
+
+	return mock
+}
+
+// FailSMS отвечает кодом statusCode на все запросы отправки SMS
+func (m *MessagingMock) FailSMS(statusCode int) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.failureCode = statusCode
+}
+
+// SMS возвращает SMS, принятые заглушкой для номера phone
+func (m *MessagingMock) SMS(phone string) []ReceivedSMS {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	var result []ReceivedSMS
+	for _, sms := range m.sms {
+		if sms.To == phone {
+			result = append(result, sms)
+		}
+	}
+	return result
+}
+
+// Emails возвращает письма на адрес to в порядке получения
+func (m *MessagingMock) Emails(t *testing.T, to string) []ReceivedEmail {
+	t.Helper()
+
+	emails, err := m.search(to)
+	require.NoError(t, err)
+	return emails
+}
+
+// WaitForEmails ждет, пока MailHog примет count писем на адрес to, и возвращает их
+func (m *MessagingMock) WaitForEmails(t *testing.T, to string, count int) []ReceivedEmail {
+	t.Helper()
+
+	var emails []ReceivedEmail
+	Eventually(t, func(c *EventuallyT) {
+		var err error
+		emails, err = m.search(to)
+		require.NoError(c, err)
+		assert.Len(c, emails, count, "Письма на %s", to)
+	}, 5*time.Second, conditionPollInterval)
+	return emails
+}
+
+// handleSMS принимает запрос отправки SMS
+func (m *MessagingMock) handleSMS(w http.ResponseWriter, req *http.Request) {
+	if req.Method != http.MethodPost || req.URL.Path != "/messages" {
+		http.NotFound(w, req)
+		return
+	}
+	if req.Header.Get("Authorization") != "Bearer "+TestSMSAPIKey {
+		w.WriteHeader(http.StatusUnauthorized)
+		return
+	}
+
+	var request messaging.SMSRequest
+	if err := json.NewDecoder(req.Body).Decode(&request); err != nil {
+		w.WriteHeader(http.StatusBadRequest)
+		return
+	}
+
+	m.mu.Lock()
+	failureCode := m.failureCode
+	if failureCode == 0 {
+		m.sms = append(m.sms, ReceivedSMS{SMSRequest: request, ReceivedAt: time.Now()})
+	}
+	m.mu.Unlock()
+
+	if failureCode != 0 {
+		w.WriteHeader(failureCode)
+		return
+	}
+	w.WriteHeader(http.StatusAccepted)
+}
+
+// mailhogMessage письмо в ответе API MailHog v2
+type mailhogMessage struct {
+	Raw struct {
+		From string   `json:"From"`
+		To   []string `json:"To"`
+	} `json:"Raw"`
+	Content struct {
+		Headers map[string][]string `json:"Headers"`
+		Body    string              `json:"Body"`
+	} `json:"Content"`
+	Created time.Time `json:"Created"`
+}
+
+// search ищет письма по получателю через API MailHog
+func (m *MessagingMock) search(to string) ([]ReceivedEmail, error) {
+	query := url.Values{"kind": {"to"}, "query": {to}}
+	resp, err := http.Get(m.mailhogURL + "/api/v2/search?" + query.Encode())
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("MailHog search failed: status %d", resp.StatusCode)
+	}
+
+	var result struct {
+		Items []mailhogMessage `json:"items"`
+	}
+	if err := json.NewDecoder(resp.Body).Decode(&result); err != nil {
+		return nil, err
+	}
+
+	// MailHog возвращает новые письма первыми
+	decoder := new(mime.WordDecoder)
+	emails := make([]ReceivedEmail, 0, len(result.Items))
+	for i := len(result.Items) - 1; i >= 0; i-- {
+		item := result.Items[i]
+		subject, err := decoder.DecodeHeader(firstHeader(item.Content.Headers, "Subject"))
+		if err != nil {
+			return nil, err
+		}
+
+		emails = append(emails, ReceivedEmail{
+			From:     item.Raw.From,
+			To:       item.Raw.To,
+			Subject:  subject,
+			Body:     strings.ReplaceAll(item.Content.Body, "\r\n", "\n"),
+			Template: firstHeader(item.Content.Headers, messaging.TemplateHeader),
+			Locale:   firstHeader(item.Content.Headers, messaging.LocaleHeader),
+		})
+	}
+	return emails, nil
+}
+
+// deleteAll удаляет все письма из MailHog
+func (m *MessagingMock) deleteAll() error {
+	ctx, cancel := context.WithTimeout(context.Background(), 2*time.Second)
+	defer cancel()
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodDelete, m.mailhogURL+"/api/v1/messages", nil)
+	if err != nil {
+		return err
+	}
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		return err
+	}
+	resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("MailHog cleanup failed: status %d", resp.StatusCode)
+	}
+	return nil
+}
+
+// firstHeader возвращает первое значение заголовка письма
+func firstHeader(headers map[string][]string, key string) string {
+	if values := headers[key]; len(values) > 0 {
+		return values[0]
+	}
+	return ""
+}
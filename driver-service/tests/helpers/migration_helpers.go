@@ -0,0 +1,101 @@
+//go:build integration
+
+package helpers
+
+import (
+	"database/sql"
+	"errors"
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+	"testing"
+
+	"github.com/golang-migrate/migrate/v4"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// LatestMigrationVersion возвращает номер последней миграции из каталога миграций
+func LatestMigrationVersion(t *testing.T) uint {
+	latest, err := latestMigrationVersion()
+	require.NoError(t, err)
+	return latest
+}
+
+// latestMigrationVersion возвращает номер последней миграции или ошибку, если каталог не читается
+// или миграций в нем нет
+func latestMigrationVersion() (uint, error) {
+	entries, err := os.ReadDir(testMigrationsDir)
+	if err != nil {
+		return 0, fmt.Errorf("failed to read migrations directory: %w", err)
+	}
+
+	var latest uint
+	for _, entry := range entries {
+		if !strings.HasSuffix(entry.Name(), ".up.sql") {
+			continue
+		}
+
+		prefix, _, _ := strings.Cut(entry.Name(), "_")
+		version, err := strconv.ParseUint(prefix, 10, 64)
+		if err != nil {
+			return 0, fmt.Errorf("invalid migration name %s: %w", entry.Name(), err)
+		}
+		if uint(version) > latest {
+			latest = uint(version)
+		}
+	}
+
+	if latest == 0 {
+		return 0, fmt.Errorf("no migrations found in %s", testMigrationsDir)
+	}
+	return latest, nil
+}
+
+// MigrateTo приводит схему тестовой БД к версии version, применяя или откатывая миграции.
+// Миграции выполняются через отдельное подключение, поэтому пул TestDB остается доступен
+// сервису и нагрузке, идущей параллельно с миграцией.
+func (tdb *TestDB) MigrateTo(t *testing.T, version uint) {
+	m, closeDB := tdb.migrator(t)
+	defer closeDB()
+
+	err := m.Migrate(version)
+	if errors.Is(err, migrate.ErrNoChange) {
+		return
+	}
+	require.NoError(t, err, "Не удалось применить миграции до версии %d", version)
+
+	tdb.logger.Info("Test database migrated", zap.Uint("version", version))
+}
+
+// MigrationVersion возвращает текущую версию схемы тестовой БД
+func (tdb *TestDB) MigrationVersion(t *testing.T) uint {
+	m, closeDB := tdb.migrator(t)
+	defer closeDB()
+
+	version, dirty, err := m.Version()
+	require.NoError(t, err)
+	require.False(t, dirty, "Схема тестовой БД в состоянии dirty на версии %d", version)
+	return version
+}
+
+// migrator открывает отдельное подключение к тестовой БД и создает поверх него экземпляр migrate
+func (tdb *TestDB) migrator(t *testing.T) (*migrate.Migrate, func()) {
+	cfg := getTestConfig()
+	cfg.Database.Database = tdb.dbName
+
+	db, err := sql.Open("postgres", cfg.Database.GetDSN())
+	require.NoError(t, err)
+
+	m, err := newTestMigrator(db)
+	if err != nil {
+		db.Close()
+		require.NoError(t, err)
+	}
+
+	return m, func() {
+		m.Close()
+		db.Close()
+	}
+}
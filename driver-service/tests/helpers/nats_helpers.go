@@ -0,0 +1,421 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"sort"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/nats"
+
+	natsgo "github.com/nats-io/nats.go"
+	"github.com/nats-io/nats.go/jetstream"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// TestNATSURLEnv адрес тестового NATS с JetStream (test-nats из docker-compose.test.yml)
+const TestNATSURLEnv = "TEST_NATS_URL"
+
+// natsReplyTimeout сколько ждать подтверждения JetStream или ответа на PING
+const natsReplyTimeout = 5 * time.Second
+
+// NATSHelper помощник для бенчмарков публикации в NATS и событий, которые получает сервис; работает через
+// тот же клиент nats.go, что и сервис
+type NATSHelper struct {
+	t   *testing.T
+	url string
+	nc  *natsgo.Conn
+	js  jetstream.JetStream
+}
+
+// NewNATSHelper создает помощник и подключается к NATS до конца теста.
+// Если NATS недоступен, тест пропускается - NATS нужен только бенчмаркам и тестам входящих событий.
+func NewNATSHelper(t *testing.T) *NATSHelper {
+	url := getEnvOrDefault(TestNATSURLEnv, "nats://localhost:4223")
+	nc, err := connectNATS(url)
+	if err != nil {
+		t.Skipf("NATS недоступен (%s): %v", url, err)
+	}
+	t.Cleanup(nc.Close)
+
+	js, err := jetstream.New(nc)
+	require.NoError(t, err)
+	return &NATSHelper{t: t, url: url, nc: nc, js: js}
+}
+
+// connectNATS подключается к тестовому NATS
+func connectNATS(url string) (*natsgo.Conn, error) {
+	return natsgo.Connect(url, natsgo.Name("driver-service-tests"), natsgo.Timeout(2*time.Second))
+}
+
+// NewLocationRelay подключает к тестовому NATS рассылку местоположений потока позиций между репликами,
+// которая передает полученные местоположения deliver; закрывается после теста
+func (h *NATSHelper) NewLocationRelay(deliver func(locations ...*entities.DriverLocation)) *nats.LocationRelay {
+	relay, err := nats.NewLocationRelay(config.NATSConfig{URL: h.url, ClientID: "driver-service-tests"}, deliver, zap.NewNop())
+	require.NoError(h.t, err)
+	h.t.Cleanup(relay.Close)
+	return relay
+}
+
+// ServiceSubjects возвращает темы событий, которые публикует сервис, из каталога событий
+func ServiceSubjects(t *testing.T) []string {
+	catalog, err := LoadEventCatalog()
+	require.NoError(t, err)
+
+	var subjects []string
+	for _, event := range catalog {
+		if event.Direction == EventPublished && event.Implemented {
+			subjects = append(subjects, event.Subject)
+		}
+	}
+	require.NotEmpty(t, subjects, "В каталоге нет публикуемых событий")
+	return subjects
+}
+
+// EnsureStream пересоздает поток JetStream с темами subjects и удаляет его после теста.
+// Если JetStream на сервере не включен, тест пропускается.
+func (h *NATSHelper) EnsureStream(name string, subjects []string) {
+	h.t.Helper()
+	ctx, cancel := context.WithTimeout(context.Background(), natsReplyTimeout)
+	defer cancel()
+
+	err := h.js.DeleteStream(ctx, name)
+	if err == nil || errors.Is(err, jetstream.ErrStreamNotFound) {
+		_, err = h.js.CreateStream(ctx, jetstream.StreamConfig{
+			Name:     name,
+			Subjects: subjects,
+			Storage:  jetstream.FileStorage,
+		})
+	}
+	if errors.Is(err, jetstream.ErrJetStreamNotEnabled) || errors.Is(err, natsgo.ErrNoResponders) ||
+		errors.Is(err, context.DeadlineExceeded) {
+		h.t.Skipf("JetStream недоступен (%s): %v", h.url, err)
+	}
+	require.NoError(h.t, err)
+
+	h.t.Cleanup(func() { h.DeleteStream(name) })
+}
+
+// DeleteStream удаляет поток JetStream, если он есть
+func (h *NATSHelper) DeleteStream(name string) {
+	ctx, cancel := context.WithTimeout(context.Background(), natsReplyTimeout)
+	defer cancel()
+
+	if err := h.js.DeleteStream(ctx, name); !errors.Is(err, jetstream.ErrStreamNotFound) {
+		require.NoError(h.t, err)
+	}
+}
+
+// StreamMessages возвращает количество сообщений в потоке JetStream
+func (h *NATSHelper) StreamMessages(name string) int {
+	ctx, cancel := context.WithTimeout(context.Background(), natsReplyTimeout)
+	defer cancel()
+
+	stream, err := h.js.Stream(ctx, name)
+	require.NoError(h.t, err)
+	info, err := stream.Info(ctx)
+	require.NoError(h.t, err)
+	return int(info.State.Msgs)
+}
+
+// ConsumerState состояние консьюмера JetStream
+type ConsumerState struct {
+	NumPending     int // сообщения потока, еще не доставленные консьюмеру
+	NumAckPending  int // доставленные сообщения без подтверждения
+	NumRedelivered int // неподтвержденные сообщения, доставленные повторно после ack_wait или отказа в обработке
+}
+
+// Drained проверяет, что все сообщения потока доставлены и подтверждены
+func (s ConsumerState) Drained() bool {
+	return s.NumPending == 0 && s.NumAckPending == 0
+}
+
+// ConsumerState возвращает состояние консьюмера durable потока stream
+func (h *NATSHelper) ConsumerState(stream, durable string) (ConsumerState, error) {
+	ctx, cancel := context.WithTimeout(context.Background(), natsReplyTimeout)
+	defer cancel()
+
+	consumer, err := h.js.Consumer(ctx, stream, durable)
+	if err != nil {
+		return ConsumerState{}, err
+	}
+	info, err := consumer.Info(ctx)
+	if err != nil {
+		return ConsumerState{}, err
+	}
+	return ConsumerState{
+		NumPending:     int(info.NumPending),
+		NumAckPending:  info.NumAckPending,
+		NumRedelivered: info.NumRedelivered,
+	}, nil
+}
+
+// NATSMessage сообщение, публикуемое тестом или полученное подпиской
+type NATSMessage struct {
+	Subject string
+	Reply   string            // тема ответа
+	Header  map[string]string // заголовки; у повторяющихся остается первое значение, без заголовков nil
+	Payload []byte
+}
+
+// Publish публикует сообщение с заголовками header и ждет, пока сервер его обработает
+func (h *NATSHelper) Publish(subject string, header map[string]string, payload []byte) {
+	h.PublishAll([]NATSMessage{{Subject: subject, Header: header, Payload: payload}})
+}
+
+// PublishAll публикует сообщения по порядку через одно соединение и ждет, пока сервер их обработает
+// и передаст подписчикам: так тысячи сообщений уходят без ожидания ответа на каждое
+func (h *NATSHelper) PublishAll(messages []NATSMessage) {
+	for _, message := range messages {
+		require.NoError(h.t, h.nc.PublishMsg(message.msg()))
+	}
+	require.NoError(h.t, h.nc.FlushTimeout(natsReplyTimeout))
+}
+
+// JetStreamAck подтверждение записи сообщения в поток JetStream
+type JetStreamAck struct {
+	Stream    string
+	Sequence  uint64
+	Duplicate bool // сообщение с тем же Nats-Msg-Id уже записано в окне дедупликации
+}
+
+// PublishJetStream публикует сообщение с заголовками header в поток JetStream и возвращает подтверждение
+func (h *NATSHelper) PublishJetStream(subject string, header map[string]string, payload []byte) JetStreamAck {
+	ctx, cancel := context.WithTimeout(context.Background(), natsReplyTimeout)
+	defer cancel()
+
+	ack, err := h.js.PublishMsg(ctx, NATSMessage{Subject: subject, Header: header, Payload: payload}.msg())
+	require.NoError(h.t, err)
+	return JetStreamAck{Stream: ack.Stream, Sequence: ack.Sequence, Duplicate: ack.Duplicate}
+}
+
+// NATSSubscription подписка теста на тему NATS
+type NATSSubscription struct {
+	t   *testing.T
+	sub *natsgo.Subscription
+}
+
+// Subscribe подписывается на тему subject до конца теста
+func (h *NATSHelper) Subscribe(subject string) *NATSSubscription {
+	sub, err := h.nc.SubscribeSync(subject)
+	require.NoError(h.t, err)
+	h.t.Cleanup(func() { sub.Unsubscribe() })
+	require.NoError(h.t, h.nc.FlushTimeout(natsReplyTimeout))
+
+	return &NATSSubscription{t: h.t, sub: sub}
+}
+
+// Next ждет следующее сообщение подписки не дольше natsReplyTimeout
+func (s *NATSSubscription) Next() *NATSMessage {
+	msg, err := s.sub.NextMsg(natsReplyTimeout)
+	require.NoError(s.t, err, "Сообщение подписки не получено")
+	return newNATSMessage(msg)
+}
+
+// Receive передает сообщения подписки visit по одному, пока visit не вернет false или сообщения
+// не перестанут приходить дольше idle, и возвращает количество переданных сообщений. Тест хранит
+// только текущее сообщение, поэтому подходит для потоков, которые не помещаются в память теста;
+// может выполняться в отдельной горутине теста.
+func (s *NATSSubscription) Receive(idle time.Duration, visit func(message *NATSMessage) bool) int {
+	received := 0
+	for {
+		msg, err := s.sub.NextMsg(idle)
+		if err != nil {
+			return received
+		}
+
+		received++
+		if !visit(newNATSMessage(msg)) {
+			return received
+		}
+	}
+}
+
+// msg сообщение клиента с заголовками в исходном написании имен
+func (m NATSMessage) msg() *natsgo.Msg {
+	msg := &natsgo.Msg{Subject: m.Subject, Reply: m.Reply, Data: m.Payload}
+	if len(m.Header) > 0 {
+		msg.Header = make(natsgo.Header, len(m.Header))
+		for name, value := range m.Header {
+			msg.Header[name] = []string{value}
+		}
+	}
+	return msg
+}
+
+// newNATSMessage сообщение теста из сообщения клиента
+func newNATSMessage(msg *natsgo.Msg) *NATSMessage {
+	message := &NATSMessage{Subject: msg.Subject, Reply: msg.Reply, Payload: msg.Data}
+	if len(msg.Header) > 0 {
+		message.Header = make(map[string]string, len(msg.Header))
+		for name, values := range msg.Header {
+			if len(values) > 0 {
+				message.Header[name] = values[0]
+			}
+		}
+	}
+	return message
+}
+
+// NATSBenchmarkOptions параметры бенчмарка публикации
+type NATSBenchmarkOptions struct {
+	Operation   string
+	Subjects    []string // темы публикуются по кругу
+	Messages    int      // всего сообщений
+	Concurrency int      // параллельных соединений, по умолчанию 1
+	Batch       int      // сообщений, публикуемых до ожидания подтверждений, по умолчанию 1
+	PayloadSize int      // размер сообщения в байтах, по умолчанию 512 (типичное событие местоположения)
+	// JetStream ждать подтверждения записи в поток на каждое сообщение; иначе пакет публикуется
+	// в core NATS и подтверждением служит ответ сервера на PING после пакета
+	JetStream bool
+}
+
+// NATSBenchmarkResult результат бенчмарка публикации с перцентилями задержки подтверждения
+type NATSBenchmarkResult struct {
+	BenchmarkResult
+	JetStream   bool
+	Concurrency int
+	Batch       int
+	P50         time.Duration
+	P95         time.Duration
+	P99         time.Duration
+	Max         time.Duration
+}
+
+// Benchmark публикует сообщения в Concurrency соединениях пакетами по Batch сообщений.
+// Сообщение без подтверждения за natsReplyTimeout или с ошибкой JetStream считается ошибкой.
+func (h *NATSHelper) Benchmark(options NATSBenchmarkOptions) *NATSBenchmarkResult {
+	if options.Concurrency < 1 {
+		options.Concurrency = 1
+	}
+	if options.Batch < 1 {
+		options.Batch = 1
+	}
+	if options.PayloadSize < 1 {
+		options.PayloadSize = 512
+	}
+	payload := bytes.Repeat([]byte("x"), options.PayloadSize)
+
+	var (
+		mu        sync.Mutex
+		wg        sync.WaitGroup
+		latencies = make([]time.Duration, 0, options.Messages)
+		failed    int
+	)
+	batches := make(chan int)
+
+	start := time.Now()
+	for w := 0; w < options.Concurrency; w++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+
+			nc, err := connectNATS(h.url)
+			var js jetstream.JetStream
+			if err == nil {
+				defer nc.Close()
+				js, err = jetstream.New(nc)
+			}
+			if err != nil {
+				h.t.Errorf("%s: failed to connect to NATS: %v", options.Operation, err)
+			}
+
+			for first := range batches {
+				subjects := make([]string, min(options.Batch, options.Messages-first))
+				for i := range subjects {
+					subjects[i] = options.Subjects[(first+i)%len(options.Subjects)]
+				}
+
+				var acked []time.Duration
+				if err == nil {
+					acked = publishBatch(nc, js, subjects, payload, options.JetStream)
+				}
+
+				mu.Lock()
+				latencies = append(latencies, acked...)
+				failed += len(subjects) - len(acked)
+				mu.Unlock()
+			}
+		}()
+	}
+
+	for first := 0; first < options.Messages; first += options.Batch {
+		batches <- first
+	}
+	close(batches)
+	wg.Wait()
+	totalTime := time.Since(start)
+
+	sort.Slice(latencies, func(i, j int) bool { return latencies[i] < latencies[j] })
+	return &NATSBenchmarkResult{
+		BenchmarkResult: BenchmarkResult{
+			Operation:      options.Operation,
+			TotalTime:      totalTime,
+			OperationCount: options.Messages,
+			AvgTime:        totalTime / time.Duration(options.Messages),
+			OpsPerSecond:   float64(options.Messages) / totalTime.Seconds(),
+			Errors:         failed,
+		},
+		JetStream:   options.JetStream,
+		Concurrency: options.Concurrency,
+		Batch:       options.Batch,
+		P50:         percentile(latencies, 50),
+		P95:         percentile(latencies, 95),
+		P99:         percentile(latencies, 99),
+		Max:         percentile(latencies, 100),
+	}
+}
+
+// publishBatch публикует пакет сообщений и возвращает задержки полученных подтверждений:
+// для JetStream - до каждого подтверждения без ошибки, для core NATS - до ответа на PING для всего пакета
+func publishBatch(nc *natsgo.Conn, js jetstream.JetStream, subjects []string, payload []byte, jetStream bool) []time.Duration {
+	sent := time.Now()
+	if !jetStream {
+		for _, subject := range subjects {
+			if err := nc.Publish(subject, payload); err != nil {
+				return nil
+			}
+		}
+		// Ответ на PING означает, что сервер обработал все сообщения пакета
+		if err := nc.FlushTimeout(natsReplyTimeout); err != nil {
+			return nil
+		}
+		elapsed := time.Since(sent)
+		acked := make([]time.Duration, len(subjects))
+		for i := range acked {
+			acked[i] = elapsed
+		}
+		return acked
+	}
+
+	futures := make([]jetstream.PubAckFuture, 0, len(subjects))
+	for _, subject := range subjects {
+		future, err := js.PublishAsync(subject, payload)
+		if err != nil {
+			break
+		}
+		futures = append(futures, future)
+	}
+
+	// Подтверждения одного соединения приходят по порядку публикации
+	timeout := time.After(natsReplyTimeout)
+	var acked []time.Duration
+	for _, future := range futures {
+		select {
+		case <-future.Ok():
+			acked = append(acked, time.Since(sent))
+		case <-future.Err():
+		case <-timeout:
+			return acked
+		}
+	}
+	return acked
+}
@@ -0,0 +1,203 @@
+//go:build integration
+
+package helpers
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"gopkg.in/yaml.v3"
+)
+
+// Виды негативных случаев, генерируемых по OpenAPI спецификации
+const (
+	NegativeMissing       = "missing"        // обязательное поле отсутствует
+	NegativeWrongType     = "wrong_type"     // значение другого JSON типа
+	NegativeOutOfEnum     = "out_of_enum"    // значение вне перечисления
+	NegativeInvalidFormat = "invalid_format" // строка не соответствует format (email)
+)
+
+// NegativeCase запрос с одним нарушением схемы тела запроса
+type NegativeCase struct {
+	OperationID string
+	Method      string
+	Path        string // шаблон пути, например /api/v1/drivers/{id}
+	Field       string
+	Kind        string
+	Body        map[string]interface{}
+}
+
+// Name возвращает имя случая вида createDriver/phone/missing
+func (c NegativeCase) Name() string {
+	return c.OperationID + "/" + c.Field + "/" + c.Kind
+}
+
+// GenerateNegativeCases строит негативные случаи для всех реализованных операций спецификации с JSON телом:
+// для каждого обязательного поля - отсутствие и неверный тип, для каждого enum - значение вне перечисления,
+// для полей с format: email - некорректный адрес. Каждый случай получается из валидного тела bases[operationId].
+func GenerateNegativeCases(bases map[string]map[string]interface{}) ([]NegativeCase, error) {
+	data, err := os.ReadFile(OpenAPISpecPath())
+	if err != nil {
+		return nil, fmt.Errorf("failed to read OpenAPI spec: %w", err)
+	}
+
+	var spec map[string]interface{}
+	if err := yaml.Unmarshal(data, &spec); err != nil {
+		return nil, fmt.Errorf("failed to parse OpenAPI spec: %w", err)
+	}
+
+	operations, err := LoadOpenAPIOperations()
+	if err != nil {
+		return nil, err
+	}
+
+	paths, _ := spec["paths"].(map[string]interface{})
+
+	var cases []NegativeCase
+	for _, operation := range operations {
+		if !operation.Implemented {
+			continue
+		}
+
+		item, _ := paths[operation.Path].(map[string]interface{})
+		raw, _ := item[strings.ToLower(operation.Method)].(map[string]interface{})
+		schema := requestBodySchema(spec, raw)
+		if schema == nil {
+			continue
+		}
+
+		properties, _ := schema["properties"].(map[string]interface{})
+		var required []string
+		for _, field := range asList(schema["required"]) {
+			if name, ok := field.(string); ok {
+				required = append(required, name)
+			}
+		}
+		sort.Strings(required)
+
+		var fields []string
+		for field := range properties {
+			fields = append(fields, field)
+		}
+		sort.Strings(fields)
+
+		newCase := func(field, kind string, mutate func(body map[string]interface{})) NegativeCase {
+			body := make(map[string]interface{})
+			for key, value := range bases[operation.OperationID] {
+				body[key] = value
+			}
+			mutate(body)
+
+			return NegativeCase{
+				OperationID: operation.OperationID,
+				Method:      operation.Method,
+				Path:        operation.Path,
+				Field:       field,
+				Kind:        kind,
+				Body:        body,
+			}
+		}
+
+		var operationCases []NegativeCase
+		for _, field := range required {
+			property := resolveSchema(spec, properties[field])
+			operationCases = append(operationCases,
+				newCase(field, NegativeMissing, func(body map[string]interface{}) { delete(body, field) }),
+				newCase(field, NegativeWrongType, func(body map[string]interface{}) { body[field] = wrongTypeValue(property) }),
+			)
+		}
+		for _, field := range fields {
+			property := resolveSchema(spec, properties[field])
+			if _, ok := property["enum"]; ok {
+				operationCases = append(operationCases,
+					newCase(field, NegativeOutOfEnum, func(body map[string]interface{}) { body[field] = "not-a-valid-value" }))
+			}
+			if property["format"] == "email" {
+				operationCases = append(operationCases,
+					newCase(field, NegativeInvalidFormat, func(body map[string]interface{}) { body[field] = "not-an-email" }))
+			}
+		}
+
+		if len(operationCases) > 0 && bases[operation.OperationID] == nil {
+			return nil, fmt.Errorf("no valid request body for operation %s", operation.OperationID)
+		}
+		cases = append(cases, operationCases...)
+	}
+
+	return cases, nil
+}
+
+// requestBodySchema возвращает схему JSON тела операции (nil, если тела нет)
+func requestBodySchema(spec, operation map[string]interface{}) map[string]interface{} {
+	body, _ := operation["requestBody"].(map[string]interface{})
+	content, _ := body["content"].(map[string]interface{})
+	media, _ := content["application/json"].(map[string]interface{})
+	if media == nil {
+		return nil
+	}
+	return resolveSchema(spec, media["schema"])
+}
+
+// resolveSchema разрешает ссылку вида #/components/schemas/Name
+func resolveSchema(spec map[string]interface{}, schema interface{}) map[string]interface{} {
+	resolved, _ := schema.(map[string]interface{})
+	ref, ok := resolved["$ref"].(string)
+	if !ok {
+		return resolved
+	}
+
+	components, _ := spec["components"].(map[string]interface{})
+	schemas, _ := components["schemas"].(map[string]interface{})
+	return resolveSchema(spec, schemas[strings.TrimPrefix(ref, "#/components/schemas/")])
+}
+
+// asList приводит значение YAML к списку
+func asList(value interface{}) []interface{} {
+	list, _ := value.([]interface{})
+	return list
+}
+
+// wrongTypeValue возвращает значение JSON типа, отличного от типа схемы
+func wrongTypeValue(schema map[string]interface{}) interface{} {
+	switch schema["type"] {
+	case "string":
+		return 12345
+	case "number", "integer":
+		return "not-a-number"
+	case "boolean":
+		return "not-a-boolean"
+	case "array":
+		return "not-an-array"
+	default:
+		return "not-an-object"
+	}
+}
+
+// AssertFieldSpecificError проверяет, что ответ - ошибка 4xx, в тексте которой указано поле.
+// Имена сравниваются без учета регистра и подчеркиваний: ошибки валидатора называют поле FirstName, а JSON - first_name.
+func AssertFieldSpecificError(t *testing.T, response *APIResponse, field string) bool {
+	t.Helper()
+
+	if !assert.True(t, response.StatusCode >= 400 && response.StatusCode < 500,
+		"Ожидался код 4xx, получен %d: %s", response.StatusCode, string(response.Body)) {
+		return false
+	}
+
+	var body struct {
+		Error   string `json:"error"`
+		Details string `json:"details"`
+	}
+	require.NoError(t, json.Unmarshal(response.Body, &body), string(response.Body))
+
+	normalize := func(s string) string {
+		return strings.ReplaceAll(strings.ToLower(s), "_", "")
+	}
+	return assert.Contains(t, normalize(body.Error+" "+body.Details), normalize(field),
+		"Ошибка не указывает на поле %q: %s", field, string(response.Body))
+}
@@ -0,0 +1,162 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"crypto/rand"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"os"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// OTLPEndpointEnv стандартная переменная OpenTelemetry с адресом OTLP/HTTP коллектора (например, http://localhost:4318).
+// Если задана, тесты, их шаги и запросы к API экспортируются как span'ы и видны в Jaeger/Grafana рядом с трассами сервиса.
+const OTLPEndpointEnv = "OTEL_EXPORTER_OTLP_ENDPOINT"
+
+// OTLPServiceNameEnv стандартная переменная OpenTelemetry с именем сервиса, от которого отправляются span'ы
+const OTLPServiceNameEnv = "OTEL_SERVICE_NAME"
+
+// defaultOTLPServiceName имя, под которым прогон тестов виден в системе трассировки
+const defaultOTLPServiceName = "driver-service-tests"
+
+// Виды span'ов OTLP
+const (
+	otlpSpanKindInternal = 1
+	otlpSpanKindClient   = 3
+)
+
+// Коды статуса span'ов OTLP
+const (
+	otlpStatusUnset = 0
+	otlpStatusOK    = 1
+	otlpStatusError = 2
+)
+
+// otlpSpan span в JSON кодировке OTLP/HTTP
+type otlpSpan struct {
+	TraceID           string          `json:"traceId"`
+	SpanID            string          `json:"spanId"`
+	ParentSpanID      string          `json:"parentSpanId,omitempty"`
+	Name              string          `json:"name"`
+	Kind              int             `json:"kind"`
+	StartTimeUnixNano string          `json:"startTimeUnixNano"`
+	EndTimeUnixNano   string          `json:"endTimeUnixNano"`
+	Attributes        []otlpAttribute `json:"attributes,omitempty"`
+	Status            otlpStatus      `json:"status"`
+}
+
+// otlpAttribute атрибут span'а или ресурса
+type otlpAttribute struct {
+	Key   string    `json:"key"`
+	Value otlpValue `json:"value"`
+}
+
+// otlpValue значение атрибута: строка или целое (в JSON OTLP целые передаются строкой)
+type otlpValue struct {
+	StringValue string `json:"stringValue,omitempty"`
+	IntValue    string `json:"intValue,omitempty"`
+}
+
+// otlpStatus статус span'а
+type otlpStatus struct {
+	Code    int    `json:"code"`
+	Message string `json:"message,omitempty"`
+}
+
+// stringAttribute создает строковый атрибут
+func stringAttribute(key, value string) otlpAttribute {
+	return otlpAttribute{Key: key, Value: otlpValue{StringValue: value}}
+}
+
+// intAttribute создает целочисленный атрибут
+func intAttribute(key string, value int) otlpAttribute {
+	return otlpAttribute{Key: key, Value: otlpValue{IntValue: strconv.Itoa(value)}}
+}
+
+// otlpTimestamp форматирует время в наносекундах Unix
+func otlpTimestamp(t time.Time) string {
+	return strconv.FormatInt(t.UnixNano(), 10)
+}
+
+// OTLPEnabled проверяет, включен ли экспорт span'ов прогона
+func OTLPEnabled() bool {
+	return os.Getenv(OTLPEndpointEnv) != ""
+}
+
+// newTraceID возвращает случайный trace id (16 байт в hex)
+func newTraceID() string {
+	return randomHex(16)
+}
+
+// newOTLPSpanID возвращает случайный span id (8 байт в hex)
+func newOTLPSpanID() string {
+	return randomHex(8)
+}
+
+// randomHex возвращает n случайных байт в hex
+func randomHex(n int) string {
+	buf := make([]byte, n)
+	_, _ = rand.Read(buf)
+	return hex.EncodeToString(buf)
+}
+
+// traceparent формирует заголовок W3C Trace Context: по нему трассы сервиса связываются со span'ом запроса
+func traceparent(traceID, spanID string) string {
+	return fmt.Sprintf("00-%s-%s-01", traceID, spanID)
+}
+
+// otlpClient клиент для отправки span'ов; коллектор не должен задерживать тесты
+var otlpClient = &http.Client{Timeout: 5 * time.Second}
+
+// exportOTLPSpans отправляет span'ы в коллектор из OTEL_EXPORTER_OTLP_ENDPOINT
+func exportOTLPSpans(spans []otlpSpan) error {
+	if len(spans) == 0 || !OTLPEnabled() {
+		return nil
+	}
+
+	serviceName := os.Getenv(OTLPServiceNameEnv)
+	if serviceName == "" {
+		serviceName = defaultOTLPServiceName
+	}
+
+	// Метаданные прогона становятся атрибутами ресурса: test.run_id, test.git_sha и т.д.
+	attributes := []otlpAttribute{stringAttribute("service.name", serviceName)}
+	for _, field := range CurrentRunMetadata().Fields() {
+		attributes = append(attributes, stringAttribute("test."+field[0], field[1]))
+	}
+
+	payload := map[string]interface{}{
+		"resourceSpans": []map[string]interface{}{{
+			"resource": map[string]interface{}{
+				"attributes": attributes,
+			},
+			"scopeSpans": []map[string]interface{}{{
+				"scope": map[string]string{"name": "driver-service/tests/helpers"},
+				"spans": spans,
+			}},
+		}},
+	}
+
+	body, err := json.Marshal(payload)
+	if err != nil {
+		return fmt.Errorf("failed to encode spans: %w", err)
+	}
+
+	endpoint := strings.TrimRight(os.Getenv(OTLPEndpointEnv), "/") + "/v1/traces"
+	resp, err := otlpClient.Post(endpoint, "application/json", bytes.NewReader(body))
+	if err != nil {
+		return fmt.Errorf("failed to export spans to %s: %w", endpoint, err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode >= http.StatusMultipleChoices {
+		return fmt.Errorf("failed to export spans to %s: status %d", endpoint, resp.StatusCode)
+	}
+	return nil
+}
@@ -5,7 +5,11 @@ package helpers
 import (
 	"context"
 	"fmt"
+	"math"
+	"net/http"
 	"runtime"
+	"sort"
+	"strings"
 	"sync"
 	"testing"
 	"time"
@@ -44,6 +48,65 @@ type BenchmarkResult struct {
 	Errors         int
 }
 
+// RequestTimings накапливает длительности HTTP запросов по операциям вида "GET /api/v1/drivers/{id}".
+// Подключается к APITestHelper через WithResponseInterceptor(timings.Record).
+type RequestTimings struct {
+	mu      sync.Mutex
+	samples map[string][]time.Duration
+}
+
+// NewRequestTimings создает пустое хранилище длительностей
+func NewRequestTimings() *RequestTimings {
+	return &RequestTimings{samples: make(map[string][]time.Duration)}
+}
+
+// Record сохраняет длительность запроса; сигнатура совпадает с ResponseInterceptor
+func (r *RequestTimings) Record(req *http.Request, _ *APIResponse, duration time.Duration) {
+	operation := req.Method + " " + templatePath(req.URL.Path)
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.samples[operation] = append(r.samples[operation], duration)
+}
+
+// Samples возвращает длительности запросов операции в порядке выполнения
+func (r *RequestTimings) Samples(operation string) []time.Duration {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	return append([]time.Duration(nil), r.samples[operation]...)
+}
+
+// Percentile возвращает перцентиль p (0-100) длительностей операции или 0, если запросов не было
+func (r *RequestTimings) Percentile(operation string, p float64) time.Duration {
+	samples := r.Samples(operation)
+	sort.Slice(samples, func(i, j int) bool { return samples[i] < samples[j] })
+	return percentile(samples, p)
+}
+
+// percentile возвращает перцентиль p (0-100) отсортированных длительностей или 0 для пустого списка
+func percentile(sorted []time.Duration, p float64) time.Duration {
+	if len(sorted) == 0 {
+		return 0
+	}
+
+	index := int(math.Ceil(p/100*float64(len(sorted)))) - 1
+	if index < 0 {
+		index = 0
+	}
+	return sorted[index]
+}
+
+// templatePath заменяет UUID в пути на {id}, чтобы запросы к разным водителям попадали в одну операцию
+func templatePath(path string) string {
+	segments := strings.Split(path, "/")
+	for i, segment := range segments {
+		if _, err := uuid.Parse(segment); err == nil {
+			segments[i] = "{id}"
+		}
+	}
+	return strings.Join(segments, "/")
+}
+
 // BenchmarkDriverCreation тестирует производительность создания водителей
 func (h *PerformanceTestHelper) BenchmarkDriverCreation(ctx context.Context, count int, concurrency int) *BenchmarkResult {
 	h.t.Logf("Benchmarking driver creation: %d drivers with %d concurrent workers", count, concurrency)
@@ -294,6 +357,7 @@ func (h *PerformanceTestHelper) LoadTest(ctx context.Context, driverID uuid.UUID
 			opCounter := 0
 			for time.Now().Before(endTime) {
 				// Чередуем операции
+				ok := true
 				switch opCounter % 3 {
 				case 0:
 					// Обновление местоположения
@@ -301,6 +365,7 @@ func (h *PerformanceTestHelper) LoadTest(ctx context.Context, driverID uuid.UUID
 					location.Latitude += float64(opCounter) * 0.0001
 					err := h.locationService.UpdateLocation(ctx, location)
 					if err != nil {
+						ok = false
 						mu.Lock()
 						errors++
 						mu.Unlock()
@@ -309,6 +374,7 @@ func (h *PerformanceTestHelper) LoadTest(ctx context.Context, driverID uuid.UUID
 					// Получение текущего местоположения
 					_, err := h.locationService.GetCurrentLocation(ctx, driverID)
 					if err != nil && err != entities.ErrLocationNotFound {
+						ok = false
 						mu.Lock()
 						errors++
 						mu.Unlock()
@@ -317,6 +383,7 @@ func (h *PerformanceTestHelper) LoadTest(ctx context.Context, driverID uuid.UUID
 					// Получение водителя
 					_, err := h.driverService.GetDriverByID(ctx, driverID)
 					if err != nil {
+						ok = false
 						mu.Lock()
 						errors++
 						mu.Unlock()
@@ -327,6 +394,7 @@ func (h *PerformanceTestHelper) LoadTest(ctx context.Context, driverID uuid.UUID
 				totalOps++
 				mu.Unlock()
 				opCounter++
+				recordLoadOperation("load_test", ok)
 
 				// Небольшая пауза для имитации реальной нагрузки
 				time.Sleep(10 * time.Millisecond)
@@ -353,6 +421,63 @@ func (h *PerformanceTestHelper) LoadTest(ctx context.Context, driverID uuid.UUID
 	return result
 }
 
+// BenchmarkAPIRequest выполняет запрос count раз последовательно и добавляет результат в отчет о производительности.
+// Ответ с кодом, отличным от expectedStatus, считается ошибкой.
+func (h *PerformanceTestHelper) BenchmarkAPIRequest(operation string, count int, expectedStatus int, request func() *APIResponse) *BenchmarkResult {
+	start := time.Now()
+	errors := 0
+
+	for i := 0; i < count; i++ {
+		response := request()
+		if response.StatusCode != expectedStatus {
+			errors++
+			h.t.Logf("%s: unexpected status %d: %.200s", operation, response.StatusCode, string(response.Body))
+		}
+	}
+
+	totalTime := time.Since(start)
+	result := &BenchmarkResult{
+		Operation:      operation,
+		TotalTime:      totalTime,
+		OperationCount: count,
+		AvgTime:        totalTime / time.Duration(count),
+		OpsPerSecond:   float64(count) / totalTime.Seconds(),
+		Errors:         errors,
+	}
+
+	h.logBenchmarkResult(result)
+	return result
+}
+
+// BenchmarkRedis выполняет бенчмарк Redis и добавляет результат с перцентилями задержки в отчет о производительности
+func (h *PerformanceTestHelper) BenchmarkRedis(redis *RedisHelper, options RedisBenchmarkOptions) *RedisBenchmarkResult {
+	h.t.Logf("Benchmarking Redis %s: %d commands with %d connections, pipeline %d",
+		options.Operation, options.Requests, options.Concurrency, options.Pipeline)
+
+	result := redis.Benchmark(options)
+
+	h.logBenchmarkResult(&result.BenchmarkResult)
+	h.t.Logf("Latency p50: %v, p95: %v, p99: %v, max: %v", result.P50, result.P95, result.P99, result.Max)
+	return result
+}
+
+// BenchmarkNATS выполняет бенчмарк публикации в NATS и добавляет результат с перцентилями задержки
+// подтверждения в отчет о производительности рядом с бенчмарками API
+func (h *PerformanceTestHelper) BenchmarkNATS(nats *NATSHelper, options NATSBenchmarkOptions) *NATSBenchmarkResult {
+	mode := "core NATS"
+	if options.JetStream {
+		mode = "JetStream"
+	}
+	h.t.Logf("Benchmarking %s publish %s: %d messages with %d connections, batch %d",
+		mode, options.Operation, options.Messages, options.Concurrency, options.Batch)
+
+	result := nats.Benchmark(options)
+
+	h.logBenchmarkResult(&result.BenchmarkResult)
+	h.t.Logf("Ack latency p50: %v, p95: %v, p99: %v, max: %v", result.P50, result.P95, result.P99, result.Max)
+	return result
+}
+
 // MemoryUsageTest тестирует использование памяти
 func (h *PerformanceTestHelper) MemoryUsageTest(ctx context.Context, operationCount int) {
 	h.t.Logf("Testing memory usage with %d operations", operationCount)
@@ -0,0 +1,109 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"math"
+	"strings"
+)
+
+// polylinePrecision точность формата Google Encoded Polyline (5 знаков после запятой)
+const polylinePrecision = 1e5
+
+// LatLng точка в формате широта/долгота
+type LatLng struct {
+	Latitude  float64
+	Longitude float64
+}
+
+// EncodePolyline кодирует последовательность точек в формат Google Encoded Polyline
+func EncodePolyline(points []LatLng) string {
+	var builder strings.Builder
+	var prevLat, prevLon int64
+
+	for _, point := range points {
+		lat := int64(math.Round(point.Latitude * polylinePrecision))
+		lon := int64(math.Round(point.Longitude * polylinePrecision))
+
+		encodePolylineValue(&builder, lat-prevLat)
+		encodePolylineValue(&builder, lon-prevLon)
+
+		prevLat, prevLon = lat, lon
+	}
+
+	return builder.String()
+}
+
+// DecodePolyline декодирует строку Google Encoded Polyline в последовательность точек
+func DecodePolyline(encoded string) ([]LatLng, error) {
+	var points []LatLng
+	var lat, lon int64
+
+	for index := 0; index < len(encoded); {
+		dLat, next, err := decodePolylineValue(encoded, index)
+		if err != nil {
+			return nil, err
+		}
+
+		dLon, next, err := decodePolylineValue(encoded, next)
+		if err != nil {
+			return nil, err
+		}
+
+		lat += dLat
+		lon += dLon
+		index = next
+
+		points = append(points, LatLng{
+			Latitude:  float64(lat) / polylinePrecision,
+			Longitude: float64(lon) / polylinePrecision,
+		})
+	}
+
+	return points, nil
+}
+
+// encodePolylineValue кодирует одно знаковое значение (дельту координаты)
+func encodePolylineValue(builder *strings.Builder, value int64) {
+	shifted := value << 1
+	if value < 0 {
+		shifted = ^shifted
+	}
+
+	for shifted >= 0x20 {
+		builder.WriteByte(byte((0x20 | (shifted & 0x1f)) + 63))
+		shifted >>= 5
+	}
+	builder.WriteByte(byte(shifted + 63))
+}
+
+// decodePolylineValue декодирует одно значение, начиная с позиции index, и возвращает позицию следующего
+func decodePolylineValue(encoded string, index int) (int64, int, error) {
+	var result int64
+	var shift uint
+
+	for {
+		if index >= len(encoded) {
+			return 0, index, fmt.Errorf("unexpected end of polyline at position %d", index)
+		}
+
+		b := int64(encoded[index]) - 63
+		index++
+		if b < 0 || b > 0x3f {
+			return 0, index, fmt.Errorf("invalid polyline character %q at position %d", encoded[index-1], index-1)
+		}
+
+		result |= (b & 0x1f) << shift
+		shift += 5
+
+		if b < 0x20 {
+			break
+		}
+	}
+
+	if result&1 != 0 {
+		return ^(result >> 1), index, nil
+	}
+	return result >> 1, index, nil
+}
@@ -0,0 +1,228 @@
+//go:build integration
+
+package helpers
+
+import (
+	"encoding/json"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/infrastructure/push"
+)
+
+// Учетные данные шлюзов push-уведомлений в тестовом окружении
+const (
+	TestFCMProjectID   = "driver-service-test"
+	TestFCMAccessToken = "test-fcm-access-token"
+	TestAPNSTopic      = "ru.taxi.driver.test"
+	TestAPNSAuthToken  = "test-apns-jwt"
+)
+
+// TestPushMaxAttempts количество попыток отправки push-уведомления в тестовом окружении
+const TestPushMaxAttempts = 3
+
+// PushRequest запрос к шлюзу push-уведомлений, полученный PushGatewayMock
+type PushRequest struct {
+	Platform   entities.PushPlatform
+	Token      string
+	Title      string
+	Body       string
+	Data       map[string]string
+	Header     http.Header
+	StatusCode int // код, которым ответил шлюз
+	ReceivedAt time.Time
+}
+
+// PushGatewayMock встроенный HTTP сервер, имитирующий FCM HTTP v1 API и APNS.
+// Проверяет авторизацию (401 при несовпадении), записывает уведомления и может отвечать ошибками по программе.
+type PushGatewayMock struct {
+	server *httptest.Server
+
+	mu            sync.Mutex
+	requests      []PushRequest
+	failures      int // сколько следующих запросов завершить ошибкой; -1 - все
+	failureCode   int
+	invalidTokens map[string]bool
+}
+
+// StartPushGateway запускает имитацию шлюзов FCM и APNS и собирает поверх нее NotificationService окружения.
+// Шлюз останавливается по окончании теста.
+func (env *TestEnvironment) StartPushGateway(t *testing.T) *PushGatewayMock {
+	mock := &PushGatewayMock{invalidTokens: make(map[string]bool)}
+	mock.server = httptest.NewServer(http.HandlerFunc(mock.handle))
+	t.Cleanup(mock.server.Close)
+
+	gateway := push.NewGateway(config.PushConfig{
+		FCM: config.FCMConfig{
+			BaseURL:     mock.server.URL,
+			ProjectID:   TestFCMProjectID,
+			AccessToken: TestFCMAccessToken,
+		},
+		APNS: config.APNSConfig{
+			BaseURL:   mock.server.URL,
+			Topic:     TestAPNSTopic,
+			AuthToken: TestAPNSAuthToken,
+		},
+		MaxAttempts:    TestPushMaxAttempts,
+		InitialBackoff: 20 * time.Millisecond,
+		Timeout:        2 * time.Second,
+	}, env.Logger)
+
+	env.Notifications = services.NewNotificationService(env.DriverRepo, env.DocumentRepo, gateway, env.Logger)
+	return mock
+}
+
+// FailNext отвечает кодом statusCode на следующие count запросов
+func (m *PushGatewayMock) FailNext(count, statusCode int) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.failures = count
+	m.failureCode = statusCode
+}
+
+// FailAlways отвечает кодом statusCode на все запросы
+func (m *PushGatewayMock) FailAlways(statusCode int) {
+	m.FailNext(-1, statusCode)
+}
+
+// InvalidateToken помечает токен устройства недействительным (приложение удалено):
+// FCM отвечает на него 404, APNS - 410
+func (m *PushGatewayMock) InvalidateToken(token string) {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+	m.invalidTokens[token] = true
+}
+
+// Requests возвращает копию всех полученных запросов, включая отклоненные
+func (m *PushGatewayMock) Requests() []PushRequest {
+	m.mu.Lock()
+	defer m.mu.Unlock()
+
+	requests := make([]PushRequest, len(m.requests))
+	copy(requests, m.requests)
+	return requests
+}
+
+// Delivered возвращает уведомления, принятые шлюзом
+func (m *PushGatewayMock) Delivered() []PushRequest {
+	var result []PushRequest
+	for _, request := range m.Requests() {
+		if request.StatusCode == http.StatusOK {
+			result = append(result, request)
+		}
+	}
+	return result
+}
+
+// RequestsFor возвращает все запросы на устройство с токеном token
+func (m *PushGatewayMock) RequestsFor(token string) []PushRequest {
+	var result []PushRequest
+	for _, request := range m.Requests() {
+		if request.Token == token {
+			result = append(result, request)
+		}
+	}
+	return result
+}
+
+// handle принимает запрос FCM или APNS
+func (m *PushGatewayMock) handle(w http.ResponseWriter, req *http.Request) {
+	body, _ := io.ReadAll(req.Body)
+
+	request := PushRequest{Header: req.Header.Clone(), ReceivedAt: time.Now()}
+	authorized := false
+
+	switch {
+	case req.Method == http.MethodPost && req.URL.Path == "/v1/projects/"+TestFCMProjectID+"/messages:send":
+		request.Platform = entities.PushPlatformFCM
+		authorized = req.Header.Get("Authorization") == "Bearer "+TestFCMAccessToken
+		parseFCMPayload(body, &request)
+
+	case req.Method == http.MethodPost && strings.HasPrefix(req.URL.Path, "/3/device/"):
+		request.Platform = entities.PushPlatformAPNS
+		request.Token = strings.TrimPrefix(req.URL.Path, "/3/device/")
+		authorized = req.Header.Get("Authorization") == "bearer "+TestAPNSAuthToken &&
+			req.Header.Get("apns-topic") == TestAPNSTopic
+		parseAPNSPayload(body, &request)
+
+	default:
+		http.NotFound(w, req)
+		return
+	}
+
+	m.mu.Lock()
+	switch {
+	case !authorized:
+		request.StatusCode = http.StatusUnauthorized
+	case m.invalidTokens[request.Token] && request.Platform == entities.PushPlatformFCM:
+		request.StatusCode = http.StatusNotFound
+	case m.invalidTokens[request.Token]:
+		request.StatusCode = http.StatusGone
+	case m.failures != 0:
+		request.StatusCode = m.failureCode
+		if m.failures > 0 {
+			m.failures--
+		}
+	default:
+		request.StatusCode = http.StatusOK
+	}
+	m.requests = append(m.requests, request)
+	m.mu.Unlock()
+
+	w.WriteHeader(request.StatusCode)
+}
+
+// parseFCMPayload разбирает тело запроса FCM HTTP v1 API
+func parseFCMPayload(body []byte, request *PushRequest) {
+	var payload struct {
+		Message struct {
+			Token        string `json:"token"`
+			Notification struct {
+				Title string `json:"title"`
+				Body  string `json:"body"`
+			} `json:"notification"`
+			Data map[string]string `json:"data"`
+		} `json:"message"`
+	}
+	_ = json.Unmarshal(body, &payload)
+
+	request.Token = payload.Message.Token
+	request.Title = payload.Message.Notification.Title
+	request.Body = payload.Message.Notification.Body
+	request.Data = payload.Message.Data
+}
+
+// parseAPNSPayload разбирает тело запроса APNS: aps и пользовательские ключи верхнего уровня
+func parseAPNSPayload(body []byte, request *PushRequest) {
+	var payload map[string]json.RawMessage
+	_ = json.Unmarshal(body, &payload)
+
+	var aps struct {
+		Alert struct {
+			Title string `json:"title"`
+			Body  string `json:"body"`
+		} `json:"alert"`
+	}
+	_ = json.Unmarshal(payload["aps"], &aps)
+	request.Title = aps.Alert.Title
+	request.Body = aps.Alert.Body
+
+	request.Data = make(map[string]string)
+	for key, raw := range payload {
+		if key == "aps" {
+			continue
+		}
+		var value string
+		if json.Unmarshal(raw, &value) == nil {
+			request.Data[key] = value
+		}
+	}
+}
@@ -0,0 +1,102 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// PlanNode узел плана запроса из EXPLAIN (FORMAT JSON)
+type PlanNode struct {
+	NodeType     string     `json:"Node Type"`
+	RelationName string     `json:"Relation Name"`
+	IndexName    string     `json:"Index Name"`
+	Plans        []PlanNode `json:"Plans"`
+}
+
+// Nodes возвращает узел и все вложенные узлы плана
+func (n PlanNode) Nodes() []PlanNode {
+	nodes := []PlanNode{n}
+	for _, child := range n.Plans {
+		nodes = append(nodes, child.Nodes()...)
+	}
+	return nodes
+}
+
+// String возвращает план в виде дерева "Node Type on relation using index" для сообщений об ошибках
+func (n PlanNode) String() string {
+	var b strings.Builder
+	n.write(&b, 0)
+	return b.String()
+}
+
+func (n PlanNode) write(b *strings.Builder, depth int) {
+	b.WriteString(strings.Repeat("  ", depth))
+	b.WriteString(n.NodeType)
+	if n.RelationName != "" {
+		b.WriteString(" on " + n.RelationName)
+	}
+	if n.IndexName != "" {
+		b.WriteString(" using " + n.IndexName)
+	}
+	b.WriteString("\n")
+	for _, child := range n.Plans {
+		child.write(b, depth+1)
+	}
+}
+
+// ExplainPlan возвращает план запроса с параметрами args (EXPLAIN (FORMAT JSON), без выполнения запроса)
+func (tdb *TestDB) ExplainPlan(t *testing.T, query string, args ...interface{}) PlanNode {
+	var raw []byte
+	err := tdb.QueryRowxContext(context.Background(), "EXPLAIN (FORMAT JSON) "+query, args...).Scan(&raw)
+	require.NoError(t, err)
+
+	var plans []struct {
+		Plan PlanNode `json:"Plan"`
+	}
+	require.NoError(t, json.Unmarshal(raw, &plans))
+	require.Len(t, plans, 1)
+	return plans[0].Plan
+}
+
+// AssertIndexUsed проверяет, что план использует один из индексов indexes и ни один узел
+// не сканирует таблицу table последовательно. Индекс ищется во всех узлах: при bitmap-сканировании
+// имя индекса указано в узле Bitmap Index Scan, а таблица - в родительском Bitmap Heap Scan.
+func AssertIndexUsed(t *testing.T, plan PlanNode, table string, indexes ...string) bool {
+	t.Helper()
+
+	used := false
+	for _, node := range plan.Nodes() {
+		if node.RelationName == table && node.NodeType == "Seq Scan" {
+			return assert.Fail(t, "Последовательное сканирование "+table, "План:\n%s", plan)
+		}
+		for _, index := range indexes {
+			if node.IndexName == index {
+				used = true
+			}
+		}
+	}
+
+	return assert.True(t, used, "Запрос к %s не использует %v. План:\n%s", table, indexes, plan)
+}
+
+// InvalidIndexes возвращает индексы схемы public, которые планировщик не может использовать:
+// например, оставшиеся после прерванного CREATE INDEX CONCURRENTLY
+func (tdb *TestDB) InvalidIndexes(t *testing.T) []string {
+	var indexes []string
+	err := tdb.SelectContext(context.Background(), &indexes, `
+		SELECT c.relname
+		FROM pg_index i
+		JOIN pg_class c ON c.oid = i.indexrelid
+		JOIN pg_namespace n ON n.oid = c.relnamespace
+		WHERE n.nspname = 'public' AND (NOT i.indisvalid OR NOT i.indisready)
+		ORDER BY c.relname`)
+	require.NoError(t, err)
+	return indexes
+}
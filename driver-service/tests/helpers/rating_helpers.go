@@ -0,0 +1,99 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"database/sql"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strconv"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// InsertRating сохраняет оценку напрямую в тестовую БД (репозитория оценок в сервисе пока нет).
+// Статистика пересчитывается триггером trigger_driver_rating_stats_update.
+func (tdb *TestDB) InsertRating(ctx context.Context, rating *entities.DriverRating) error {
+	criteriaScores, err := json.Marshal(rating.CriteriaScores)
+	if err != nil {
+		return fmt.Errorf("failed to marshal criteria scores: %w", err)
+	}
+
+	query := `
+		INSERT INTO driver_ratings (
+			id, driver_id, order_id, customer_id, rating, comment, rating_type,
+			criteria_scores, is_verified, is_anonymous, metadata, created_at, updated_at
+		) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)`
+
+	_, err = tdb.ExecContext(ctx, query,
+		rating.ID, rating.DriverID, rating.OrderID, rating.CustomerID, rating.Rating, rating.Comment,
+		rating.RatingType, criteriaScores, rating.IsVerified, rating.IsAnonymous, rating.Metadata,
+		rating.CreatedAt, rating.UpdatedAt)
+	return err
+}
+
+// RatingStats строка driver_rating_stats
+type RatingStats struct {
+	AverageRating      float64 `db:"average_rating"`
+	TotalRatings       int     `db:"total_ratings"`
+	RatingDistribution []byte  `db:"rating_distribution"`
+}
+
+// Distribution возвращает распределение оценок вида {"5": 10, "4": 3}
+func (s *RatingStats) Distribution(t *testing.T) map[string]int {
+	distribution := make(map[string]int)
+	require.NoError(t, json.Unmarshal(s.RatingDistribution, &distribution))
+	return distribution
+}
+
+// GetRatingStats читает статистику оценок водителя (nil, если строки нет)
+func (tdb *TestDB) GetRatingStats(t *testing.T, driverID uuid.UUID) *RatingStats {
+	var stats RatingStats
+	err := tdb.GetContext(context.Background(), &stats, `
+		SELECT average_rating, total_ratings, rating_distribution
+		FROM driver_rating_stats WHERE driver_id = $1`, driverID)
+	if errors.Is(err, sql.ErrNoRows) {
+		return nil
+	}
+	require.NoError(t, err)
+	return &stats
+}
+
+// AssertRatingStatsConsistent проверяет, что статистика и current_rating водителя совпадают с исходными оценками
+func AssertRatingStatsConsistent(t *testing.T, tdb *TestDB, driverID uuid.UUID) {
+	t.Helper()
+
+	var raw []int
+	err := tdb.SelectContext(context.Background(), &raw, "SELECT rating FROM driver_ratings WHERE driver_id = $1", driverID)
+	require.NoError(t, err)
+
+	stats := tdb.GetRatingStats(t, driverID)
+	require.NotNil(t, stats, "Нет строки driver_rating_stats для водителя %s", driverID)
+
+	expectedDistribution := make(map[string]int)
+	expectedAverage := 0.0
+	for _, rating := range raw {
+		expectedDistribution[strconv.Itoa(rating)]++
+		expectedAverage += float64(rating)
+	}
+	if len(raw) > 0 {
+		expectedAverage /= float64(len(raw))
+	}
+
+	// average_rating и current_rating хранятся как DECIMAL(3,2)
+	assert.Equal(t, len(raw), stats.TotalRatings, "total_ratings")
+	assert.InDelta(t, expectedAverage, stats.AverageRating, 0.005, "average_rating")
+	assert.Equal(t, expectedDistribution, stats.Distribution(t), "rating_distribution")
+
+	var currentRating float64
+	err = tdb.GetContext(context.Background(), &currentRating, "SELECT current_rating FROM drivers WHERE id = $1", driverID)
+	require.NoError(t, err)
+	assert.InDelta(t, stats.AverageRating, currentRating, 0.001, "current_rating")
+}
@@ -0,0 +1,371 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bufio"
+	"fmt"
+	"io"
+	"net"
+	"os"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения тестового Redis (test-redis из docker-compose.test.yml)
+const (
+	TestRedisHostEnv = "TEST_REDIS_HOST"
+	TestRedisPortEnv = "TEST_REDIS_PORT"
+)
+
+// Переменные окружения порогов бенчмарка Redis: длительности (например, "5ms") и операций в секунду
+const (
+	RedisSLOP95Env    = "REDIS_SLO_P95"
+	RedisSLOP99Env    = "REDIS_SLO_P99"
+	RedisSLOMinOpsEnv = "REDIS_SLO_MIN_OPS"
+)
+
+// RedisHelper помощник для бенчмарков тестового Redis. Клиент сервиса Redis не использует, поэтому
+// помощник работает по протоколу RESP напрямую и не добавляет зависимостей.
+type RedisHelper struct {
+	t    *testing.T
+	addr string
+}
+
+// NewRedisHelper создает помощник и проверяет доступность Redis командой PING.
+// Если Redis недоступен, тест пропускается - Redis нужен только бенчмаркам.
+func NewRedisHelper(t *testing.T) *RedisHelper {
+	h := &RedisHelper{
+		t:    t,
+		addr: net.JoinHostPort(getEnvOrDefault(TestRedisHostEnv, "localhost"), getEnvOrDefault(TestRedisPortEnv, "6380")),
+	}
+
+	conn, err := h.dial()
+	if err != nil {
+		t.Skipf("Redis недоступен (%s): %v", h.addr, err)
+	}
+	conn.Close()
+
+	return h
+}
+
+// Do выполняет одну команду в отдельном соединении
+func (h *RedisHelper) Do(args ...string) (interface{}, error) {
+	conn, err := h.dial()
+	if err != nil {
+		return nil, err
+	}
+	defer conn.Close()
+
+	return conn.do(args...)
+}
+
+// DeleteKeys удаляет ключи, подходящие под шаблон SCAN MATCH (например, "benchmark:*")
+func (h *RedisHelper) DeleteKeys(pattern string) {
+	conn, err := h.dial()
+	require.NoError(h.t, err)
+	defer conn.Close()
+
+	cursor := "0"
+	for {
+		reply, err := conn.do("SCAN", cursor, "MATCH", pattern, "COUNT", "1000")
+		require.NoError(h.t, err)
+
+		page := reply.([]interface{})
+		cursor = page[0].(string)
+		if keys := page[1].([]interface{}); len(keys) > 0 {
+			args := []string{"DEL"}
+			for _, key := range keys {
+				args = append(args, key.(string))
+			}
+			_, err = conn.do(args...)
+			require.NoError(h.t, err)
+		}
+
+		if cursor == "0" {
+			return
+		}
+	}
+}
+
+// RedisBenchmarkOptions параметры бенчмарка Redis
+type RedisBenchmarkOptions struct {
+	Operation   string
+	Requests    int                  // всего команд
+	Concurrency int                  // параллельных соединений, по умолчанию 1
+	Pipeline    int                  // команд в одном пакете, по умолчанию 1 (без конвейера)
+	Command     func(i int) []string // i-я команда, например SET benchmark:{i} value
+}
+
+// RedisBenchmarkResult результат бенчмарка Redis с перцентилями задержки.
+// Задержка команды в пакете равна времени ответа на весь пакет, как в redis-benchmark.
+type RedisBenchmarkResult struct {
+	BenchmarkResult
+	Concurrency int
+	Pipeline    int
+	P50         time.Duration
+	P95         time.Duration
+	P99         time.Duration
+	Max         time.Duration
+}
+
+// Benchmark выполняет команды в Concurrency соединениях пакетами по Pipeline команд.
+// Ответ с ошибкой Redis и команды пакета, на который не пришел ответ, считаются ошибками.
+func (h *RedisHelper) Benchmark(options RedisBenchmarkOptions) *RedisBenchmarkResult {
+	if options.Concurrency < 1 {
+		options.Concurrency = 1
+	}
+	if options.Pipeline < 1 {
+		options.Pipeline = 1
+	}
+
+	var (
+		mu        sync.Mutex
+		wg        sync.WaitGroup
+		latencies = make([]time.Duration, 0, options.Requests)
+		errors    int
+	)
+	batches := make(chan int)
+
+	start := time.Now()
+	for w := 0; w < options.Concurrency; w++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+
+			conn, err := h.dial()
+			if err != nil {
+				h.t.Errorf("%s: failed to connect to Redis: %v", options.Operation, err)
+			} else {
+				defer conn.Close()
+			}
+
+			for first := range batches {
+				commands := make([][]string, min(options.Pipeline, options.Requests-first))
+				for i := range commands {
+					commands[i] = options.Command(first + i)
+				}
+
+				failed := len(commands)
+				sent := time.Now()
+				if conn != nil {
+					failed = conn.pipeline(commands)
+				}
+				elapsed := time.Since(sent)
+
+				mu.Lock()
+				for range commands {
+					latencies = append(latencies, elapsed)
+				}
+				errors += failed
+				mu.Unlock()
+			}
+		}()
+	}
+
+	for first := 0; first < options.Requests; first += options.Pipeline {
+		batches <- first
+	}
+	close(batches)
+	wg.Wait()
+	totalTime := time.Since(start)
+
+	sort.Slice(latencies, func(i, j int) bool { return latencies[i] < latencies[j] })
+	return &RedisBenchmarkResult{
+		BenchmarkResult: BenchmarkResult{
+			Operation:      options.Operation,
+			TotalTime:      totalTime,
+			OperationCount: options.Requests,
+			AvgTime:        totalTime / time.Duration(options.Requests),
+			OpsPerSecond:   float64(options.Requests) / totalTime.Seconds(),
+			Errors:         errors,
+		},
+		Concurrency: options.Concurrency,
+		Pipeline:    options.Pipeline,
+		P50:         percentile(latencies, 50),
+		P95:         percentile(latencies, 95),
+		P99:         percentile(latencies, 99),
+		Max:         percentile(latencies, 100),
+	}
+}
+
+// RedisSLO пороги бенчмарка Redis
+type RedisSLO struct {
+	P95             time.Duration
+	P99             time.Duration
+	MinOpsPerSecond float64
+}
+
+// DefaultRedisSLO возвращает пороги по умолчанию для Redis в docker-compose.test.yml
+func DefaultRedisSLO() RedisSLO {
+	return RedisSLO{
+		P95:             10 * time.Millisecond,
+		P99:             25 * time.Millisecond,
+		MinOpsPerSecond: 1000,
+	}
+}
+
+// LoadRedisSLO возвращает пороги по умолчанию с переопределениями из REDIS_SLO_P95, REDIS_SLO_P99 и
+// REDIS_SLO_MIN_OPS. Пороги задержки умножаются на LATENCY_BUDGET_MULTIPLIER (для медленных окружений).
+func LoadRedisSLO(t *testing.T) RedisSLO {
+	slo := DefaultRedisSLO()
+
+	for env, target := range map[string]*time.Duration{RedisSLOP95Env: &slo.P95, RedisSLOP99Env: &slo.P99} {
+		if value := os.Getenv(env); value != "" {
+			duration, err := time.ParseDuration(value)
+			require.NoError(t, err, "Некорректный %s: %q", env, value)
+			*target = duration
+		}
+	}
+	if value := os.Getenv(RedisSLOMinOpsEnv); value != "" {
+		minOps, err := strconv.ParseFloat(value, 64)
+		require.NoError(t, err, "Некорректный %s: %q", RedisSLOMinOpsEnv, value)
+		slo.MinOpsPerSecond = minOps
+	}
+
+	if multiplier, err := strconv.ParseFloat(os.Getenv(LatencyBudgetMultiplierEnv), 64); err == nil && multiplier > 0 {
+		slo.P95 = time.Duration(float64(slo.P95) * multiplier)
+		slo.P99 = time.Duration(float64(slo.P99) * multiplier)
+	}
+
+	return slo
+}
+
+// AssertRedisSLO проверяет перцентили и пропускную способность бенчмарка; команды не должны завершаться ошибкой
+func AssertRedisSLO(t *testing.T, result *RedisBenchmarkResult, slo RedisSLO) {
+	t.Helper()
+
+	AssertWithinSLO(t, result.Operation+" p95", result.P95, slo.P95)
+	AssertWithinSLO(t, result.Operation+" p99", result.P99, slo.P99)
+	if result.OpsPerSecond < slo.MinOpsPerSecond {
+		t.Errorf("%s: %s throughput %.2f ops/s, limit %.2f ops/s",
+			SLOBreachMarker, result.Operation, result.OpsPerSecond, slo.MinOpsPerSecond)
+	}
+	assert.Zero(t, result.Errors, "%s: команды завершились ошибкой", result.Operation)
+}
+
+// redisError ответ Redis с ошибкой ("-ERR ...")
+type redisError string
+
+func (e redisError) Error() string {
+	return "redis: " + string(e)
+}
+
+// redisConn соединение с Redis по протоколу RESP
+type redisConn struct {
+	net.Conn
+	reader *bufio.Reader
+	writer *bufio.Writer
+}
+
+func (h *RedisHelper) dial() (*redisConn, error) {
+	conn, err := net.DialTimeout("tcp", h.addr, 2*time.Second)
+	if err != nil {
+		return nil, err
+	}
+
+	c := &redisConn{Conn: conn, reader: bufio.NewReader(conn), writer: bufio.NewWriter(conn)}
+	if _, err := c.do("PING"); err != nil {
+		conn.Close()
+		return nil, err
+	}
+	return c, nil
+}
+
+// do выполняет команду и возвращает ответ: string, int64, []interface{} или nil
+func (c *redisConn) do(args ...string) (interface{}, error) {
+	c.write(args)
+	if err := c.writer.Flush(); err != nil {
+		return nil, err
+	}
+
+	reply, err := c.read()
+	if err != nil {
+		return nil, err
+	}
+	if redisErr, ok := reply.(redisError); ok {
+		return nil, redisErr
+	}
+	return reply, nil
+}
+
+// pipeline отправляет команды одним пакетом, читает все ответы и возвращает количество ошибок.
+// При ошибке соединения ошибками считаются все команды, ответ на которые не получен.
+func (c *redisConn) pipeline(commands [][]string) int {
+	for _, args := range commands {
+		c.write(args)
+	}
+	if err := c.writer.Flush(); err != nil {
+		return len(commands)
+	}
+
+	failed := 0
+	for i := range commands {
+		reply, err := c.read()
+		if err != nil {
+			return failed + len(commands) - i
+		}
+		if _, ok := reply.(redisError); ok {
+			failed++
+		}
+	}
+	return failed
+}
+
+func (c *redisConn) write(args []string) {
+	fmt.Fprintf(c.writer, "*%d\r\n", len(args))
+	for _, arg := range args {
+		fmt.Fprintf(c.writer, "$%d\r\n%s\r\n", len(arg), arg)
+	}
+}
+
+func (c *redisConn) read() (interface{}, error) {
+	line, err := c.reader.ReadString('\n')
+	if err != nil {
+		return nil, err
+	}
+	line = strings.TrimSuffix(line, "\r\n")
+	if line == "" {
+		return nil, fmt.Errorf("empty redis reply")
+	}
+
+	switch line[0] {
+	case '+':
+		return line[1:], nil
+	case '-':
+		return redisError(line[1:]), nil
+	case ':':
+		return strconv.ParseInt(line[1:], 10, 64)
+	case '$':
+		size, err := strconv.Atoi(line[1:])
+		if err != nil || size < 0 {
+			return nil, err
+		}
+		data := make([]byte, size+2)
+		if _, err := io.ReadFull(c.reader, data); err != nil {
+			return nil, err
+		}
+		return string(data[:size]), nil
+	case '*':
+		count, err := strconv.Atoi(line[1:])
+		if err != nil || count < 0 {
+			return nil, err
+		}
+		items := make([]interface{}, count)
+		for i := range items {
+			if items[i], err = c.read(); err != nil {
+				return nil, err
+			}
+		}
+		return items, nil
+	default:
+		return nil, fmt.Errorf("unexpected redis reply: %q", line)
+	}
+}
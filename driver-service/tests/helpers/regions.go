@@ -0,0 +1,88 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"testing"
+	"time"
+)
+
+// Переменные окружения многорегионального режима: базовые URL двух регионов стенда
+// и SLA репликации между ними (в формате time.ParseDuration, например "2s")
+const (
+	RegionPrimaryURLEnv   = "REGION_PRIMARY_URL"
+	RegionSecondaryURLEnv = "REGION_SECONDARY_URL"
+	ReplicationSLAEnv     = "REGION_REPLICATION_SLA"
+)
+
+// DefaultReplicationSLA SLA репликации между регионами по умолчанию
+const DefaultReplicationSLA = 2 * time.Second
+
+// Region регион стенда и клиент его API
+type Region struct {
+	Name string
+	API  *APITestHelper
+}
+
+// RegionTargets основной и дополнительный регионы с SLA репликации между ними
+type RegionTargets struct {
+	Primary        Region
+	Secondary      Region
+	ReplicationSLA time.Duration
+	// Remote регионы заданы REGION_PRIMARY_URL и REGION_SECONDARY_URL, а не роутером в памяти
+	Remote bool
+}
+
+// LoadRegionTargets возвращает регионы из REGION_PRIMARY_URL и REGION_SECONDARY_URL.
+// Без них оба региона - клиент, возвращенный local (роутер в памяти с тестовой БД): репликация мгновенная,
+// и тесты проверяют сами сценарии. Задан только один URL - ошибка конфигурации.
+func LoadRegionTargets(t *testing.T, local func() *APITestHelper) RegionTargets {
+	t.Helper()
+
+	targets := RegionTargets{ReplicationSLA: DefaultReplicationSLA}
+	if value := os.Getenv(ReplicationSLAEnv); value != "" {
+		sla, err := time.ParseDuration(value)
+		if err != nil || sla <= 0 {
+			t.Fatalf("Некорректный %s: %q", ReplicationSLAEnv, value)
+		}
+		targets.ReplicationSLA = sla
+	}
+
+	primaryURL, secondaryURL := os.Getenv(RegionPrimaryURLEnv), os.Getenv(RegionSecondaryURLEnv)
+	switch {
+	case primaryURL == "" && secondaryURL == "":
+		api := local()
+		targets.Primary = Region{Name: "primary", API: api}
+		targets.Secondary = Region{Name: "secondary", API: api}
+	case primaryURL == "" || secondaryURL == "":
+		t.Fatalf("%s и %s задаются вместе", RegionPrimaryURLEnv, RegionSecondaryURLEnv)
+	default:
+		t.Logf("Region targets: primary %s, secondary %s, replication SLA %v", primaryURL, secondaryURL, targets.ReplicationSLA)
+		targets.Primary = Region{Name: "primary", API: NewRemoteAPITestHelper(primaryURL, t)}
+		targets.Secondary = Region{Name: "secondary", API: NewRemoteAPITestHelper(secondaryURL, t)}
+		targets.Remote = true
+	}
+
+	return targets
+}
+
+// Directions возвращает пары регионов (запись, чтение) в обоих направлениях
+func (r RegionTargets) Directions() [][2]Region {
+	return [][2]Region{{r.Primary, r.Secondary}, {r.Secondary, r.Primary}}
+}
+
+// AssertReplicated проверяет, что запись what, сделанная в регионе from, стала видна в регионе to
+// в пределах SLA репликации. Возвращает измеренную задержку.
+func (r RegionTargets) AssertReplicated(t *testing.T, what string, from, to Region, visible func(api *APITestHelper) (bool, error)) time.Duration {
+	t.Helper()
+
+	return AssertPropagatedWithin(t, fmt.Sprintf("%s %s->%s", what, from.Name, to.Name), PropagationBudget{
+		Within: r.ReplicationSLA,
+		Visible: func(context.Context) (bool, error) {
+			return visible(to.API)
+		},
+	})
+}
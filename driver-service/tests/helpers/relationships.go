@@ -0,0 +1,219 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"fmt"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// DeleteMode способ удаления родительской сущности
+type DeleteMode string
+
+const (
+	HardDelete DeleteMode = "hard" // строка родителя удаляется из таблицы
+	SoftDelete DeleteMode = "soft" // строка родителя помечается deleted_at
+)
+
+// ChildBehavior документированное поведение дочерних строк при удалении родителя
+type ChildBehavior string
+
+const (
+	ChildCascade ChildBehavior = "cascade" // строки удаляются вместе с родителем
+	ChildRetain  ChildBehavior = "retain"  // строки сохраняются без изменений
+)
+
+// ChildRelation дочерняя таблица, ссылающаяся на родителя внешним ключом Column
+type ChildRelation struct {
+	Table    string
+	Column   string
+	OnDelete map[DeleteMode]ChildBehavior
+	// Seed добавляет дочерние строки родителю; nil - строки появляются как следствие других
+	// (например, статистика оценок пересчитывается триггером при добавлении оценки)
+	Seed func(t *testing.T, env *TestEnvironment, parentID uuid.UUID)
+}
+
+// EntityRelations родительская сущность, способы ее удаления и дочерние таблицы
+type EntityRelations struct {
+	Entity   string
+	Table    string
+	Create   func(t *testing.T, env *TestEnvironment) uuid.UUID
+	Delete   map[DeleteMode]func(t *testing.T, env *TestEnvironment, id uuid.UUID)
+	Children []ChildRelation
+}
+
+// EntityRelationships декларативная карта связей между сущностями сервиса. По ней cascade_integrity_test
+// проверяет, что внешние ключи схемы совпадают с картой и что удаление каждой сущности каждым способом
+// ведет себя так, как здесь описано. Новую таблицу со ссылкой на сущность нужно добавить в карту.
+//
+// Мягкое удаление водителя сохраняет все связанные данные, чтобы водителя можно было восстановить
+// вместе с историей. Полное удаление (только DriverRepository.Delete, API его не вызывает) удаляет их каскадно.
+var EntityRelationships = []EntityRelations{
+	{
+		Entity: "driver",
+		Table:  "drivers",
+		Create: createRelationshipDriver,
+		Delete: map[DeleteMode]func(t *testing.T, env *TestEnvironment, id uuid.UUID){
+			HardDelete: func(t *testing.T, env *TestEnvironment, id uuid.UUID) {
+				require.NoError(t, env.DriverRepo.Delete(env.Ctx, id))
+			},
+			SoftDelete: func(t *testing.T, env *TestEnvironment, id uuid.UUID) {
+				require.NoError(t, env.DriverService.DeleteDriver(env.Ctx, id))
+			},
+		},
+		Children: []ChildRelation{
+			{
+				Table:    "driver_documents",
+				Column:   "driver_id",
+				OnDelete: driverChildBehavior,
+				Seed: func(t *testing.T, env *TestEnvironment, driverID uuid.UUID) {
+					document := fixtures.CreateTestDocument(driverID, entities.DocumentTypeDriverLicense)
+					require.NoError(t, env.DocumentRepo.Create(env.Ctx, document))
+				},
+			},
+			{
+				Table:    "driver_locations",
+				Column:   "driver_id",
+				OnDelete: driverChildBehavior,
+				Seed: func(t *testing.T, env *TestEnvironment, driverID uuid.UUID) {
+					require.NoError(t, env.LocationRepo.Create(env.Ctx, fixtures.CreateTestLocation(driverID)))
+				},
+			},
+			{
+				Table:    "driver_shifts",
+				Column:   "driver_id",
+				OnDelete: driverChildBehavior,
+				Seed: func(t *testing.T, env *TestEnvironment, driverID uuid.UUID) {
+					env.DB.InsertShift(t, fixtures.CreateTestShift(driverID))
+				},
+			},
+			{
+				Table:    "driver_ratings",
+				Column:   "driver_id",
+				OnDelete: driverChildBehavior,
+				Seed: func(t *testing.T, env *TestEnvironment, driverID uuid.UUID) {
+					require.NoError(t, env.DB.InsertRating(env.Ctx, fixtures.CreateTestRating(driverID, 5)))
+				},
+			},
+			{
+				Table:    "driver_rating_stats",
+				Column:   "driver_id",
+				OnDelete: driverChildBehavior,
+			},
+			{
+				Table:    "driver_heartbeats",
+				Column:   "driver_id",
+				OnDelete: driverChildBehavior,
+				Seed: func(t *testing.T, env *TestEnvironment, driverID uuid.UUID) {
+					require.NoError(t, env.HeartbeatRepo.Record(env.Ctx, driverID, time.Now(), TestHeartbeatMinInterval))
+				},
+			},
+			{
+				Table:    "driver_payments",
+				Column:   "driver_id",
+				OnDelete: driverChildBehavior,
+				Seed: func(t *testing.T, env *TestEnvironment, driverID uuid.UUID) {
+					_, err := env.DB.ExecContext(env.Ctx, `
+						INSERT INTO driver_payments (payment_id, driver_id, gross_amount, commission, net_amount)
+						VALUES ($1, $2, 500, 100, 400)`, uuid.New(), driverID)
+					require.NoError(t, err)
+				},
+			},
+			{
+				Table:    "driver_connection_losses",
+				Column:   "driver_id",
+				OnDelete: driverChildBehavior,
+				Seed: func(t *testing.T, env *TestEnvironment, driverID uuid.UUID) {
+					_, err := env.DB.ExecContext(env.Ctx, `
+						INSERT INTO driver_connection_losses (driver_id, last_location_at)
+						VALUES ($1, $2)`, driverID, time.Now().Add(-time.Hour))
+					require.NoError(t, err)
+				},
+			},
+		},
+	},
+	{
+		// Смена удаляется только вместе с водителем; на смены не ссылается внешним ключом ни одна таблица
+		// (driver_payments.shift_id - ссылка без внешнего ключа)
+		Entity: "shift",
+		Table:  "driver_shifts",
+		Create: func(t *testing.T, env *TestEnvironment) uuid.UUID {
+			shift := fixtures.CreateTestShift(createRelationshipDriver(t, env))
+			env.DB.InsertShift(t, shift)
+			return shift.ID
+		},
+		Delete: map[DeleteMode]func(t *testing.T, env *TestEnvironment, id uuid.UUID){
+			HardDelete: func(t *testing.T, env *TestEnvironment, id uuid.UUID) {
+				_, err := env.DB.ExecContext(env.Ctx, "DELETE FROM driver_shifts WHERE id = $1", id)
+				require.NoError(t, err)
+			},
+		},
+	},
+}
+
+// driverChildBehavior поведение данных водителя при его удалении
+var driverChildBehavior = map[DeleteMode]ChildBehavior{
+	HardDelete: ChildCascade,
+	SoftDelete: ChildRetain,
+}
+
+// relationshipDrivers счетчик водителей для уникальных телефонов
+var relationshipDrivers int64
+
+// createRelationshipDriver создает водителя с уникальными контактами через сервис
+func createRelationshipDriver(t *testing.T, env *TestEnvironment) uuid.UUID {
+	n := atomic.AddInt64(&relationshipDrivers, 1)
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900999%04d", n)
+	driver.Email = fmt.Sprintf("relationship.driver%d@example.com", n)
+	driver.LicenseNumber = fmt.Sprintf("RL%08d", n)
+
+	created, err := env.DriverService.CreateDriver(env.Ctx, driver)
+	require.NoError(t, err)
+	return created.ID
+}
+
+// ForeignKey внешний ключ схемы public
+type ForeignKey struct {
+	ChildTable  string `db:"child_table"`
+	Column      string `db:"column_name"`
+	ParentTable string `db:"parent_table"`
+	DeleteRule  string `db:"delete_rule"` // CASCADE, RESTRICT, NO ACTION, SET NULL, SET DEFAULT
+}
+
+// ForeignKeys возвращает внешние ключи таблиц схемы public
+func (tdb *TestDB) ForeignKeys(t *testing.T) []ForeignKey {
+	var keys []ForeignKey
+	err := tdb.SelectContext(context.Background(), &keys, `
+		SELECT
+			kcu.table_name AS child_table,
+			kcu.column_name AS column_name,
+			ccu.table_name AS parent_table,
+			rc.delete_rule AS delete_rule
+		FROM information_schema.referential_constraints rc
+		JOIN information_schema.key_column_usage kcu
+			ON kcu.constraint_schema = rc.constraint_schema AND kcu.constraint_name = rc.constraint_name
+		JOIN information_schema.constraint_column_usage ccu
+			ON ccu.constraint_schema = rc.unique_constraint_schema AND ccu.constraint_name = rc.unique_constraint_name
+		WHERE rc.constraint_schema = 'public'
+		ORDER BY kcu.table_name, kcu.column_name`)
+	require.NoError(t, err)
+	return keys
+}
+
+// CountRows возвращает количество строк таблицы table, у которых column = value
+func (tdb *TestDB) CountRows(t *testing.T, table, column string, value interface{}) int {
+	var count int
+	query := fmt.Sprintf("SELECT COUNT(*) FROM %s WHERE %s = $1", table, column)
+	require.NoError(t, tdb.GetContext(context.Background(), &count, query, value), "Таблица %s", table)
+	return count
+}
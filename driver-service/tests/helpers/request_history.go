@@ -0,0 +1,201 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения истории запросов APITestHelper
+const (
+	// RequestHistorySizeEnv сколько последних запросов хранит клиент (по умолчанию 50)
+	RequestHistorySizeEnv = "TEST_REQUEST_HISTORY"
+	// RequestHistoryBodyLimitEnv до скольких байт обрезаются тела запросов и ответов в истории (по умолчанию 2048)
+	RequestHistoryBodyLimitEnv = "TEST_REQUEST_HISTORY_BODY_LIMIT"
+)
+
+// Значения по умолчанию истории запросов
+const (
+	DefaultRequestHistorySize      = 50
+	DefaultRequestHistoryBodyLimit = 2048
+)
+
+// RequestHistory кольцевой буфер последних запросов клиента к API: метод, URL, код ответа, обрезанные тела
+// и длительность. Общий для копий APITestHelper, созданных через With*. Для упавшего теста история
+// прикладывается к его результату и сохраняется в TEST_ARTIFACTS_DIR, если клиент не привязан к span теста
+// (тогда запросы уже есть в отчете span'а).
+type RequestHistory struct {
+	mu        sync.Mutex
+	entries   []RecordedExchange
+	next      int // позиция следующей записи, после заполнения - самая старая запись
+	total     int // запросов с последней очистки
+	slowest   RecordedExchange
+	bodyLimit int
+	inSpan    bool
+}
+
+// NewRequestHistory создает историю на size запросов с телами, обрезанными до bodyLimit байт
+func NewRequestHistory(size, bodyLimit int) *RequestHistory {
+	return &RequestHistory{
+		entries:   make([]RecordedExchange, 0, size),
+		bodyLimit: bodyLimit,
+	}
+}
+
+// LoadRequestHistory создает историю с размером и ограничением тел из TEST_REQUEST_HISTORY
+// и TEST_REQUEST_HISTORY_BODY_LIMIT
+func LoadRequestHistory(t *testing.T) *RequestHistory {
+	size, bodyLimit := DefaultRequestHistorySize, DefaultRequestHistoryBodyLimit
+
+	for env, target := range map[string]*int{RequestHistorySizeEnv: &size, RequestHistoryBodyLimitEnv: &bodyLimit} {
+		if value := os.Getenv(env); value != "" {
+			parsed, err := strconv.Atoi(value)
+			require.NoError(t, err, "Некорректный %s: %q", env, value)
+			require.Positive(t, parsed, "Некорректный %s: %q", env, value)
+			*target = parsed
+		}
+	}
+
+	return NewRequestHistory(size, bodyLimit)
+}
+
+// record добавляет запрос в историю, вытесняя самый старый
+func (h *RequestHistory) record(exchange RecordedExchange) {
+	exchange.RequestBody = truncateBody(exchange.RequestBody, h.bodyLimit)
+	exchange.ResponseBody = truncateBody(exchange.ResponseBody, h.bodyLimit)
+	exchange.traceSpanID, exchange.parentSpanID = "", ""
+
+	h.mu.Lock()
+	defer h.mu.Unlock()
+
+	h.total++
+	if exchange.Duration > h.slowest.Duration {
+		h.slowest = exchange
+	}
+	if len(h.entries) < cap(h.entries) {
+		h.entries = append(h.entries, exchange)
+		return
+	}
+	h.entries[h.next] = exchange
+	h.next = (h.next + 1) % len(h.entries)
+}
+
+// truncateBody обрезает тело до limit байт с пометкой о числе отброшенных
+func truncateBody(body []byte, limit int) []byte {
+	if len(body) <= limit {
+		return body
+	}
+	return append(body[:limit:limit], fmt.Sprintf("... (%d bytes truncated)", len(body)-limit)...)
+}
+
+// Exchanges возвращает сохраненные запросы от самого старого к последнему
+func (h *RequestHistory) Exchanges() []RecordedExchange {
+	h.mu.Lock()
+	defer h.mu.Unlock()
+
+	return append(append([]RecordedExchange(nil), h.entries[h.next:]...), h.entries[:h.next]...)
+}
+
+// Total возвращает число запросов с последней очистки, включая вытесненные из истории
+func (h *RequestHistory) Total() int {
+	h.mu.Lock()
+	defer h.mu.Unlock()
+
+	return h.total
+}
+
+// Slowest возвращает самый долгий запрос с последней очистки, в том числе вытесненный из истории
+func (h *RequestHistory) Slowest() RecordedExchange {
+	h.mu.Lock()
+	defer h.mu.Unlock()
+
+	return h.slowest
+}
+
+// Clear очищает историю; TestEnvironment.Reset вызывает его перед каждым тестом
+func (h *RequestHistory) Clear() {
+	h.mu.Lock()
+	defer h.mu.Unlock()
+
+	h.entries = h.entries[:0]
+	h.next = 0
+	h.total = 0
+	h.slowest = RecordedExchange{}
+}
+
+// AssertMaxDuration проверяет, что ни один запрос с последней очистки не выполнялся дольше limit
+func (h *RequestHistory) AssertMaxDuration(t *testing.T, limit time.Duration) {
+	t.Helper()
+
+	slowest := h.Slowest()
+	AssertWithinSLO(t, fmt.Sprintf("slowest request %s %s", slowest.Method, slowest.URL), slowest.Duration, limit)
+}
+
+// Report формирует отчет о последних запросах
+func (h *RequestHistory) Report() string {
+	exchanges := h.Exchanges()
+
+	var report strings.Builder
+	fmt.Fprintf(&report, "--- last %d of %d API requests ---\n", len(exchanges), h.Total())
+	for _, exchange := range exchanges {
+		fmt.Fprintf(&report, "%s %s %s [%s] -> %d (%s)\n",
+			exchange.Started.Format("15:04:05.000"), exchange.Method, exchange.URL, exchange.RequestID,
+			exchange.StatusCode, exchange.Duration.Round(time.Microsecond))
+		if len(exchange.RequestBody) > 0 {
+			fmt.Fprintf(&report, "  request:  %s\n", exchange.RequestBody)
+		}
+		if len(exchange.ResponseBody) > 0 {
+			fmt.Fprintf(&report, "  response: %s\n", exchange.ResponseBody)
+		}
+	}
+	return report.String()
+}
+
+// attachToSpan отмечает, что запросы клиента попадают в отчет span'а теста
+func (h *RequestHistory) attachToSpan() {
+	h.mu.Lock()
+	defer h.mu.Unlock()
+
+	h.inSpan = true
+}
+
+// finish прикладывает историю к упавшему тесту и, если задан TEST_ARTIFACTS_DIR, сохраняет ее в файл.
+// Регистрируется конструкторами APITestHelper и TestEnvironment.Reset.
+func (h *RequestHistory) finish(t *testing.T) {
+	h.mu.Lock()
+	skip := h.inSpan || h.total == 0
+	h.mu.Unlock()
+	if !t.Failed() || skip {
+		return
+	}
+
+	// Выведенная история очищается, чтобы не повторяться в отчете родительского теста
+	report := h.Report()
+	h.Clear()
+	t.Logf("Recent API requests:\n%s", report)
+
+	dir := os.Getenv(TestArtifactsDirEnv)
+	if dir == "" {
+		return
+	}
+
+	path := filepath.Join(dir, RunID(), artifactName.ReplaceAllString(t.Name(), "_")+".requests.log")
+	if err := os.MkdirAll(filepath.Dir(path), 0o755); err != nil {
+		t.Logf("Failed to save request history: %v", err)
+		return
+	}
+	if err := os.WriteFile(path, []byte(report), 0o644); err != nil {
+		t.Logf("Failed to save request history: %v", err)
+		return
+	}
+	t.Logf("Request history saved to %s", path)
+}
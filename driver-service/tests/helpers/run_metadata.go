@@ -0,0 +1,206 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"runtime"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+)
+
+// Переменные окружения с метаданными прогона, которые задает CI. Без них значения определяются на машине прогона.
+const (
+	// RunGitSHAEnv коммит, из которого собраны тесты (по умолчанию git rev-parse HEAD)
+	RunGitSHAEnv = "TEST_GIT_SHA"
+	// ServiceImageEnv образ сервиса, против которого идет прогон (smoke, multi-region); digest определяется через docker
+	ServiceImageEnv = "SERVICE_IMAGE"
+	// ServiceImageDigestEnv digest образа сервиса, если docker на машине прогона недоступен
+	ServiceImageDigestEnv = "SERVICE_IMAGE_DIGEST"
+	// ConfigProfileEnv профиль конфигурации сервиса (по умолчанию server.environment тестовой конфигурации)
+	ConfigProfileEnv = "TEST_CONFIG_PROFILE"
+)
+
+// RunMetadataFile файл с метаданными прогона в каталоге TEST_ARTIFACTS_DIR/<run_id>
+const RunMetadataFile = "run-metadata.json"
+
+// metadataCommandTimeout сколько ждать git и docker при сборе метаданных
+const metadataCommandTimeout = 5 * time.Second
+
+// RunMetadata метаданные прогона, по которым любой результат сопоставляется с кодом и окружением,
+// в котором он получен. Пустое значение не удалось определить.
+type RunMetadata struct {
+	RunID    string `json:"run_id"`
+	GitSHA   string `json:"git_sha"`
+	GitDirty bool   `json:"git_dirty"`
+	// ServiceImage образ сервиса с digest; пустой, если сервис запущен в процессе тестов из GitSHA
+	ServiceImage     string `json:"service_image,omitempty"`
+	MigrationVersion uint   `json:"migration_version"`
+	ConfigProfile    string `json:"config_profile"`
+	// TestSeed значение TEST_SEED; без него тесты используют собственные seed, которые выводятся в лог теста
+	TestSeed        string `json:"test_seed,omitempty"`
+	GoVersion       string `json:"go_version"`
+	Platform        string `json:"platform"`
+	Hostname        string `json:"hostname"`
+	PostgresVersion string `json:"postgres_version,omitempty"`
+	DockerVersion   string `json:"docker_version,omitempty"`
+}
+
+var (
+	runMetadataOnce sync.Once
+	runMetadataMu   sync.Mutex
+	runMetadata     RunMetadata
+)
+
+// CurrentRunMetadata возвращает метаданные прогона. Они собираются при первом вызове,
+// версия PostgreSQL дописывается при создании первой тестовой БД.
+func CurrentRunMetadata() RunMetadata {
+	runMetadataOnce.Do(func() {
+		collected := collectRunMetadata()
+
+		runMetadataMu.Lock()
+		defer runMetadataMu.Unlock()
+		runMetadata = collected
+	})
+
+	runMetadataMu.Lock()
+	defer runMetadataMu.Unlock()
+	return runMetadata
+}
+
+// collectRunMetadata собирает метаданные из переменных окружения, git, docker и каталога миграций
+func collectRunMetadata() RunMetadata {
+	metadata := RunMetadata{
+		RunID:         RunID(),
+		GitSHA:        os.Getenv(RunGitSHAEnv),
+		ServiceImage:  os.Getenv(ServiceImageDigestEnv),
+		ConfigProfile: getEnvOrDefault(ConfigProfileEnv, getTestConfig().Server.Environment),
+		TestSeed:      os.Getenv(TestSeedEnv),
+		GoVersion:     runtime.Version(),
+		Platform:      runtime.GOOS + "/" + runtime.GOARCH,
+		DockerVersion: commandOutput("docker", "version", "--format", "{{.Server.Version}}"),
+	}
+
+	if metadata.GitSHA == "" {
+		metadata.GitSHA = commandOutput("git", "rev-parse", "HEAD")
+		metadata.GitDirty = metadata.GitSHA != "" && commandOutput("git", "status", "--porcelain") != ""
+	}
+
+	if image := os.Getenv(ServiceImageEnv); image != "" && metadata.ServiceImage == "" {
+		metadata.ServiceImage = commandOutput("docker", "image", "inspect", "--format", "{{index .RepoDigests 0}}", image)
+		if metadata.ServiceImage == "" {
+			metadata.ServiceImage = image
+		}
+	}
+
+	if version, err := latestMigrationVersion(); err == nil {
+		metadata.MigrationVersion = version
+	}
+	if hostname, err := os.Hostname(); err == nil {
+		metadata.Hostname = hostname
+	}
+
+	return metadata
+}
+
+// commandOutput выполняет команду и возвращает ее вывод без пробелов по краям или пустую строку при ошибке
+func commandOutput(name string, args ...string) string {
+	ctx, cancel := context.WithTimeout(context.Background(), metadataCommandTimeout)
+	defer cancel()
+
+	output, err := exec.CommandContext(ctx, name, args...).Output()
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
+
+// recordPostgresVersion дописывает в метаданные версию сервера тестовой БД
+func recordPostgresVersion(tdb *TestDB) {
+	if CurrentRunMetadata().PostgresVersion != "" {
+		return
+	}
+
+	var version string
+	if err := tdb.Get(&version, "SHOW server_version"); err != nil {
+		return
+	}
+
+	runMetadataMu.Lock()
+	defer runMetadataMu.Unlock()
+	runMetadata.PostgresVersion = version
+}
+
+// Fields возвращает метаданные парами ключ-значение в постоянном порядке, без пустых значений
+func (m RunMetadata) Fields() [][2]string {
+	fields := [][2]string{
+		{"run_id", m.RunID},
+		{"git_sha", m.GitSHA},
+		{"git_dirty", strconv.FormatBool(m.GitDirty)},
+		{"service_image", m.ServiceImage},
+		{"migration_version", strconv.FormatUint(uint64(m.MigrationVersion), 10)},
+		{"config_profile", m.ConfigProfile},
+		{"test_seed", m.TestSeed},
+		{"go_version", m.GoVersion},
+		{"platform", m.Platform},
+		{"hostname", m.Hostname},
+		{"postgres_version", m.PostgresVersion},
+		{"docker_version", m.DockerVersion},
+	}
+
+	nonEmpty := fields[:0]
+	for _, field := range fields {
+		if field[1] != "" {
+			nonEmpty = append(nonEmpty, field)
+		}
+	}
+	return nonEmpty
+}
+
+// Logfmt возвращает метаданные строкой logfmt; значения с пробелами и кавычками берутся в кавычки
+func (m RunMetadata) Logfmt() string {
+	pairs := make([]string, 0, 12)
+	for _, field := range m.Fields() {
+		value := field[1]
+		if strings.ContainsAny(value, " \"=") {
+			value = strconv.Quote(value)
+		}
+		pairs = append(pairs, field[0]+"="+value)
+	}
+	return strings.Join(pairs, " ")
+}
+
+// runMetadataHeader первая строка текстовых отчетов: метаданные прогона в комментарии
+func runMetadataHeader() string {
+	return "# " + CurrentRunMetadata().Logfmt() + "\n\n"
+}
+
+// WriteRunMetadata сохраняет метаданные прогона в TEST_ARTIFACTS_DIR/<run_id>/run-metadata.json рядом
+// с отчетами упавших тестов и выводит их. Вызывается из TestMain после прогона; без TEST_ARTIFACTS_DIR только выводит.
+func WriteRunMetadata() error {
+	metadata := CurrentRunMetadata()
+	fmt.Printf("\nRun metadata: %s\n", metadata.Logfmt())
+
+	dir := os.Getenv(TestArtifactsDirEnv)
+	if dir == "" {
+		return nil
+	}
+
+	data, err := json.MarshalIndent(metadata, "", "  ")
+	if err != nil {
+		return err
+	}
+
+	path := filepath.Join(dir, metadata.RunID, RunMetadataFile)
+	if err := os.MkdirAll(filepath.Dir(path), 0o755); err != nil {
+		return err
+	}
+	return os.WriteFile(path, append(data, '\n'), 0o644)
+}
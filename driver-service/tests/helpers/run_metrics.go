@@ -0,0 +1,390 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"context"
+	"fmt"
+	"net"
+	"net/http"
+	"os"
+	"sort"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+)
+
+// Переменные окружения метрик прогона. Без них метрики не собираются.
+const (
+	// RunMetricsAddrEnv адрес, на котором прогон отдает /metrics для Prometheus (например, ":9464")
+	RunMetricsAddrEnv = "TEST_METRICS_ADDR"
+	// RunMetricsPushgatewayEnv URL Pushgateway, в который метрики отправляются периодически и в конце прогона
+	RunMetricsPushgatewayEnv = "TEST_METRICS_PUSHGATEWAY"
+	// RunMetricsPushIntervalEnv интервал отправки в Pushgateway (по умолчанию 15s)
+	RunMetricsPushIntervalEnv = "TEST_METRICS_PUSH_INTERVAL"
+)
+
+// runMetricsJob имя job в Pushgateway; instance - RunID прогона
+const runMetricsJob = "driver_service_tests"
+
+// clientLatencyBuckets границы гистограммы длительности запросов к API в секундах
+var clientLatencyBuckets = []float64{0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1, 2.5, 5}
+
+// runMetrics метрики текущего прогона: тесты, запросы к API и генераторы нагрузки
+var runMetrics = newRunMetricsRegistry()
+
+// requestMetricKey метки гистограммы запросов к API
+type requestMetricKey struct {
+	method string
+	route  string
+	status int
+}
+
+// latencyHistogram накопительная гистограмма в формате Prometheus
+type latencyHistogram struct {
+	buckets []uint64
+	count   uint64
+	sum     float64
+}
+
+// loadMetricKey метки счетчика операций генератора нагрузки
+type loadMetricKey struct {
+	generator string
+	result    string
+}
+
+// runMetricsRegistry хранилище метрик прогона
+type runMetricsRegistry struct {
+	// enabled проверяется без блокировки, чтобы выключенные метрики не добавляли конкуренции в нагрузочные тесты
+	enabled atomic.Bool
+	mu      sync.Mutex
+
+	tracked      map[*testing.T]bool
+	testsRunning int
+	testsTotal   map[string]uint64
+	requests     map[requestMetricKey]*latencyHistogram
+	load         map[loadMetricKey]uint64
+	// connections запросы к развернутому сервису по повторному использованию соединения,
+	// connectionSetup суммарное время установки новых соединений в секундах
+	connections     map[bool]uint64
+	connectionSetup float64
+	// loadSecond и loadCount операции генераторов в текущей секунде, loadLastSecond - в предыдущей
+	loadSecond     int64
+	loadCount      uint64
+	loadLastSecond uint64
+}
+
+func newRunMetricsRegistry() *runMetricsRegistry {
+	return &runMetricsRegistry{
+		tracked:     make(map[*testing.T]bool),
+		testsTotal:  make(map[string]uint64),
+		requests:    make(map[requestMetricKey]*latencyHistogram),
+		load:        make(map[loadMetricKey]uint64),
+		connections: make(map[bool]uint64),
+	}
+}
+
+// StartRunMetrics включает сбор метрик прогона, если задан TEST_METRICS_ADDR или TEST_METRICS_PUSHGATEWAY:
+// поднимает /metrics и/или начинает периодическую отправку в Pushgateway. Вызывается из TestMain;
+// возвращаемая функция останавливает сервер и отправляет итоговые значения.
+func StartRunMetrics() (func() error, error) {
+	addr := os.Getenv(RunMetricsAddrEnv)
+	pushgateway := strings.TrimRight(os.Getenv(RunMetricsPushgatewayEnv), "/")
+	if addr == "" && pushgateway == "" {
+		return func() error { return nil }, nil
+	}
+
+	interval := 15 * time.Second
+	if value := os.Getenv(RunMetricsPushIntervalEnv); value != "" {
+		parsed, err := time.ParseDuration(value)
+		if err != nil || parsed <= 0 {
+			return nil, fmt.Errorf("invalid %s: %q", RunMetricsPushIntervalEnv, value)
+		}
+		interval = parsed
+	}
+
+	runMetrics.enabled.Store(true)
+
+	var server *http.Server
+	if addr != "" {
+		listener, err := net.Listen("tcp", addr)
+		if err != nil {
+			return nil, fmt.Errorf("failed to listen on %s: %w", addr, err)
+		}
+
+		mux := http.NewServeMux()
+		mux.HandleFunc("/metrics", func(w http.ResponseWriter, _ *http.Request) {
+			w.Header().Set("Content-Type", "text/plain; version=0.0.4; charset=utf-8")
+			_, _ = w.Write(runMetrics.expose())
+		})
+		server = &http.Server{Handler: mux, ReadHeaderTimeout: 5 * time.Second}
+		go func() { _ = server.Serve(listener) }()
+		fmt.Fprintf(os.Stderr, "Test run metrics: http://%s/metrics\n", listener.Addr())
+	}
+
+	done := make(chan struct{})
+	var pushed sync.WaitGroup
+	if pushgateway != "" {
+		pushed.Add(1)
+		go func() {
+			defer pushed.Done()
+
+			ticker := time.NewTicker(interval)
+			defer ticker.Stop()
+			for {
+				select {
+				case <-done:
+					return
+				case <-ticker.C:
+					if err := pushRunMetrics(pushgateway); err != nil {
+						fmt.Fprintf(os.Stderr, "Failed to push test run metrics: %v\n", err)
+					}
+				}
+			}
+		}()
+	}
+
+	return func() error {
+		close(done)
+		pushed.Wait()
+
+		var err error
+		if pushgateway != "" {
+			err = pushRunMetrics(pushgateway)
+		}
+		if server != nil {
+			ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+			defer cancel()
+			if shutdownErr := server.Shutdown(ctx); err == nil {
+				err = shutdownErr
+			}
+		}
+		return err
+	}, nil
+}
+
+// pushRunMetrics заменяет метрики прогона в Pushgateway текущими значениями
+func pushRunMetrics(pushgateway string) error {
+	target := fmt.Sprintf("%s/metrics/job/%s/instance/%s", pushgateway, runMetricsJob, RunID())
+	request, err := http.NewRequest(http.MethodPut, target, bytes.NewReader(runMetrics.expose()))
+	if err != nil {
+		return err
+	}
+	request.Header.Set("Content-Type", "text/plain; version=0.0.4")
+
+	client := &http.Client{Timeout: 10 * time.Second}
+	response, err := client.Do(request)
+	if err != nil {
+		return err
+	}
+	defer response.Body.Close()
+
+	if response.StatusCode >= http.StatusBadRequest {
+		return fmt.Errorf("pushgateway %s: status %d", target, response.StatusCode)
+	}
+	return nil
+}
+
+// TrackTest учитывает тест в метриках прогона: он считается выполняющимся до завершения,
+// затем попадает в счетчик с результатом passed, failed или skipped. Повторный вызов для того же t ничего не делает.
+// Вызывается из TestEnvironment.Reset и StartTestSpan, тестам без окружения - явно.
+func TrackTest(t *testing.T) {
+	if !runMetrics.enabled.Load() {
+		return
+	}
+
+	runMetrics.mu.Lock()
+	defer runMetrics.mu.Unlock()
+
+	if runMetrics.tracked[t] {
+		return
+	}
+	runMetrics.tracked[t] = true
+	runMetrics.testsRunning++
+
+	t.Cleanup(func() {
+		result := "passed"
+		switch {
+		case t.Skipped():
+			result = "skipped"
+		case t.Failed():
+			result = "failed"
+		}
+
+		runMetrics.mu.Lock()
+		defer runMetrics.mu.Unlock()
+		delete(runMetrics.tracked, t)
+		runMetrics.testsRunning--
+		runMetrics.testsTotal[result]++
+	})
+}
+
+// recordClientRequest учитывает запрос к API в гистограмме длительности
+func recordClientRequest(method, path string, status int, duration time.Duration) {
+	if !runMetrics.enabled.Load() {
+		return
+	}
+
+	runMetrics.mu.Lock()
+	defer runMetrics.mu.Unlock()
+
+	key := requestMetricKey{method: method, route: templatePath(path), status: status}
+	histogram, ok := runMetrics.requests[key]
+	if !ok {
+		histogram = &latencyHistogram{buckets: make([]uint64, len(clientLatencyBuckets))}
+		runMetrics.requests[key] = histogram
+	}
+
+	seconds := duration.Seconds()
+	for i, bound := range clientLatencyBuckets {
+		if seconds <= bound {
+			histogram.buckets[i]++
+		}
+	}
+	histogram.count++
+	histogram.sum += seconds
+}
+
+// recordClientConnection учитывает соединение запроса к развернутому сервису
+func recordClientConnection(connection *ConnectionInfo) {
+	if !runMetrics.enabled.Load() {
+		return
+	}
+
+	runMetrics.mu.Lock()
+	defer runMetrics.mu.Unlock()
+	runMetrics.connections[connection.Reused]++
+	runMetrics.connectionSetup += connection.Setup.Seconds()
+}
+
+// recordLoadOperation учитывает операцию генератора нагрузки
+func recordLoadOperation(generator string, ok bool) {
+	if !runMetrics.enabled.Load() {
+		return
+	}
+
+	runMetrics.mu.Lock()
+	defer runMetrics.mu.Unlock()
+
+	result := "ok"
+	if !ok {
+		result = "error"
+	}
+	runMetrics.load[loadMetricKey{generator: generator, result: result}]++
+
+	now := time.Now().Unix()
+	if now != runMetrics.loadSecond {
+		runMetrics.loadLastSecond = 0
+		if now == runMetrics.loadSecond+1 {
+			runMetrics.loadLastSecond = runMetrics.loadCount
+		}
+		runMetrics.loadSecond, runMetrics.loadCount = now, 0
+	}
+	runMetrics.loadCount++
+}
+
+// loadRPS возвращает число операций генераторов за последнюю завершившуюся секунду
+func (r *runMetricsRegistry) loadRPS() uint64 {
+	switch time.Now().Unix() {
+	case r.loadSecond:
+		return r.loadLastSecond
+	case r.loadSecond + 1:
+		return r.loadCount
+	default:
+		return 0
+	}
+}
+
+// expose возвращает метрики в текстовом формате Prometheus
+func (r *runMetricsRegistry) expose() []byte {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	var out bytes.Buffer
+
+	writeMetricHeader(&out, "driver_tests_run_info", "gauge", "Run metadata: commit, migrations and environment versions.")
+	fields := CurrentRunMetadata().Fields()
+	infoLabels := make([]string, 0, len(fields))
+	for _, field := range fields {
+		infoLabels = append(infoLabels, field[0]+"="+labelValue(field[1]))
+	}
+	fmt.Fprintf(&out, "driver_tests_run_info{%s} 1\n", strings.Join(infoLabels, ","))
+
+	writeMetricHeader(&out, "driver_tests_running", "gauge", "Tests currently running.")
+	fmt.Fprintf(&out, "driver_tests_running %d\n", r.testsRunning)
+
+	writeMetricHeader(&out, "driver_tests_total", "counter", "Finished tests by result.")
+	for _, result := range []string{"passed", "failed", "skipped"} {
+		fmt.Fprintf(&out, "driver_tests_total{result=%s} %d\n", labelValue(result), r.testsTotal[result])
+	}
+
+	writeMetricHeader(&out, "driver_tests_client_request_duration_seconds", "histogram", "Latency of API requests made by tests.")
+	keys := make([]requestMetricKey, 0, len(r.requests))
+	for key := range r.requests {
+		keys = append(keys, key)
+	}
+	sort.Slice(keys, func(i, j int) bool {
+		if keys[i].route != keys[j].route {
+			return keys[i].route < keys[j].route
+		}
+		if keys[i].method != keys[j].method {
+			return keys[i].method < keys[j].method
+		}
+		return keys[i].status < keys[j].status
+	})
+	for _, key := range keys {
+		histogram := r.requests[key]
+		labels := fmt.Sprintf("method=%s,route=%s,status=\"%d\"", labelValue(key.method), labelValue(key.route), key.status)
+		for i, bound := range clientLatencyBuckets {
+			fmt.Fprintf(&out, "driver_tests_client_request_duration_seconds_bucket{%s,le=\"%g\"} %d\n", labels, bound, histogram.buckets[i])
+		}
+		fmt.Fprintf(&out, "driver_tests_client_request_duration_seconds_bucket{%s,le=\"+Inf\"} %d\n", labels, histogram.count)
+		fmt.Fprintf(&out, "driver_tests_client_request_duration_seconds_sum{%s} %g\n", labels, histogram.sum)
+		fmt.Fprintf(&out, "driver_tests_client_request_duration_seconds_count{%s} %d\n", labels, histogram.count)
+	}
+
+	writeMetricHeader(&out, "driver_tests_client_connections_total", "counter", "API requests to a deployed service by connection reuse.")
+	for _, reused := range []bool{true, false} {
+		fmt.Fprintf(&out, "driver_tests_client_connections_total{reused=\"%t\"} %d\n", reused, r.connections[reused])
+	}
+
+	writeMetricHeader(&out, "driver_tests_client_connection_setup_seconds_total", "counter", "Time spent establishing new connections (DNS, TCP, TLS).")
+	fmt.Fprintf(&out, "driver_tests_client_connection_setup_seconds_total %g\n", r.connectionSetup)
+
+	writeMetricHeader(&out, "driver_tests_load_operations_total", "counter", "Load generator operations by result.")
+	loadKeys := make([]loadMetricKey, 0, len(r.load))
+	for key := range r.load {
+		loadKeys = append(loadKeys, key)
+	}
+	sort.Slice(loadKeys, func(i, j int) bool {
+		if loadKeys[i].generator != loadKeys[j].generator {
+			return loadKeys[i].generator < loadKeys[j].generator
+		}
+		return loadKeys[i].result < loadKeys[j].result
+	})
+	for _, key := range loadKeys {
+		fmt.Fprintf(&out, "driver_tests_load_operations_total{generator=%s,result=%s} %d\n",
+			labelValue(key.generator), labelValue(key.result), r.load[key])
+	}
+
+	writeMetricHeader(&out, "driver_tests_load_rps", "gauge", "Load generator operations in the last completed second.")
+	fmt.Fprintf(&out, "driver_tests_load_rps %d\n", r.loadRPS())
+
+	return out.Bytes()
+}
+
+// writeMetricHeader пишет строки HELP и TYPE метрики
+func writeMetricHeader(out *bytes.Buffer, name, kind, help string) {
+	fmt.Fprintf(out, "# HELP %s %s\n# TYPE %s %s\n", name, help, name, kind)
+}
+
+// labelEscaper экранирует значение метки по правилам текстового формата Prometheus
+var labelEscaper = strings.NewReplacer(`\`, `\\`, `"`, `\"`, "\n", `\n`)
+
+// labelValue возвращает значение метки в кавычках
+func labelValue(value string) string {
+	return `"` + labelEscaper.Replace(value) + `"`
+}
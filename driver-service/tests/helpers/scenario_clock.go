@@ -0,0 +1,81 @@
+//go:build integration
+
+package helpers
+
+import (
+	"os"
+	"sync"
+	"testing"
+	"time"
+)
+
+// ScenarioClockEnv режим часов сценариев: "simulated" (по умолчанию) или "real"
+const ScenarioClockEnv = "SCENARIO_CLOCK"
+
+// Режимы часов сценариев
+const (
+	ScenarioClockSimulated = "simulated"
+	ScenarioClockReal      = "real"
+)
+
+// ScenarioClock часы сценария: шаги ждут через Sleep, а метки времени в запросах и событиях берут из Now.
+// С имитируемыми часами многочасовой сценарий выполняется за секунды, с реальными - в реальном времени
+// (например, при прогоне против развернутого стенда).
+type ScenarioClock interface {
+	Now() time.Time
+	Sleep(d time.Duration)
+}
+
+// NewScenarioClock возвращает часы сценария длительностью span в режиме из SCENARIO_CLOCK.
+// Имитируемое время начинается за span до начала теста и к концу сценария доходит до текущего момента:
+// последние местоположения остаются свежими для поиска поблизости и текущего местоположения.
+func NewScenarioClock(t *testing.T, span time.Duration) ScenarioClock {
+	switch mode := getEnvOrDefault(ScenarioClockEnv, ScenarioClockSimulated); mode {
+	case ScenarioClockSimulated:
+		return NewSimulatedClock(time.Now().Add(-span).Truncate(time.Second))
+	case ScenarioClockReal:
+		t.Logf("Scenario runs in real time: %v", span)
+		return realClock{}
+	default:
+		t.Fatalf("Некорректный %s: %q (ожидается %q или %q)",
+			ScenarioClockEnv, os.Getenv(ScenarioClockEnv), ScenarioClockSimulated, ScenarioClockReal)
+		return nil
+	}
+}
+
+// IsSimulated проверяет, что часы сценария имитируемые
+func IsSimulated(clock ScenarioClock) bool {
+	_, ok := clock.(*SimulatedClock)
+	return ok
+}
+
+// SimulatedClock детерминированные часы: время меняется только через Sleep, без реального ожидания
+type SimulatedClock struct {
+	mu  sync.Mutex
+	now time.Time
+}
+
+// NewSimulatedClock создает имитируемые часы, показывающие start
+func NewSimulatedClock(start time.Time) *SimulatedClock {
+	return &SimulatedClock{now: start}
+}
+
+// Now возвращает текущее имитируемое время
+func (c *SimulatedClock) Now() time.Time {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	return c.now
+}
+
+// Sleep сразу переводит имитируемое время на d вперед
+func (c *SimulatedClock) Sleep(d time.Duration) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	c.now = c.now.Add(d)
+}
+
+// realClock часы реального времени
+type realClock struct{}
+
+func (realClock) Now() time.Time        { return time.Now() }
+func (realClock) Sleep(d time.Duration) { time.Sleep(d) }
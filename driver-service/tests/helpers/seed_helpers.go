@@ -0,0 +1,28 @@
+//go:build integration
+
+package helpers
+
+import (
+	"os"
+	"strconv"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+// TestSeedEnv переменная окружения с seed генераторов случайных данных.
+// Задается скриптом run-tests.sh в режиме flake-hunt, чтобы упавшую итерацию можно было воспроизвести.
+const TestSeedEnv = "TEST_SEED"
+
+// TestSeed возвращает seed из TEST_SEED или defaultSeed, если переменная не задана, и выводит его в лог теста
+func TestSeed(t *testing.T, defaultSeed int64) int64 {
+	seed := defaultSeed
+	if value := os.Getenv(TestSeedEnv); value != "" {
+		parsed, err := strconv.ParseInt(value, 10, 64)
+		require.NoError(t, err, "Некорректный %s: %q", TestSeedEnv, value)
+		seed = parsed
+	}
+
+	t.Logf("%s=%d", TestSeedEnv, seed)
+	return seed
+}
@@ -0,0 +1,81 @@
+//go:build integration
+
+package helpers
+
+import (
+	"database/sql"
+	"fmt"
+	"os"
+	"runtime"
+	"testing"
+	"time"
+)
+
+// goroutineLeakTolerance допустимый прирост горутин после нагрузки (таймеры, фоновые горутины драйвера БД)
+const goroutineLeakTolerance = 5
+
+// fdLeakTolerance допустимый прирост открытых файловых дескрипторов без учета соединений пула БД
+const fdLeakTolerance = 5
+
+// ServiceHealth состояние сервиса, по которому после нагрузочного теста проверяются утечки.
+// Сервис пока не публикует /metrics, поэтому состояние снимается в процессе теста: роутер и пул соединений
+// работают в том же процессе.
+type ServiceHealth struct {
+	Goroutines int
+	OpenFDs    int // -1, если количество дескрипторов не удается получить (нет /proc)
+	DB         sql.DBStats
+}
+
+// String возвращает краткое описание состояния для сообщений об ошибках
+func (h ServiceHealth) String() string {
+	return fmt.Sprintf("goroutines=%d open_fds=%d db_open=%d db_in_use=%d db_idle=%d db_wait_count=%d",
+		h.Goroutines, h.OpenFDs, h.DB.OpenConnections, h.DB.InUse, h.DB.Idle, h.DB.WaitCount)
+}
+
+// CaptureServiceHealth снимает текущее состояние сервиса
+func CaptureServiceHealth(db *TestDB) ServiceHealth {
+	return ServiceHealth{
+		Goroutines: runtime.NumGoroutine(),
+		OpenFDs:    openFDs(),
+		DB:         db.GetStats(),
+	}
+}
+
+// openFDs возвращает количество открытых файловых дескрипторов процесса (как process_open_fds в Prometheus)
+func openFDs() int {
+	entries, err := os.ReadDir("/proc/self/fd")
+	if err != nil {
+		return -1
+	}
+	return len(entries)
+}
+
+// AssertServiceHealthRecovered проверяет, что после нагрузки сервис вернулся к исходному состоянию:
+// количество горутин и открытых файловых дескрипторов вернулось к baseline, все соединения возвращены в пул
+// и пул не исчерпан.
+// Ждет не дольше timeout, так как горутины и соединения освобождаются не мгновенно.
+func AssertServiceHealthRecovered(t *testing.T, db *TestDB, baseline ServiceHealth, timeout time.Duration) {
+	t.Helper()
+
+	Eventually(t, func(c *EventuallyT) {
+		current := CaptureServiceHealth(db)
+
+		if current.Goroutines > baseline.Goroutines+goroutineLeakTolerance {
+			c.Errorf("goroutine count did not return to baseline: before %s, after %s", baseline, current)
+		}
+		// Соединения пула БД не считаются утечкой дескрипторов: после нагрузки пул держит простаивающие соединения
+		if baseline.OpenFDs >= 0 && current.OpenFDs >= 0 &&
+			current.OpenFDs-current.DB.OpenConnections > baseline.OpenFDs-baseline.DB.OpenConnections+fdLeakTolerance {
+			c.Errorf("file descriptors leaked: before %s, after %s", baseline, current)
+		}
+		if current.DB.InUse > 0 {
+			c.Errorf("database connections not returned to pool: before %s, after %s", baseline, current)
+		}
+		if limit := current.DB.MaxOpenConnections; limit > 0 && current.DB.OpenConnections >= limit && current.DB.Idle == 0 {
+			c.Errorf("database pool exhausted: %s (max_open=%d)", current, limit)
+		}
+	}, timeout, 100*time.Millisecond)
+
+	after := CaptureServiceHealth(db)
+	t.Logf("Service health after load: %s (before: %s)", after, baseline)
+}
@@ -0,0 +1,113 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"net"
+	"strconv"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/infrastructure/redis"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// TestSessionKeyPrefix префикс ключей сессий приложения водителя в тестовом Redis
+const TestSessionKeyPrefix = "test:driver:session:"
+
+// sessionSubscribeTimeout сколько ждать подписки слушателя на уведомления Redis
+const sessionSubscribeTimeout = 5 * time.Second
+
+// StartSessionExpiryListener включает уведомления об истечении ключей в тестовом Redis и подписывает
+// сервис окружения на истечение ключей сессий TestSessionKeyPrefix до конца теста t.
+// Возвращает помощник тестового Redis; без Redis тест пропускается.
+func (env *TestEnvironment) StartSessionExpiryListener(t *testing.T) *RedisHelper {
+	h := NewRedisHelper(t)
+
+	host, port, err := net.SplitHostPort(h.addr)
+	require.NoError(t, err)
+	portNumber, err := strconv.Atoi(port)
+	require.NoError(t, err)
+
+	env.startSessionExpiryListener(t, h, config.RedisConfig{Host: host, Port: portNumber})
+	return h
+}
+
+// StartSessionExpiryListenerThrough как StartSessionExpiryListener, но слушатель подключается к Redis
+// через прокси chaos: задержка и отказ Redis в chaos доходят до обработки истечения сессий.
+// Без тестового Redis тест пропускается.
+func (env *TestEnvironment) StartSessionExpiryListenerThrough(t *testing.T, chaos *DependencyChaos) *RedisHelper {
+	if chaos.Redis == nil {
+		t.Skip("Тестовый Redis недоступен, прокси Redis не запущен")
+	}
+	h := NewRedisHelper(t)
+
+	env.startSessionExpiryListener(t, h, config.RedisConfig{Host: chaos.Redis.Host, Port: chaos.Redis.Port})
+	return h
+}
+
+// startSessionExpiryListener включает уведомления в Redis h и запускает слушатель сервиса окружения,
+// подключенный по cfg, до конца теста t
+func (env *TestEnvironment) startSessionExpiryListener(t *testing.T, h *RedisHelper, cfg config.RedisConfig) {
+	h.EnableExpiryNotifications()
+
+	cfg.SessionKeyPrefix = TestSessionKeyPrefix
+	listener := redis.NewSessionExpiryListener(cfg, func(ctx context.Context, driverID uuid.UUID) {
+		if err := env.Heartbeats.ExpireSession(ctx, driverID); err != nil {
+			env.Logger.Error("Failed to expire driver session", zap.Error(err), zap.String("driver_id", driverID.String()))
+		}
+	}, env.Logger)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan struct{})
+	go func() {
+		defer close(done)
+		listener.Run(ctx)
+	}()
+	t.Cleanup(func() {
+		cancel()
+		<-done
+	})
+
+	select {
+	case <-listener.Subscribed():
+	case <-time.After(sessionSubscribeTimeout):
+		t.Fatalf("Слушатель не подписался на истечение ключей в Redis %s:%d за %v", cfg.Host, cfg.Port, sessionSubscribeTimeout)
+	}
+}
+
+// EnableExpiryNotifications добавляет в notify-keyspace-events классы E (keyevent) и x (expired),
+// если их нет: test-redis из docker-compose.test.yml запускается с ними, локальный Redis - не обязательно
+func (h *RedisHelper) EnableExpiryNotifications() {
+	reply, err := h.Do("CONFIG", "GET", "notify-keyspace-events")
+	require.NoError(h.t, err)
+
+	flags := ""
+	if values, ok := reply.([]interface{}); ok && len(values) == 2 {
+		flags, _ = values[1].(string)
+	}
+	if strings.Contains(flags, "E") && (strings.Contains(flags, "x") || strings.Contains(flags, "A")) {
+		return
+	}
+
+	_, err = h.Do("CONFIG", "SET", "notify-keyspace-events", flags+"Ex")
+	require.NoError(h.t, err, "Не удалось включить уведомления об истечении ключей")
+}
+
+// SetSession записывает ключ сессии приложения водителя, истекающий через ttl
+func (h *RedisHelper) SetSession(driverID uuid.UUID, ttl time.Duration) {
+	_, err := h.Do("SET", TestSessionKeyPrefix+driverID.String(), "1", "PX", strconv.FormatInt(ttl.Milliseconds(), 10))
+	require.NoError(h.t, err)
+}
+
+// DeleteSession удаляет ключ сессии водителя (выход из приложения, а не истечение)
+func (h *RedisHelper) DeleteSession(driverID uuid.UUID) {
+	_, err := h.Do("DEL", TestSessionKeyPrefix+driverID.String())
+	require.NoError(h.t, err)
+}
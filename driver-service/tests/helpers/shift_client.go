@@ -0,0 +1,53 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"net/http"
+
+	"driver-service/internal/domain/entities"
+
+	"github.com/google/uuid"
+)
+
+// StartShift начинает смену водителя через API
+func (h *APITestHelper) StartShift(driverID uuid.UUID) (*entities.ShiftResponse, *APIResponse) {
+	return h.shiftRequest(http.MethodPost, driverID, "/start", http.StatusCreated)
+}
+
+// PauseShift приостанавливает смену водителя (перерыв) через API
+func (h *APITestHelper) PauseShift(driverID uuid.UUID) (*entities.ShiftResponse, *APIResponse) {
+	return h.shiftRequest(http.MethodPost, driverID, "/pause", http.StatusOK)
+}
+
+// ResumeShift возобновляет смену водителя после перерыва через API
+func (h *APITestHelper) ResumeShift(driverID uuid.UUID) (*entities.ShiftResponse, *APIResponse) {
+	return h.shiftRequest(http.MethodPost, driverID, "/resume", http.StatusOK)
+}
+
+// EndShift завершает смену водителя через API
+func (h *APITestHelper) EndShift(driverID uuid.UUID) (*entities.ShiftResponse, *APIResponse) {
+	return h.shiftRequest(http.MethodPost, driverID, "/end", http.StatusOK)
+}
+
+// GetCurrentShift запрашивает текущую смену водителя через API
+func (h *APITestHelper) GetCurrentShift(driverID uuid.UUID) (*entities.ShiftResponse, *APIResponse) {
+	return h.shiftRequest(http.MethodGet, driverID, "/current", http.StatusOK)
+}
+
+// shiftRequest выполняет запрос к API смен и разбирает ответ при ожидаемом статусе
+func (h *APITestHelper) shiftRequest(method string, driverID uuid.UUID, action string, expectedStatus int) (*entities.ShiftResponse, *APIResponse) {
+	response := h.MakeRequest(APIRequest{
+		Method: method,
+		URL:    h.APIPath(fmt.Sprintf("/drivers/%s/shifts%s", driverID, action)),
+	})
+
+	if response.StatusCode != expectedStatus {
+		return nil, response
+	}
+
+	var shift entities.ShiftResponse
+	h.UnmarshalResponse(response, &shift)
+	return &shift, response
+}
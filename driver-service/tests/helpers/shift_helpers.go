@@ -0,0 +1,51 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// InsertShift сохраняет смену напрямую в тестовую БД (репозиторий смен поддерживает только операции фоновых задач)
+func (tdb *TestDB) InsertShift(t *testing.T, shift *entities.DriverShift) {
+	query := `
+		INSERT INTO driver_shifts (
+			id, driver_id, vehicle_id, start_time, end_time, status,
+			start_latitude, start_longitude, end_latitude, end_longitude,
+			total_trips, total_distance, total_earnings, metadata, created_at, updated_at
+		) VALUES (
+			:id, :driver_id, :vehicle_id, :start_time, :end_time, :status,
+			:start_latitude, :start_longitude, :end_latitude, :end_longitude,
+			:total_trips, :total_distance, :total_earnings, :metadata, :created_at, :updated_at
+		)`
+
+	_, err := tdb.NamedExecContext(context.Background(), query, shift)
+	require.NoError(t, err)
+}
+
+// GetShift читает смену из тестовой БД
+func (tdb *TestDB) GetShift(t *testing.T, shiftID uuid.UUID) *entities.DriverShift {
+	var stored entities.DriverShift
+	err := tdb.GetContext(context.Background(), &stored, "SELECT * FROM driver_shifts WHERE id = $1", shiftID)
+	require.NoError(t, err)
+	return &stored
+}
+
+// UpdateShift сохраняет изменения статуса, итогов и метаданных смены в тестовой БД
+func (tdb *TestDB) UpdateShift(t *testing.T, shift *entities.DriverShift) {
+	query := `
+		UPDATE driver_shifts SET
+			end_time = :end_time, status = :status,
+			total_trips = :total_trips, total_distance = :total_distance, total_earnings = :total_earnings,
+			metadata = :metadata, updated_at = :updated_at
+		WHERE id = :id`
+
+	_, err := tdb.NamedExecContext(context.Background(), query, shift)
+	require.NoError(t, err)
+}
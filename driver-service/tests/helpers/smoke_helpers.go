@@ -0,0 +1,69 @@
+//go:build integration
+
+package helpers
+
+import (
+	"os"
+	"strconv"
+	"testing"
+	"time"
+)
+
+// SLOBreachMarker префикс сообщения о нарушении SLO; по нему run-tests.sh отличает
+// нарушение SLO от функциональной ошибки
+const SLOBreachMarker = "SLO breach"
+
+// SmokeSLO пороги времени ответа для smoke-проверки после деплоя
+type SmokeSLO struct {
+	Health         time.Duration
+	CreateDriver   time.Duration
+	UpdateLocation time.Duration
+	NearbySearch   time.Duration
+	EventRoundtrip time.Duration
+	Total          time.Duration
+}
+
+// DefaultSmokeSLO возвращает пороги по умолчанию
+func DefaultSmokeSLO() SmokeSLO {
+	return SmokeSLO{
+		Health:         200 * time.Millisecond,
+		CreateDriver:   500 * time.Millisecond,
+		UpdateLocation: 300 * time.Millisecond,
+		NearbySearch:   500 * time.Millisecond,
+		EventRoundtrip: time.Second,
+		Total:          60 * time.Second,
+	}
+}
+
+// LoadSmokeSLO возвращает пороги по умолчанию, умноженные на SMOKE_SLO_MULTIPLIER (для медленных окружений).
+// Общий бюджет в 60 секунд не масштабируется.
+func LoadSmokeSLO() SmokeSLO {
+	slo := DefaultSmokeSLO()
+
+	multiplier, err := strconv.ParseFloat(os.Getenv("SMOKE_SLO_MULTIPLIER"), 64)
+	if err != nil || multiplier <= 0 {
+		return slo
+	}
+
+	scale := func(d time.Duration) time.Duration {
+		return time.Duration(float64(d) * multiplier)
+	}
+	slo.Health = scale(slo.Health)
+	slo.CreateDriver = scale(slo.CreateDriver)
+	slo.UpdateLocation = scale(slo.UpdateLocation)
+	slo.NearbySearch = scale(slo.NearbySearch)
+	slo.EventRoundtrip = scale(slo.EventRoundtrip)
+
+	return slo
+}
+
+// AssertWithinSLO проверяет, что шаг уложился в порог
+func AssertWithinSLO(t *testing.T, step string, elapsed, limit time.Duration) {
+	t.Helper()
+
+	if elapsed > limit {
+		t.Errorf("%s: %s took %v, limit %v", SLOBreachMarker, step, elapsed, limit)
+		return
+	}
+	t.Logf("%s: %v (limit %v)", step, elapsed, limit)
+}
@@ -0,0 +1,110 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// UpdateSnapshotsEnv переменная окружения, при UPDATE_SNAPSHOTS=1 снимки перезаписываются фактическими ответами
+const UpdateSnapshotsEnv = "UPDATE_SNAPSHOTS"
+
+// Заглушки, которыми заменяются изменчивые поля в снимках
+const (
+	RedactedUUID      = "[uuid]"
+	RedactedTimestamp = "[timestamp]"
+)
+
+// DefaultSnapshotRedactions изменчивые поля ответов API: идентификаторы и серверные метки времени.
+// Поля из запроса (birth_date, license_expiry) не маскируются, так как они стабильны.
+var DefaultSnapshotRedactions = map[string]string{
+	"id":          RedactedUUID,
+	"driver_id":   RedactedUUID,
+	"created_at":  RedactedTimestamp,
+	"updated_at":  RedactedTimestamp,
+	"recorded_at": RedactedTimestamp,
+}
+
+// AssertSnapshot сравнивает JSON ответа со снимком tests/testdata/snapshots/<name>.json.
+// Изменчивые поля заменяются заглушками на любой глубине вложенности, ключи сортируются.
+// Если снимка нет или он отличается, фактический результат сохраняется в <name>.json.new для ревью;
+// с UPDATE_SNAPSHOTS=1 снимок перезаписывается.
+func AssertSnapshot(t *testing.T, name string, body []byte, redactions map[string]string) {
+	t.Helper()
+
+	if redactions == nil {
+		redactions = DefaultSnapshotRedactions
+	}
+
+	var value interface{}
+	require.NoError(t, json.Unmarshal(body, &value), "Ответ не является JSON: %s", string(body))
+	actual := renderSnapshot(t, redactValue(value, redactions))
+
+	path := TestDataPath(filepath.Join("snapshots", name+".json"))
+	pending := path + ".new"
+
+	if os.Getenv(UpdateSnapshotsEnv) == "1" {
+		require.NoError(t, os.MkdirAll(filepath.Dir(path), 0o755))
+		require.NoError(t, os.WriteFile(path, actual, 0o644))
+		_ = os.Remove(pending)
+		t.Logf("Snapshot %s updated", name)
+		return
+	}
+
+	expected, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		require.NoError(t, os.WriteFile(pending, actual, 0o644))
+		t.Fatalf("Снимок %s отсутствует: результат сохранен в %s, проверьте и переименуйте его или запустите с %s=1",
+			name, pending, UpdateSnapshotsEnv)
+	}
+	require.NoError(t, err)
+
+	if bytes.Equal(expected, actual) {
+		_ = os.Remove(pending)
+		return
+	}
+
+	require.NoError(t, os.WriteFile(pending, actual, 0o644))
+	assert.Equal(t, string(expected), string(actual),
+		"Снимок %s изменился: новый результат сохранен в %s. Если изменение ожидаемо, запустите с %s=1",
+		name, pending, UpdateSnapshotsEnv)
+}
+
+// redactValue рекурсивно заменяет значения изменчивых полей заглушками
+func redactValue(value interface{}, redactions map[string]string) interface{} {
+	switch v := value.(type) {
+	case map[string]interface{}:
+		for key, field := range v {
+			if placeholder, ok := redactions[key]; ok && field != nil {
+				v[key] = placeholder
+				continue
+			}
+			v[key] = redactValue(field, redactions)
+		}
+		return v
+	case []interface{}:
+		for i, item := range v {
+			v[i] = redactValue(item, redactions)
+		}
+		return v
+	default:
+		return v
+	}
+}
+
+// renderSnapshot сериализует значение в стабильный вид: отступы, отсортированные ключи, без HTML-экранирования
+func renderSnapshot(t *testing.T, value interface{}) []byte {
+	var buf bytes.Buffer
+	encoder := json.NewEncoder(&buf)
+	encoder.SetEscapeHTML(false)
+	encoder.SetIndent("", "  ")
+	require.NoError(t, encoder.Encode(value))
+	return buf.Bytes()
+}
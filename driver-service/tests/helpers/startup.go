@@ -0,0 +1,121 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/infrastructure/database"
+	"driver-service/internal/infrastructure/nats"
+	"driver-service/internal/infrastructure/redis"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+// TestStartupRetryInterval пауза между попытками подключения к БД и NATS при запуске сервиса раньше зависимостей
+const TestStartupRetryInterval = 100 * time.Millisecond
+
+// LateStart сервис окружения, запущенный раньше своих зависимостей: подключение к БД, подписки на Redis и NATS
+// и проверки готовности идут через прокси Chaos, все зависимости которых изначально недоступны
+type LateStart struct {
+	Chaos *DependencyChaos
+
+	connected chan struct{}
+	db        *database.DB
+	dbErr     error
+
+	subscribed map[string]<-chan struct{} // имя зависимости -> подписка сервиса на нее
+}
+
+// StartBeforeDependencies запускает сервис окружения до зависимостей, как при одновременном старте контейнеров:
+// все прокси NewDependencyChaos отключены, а сервис в фоне подключается к БД (database.ConnectWithRetry),
+// подписывается на истечение сессий в Redis и события в NATS и отвечает на /health/live и /health/ready.
+// Зависимости возвращает тест через Chaos.Restore. Вызывается один раз для окружения; сервис работает до конца t.
+func (env *TestEnvironment) StartBeforeDependencies(t *testing.T) *LateStart {
+	s := &LateStart{
+		Chaos:      env.NewDependencyChaos(t),
+		connected:  make(chan struct{}),
+		subscribed: make(map[string]<-chan struct{}),
+	}
+	s.Chaos.CutAll()
+	env.registerHealthChecks(t, s.Chaos)
+
+	ctx, cancel := context.WithCancel(context.Background())
+	var wg sync.WaitGroup
+	t.Cleanup(func() {
+		cancel()
+		wg.Wait()
+		if s.db != nil {
+			s.db.Close()
+		}
+	})
+
+	dbConfig := s.Chaos.Database
+	dbConfig.ConnectRetryInterval = TestStartupRetryInterval
+	wg.Add(1)
+	go func() {
+		defer wg.Done()
+		defer close(s.connected)
+		s.db, s.dbErr = database.ConnectWithRetry(ctx, &dbConfig, env.Logger)
+	}()
+
+	if s.Chaos.Redis != nil {
+		redisConfig := *s.Chaos.Redis
+		redisConfig.SessionKeyPrefix = TestSessionKeyPrefix
+		listener := redis.NewSessionExpiryListener(redisConfig, func(ctx context.Context, driverID uuid.UUID) {
+			if err := env.Heartbeats.ExpireSession(ctx, driverID); err != nil {
+				env.Logger.Error("Failed to expire driver session", zap.Error(err), zap.String("driver_id", driverID.String()))
+			}
+		}, env.Logger)
+		s.subscribed["redis"] = listener.Subscribed()
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			listener.Run(ctx)
+		}()
+	}
+
+	if s.Chaos.NATS != nil {
+		natsConfig := *s.Chaos.NATS
+		natsConfig.ConnectTimeout = 2 * time.Second
+		natsConfig.ReconnectDelay = TestStartupRetryInterval
+		subscriber := nats.NewSubscriber(natsConfig, env.Logger)
+		nats.RegisterOrderHandlers(subscriber, env.OrderEvents, env.Notifications)
+		s.subscribed["nats"] = subscriber.Subscribed()
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			subscriber.Run(ctx)
+		}()
+	}
+
+	return s
+}
+
+// Connected закрывается, когда сервис подключился к БД
+func (s *LateStart) Connected() <-chan struct{} {
+	return s.connected
+}
+
+// DB возвращает подключение сервиса к БД; тест завершается, если сервис еще не подключился или сдался
+func (s *LateStart) DB(t *testing.T) *database.DB {
+	select {
+	case <-s.connected:
+	default:
+		t.Fatalf("Сервис еще не подключился к БД")
+	}
+	require.NoError(t, s.dbErr, "Сервис не подключился к БД")
+	return s.db
+}
+
+// Subscribed возвращает канал, закрываемый после подписки сервиса на зависимость name (redis или nats)
+func (s *LateStart) Subscribed(t *testing.T, name string) <-chan struct{} {
+	subscribed, ok := s.subscribed[name]
+	require.True(t, ok, "Сервис не подписывается на %q", name)
+	return subscribed
+}
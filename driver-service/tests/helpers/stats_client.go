@@ -0,0 +1,40 @@
+//go:build integration
+
+package helpers
+
+import (
+	"fmt"
+	"net/http"
+	"time"
+
+	"github.com/google/uuid"
+)
+
+// DriverStats сводная статистика водителя
+type DriverStats struct {
+	DriverID      uuid.UUID `json:"driver_id" db:"driver_id"`
+	TotalTrips    int       `json:"total_trips" db:"total_trips"`
+	AverageRating float64   `json:"average_rating" db:"average_rating"`
+	TotalRatings  int       `json:"total_ratings" db:"total_ratings"`
+	DistanceKm    float64   `json:"distance_km" db:"distance_km"`
+}
+
+// GetDriverStats запрашивает статистику водителя через API (пройденное расстояние - за период)
+func (h *APITestHelper) GetDriverStats(driverID uuid.UUID, from, to time.Time) (*DriverStats, *APIResponse) {
+	response := h.MakeRequest(APIRequest{
+		Method: http.MethodGet,
+		URL:    h.APIPath(fmt.Sprintf("/drivers/%s/stats", driverID)),
+		QueryParams: map[string]string{
+			"from": from.UTC().Format(time.RFC3339),
+			"to":   to.UTC().Format(time.RFC3339),
+		},
+	})
+
+	if response.StatusCode != http.StatusOK {
+		return nil, response
+	}
+
+	var stats DriverStats
+	h.UnmarshalResponse(response, &stats)
+	return &stats, response
+}
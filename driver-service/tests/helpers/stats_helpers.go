@@ -0,0 +1,52 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// ComputeDriverStats вычисляет статистику водителя напрямую из исходных таблиц, минуя счетчики в drivers:
+// поездки - по заказам, отслеживание которых начиналось по местоположению водителя, рейтинг - по оценкам,
+// расстояние - по формуле гаверсинусов между соседними точками за период [from, to]
+func (tdb *TestDB) ComputeDriverStats(t *testing.T, driverID uuid.UUID, from, to time.Time) *DriverStats {
+	ctx := context.Background()
+	stats := &DriverStats{DriverID: driverID}
+
+	err := tdb.GetContext(ctx, &stats.TotalTrips, `
+		SELECT COUNT(DISTINCT metadata->>'order_id')
+		FROM driver_locations
+		WHERE driver_id = $1 AND metadata->>'order_id' IS NOT NULL`, driverID)
+	require.NoError(t, err)
+
+	err = tdb.QueryRowxContext(ctx, `
+		SELECT COALESCE(AVG(rating), 0), COUNT(*)
+		FROM driver_ratings
+		WHERE driver_id = $1`, driverID).Scan(&stats.AverageRating, &stats.TotalRatings)
+	require.NoError(t, err)
+
+	err = tdb.GetContext(ctx, &stats.DistanceKm, `
+		WITH points AS (
+			SELECT latitude, longitude,
+				LAG(latitude) OVER w AS prev_latitude,
+				LAG(longitude) OVER w AS prev_longitude
+			FROM driver_locations
+			WHERE driver_id = $1 AND recorded_at BETWEEN $2 AND $3
+			WINDOW w AS (ORDER BY recorded_at)
+		)
+		SELECT COALESCE(SUM(2 * 6371.0 * ASIN(SQRT(
+			POWER(SIN(RADIANS(latitude - prev_latitude) / 2), 2) +
+			COS(RADIANS(prev_latitude)) * COS(RADIANS(latitude)) *
+			POWER(SIN(RADIANS(longitude - prev_longitude) / 2), 2)
+		))), 0)
+		FROM points
+		WHERE prev_latitude IS NOT NULL`, driverID, from, to)
+	require.NoError(t, err)
+
+	return stats
+}
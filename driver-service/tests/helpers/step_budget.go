@@ -0,0 +1,77 @@
+//go:build integration
+
+package helpers
+
+import (
+	"context"
+	"testing"
+	"time"
+)
+
+// propagationPollInterval интервал опроса при измерении распространения изменения:
+// мельче conditionPollInterval, чтобы бюджеты в сотни миллисекунд измерялись точно
+const propagationPollInterval = 10 * time.Millisecond
+
+// propagationGrace сколько продолжать опрос после истечения бюджета, чтобы в сообщении
+// о нарушении была фактическая задержка, а не только факт превышения
+const propagationGrace = 5 * time.Second
+
+// PropagationBudget бюджет задержки шага сценария: за Within после действия шага изменение
+// должно стать видно в другой части API (например, смена статуса - в списке активных водителей)
+type PropagationBudget struct {
+	Within  time.Duration
+	Visible func(ctx context.Context) (bool, error)
+}
+
+// AssertPropagatedWithin опрашивает Visible и проверяет, что изменение стало видно в пределах бюджета.
+// Возвращает измеренную задержку. Нарушение бюджета помечается SLOBreachMarker, как и нарушение SLO.
+func AssertPropagatedWithin(t *testing.T, step string, budget PropagationBudget) time.Duration {
+	t.Helper()
+
+	ctx, cancel := context.WithTimeout(context.Background(), budget.Within+propagationGrace)
+	defer cancel()
+
+	started := time.Now()
+	ticker := time.NewTicker(propagationPollInterval)
+	defer ticker.Stop()
+
+	var lastErr error
+	for {
+		visible, err := budget.Visible(ctx)
+		if err == nil && visible {
+			elapsed := time.Since(started)
+			AssertWithinSLO(t, step+" propagation", elapsed, budget.Within)
+			return elapsed
+		}
+		if err != nil {
+			lastErr = err
+		}
+
+		select {
+		case <-ticker.C:
+		case <-ctx.Done():
+			elapsed := time.Since(started)
+			t.Errorf("%s: %s not propagated within %v, budget %v (last error: %v)",
+				SLOBreachMarker, step, elapsed.Round(time.Millisecond), budget.Within, lastErr)
+			return elapsed
+		}
+	}
+}
+
+// StepWithin выполняет шаг сценария и проверяет, что его результат распространился в пределах бюджета.
+// Бюджет и измеренная задержка сохраняются в атрибутах span'а шага.
+func (s *TestSpan) StepWithin(t *testing.T, name string, budget PropagationBudget, act func()) time.Duration {
+	t.Helper()
+
+	var propagation time.Duration
+	s.step(name, func() {
+		act()
+		propagation = AssertPropagatedWithin(t, name, budget)
+	}, func() []otlpAttribute {
+		return []otlpAttribute{
+			intAttribute("step.budget_ms", int(budget.Within.Milliseconds())),
+			intAttribute("step.propagation_ms", int(propagation.Milliseconds())),
+		}
+	})
+	return propagation
+}
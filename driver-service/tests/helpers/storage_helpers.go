@@ -0,0 +1,113 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"context"
+	"io"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/infrastructure/storage"
+	"driver-service/tests/fixtures"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения тестового объектного хранилища (MinIO из docker-compose.test.yml)
+const (
+	TestS3EndpointEnv  = "TEST_S3_ENDPOINT"
+	TestS3AccessKeyEnv = "TEST_S3_ACCESS_KEY"
+	TestS3SecretKeyEnv = "TEST_S3_SECRET_KEY"
+	TestS3BucketEnv    = "TEST_S3_BUCKET"
+)
+
+// TestUploadURLExpiry срок действия ссылок на загрузку документов в тестах
+const TestUploadURLExpiry = 15 * time.Minute
+
+// testStorageReadyTimeout сколько ждать готовности MinIO, прежде чем пропустить тесты хранилища
+const testStorageReadyTimeout = 15 * time.Second
+
+// EnableStorage подключает окружение к тестовому MinIO: создает бакет документов и DocumentUploadService.
+// Если хранилище недоступно, тест пропускается - MinIO нужен только тестам загрузки документов.
+func (env *TestEnvironment) EnableStorage(t *testing.T) {
+	t.Helper()
+
+	cfg := config.S3Config{
+		Endpoint:        getEnvOrDefault(TestS3EndpointEnv, "http://localhost:9010"),
+		AccessKeyID:     getEnvOrDefault(TestS3AccessKeyEnv, "minioadmin"),
+		SecretAccessKey: getEnvOrDefault(TestS3SecretKeyEnv, "minioadmin"),
+		BucketName:      getEnvOrDefault(TestS3BucketEnv, "driver-documents-test"),
+		Region:          "us-east-1",
+		UploadURLExpiry: TestUploadURLExpiry,
+	}
+
+	client, err := storage.NewS3Client(cfg)
+	require.NoError(t, err)
+
+	// Ждем готовности MinIO: контейнер из docker-compose.test.yml может еще запускаться
+	deadline := time.Now().Add(testStorageReadyTimeout)
+	for {
+		ctx, cancel := context.WithTimeout(context.Background(), 2*time.Second)
+		err = client.EnsureBucket(ctx)
+		cancel()
+
+		if err == nil {
+			break
+		}
+		if time.Now().After(deadline) {
+			t.Skipf("Объектное хранилище %s недоступно: %v", cfg.Endpoint, err)
+		}
+		time.Sleep(conditionPollInterval)
+	}
+
+	env.Storage = client
+	env.DocumentUploads = services.NewDocumentUploadService(env.DriverRepo, env.DocumentRepo, client, TestUploadURLExpiry, env.Logger)
+}
+
+// UploadFile загружает файл по ссылке из UploadTicket так, как это делает мобильное приложение водителя,
+// и возвращает код и тело ответа хранилища
+func UploadFile(t *testing.T, uploadURL, contentType string, content []byte) (int, string) {
+	t.Helper()
+
+	req, err := http.NewRequest(http.MethodPut, uploadURL, bytes.NewReader(content))
+	require.NoError(t, err)
+	req.Header.Set("Content-Type", contentType)
+
+	resp, err := http.DefaultClient.Do(req)
+	require.NoError(t, err)
+	defer resp.Body.Close()
+
+	body, _ := io.ReadAll(resp.Body)
+	return resp.StatusCode, string(body)
+}
+
+// ScanDocument имитирует антивирусный сканер: скачивает файл документа из хранилища, ищет сигнатуру EICAR
+// и записывает результат через DocumentUploadService. Ошибка скачивания считается сбоем проверки.
+func (env *TestEnvironment) ScanDocument(t *testing.T, documentID uuid.UUID) *entities.DriverDocument {
+	t.Helper()
+
+	document, err := env.DocumentRepo.GetByID(env.Ctx, documentID)
+	require.NoError(t, err)
+	object, ok := document.Object()
+	require.True(t, ok, "Документ %s не ссылается на файл в хранилище", documentID)
+
+	status := entities.ScanStatusClean
+	content, err := env.Storage.GetObject(env.Ctx, object.Key)
+	switch {
+	case err != nil:
+		status = entities.ScanStatusFailed
+	case bytes.Contains(content, []byte(fixtures.EICARSignature)):
+		status = entities.ScanStatusInfected
+	}
+
+	scanned, err := env.DocumentUploads.RecordScanResult(env.Ctx, documentID, status)
+	require.NoError(t, err)
+	return scanned
+}
@@ -17,9 +17,13 @@ import (
 	"github.com/golang-migrate/migrate/v4/database/postgres"
 	_ "github.com/golang-migrate/migrate/v4/source/file"
 	"go.uber.org/zap"
+	"go.uber.org/zap/zapcore"
 	"go.uber.org/zap/zaptest"
 )
 
+// testDBReadyTimeout сколько SetupTestDB ждет готовности PostgreSQL
+const testDBReadyTimeout = 60 * time.Second
+
 // TestDB структура для тестовой базы данных
 type TestDB struct {
 	*database.DB
@@ -28,16 +32,30 @@ type TestDB struct {
 }
 
 // SetupTestDB создает тестовую базу данных
-func SetupTestDB(t *testing.T) *TestDB {
+func SetupTestDB(t testing.TB) *TestDB {
+	// Создаем уникальное имя для тестовой БД
+	testDBName := fmt.Sprintf("test_%s_%d", t.Name(), time.Now().Unix())
+	testDB := createTestDB(t, sanitizeDBName(testDBName))
+
+	// Выполняем миграции
+	if err := runTestMigrations(testDB.DB.DB.DB, testDB.logger); err != nil {
+		t.Fatalf("Failed to run test migrations: %v", err)
+	}
+
+	testDB.logger.Info("Test database created",
+		zap.String("db_name", testDB.dbName),
+	)
+
+	return testDB
+}
+
+// createTestDB создает пустую базу данных testDBName и подключается к ней
+func createTestDB(t testing.TB, testDBName string) *TestDB {
 	logger := zaptest.NewLogger(t)
 
 	// Получаем конфигурацию для тестов
 	cfg := getTestConfig()
 
-	// Создаем уникальное имя для тестовой БД
-	testDBName := fmt.Sprintf("test_%s_%d", t.Name(), time.Now().Unix())
-	testDBName = sanitizeDBName(testDBName)
-
 	// Подключаемся к основной БД для создания тестовой
 	mainDB, err := sql.Open("postgres", fmt.Sprintf(
 		"host=%s port=%d user=%s password=%s dbname=postgres sslmode=%s",
@@ -48,6 +66,9 @@ func SetupTestDB(t *testing.T) *TestDB {
 	}
 	defer mainDB.Close()
 
+	// Ждем готовности PostgreSQL: контейнер из docker-compose.test.yml может еще запускаться
+	WaitForDB(t, mainDB, testDBReadyTimeout)
+
 	// Создаем тестовую базу данных
 	_, err = mainDB.Exec(fmt.Sprintf("CREATE DATABASE %s", testDBName))
 	if err != nil {
@@ -61,24 +82,17 @@ func SetupTestDB(t *testing.T) *TestDB {
 		t.Fatalf("Failed to connect to test database: %v", err)
 	}
 
-	// Выполняем миграции
-	if err := runTestMigrations(testDB.DB.DB, logger); err != nil {
-		t.Fatalf("Failed to run test migrations: %v", err)
-	}
-
-	logger.Info("Test database created",
-		zap.String("db_name", testDBName),
-	)
-
-	return &TestDB{
+	tdb := &TestDB{
 		DB:     testDB,
 		dbName: testDBName,
 		logger: logger,
 	}
+	recordPostgresVersion(tdb)
+	return tdb
 }
 
 // TeardownTestDB удаляет тестовую базу данных
-func (tdb *TestDB) TeardownTestDB(t *testing.T) {
+func (tdb *TestDB) TeardownTestDB(t testing.TB) {
 	if tdb == nil {
 		return
 	}
@@ -120,14 +134,18 @@ func (tdb *TestDB) TeardownTestDB(t *testing.T) {
 }
 
 // CleanupTables очищает все таблицы в тестовой БД
-func (tdb *TestDB) CleanupTables(t *testing.T) {
+func (tdb *TestDB) CleanupTables(t testing.TB) {
 	tables := []string{
 		"driver_ratings",
 		"driver_rating_stats",
 		"driver_locations",
 		"driver_shifts",
+		"driver_heartbeats",
+		"driver_payments",
+		"driver_connection_losses",
 		"driver_documents",
 		"drivers",
+		"processed_events",
 	}
 
 	for _, table := range tables {
@@ -169,17 +187,28 @@ func getTestConfig() *config.Config {
 	return cfg
 }
 
-// runTestMigrations выполняет миграции для тестовой БД
-func runTestMigrations(db *sql.DB, logger *zap.Logger) error {
+// testMigrationsDir каталог миграций схемы БД
+const testMigrationsDir = "internal/infrastructure/database/migrations"
+
+// newTestMigrator создает экземпляр migrate поверх открытого подключения к тестовой БД
+func newTestMigrator(db *sql.DB) (*migrate.Migrate, error) {
 	driver, err := postgres.WithInstance(db, &postgres.Config{})
 	if err != nil {
-		return fmt.Errorf("failed to create migration driver: %w", err)
+		return nil, fmt.Errorf("failed to create migration driver: %w", err)
 	}
 
-	migrationsPath := "file://internal/infrastructure/database/migrations"
-	m, err := migrate.NewWithDatabaseInstance(migrationsPath, "postgres", driver)
+	m, err := migrate.NewWithDatabaseInstance("file://"+testMigrationsDir, "postgres", driver)
 	if err != nil {
-		return fmt.Errorf("failed to create migration instance: %w", err)
+		return nil, fmt.Errorf("failed to create migration instance: %w", err)
+	}
+	return m, nil
+}
+
+// runTestMigrations выполняет миграции для тестовой БД
+func runTestMigrations(db *sql.DB, logger *zap.Logger) error {
+	m, err := newTestMigrator(db)
+	if err != nil {
+		return err
 	}
 
 	if err := m.Up(); err != nil && err != migrate.ErrNoChange {
@@ -232,26 +261,20 @@ func sanitizeDBName(name string) string {
 }
 
 // WaitForDB ждет доступности базы данных
-func WaitForDB(db *database.DB, timeout time.Duration) error {
-	ctx, cancel := context.WithTimeout(context.Background(), timeout)
-	defer cancel()
-
-	ticker := time.NewTicker(100 * time.Millisecond)
-	defer ticker.Stop()
-
-	for {
-		select {
-		case <-ctx.Done():
-			return fmt.Errorf("database not ready within %v", timeout)
-		case <-ticker.C:
-			if err := db.Health(); err == nil {
-				return nil
-			}
-		}
-	}
+func WaitForDB(t testing.TB, db *sql.DB, timeout time.Duration) {
+	t.Helper()
+
+	WaitForConditionContext(t, func(ctx context.Context) (bool, error) {
+		return true, db.PingContext(ctx)
+	}, timeout, "database is ready")
 }
 
-// CreateTestLogger создает логгер для тестов
+// CreateTestLogger создает логгер для тестов. HTTP запросы из его логов учитываются в отчете о покрытии эндпоинтов.
 func CreateTestLogger(t *testing.T) *zap.Logger {
-	return zaptest.NewLogger(t, zaptest.Level(zap.DebugLevel))
+	return zaptest.NewLogger(t,
+		zaptest.Level(zap.DebugLevel),
+		zaptest.WrapOptions(zap.WrapCore(func(core zapcore.Core) zapcore.Core {
+			return zapcore.NewTee(core, endpointCoverageCore{})
+		})),
+	)
 }
@@ -0,0 +1,433 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bytes"
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+	"go.uber.org/zap"
+	"go.uber.org/zap/zapcore"
+)
+
+// TestRunIDEnv переменная окружения с идентификатором прогона (например, номером сборки CI)
+const TestRunIDEnv = "TEST_RUN_ID"
+
+// TestArtifactsDirEnv каталог, в который сохраняются логи и история запросов упавших тестов
+const TestArtifactsDirEnv = "TEST_ARTIFACTS_DIR"
+
+var (
+	runIDOnce sync.Once
+	runID     string
+)
+
+// RunID возвращает идентификатор текущего прогона тестов: TEST_RUN_ID или UUID, общий для процесса
+func RunID() string {
+	runIDOnce.Do(func() {
+		runID = os.Getenv(TestRunIDEnv)
+		if runID == "" {
+			runID = uuid.New().String()
+		}
+	})
+	return runID
+}
+
+// RecordedExchange запрос к API и ответ на него, выполненные в рамках теста
+type RecordedExchange struct {
+	RequestID    string
+	Method       string
+	URL          string
+	RequestBody  []byte
+	StatusCode   int
+	ResponseBody []byte
+	Started      time.Time
+	Duration     time.Duration
+
+	// traceSpanID и parentSpanID span'а запроса при экспорте в OpenTelemetry
+	traceSpanID  string
+	parentSpanID string
+}
+
+// TestSpan контекст выполнения одного теста: имя, прогон, перехваченные логи и история запросов к API.
+// Логи и запросы выводятся только для упавшего теста, а не перемешиваются в общем логе.
+type TestSpan struct {
+	RunID    string
+	SpanID   string
+	TestName string
+	Started  time.Time
+	// TraceID трасса теста в OpenTelemetry (см. OTLPEndpointEnv)
+	TraceID string
+
+	mu        sync.Mutex
+	logs      bytes.Buffer
+	exchanges []RecordedExchange
+	sequence  int
+
+	// rootSpanID span теста, currentStep - span выполняемого шага, otlpSpans - завершенные шаги и запросы
+	rootSpanID  string
+	currentStep string
+	otlpSpans   []otlpSpan
+}
+
+// nextRequestID возвращает X-Request-ID очередного запроса, по которому его можно найти в логах сервиса
+func (s *TestSpan) nextRequestID() string {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.sequence++
+	return fmt.Sprintf("%s-%d", s.SpanID, s.sequence)
+}
+
+// startClientCall возвращает span id запроса к API, родительский span (текущий шаг или тест) и заголовок traceparent
+func (s *TestSpan) startClientCall() (spanID, parentSpanID, header string) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	spanID = newOTLPSpanID()
+	parentSpanID = s.currentStep
+	if parentSpanID == "" {
+		parentSpanID = s.rootSpanID
+	}
+	return spanID, parentSpanID, traceparent(s.TraceID, spanID)
+}
+
+// record сохраняет запрос и ответ в историю теста
+func (s *TestSpan) record(exchange RecordedExchange) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.exchanges = append(s.exchanges, exchange)
+
+	if exchange.traceSpanID == "" {
+		return
+	}
+	finished := time.Now()
+	status := otlpStatus{Code: otlpStatusUnset}
+	if exchange.StatusCode >= http.StatusInternalServerError {
+		status = otlpStatus{Code: otlpStatusError, Message: http.StatusText(exchange.StatusCode)}
+	}
+	s.otlpSpans = append(s.otlpSpans, otlpSpan{
+		TraceID:           s.TraceID,
+		SpanID:            exchange.traceSpanID,
+		ParentSpanID:      exchange.parentSpanID,
+		Name:              exchange.Method + " " + exchange.URL,
+		Kind:              otlpSpanKindClient,
+		StartTimeUnixNano: otlpTimestamp(finished.Add(-exchange.Duration)),
+		EndTimeUnixNano:   otlpTimestamp(finished),
+		Attributes: []otlpAttribute{
+			stringAttribute("http.request.method", exchange.Method),
+			stringAttribute("url.full", exchange.URL),
+			intAttribute("http.response.status_code", exchange.StatusCode),
+			stringAttribute("http.request.header.x-request-id", exchange.RequestID),
+		},
+		Status: status,
+	})
+}
+
+// Step выполняет шаг сценария. При экспорте в OpenTelemetry шаг становится дочерним span'ом теста,
+// а запросы к API внутри шага - его дочерними span'ами.
+func (s *TestSpan) Step(name string, fn func()) {
+	s.step(name, fn, nil)
+}
+
+// step выполняет шаг сценария; attributes вызывается после шага и дополняет атрибуты его span'а
+func (s *TestSpan) step(name string, fn func(), attributes func() []otlpAttribute) {
+	s.mu.Lock()
+	previousStep := s.currentStep
+	parentSpanID := previousStep
+	if parentSpanID == "" {
+		parentSpanID = s.rootSpanID
+	}
+	stepSpanID := newOTLPSpanID()
+	s.currentStep = stepSpanID
+	s.mu.Unlock()
+
+	started := time.Now()
+	defer func() {
+		s.mu.Lock()
+		defer s.mu.Unlock()
+
+		s.currentStep = previousStep
+		stepAttributes := []otlpAttribute{stringAttribute("test.name", s.TestName)}
+		if attributes != nil {
+			stepAttributes = append(stepAttributes, attributes()...)
+		}
+		s.otlpSpans = append(s.otlpSpans, otlpSpan{
+			TraceID:           s.TraceID,
+			SpanID:            stepSpanID,
+			ParentSpanID:      parentSpanID,
+			Name:              name,
+			Kind:              otlpSpanKindInternal,
+			StartTimeUnixNano: otlpTimestamp(started),
+			EndTimeUnixNano:   otlpTimestamp(time.Now()),
+			Attributes:        stepAttributes,
+		})
+	}()
+
+	fn()
+}
+
+// writeLog добавляет закодированную запись лога
+func (s *TestSpan) writeLog(line []byte) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.logs.Write(line)
+}
+
+// Exchanges возвращает историю запросов к API
+func (s *TestSpan) Exchanges() []RecordedExchange {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	return append([]RecordedExchange(nil), s.exchanges...)
+}
+
+// Logs возвращает перехваченные логи сервиса
+func (s *TestSpan) Logs() string {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	return s.logs.String()
+}
+
+// Report формирует отчет по тесту: контекст, логи сервиса и история запросов
+func (s *TestSpan) Report() string {
+	var report strings.Builder
+
+	fmt.Fprintf(&report, "test=%s run_id=%s span_id=%s duration=%s\n",
+		s.TestName, s.RunID, s.SpanID, time.Since(s.Started).Round(time.Millisecond))
+	fmt.Fprintf(&report, "environment: %s\n", CurrentRunMetadata().Logfmt())
+
+	report.WriteString("--- captured logs ---\n")
+	report.WriteString(s.Logs())
+
+	report.WriteString("--- API requests ---\n")
+	for _, exchange := range s.Exchanges() {
+		fmt.Fprintf(&report, "%s %s [%s] -> %d (%s)\n",
+			exchange.Method, exchange.URL, exchange.RequestID, exchange.StatusCode, exchange.Duration.Round(time.Microsecond))
+		if len(exchange.RequestBody) > 0 {
+			fmt.Fprintf(&report, "  request:  %s\n", exchange.RequestBody)
+		}
+		if len(exchange.ResponseBody) > 0 {
+			fmt.Fprintf(&report, "  response: %s\n", exchange.ResponseBody)
+		}
+	}
+
+	return report.String()
+}
+
+// StartTestSpan начинает span теста t: логи сервиса перенаправляются в него до завершения теста,
+// а для упавшего теста логи и история запросов прикладываются к его результату
+func StartTestSpan(t *testing.T, capture *LogCapture) *TestSpan {
+	span := &TestSpan{
+		RunID:    RunID(),
+		SpanID:   uuid.New().String(),
+		TestName: t.Name(),
+		Started:  time.Now(),
+		TraceID:  newTraceID(),
+
+		rootSpanID: newOTLPSpanID(),
+	}
+
+	TrackTest(t)
+	if capture != nil {
+		capture.Attach(span)
+	}
+	t.Cleanup(func() {
+		if capture != nil {
+			capture.Detach(span)
+		}
+		span.finish(t)
+	})
+
+	return span
+}
+
+// artifactName заменяет в имени теста символы, недопустимые в имени файла
+var artifactName = regexp.MustCompile(`[^A-Za-z0-9._-]+`)
+
+// finish экспортирует span'ы теста в OpenTelemetry, прикладывает отчет к упавшему тесту и,
+// если задан TEST_ARTIFACTS_DIR, сохраняет его в файл
+func (s *TestSpan) finish(t *testing.T) {
+	s.export(t)
+
+	if !t.Failed() {
+		return
+	}
+
+	report := s.Report()
+	t.Logf("Failure context:\n%s", report)
+
+	dir := os.Getenv(TestArtifactsDirEnv)
+	if dir == "" {
+		return
+	}
+
+	path := filepath.Join(dir, s.RunID, artifactName.ReplaceAllString(s.TestName, "_")+".log")
+	if err := os.MkdirAll(filepath.Dir(path), 0o755); err != nil {
+		t.Logf("Failed to save test report: %v", err)
+		return
+	}
+	if err := os.WriteFile(path, []byte(report), 0o644); err != nil {
+		t.Logf("Failed to save test report: %v", err)
+		return
+	}
+	t.Logf("Test report saved to %s", path)
+}
+
+// export отправляет span теста вместе с его шагами и запросами в коллектор из OTEL_EXPORTER_OTLP_ENDPOINT.
+// Ошибка экспорта не влияет на результат теста.
+func (s *TestSpan) export(t *testing.T) {
+	if !OTLPEnabled() {
+		return
+	}
+
+	result, status := "passed", otlpStatus{Code: otlpStatusOK}
+	switch {
+	case t.Failed():
+		result, status = "failed", otlpStatus{Code: otlpStatusError, Message: "test failed"}
+	case t.Skipped():
+		result = "skipped"
+	}
+
+	s.mu.Lock()
+	spans := append(append([]otlpSpan(nil), s.otlpSpans...), otlpSpan{
+		TraceID:           s.TraceID,
+		SpanID:            s.rootSpanID,
+		Name:              s.TestName,
+		Kind:              otlpSpanKindInternal,
+		StartTimeUnixNano: otlpTimestamp(s.Started),
+		EndTimeUnixNano:   otlpTimestamp(time.Now()),
+		Attributes: []otlpAttribute{
+			stringAttribute("test.name", s.TestName),
+			stringAttribute("test.run_id", s.RunID),
+			stringAttribute("test.span_id", s.SpanID),
+			stringAttribute("test.result", result),
+		},
+		Status: status,
+	})
+	s.mu.Unlock()
+
+	if err := exportOTLPSpans(spans); err != nil {
+		t.Logf("OpenTelemetry export: %v", err)
+		return
+	}
+	t.Logf("Trace %s exported (%d spans)", s.TraceID, len(spans))
+}
+
+// LogCapture ядро zap, перенаправляющее логи сервиса в активный TestSpan.
+// Вне теста записи передаются исходному ядру.
+type LogCapture struct {
+	base   zapcore.Core
+	fields []zapcore.Field
+	state  *captureState
+}
+
+// captureState активный span, общий для всех ядер, порожденных через With
+type captureState struct {
+	mu   sync.Mutex
+	span *TestSpan
+}
+
+// NewLogCapture оборачивает ядро логгера перехватом логов
+func NewLogCapture(logger *zap.Logger) (*zap.Logger, *LogCapture) {
+	capture := &LogCapture{
+		base:  logger.Core(),
+		state: &captureState{},
+	}
+	return zap.New(capture), capture
+}
+
+// Attach направляет логи в span до вызова Detach
+func (c *LogCapture) Attach(span *TestSpan) {
+	c.state.mu.Lock()
+	defer c.state.mu.Unlock()
+
+	c.state.span = span
+}
+
+// Detach прекращает перенаправление логов в span
+func (c *LogCapture) Detach(span *TestSpan) {
+	c.state.mu.Lock()
+	defer c.state.mu.Unlock()
+
+	if c.state.span == span {
+		c.state.span = nil
+	}
+}
+
+// activeSpan возвращает span, в который сейчас перенаправляются логи
+func (c *LogCapture) activeSpan() *TestSpan {
+	c.state.mu.Lock()
+	defer c.state.mu.Unlock()
+
+	return c.state.span
+}
+
+// Enabled реализует zapcore.Core: перехватываются записи всех уровней
+func (c *LogCapture) Enabled(level zapcore.Level) bool {
+	return zapcore.DebugLevel.Enabled(level)
+}
+
+// With реализует zapcore.Core
+func (c *LogCapture) With(fields []zapcore.Field) zapcore.Core {
+	return &LogCapture{
+		base:   c.base.With(fields),
+		fields: append(append([]zapcore.Field(nil), c.fields...), fields...),
+		state:  c.state,
+	}
+}
+
+// Check реализует zapcore.Core
+func (c *LogCapture) Check(entry zapcore.Entry, checked *zapcore.CheckedEntry) *zapcore.CheckedEntry {
+	if c.Enabled(entry.Level) {
+		return checked.AddCore(entry, c)
+	}
+	return checked
+}
+
+// Write реализует zapcore.Core
+func (c *LogCapture) Write(entry zapcore.Entry, fields []zapcore.Field) error {
+	span := c.activeSpan()
+	if span == nil {
+		if !c.base.Enabled(entry.Level) {
+			return nil
+		}
+		return c.base.Write(entry, fields)
+	}
+
+	// Исходное ядро в span не пишется, поэтому запрос учитывается в покрытии эндпоинтов здесь
+	observeRequestLog(entry, append(append([]zapcore.Field(nil), c.fields...), fields...))
+
+	all := make([]zapcore.Field, 0, len(c.fields)+len(fields)+2)
+	all = append(all, zap.String("test", span.TestName), zap.String("span_id", span.SpanID))
+	all = append(all, c.fields...)
+	all = append(all, fields...)
+
+	config := zap.NewProductionEncoderConfig()
+	config.EncodeTime = zapcore.ISO8601TimeEncoder
+	encoder := zapcore.NewJSONEncoder(config)
+	buf, err := encoder.EncodeEntry(entry, all)
+	if err != nil {
+		return err
+	}
+	defer buf.Free()
+
+	span.writeLog(buf.Bytes())
+	return nil
+}
+
+// Sync реализует zapcore.Core
+func (c *LogCapture) Sync() error {
+	return c.base.Sync()
+}
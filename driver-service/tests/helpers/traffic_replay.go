@@ -0,0 +1,344 @@
+//go:build integration
+
+package helpers
+
+import (
+	"bufio"
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// Переменные окружения воспроизведения трафика
+const (
+	// TrafficCaptureEnv путь к записи трафика (.har или .jsonl) вместо образцов из tests/testdata/traffic
+	TrafficCaptureEnv = "TRAFFIC_CAPTURE"
+	// TrafficPacingEnv "original" - воспроизводить с интервалами записи, иначе запросы идут подряд
+	TrafficPacingEnv = "TRAFFIC_REPLAY_PACING"
+	// TrafficSpeedEnv ускорение воспроизведения с интервалами записи (2 - вдвое быстрее)
+	TrafficSpeedEnv = "TRAFFIC_REPLAY_SPEED"
+	// TrafficToleranceEnv допустимое расхождение доли каждого кода ответа с продакшеном (0.05 - 5 п.п.)
+	TrafficToleranceEnv = "TRAFFIC_STATUS_TOLERANCE"
+)
+
+// DefaultTrafficTolerance допустимое расхождение доли кода ответа по умолчанию
+const DefaultTrafficTolerance = 0.05
+
+// replayedHeaderSkip заголовки записи, которые не воспроизводятся: учетные данные и транспортные заголовки
+var replayedHeaderSkip = map[string]bool{
+	"Authorization":     true,
+	"Cookie":            true,
+	"Host":              true,
+	"Content-Length":    true,
+	"Connection":        true,
+	"Transfer-Encoding": true,
+	"Accept-Encoding":   true,
+}
+
+// uuidPattern UUID в пути запроса
+var uuidPattern = regexp.MustCompile(`[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}`)
+
+// CapturedRequest анонимизированный запрос из продакшена и код ответа, который вернул продакшен.
+// Строка JSONL имеет тот же формат; HAR разбирается в него же.
+type CapturedRequest struct {
+	Time    time.Time         `json:"time"`
+	Method  string            `json:"method"`
+	URL     string            `json:"url"`
+	Headers map[string]string `json:"headers,omitempty"`
+	Body    string            `json:"body,omitempty"`
+	Status  int               `json:"status"`
+}
+
+// TrafficCapture запись трафика
+type TrafficCapture struct {
+	Name     string
+	Requests []CapturedRequest
+}
+
+// TrafficCapturePaths возвращает запись из TRAFFIC_CAPTURE или все образцы tests/testdata/<dir>
+func TrafficCapturePaths(t *testing.T, dir string) []string {
+	if path := os.Getenv(TrafficCaptureEnv); path != "" {
+		return []string{path}
+	}
+
+	var paths []string
+	for _, pattern := range []string{"*.har", "*.jsonl"} {
+		matches, err := filepath.Glob(filepath.Join(TestDataPath(dir), pattern))
+		require.NoError(t, err)
+		paths = append(paths, matches...)
+	}
+	sort.Strings(paths)
+	require.NotEmpty(t, paths, "Нет записей трафика в %s", TestDataPath(dir))
+	return paths
+}
+
+// LoadTrafficCapture загружает запись трафика в формате HAR (.har) или JSONL (.jsonl),
+// запросы упорядочиваются по времени
+func LoadTrafficCapture(t *testing.T, path string) TrafficCapture {
+	data, err := os.ReadFile(path)
+	require.NoError(t, err, "Не удалось прочитать %s", path)
+
+	capture := TrafficCapture{Name: filepath.Base(path)}
+	switch strings.ToLower(filepath.Ext(path)) {
+	case ".har":
+		capture.Requests, err = parseHAR(data)
+	case ".jsonl":
+		capture.Requests, err = parseCaptureJSONL(data)
+	default:
+		err = fmt.Errorf("unsupported capture format %q", filepath.Ext(path))
+	}
+	require.NoError(t, err, "Некорректная запись трафика %s", path)
+	require.NotEmpty(t, capture.Requests, "Пустая запись трафика %s", path)
+
+	sort.SliceStable(capture.Requests, func(i, j int) bool {
+		return capture.Requests[i].Time.Before(capture.Requests[j].Time)
+	})
+	return capture
+}
+
+// harFile подмножество формата HAR 1.2, нужное для воспроизведения
+type harFile struct {
+	Log struct {
+		Entries []struct {
+			StartedDateTime time.Time `json:"startedDateTime"`
+			Request         struct {
+				Method  string `json:"method"`
+				URL     string `json:"url"`
+				Headers []struct {
+					Name  string `json:"name"`
+					Value string `json:"value"`
+				} `json:"headers"`
+				PostData *struct {
+					MimeType string `json:"mimeType"`
+					Text     string `json:"text"`
+				} `json:"postData"`
+			} `json:"request"`
+			Response struct {
+				Status int `json:"status"`
+			} `json:"response"`
+		} `json:"entries"`
+	} `json:"log"`
+}
+
+// parseHAR разбирает запись HAR
+func parseHAR(data []byte) ([]CapturedRequest, error) {
+	var har harFile
+	if err := json.Unmarshal(data, &har); err != nil {
+		return nil, err
+	}
+
+	requests := make([]CapturedRequest, 0, len(har.Log.Entries))
+	for _, entry := range har.Log.Entries {
+		request := CapturedRequest{
+			Time:    entry.StartedDateTime,
+			Method:  entry.Request.Method,
+			URL:     entry.Request.URL,
+			Headers: make(map[string]string, len(entry.Request.Headers)),
+			Status:  entry.Response.Status,
+		}
+		for _, header := range entry.Request.Headers {
+			request.Headers[header.Name] = header.Value
+		}
+		if entry.Request.PostData != nil {
+			request.Body = entry.Request.PostData.Text
+			if _, ok := request.Headers["Content-Type"]; !ok && entry.Request.PostData.MimeType != "" {
+				request.Headers["Content-Type"] = entry.Request.PostData.MimeType
+			}
+		}
+		requests = append(requests, request)
+	}
+	return requests, nil
+}
+
+// parseCaptureJSONL разбирает запись JSONL: по одному CapturedRequest в строке, пустые строки пропускаются
+func parseCaptureJSONL(data []byte) ([]CapturedRequest, error) {
+	var requests []CapturedRequest
+
+	scanner := bufio.NewScanner(bytes.NewReader(data))
+	scanner.Buffer(make([]byte, 0, 64*1024), 10*1024*1024)
+	for line := 1; scanner.Scan(); line++ {
+		text := strings.TrimSpace(scanner.Text())
+		if text == "" {
+			continue
+		}
+
+		var request CapturedRequest
+		if err := json.Unmarshal([]byte(text), &request); err != nil {
+			return nil, fmt.Errorf("line %d: %w", line, err)
+		}
+		requests = append(requests, request)
+	}
+	return requests, scanner.Err()
+}
+
+// ReplayOptions параметры воспроизведения
+type ReplayOptions struct {
+	// Pacing воспроизводить с интервалами между запросами из записи, деленными на Speed
+	Pacing bool
+	Speed  float64
+	// MapID заменяет анонимизированный UUID из пути запроса на ID сущности тестового стенда
+	// (например, создает водителя при первом упоминании); nil - пути воспроизводятся как есть
+	MapID func(id string) string
+}
+
+// LoadReplayOptions возвращает параметры воспроизведения из TRAFFIC_REPLAY_PACING и TRAFFIC_REPLAY_SPEED
+func LoadReplayOptions(t *testing.T) ReplayOptions {
+	options := ReplayOptions{
+		Pacing: os.Getenv(TrafficPacingEnv) == "original",
+		Speed:  1,
+	}
+	if value := os.Getenv(TrafficSpeedEnv); value != "" {
+		speed, err := strconv.ParseFloat(value, 64)
+		if err != nil || speed <= 0 {
+			t.Fatalf("Некорректный %s: %q", TrafficSpeedEnv, value)
+		}
+		options.Speed = speed
+	}
+	return options
+}
+
+// LoadTrafficTolerance возвращает допустимое расхождение долей кодов ответа из TRAFFIC_STATUS_TOLERANCE
+func LoadTrafficTolerance(t *testing.T) float64 {
+	value := os.Getenv(TrafficToleranceEnv)
+	if value == "" {
+		return DefaultTrafficTolerance
+	}
+
+	tolerance, err := strconv.ParseFloat(value, 64)
+	if err != nil || tolerance < 0 || tolerance > 1 {
+		t.Fatalf("Некорректный %s: %q", TrafficToleranceEnv, value)
+	}
+	return tolerance
+}
+
+// ReplayMismatch запрос, на который стенд ответил иначе, чем продакшен
+type ReplayMismatch struct {
+	Method     string
+	Path       string
+	Production int
+	Replayed   int
+}
+
+// ReplayResult распределения кодов ответа продакшена и стенда
+type ReplayResult struct {
+	Production map[int]int
+	Replayed   map[int]int
+	Mismatches []ReplayMismatch
+	Duration   time.Duration
+}
+
+// ReplayTraffic воспроизводит запись через API помощника: путь и query берутся из URL записи,
+// учетные данные и транспортные заголовки отбрасываются
+func (h *APITestHelper) ReplayTraffic(capture TrafficCapture, options ReplayOptions) ReplayResult {
+	result := ReplayResult{Production: make(map[int]int), Replayed: make(map[int]int)}
+
+	started := time.Now()
+	for _, captured := range capture.Requests {
+		if options.Pacing {
+			offset := captured.Time.Sub(capture.Requests[0].Time)
+			if wait := time.Duration(float64(offset)/options.Speed) - time.Since(started); wait > 0 {
+				time.Sleep(wait)
+			}
+		}
+
+		target, err := url.Parse(captured.URL)
+		require.NoError(h.t, err, "Некорректный URL в записи: %s", captured.URL)
+		path := target.RequestURI()
+		if options.MapID != nil {
+			path = uuidPattern.ReplaceAllStringFunc(path, options.MapID)
+		}
+
+		request := APIRequest{Method: captured.Method, URL: path, Headers: make(map[string]string)}
+		for name, value := range captured.Headers {
+			if !replayedHeaderSkip[http.CanonicalHeaderKey(name)] {
+				request.Headers[name] = value
+			}
+		}
+		if captured.Body != "" {
+			request.RawBody = []byte(captured.Body)
+		}
+
+		response := h.MakeRequest(request)
+		result.Production[captured.Status]++
+		result.Replayed[response.StatusCode]++
+		if response.StatusCode != captured.Status {
+			result.Mismatches = append(result.Mismatches, ReplayMismatch{
+				Method:     captured.Method,
+				Path:       path,
+				Production: captured.Status,
+				Replayed:   response.StatusCode,
+			})
+		}
+	}
+	result.Duration = time.Since(started)
+
+	return result
+}
+
+// AssertStatusDistribution сравнивает доли кодов ответа стенда и продакшена: расхождение доли любого кода
+// больше tolerance - ошибка. Это сигнал smoke-уровня: отдельные расхождения выводятся для разбора.
+func AssertStatusDistribution(t *testing.T, result ReplayResult, tolerance float64) {
+	t.Helper()
+
+	var productionTotal, replayedTotal int
+	codes := make(map[int]bool)
+	for code, count := range result.Production {
+		productionTotal += count
+		codes[code] = true
+	}
+	for code, count := range result.Replayed {
+		replayedTotal += count
+		codes[code] = true
+	}
+	if productionTotal == 0 {
+		t.Error("Запись трафика пуста")
+		return
+	}
+
+	sorted := make([]int, 0, len(codes))
+	for code := range codes {
+		sorted = append(sorted, code)
+	}
+	sort.Ints(sorted)
+
+	var report strings.Builder
+	failed := false
+	fmt.Fprintf(&report, "%-6s %12s %12s\n", "status", "production", "replayed")
+	for _, code := range sorted {
+		production := float64(result.Production[code]) / float64(productionTotal)
+		replayed := float64(result.Replayed[code]) / float64(replayedTotal)
+		marker := ""
+		if diff := replayed - production; diff > tolerance || -diff > tolerance {
+			marker = "  ✗"
+			failed = true
+		}
+		fmt.Fprintf(&report, "%-6d %11.1f%% %11.1f%%%s\n", code, production*100, replayed*100, marker)
+	}
+
+	for i, mismatch := range result.Mismatches {
+		if i == 10 {
+			fmt.Fprintf(&report, "... и еще %d расхождений\n", len(result.Mismatches)-i)
+			break
+		}
+		fmt.Fprintf(&report, "%s %s: production %d, replayed %d\n",
+			mismatch.Method, mismatch.Path, mismatch.Production, mismatch.Replayed)
+	}
+
+	if failed {
+		t.Errorf("Распределение кодов ответа расходится с продакшеном больше чем на %.1f п.п.:\n%s", tolerance*100, report.String())
+		return
+	}
+	t.Logf("Replayed %d requests in %v:\n%s", replayedTotal, result.Duration.Round(time.Millisecond), report.String())
+}
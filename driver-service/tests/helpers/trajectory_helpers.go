@@ -0,0 +1,83 @@
+//go:build integration
+
+package helpers
+
+import (
+	"testing"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+
+	"github.com/stretchr/testify/assert"
+)
+
+// TrajectoryLimits физические ограничения, которым должен соответствовать трек водителя
+type TrajectoryLimits struct {
+	MaxSpeedKmh   float64 // максимальная скорость между соседними точками
+	MaxJumpKm     float64 // максимальное расстояние между соседними точками
+	MaxAccuracyM  float64 // максимальная погрешность GPS (0 - не проверять)
+	AllowSameTime bool    // допускать одинаковые метки времени соседних точек
+}
+
+// DefaultTrajectoryLimits ограничения для городского такси
+func DefaultTrajectoryLimits() TrajectoryLimits {
+	return TrajectoryLimits{
+		MaxSpeedKmh:  200,
+		MaxJumpKm:    5,
+		MaxAccuracyM: 100,
+	}
+}
+
+// AssertTrajectorySane проверяет историю местоположений, упорядоченную по времени, на физическую правдоподобность
+func AssertTrajectorySane(t *testing.T, locations []*httpHandlers.LocationResponse, limits TrajectoryLimits) {
+	AssertTimestampsIncreasing(t, locations, limits.AllowSameTime)
+	AssertNoTeleports(t, locations, limits.MaxJumpKm)
+	AssertImpliedSpeedBelow(t, locations, limits.MaxSpeedKmh)
+	if limits.MaxAccuracyM > 0 {
+		AssertAccuracyWithin(t, locations, limits.MaxAccuracyM)
+	}
+}
+
+// AssertTimestampsIncreasing проверяет, что метки времени точек строго возрастают
+func AssertTimestampsIncreasing(t *testing.T, locations []*httpHandlers.LocationResponse, allowSameTime bool) {
+	for i := 1; i < len(locations); i++ {
+		prev, curr := locations[i-1].RecordedAt, locations[i].RecordedAt
+		if allowSameTime {
+			assert.False(t, curr.Before(prev), "Точка %d (%s) раньше предыдущей (%s)", i, curr, prev)
+		} else {
+			assert.True(t, curr.After(prev), "Точка %d (%s) не позже предыдущей (%s)", i, curr, prev)
+		}
+	}
+}
+
+// AssertNoTeleports проверяет, что расстояние между соседними точками не превышает maxJumpKm
+func AssertNoTeleports(t *testing.T, locations []*httpHandlers.LocationResponse, maxJumpKm float64) {
+	for i := 1; i < len(locations); i++ {
+		distance := HaversineKm(locations[i-1].Latitude, locations[i-1].Longitude, locations[i].Latitude, locations[i].Longitude)
+		assert.LessOrEqual(t, distance, maxJumpKm, "Скачок %.2f км между точками %d и %d", distance, i-1, i)
+	}
+}
+
+// AssertImpliedSpeedBelow проверяет, что скорость, вычисленная по соседним точкам, не превышает maxSpeedKmh
+func AssertImpliedSpeedBelow(t *testing.T, locations []*httpHandlers.LocationResponse, maxSpeedKmh float64) {
+	for i := 1; i < len(locations); i++ {
+		hours := locations[i].RecordedAt.Sub(locations[i-1].RecordedAt).Hours()
+		if hours <= 0 {
+			continue
+		}
+
+		distance := HaversineKm(locations[i-1].Latitude, locations[i-1].Longitude, locations[i].Latitude, locations[i].Longitude)
+		speed := distance / hours
+		assert.LessOrEqual(t, speed, maxSpeedKmh, "Скорость %.1f км/ч между точками %d и %d", speed, i-1, i)
+	}
+}
+
+// AssertAccuracyWithin проверяет, что погрешность каждой точки не превышает maxAccuracyM
+func AssertAccuracyWithin(t *testing.T, locations []*httpHandlers.LocationResponse, maxAccuracyM float64) {
+	for i, location := range locations {
+		if location.Accuracy == nil {
+			continue
+		}
+		assert.GreaterOrEqual(t, *location.Accuracy, 0.0, "Отрицательная погрешность в точке %d", i)
+		assert.LessOrEqual(t, *location.Accuracy, maxAccuracyM, "Погрешность %.1f м в точке %d", *location.Accuracy, i)
+	}
+}
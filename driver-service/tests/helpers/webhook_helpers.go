@@ -0,0 +1,185 @@
+//go:build integration
+
+package helpers
+
+import (
+	"encoding/json"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"strconv"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/infrastructure/webhooks"
+
+	"github.com/google/uuid"
+)
+
+// TestWebhookConfig параметры доставки webhooks в тестовом окружении: короткие паузы,
+// чтобы повторы и dead letter проверялись за доли секунды
+var TestWebhookConfig = webhooks.Config{
+	MaxAttempts:    4,
+	InitialBackoff: 20 * time.Millisecond,
+	MaxBackoff:     200 * time.Millisecond,
+	Timeout:        2 * time.Second,
+}
+
+// WebhookDelivery запрос доставки, полученный WebhookReceiver
+type WebhookDelivery struct {
+	DeliveryID     string
+	EventType      string
+	Attempt        int
+	Signature      string
+	SignatureValid bool
+	StatusCode     int // код, которым ответил получатель
+	Body           []byte
+	Payload        webhooks.Payload
+	ReceivedAt     time.Time
+}
+
+// DataMap возвращает данные события в виде map
+func (d WebhookDelivery) DataMap() map[string]interface{} {
+	data, _ := d.Payload.Data.(map[string]interface{})
+	return data
+}
+
+// WebhookReceiver встроенный HTTP сервер, принимающий доставки webhooks.
+// Проверяет подпись своим секретом (401 при несовпадении) и может отвечать ошибками по программе.
+type WebhookReceiver struct {
+	server   *httptest.Server
+	secret   string
+	TargetID uuid.UUID
+
+	mu          sync.Mutex
+	deliveries  []WebhookDelivery
+	failures    int // сколько следующих запросов завершить ошибкой; -1 - все
+	failureCode int
+}
+
+// StartWebhookReceiver запускает получатель и регистрирует его адресатом событий eventTypes
+// (пустой список - все события). Получатель снимается с регистрации и останавливается по окончании теста.
+func (env *TestEnvironment) StartWebhookReceiver(t *testing.T, secret string, eventTypes ...string) *WebhookReceiver {
+	receiver := &WebhookReceiver{secret: secret}
+	receiver.server = httptest.NewServer(http.HandlerFunc(receiver.handle))
+
+	receiver.TargetID = env.Webhooks.Register(webhooks.Target{
+		URL:    receiver.server.URL,
+		Secret: secret,
+		Events: eventTypes,
+	})
+
+	t.Cleanup(func() {
+		env.Webhooks.Unregister(receiver.TargetID)
+		receiver.server.Close()
+	})
+	return receiver
+}
+
+// URL возвращает адрес получателя
+func (r *WebhookReceiver) URL() string {
+	return r.server.URL
+}
+
+// RotateSecret меняет секрет проверки подписи на стороне получателя, как если бы партнер
+// сменил секрет, а адресат в сервисе остался со старым
+func (r *WebhookReceiver) RotateSecret(secret string) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.secret = secret
+}
+
+// Close останавливает сервер получателя: следующие доставки завершатся сетевой ошибкой
+func (r *WebhookReceiver) Close() {
+	r.server.Close()
+}
+
+// FailNext отвечает кодом statusCode на следующие count запросов
+func (r *WebhookReceiver) FailNext(count, statusCode int) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	r.failures = count
+	r.failureCode = statusCode
+}
+
+// FailAlways отвечает кодом statusCode на все запросы
+func (r *WebhookReceiver) FailAlways(statusCode int) {
+	r.FailNext(-1, statusCode)
+}
+
+// Deliveries возвращает копию всех полученных запросов, включая отклоненные
+func (r *WebhookReceiver) Deliveries() []WebhookDelivery {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	deliveries := make([]WebhookDelivery, len(r.deliveries))
+	copy(deliveries, r.deliveries)
+	return deliveries
+}
+
+// Accepted возвращает запросы, на которые получатель ответил 2xx
+func (r *WebhookReceiver) Accepted() []WebhookDelivery {
+	var result []WebhookDelivery
+	for _, delivery := range r.Deliveries() {
+		if delivery.StatusCode < 300 {
+			result = append(result, delivery)
+		}
+	}
+	return result
+}
+
+// AttemptsFor возвращает все попытки доставки с указанным ID в порядке получения
+func (r *WebhookReceiver) AttemptsFor(deliveryID string) []WebhookDelivery {
+	var result []WebhookDelivery
+	for _, delivery := range r.Deliveries() {
+		if delivery.DeliveryID == deliveryID {
+			result = append(result, delivery)
+		}
+	}
+	return result
+}
+
+// handle принимает запрос доставки
+func (r *WebhookReceiver) handle(w http.ResponseWriter, req *http.Request) {
+	body, _ := io.ReadAll(req.Body)
+
+	delivery := WebhookDelivery{
+		DeliveryID: req.Header.Get(webhooks.DeliveryHeader),
+		EventType:  req.Header.Get(webhooks.EventHeader),
+		Signature:  req.Header.Get(webhooks.SignatureHeader),
+		Body:       body,
+		ReceivedAt: time.Now(),
+	}
+	delivery.Attempt, _ = strconv.Atoi(req.Header.Get(webhooks.AttemptHeader))
+	_ = json.Unmarshal(body, &delivery.Payload)
+
+	r.mu.Lock()
+	delivery.SignatureValid = webhooks.VerifySignature(r.secret, body, delivery.Signature)
+	switch {
+	case !delivery.SignatureValid:
+		delivery.StatusCode = http.StatusUnauthorized
+	case r.failures != 0:
+		delivery.StatusCode = r.failureCode
+		if r.failures > 0 {
+			r.failures--
+		}
+	default:
+		delivery.StatusCode = http.StatusOK
+	}
+	r.deliveries = append(r.deliveries, delivery)
+	r.mu.Unlock()
+
+	w.WriteHeader(delivery.StatusCode)
+}
+
+// DeadLettersFor возвращает доставки адресата targetID, от которых отказался Publisher
+func (env *TestEnvironment) DeadLettersFor(targetID uuid.UUID) []webhooks.DeadLetter {
+	var result []webhooks.DeadLetter
+	for _, deadLetter := range env.Webhooks.DeadLetters() {
+		if deadLetter.TargetID == targetID {
+			result = append(result, deadLetter)
+		}
+	}
+	return result
+}
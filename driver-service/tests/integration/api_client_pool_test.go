@@ -0,0 +1,192 @@
+//go:build integration
+
+package integration
+
+import (
+	"crypto/x509"
+	"net/http"
+	"net/http/httptest"
+	"sync"
+	"time"
+
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры проверок пула соединений
+const (
+	poolRequests    = 20
+	poolConcurrency = 10
+	poolSlowdown    = 50 * time.Millisecond // задержка ответа, чтобы параллельные запросы шли по разным соединениям
+	healthOperation = "GET /health"
+)
+
+// APIClientPoolTestSuite тестирует настройки пула соединений клиента к развернутому сервису (TEST_HTTP_*)
+// и статистику повторного использования соединений. Сервис окружения поднимается на реальном порту.
+type APIClientPoolTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *APIClientPoolTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *APIClientPoolTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *APIClientPoolTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestConnectionsReused тестирует, что последовательные запросы идут по одному соединению из пула,
+// а установка соединения учитывается отдельно от задержки сервиса
+func (suite *APIClientPoolTestSuite) TestConnectionsReused() {
+	// Arrange
+	server := httptest.NewServer(suite.env.Router)
+	defer server.Close()
+	stats := helpers.NewConnectionStats()
+	api := helpers.NewRemoteAPITestHelper(server.URL, suite.T()).
+		WithHTTPClient(helpers.DefaultHTTPClientConfig()).
+		WithResponseInterceptor(stats.Record)
+
+	// Act
+	for i := 0; i < poolRequests; i++ {
+		response := api.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health"})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+		require.NotNil(suite.T(), response.Connection)
+	}
+
+	// Assert
+	stats.Log(suite.T())
+	summary := stats.Summary(healthOperation)
+	assert.Equal(suite.T(), poolRequests, summary.Requests)
+	assert.Equal(suite.T(), 1, summary.NewConnections)
+	assert.Equal(suite.T(), poolRequests-1, summary.Reused)
+	assert.Positive(suite.T(), summary.SetupTime, "Установка нового соединения учтена")
+	assert.Equal(suite.T(), summary, stats.Total())
+}
+
+// TestIdleTimeoutClosesConnections тестирует, что соединение, простаивающее дольше TEST_HTTP_IDLE_TIMEOUT,
+// закрывается и следующий запрос открывает новое
+func (suite *APIClientPoolTestSuite) TestIdleTimeoutClosesConnections() {
+	// Arrange
+	server := httptest.NewServer(suite.env.Router)
+	defer server.Close()
+	cfg := helpers.DefaultHTTPClientConfig()
+	cfg.IdleConnTimeout = 50 * time.Millisecond
+	stats := helpers.NewConnectionStats()
+	api := helpers.NewRemoteAPITestHelper(server.URL, suite.T()).WithHTTPClient(cfg).WithResponseInterceptor(stats.Record)
+
+	// Act
+	for i := 0; i < 3; i++ {
+		response := api.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health"})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+		time.Sleep(3 * cfg.IdleConnTimeout)
+	}
+
+	// Assert
+	summary := stats.Summary(healthOperation)
+	assert.Equal(suite.T(), 3, summary.NewConnections)
+	assert.Zero(suite.T(), summary.Reused)
+}
+
+// TestMaxIdlePerHost тестирует, что после волны параллельных запросов пул сохраняет не больше
+// TEST_HTTP_MAX_IDLE_PER_HOST соединений, а остальные открываются заново
+func (suite *APIClientPoolTestSuite) TestMaxIdlePerHost() {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		time.Sleep(poolSlowdown)
+		suite.env.Router.ServeHTTP(w, r)
+	}))
+	defer server.Close()
+
+	testCases := []struct {
+		name           string
+		maxIdlePerHost int
+		newInSecond    int // новых соединений во второй волне
+	}{
+		{name: "PoolFitsConcurrency", maxIdlePerHost: poolConcurrency, newInSecond: 0},
+		{name: "PoolSmallerThanConcurrency", maxIdlePerHost: 1, newInSecond: poolConcurrency - 1},
+	}
+
+	for _, tc := range testCases {
+		suite.Run(tc.name, func() {
+			// Arrange
+			cfg := helpers.DefaultHTTPClientConfig()
+			cfg.MaxIdleConnsPerHost = tc.maxIdlePerHost
+			api := helpers.NewRemoteAPITestHelper(server.URL, suite.T()).WithHTTPClient(cfg)
+
+			wave := func() helpers.ConnectionSummary {
+				stats := helpers.NewConnectionStats()
+				client := api.WithResponseInterceptor(stats.Record)
+
+				var wg sync.WaitGroup
+				for i := 0; i < poolConcurrency; i++ {
+					wg.Add(1)
+					go func() {
+						defer wg.Done()
+						client.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health"})
+					}()
+				}
+				wg.Wait()
+				return stats.Summary(healthOperation)
+			}
+
+			// Act
+			first := wave()
+			second := wave()
+
+			// Assert
+			assert.Equal(suite.T(), poolConcurrency, first.NewConnections, "Параллельные запросы открывают свои соединения")
+			assert.Equal(suite.T(), tc.newInSecond, second.NewConnections)
+			assert.Equal(suite.T(), poolConcurrency-tc.newInSecond, second.Reused)
+		})
+	}
+}
+
+// TestHTTP2Toggle тестирует, что TEST_HTTP2 переключает протокол запросов к сервису по TLS
+func (suite *APIClientPoolTestSuite) TestHTTP2Toggle() {
+	server := httptest.NewUnstartedServer(suite.env.Router)
+	server.EnableHTTP2 = true
+	server.StartTLS()
+	defer server.Close()
+
+	roots := x509.NewCertPool()
+	roots.AddCert(server.Certificate())
+
+	for _, tc := range []struct {
+		http2    bool
+		protocol string
+	}{
+		{http2: true, protocol: "HTTP/2.0"},
+		{http2: false, protocol: "HTTP/1.1"},
+	} {
+		suite.Run(tc.protocol, func() {
+			// Arrange
+			cfg := helpers.DefaultHTTPClientConfig()
+			cfg.HTTP2 = tc.http2
+			cfg.RootCAs = roots
+			stats := helpers.NewConnectionStats()
+			api := helpers.NewRemoteAPITestHelper(server.URL, suite.T()).WithHTTPClient(cfg).WithResponseInterceptor(stats.Record)
+
+			// Act
+			response := api.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health"})
+
+			// Assert
+			require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+			assert.Equal(suite.T(), tc.protocol, response.Connection.Protocol)
+			if tc.http2 {
+				assert.Equal(suite.T(), 1, stats.Total().HTTP2)
+			} else {
+				assert.Zero(suite.T(), stats.Total().HTTP2)
+			}
+		})
+	}
+}
@@ -0,0 +1,109 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// APISnapshotTestSuite тестовый suite для снимков (golden files) ответов API.
+// Снимки лежат в tests/testdata/snapshots, обновляются запуском с UPDATE_SNAPSHOTS=1
+// и проверяются на ревью как обычный diff.
+type APISnapshotTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *APISnapshotTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *APISnapshotTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *APISnapshotTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestDriverResponseSnapshots тестирует структуру DriverResponse и ListDriversResponse
+func (suite *APISnapshotTestSuite) TestDriverResponseSnapshots() {
+	// Arrange & Act
+	created := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   helpers.CreateDriverRequest(),
+	})
+	require.Equal(suite.T(), http.StatusCreated, created.StatusCode, string(created.Body))
+	driverID := suite.driverID(created)
+
+	fetched := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath("/drivers/" + driverID),
+	})
+	require.Equal(suite.T(), http.StatusOK, fetched.StatusCode)
+
+	listed := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath("/drivers"),
+	})
+	require.Equal(suite.T(), http.StatusOK, listed.StatusCode)
+
+	// Assert
+	helpers.AssertSnapshot(suite.T(), "driver_create", created.Body, nil)
+	helpers.AssertSnapshot(suite.T(), "driver_get", fetched.Body, nil)
+	helpers.AssertSnapshot(suite.T(), "driver_list", listed.Body, nil)
+}
+
+// TestLocationResponseSnapshots тестирует структуру LocationResponse
+func (suite *APISnapshotTestSuite) TestLocationResponseSnapshots() {
+	// Arrange
+	created := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   helpers.CreateDriverRequest(),
+	})
+	require.Equal(suite.T(), http.StatusCreated, created.StatusCode, string(created.Body))
+	driverID := suite.driverID(created)
+
+	// Act
+	updated := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body:   helpers.CreateLocationRequest(),
+	})
+	require.Equal(suite.T(), http.StatusOK, updated.StatusCode, string(updated.Body))
+
+	current := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations/current", driverID)),
+	})
+	require.Equal(suite.T(), http.StatusOK, current.StatusCode)
+
+	// Assert
+	helpers.AssertSnapshot(suite.T(), "location_update", updated.Body, nil)
+	helpers.AssertSnapshot(suite.T(), "location_current", current.Body, nil)
+}
+
+// driverID извлекает ID водителя из ответа на создание
+func (suite *APISnapshotTestSuite) driverID(response *helpers.APIResponse) string {
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return driver.ID.String()
+}
+
+// TestAPISnapshotTestSuite запускает тестовый suite
+func TestAPISnapshotTestSuite(t *testing.T) {
+	suite.Run(t, new(APISnapshotTestSuite))
+}
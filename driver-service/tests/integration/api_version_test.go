@@ -0,0 +1,171 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Контракты ответов API v1, на которые опираются существующие клиенты.
+// Поля могут добавляться, но не удаляться и не менять тип.
+var (
+	v1DriverContract = helpers.APIContract{
+		"id":              helpers.JSONString,
+		"phone":           helpers.JSONString,
+		"email":           helpers.JSONString,
+		"first_name":      helpers.JSONString,
+		"last_name":       helpers.JSONString,
+		"birth_date":      helpers.JSONString,
+		"passport_series": helpers.JSONString,
+		"passport_number": helpers.JSONString,
+		"license_number":  helpers.JSONString,
+		"license_expiry":  helpers.JSONString,
+		"status":          helpers.JSONString,
+		"current_rating":  helpers.JSONNumber,
+		"total_trips":     helpers.JSONNumber,
+		"created_at":      helpers.JSONString,
+		"updated_at":      helpers.JSONString,
+	}
+
+	v1DriverListContract = helpers.APIContract{
+		"drivers":  helpers.JSONArray,
+		"total":    helpers.JSONNumber,
+		"limit":    helpers.JSONNumber,
+		"offset":   helpers.JSONNumber,
+		"has_more": helpers.JSONBool,
+	}
+
+	v1LocationContract = helpers.APIContract{
+		"id":          helpers.JSONString,
+		"driver_id":   helpers.JSONString,
+		"latitude":    helpers.JSONNumber,
+		"longitude":   helpers.JSONNumber,
+		"recorded_at": helpers.JSONString,
+		"created_at":  helpers.JSONString,
+	}
+)
+
+// APIVersionTestSuite тестовый suite для совместимости версий API
+type APIVersionTestSuite struct {
+	suite.Suite
+	env      *helpers.TestEnvironment
+	sequence int
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *APIVersionTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *APIVersionTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *APIVersionTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestV1DriverContract тестирует стабильность полей и типов ответа с водителем в v1
+func (suite *APIVersionTestSuite) TestV1DriverContract() {
+	// Arrange
+	v1 := suite.env.API.WithAPIVersion("v1")
+	created := suite.createDriver(v1)
+	helpers.AssertJSONContract(suite.T(), created.Body, v1DriverContract)
+
+	// Act
+	response := v1.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: v1.APIPath("/drivers/" + suite.driverID(created))})
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	driver := helpers.AssertJSONContract(suite.T(), response.Body, v1DriverContract)
+
+	_, err := uuid.Parse(driver["id"].(string))
+	assert.NoError(suite.T(), err, "id - UUID")
+	for _, field := range []string{"birth_date", "license_expiry", "created_at", "updated_at"} {
+		_, err := time.Parse(time.RFC3339, driver[field].(string))
+		assert.NoError(suite.T(), err, "%s - RFC3339", field)
+	}
+}
+
+// TestV1ListAndLocationContracts тестирует стабильность ответов со списком водителей и местоположением в v1
+func (suite *APIVersionTestSuite) TestV1ListAndLocationContracts() {
+	// Arrange
+	v1 := suite.env.API.WithAPIVersion("v1")
+	driverID := suite.driverID(suite.createDriver(v1))
+
+	// Act & Assert
+	response := v1.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: v1.APIPath("/drivers")})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	list := helpers.AssertJSONContract(suite.T(), response.Body, v1DriverListContract)
+	require.Len(suite.T(), list["drivers"], 1)
+
+	response = v1.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    v1.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body:   helpers.CreateLocationRequest(),
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	response = v1.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    v1.APIPath(fmt.Sprintf("/drivers/%s/locations/current", driverID)),
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	helpers.AssertJSONContract(suite.T(), response.Body, v1LocationContract)
+}
+
+// TestUnsupportedVersions тестирует, что несуществующие версии API не обслуживаются
+func (suite *APIVersionTestSuite) TestUnsupportedVersions() {
+	for _, version := range []string{"v0", "v2", "v99", "latest"} {
+		suite.T().Run(version, func(t *testing.T) {
+			client := suite.env.API.WithAPIVersion(version)
+
+			response := client.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: client.APIPath("/drivers")})
+
+			assert.Equal(t, http.StatusNotFound, response.StatusCode)
+		})
+	}
+}
+
+// createDriver создает водителя через указанную версию API
+func (suite *APIVersionTestSuite) createDriver(client *helpers.APITestHelper) *helpers.APIResponse {
+	suite.sequence++
+
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7900777%04d", suite.sequence)
+	request["email"] = fmt.Sprintf("version%d@example.com", suite.sequence)
+	request["license_number"] = fmt.Sprintf("VER%06d", suite.sequence)
+
+	response := client.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    client.APIPath("/drivers"),
+		Body:   request,
+	})
+	require.Equal(suite.T(), http.StatusCreated, response.StatusCode, string(response.Body))
+	return response
+}
+
+// driverID извлекает ID водителя из ответа на создание
+func (suite *APIVersionTestSuite) driverID(response *helpers.APIResponse) string {
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return driver.ID.String()
+}
+
+// TestAPIVersionTestSuite запускает тестовый suite
+func TestAPIVersionTestSuite(t *testing.T) {
+	suite.Run(t, new(APIVersionTestSuite))
+}
@@ -0,0 +1,323 @@
+//go:build integration
+
+package integration
+
+import (
+	"bytes"
+	"fmt"
+	"image"
+	_ "image/jpeg"
+	_ "image/png"
+	"net/http"
+	"net/url"
+	"strings"
+	"testing"
+
+	"driver-service/internal/domain/services"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// AvatarUploadTestSuite тестирует загрузку фотографий водителей: multipart форма, ограничения размера и типа,
+// удаление EXIF и доступность фотографии по адресу CDN из ответа
+type AvatarUploadTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных контактов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *AvatarUploadTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.env.EnableAvatars(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *AvatarUploadTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *AvatarUploadTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestUploadAvatar тестирует загрузку JPEG и PNG: адрес фотографии ведет на CDN, сохраняется у водителя
+// и отдает загруженное изображение
+func (suite *AvatarUploadTestSuite) TestUploadAvatar() {
+	testCases := []struct {
+		name        string
+		filename    string
+		contentType string
+		content     []byte
+	}{
+		{name: "JPEG", filename: "avatar.jpg", contentType: "image/jpeg", content: fixtures.CreateAvatarJPEG(256, 256)},
+		{name: "PNG", filename: "avatar.png", contentType: "image/png", content: fixtures.CreateAvatarPNG(256, 256)},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t)
+
+			// Act
+			response := suite.env.API.UploadAvatar(driverID, tc.filename, tc.contentType, tc.content)
+
+			// Assert
+			require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+			var avatar services.Avatar
+			suite.env.API.UnmarshalResponse(response, &avatar)
+			assert.Equal(t, tc.contentType, avatar.ContentType)
+			assert.True(t, strings.HasPrefix(avatar.URL, suite.env.AvatarCDNURL+"/drivers/"+driverID.String()+"/avatar/"),
+				avatar.URL)
+
+			cdnResponse := helpers.FetchAvatar(t, avatar.URL)
+			require.Equal(t, http.StatusOK, cdnResponse.StatusCode)
+			assert.Equal(t, tc.contentType, cdnResponse.Header.Get("Content-Type"))
+			content := helpers.ReadAvatar(t, avatar.URL)
+			assert.Len(t, content, avatar.Size)
+
+			decoded, _, err := image.Decode(bytes.NewReader(content))
+			require.NoError(t, err, "CDN отдает изображение")
+			assert.Equal(t, image.Rect(0, 0, 256, 256), decoded.Bounds())
+
+			driver := suite.getDriver(t, driverID)
+			assert.Equal(t, avatar.URL, driver.Metadata["avatar_url"])
+		})
+	}
+}
+
+// TestEXIFStripped тестирует, что в опубликованной фотографии нет координат съемки и модели телефона
+func (suite *AvatarUploadTestSuite) TestEXIFStripped() {
+	testCases := []struct {
+		name        string
+		contentType string
+		content     []byte
+	}{
+		{name: "JPEG", contentType: "image/jpeg", content: fixtures.CreateAvatarJPEG(128, 96)},
+		{name: "PNG", contentType: "image/png", content: fixtures.CreateAvatarPNG(128, 96)},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t)
+			require.Contains(t, string(tc.content), fixtures.AvatarGPSTag)
+
+			// Act
+			response := suite.env.API.UploadAvatar(driverID, "avatar", tc.contentType, tc.content)
+
+			// Assert
+			require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+			var avatar services.Avatar
+			suite.env.API.UnmarshalResponse(response, &avatar)
+			assert.Less(t, avatar.Size, len(tc.content))
+
+			content := helpers.ReadAvatar(t, avatar.URL)
+			assert.NotContains(t, string(content), fixtures.AvatarGPSTag)
+			assert.NotContains(t, string(content), fixtures.AvatarDeviceTag)
+			assert.NotContains(t, string(content), "Exif\x00\x00")
+
+			decoded, _, err := image.Decode(bytes.NewReader(content))
+			require.NoError(t, err)
+			assert.Equal(t, image.Rect(0, 0, 128, 96), decoded.Bounds())
+		})
+	}
+}
+
+// TestSizeLimits тестирует ограничение размера файла: ровно MaxAvatarSize принимается, больше - 413,
+// в том числе когда тело запроса превышает лимит формы
+func (suite *AvatarUploadTestSuite) TestSizeLimits() {
+	photo := fixtures.CreateAvatarJPEG(64, 64)
+
+	testCases := []struct {
+		name           string
+		size           int
+		expectedStatus int
+	}{
+		{name: "AtLimit", size: services.MaxAvatarSize, expectedStatus: http.StatusOK},
+		{name: "OneByteOver", size: services.MaxAvatarSize + 1, expectedStatus: http.StatusRequestEntityTooLarge},
+		{name: "FarOver", size: 2 * services.MaxAvatarSize, expectedStatus: http.StatusRequestEntityTooLarge},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t)
+			content := fixtures.PadAvatarJPEG(photo, tc.size)
+			require.Len(t, content, tc.size)
+
+			// Act
+			response := suite.env.API.UploadAvatar(driverID, "avatar.jpg", "image/jpeg", content)
+
+			// Assert
+			suite.env.API.AssertStatusCode(response, tc.expectedStatus)
+			if tc.expectedStatus == http.StatusOK {
+				var avatar services.Avatar
+				suite.env.API.UnmarshalResponse(response, &avatar)
+				assert.Len(t, helpers.ReadAvatar(t, avatar.URL), avatar.Size)
+				assert.Less(t, avatar.Size, len(photo), "Комментарии-заполнители удалены вместе с EXIF")
+				return
+			}
+
+			suite.env.API.AssertErrorResponse(response, "AVATAR_TOO_LARGE")
+			_, hasAvatar := suite.getDriver(t, driverID).Metadata["avatar_url"]
+			assert.False(t, hasAvatar, "Отклоненная фотография не сохраняется")
+		})
+	}
+}
+
+// TestTypeLimits тестирует, что тип определяется по содержимому: GIF, PDF и текст отклоняются с 415
+// независимо от имени файла и Content-Type, а поврежденный JPEG - с 400
+func (suite *AvatarUploadTestSuite) TestTypeLimits() {
+	testCases := []struct {
+		name           string
+		filename       string
+		contentType    string
+		content        []byte
+		expectedStatus int
+		expectedCode   string
+	}{
+		{
+			name: "GIF", filename: "avatar.gif", contentType: "image/gif",
+			content: fixtures.CreateAvatarGIF(), expectedStatus: http.StatusUnsupportedMediaType, expectedCode: "UNSUPPORTED_MEDIA_TYPE",
+		},
+		{
+			name: "PDF disguised as JPEG", filename: "avatar.jpg", contentType: "image/jpeg",
+			content: fixtures.CreateTestDocumentFile("77AA123456"), expectedStatus: http.StatusUnsupportedMediaType, expectedCode: "UNSUPPORTED_MEDIA_TYPE",
+		},
+		{
+			name: "text disguised as PNG", filename: "avatar.png", contentType: "image/png",
+			content: []byte("definitely not an image"), expectedStatus: http.StatusUnsupportedMediaType, expectedCode: "UNSUPPORTED_MEDIA_TYPE",
+		},
+		{
+			name: "truncated JPEG", filename: "avatar.jpg", contentType: "image/jpeg",
+			content: fixtures.CreateTruncatedJPEG(), expectedStatus: http.StatusBadRequest, expectedCode: "INVALID_IMAGE",
+		},
+	}
+
+	driverID := suite.createDriver(suite.T())
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			response := suite.env.API.UploadAvatar(driverID, tc.filename, tc.contentType, tc.content)
+
+			// Assert
+			suite.env.API.AssertStatusCode(response, tc.expectedStatus)
+			suite.env.API.AssertErrorResponse(response, tc.expectedCode)
+		})
+	}
+
+	suite.T().Run("JPEG named as PNG", func(t *testing.T) {
+		response := suite.env.API.UploadAvatar(driverID, "avatar.png", "image/png", fixtures.CreateAvatarJPEG(32, 32))
+
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+		var avatar services.Avatar
+		suite.env.API.UnmarshalResponse(response, &avatar)
+		assert.Equal(t, "image/jpeg", avatar.ContentType)
+		assert.True(t, strings.HasSuffix(avatar.URL, ".jpg"), avatar.URL)
+	})
+}
+
+// TestInvalidRequests тестирует запросы без файла и к несуществующему водителю
+func (suite *AvatarUploadTestSuite) TestInvalidRequests() {
+	photo := fixtures.CreateAvatarJPEG(32, 32)
+
+	suite.T().Run("UnknownDriver", func(t *testing.T) {
+		response := suite.env.API.UploadAvatar(uuid.New(), "avatar.jpg", "image/jpeg", photo)
+
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+		suite.env.API.AssertErrorResponse(response, "DRIVER_NOT_FOUND")
+	})
+
+	suite.T().Run("MissingFile", func(t *testing.T) {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodPost,
+			URL:    fmt.Sprintf("/api/v1/drivers/%s/avatar", suite.createDriver(t)),
+			Body:   map[string]interface{}{"avatar": "photo.jpg"},
+		})
+
+		suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+		suite.env.API.AssertErrorResponse(response, "INVALID_REQUEST")
+	})
+}
+
+// TestReuploadKeepsCDNURLsReachable тестирует, что повторная загрузка выдает новый адрес (кэш CDN не отдаст
+// прежнюю фотографию), прежний адрес остается доступным, а неизвестный ключ отдает 404
+func (suite *AvatarUploadTestSuite) TestReuploadKeepsCDNURLsReachable() {
+	// Arrange
+	driverID := suite.createDriver(suite.T())
+	first := suite.uploadAvatar(driverID, fixtures.CreateAvatarJPEG(64, 64))
+
+	// Act
+	second := suite.uploadAvatar(driverID, fixtures.CreateAvatarPNG(64, 64))
+
+	// Assert
+	assert.NotEqual(suite.T(), first.URL, second.URL)
+	assert.Equal(suite.T(), second.URL, suite.getDriver(suite.T(), driverID).Metadata["avatar_url"])
+
+	for _, avatarURL := range []string{first.URL, second.URL} {
+		response := helpers.FetchAvatar(suite.T(), avatarURL)
+		assert.Equal(suite.T(), http.StatusOK, response.StatusCode, avatarURL)
+		assert.Contains(suite.T(), response.Header.Get("Cache-Control"), "immutable")
+	}
+
+	parsed, err := url.Parse(second.URL)
+	require.NoError(suite.T(), err)
+	parsed.Path = strings.TrimSuffix(parsed.Path, ".png") + "-missing.png"
+	assert.Equal(suite.T(), http.StatusNotFound, helpers.FetchAvatar(suite.T(), parsed.String()).StatusCode)
+}
+
+// uploadAvatar загружает фотографию и возвращает ответ сервиса
+func (suite *AvatarUploadTestSuite) uploadAvatar(driverID uuid.UUID, content []byte) services.Avatar {
+	response := suite.env.API.UploadAvatar(driverID, "avatar", "application/octet-stream", content)
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	var avatar services.Avatar
+	suite.env.API.UnmarshalResponse(response, &avatar)
+	return avatar
+}
+
+// createDriver регистрирует водителя через API
+func (suite *AvatarUploadTestSuite) createDriver(t *testing.T) uuid.UUID {
+	suite.drivers++
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7900555%04d", suite.drivers)
+	request["email"] = fmt.Sprintf("avatar.driver%d@example.com", suite.drivers)
+	request["license_number"] = fmt.Sprintf("AV%08d", suite.drivers)
+
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+	require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return driver.ID
+}
+
+// getDriver возвращает водителя через API
+func (suite *AvatarUploadTestSuite) getDriver(t *testing.T, driverID uuid.UUID) httpHandlers.DriverResponse {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return driver
+}
+
+// TestAvatarUploadTestSuite запускает тестовый suite
+func TestAvatarUploadTestSuite(t *testing.T) {
+	suite.Run(t, new(AvatarUploadTestSuite))
+}
@@ -0,0 +1,180 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// BackupRestoreTestSuite проверяет резервное копирование: дамп pg_dump заполненного окружения,
+// полная очистка БД, восстановление через pg_restore и проверка, что восстановленный сервис
+// содержит те же данные и работает. Требует pg_dump и pg_restore (см. TEST_PG_BIN_DIR).
+type BackupRestoreTestSuite struct {
+	suite.Suite
+	env       *helpers.TestEnvironment
+	driverIDs []uuid.UUID
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *BackupRestoreTestSuite) SetupSuite() {
+	helpers.RequireBackupTools(suite.T())
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *BackupRestoreTestSuite) TearDownSuite() {
+	if suite.env != nil {
+		suite.env.Close(suite.T())
+	}
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *BackupRestoreTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.driverIDs = nil
+}
+
+// TestBackupAndRestore тестирует восстановление заполненного окружения из дампа
+func (suite *BackupRestoreTestSuite) TestBackupAndRestore() {
+	// Arrange
+	suite.seed()
+	checksums := suite.env.DB.TableChecksums(suite.T())
+	version := suite.env.DB.MigrationVersion(suite.T())
+	dump := suite.env.DB.Backup(suite.T())
+
+	suite.env.DB.Wipe(suite.T())
+	require.Empty(suite.T(), suite.env.DB.TableChecksums(suite.T()), "После очистки в БД не должно остаться таблиц")
+
+	// Act
+	suite.env.DB.Restore(suite.T(), dump)
+
+	// Assert - данные и схема совпадают с исходными
+	assert.Equal(suite.T(), checksums, suite.env.DB.TableChecksums(suite.T()))
+	assert.Equal(suite.T(), version, suite.env.DB.MigrationVersion(suite.T()))
+
+	suite.T().Run("RatingStatsConsistent", func(t *testing.T) {
+		for _, driverID := range suite.driverIDs {
+			helpers.AssertRatingStatsConsistent(t, suite.env.DB, driverID)
+		}
+	})
+
+	suite.T().Run("RatingTriggerRestored", func(t *testing.T) {
+		driverID := suite.driverIDs[0]
+		require.NoError(t, suite.env.DB.InsertRating(suite.env.Ctx, fixtures.CreateTestRating(driverID, 1)))
+		helpers.AssertRatingStatsConsistent(t, suite.env.DB, driverID)
+	})
+
+	suite.T().Run("API", func(t *testing.T) {
+		suite.assertRestoredAPI(t, suite.env.ForTest(t))
+	})
+}
+
+// seed заполняет окружение водителями с документами, сменами, местоположениями и оценками
+func (suite *BackupRestoreTestSuite) seed() {
+	ratingsByDriver := [][]int{{5, 4, 5}, {3}, {}}
+	for i, driver := range fixtures.CreateMultipleTestDrivers(len(ratingsByDriver)) {
+		created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+		require.NoError(suite.T(), err)
+		require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, entities.StatusAvailable))
+		suite.driverIDs = append(suite.driverIDs, created.ID)
+
+		document := fixtures.CreateTestDocument(created.ID, entities.DocumentTypeDriverLicense)
+		require.NoError(suite.T(), suite.env.DocumentRepo.Create(suite.env.Ctx, document))
+
+		suite.env.DB.InsertShift(suite.T(), fixtures.CreateTestShift(created.ID))
+
+		history := fixtures.CreateTestLocationHistory(created.ID, 5, time.Minute)
+		require.NoError(suite.T(), suite.env.LocationService.BatchUpdateLocations(suite.env.Ctx, history))
+
+		for _, rating := range ratingsByDriver[i] {
+			require.NoError(suite.T(), suite.env.DB.InsertRating(suite.env.Ctx, fixtures.CreateTestRating(created.ID, rating)))
+		}
+		if len(ratingsByDriver[i]) == 0 {
+			continue
+		}
+		helpers.AssertRatingStatsConsistent(suite.T(), suite.env.DB, created.ID)
+	}
+}
+
+// assertRestoredAPI проверяет основные сценарии API на восстановленной БД: чтение сохраненных данных и запись новых
+func (suite *BackupRestoreTestSuite) assertRestoredAPI(t *testing.T, env *helpers.TestEnvironment) {
+	for _, driverID := range suite.driverIDs {
+		response := env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    env.API.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+		})
+		env.API.AssertStatusCode(response, http.StatusOK)
+
+		response = env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    env.API.APIPath(fmt.Sprintf("/drivers/%s/locations/current", driverID)),
+		})
+		env.API.AssertStatusCode(response, http.StatusOK)
+	}
+
+	// Поиск поблизости находит восстановленных водителей
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    env.API.APIPath("/locations/nearby"),
+		QueryParams: map[string]string{
+			"latitude":  "55.7558",
+			"longitude": "37.6173",
+			"radius_km": "10",
+		},
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+	var nearby httpHandlers.NearbyDriversResponse
+	env.API.UnmarshalResponse(response, &nearby)
+	found := make([]uuid.UUID, 0, len(nearby.Drivers))
+	for _, driver := range nearby.Drivers {
+		found = append(found, driver.DriverID)
+	}
+	assert.ElementsMatch(t, suite.driverIDs, found)
+
+	// Новые записи: уникальные ограничения и значения по умолчанию восстановлены вместе со схемой
+	request := helpers.CreateDriverRequest()
+	request["phone"] = "+79009990001"
+	request["email"] = "restored.driver@example.com"
+	request["license_number"] = "RS00000001"
+	response = env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+	require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var created httpHandlers.DriverResponse
+	env.API.UnmarshalResponse(response, &created)
+
+	response = env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+	env.API.AssertStatusCode(response, http.StatusConflict)
+
+	response = env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", created.ID)),
+		Body:   helpers.CreateLocationRequest(),
+	})
+	env.API.AssertStatusCode(response, http.StatusOK)
+}
+
+func TestBackupRestoreTestSuite(t *testing.T) {
+	suite.Run(t, new(BackupRestoreTestSuite))
+}
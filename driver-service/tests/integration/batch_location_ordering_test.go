@@ -0,0 +1,183 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"math/rand"
+	"net/http"
+	"testing"
+	"time"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// BatchLocationOrderingTestSuite тестовый suite для пакетов с перемешанными и повторяющимися точками
+type BatchLocationOrderingTestSuite struct {
+	suite.Suite
+	env          *helpers.TestEnvironment
+	testDriverID uuid.UUID
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *BatchLocationOrderingTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *BatchLocationOrderingTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *BatchLocationOrderingTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	suite.testDriverID = driver.ID
+}
+
+// TestShuffledBatchReturnsOrderedHistory тестирует, что история упорядочена по времени независимо от порядка в пакете
+func (suite *BatchLocationOrderingTestSuite) TestShuffledBatchReturnsOrderedHistory() {
+	// Arrange
+	points := suite.trackPoints(20)
+	shuffled := make([]map[string]interface{}, len(points))
+	copy(shuffled, points)
+	rand.New(rand.NewSource(7)).Shuffle(len(shuffled), func(i, j int) {
+		shuffled[i], shuffled[j] = shuffled[j], shuffled[i]
+	})
+
+	// Act
+	suite.postBatch(shuffled)
+	history := suite.getHistory()
+
+	// Assert
+	require.Len(suite.T(), history.Locations, len(points))
+	helpers.AssertTimestampsIncreasing(suite.T(), history.Locations, false)
+
+	for i, location := range history.Locations {
+		assert.Equal(suite.T(), points[i]["timestamp"], location.RecordedAt.Unix(), "Точка %d", i)
+		assert.InDelta(suite.T(), points[i]["latitude"], location.Latitude, 1e-6, "Точка %d", i)
+	}
+}
+
+// TestCurrentLocationIsNewestTimestamp тестирует, что текущим считается самое свежее местоположение, а не последний элемент пакета
+func (suite *BatchLocationOrderingTestSuite) TestCurrentLocationIsNewestTimestamp() {
+	// Arrange
+	points := suite.trackPoints(5)
+	newest := points[len(points)-1]
+
+	// Самая свежая точка в середине пакета, самая старая - в конце
+	batch := []map[string]interface{}{points[1], points[2], newest, points[3], points[0]}
+
+	// Act
+	suite.postBatch(batch)
+
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/locations/current", suite.testDriverID),
+	})
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+
+	var current httpHandlers.LocationResponse
+	suite.env.API.UnmarshalResponse(response, &current)
+	assert.Equal(suite.T(), newest["timestamp"], current.RecordedAt.Unix())
+	assert.InDelta(suite.T(), newest["latitude"], current.Latitude, 1e-6)
+	assert.InDelta(suite.T(), newest["longitude"], current.Longitude, 1e-6)
+}
+
+// TestDuplicatePointsDeduplicated тестирует, что повторяющиеся точки не дублируются в истории
+func (suite *BatchLocationOrderingTestSuite) TestDuplicatePointsDeduplicated() {
+	// Arrange
+	points := suite.trackPoints(10)
+	batch := append([]map[string]interface{}{}, points...)
+	batch = append(batch, points[2], points[5], points[5])
+
+	// Act
+	suite.postBatch(batch)
+	// Повторная отправка того же пакета (например, ретрай клиента после таймаута)
+	suite.postBatch(points)
+	history := suite.getHistory()
+
+	// Assert
+	require.Len(suite.T(), history.Locations, len(points))
+	helpers.AssertTimestampsIncreasing(suite.T(), history.Locations, true)
+	for i, location := range history.Locations {
+		assert.Equal(suite.T(), points[i]["timestamp"], location.RecordedAt.Unix(), "Точка %d", i)
+	}
+}
+
+// TestSameTimestampDifferentCoordinatesKept тестирует, что точки с одинаковым временем, но разными координатами
+// не считаются повтором
+func (suite *BatchLocationOrderingTestSuite) TestSameTimestampDifferentCoordinatesKept() {
+	// Arrange
+	points := suite.trackPoints(2)
+	shifted := map[string]interface{}{
+		"latitude":  points[0]["latitude"].(float64) + 0.001,
+		"longitude": points[0]["longitude"],
+		"timestamp": points[0]["timestamp"],
+	}
+
+	// Act
+	suite.postBatch([]map[string]interface{}{points[0], shifted, points[1]})
+	history := suite.getHistory()
+
+	// Assert
+	assert.Len(suite.T(), history.Locations, 3)
+}
+
+// trackPoints создает упорядоченные по времени точки трека с интервалом 10 секунд
+func (suite *BatchLocationOrderingTestSuite) trackPoints(count int) []map[string]interface{} {
+	route := fixtures.CreateStraightRoute(
+		fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173},
+		fixtures.RoutePoint{Latitude: 55.7658, Longitude: 37.6273},
+		count-1,
+	)
+
+	baseTime := time.Now().Add(-time.Duration(count) * 10 * time.Second).Unix()
+	points := make([]map[string]interface{}, count)
+	for i, point := range route {
+		points[i] = map[string]interface{}{
+			"latitude":  point.Latitude,
+			"longitude": point.Longitude,
+			"timestamp": baseTime + int64(i*10),
+		}
+	}
+
+	return points
+}
+
+// postBatch отправляет пакет местоположений через API
+func (suite *BatchLocationOrderingTestSuite) postBatch(points []map[string]interface{}) {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/locations/batch", suite.testDriverID),
+		Body:   map[string]interface{}{"locations": points},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+}
+
+// getHistory получает историю местоположений тестового водителя за последний час
+func (suite *BatchLocationOrderingTestSuite) getHistory() *helpers.HistoryPage {
+	page, response := suite.env.API.GetLocationHistory(suite.testDriverID, helpers.HistoryQuery{
+		From: time.Now().Add(-time.Hour),
+		To:   time.Now().Add(time.Minute),
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	return page
+}
+
+// TestBatchLocationOrderingTestSuite запускает тестовый suite
+func TestBatchLocationOrderingTestSuite(t *testing.T) {
+	suite.Run(t, new(BatchLocationOrderingTestSuite))
+}
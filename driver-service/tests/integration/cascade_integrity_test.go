@@ -0,0 +1,172 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"testing"
+
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// CascadeIntegrityTestSuite проверяет удаление каждой сущности каждым способом по декларативной карте связей
+// helpers.EntityRelationships: дочерние строки удаляются или сохраняются так, как описано в карте,
+// данные других сущностей не затрагиваются, а внешние ключи схемы совпадают с картой.
+//
+// Примечание: сервис не хранит данные сущностей в Redis (конфигурация есть, клиента нет),
+// поэтому карта описывает только таблицы.
+type CascadeIntegrityTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *CascadeIntegrityTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *CascadeIntegrityTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *CascadeIntegrityTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestSchemaMatchesRelationshipMap тестирует, что каждый внешний ключ схемы описан в карте связей
+// с правилом удаления, соответствующим документированному поведению при полном удалении
+func (suite *CascadeIntegrityTestSuite) TestSchemaMatchesRelationshipMap() {
+	// Arrange
+	declared := make(map[string]helpers.ChildBehavior)
+	parents := make(map[string]bool)
+	for _, entity := range helpers.EntityRelationships {
+		parents[entity.Table] = true
+		for _, child := range entity.Children {
+			declared[foreignKeyName(child.Table, child.Column, entity.Table)] = child.OnDelete[helpers.HardDelete]
+		}
+	}
+
+	// Act
+	keys := suite.env.DB.ForeignKeys(suite.T())
+
+	// Assert
+	require.NotEmpty(suite.T(), keys)
+	actual := make(map[string]bool)
+	for _, key := range keys {
+		name := foreignKeyName(key.ChildTable, key.Column, key.ParentTable)
+		actual[name] = true
+
+		assert.True(suite.T(), parents[key.ParentTable], "Таблица %s, на которую ссылается %s, не описана в карте связей", key.ParentTable, name)
+
+		behavior, ok := declared[name]
+		if !assert.True(suite.T(), ok, "Внешний ключ %s не описан в карте связей", name) {
+			continue
+		}
+		if behavior == helpers.ChildCascade {
+			assert.Equal(suite.T(), "CASCADE", key.DeleteRule, "Внешний ключ %s", name)
+		} else {
+			assert.NotEqual(suite.T(), "CASCADE", key.DeleteRule, "Внешний ключ %s", name)
+		}
+	}
+
+	for name := range declared {
+		assert.True(suite.T(), actual[name], "Связь %s из карты не подкреплена внешним ключом", name)
+	}
+}
+
+// TestDeleteBehavior тестирует удаление каждой сущности каждым поддерживаемым способом
+func (suite *CascadeIntegrityTestSuite) TestDeleteBehavior() {
+	for _, entity := range helpers.EntityRelationships {
+		for _, mode := range []helpers.DeleteMode{helpers.HardDelete, helpers.SoftDelete} {
+			deleteFn, supported := entity.Delete[mode]
+			if !supported {
+				continue
+			}
+
+			suite.T().Run(fmt.Sprintf("%s/%s", entity.Entity, mode), func(t *testing.T) {
+				suite.env.Reset(t)
+
+				// Arrange
+				target := entity.Create(t, suite.env)
+				bystander := entity.Create(t, suite.env)
+				for _, parentID := range []uuid.UUID{target, bystander} {
+					for _, child := range entity.Children {
+						if child.Seed != nil {
+							child.Seed(t, suite.env, parentID)
+						}
+					}
+				}
+
+				targetBefore := suite.childCounts(t, entity, target)
+				bystanderBefore := suite.childCounts(t, entity, bystander)
+				for table, count := range targetBefore {
+					require.Positive(t, count, "Нет дочерних строк %s для проверки", table)
+				}
+				unrelatedBefore := suite.unrelatedChecksums(t, entity)
+
+				// Act
+				deleteFn(t, suite.env, target)
+
+				// Assert
+				targetAfter := suite.childCounts(t, entity, target)
+				for _, child := range entity.Children {
+					switch child.OnDelete[mode] {
+					case helpers.ChildCascade:
+						assert.Zero(t, targetAfter[child.Table], "Строки %s должны удаляться вместе с родителем", child.Table)
+					case helpers.ChildRetain:
+						assert.Equal(t, targetBefore[child.Table], targetAfter[child.Table], "Строки %s должны сохраняться", child.Table)
+					default:
+						t.Errorf("Для %s не описано поведение при удалении %s", child.Table, mode)
+					}
+				}
+
+				parentRows := suite.env.DB.CountRows(t, entity.Table, "id", target)
+				if mode == helpers.HardDelete {
+					assert.Zero(t, parentRows, "Строка %s должна удаляться", entity.Table)
+				} else {
+					assert.Equal(t, 1, parentRows, "Строка %s должна сохраняться", entity.Table)
+				}
+
+				assert.Equal(t, bystanderBefore, suite.childCounts(t, entity, bystander), "Данные другой сущности не затрагиваются")
+				assert.Equal(t, 1, suite.env.DB.CountRows(t, entity.Table, "id", bystander))
+				assert.Equal(t, unrelatedBefore, suite.unrelatedChecksums(t, entity), "Таблицы вне связей сущности не меняются")
+			})
+		}
+	}
+}
+
+// childCounts возвращает количество дочерних строк родителя по таблицам
+func (suite *CascadeIntegrityTestSuite) childCounts(t *testing.T, entity helpers.EntityRelations, parentID uuid.UUID) map[string]int {
+	counts := make(map[string]int, len(entity.Children))
+	for _, child := range entity.Children {
+		counts[child.Table] = suite.env.DB.CountRows(t, child.Table, child.Column, parentID)
+	}
+	return counts
+}
+
+// unrelatedChecksums возвращает контрольные суммы таблиц, не связанных с сущностью в карте
+func (suite *CascadeIntegrityTestSuite) unrelatedChecksums(t *testing.T, entity helpers.EntityRelations) map[string]helpers.TableChecksum {
+	checksums := suite.env.DB.TableChecksums(t)
+	delete(checksums, entity.Table)
+	for _, child := range entity.Children {
+		delete(checksums, child.Table)
+	}
+	return checksums
+}
+
+// foreignKeyName имя связи вида driver_documents.driver_id -> drivers
+func foreignKeyName(childTable, column, parentTable string) string {
+	return fmt.Sprintf("%s.%s -> %s", childTable, column, parentTable)
+}
+
+// TestCascadeIntegrityTestSuite запускает тестовый suite
+func TestCascadeIntegrityTestSuite(t *testing.T) {
+	suite.Run(t, new(CascadeIntegrityTestSuite))
+}
@@ -0,0 +1,253 @@
+//go:build integration
+
+package integration
+
+import (
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// ConditionalRequestsTestSuite тестовый suite для условных GET запросов (ETag, Last-Modified)
+type ConditionalRequestsTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *ConditionalRequestsTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *ConditionalRequestsTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *ConditionalRequestsTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestETagRevalidation тестирует повторную проверку водителя по ETag до и после изменения
+func (suite *ConditionalRequestsTestSuite) TestETagRevalidation() {
+	// Arrange
+	driverID := suite.createDriver(fixtures.CreateTestDriver())
+
+	initial := suite.getDriver(suite.env.API, driverID, nil)
+	require.Equal(suite.T(), http.StatusOK, initial.StatusCode, string(initial.Body))
+	etag := initial.ETag()
+	require.NotEmpty(suite.T(), etag, "Ответ должен содержать ETag")
+	_, ok := initial.LastModified()
+	require.True(suite.T(), ok, "Ответ должен содержать Last-Modified")
+
+	// Act & Assert - ресурс не изменился
+	cached := suite.getDriver(suite.env.API, driverID, map[string]string{"If-None-Match": etag})
+	assert.Equal(suite.T(), http.StatusNotModified, cached.StatusCode)
+	assert.Empty(suite.T(), cached.Body, "Ответ 304 не должен содержать тело")
+	assert.Equal(suite.T(), etag, cached.ETag())
+
+	// Act - изменяем водителя
+	firstName := "Измененный"
+	updated := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPut,
+		URL:    suite.env.API.APIPath("/drivers/" + driverID.String()),
+		Body:   httpHandlers.UpdateDriverRequest{FirstName: &firstName},
+	})
+	require.Equal(suite.T(), http.StatusOK, updated.StatusCode, string(updated.Body))
+
+	// Assert - устаревший ETag больше не совпадает
+	fresh := suite.getDriver(suite.env.API, driverID, map[string]string{"If-None-Match": etag})
+	require.Equal(suite.T(), http.StatusOK, fresh.StatusCode, string(fresh.Body))
+	assert.NotEqual(suite.T(), etag, fresh.ETag())
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(fresh, &driver)
+	assert.Equal(suite.T(), firstName, driver.FirstName)
+
+	testCases := []struct {
+		name        string
+		ifNoneMatch string
+	}{
+		{name: "ListOfTags", ifNoneMatch: `"stale", ` + fresh.ETag()},
+		{name: "WeakComparison", ifNoneMatch: "W/" + fresh.ETag()},
+		{name: "Wildcard", ifNoneMatch: "*"},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			response := suite.getDriver(suite.env.API, driverID, map[string]string{"If-None-Match": tc.ifNoneMatch})
+			assert.Equal(t, http.StatusNotModified, response.StatusCode)
+		})
+	}
+}
+
+// TestIfModifiedSince тестирует проверку водителя по времени последнего изменения
+func (suite *ConditionalRequestsTestSuite) TestIfModifiedSince() {
+	// Arrange
+	driverID := suite.createDriver(fixtures.CreateTestDriver())
+
+	initial := suite.getDriver(suite.env.API, driverID, nil)
+	require.Equal(suite.T(), http.StatusOK, initial.StatusCode, string(initial.Body))
+	lastModified, ok := initial.LastModified()
+	require.True(suite.T(), ok, "Ответ должен содержать Last-Modified")
+
+	testCases := []struct {
+		name           string
+		headers        map[string]string
+		expectedStatus int
+	}{
+		{
+			name:           "SameAsLastModified",
+			headers:        map[string]string{"If-Modified-Since": lastModified.Format(http.TimeFormat)},
+			expectedStatus: http.StatusNotModified,
+		},
+		{
+			name:           "AfterLastModified",
+			headers:        map[string]string{"If-Modified-Since": lastModified.Add(time.Hour).Format(http.TimeFormat)},
+			expectedStatus: http.StatusNotModified,
+		},
+		{
+			name:           "BeforeLastModified",
+			headers:        map[string]string{"If-Modified-Since": lastModified.Add(-time.Hour).Format(http.TimeFormat)},
+			expectedStatus: http.StatusOK,
+		},
+		{
+			name:           "InvalidDate",
+			headers:        map[string]string{"If-Modified-Since": "yesterday"},
+			expectedStatus: http.StatusOK,
+		},
+		{
+			// If-None-Match имеет приоритет: несовпавший ETag не перекрывается свежим If-Modified-Since
+			name: "IfNoneMatchTakesPrecedence",
+			headers: map[string]string{
+				"If-None-Match":     `"stale"`,
+				"If-Modified-Since": lastModified.Add(time.Hour).Format(http.TimeFormat),
+			},
+			expectedStatus: http.StatusOK,
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			response := suite.getDriver(suite.env.API, driverID, tc.headers)
+
+			// Assert
+			assert.Equal(t, tc.expectedStatus, response.StatusCode)
+			if tc.expectedStatus == http.StatusOK {
+				assert.NotEmpty(t, response.Body)
+			}
+		})
+	}
+}
+
+// TestConditionalRequestForMissingDriver тестирует, что ошибки не превращаются в 304
+func (suite *ConditionalRequestsTestSuite) TestConditionalRequestForMissingDriver() {
+	// Act
+	response := suite.getDriver(suite.env.API, uuid.New(), map[string]string{"If-None-Match": "*"})
+
+	// Assert
+	assert.Equal(suite.T(), http.StatusNotFound, response.StatusCode)
+	assert.Empty(suite.T(), response.ETag(), "Ответ с ошибкой не должен содержать ETag")
+}
+
+// TestDriverListHasNoETag тестирует, что список водителей не буферизуется ради ETag
+func (suite *ConditionalRequestsTestSuite) TestDriverListHasNoETag() {
+	// Arrange
+	suite.createDriver(fixtures.CreateTestDriver())
+
+	// Act
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method:  http.MethodGet,
+		URL:     suite.env.API.APIPath("/drivers"),
+		Headers: map[string]string{"If-None-Match": "*"},
+	})
+
+	// Assert
+	assert.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	assert.Empty(suite.T(), response.ETag(), "Условные запросы действуют только для одного водителя")
+}
+
+// TestCacheHitRatio тестирует подсчет попаданий в кэш при повторных проверках водителей
+func (suite *ConditionalRequestsTestSuite) TestCacheHitRatio() {
+	const (
+		operation = "GET /api/v1/drivers/{id}"
+		rounds    = 10
+	)
+
+	// Arrange
+	stats := helpers.NewCacheStats()
+	client := helpers.NewAPITestHelper(suite.env.Router, suite.T()).WithResponseInterceptor(stats.Record)
+
+	var driverIDs []uuid.UUID
+	for _, driver := range fixtures.CreateMultipleTestDrivers(3) {
+		driverIDs = append(driverIDs, suite.createDriver(driver))
+	}
+
+	// Первое чтение без условий не учитывается в статистике кэша
+	etags := make(map[uuid.UUID]string, len(driverIDs))
+	for _, driverID := range driverIDs {
+		response := suite.getDriver(client, driverID, nil)
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+		etags[driverID] = response.ETag()
+	}
+	require.Zero(suite.T(), stats.Conditional(operation))
+
+	// Act - клиент повторно проверяет водителей, один из них изменяется на середине
+	for round := 0; round < rounds; round++ {
+		if round == rounds/2 {
+			driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverIDs[0])
+			require.NoError(suite.T(), err)
+			driver.FirstName = "Измененный"
+			_, err = suite.env.DriverService.UpdateDriver(suite.env.Ctx, driver)
+			require.NoError(suite.T(), err)
+		}
+
+		for _, driverID := range driverIDs {
+			response := suite.getDriver(client, driverID, map[string]string{"If-None-Match": etags[driverID]})
+			if !response.NotModified() {
+				require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+				etags[driverID] = response.ETag()
+			}
+		}
+	}
+
+	// Assert - промах только на первой проверке после изменения
+	total := rounds * len(driverIDs)
+	assert.Equal(suite.T(), total, stats.Conditional(operation))
+	assert.Equal(suite.T(), total-1, stats.Hits(operation))
+	assert.InDelta(suite.T(), float64(total-1)/float64(total), stats.HitRatio(operation), 1e-9)
+	suite.T().Logf("Доля попаданий в кэш %s: %.2f", operation, stats.HitRatio(operation))
+}
+
+// createDriver создает водителя через сервис
+func (suite *ConditionalRequestsTestSuite) createDriver(driver *entities.Driver) uuid.UUID {
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	return createdDriver.ID
+}
+
+// getDriver запрашивает водителя через API с заголовками условного запроса
+func (suite *ConditionalRequestsTestSuite) getDriver(client *helpers.APITestHelper, driverID uuid.UUID, headers map[string]string) *helpers.APIResponse {
+	return client.MakeRequest(helpers.APIRequest{
+		Method:  http.MethodGet,
+		URL:     client.APIPath("/drivers/" + driverID.String()),
+		Headers: headers,
+	})
+}
+
+// TestConditionalRequestsTestSuite запускает тестовый suite
+func TestConditionalRequestsTestSuite(t *testing.T) {
+	suite.Run(t, new(ConditionalRequestsTestSuite))
+}
@@ -0,0 +1,285 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// reloadDriverOffsetLat смещение водителя к северу от центра поиска: ~8 км, между радиусами 5 и 10 км
+const reloadDriverOffsetLat = 0.072
+
+// ConfigReloadTestSuite тестирует перечитывание ограничений API (частота запросов, радиус поиска поблизости)
+// из файла конфигурации без перезапуска сервиса: файл меняется, POST /api/v1/admin/config/reload применяет его,
+// как SIGHUP, а запросы, идущие во время перечитывания, не обрываются.
+type ConfigReloadTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *ConfigReloadTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *ConfigReloadTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *ConfigReloadTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestNearbyRadiusReload тестирует, что новый радиус поиска по умолчанию действует после перечитывания,
+// а не после записи файла
+func (suite *ConfigReloadTestSuite) TestNearbyRadiusReload() {
+	// Arrange
+	driverID := suite.createDriverAt(featureCenterLat+reloadDriverOffsetLat, featureCenterLon)
+	distanceKm := helpers.HaversineKm(featureCenterLat, featureCenterLon, featureCenterLat+reloadDriverOffsetLat, featureCenterLon)
+	require.InDelta(suite.T(), 8.0, distanceKm, 0.1)
+	require.NotContains(suite.T(), suite.nearbyDrivers(), driverID, "Водитель дальше радиуса по умолчанию")
+
+	// Act - файл изменен, но не перечитан
+	suite.env.WriteLimits(suite.T(), config.LimitsConfig{Burst: 20, NearbyRadiusKm: 10})
+
+	// Assert
+	assert.NotContains(suite.T(), suite.nearbyDrivers(), driverID, "Изменение файла без перечитывания не действует")
+
+	// Act
+	response := suite.env.API.ReloadConfig()
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	var limits httpHandlers.LimitsResponse
+	suite.env.API.UnmarshalResponse(response, &limits)
+	assert.Equal(suite.T(), 10.0, limits.NearbyRadiusKm)
+	assert.Contains(suite.T(), suite.nearbyDrivers(), driverID, "Поиск без radius_km использует новый радиус")
+}
+
+// TestRateLimitReload тестирует включение, изменение и отключение ограничения частоты запросов перечитыванием
+func (suite *ConfigReloadTestSuite) TestRateLimitReload() {
+	// Arrange
+	assert.Equal(suite.T(), map[int]int{http.StatusOK: 30}, suite.burst(30), "По умолчанию частота не ограничена")
+
+	// Act
+	suite.env.WriteLimits(suite.T(), config.LimitsConfig{RequestsPerSecond: 0.5, Burst: 5, NearbyRadiusKm: 5})
+	require.Equal(suite.T(), http.StatusOK, suite.env.API.ReloadConfig().StatusCode)
+
+	// Assert
+	assert.Equal(suite.T(), map[int]int{http.StatusOK: 5, http.StatusTooManyRequests: 5}, suite.burst(10))
+	limited := suite.env.API.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: suite.env.API.APIPath("/drivers")})
+	suite.env.API.AssertStatusCode(limited, http.StatusTooManyRequests)
+	suite.env.API.AssertErrorResponse(limited, "RATE_LIMITED")
+	assert.Equal(suite.T(), http.StatusOK, suite.env.API.GetLimits().StatusCode, "Административный API не ограничивается")
+
+	// Act - лимит увеличен
+	suite.env.WriteLimits(suite.T(), config.LimitsConfig{RequestsPerSecond: 0.5, Burst: 15, NearbyRadiusKm: 5})
+	require.Equal(suite.T(), http.StatusOK, suite.env.API.ReloadConfig().StatusCode)
+
+	// Assert
+	assert.Equal(suite.T(), map[int]int{http.StatusOK: 15, http.StatusTooManyRequests: 5}, suite.burst(20),
+		"Новый лимит действует с полной корзины")
+
+	// Act - ограничение снято
+	suite.env.WriteLimits(suite.T(), config.LimitsConfig{Burst: 15, NearbyRadiusKm: 5})
+	require.Equal(suite.T(), http.StatusOK, suite.env.API.ReloadConfig().StatusCode)
+
+	// Assert
+	assert.Equal(suite.T(), map[int]int{http.StatusOK: 30}, suite.burst(30))
+}
+
+// TestSpoofedForwardedForSharesLimit тестирует, что подделанный X-Forwarded-For не дает клиенту новую корзину:
+// без trusted_proxies адрес клиента берется из соединения
+func (suite *ConfigReloadTestSuite) TestSpoofedForwardedForSharesLimit() {
+	// Arrange
+	suite.env.WriteLimits(suite.T(), config.LimitsConfig{RequestsPerSecond: 0.5, Burst: 5, NearbyRadiusKm: 5})
+	require.Equal(suite.T(), http.StatusOK, suite.env.API.ReloadConfig().StatusCode)
+
+	// Act
+	statuses := make(map[int]int)
+	for i := 0; i < 10; i++ {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method:  http.MethodGet,
+			URL:     suite.env.API.APIPath("/drivers"),
+			Headers: map[string]string{"X-Forwarded-For": fmt.Sprintf("203.0.113.%d", i+1)},
+		})
+		statuses[response.StatusCode]++
+	}
+
+	// Assert
+	assert.Equal(suite.T(), map[int]int{http.StatusOK: 5, http.StatusTooManyRequests: 5}, statuses)
+}
+
+// TestInvalidConfigKeepsLimits тестирует, что ошибочный файл отклоняется, а действующие ограничения сохраняются
+func (suite *ConfigReloadTestSuite) TestInvalidConfigKeepsLimits() {
+	// Arrange
+	suite.env.WriteLimits(suite.T(), config.LimitsConfig{Burst: 20, NearbyRadiusKm: 10})
+	require.Equal(suite.T(), http.StatusOK, suite.env.API.ReloadConfig().StatusCode)
+
+	testCases := []struct {
+		name    string
+		content string
+	}{
+		{name: "NegativeRadius", content: "limits:\n  nearby_radius_km: -1\n"},
+		{name: "NegativeRate", content: "limits:\n  requests_per_second: -5\n"},
+		{name: "BrokenYAML", content: "limits:\n  burst: [20\n"},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			suite.env.WriteConfig(t, tc.content)
+			response := suite.env.API.ReloadConfig()
+
+			// Assert
+			suite.env.API.AssertStatusCode(response, http.StatusUnprocessableEntity)
+			suite.env.API.AssertErrorResponse(response, "INVALID_CONFIG")
+			assert.Equal(t, 10.0, suite.env.Limits.Config().NearbyRadiusKm, "Действуют прежние ограничения")
+		})
+	}
+}
+
+// TestReloadWithoutDroppedRequests тестирует, что перечитывание посреди нагрузки не обрывает и не отклоняет
+// запросы: клиенты поверх TCP непрерывно ищут водителей, пока радиус по умолчанию переключается между 5 и 10 км
+func (suite *ConfigReloadTestSuite) TestReloadWithoutDroppedRequests() {
+	// Arrange
+	driverID := suite.createDriverAt(featureCenterLat+reloadDriverOffsetLat, featureCenterLon)
+	server := httptest.NewServer(suite.env.Router)
+	defer server.Close()
+	nearbyURL := fmt.Sprintf("%s%s?latitude=%f&longitude=%f",
+		server.URL, suite.env.API.APIPath("/locations/nearby"), featureCenterLat, featureCenterLon)
+
+	const workers = 8
+	var requests, failures atomic.Int64
+	var mu sync.Mutex
+	var reasons []string
+	stop := make(chan struct{})
+	var wg sync.WaitGroup
+	for i := 0; i < workers; i++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			for {
+				select {
+				case <-stop:
+					return
+				default:
+				}
+
+				requests.Add(1)
+				response, err := http.Get(nearbyURL)
+				if err == nil {
+					response.Body.Close()
+					if response.StatusCode == http.StatusOK {
+						continue
+					}
+					err = fmt.Errorf("status %d", response.StatusCode)
+				}
+				failures.Add(1)
+				mu.Lock()
+				reasons = append(reasons, err.Error())
+				mu.Unlock()
+			}
+		}()
+	}
+
+	// Act
+	reloads := 0
+	for _, radiusKm := range []float64{10, 5, 10, 5, 10} {
+		time.Sleep(50 * time.Millisecond)
+		suite.env.WriteLimits(suite.T(), config.LimitsConfig{Burst: 20, NearbyRadiusKm: radiusKm})
+		response := suite.env.API.ReloadConfig()
+		assert.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+		reloads++
+	}
+	time.Sleep(50 * time.Millisecond)
+	close(stop)
+	wg.Wait()
+
+	// Assert
+	suite.T().Logf("Requests during %d reloads: %d", reloads, requests.Load())
+	assert.Greater(suite.T(), requests.Load(), int64(workers*reloads), "Запросы шли во время перечитывания")
+	assert.Zero(suite.T(), failures.Load(), "Запросы не обрываются и не отклоняются: %v", reasons)
+	assert.Contains(suite.T(), suite.nearbyDrivers(), driverID, "Действует последний перечитанный радиус")
+}
+
+// createDriverAt регистрирует водителя через API, переводит его в статус available и отправляет его местоположение
+func (suite *ConfigReloadTestSuite) createDriverAt(lat, lon float64) uuid.UUID {
+	created := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   helpers.CreateDriverRequest(),
+	})
+	require.Equal(suite.T(), http.StatusCreated, created.StatusCode, string(created.Body))
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(created, &driver)
+
+	// Обходим цепочку переходов статусов - для поиска важен только итоговый статус
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driver.ID, entities.StatusAvailable))
+
+	located := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driver.ID)),
+		Body: map[string]interface{}{
+			"latitude":  lat,
+			"longitude": lon,
+		},
+	})
+	require.Equal(suite.T(), http.StatusOK, located.StatusCode, string(located.Body))
+	return driver.ID
+}
+
+// nearbyDrivers возвращает ID водителей поблизости от центра поиска без radius_km
+func (suite *ConfigReloadTestSuite) nearbyDrivers() []uuid.UUID {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath("/locations/nearby"),
+		QueryParams: map[string]string{
+			"latitude":  fmt.Sprintf("%f", featureCenterLat),
+			"longitude": fmt.Sprintf("%f", featureCenterLon),
+		},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	var nearby httpHandlers.NearbyDriversResponse
+	suite.env.API.UnmarshalResponse(response, &nearby)
+
+	ids := make([]uuid.UUID, 0, len(nearby.Drivers))
+	for _, driver := range nearby.Drivers {
+		ids = append(ids, driver.DriverID)
+	}
+	return ids
+}
+
+// burst отправляет count запросов подряд и возвращает число ответов по статусам
+func (suite *ConfigReloadTestSuite) burst(count int) map[int]int {
+	statuses := make(map[int]int)
+	for i := 0; i < count; i++ {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: suite.env.API.APIPath("/drivers")})
+		statuses[response.StatusCode]++
+	}
+	return statuses
+}
+
+// TestConfigReloadTestSuite запускает тестовый suite
+func TestConfigReloadTestSuite(t *testing.T) {
+	suite.Run(t, new(ConfigReloadTestSuite))
+}
@@ -0,0 +1,265 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/consistency"
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// ConsistencyCheckTestSuite тестирует проверку согласованности данных через /api/v1/admin/consistency:
+// каждое правило находит заложенные нарушения и не трогает корректные данные,
+// а предложенные в отчете исправления возвращают БД в согласованное состояние
+type ConsistencyCheckTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *ConsistencyCheckTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *ConsistencyCheckTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *ConsistencyCheckTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestConsistentData тестирует, что данные, записанные через сервис, проходят все правила
+func (suite *ConsistencyCheckTestSuite) TestConsistentData() {
+	// Arrange
+	driverID := suite.createDriver(entities.StatusAvailable)
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, fixtures.CreateTestLocation(driverID)))
+	require.NoError(suite.T(), suite.env.DB.InsertRating(suite.env.Ctx, fixtures.CreateTestRating(driverID, 4)))
+	suite.insertShift(driverID, 5*time.Hour, 3*time.Hour, entities.ShiftStatusCompleted)
+	suite.insertShift(driverID, 3*time.Hour, time.Hour, entities.ShiftStatusCompleted)
+
+	// Act
+	report, response := suite.env.API.CheckConsistency()
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.True(suite.T(), report.Consistent, "Нарушения: %+v", report.Rules)
+	assert.Zero(suite.T(), report.Violations)
+	assert.Len(suite.T(), report.Rules, len(consistency.DefaultRules(consistency.DefaultLocationStaleAfter)))
+	for _, result := range report.Rules {
+		assert.Empty(suite.T(), result.Error, "Правило %s", result.Rule)
+	}
+}
+
+// TestViolationsAndRepairs тестирует обнаружение нарушений каждым правилом и применение предложенных исправлений
+func (suite *ConsistencyCheckTestSuite) TestViolationsAndRepairs() {
+	// Arrange
+	// orphaned_locations: история водителя, строки которого нет (внешний ключ снят на время вставки)
+	ghostID := uuid.New()
+	suite.env.DB.WithoutConstraint(suite.T(), "driver_locations", "driver_locations_driver_id_fkey", func() {
+		for i := 0; i < 3; i++ {
+			require.NoError(suite.T(), suite.env.LocationRepo.Create(suite.env.Ctx, fixtures.CreateTestLocation(ghostID)))
+		}
+	})
+
+	// negative_earnings
+	earnerID := suite.createDriver(entities.StatusRegistered)
+	var negativeShift *entities.DriverShift
+	suite.env.DB.WithoutConstraint(suite.T(), "driver_shifts", "check_driver_shifts_totals", func() {
+		negativeShift = suite.newShift(earnerID, 30*time.Hour, 26*time.Hour, entities.ShiftStatusCompleted)
+		negativeShift.TotalEarnings = -150
+		suite.env.DB.InsertShift(suite.T(), negativeShift)
+	})
+
+	// overlapping_shifts: ранняя смена пересекается с поздней, отмененная и соседняя смены не учитываются
+	shifterID := suite.createDriver(entities.StatusRegistered)
+	early := suite.insertShift(shifterID, 5*time.Hour, 2*time.Hour, entities.ShiftStatusCompleted)
+	late := suite.insertShift(shifterID, 3*time.Hour, time.Hour, entities.ShiftStatusCompleted)
+	suite.insertShift(shifterID, 4*time.Hour, 90*time.Minute, entities.ShiftStatusCancelled)
+	suite.insertShift(shifterID, 10*time.Hour, 8*time.Hour, entities.ShiftStatusCompleted)
+
+	// stale_active_status: водители на линии без свежего местоположения
+	staleID := suite.createDriver(entities.StatusAvailable)
+	suite.insertLocation(staleID, consistency.DefaultLocationStaleAfter+10*time.Minute)
+	silentID := suite.createDriver(entities.StatusOnShift)
+	freshID := suite.createDriver(entities.StatusAvailable)
+	suite.insertLocation(freshID, time.Minute)
+	inactiveID := suite.createDriver(entities.StatusInactive)
+	suite.insertLocation(inactiveID, time.Hour)
+	deletedID := suite.createDriver(entities.StatusAvailable)
+	require.NoError(suite.T(), suite.env.DriverService.DeleteDriver(suite.env.Ctx, deletedID))
+
+	// rating_stats_cache: потерянная и устаревшая строки агрегатов
+	lostStatsID := suite.createDriver(entities.StatusRegistered)
+	driftedStatsID := suite.createDriver(entities.StatusRegistered)
+	for _, driverID := range []uuid.UUID{lostStatsID, driftedStatsID} {
+		require.NoError(suite.T(), suite.env.DB.InsertRating(suite.env.Ctx, fixtures.CreateTestRating(driverID, 5)))
+		require.NoError(suite.T(), suite.env.DB.InsertRating(suite.env.Ctx, fixtures.CreateTestRating(driverID, 3)))
+	}
+	_, err := suite.env.DB.ExecContext(suite.env.Ctx, "DELETE FROM driver_rating_stats WHERE driver_id = $1", lostStatsID)
+	require.NoError(suite.T(), err)
+	_, err = suite.env.DB.ExecContext(suite.env.Ctx,
+		"UPDATE driver_rating_stats SET total_ratings = 100, average_rating = 4.9 WHERE driver_id = $1", driftedStatsID)
+	require.NoError(suite.T(), err)
+
+	expected := map[string][]string{
+		consistency.RuleOrphanedLocations: {ghostID.String()},
+		consistency.RuleNegativeEarnings:  {negativeShift.ID.String()},
+		consistency.RuleOverlappingShifts: {early.ID.String()},
+		consistency.RuleStaleActiveStatus: {staleID.String(), silentID.String()},
+		consistency.RuleRatingStatsCache:  {lostStatsID.String(), driftedStatsID.String()},
+	}
+
+	// Act
+	report, response := suite.env.API.CheckConsistency()
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.False(suite.T(), report.Consistent)
+	assert.Equal(suite.T(), 7, report.Violations)
+
+	for _, result := range report.Rules {
+		require.Empty(suite.T(), result.Error, "Правило %s", result.Rule)
+
+		var found []string
+		for _, violation := range result.Violations {
+			found = append(found, violation.EntityID)
+			assert.NotEmpty(suite.T(), violation.Details)
+			assert.NotEmpty(suite.T(), violation.RepairSQL, "Правило %s предлагает исправление для %s", result.Rule, violation.EntityID)
+		}
+		assert.ElementsMatch(suite.T(), expected[result.Rule], found, "Правило %s", result.Rule)
+	}
+
+	suite.T().Run("RepairsRestoreConsistency", func(t *testing.T) {
+		suite.env.DB.ApplyRepairs(t, report)
+
+		repaired, response := suite.env.API.CheckConsistency()
+
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+		assert.True(t, repaired.Consistent, "Нарушения после исправления: %+v", repaired.Rules)
+		assert.NoError(t, suite.env.DB.ValidateConstraint("driver_locations", "driver_locations_driver_id_fkey"))
+		assert.NoError(t, suite.env.DB.ValidateConstraint("driver_shifts", "check_driver_shifts_totals"))
+
+		shortened := suite.env.DB.GetShift(t, early.ID)
+		require.NotNil(t, shortened.EndTime)
+		assert.WithinDuration(t, late.StartTime, *shortened.EndTime, time.Millisecond, "Ранняя смена завершается началом поздней")
+
+		suite.assertDriverStatus(t, staleID, entities.StatusOffline)
+		suite.assertDriverStatus(t, silentID, entities.StatusOffline)
+		suite.assertDriverStatus(t, freshID, entities.StatusAvailable)
+		suite.assertDriverStatus(t, inactiveID, entities.StatusInactive)
+
+		for _, driverID := range []uuid.UUID{lostStatsID, driftedStatsID} {
+			helpers.AssertRatingStatsConsistent(t, suite.env.DB, driverID)
+		}
+	})
+}
+
+// TestOverlapWithSameStartNeedsManualRepair тестирует, что для смен с одинаковым началом исправление не предлагается
+func (suite *ConsistencyCheckTestSuite) TestOverlapWithSameStartNeedsManualRepair() {
+	// Arrange
+	driverID := suite.createDriver(entities.StatusRegistered)
+	first := suite.insertShift(driverID, 4*time.Hour, 2*time.Hour, entities.ShiftStatusCompleted)
+	second := suite.newShift(driverID, 4*time.Hour, time.Hour, entities.ShiftStatusCompleted)
+	second.StartTime = first.StartTime
+	suite.env.DB.InsertShift(suite.T(), second)
+
+	// Act
+	report, response := suite.env.API.CheckConsistency(consistency.RuleOverlappingShifts)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	require.Len(suite.T(), report.Rules, 1)
+	require.Len(suite.T(), report.Rules[0].Violations, 1)
+	assert.Empty(suite.T(), report.Rules[0].Violations[0].RepairSQL)
+	assert.Empty(suite.T(), report.RepairStatements())
+}
+
+// TestRuleSelection тестирует выбор правил параметром rules
+func (suite *ConsistencyCheckTestSuite) TestRuleSelection() {
+	// Arrange
+	suite.createDriver(entities.StatusAvailable) // нарушает только stale_active_status
+
+	// Act
+	report, response := suite.env.API.CheckConsistency(consistency.RuleNegativeEarnings, consistency.RuleOrphanedLocations)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.True(suite.T(), report.Consistent)
+	require.Len(suite.T(), report.Rules, 2)
+	assert.Equal(suite.T(), consistency.RuleOrphanedLocations, report.Rules[0].Rule, "Правила выполняются в порядке регистрации")
+	assert.Equal(suite.T(), consistency.RuleNegativeEarnings, report.Rules[1].Rule)
+
+	suite.T().Run("UnknownRule", func(t *testing.T) {
+		_, response := suite.env.API.CheckConsistency("cache_orphans")
+
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+		suite.env.API.AssertErrorResponse(response, "RULE_NOT_FOUND")
+	})
+}
+
+// createDriver создает водителя с уникальными контактами и переводит его в статус status
+func (suite *ConsistencyCheckTestSuite) createDriver(status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900555%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("consistency.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("CC%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	if created.Status != status {
+		require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	}
+	return created.ID
+}
+
+// newShift создает смену водителя, начавшуюся startedAgo и завершившуюся endedAgo назад
+func (suite *ConsistencyCheckTestSuite) newShift(driverID uuid.UUID, startedAgo, endedAgo time.Duration, status entities.ShiftStatus) *entities.DriverShift {
+	shift := fixtures.CreateTestShift(driverID)
+	shift.StartTime = time.Now().Add(-startedAgo)
+	endTime := time.Now().Add(-endedAgo)
+	shift.EndTime = &endTime
+	shift.Status = status
+	return shift
+}
+
+// insertShift сохраняет смену, созданную newShift
+func (suite *ConsistencyCheckTestSuite) insertShift(driverID uuid.UUID, startedAgo, endedAgo time.Duration, status entities.ShiftStatus) *entities.DriverShift {
+	shift := suite.newShift(driverID, startedAgo, endedAgo, status)
+	suite.env.DB.InsertShift(suite.T(), shift)
+	return shift
+}
+
+// insertLocation сохраняет местоположение водителя, записанное age назад
+func (suite *ConsistencyCheckTestSuite) insertLocation(driverID uuid.UUID, age time.Duration) {
+	location := fixtures.CreateTestLocation(driverID)
+	location.RecordedAt = time.Now().Add(-age)
+	require.NoError(suite.T(), suite.env.LocationRepo.Create(suite.env.Ctx, location))
+}
+
+// assertDriverStatus проверяет текущий статус водителя
+func (suite *ConsistencyCheckTestSuite) assertDriverStatus(t *testing.T, driverID uuid.UUID, expected entities.Status) {
+	driver, err := suite.env.DriverRepo.GetByID(suite.env.Ctx, driverID)
+	require.NoError(t, err)
+	assert.Equal(t, expected, driver.Status)
+}
+
+// TestConsistencyCheckTestSuite запускает тестовый suite
+func TestConsistencyCheckTestSuite(t *testing.T) {
+	suite.Run(t, new(ConsistencyCheckTestSuite))
+}
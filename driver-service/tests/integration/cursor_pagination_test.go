@@ -0,0 +1,332 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры проверок постраничного обхода по курсору
+const (
+	cursorSeedDrivers = 60
+	cursorPageSize    = 7
+	cursorWrites      = 40              // сколько водителей регистрирует фоновый писатель
+	cursorWriteWait   = 5 * time.Second // ожидание записи фонового водителя между страницами
+)
+
+// CursorPaginationTestSuite тестирует постраничный обход списка водителей по next_cursor: в отличие
+// от offset, курсор не сдвигается, когда водители добавляются между запросами страниц
+type CursorPaginationTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных контактов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *CursorPaginationTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *CursorPaginationTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *CursorPaginationTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestCursorWalkMatchesOffsetWalk тестирует, что без записей обход по курсору дает тот же порядок, что
+// и обход по offset, для каждой сортировки, в том числе при равных значениях поля
+func (suite *CursorPaginationTestSuite) TestCursorWalkMatchesOffsetWalk() {
+	// Arrange
+	for i := 0; i < 17; i++ {
+		id := suite.createDriver()
+		_, err := suite.env.DB.ExecContext(suite.env.Ctx,
+			"UPDATE drivers SET current_rating = $1, total_trips = $2 WHERE id = $3",
+			sortingRatings[i%len(sortingRatings)], sortingTrips[i%len(sortingTrips)], id)
+		require.NoError(suite.T(), err)
+	}
+
+	testCases := []helpers.DriverListFilter{
+		{},
+		{SortBy: "created_at", SortDirection: "asc"},
+		{SortBy: "current_rating", SortDirection: "desc"},
+		{SortBy: "current_rating", SortDirection: "asc"},
+		{SortBy: "total_trips", SortDirection: "desc"},
+	}
+
+	for _, filter := range testCases {
+		suite.T().Run(fmt.Sprintf("%s_%s", filter.SortBy, filter.SortDirection), func(t *testing.T) {
+			// Act
+			byOffset, _ := suite.env.API.ListAllDrivers(filter, cursorPageSize)
+			byCursor := suite.env.API.ListDriversByCursor(filter, cursorPageSize, nil)
+
+			// Assert
+			assert.Len(t, byCursor, 17)
+			assert.Equal(t, listedIDs(byOffset), listedIDs(byCursor))
+		})
+	}
+}
+
+// TestNoSkipsOrDuplicatesUnderConcurrentWrites тестирует обход по курсору от новых водителей к старым,
+// пока в фоне регистрируются новые: они появляются в начале списка, до уже пройденных страниц
+func (suite *CursorPaginationTestSuite) TestNoSkipsOrDuplicatesUnderConcurrentWrites() {
+	suite.assertWalkUnderWrites(helpers.DriverListFilter{})
+}
+
+// TestNoSkipsOrDuplicatesUnderConcurrentWritesAscending тестирует обход по курсору от старых водителей
+// к новым, пока в фоне регистрируются новые: они появляются в конце списка и попадают в обход
+func (suite *CursorPaginationTestSuite) TestNoSkipsOrDuplicatesUnderConcurrentWritesAscending() {
+	suite.assertWalkUnderWrites(helpers.DriverListFilter{SortBy: "created_at", SortDirection: "asc"})
+}
+
+// TestOffsetPaginationShiftsUnderWrites тестирует, от чего защищает курсор: водитель, добавленный
+// между страницами, сдвигает offset, и последний водитель страницы повторяется на следующей
+func (suite *CursorPaginationTestSuite) TestOffsetPaginationShiftsUnderWrites() {
+	// Arrange
+	for i := 0; i < 10; i++ {
+		suite.createDriver()
+	}
+	filter := helpers.DriverListFilter{Limit: 4}
+
+	// Act
+	first, response := suite.env.API.ListDrivers(filter)
+	require.NotNil(suite.T(), first, string(response.Body))
+	suite.createDriver()
+	filter.Offset = filter.Limit
+	second, response := suite.env.API.ListDrivers(filter)
+	require.NotNil(suite.T(), second, string(response.Body))
+
+	// Assert
+	assert.Equal(suite.T(), first.Drivers[len(first.Drivers)-1].ID, second.Drivers[0].ID,
+		"Новый водитель сдвигает offset на одну позицию")
+
+	// Act
+	byCursor := suite.env.API.ListDriversByCursor(helpers.DriverListFilter{}, 4, func(int) { suite.createDriver() })
+
+	// Assert
+	ids := listedIDs(byCursor)
+	assert.Len(suite.T(), ids, 11, "Водители, добавленные во время обхода, в начале списка и не попадают в него")
+	assert.Equal(suite.T(), uniqueIDs(ids), ids, "Курсор не повторяет водителей")
+}
+
+// TestInvalidCursor тестирует отклонение поврежденного курсора, курсора другой сортировки
+// и курсора вместе с offset
+func (suite *CursorPaginationTestSuite) TestInvalidCursor() {
+	// Arrange
+	for i := 0; i < 3; i++ {
+		suite.createDriver()
+	}
+	page, response := suite.env.API.ListDrivers(helpers.DriverListFilter{Limit: 1})
+	require.NotNil(suite.T(), page, string(response.Body))
+	require.NotEmpty(suite.T(), page.NextCursor)
+
+	testCases := []struct {
+		name   string
+		filter helpers.DriverListFilter
+	}{
+		{name: "Garbage", filter: helpers.DriverListFilter{Cursor: "not-a-cursor"}},
+		{name: "Truncated", filter: helpers.DriverListFilter{Cursor: page.NextCursor[:len(page.NextCursor)/2]}},
+		{name: "OtherSortField", filter: helpers.DriverListFilter{Cursor: page.NextCursor, SortBy: "current_rating"}},
+		{name: "OtherDirection", filter: helpers.DriverListFilter{Cursor: page.NextCursor, SortBy: "created_at", SortDirection: "asc"}},
+		{name: "WithOffset", filter: helpers.DriverListFilter{Cursor: page.NextCursor, Offset: 1}},
+	}
+
+	for _, tc := range testCases {
+		suite.Run(tc.name, func() {
+			// Act
+			list, response := suite.env.API.ListDrivers(tc.filter)
+
+			// Assert
+			assert.Nil(suite.T(), list)
+			suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+			suite.env.API.AssertErrorResponse(response, "INVALID_REQUEST")
+		})
+	}
+}
+
+// assertWalkUnderWrites обходит список по курсору, пока в фоне регистрируются водители, и проверяет,
+// что каждый водитель, существовавший до начала обхода, встречается ровно один раз, и ни один
+// водитель не повторяется
+func (suite *CursorPaginationTestSuite) assertWalkUnderWrites(filter helpers.DriverListFilter) {
+	t := suite.T()
+
+	// Arrange
+	existing := make([]uuid.UUID, cursorSeedDrivers)
+	for i := range existing {
+		existing[i] = suite.createDriver()
+	}
+	writer := suite.startWriter(t)
+
+	// Act - между страницами дожидаемся записи, чтобы обход гарантированно шел под записью
+	walked := suite.env.API.ListDriversByCursor(filter, cursorPageSize, func(int) { writer.waitForWrite(t) })
+	created := writer.stop(t)
+
+	// Assert
+	ids := listedIDs(walked)
+	seen := make(map[uuid.UUID]int, len(ids))
+	for _, id := range ids {
+		seen[id]++
+	}
+	for id, count := range seen {
+		assert.Equal(t, 1, count, "Водитель %s встретился %d раз", id, count)
+	}
+	for _, id := range existing {
+		assert.Contains(t, seen, id, "Водитель %s пропущен", id)
+	}
+	listedCreated := countPresent(seen, created)
+	assert.Equal(t, len(existing)+listedCreated, len(ids), "В обходе только существующие и новые водители")
+	t.Logf("Walked %d drivers while %d were created concurrently (%d of them listed)", len(ids), len(created), listedCreated)
+}
+
+// createDriver создает водителя с уникальными контактами
+func (suite *CursorPaginationTestSuite) createDriver() uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900740%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("cursor.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("CP%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	return created.ID
+}
+
+// cursorWriter регистрирует до cursorWrites водителей в фоне, пока его не остановят
+type cursorWriter struct {
+	created  chan uuid.UUID
+	count    atomic.Int64
+	done     chan struct{} // закрывается при остановке
+	finished chan struct{} // закрывается, когда горутина писателя завершилась
+	errs     chan error
+	ids      []uuid.UUID
+}
+
+// cursorWriterDrivers счетчик фоновых водителей для уникальных контактов (общий для всех тестов)
+var cursorWriterDrivers atomic.Int64
+
+// startWriter запускает фоновую регистрацию водителей
+func (suite *CursorPaginationTestSuite) startWriter(t *testing.T) *cursorWriter {
+	writer := &cursorWriter{
+		created:  make(chan uuid.UUID, cursorWrites),
+		done:     make(chan struct{}),
+		finished: make(chan struct{}),
+		errs:     make(chan error, 1),
+	}
+
+	go func() {
+		defer close(writer.finished)
+		defer close(writer.created)
+		for i := 0; i < cursorWrites; i++ {
+			select {
+			case <-writer.done:
+				return
+			default:
+			}
+
+			n := cursorWriterDrivers.Add(1)
+			driver := fixtures.CreateTestDriver()
+			driver.Phone = fmt.Sprintf("+7900741%04d", n)
+			driver.Email = fmt.Sprintf("cursor.writer%d@example.com", n)
+			driver.LicenseNumber = fmt.Sprintf("CW%08d", n)
+
+			created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+			if err != nil {
+				writer.errs <- err
+				return
+			}
+			writer.count.Add(1)
+			writer.created <- created.ID
+		}
+	}()
+
+	t.Cleanup(func() { writer.stop(t) })
+	return writer
+}
+
+// waitForWrite дожидается, пока фоновый писатель зарегистрирует еще хотя бы одного водителя
+// (или завершится, зарегистрировав всех)
+func (w *cursorWriter) waitForWrite(t *testing.T) {
+	before := w.count.Load()
+	require.Eventually(t, func() bool {
+		select {
+		case <-w.finished:
+			return true
+		default:
+			return w.count.Load() > before
+		}
+	}, cursorWriteWait, time.Millisecond, "Фоновая регистрация водителей остановилась")
+}
+
+// stop останавливает писателя и возвращает ID зарегистрированных им водителей
+func (w *cursorWriter) stop(t *testing.T) []uuid.UUID {
+	select {
+	case <-w.done:
+		return w.ids
+	default:
+		close(w.done)
+	}
+
+	for id := range w.created {
+		w.ids = append(w.ids, id)
+	}
+	select {
+	case err := <-w.errs:
+		require.NoError(t, err, "Фоновая регистрация водителя")
+	default:
+	}
+	return w.ids
+}
+
+// listedIDs возвращает ID водителей списка по порядку
+func listedIDs(drivers []*httpHandlers.DriverResponse) []uuid.UUID {
+	ids := make([]uuid.UUID, len(drivers))
+	for i, driver := range drivers {
+		ids[i] = driver.ID
+	}
+	return ids
+}
+
+// uniqueIDs возвращает ID без повторов в порядке первого появления
+func uniqueIDs(ids []uuid.UUID) []uuid.UUID {
+	seen := make(map[uuid.UUID]bool, len(ids))
+	unique := make([]uuid.UUID, 0, len(ids))
+	for _, id := range ids {
+		if !seen[id] {
+			seen[id] = true
+			unique = append(unique, id)
+		}
+	}
+	return unique
+}
+
+// countPresent возвращает, сколько из ids встречается в seen
+func countPresent(seen map[uuid.UUID]int, ids []uuid.UUID) int {
+	count := 0
+	for _, id := range ids {
+		if seen[id] > 0 {
+			count++
+		}
+	}
+	return count
+}
+
+// TestCursorPaginationTestSuite запускает тестовый suite
+func TestCursorPaginationTestSuite(t *testing.T) {
+	suite.Run(t, new(CursorPaginationTestSuite))
+}
@@ -0,0 +1,184 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"math/rand"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры сценария аварийного восстановления
+const (
+	drDrivers         = 5
+	drLoadWorkers     = 4
+	drLoadInterval    = 20 * time.Millisecond
+	drBackupInterval  = time.Second
+	drMinKillAfter    = 3 * time.Second // не раньше, чем будет снято несколько дампов
+	drKillWindow      = 3 * time.Second // отказ происходит в случайный момент окна [drMinKillAfter, drMinKillAfter+drKillWindow)
+	drMaxRTO          = 30 * time.Second
+	drHealthyInterval = 50 * time.Millisecond
+)
+
+// DisasterRecoveryTestSuite проверяет аварийное восстановление: под нагрузкой и при резервном копировании
+// по расписанию окружение целиком теряет БД в случайный момент, сервис поднимается на БД, восстановленной
+// из последнего дампа, и измеряются окно потери данных (RPO) и время до работоспособности (RTO).
+// Момент отказа определяется TEST_SEED, метрики дописываются в DR_REPORT (см. make test-dr-drill).
+type DisasterRecoveryTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DisasterRecoveryTestSuite) SetupSuite() {
+	helpers.RequireBackupTools(suite.T())
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DisasterRecoveryTestSuite) TearDownSuite() {
+	if suite.env != nil {
+		suite.env.Close(suite.T())
+	}
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DisasterRecoveryTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestRecoverFromLatestBackup тестирует восстановление из последнего дампа после отказа под нагрузкой
+func (suite *DisasterRecoveryTestSuite) TestRecoverFromLatestBackup() {
+	// Arrange
+	seed := helpers.TestSeed(suite.T(), 1700)
+	rng := rand.New(rand.NewSource(seed))
+	killAfter := drMinKillAfter + time.Duration(rng.Int63n(int64(drKillWindow)))
+
+	driverIDs := make([]uuid.UUID, 0, drDrivers)
+	for i := 1; i <= drDrivers; i++ {
+		driverIDs = append(driverIDs, suite.createDriver(i))
+	}
+
+	startedAt := time.Now()
+	load := helpers.StartLocationLoad(suite.env.API, driverIDs, drLoadWorkers, drLoadInterval)
+	defer load.Stop()
+	backups := helpers.StartBackups(suite.T(), suite.env.DB, drBackupInterval)
+	defer backups.Stop()
+
+	// Act - отказ: БД удаляется вместе со всеми подключениями, пока нагрузка продолжается
+	time.Sleep(killAfter)
+	killedAt := time.Now()
+	require.NoError(suite.T(), backups.Stop())
+	suite.env.DB.TeardownTestDB(suite.T())
+	report := load.Stop()
+
+	latest, ok := backups.Latest()
+	require.True(suite.T(), ok, "До отказа не снято ни одного дампа")
+
+	// Восстановление из последнего дампа и ожидание работоспособности
+	suite.env.SwitchDB(suite.T(), helpers.RestoreTestDB(suite.T(), latest.Path))
+	helpers.Eventually(suite.T(), func(c *helpers.EventuallyT) {
+		suite.assertHealthy(c, driverIDs[0])
+	}, drMaxRTO, drHealthyInterval)
+	healthyAt := time.Now()
+
+	// Assert
+	restored := suite.restoredLocations()
+	var lost []uuid.UUID
+	for i, id := range report.Accepted {
+		if restored[id] {
+			continue
+		}
+		lost = append(lost, id)
+		assert.True(suite.T(), report.AcceptedAt[i].After(latest.StartedAt),
+			"Потеряно местоположение %s, подтвержденное до запуска последнего дампа", id)
+	}
+
+	suite.T().Run("NoFailuresBeforeKill", func(t *testing.T) {
+		for _, failure := range report.Failures {
+			assert.False(t, failure.At.Before(killedAt), "Запрос завершился ошибкой до отказа: %d %s",
+				failure.StatusCode, failure.Body)
+		}
+	})
+
+	suite.T().Run("DriversRestored", func(t *testing.T) {
+		for _, id := range driverIDs {
+			_, err := suite.env.DriverRepo.GetByID(suite.env.Ctx, id)
+			assert.NoError(t, err, "Водитель %s", id)
+		}
+	})
+
+	metrics := helpers.DRMetrics{
+		Seed:           seed,
+		KilledAfter:    killedAt.Sub(startedAt),
+		RPO:            killedAt.Sub(latest.StartedAt),
+		RTO:            healthyAt.Sub(killedAt),
+		BackupsTaken:   backups.Count(),
+		AcceptedWrites: len(report.Accepted),
+		LostWrites:     len(lost),
+	}
+	assert.Less(suite.T(), metrics.RPO, killAfter, "Окно потери данных больше времени до отказа")
+	assert.Less(suite.T(), metrics.RTO, drMaxRTO)
+	helpers.WriteDRReport(suite.T(), metrics)
+}
+
+// assertHealthy проверяет, что восстановленный сервис отвечает и принимает запись
+func (suite *DisasterRecoveryTestSuite) assertHealthy(c *helpers.EventuallyT, driverID uuid.UUID) {
+	api := suite.env.API
+
+	response := api.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health"})
+	assert.Equal(c, http.StatusOK, response.StatusCode)
+
+	response = api.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    api.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+	})
+	assert.Equal(c, http.StatusOK, response.StatusCode, string(response.Body))
+
+	response = api.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    api.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body:   helpers.CreateLocationRequest(),
+	})
+	assert.Equal(c, http.StatusOK, response.StatusCode, string(response.Body))
+}
+
+// restoredLocations возвращает ID местоположений, сохранившихся в восстановленной БД
+func (suite *DisasterRecoveryTestSuite) restoredLocations() map[uuid.UUID]bool {
+	var ids []uuid.UUID
+	require.NoError(suite.T(), suite.env.DB.SelectContext(suite.env.Ctx, &ids, "SELECT id FROM driver_locations"))
+
+	restored := make(map[uuid.UUID]bool, len(ids))
+	for _, id := range ids {
+		restored[id] = true
+	}
+	return restored
+}
+
+// createDriver создает доступного водителя с уникальными контактами через сервис
+func (suite *DisasterRecoveryTestSuite) createDriver(n int) uuid.UUID {
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900777%04d", n)
+	driver.Email = fmt.Sprintf("dr.driver%d@example.com", n)
+	driver.LicenseNumber = fmt.Sprintf("DR%08d", n)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, entities.StatusAvailable))
+	return created.ID
+}
+
+func TestDisasterRecoveryTestSuite(t *testing.T) {
+	suite.Run(t, new(DisasterRecoveryTestSuite))
+}
@@ -0,0 +1,234 @@
+//go:build integration
+
+package integration
+
+import (
+	"errors"
+	"fmt"
+	"math/rand"
+	"net/http"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры сценария диспетчеризации
+const (
+	dispatchClusterRadiusKm     = 1.0  // водители и заказы сосредоточены в круге этого радиуса
+	dispatchSearchRadiusKm      = 5.0  // радиус поиска водителей для заказа
+	dispatchDistanceToleranceKm = 0.05 // допуск сравнения расстояний (погрешность хранения координат)
+)
+
+// DispatchFairnessTestSuite тестовый suite для одновременного назначения нескольких заказов водителям рядом
+type DispatchFairnessTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DispatchFairnessTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DispatchFairnessTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DispatchFairnessTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	// Назначение меняет статусы водителей, а кэш поиска поблизости статусы не отслеживает
+	suite.env.SetFeature(suite.T(), features.GeoCache, false)
+}
+
+// TestConcurrentDispatchFairness тестирует одновременное назначение M заказов N водителям, стоящим рядом:
+// ни один водитель не получает два заказа, назначается ближайший свободный водитель, исход совпадает
+// в событиях order.assigned и в состоянии API
+func (suite *DispatchFairnessTestSuite) TestConcurrentDispatchFairness() {
+	testCases := []struct {
+		name    string
+		drivers int
+		orders  int
+	}{
+		{name: "MoreDriversThanOrders", drivers: 8, orders: 5},
+		{name: "AsManyDriversAsOrders", drivers: 6, orders: 6},
+		{name: "FewerDriversThanOrders", drivers: 4, orders: 9},
+	}
+
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	seed := helpers.TestSeed(suite.T(), 7)
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			suite.env.DB.CleanupTables(t)
+			suite.env.Events.Reset()
+
+			rng := rand.New(rand.NewSource(seed))
+			driverPoints := fixtures.CreateRandomPointsAround(rng, center, dispatchClusterRadiusKm, tc.drivers)
+			positions := suite.seedOnShiftDrivers(t, driverPoints)
+
+			orders := make([]helpers.OrderRequest, tc.orders)
+			for i, point := range fixtures.CreateRandomPointsAround(rng, center, dispatchClusterRadiusKm, tc.orders) {
+				orders[i] = helpers.OrderRequest{OrderID: uuid.New(), Latitude: point.Latitude, Longitude: point.Longitude}
+			}
+
+			dispatcher := helpers.NewDispatcher(suite.env.API, suite.env.Events, dispatchSearchRadiusKm)
+
+			// Act
+			assignments := make([]*helpers.OrderAssignment, len(orders))
+			errs := make([]error, len(orders))
+
+			var wg sync.WaitGroup
+			start := make(chan struct{})
+			for i, order := range orders {
+				wg.Add(1)
+				go func(i int, order helpers.OrderRequest) {
+					defer wg.Done()
+					<-start
+					assignments[i], errs[i] = dispatcher.Dispatch(suite.env.Ctx, order)
+				}(i, order)
+			}
+			close(start)
+			wg.Wait()
+
+			// Примечание: подписчик сервиса на order.assigned (nats.RegisterOrderHandlers) отслеживание заказа
+			// пока не начинает. Обработчик ниже делает то, что должен делать он: начинает отслеживание заказа
+			// по местоположению водителя.
+			for _, event := range suite.env.Events.EventsOfType("order.assigned") {
+				orderID, err := uuid.Parse(event.DataMap()["order_id"].(string))
+				require.NoError(t, err)
+				require.NoError(t, suite.env.LocationService.StartOrderTracking(suite.env.Ctx, event.DriverID, orderID))
+			}
+
+			// Assert
+			assigned := make(map[uuid.UUID]*helpers.OrderAssignment)
+			for i, order := range orders {
+				if errors.Is(errs[i], helpers.ErrNoDriverAvailable) {
+					continue
+				}
+				require.NoError(t, errs[i], "Заказ %s", order.OrderID)
+				assigned[order.OrderID] = assignments[i]
+			}
+
+			expectedAssigned := tc.orders
+			if tc.drivers < expectedAssigned {
+				expectedAssigned = tc.drivers
+			}
+			assert.Len(t, assigned, expectedAssigned, "Заказ не должен оставаться без водителя, пока есть свободные")
+
+			// Перехваченные события: каждый заказ назначен один раз, у каждого водителя не больше одного заказа
+			ordersByDriver := make(map[uuid.UUID][]string)
+			ordersSeen := make(map[string]int)
+			for _, event := range suite.env.Events.EventsOfType("order.assigned") {
+				orderID := event.DataMap()["order_id"].(string)
+				ordersByDriver[event.DriverID] = append(ordersByDriver[event.DriverID], orderID)
+				ordersSeen[orderID]++
+			}
+			for driverID, driverOrders := range ordersByDriver {
+				assert.Len(t, driverOrders, 1, "Водитель %s получил несколько заказов одновременно: %v", driverID, driverOrders)
+			}
+			for orderID, count := range ordersSeen {
+				assert.Equal(t, 1, count, "Заказ %s назначен %d раз", orderID, count)
+			}
+			assert.Len(t, ordersSeen, len(assigned))
+
+			// Состояние API: назначенные водители заняты заказом, остальные свободны
+			busyDrivers := make(map[uuid.UUID]uuid.UUID)
+			for _, assignment := range assigned {
+				busyDrivers[assignment.DriverID] = assignment.OrderID
+			}
+
+			var freeDrivers []uuid.UUID
+			for driverID := range positions {
+				status := suite.driverStatus(t, driverID)
+				orderID, busy := busyDrivers[driverID]
+				if !busy {
+					assert.Equal(t, entities.StatusOnShift, status, "Водитель %s без заказа должен оставаться свободным", driverID)
+					freeDrivers = append(freeDrivers, driverID)
+					continue
+				}
+
+				assert.Equal(t, entities.StatusBusy, status, "Водитель %s назначен на заказ %s", driverID, orderID)
+
+				assert.Equal(t, []string{orderID.String()}, suite.trackedOrders(t, driverID), "Отслеживается не тот заказ")
+			}
+
+			// Предпочтение ближайшего: ни один оставшийся свободным водитель не ближе к заказу, чем назначенный
+			for _, order := range orders {
+				assignment, ok := assigned[order.OrderID]
+				if !ok {
+					continue
+				}
+
+				for _, driverID := range freeDrivers {
+					position := positions[driverID]
+					distance := helpers.HaversineKm(order.Latitude, order.Longitude, position.Latitude, position.Longitude)
+					assert.LessOrEqual(t, assignment.DistanceKm, distance+dispatchDistanceToleranceKm,
+						"Заказ %s назначен водителю в %.3f км, хотя свободный водитель %s в %.3f км",
+						order.OrderID, assignment.DistanceKm, driverID, distance)
+				}
+			}
+		})
+	}
+}
+
+// seedOnShiftDrivers создает по одному водителю на смене в каждой точке и возвращает их местоположения
+func (suite *DispatchFairnessTestSuite) seedOnShiftDrivers(t *testing.T, points []fixtures.RoutePoint) map[uuid.UUID]fixtures.RoutePoint {
+	drivers := fixtures.CreateMultipleTestDrivers(len(points))
+	recordedAt := time.Now().Add(-time.Minute)
+
+	positions := make(map[uuid.UUID]fixtures.RoutePoint, len(points))
+	for i, point := range points {
+		createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, drivers[i])
+		require.NoError(t, err)
+
+		// Обходим цепочку переходов статусов - для диспетчеризации важен только итоговый статус
+		require.NoError(t, suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, createdDriver.ID, entities.StatusOnShift))
+
+		location := entities.NewDriverLocation(createdDriver.ID, point.Latitude, point.Longitude, recordedAt)
+		require.NoError(t, suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+
+		positions[createdDriver.ID] = point
+	}
+	return positions
+}
+
+// driverStatus возвращает статус водителя через API
+func (suite *DispatchFairnessTestSuite) driverStatus(t *testing.T, driverID uuid.UUID) entities.Status {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s", driverID),
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return driver.Status
+}
+
+// trackedOrders возвращает заказы, отслеживание которых начато по местоположению водителя
+func (suite *DispatchFairnessTestSuite) trackedOrders(t *testing.T, driverID uuid.UUID) []string {
+	var orderIDs []string
+	err := suite.env.DB.SelectContext(suite.env.Ctx, &orderIDs,
+		"SELECT metadata->>'order_id' FROM driver_locations WHERE driver_id = $1 AND metadata->>'order_id' IS NOT NULL", driverID)
+	require.NoError(t, err)
+	return orderIDs
+}
+
+// TestDispatchFairnessTestSuite запускает тестовый suite
+func TestDispatchFairnessTestSuite(t *testing.T) {
+	suite.Run(t, new(DispatchFairnessTestSuite))
+}
@@ -0,0 +1,354 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Время ожидания обновлений потока и тишины после последнего ожидаемого обновления
+const (
+	dispatchFeedTimeout = 2 * time.Second
+	dispatchFeedQuiet   = 200 * time.Millisecond
+)
+
+// Области диспетчеров: центр Москвы и Санкт-Петербург
+var (
+	dispatchMoscowArea = entities.GeoBounds{
+		SouthWest: entities.Location{Latitude: 55.70, Longitude: 37.50},
+		NorthEast: entities.Location{Latitude: 55.80, Longitude: 37.70},
+	}
+	dispatchPetersburgArea = entities.GeoBounds{
+		SouthWest: entities.Location{Latitude: 59.90, Longitude: 30.20},
+		NorthEast: entities.Location{Latitude: 60.00, Longitude: 30.40},
+	}
+)
+
+// DispatchFeedTestSuite тестирует поток позиций водителей для диспетчеров (GET /api/v1/locations/feed):
+// подписка на область, перемещение водителей через API, фильтрация обновлений по границам, рассылка
+// между репликами через NATS и завершение после отписки
+type DispatchFeedTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DispatchFeedTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DispatchFeedTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DispatchFeedTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestUpdatesFilteredToArea тестирует, что подписчик получает только позиции внутри своей области
+func (suite *DispatchFeedTestSuite) TestUpdatesFilteredToArea() {
+	// Arrange
+	inside := suite.createDriver()
+	outside := suite.createDriver()
+	suite.moveDriver(inside, 55.7000, 37.4000) // до подписки и вне области
+
+	feed := suite.subscribe(dispatchMoscowArea)
+
+	// Act
+	suite.moveDriver(outside, 59.9343, 30.3351)
+	entered := suite.moveDriver(inside, 55.7558, 37.6173)
+	suite.moveDriver(outside, 55.8500, 37.6173)
+	moved := suite.moveDriver(inside, 55.7600, 37.6200)
+	suite.moveDriver(inside, 55.7600, 37.8000) // водитель покинул область
+
+	// Assert
+	received := suite.receive(feed, 2)
+	assert.Equal(suite.T(), []uuid.UUID{entered, moved}, locationIDs(received))
+	for _, location := range received {
+		assert.Equal(suite.T(), inside, location.DriverID)
+		assert.True(suite.T(), dispatchMoscowArea.Contains(location.Latitude, location.Longitude),
+			"Позиция %.4f, %.4f вне области", location.Latitude, location.Longitude)
+	}
+	suite.assertNoUpdates(feed)
+}
+
+// TestBatchUpdatesFilteredToArea тестирует фильтрацию позиций, пришедших пакетом
+func (suite *DispatchFeedTestSuite) TestBatchUpdatesFilteredToArea() {
+	// Arrange
+	driverID := suite.createDriver()
+	feed := suite.subscribe(dispatchMoscowArea)
+	now := time.Now()
+
+	// Act - поездка из области в Санкт-Петербург
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations/batch", driverID)),
+		Body: map[string]interface{}{
+			"locations": []map[string]interface{}{
+				{"latitude": 55.7558, "longitude": 37.6173, "timestamp": now.Add(-3 * time.Minute).Unix()},
+				{"latitude": 55.7900, "longitude": 37.6500, "timestamp": now.Add(-2 * time.Minute).Unix()},
+				{"latitude": 56.8000, "longitude": 35.9000, "timestamp": now.Add(-time.Minute).Unix()},
+				{"latitude": 59.9343, "longitude": 30.3351, "timestamp": now.Unix()},
+			},
+		},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	// Assert
+	received := suite.receive(feed, 2)
+	assert.InDelta(suite.T(), 55.7558, received[0].Latitude, 1e-9)
+	assert.InDelta(suite.T(), 55.7900, received[1].Latitude, 1e-9)
+	suite.assertNoUpdates(feed)
+}
+
+// TestSubscribersWatchIndependentAreas тестирует, что подписки на разные области не мешают друг другу
+func (suite *DispatchFeedTestSuite) TestSubscribersWatchIndependentAreas() {
+	// Arrange
+	moscowDriver := suite.createDriver()
+	petersburgDriver := suite.createDriver()
+	moscowFeed := suite.subscribe(dispatchMoscowArea)
+	petersburgFeed := suite.subscribe(dispatchPetersburgArea)
+	overlappingFeed := suite.subscribe(dispatchMoscowArea)
+
+	// Act
+	moscowLocation := suite.moveDriver(moscowDriver, 55.7558, 37.6173)
+	petersburgLocation := suite.moveDriver(petersburgDriver, 59.9343, 30.3351)
+
+	// Assert
+	assert.Equal(suite.T(), []uuid.UUID{moscowLocation}, locationIDs(suite.receive(moscowFeed, 1)))
+	assert.Equal(suite.T(), []uuid.UUID{moscowLocation}, locationIDs(suite.receive(overlappingFeed, 1)))
+	assert.Equal(suite.T(), []uuid.UUID{petersburgLocation}, locationIDs(suite.receive(petersburgFeed, 1)))
+
+	suite.assertNoUpdates(moscowFeed)
+	suite.assertNoUpdates(petersburgFeed)
+}
+
+// TestStreamStopsAfterUnsubscribe тестирует завершение потока после отписки
+func (suite *DispatchFeedTestSuite) TestStreamStopsAfterUnsubscribe() {
+	// Arrange
+	driverID := suite.createDriver()
+	subscribers := suite.env.DispatchFeed.Subscribers()
+
+	ctx, unsubscribe := context.WithCancel(suite.env.Ctx)
+	feed, err := suite.env.DispatchFeed.SubscribeArea(ctx, dispatchMoscowArea)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), subscribers+1, suite.env.DispatchFeed.Subscribers())
+
+	location := suite.moveDriver(driverID, 55.7558, 37.6173)
+	assert.Equal(suite.T(), []uuid.UUID{location}, locationIDs(suite.receive(feed, 1)))
+
+	// Act
+	unsubscribe()
+
+	// Assert - поток закрыт, подписка удалена, запись местоположений продолжает работать
+	select {
+	case update, ok := <-feed:
+		assert.False(suite.T(), ok, "После отписки получено обновление %v", update)
+	case <-time.After(dispatchFeedTimeout):
+		suite.T().Fatal("Поток не закрылся после отписки")
+	}
+
+	helpers.WaitForCondition(suite.T(), func() bool {
+		return suite.env.DispatchFeed.Subscribers() == subscribers
+	}, dispatchFeedTimeout, "subscription removed")
+
+	suite.moveDriver(driverID, 55.7600, 37.6200)
+}
+
+// TestInvalidAreaRejected тестирует отказ в подписке на некорректную область
+func (suite *DispatchFeedTestSuite) TestInvalidAreaRejected() {
+	tests := []struct {
+		name   string
+		bounds entities.GeoBounds
+	}{
+		{
+			name: "inverted latitude",
+			bounds: entities.GeoBounds{
+				SouthWest: dispatchMoscowArea.NorthEast,
+				NorthEast: dispatchMoscowArea.SouthWest,
+			},
+		},
+		{
+			name: "latitude out of range",
+			bounds: entities.GeoBounds{
+				SouthWest: entities.Location{Latitude: -91, Longitude: 0},
+				NorthEast: entities.Location{Latitude: 10, Longitude: 10},
+			},
+		},
+	}
+
+	for _, tt := range tests {
+		suite.T().Run(tt.name, func(t *testing.T) {
+			_, err := suite.env.DispatchFeed.SubscribeArea(suite.env.Ctx, tt.bounds)
+			assert.ErrorIs(t, err, entities.ErrInvalidLocation)
+		})
+	}
+}
+
+// TestFeedEndpointRejectsInvalidArea тестирует ответ HTTP потока на некорректные границы области
+func (suite *DispatchFeedTestSuite) TestFeedEndpointRejectsInvalidArea() {
+	tests := []struct {
+		name         string
+		query        map[string]string
+		expectedCode string
+	}{
+		{
+			name:  "missing corner",
+			query: map[string]string{"sw_lat": "55.70", "sw_lon": "37.50", "ne_lat": "55.80"},
+		},
+		{
+			name:         "inverted latitude",
+			query:        map[string]string{"sw_lat": "55.80", "sw_lon": "37.50", "ne_lat": "55.70", "ne_lon": "37.70"},
+			expectedCode: "INVALID_LOCATION",
+		},
+	}
+
+	for _, tt := range tests {
+		suite.T().Run(tt.name, func(t *testing.T) {
+			env := suite.env.ForTest(t)
+			response := env.API.MakeRequest(helpers.APIRequest{
+				Method:      http.MethodGet,
+				URL:         env.API.APIPath("/locations/feed"),
+				QueryParams: tt.query,
+			})
+
+			env.API.AssertStatusCode(response, http.StatusBadRequest)
+			if tt.expectedCode != "" {
+				env.API.AssertErrorResponse(response, tt.expectedCode)
+			}
+		})
+	}
+}
+
+// TestUpdatesReachSubscribersOfOtherReplicas тестирует, что диспетчер, подключенный к другой реплике,
+// получает местоположения, сохраненные этой репликой
+func (suite *DispatchFeedTestSuite) TestUpdatesReachSubscribersOfOtherReplicas() {
+	// Arrange
+	natsHelper := helpers.NewNATSHelper(suite.T())
+	writer := services.NewDispatchFeed(suite.env.LocationService, suite.env.Logger)
+	writer.UseRelay(natsHelper.NewLocationRelay(writer.Deliver))
+	reader := services.NewDispatchFeed(suite.env.LocationService, suite.env.Logger)
+	reader.UseRelay(natsHelper.NewLocationRelay(reader.Deliver))
+
+	ctx, cancel := context.WithCancel(suite.env.Ctx)
+	defer cancel()
+	feed, err := reader.SubscribeArea(ctx, dispatchMoscowArea)
+	require.NoError(suite.T(), err)
+	driverID := suite.createDriver()
+
+	// Act
+	outside := entities.NewDriverLocation(driverID, 59.9343, 30.3351, time.Now().Add(-time.Second))
+	inside := entities.NewDriverLocation(driverID, 55.7558, 37.6173, time.Now())
+	require.NoError(suite.T(), writer.UpdateLocation(suite.env.Ctx, outside))
+	require.NoError(suite.T(), writer.UpdateLocation(suite.env.Ctx, inside))
+
+	// Assert
+	received := suite.receive(feed, 1)
+	assert.Equal(suite.T(), inside.ID, received[0].ID)
+	assert.Equal(suite.T(), driverID, received[0].DriverID)
+	assert.InDelta(suite.T(), inside.Latitude, received[0].Latitude, 1e-9)
+	suite.assertNoUpdates(feed)
+}
+
+// subscribe подписывается на область до конца теста
+func (suite *DispatchFeedTestSuite) subscribe(bounds entities.GeoBounds) <-chan *entities.DriverLocation {
+	ctx, cancel := context.WithCancel(suite.env.Ctx)
+	suite.T().Cleanup(cancel)
+
+	feed, err := suite.env.DispatchFeed.SubscribeArea(ctx, bounds)
+	require.NoError(suite.T(), err)
+	return feed
+}
+
+// receive ждет count обновлений потока
+func (suite *DispatchFeedTestSuite) receive(feed <-chan *entities.DriverLocation, count int) []*entities.DriverLocation {
+	received := make([]*entities.DriverLocation, 0, count)
+	timeout := time.After(dispatchFeedTimeout)
+	for len(received) < count {
+		select {
+		case location, ok := <-feed:
+			require.True(suite.T(), ok, "Поток закрылся, получено %d из %d обновлений", len(received), count)
+			received = append(received, location)
+		case <-timeout:
+			suite.T().Fatalf("Получено %d из %d обновлений потока", len(received), count)
+		}
+	}
+	return received
+}
+
+// assertNoUpdates проверяет, что в поток больше не приходят обновления
+func (suite *DispatchFeedTestSuite) assertNoUpdates(feed <-chan *entities.DriverLocation) {
+	select {
+	case location := <-feed:
+		suite.T().Errorf("Неожиданное обновление водителя %s: %.4f, %.4f",
+			location.DriverID, location.Latitude, location.Longitude)
+	case <-time.After(dispatchFeedQuiet):
+	}
+}
+
+// createDriver создает водителя через API
+func (suite *DispatchFeedTestSuite) createDriver() uuid.UUID {
+	suite.drivers++
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7900666%04d", suite.drivers)
+	request["email"] = fmt.Sprintf("dispatch.driver%d@example.com", suite.drivers)
+	request["license_number"] = fmt.Sprintf("DF%08d", suite.drivers)
+
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+	require.Equal(suite.T(), http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return driver.ID
+}
+
+// moveDriver отправляет местоположение водителя через API и возвращает ID сохраненного местоположения
+func (suite *DispatchFeedTestSuite) moveDriver(driverID uuid.UUID, lat, lon float64) uuid.UUID {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body: map[string]interface{}{
+			"latitude":  lat,
+			"longitude": lon,
+		},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	var location httpHandlers.LocationResponse
+	suite.env.API.UnmarshalResponse(response, &location)
+	return location.ID
+}
+
+// locationIDs возвращает ID местоположений в порядке получения
+func locationIDs(locations []*entities.DriverLocation) []uuid.UUID {
+	ids := make([]uuid.UUID, 0, len(locations))
+	for _, location := range locations {
+		ids = append(ids, location.ID)
+	}
+	return ids
+}
+
+func TestDispatchFeedTestSuite(t *testing.T) {
+	suite.Run(t, new(DispatchFeedTestSuite))
+}
@@ -0,0 +1,247 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// DocumentCountryTestSuite тестирует регистрацию водителей с паспортом и ВУ не только российского образца:
+// при строгой валидации серия и номер паспорта и номер ВУ проверяются по формату страны выдачи (поле country)
+type DocumentCountryTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных контактов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DocumentCountryTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DocumentCountryTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DocumentCountryTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.env.SetFeature(suite.T(), features.StrictValidation, true)
+}
+
+// TestRegisterWithCountryDocuments тестирует регистрацию водителя с документами каждой поддерживаемой страны
+func (suite *DocumentCountryTestSuite) TestRegisterWithCountryDocuments() {
+	for _, documents := range fixtures.InternationalDocuments() {
+		suite.T().Run(string(documents.Country), func(t *testing.T) {
+			// Arrange
+			request := suite.driverRequest(documents)
+
+			// Act
+			response := suite.createDriver(request)
+
+			// Assert
+			require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+			var driver httpHandlers.DriverResponse
+			suite.env.API.UnmarshalResponse(response, &driver)
+			assert.Equal(t, string(documents.Country), driver.Metadata["document_country"])
+			assert.Equal(t, documents.PassportSeries, driver.PassportSeries)
+			assert.Equal(t, request["license_number"], driver.LicenseNumber)
+		})
+	}
+}
+
+// TestRejectInvalidCountryDocuments тестирует отклонение документов, нарушающих формат страны выдачи
+func (suite *DocumentCountryTestSuite) TestRejectInvalidCountryDocuments() {
+	for _, documents := range fixtures.InternationalDocuments() {
+		for _, invalid := range documents.Invalid {
+			suite.T().Run(fmt.Sprintf("%s/%s", documents.Country, invalid.Name), func(t *testing.T) {
+				// Arrange
+				request := suite.driverRequest(documents)
+				request[invalid.Field] = invalid.Value
+
+				// Act
+				response := suite.createDriver(request)
+
+				// Assert
+				suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+				suite.env.API.AssertErrorResponse(response, "INVALID_DATA")
+			})
+		}
+	}
+}
+
+// TestCountryDefaultsToRussia тестирует, что без country документы проверяются по российскому формату
+func (suite *DocumentCountryTestSuite) TestCountryDefaultsToRussia() {
+	// Arrange
+	belarus := suite.documents(entities.CountryBY)
+	request := suite.driverRequest(belarus)
+	delete(request, "country")
+
+	// Act
+	response := suite.createDriver(request)
+
+	// Assert
+	suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+	suite.env.API.AssertErrorResponse(response, "INVALID_DATA")
+
+	// Act
+	request["country"] = string(entities.CountryBY)
+	response = suite.createDriver(request)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusCreated, response.StatusCode, string(response.Body))
+}
+
+// TestCountryCode тестирует разбор кода страны: регистр не важен, неподдерживаемая страна отклоняется
+// и без строгой валидации
+func (suite *DocumentCountryTestSuite) TestCountryCode() {
+	kazakhstan := suite.documents(entities.CountryKZ)
+
+	suite.T().Run("Lowercase", func(t *testing.T) {
+		request := suite.driverRequest(kazakhstan)
+		request["country"] = "kz"
+
+		response := suite.createDriver(request)
+
+		require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+		var driver httpHandlers.DriverResponse
+		suite.env.API.UnmarshalResponse(response, &driver)
+		assert.Equal(t, "KZ", driver.Metadata["document_country"])
+	})
+
+	for _, strict := range []bool{true, false} {
+		suite.T().Run(fmt.Sprintf("Unsupported/strict=%t", strict), func(t *testing.T) {
+			suite.env.SetFeature(t, features.StrictValidation, strict)
+			request := suite.driverRequest(kazakhstan)
+			request["country"] = "DE"
+
+			response := suite.createDriver(request)
+
+			suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+			suite.env.API.AssertErrorResponse(response, "INVALID_DATA")
+		})
+	}
+}
+
+// TestFormatsNotEnforcedWithoutStrictValidation тестирует, что без строгой валидации формат документов
+// не проверяется, как и до появления стран
+func (suite *DocumentCountryTestSuite) TestFormatsNotEnforcedWithoutStrictValidation() {
+	// Arrange
+	suite.env.SetFeature(suite.T(), features.StrictValidation, false)
+
+	for _, documents := range fixtures.InternationalDocuments() {
+		for _, invalid := range documents.Invalid {
+			request := suite.driverRequest(documents)
+			request[invalid.Field] = invalid.Value
+
+			// Act
+			response := suite.createDriver(request)
+
+			// Assert
+			assert.Equal(suite.T(), http.StatusCreated, response.StatusCode,
+				"%s/%s: %s", documents.Country, invalid.Name, string(response.Body))
+		}
+	}
+}
+
+// TestUpdateCountryRevalidatesDocuments тестирует, что смена страны при обновлении проверяет
+// сохраненные документы по формату новой страны, а отклоненное обновление не меняет страну
+func (suite *DocumentCountryTestSuite) TestUpdateCountryRevalidatesDocuments() {
+	// Arrange
+	uzbekistan := suite.documents(entities.CountryUZ)
+	created := suite.createDriver(suite.driverRequest(uzbekistan))
+	require.Equal(suite.T(), http.StatusCreated, created.StatusCode, string(created.Body))
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(created, &driver)
+
+	// Act - паспорт Узбекистана не подходит под формат Казахстана
+	response := suite.updateDriver(driver.ID, map[string]interface{}{"country": "KZ"})
+
+	// Assert
+	suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+	suite.env.API.AssertErrorResponse(response, "INVALID_DATA")
+
+	// Act - новый паспорт той же страны
+	response = suite.updateDriver(driver.ID, map[string]interface{}{
+		"country":         "UZ",
+		"passport_series": "FA",
+		"passport_number": "7654321",
+	})
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	var updated httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &updated)
+	assert.Equal(suite.T(), "UZ", updated.Metadata["document_country"], "Отклоненная смена страны не сохранилась")
+	assert.Equal(suite.T(), "FA", updated.PassportSeries)
+}
+
+// documents возвращает образцы документов страны из фикстур
+func (suite *DocumentCountryTestSuite) documents(country entities.Country) fixtures.CountryDocuments {
+	for _, documents := range fixtures.InternationalDocuments() {
+		if documents.Country == country {
+			return documents
+		}
+	}
+	suite.T().Fatalf("no document fixtures for %s", country)
+	return fixtures.CountryDocuments{}
+}
+
+// driverRequest возвращает запрос на регистрацию водителя с документами страны и уникальными контактами
+func (suite *DocumentCountryTestSuite) driverRequest(documents fixtures.CountryDocuments) map[string]interface{} {
+	suite.drivers++
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("%s%0*d", documents.PhonePrefix, documents.PhoneDigits, suite.drivers)
+	request["email"] = fmt.Sprintf("country.%s.driver%d@example.com", strings.ToLower(string(documents.Country)), suite.drivers)
+	request["country"] = string(documents.Country)
+	request["passport_series"] = documents.PassportSeries
+	request["passport_number"] = documents.PassportNumber
+	request["license_number"] = uniqueLicenseNumber(documents.LicenseNumber, suite.drivers)
+	request["license_expiry"] = time.Now().AddDate(2, 0, 0).UTC().Format(time.RFC3339)
+	return request
+}
+
+// uniqueLicenseNumber заменяет последние 4 цифры номера ВУ номером водителя: формат страны сохраняется,
+// а номер не повторяется (license_number уникален)
+func uniqueLicenseNumber(license string, n int) string {
+	return fmt.Sprintf("%s%04d", license[:len(license)-4], n%10000)
+}
+
+// createDriver регистрирует водителя через API
+func (suite *DocumentCountryTestSuite) createDriver(request map[string]interface{}) *helpers.APIResponse {
+	return suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+}
+
+// updateDriver обновляет водителя через API
+func (suite *DocumentCountryTestSuite) updateDriver(driverID uuid.UUID, request map[string]interface{}) *helpers.APIResponse {
+	return suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPut,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+		Body:   request,
+	})
+}
+
+// TestDocumentCountryTestSuite запускает тестовый suite
+func TestDocumentCountryTestSuite(t *testing.T) {
+	suite.Run(t, new(DocumentCountryTestSuite))
+}
@@ -0,0 +1,201 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"fmt"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/repositories"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// DocumentExpiryTestSuite тестовый suite для жизненного цикла истекающих документов
+type DocumentExpiryTestSuite struct {
+	suite.Suite
+	testDB        *helpers.TestDB
+	driverRepo    repositories.DriverRepository
+	documentRepo  repositories.DocumentRepository
+	driverService services.DriverService
+	events        *helpers.EventRecorder
+	ctx           context.Context
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DocumentExpiryTestSuite) SetupSuite() {
+	suite.testDB = helpers.SetupTestDB(suite.T())
+	logger := helpers.CreateTestLogger(suite.T())
+	suite.ctx = context.Background()
+
+	suite.driverRepo = repositories.NewDriverRepository(suite.testDB.DB, logger)
+	suite.documentRepo = repositories.NewDocumentRepository(suite.testDB.DB, logger)
+	suite.events = helpers.NewEventRecorder()
+
+	suite.driverService = services.NewDriverService(suite.driverRepo, suite.documentRepo, suite.events, logger)
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DocumentExpiryTestSuite) TearDownSuite() {
+	suite.testDB.TeardownTestDB(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DocumentExpiryTestSuite) SetupTest() {
+	suite.testDB.CleanupTables(suite.T())
+	suite.events.Reset()
+}
+
+// TestLicenseExpiryWorkflow тестирует путь водителя с удостоверением, срок которого истекает
+func (suite *DocumentExpiryTestSuite) TestLicenseExpiryWorkflow() {
+	suite.T().Log("Step 1: Available driver with a verified license expiring in 3 days")
+	driverID := suite.createAvailableDriver(fixtures.CreateTestDriver())
+	license := suite.createVerifiedLicense(driverID, time.Now().AddDate(0, 0, 3))
+
+	require.NoError(suite.T(), suite.driverService.ValidateDriverForOrder(suite.ctx, driverID))
+
+	suite.T().Log("Step 2: License is reported as expiring within the notification window")
+	suite.assertExpiring(license.ID, 7, true)
+	suite.assertExpiring(license.ID, 1, false)
+
+	suite.T().Log("Step 3: Time passes and the license expires")
+	suite.moveExpiryDate(license.ID, time.Now().AddDate(0, 0, -1))
+
+	err := suite.driverService.ValidateDriverForOrder(suite.ctx, driverID)
+	assert.ErrorIs(suite.T(), err, entities.ErrDocumentNotVerified, "Водитель с истекшим удостоверением не должен получать заказы")
+
+	suite.T().Log("Step 4: Expired license is picked up and marked as expired")
+	expired, err := suite.documentRepo.GetExpired(suite.ctx)
+	require.NoError(suite.T(), err)
+	require.Len(suite.T(), expired, 1)
+	assert.Equal(suite.T(), license.ID, expired[0].ID)
+
+	require.NoError(suite.T(), suite.documentRepo.MarkExpired(suite.ctx, []uuid.UUID{license.ID}))
+
+	stored, err := suite.documentRepo.GetByID(suite.ctx, license.ID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), entities.VerificationStatusExpired, stored.Status)
+
+	expired, err = suite.documentRepo.GetExpired(suite.ctx)
+	require.NoError(suite.T(), err)
+	assert.Empty(suite.T(), expired, "Помеченный документ не должен обрабатываться повторно")
+
+	suite.T().Log("Step 5: Driver status and events after expiry")
+	driver, err := suite.driverService.GetDriverByID(suite.ctx, driverID)
+	require.NoError(suite.T(), err)
+
+	// В данной реализации фоновой задачи истечения документов нет: статус водителя не меняется,
+	// а события driver.document.expiring не публикуются.
+	// Примечание: когда задача будет реализована, здесь следует ожидать статус pending_verification
+	// и ровно одно событие driver.document.expiring с document_id удостоверения.
+	assert.Contains(suite.T(), []entities.Status{entities.StatusAvailable, entities.StatusPendingVerification}, driver.Status)
+
+	expiringEvents := suite.events.EventsForDriver(driverID, "driver.document.expiring")
+	assert.LessOrEqual(suite.T(), len(expiringEvents), 1)
+	for _, event := range expiringEvents {
+		assert.Equal(suite.T(), license.ID.String(), event.DataMap()["document_id"])
+	}
+}
+
+// TestExpiringWindows тестирует выборку истекающих документов для разных окон уведомления
+func (suite *DocumentExpiryTestSuite) TestExpiringWindows() {
+	// Arrange
+	drivers := fixtures.CreateMultipleTestDrivers(4)
+	expiresInDays := []int{1, 7, 30, 90}
+	documentIDs := make(map[int]uuid.UUID)
+
+	for i, days := range expiresInDays {
+		driverID := suite.createAvailableDriver(drivers[i])
+		license := suite.createVerifiedLicense(driverID, time.Now().AddDate(0, 0, days))
+		documentIDs[days] = license.ID
+	}
+
+	testCases := []struct {
+		window   int
+		expected []int
+	}{
+		{window: 0, expected: nil},
+		{window: 2, expected: []int{1}},
+		{window: 14, expected: []int{1, 7}},
+		{window: 31, expected: []int{1, 7, 30}},
+		{window: 365, expected: []int{1, 7, 30, 90}},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(fmt.Sprintf("window_%d_days", tc.window), func(t *testing.T) {
+			// Act
+			documents, err := suite.documentRepo.GetExpiring(suite.ctx, tc.window)
+
+			// Assert
+			require.NoError(t, err)
+
+			var expected []uuid.UUID
+			for _, days := range tc.expected {
+				expected = append(expected, documentIDs[days])
+			}
+
+			var actual []uuid.UUID
+			for _, document := range documents {
+				actual = append(actual, document.ID)
+			}
+			assert.ElementsMatch(t, expected, actual)
+		})
+	}
+}
+
+// createAvailableDriver создает водителя со свежим удостоверением в статусе available
+func (suite *DocumentExpiryTestSuite) createAvailableDriver(driver *entities.Driver) uuid.UUID {
+	driver.LicenseExpiry = time.Now().AddDate(1, 0, 0)
+
+	createdDriver, err := suite.driverService.CreateDriver(suite.ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.driverRepo.UpdateStatus(suite.ctx, createdDriver.ID, entities.StatusAvailable))
+
+	return createdDriver.ID
+}
+
+// createVerifiedLicense создает верифицированное водительское удостоверение с указанной датой истечения
+func (suite *DocumentExpiryTestSuite) createVerifiedLicense(driverID uuid.UUID, expiry time.Time) *entities.DriverDocument {
+	document := fixtures.CreateTestDocument(driverID, entities.DocumentTypeDriverLicense)
+	document.DocumentNumber = "LIC-" + driverID.String()[:8]
+	document.ExpiryDate = expiry
+	document.Verify("test-verifier")
+
+	require.NoError(suite.T(), suite.documentRepo.Create(suite.ctx, document))
+	return document
+}
+
+// moveExpiryDate переносит дату истечения документа, имитируя течение времени
+func (suite *DocumentExpiryTestSuite) moveExpiryDate(documentID uuid.UUID, expiry time.Time) {
+	_, err := suite.testDB.ExecContext(suite.ctx,
+		"UPDATE driver_documents SET expiry_date = $1 WHERE id = $2", expiry, documentID)
+	require.NoError(suite.T(), err)
+}
+
+// assertExpiring проверяет наличие документа среди истекающих в течение days дней
+func (suite *DocumentExpiryTestSuite) assertExpiring(documentID uuid.UUID, days int, expected bool) {
+	documents, err := suite.documentRepo.GetExpiring(suite.ctx, days)
+	require.NoError(suite.T(), err)
+
+	found := false
+	for _, document := range documents {
+		if document.ID == documentID {
+			found = true
+		}
+	}
+	assert.Equal(suite.T(), expected, found, "Документ в окне %d дней", days)
+}
+
+// TestDocumentExpiryTestSuite запускает тестовый suite
+func TestDocumentExpiryTestSuite(t *testing.T) {
+	suite.Run(t, new(DocumentExpiryTestSuite))
+}
@@ -0,0 +1,297 @@
+//go:build integration
+
+package integration
+
+import (
+	"crypto/md5"
+	"encoding/hex"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// DocumentStorageTestSuite тестирует загрузку файлов документов в объектное хранилище (MinIO)
+type DocumentStorageTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов в подтестах
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DocumentStorageTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.env.EnableStorage(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DocumentStorageTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DocumentStorageTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestPresignedUploadURL тестирует выдачу подписанной ссылки на загрузку файла документа
+func (suite *DocumentStorageTestSuite) TestPresignedUploadURL() {
+	// Arrange
+	driverID := suite.createDriver(suite.T())
+	issuedAt := time.Now()
+
+	// Act
+	ticket, err := suite.env.DocumentUploads.RequestUpload(suite.env.Ctx, driverID, entities.DocumentTypeDriverLicense)
+
+	// Assert
+	require.NoError(suite.T(), err)
+	assert.True(suite.T(), strings.HasPrefix(ticket.ObjectKey, "drivers/"+driverID.String()+"/driver_license/"), ticket.ObjectKey)
+	assert.WithinDuration(suite.T(), issuedAt.Add(helpers.TestUploadURLExpiry), ticket.ExpiresAt, 5*time.Second)
+
+	uploadURL, err := url.Parse(ticket.UploadURL)
+	require.NoError(suite.T(), err)
+	objectURL, err := url.Parse(suite.env.Storage.ObjectURL(ticket.ObjectKey))
+	require.NoError(suite.T(), err)
+
+	assert.Equal(suite.T(), objectURL.Host, uploadURL.Host, "Ссылка должна вести в хранилище документов")
+	assert.Equal(suite.T(), objectURL.Path, uploadURL.Path, "Ссылка должна указывать на выданный ключ")
+
+	query := uploadURL.Query()
+	assert.Equal(suite.T(), "AWS4-HMAC-SHA256", query.Get("X-Amz-Algorithm"))
+	assert.Equal(suite.T(), "900", query.Get("X-Amz-Expires"))
+	assert.Equal(suite.T(), "host", query.Get("X-Amz-SignedHeaders"))
+	assert.NotEmpty(suite.T(), query.Get("X-Amz-Signature"))
+
+	testCases := []struct {
+		name        string
+		driverID    uuid.UUID
+		docType     entities.DocumentType
+		expectedErr error
+	}{
+		{name: "UnknownDriver", driverID: uuid.New(), docType: entities.DocumentTypeDriverLicense, expectedErr: entities.ErrDriverNotFound},
+		{name: "UnknownDocumentType", driverID: driverID, docType: "selfie", expectedErr: entities.ErrInvalidDocumentType},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			_, err := suite.env.DocumentUploads.RequestUpload(suite.env.Ctx, tc.driverID, tc.docType)
+			assert.ErrorIs(t, err, tc.expectedErr)
+		})
+	}
+
+	// Ключи уникальны: повторный запрос не перезаписывает ранее загруженный файл
+	second, err := suite.env.DocumentUploads.RequestUpload(suite.env.Ctx, driverID, entities.DocumentTypeDriverLicense)
+	require.NoError(suite.T(), err)
+	assert.NotEqual(suite.T(), ticket.ObjectKey, second.ObjectKey)
+}
+
+// TestPresignedURLRejectsTamperingAndExpiry тестирует, что хранилище принимает файл только по действующей
+// и неизмененной ссылке
+func (suite *DocumentStorageTestSuite) TestPresignedURLRejectsTamperingAndExpiry() {
+	driverID := suite.createDriver(suite.T())
+	content := fixtures.CreateTestDocumentFile("77AA123456")
+
+	suite.T().Run("TamperedKey", func(t *testing.T) {
+		ticket, err := suite.env.DocumentUploads.RequestUpload(suite.env.Ctx, driverID, entities.DocumentTypeDriverLicense)
+		require.NoError(t, err)
+
+		// Подпись выдана на другой ключ
+		tampered := strings.Replace(ticket.UploadURL, "/driver_license/", "/passport/", 1)
+		status, body := helpers.UploadFile(t, tampered, fixtures.DocumentContentType, content)
+		assert.Equal(t, http.StatusForbidden, status, body)
+	})
+
+	suite.T().Run("Expired", func(t *testing.T) {
+		ticket, err := suite.env.DocumentUploads.RequestUpload(suite.env.Ctx, driverID, entities.DocumentTypeDriverLicense)
+		require.NoError(t, err)
+
+		shortURL, err := suite.env.Storage.PresignPut(ticket.ObjectKey, time.Second)
+		require.NoError(t, err)
+		time.Sleep(2 * time.Second)
+
+		status, body := helpers.UploadFile(t, shortURL, fixtures.DocumentContentType, content)
+		assert.Equal(t, http.StatusForbidden, status, body)
+
+		_, err = suite.env.Storage.StatObject(suite.env.Ctx, ticket.ObjectKey)
+		assert.ErrorIs(t, err, entities.ErrObjectNotFound, "Файл не должен сохраниться")
+	})
+}
+
+// TestUploadedObjectReferencedByDocument тестирует загрузку файла и то, что метаданные документа
+// ссылаются на сохраненный объект
+func (suite *DocumentStorageTestSuite) TestUploadedObjectReferencedByDocument() {
+	// Arrange
+	driverID := suite.createDriver(suite.T())
+	content := fixtures.CreateTestDocumentFile("77AA123456")
+	checksum := md5.Sum(content)
+
+	ticket, err := suite.env.DocumentUploads.RequestUpload(suite.env.Ctx, driverID, entities.DocumentTypeDriverLicense)
+	require.NoError(suite.T(), err)
+
+	// Act
+	status, body := helpers.UploadFile(suite.T(), ticket.UploadURL, fixtures.DocumentContentType, content)
+	require.Equal(suite.T(), http.StatusOK, status, body)
+
+	document, err := suite.env.DocumentUploads.CompleteUpload(suite.env.Ctx,
+		fixtures.CreateTestDocument(driverID, entities.DocumentTypeDriverLicense), ticket.ObjectKey)
+	require.NoError(suite.T(), err)
+
+	// Assert - документ перечитывается из БД, чтобы проверить сохраненные метаданные
+	stored, err := suite.env.DocumentRepo.GetByID(suite.env.Ctx, document.ID)
+	require.NoError(suite.T(), err)
+
+	assert.Equal(suite.T(), suite.env.Storage.ObjectURL(ticket.ObjectKey), stored.FileURL)
+	assert.Equal(suite.T(), entities.VerificationStatusProcessing, stored.Status, "До антивирусной проверки документ в обработке")
+	assert.Equal(suite.T(), entities.ScanStatusPending, stored.ScanStatus())
+
+	object, ok := stored.Object()
+	require.True(suite.T(), ok, "Метаданные документа должны ссылаться на объект")
+	assert.Equal(suite.T(), suite.env.Storage.Bucket(), object.Bucket)
+	assert.Equal(suite.T(), ticket.ObjectKey, object.Key)
+	assert.Equal(suite.T(), int64(len(content)), object.Size)
+	assert.Equal(suite.T(), fixtures.DocumentContentType, object.ContentType)
+	assert.Equal(suite.T(), hex.EncodeToString(checksum[:]), object.ETag, "ETag однокомпонентной загрузки - MD5 содержимого")
+
+	// Объект по ссылке из метаданных совпадает с загруженным файлом
+	downloaded, err := suite.env.Storage.GetObject(suite.env.Ctx, object.Key)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), content, downloaded)
+
+	suite.T().Run("ObjectNotUploaded", func(t *testing.T) {
+		ticket, err := suite.env.DocumentUploads.RequestUpload(suite.env.Ctx, driverID, entities.DocumentTypeMedicalCert)
+		require.NoError(t, err)
+
+		_, err = suite.env.DocumentUploads.CompleteUpload(suite.env.Ctx,
+			fixtures.CreateTestDocument(driverID, entities.DocumentTypeMedicalCert), ticket.ObjectKey)
+		assert.ErrorIs(t, err, entities.ErrObjectNotFound)
+	})
+
+	suite.T().Run("ObjectOfAnotherDriver", func(t *testing.T) {
+		otherDriverID := suite.createDriver(t)
+
+		// Водитель не может привязать к своему документу файл другого водителя
+		_, err := suite.env.DocumentUploads.CompleteUpload(suite.env.Ctx,
+			fixtures.CreateTestDocument(otherDriverID, entities.DocumentTypeDriverLicense), ticket.ObjectKey)
+		assert.ErrorIs(t, err, entities.ErrInvalidObjectKey)
+	})
+}
+
+// TestVirusScanStatusTransitions тестирует переходы статуса антивирусной проверки загруженных файлов
+func (suite *DocumentStorageTestSuite) TestVirusScanStatusTransitions() {
+	testCases := []struct {
+		name                   string
+		content                []byte
+		expectedScan           entities.ScanStatus
+		expectedDocumentStatus entities.VerificationStatus
+	}{
+		{
+			name:                   "CleanFile",
+			content:                fixtures.CreateTestDocumentFile("77AA123456"),
+			expectedScan:           entities.ScanStatusClean,
+			expectedDocumentStatus: entities.VerificationStatusPending,
+		},
+		{
+			name:                   "InfectedFile",
+			content:                fixtures.CreateInfectedDocumentFile(),
+			expectedScan:           entities.ScanStatusInfected,
+			expectedDocumentStatus: entities.VerificationStatusRejected,
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			document := suite.uploadDocument(t, tc.content)
+
+			// Act
+			scanned := suite.env.ScanDocument(t, document.ID)
+
+			// Assert
+			assert.Equal(t, tc.expectedScan, scanned.ScanStatus())
+			assert.Equal(t, tc.expectedDocumentStatus, scanned.Status)
+
+			stored, err := suite.env.DocumentRepo.GetByID(suite.env.Ctx, document.ID)
+			require.NoError(t, err)
+			assert.Equal(t, tc.expectedScan, stored.ScanStatus())
+			assert.Equal(t, tc.expectedDocumentStatus, stored.Status)
+
+			if tc.expectedScan == entities.ScanStatusInfected {
+				require.NotNil(t, stored.RejectionReason)
+				assert.Equal(t, entities.ScanRejectionReason, *stored.RejectionReason)
+			}
+
+			// Итоговый результат проверки не меняется
+			_, err = suite.env.DocumentUploads.RecordScanResult(suite.env.Ctx, document.ID, entities.ScanStatusPending)
+			assert.ErrorIs(t, err, entities.ErrInvalidScanTransition)
+		})
+	}
+
+	suite.T().Run("RescanAfterFailure", func(t *testing.T) {
+		// Arrange
+		document := suite.uploadDocument(t, fixtures.CreateTestDocumentFile("77AA654321"))
+
+		// Act - сканер не смог проверить файл, проверка запрошена повторно
+		failed, err := suite.env.DocumentUploads.RecordScanResult(suite.env.Ctx, document.ID, entities.ScanStatusFailed)
+		require.NoError(t, err)
+		assert.Equal(t, entities.VerificationStatusProcessing, failed.Status)
+
+		_, err = suite.env.DocumentUploads.RecordScanResult(suite.env.Ctx, document.ID, entities.ScanStatusPending)
+		require.NoError(t, err)
+
+		scanned := suite.env.ScanDocument(t, document.ID)
+
+		// Assert
+		assert.Equal(t, entities.ScanStatusClean, scanned.ScanStatus())
+		assert.Equal(t, entities.VerificationStatusPending, scanned.Status)
+	})
+
+	suite.T().Run("UnknownDocument", func(t *testing.T) {
+		_, err := suite.env.DocumentUploads.RecordScanResult(suite.env.Ctx, uuid.New(), entities.ScanStatusClean)
+		assert.ErrorIs(t, err, entities.ErrDocumentNotFound)
+	})
+}
+
+// createDriver создает водителя через сервис
+func (suite *DocumentStorageTestSuite) createDriver(t *testing.T) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900777%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("document.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("DS%08d", suite.drivers)
+
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+	return createdDriver.ID
+}
+
+// uploadDocument создает водителя и загружает ему водительское удостоверение с указанным содержимым
+func (suite *DocumentStorageTestSuite) uploadDocument(t *testing.T, content []byte) *entities.DriverDocument {
+	driverID := suite.createDriver(t)
+
+	ticket, err := suite.env.DocumentUploads.RequestUpload(suite.env.Ctx, driverID, entities.DocumentTypeDriverLicense)
+	require.NoError(t, err)
+
+	status, body := helpers.UploadFile(t, ticket.UploadURL, fixtures.DocumentContentType, content)
+	require.Equal(t, http.StatusOK, status, body)
+
+	document, err := suite.env.DocumentUploads.CompleteUpload(suite.env.Ctx,
+		fixtures.CreateTestDocument(driverID, entities.DocumentTypeDriverLicense), ticket.ObjectKey)
+	require.NoError(t, err)
+	return document
+}
+
+// TestDocumentStorageTestSuite запускает тестовый suite
+func TestDocumentStorageTestSuite(t *testing.T) {
+	suite.Run(t, new(DocumentStorageTestSuite))
+}
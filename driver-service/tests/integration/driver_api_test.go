@@ -9,6 +9,7 @@ import (
 	"fmt"
 	"net/http"
 	"net/http/httptest"
+	"strconv"
 	"testing"
 	"time"
 
@@ -123,61 +124,6 @@ func (suite *DriverAPITestSuite) TestCreateDriverAPI() {
 	assert.NotEqual(suite.T(), uuid.Nil, response.ID)
 }
 
-// TestCreateDriverAPIValidation тестирует валидацию при создании водителя
-func (suite *DriverAPITestSuite) TestCreateDriverAPIValidation() {
-	// Arrange - невалидные данные
-	testCases := []struct {
-		name         string
-		requestBody  map[string]interface{}
-		expectedCode int
-	}{
-		{
-			name: "missing phone",
-			requestBody: map[string]interface{}{
-				"email":      "test@example.com",
-				"first_name": "Иван",
-				"last_name":  "Тестовый",
-			},
-			expectedCode: http.StatusBadRequest,
-		},
-		{
-			name: "invalid email",
-			requestBody: map[string]interface{}{
-				"phone":      "+79001234567",
-				"email":      "invalid-email",
-				"first_name": "Иван",
-				"last_name":  "Тестовый",
-			},
-			expectedCode: http.StatusBadRequest,
-		},
-		{
-			name: "empty first_name",
-			requestBody: map[string]interface{}{
-				"phone":      "+79001234567",
-				"email":      "test@example.com",
-				"first_name": "",
-				"last_name":  "Тестовый",
-			},
-			expectedCode: http.StatusBadRequest,
-		},
-	}
-
-	for _, tc := range testCases {
-		suite.T().Run(tc.name, func(t *testing.T) {
-			// Act
-			bodyBytes, _ := json.Marshal(tc.requestBody)
-			req := httptest.NewRequest(http.MethodPost, "/api/v1/drivers", bytes.NewBuffer(bodyBytes))
-			req.Header.Set("Content-Type", "application/json")
-			w := httptest.NewRecorder()
-
-			suite.router.ServeHTTP(w, req)
-
-			// Assert
-			assert.Equal(t, tc.expectedCode, w.Code)
-		})
-	}
-}
-
 // TestGetDriverAPI тестирует получение водителя через API
 func (suite *DriverAPITestSuite) TestGetDriverAPI() {
 	// Arrange
@@ -359,15 +305,8 @@ func (suite *DriverAPITestSuite) TestGetActiveDriversAPI() {
 	// Assert
 	assert.Equal(suite.T(), http.StatusOK, w.Code)
 
-	var response map[string]interface{}
-	err := json.Unmarshal(w.Body.Bytes(), &response)
-	require.NoError(suite.T(), err)
-
-	drivers_list := response["drivers"].([]interface{})
-	count := response["count"].(float64)
-
-	assert.Equal(suite.T(), float64(3), count) // available, on_shift, busy
-	assert.Len(suite.T(), drivers_list, 3)
+	helpers.AssertJSONPath(suite.T(), w.Body.Bytes(), "$.count", helpers.Equals(3)) // available, on_shift, busy
+	helpers.AssertJSONPath(suite.T(), w.Body.Bytes(), "$.drivers", helpers.HasLen(3))
 }
 
 // TestDriverAPIFilters тестирует фильтрацию водителей через API
@@ -549,13 +488,179 @@ func (suite *DriverAPITestSuite) TestRequestIDMiddleware() {
 	assert.Equal(suite.T(), customRequestID, w2.Header().Get("X-Request-ID"))
 }
 
+// TestAPIHelperInterceptors тестирует подстановку заголовков, сбор длительностей и проверку ответов через interceptor'ы
+func (suite *DriverAPITestSuite) TestAPIHelperInterceptors() {
+	// Arrange
+	traceID := uuid.New().String()
+	timings := helpers.NewRequestTimings()
+	var traced []string
+
+	client := helpers.NewAPITestHelper(suite.router, suite.T()).
+		WithHeader("X-Request-ID", traceID).
+		WithResponseInterceptor(timings.Record).
+		WithResponseInterceptor(func(req *http.Request, resp *helpers.APIResponse, _ time.Duration) {
+			traced = append(traced, resp.Headers.Get("X-Request-ID"))
+			assert.Contains(suite.T(), resp.Headers.Get("Content-Type"), "application/json", "%s %s", req.Method, req.URL.Path)
+		})
+
+	// Act
+	created := client.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    client.APIPath("/drivers"),
+		Body:   helpers.CreateDriverRequest(),
+	})
+	require.Equal(suite.T(), http.StatusCreated, created.StatusCode, string(created.Body))
+
+	var driver httpHandlers.DriverResponse
+	client.UnmarshalResponse(created, &driver)
+	for i := 0; i < 3; i++ {
+		response := client.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: client.APIPath("/drivers/" + driver.ID.String())})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	}
+
+	// Assert
+	assert.Equal(suite.T(), []string{traceID, traceID, traceID, traceID}, traced)
+	assert.Len(suite.T(), timings.Samples("POST /api/v1/drivers"), 1)
+	assert.Len(suite.T(), timings.Samples("GET /api/v1/drivers/{id}"), 3)
+	assert.NotZero(suite.T(), timings.Percentile("GET /api/v1/drivers/{id}", 95))
+}
+
+// TestAPIHelperRequestHistory тестирует кольцевую историю последних запросов клиента и проверку их длительности
+func (suite *DriverAPITestSuite) TestAPIHelperRequestHistory() {
+	// Arrange
+	suite.T().Setenv(helpers.RequestHistorySizeEnv, "3")
+	suite.T().Setenv(helpers.RequestHistoryBodyLimitEnv, "8")
+	client := helpers.NewAPITestHelper(suite.router, suite.T())
+
+	// Act - копии помощника пишут в общую историю
+	for i := 0; i < 5; i++ {
+		response := client.WithHeader("X-Env", "history").MakeRequest(helpers.APIRequest{
+			Method:      http.MethodGet,
+			URL:         "/health",
+			QueryParams: map[string]string{"n": strconv.Itoa(i)},
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	}
+
+	// Assert
+	history := client.History()
+	exchanges := history.Exchanges()
+	require.Len(suite.T(), exchanges, 3, "Хранятся только последние запросы")
+	for i, exchange := range exchanges {
+		assert.Equal(suite.T(), fmt.Sprintf("/health?n=%d", i+2), exchange.URL)
+		assert.Equal(suite.T(), http.StatusOK, exchange.StatusCode)
+		assert.Contains(suite.T(), string(exchange.ResponseBody), "bytes truncated")
+		assert.False(suite.T(), exchange.Started.IsZero())
+	}
+	assert.Equal(suite.T(), 5, history.Total())
+	assert.Positive(suite.T(), history.Slowest().Duration)
+	history.AssertMaxDuration(suite.T(), time.Second)
+
+	history.Clear()
+	assert.Empty(suite.T(), history.Exchanges())
+	assert.Zero(suite.T(), history.Total())
+}
+
+// TestAPIHelperDefaultHeaders тестирует заголовки окружения из TEST_DEFAULT_HEADERS и TEST_USER_AGENT
+func (suite *DriverAPITestSuite) TestAPIHelperDefaultHeaders() {
+	// Arrange
+	suite.T().Setenv(helpers.DefaultHeadersEnv, "X-Env: test; authorization: Bearer staging-token")
+	suite.T().Setenv(helpers.UserAgentEnv, "driver-service-tests/staging")
+
+	var sent http.Header
+	client := helpers.NewAPITestHelper(suite.router, suite.T()).
+		WithRequestInterceptor(func(req *http.Request) { sent = req.Header.Clone() })
+
+	// Act
+	response := client.MakeRequest(helpers.APIRequest{
+		Method:  http.MethodGet,
+		URL:     "/health",
+		Headers: map[string]string{"X-Env": "override"},
+	})
+
+	// Assert
+	assert.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	assert.Equal(suite.T(), "override", sent.Get("X-Env"), "Явно переданный заголовок имеет приоритет")
+	assert.Equal(suite.T(), "Bearer staging-token", sent.Get("Authorization"))
+	assert.Equal(suite.T(), "driver-service-tests/staging", sent.Get("User-Agent"))
+}
+
+// TestAPIHelperAssertLatency тестирует проверку длительности запросов вызова клиента: запросы в пределах
+// порога только логируются, при превышении сообщение содержит p50/p95 операции за прогон
+func (suite *DriverAPITestSuite) TestAPIHelperAssertLatency() {
+	// Arrange
+	suite.T().Setenv(helpers.LatencyBudgetMultiplierEnv, "1")
+	client := helpers.NewAPITestHelper(suite.router, suite.T())
+	health := func() {
+		response := client.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health"})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	}
+	for i := 0; i < 20; i++ {
+		health()
+	}
+
+	// Act
+	within := &latencyRecorder{}
+	client.AssertLatency(within, time.Minute, func() {
+		health()
+		health()
+	})
+
+	// Assert
+	assert.Empty(suite.T(), within.errors)
+	require.Len(suite.T(), within.logs, 2, "Проверяется каждый запрос вызова")
+	assert.Contains(suite.T(), within.logs[0], "GET /health")
+
+	// Act - любой запрос дольше порога в 1ns
+	breached := &latencyRecorder{}
+	client.AssertLatency(breached, time.Nanosecond, health)
+
+	// Assert
+	run := helpers.RunLatency("GET /health")
+	assert.GreaterOrEqual(suite.T(), run.Samples, 23)
+	assert.Positive(suite.T(), run.P50)
+	assert.GreaterOrEqual(suite.T(), run.P95, run.P50)
+	require.Len(suite.T(), breached.errors, 1)
+	message := breached.errors[0]
+	assert.Contains(suite.T(), message, helpers.LatencyBreachMarker)
+	assert.Contains(suite.T(), message, "GET /health took")
+	assert.Contains(suite.T(), message, "-> 200, limit 1ns")
+	assert.Contains(suite.T(), message, fmt.Sprintf("p50 %v, p95 %v", run.P50, run.P95))
+	assert.Contains(suite.T(), message, fmt.Sprintf("over %d requests", run.Samples))
+
+	// Act - вызов без запросов к API
+	idle := &latencyRecorder{}
+	client.AssertLatency(idle, time.Second, func() {})
+
+	// Assert
+	require.Len(suite.T(), idle.errors, 1)
+	assert.Contains(suite.T(), idle.errors[0], "no API requests")
+}
+
+// latencyRecorder сохраняет сообщения AssertLatency вместо того, чтобы проваливать тест
+type latencyRecorder struct {
+	errors []string
+	logs   []string
+}
+
+func (r *latencyRecorder) Helper() {}
+
+func (r *latencyRecorder) Errorf(format string, args ...interface{}) {
+	r.errors = append(r.errors, fmt.Sprintf(format, args...))
+}
+
+func (r *latencyRecorder) Logf(format string, args ...interface{}) {
+	r.logs = append(r.logs, fmt.Sprintf(format, args...))
+}
+
 // mockEventPublisher заглушка для EventPublisher в тестах
 type mockEventPublisher struct {
 	logger interface{} // zap.Logger, но не импортируем zap здесь
 }
 
 func (m *mockEventPublisher) PublishDriverEvent(ctx context.Context, eventType string, driverID uuid.UUID, data interface{}) error {
-	// В тестах просто логируем события
+	// В тестах события не отправляются, только учитываются в отчете о покрытии событий
+	helpers.TrackEvent(eventType)
 	return nil
 }
 
@@ -0,0 +1,71 @@
+//go:build integration
+
+package integration
+
+import (
+	"net/http"
+	"testing"
+
+	"driver-service/internal/features"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// DriverCreationCasesTestSuite тестовый suite для валидации создания водителя по внешним таблицам случаев.
+// Случаи описываются в tests/testdata/driver_creation/*.json и *.csv и добавляются без изменения кода.
+type DriverCreationCasesTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DriverCreationCasesTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DriverCreationCasesTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DriverCreationCasesTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	// Ожидаемые ответы в таблицах случаев описывают валидацию без флага strict_validation
+	suite.env.SetFeature(suite.T(), features.StrictValidation, false)
+}
+
+// TestDriverCreationCases тестирует создание водителя на случаях из tests/testdata/driver_creation
+func (suite *DriverCreationCasesTestSuite) TestDriverCreationCases() {
+	for file, cases := range helpers.LoadAPICases(suite.T(), "driver_creation") {
+		suite.T().Run(file, func(t *testing.T) {
+			require.NotEmpty(t, cases, "Файл %s не содержит случаев", file)
+
+			for _, tc := range cases {
+				t.Run(tc.Name, func(t *testing.T) {
+					// Arrange
+					// Каждый случай начинается с пустой БД, чтобы базовый запрос не конфликтовал с предыдущими
+					suite.env.DB.CleanupTables(t)
+					request := tc.Apply(helpers.CreateDriverRequest())
+
+					// Act
+					response := suite.env.API.MakeRequest(helpers.APIRequest{
+						Method: http.MethodPost,
+						URL:    suite.env.API.APIPath("/drivers"),
+						Body:   request,
+					})
+
+					// Assert
+					tc.AssertResponse(t, response)
+				})
+			}
+		})
+	}
+}
+
+// TestDriverCreationCasesTestSuite запускает тестовый suite
+func TestDriverCreationCasesTestSuite(t *testing.T) {
+	suite.Run(t, new(DriverCreationCasesTestSuite))
+}
@@ -0,0 +1,373 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"sort"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры набора водителей для матрицы фильтров
+const (
+	filterMatrixDrivers  = 30
+	filterMatrixPageSize = 4
+)
+
+// Значения атрибутов водителей набора; периоды повторения взаимно просты, чтобы фильтры не были
+// связаны между собой и их пересечения не оказывались всегда пустыми
+var (
+	filterMatrixStatuses = []entities.Status{
+		entities.StatusAvailable, entities.StatusBusy, entities.StatusOnShift, entities.StatusInactive,
+	}
+	filterMatrixCities    = []string{"Москва", "Казань", "Санкт-Петербург"}
+	filterMatrixLastNames = []string{"Кузнецов", "Соколов", "Морозов", "Волков", "Лебедев"}
+)
+
+// listedDriver атрибуты водителя набора, по которым фильтруется и сортируется список
+type listedDriver struct {
+	id            uuid.UUID
+	status        entities.Status
+	rating        float64
+	city          string
+	lastName      string
+	createdAt     time.Time
+	licenseExpiry time.Time // дата в UTC
+}
+
+// listFilterDimension один фильтр матрицы: значение в запросе и проверка водителя по нему
+type listFilterDimension struct {
+	name    string
+	apply   func(filter *helpers.DriverListFilter)
+	matches func(driver listedDriver) bool
+}
+
+// listSortOrder сортировка списка и порядок, которого она должна придерживаться
+type listSortOrder struct {
+	name          string
+	sortBy        string
+	sortDirection string
+	less          func(a, b listedDriver) bool
+}
+
+// DriverListFiltersTestSuite тестирует фильтры списка водителей (статус, рейтинг, город, дата регистрации,
+// срок ВУ, поиск по ФИО) во всех сочетаниях вместе с сортировкой и постраничным обходом
+type DriverListFiltersTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных контактов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DriverListFiltersTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DriverListFiltersTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DriverListFiltersTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestFilterMatrix тестирует все сочетания фильтров с каждой сортировкой: обход страниц возвращает
+// ровно тех водителей, которые проходят все фильтры, в порядке сортировки, а total совпадает с их числом
+func (suite *DriverListFiltersTestSuite) TestFilterMatrix() {
+	// Arrange
+	createdBase := time.Now().Truncate(time.Second)
+	today := time.Now().UTC().Truncate(24 * time.Hour)
+	drivers := suite.seedDrivers(createdBase, today)
+
+	minRating := 3.5
+	createdAfter := createdBase.Add(-20 * time.Hour)
+	expiringBefore := today.AddDate(0, 0, 150)
+
+	dimensions := []listFilterDimension{
+		{
+			name:    "status",
+			apply:   func(filter *helpers.DriverListFilter) { filter.Status = entities.StatusAvailable },
+			matches: func(driver listedDriver) bool { return driver.status == entities.StatusAvailable },
+		},
+		{
+			name:    "min_rating",
+			apply:   func(filter *helpers.DriverListFilter) { filter.MinRating = &minRating },
+			matches: func(driver listedDriver) bool { return driver.rating >= minRating },
+		},
+		{
+			// Город сравнивается без учета регистра
+			name:    "city",
+			apply:   func(filter *helpers.DriverListFilter) { filter.City = "казань" },
+			matches: func(driver listedDriver) bool { return driver.city == "Казань" },
+		},
+		{
+			name:    "created_after",
+			apply:   func(filter *helpers.DriverListFilter) { filter.CreatedAfter = createdAfter },
+			matches: func(driver listedDriver) bool { return !driver.createdAt.Before(createdAfter) },
+		},
+		{
+			name:    "license_expiring_before",
+			apply:   func(filter *helpers.DriverListFilter) { filter.LicenseExpiringBefore = expiringBefore },
+			matches: func(driver listedDriver) bool { return driver.licenseExpiry.Before(expiringBefore) },
+		},
+		{
+			name:    "search",
+			apply:   func(filter *helpers.DriverListFilter) { filter.Search = "Соколов" },
+			matches: func(driver listedDriver) bool { return driver.lastName == "Соколов" },
+		},
+	}
+
+	sortOrders := []listSortOrder{
+		{
+			name: "default",
+			less: func(a, b listedDriver) bool { return a.createdAt.After(b.createdAt) },
+		},
+		{
+			name:          "created_at_asc",
+			sortBy:        "created_at",
+			sortDirection: "asc",
+			less:          func(a, b listedDriver) bool { return a.createdAt.Before(b.createdAt) },
+		},
+		{
+			name:          "current_rating_asc",
+			sortBy:        "current_rating",
+			sortDirection: "asc",
+			less:          func(a, b listedDriver) bool { return a.rating < b.rating },
+		},
+		{
+			name:          "current_rating_desc",
+			sortBy:        "current_rating",
+			sortDirection: "desc",
+			less:          func(a, b listedDriver) bool { return a.rating > b.rating },
+		},
+	}
+
+	for mask := 0; mask < 1<<len(dimensions); mask++ {
+		var enabled []listFilterDimension
+		for i, dimension := range dimensions {
+			if mask&(1<<i) != 0 {
+				enabled = append(enabled, dimension)
+			}
+		}
+
+		for _, order := range sortOrders {
+			suite.T().Run(listMatrixCaseName(enabled, order), func(t *testing.T) {
+				// Arrange
+				filter := helpers.DriverListFilter{SortBy: order.sortBy, SortDirection: order.sortDirection}
+				for _, dimension := range enabled {
+					dimension.apply(&filter)
+				}
+				expected := expectedListedDrivers(drivers, enabled, order)
+
+				// Act
+				listed, total := suite.env.API.ListAllDrivers(filter, filterMatrixPageSize)
+
+				// Assert
+				assert.Equal(t, len(expected), total, "total")
+				actual := make([]uuid.UUID, len(listed))
+				for i, driver := range listed {
+					actual[i] = driver.ID
+				}
+				assert.Equal(t, expected, actual)
+			})
+		}
+	}
+}
+
+// TestPagesRespectLimit тестирует, что при фильтрах каждая страница не длиннее limit, а has_more
+// сбрасывается на последней странице
+func (suite *DriverListFiltersTestSuite) TestPagesRespectLimit() {
+	// Arrange
+	today := time.Now().UTC().Truncate(24 * time.Hour)
+	drivers := suite.seedDrivers(time.Now().Truncate(time.Second), today)
+	filter := helpers.DriverListFilter{City: "Москва", Limit: 3}
+
+	expected := 0
+	for _, driver := range drivers {
+		if driver.city == "Москва" {
+			expected++
+		}
+	}
+	require.Greater(suite.T(), expected, filter.Limit, "Набор должен занимать несколько страниц")
+
+	for filter.Offset = 0; filter.Offset < expected; filter.Offset += filter.Limit {
+		// Act
+		page, response := suite.env.API.ListDrivers(filter)
+
+		// Assert
+		require.NotNil(suite.T(), page, string(response.Body))
+		assert.Equal(suite.T(), expected, page.Total)
+		assert.Equal(suite.T(), min(filter.Limit, expected-filter.Offset), len(page.Drivers), "offset %d", filter.Offset)
+		assert.Equal(suite.T(), filter.Offset+filter.Limit < expected, page.HasMore, "offset %d", filter.Offset)
+	}
+}
+
+// TestCityFromCreateAndUpdate тестирует, что город из запросов создания и обновления водителя
+// попадает в фильтр city, а пустой город убирает водителя из результатов
+func (suite *DriverListFiltersTestSuite) TestCityFromCreateAndUpdate() {
+	// Arrange
+	suite.drivers++
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7900738%04d", suite.drivers)
+	request["email"] = fmt.Sprintf("list.filters%d@example.com", suite.drivers)
+	request["license_number"] = fmt.Sprintf("LF%08d", suite.drivers)
+	request["city"] = " Казань "
+
+	// Act
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+
+	// Assert
+	require.Equal(suite.T(), http.StatusCreated, response.StatusCode, string(response.Body))
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	assert.Equal(suite.T(), "Казань", driver.Metadata["city"])
+	assert.Equal(suite.T(), []uuid.UUID{driver.ID}, suite.listIDs(helpers.DriverListFilter{City: "КАЗАНЬ"}))
+	assert.Empty(suite.T(), suite.listIDs(helpers.DriverListFilter{City: "Москва"}))
+
+	// Act
+	response = suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPut,
+		URL:    suite.env.API.APIPath("/drivers/" + driver.ID.String()),
+		Body:   map[string]interface{}{"city": ""},
+	})
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.Empty(suite.T(), suite.listIDs(helpers.DriverListFilter{City: "Казань"}))
+	assert.Equal(suite.T(), []uuid.UUID{driver.ID}, suite.listIDs(helpers.DriverListFilter{}))
+}
+
+// TestInvalidFilterValues тестирует отклонение дат фильтров в неверном формате
+func (suite *DriverListFiltersTestSuite) TestInvalidFilterValues() {
+	testCases := []struct {
+		name  string
+		param string
+		value string
+	}{
+		{name: "CreatedAfterDateOnly", param: "created_after", value: "2024-01-15"},
+		{name: "CreatedAfterUnixTime", param: "created_after", value: "1705305600"},
+		{name: "LicenseExpiringBeforeLocalFormat", param: "license_expiring_before", value: "31.12.2026"},
+		{name: "LicenseExpiringBeforeInvalidDate", param: "license_expiring_before", value: "2026-02-30"},
+	}
+
+	for _, tc := range testCases {
+		suite.Run(tc.name, func() {
+			// Act
+			response := suite.env.API.MakeRequest(helpers.APIRequest{
+				Method:      http.MethodGet,
+				URL:         suite.env.API.APIPath("/drivers"),
+				QueryParams: map[string]string{tc.param: tc.value},
+			})
+
+			// Assert
+			suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+			suite.env.API.AssertErrorResponse(response, "INVALID_REQUEST")
+			assert.Contains(suite.T(), string(response.Body), tc.param)
+		})
+	}
+}
+
+// seedDrivers создает filterMatrixDrivers водителей с различающимися рейтингами и датами регистрации.
+// Атрибуты, которые нельзя задать при регистрации, записываются напрямую в БД.
+func (suite *DriverListFiltersTestSuite) seedDrivers(createdBase, today time.Time) []listedDriver {
+	drivers := make([]listedDriver, filterMatrixDrivers)
+	for i := range drivers {
+		suite.drivers++
+		driver := fixtures.CreateTestDriver()
+		driver.Phone = fmt.Sprintf("+7900738%04d", suite.drivers)
+		driver.Email = fmt.Sprintf("list.filters%d@example.com", suite.drivers)
+		driver.LicenseNumber = fmt.Sprintf("LF%08d", suite.drivers)
+		driver.LastName = filterMatrixLastNames[i%len(filterMatrixLastNames)]
+		driver.SetCity(filterMatrixCities[i%len(filterMatrixCities)])
+
+		created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+		require.NoError(suite.T(), err)
+
+		listed := listedDriver{
+			id:            created.ID,
+			status:        filterMatrixStatuses[i%len(filterMatrixStatuses)],
+			rating:        2 + float64(i)/10,
+			city:          filterMatrixCities[i%len(filterMatrixCities)],
+			lastName:      driver.LastName,
+			createdAt:     createdBase.Add(-time.Duration(i) * time.Hour),
+			licenseExpiry: today.AddDate(0, 0, (i*7%filterMatrixDrivers+1)*10),
+		}
+		_, err = suite.env.DB.ExecContext(suite.env.Ctx, `
+			UPDATE drivers SET status = $1, current_rating = $2, created_at = $3, license_expiry = $4::date
+			WHERE id = $5`,
+			listed.status, listed.rating, listed.createdAt, listed.licenseExpiry.Format(time.DateOnly), listed.id)
+		require.NoError(suite.T(), err)
+
+		drivers[i] = listed
+	}
+	return drivers
+}
+
+// listIDs возвращает ID водителей первой страницы списка
+func (suite *DriverListFiltersTestSuite) listIDs(filter helpers.DriverListFilter) []uuid.UUID {
+	page, response := suite.env.API.ListDrivers(filter)
+	require.NotNil(suite.T(), page, string(response.Body))
+
+	ids := make([]uuid.UUID, 0, len(page.Drivers))
+	for _, driver := range page.Drivers {
+		ids = append(ids, driver.ID)
+	}
+	return ids
+}
+
+// expectedListedDrivers возвращает ID водителей, проходящих все фильтры, в порядке сортировки
+func expectedListedDrivers(drivers []listedDriver, filters []listFilterDimension, order listSortOrder) []uuid.UUID {
+	var matched []listedDriver
+	for _, driver := range drivers {
+		matches := true
+		for _, filter := range filters {
+			matches = matches && filter.matches(driver)
+		}
+		if matches {
+			matched = append(matched, driver)
+		}
+	}
+	sort.Slice(matched, func(i, j int) bool { return order.less(matched[i], matched[j]) })
+
+	ids := make([]uuid.UUID, len(matched))
+	for i, driver := range matched {
+		ids[i] = driver.id
+	}
+	return ids
+}
+
+// listMatrixCaseName возвращает имя подтеста вида "city+search/current_rating_desc"
+func listMatrixCaseName(filters []listFilterDimension, order listSortOrder) string {
+	names := []string{"none"}
+	if len(filters) > 0 {
+		names = names[:0]
+		for _, filter := range filters {
+			names = append(names, filter.name)
+		}
+	}
+	return strings.Join(names, "+") + "/" + order.name
+}
+
+// TestDriverListFiltersTestSuite запускает тестовый suite
+func TestDriverListFiltersTestSuite(t *testing.T) {
+	suite.Run(t, new(DriverListFiltersTestSuite))
+}
@@ -0,0 +1,280 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// DriverLookupTestSuite тестирует поиск водителя службой поддержки (GET /api/v1/admin/drivers/lookup):
+// телефон без учета формата записи, email без учета регистра, удаленные водители - только с include_deleted
+type DriverLookupTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных контактов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DriverLookupTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DriverLookupTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DriverLookupTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestLookupByPhoneAcrossFormats тестирует поиск по телефону: номер, сохраненный в любом формате записи,
+// находится по любому эквивалентному представлению и не находится по номерам других групп
+func (suite *DriverLookupTestSuite) TestLookupByPhoneAcrossFormats() {
+	// Arrange - по водителю на каждый формат записи номера. Сервис сохраняет номер в E.164, поэтому
+	// водители записываются в обход него, как зарегистрированные до нормализации номеров
+	stored := make(map[string][]uuid.UUID, len(phoneFormatGroups))
+	for _, group := range phoneFormatGroups {
+		for _, variant := range group.variants {
+			stored[group.name] = append(stored[group.name], suite.insertLegacyDriver(variant, suite.nextEmail()))
+		}
+	}
+
+	for _, group := range phoneFormatGroups {
+		for _, variant := range group.variants {
+			suite.T().Run(group.name+"/"+variant, func(t *testing.T) {
+				// Act
+				found, response := suite.env.API.LookupDrivers(entities.DriverLookup{Phone: variant})
+
+				// Assert
+				require.NotNil(t, found, string(response.Body))
+				assert.ElementsMatch(t, stored[group.name], lookupIDs(found))
+			})
+		}
+	}
+
+	testCases := []struct {
+		name  string
+		phone string
+	}{
+		{name: "OtherNumber", phone: "+7 (916) 123-45-68"},
+		{name: "Prefix", phone: "+7 916 123"},
+		{name: "WithoutCountryCode", phone: "916 123-45-67"},
+		{name: "ForeignTrunkPrefix", phone: "+8 916 123 45 67"},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			found, response := suite.env.API.LookupDrivers(entities.DriverLookup{Phone: tc.phone})
+
+			// Assert
+			require.NotNil(t, found, string(response.Body))
+			assert.Empty(t, found.Drivers, "Номер %q не совпадает ни с одним водителем", tc.phone)
+		})
+	}
+}
+
+// TestLookupByEmailCaseInsensitive тестирует поиск по email без учета регистра и пробелов по краям;
+// частичное совпадение водителя не находит
+func (suite *DriverLookupTestSuite) TestLookupByEmailCaseInsensitive() {
+	// Arrange
+	driverID := suite.createDriver(suite.nextPhone(), "Support.Driver@Example.COM")
+	suite.createDriver(suite.nextPhone(), "support.driver2@example.com")
+
+	testCases := []struct {
+		name     string
+		email    string
+		expected []uuid.UUID
+	}{
+		{name: "AsStored", email: "Support.Driver@Example.COM", expected: []uuid.UUID{driverID}},
+		{name: "LowerCase", email: "support.driver@example.com", expected: []uuid.UUID{driverID}},
+		{name: "UpperCase", email: "SUPPORT.DRIVER@EXAMPLE.COM", expected: []uuid.UUID{driverID}},
+		{name: "Padded", email: "  support.driver@example.com ", expected: []uuid.UUID{driverID}},
+		{name: "OtherDomain", email: "support.driver@example.org"},
+		{name: "LocalPartOnly", email: "support.driver"},
+		{name: "Prefix", email: "support.driver@"},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			found, response := suite.env.API.LookupDrivers(entities.DriverLookup{Email: tc.email})
+
+			// Assert
+			require.NotNil(t, found, string(response.Body))
+			assert.ElementsMatch(t, tc.expected, lookupIDs(found))
+		})
+	}
+}
+
+// TestDeletedDriversOnlyWithIncludeDeleted тестирует, что удаленный водитель находится только
+// с include_deleted=true, а после повторной регистрации контакта активный водитель идет первым,
+// затем удаленные от недавно удаленных
+func (suite *DriverLookupTestSuite) TestDeletedDriversOnlyWithIncludeDeleted() {
+	// Arrange
+	phone, email := "+7 (916) 555-01-01", "Deleted.Driver@example.com"
+	first := suite.createDriver(phone, email)
+	require.NoError(suite.T(), suite.env.DriverService.DeleteDriver(suite.env.Ctx, first))
+
+	// Act & Assert - без include_deleted удаленный водитель не виден, в том числе при include_deleted=false
+	for _, lookup := range contactLookups(phone, email, false) {
+		found, response := suite.env.API.LookupDrivers(lookup)
+		require.NotNil(suite.T(), found, string(response.Body))
+		assert.Empty(suite.T(), found.Drivers, "Поиск %+v", lookup)
+	}
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method:      http.MethodGet,
+		URL:         suite.env.API.APIPath("/admin/drivers/lookup"),
+		QueryParams: map[string]string{"email": email, "include_deleted": "false"},
+	})
+	suite.env.API.AssertStatusCode(response, http.StatusOK)
+	var explicit httpHandlers.LookupDriversResponse
+	suite.env.API.UnmarshalResponse(response, &explicit)
+	assert.Empty(suite.T(), explicit.Drivers)
+
+	// Act & Assert - с include_deleted удаленный водитель виден вместе с временем удаления
+	for _, lookup := range contactLookups(phone, email, true) {
+		found, response := suite.env.API.LookupDrivers(lookup)
+		require.NotNil(suite.T(), found, string(response.Body))
+		require.Equal(suite.T(), []uuid.UUID{first}, lookupIDs(found), "Поиск %+v", lookup)
+		assert.NotNil(suite.T(), found.Drivers[0].DeletedAt)
+	}
+
+	// Arrange - контакт удаленного водителя занят новой регистрацией в другом формате записи
+	second := suite.createDriver("89165550101", strings.ToLower(email))
+
+	// Act & Assert
+	for _, lookup := range contactLookups(phone, email, false) {
+		found, response := suite.env.API.LookupDrivers(lookup)
+		require.NotNil(suite.T(), found, string(response.Body))
+		assert.Equal(suite.T(), []uuid.UUID{second}, lookupIDs(found), "Поиск %+v", lookup)
+	}
+	for _, lookup := range contactLookups(phone, email, true) {
+		found, response := suite.env.API.LookupDrivers(lookup)
+		require.NotNil(suite.T(), found, string(response.Body))
+		require.Equal(suite.T(), []uuid.UUID{second, first}, lookupIDs(found), "Активный водитель первым, поиск %+v", lookup)
+		assert.Nil(suite.T(), found.Drivers[0].DeletedAt)
+		assert.NotNil(suite.T(), found.Drivers[1].DeletedAt)
+	}
+
+	// Arrange - удаляем и второго водителя: теперь он удален позже первого
+	require.NoError(suite.T(), suite.env.DriverService.DeleteDriver(suite.env.Ctx, second))
+
+	// Act & Assert
+	for _, lookup := range contactLookups(phone, email, false) {
+		found, response := suite.env.API.LookupDrivers(lookup)
+		require.NotNil(suite.T(), found, string(response.Body))
+		assert.Empty(suite.T(), found.Drivers, "Поиск %+v", lookup)
+	}
+	for _, lookup := range contactLookups(phone, email, true) {
+		found, response := suite.env.API.LookupDrivers(lookup)
+		require.NotNil(suite.T(), found, string(response.Body))
+		assert.Equal(suite.T(), []uuid.UUID{second, first}, lookupIDs(found), "Недавно удаленный первым, поиск %+v", lookup)
+	}
+}
+
+// TestInvalidLookup тестирует отклонение поиска без контакта, с обоими контактами, с телефоном без цифр
+// и с неверным include_deleted
+func (suite *DriverLookupTestSuite) TestInvalidLookup() {
+	testCases := []struct {
+		name         string
+		query        map[string]string
+		expectedCode string
+	}{
+		{name: "NoContact", query: map[string]string{}, expectedCode: "INVALID_REQUEST"},
+		{name: "OnlyIncludeDeleted", query: map[string]string{"include_deleted": "true"}, expectedCode: "INVALID_REQUEST"},
+		{name: "BlankContacts", query: map[string]string{"phone": " ", "email": " "}, expectedCode: "INVALID_REQUEST"},
+		{name: "PhoneAndEmail", query: map[string]string{"phone": "+79161234567", "email": "driver@example.com"}, expectedCode: "INVALID_REQUEST"},
+		{name: "PhoneWithoutDigits", query: map[string]string{"phone": "+7 (---) ---"}, expectedCode: "INVALID_DATA"},
+		{name: "InvalidIncludeDeleted", query: map[string]string{"email": "driver@example.com", "include_deleted": "maybe"}, expectedCode: "INVALID_REQUEST"},
+	}
+
+	for _, tc := range testCases {
+		suite.Run(tc.name, func() {
+			// Act
+			response := suite.env.API.MakeRequest(helpers.APIRequest{
+				Method:      http.MethodGet,
+				URL:         suite.env.API.APIPath("/admin/drivers/lookup"),
+				QueryParams: tc.query,
+			})
+
+			// Assert
+			suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+			suite.env.API.AssertErrorResponse(response, tc.expectedCode)
+		})
+	}
+}
+
+// contactLookups возвращает поиски водителя по телефону и email в разных форматах записи
+func contactLookups(phone, email string, includeDeleted bool) []entities.DriverLookup {
+	return []entities.DriverLookup{
+		{Phone: phone, IncludeDeleted: includeDeleted},
+		{Phone: entities.NormalizePhone(phone), IncludeDeleted: includeDeleted},
+		{Email: email, IncludeDeleted: includeDeleted},
+		{Email: strings.ToUpper(email), IncludeDeleted: includeDeleted},
+	}
+}
+
+// createDriver создает водителя с указанными телефоном и email и уникальным ВУ
+func (suite *DriverLookupTestSuite) createDriver(phone, email string) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = phone
+	driver.Email = email
+	driver.LicenseNumber = fmt.Sprintf("LK%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	return created.ID
+}
+
+// insertLegacyDriver записывает водителя с телефоном в формате записи как есть, минуя сервис
+func (suite *DriverLookupTestSuite) insertLegacyDriver(phone, email string) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = phone
+	driver.Email = email
+	driver.LicenseNumber = fmt.Sprintf("LK%08d", suite.drivers)
+
+	require.NoError(suite.T(), suite.env.DriverRepo.Create(suite.env.Ctx, driver))
+	return driver.ID
+}
+
+// nextPhone возвращает уникальный телефон водителя
+func (suite *DriverLookupTestSuite) nextPhone() string {
+	return fmt.Sprintf("+7901741%04d", suite.drivers+1)
+}
+
+// nextEmail возвращает уникальный email водителя
+func (suite *DriverLookupTestSuite) nextEmail() string {
+	return fmt.Sprintf("lookup.driver%d@example.com", suite.drivers+1)
+}
+
+// lookupIDs возвращает ID найденных водителей по порядку
+func lookupIDs(found *httpHandlers.LookupDriversResponse) []uuid.UUID {
+	ids := make([]uuid.UUID, 0, len(found.Drivers))
+	for _, driver := range found.Drivers {
+		ids = append(ids, driver.ID)
+	}
+	return ids
+}
+
+// TestDriverLookupTestSuite запускает тестовый suite
+func TestDriverLookupTestSuite(t *testing.T) {
+	suite.Run(t, new(DriverLookupTestSuite))
+}
@@ -0,0 +1,254 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/messaging"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// DriverMessagesTestSuite тестирует письма и SMS водителям при онбординге и блокировке (MailHog и заглушка SMS провайдера)
+type DriverMessagesTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов и адресов в подтестах
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DriverMessagesTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DriverMessagesTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DriverMessagesTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestOnboardingMessages тестирует приветствие при регистрации и уведомление о проверке документов на языке водителя
+func (suite *DriverMessagesTestSuite) TestOnboardingMessages() {
+	testCases := []struct {
+		name            string
+		locale          entities.Locale
+		welcomeSubject  string
+		approvedSubject string
+		greeting        string
+		welcomeSMS      string
+		approvedSMS     string
+	}{
+		{
+			name:            "Russian",
+			locale:          entities.LocaleRU,
+			welcomeSubject:  "Добро пожаловать в сервис такси",
+			approvedSubject: "Документы проверены",
+			greeting:        "Здравствуйте, Иван!",
+			welcomeSMS:      "Иван, вы зарегистрированы как водитель.",
+			approvedSMS:     "Иван, документы проверены.",
+		},
+		{
+			name:            "English",
+			locale:          entities.LocaleEN,
+			welcomeSubject:  "Welcome to the taxi service",
+			approvedSubject: "Your documents are verified",
+			greeting:        "Hello, Иван!",
+			welcomeSMS:      "Иван, you are registered as a driver.",
+			approvedSMS:     "Иван, your documents are verified.",
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			mock := suite.env.EnableMessaging(t)
+
+			// Act
+			driver := suite.createDriver(t, tc.locale)
+			suite.changeStatus(t, driver.ID, entities.StatusPendingVerification)
+			suite.changeStatus(t, driver.ID, entities.StatusVerified)
+			suite.changeStatus(t, driver.ID, entities.StatusAvailable)
+
+			// Assert - письма
+			emails := mock.WaitForEmails(t, driver.Email, 2)
+			assert.Equal(t, []string{messaging.TemplateWelcome, messaging.TemplateApproved}, emailTemplates(emails))
+			assert.Equal(t, tc.welcomeSubject, emails[0].Subject)
+			assert.Equal(t, tc.approvedSubject, emails[1].Subject)
+			for _, email := range emails {
+				assert.Equal(t, string(tc.locale), email.Locale)
+				assert.Equal(t, []string{driver.Email}, email.To)
+				assert.Contains(t, email.Body, tc.greeting)
+			}
+
+			// Assert - SMS
+			sms := mock.SMS(driver.Phone)
+			require.Len(t, sms, 2)
+			assert.Contains(t, sms[0].Text, tc.welcomeSMS)
+			assert.Contains(t, sms[1].Text, tc.approvedSMS)
+			for _, message := range sms {
+				assert.Equal(t, helpers.TestSMSFrom, message.From)
+			}
+		})
+	}
+}
+
+// TestRejectionMessage тестирует уведомление об отклоненных документах
+func (suite *DriverMessagesTestSuite) TestRejectionMessage() {
+	// Arrange
+	mock := suite.env.EnableMessaging(suite.T())
+	driver := suite.createDriver(suite.T(), entities.LocaleRU)
+
+	// Act
+	suite.changeStatus(suite.T(), driver.ID, entities.StatusPendingVerification)
+	suite.changeStatus(suite.T(), driver.ID, entities.StatusRejected)
+
+	// Assert
+	emails := mock.WaitForEmails(suite.T(), driver.Email, 2)
+	assert.Equal(suite.T(), []string{messaging.TemplateWelcome, messaging.TemplateRejected}, emailTemplates(emails))
+	assert.Equal(suite.T(), "Документы отклонены", emails[1].Subject)
+	assert.Contains(suite.T(), emails[1].Body, "Загрузите исправленные документы")
+
+	sms := mock.SMS(driver.Phone)
+	require.Len(suite.T(), sms, 2)
+	assert.Contains(suite.T(), sms[1].Text, "документы не прошли проверку")
+}
+
+// TestBlockingMessages тестирует уведомление о блокировке из разных статусов
+func (suite *DriverMessagesTestSuite) TestBlockingMessages() {
+	testCases := []struct {
+		name        string
+		locale      entities.Locale
+		path        []entities.Status // переходы до блокировки
+		subject     string
+		body        string
+		sms         string
+		totalEmails int
+	}{
+		{
+			name:        "RegisteredDriver",
+			locale:      entities.LocaleRU,
+			subject:     "Аккаунт заблокирован",
+			body:        "Ваш аккаунт водителя заблокирован. Если вы считаете это ошибкой, обратитесь в службу поддержки.",
+			sms:         "Иван, ваш аккаунт водителя заблокирован.",
+			totalEmails: 2,
+		},
+		{
+			name:        "ActiveDriver",
+			locale:      entities.LocaleEN,
+			path:        []entities.Status{entities.StatusPendingVerification, entities.StatusVerified, entities.StatusAvailable},
+			subject:     "Your account is blocked",
+			body:        "Your driver account has been blocked. If you believe this is a mistake, please contact support.",
+			sms:         "Иван, your driver account has been blocked.",
+			totalEmails: 3,
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			mock := suite.env.EnableMessaging(t)
+			driver := suite.createDriver(t, tc.locale)
+			for _, status := range tc.path {
+				suite.changeStatus(t, driver.ID, status)
+			}
+
+			// Act
+			suite.changeStatus(t, driver.ID, entities.StatusBlocked)
+
+			// Assert
+			emails := mock.WaitForEmails(t, driver.Email, tc.totalEmails)
+			blocked := emails[len(emails)-1]
+			assert.Equal(t, messaging.TemplateBlocked, blocked.Template)
+			assert.Equal(t, string(tc.locale), blocked.Locale)
+			assert.Equal(t, tc.subject, blocked.Subject)
+			assert.Contains(t, blocked.Body, tc.body)
+
+			sms := mock.SMS(driver.Phone)
+			require.Len(t, sms, tc.totalEmails)
+			assert.Contains(t, sms[len(sms)-1].Text, tc.sms)
+		})
+	}
+}
+
+// TestNoMessagesForOtherEvents тестирует, что переходы без шаблона и удаление аккаунта не отправляют сообщений
+func (suite *DriverMessagesTestSuite) TestNoMessagesForOtherEvents() {
+	// Arrange
+	mock := suite.env.EnableMessaging(suite.T())
+	driver := suite.createDriver(suite.T(), entities.LocaleRU)
+	mock.WaitForEmails(suite.T(), driver.Email, 1)
+
+	// Act
+	suite.changeStatus(suite.T(), driver.ID, entities.StatusPendingVerification)
+	suite.changeStatus(suite.T(), driver.ID, entities.StatusRegistered)
+	require.NoError(suite.T(), suite.env.DriverService.DeleteDriver(suite.env.Ctx, driver.ID))
+
+	// Assert - только приветствие
+	emails := mock.Emails(suite.T(), driver.Email)
+	assert.Equal(suite.T(), []string{messaging.TemplateWelcome}, emailTemplates(emails))
+	assert.Len(suite.T(), mock.SMS(driver.Phone), 1)
+}
+
+// TestSMSProviderFailure тестирует, что сбой SMS провайдера не мешает регистрации и отправке письма
+func (suite *DriverMessagesTestSuite) TestSMSProviderFailure() {
+	// Arrange
+	mock := suite.env.EnableMessaging(suite.T())
+	mock.FailSMS(http.StatusServiceUnavailable)
+
+	// Act
+	driver := suite.createDriver(suite.T(), entities.LocaleRU)
+
+	// Assert
+	emails := mock.WaitForEmails(suite.T(), driver.Email, 1)
+	assert.Equal(suite.T(), messaging.TemplateWelcome, emails[0].Template)
+	assert.Empty(suite.T(), mock.SMS(driver.Phone))
+
+	stored, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driver.ID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), entities.StatusRegistered, stored.Status)
+}
+
+// createDriver регистрирует водителя с уникальными контактами и выбранным языком сообщений
+func (suite *DriverMessagesTestSuite) createDriver(t *testing.T, locale entities.Locale) *entities.Driver {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900999%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("messages.driver%d.%s@example.com", suite.drivers, uuid.New().String()[:8])
+	driver.LicenseNumber = fmt.Sprintf("MS%08d", suite.drivers)
+	require.NoError(t, driver.SetLocale(locale))
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+	return created
+}
+
+// changeStatus меняет статус водителя через API
+func (suite *DriverMessagesTestSuite) changeStatus(t *testing.T, driverID uuid.UUID, status entities.Status) {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPatch,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/status", driverID),
+		Body:   map[string]string{"status": string(status)},
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, "Переход в %s: %s", status, string(response.Body))
+}
+
+// emailTemplates возвращает шаблоны писем в порядке получения
+func emailTemplates(emails []helpers.ReceivedEmail) []string {
+	templates := make([]string, 0, len(emails))
+	for _, email := range emails {
+		templates = append(templates, email.Template)
+	}
+	return templates
+}
@@ -0,0 +1,199 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// locationHeartbeatWindow окно, после которого местоположение водителя считается устаревшим
+const locationHeartbeatWindow = entities.LocationStaleAfter
+
+// DriverOfflineTestSuite тестовый suite для потери связи с водителем во время поездки
+type DriverOfflineTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DriverOfflineTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DriverOfflineTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DriverOfflineTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestDriverGoesOfflineMidTrip тестирует потерю связи во время поездки: после окна heartbeat местоположение
+// водителя считается устаревшим, а после восстановления связи отслеживание продолжается без потерь
+func (suite *DriverOfflineTestSuite) TestDriverGoesOfflineMidTrip() {
+	route := fixtures.CreateCenterToAirportRoute(12)
+	online, buffered, resumed := route[:5], route[5:11], route[11]
+	orderID := uuid.New()
+
+	suite.T().Log("Step 1: Driver on a trip sends locations")
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	driverID := created.ID
+
+	// Обходим цепочку переходов статусов - водитель уже на смене
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driverID, entities.StatusOnShift))
+	suite.changeStatus(driverID, entities.StatusBusy)
+
+	start := time.Now().Add(-time.Duration(len(online)) * time.Second)
+	for i, point := range online {
+		suite.postLocation(driverID, point, start.Add(time.Duration(i)*time.Second))
+	}
+	require.NoError(suite.T(), suite.env.LocationService.StartOrderTracking(suite.env.Ctx, driverID, orderID))
+
+	suite.T().Log("Step 2: Current location is fresh while online")
+	current := suite.currentLocation(driverID)
+	require.Equal(suite.T(), http.StatusOK, current.StatusCode, string(current.Body))
+	suite.assertCurrentAt(current, online[len(online)-1])
+
+	suite.T().Log("Step 3: Network is lost and the heartbeat window passes")
+	lostAt := time.Now()
+	suite.env.DB.AgeDriverLocations(suite.T(), driverID, locationHeartbeatWindow+time.Minute)
+
+	result, jobResponse := suite.env.API.RunJob(services.JobDetectConnectionLoss)
+	require.Equal(suite.T(), http.StatusOK, jobResponse.StatusCode, string(jobResponse.Body))
+	assert.Equal(suite.T(), 1, result.Affected)
+
+	// Повторный запуск не публикует потерю связи еще раз
+	result, jobResponse = suite.env.API.RunJob(services.JobDetectConnectionLoss)
+	require.Equal(suite.T(), http.StatusOK, jobResponse.StatusCode, string(jobResponse.Body))
+	assert.Zero(suite.T(), result.Affected)
+
+	current = suite.currentLocation(driverID)
+	assert.Equal(suite.T(), http.StatusNotFound, current.StatusCode, string(current.Body))
+	suite.env.API.AssertErrorResponse(current, "LOCATION_TOO_OLD")
+
+	// Потеря связи не отменяет поездку
+	driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), entities.StatusBusy, driver.Status)
+
+	staleEvents := suite.env.Events.EventsForDriver(driverID, services.EventAvailabilityChanged)
+
+	suite.T().Log("Step 4: Connection restored, buffered points are flushed and live updates resume")
+	locationEventsBefore := len(suite.env.Events.EventsForDriver(driverID, "driver.location.updated"))
+
+	// Приложение досылает точки, накопленные без связи, с их исходными метками времени
+	offlineStart := lostAt.Add(-locationHeartbeatWindow)
+	step := locationHeartbeatWindow / time.Duration(len(buffered)+1)
+	batch := make([]map[string]interface{}, len(buffered))
+	for i, point := range buffered {
+		batch[i] = map[string]interface{}{
+			"latitude":  point.Latitude,
+			"longitude": point.Longitude,
+			"timestamp": offlineStart.Add(time.Duration(i+1) * step).Unix(),
+		}
+	}
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/locations/batch", driverID),
+		Body:   map[string]interface{}{"locations": batch},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	suite.postLocation(driverID, resumed, time.Now())
+
+	current = suite.currentLocation(driverID)
+	require.Equal(suite.T(), http.StatusOK, current.StatusCode, string(current.Body))
+	suite.assertCurrentAt(current, resumed)
+
+	assert.Len(suite.T(), suite.env.Events.EventsForDriver(driverID, "driver.location.updated"), locationEventsBefore+1,
+		"Живое обновление после восстановления связи должно публиковать driver.location.updated")
+
+	// Точки, досланные после восстановления связи, не теряются и не дублируются
+	history, historyResponse := suite.env.API.GetLocationHistory(driverID, helpers.HistoryQuery{
+		From: time.Now().Add(-time.Hour),
+		To:   time.Now().Add(time.Minute),
+	})
+	require.NotNil(suite.T(), history, string(historyResponse.Body))
+
+	recorded := make(map[int64]int)
+	for _, location := range history.Locations {
+		recorded[location.RecordedAt.Unix()]++
+	}
+	for _, point := range batch {
+		assert.Equal(suite.T(), 1, recorded[point["timestamp"].(int64)], "Досланная точка %v", point)
+	}
+
+	suite.T().Run("Step 5: Stale and resumed availability events", func(t *testing.T) {
+		require.Len(t, staleEvents, 1, "Одно событие о потере связи")
+		assert.Equal(t, false, staleEvents[0].DataMap()["available"])
+		assert.Equal(t, "connection_lost", staleEvents[0].DataMap()["reason"])
+
+		resumedEvents := suite.env.Events.EventsForDriver(driverID, services.EventAvailabilityChanged)[len(staleEvents):]
+		require.Len(t, resumedEvents, 1, "Одно событие о восстановлении связи")
+		assert.Equal(t, true, resumedEvents[0].DataMap()["available"])
+		assert.Equal(t, "connection_restored", resumedEvents[0].DataMap()["reason"])
+	})
+}
+
+// changeStatus меняет статус водителя через API
+func (suite *DriverOfflineTestSuite) changeStatus(driverID uuid.UUID, status entities.Status) {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPatch,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/status", driverID),
+		Body:   map[string]string{"status": string(status)},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+}
+
+// postLocation отправляет местоположение водителя через API с указанной меткой времени
+func (suite *DriverOfflineTestSuite) postLocation(driverID uuid.UUID, point fixtures.RoutePoint, at time.Time) {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/locations", driverID),
+		Body: map[string]interface{}{
+			"latitude":  point.Latitude,
+			"longitude": point.Longitude,
+			"timestamp": at.Unix(),
+		},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+}
+
+// currentLocation запрашивает текущее местоположение водителя через API
+func (suite *DriverOfflineTestSuite) currentLocation(driverID uuid.UUID) *helpers.APIResponse {
+	return suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/locations/current", driverID),
+	})
+}
+
+// assertCurrentAt проверяет, что текущее местоположение совпадает с точкой маршрута
+func (suite *DriverOfflineTestSuite) assertCurrentAt(response *helpers.APIResponse, point fixtures.RoutePoint) {
+	var location httpHandlers.LocationResponse
+	suite.env.API.UnmarshalResponse(response, &location)
+
+	assert.InDelta(suite.T(), point.Latitude, location.Latitude, 1e-6)
+	assert.InDelta(suite.T(), point.Longitude, location.Longitude, 1e-6)
+}
+
+// TestDriverOfflineTestSuite запускает тестовый suite
+func TestDriverOfflineTestSuite(t *testing.T) {
+	suite.Run(t, new(DriverOfflineTestSuite))
+}
@@ -162,6 +162,27 @@ func (suite *DriverRepositoryTestSuite) TestUpdateDriverStatus() {
 	assert.Equal(suite.T(), newStatus, updatedDriver.Status)
 }
 
+// TestUpdateDriverStatusFrom тестирует обновление статуса с проверкой текущего статуса
+func (suite *DriverRepositoryTestSuite) TestUpdateDriverStatusFrom() {
+	// Arrange
+	driver := fixtures.CreateTestDriverWithStatus(entities.StatusOnShift)
+	err := suite.repository.Create(suite.ctx, driver)
+	require.NoError(suite.T(), err)
+
+	// Act
+	err = suite.repository.UpdateStatusFrom(suite.ctx, driver.ID, entities.StatusOnShift, entities.StatusBusy)
+	require.NoError(suite.T(), err)
+
+	staleErr := suite.repository.UpdateStatusFrom(suite.ctx, driver.ID, entities.StatusOnShift, entities.StatusBusy)
+
+	// Assert
+	assert.Equal(suite.T(), entities.ErrConcurrentModification, staleErr, "Статус уже изменен, повторный переход должен быть отклонен")
+
+	updatedDriver, err := suite.repository.GetByID(suite.ctx, driver.ID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), entities.StatusBusy, updatedDriver.Status)
+}
+
 // TestSoftDeleteDriver тестирует мягкое удаление водителя
 func (suite *DriverRepositoryTestSuite) TestSoftDeleteDriver() {
 	// Arrange
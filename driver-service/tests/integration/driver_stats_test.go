@@ -0,0 +1,188 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Допуски сверки статистики API с исходными таблицами
+const (
+	statsRatingTolerance   = 0.005 // current_rating хранится с точностью DECIMAL(3,2)
+	statsDistanceTolerance = 0.001 // расстояние в км считается в Go и в SQL независимо
+)
+
+// DriverStatsTestSuite сверяет статистику водителя из API с вычисленной напрямую по исходным таблицам
+type DriverStatsTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DriverStatsTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DriverStatsTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DriverStatsTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestStatsMatchDatabase тестирует, что поездки, средний рейтинг и пройденное расстояние в API
+// совпадают с вычисленными по исходным таблицам
+func (suite *DriverStatsTestSuite) TestStatsMatchDatabase() {
+	testCases := []struct {
+		name    string
+		ratings []int // оценки поездок, 0 - поездка без оценки
+	}{
+		{name: "NoTrips", ratings: nil},
+		{name: "SingleRatedTrip", ratings: []int{5}},
+		{name: "SeveralTripsPartlyRated", ratings: []int{5, 0, 3, 4, 0, 4}},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			suite.env.DB.CleanupTables(t)
+
+			createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+			require.NoError(t, err)
+			driverID := createdDriver.ID
+
+			from := time.Now().Add(-3 * time.Hour)
+			suite.completeTrips(t, driverID, from.Add(time.Minute), tc.ratings)
+			to := time.Now().Add(time.Minute)
+
+			// Эталон из исходных таблиц
+			expected := suite.env.DB.ComputeDriverStats(t, driverID, from, to)
+			assert.Equal(t, len(tc.ratings), expected.TotalTrips, "Эталон должен видеть все проведенные поездки")
+
+			// Act
+			driver := suite.getDriver(t, driverID)
+			history, historyResponse := suite.env.API.GetLocationHistory(driverID, helpers.HistoryQuery{From: from, To: to})
+			require.NotNil(t, history, string(historyResponse.Body))
+
+			// Assert
+			assert.Equal(t, expected.TotalTrips, driver.TotalTrips, "Количество поездок")
+			assert.InDelta(t, expected.AverageRating, driver.CurrentRating, statsRatingTolerance, "Средний рейтинг")
+
+			require.NotNil(t, history.Stats, "История должна содержать статистику")
+			assert.InDelta(t, expected.DistanceKm, history.Stats.DistanceTraveled, statsDistanceTolerance, "Пройденное расстояние")
+
+			t.Run("StatsEndpoint", func(t *testing.T) {
+				stats, response := suite.env.API.GetDriverStats(driverID, from, to)
+				require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+				assert.Equal(t, driverID, stats.DriverID)
+				assert.Equal(t, expected.TotalTrips, stats.TotalTrips)
+				assert.Equal(t, expected.TotalRatings, stats.TotalRatings)
+				assert.InDelta(t, expected.AverageRating, stats.AverageRating, statsRatingTolerance)
+				assert.InDelta(t, expected.DistanceKm, stats.DistanceKm, statsDistanceTolerance)
+			})
+		})
+	}
+}
+
+// TestStatsPeriod тестирует, что расстояние считается только за запрошенный период, а поездки и рейтинг - за все время
+func (suite *DriverStatsTestSuite) TestStatsPeriod() {
+	// Arrange
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	driverID := createdDriver.ID
+
+	suite.completeTrips(suite.T(), driverID, time.Now().Add(-3*time.Hour), []int{4, 5})
+	before := time.Now().Add(-time.Hour)
+	after := time.Now().Add(time.Minute)
+
+	// Act
+	stats, response := suite.env.API.GetDriverStats(driverID, before, after)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.Equal(suite.T(), 2, stats.TotalTrips)
+	assert.Equal(suite.T(), 2, stats.TotalRatings)
+	assert.InDelta(suite.T(), 4.5, stats.AverageRating, statsRatingTolerance)
+	assert.Zero(suite.T(), stats.DistanceKm, "Точек трека в периоде нет")
+}
+
+// TestStatsErrors тестирует ответы статистики на неизвестного водителя и неверный период
+func (suite *DriverStatsTestSuite) TestStatsErrors() {
+	// Arrange
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+
+	// Act
+	_, unknown := suite.env.API.GetDriverStats(uuid.New(), time.Now().Add(-time.Hour), time.Now())
+	_, reversed := suite.env.API.GetDriverStats(createdDriver.ID, time.Now(), time.Now().Add(-time.Hour))
+
+	// Assert
+	assert.Equal(suite.T(), http.StatusNotFound, unknown.StatusCode)
+	suite.env.API.AssertErrorResponse(unknown, "DRIVER_NOT_FOUND")
+	assert.Equal(suite.T(), http.StatusBadRequest, reversed.StatusCode)
+	suite.env.API.AssertErrorResponse(reversed, "INVALID_REQUEST")
+}
+
+// completeTrips проводит поездки из центра в аэропорт: записывает маршрут, начинает отслеживание заказа,
+// засчитывает поездку и сохраняет оценку, если она задана
+func (suite *DriverStatsTestSuite) completeTrips(t *testing.T, driverID uuid.UUID, start time.Time, ratings []int) {
+	route := fixtures.CreateCenterToAirportRoute(6)
+	recordedAt := start
+
+	for _, rating := range ratings {
+		orderID := uuid.New()
+
+		for i, point := range route {
+			location := entities.NewDriverLocation(driverID, point.Latitude, point.Longitude, recordedAt)
+			require.NoError(t, suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+			recordedAt = recordedAt.Add(30 * time.Second)
+
+			if i == 0 {
+				require.NoError(t, suite.env.LocationService.StartOrderTracking(suite.env.Ctx, driverID, orderID))
+			}
+		}
+
+		require.NoError(t, suite.env.DriverService.IncrementTripCount(suite.env.Ctx, driverID))
+
+		if rating > 0 {
+			driverRating := fixtures.CreateTestRating(driverID, rating)
+			driverRating.OrderID = &orderID
+			require.NoError(t, suite.env.DB.InsertRating(suite.env.Ctx, driverRating))
+		}
+	}
+}
+
+// getDriver возвращает карточку водителя через API
+func (suite *DriverStatsTestSuite) getDriver(t *testing.T, driverID uuid.UUID) *httpHandlers.DriverResponse {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s", driverID),
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return &driver
+}
+
+// TestDriverStatsTestSuite запускает тестовый suite
+func TestDriverStatsTestSuite(t *testing.T) {
+	suite.Run(t, new(DriverStatsTestSuite))
+}
@@ -0,0 +1,256 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"math/rand"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/lib/pq"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры проверки задержки пакетного запроса статусов
+const (
+	statusBatchSamples       = 30 // запросов по MaxStatusBatchSize ID
+	statusBatchWarmupSamples = 3  // запросов на прогрев перед замером
+)
+
+// statusBatchQuery запрос репозитория, который выполняет пакетный запрос статусов
+const statusBatchQuery = `
+	SELECT id, status, updated_at FROM drivers
+	WHERE id = ANY($1::uuid[]) AND deleted_at IS NULL`
+
+// DriverStatusBatchTestSuite тестирует пакетный запрос статусов водителей (POST /api/v1/drivers/statuses),
+// которым диспетчерская опрашивает сотни водителей за раз
+type DriverStatusBatchTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных контактов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *DriverStatusBatchTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *DriverStatusBatchTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *DriverStatusBatchTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestPartialUnknownIDs тестирует, что неизвестные и удаленные водители попадают в not_found,
+// а не проваливают весь запрос
+func (suite *DriverStatusBatchTestSuite) TestPartialUnknownIDs() {
+	// Arrange
+	available := suite.createDriver(entities.StatusAvailable)
+	busy := suite.createDriver(entities.StatusBusy)
+	deleted := suite.createDriver(entities.StatusAvailable)
+	require.NoError(suite.T(), suite.env.DriverService.DeleteDriver(suite.env.Ctx, deleted))
+	unknown := uuid.New()
+
+	// Act
+	statuses, response := suite.env.API.GetDriverStatuses([]uuid.UUID{available, unknown, busy, deleted})
+
+	// Assert
+	require.NotNil(suite.T(), statuses, string(response.Body))
+	require.Len(suite.T(), statuses.Statuses, 2)
+	assert.Equal(suite.T(), available, statuses.Statuses[0].DriverID)
+	assert.Equal(suite.T(), entities.StatusAvailable, statuses.Statuses[0].Status)
+	assert.Equal(suite.T(), busy, statuses.Statuses[1].DriverID)
+	assert.Equal(suite.T(), entities.StatusBusy, statuses.Statuses[1].Status)
+	assert.False(suite.T(), statuses.Statuses[0].UpdatedAt.IsZero())
+	assert.Equal(suite.T(), []uuid.UUID{unknown, deleted}, statuses.NotFound)
+}
+
+// TestAllUnknownIDs тестирует, что запрос только неизвестных водителей возвращает пустые статусы, а не 404
+func (suite *DriverStatusBatchTestSuite) TestAllUnknownIDs() {
+	// Arrange
+	ids := []uuid.UUID{uuid.New(), uuid.New()}
+
+	// Act
+	statuses, response := suite.env.API.GetDriverStatuses(ids)
+
+	// Assert
+	require.NotNil(suite.T(), statuses, string(response.Body))
+	assert.Empty(suite.T(), statuses.Statuses)
+	assert.Equal(suite.T(), ids, statuses.NotFound)
+	assert.Contains(suite.T(), string(response.Body), `"statuses":[]`, "Пустой список статусов сериализуется как [], а не null")
+}
+
+// TestResponseOrder тестирует, что статусы возвращаются в порядке driver_ids запроса, а не в порядке БД,
+// и повторяющиеся ID встречаются в ответе один раз
+func (suite *DriverStatusBatchTestSuite) TestResponseOrder() {
+	// Arrange
+	statusesByDriver := map[uuid.UUID]entities.Status{}
+	var ids []uuid.UUID
+	for _, status := range []entities.Status{
+		entities.StatusAvailable, entities.StatusBusy, entities.StatusOnShift, entities.StatusOffline,
+		entities.StatusInactive, entities.StatusSuspended, entities.StatusAvailable, entities.StatusBusy,
+	} {
+		id := suite.createDriver(status)
+		statusesByDriver[id] = status
+		ids = append(ids, id)
+	}
+	rng := rand.New(rand.NewSource(helpers.TestSeed(suite.T(), 1737)))
+
+	for round := 0; round < 5; round++ {
+		order := append([]uuid.UUID(nil), ids...)
+		rng.Shuffle(len(order), func(i, j int) { order[i], order[j] = order[j], order[i] })
+		// Повторы первого и последнего ID в конце запроса
+		request := append(append([]uuid.UUID(nil), order...), order[len(order)-1], order[0])
+
+		// Act
+		statuses, response := suite.env.API.GetDriverStatuses(request)
+
+		// Assert
+		require.NotNil(suite.T(), statuses, string(response.Body))
+		require.Len(suite.T(), statuses.Statuses, len(order), "Раунд %d", round)
+		for i, status := range statuses.Statuses {
+			assert.Equal(suite.T(), order[i], status.DriverID, "Раунд %d, позиция %d", round, i)
+			assert.Equal(suite.T(), statusesByDriver[status.DriverID], status.Status)
+		}
+		assert.Empty(suite.T(), statuses.NotFound)
+	}
+}
+
+// TestInvalidBatch тестирует отклонение пустого, слишком большого и некорректного пакета
+func (suite *DriverStatusBatchTestSuite) TestInvalidBatch() {
+	testCases := []struct {
+		name         string
+		body         interface{}
+		expectedCode string
+	}{
+		{
+			name:         "Empty",
+			body:         map[string]interface{}{"driver_ids": []string{}},
+			expectedCode: "INVALID_REQUEST",
+		},
+		{
+			name:         "TooLarge",
+			body:         map[string]interface{}{"driver_ids": unknownDriverIDs(services.MaxStatusBatchSize + 1)},
+			expectedCode: "BATCH_TOO_LARGE",
+		},
+		{
+			name: "MissingDriverIDs",
+			body: map[string]interface{}{},
+		},
+		{
+			name: "InvalidUUID",
+			body: map[string]interface{}{"driver_ids": []string{uuid.NewString(), "not-a-uuid"}},
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.Run(tc.name, func() {
+			// Act
+			response := suite.env.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodPost,
+				URL:    suite.env.API.APIPath("/drivers/statuses"),
+				Body:   tc.body,
+			})
+
+			// Assert
+			suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+			suite.env.API.AssertErrorResponse(response, tc.expectedCode)
+		})
+	}
+}
+
+// TestMaxBatchLatency тестирует задержку запроса MaxStatusBatchSize водителей, как при опросе
+// диспетчерской крупного автопарка (порог умножается на LATENCY_BUDGET_MULTIPLIER)
+func (suite *DriverStatusBatchTestSuite) TestMaxBatchLatency() {
+	// Arrange - водителей больше размера пакета, часть ID пакета неизвестна
+	dataset := suite.env.DB.SeedLargeDataset(suite.T(), helpers.LargeDataset{
+		Drivers:            services.MaxStatusBatchSize * 2,
+		LocationsPerDriver: 1,
+		Interval:           time.Minute,
+		SpreadKm:           30,
+	}, helpers.TestSeed(suite.T(), 1737))
+	rng := rand.New(rand.NewSource(helpers.TestSeed(suite.T(), 1737)))
+	timings := helpers.NewRequestTimings()
+
+	var sampleIDs []string
+	for i := 0; i < statusBatchWarmupSamples+statusBatchSamples; i++ {
+		client := suite.env.API
+		if i >= statusBatchWarmupSamples {
+			client = client.WithResponseInterceptor(timings.Record)
+		}
+
+		ids := make([]uuid.UUID, 0, services.MaxStatusBatchSize)
+		for _, index := range rng.Perm(len(dataset.DriverIDs))[:services.MaxStatusBatchSize-10] {
+			ids = append(ids, dataset.DriverIDs[index])
+		}
+		unknown := unknownDriverIDs(10)
+		ids = append(ids, unknown...)
+		rng.Shuffle(len(ids), func(i, j int) { ids[i], ids[j] = ids[j], ids[i] })
+
+		// Act
+		statuses, response := client.GetDriverStatuses(ids)
+
+		// Assert
+		require.NotNil(suite.T(), statuses, string(response.Body))
+		require.Len(suite.T(), statuses.Statuses, services.MaxStatusBatchSize-len(unknown))
+		require.ElementsMatch(suite.T(), unknown, statuses.NotFound)
+		sampleIDs = sampleIDs[:0]
+		for _, id := range ids {
+			sampleIDs = append(sampleIDs, id.String())
+		}
+	}
+
+	suite.env.DB.AssertLatencyBudget(suite.T(), timings, helpers.LatencyBudget{
+		Operation: "POST /api/v1/drivers/statuses",
+		P95:       150 * time.Millisecond,
+		Queries: []helpers.PlannedQuery{
+			{Name: "statuses", Query: statusBatchQuery, Args: []interface{}{pq.Array(sampleIDs)}},
+		},
+	})
+}
+
+// createDriver создает водителя с уникальными контактами и переводит его в статус status
+func (suite *DriverStatusBatchTestSuite) createDriver(status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900737%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("status.batch%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("SB%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+
+	// Статус задается напрямую: переходы между статусами проверяются в других тестах
+	_, err = suite.env.DB.ExecContext(suite.env.Ctx,
+		"UPDATE drivers SET status = $1 WHERE id = $2", status, created.ID)
+	require.NoError(suite.T(), err)
+	return created.ID
+}
+
+// unknownDriverIDs возвращает n случайных ID, которых нет в БД
+func unknownDriverIDs(n int) []uuid.UUID {
+	ids := make([]uuid.UUID, n)
+	for i := range ids {
+		ids[i] = uuid.New()
+	}
+	return ids
+}
+
+// TestDriverStatusBatchTestSuite запускает тестовый suite
+func TestDriverStatusBatchTestSuite(t *testing.T) {
+	suite.Run(t, new(DriverStatusBatchTestSuite))
+}
@@ -174,11 +174,7 @@ func (suite *E2ETestSuite) TestCompleteDriverWorkflow() {
 
 	suite.apiHelper.AssertStatusCode(activeResponse, http.StatusOK)
 
-	var activeDriversResp map[string]interface{}
-	suite.apiHelper.UnmarshalResponse(activeResponse, &activeDriversResp)
-
-	drivers := activeDriversResp["drivers"].([]interface{})
-	assert.Len(suite.T(), drivers, 1)
+	helpers.AssertJSONPath(suite.T(), activeResponse.Body, "$.drivers", helpers.HasLen(1))
 
 	// 7. Обновление местоположения
 	suite.T().Log("Step 7: Update driver location")
@@ -282,7 +278,12 @@ func (suite *E2ETestSuite) TestDriverLocationTracking() {
 		{"latitude": 55.7655, "longitude": 37.6220, "speed": 0.0},  // Остановка
 	}
 
+	// Точки отправляются с интервалом 30 секунд, как у реального трекера
+	baseTime := time.Now().Add(-time.Duration(len(route)) * 30 * time.Second)
 	for i, point := range route {
+		point["timestamp"] = baseTime.Add(time.Duration(i) * 30 * time.Second).Unix()
+		point["accuracy"] = 10.0
+
 		locationResponse := suite.apiHelper.MakeRequest(helpers.APIRequest{
 			Method: http.MethodPost,
 			URL:    fmt.Sprintf("/api/v1/drivers/%s/locations", driver.ID),
@@ -290,11 +291,6 @@ func (suite *E2ETestSuite) TestDriverLocationTracking() {
 		})
 
 		suite.apiHelper.AssertStatusCode(locationResponse, http.StatusOK)
-
-		// Небольшая пауза между обновлениями
-		if i < len(route)-1 {
-			time.Sleep(100 * time.Millisecond)
-		}
 	}
 
 	// 3. Получаем историю местоположений
@@ -321,6 +317,7 @@ func (suite *E2ETestSuite) TestDriverLocationTracking() {
 	assert.NotNil(suite.T(), history.Stats)
 	assert.Greater(suite.T(), history.Stats.DistanceTraveled, 0.0)
 	assert.Equal(suite.T(), 40.0, history.Stats.MaxSpeed)
+	helpers.AssertTrajectorySane(suite.T(), history.Locations, helpers.DefaultTrajectoryLimits())
 
 	// 4. Проверяем текущее местоположение
 	suite.T().Log("Checking current location")
@@ -428,11 +425,7 @@ func (suite *E2ETestSuite) TestMultipleDriversScenario() {
 
 	suite.apiHelper.AssertStatusCode(activeResponse, http.StatusOK)
 
-	var activeDriversResp map[string]interface{}
-	suite.apiHelper.UnmarshalResponse(activeResponse, &activeDriversResp)
-
-	drivers := activeDriversResp["drivers"].([]interface{})
-	assert.Len(suite.T(), drivers, 5)
+	helpers.AssertJSONPath(suite.T(), activeResponse.Body, "$.drivers", helpers.HasLen(5))
 
 	// 5. Тестируем поиск водителей поблизости
 	suite.T().Log("Testing nearby drivers search")
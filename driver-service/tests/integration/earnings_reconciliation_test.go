@@ -0,0 +1,242 @@
+//go:build integration
+
+package integration
+
+import (
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// EarningsReconciliationTestSuite тестовый suite для сверки заработка за смену с оплатами поездок
+type EarningsReconciliationTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *EarningsReconciliationTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *EarningsReconciliationTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *EarningsReconciliationTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestShiftEarningsReconciliation тестирует сверку итогов смены и API заработка с опубликованными оплатами
+func (suite *EarningsReconciliationTestSuite) TestShiftEarningsReconciliation() {
+	suite.T().Log("Step 1: Driver goes on shift")
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driver.ID, entities.StatusOnShift))
+
+	shiftStart := time.Now().Add(-4 * time.Hour)
+	shift := fixtures.CreateTestShift(driver.ID)
+	shift.StartTime = shiftStart
+	suite.env.DB.InsertShift(suite.T(), shift)
+
+	suite.T().Log("Step 2: Trips are completed and paid, payment.processed events are published")
+	trips := []struct {
+		gross    float64
+		rate     float64
+		distance float64
+	}{
+		{gross: 450, rate: fixtures.DefaultCommissionRate, distance: 6.2},
+		{gross: 1230.5, rate: fixtures.DefaultCommissionRate, distance: 18.4},
+		{gross: 333.33, rate: 0.15, distance: 3.1}, // комиссия с округлением до копеек
+		{gross: 99.99, rate: fixtures.DefaultCommissionRate, distance: 1.2},
+		{gross: 2780, rate: 0.1, distance: 41.7},
+	}
+
+	var payments []*fixtures.PaymentProcessed
+	for _, trip := range trips {
+		payment := fixtures.CreatePaymentProcessed(driver.ID, shift.ID, trip.gross, trip.rate, trip.distance)
+		payments = append(payments, payment)
+
+		orderID := uuid.New()
+		err := suite.env.OrderEvents.HandleOrderCompleted(suite.env.Ctx,
+			services.SubjectOrderCompleted+":"+orderID.String(), driver.ID, orderID, payment.Distance)
+		require.NoError(suite.T(), err)
+		suite.publishPayment(payment)
+	}
+
+	// Повторная доставка события (at-least-once) не должна учитываться в заработке дважды
+	suite.publishPayment(payments[1])
+
+	suite.T().Log("Step 3: Shift ends")
+	require.NoError(suite.T(), suite.env.ShiftRepo.Complete(suite.env.Ctx, shift.ID, time.Now()))
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driver.ID, entities.StatusAvailable))
+
+	suite.T().Log("Step 4: Reconcile shift totals with published payments")
+	published := suite.uniquePayments(driver.ID)
+	require.Len(suite.T(), published, len(payments), "Сверка должна вестись по уникальным payment_id")
+
+	var gross, commission, net float64
+	for _, payment := range payments {
+		event, ok := published[payment.PaymentID]
+		require.True(suite.T(), ok, "Оплата %s не опубликована", payment.PaymentID)
+
+		assert.InDelta(suite.T(), payment.GrossAmount, event["gross_amount"], 0.001)
+		assert.InDelta(suite.T(), payment.GrossAmount-payment.Commission, event["net_amount"], 0.001,
+			"net_amount = gross_amount - commission")
+		assert.InDelta(suite.T(), payment.GrossAmount*payment.CommissionRate, event["commission"], 0.005,
+			"Комиссия должна округляться не более чем на полкопейки")
+
+		gross += payment.GrossAmount
+		commission += payment.Commission
+		net += payment.NetAmount
+	}
+	assert.InDelta(suite.T(), gross-commission, net, 0.001)
+
+	stored := suite.env.DB.GetShift(suite.T(), shift.ID)
+	assert.Equal(suite.T(), entities.ShiftStatusCompleted, stored.Status)
+	assert.Equal(suite.T(), len(payments), stored.TotalTrips, "Каждая оплата - ровно одна поездка")
+	assert.InDelta(suite.T(), net, stored.TotalEarnings, 0.001, "Заработок смены должен совпадать с суммой оплат")
+
+	suite.T().Log("Step 5: Earnings API returns the same totals")
+	summary, response := suite.env.API.GetDriverEarnings(driver.ID, shiftStart.Add(-time.Minute), time.Now().Add(time.Minute))
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	assert.Equal(suite.T(), driver.ID, summary.DriverID)
+	assert.Equal(suite.T(), len(payments), summary.TotalTrips)
+	assert.InDelta(suite.T(), gross, summary.GrossEarnings, 0.001)
+	assert.InDelta(suite.T(), commission, summary.Commission, 0.001)
+	assert.InDelta(suite.T(), net, summary.NetEarnings, 0.001)
+
+	var expectedIDs []uuid.UUID
+	for id := range published {
+		expectedIDs = append(expectedIDs, id)
+	}
+	assert.ElementsMatch(suite.T(), expectedIDs, summary.PaymentIDs, "Ни одна оплата не должна учитываться дважды")
+}
+
+// TestEarningsOutsidePeriod тестирует, что оплаты вне запрошенного периода не учитываются
+func (suite *EarningsReconciliationTestSuite) TestEarningsOutsidePeriod() {
+	// Arrange
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	shift := fixtures.CreateTestShift(driver.ID)
+	shift.StartTime = time.Now().Add(-time.Hour)
+	suite.env.DB.InsertShift(suite.T(), shift)
+	suite.publishPayment(fixtures.CreatePaymentProcessed(driver.ID, shift.ID, 500, fixtures.DefaultCommissionRate, 5))
+
+	// Act
+	summary, response := suite.env.API.GetDriverEarnings(driver.ID, time.Now().Add(-48*time.Hour), time.Now().Add(-24*time.Hour))
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.Zero(suite.T(), summary.TotalTrips)
+	assert.Zero(suite.T(), summary.NetEarnings)
+	assert.Empty(suite.T(), summary.PaymentIDs)
+}
+
+// TestPaymentAfterShiftEnded тестирует, что оплата, пришедшая после окончания смены, не теряется: она записывается
+// без смены, входит в заработок водителя и не меняет итоги закрытой смены
+func (suite *EarningsReconciliationTestSuite) TestPaymentAfterShiftEnded() {
+	// Arrange
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	shift := fixtures.CreateTestShift(driver.ID)
+	shift.StartTime = time.Now().Add(-time.Hour)
+	suite.env.DB.InsertShift(suite.T(), shift)
+	require.NoError(suite.T(), suite.env.ShiftRepo.Complete(suite.env.Ctx, shift.ID, time.Now()))
+	payment := fixtures.CreatePaymentProcessed(driver.ID, shift.ID, 500, fixtures.DefaultCommissionRate, 5)
+
+	// Act
+	suite.publishPayment(payment)
+
+	// Assert
+	var shiftID *uuid.UUID
+	require.NoError(suite.T(), suite.env.DB.GetContext(suite.env.Ctx, &shiftID,
+		"SELECT shift_id FROM driver_payments WHERE payment_id = $1", payment.PaymentID))
+	assert.Nil(suite.T(), shiftID, "Оплата после смены записывается без смены")
+	assert.Zero(suite.T(), suite.env.DB.GetShift(suite.T(), shift.ID).TotalEarnings, "Итоги закрытой смены не меняются")
+
+	summary, response := suite.env.API.GetDriverEarnings(driver.ID, time.Now().Add(-time.Hour), time.Now().Add(time.Minute))
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.InDelta(suite.T(), payment.NetAmount, summary.NetEarnings, 0.001)
+	assert.Equal(suite.T(), []uuid.UUID{payment.PaymentID}, summary.PaymentIDs)
+}
+
+// TestPaymentDuringBreak тестирует, что оплата, пришедшая во время перерыва, добавляется к заработку смены
+func (suite *EarningsReconciliationTestSuite) TestPaymentDuringBreak() {
+	// Arrange
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	shift := fixtures.CreateTestShift(driver.ID)
+	shift.StartTime = time.Now().Add(-time.Hour)
+	shift.Status = entities.ShiftStatusSuspended
+	suite.env.DB.InsertShift(suite.T(), shift)
+	payment := fixtures.CreatePaymentProcessed(driver.ID, shift.ID, 500, fixtures.DefaultCommissionRate, 5)
+
+	// Act
+	suite.publishPayment(payment)
+
+	// Assert
+	assert.InDelta(suite.T(), payment.NetAmount, suite.env.DB.GetShift(suite.T(), shift.ID).TotalEarnings, 0.001)
+}
+
+// TestEarningsErrors тестирует ответы API заработка на неизвестного водителя и неверный период
+func (suite *EarningsReconciliationTestSuite) TestEarningsErrors() {
+	// Arrange
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+
+	// Act
+	_, unknown := suite.env.API.GetDriverEarnings(uuid.New(), time.Now().Add(-time.Hour), time.Now())
+	_, reversed := suite.env.API.GetDriverEarnings(driver.ID, time.Now(), time.Now().Add(-time.Hour))
+
+	// Assert
+	assert.Equal(suite.T(), http.StatusNotFound, unknown.StatusCode)
+	suite.env.API.AssertErrorResponse(unknown, "DRIVER_NOT_FOUND")
+	assert.Equal(suite.T(), http.StatusBadRequest, reversed.StatusCode)
+	suite.env.API.AssertErrorResponse(reversed, "INVALID_REQUEST")
+}
+
+// publishPayment публикует событие payment.processed от имени платежного сервиса и доставляет его сервису
+// с идентификатором события, который подписчик NATS берет для событий без Nats-Msg-Id
+func (suite *EarningsReconciliationTestSuite) publishPayment(payment *fixtures.PaymentProcessed) {
+	err := suite.env.Events.PublishDriverEvent(suite.env.Ctx, services.SubjectPaymentProcessed, payment.DriverID, payment.EventData())
+	require.NoError(suite.T(), err)
+
+	eventID := services.SubjectPaymentProcessed + ":" + payment.PaymentID.String()
+	require.NoError(suite.T(), suite.env.OrderEvents.HandlePaymentProcessed(suite.env.Ctx, eventID, payment.Payment()))
+}
+
+// uniquePayments возвращает опубликованные оплаты водителя, сгруппированные по payment_id
+func (suite *EarningsReconciliationTestSuite) uniquePayments(driverID uuid.UUID) map[uuid.UUID]map[string]interface{} {
+	result := make(map[uuid.UUID]map[string]interface{})
+	for _, event := range suite.env.Events.EventsForDriver(driverID, services.SubjectPaymentProcessed) {
+		data := event.DataMap()
+		paymentID, err := uuid.Parse(data["payment_id"].(string))
+		require.NoError(suite.T(), err)
+
+		if previous, ok := result[paymentID]; ok {
+			assert.Equal(suite.T(), previous, data, "Повторная доставка оплаты %s с другими данными", paymentID)
+			continue
+		}
+		result[paymentID] = data
+	}
+	return result
+}
+
+// TestEarningsReconciliationTestSuite запускает тестовый suite
+func TestEarningsReconciliationTestSuite(t *testing.T) {
+	suite.Run(t, new(EarningsReconciliationTestSuite))
+}
@@ -0,0 +1,421 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"math/rand"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры хаос-теста: очередь событий, ожидание подтверждения JetStream до повторной доставки,
+// пауза после применения эффекта (окно между фиксацией эффекта и подтверждением) и перезапуски подписчика
+const (
+	chaosDrivers         = 10
+	chaosOrdersPerDriver = 5
+	chaosAckWait         = time.Second
+	chaosHandleDelay     = 20 * time.Millisecond
+	chaosRestarts        = 5
+	chaosRestartEvery    = 15 // доставок между перезапусками
+	chaosDrainBudget     = 60 * time.Second
+)
+
+// EventRedeliveryChaosTestSuite тестирует применение эффектов событий order.completed и payment.processed
+// ровно один раз: сервис перезапускается посреди обработки очереди событий JetStream, неподтвержденные
+// события доставляются повторно, но поездки и оплаты учитываются по одному разу на логическое событие.
+// Без тестового NATS с JetStream тесты пропускаются.
+type EventRedeliveryChaosTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *EventRedeliveryChaosTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *EventRedeliveryChaosTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *EventRedeliveryChaosTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// expectedEffects ожидаемые итоги водителя после обработки всех его событий
+type expectedEffects struct {
+	shiftID  uuid.UUID
+	trips    int
+	distance float64
+	earnings float64
+}
+
+// TestExactlyOnceEffectsUnderRestarts тестирует итоги водителей и смен после обработки очереди событий
+// с перезапусками сервиса
+func (suite *EventRedeliveryChaosTestSuite) TestExactlyOnceEffectsUnderRestarts() {
+	// Arrange
+	stream := "ORDER_EVENTS_CHAOS"
+	durable := "driver-service-chaos"
+
+	nats := helpers.NewNATSHelper(suite.T())
+	nats.EnsureStream(stream, []string{services.SubjectOrderCompleted, services.SubjectPaymentProcessed})
+
+	random := rand.New(rand.NewSource(helpers.TestSeed(suite.T(), time.Now().UnixNano())))
+	expected := make(map[uuid.UUID]*expectedEffects, chaosDrivers)
+	events := 0
+	for i := 0; i < chaosDrivers; i++ {
+		driverID, shiftID := suite.createDriverOnShift()
+		effects := &expectedEffects{shiftID: shiftID}
+		expected[driverID] = effects
+
+		for j := 0; j < chaosOrdersPerDriver; j++ {
+			orderID := uuid.New()
+			distance := float64(random.Intn(3000)+100) / 100
+			amount := float64(random.Intn(150000)+10000) / 100
+
+			nats.PublishJetStream(services.SubjectOrderCompleted, map[string]string{services.MessageIDHeader: "order-completed-" + orderID.String()},
+				helpers.OrderCompletedPayload(orderID, driverID, distance))
+			nats.PublishJetStream(services.SubjectPaymentProcessed, map[string]string{services.MessageIDHeader: "payment-" + orderID.String()},
+				helpers.PaymentProcessedPayload(uuid.New(), orderID, driverID, amount))
+
+			effects.trips++
+			effects.distance += distance
+			effects.earnings += amount
+			events += 2
+		}
+	}
+	require.Equal(suite.T(), events, nats.StreamMessages(stream))
+
+	orders := &deliveryCounter{OrderEventService: suite.env.OrderEvents, delay: chaosHandleDelay}
+
+	// Act - перезапуски каждые chaosRestartEvery доставок, пока очередь обрабатывается
+	consumer := suite.env.StartEventConsumer(suite.T(), nats, helpers.EventStream{Stream: stream, Durable: durable, AckWait: chaosAckWait}, orders)
+	for consumer.Restarts() < chaosRestarts {
+		threshold := (consumer.Restarts() + 1) * chaosRestartEvery
+		if !orders.waitDeliveries(threshold, chaosDrainBudget) {
+			break
+		}
+		consumer.Restart()
+	}
+
+	helpers.AssertPropagatedWithin(suite.T(), "event backlog drained", helpers.PropagationBudget{
+		Within: chaosDrainBudget,
+		Visible: func(ctx context.Context) (bool, error) {
+			state, err := nats.ConsumerState(stream, durable)
+			return state.Drained(), err
+		},
+	})
+
+	// Assert
+	deliveries, distinct := orders.stats()
+	suite.T().Logf("Событий: %d, доставок обработчикам: %d, перезапусков: %d", events, deliveries, consumer.Restarts())
+	require.Equal(suite.T(), chaosRestarts, consumer.Restarts(), "Очередь обработана раньше перезапусков")
+	assert.Equal(suite.T(), events, distinct, "Каждое событие доставлено обработчику")
+	assert.Greater(suite.T(), deliveries, distinct, "Перезапуски должны приводить к повторной доставке")
+
+	suite.T().Run("ProcessedEventsRecordedOnce", func(t *testing.T) {
+		var count int
+		require.NoError(t, suite.env.DB.GetContext(suite.env.Ctx, &count, "SELECT COUNT(*) FROM processed_events"))
+		assert.Equal(t, events, count)
+	})
+
+	suite.T().Run("EffectsAppliedOnce", func(t *testing.T) {
+		for driverID, effects := range expected {
+			driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+			require.NoError(t, err)
+			assert.Equal(t, effects.trips, driver.TotalTrips, "Поездки водителя %s", driverID)
+			assert.Equal(t, entities.StatusOnShift, driver.Status)
+
+			shift := suite.env.DB.GetShift(t, effects.shiftID)
+			assert.Equal(t, effects.trips, shift.TotalTrips, "Поездки смены водителя %s", driverID)
+			assert.InDelta(t, effects.distance, shift.TotalDistance, 0.001, "Пробег смены водителя %s", driverID)
+			assert.InDelta(t, effects.earnings, shift.TotalEarnings, 0.001, "Заработок смены водителя %s", driverID)
+		}
+	})
+
+	suite.T().Run("StatusChangedOnce", func(t *testing.T) {
+		for driverID := range expected {
+			assert.Len(t, suite.env.Events.EventsForDriver(driverID, "driver.status.changed"), 1, "Водитель %s", driverID)
+		}
+	})
+}
+
+// TestUnprocessableEventsNotRedelivered тестирует, что события, которые не применить (поездка и оплата
+// неизвестного водителя), подтверждаются без эффекта и не доставляются повторно
+func (suite *EventRedeliveryChaosTestSuite) TestUnprocessableEventsNotRedelivered() {
+	// Arrange
+	stream := "ORDER_EVENTS_UNPROCESSABLE"
+	durable := "driver-service-unprocessable"
+
+	nats := helpers.NewNATSHelper(suite.T())
+	nats.EnsureStream(stream, []string{services.SubjectOrderCompleted, services.SubjectPaymentProcessed})
+
+	nats.PublishJetStream(services.SubjectOrderCompleted, nil, helpers.OrderCompletedPayload(uuid.New(), uuid.New(), 5))
+	nats.PublishJetStream(services.SubjectPaymentProcessed, nil, helpers.PaymentProcessedPayload(uuid.New(), uuid.New(), uuid.New(), 500))
+	nats.PublishJetStream(services.SubjectPaymentProcessed, nil, []byte(`{"driver_id":"not-a-uuid"}`))
+
+	orders := &deliveryCounter{OrderEventService: suite.env.OrderEvents}
+
+	// Act
+	suite.env.StartEventConsumer(suite.T(), nats, helpers.EventStream{Stream: stream, Durable: durable, AckWait: chaosAckWait}, orders)
+
+	// Assert
+	helpers.AssertPropagatedWithin(suite.T(), "unprocessable events terminated", helpers.PropagationBudget{
+		Within: chaosAckWait,
+		Visible: func(ctx context.Context) (bool, error) {
+			state, err := nats.ConsumerState(stream, durable)
+			return state.Drained(), err
+		},
+	})
+
+	// Повторная доставка пришла бы после chaosAckWait
+	time.Sleep(2 * chaosAckWait)
+	deliveries, distinct := orders.stats()
+	assert.Equal(suite.T(), 2, distinct, "Некорректные данные не доходят до сервиса")
+	assert.Equal(suite.T(), distinct, deliveries, "События не доставляются повторно")
+
+	var count int
+	require.NoError(suite.T(), suite.env.DB.GetContext(suite.env.Ctx, &count, "SELECT COUNT(*) FROM processed_events"))
+	assert.Zero(suite.T(), count, "Эффекты неприменимых событий откатываются вместе с записью о них")
+}
+
+// TestFailedEventRedeliveredAfterNak тестирует, что событие с временной ошибкой обработки сервис отклоняет (nak)
+// и JetStream доставляет его повторно сразу, не дожидаясь ack_wait, а эффект применяется один раз
+func (suite *EventRedeliveryChaosTestSuite) TestFailedEventRedeliveredAfterNak() {
+	// Arrange
+	stream := "ORDER_EVENTS_NAK"
+	durable := "driver-service-nak"
+	ackWait := 30 * time.Second
+
+	nats := helpers.NewNATSHelper(suite.T())
+	nats.EnsureStream(stream, []string{services.SubjectOrderCompleted})
+
+	driverID, shiftID := suite.createDriverOnShift()
+	distances := []float64{3.5, 7.25, 12}
+	for _, distance := range distances {
+		nats.PublishJetStream(services.SubjectOrderCompleted, nil, helpers.OrderCompletedPayload(uuid.New(), driverID, distance))
+	}
+	orders := &firstDeliveryFault{OrderEventService: suite.env.OrderEvents, fail: errors.New("database unavailable")}
+
+	// Act
+	consumer := suite.env.StartEventConsumer(suite.T(), nats, helpers.EventStream{Stream: stream, Durable: durable, AckWait: ackWait}, orders)
+
+	// Assert - очередь обработана задолго до ack_wait: повторная доставка вызвана отказом, а не таймаутом
+	helpers.AssertPropagatedWithin(suite.T(), "rejected events redelivered", helpers.PropagationBudget{
+		Within: 5 * time.Second,
+		Visible: func(ctx context.Context) (bool, error) {
+			state, err := nats.ConsumerState(stream, durable)
+			return state.Drained(), err
+		},
+	})
+
+	deliveries := orders.deliveries()
+	assert.Len(suite.T(), deliveries, len(distances))
+	for eventID, count := range deliveries {
+		assert.Equal(suite.T(), 2, count, "Событие %s", eventID)
+	}
+	stats := consumer.Stats()
+	assert.EqualValues(suite.T(), len(distances), stats.Failed, "Первые доставки отклонены")
+	assert.EqualValues(suite.T(), len(distances), stats.Processed)
+
+	shift := suite.env.DB.GetShift(suite.T(), shiftID)
+	assert.Equal(suite.T(), len(distances), shift.TotalTrips)
+	assert.InDelta(suite.T(), 22.75, shift.TotalDistance, 0.001)
+}
+
+// TestSlowEventRedeliveredAfterAckWait тестирует, что событие, обработка которого дольше ack_wait, JetStream
+// доставляет повторно по истечении ack_wait, а эффект применяется один раз
+func (suite *EventRedeliveryChaosTestSuite) TestSlowEventRedeliveredAfterAckWait() {
+	// Arrange
+	stream := "ORDER_EVENTS_ACK_WAIT"
+	durable := "driver-service-ack-wait"
+	stall := 3 * chaosAckWait
+
+	nats := helpers.NewNATSHelper(suite.T())
+	nats.EnsureStream(stream, []string{services.SubjectOrderCompleted})
+
+	driverID, shiftID := suite.createDriverOnShift()
+	nats.PublishJetStream(services.SubjectOrderCompleted, nil, helpers.OrderCompletedPayload(uuid.New(), driverID, 4.5))
+	orders := &firstDeliveryFault{OrderEventService: suite.env.OrderEvents, stall: stall}
+
+	// Act
+	consumer := suite.env.StartEventConsumer(suite.T(), nats, helpers.EventStream{Stream: stream, Durable: durable, AckWait: chaosAckWait}, orders)
+
+	// Assert - сервер доставляет событие повторно, пока первая обработка еще идет
+	helpers.AssertPropagatedWithin(suite.T(), "slow event redelivered after ack wait", helpers.PropagationBudget{
+		Within: stall - chaosAckWait/2,
+		Visible: func(ctx context.Context) (bool, error) {
+			state, err := nats.ConsumerState(stream, durable)
+			return state.NumRedelivered == 1, err
+		},
+	})
+	helpers.AssertPropagatedWithin(suite.T(), "slow event acknowledged", helpers.PropagationBudget{
+		Within: stall + chaosDrainBudget,
+		Visible: func(ctx context.Context) (bool, error) {
+			state, err := nats.ConsumerState(stream, durable)
+			return state.Drained(), err
+		},
+	})
+
+	deliveries := orders.deliveries()
+	require.Len(suite.T(), deliveries, 1)
+	for eventID, count := range deliveries {
+		assert.Equal(suite.T(), 2, count, "Событие %s", eventID)
+	}
+	stats := consumer.Stats()
+	assert.EqualValues(suite.T(), 2, stats.Processed, "Повторная доставка обработана без повторного эффекта")
+	assert.Zero(suite.T(), stats.Failed)
+
+	shift := suite.env.DB.GetShift(suite.T(), shiftID)
+	assert.Equal(suite.T(), 1, shift.TotalTrips)
+	assert.InDelta(suite.T(), 4.5, shift.TotalDistance, 0.001)
+}
+
+// createDriverOnShift создает занятого водителя с активной сменой
+func (suite *EventRedeliveryChaosTestSuite) createDriverOnShift() (driverID, shiftID uuid.UUID) {
+	driverID = suite.createDriver(entities.StatusBusy)
+	shift := fixtures.CreateTestShift(driverID)
+	suite.env.DB.InsertShift(suite.T(), shift)
+	return driverID, shift.ID
+}
+
+// createDriver создает водителя с уникальными контактами в статусе status
+func (suite *EventRedeliveryChaosTestSuite) createDriver(status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900111%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("chaos.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("CH%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	return created.ID
+}
+
+// deliveryCounter считает доставки событий сервису и после применения эффекта держит обработку delay,
+// чтобы перезапуски попадали между фиксацией эффекта и подтверждением доставки
+type deliveryCounter struct {
+	services.OrderEventService
+	delay time.Duration
+
+	mu         sync.Mutex
+	deliveries map[string]int
+	total      int
+}
+
+// HandleOrderCompleted передает событие сервису и учитывает доставку
+func (c *deliveryCounter) HandleOrderCompleted(ctx context.Context, eventID string, driverID, orderID uuid.UUID, distanceKm float64) error {
+	err := c.OrderEventService.HandleOrderCompleted(ctx, eventID, driverID, orderID, distanceKm)
+	return c.delivered(ctx, eventID, err)
+}
+
+// HandlePaymentProcessed передает событие сервису и учитывает доставку
+func (c *deliveryCounter) HandlePaymentProcessed(ctx context.Context, eventID string, payment *entities.TripPayment) error {
+	err := c.OrderEventService.HandlePaymentProcessed(ctx, eventID, payment)
+	return c.delivered(ctx, eventID, err)
+}
+
+// delivered учитывает доставку и ждет delay; остановка подписчика прерывает ожидание до подтверждения
+func (c *deliveryCounter) delivered(ctx context.Context, eventID string, err error) error {
+	c.mu.Lock()
+	if c.deliveries == nil {
+		c.deliveries = make(map[string]int)
+	}
+	c.deliveries[eventID]++
+	c.total++
+	c.mu.Unlock()
+
+	select {
+	case <-ctx.Done():
+		return ctx.Err()
+	case <-time.After(c.delay):
+		return err
+	}
+}
+
+// waitDeliveries ждет, пока доставок станет не меньше n; false, если за timeout их не набралось
+func (c *deliveryCounter) waitDeliveries(n int, timeout time.Duration) bool {
+	deadline := time.Now().Add(timeout)
+	for time.Now().Before(deadline) {
+		if total, _ := c.stats(); total >= n {
+			return true
+		}
+		time.Sleep(5 * time.Millisecond)
+	}
+	return false
+}
+
+// stats возвращает количество доставок и различных событий
+func (c *deliveryCounter) stats() (total, distinct int) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	return c.total, len(c.deliveries)
+}
+
+// firstDeliveryFault сбой первой доставки каждого события order.completed: ошибка fail до применения эффекта
+// (сервис отклоняет доставку) или обработка дольше на stall (подтверждение позже ack_wait)
+type firstDeliveryFault struct {
+	services.OrderEventService
+	fail  error
+	stall time.Duration
+
+	mu    sync.Mutex
+	count map[string]int
+}
+
+// HandleOrderCompleted при первой доставке события выполняет сбой, затем передает событие сервису
+func (f *firstDeliveryFault) HandleOrderCompleted(ctx context.Context, eventID string, driverID, orderID uuid.UUID, distanceKm float64) error {
+	f.mu.Lock()
+	if f.count == nil {
+		f.count = make(map[string]int)
+	}
+	f.count[eventID]++
+	first := f.count[eventID] == 1
+	f.mu.Unlock()
+
+	if first && f.fail != nil {
+		return f.fail
+	}
+	if first && f.stall > 0 {
+		select {
+		case <-ctx.Done():
+			return ctx.Err()
+		case <-time.After(f.stall):
+		}
+	}
+	return f.OrderEventService.HandleOrderCompleted(ctx, eventID, driverID, orderID, distanceKm)
+}
+
+// deliveries возвращает количество доставок каждого события
+func (f *firstDeliveryFault) deliveries() map[string]int {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	result := make(map[string]int, len(f.count))
+	for eventID, count := range f.count {
+		result[eventID] = count
+	}
+	return result
+}
+
+// TestEventRedeliveryChaosTestSuite запускает тестовый suite
+func TestEventRedeliveryChaosTestSuite(t *testing.T) {
+	suite.Run(t, new(EventRedeliveryChaosTestSuite))
+}
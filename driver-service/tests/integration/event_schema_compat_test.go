@@ -0,0 +1,218 @@
+//go:build integration
+
+package integration
+
+import (
+	"encoding/json"
+	"fmt"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// publishedEventContracts поля событий, на которые опираются мобильное приложение и бэкофис.
+// Новые поля могут добавляться, но существующие не должны удаляться, переименовываться или менять тип.
+var publishedEventContracts = map[string]helpers.APIContract{
+	"driver.registered": {
+		"phone":          helpers.JSONString,
+		"email":          helpers.JSONString,
+		"name":           helpers.JSONString,
+		"license_number": helpers.JSONString,
+	},
+	"driver.status.changed": {
+		"old_status": helpers.JSONString,
+		"new_status": helpers.JSONString,
+		"changed_by": helpers.JSONString,
+	},
+	"driver.rating.updated": {
+		"new_rating":      helpers.JSONNumber,
+		"previous_rating": helpers.JSONNumber,
+	},
+	"driver.location.updated": {
+		"location": helpers.JSONObject,
+		"speed":    helpers.JSONNumber,
+		"bearing":  helpers.JSONNumber,
+		"accuracy": helpers.JSONNumber,
+	},
+	"driver.availability.changed": {
+		"available": helpers.JSONBool,
+		"reason":    helpers.JSONString,
+	},
+	"driver.blocked": {
+		"reason": helpers.JSONString,
+	},
+	"driver.restored": {
+		"status": helpers.JSONString,
+	},
+}
+
+// EventSchemaCompatTestSuite тестовый suite для обратной совместимости схем событий
+type EventSchemaCompatTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *EventSchemaCompatTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *EventSchemaCompatTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *EventSchemaCompatTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestPublishedEventSchemasStable тестирует, что публикуемые события сохраняют поля предыдущего поколения
+func (suite *EventSchemaCompatTestSuite) TestPublishedEventSchemasStable() {
+	// Arrange
+	// Проходим жизненный цикл водителя так, чтобы были опубликованы все события из контракта
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverService.ChangeDriverStatus(suite.env.Ctx, driver.ID, entities.StatusPendingVerification))
+	require.NoError(suite.T(), suite.env.DriverService.UpdateDriverRating(suite.env.Ctx, driver.ID, 4.5))
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, fixtures.CreateTestLocation(driver.ID)))
+
+	// Активный водитель теряет связь и восстанавливает ее свежим местоположением
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driver.ID, entities.StatusAvailable))
+	suite.env.DB.AgeDriverLocations(suite.T(), driver.ID, entities.LocationStaleAfter+time.Minute)
+	_, err = suite.env.Jobs.RunJob(suite.env.Ctx, services.JobDetectConnectionLoss)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, fixtures.CreateTestLocation(driver.ID)))
+
+	require.NoError(suite.T(), suite.env.DriverService.DeleteDriver(suite.env.Ctx, driver.ID))
+	_, err = suite.env.DriverService.RestoreDriver(suite.env.Ctx, driver.ID)
+	require.NoError(suite.T(), err)
+
+	for eventType, contract := range publishedEventContracts {
+		suite.T().Run(eventType, func(t *testing.T) {
+			// Act
+			events := suite.env.Events.EventsForDriver(driver.ID, eventType)
+
+			// Assert
+			require.NotEmpty(t, events, "Событие %s не опубликовано", eventType)
+			for _, event := range events {
+				// Потребители получают событие в JSON, поэтому проверяется сериализованное представление
+				body, err := json.Marshal(event.Data)
+				require.NoError(t, err)
+				helpers.AssertJSONContract(t, body, contract)
+			}
+		})
+	}
+}
+
+// TestContractsMatchEventCatalog тестирует, что контракты заданы ровно для реализованных публикуемых событий каталога
+func (suite *EventSchemaCompatTestSuite) TestContractsMatchEventCatalog() {
+	// Arrange
+	catalog, err := helpers.LoadEventCatalog()
+	require.NoError(suite.T(), err)
+
+	// Act
+	implemented := make(map[string]bool)
+	for _, event := range catalog {
+		if event.Direction == helpers.EventPublished && event.Implemented {
+			implemented[event.Subject] = true
+		}
+	}
+
+	// Assert
+	for subject := range implemented {
+		assert.Contains(suite.T(), publishedEventContracts, subject, "Для события %s нет контракта", subject)
+	}
+	for subject := range publishedEventContracts {
+		assert.True(suite.T(), implemented[subject], "Событие %s не отмечено в api/events/catalog.yaml как реализованное", subject)
+	}
+}
+
+// TestLegacyConsumedPayloads тестирует обработку событий предыдущего поколения во входящих темах реальным
+// подписчиком сервиса: без полей, добавленных позже (distance_km, payment_id), и с полями, которые издатели
+// больше не отправляют. Эффекты должны быть такими же, как у актуальных событий.
+func (suite *EventSchemaCompatTestSuite) TestLegacyConsumedPayloads() {
+	// Arrange
+	nats := suite.env.StartEventSubscriber(suite.T())
+	suite.env.StartPushGateway(suite.T())
+
+	driverID, shiftID := suite.createDriverOnShift()
+	firstOrder, secondOrder := uuid.New(), uuid.New()
+	legacy := []struct {
+		subject string
+		payload string
+	}{
+		{services.SubjectOrderAssigned, `{"order_id":"%[1]s","driver_id":"%[3]s","assigned_at":"2023-05-01T10:00:00Z"}`},
+		{services.SubjectOrderCompleted, `{"order_id":"%[1]s","driver_id":"%[3]s","status":"completed"}`},
+		{services.SubjectPaymentProcessed, `{"order_id":"%[1]s","driver_id":"%[3]s","amount":450.5,"currency":"RUB"}`},
+		{services.SubjectOrderAssigned, `{"order_id":"%[2]s","driver_id":"%[3]s"}`},
+		{services.SubjectOrderCompleted, `{"order_id":"%[2]s","driver_id":"%[3]s"}`},
+		{services.SubjectPaymentProcessed, `{"order_id":"%[2]s","driver_id":"%[3]s","amount":300}`},
+	}
+
+	// Act - события одного водителя обрабатываются по порядку публикации
+	for _, event := range legacy {
+		nats.Publish(event.subject, nil, []byte(fmt.Sprintf(event.payload, firstOrder, secondOrder, driverID)))
+	}
+
+	// Assert
+	helpers.Eventually(suite.T(), func(c *helpers.EventuallyT) {
+		shift := suite.env.DB.GetShift(suite.T(), shiftID)
+		assert.Equal(c, 2, shift.TotalTrips, "Поездки без distance_km учитываются")
+		assert.InDelta(c, 750.5, shift.TotalEarnings, 0.001, "Оплаты без payment_id учитываются по заказу")
+	}, orderEventBudget, 50*time.Millisecond)
+
+	shift := suite.env.DB.GetShift(suite.T(), shiftID)
+	assert.Zero(suite.T(), shift.TotalDistance)
+
+	driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), entities.StatusOnShift, driver.Status)
+	assert.Equal(suite.T(), 2, driver.TotalTrips)
+
+	statuses := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+	require.Len(suite.T(), statuses, 4, "Назначение и завершение каждого заказа")
+	for i, expected := range []entities.Status{entities.StatusBusy, entities.StatusOnShift, entities.StatusBusy, entities.StatusOnShift} {
+		assert.Equal(suite.T(), string(expected), statuses[i].DataMap()["new_status"])
+	}
+
+	var processed []string
+	require.NoError(suite.T(), suite.env.DB.SelectContext(suite.env.Ctx, &processed,
+		"SELECT event_id FROM processed_events WHERE subject = $1 ORDER BY event_id", services.SubjectPaymentProcessed))
+	assert.ElementsMatch(suite.T(), []string{
+		services.SubjectPaymentProcessed + ":" + firstOrder.String(),
+		services.SubjectPaymentProcessed + ":" + secondOrder.String(),
+	}, processed)
+}
+
+// createDriverOnShift создает доступного водителя с активной сменой
+func (suite *EventSchemaCompatTestSuite) createDriverOnShift() (driverID, shiftID uuid.UUID) {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900222%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("compat.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("CP%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, entities.StatusOnShift))
+
+	shift := fixtures.CreateTestShift(created.ID)
+	suite.env.DB.InsertShift(suite.T(), shift)
+	return created.ID, shift.ID
+}
+
+// TestEventSchemaCompatTestSuite запускает тестовый suite
+func TestEventSchemaCompatTestSuite(t *testing.T) {
+	suite.Run(t, new(EventSchemaCompatTestSuite))
+}
@@ -0,0 +1,219 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/infrastructure/nats"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// orderEventBudget бюджет обработки сервисом события из NATS
+const orderEventBudget = 2 * time.Second
+
+// EventTracePropagationTestSuite тестирует заголовки входящих событий NATS: трасса из traceparent события
+// order.completed продолжается в событии driver.status.changed, которое сервис публикует при его обработке.
+// Без тестового NATS тесты пропускаются.
+type EventTracePropagationTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	nats    *helpers.NATSHelper
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *EventTracePropagationTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *EventTracePropagationTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *EventTracePropagationTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.nats = suite.env.StartEventSubscriber(suite.T())
+}
+
+// TestTraceContextPropagatedToDownstreamEvents тестирует, что событие, опубликованное при обработке входящего,
+// продолжает его трассу: тот же trace id и флаги, новый span id обработки сервисом
+func (suite *EventTracePropagationTestSuite) TestTraceContextPropagatedToDownstreamEvents() {
+	for _, sampled := range []bool{true, false} {
+		suite.T().Run(fmt.Sprintf("sampled=%t", sampled), func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t, entities.StatusBusy)
+			incoming := helpers.NewTraceparent(sampled)
+
+			// Act
+			suite.nats.PublishOrderEvent(nats.SubjectOrderCompleted, uuid.New(), driverID, helpers.TraceHeader(incoming))
+
+			// Assert
+			helpers.AssertPropagatedWithin(t, "order.completed", suite.onShiftBudget(driverID))
+
+			events := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+			require.Len(t, events, 1)
+			assert.Equal(t, string(entities.StatusOnShift), events[0].DataMap()["new_status"])
+
+			incomingParts := strings.Split(incoming, "-")
+			downstream := strings.Split(events[0].Traceparent, "-")
+			require.Len(t, downstream, 4, "traceparent события: %q", events[0].Traceparent)
+			assert.Equal(t, "00", downstream[0], "Версия")
+			assert.Equal(t, incomingParts[1], downstream[1], "Trace id входящего события")
+			assert.NotEqual(t, incomingParts[2], downstream[2], "Span id обработки сервисом")
+			assert.Len(t, downstream[2], 16)
+			assert.Equal(t, incomingParts[3], downstream[3], "Флаги входящего события")
+		})
+	}
+
+	suite.T().Run("SeparateTracesPerEvent", func(t *testing.T) {
+		// Arrange
+		first := suite.createDriver(t, entities.StatusBusy)
+		second := suite.createDriver(t, entities.StatusBusy)
+		firstTrace, secondTrace := helpers.NewTraceparent(true), helpers.NewTraceparent(true)
+
+		// Act
+		suite.nats.PublishOrderEvent(nats.SubjectOrderCompleted, uuid.New(), first, helpers.TraceHeader(firstTrace))
+		suite.nats.PublishOrderEvent(nats.SubjectOrderCompleted, uuid.New(), second, helpers.TraceHeader(secondTrace))
+
+		// Assert - события разных водителей обрабатываются параллельно, поэтому ждем оба
+		helpers.AssertPropagatedWithin(t, "order.completed", suite.onShiftBudget(first))
+		helpers.AssertPropagatedWithin(t, "order.completed", suite.onShiftBudget(second))
+
+		for driverID, trace := range map[uuid.UUID]string{first: firstTrace, second: secondTrace} {
+			events := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+			require.Len(t, events, 1)
+			assert.Equal(t, traceID(trace), traceID(events[0].Traceparent))
+		}
+	})
+}
+
+// TestEventsWithoutTraceContext тестирует, что событие без заголовков или с некорректным traceparent
+// обрабатывается, а опубликованное при обработке событие не привязано к трассе
+func (suite *EventTracePropagationTestSuite) TestEventsWithoutTraceContext() {
+	testCases := []struct {
+		name   string
+		header map[string]string
+	}{
+		{name: "NoHeaders", header: nil},
+		{name: "MessageIDOnly", header: map[string]string{services.MessageIDHeader: uuid.NewString()}},
+		{name: "MalformedTraceparent", header: helpers.TraceHeader("00-not-a-trace-01")},
+		{name: "ZeroTraceID", header: helpers.TraceHeader("00-00000000000000000000000000000000-b7ad6b7169203331-01")},
+		{name: "UnsupportedVersion", header: helpers.TraceHeader("ff-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01")},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t, entities.StatusBusy)
+
+			// Act
+			suite.nats.PublishOrderEvent(nats.SubjectOrderCompleted, uuid.New(), driverID, tc.header)
+
+			// Assert
+			helpers.AssertPropagatedWithin(t, "order.completed", suite.onShiftBudget(driverID))
+
+			events := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+			require.Len(t, events, 1)
+			assert.Empty(t, events[0].Traceparent)
+		})
+	}
+}
+
+// TestHeadersDeliveredToSubscribers тестирует, что заголовки публикуемых тестами событий доходят до подписчиков
+// без изменений, а сообщение без заголовков приходит без них
+func (suite *EventTracePropagationTestSuite) TestHeadersDeliveredToSubscribers() {
+	// Arrange
+	subject := "test.headers." + strings.ReplaceAll(uuid.NewString(), "-", "")
+	subscription := suite.nats.Subscribe(subject)
+	header := helpers.TraceHeader(helpers.NewTraceparent(true))
+
+	// Act
+	suite.nats.Publish(subject, header, []byte(`{"order_id":"1"}`))
+	suite.nats.Publish(subject, nil, []byte(`{"order_id":"2"}`))
+
+	// Assert
+	withHeaders := subscription.Next()
+	assert.Equal(suite.T(), header, withHeaders.Header)
+	assert.JSONEq(suite.T(), `{"order_id":"1"}`, string(withHeaders.Payload))
+
+	withoutHeaders := subscription.Next()
+	assert.Nil(suite.T(), withoutHeaders.Header)
+	assert.JSONEq(suite.T(), `{"order_id":"2"}`, string(withoutHeaders.Payload))
+}
+
+// TestMessageIDDeduplicatedByJetStream тестирует, что повторная публикация события с тем же Nats-Msg-Id
+// не записывается в поток JetStream второй раз
+func (suite *EventTracePropagationTestSuite) TestMessageIDDeduplicatedByJetStream() {
+	// Arrange
+	const stream = "ORDER_EVENTS_TRACE_TEST"
+	subject := "order.dedup." + strings.ReplaceAll(uuid.NewString(), "-", "")
+	suite.nats.EnsureStream(stream, []string{subject})
+	header := helpers.TraceHeader(helpers.NewTraceparent(true))
+
+	// Act
+	first := suite.nats.PublishJetStream(subject, header, []byte(`{}`))
+	retry := suite.nats.PublishJetStream(subject, header, []byte(`{}`))
+
+	// Assert
+	assert.False(suite.T(), first.Duplicate)
+	assert.True(suite.T(), retry.Duplicate, "Повтор с тем же %s", services.MessageIDHeader)
+	assert.Equal(suite.T(), first.Sequence, retry.Sequence)
+	assert.Equal(suite.T(), 1, suite.nats.StreamMessages(stream))
+}
+
+// onShiftBudget бюджет возврата водителя на смену после order.completed
+func (suite *EventTracePropagationTestSuite) onShiftBudget(driverID uuid.UUID) helpers.PropagationBudget {
+	return helpers.PropagationBudget{
+		Within: orderEventBudget,
+		Visible: func(ctx context.Context) (bool, error) {
+			driver, err := suite.env.DriverService.GetDriverByID(ctx, driverID)
+			if err != nil {
+				return false, err
+			}
+			return driver.Status == entities.StatusOnShift, nil
+		},
+	}
+}
+
+// createDriver создает водителя с уникальными контактами в статусе status
+func (suite *EventTracePropagationTestSuite) createDriver(t *testing.T, status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900222%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("trace.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("TR%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+	require.NoError(t, suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	return created.ID
+}
+
+// traceID возвращает trace id из заголовка traceparent
+func traceID(traceparent string) string {
+	parts := strings.Split(traceparent, "-")
+	if len(parts) < 2 {
+		return ""
+	}
+	return parts[1]
+}
+
+// TestEventTracePropagationTestSuite запускает тестовый suite
+func TestEventTracePropagationTestSuite(t *testing.T) {
+	suite.Run(t, new(EventTracePropagationTestSuite))
+}
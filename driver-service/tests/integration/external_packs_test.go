@@ -0,0 +1,27 @@
+//go:build integration
+
+package integration
+
+import (
+	"testing"
+
+	"driver-service/tests/helpers"
+	"driver-service/tests/packs"
+
+	// Внешние тестовые пакеты подключаются blank-импортом и регистрируются в init
+	_ "driver-service/tests/packs/driverapp"
+	_ "driver-service/tests/packs/example"
+)
+
+// TestExternalPacks выполняет все зарегистрированные внешние наборы тестов в общем окружении
+// в порядке их зависимостей
+func TestExternalPacks(t *testing.T) {
+	if len(packs.Registered()) == 0 {
+		t.Skip("Нет зарегистрированных внешних наборов тестов")
+	}
+
+	env := helpers.NewTestEnvironment(t)
+	defer env.Close(t)
+
+	packs.RunAll(t, env)
+}
@@ -0,0 +1,344 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Точки поиска водителей поблизости: центр Москвы и Санкт-Петербург
+const (
+	featureCenterLat = 55.7558
+	featureCenterLon = 37.6173
+	featureFarLat    = 59.9343
+	featureFarLon    = 30.3351
+)
+
+// FeatureFlagsTestSuite тестирует переключение флагов функциональности и поведение сервиса
+// при разных комбинациях флагов (режим матрицы включается FEATURE_MATRIX, см. RunFeatureMatrix)
+type FeatureFlagsTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *FeatureFlagsTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *FeatureFlagsTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *FeatureFlagsTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestFeaturesAdminAPI тестирует чтение и переключение флагов через административный API
+func (suite *FeatureFlagsTestSuite) TestFeaturesAdminAPI() {
+	// Arrange
+	initial := suite.env.Features.Snapshot()
+
+	// Act
+	flags, response := suite.env.API.ListFeatures()
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	assert.Equal(suite.T(), initial, flags)
+
+	suite.T().Run("Toggle", func(t *testing.T) {
+		enabled := !suite.env.Features.Enabled(features.StrictValidation)
+
+		response := suite.env.API.SetFeature(features.StrictValidation, enabled)
+
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+		assert.Equal(t, enabled, suite.env.Features.Enabled(features.StrictValidation))
+		assert.Equal(t, initial[features.GeoCache], suite.env.Features.Enabled(features.GeoCache),
+			"Остальные флаги не меняются")
+	})
+
+	suite.T().Run("UnknownFeature", func(t *testing.T) {
+		response := suite.env.API.SetFeature("surge_pricing", true)
+
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+		suite.env.API.AssertErrorResponse(response, "FEATURE_NOT_FOUND")
+	})
+
+	suite.T().Run("MissingValue", func(t *testing.T) {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodPut,
+			URL:    "/api/v1/admin/features/" + string(features.GeoCache),
+			Body:   map[string]interface{}{},
+		})
+
+		suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+	})
+}
+
+// TestResetRestoresFeatures тестирует возврат флагов к значениям из TEST_FEATURE_FLAGS между тестами
+func (suite *FeatureFlagsTestSuite) TestResetRestoresFeatures() {
+	// Arrange
+	initial := suite.env.Features.Snapshot()
+	for _, flag := range features.All() {
+		suite.env.SetFeature(suite.T(), flag, !initial[flag])
+	}
+
+	// Act
+	suite.env.Reset(suite.T())
+
+	// Assert
+	assert.Equal(suite.T(), initial, suite.env.Features.Snapshot())
+}
+
+// TestDriverRegistrationFeatureMatrix тестирует регистрацию водителей со строгой валидацией и без нее
+func (suite *FeatureFlagsTestSuite) TestDriverRegistrationFeatureMatrix() {
+	helpers.RunFeatureMatrix(suite.T(), suite.env, []features.Flag{features.StrictValidation}, func(t *testing.T) {
+		env := suite.env.ForTest(t)
+		strict := env.Features.Enabled(features.StrictValidation)
+
+		testCases := []struct {
+			name         string
+			modify       func(request map[string]interface{})
+			strictReject bool // отклоняется только в строгом режиме
+		}{
+			{
+				name:   "valid",
+				modify: func(request map[string]interface{}) {},
+			},
+			{
+				name: "local phone format",
+				modify: func(request map[string]interface{}) {
+					request["phone"] = fmt.Sprintf("8900777%04d", suite.drivers)
+				},
+				strictReject: true,
+			},
+			{
+				name: "expired license",
+				modify: func(request map[string]interface{}) {
+					request["license_expiry"] = time.Now().AddDate(0, 0, -1).UTC().Format(time.RFC3339)
+				},
+				strictReject: true,
+			},
+		}
+
+		for _, tc := range testCases {
+			t.Run(tc.name, func(t *testing.T) {
+				// Arrange
+				request := suite.driverRequest()
+				tc.modify(request)
+
+				// Act
+				response := env.API.MakeRequest(helpers.APIRequest{
+					Method: http.MethodPost,
+					URL:    env.API.APIPath("/drivers"),
+					Body:   request,
+				})
+
+				// Assert
+				if strict && tc.strictReject {
+					env.API.AssertStatusCode(response, http.StatusBadRequest)
+					env.API.AssertErrorResponse(response, "INVALID_DATA")
+					return
+				}
+				env.API.AssertStatusCode(response, http.StatusCreated)
+			})
+		}
+	})
+}
+
+// TestNearbyDriversFeatureMatrix тестирует, что поиск поблизости видит перемещение водителя
+// через API при любом состоянии кэша
+func (suite *FeatureFlagsTestSuite) TestNearbyDriversFeatureMatrix() {
+	helpers.RunFeatureMatrix(suite.T(), suite.env, []features.Flag{features.GeoCache}, func(t *testing.T) {
+		// Arrange
+		env := suite.env.ForTest(t)
+		driverID := suite.createDriver(t, env)
+		suite.updateLocation(t, env, driverID, featureCenterLat, featureCenterLon)
+		require.Contains(t, suite.nearbyDrivers(t, env), driverID)
+
+		// Act
+		suite.updateLocation(t, env, driverID, featureFarLat, featureFarLon)
+
+		// Assert
+		assert.NotContains(t, suite.nearbyDrivers(t, env), driverID,
+			"Обновление местоположения через сервис сбрасывает кэш")
+	})
+}
+
+// TestGeoCacheServesCachedResult тестирует, что при включенном geo_cache поиск поблизости
+// отдает закэшированный результат, пока местоположения меняются в обход сервиса
+func (suite *FeatureFlagsTestSuite) TestGeoCacheServesCachedResult() {
+	// Arrange
+	suite.env.SetFeature(suite.T(), features.GeoCache, true)
+	cachedDriver := suite.createDriver(suite.T(), suite.env)
+	suite.updateLocation(suite.T(), suite.env, cachedDriver, featureCenterLat, featureCenterLon)
+	require.Equal(suite.T(), []uuid.UUID{cachedDriver}, suite.nearbyDrivers(suite.T(), suite.env))
+
+	lateDriver := suite.createDriver(suite.T(), suite.env)
+	location := fixtures.CreateTestLocation(lateDriver)
+	require.NoError(suite.T(), suite.env.LocationRepo.Create(suite.env.Ctx, location))
+
+	// Act
+	cached := suite.nearbyDrivers(suite.T(), suite.env)
+	suite.env.SetFeature(suite.T(), features.GeoCache, false)
+	uncached := suite.nearbyDrivers(suite.T(), suite.env)
+
+	// Assert
+	assert.Equal(suite.T(), []uuid.UUID{cachedDriver}, cached)
+	assert.ElementsMatch(suite.T(), []uuid.UUID{cachedDriver, lateDriver}, uncached)
+}
+
+// TestGeoCacheDropsDriverLeavingLine тестирует, что при включенном geo_cache водитель, ушедший с линии
+// (перерыв, offline, блокировка, удаление), сразу пропадает из закэшированного результата поиска поблизости
+func (suite *FeatureFlagsTestSuite) TestGeoCacheDropsDriverLeavingLine() {
+	suite.env.SetFeature(suite.T(), features.GeoCache, true)
+
+	changeStatus := func(t *testing.T, driverID uuid.UUID, status entities.Status) {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodPatch,
+			URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/status", driverID)),
+			Body:   map[string]string{"status": string(status)},
+		})
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+	}
+
+	testCases := []struct {
+		name    string
+		prepare func(t *testing.T, driverID uuid.UUID)
+		leave   func(t *testing.T, driverID uuid.UUID)
+	}{
+		{
+			name: "Pause",
+			prepare: func(t *testing.T, driverID uuid.UUID) {
+				_, response := suite.env.API.StartShift(driverID)
+				require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+			},
+			leave: func(t *testing.T, driverID uuid.UUID) {
+				_, response := suite.env.API.PauseShift(driverID)
+				require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+			},
+		},
+		{
+			name:  "Offline",
+			leave: func(t *testing.T, driverID uuid.UUID) { changeStatus(t, driverID, entities.StatusOffline) },
+		},
+		{
+			name:  "Blocked",
+			leave: func(t *testing.T, driverID uuid.UUID) { changeStatus(t, driverID, entities.StatusBlocked) },
+		},
+		{
+			name: "Deleted",
+			leave: func(t *testing.T, driverID uuid.UUID) {
+				response := suite.env.API.MakeRequest(helpers.APIRequest{
+					Method: http.MethodDelete,
+					URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+				})
+				require.Equal(t, http.StatusNoContent, response.StatusCode, string(response.Body))
+			},
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t, suite.env)
+			if tc.prepare != nil {
+				tc.prepare(t, driverID)
+			}
+			suite.updateLocation(t, suite.env, driverID, featureCenterLat, featureCenterLon)
+			require.Contains(t, suite.nearbyDrivers(t, suite.env), driverID)
+
+			// Act
+			tc.leave(t, driverID)
+
+			// Assert
+			assert.NotContains(t, suite.nearbyDrivers(t, suite.env), driverID,
+				"Смена статуса водителя сбрасывает закэшированный результат")
+		})
+	}
+}
+
+// driverRequest возвращает запрос на регистрацию водителя с уникальными контактами
+func (suite *FeatureFlagsTestSuite) driverRequest() map[string]interface{} {
+	suite.drivers++
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7900777%04d", suite.drivers)
+	request["email"] = fmt.Sprintf("features.driver%d@example.com", suite.drivers)
+	request["license_number"] = fmt.Sprintf("FF%08d", suite.drivers)
+	request["license_expiry"] = time.Now().AddDate(2, 0, 0).UTC().Format(time.RFC3339)
+	return request
+}
+
+// createDriver регистрирует водителя через API и переводит его в статус available, чтобы он попадал в поиск поблизости
+func (suite *FeatureFlagsTestSuite) createDriver(t *testing.T, env *helpers.TestEnvironment) uuid.UUID {
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    env.API.APIPath("/drivers"),
+		Body:   suite.driverRequest(),
+	})
+	require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	env.API.UnmarshalResponse(response, &driver)
+
+	// Обходим цепочку переходов статусов - для поиска важен только итоговый статус
+	require.NoError(t, env.DriverRepo.UpdateStatus(env.Ctx, driver.ID, entities.StatusAvailable))
+	return driver.ID
+}
+
+// updateLocation отправляет местоположение водителя через API
+func (suite *FeatureFlagsTestSuite) updateLocation(t *testing.T, env *helpers.TestEnvironment, driverID uuid.UUID, lat, lon float64) {
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body: map[string]interface{}{
+			"latitude":  lat,
+			"longitude": lon,
+		},
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+}
+
+// nearbyDrivers возвращает ID водителей поблизости от центра Москвы
+func (suite *FeatureFlagsTestSuite) nearbyDrivers(t *testing.T, env *helpers.TestEnvironment) []uuid.UUID {
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    env.API.APIPath("/locations/nearby"),
+		QueryParams: map[string]string{
+			"latitude":  fmt.Sprintf("%f", featureCenterLat),
+			"longitude": fmt.Sprintf("%f", featureCenterLon),
+			"radius_km": "5",
+		},
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+	var nearby httpHandlers.NearbyDriversResponse
+	env.API.UnmarshalResponse(response, &nearby)
+
+	ids := make([]uuid.UUID, 0, len(nearby.Drivers))
+	for _, driver := range nearby.Drivers {
+		ids = append(ids, driver.DriverID)
+	}
+	return ids
+}
+
+func TestFeatureFlagsTestSuite(t *testing.T) {
+	suite.Run(t, new(FeatureFlagsTestSuite))
+}
@@ -0,0 +1,368 @@
+//go:build integration
+
+package integration
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"math/rand"
+	"net/http"
+	"sort"
+	"strconv"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	"driver-service/internal/infrastructure/nats"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры масштабного теста парка
+const (
+	fleetPageSize         = 500
+	fleetActiveSamples    = 3
+	fleetNearbySamples    = 50
+	fleetNearbyLimit      = 100
+	fleetNearbyRadius     = 3.0
+	fleetFirehoseWorkers  = 16
+	fleetFirehoseIdle     = 5 * time.Second // сколько ждать следующего события потока
+	fleetHeapSampleEvery  = 1000            // водителей между замерами кучи при разборе ответа
+	fleetHeapSampleTicker = 250 * time.Millisecond
+)
+
+// Ограничения размера ответов и событий
+const (
+	fleetDriverBytes        = 1024 // водитель в списке
+	fleetNearbyDriverBytes  = 256  // водитель в результате поиска поблизости
+	fleetLocationEventBytes = 512  // данные driver.location.updated, как в бенчмарке публикации
+	fleetEnvelopeBytes      = 1024 // поля ответа помимо массива водителей
+)
+
+// fleetHeapGrowth допустимый рост кучи при проверке парка: проверки разбирают ответы и события по одному,
+// поэтому порог не зависит от размера парка
+const fleetHeapGrowth = 16 << 20
+
+// fleetActivePerThousand порог p95 ответа /drivers/active на каждую 1000 водителей: список не разбит на страницы
+const fleetActivePerThousand = 100 * time.Millisecond
+
+// fleetLocationEvent тема потока местоположений водителей
+const fleetLocationEvent = "driver.location.updated"
+
+// activeDriversQuery запрос репозитория для /drivers/active
+const activeDriversQuery = `
+		SELECT d.* FROM drivers d
+		LEFT JOIN LATERAL (
+			SELECT recorded_at FROM driver_locations
+			WHERE driver_id = d.id
+			ORDER BY recorded_at DESC
+			LIMIT 1
+		) latest ON true
+		WHERE d.status IN ('available', 'on_shift', 'busy')
+		AND d.deleted_at IS NULL
+		AND (latest.recorded_at IS NULL OR latest.recorded_at >= $1)
+		ORDER BY d.current_rating DESC`
+
+// FleetScaleTestSuite проверяет список, активных водителей, поиск поблизости и поток местоположений в NATS
+// на парке из TEST_FLEET_SIZE доступных водителей (по умолчанию 10 000), записанном минуя API
+// (helpers.SeedLargeDataset). Ответы и события проверяются по одному водителю за раз: память теста
+// не растет с размером парка, и это тоже проверяется.
+type FleetScaleTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	dataset *helpers.SeededDataset
+	seed    int64
+}
+
+// SetupSuite выполняется один раз перед всеми тестами: парк записывается один раз и между тестами не очищается
+func (suite *FleetScaleTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	// Поиск поблизости должен доходить до БД при каждом запросе
+	suite.env.SetFeature(suite.T(), features.GeoCache, false)
+
+	suite.seed = helpers.TestSeed(suite.T(), 1746)
+	suite.dataset = suite.env.DB.SeedLargeDataset(suite.T(), helpers.LargeDataset{
+		Drivers:            helpers.LoadFleetSize(suite.T()),
+		LocationsPerDriver: 1,
+		Interval:           time.Minute,
+		SpreadKm:           30,
+	}, suite.seed)
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *FleetScaleTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом: текущий трек набора данных сдвигается к текущему времени
+func (suite *FleetScaleTestSuite) SetupTest() {
+	suite.env.DB.RefreshLargeDataset(suite.T(), suite.dataset)
+}
+
+// TestListWalkedByCursor тестирует обход всего парка страницами списка по next_cursor: каждый водитель
+// встречается один раз, страницы укладываются в размер и порог задержки
+func (suite *FleetScaleTestSuite) TestListWalkedByCursor() {
+	// Arrange
+	timings := helpers.NewRequestTimings()
+	api := helpers.NewAPITestHelper(suite.env.Router, suite.T()).WithResponseInterceptor(timings.Record)
+	coverage := helpers.NewFleetCoverage(suite.dataset.DriverIDs)
+	watermark := helpers.NewHeapWatermark()
+
+	// Act
+	largestPage, pages := 0, 0
+	api.WalkDriversByCursor(helpers.DriverListFilter{}, fleetPageSize,
+		func(_ int, list *httpHandlers.ListDriversResponse, response *helpers.APIResponse) {
+			largestPage = max(largestPage, len(response.Body))
+			pages++
+			for _, driver := range list.Drivers {
+				coverage.Mark(driver.ID)
+			}
+			watermark.Sample()
+		})
+
+	// Assert
+	helpers.AssertFleetCovered(suite.T(), "GET /api/v1/drivers", coverage)
+	assert.LessOrEqual(suite.T(), largestPage, fleetPageSize*fleetDriverBytes+fleetEnvelopeBytes,
+		"Размер страницы из %d водителей", fleetPageSize)
+	suite.T().Logf("%d drivers in %d pages, largest page %d KB", coverage.Marked(), pages, largestPage/1024)
+
+	suite.env.DB.AssertLatencyBudget(suite.T(), timings, helpers.LatencyBudget{
+		Operation: "GET /api/v1/drivers",
+		P95:       300 * time.Millisecond,
+	})
+	helpers.AssertHeapGrowth(suite.T(), "cursor walk", watermark, fleetHeapGrowth)
+}
+
+// TestActiveDriversStreamed тестирует ответ /drivers/active на весь парк: водители разбираются из ответа
+// по одному, каждый встречается один раз, размер ответа растет не быстрее fleetDriverBytes на водителя
+func (suite *FleetScaleTestSuite) TestActiveDriversStreamed() {
+	// Arrange
+	timings := helpers.NewRequestTimings()
+	api := helpers.NewAPITestHelper(suite.env.Router, suite.T())
+	fleet := len(suite.dataset.DriverIDs)
+
+	// Act
+	var response *helpers.APIResponse
+	for i := 0; i < latencyWarmupSamples+fleetActiveSamples; i++ {
+		client := api
+		if i >= latencyWarmupSamples {
+			client = api.WithResponseInterceptor(timings.Record)
+		}
+
+		response = client.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    client.APIPath("/drivers/active"),
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	}
+
+	// Ответ уже в памяти клиента: замер показывает, сколько добавляет его разбор
+	coverage := helpers.NewFleetCoverage(suite.dataset.DriverIDs)
+	watermark := helpers.NewHeapWatermark()
+	largestDriver := 0
+	streamed, err := helpers.StreamJSONArray(bytes.NewReader(response.Body), "drivers", func(item json.RawMessage) error {
+		var driver httpHandlers.DriverResponse
+		if err := json.Unmarshal(item, &driver); err != nil {
+			return err
+		}
+		largestDriver = max(largestDriver, len(item))
+		coverage.Mark(driver.ID)
+		if coverage.Marked()%fleetHeapSampleEvery == 0 {
+			watermark.Sample()
+		}
+		return nil
+	})
+
+	// Assert
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), fleet, streamed)
+	helpers.AssertFleetCovered(suite.T(), "GET /api/v1/drivers/active", coverage)
+	assert.LessOrEqual(suite.T(), largestDriver, fleetDriverBytes, "Размер водителя в ответе")
+	assert.LessOrEqual(suite.T(), len(response.Body), fleet*fleetDriverBytes+fleetEnvelopeBytes,
+		"Размер ответа на %d водителей", fleet)
+	suite.T().Logf("Active drivers: %d in %d KB, largest driver %d bytes", streamed, len(response.Body)/1024, largestDriver)
+
+	suite.env.DB.AssertLatencyBudget(suite.T(), timings, helpers.LatencyBudget{
+		Operation: "GET /api/v1/drivers/active",
+		P95:       time.Duration(max(fleet/1000, 1)) * fleetActivePerThousand,
+		Queries: []helpers.PlannedQuery{{Name: "active drivers", Query: activeDriversQuery,
+			Args: []interface{}{time.Now().Add(-entities.LocationStaleAfter)}}},
+	})
+	helpers.AssertHeapGrowth(suite.T(), "active drivers decoding", watermark, fleetHeapGrowth)
+}
+
+// TestNearbyBoundedByLimit тестирует поиск поблизости в плотном парке: результат ограничен limit,
+// отсортирован по расстоянию в пределах радиуса, ответ укладывается в размер и порог задержки
+func (suite *FleetScaleTestSuite) TestNearbyBoundedByLimit() {
+	// Arrange
+	timings := helpers.NewRequestTimings()
+	api := helpers.NewAPITestHelper(suite.env.Router, suite.T())
+	rng := rand.New(rand.NewSource(suite.seed))
+
+	// Act
+	var latitude, longitude float64
+	largestResponse, fullResponses := 0, 0
+	for i := 0; i < latencyWarmupSamples+fleetNearbySamples; i++ {
+		client := api
+		if i >= latencyWarmupSamples {
+			client = api.WithResponseInterceptor(timings.Record)
+		}
+
+		// Точки поиска в пределах нескольких километров от центра, где плотность водителей наибольшая
+		latitude = suite.dataset.Latitude + (rng.Float64()-0.5)*0.05
+		longitude = suite.dataset.Longitude + (rng.Float64()-0.5)*0.08
+		response := client.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    client.APIPath("/locations/nearby"),
+			QueryParams: map[string]string{
+				"latitude":  strconv.FormatFloat(latitude, 'f', -1, 64),
+				"longitude": strconv.FormatFloat(longitude, 'f', -1, 64),
+				"radius_km": strconv.FormatFloat(fleetNearbyRadius, 'f', -1, 64),
+				"limit":     strconv.Itoa(fleetNearbyLimit),
+			},
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+		var nearby httpHandlers.NearbyDriversResponse
+		api.UnmarshalResponse(response, &nearby)
+		require.LessOrEqual(suite.T(), len(nearby.Drivers), fleetNearbyLimit)
+		require.True(suite.T(), sort.SliceIsSorted(nearby.Drivers, func(a, b int) bool {
+			return nearby.Drivers[a].Distance < nearby.Drivers[b].Distance
+		}), "Водители поблизости не отсортированы по расстоянию")
+		for _, driver := range nearby.Drivers {
+			require.LessOrEqual(suite.T(), driver.Distance, fleetNearbyRadius+0.01, "Водитель %s вне радиуса", driver.DriverID)
+		}
+
+		largestResponse = max(largestResponse, len(response.Body))
+		if len(nearby.Drivers) == fleetNearbyLimit {
+			fullResponses++
+		}
+	}
+
+	// Assert
+	assert.Positive(suite.T(), fullResponses, "Поиск в плотном парке ни разу не уперся в limit %d", fleetNearbyLimit)
+	assert.LessOrEqual(suite.T(), largestResponse, fleetNearbyLimit*fleetNearbyDriverBytes+fleetEnvelopeBytes,
+		"Размер ответа на %d водителей", fleetNearbyLimit)
+
+	suite.env.DB.AssertLatencyBudget(suite.T(), timings, helpers.LatencyBudget{
+		Operation: "GET /api/v1/locations/nearby",
+		P95:       500 * time.Millisecond,
+		Queries: []helpers.PlannedQuery{
+			{Name: "nearby", Query: nearbyQuery, Args: []interface{}{longitude, latitude, fleetNearbyRadius, fleetNearbyLimit}},
+		},
+	})
+}
+
+// TestLocationFirehose тестирует поток driver.location.updated в NATS, когда весь парк присылает по обновлению
+// местоположения: каждое событие доходит до подписчика один раз, в пределах размера и порога задержки доставки.
+// События публикует nats.Publisher сервиса (помощник EventFirehose), задержка считается от заголовка Published-At.
+func (suite *FleetScaleTestSuite) TestLocationFirehose() {
+	// Arrange
+	h := helpers.NewNATSHelper(suite.T())
+	subscription := h.Subscribe(fleetLocationEvent)
+	firehose := h.StartEventFirehose(suite.env.Events, fleetLocationEvent)
+	suite.T().Cleanup(suite.env.Events.Reset)
+
+	fleet := len(suite.dataset.DriverIDs)
+	timings := helpers.NewRequestTimings()
+	api := helpers.NewAPITestHelper(suite.env.Router, suite.T()).WithResponseInterceptor(timings.Record)
+	coverage := helpers.NewFleetCoverage(suite.dataset.DriverIDs)
+
+	// Подписчик разбирает события на лету: сохраняются только задержки доставки, место под них выделено заранее
+	var (
+		largestEvent int
+		delivery     = make([]time.Duration, 0, fleet)
+		received     = make(chan int, 1)
+	)
+	watermark := helpers.NewHeapWatermark()
+	stopSampling := watermark.SampleEvery(fleetHeapSampleTicker)
+	defer stopSampling()
+	go func() {
+		received <- subscription.Receive(fleetFirehoseIdle, func(message *helpers.NATSMessage) bool {
+			if publishedAt, err := helpers.PublishedAt(message); err == nil {
+				delivery = append(delivery, time.Since(publishedAt))
+			}
+			largestEvent = max(largestEvent, len(message.Payload))
+			if driverID, err := uuid.Parse(message.Header[nats.DriverIDHeader]); err == nil {
+				coverage.Mark(driverID)
+			}
+			return len(delivery) < fleet
+		})
+	}()
+
+	// Act
+	var failed atomic.Int64
+	drivers := make(chan int)
+	var wg sync.WaitGroup
+	for w := 0; w < fleetFirehoseWorkers; w++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			for i := range drivers {
+				response := api.MakeRequest(helpers.APIRequest{
+					Method: http.MethodPost,
+					URL:    api.APIPath(fmt.Sprintf("/drivers/%s/locations", suite.dataset.DriverIDs[i])),
+					Body: map[string]interface{}{
+						"latitude":  suite.dataset.Latitude + float64(i%200-100)*0.001,
+						"longitude": suite.dataset.Longitude + float64(i%300-150)*0.001,
+					},
+				})
+				if response.StatusCode != http.StatusOK {
+					failed.Add(1)
+				}
+			}
+		}()
+	}
+	for i := range suite.dataset.DriverIDs {
+		drivers <- i
+	}
+	close(drivers)
+	wg.Wait()
+
+	published := firehose.Drain()
+	var events int
+	select {
+	case events = <-received:
+	case <-time.After(2 * fleetFirehoseIdle):
+		suite.T().Fatalf("Подписчик не дочитал поток местоположений за %v", 2*fleetFirehoseIdle)
+	}
+	stopSampling()
+
+	// Assert
+	assert.Zero(suite.T(), failed.Load(), "Обновления местоположения отклонены")
+	assert.Equal(suite.T(), fleet, published, "Событий опубликовано в NATS")
+	assert.Equal(suite.T(), fleet, events, "Событий получено подписчиком")
+	helpers.AssertFleetCovered(suite.T(), fleetLocationEvent, coverage)
+	assert.Empty(suite.T(), suite.env.Events.EventsOfType(fleetLocationEvent), "События потока копятся в памяти теста")
+	assert.LessOrEqual(suite.T(), largestEvent, fleetLocationEventBytes, "Размер данных %s", fleetLocationEvent)
+
+	latency := helpers.NewLatencyPercentiles(delivery)
+	suite.T().Logf("%s: %d events, largest %d bytes; delivery p50 %v, p95 %v, max %v",
+		fleetLocationEvent, events, largestEvent, latency.P50, latency.P95, latency.Max)
+	helpers.AssertWithinSLO(suite.T(), fleetLocationEvent+" delivery p95", latency.P95, 250*time.Millisecond)
+
+	suite.env.DB.AssertLatencyBudget(suite.T(), timings, helpers.LatencyBudget{
+		Operation: "POST /api/v1/drivers/{id}/locations",
+		P95:       250 * time.Millisecond,
+	})
+	helpers.AssertHeapGrowth(suite.T(), "location firehose", watermark, fleetHeapGrowth)
+}
+
+// TestFleetScaleTestSuite запускает тестовый suite
+func TestFleetScaleTestSuite(t *testing.T) {
+	// Пропускаем масштабные тесты в быстром режиме
+	if testing.Short() {
+		t.Skip("Skipping fleet scale tests in short mode")
+	}
+
+	suite.Run(t, new(FleetScaleTestSuite))
+}
@@ -0,0 +1,215 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// shortGeoCacheTTL время жизни кэша в тестах истечения: записи успевают истечь за время теста
+const shortGeoCacheTTL = 200 * time.Millisecond
+
+// GeoCacheTTLTestSuite тестирует время жизни ключей кэша поиска поблизости (geo_cache):
+// у каждого ключа есть срок в пределах политики, истекшие ключи удаляются, а данные
+// перечитываются из БД лениво - при следующем запросе, а не фоновым обновлением.
+//
+// Кэш местоположений в сервисе один - geo_cache в памяти процесса, статусы водителей не кэшируются, а Redis
+// хранит только сессии приложения водителя. Поэтому аудит TTL проверяет ключи geo_cache, а не ключи Redis.
+type GeoCacheTTLTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *GeoCacheTTLTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *GeoCacheTTLTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *GeoCacheTTLTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.env.SetFeature(suite.T(), features.GeoCache, true)
+}
+
+// TestKeysHaveTTLWithinPolicy тестирует, что каждый ключ кэша получает срок жизни не больше политики
+func (suite *GeoCacheTTLTestSuite) TestKeysHaveTTLWithinPolicy() {
+	// Arrange
+	driverID := suite.createDriver(suite.T())
+	suite.updateLocation(suite.T(), driverID, featureCenterLat, featureCenterLon)
+
+	// Act
+	suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5")
+	suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "10")
+	suite.nearbyDrivers(suite.T(), featureFarLat, featureFarLon, "5")
+	suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5") // попадание в кэш, новый ключ не создается
+
+	// Assert
+	assert.Equal(suite.T(), 3, suite.env.AuditGeoCacheTTL(suite.T()), "Ключ на каждую уникальную пару точки и радиуса")
+}
+
+// TestStaleKeysExpire тестирует, что истекший ключ не отдается и удаляется из кэша
+func (suite *GeoCacheTTLTestSuite) TestStaleKeysExpire() {
+	// Arrange
+	suite.env.SetGeoCacheTTL(suite.T(), shortGeoCacheTTL)
+	suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5")
+	entries := suite.env.GeoCacheEntries()
+	require.Len(suite.T(), entries, 1)
+	staleKey := entries[0].Key
+
+	// Act
+	suite.waitGeoCacheExpired()
+	suite.nearbyDrivers(suite.T(), featureFarLat, featureFarLon, "5")
+
+	// Assert
+	entries = suite.env.GeoCacheEntries()
+	require.Len(suite.T(), entries, 1, "Истекший ключ удален при промахе кэша")
+	assert.NotEqual(suite.T(), staleKey, entries[0].Key)
+	suite.env.AuditGeoCacheTTL(suite.T())
+}
+
+// TestExpiredKeyRepopulatedLazily тестирует, что после истечения ключа поиск поблизости перечитывает
+// местоположения из БД и кэширует свежий результат с новым сроком жизни
+func (suite *GeoCacheTTLTestSuite) TestExpiredKeyRepopulatedLazily() {
+	// Arrange
+	suite.env.SetGeoCacheTTL(suite.T(), shortGeoCacheTTL)
+	cachedDriver := suite.createDriver(suite.T())
+	suite.updateLocation(suite.T(), cachedDriver, featureCenterLat, featureCenterLon)
+	require.Equal(suite.T(), []uuid.UUID{cachedDriver}, suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5"))
+
+	// Местоположение записывается в обход сервиса и не сбрасывает кэш
+	lateDriver := suite.createDriver(suite.T())
+	require.NoError(suite.T(), suite.env.LocationRepo.Create(suite.env.Ctx, fixtures.CreateTestLocation(lateDriver)))
+	require.Equal(suite.T(), []uuid.UUID{cachedDriver}, suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5"),
+		"До истечения срока отдается закэшированный результат")
+
+	// Act
+	suite.waitGeoCacheExpired() // без запросов ключ не обновляется в фоне
+	nearby := suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5")
+
+	// Assert
+	assert.ElementsMatch(suite.T(), []uuid.UUID{cachedDriver, lateDriver}, nearby, "Истекший ключ перечитан из БД")
+	assert.Equal(suite.T(), 1, suite.env.AuditGeoCacheTTL(suite.T()), "Ключ закэширован заново с новым сроком")
+}
+
+// TestCacheHitsWhileOtherDriversUpdate тестирует, что обновления местоположений водителей вне области поиска
+// не сбрасывают закэшированный результат, а обновление внутри области сбрасывает
+func (suite *GeoCacheTTLTestSuite) TestCacheHitsWhileOtherDriversUpdate() {
+	// Arrange
+	nearDriver := suite.createDriver(suite.T())
+	suite.updateLocation(suite.T(), nearDriver, featureCenterLat, featureCenterLon)
+	farDrivers := []uuid.UUID{suite.createDriver(suite.T()), suite.createDriver(suite.T())}
+	require.Equal(suite.T(), []uuid.UUID{nearDriver}, suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5"))
+	before := suite.env.GeoCacheStats()
+
+	// Act - водители в другом городе обновляют местоположения между поисками
+	for i := 0; i < 5; i++ {
+		for _, driverID := range farDrivers {
+			suite.updateLocation(suite.T(), driverID, featureFarLat+float64(i)*0.001, featureFarLon)
+		}
+		assert.Equal(suite.T(), []uuid.UUID{nearDriver}, suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5"))
+	}
+	afterFar := suite.env.GeoCacheStats()
+
+	suite.updateLocation(suite.T(), farDrivers[0], featureCenterLat+0.001, featureCenterLon)
+	nearby := suite.nearbyDrivers(suite.T(), featureCenterLat, featureCenterLon, "5")
+	afterNear := suite.env.GeoCacheStats()
+
+	// Assert
+	assert.Equal(suite.T(), uint64(5), afterFar.Hits-before.Hits, "Обновления вне области не сбрасывают запись")
+	assert.Equal(suite.T(), before.Misses, afterFar.Misses)
+	assert.ElementsMatch(suite.T(), []uuid.UUID{nearDriver, farDrivers[0]}, nearby)
+	assert.Equal(suite.T(), afterFar.Misses+1, afterNear.Misses, "Водитель, въехавший в область, сбрасывает запись")
+}
+
+// createDriver регистрирует водителя через API и переводит его в статус available, чтобы он попадал в поиск поблизости
+func (suite *GeoCacheTTLTestSuite) createDriver(t *testing.T) uuid.UUID {
+	suite.drivers++
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7900444%04d", suite.drivers)
+	request["email"] = fmt.Sprintf("cache.driver%d@example.com", suite.drivers)
+	request["license_number"] = fmt.Sprintf("GC%08d", suite.drivers)
+	request["license_expiry"] = time.Now().AddDate(2, 0, 0).UTC().Format(time.RFC3339)
+
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+	require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+
+	// Обходим цепочку переходов статусов - для поиска важен только итоговый статус
+	require.NoError(t, suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driver.ID, entities.StatusAvailable))
+	return driver.ID
+}
+
+// updateLocation отправляет местоположение водителя через API
+func (suite *GeoCacheTTLTestSuite) updateLocation(t *testing.T, driverID uuid.UUID, lat, lon float64) {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body: map[string]interface{}{
+			"latitude":  lat,
+			"longitude": lon,
+		},
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+}
+
+// waitGeoCacheExpired ждет, пока у единственного ключа кэша истечет срок жизни
+func (suite *GeoCacheTTLTestSuite) waitGeoCacheExpired() {
+	helpers.Eventually(suite.T(), func(c *helpers.EventuallyT) {
+		entries := suite.env.GeoCacheEntries()
+		require.Len(c, entries, 1)
+		assert.LessOrEqual(c, entries[0].ExpiresIn, time.Duration(0), "Срок жизни ключа %q не истек", entries[0].Key)
+	}, shortGeoCacheTTL+time.Second, 20*time.Millisecond)
+}
+
+// nearbyDrivers возвращает ID водителей в радиусе radiusKm от точки
+func (suite *GeoCacheTTLTestSuite) nearbyDrivers(t *testing.T, lat, lon float64, radiusKm string) []uuid.UUID {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath("/locations/nearby"),
+		QueryParams: map[string]string{
+			"latitude":  fmt.Sprintf("%f", lat),
+			"longitude": fmt.Sprintf("%f", lon),
+			"radius_km": radiusKm,
+		},
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+	var nearby httpHandlers.NearbyDriversResponse
+	suite.env.API.UnmarshalResponse(response, &nearby)
+
+	ids := make([]uuid.UUID, 0, len(nearby.Drivers))
+	for _, driver := range nearby.Drivers {
+		ids = append(ids, driver.DriverID)
+	}
+	return ids
+}
+
+func TestGeoCacheTTLTestSuite(t *testing.T) {
+	suite.Run(t, new(GeoCacheTTLTestSuite))
+}
@@ -0,0 +1,236 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// zoneTransition ожидаемый переход водителя через границу зоны
+type zoneTransition struct {
+	EventType  string
+	ZoneID     string
+	PointIndex int
+}
+
+// GeofenceTestSuite тестовый suite для событий входа/выхода из геозон
+type GeofenceTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *GeofenceTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.env.EnableGeofences(suite.T(), fixtures.ServiceZones(fixtures.CreateTestZones()))
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *GeofenceTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *GeofenceTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestRouteAcrossZonesEmitsZoneEvents тестирует события входа/выхода при проезде через границы зон
+func (suite *GeofenceTestSuite) TestRouteAcrossZonesEmitsZoneEvents() {
+	// Arrange
+	driverID := suite.createActiveDriver(fixtures.CreateTestDriver())
+	zones := fixtures.CreateTestZones()
+	route := fixtures.CreateCenterToAirportRoute(30)
+
+	expected := suite.expectedTransitions(route, zones)
+	require.NotEmpty(suite.T(), expected, "Маршрут должен пересекать границы зон")
+
+	// Act
+	suite.driveRoute(driverID, route)
+
+	// Assert
+	locationEvents := suite.env.Events.EventsForDriver(driverID, "driver.location.updated")
+	assert.Len(suite.T(), locationEvents, len(route), "Каждая точка маршрута должна публиковать событие обновления")
+
+	zoneEvents := suite.zoneEventsForDriver(driverID)
+	require.Len(suite.T(), zoneEvents, len(expected))
+	for i, transition := range expected {
+		assert.Equal(suite.T(), transition.EventType, zoneEvents[i].EventType, "Переход %d", i)
+		assert.Equal(suite.T(), transition.ZoneID, zoneEvents[i].DataMap()["zone_id"], "Переход %d", i)
+		location, ok := zoneEvents[i].DataMap()["location"].(entities.Location)
+		require.True(suite.T(), ok, "Переход %d без местоположения", i)
+		assert.InDelta(suite.T(), route[transition.PointIndex].Latitude, location.Latitude, 1e-9, "Переход %d", i)
+		assert.InDelta(suite.T(), route[transition.PointIndex].Longitude, location.Longitude, 1e-9, "Переход %d", i)
+	}
+}
+
+// TestRouteInsideZoneEmitsNoZoneEvents тестирует отсутствие событий при движении внутри одной зоны
+func (suite *GeofenceTestSuite) TestRouteInsideZoneEmitsNoZoneEvents() {
+	// Arrange
+	driverID := suite.createActiveDriver(fixtures.CreateTestDriver())
+	center := fixtures.CreateCityCenterZone()
+	route := fixtures.CreateStraightRoute(
+		fixtures.RoutePoint{Latitude: center.CenterLat, Longitude: center.CenterLon},
+		fixtures.RoutePoint{Latitude: center.CenterLat + 0.005, Longitude: center.CenterLon + 0.005},
+		5,
+	)
+
+	for _, point := range route {
+		require.True(suite.T(), center.Contains(point.Latitude, point.Longitude))
+	}
+
+	// Act
+	suite.driveRoute(driverID, route)
+
+	// Assert
+	// Первая точка - вход в зону, дальше переходов быть не должно
+	zoneEvents := suite.zoneEventsForDriver(driverID)
+	require.Len(suite.T(), zoneEvents, 1)
+	assert.Equal(suite.T(), services.EventZoneEntered, zoneEvents[0].EventType)
+	assert.Equal(suite.T(), center.ID, zoneEvents[0].DataMap()["zone_id"])
+	assert.Equal(suite.T(), center.Name, zoneEvents[0].DataMap()["zone_name"])
+}
+
+// TestNearbySearchWithinZoneRadius тестирует, что поиск от центра зоны в ее радиусе возвращает только водителей,
+// находящихся в этой зоне
+func (suite *GeofenceTestSuite) TestNearbySearchWithinZoneRadius() {
+	// Arrange
+	center := fixtures.CreateCityCenterZone()
+	airport := fixtures.CreateAirportZone()
+	drivers := fixtures.CreateMultipleTestDrivers(2)
+
+	centerDriverID := suite.createActiveDriver(drivers[0])
+	airportDriverID := suite.createActiveDriver(drivers[1])
+
+	suite.driveRoute(centerDriverID, []fixtures.RoutePoint{{Latitude: center.CenterLat, Longitude: center.CenterLon}})
+	suite.driveRoute(airportDriverID, []fixtures.RoutePoint{{Latitude: airport.CenterLat, Longitude: airport.CenterLon}})
+
+	testCases := []struct {
+		name     string
+		zone     fixtures.Zone
+		included uuid.UUID
+		excluded uuid.UUID
+	}{
+		{name: "city center", zone: center, included: centerDriverID, excluded: airportDriverID},
+		{name: "airport", zone: airport, included: airportDriverID, excluded: centerDriverID},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			response := suite.env.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodGet,
+				URL:    "/api/v1/locations/nearby",
+				QueryParams: map[string]string{
+					"latitude":  fmt.Sprintf("%f", tc.zone.CenterLat),
+					"longitude": fmt.Sprintf("%f", tc.zone.CenterLon),
+					"radius_km": fmt.Sprintf("%f", tc.zone.RadiusKm),
+				},
+			})
+
+			// Assert
+			require.Equal(t, http.StatusOK, response.StatusCode)
+
+			var nearby httpHandlers.NearbyDriversResponse
+			suite.env.API.UnmarshalResponse(response, &nearby)
+
+			found := make(map[uuid.UUID]bool)
+			for _, driver := range nearby.Drivers {
+				found[driver.DriverID] = true
+				assert.True(t, tc.zone.Contains(driver.Latitude, driver.Longitude),
+					"Водитель %s вне зоны %s", driver.DriverID, tc.zone.ID)
+			}
+
+			assert.True(t, found[tc.included], "Водитель зоны %s должен быть найден", tc.zone.ID)
+			assert.False(t, found[tc.excluded], "Водитель другой зоны не должен быть найден")
+		})
+	}
+}
+
+// createActiveDriver создает водителя и переводит его в статус available
+func (suite *GeofenceTestSuite) createActiveDriver(driver *entities.Driver) uuid.UUID {
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+
+	for _, status := range []entities.Status{
+		entities.StatusPendingVerification,
+		entities.StatusVerified,
+		entities.StatusAvailable,
+	} {
+		require.NoError(suite.T(), suite.env.DriverService.ChangeDriverStatus(suite.env.Ctx, createdDriver.ID, status))
+	}
+
+	return createdDriver.ID
+}
+
+// driveRoute отправляет точки маршрута через API в хронологическом порядке
+func (suite *GeofenceTestSuite) driveRoute(driverID uuid.UUID, route []fixtures.RoutePoint) {
+	baseTime := time.Now().Add(-time.Duration(len(route)) * time.Second)
+
+	for i, point := range route {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodPost,
+			URL:    fmt.Sprintf("/api/v1/drivers/%s/locations", driverID),
+			Body: map[string]interface{}{
+				"latitude":  point.Latitude,
+				"longitude": point.Longitude,
+				"timestamp": baseTime.Add(time.Duration(i) * time.Second).Unix(),
+			},
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, "Точка маршрута %d", i)
+	}
+}
+
+// expectedTransitions вычисляет ожидаемые переходы через границы зон вдоль маршрута
+func (suite *GeofenceTestSuite) expectedTransitions(route []fixtures.RoutePoint, zones []fixtures.Zone) []zoneTransition {
+	var transitions []zoneTransition
+	inside := make(map[string]bool)
+
+	for i, point := range route {
+		for _, zone := range zones {
+			isInside := zone.Contains(point.Latitude, point.Longitude)
+			switch {
+			case isInside && !inside[zone.ID]:
+				transitions = append(transitions, zoneTransition{EventType: services.EventZoneEntered, ZoneID: zone.ID, PointIndex: i})
+			case !isInside && inside[zone.ID]:
+				transitions = append(transitions, zoneTransition{EventType: services.EventZoneExited, ZoneID: zone.ID, PointIndex: i})
+			}
+			inside[zone.ID] = isInside
+		}
+	}
+
+	return transitions
+}
+
+// zoneEventsForDriver возвращает события геозон водителя в порядке публикации
+func (suite *GeofenceTestSuite) zoneEventsForDriver(driverID uuid.UUID) []helpers.RecordedEvent {
+	var result []helpers.RecordedEvent
+	for _, event := range suite.env.Events.Events() {
+		if event.DriverID != driverID {
+			continue
+		}
+		if event.EventType == services.EventZoneEntered || event.EventType == services.EventZoneExited {
+			result = append(result, event)
+		}
+	}
+	return result
+}
+
+// TestGeofenceTestSuite запускает тестовый suite
+func TestGeofenceTestSuite(t *testing.T) {
+	suite.Run(t, new(GeofenceTestSuite))
+}
@@ -0,0 +1,153 @@
+//go:build integration
+
+package integration
+
+import (
+	"net/http"
+	"sort"
+	"testing"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// HealthChecksTestSuite тестирует проверки живости (/health/live) и готовности (/health/ready):
+// структуру ответа с состоянием зависимостей и переход в not_ready при отказе БД, Redis или NATS,
+// имитируемом через ChaosProxy. Redis и NATS проверяются, если доступны тестовые экземпляры.
+type HealthChecksTestSuite struct {
+	suite.Suite
+	env   *helpers.TestEnvironment
+	chaos *helpers.DependencyChaos
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *HealthChecksTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.chaos = suite.env.EnableHealthChecks(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *HealthChecksTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *HealthChecksTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.chaos.RestoreAll()
+}
+
+// TestLiveness тестирует, что /health/live отвечает без проверки зависимостей
+func (suite *HealthChecksTestSuite) TestLiveness() {
+	// Act
+	response := suite.get("/health/live")
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	body := helpers.AssertJSONContract(suite.T(), response.Body, helpers.APIContract{
+		"status":    helpers.JSONString,
+		"service":   helpers.JSONString,
+		"timestamp": helpers.JSONString,
+	})
+	assert.Equal(suite.T(), httpHandlers.HealthStatusAlive, body["status"])
+	assert.Equal(suite.T(), "driver-service", body["service"])
+	assert.NotContains(suite.T(), body, "dependencies")
+}
+
+// TestReadinessDependencyDetails тестирует структуру ответа /health/ready при доступных зависимостях
+func (suite *HealthChecksTestSuite) TestReadinessDependencyDetails() {
+	// Act
+	response := suite.get("/health/ready")
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	body := helpers.AssertJSONContract(suite.T(), response.Body, helpers.APIContract{
+		"status":       helpers.JSONString,
+		"service":      helpers.JSONString,
+		"timestamp":    helpers.JSONString,
+		"dependencies": helpers.JSONObject,
+	})
+	assert.Equal(suite.T(), httpHandlers.HealthStatusReady, body["status"])
+
+	dependencies := body["dependencies"].(map[string]interface{})
+	assert.ElementsMatch(suite.T(), suite.dependencies(), sortedKeys(dependencies), "Проверяются все подключенные зависимости")
+	for name, raw := range dependencies {
+		dependency, ok := raw.(map[string]interface{})
+		require.True(suite.T(), ok, "Зависимость %s: %v", name, raw)
+		assert.Equal(suite.T(), httpHandlers.DependencyStatusUp, dependency["status"], name)
+		assert.IsType(suite.T(), float64(0), dependency["latency_ms"], name)
+		assert.NotContains(suite.T(), dependency, "error", "У доступной зависимости %s нет ошибки", name)
+	}
+}
+
+// TestReadinessDuringDependencyOutage тестирует, что отказ каждой зависимости переводит сервис в not_ready
+// (503) не дольше таймаута проверки, не затрагивая живость, а после восстановления сервис снова готов
+func (suite *HealthChecksTestSuite) TestReadinessDuringDependencyOutage() {
+	for _, outage := range suite.dependencies() {
+		suite.Run(outage, func() {
+			// Arrange
+			suite.chaos.RestoreAll()
+			require.Equal(suite.T(), http.StatusOK, suite.get("/health/ready").StatusCode)
+
+			// Act
+			suite.chaos.Cut(suite.T(), outage)
+			var response *helpers.APIResponse
+			// Ответ о неготовности не ждет зависшую зависимость
+			suite.env.API.AssertLatency(suite.T(), 2*helpers.TestReadinessTimeout, func() { response = suite.get("/health/ready") })
+
+			// Assert
+			require.Equal(suite.T(), http.StatusServiceUnavailable, response.StatusCode, string(response.Body))
+
+			var readiness httpHandlers.ReadinessResponse
+			suite.env.API.UnmarshalResponse(response, &readiness)
+			assert.Equal(suite.T(), httpHandlers.HealthStatusNotReady, readiness.Status)
+			for name, dependency := range readiness.Dependencies {
+				if name == outage {
+					assert.Equal(suite.T(), httpHandlers.DependencyStatusDown, dependency.Status)
+					assert.NotEmpty(suite.T(), dependency.Error)
+				} else {
+					assert.Equal(suite.T(), httpHandlers.DependencyStatusUp, dependency.Status, "Отказ %s не затрагивает %s", outage, name)
+				}
+			}
+
+			assert.Equal(suite.T(), http.StatusOK, suite.get("/health/live").StatusCode, "Живость не зависит от зависимостей")
+			assert.Equal(suite.T(), http.StatusOK, suite.get("/health").StatusCode)
+
+			// Act - восстановление
+			suite.chaos.RestoreAll()
+
+			// Assert
+			response = suite.get("/health/ready")
+			assert.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+		})
+	}
+}
+
+// get выполняет GET запрос к роутеру окружения
+func (suite *HealthChecksTestSuite) get(url string) *helpers.APIResponse {
+	return suite.env.API.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: url})
+}
+
+// dependencies возвращает имена подключенных зависимостей по алфавиту
+func (suite *HealthChecksTestSuite) dependencies() []string {
+	return sortedKeys(suite.chaos.Proxies)
+}
+
+// sortedKeys возвращает ключи map по алфавиту
+func sortedKeys[V any](values map[string]V) []string {
+	result := make([]string, 0, len(values))
+	for key := range values {
+		result = append(result, key)
+	}
+	sort.Strings(result)
+	return result
+}
+
+// TestHealthChecksTestSuite запускает тестовый suite
+func TestHealthChecksTestSuite(t *testing.T) {
+	suite.Run(t, new(HealthChecksTestSuite))
+}
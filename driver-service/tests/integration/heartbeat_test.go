@@ -0,0 +1,250 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"strconv"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// HeartbeatTestSuite тестирует heartbeat приложения водителя: перевод в offline после пропущенных
+// heartbeat, сохранение последнего местоположения и ограничение частоты
+type HeartbeatTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *HeartbeatTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *HeartbeatTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *HeartbeatTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestMissedHeartbeatsMarkOffline тестирует перевод в offline водителей без heartbeat дольше таймаута
+func (suite *HeartbeatTestSuite) TestMissedHeartbeatsMarkOffline() {
+	// Arrange
+	silentAvailable := suite.createDriverWithHeartbeat(entities.StatusAvailable)
+	suite.env.DB.AgeHeartbeat(suite.T(), silentAvailable, helpers.TestHeartbeatTimeout+time.Minute)
+
+	silentOnShift := suite.createDriverWithHeartbeat(entities.StatusOnShift)
+	suite.env.DB.AgeHeartbeat(suite.T(), silentOnShift, helpers.TestHeartbeatTimeout+time.Minute)
+
+	// Таймаут еще не истек
+	recent := suite.createDriverWithHeartbeat(entities.StatusAvailable)
+	suite.env.DB.AgeHeartbeat(suite.T(), recent, helpers.TestHeartbeatTimeout-time.Minute)
+
+	// Водитель не на линии: пропущенные heartbeat его статус не меняют
+	inactive := suite.createDriverWithHeartbeat(entities.StatusInactive)
+	suite.env.DB.AgeHeartbeat(suite.T(), inactive, helpers.TestHeartbeatTimeout+time.Minute)
+
+	// Act
+	result, response := suite.env.API.RunJob(services.JobMarkOffline)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.Equal(suite.T(), 2, result.Affected)
+
+	suite.assertDriverStatus(silentAvailable, entities.StatusOffline)
+	suite.assertDriverStatus(silentOnShift, entities.StatusOffline)
+	suite.assertDriverStatus(recent, entities.StatusAvailable)
+	suite.assertDriverStatus(inactive, entities.StatusInactive)
+
+	for driverID, oldStatus := range map[uuid.UUID]entities.Status{
+		silentAvailable: entities.StatusAvailable,
+		silentOnShift:   entities.StatusOnShift,
+	} {
+		events := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+		require.Len(suite.T(), events, 1)
+		data := events[0].DataMap()
+		assert.Equal(suite.T(), string(oldStatus), data["old_status"])
+		assert.Equal(suite.T(), string(entities.StatusOffline), data["new_status"])
+		assert.Equal(suite.T(), "heartbeat_timeout", data["changed_by"])
+	}
+
+	suite.T().Run("SecondRunIsNoop", func(t *testing.T) {
+		result, response := suite.env.API.RunJob(services.JobMarkOffline)
+
+		require.Equal(t, http.StatusOK, response.StatusCode)
+		assert.Equal(t, 0, result.Affected)
+		assert.Len(t, suite.env.Events.EventsOfType("driver.status.changed"), 2, "Повторный запуск не публикует событий")
+	})
+
+	suite.T().Run("HeartbeatBringsDriverOnline", func(t *testing.T) {
+		heartbeat, response := suite.env.API.Heartbeat(silentAvailable, nil)
+
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+		assert.Equal(t, silentAvailable, heartbeat.DriverID)
+		suite.assertDriverStatus(silentAvailable, entities.StatusAvailable)
+
+		events := suite.env.Events.EventsForDriver(silentAvailable, "driver.status.changed")
+		require.Len(t, events, 2)
+		data := events[1].DataMap()
+		assert.Equal(t, string(entities.StatusOffline), data["old_status"])
+		assert.Equal(t, string(entities.StatusAvailable), data["new_status"])
+		assert.Equal(t, "heartbeat", data["changed_by"])
+	})
+}
+
+// TestHeartbeatWithoutLocation тестирует, что heartbeat без местоположения не меняет последнее известное местоположение
+func (suite *HeartbeatTestSuite) TestHeartbeatWithoutLocation() {
+	// Arrange
+	driverID := suite.createDriver(entities.StatusAvailable)
+	known := fixtures.CreateTestLocationWithCoords(driverID, 55.7558, 37.6173)
+	known.RecordedAt = time.Now().Add(-time.Minute)
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, known))
+
+	// Act
+	heartbeat, response := suite.env.API.Heartbeat(driverID, nil)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.WithinDuration(suite.T(), time.Now(), heartbeat.LastSeenAt, 5*time.Second)
+
+	current, err := suite.env.LocationService.GetCurrentLocation(suite.env.Ctx, driverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), known.ID, current.ID, "Heartbeat без местоположения не затирает последнюю позицию")
+	assert.InDelta(suite.T(), known.Latitude, current.Latitude, 1e-9)
+	assert.InDelta(suite.T(), known.Longitude, current.Longitude, 1e-9)
+	assert.Len(suite.T(), suite.locationHistory(driverID), 1, "Heartbeat без местоположения не пишет историю")
+
+	suite.T().Run("WithLocation", func(t *testing.T) {
+		suite.env.DB.AgeHeartbeat(t, driverID, helpers.TestHeartbeatMinInterval)
+
+		_, response := suite.env.API.Heartbeat(driverID, &httpHandlers.UpdateLocationRequest{
+			Latitude:  55.7602,
+			Longitude: 37.6188,
+		})
+
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+		current, err := suite.env.LocationService.GetCurrentLocation(suite.env.Ctx, driverID)
+		require.NoError(t, err)
+		assert.NotEqual(t, known.ID, current.ID)
+		assert.InDelta(t, 55.7602, current.Latitude, 1e-9)
+		assert.InDelta(t, 37.6188, current.Longitude, 1e-9)
+		assert.Len(t, suite.locationHistory(driverID), 2)
+	})
+}
+
+// TestHeartbeatRateLimit тестирует отклонение heartbeat чаще минимального интервала
+func (suite *HeartbeatTestSuite) TestHeartbeatRateLimit() {
+	// Arrange
+	driverID := suite.createDriver(entities.StatusAvailable)
+	first, response := suite.env.API.Heartbeat(driverID, nil)
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	// Act
+	_, response = suite.env.API.Heartbeat(driverID, &httpHandlers.UpdateLocationRequest{
+		Latitude:  55.7602,
+		Longitude: 37.6188,
+	})
+
+	// Assert
+	suite.env.API.AssertStatusCode(response, http.StatusTooManyRequests)
+	suite.env.API.AssertErrorResponse(response, "RATE_LIMITED")
+	assert.Equal(suite.T(), strconv.Itoa(int(helpers.TestHeartbeatMinInterval.Seconds())), response.Headers.Get("Retry-After"))
+
+	stored, err := suite.env.HeartbeatRepo.GetByDriverID(suite.env.Ctx, driverID)
+	require.NoError(suite.T(), err)
+	assert.WithinDuration(suite.T(), first.LastSeenAt, stored.LastSeenAt, time.Millisecond,
+		"Отклоненный heartbeat не сдвигает время последнего сигнала")
+	assert.Empty(suite.T(), suite.locationHistory(driverID), "Местоположение из отклоненного heartbeat не сохраняется")
+
+	suite.T().Run("InvalidLocationDoesNotConsumeLimit", func(t *testing.T) {
+		other := suite.createDriver(entities.StatusAvailable)
+
+		_, response := suite.env.API.Heartbeat(other, &httpHandlers.UpdateLocationRequest{
+			Latitude:  120,
+			Longitude: 37.6188,
+		})
+		suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+		suite.env.API.AssertErrorResponse(response, "INVALID_LOCATION")
+
+		_, response = suite.env.API.Heartbeat(other, nil)
+		assert.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+	})
+
+	suite.T().Run("AllowedAfterInterval", func(t *testing.T) {
+		suite.env.DB.AgeHeartbeat(t, driverID, helpers.TestHeartbeatMinInterval)
+
+		heartbeat, response := suite.env.API.Heartbeat(driverID, nil)
+
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+		assert.True(t, heartbeat.LastSeenAt.After(first.LastSeenAt))
+	})
+}
+
+// TestHeartbeatUnknownDriver тестирует heartbeat несуществующего водителя
+func (suite *HeartbeatTestSuite) TestHeartbeatUnknownDriver() {
+	// Act
+	_, response := suite.env.API.Heartbeat(uuid.New(), nil)
+
+	// Assert
+	suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+	suite.env.API.AssertErrorResponse(response, "DRIVER_NOT_FOUND")
+}
+
+// createDriver создает водителя с уникальными контактами и переводит его в статус status
+func (suite *HeartbeatTestSuite) createDriver(status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900777%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("heartbeat.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("HB%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	return created.ID
+}
+
+// createDriverWithHeartbeat создает водителя в статусе status и отправляет его первый heartbeat
+func (suite *HeartbeatTestSuite) createDriverWithHeartbeat(status entities.Status) uuid.UUID {
+	driverID := suite.createDriver(status)
+	_, response := suite.env.API.Heartbeat(driverID, nil)
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	return driverID
+}
+
+// locationHistory возвращает всю сохраненную историю местоположений водителя
+func (suite *HeartbeatTestSuite) locationHistory(driverID uuid.UUID) []*entities.DriverLocation {
+	history, err := suite.env.LocationRepo.GetByDriverIDInTimeRange(suite.env.Ctx, driverID,
+		time.Now().Add(-time.Hour), time.Now().Add(time.Hour))
+	require.NoError(suite.T(), err)
+	return history
+}
+
+// assertDriverStatus проверяет текущий статус водителя
+func (suite *HeartbeatTestSuite) assertDriverStatus(driverID uuid.UUID, expected entities.Status) {
+	driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), expected, driver.Status)
+}
+
+// TestHeartbeatTestSuite запускает тестовый suite
+func TestHeartbeatTestSuite(t *testing.T) {
+	suite.Run(t, new(HeartbeatTestSuite))
+}
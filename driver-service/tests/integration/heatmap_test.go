@@ -0,0 +1,161 @@
+//go:build integration
+
+package integration
+
+import (
+	"math/rand"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// HeatmapTestSuite сверяет агрегаты плотности водителей по ячейкам geohash с исходными местоположениями
+type HeatmapTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *HeatmapTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *HeatmapTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *HeatmapTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestGeohashReferenceVectors тестирует эталонное кодирование geohash на известных значениях
+func (suite *HeatmapTestSuite) TestGeohashReferenceVectors() {
+	testCases := []struct {
+		name      string
+		latitude  float64
+		longitude float64
+		precision int
+		expected  string
+	}{
+		{name: "Jutland", latitude: 57.64911, longitude: 10.40744, precision: 11, expected: "u4pruydqqvj"},
+		{name: "Spain", latitude: 42.6, longitude: -5.6, precision: 5, expected: "ezs42"},
+		{name: "Sydney", latitude: -33.8688, longitude: 151.2093, precision: 6, expected: "r3gx2f"},
+		{name: "NewYork", latitude: 40.7128, longitude: -74.0060, precision: 6, expected: "dr5reg"},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			geohash := helpers.GeohashEncode(tc.latitude, tc.longitude, tc.precision)
+			assert.Equal(t, tc.expected, geohash)
+
+			// Более грубая ячейка - префикс более точной
+			assert.Equal(t, tc.expected[:3], helpers.GeohashEncode(tc.latitude, tc.longitude, 3))
+		})
+	}
+}
+
+// TestHeatmapMatchesSeededPositions тестирует, что количество водителей в ячейках совпадает
+// с исходными местоположениями на нескольких уровнях масштаба
+func (suite *HeatmapTestSuite) TestHeatmapMatchesSeededPositions() {
+	zoomLevels := []struct {
+		name      string
+		precision int
+	}{
+		{name: "City", precision: 4},
+		{name: "District", precision: 5},
+		{name: "Neighbourhood", precision: 6},
+		{name: "Street", precision: 7},
+	}
+
+	// Arrange
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	rng := rand.New(rand.NewSource(helpers.TestSeed(suite.T(), 42)))
+	points := fixtures.CreateRandomPointsAround(rng, center, 15, 60)
+
+	drivers := fixtures.CreateMultipleTestDrivers(len(points) + 1)
+	recordedAt := time.Now().Add(-time.Minute)
+	for i, point := range points {
+		suite.seedDriver(drivers[i], entities.StatusAvailable, point, recordedAt)
+	}
+
+	// Неактивный водитель не должен попадать в агрегаты
+	suite.seedDriver(drivers[len(points)], entities.StatusInactive, center, recordedAt)
+
+	for _, zoom := range zoomLevels {
+		suite.T().Run(zoom.name, func(t *testing.T) {
+			// Act
+			heatmap, response := suite.env.API.GetDriverHeatmap(zoom.precision)
+			require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+			// Assert
+			expected := helpers.ExpectedHeatmap(points, zoom.precision)
+			t.Logf("precision=%d: ячеек API=%d, эталон=%d", zoom.precision, len(heatmap.Cells), len(expected))
+
+			assert.Equal(t, zoom.precision, heatmap.Precision)
+			assert.Equal(t, len(points), heatmap.Total, "Итог должен совпадать с числом активных водителей")
+			for _, cell := range heatmap.Cells {
+				assert.Len(t, cell.Geohash, zoom.precision, "Ячейка %s другой точности", cell.Geohash)
+				assert.Positive(t, cell.Count, "Пустая ячейка %s в выдаче", cell.Geohash)
+			}
+			assert.Equal(t, expected, heatmap.Counts())
+		})
+	}
+}
+
+// TestHeatmapExcludesStaleLocations тестирует, что водитель с устаревшим местоположением не учитывается
+func (suite *HeatmapTestSuite) TestHeatmapExcludesStaleLocations() {
+	// Arrange
+	drivers := fixtures.CreateMultipleTestDrivers(2)
+	fresh := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	stale := fixtures.RoutePoint{Latitude: 59.9343, Longitude: 30.3351}
+	suite.seedDriver(drivers[0], entities.StatusAvailable, fresh, time.Now().Add(-time.Minute))
+	suite.seedDriver(drivers[1], entities.StatusAvailable, stale, time.Now().Add(-entities.LocationStaleAfter-time.Minute))
+
+	// Act
+	heatmap, response := suite.env.API.GetDriverHeatmap(5)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.Equal(suite.T(), 1, heatmap.Total)
+	assert.Equal(suite.T(), map[string]int{helpers.GeohashEncode(fresh.Latitude, fresh.Longitude, 5): 1}, heatmap.Counts())
+}
+
+// TestHeatmapInvalidPrecision тестирует отказ при точности вне диапазона 1..9
+func (suite *HeatmapTestSuite) TestHeatmapInvalidPrecision() {
+	for _, precision := range []int{0, 10} {
+		// Act
+		heatmap, response := suite.env.API.GetDriverHeatmap(precision)
+
+		// Assert
+		assert.Nil(suite.T(), heatmap)
+		assert.Equal(suite.T(), http.StatusBadRequest, response.StatusCode, "precision=%d", precision)
+		suite.env.API.AssertErrorResponse(response, "INVALID_REQUEST")
+	}
+}
+
+// seedDriver создает водителя в указанном статусе с местоположением в точке
+func (suite *HeatmapTestSuite) seedDriver(driver *entities.Driver, status entities.Status, point fixtures.RoutePoint, recordedAt time.Time) {
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+
+	// Обходим цепочку переходов статусов - для агрегатов важен только итоговый статус
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, createdDriver.ID, status))
+
+	location := entities.NewDriverLocation(createdDriver.ID, point.Latitude, point.Longitude, recordedAt)
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+}
+
+// TestHeatmapTestSuite запускает тестовый suite
+func TestHeatmapTestSuite(t *testing.T) {
+	suite.Run(t, new(HeatmapTestSuite))
+}
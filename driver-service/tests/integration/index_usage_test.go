@@ -0,0 +1,131 @@
+//go:build integration
+
+package integration
+
+import (
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/suite"
+)
+
+// Запросы репозитория местоположений, не вошедшие в проверки задержки
+const (
+	latestLocationQuery = `
+		SELECT * FROM driver_locations
+		WHERE driver_id = $1
+		ORDER BY recorded_at DESC
+		LIMIT 1`
+	deleteOldLocationsQuery = `
+		DELETE FROM driver_locations
+		WHERE recorded_at < $1`
+)
+
+// IndexUsageTestSuite проверяет планы запросов сервиса: запросы к driver_locations и drivers должны
+// использовать индексы, а не последовательное сканирование. Тест падает, если миграция удалила
+// или испортила индекс, на который опирается запрос.
+type IndexUsageTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	dataset *helpers.SeededDataset
+}
+
+// SetupSuite выполняется один раз перед всеми тестами: на пустых таблицах планировщик выбирает
+// последовательное сканирование, поэтому записывается набор данных, на котором индексы выгоднее
+func (suite *IndexUsageTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+
+	dataset := helpers.DefaultLargeDataset()
+	dataset.Drivers = 200
+	suite.dataset = suite.env.DB.SeedLargeDataset(suite.T(), dataset, helpers.TestSeed(suite.T(), 1709))
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *IndexUsageTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// TestQueriesUseIndexes тестирует, что запросы сервиса используют ожидаемые индексы
+func (suite *IndexUsageTestSuite) TestQueriesUseIndexes() {
+	driverID := suite.dataset.DriverIDs[0]
+
+	testCases := []struct {
+		name    string
+		query   string
+		args    []interface{}
+		table   string
+		indexes []string // подходит любой из индексов
+	}{
+		{
+			name:    "DriverByID",
+			query:   driverByIDQuery,
+			args:    []interface{}{driverID},
+			table:   "drivers",
+			indexes: []string{"drivers_pkey"},
+		},
+		{
+			name:    "LatestLocation",
+			query:   latestLocationQuery,
+			args:    []interface{}{driverID},
+			table:   "driver_locations",
+			indexes: []string{"idx_driver_locations_driver_time", "idx_driver_locations_recent"},
+		},
+		{
+			name:    "HistoryRange",
+			query:   historyRangeQuery,
+			args:    []interface{}{driverID, suite.dataset.To.Add(-time.Hour), suite.dataset.To},
+			table:   "driver_locations",
+			indexes: []string{"idx_driver_locations_driver_time", "idx_driver_locations_recent"},
+		},
+		{
+			// Очистка выполняется регулярно и удаляет малую часть строк
+			name:    "DeleteOld",
+			query:   deleteOldLocationsQuery,
+			args:    []interface{}{suite.dataset.From.Add(-time.Hour)},
+			table:   "driver_locations",
+			indexes: []string{"idx_driver_locations_recorded_at"},
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			plan := suite.env.DB.ExplainPlan(t, tc.query, tc.args...)
+
+			// Assert
+			helpers.AssertIndexUsed(t, plan, tc.table, tc.indexes...)
+		})
+	}
+}
+
+// TestNearbyUsesLatestLocationIndex тестирует, что поиск поблизости берет последнюю точку каждого водителя
+// по индексу (driver_id, recorded_at DESC), а не сканирует всю историю местоположений. Пространственный
+// индекс idx_driver_locations_spatial запросу не подходит: радиус проверяется по последней точке водителя,
+// а не по всем точкам истории.
+func (suite *IndexUsageTestSuite) TestNearbyUsesLatestLocationIndex() {
+	// Act
+	plan := suite.env.DB.ExplainPlan(suite.T(), nearbyQuery,
+		suite.dataset.Longitude, suite.dataset.Latitude, latencyNearbyRadius, 50,
+		time.Now().Add(-entities.LocationStaleAfter))
+
+	// Assert
+	helpers.AssertIndexUsed(suite.T(), plan, "driver_locations", "idx_driver_locations_driver_time")
+}
+
+// TestIndexesValid тестирует, что после миграций не осталось неготовых индексов
+func (suite *IndexUsageTestSuite) TestIndexesValid() {
+	// Act
+	invalid := suite.env.DB.InvalidIndexes(suite.T())
+
+	// Assert
+	assert.Empty(suite.T(), invalid, "Индексы недоступны планировщику")
+}
+
+// TestIndexUsageTestSuite запускает тестовый suite
+func TestIndexUsageTestSuite(t *testing.T) {
+	suite.Run(t, new(IndexUsageTestSuite))
+}
@@ -0,0 +1,278 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// JobsTestSuite тестирует фоновые задачи, запущенные вручную через административный API:
+// результат каждой задачи и идемпотентность повторного запуска
+type JobsTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *JobsTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *JobsTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *JobsTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestListJobs тестирует список задач, доступных для ручного запуска
+func (suite *JobsTestSuite) TestListJobs() {
+	// Act
+	jobs, response := suite.env.API.ListJobs()
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	assert.ElementsMatch(suite.T(), []string{
+		services.JobAutoCloseShifts,
+		services.JobPruneLocations,
+		services.JobExpireDocuments,
+		services.JobMarkOffline,
+		services.JobExpireVerification,
+		services.JobDetectConnectionLoss,
+	}, jobs)
+}
+
+// TestUnknownJob тестирует запуск несуществующей задачи
+func (suite *JobsTestSuite) TestUnknownJob() {
+	// Act
+	result, response := suite.env.API.RunJob("rebuild_search_index")
+
+	// Assert
+	assert.Nil(suite.T(), result)
+	suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+	suite.env.API.AssertErrorResponse(response, "JOB_NOT_FOUND")
+}
+
+// TestAutoCloseShifts тестирует закрытие смен, открытых дольше максимальной длительности
+func (suite *JobsTestSuite) TestAutoCloseShifts() {
+	// Arrange
+	now := time.Now()
+	onShiftDriver := suite.createDriver(entities.StatusOnShift)
+	orphan := suite.insertShift(onShiftDriver, now.Add(-helpers.TestMaxShiftDuration-2*time.Hour), 7, 63.4, 5100)
+
+	// Водитель уже ушел с линии, а смена осталась открытой
+	inactiveDriver := suite.createDriver(entities.StatusInactive)
+	inactiveOrphan := suite.insertShift(inactiveDriver, now.Add(-3*helpers.TestMaxShiftDuration), 0, 0, 0)
+
+	workingDriver := suite.createDriver(entities.StatusOnShift)
+	fresh := suite.insertShift(workingDriver, now.Add(-time.Hour), 2, 14.2, 900)
+
+	// Act
+	result, response := suite.env.API.RunJob(services.JobAutoCloseShifts)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.Equal(suite.T(), services.JobAutoCloseShifts, result.Job)
+	assert.Equal(suite.T(), 2, result.Affected)
+	assert.False(suite.T(), result.FinishedAt.Before(result.StartedAt))
+
+	for _, shift := range []*entities.DriverShift{orphan, inactiveOrphan} {
+		stored := suite.env.DB.GetShift(suite.T(), shift.ID)
+		assert.Equal(suite.T(), entities.ShiftStatusCompleted, stored.Status)
+		require.NotNil(suite.T(), stored.EndTime)
+		assert.WithinDuration(suite.T(), shift.StartTime.Add(helpers.TestMaxShiftDuration), *stored.EndTime, time.Second,
+			"Смена закрывается моментом истечения максимальной длительности")
+
+		// Итоги смены не теряются при закрытии
+		assert.Equal(suite.T(), shift.TotalTrips, stored.TotalTrips)
+		assert.InDelta(suite.T(), shift.TotalDistance, stored.TotalDistance, 0.01)
+		assert.InDelta(suite.T(), shift.TotalEarnings, stored.TotalEarnings, 0.01)
+
+		events := suite.env.Events.EventsForDriver(shift.DriverID, "shift.auto_closed")
+		require.Len(suite.T(), events, 1)
+		assert.Equal(suite.T(), shift.ID.String(), events[0].DataMap()["shift_id"])
+	}
+
+	assert.True(suite.T(), suite.env.DB.GetShift(suite.T(), fresh.ID).IsActive(), "Свежая смена не закрывается")
+	suite.assertDriverStatus(onShiftDriver, entities.StatusAvailable)
+	suite.assertDriverStatus(inactiveDriver, entities.StatusInactive)
+	suite.assertDriverStatus(workingDriver, entities.StatusOnShift)
+
+	suite.T().Run("SecondRunIsNoop", func(t *testing.T) {
+		closedAt := suite.env.DB.GetShift(t, orphan.ID).EndTime
+
+		result, response := suite.env.API.RunJob(services.JobAutoCloseShifts)
+
+		require.Equal(t, http.StatusOK, response.StatusCode)
+		assert.Equal(t, 0, result.Affected)
+		assert.Equal(t, closedAt.UTC(), suite.env.DB.GetShift(t, orphan.ID).EndTime.UTC())
+		assert.Len(t, suite.env.Events.EventsOfType("shift.auto_closed"), 2, "Повторный запуск не публикует событий")
+		assert.True(t, suite.env.DB.GetShift(t, fresh.ID).IsActive())
+	})
+}
+
+// TestPruneLocations тестирует удаление истории местоположений старше срока хранения
+func (suite *JobsTestSuite) TestPruneLocations() {
+	// Arrange
+	driverID := suite.createDriver(entities.StatusAvailable)
+	now := time.Now()
+
+	var stale, recent []*entities.DriverLocation
+	for i := 0; i < 3; i++ {
+		location := fixtures.CreateTestLocation(driverID)
+		location.RecordedAt = now.Add(-helpers.TestLocationRetention - time.Duration(i+1)*24*time.Hour)
+		require.NoError(suite.T(), suite.env.LocationRepo.Create(suite.env.Ctx, location))
+		stale = append(stale, location)
+	}
+	for i := 0; i < 2; i++ {
+		location := fixtures.CreateTestLocation(driverID)
+		location.RecordedAt = now.Add(-time.Duration(i+1) * time.Hour)
+		require.NoError(suite.T(), suite.env.LocationRepo.Create(suite.env.Ctx, location))
+		recent = append(recent, location)
+	}
+
+	// Act
+	result, response := suite.env.API.RunJob(services.JobPruneLocations)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.Equal(suite.T(), len(stale), result.Affected)
+	for _, location := range stale {
+		_, err := suite.env.LocationRepo.GetByID(suite.env.Ctx, location.ID)
+		assert.ErrorIs(suite.T(), err, entities.ErrLocationNotFound)
+	}
+	suite.assertLocationsExist(suite.T(), recent)
+
+	suite.T().Run("SecondRunIsNoop", func(t *testing.T) {
+		result, response := suite.env.API.RunJob(services.JobPruneLocations)
+
+		require.Equal(t, http.StatusOK, response.StatusCode)
+		assert.Equal(t, 0, result.Affected)
+		suite.assertLocationsExist(t, recent)
+	})
+}
+
+// TestExpireDocuments тестирует пометку истекших документов
+func (suite *JobsTestSuite) TestExpireDocuments() {
+	// Arrange
+	now := time.Now()
+	driverID := suite.createDriver(entities.StatusAvailable)
+	expiredLicense := suite.createDocument(driverID, entities.DocumentTypeDriverLicense, now.AddDate(0, 0, -1), entities.VerificationStatusVerified)
+	expiredPending := suite.createDocument(driverID, entities.DocumentTypeMedicalCert, now.AddDate(0, 0, -10), entities.VerificationStatusPending)
+	validInsurance := suite.createDocument(driverID, entities.DocumentTypeInsurance, now.AddDate(0, 0, 30), entities.VerificationStatusVerified)
+	rejected := suite.createDocument(driverID, entities.DocumentTypeTaxiPermit, now.AddDate(0, 0, -5), entities.VerificationStatusRejected)
+
+	expected := map[uuid.UUID]entities.VerificationStatus{
+		expiredLicense.ID: entities.VerificationStatusExpired,
+		expiredPending.ID: entities.VerificationStatusExpired,
+		validInsurance.ID: entities.VerificationStatusVerified,
+		rejected.ID:       entities.VerificationStatusRejected,
+	}
+
+	// Act
+	result, response := suite.env.API.RunJob(services.JobExpireDocuments)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	assert.Equal(suite.T(), 2, result.Affected)
+	suite.assertDocumentStatuses(suite.T(), expected)
+
+	suite.T().Run("SecondRunIsNoop", func(t *testing.T) {
+		expiredAt := suite.getDocument(t, expiredLicense.ID).UpdatedAt
+
+		result, response := suite.env.API.RunJob(services.JobExpireDocuments)
+
+		require.Equal(t, http.StatusOK, response.StatusCode)
+		assert.Equal(t, 0, result.Affected)
+		suite.assertDocumentStatuses(t, expected)
+		assert.True(t, expiredAt.Equal(suite.getDocument(t, expiredLicense.ID).UpdatedAt),
+			"Уже истекший документ не обновляется повторно")
+	})
+}
+
+// createDriver создает водителя с уникальными контактами в статусе status
+func (suite *JobsTestSuite) createDriver(status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900666%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("jobs.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("JB%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	return created.ID
+}
+
+// insertShift сохраняет активную смену водителя с накопленными итогами
+func (suite *JobsTestSuite) insertShift(driverID uuid.UUID, start time.Time, trips int, distance, earnings float64) *entities.DriverShift {
+	shift := fixtures.CreateTestShift(driverID)
+	shift.StartTime = start
+	shift.TotalTrips = trips
+	shift.TotalDistance = distance
+	shift.TotalEarnings = earnings
+
+	suite.env.DB.InsertShift(suite.T(), shift)
+	return shift
+}
+
+// createDocument сохраняет документ водителя с заданным сроком действия и статусом
+func (suite *JobsTestSuite) createDocument(driverID uuid.UUID, docType entities.DocumentType, expiry time.Time, status entities.VerificationStatus) *entities.DriverDocument {
+	document := fixtures.CreateTestDocument(driverID, docType)
+	document.ExpiryDate = expiry
+	document.Status = status
+
+	require.NoError(suite.T(), suite.env.DocumentRepo.Create(suite.env.Ctx, document))
+	return document
+}
+
+// getDocument читает документ из репозитория
+func (suite *JobsTestSuite) getDocument(t *testing.T, documentID uuid.UUID) *entities.DriverDocument {
+	document, err := suite.env.DocumentRepo.GetByID(suite.env.Ctx, documentID)
+	require.NoError(t, err)
+	return document
+}
+
+// assertDocumentStatuses проверяет статусы документов
+func (suite *JobsTestSuite) assertDocumentStatuses(t *testing.T, expected map[uuid.UUID]entities.VerificationStatus) {
+	for documentID, status := range expected {
+		assert.Equal(t, status, suite.getDocument(t, documentID).Status, "Документ %s", documentID)
+	}
+}
+
+// assertLocationsExist проверяет, что местоположения не удалены
+func (suite *JobsTestSuite) assertLocationsExist(t *testing.T, locations []*entities.DriverLocation) {
+	for _, location := range locations {
+		_, err := suite.env.LocationRepo.GetByID(suite.env.Ctx, location.ID)
+		assert.NoError(t, err, "Местоположение %s", location.ID)
+	}
+}
+
+// assertDriverStatus проверяет статус водителя
+func (suite *JobsTestSuite) assertDriverStatus(driverID uuid.UUID, expected entities.Status) {
+	driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), expected, driver.Status)
+}
+
+// TestJobsTestSuite запускает тестовый suite
+func TestJobsTestSuite(t *testing.T) {
+	suite.Run(t, new(JobsTestSuite))
+}
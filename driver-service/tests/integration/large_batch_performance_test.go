@@ -0,0 +1,197 @@
+//go:build integration
+
+package integration
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	httpServer "driver-service/internal/interfaces/http"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// largeBatchSize количество точек в пакете, который мобильное приложение накапливает без связи
+const largeBatchSize = 10000
+
+// LargeBatchPerformanceTestSuite тестовый suite для больших пакетов координат, в том числе сжатых gzip
+type LargeBatchPerformanceTestSuite struct {
+	suite.Suite
+	env            *helpers.TestEnvironment
+	perfHelper     *helpers.PerformanceTestHelper
+	healthBaseline helpers.ServiceHealth
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *LargeBatchPerformanceTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.perfHelper = helpers.NewPerformanceTestHelper(suite.T(), suite.env.DriverService, suite.env.LocationService)
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *LargeBatchPerformanceTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *LargeBatchPerformanceTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.healthBaseline = helpers.CaptureServiceHealth(suite.env.DB)
+}
+
+// TearDownTest выполняется после каждого теста
+func (suite *LargeBatchPerformanceTestSuite) TearDownTest() {
+	helpers.AssertServiceHealthRecovered(suite.T(), suite.env.DB, suite.healthBaseline, 10*time.Second)
+}
+
+// TestLargeBatchAccepted тестирует прием пакета из 10 000 точек без сжатия и со сжатием gzip
+func (suite *LargeBatchPerformanceTestSuite) TestLargeBatchAccepted() {
+	testCases := []struct {
+		name            string
+		contentEncoding string
+	}{
+		{"Uncompressed", ""},
+		{"Gzip", "gzip"},
+	}
+
+	drivers := fixtures.CreateMultipleTestDrivers(len(testCases))
+	for i, tc := range testCases {
+		driver := drivers[i]
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			_, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+			require.NoError(t, err)
+
+			// Act
+			response := suite.postBatch(driver.ID, suite.largeBatch(largeBatchSize), tc.contentEncoding)
+
+			// Assert
+			require.Equal(t, http.StatusOK, response.StatusCode, "%.500s", string(response.Body))
+
+			var result map[string]interface{}
+			require.NoError(t, json.Unmarshal(response.Body, &result))
+			assert.Equal(t, float64(largeBatchSize), result["count"])
+			assert.Equal(t, largeBatchSize, suite.storedLocations(driver.ID), "Все точки пакета должны быть сохранены")
+		})
+	}
+}
+
+// TestLargeBatchBodyLimit тестирует прием пакета в пределах документированного лимита и 413 при его превышении
+func (suite *LargeBatchPerformanceTestSuite) TestLargeBatchBodyLimit() {
+	// Arrange
+	operations, err := helpers.LoadOpenAPIOperations()
+	require.NoError(suite.T(), err)
+
+	var limit int64
+	for _, operation := range operations {
+		if operation.OperationID == "batchUpdateLocations" {
+			limit = operation.MaxBodyBytes
+		}
+	}
+	require.Equal(suite.T(), int64(httpServer.MaxBatchBodyBytes), limit,
+		"x-max-body-bytes в api/openapi/driver-api.yaml должен совпадать с лимитом сервиса")
+
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+
+	// Размер точки оценивается по сериализованному пакету, чтобы подобрать пакеты по обе стороны лимита
+	sample, err := json.Marshal(suite.largeBatch(100))
+	require.NoError(suite.T(), err)
+	pointsAtLimit := int(limit * 100 / int64(len(sample)))
+
+	// Act
+	withinLimit := suite.postBatch(driver.ID, suite.largeBatch(pointsAtLimit*9/10), "")
+	overLimit := suite.postBatch(driver.ID, suite.largeBatch(pointsAtLimit*2), "gzip")
+
+	// Assert
+	assert.Equal(suite.T(), http.StatusOK, withinLimit.StatusCode, "%.500s", string(withinLimit.Body))
+	// Лимит относится к распакованному телу: сжатый пакет не должен обходить его
+	assert.Equal(suite.T(), http.StatusRequestEntityTooLarge, overLimit.StatusCode, "%.500s", string(overLimit.Body))
+	suite.env.API.AssertErrorResponse(overLimit, "PAYLOAD_TOO_LARGE")
+}
+
+// TestUnsupportedContentEncoding тестирует, что тело в неподдерживаемой кодировке отклоняется, а не разбирается как JSON
+func (suite *LargeBatchPerformanceTestSuite) TestUnsupportedContentEncoding() {
+	// Arrange
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+
+	// Act
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method:  http.MethodPost,
+		URL:     suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations/batch", driver.ID)),
+		Body:    suite.largeBatch(10),
+		Headers: map[string]string{"Content-Encoding": "br"},
+	})
+
+	// Assert
+	assert.Equal(suite.T(), http.StatusUnsupportedMediaType, response.StatusCode, string(response.Body))
+	assert.Zero(suite.T(), suite.storedLocations(driver.ID))
+}
+
+// TestLargeBatchIngestionPerformance тестирует время приема пакета из 10 000 точек
+func (suite *LargeBatchPerformanceTestSuite) TestLargeBatchIngestionPerformance() {
+	// Arrange
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+
+	batch := suite.largeBatch(largeBatchSize)
+
+	// Act
+	result := suite.perfHelper.BenchmarkAPIRequest(fmt.Sprintf("Batch of %d locations via API", largeBatchSize), 3, http.StatusOK,
+		func() *helpers.APIResponse {
+			return suite.postBatch(driver.ID, batch, "")
+		})
+
+	// Assert
+	suite.perfHelper.AssertPerformanceThresholds(result, 10*time.Second, 0.1)
+}
+
+// largeBatch создает пакет из count точек с интервалом в секунду, заканчивающийся текущим моментом
+func (suite *LargeBatchPerformanceTestSuite) largeBatch(count int) map[string]interface{} {
+	batch := helpers.CreateBatchLocationRequest(count)
+
+	start := time.Now().Add(-time.Duration(count) * time.Second).Unix()
+	for i, location := range batch["locations"].([]map[string]interface{}) {
+		location["timestamp"] = start + int64(i)
+		location["accuracy"] = 5.0
+		location["bearing"] = float64(i % 360)
+	}
+	return batch
+}
+
+// postBatch отправляет пакет координат водителя
+func (suite *LargeBatchPerformanceTestSuite) postBatch(driverID uuid.UUID, batch map[string]interface{}, contentEncoding string) *helpers.APIResponse {
+	return suite.env.API.MakeRequest(helpers.APIRequest{
+		Method:          http.MethodPost,
+		URL:             suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations/batch", driverID)),
+		Body:            batch,
+		ContentEncoding: contentEncoding,
+	})
+}
+
+// storedLocations возвращает количество сохраненных точек водителя
+func (suite *LargeBatchPerformanceTestSuite) storedLocations(driverID uuid.UUID) int {
+	var count int
+	err := suite.env.DB.DB.GetContext(suite.env.Ctx, &count, "SELECT COUNT(*) FROM driver_locations WHERE driver_id = $1", driverID)
+	require.NoError(suite.T(), err)
+	return count
+}
+
+// TestLargeBatchPerformanceTestSuite запускает тестовый suite
+func TestLargeBatchPerformanceTestSuite(t *testing.T) {
+	// Пропускаем performance тесты в быстром режиме
+	if testing.Short() {
+		t.Skip("Skipping performance tests in short mode")
+	}
+
+	suite.Run(t, new(LargeBatchPerformanceTestSuite))
+}
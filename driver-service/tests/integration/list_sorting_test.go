@@ -0,0 +1,326 @@
+//go:build integration
+
+package integration
+
+import (
+	"bytes"
+	"fmt"
+	"net/http"
+	"sort"
+	"strconv"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры набора водителей для проверок сортировки: значения полей сортировки повторяются,
+// чтобы на каждой странице были водители с одинаковым значением
+const sortingDrivers = 12
+
+var (
+	sortingRatings = []float64{4.5, 4.8, 5.0}
+	sortingTrips   = []int{0, 10, 25, 10}
+	sortingPages   = []int{1, 3, 5, sortingDrivers} // размеры страниц обхода
+)
+
+// sortedDriver значения полей сортировки водителя набора
+type sortedDriver struct {
+	id        uuid.UUID
+	rating    float64
+	trips     int
+	createdAt time.Time
+}
+
+// ListSortingTestSuite тестирует порядок списков: каждое поле сортировки списка водителей дает правильный
+// и полный порядок (при равных значениях - по id), поэтому обход страницами любого размера возвращает
+// каждого водителя ровно один раз; водители поблизости упорядочены по расстоянию
+type ListSortingTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных контактов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *ListSortingTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *ListSortingTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *ListSortingTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestDriverListSortKeys тестирует каждое поле и направление сортировки списка водителей
+func (suite *ListSortingTestSuite) TestDriverListSortKeys() {
+	// Arrange
+	drivers := suite.seedDrivers()
+
+	testCases := []struct {
+		name          string
+		sortBy        string
+		sortDirection string
+		compare       func(a, b sortedDriver) int // сравнение по полю сортировки без учета направления
+	}{
+		{name: "default", compare: compareCreatedAt, sortDirection: "desc"},
+		{name: "created_at/asc", sortBy: "created_at", sortDirection: "asc", compare: compareCreatedAt},
+		{name: "created_at/desc", sortBy: "created_at", sortDirection: "desc", compare: compareCreatedAt},
+		{name: "current_rating/asc", sortBy: "current_rating", sortDirection: "asc", compare: compareRating},
+		{name: "current_rating/desc", sortBy: "current_rating", sortDirection: "desc", compare: compareRating},
+		{name: "total_trips/asc", sortBy: "total_trips", sortDirection: "asc", compare: compareTrips},
+		{name: "total_trips/desc", sortBy: "total_trips", sortDirection: "desc", compare: compareTrips},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			expected := expectedSortOrder(drivers, tc.compare, tc.sortDirection == "desc")
+			filter := helpers.DriverListFilter{SortBy: tc.sortBy}
+			if tc.sortBy != "" {
+				filter.SortDirection = tc.sortDirection
+			}
+
+			for _, pageSize := range sortingPages {
+				// Act
+				listed, total := suite.env.API.ListAllDrivers(filter, pageSize)
+
+				// Assert
+				assert.Equal(t, sortingDrivers, total, "pageSize %d", pageSize)
+				actual := make([]uuid.UUID, len(listed))
+				for i, driver := range listed {
+					actual[i] = driver.ID
+				}
+				assert.Equal(t, expected, actual, "pageSize %d", pageSize)
+			}
+		})
+	}
+}
+
+// TestInvalidSort тестирует отклонение неизвестного поля и направления сортировки: sort_by
+// не должен попадать в SQL как есть
+func (suite *ListSortingTestSuite) TestInvalidSort() {
+	// Arrange
+	suite.seedDrivers()
+
+	testCases := []struct {
+		name  string
+		query map[string]string
+	}{
+		{name: "UnknownField", query: map[string]string{"sort_by": "first_name"}},
+		{name: "SQLInjection", query: map[string]string{"sort_by": "created_at; DROP TABLE drivers"}},
+		{name: "Expression", query: map[string]string{"sort_by": "random()"}},
+		{name: "UnknownDirection", query: map[string]string{"sort_by": "created_at", "sort_direction": "sideways"}},
+	}
+
+	for _, tc := range testCases {
+		suite.Run(tc.name, func() {
+			// Act
+			response := suite.env.API.MakeRequest(helpers.APIRequest{
+				Method:      http.MethodGet,
+				URL:         suite.env.API.APIPath("/drivers"),
+				QueryParams: tc.query,
+			})
+
+			// Assert
+			suite.env.API.AssertStatusCode(response, http.StatusBadRequest)
+			suite.env.API.AssertErrorResponse(response, "INVALID_REQUEST")
+		})
+	}
+
+	_, total := suite.env.API.ListAllDrivers(helpers.DriverListFilter{}, sortingDrivers)
+	assert.Equal(suite.T(), sortingDrivers, total, "Таблица водителей не изменилась")
+}
+
+// TestNearbySortedByDistance тестирует, что водители поблизости упорядочены по расстоянию, при равном
+// расстоянии - по driver_id, а выдача с меньшим limit совпадает с началом выдачи с большим
+func (suite *ListSortingTestSuite) TestNearbySortedByDistance() {
+	// Arrange - по три водителя в одной точке на каждом расстоянии, ближние создаются последними
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	offsets := []float64{0.02, 0.01, 0.005, 0.001} // смещение по широте, ~110 м на 0.001
+	for _, offset := range offsets {
+		for i := 0; i < 3; i++ {
+			suite.createNearbyDriver(fixtures.RoutePoint{Latitude: center.Latitude + offset, Longitude: center.Longitude})
+		}
+	}
+	total := len(offsets) * 3
+
+	// Act
+	all := suite.nearby(center, total)
+
+	// Assert
+	require.Len(suite.T(), all, total)
+	for i := 1; i < len(all); i++ {
+		previous, current := all[i-1], all[i]
+		require.LessOrEqual(suite.T(), previous.Distance, current.Distance, "Позиция %d", i)
+		if previous.Latitude == current.Latitude && previous.Longitude == current.Longitude {
+			assert.Negative(suite.T(), bytes.Compare(previous.DriverID[:], current.DriverID[:]),
+				"Водители в одной точке упорядочены по driver_id, позиция %d", i)
+		}
+	}
+
+	for limit := 1; limit < total; limit++ {
+		// Act
+		page := suite.nearby(center, limit)
+
+		// Assert
+		assert.Equal(suite.T(), nearbyIDs(all[:limit]), nearbyIDs(page), "limit %d", limit)
+	}
+}
+
+// TestNearbyUsesLatestLocation тестирует, что радиус проверяется по последней точке водителя в километрах:
+// водитель, уехавший из радиуса, не находится по старой точке внутри него, а водитель в 7 км
+// не попадает в радиус 5 км (5 миль - около 8 км)
+func (suite *ListSortingTestSuite) TestNearbyUsesLatestLocation() {
+	// Arrange
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	inside := suite.createNearbyDriver(fixtures.RoutePoint{Latitude: center.Latitude + 0.01, Longitude: center.Longitude})
+	outsideKm := suite.createNearbyDriver(fixtures.RoutePoint{Latitude: center.Latitude + 0.063, Longitude: center.Longitude})
+
+	// Старая точка уехавшего водителя в центре, последняя - в 11 км
+	left := suite.createNearbyDriver(center)
+	latest := entities.NewDriverLocation(left, center.Latitude+0.1, center.Longitude, time.Now())
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, latest))
+
+	// Act
+	drivers := suite.nearby(center, 10)
+
+	// Assert
+	require.Equal(suite.T(), []uuid.UUID{inside}, nearbyIDs(drivers),
+		"Ожидается только водитель в радиусе (уехавший %s, в 7 км %s)", left, outsideKm)
+	expected := helpers.HaversineKm(center.Latitude, center.Longitude, drivers[0].Latitude, drivers[0].Longitude)
+	assert.InDelta(suite.T(), expected, drivers[0].Distance, 0.01, "Расстояние в километрах")
+}
+
+// seedDrivers создает sortingDrivers водителей; рейтинг, число поездок и время регистрации повторяются
+// у нескольких водителей и записываются напрямую в БД
+func (suite *ListSortingTestSuite) seedDrivers() []sortedDriver {
+	base := time.Now().Truncate(time.Second)
+	drivers := make([]sortedDriver, sortingDrivers)
+	for i := range drivers {
+		id := suite.createDriver()
+		drivers[i] = sortedDriver{
+			id:        id,
+			rating:    sortingRatings[i%len(sortingRatings)],
+			trips:     sortingTrips[i%len(sortingTrips)],
+			createdAt: base.Add(-time.Duration(i/3) * time.Hour),
+		}
+
+		_, err := suite.env.DB.ExecContext(suite.env.Ctx,
+			"UPDATE drivers SET current_rating = $1, total_trips = $2, created_at = $3 WHERE id = $4",
+			drivers[i].rating, drivers[i].trips, drivers[i].createdAt, id)
+		require.NoError(suite.T(), err)
+	}
+	return drivers
+}
+
+// createDriver создает водителя с уникальными контактами
+func (suite *ListSortingTestSuite) createDriver() uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900739%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("sorting.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("ST%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	return created.ID
+}
+
+// createNearbyDriver создает доступного водителя в точке point
+func (suite *ListSortingTestSuite) createNearbyDriver(point fixtures.RoutePoint) uuid.UUID {
+	id := suite.createDriver()
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, id, entities.StatusAvailable))
+
+	location := entities.NewDriverLocation(id, point.Latitude, point.Longitude, time.Now().Add(-time.Minute))
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+	return id
+}
+
+// nearby выполняет поиск водителей поблизости в радиусе 5 км
+func (suite *ListSortingTestSuite) nearby(center fixtures.RoutePoint, limit int) []*httpHandlers.NearbyDriverInfo {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath("/locations/nearby"),
+		QueryParams: map[string]string{
+			"latitude":  strconv.FormatFloat(center.Latitude, 'f', -1, 64),
+			"longitude": strconv.FormatFloat(center.Longitude, 'f', -1, 64),
+			"radius_km": "5",
+			"limit":     strconv.Itoa(limit),
+		},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	var nearby httpHandlers.NearbyDriversResponse
+	suite.env.API.UnmarshalResponse(response, &nearby)
+	return nearby.Drivers
+}
+
+// nearbyIDs возвращает ID водителей выдачи поиска поблизости по порядку
+func nearbyIDs(drivers []*httpHandlers.NearbyDriverInfo) []uuid.UUID {
+	ids := make([]uuid.UUID, len(drivers))
+	for i, driver := range drivers {
+		ids[i] = driver.DriverID
+	}
+	return ids
+}
+
+// expectedSortOrder возвращает ID водителей в порядке сортировки по compare, при равенстве - по id
+// (в том же направлении, как в запросе к БД; uuid в PostgreSQL сравниваются побайтно)
+func expectedSortOrder(drivers []sortedDriver, compare func(a, b sortedDriver) int, descending bool) []uuid.UUID {
+	sorted := append([]sortedDriver(nil), drivers...)
+	sort.Slice(sorted, func(i, j int) bool {
+		result := compare(sorted[i], sorted[j])
+		if result == 0 {
+			result = bytes.Compare(sorted[i].id[:], sorted[j].id[:])
+		}
+		if descending {
+			return result > 0
+		}
+		return result < 0
+	})
+
+	ids := make([]uuid.UUID, len(sorted))
+	for i, driver := range sorted {
+		ids[i] = driver.id
+	}
+	return ids
+}
+
+// compareCreatedAt сравнивает водителей по времени регистрации
+func compareCreatedAt(a, b sortedDriver) int {
+	return a.createdAt.Compare(b.createdAt)
+}
+
+// compareRating сравнивает водителей по рейтингу
+func compareRating(a, b sortedDriver) int {
+	switch {
+	case a.rating < b.rating:
+		return -1
+	case a.rating > b.rating:
+		return 1
+	}
+	return 0
+}
+
+// compareTrips сравнивает водителей по числу поездок
+func compareTrips(a, b sortedDriver) int {
+	return a.trips - b.trips
+}
+
+// TestListSortingTestSuite запускает тестовый suite
+func TestListSortingTestSuite(t *testing.T) {
+	suite.Run(t, new(ListSortingTestSuite))
+}
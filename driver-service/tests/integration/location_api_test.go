@@ -171,6 +171,67 @@ func (suite *LocationAPITestSuite) TestBatchUpdateLocationsAPI() {
 	assert.Equal(suite.T(), float64(3), response["count"])
 }
 
+// TestBatchUpdateLocationsMessagePack тестирует, что пакет в MessagePack сохраняется так же, как тот же пакет в JSON
+func (suite *LocationAPITestSuite) TestBatchUpdateLocationsMessagePack() {
+	// Arrange
+	msgpackDriver, err := suite.driverService.CreateDriver(suite.ctx, fixtures.CreateMultipleTestDrivers(1)[0])
+	require.NoError(suite.T(), err)
+
+	batch := helpers.CreateBatchLocationRequest(3)
+	baseTime := time.Now().Add(-time.Hour).Unix()
+	for i, location := range batch["locations"].([]map[string]interface{}) {
+		location["timestamp"] = baseTime + int64(i)*60
+		location["accuracy"] = 5.0
+	}
+
+	apiHelper := helpers.NewAPITestHelper(suite.router, suite.T())
+	send := func(driverID uuid.UUID, contentType string) *helpers.APIResponse {
+		return apiHelper.MakeRequest(helpers.APIRequest{
+			Method:      http.MethodPost,
+			URL:         apiHelper.APIPath(fmt.Sprintf("/drivers/%s/locations/batch", driverID)),
+			Body:        batch,
+			ContentType: contentType,
+		})
+	}
+
+	// Act
+	jsonResponse := send(suite.testDriverID, "application/json")
+	msgpackResponse := send(msgpackDriver.ID, helpers.MIMEMsgPack)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, jsonResponse.StatusCode, string(jsonResponse.Body))
+	require.Equal(suite.T(), http.StatusOK, msgpackResponse.StatusCode, string(msgpackResponse.Body))
+	assert.JSONEq(suite.T(), string(jsonResponse.Body), string(msgpackResponse.Body))
+
+	from, to := time.Unix(baseTime, 0).Add(-time.Minute), time.Now()
+	jsonHistory, err := suite.locationService.GetLocationHistory(suite.ctx, suite.testDriverID, from, to)
+	require.NoError(suite.T(), err)
+	msgpackHistory, err := suite.locationService.GetLocationHistory(suite.ctx, msgpackDriver.ID, from, to)
+	require.NoError(suite.T(), err)
+
+	require.Len(suite.T(), msgpackHistory, len(jsonHistory))
+	require.Len(suite.T(), jsonHistory, 3)
+	for i := range jsonHistory {
+		expected, actual := jsonHistory[i], msgpackHistory[i]
+		assert.Equal(suite.T(), expected.Latitude, actual.Latitude)
+		assert.Equal(suite.T(), expected.Longitude, actual.Longitude)
+		assert.Equal(suite.T(), expected.Speed, actual.Speed)
+		assert.Equal(suite.T(), expected.Accuracy, actual.Accuracy)
+		assert.True(suite.T(), expected.RecordedAt.Equal(actual.RecordedAt), "recorded_at: %v != %v", expected.RecordedAt, actual.RecordedAt)
+	}
+
+	suite.T().Run("invalid MessagePack body", func(t *testing.T) {
+		response := apiHelper.MakeRequest(helpers.APIRequest{
+			Method:  http.MethodPost,
+			URL:     apiHelper.APIPath(fmt.Sprintf("/drivers/%s/locations/batch", msgpackDriver.ID)),
+			Body:    "not a batch",
+			Headers: map[string]string{"Content-Type": helpers.MIMEMsgPack},
+		})
+
+		assert.Equal(t, http.StatusBadRequest, response.StatusCode)
+	})
+}
+
 // TestGetCurrentLocationAPI тестирует получение текущего местоположения
 func (suite *LocationAPITestSuite) TestGetCurrentLocationAPI() {
 	// Arrange - создаем несколько местоположений
@@ -277,11 +338,8 @@ func (suite *LocationAPITestSuite) TestGetNearbyDriversAPI() {
 	assert.Len(suite.T(), response.Drivers, 2) // Первые два водителя в радиусе 5км
 	assert.Equal(suite.T(), 2, response.Count)
 
-	// Проверяем, что расстояния рассчитаны
-	for _, driver := range response.Drivers {
-		assert.Greater(suite.T(), driver.Distance, 0.0)
-		assert.Less(suite.T(), driver.Distance, 5.0) // В пределах радиуса
-	}
+	// Проверяем, что расстояния рассчитаны и находятся в пределах радиуса
+	helpers.AssertJSONPath(suite.T(), w.Body.Bytes(), "$.drivers[*].distance_km", helpers.AllBetween(0, 5.0))
 }
 
 // TestLocationAPIValidation тестирует валидацию координат
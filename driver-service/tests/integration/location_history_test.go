@@ -0,0 +1,230 @@
+//go:build integration
+
+package integration
+
+import (
+	"net/http"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// LocationHistoryTestSuite тестовый suite для прореживания и пагинации истории местоположений
+type LocationHistoryTestSuite struct {
+	suite.Suite
+	env          *helpers.TestEnvironment
+	testDriverID uuid.UUID
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *LocationHistoryTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *LocationHistoryTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *LocationHistoryTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	suite.testDriverID = driver.ID
+}
+
+// TestHistoryTimeRange тестирует выборку истории в заданном временном диапазоне
+func (suite *LocationHistoryTestSuite) TestHistoryTimeRange() {
+	// Arrange
+	history := suite.seedHistory(60, 10*time.Second)
+	from := history[10].RecordedAt
+	to := history[29].RecordedAt
+
+	// Act
+	page, response := suite.env.API.GetLocationHistory(suite.testDriverID, helpers.HistoryQuery{From: from, To: to})
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	require.NotNil(suite.T(), page)
+
+	// Границы передаются в RFC3339 (с точностью до секунды), поэтому допускаем погрешность в одну точку
+	assert.InDelta(suite.T(), 20, page.Count, 1)
+	assert.Equal(suite.T(), len(page.Locations), page.Count)
+	for _, location := range page.Locations {
+		assert.False(suite.T(), location.RecordedAt.Before(from.Truncate(time.Second)))
+		assert.False(suite.T(), location.RecordedAt.After(to))
+	}
+}
+
+// TestHistoryIntervalDownsampling тестирует прореживание истории параметром interval
+func (suite *LocationHistoryTestSuite) TestHistoryIntervalDownsampling() {
+	// Arrange
+	// 60 точек каждые 10 секунд - 10 минут трека
+	history := suite.seedHistory(60, 10*time.Second)
+	from := history[0].RecordedAt.Add(-time.Second)
+	to := history[len(history)-1].RecordedAt.Add(time.Second)
+
+	// Act
+	page, response := suite.env.API.GetLocationHistory(suite.testDriverID, helpers.HistoryQuery{
+		From:     from,
+		To:       to,
+		Interval: "1m",
+	})
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	require.NotNil(suite.T(), page)
+	assert.Equal(suite.T(), "1m", page.Interval)
+	assert.Empty(suite.T(), page.NextCursor, "Без limit история возвращается целиком")
+
+	// В каждой минутной корзине трека остается ровно одна точка
+	expectedBuckets := make(map[int64]bool)
+	for _, source := range history {
+		expectedBuckets[source.RecordedAt.Truncate(time.Minute).Unix()] = true
+	}
+	assert.Equal(suite.T(), len(expectedBuckets), page.Count)
+	buckets := make(map[int64]bool)
+	for _, location := range page.Locations {
+		bucket := location.RecordedAt.Truncate(time.Minute).Unix()
+		assert.False(suite.T(), buckets[bucket], "Несколько точек в одной корзине %d", bucket)
+		buckets[bucket] = true
+
+		// Агрегированная точка должна лежать в пределах координат исходных точек своей корзины
+		minLat, maxLat := 90.0, -90.0
+		for _, source := range history {
+			if source.RecordedAt.Truncate(time.Minute).Unix() != bucket {
+				continue
+			}
+			if source.Latitude < minLat {
+				minLat = source.Latitude
+			}
+			if source.Latitude > maxLat {
+				maxLat = source.Latitude
+			}
+		}
+		assert.GreaterOrEqual(suite.T(), location.Latitude, minLat-1e-6)
+		assert.LessOrEqual(suite.T(), location.Latitude, maxLat+1e-6)
+	}
+}
+
+// TestHistoryCursorStableUnderConcurrentInserts тестирует стабильность курсора при параллельной записи
+func (suite *LocationHistoryTestSuite) TestHistoryCursorStableUnderConcurrentInserts() {
+	// Arrange
+	history := suite.seedHistory(50, 5*time.Second)
+	from := history[0].RecordedAt.Add(-time.Second)
+	to := time.Now().Add(time.Minute)
+
+	first, response := suite.env.API.GetLocationHistory(suite.testDriverID, helpers.HistoryQuery{From: from, To: to, Limit: 10})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	require.Len(suite.T(), first.Locations, 10)
+	require.NotEmpty(suite.T(), first.NextCursor, "Первая страница из 50 точек должна ссылаться на следующую")
+
+	// Параллельно дописываем новые точки, пока клиент листает страницы
+	var wg sync.WaitGroup
+	stop := make(chan struct{})
+	wg.Add(1)
+	go func() {
+		defer wg.Done()
+		for {
+			select {
+			case <-stop:
+				return
+			default:
+				location := fixtures.CreateTestLocation(suite.testDriverID)
+				location.RecordedAt = time.Now()
+				_ = suite.env.LocationService.UpdateLocation(suite.env.Ctx, location)
+				time.Sleep(10 * time.Millisecond)
+			}
+		}
+	}()
+
+	// Act
+	seen := make(map[uuid.UUID]bool)
+	var previous time.Time
+	page := first
+	for pages := 0; page != nil && pages < 20; pages++ {
+		assert.LessOrEqual(suite.T(), len(page.Locations), 10)
+		for _, location := range page.Locations {
+			assert.False(suite.T(), seen[location.ID], "Точка %s встречается на нескольких страницах", location.ID)
+			assert.False(suite.T(), location.RecordedAt.Before(previous), "Точки страниц идут по возрастанию времени")
+			seen[location.ID] = true
+			previous = location.RecordedAt
+		}
+
+		if page.NextCursor == "" {
+			break
+		}
+		page, response = suite.env.API.GetLocationHistory(suite.testDriverID, helpers.HistoryQuery{
+			From: from, To: to, Limit: 10, Cursor: page.NextCursor,
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+	}
+
+	close(stop)
+	wg.Wait()
+
+	// Assert
+	// Все исходные точки должны быть получены ровно один раз, без пропусков
+	for _, location := range history {
+		assert.True(suite.T(), seen[location.ID], "Точка %s пропущена при пагинации", location.ID)
+	}
+}
+
+// TestHistoryLimits тестирует граничные значения limit
+func (suite *LocationHistoryTestSuite) TestHistoryLimits() {
+	// Arrange
+	history := suite.seedHistory(30, 5*time.Second)
+	from := history[0].RecordedAt.Add(-time.Second)
+
+	testCases := []struct {
+		name           string
+		limit          int
+		expectedStatus int
+		expectedCount  int
+		hasNextPage    bool
+	}{
+		{name: "limit 1", limit: 1, expectedStatus: http.StatusOK, expectedCount: 1, hasNextPage: true},
+		{name: "limit 5", limit: 5, expectedStatus: http.StatusOK, expectedCount: 5, hasNextPage: true},
+		{name: "limit equal to history", limit: len(history), expectedStatus: http.StatusOK, expectedCount: len(history)},
+		{name: "limit larger than history", limit: 1000, expectedStatus: http.StatusOK, expectedCount: len(history)},
+		{name: "limit above maximum", limit: 1001, expectedStatus: http.StatusBadRequest},
+		{name: "negative limit", limit: -1, expectedStatus: http.StatusBadRequest},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			page, response := suite.env.API.GetLocationHistory(suite.testDriverID, helpers.HistoryQuery{From: from, Limit: tc.limit})
+
+			// Assert
+			require.Equal(t, tc.expectedStatus, response.StatusCode)
+			if tc.expectedStatus == http.StatusOK {
+				assert.Equal(t, tc.expectedCount, page.Count)
+				assert.Equal(t, tc.hasNextPage, page.NextCursor != "", "next_cursor")
+			}
+		})
+	}
+}
+
+// seedHistory сохраняет историю местоположений тестового водителя
+func (suite *LocationHistoryTestSuite) seedHistory(count int, interval time.Duration) []*entities.DriverLocation {
+	history := fixtures.CreateTestLocationHistory(suite.testDriverID, count, interval)
+	require.NoError(suite.T(), suite.env.LocationService.BatchUpdateLocations(suite.env.Ctx, history))
+	return history
+}
+
+// TestLocationHistoryTestSuite запускает тестовый suite
+func TestLocationHistoryTestSuite(t *testing.T) {
+	suite.Run(t, new(LocationHistoryTestSuite))
+}
@@ -161,9 +161,10 @@ func (suite *LocationRepositoryTestSuite) TestBatchCreateLocations() {
 // TestGetNearbyLocations тестирует поиск ближайших местоположений
 func (suite *LocationRepositoryTestSuite) TestGetNearbyLocations() {
 	// Arrange
-	// Создаем нескольких водителей
+	// Создаем нескольких водителей на линии - поиск поблизости находит только их
 	drivers := fixtures.CreateMultipleTestDrivers(4)
 	for _, driver := range drivers {
+		driver.Status = entities.StatusAvailable
 		err := suite.driverRepo.Create(suite.ctx, driver)
 		require.NoError(suite.T(), err)
 	}
@@ -217,10 +218,11 @@ func (suite *LocationRepositoryTestSuite) TestDeleteOldLocations() {
 	}
 
 	// Act
-	err := suite.locationRepo.DeleteOld(suite.ctx, cutoffTime)
+	deleted, err := suite.locationRepo.DeleteOld(suite.ctx, cutoffTime)
 
 	// Assert
 	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), int64(len(oldLocations)), deleted)
 
 	// Проверяем, что старые удалены, а новые остались
 	for _, location := range oldLocations {
@@ -0,0 +1,48 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"os"
+	"testing"
+
+	"driver-service/tests/helpers"
+)
+
+// TestMain выполняет тесты и формирует отчеты о покрытии эндпоинтов (ENDPOINT_COVERAGE)
+// и типов событий (EVENT_COVERAGE), если заданы пути к ним. Метрики прогона публикуются
+// для Prometheus, если задан TEST_METRICS_ADDR или TEST_METRICS_PUSHGATEWAY. Метаданные прогона
+// (коммит, образ, миграции, версии окружения) сохраняются в TEST_ARTIFACTS_DIR.
+func TestMain(m *testing.M) {
+	stopMetrics, err := helpers.StartRunMetrics()
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Failed to start test run metrics: %v\n", err)
+		os.Exit(1)
+	}
+
+	code := m.Run()
+
+	if err := stopMetrics(); err != nil {
+		fmt.Fprintf(os.Stderr, "Failed to publish test run metrics: %v\n", err)
+	}
+
+	reports := []struct {
+		name  string
+		write func() error
+	}{
+		{"endpoint coverage", helpers.WriteEndpointCoverageReport},
+		{"event coverage", helpers.WriteEventCoverageReport},
+		{"run metadata", helpers.WriteRunMetadata},
+	}
+	for _, report := range reports {
+		if err := report.write(); err != nil {
+			fmt.Fprintf(os.Stderr, "Failed to write %s report: %v\n", report.name, err)
+			if code == 0 {
+				code = 1
+			}
+		}
+	}
+
+	os.Exit(code)
+}
@@ -0,0 +1,302 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"fmt"
+	"math/rand"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры потока некорректных событий: в каждом раунде одно корректное событие окружено
+// malformedPerRound некорректными сообщениями; задержка корректных событий сравнивается с раундами без них
+const (
+	malformedDrivers          = 5
+	malformedRounds           = 60
+	malformedPerRound         = 50
+	malformedLoadWorkers      = 4
+	malformedLoadInterval     = 20 * time.Millisecond
+	malformedHandleTimeout    = 10 * time.Second
+	malformedLatencyFactor    = 2
+	malformedLatencyAllowance = 25 * time.Millisecond
+)
+
+// malformedSubjects темы событий, на которые подписан сервис
+var malformedSubjects = []string{services.SubjectOrderAssigned, services.SubjectOrderCompleted, services.SubjectPaymentProcessed}
+
+// MalformedEventsTestSuite тестирует устойчивость подписчика к тысячам некорректных сообщений (мусорные байты,
+// обрезанный JSON, неверные типы и значения) вперемешку с корректными событиями под нагрузкой:
+// задержка обработки корректных событий не растет, а счетчики подписчика учитывают каждое некорректное
+// сообщение как неприменимое. Без тестового NATS тесты пропускаются.
+type MalformedEventsTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *MalformedEventsTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *MalformedEventsTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *MalformedEventsTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestValidEventsUnaffectedByMalformedFlood тестирует задержку корректных событий и счетчики ошибок
+// при потоке некорректных сообщений
+func (suite *MalformedEventsTestSuite) TestValidEventsUnaffectedByMalformedFlood() {
+	// Arrange
+	nats := helpers.NewNATSHelper(suite.T())
+	events := &handledEvents{OrderEventService: suite.env.OrderEvents}
+	consumer := suite.env.StartEventConsumer(suite.T(), nats, helpers.EventStream{}, events)
+
+	driverIDs := make([]uuid.UUID, 0, malformedDrivers)
+	expected := make(map[uuid.UUID]*expectedEffects, malformedDrivers)
+	for i := 0; i < malformedDrivers; i++ {
+		driverID, shiftID := suite.createDriverOnShift()
+		driverIDs = append(driverIDs, driverID)
+		expected[driverID] = &expectedEffects{shiftID: shiftID}
+	}
+
+	random := rand.New(rand.NewSource(helpers.TestSeed(suite.T(), time.Now().UnixNano())))
+	load := helpers.StartLocationLoad(suite.env.API, driverIDs, malformedLoadWorkers, malformedLoadInterval)
+	defer load.Stop()
+
+	// Act - раунды только с корректными событиями
+	baseline := make([]time.Duration, 0, malformedRounds)
+	for round := 0; round < malformedRounds; round++ {
+		valid := suite.validEvent(random, round, driverIDs, expected)
+		baseline = append(baseline, suite.publishRound(nats, events, []helpers.NATSMessage{valid}))
+	}
+	before := consumer.Stats()
+
+	// Act - те же раунды с некорректными сообщениями до и после корректного события
+	flood := make([]time.Duration, 0, malformedRounds)
+	for round := 0; round < malformedRounds; round++ {
+		batch := make([]helpers.NATSMessage, 0, malformedPerRound+1)
+		for i := 0; i < malformedPerRound/2; i++ {
+			batch = append(batch, malformedMessage(random))
+		}
+		batch = append(batch, suite.validEvent(random, malformedRounds+round, driverIDs, expected))
+		for i := malformedPerRound / 2; i < malformedPerRound; i++ {
+			batch = append(batch, malformedMessage(random))
+		}
+		flood = append(flood, suite.publishRound(nats, events, batch))
+	}
+
+	malformed := uint64(malformedRounds * malformedPerRound)
+	helpers.WaitForCondition(suite.T(), func() bool {
+		return consumer.Stats().Unprocessable-before.Unprocessable >= malformed
+	}, malformedHandleTimeout, "malformed messages handled")
+	after := consumer.Stats()
+	report := load.Stop()
+
+	// Assert
+	suite.T().Run("ValidEventLatencyUnaffected", func(t *testing.T) {
+		baselineLatency := helpers.NewLatencyPercentiles(baseline)
+		floodLatency := helpers.NewLatencyPercentiles(flood)
+		t.Logf("Valid event latency: baseline p50 %v, p95 %v, max %v; with %d malformed messages p50 %v, p95 %v, max %v",
+			baselineLatency.P50, baselineLatency.P95, baselineLatency.Max, malformed,
+			floodLatency.P50, floodLatency.P95, floodLatency.Max)
+
+		limit := malformedLatencyFactor*baselineLatency.P95 + malformedLatencyAllowance
+		assert.LessOrEqual(t, floodLatency.P95, limit, "p95 задержки корректных событий среди некорректных")
+	})
+
+	suite.T().Run("ErrorCountersIncremented", func(t *testing.T) {
+		assert.Equal(t, malformed, after.Unprocessable-before.Unprocessable, "Каждое некорректное сообщение учтено как неприменимое")
+		assert.Equal(t, uint64(malformedRounds), after.Processed-before.Processed, "Корректные события обработаны")
+		assert.Equal(t, before.Failed, after.Failed, "Некорректные сообщения не считаются временными ошибками")
+	})
+
+	suite.T().Run("ValidEffectsApplied", func(t *testing.T) {
+		var count int
+		require.NoError(t, suite.env.DB.GetContext(suite.env.Ctx, &count, "SELECT COUNT(*) FROM processed_events"))
+		assert.Equal(t, 2*malformedRounds, count, "Только корректные события записаны как обработанные")
+
+		for driverID, effects := range expected {
+			driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+			require.NoError(t, err)
+			assert.Equal(t, effects.trips, driver.TotalTrips, "Поездки водителя %s", driverID)
+
+			shift := suite.env.DB.GetShift(t, effects.shiftID)
+			assert.InDelta(t, effects.distance, shift.TotalDistance, 0.001, "Пробег смены водителя %s", driverID)
+			assert.InDelta(t, effects.earnings, shift.TotalEarnings, 0.001, "Заработок смены водителя %s", driverID)
+		}
+	})
+
+	suite.T().Run("LoadUnaffected", func(t *testing.T) {
+		assert.Empty(t, report.Failures, "Ошибки записи местоположений во время потока некорректных событий")
+		assert.NotEmpty(t, report.Accepted)
+	})
+}
+
+// publishRound публикует сообщения раунда одним пакетом и возвращает задержку от публикации до обработки
+// корректного события раунда (сообщения с заголовком Nats-Msg-Id)
+func (suite *MalformedEventsTestSuite) publishRound(nats *helpers.NATSHelper, events *handledEvents, batch []helpers.NATSMessage) time.Duration {
+	var eventID string
+	for _, message := range batch {
+		if id := message.Header[services.MessageIDHeader]; id != "" {
+			eventID = id
+		}
+	}
+	require.NotEmpty(suite.T(), eventID, "В раунде нет корректного события")
+
+	published := time.Now()
+	nats.PublishAll(batch)
+	var handledAt time.Time
+	helpers.Eventually(suite.T(), func(c *helpers.EventuallyT) {
+		var ok bool
+		handledAt, ok = events.handledAt(eventID)
+		assert.True(c, ok, "Событие %s не обработано", eventID)
+	}, malformedHandleTimeout, time.Millisecond)
+	return handledAt.Sub(published)
+}
+
+// validEvent возвращает корректное событие n: четные - order.completed, нечетные - payment.processed
+// водителя n по кругу; ожидаемый эффект добавляется в expected
+func (suite *MalformedEventsTestSuite) validEvent(random *rand.Rand, n int, driverIDs []uuid.UUID, expected map[uuid.UUID]*expectedEffects) helpers.NATSMessage {
+	driverID := driverIDs[n%len(driverIDs)]
+	orderID := uuid.New()
+	effects := expected[driverID]
+
+	if n%2 == 0 {
+		distance := float64(random.Intn(3000)+100) / 100
+		effects.trips++
+		effects.distance += distance
+		return helpers.NATSMessage{
+			Subject: services.SubjectOrderCompleted,
+			Header:  map[string]string{services.MessageIDHeader: "order-completed-" + orderID.String()},
+			Payload: helpers.OrderCompletedPayload(orderID, driverID, distance),
+		}
+	}
+
+	amount := float64(random.Intn(150000)+10000) / 100
+	effects.earnings += amount
+	return helpers.NATSMessage{
+		Subject: services.SubjectPaymentProcessed,
+		Header:  map[string]string{services.MessageIDHeader: "payment-" + orderID.String()},
+		Payload: helpers.PaymentProcessedPayload(uuid.New(), orderID, driverID, amount),
+	}
+}
+
+// malformedMessage возвращает некорректное сообщение на одну из тем подписчика: подписчик должен
+// отклонить его до обращения к БД
+func malformedMessage(random *rand.Rand) helpers.NATSMessage {
+	subject := malformedSubjects[random.Intn(len(malformedSubjects))]
+
+	var payload []byte
+	switch random.Intn(9) {
+	case 0: // мусорные байты, в том числе не UTF-8
+		payload = make([]byte, random.Intn(512)+1)
+		random.Read(payload)
+	case 1: // обрезанный JSON
+		valid := helpers.OrderCompletedPayload(uuid.New(), uuid.New(), 5)
+		payload = valid[:random.Intn(len(valid)-1)+1]
+	case 2:
+		payload = []byte(`{"driver_id":"not-a-uuid","order_id":"not-a-uuid"}`)
+	case 3:
+		payload = []byte(`{"driver_id":42,"amount":"many","distance_km":"far"}`)
+	case 4:
+		payload = []byte(fmt.Sprintf(`{"order_id":%q}`, uuid.New()))
+	case 5:
+		payload = []byte(`[{"driver_id":null}]`)
+	case 6:
+		payload = []byte("null")
+	case 7:
+		payload = nil
+	default: // корректный JSON с недопустимым значением
+		subject = services.SubjectPaymentProcessed
+		payload = helpers.PaymentProcessedPayload(uuid.New(), uuid.New(), uuid.New(), -float64(random.Intn(1000)+1))
+		if random.Intn(2) == 0 {
+			subject = services.SubjectOrderCompleted
+			payload = helpers.OrderCompletedPayload(uuid.New(), uuid.New(), -float64(random.Intn(100)+1))
+		}
+	}
+	return helpers.NATSMessage{Subject: subject, Payload: payload}
+}
+
+// createDriverOnShift создает водителя на смене с активной сменой
+func (suite *MalformedEventsTestSuite) createDriverOnShift() (driverID, shiftID uuid.UUID) {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900745%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("malformed.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("MF%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, entities.StatusOnShift))
+
+	shift := fixtures.CreateTestShift(created.ID)
+	suite.env.DB.InsertShift(suite.T(), shift)
+	return created.ID, shift.ID
+}
+
+// handledEvents запоминает время успешной обработки событий order.completed и payment.processed по eventID
+type handledEvents struct {
+	services.OrderEventService
+
+	mu      sync.Mutex
+	handled map[string]time.Time
+}
+
+// HandleOrderCompleted передает событие сервису и запоминает время обработки
+func (e *handledEvents) HandleOrderCompleted(ctx context.Context, eventID string, driverID, orderID uuid.UUID, distanceKm float64) error {
+	err := e.OrderEventService.HandleOrderCompleted(ctx, eventID, driverID, orderID, distanceKm)
+	e.record(eventID, err)
+	return err
+}
+
+// HandlePaymentProcessed передает событие сервису и запоминает время обработки
+func (e *handledEvents) HandlePaymentProcessed(ctx context.Context, eventID string, payment *entities.TripPayment) error {
+	err := e.OrderEventService.HandlePaymentProcessed(ctx, eventID, payment)
+	e.record(eventID, err)
+	return err
+}
+
+// record запоминает время обработки события без ошибки
+func (e *handledEvents) record(eventID string, err error) {
+	if err != nil {
+		return
+	}
+	e.mu.Lock()
+	defer e.mu.Unlock()
+	if e.handled == nil {
+		e.handled = make(map[string]time.Time)
+	}
+	e.handled[eventID] = time.Now()
+}
+
+// handledAt возвращает время обработки события eventID; false, если событие еще не обработано
+func (e *handledEvents) handledAt(eventID string) (time.Time, bool) {
+	e.mu.Lock()
+	defer e.mu.Unlock()
+	handledAt, ok := e.handled[eventID]
+	return handledAt, ok
+}
+
+// TestMalformedEventsTestSuite запускает тестовый suite
+func TestMalformedEventsTestSuite(t *testing.T) {
+	suite.Run(t, new(MalformedEventsTestSuite))
+}
@@ -0,0 +1,144 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры нагрузки во время миграции
+const (
+	migrationLoadDrivers   = 5
+	migrationLoadWorkers   = 4
+	migrationLoadInterval  = 20 * time.Millisecond
+	migrationLoadRequests  = 20 // сколько обновлений должно быть принято до и после миграции
+	migrationLoadTimeout   = 10 * time.Second
+	migrationSeedLocations = 3
+)
+
+// MigrationUnderLoadTestSuite проверяет обещание миграций без простоя (blue/green): данные создаются
+// на предыдущей версии схемы, следующая миграция применяется, пока генератор нагрузки отправляет
+// обновления местоположений, и ни один запрос не должен завершиться ошибкой, а данные - потеряться.
+// Всегда проверяется переход на последнюю миграцию, поэтому новая миграция покрывается автоматически.
+type MigrationUnderLoadTestSuite struct {
+	suite.Suite
+	env    *helpers.TestEnvironment
+	latest uint
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *MigrationUnderLoadTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.latest = helpers.LatestMigrationVersion(suite.T())
+	require.Greater(suite.T(), suite.latest, uint(1), "Нужна хотя бы одна миграция поверх начальной схемы")
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *MigrationUnderLoadTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *MigrationUnderLoadTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TearDownTest возвращает схему к последней версии, даже если тест прервался посреди сценария
+func (suite *MigrationUnderLoadTestSuite) TearDownTest() {
+	suite.env.DB.MigrateTo(suite.T(), suite.latest)
+}
+
+// TestLatestMigrationUnderLocationLoad тестирует применение последней миграции под потоком обновлений местоположений
+func (suite *MigrationUnderLoadTestSuite) TestLatestMigrationUnderLocationLoad() {
+	// Arrange - данные на предыдущей версии схемы
+	previous := suite.latest - 1
+	suite.env.DB.MigrateTo(suite.T(), previous)
+	require.Equal(suite.T(), previous, suite.env.DB.MigrationVersion(suite.T()))
+
+	driversBefore := make(map[uuid.UUID]*entities.Driver, migrationLoadDrivers)
+	driverIDs := make([]uuid.UUID, 0, migrationLoadDrivers)
+	var seededLocations []uuid.UUID
+	for i := 1; i <= migrationLoadDrivers; i++ {
+		driver := suite.createDriver(i)
+		driversBefore[driver.ID] = driver
+		driverIDs = append(driverIDs, driver.ID)
+
+		for j := 0; j < migrationSeedLocations; j++ {
+			location := fixtures.CreateTestLocation(driver.ID)
+			location.RecordedAt = time.Now().Add(-time.Duration(j+1) * time.Hour)
+			require.NoError(suite.T(), suite.env.LocationRepo.Create(suite.env.Ctx, location))
+			seededLocations = append(seededLocations, location.ID)
+		}
+	}
+
+	load := helpers.StartLocationLoad(suite.env.API, driverIDs, migrationLoadWorkers, migrationLoadInterval)
+	defer load.Stop()
+	load.WaitForAccepted(suite.T(), migrationLoadRequests, migrationLoadTimeout)
+
+	// Act - миграция, пока нагрузка продолжается
+	suite.env.DB.MigrateTo(suite.T(), suite.latest)
+	load.WaitForAccepted(suite.T(), migrationLoadRequests, migrationLoadTimeout)
+	report := load.Stop()
+
+	// Assert
+	assert.Equal(suite.T(), suite.latest, suite.env.DB.MigrationVersion(suite.T()))
+
+	suite.T().Run("NoFailedRequests", func(t *testing.T) {
+		assert.Empty(t, report.Failures, "Запросы, завершившиеся ошибкой во время миграции")
+		assert.Equal(t, report.Sent, len(report.Accepted))
+		assert.GreaterOrEqual(t, len(report.Accepted), 2*migrationLoadRequests)
+	})
+
+	suite.T().Run("DriversPreserved", func(t *testing.T) {
+		for id, before := range driversBefore {
+			after, err := suite.env.DriverRepo.GetByID(suite.env.Ctx, id)
+			require.NoError(t, err)
+			assert.Equal(t, before.Phone, after.Phone)
+			assert.Equal(t, before.Email, after.Email)
+			assert.Equal(t, before.LicenseNumber, after.LicenseNumber)
+			assert.Equal(t, before.Status, after.Status)
+			assert.True(t, before.CreatedAt.Equal(after.CreatedAt), "Дата создания водителя %s", id)
+		}
+	})
+
+	suite.T().Run("NoLocationsLost", func(t *testing.T) {
+		for _, id := range append(append([]uuid.UUID(nil), seededLocations...), report.Accepted...) {
+			_, err := suite.env.LocationRepo.GetByID(suite.env.Ctx, id)
+			assert.NoError(t, err, "Местоположение %s", id)
+		}
+
+		var count int
+		require.NoError(t, suite.env.DB.GetContext(suite.env.Ctx, &count, "SELECT COUNT(*) FROM driver_locations"))
+		assert.Equal(t, len(seededLocations)+len(report.Accepted), count)
+	})
+}
+
+// createDriver создает водителя с уникальными контактами через сервис
+func (suite *MigrationUnderLoadTestSuite) createDriver(n int) *entities.Driver {
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900888%04d", n)
+	driver.Email = fmt.Sprintf("migration.driver%d@example.com", n)
+	driver.LicenseNumber = fmt.Sprintf("MG%08d", n)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+
+	stored, err := suite.env.DriverRepo.GetByID(suite.env.Ctx, created.ID)
+	require.NoError(suite.T(), err)
+	return stored
+}
+
+func TestMigrationUnderLoadTestSuite(t *testing.T) {
+	suite.Run(t, new(MigrationUnderLoadTestSuite))
+}
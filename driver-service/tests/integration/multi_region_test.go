@@ -0,0 +1,222 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// MultiRegionTestSuite проверяет, что записи в одном регионе становятся видны в другом
+// в пределах SLA репликации (REGION_REPLICATION_SLA). Регионы задаются REGION_PRIMARY_URL
+// и REGION_SECONDARY_URL (стенд с гео-маршрутизацией чтения); без них оба региона - роутер в памяти.
+type MultiRegionTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	regions helpers.RegionTargets
+	runID   int64 // на развернутом стенде БД не очищается между запусками
+	drivers int   // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *MultiRegionTestSuite) SetupSuite() {
+	suite.runID = time.Now().Unix() % 100000
+	suite.regions = helpers.LoadRegionTargets(suite.T(), func() *helpers.APITestHelper {
+		suite.env = helpers.NewTestEnvironment(suite.T())
+		return suite.env.API
+	})
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *MultiRegionTestSuite) TearDownSuite() {
+	if suite.env != nil {
+		suite.env.Close(suite.T())
+	}
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *MultiRegionTestSuite) SetupTest() {
+	if suite.env != nil {
+		suite.env.Reset(suite.T())
+	}
+}
+
+// TestDriverCreated тестирует видимость нового водителя в другом регионе
+func (suite *MultiRegionTestSuite) TestDriverCreated() {
+	for _, direction := range suite.regions.Directions() {
+		writer, reader := direction[0], direction[1]
+
+		suite.T().Run(writer.Name+"->"+reader.Name, func(t *testing.T) {
+			// Act
+			driverID := suite.createDriver(t, writer)
+
+			// Assert
+			suite.regions.AssertReplicated(t, "create driver", writer, reader, func(api *helpers.APITestHelper) (bool, error) {
+				_, found, err := getDriver(api, driverID)
+				return found, err
+			})
+		})
+	}
+}
+
+// TestProfileUpdated тестирует видимость изменения профиля водителя в другом регионе
+func (suite *MultiRegionTestSuite) TestProfileUpdated() {
+	for _, direction := range suite.regions.Directions() {
+		writer, reader := direction[0], direction[1]
+
+		suite.T().Run(writer.Name+"->"+reader.Name, func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t, writer)
+			firstName := "Регион" + writer.Name
+
+			// Act
+			response := writer.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodPut,
+				URL:    writer.API.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+				Body:   map[string]string{"first_name": firstName},
+			})
+			require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+			// Assert
+			suite.regions.AssertReplicated(t, "update profile", writer, reader, func(api *helpers.APITestHelper) (bool, error) {
+				driver, found, err := getDriver(api, driverID)
+				return found && driver.FirstName == firstName, err
+			})
+		})
+	}
+}
+
+// TestLocationUpdated тестирует видимость нового местоположения водителя в другом регионе
+func (suite *MultiRegionTestSuite) TestLocationUpdated() {
+	for i, direction := range suite.regions.Directions() {
+		writer, reader := direction[0], direction[1]
+		latitude, longitude := 55.7558+float64(i)*0.01, 37.6173
+
+		suite.T().Run(writer.Name+"->"+reader.Name, func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t, writer)
+
+			// Act
+			location := helpers.CreateLocationRequest()
+			location["latitude"] = latitude
+			location["longitude"] = longitude
+			response := writer.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodPost,
+				URL:    writer.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+				Body:   location,
+			})
+			require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+			// Assert
+			suite.regions.AssertReplicated(t, "update location", writer, reader, func(api *helpers.APITestHelper) (bool, error) {
+				response := api.MakeRequest(helpers.APIRequest{
+					Method: http.MethodGet,
+					URL:    api.APIPath(fmt.Sprintf("/drivers/%s/locations/current", driverID)),
+				})
+				switch response.StatusCode {
+				case http.StatusOK:
+				case http.StatusNotFound:
+					return false, nil
+				default:
+					return false, fmt.Errorf("current location: status %d", response.StatusCode)
+				}
+
+				var current httpHandlers.LocationResponse
+				api.UnmarshalResponse(response, &current)
+				return current.Latitude == latitude && current.Longitude == longitude, nil
+			})
+		})
+	}
+}
+
+// TestDriverDeleted тестирует исчезновение удаленного водителя в другом регионе
+func (suite *MultiRegionTestSuite) TestDriverDeleted() {
+	for _, direction := range suite.regions.Directions() {
+		writer, reader := direction[0], direction[1]
+
+		suite.T().Run(writer.Name+"->"+reader.Name, func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t, writer)
+			suite.regions.AssertReplicated(t, "create driver", writer, reader, func(api *helpers.APITestHelper) (bool, error) {
+				_, found, err := getDriver(api, driverID)
+				return found, err
+			})
+
+			// Act
+			response := writer.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodDelete,
+				URL:    writer.API.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+			})
+			require.Less(t, response.StatusCode, http.StatusBadRequest, string(response.Body))
+
+			// Assert
+			suite.regions.AssertReplicated(t, "delete driver", writer, reader, func(api *helpers.APITestHelper) (bool, error) {
+				_, found, err := getDriver(api, driverID)
+				return !found, err
+			})
+		})
+	}
+}
+
+// createDriver создает водителя через API региона и удаляет его после теста
+func (suite *MultiRegionTestSuite) createDriver(t *testing.T, region helpers.Region) uuid.UUID {
+	suite.drivers++
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7998%05d%02d", suite.runID, suite.drivers)
+	request["email"] = fmt.Sprintf("region-%d-%d@example.com", suite.runID, suite.drivers)
+	request["license_number"] = fmt.Sprintf("RG%05d%03d", suite.runID, suite.drivers)
+	request["license_expiry"] = time.Now().AddDate(1, 0, 0).UTC().Format(time.RFC3339)
+
+	response := region.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    region.API.APIPath("/drivers"),
+		Body:   request,
+	})
+	require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	region.API.UnmarshalResponse(response, &driver)
+
+	if suite.regions.Remote {
+		t.Cleanup(func() {
+			region.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodDelete,
+				URL:    region.API.APIPath(fmt.Sprintf("/drivers/%s", driver.ID)),
+			})
+		})
+	}
+	return driver.ID
+}
+
+// getDriver читает водителя через API региона; found=false, если регион его не знает
+func getDriver(api *helpers.APITestHelper, driverID uuid.UUID) (*httpHandlers.DriverResponse, bool, error) {
+	response := api.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    api.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+	})
+	switch response.StatusCode {
+	case http.StatusOK:
+	case http.StatusNotFound:
+		return nil, false, nil
+	default:
+		return nil, false, fmt.Errorf("get driver: status %d", response.StatusCode)
+	}
+
+	var driver httpHandlers.DriverResponse
+	api.UnmarshalResponse(response, &driver)
+	return &driver, true, nil
+}
+
+// TestMultiRegionTestSuite запускает тестовый suite
+func TestMultiRegionTestSuite(t *testing.T) {
+	suite.Run(t, new(MultiRegionTestSuite))
+}
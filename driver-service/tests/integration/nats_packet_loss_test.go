@@ -0,0 +1,278 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"math/rand"
+	"strconv"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры поездок при потере пакетов: водители ездят параллельно, поездки каждого - по очереди.
+// Шаг поездки ждет эффекта события с запасом на повторные передачи и повторную доставку JetStream.
+const (
+	packetLossDrivers     = 10
+	packetLossRides       = 3
+	packetLossRoutePoints = 5
+	packetLossAckWait     = time.Second
+	packetLossStepBudget  = 20 * time.Second
+	packetLossDrainBudget = 60 * time.Second
+	packetLossPoll        = 20 * time.Millisecond
+)
+
+// NATSPacketLossTestSuite проигрывает жизненный цикл поездки (назначение заказа, движение по маршруту,
+// завершение и оплата) при потере пакетов на соединении сервиса с NATS через ChaosProxy: поездки
+// завершаются, события доходят до сервиса, в том числе повторной доставкой JetStream, а поездки и
+// заработок учитываются по одному разу. Без тестового NATS с JetStream тесты пропускаются.
+type NATSPacketLossTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	chaos   *helpers.DependencyChaos
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *NATSPacketLossTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.chaos = suite.env.NewDependencyChaos(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *NATSPacketLossTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *NATSPacketLossTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.env.StartPushGateway(suite.T())
+	suite.chaos.RestoreAll()
+}
+
+// TestRideLifecycleWithFivePercentLoss тестирует поездки при потере 5% пакетов
+func (suite *NATSPacketLossTestSuite) TestRideLifecycleWithFivePercentLoss() {
+	suite.assertRideLifecycleUnderLoss(0.05)
+}
+
+// TestRideLifecycleWithTenPercentLoss тестирует поездки при потере 10% пакетов
+func (suite *NATSPacketLossTestSuite) TestRideLifecycleWithTenPercentLoss() {
+	suite.assertRideLifecycleUnderLoss(0.10)
+}
+
+// rideEffects ожидаемые итоги водителя после всех его поездок
+type rideEffects struct {
+	shiftID  uuid.UUID
+	distance float64
+	earnings float64
+}
+
+// assertRideLifecycleUnderLoss проигрывает поездки водителей при потере доли rate пакетов на соединении с NATS
+// и проверяет итоги поездок, доставку событий и однократный учет заработка
+func (suite *NATSPacketLossTestSuite) assertRideLifecycleUnderLoss(rate float64) {
+	// Arrange
+	suffix := strconv.Itoa(int(rate * 100))
+	stream := "RIDE_EVENTS_LOSS_" + suffix
+	durable := "driver-service-loss-" + suffix
+
+	nats := helpers.NewNATSHelper(suite.T())
+	nats.EnsureStream(stream, []string{services.SubjectOrderAssigned, services.SubjectOrderCompleted, services.SubjectPaymentProcessed})
+
+	orders := &deliveryCounter{OrderEventService: suite.env.OrderEvents}
+	suite.env.StartEventConsumerThrough(suite.T(), suite.chaos, helpers.EventStream{Stream: stream, Durable: durable, AckWait: packetLossAckWait}, orders)
+
+	seed := helpers.TestSeed(suite.T(), 1744)
+	random := rand.New(rand.NewSource(seed))
+	expected := make(map[uuid.UUID]*rideEffects, packetLossDrivers)
+	rides := make(map[uuid.UUID][]rideFare, packetLossDrivers)
+	for i := 0; i < packetLossDrivers; i++ {
+		driverID, shiftID := suite.createDriverOnShift()
+		effects := &rideEffects{shiftID: shiftID}
+		for j := 0; j < packetLossRides; j++ {
+			fare := rideFare{
+				orderID:  uuid.New(),
+				distance: float64(random.Intn(3000)+100) / 100,
+				amount:   float64(random.Intn(150000)+10000) / 100,
+			}
+			rides[driverID] = append(rides[driverID], fare)
+			effects.distance += fare.distance
+			effects.earnings += fare.amount
+		}
+		expected[driverID] = effects
+	}
+	route := fixtures.CreateCenterToAirportRoute(packetLossRoutePoints)
+
+	// Потери включаются после подписки: переподключения после сброса соединения проходят уже при потерях
+	suite.chaos.SetPacketLoss(suite.T(), "nats", rate, seed)
+
+	// Act
+	var wg sync.WaitGroup
+	errs := make(chan error, packetLossDrivers)
+	for driverID, fares := range rides {
+		wg.Add(1)
+		go func(driverID uuid.UUID, fares []rideFare) {
+			defer wg.Done()
+			for i, fare := range fares {
+				if err := suite.ride(nats, driverID, i+1, fare, route); err != nil {
+					errs <- fmt.Errorf("driver %s ride %d: %w", driverID, i+1, err)
+					return
+				}
+			}
+		}(driverID, fares)
+	}
+	wg.Wait()
+	close(errs)
+
+	helpers.AssertPropagatedWithin(suite.T(), "ride events drained", helpers.PropagationBudget{
+		Within: packetLossDrainBudget,
+		Visible: func(ctx context.Context) (bool, error) {
+			state, err := nats.ConsumerState(stream, durable)
+			return state.Drained(), err
+		},
+	})
+	lost, resets := suite.chaos.Proxies["nats"].PacketLoss()
+	suite.chaos.RestoreAll()
+
+	// Assert
+	deliveries, distinct := orders.stats()
+	state, err := nats.ConsumerState(stream, durable)
+	require.NoError(suite.T(), err)
+	suite.T().Logf("Потеря %.0f%%: потеряно блоков %d, сброшено соединений %d, доставок завершений и оплат %d на %d событий, повторных доставок JetStream %d",
+		rate*100, lost, resets, deliveries, distinct, state.NumRedelivered)
+	assert.Positive(suite.T(), lost, "Потери пакетов должны случаться")
+
+	suite.T().Run("TripsCompleted", func(t *testing.T) {
+		for err := range errs {
+			assert.NoError(t, err)
+		}
+		for driverID, effects := range expected {
+			driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+			require.NoError(t, err)
+			assert.Equal(t, packetLossRides, driver.TotalTrips, "Поездки водителя %s", driverID)
+
+			shift := suite.env.DB.GetShift(t, effects.shiftID)
+			assert.Equal(t, packetLossRides, shift.TotalTrips, "Поездки смены водителя %s", driverID)
+			assert.InDelta(t, effects.distance, shift.TotalDistance, 0.001, "Пробег смены водителя %s", driverID)
+		}
+	})
+
+	suite.T().Run("EventsArrived", func(t *testing.T) {
+		// order.assigned не проходит через deliveryCounter: его доставку подтверждает переход водителя в busy
+		assert.Equal(t, 2*packetLossDrivers*packetLossRides, distinct, "Каждое завершение и оплата доставлены сервису")
+		assert.True(t, state.Drained(), "Все события потока подтверждены: %+v", state)
+
+		var count int
+		require.NoError(t, suite.env.DB.GetContext(suite.env.Ctx, &count, "SELECT COUNT(*) FROM processed_events"))
+		assert.Equal(t, distinct, count)
+	})
+
+	suite.T().Run("NoDuplicateEarnings", func(t *testing.T) {
+		for driverID, effects := range expected {
+			shift := suite.env.DB.GetShift(t, effects.shiftID)
+			assert.InDelta(t, effects.earnings, shift.TotalEarnings, 0.001, "Заработок смены водителя %s", driverID)
+		}
+	})
+}
+
+// rideFare заказ поездки: пробег по order.completed и оплата по payment.processed
+type rideFare struct {
+	orderID  uuid.UUID
+	distance float64
+	amount   float64
+}
+
+// ride проигрывает n-ю поездку водителя: назначение заказа, движение по маршруту, завершение и оплату.
+// Каждый шаг ждет эффекта предыдущего события. Ожидается учет поездки, а не возврат водителя на смену:
+// повторная доставка order.assigned после завершения снова переводит водителя в busy, поэтому
+// статус после поездки при потерях не проверяется.
+func (suite *NATSPacketLossTestSuite) ride(nats *helpers.NATSHelper, driverID uuid.UUID, n int, fare rideFare, route []fixtures.RoutePoint) error {
+	nats.PublishJetStream(services.SubjectOrderAssigned, map[string]string{services.MessageIDHeader: "order-assigned-" + fare.orderID.String()},
+		orderAssignedPayload(fare.orderID, driverID))
+	if err := suite.waitDriver(driverID, "busy", func(driver *entities.Driver) bool {
+		return driver.Status == entities.StatusBusy
+	}); err != nil {
+		return err
+	}
+
+	for _, point := range route {
+		location := entities.NewDriverLocation(driverID, point.Latitude, point.Longitude, time.Now())
+		if err := suite.env.LocationService.UpdateLocation(suite.env.Ctx, location); err != nil {
+			return fmt.Errorf("update location: %w", err)
+		}
+	}
+
+	nats.PublishJetStream(services.SubjectOrderCompleted, map[string]string{services.MessageIDHeader: "order-completed-" + fare.orderID.String()},
+		helpers.OrderCompletedPayload(fare.orderID, driverID, fare.distance))
+	if err := suite.waitDriver(driverID, "trip recorded", func(driver *entities.Driver) bool {
+		return driver.TotalTrips >= n
+	}); err != nil {
+		return err
+	}
+
+	nats.PublishJetStream(services.SubjectPaymentProcessed, map[string]string{services.MessageIDHeader: "payment-" + fare.orderID.String()},
+		helpers.PaymentProcessedPayload(uuid.New(), fare.orderID, driverID, fare.amount))
+	return nil
+}
+
+// waitDriver ждет, пока водитель удовлетворит done, не дольше packetLossStepBudget
+func (suite *NATSPacketLossTestSuite) waitDriver(driverID uuid.UUID, step string, done func(driver *entities.Driver) bool) error {
+	deadline := time.Now().Add(packetLossStepBudget)
+	for {
+		driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+		if err != nil {
+			return err
+		}
+		if done(driver) {
+			return nil
+		}
+		if time.Now().After(deadline) {
+			return fmt.Errorf("%s not reached within %v: status %s, trips %d", step, packetLossStepBudget, driver.Status, driver.TotalTrips)
+		}
+		time.Sleep(packetLossPoll)
+	}
+}
+
+// orderAssignedPayload возвращает данные события order.assigned
+func orderAssignedPayload(orderID, driverID uuid.UUID) []byte {
+	payload, _ := json.Marshal(map[string]string{
+		"order_id":  orderID.String(),
+		"driver_id": driverID.String(),
+	})
+	return payload
+}
+
+// createDriverOnShift создает водителя на смене с активной сменой
+func (suite *NATSPacketLossTestSuite) createDriverOnShift() (driverID, shiftID uuid.UUID) {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900744%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("packet.loss.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("PL%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, entities.StatusOnShift))
+
+	shift := fixtures.CreateTestShift(created.ID)
+	suite.env.DB.InsertShift(suite.T(), shift)
+	return created.ID, shift.ID
+}
+
+// TestNATSPacketLossTestSuite запускает тестовый suite
+func TestNATSPacketLossTestSuite(t *testing.T) {
+	suite.Run(t, new(NATSPacketLossTestSuite))
+}
@@ -0,0 +1,347 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// NearbyEdgeCasesTestSuite тестовый suite для граничных случаев поиска водителей поблизости
+type NearbyEdgeCasesTestSuite struct {
+	suite.Suite
+	env    *helpers.TestEnvironment
+	oracle *helpers.NearbyOracle
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *NearbyEdgeCasesTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.oracle = helpers.NewNearbyOracle(suite.env.DB)
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *NearbyEdgeCasesTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *NearbyEdgeCasesTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	// Результаты сравниваются с оракулом по БД: поиск поблизости должен доходить до БД
+	suite.env.SetFeature(suite.T(), features.GeoCache, false)
+}
+
+// TestNearbyNearPoles тестирует поиск около полюсов, где долгота вырождается
+func (suite *NearbyEdgeCasesTestSuite) TestNearbyNearPoles() {
+	testCases := []struct {
+		name   string
+		search fixtures.RoutePoint
+		points []fixtures.RoutePoint
+	}{
+		{
+			name:   "north pole",
+			search: fixtures.RoutePoint{Latitude: 90, Longitude: 0},
+			// Точки по разные стороны от полюса находятся в ~1.1 км от него
+			points: []fixtures.RoutePoint{{Latitude: 89.99, Longitude: 0}, {Latitude: 89.99, Longitude: 180}, {Latitude: 89.99, Longitude: -90}},
+		},
+		{
+			name:   "south pole",
+			search: fixtures.RoutePoint{Latitude: -90, Longitude: 45},
+			points: []fixtures.RoutePoint{{Latitude: -89.99, Longitude: 0}, {Latitude: -89.99, Longitude: 179}, {Latitude: -89.99, Longitude: -135}},
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			suite.env.DB.CleanupTables(t)
+			driverIDs := suite.seedDrivers(t, tc.points)
+
+			// Act
+			actual, statusCode := suite.searchNearby(t, tc.search, "5", 100)
+
+			// Assert
+			require.Equal(t, http.StatusOK, statusCode)
+			assert.ElementsMatch(t, driverIDs, actual, "Все водители у полюса должны быть найдены независимо от долготы")
+			suite.assertMatchesOracle(t, tc.search, 5, actual, 100)
+		})
+	}
+}
+
+// TestNearbyAcrossAntimeridian тестирует поиск через линию перемены дат (долгота ±180)
+func (suite *NearbyEdgeCasesTestSuite) TestNearbyAcrossAntimeridian() {
+	// Arrange
+	east := fixtures.RoutePoint{Latitude: 0, Longitude: 179.99}
+	west := fixtures.RoutePoint{Latitude: 0, Longitude: -179.99}
+	driverIDs := suite.seedDrivers(suite.T(), []fixtures.RoutePoint{east, west})
+
+	searches := []fixtures.RoutePoint{
+		{Latitude: 0, Longitude: 180},
+		{Latitude: 0, Longitude: -180},
+		east,
+		west,
+	}
+
+	for _, search := range searches {
+		suite.T().Run(fmt.Sprintf("search_%.2f", search.Longitude), func(t *testing.T) {
+			// Act
+			actual, statusCode := suite.searchNearby(t, search, "5", 100)
+
+			// Assert
+			require.Equal(t, http.StatusOK, statusCode)
+			assert.ElementsMatch(t, driverIDs, actual, "Водители по обе стороны антимеридиана должны быть найдены")
+			suite.assertMatchesOracle(t, search, 5, actual, 100)
+		})
+	}
+}
+
+// TestNearbyZeroAndNegativeRadius тестирует нулевой и отрицательный радиус
+func (suite *NearbyEdgeCasesTestSuite) TestNearbyZeroAndNegativeRadius() {
+	// Arrange
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	suite.seedDrivers(suite.T(), []fixtures.RoutePoint{
+		center,
+		{Latitude: 55.7758, Longitude: 37.6173}, // ~2.2 км
+	})
+
+	for _, radius := range []string{"0", "-1", "-0.0001", "NaN", "five"} {
+		suite.T().Run("radius_"+radius, func(t *testing.T) {
+			// Act
+			response := suite.nearbyRequest(center, radius, 100)
+
+			// Assert
+			assert.Equal(t, http.StatusBadRequest, response.StatusCode, string(response.Body))
+			suite.env.API.AssertErrorResponse(response, "INVALID_RADIUS")
+		})
+	}
+}
+
+// TestNearbyLimitBounds тестирует границы limit: выдача не больше 100 водителей, больший или неположительный
+// limit отклоняется
+func (suite *NearbyEdgeCasesTestSuite) TestNearbyLimitBounds() {
+	// Arrange
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	driverIDs := suite.seedDrivers(suite.T(), []fixtures.RoutePoint{center})
+
+	suite.T().Run("limit_100", func(t *testing.T) {
+		// Act
+		actual, statusCode := suite.searchNearby(t, center, "5", 100)
+
+		// Assert
+		require.Equal(t, http.StatusOK, statusCode)
+		assert.Equal(t, driverIDs, actual)
+	})
+
+	for _, limit := range []int{101, 0, -1} {
+		suite.T().Run(fmt.Sprintf("limit_%d", limit), func(t *testing.T) {
+			// Act
+			response := suite.nearbyRequest(center, "5", limit)
+
+			// Assert
+			assert.Equal(t, http.StatusBadRequest, response.StatusCode, string(response.Body))
+			suite.env.API.AssertErrorResponse(response, "INVALID_REQUEST")
+		})
+	}
+}
+
+// TestNearbyRadiusLargerThanEarth тестирует радиус, превышающий размеры Земли
+func (suite *NearbyEdgeCasesTestSuite) TestNearbyRadiusLargerThanEarth() {
+	// Arrange
+	points := []fixtures.RoutePoint{
+		{Latitude: 55.7558, Longitude: 37.6173},   // Москва
+		{Latitude: -33.8688, Longitude: 151.2093}, // Сидней
+		{Latitude: 40.7128, Longitude: -74.0060},  // Нью-Йорк
+		{Latitude: -89.5, Longitude: 0},           // Антарктида
+	}
+	driverIDs := suite.seedDrivers(suite.T(), points)
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+
+	for _, radius := range []string{"20100", "50000", "1e9"} {
+		suite.T().Run("radius_"+radius, func(t *testing.T) {
+			// Act
+			actual, statusCode := suite.searchNearby(t, center, radius, 100)
+
+			// Assert
+			require.Equal(t, http.StatusOK, statusCode)
+			assert.ElementsMatch(t, driverIDs, actual, "Радиус больше половины окружности Земли должен охватывать всех водителей")
+		})
+	}
+
+	suite.T().Run("limit still applies", func(t *testing.T) {
+		actual, statusCode := suite.searchNearby(t, center, "50000", 2)
+
+		require.Equal(t, http.StatusOK, statusCode)
+		assert.Len(t, actual, 2)
+	})
+}
+
+// TestNearbyCoincidentPoints тестирует водителя, находящегося ровно в точке поиска
+func (suite *NearbyEdgeCasesTestSuite) TestNearbyCoincidentPoints() {
+	// Arrange
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	driverIDs := suite.seedDrivers(suite.T(), []fixtures.RoutePoint{center, center})
+
+	// Act
+	response := suite.nearbyRequest(center, "0.001", 100)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+
+	var nearby httpHandlers.NearbyDriversResponse
+	suite.env.API.UnmarshalResponse(response, &nearby)
+
+	require.Len(suite.T(), nearby.Drivers, len(driverIDs))
+	for _, driver := range nearby.Drivers {
+		assert.Contains(suite.T(), driverIDs, driver.DriverID)
+		assert.InDelta(suite.T(), 0.0, driver.Distance, 1e-9, "Расстояние до совпадающей точки должно быть нулевым")
+	}
+}
+
+// TestNearbyInvalidCenter тестирует координаты центра за пределами допустимого диапазона
+func (suite *NearbyEdgeCasesTestSuite) TestNearbyInvalidCenter() {
+	suite.seedDrivers(suite.T(), []fixtures.RoutePoint{{Latitude: 55.7558, Longitude: 37.6173}})
+
+	testCases := []fixtures.RoutePoint{
+		{Latitude: 91, Longitude: 0},
+		{Latitude: -91, Longitude: 0},
+		{Latitude: 0, Longitude: 181},
+		{Latitude: 0, Longitude: -181},
+	}
+
+	for _, center := range testCases {
+		suite.T().Run(fmt.Sprintf("%.0f_%.0f", center.Latitude, center.Longitude), func(t *testing.T) {
+			// Act
+			response := suite.nearbyRequest(center, "5", 100)
+
+			// Assert
+			assert.Equal(t, http.StatusBadRequest, response.StatusCode, string(response.Body))
+			suite.env.API.AssertErrorResponse(response, "INVALID_LOCATION")
+		})
+	}
+}
+
+// TestNearbyEvictsStaleLocations тестирует, что водители без обновлений дольше окна свежести
+// не попадают в поиск поблизости и в список активных, даже оставаясь в статусе available
+func (suite *NearbyEdgeCasesTestSuite) TestNearbyEvictsStaleLocations() {
+	// Arrange
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	driverIDs := suite.seedDrivers(suite.T(), []fixtures.RoutePoint{
+		center,
+		{Latitude: 55.7578, Longitude: 37.6173}, // ~0.2 км
+		{Latitude: 55.7558, Longitude: 37.6223}, // ~0.3 км
+		{Latitude: 55.7608, Longitude: 37.6173}, // ~0.6 км
+	})
+	fresh, stale := driverIDs[:2], driverIDs[2:]
+
+	for _, driverID := range stale {
+		suite.env.DB.AgeDriverLocations(suite.T(), driverID, locationHeartbeatWindow+time.Minute)
+	}
+
+	suite.T().Run("nearby", func(t *testing.T) {
+		// Act
+		actual, statusCode := suite.searchNearby(t, center, "5", 100)
+
+		// Assert
+		require.Equal(t, http.StatusOK, statusCode)
+		assert.ElementsMatch(t, fresh, actual, "Водители с устаревшим местоположением не должны быть найдены")
+	})
+
+	suite.T().Run("active", func(t *testing.T) {
+		// Act
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    "/api/v1/drivers/active",
+		})
+
+		// Assert
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+		var result struct {
+			Drivers []httpHandlers.DriverResponse `json:"drivers"`
+		}
+		suite.env.API.UnmarshalResponse(response, &result)
+
+		var actual []uuid.UUID
+		for _, driver := range result.Drivers {
+			assert.Equal(t, entities.StatusAvailable, driver.Status)
+			actual = append(actual, driver.ID)
+		}
+		assert.ElementsMatch(t, fresh, actual, "Водители с устаревшим местоположением не должны быть в списке активных")
+	})
+}
+
+// seedDrivers создает активных водителей в указанных точках
+func (suite *NearbyEdgeCasesTestSuite) seedDrivers(t *testing.T, points []fixtures.RoutePoint) []uuid.UUID {
+	drivers := fixtures.CreateMultipleTestDrivers(len(points))
+	driverIDs := make([]uuid.UUID, len(points))
+
+	for i, point := range points {
+		createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, drivers[i])
+		require.NoError(t, err)
+		require.NoError(t, suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, createdDriver.ID, entities.StatusAvailable))
+
+		location := entities.NewDriverLocation(createdDriver.ID, point.Latitude, point.Longitude, time.Now().Add(-time.Minute))
+		require.NoError(t, suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+
+		driverIDs[i] = createdDriver.ID
+	}
+
+	return driverIDs
+}
+
+// nearbyRequest выполняет запрос поиска водителей поблизости
+func (suite *NearbyEdgeCasesTestSuite) nearbyRequest(center fixtures.RoutePoint, radius string, limit int) *helpers.APIResponse {
+	return suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    "/api/v1/locations/nearby",
+		QueryParams: map[string]string{
+			"latitude":  fmt.Sprintf("%f", center.Latitude),
+			"longitude": fmt.Sprintf("%f", center.Longitude),
+			"radius_km": radius,
+			"limit":     fmt.Sprintf("%d", limit),
+		},
+	})
+}
+
+// searchNearby выполняет поиск и возвращает идентификаторы найденных водителей и код ответа
+func (suite *NearbyEdgeCasesTestSuite) searchNearby(t *testing.T, center fixtures.RoutePoint, radius string, limit int) ([]uuid.UUID, int) {
+	response := suite.nearbyRequest(center, radius, limit)
+	if response.StatusCode != http.StatusOK {
+		return nil, response.StatusCode
+	}
+
+	var nearby httpHandlers.NearbyDriversResponse
+	suite.env.API.UnmarshalResponse(response, &nearby)
+
+	driverIDs := make([]uuid.UUID, len(nearby.Drivers))
+	for i, driver := range nearby.Drivers {
+		driverIDs[i] = driver.DriverID
+	}
+	return driverIDs, response.StatusCode
+}
+
+// assertMatchesOracle сверяет выдачу с эталонным перебором
+func (suite *NearbyEdgeCasesTestSuite) assertMatchesOracle(t *testing.T, center fixtures.RoutePoint, radiusKm float64, actual []uuid.UUID, limit int) {
+	expected, err := suite.oracle.Expected(suite.env.Ctx, center.Latitude, center.Longitude, radiusKm)
+	require.NoError(t, err)
+	helpers.AssertNearbyMatchesOracle(t, expected, actual, limit)
+}
+
+// TestNearbyEdgeCasesTestSuite запускает тестовый suite
+func TestNearbyEdgeCasesTestSuite(t *testing.T) {
+	suite.Run(t, new(NearbyEdgeCasesTestSuite))
+}
@@ -0,0 +1,202 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"math/rand"
+	"net/http"
+	"os"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// NearbyOracleTestSuite сверяет поиск водителей поблизости с полным перебором по формуле гаверсинусов
+type NearbyOracleTestSuite struct {
+	suite.Suite
+	env    *helpers.TestEnvironment
+	oracle *helpers.NearbyOracle
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *NearbyOracleTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.oracle = helpers.NewNearbyOracle(suite.env.DB)
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *NearbyOracleTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *NearbyOracleTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	// Результаты сравниваются с оракулом по БД: поиск поблизости должен доходить до БД
+	suite.env.SetFeature(suite.T(), features.GeoCache, false)
+}
+
+// TestNearbyMatchesOracleForRandomDistributions тестирует выдачу на случайных распределениях водителей
+func (suite *NearbyOracleTestSuite) TestNearbyMatchesOracleForRandomDistributions() {
+	seeds := []int64{1, 42, 20240101}
+	if os.Getenv(helpers.TestSeedEnv) != "" {
+		// Дополнительное случайное распределение из режима flake-hunt
+		seeds = append(seeds, helpers.TestSeed(suite.T(), 0))
+	}
+	radii := []float64{0.5, 1, 3, 5, 8}
+	limits := []int{100, 5}
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+
+	for _, seed := range seeds {
+		suite.T().Run(fmt.Sprintf("seed_%d", seed), func(t *testing.T) {
+			// Arrange
+			suite.env.DB.CleanupTables(t)
+			rng := rand.New(rand.NewSource(seed))
+			t.Logf("Seed распределения: %d", seed)
+
+			points := fixtures.CreateRandomPointsAround(rng, center, 10, 40)
+			suite.seedDrivers(t, points)
+
+			for _, radius := range radii {
+				for _, limit := range limits {
+					// Act
+					actual := suite.searchNearby(t, center, radius, limit)
+
+					// Assert
+					expected, err := suite.oracle.Expected(suite.env.Ctx, center.Latitude, center.Longitude, radius)
+					require.NoError(t, err)
+
+					t.Logf("radius=%.1f limit=%d: API=%d, эталон=%d", radius, limit, len(actual), len(expected.InRadius))
+					helpers.AssertNearbyMatchesOracle(t, expected, actual, limit)
+				}
+			}
+		})
+	}
+}
+
+// TestNearbyUsesLatestLocationOnly тестирует, что учитывается только последнее местоположение водителя
+func (suite *NearbyOracleTestSuite) TestNearbyUsesLatestLocationOnly() {
+	// Arrange
+	// Водитель был в центре, а затем уехал на 20 км - в радиус 5 км он попадать не должен
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	driverID := suite.createAvailableDriver(suite.T(), fixtures.CreateTestDriver())
+
+	suite.saveLocation(suite.T(), driverID, center, time.Now().Add(-2*time.Minute))
+	suite.saveLocation(suite.T(), driverID, fixtures.RoutePoint{Latitude: 55.9358, Longitude: 37.6173}, time.Now().Add(-time.Minute))
+
+	// Act
+	actual := suite.searchNearby(suite.T(), center, 5, 100)
+
+	// Assert
+	expected, err := suite.oracle.Expected(suite.env.Ctx, center.Latitude, center.Longitude, 5)
+	require.NoError(suite.T(), err)
+	require.Empty(suite.T(), expected.InRadius)
+	helpers.AssertNearbyMatchesOracle(suite.T(), expected, actual, 100)
+}
+
+// TestNearbyLimitSkipsInactiveDrivers тестирует, что неактивные и удаленные водители ближе к центру не занимают
+// места в выдаче: при limit возвращается ровно limit водителей на линии
+func (suite *NearbyOracleTestSuite) TestNearbyLimitSkipsInactiveDrivers() {
+	// Arrange
+	center := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+	limit := 5
+	drivers := fixtures.CreateMultipleTestDrivers(limit + 4)
+	recordedAt := time.Now().Add(-time.Minute)
+
+	// Скрытые водители в сотнях метров от центра, водители на линии - в километрах
+	var hidden []uuid.UUID
+	for i, driver := range drivers[:4] {
+		driverID := suite.createAvailableDriver(suite.T(), driver)
+		point := fixtures.RoutePoint{Latitude: center.Latitude + float64(i+1)*0.001, Longitude: center.Longitude}
+		suite.saveLocation(suite.T(), driverID, point, recordedAt)
+		hidden = append(hidden, driverID)
+	}
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, hidden[0], entities.StatusOffline))
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, hidden[1], entities.StatusBlocked))
+	require.NoError(suite.T(), suite.env.DriverRepo.SoftDelete(suite.env.Ctx, hidden[2]))
+	require.NoError(suite.T(), suite.env.DriverRepo.SoftDelete(suite.env.Ctx, hidden[3]))
+
+	for i, driver := range drivers[4:] {
+		driverID := suite.createAvailableDriver(suite.T(), driver)
+		point := fixtures.RoutePoint{Latitude: center.Latitude + float64(i+1)*0.01, Longitude: center.Longitude}
+		suite.saveLocation(suite.T(), driverID, point, recordedAt)
+	}
+
+	// Act
+	actual := suite.searchNearby(suite.T(), center, 10, limit)
+
+	// Assert
+	require.Len(suite.T(), actual, limit, "Неактивные водители не уменьшают выдачу")
+	for _, driverID := range hidden {
+		require.NotContains(suite.T(), actual, driverID)
+	}
+	expected, err := suite.oracle.Expected(suite.env.Ctx, center.Latitude, center.Longitude, 10)
+	require.NoError(suite.T(), err)
+	helpers.AssertNearbyMatchesOracle(suite.T(), expected, actual, limit)
+}
+
+// seedDrivers создает по одному активному водителю на каждую точку
+func (suite *NearbyOracleTestSuite) seedDrivers(t *testing.T, points []fixtures.RoutePoint) {
+	drivers := fixtures.CreateMultipleTestDrivers(len(points))
+	recordedAt := time.Now().Add(-time.Minute)
+
+	for i, point := range points {
+		driverID := suite.createAvailableDriver(t, drivers[i])
+		suite.saveLocation(t, driverID, point, recordedAt)
+	}
+}
+
+// createAvailableDriver создает водителя и сразу переводит его в статус available
+func (suite *NearbyOracleTestSuite) createAvailableDriver(t *testing.T, driver *entities.Driver) uuid.UUID {
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+
+	// Обходим цепочку переходов статусов - для поиска важен только итоговый статус
+	require.NoError(t, suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, createdDriver.ID, entities.StatusAvailable))
+	return createdDriver.ID
+}
+
+// saveLocation сохраняет местоположение водителя через сервис
+func (suite *NearbyOracleTestSuite) saveLocation(t *testing.T, driverID uuid.UUID, point fixtures.RoutePoint, recordedAt time.Time) {
+	location := entities.NewDriverLocation(driverID, point.Latitude, point.Longitude, recordedAt)
+	require.NoError(t, suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+}
+
+// searchNearby выполняет поиск через API и возвращает идентификаторы найденных водителей
+func (suite *NearbyOracleTestSuite) searchNearby(t *testing.T, center fixtures.RoutePoint, radiusKm float64, limit int) []uuid.UUID {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    "/api/v1/locations/nearby",
+		QueryParams: map[string]string{
+			"latitude":  fmt.Sprintf("%f", center.Latitude),
+			"longitude": fmt.Sprintf("%f", center.Longitude),
+			"radius_km": fmt.Sprintf("%f", radiusKm),
+			"limit":     fmt.Sprintf("%d", limit),
+		},
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode)
+
+	var nearby httpHandlers.NearbyDriversResponse
+	suite.env.API.UnmarshalResponse(response, &nearby)
+
+	driverIDs := make([]uuid.UUID, len(nearby.Drivers))
+	for i, driver := range nearby.Drivers {
+		driverIDs[i] = driver.DriverID
+	}
+	return driverIDs
+}
+
+// TestNearbyOracleTestSuite запускает тестовый suite
+func TestNearbyOracleTestSuite(t *testing.T) {
+	suite.Run(t, new(NearbyOracleTestSuite))
+}
@@ -0,0 +1,299 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// OnboardingScenariosTestSuite тестовый suite для вариантов онбординга водителя
+type OnboardingScenariosTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *OnboardingScenariosTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *OnboardingScenariosTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *OnboardingScenariosTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestOnboardingVariants тестирует варианты онбординга: для каждого проверяются переходы статусов
+// водителя и опубликованные события driver.registered и driver.status.changed
+func (suite *OnboardingScenariosTestSuite) TestOnboardingVariants() {
+	now := time.Now().UTC()
+	adultBirthDate := now.AddDate(-30, 0, 0)
+	validLicenseExpiry := now.AddDate(2, 0, 0)
+
+	testCases := []struct {
+		name          string
+		birthDate     time.Time
+		licenseExpiry time.Time
+		// strictValidation регистрация с включенным флагом strict_validation
+		strictValidation bool
+		// rejectedWith ошибка, с которой регистрация должна быть отклонена с кодом 400 без событий
+		rejectedWith error
+		// onboard проводит водителя по сценарию после регистрации
+		onboard func(t *testing.T, driverID uuid.UUID)
+		// transitions ожидаемая цепочка статусов после registered
+		transitions []entities.Status
+	}{
+		{
+			name:          "HappyPath",
+			birthDate:     adultBirthDate,
+			licenseExpiry: validLicenseExpiry,
+			onboard: func(t *testing.T, driverID uuid.UUID) {
+				suite.changeStatus(t, driverID, entities.StatusPendingVerification)
+				license := suite.uploadLicense(t, driverID, validLicenseExpiry)
+				suite.verifyDocument(t, license.ID)
+				suite.changeStatus(t, driverID, entities.StatusVerified)
+				suite.changeStatus(t, driverID, entities.StatusAvailable)
+
+				assert.NoError(t, suite.env.DriverService.ValidateDriverForOrder(suite.env.Ctx, driverID))
+			},
+			transitions: []entities.Status{
+				entities.StatusPendingVerification,
+				entities.StatusVerified,
+				entities.StatusAvailable,
+			},
+		},
+		{
+			name:          "DocumentRejectedThenReuploaded",
+			birthDate:     adultBirthDate,
+			licenseExpiry: validLicenseExpiry,
+			onboard: func(t *testing.T, driverID uuid.UUID) {
+				suite.changeStatus(t, driverID, entities.StatusPendingVerification)
+				license := suite.uploadLicense(t, driverID, validLicenseExpiry)
+
+				// Проверяющий отклоняет нечитаемую копию, водитель переводится в rejected
+				reason := "Нечитаемая копия удостоверения"
+				require.NoError(t, suite.env.DocumentRepo.UpdateStatus(suite.env.Ctx, license.ID,
+					entities.VerificationStatusRejected, stringPtr("admin"), &reason))
+				suite.changeStatus(t, driverID, entities.StatusRejected)
+
+				rejected, err := suite.env.DocumentRepo.GetByID(suite.env.Ctx, license.ID)
+				require.NoError(t, err)
+				assert.Equal(t, entities.VerificationStatusRejected, rejected.Status)
+				require.NotNil(t, rejected.RejectionReason)
+				assert.Equal(t, reason, *rejected.RejectionReason)
+
+				// Водитель загружает новую копию: документ того же типа заменяется и снова ждет проверки
+				rejected.FileURL = "https://example.com/documents/license-reupload.pdf"
+				rejected.Status = entities.VerificationStatusPending
+				rejected.VerifiedBy = nil
+				rejected.VerifiedAt = nil
+				rejected.RejectionReason = nil
+				require.NoError(t, suite.env.DocumentRepo.Update(suite.env.Ctx, rejected))
+				suite.changeStatus(t, driverID, entities.StatusPendingVerification)
+
+				assert.ErrorIs(t, suite.env.DriverService.ValidateDriverForOrder(suite.env.Ctx, driverID), entities.ErrDriverNotAvailable)
+
+				suite.verifyDocument(t, license.ID)
+				suite.changeStatus(t, driverID, entities.StatusVerified)
+				suite.changeStatus(t, driverID, entities.StatusAvailable)
+
+				reuploaded, err := suite.env.DocumentRepo.GetByID(suite.env.Ctx, license.ID)
+				require.NoError(t, err)
+				assert.Equal(t, entities.VerificationStatusVerified, reuploaded.Status)
+				assert.Nil(t, reuploaded.RejectionReason, "Причина отклонения не должна сохраняться после повторной проверки")
+				assert.NoError(t, suite.env.DriverService.ValidateDriverForOrder(suite.env.Ctx, driverID))
+			},
+			transitions: []entities.Status{
+				entities.StatusPendingVerification,
+				entities.StatusRejected,
+				entities.StatusPendingVerification,
+				entities.StatusVerified,
+				entities.StatusAvailable,
+			},
+		},
+		{
+			name:          "VerificationTimeout",
+			birthDate:     adultBirthDate,
+			licenseExpiry: validLicenseExpiry,
+			onboard: func(t *testing.T, driverID uuid.UUID) {
+				suite.changeStatus(t, driverID, entities.StatusPendingVerification)
+				suite.uploadLicense(t, driverID, validLicenseExpiry)
+
+				// Документ не проверяется, время ожидания истекает
+				_, err := suite.env.DB.ExecContext(suite.env.Ctx, "UPDATE drivers SET updated_at = $1 WHERE id = $2",
+					time.Now().Add(-helpers.TestVerificationTimeout-time.Hour), driverID)
+				require.NoError(t, err)
+
+				result, response := suite.env.API.RunJob(services.JobExpireVerification)
+				require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+				assert.Equal(t, 1, result.Affected)
+
+				events := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+				require.NotEmpty(t, events)
+				assert.Equal(t, "verification_timeout", events[len(events)-1].DataMap()["changed_by"])
+				assert.ErrorIs(t, suite.env.DriverService.ValidateDriverForOrder(suite.env.Ctx, driverID), entities.ErrDriverNotAvailable)
+
+				// Повторный запуск не затрагивает уже отклоненного водителя
+				result, response = suite.env.API.RunJob(services.JobExpireVerification)
+				require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+				assert.Zero(t, result.Affected)
+			},
+			transitions: []entities.Status{
+				entities.StatusPendingVerification,
+				entities.StatusRejected,
+			},
+		},
+		{
+			name:          "UnderageDriver",
+			birthDate:     now.AddDate(-entities.MinDriverAge+1, 0, 0),
+			licenseExpiry: validLicenseExpiry,
+			rejectedWith:  entities.ErrDriverUnderage,
+		},
+		{
+			name:             "ExpiredLicenseAtRegistration",
+			birthDate:        adultBirthDate,
+			licenseExpiry:    now.AddDate(0, 0, -1),
+			strictValidation: true,
+			rejectedWith:     entities.ErrLicenseExpired,
+		},
+	}
+
+	drivers := fixtures.CreateMultipleTestDrivers(len(testCases))
+	for i, tc := range testCases {
+		driver := drivers[i]
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			request := registrationRequest(driver, tc.birthDate, tc.licenseExpiry)
+			if tc.strictValidation {
+				snapshot := suite.env.Features.Snapshot()
+				suite.env.SetFeature(t, features.StrictValidation, true)
+				t.Cleanup(func() { suite.env.Features.Restore(snapshot) })
+			}
+
+			// Act
+			response := suite.env.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodPost,
+				URL:    "/api/v1/drivers",
+				Body:   request,
+			})
+
+			// Assert
+			if tc.rejectedWith != nil {
+				require.Equal(t, http.StatusBadRequest, response.StatusCode, string(response.Body))
+				var errorResponse httpHandlers.ErrorResponse
+				suite.env.API.UnmarshalResponse(response, &errorResponse)
+				assert.Equal(t, "INVALID_DATA", errorResponse.Code)
+				assert.Equal(t, tc.rejectedWith.Error(), errorResponse.Details)
+				assert.Empty(t, suite.eventsForPhone(driver.Phone), "Отклоненная регистрация не должна публиковать событий")
+				return
+			}
+
+			require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+			var created httpHandlers.DriverResponse
+			suite.env.API.UnmarshalResponse(response, &created)
+			assert.Equal(t, entities.StatusRegistered, created.Status)
+
+			tc.onboard(t, created.ID)
+
+			stored, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, created.ID)
+			require.NoError(t, err)
+			assert.Equal(t, tc.transitions[len(tc.transitions)-1], stored.Status)
+
+			suite.assertOnboardingEvents(t, created.ID, tc.transitions)
+		})
+	}
+}
+
+// registrationRequest формирует тело запроса регистрации по данным водителя
+func registrationRequest(driver *entities.Driver, birthDate, licenseExpiry time.Time) map[string]interface{} {
+	return map[string]interface{}{
+		"phone":           driver.Phone,
+		"email":           driver.Email,
+		"first_name":      driver.FirstName,
+		"last_name":       driver.LastName,
+		"birth_date":      birthDate.Format(time.RFC3339),
+		"passport_series": driver.PassportSeries,
+		"passport_number": driver.PassportNumber,
+		"license_number":  driver.LicenseNumber,
+		"license_expiry":  licenseExpiry.Format(time.RFC3339),
+	}
+}
+
+// changeStatus меняет статус водителя через API
+func (suite *OnboardingScenariosTestSuite) changeStatus(t *testing.T, driverID uuid.UUID, status entities.Status) {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPatch,
+		URL:    fmt.Sprintf("/api/v1/drivers/%s/status", driverID),
+		Body:   map[string]string{"status": string(status)},
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, "Переход в %s: %s", status, string(response.Body))
+}
+
+// uploadLicense загружает водительское удостоверение, ожидающее проверки
+func (suite *OnboardingScenariosTestSuite) uploadLicense(t *testing.T, driverID uuid.UUID, expiry time.Time) *entities.DriverDocument {
+	// API для документов не реализован, документ создается через репозиторий
+	document := fixtures.CreateTestDocument(driverID, entities.DocumentTypeDriverLicense)
+	document.DocumentNumber = "LIC-" + driverID.String()[:8]
+	document.ExpiryDate = expiry
+
+	require.NoError(t, suite.env.DocumentRepo.Create(suite.env.Ctx, document))
+	return document
+}
+
+// verifyDocument отмечает документ как проверенный
+func (suite *OnboardingScenariosTestSuite) verifyDocument(t *testing.T, documentID uuid.UUID) {
+	require.NoError(t, suite.env.DocumentRepo.UpdateStatus(suite.env.Ctx, documentID,
+		entities.VerificationStatusVerified, stringPtr("admin"), nil))
+}
+
+// eventsForPhone возвращает события driver.registered для водителя с указанным телефоном
+func (suite *OnboardingScenariosTestSuite) eventsForPhone(phone string) []helpers.RecordedEvent {
+	var result []helpers.RecordedEvent
+	for _, event := range suite.env.Events.EventsOfType("driver.registered") {
+		if event.DataMap()["phone"] == phone {
+			result = append(result, event)
+		}
+	}
+	return result
+}
+
+// assertOnboardingEvents проверяет одно событие регистрации и цепочку событий смены статуса в порядке публикации
+func (suite *OnboardingScenariosTestSuite) assertOnboardingEvents(t *testing.T, driverID uuid.UUID, transitions []entities.Status) {
+	assert.Len(t, suite.env.Events.EventsForDriver(driverID, "driver.registered"), 1)
+
+	statusEvents := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+	require.Len(t, statusEvents, len(transitions), "Количество событий driver.status.changed")
+
+	previous := entities.StatusRegistered
+	for i, event := range statusEvents {
+		data := event.DataMap()
+		assert.Equal(t, string(previous), data["old_status"], "old_status события %d", i)
+		assert.Equal(t, string(transitions[i]), data["new_status"], "new_status события %d", i)
+		previous = transitions[i]
+	}
+}
+
+// TestOnboardingScenariosTestSuite запускает тестовый suite
+func TestOnboardingScenariosTestSuite(t *testing.T) {
+	suite.Run(t, new(OnboardingScenariosTestSuite))
+}
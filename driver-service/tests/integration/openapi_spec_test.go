@@ -0,0 +1,65 @@
+//go:build integration
+
+package integration
+
+import (
+	"regexp"
+	"testing"
+	"time"
+
+	"driver-service/internal/config"
+	httpServer "driver-service/internal/interfaces/http"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/gin-gonic/gin"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// TestOpenAPISpecMatchesRouter тестирует, что OpenAPI спецификация, по которой считается покрытие эндпоинтов,
+// описывает все маршруты сервиса и помечает еще не реализованные операции x-implemented: false
+func TestOpenAPISpecMatchesRouter(t *testing.T) {
+	gin.SetMode(gin.TestMode)
+	logger := helpers.CreateTestLogger(t)
+
+	// Arrange
+	// Для списка маршрутов сервисы не нужны: обработчики не вызываются
+	cfg := &config.Config{
+		Server: config.ServerConfig{
+			HTTPPort:    8001,
+			Environment: "test",
+			Timeout:     30 * time.Second,
+		},
+	}
+	server := httpServer.NewServer(cfg, logger,
+		httpHandlers.NewDriverHandler(nil, logger), httpHandlers.NewLocationHandler(nil, logger))
+
+	operations, err := helpers.LoadOpenAPIOperations()
+	require.NoError(t, err)
+
+	// Act
+	// Параметры gin (:id) приводятся к виду OpenAPI ({id})
+	ginParam := regexp.MustCompile(`:([A-Za-z_]+)`)
+	routes := make(map[string]bool)
+	for _, route := range server.GetRouter().Routes() {
+		routes[route.Method+" "+ginParam.ReplaceAllString(route.Path, "{$1}")] = true
+	}
+
+	// Assert
+	documented := make(map[string]bool)
+	for _, operation := range operations {
+		key := operation.Method + " " + operation.Path
+		documented[key] = true
+
+		if operation.Implemented {
+			assert.True(t, routes[key], "Операция %s (%s) описана в спецификации, но маршрута нет", key, operation.OperationID)
+		} else {
+			assert.False(t, routes[key], "Операция %s реализована: уберите x-implemented: false из спецификации", key)
+		}
+	}
+
+	for route := range routes {
+		assert.True(t, documented[route], "Маршрут %s не описан в api/openapi/driver-api.yaml", route)
+	}
+}
@@ -0,0 +1,163 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/nats"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры замера задержки назначения заказа
+const (
+	assignmentIterations   = 100
+	assignmentPollInterval = 5 * time.Millisecond
+	assignmentTimeout      = 5 * time.Second // итерация без перевода в busy за это время считается потерянной
+)
+
+// OrderAssignmentLatencyTestSuite тестирует задержку от публикации order.assigned в NATS до перевода водителя
+// в статус busy: по опросу GET /drivers/{id} и по событию driver.status.changed. Перцентили по итерациям
+// проверяются по SLO диспетчеризации (DISPATCH_SLO_P95, DISPATCH_SLO_P99). Без тестового NATS тесты пропускаются.
+type OrderAssignmentLatencyTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	nats    *helpers.NATSHelper
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *OrderAssignmentLatencyTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *OrderAssignmentLatencyTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *OrderAssignmentLatencyTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.env.StartPushGateway(suite.T())
+	suite.nats = suite.env.StartEventSubscriber(suite.T())
+}
+
+// TestOrderAssignmentLatencySLO тестирует перцентили задержки перевода водителя в busy после order.assigned
+func (suite *OrderAssignmentLatencyTestSuite) TestOrderAssignmentLatencySLO() {
+	// Arrange
+	slo := helpers.LoadDispatchSLO(suite.T())
+	drivers := make([]uuid.UUID, assignmentIterations)
+	for i := range drivers {
+		drivers[i] = suite.createDriver(suite.T(), entities.StatusOnShift)
+	}
+
+	// Act
+	var apiLatencies, eventLatencies []time.Duration
+	lost := 0
+	for _, driverID := range drivers {
+		// Публикация через новое соединение входит в задержку: замер дает оценку сверху
+		published := time.Now()
+		suite.nats.PublishOrderEvent(nats.SubjectOrderAssigned, uuid.New(), driverID, nil)
+
+		apiLatency, ok := suite.waitBusy(driverID, published)
+		if !ok {
+			lost++
+			continue
+		}
+		apiLatencies = append(apiLatencies, apiLatency)
+
+		events := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+		require.Len(suite.T(), events, 1, "Водитель %s", driverID)
+		assert.Equal(suite.T(), string(entities.StatusBusy), events[0].DataMap()["new_status"])
+		eventLatencies = append(eventLatencies, events[0].Timestamp.Sub(published))
+	}
+
+	// Assert
+	assert.Zero(suite.T(), lost, "Назначения без перевода водителя в busy за %v", assignmentTimeout)
+	helpers.AssertDispatchSLO(suite.T(), "order.assigned -> API status busy", helpers.NewLatencyPercentiles(apiLatencies), slo)
+	helpers.AssertDispatchSLO(suite.T(), "order.assigned -> driver.status.changed", helpers.NewLatencyPercentiles(eventLatencies), slo)
+}
+
+// TestAssignmentToDriverNotOnShift тестирует, что назначение водителю не на смене его статус не меняет
+func (suite *OrderAssignmentLatencyTestSuite) TestAssignmentToDriverNotOnShift() {
+	for _, status := range []entities.Status{entities.StatusAvailable, entities.StatusBusy} {
+		suite.T().Run(string(status), func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t, status)
+			marker := suite.createDriver(t, entities.StatusOnShift)
+
+			// Act - назначение marker обрабатывается после назначения driverID
+			suite.nats.PublishOrderEvent(nats.SubjectOrderAssigned, uuid.New(), driverID, nil)
+			suite.nats.PublishOrderEvent(nats.SubjectOrderAssigned, uuid.New(), marker, nil)
+
+			// Assert
+			_, ok := suite.waitBusy(marker, time.Now())
+			require.True(t, ok, "Назначение не обработано")
+
+			driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+			require.NoError(t, err)
+			assert.Equal(t, status, driver.Status)
+			assert.Empty(t, suite.env.Events.EventsForDriver(driverID, "driver.status.changed"))
+		})
+	}
+}
+
+// waitBusy опрашивает GET /drivers/{id}, пока водитель не станет busy, и возвращает задержку от since
+func (suite *OrderAssignmentLatencyTestSuite) waitBusy(driverID uuid.UUID, since time.Time) (time.Duration, bool) {
+	ctx, cancel := context.WithTimeout(context.Background(), assignmentTimeout)
+	defer cancel()
+
+	ticker := time.NewTicker(assignmentPollInterval)
+	defer ticker.Stop()
+	for {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+		})
+		if response.StatusCode == http.StatusOK {
+			var driver httpHandlers.DriverResponse
+			if err := json.Unmarshal(response.Body, &driver); err == nil && driver.Status == entities.StatusBusy {
+				return time.Since(since), true
+			}
+		}
+
+		select {
+		case <-ticker.C:
+		case <-ctx.Done():
+			return 0, false
+		}
+	}
+}
+
+// createDriver создает водителя с уникальными контактами в статусе status
+func (suite *OrderAssignmentLatencyTestSuite) createDriver(t *testing.T, status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900100%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("assignment.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("AS%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+	require.NoError(t, suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	return created.ID
+}
+
+// TestOrderAssignmentLatencyTestSuite запускает тестовый suite
+func TestOrderAssignmentLatencyTestSuite(t *testing.T) {
+	suite.Run(t, new(OrderAssignmentLatencyTestSuite))
+}
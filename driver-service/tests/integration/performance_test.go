@@ -27,6 +27,7 @@ type PerformanceTestSuite struct {
 	locationService services.LocationService
 	perfHelper      *helpers.PerformanceTestHelper
 	ctx             context.Context
+	healthBaseline  helpers.ServiceHealth
 }
 
 // SetupSuite выполняется один раз перед всеми тестами
@@ -59,6 +60,12 @@ func (suite *PerformanceTestSuite) TearDownSuite() {
 // SetupTest выполняется перед каждым тестом
 func (suite *PerformanceTestSuite) SetupTest() {
 	suite.testDB.CleanupTables(suite.T())
+	suite.healthBaseline = helpers.CaptureServiceHealth(suite.testDB)
+}
+
+// TearDownTest выполняется после каждого теста: проверяет утечки, которые не видны по пропускной способности
+func (suite *PerformanceTestSuite) TearDownTest() {
+	helpers.AssertServiceHealthRecovered(suite.T(), suite.testDB, suite.healthBaseline, 10*time.Second)
 }
 
 // TestDriverCreationPerformance тестирует производительность создания водителей
@@ -214,6 +221,107 @@ func (suite *PerformanceTestSuite) TestNearbyDriversSearchPerformance() {
 	}
 }
 
+// TestRedisPerformance тестирует задержку и пропускную способность Redis последовательно,
+// в нескольких соединениях и с конвейером команд
+func (suite *PerformanceTestSuite) TestRedisPerformance() {
+	redis := helpers.NewRedisHelper(suite.T())
+	defer redis.DeleteKeys("benchmark:*")
+	slo := helpers.LoadRedisSLO(suite.T())
+
+	set := func(i int) []string {
+		return []string{"SET", fmt.Sprintf("benchmark:%d", i%1000), "value"}
+	}
+	get := func(i int) []string {
+		return []string{"GET", fmt.Sprintf("benchmark:%d", i%1000)}
+	}
+
+	testCases := []struct {
+		name        string
+		command     func(i int) []string
+		concurrency int
+		pipeline    int
+	}{
+		{name: "SET sequential", command: set, concurrency: 1, pipeline: 1},
+		{name: "SET concurrent", command: set, concurrency: 10, pipeline: 1},
+		{name: "SET pipelined", command: set, concurrency: 1, pipeline: 16},
+		{name: "GET concurrent pipelined", command: get, concurrency: 10, pipeline: 16},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			result := suite.perfHelper.BenchmarkRedis(redis, helpers.RedisBenchmarkOptions{
+				Operation:   tc.name,
+				Requests:    10000,
+				Concurrency: tc.concurrency,
+				Pipeline:    tc.pipeline,
+				Command:     tc.command,
+			})
+			helpers.AssertRedisSLO(t, result, slo)
+		})
+	}
+}
+
+// TestNATSPublishPerformance тестирует пропускную способность публикации событий сервиса и задержку
+// подтверждения в core NATS и JetStream
+func (suite *PerformanceTestSuite) TestNATSPublishPerformance() {
+	const stream = "DRIVER_EVENTS_BENCHMARK"
+
+	nats := helpers.NewNATSHelper(suite.T())
+	subjects := helpers.ServiceSubjects(suite.T())
+	nats.EnsureStream(stream, subjects)
+
+	testCases := []struct {
+		name        string
+		jetStream   bool
+		concurrency int
+		batch       int
+	}{
+		{name: "Core sequential", jetStream: false, concurrency: 1, batch: 1},
+		{name: "Core concurrent batched", jetStream: false, concurrency: 10, batch: 100},
+		{name: "JetStream sync acks", jetStream: true, concurrency: 1, batch: 1},
+		{name: "JetStream async acks", jetStream: true, concurrency: 10, batch: 100},
+	}
+
+	jetStreamMessages := 0
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			result := suite.perfHelper.BenchmarkNATS(nats, helpers.NATSBenchmarkOptions{
+				Operation:   tc.name,
+				Subjects:    subjects,
+				Messages:    5000,
+				Concurrency: tc.concurrency,
+				Batch:       tc.batch,
+				JetStream:   tc.jetStream,
+			})
+			assert.Zero(t, result.Errors, "Сообщения без подтверждения")
+
+			if tc.jetStream {
+				jetStreamMessages += result.OperationCount - result.Errors
+			}
+		})
+	}
+
+	// Подтвержденные JetStream сообщения записаны в поток; core NATS сообщения поток тоже сохраняет
+	assert.GreaterOrEqual(suite.T(), nats.StreamMessages(stream), jetStreamMessages)
+}
+
+// TestNATSConsumerLagCatchUp тестирует, как отстающий консьюмер событий сервиса догоняет очередь JetStream:
+// подтверждения задерживаются на CONSUMER_LAG, пока публикуются события, затем задержка снимается
+func (suite *PerformanceTestSuite) TestNATSConsumerLagCatchUp() {
+	// Arrange
+	options := helpers.LoadConsumerLagOptions(suite.T())
+	options.Subjects = helpers.ServiceSubjects(suite.T())
+
+	nats := helpers.NewNATSHelper(suite.T())
+	nats.EnsureStream(options.Stream, options.Subjects)
+
+	// Act
+	result := nats.ConsumerLag(options)
+
+	// Assert
+	helpers.AssertConsumerCaughtUp(suite.T(), result, options)
+}
+
 // TestMemoryUsage тестирует использование памяти
 func (suite *PerformanceTestSuite) TestMemoryUsage() {
 	// Тест создания большого количества объектов
@@ -0,0 +1,172 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// PhoneNormalizationTestSuite тестовый suite для форматов номеров телефонов: номер сохраняется в E.164,
+// поэтому эквивалентные представления одного номера - дубликаты
+type PhoneNormalizationTestSuite struct {
+	suite.Suite
+	env      *helpers.TestEnvironment
+	sequence int
+}
+
+// phoneFormatGroup эквивалентные представления одного номера
+type phoneFormatGroup struct {
+	name      string
+	canonical string // E.164
+	variants  []string
+}
+
+// phoneFormatGroups номера в разных форматах записи
+var phoneFormatGroups = []phoneFormatGroup{
+	{
+		name:      "russian mobile",
+		canonical: "+79161234567",
+		variants: []string{
+			"+79161234567",
+			"+7 916 123 45 67",
+			"+7-916-123-45-67",
+			"+7 (916) 123-45-67",
+			"8 (916) 123-45-67",
+			"89161234567",
+			"79161234567",
+		},
+	},
+	{
+		name:      "belarus",
+		canonical: "+375291234567",
+		variants:  []string{"+375291234567", "+375 29 123-45-67", "+375 (29) 1234567"},
+	},
+	{
+		name:      "usa",
+		canonical: "+12025550143",
+		variants:  []string{"+12025550143", "+1 202 555 0143", "+1 (202) 555-0143"},
+	},
+	{
+		name:      "uk",
+		canonical: "+442079460958",
+		variants:  []string{"+442079460958", "+44 20 7946 0958"},
+	},
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *PhoneNormalizationTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *PhoneNormalizationTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом. Строгая валидация требует номер уже в E.164
+// и отклонила бы остальные форматы, поэтому выключается.
+func (suite *PhoneNormalizationTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.env.SetFeature(suite.T(), features.StrictValidation, false)
+}
+
+// TestPhoneFormatsAccepted тестирует, что все распространенные форматы принимаются и сохраняются в E.164
+func (suite *PhoneNormalizationTestSuite) TestPhoneFormatsAccepted() {
+	for _, group := range phoneFormatGroups {
+		for _, variant := range group.variants {
+			suite.T().Run(group.name+"/"+variant, func(t *testing.T) {
+				// Arrange
+				suite.env.DB.CleanupTables(t)
+
+				// Act
+				response := suite.createDriverWithPhone(variant)
+
+				// Assert
+				require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+				var driver httpHandlers.DriverResponse
+				suite.env.API.UnmarshalResponse(response, &driver)
+				assert.Equal(t, group.canonical, driver.Phone)
+			})
+		}
+	}
+}
+
+// TestDuplicateDetectionAcrossFormats тестирует обнаружение дубликатов среди эквивалентных представлений номера
+func (suite *PhoneNormalizationTestSuite) TestDuplicateDetectionAcrossFormats() {
+	for _, group := range phoneFormatGroups {
+		suite.T().Run(group.name, func(t *testing.T) {
+			// Arrange - первым регистрируется неканонический вариант
+			suite.env.DB.CleanupTables(t)
+
+			original := group.variants[len(group.variants)-1]
+			first := suite.createDriverWithPhone(original)
+			require.Equal(t, http.StatusCreated, first.StatusCode, string(first.Body))
+
+			for _, variant := range group.variants {
+				// Act
+				response := suite.createDriverWithPhone(variant)
+
+				// Assert
+				assert.Equal(t, http.StatusConflict, response.StatusCode, "Формат %q - дубликат %q", variant, original)
+				suite.env.API.AssertErrorResponse(response, "DRIVER_EXISTS")
+			}
+		})
+	}
+}
+
+// TestInvalidPhones тестирует отклонение номеров, которые нельзя привести к E.164
+func (suite *PhoneNormalizationTestSuite) TestInvalidPhones() {
+	testCases := []struct {
+		name         string
+		phone        string
+		expectedCode string
+	}{
+		{name: "empty", phone: ""}, // обязательное поле запроса отклоняется до проверки номера, без кода
+		{name: "too short", phone: "+7 916 123", expectedCode: "INVALID_DATA"},
+		{name: "longer than column", phone: "+7916123456789012345678", expectedCode: "INVALID_DATA"},
+		{name: "letters", phone: "+7 916 ABC-45-67", expectedCode: "INVALID_DATA"},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			response := suite.createDriverWithPhone(tc.phone)
+
+			// Assert
+			assert.Equal(t, http.StatusBadRequest, response.StatusCode, string(response.Body))
+			suite.env.API.AssertErrorResponse(response, tc.expectedCode)
+		})
+	}
+}
+
+// createDriverWithPhone создает водителя с указанным телефоном и уникальными остальными полями
+func (suite *PhoneNormalizationTestSuite) createDriverWithPhone(phone string) *helpers.APIResponse {
+	suite.sequence++
+
+	request := helpers.CreateDriverRequest()
+	request["phone"] = phone
+	request["email"] = fmt.Sprintf("phone%d@example.com", suite.sequence)
+	request["license_number"] = fmt.Sprintf("PHN%06d", suite.sequence)
+
+	return suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+}
+
+// TestPhoneNormalizationTestSuite запускает тестовый suite
+func TestPhoneNormalizationTestSuite(t *testing.T) {
+	suite.Run(t, new(PhoneNormalizationTestSuite))
+}
@@ -0,0 +1,136 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// PointInTimeLocationTestSuite тестовый suite для запроса "где был водитель в момент T"
+type PointInTimeLocationTestSuite struct {
+	suite.Suite
+	env          *helpers.TestEnvironment
+	testDriverID uuid.UUID
+	track        []*entities.DriverLocation
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *PointInTimeLocationTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *PointInTimeLocationTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *PointInTimeLocationTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	suite.testDriverID = driver.ID
+
+	// Трек из 5 точек с интервалом в минуту; время округлено до секунд, чтобы совпадать с RFC3339
+	baseTime := time.Now().Add(-10 * time.Minute).Truncate(time.Second)
+	route := fixtures.CreateStraightRoute(
+		fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173},
+		fixtures.RoutePoint{Latitude: 55.7658, Longitude: 37.6373},
+		4,
+	)
+
+	suite.track = make([]*entities.DriverLocation, len(route))
+	for i, point := range route {
+		suite.track[i] = entities.NewDriverLocation(driver.ID, point.Latitude, point.Longitude, baseTime.Add(time.Duration(i)*time.Minute))
+	}
+	require.NoError(suite.T(), suite.env.LocationService.BatchUpdateLocations(suite.env.Ctx, suite.track))
+}
+
+// TestLocationAtExactSample тестирует запрос в момент, совпадающий с точкой трека
+func (suite *PointInTimeLocationTestSuite) TestLocationAtExactSample() {
+	for i, sample := range suite.track {
+		// Act
+		location, response := suite.env.API.GetLocationAt(suite.testDriverID, sample.RecordedAt)
+
+		// Assert
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, "Точка %d", i)
+		assert.InDelta(suite.T(), sample.Latitude, location.Latitude, 1e-6, "Точка %d", i)
+		assert.InDelta(suite.T(), sample.Longitude, location.Longitude, 1e-6, "Точка %d", i)
+	}
+}
+
+// TestLocationBetweenSamples тестирует запрос между соседними точками трека
+func (suite *PointInTimeLocationTestSuite) TestLocationBetweenSamples() {
+	for i := 1; i < len(suite.track); i++ {
+		prev, next := suite.track[i-1], suite.track[i]
+		at := prev.RecordedAt.Add(next.RecordedAt.Sub(prev.RecordedAt) / 4)
+
+		// Act
+		location, response := suite.env.API.GetLocationAt(suite.testDriverID, at)
+
+		// Assert
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+
+		// Между точками действует последняя записанная точка, интерполяции нет
+		assert.InDelta(suite.T(), prev.Latitude, location.Latitude, 1e-6, "Отрезок %d-%d", i-1, i)
+		assert.InDelta(suite.T(), prev.Longitude, location.Longitude, 1e-6, "Отрезок %d-%d", i-1, i)
+		assert.True(suite.T(), location.RecordedAt.Equal(prev.RecordedAt), "Отрезок %d-%d", i-1, i)
+	}
+}
+
+// TestLocationOutsideTrackRange тестирует запрос за пределами трека
+func (suite *PointInTimeLocationTestSuite) TestLocationOutsideTrackRange() {
+	first := suite.track[0]
+	last := suite.track[len(suite.track)-1]
+
+	suite.T().Run("before first sample", func(t *testing.T) {
+		_, response := suite.env.API.GetLocationAt(suite.testDriverID, first.RecordedAt.Add(-time.Hour))
+
+		assert.Equal(t, http.StatusNotFound, response.StatusCode)
+		suite.env.API.AssertErrorResponse(response, "LOCATION_NOT_FOUND")
+	})
+
+	suite.T().Run("after last sample", func(t *testing.T) {
+		location, response := suite.env.API.GetLocationAt(suite.testDriverID, last.RecordedAt.Add(time.Minute))
+
+		require.Equal(t, http.StatusOK, response.StatusCode)
+		assert.InDelta(t, last.Latitude, location.Latitude, 1e-6)
+		assert.InDelta(t, last.Longitude, location.Longitude, 1e-6)
+	})
+
+	suite.T().Run("unknown driver", func(t *testing.T) {
+		_, response := suite.env.API.GetLocationAt(uuid.New(), last.RecordedAt)
+
+		assert.Equal(t, http.StatusNotFound, response.StatusCode)
+		suite.env.API.AssertErrorResponse(response, "DRIVER_NOT_FOUND")
+	})
+
+	suite.T().Run("invalid timestamp", func(t *testing.T) {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method:      http.MethodGet,
+			URL:         fmt.Sprintf("/api/v1/drivers/%s/locations/at", suite.testDriverID),
+			QueryParams: map[string]string{"timestamp": "yesterday"},
+		})
+
+		assert.Equal(t, http.StatusBadRequest, response.StatusCode)
+		suite.env.API.AssertErrorResponse(response, "INVALID_TIMESTAMP")
+	})
+}
+
+// TestPointInTimeLocationTestSuite запускает тестовый suite
+func TestPointInTimeLocationTestSuite(t *testing.T) {
+	suite.Run(t, new(PointInTimeLocationTestSuite))
+}
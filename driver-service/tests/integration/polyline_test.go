@@ -0,0 +1,104 @@
+//go:build integration
+
+package integration
+
+import (
+	"testing"
+
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// TestPolylineEncoding тестирует кодирование и декодирование Google Encoded Polyline
+func TestPolylineEncoding(t *testing.T) {
+	testCases := []struct {
+		name    string
+		points  []helpers.LatLng
+		encoded string
+	}{
+		{
+			name:    "empty",
+			points:  nil,
+			encoded: "",
+		},
+		{
+			// Пример из документации Google Maps
+			name: "reference example",
+			points: []helpers.LatLng{
+				{Latitude: 38.5, Longitude: -120.2},
+				{Latitude: 40.7, Longitude: -120.95},
+				{Latitude: 43.252, Longitude: -126.453},
+			},
+			encoded: "_p~iF~ps|U_ulLnnqC_mqNvxq`@",
+		},
+		{
+			name:    "single point at origin",
+			points:  []helpers.LatLng{{Latitude: 0, Longitude: 0}},
+			encoded: "??",
+		},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			assert.Equal(t, tc.encoded, helpers.EncodePolyline(tc.points))
+
+			decoded, err := helpers.DecodePolyline(tc.encoded)
+			require.NoError(t, err)
+			require.Len(t, decoded, len(tc.points))
+			for i := range tc.points {
+				assert.InDelta(t, tc.points[i].Latitude, decoded[i].Latitude, 1e-5)
+				assert.InDelta(t, tc.points[i].Longitude, decoded[i].Longitude, 1e-5)
+			}
+		})
+	}
+}
+
+// TestPolylineRoundTripRoute тестирует кодирование маршрута из фикстур без потери точности выше 1e-5
+func TestPolylineRoundTripRoute(t *testing.T) {
+	// Arrange
+	route := fixtures.CreateCenterToAirportRoute(50)
+	points := make([]helpers.LatLng, len(route))
+	for i, point := range route {
+		points[i] = helpers.LatLng{Latitude: point.Latitude, Longitude: point.Longitude}
+	}
+
+	// Act
+	encoded := helpers.EncodePolyline(points)
+	decoded, err := helpers.DecodePolyline(encoded)
+
+	// Assert
+	require.NoError(t, err)
+	require.Len(t, decoded, len(points))
+	for i := range points {
+		assert.InDelta(t, points[i].Latitude, decoded[i].Latitude, 1e-5, "Точка %d", i)
+		assert.InDelta(t, points[i].Longitude, decoded[i].Longitude, 1e-5, "Точка %d", i)
+	}
+
+	// Закодированный маршрут должен быть заметно компактнее JSON-представления
+	assert.Less(t, len(encoded), len(points)*10)
+}
+
+// TestPolylineDecodeInvalid тестирует обработку поврежденных строк
+func TestPolylineDecodeInvalid(t *testing.T) {
+	testCases := []struct {
+		name    string
+		encoded string
+	}{
+		{name: "truncated value", encoded: "_p~iF~ps|U_"},
+		{name: "missing longitude", encoded: "_p~iF"},
+		{name: "invalid character", encoded: "_p~iF\x01"},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			_, err := helpers.DecodePolyline(tc.encoded)
+			assert.Error(t, err)
+		})
+	}
+
+	// Примечание: в ответах сервиса пока нет поля route (сводка поездки отсутствует),
+	// поэтому хелпер используется для компактных фикстур маршрутов.
+}
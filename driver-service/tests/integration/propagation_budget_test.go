@@ -0,0 +1,171 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"strconv"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Бюджеты распространения изменений между частями API
+const (
+	statusPropagationBudget   = 500 * time.Millisecond // смена статуса - список активных водителей
+	locationPropagationBudget = 500 * time.Millisecond // обновление местоположения - поиск поблизости
+)
+
+// PropagationBudgetTestSuite проверяет, что результат каждого шага сценария становится виден
+// в зависимых частях API в пределах бюджета, измеренного опросом (helpers.TestSpan.StepWithin)
+type PropagationBudgetTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *PropagationBudgetTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *PropagationBudgetTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *PropagationBudgetTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestDriverShiftPropagation тестирует выход водителя на линию, обновление местоположения и уход с линии
+func (suite *PropagationBudgetTestSuite) TestDriverShiftPropagation() {
+	// Arrange
+	env := suite.env.ForTest(suite.T())
+	driverID := suite.createDriver(entities.StatusVerified)
+	point := fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+
+	// Act & Assert
+	env.Span.StepWithin(suite.T(), "go available", helpers.PropagationBudget{
+		Within: statusPropagationBudget,
+		Visible: func(context.Context) (bool, error) {
+			return suite.isActive(env, driverID)
+		},
+	}, func() {
+		suite.changeStatus(env, driverID, entities.StatusAvailable)
+	})
+
+	env.Span.StepWithin(suite.T(), "update location", helpers.PropagationBudget{
+		Within: locationPropagationBudget,
+		Visible: func(context.Context) (bool, error) {
+			return suite.isNearby(env, driverID, point)
+		},
+	}, func() {
+		response := env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodPost,
+			URL:    env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+			Body: map[string]interface{}{
+				"latitude":  point.Latitude,
+				"longitude": point.Longitude,
+			},
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	})
+
+	env.Span.StepWithin(suite.T(), "go offline", helpers.PropagationBudget{
+		Within: statusPropagationBudget,
+		Visible: func(context.Context) (bool, error) {
+			active, err := suite.isActive(env, driverID)
+			return !active, err
+		},
+	}, func() {
+		suite.changeStatus(env, driverID, entities.StatusOffline)
+	})
+}
+
+// createDriver создает водителя в статусе status
+func (suite *PropagationBudgetTestSuite) createDriver(status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900999%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("propagation.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("PB%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	return created.ID
+}
+
+// changeStatus меняет статус водителя через API
+func (suite *PropagationBudgetTestSuite) changeStatus(env *helpers.TestEnvironment, driverID uuid.UUID, status entities.Status) {
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPatch,
+		URL:    env.API.APIPath(fmt.Sprintf("/drivers/%s/status", driverID)),
+		Body:   map[string]string{"status": string(status)},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+}
+
+// isActive проверяет, что водитель есть в списке активных водителей
+func (suite *PropagationBudgetTestSuite) isActive(env *helpers.TestEnvironment, driverID uuid.UUID) (bool, error) {
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    env.API.APIPath("/drivers/active"),
+	})
+	if response.StatusCode != http.StatusOK {
+		return false, fmt.Errorf("active drivers: status %d", response.StatusCode)
+	}
+
+	var active struct {
+		Drivers []*httpHandlers.DriverResponse `json:"drivers"`
+	}
+	env.API.UnmarshalResponse(response, &active)
+	for _, driver := range active.Drivers {
+		if driver.ID == driverID {
+			return true, nil
+		}
+	}
+	return false, nil
+}
+
+// isNearby проверяет, что поиск поблизости от point находит водителя
+func (suite *PropagationBudgetTestSuite) isNearby(env *helpers.TestEnvironment, driverID uuid.UUID, point fixtures.RoutePoint) (bool, error) {
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    env.API.APIPath("/locations/nearby"),
+		QueryParams: map[string]string{
+			"latitude":  strconv.FormatFloat(point.Latitude, 'f', -1, 64),
+			"longitude": strconv.FormatFloat(point.Longitude, 'f', -1, 64),
+			"radius_km": "1",
+		},
+	})
+	if response.StatusCode != http.StatusOK {
+		return false, fmt.Errorf("nearby search: status %d", response.StatusCode)
+	}
+
+	var nearby httpHandlers.NearbyDriversResponse
+	env.API.UnmarshalResponse(response, &nearby)
+	for _, driver := range nearby.Drivers {
+		if driver.DriverID == driverID {
+			return true, nil
+		}
+	}
+	return false, nil
+}
+
+// TestPropagationBudgetTestSuite запускает тестовый suite
+func TestPropagationBudgetTestSuite(t *testing.T) {
+	suite.Run(t, new(PropagationBudgetTestSuite))
+}
@@ -0,0 +1,309 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// PushNotificationsTestSuite тестирует push-уведомления водителям через имитацию шлюзов FCM и APNS
+type PushNotificationsTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов в подтестах
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *PushNotificationsTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *PushNotificationsTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *PushNotificationsTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestOrderAssignedNotification тестирует уведомление водителя о назначенном заказе на обеих платформах
+func (suite *PushNotificationsTestSuite) TestOrderAssignedNotification() {
+	testCases := []struct {
+		name     string
+		platform entities.PushPlatform
+	}{
+		{name: "Android", platform: entities.PushPlatformFCM},
+		{name: "iOS", platform: entities.PushPlatformAPNS},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			gateway := suite.env.StartPushGateway(t)
+			token := fmt.Sprintf("%s-device-%s", tc.platform, uuid.New())
+			driverID := suite.createDriver(t, tc.platform, token)
+			orderID := uuid.New()
+
+			// Act
+			err := suite.env.Notifications.HandleOrderAssigned(suite.env.Ctx, driverID, orderID)
+
+			// Assert
+			require.NoError(t, err)
+			delivered := gateway.Delivered()
+			require.Len(t, delivered, 1)
+
+			notification := delivered[0]
+			assert.Equal(t, tc.platform, notification.Platform)
+			assert.Equal(t, token, notification.Token)
+			assert.Equal(t, "Новый заказ", notification.Title)
+			assert.NotEmpty(t, notification.Body)
+			assert.Equal(t, map[string]string{
+				"type":      services.NotificationOrderAssigned,
+				"order_id":  orderID.String(),
+				"driver_id": driverID.String(),
+			}, notification.Data)
+
+			if tc.platform == entities.PushPlatformAPNS {
+				assert.Equal(t, "alert", notification.Header.Get("apns-push-type"))
+				assert.Equal(t, helpers.TestAPNSTopic, notification.Header.Get("apns-topic"))
+			}
+		})
+	}
+
+	suite.T().Run("DriverWithoutToken", func(t *testing.T) {
+		gateway := suite.env.StartPushGateway(t)
+		driverID := suite.createDriver(t, "", "")
+
+		err := suite.env.Notifications.HandleOrderAssigned(suite.env.Ctx, driverID, uuid.New())
+
+		assert.ErrorIs(t, err, entities.ErrPushTokenMissing)
+		assert.Empty(t, gateway.Requests(), "Без токена шлюз не вызывается")
+	})
+
+	suite.T().Run("UnknownDriver", func(t *testing.T) {
+		gateway := suite.env.StartPushGateway(t)
+
+		err := suite.env.Notifications.HandleOrderAssigned(suite.env.Ctx, uuid.New(), uuid.New())
+
+		assert.ErrorIs(t, err, entities.ErrDriverNotFound)
+		assert.Empty(t, gateway.Requests())
+	})
+}
+
+// TestDocumentExpiryNotification тестирует напоминания о верифицированных документах, срок которых скоро истекает
+func (suite *PushNotificationsTestSuite) TestDocumentExpiryNotification() {
+	// Arrange
+	gateway := suite.env.StartPushGateway(suite.T())
+	now := time.Now()
+
+	androidToken := "fcm-expiring-" + uuid.New().String()
+	androidDriver := suite.createDriver(suite.T(), entities.PushPlatformFCM, androidToken)
+	license := suite.createDocument(androidDriver, entities.DocumentTypeDriverLicense, "77AA000001", now.AddDate(0, 0, 5), entities.VerificationStatusVerified)
+	suite.createDocument(androidDriver, entities.DocumentTypeMedicalCert, "MED-000001", now.AddDate(0, 0, 60), entities.VerificationStatusVerified)
+
+	iosToken := "apns-expiring-" + uuid.New().String()
+	iosDriver := suite.createDriver(suite.T(), entities.PushPlatformAPNS, iosToken)
+	insurance := suite.createDocument(iosDriver, entities.DocumentTypeInsurance, "INS-000001", now.AddDate(0, 0, 10), entities.VerificationStatusVerified)
+
+	// Водитель без токена и непроверенный документ уведомлений не получают
+	noTokenDriver := suite.createDriver(suite.T(), "", "")
+	suite.createDocument(noTokenDriver, entities.DocumentTypeDriverLicense, "77AA000002", now.AddDate(0, 0, 5), entities.VerificationStatusVerified)
+	pendingDriver := suite.createDriver(suite.T(), entities.PushPlatformFCM, "fcm-pending-"+uuid.New().String())
+	suite.createDocument(pendingDriver, entities.DocumentTypeDriverLicense, "77AA000003", now.AddDate(0, 0, 5), entities.VerificationStatusPending)
+
+	// Act
+	sent, err := suite.env.Notifications.NotifyExpiringDocuments(suite.env.Ctx, 30)
+
+	// Assert
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), 2, sent)
+	require.Len(suite.T(), gateway.Delivered(), 2)
+
+	expected := map[string]*entities.DriverDocument{androidToken: license, iosToken: insurance}
+	for token, document := range expected {
+		requests := gateway.RequestsFor(token)
+		require.Len(suite.T(), requests, 1, "Одно уведомление на документ %s", document.DocumentNumber)
+
+		notification := requests[0]
+		assert.Equal(suite.T(), "Истекает срок документа", notification.Title)
+		assert.Contains(suite.T(), notification.Body, document.DocumentNumber)
+		assert.Contains(suite.T(), notification.Body, document.ExpiryDate.Format("02.01.2006"))
+		assert.Equal(suite.T(), services.NotificationDocumentExpiring, notification.Data["type"])
+		assert.Equal(suite.T(), document.DriverID.String(), notification.Data["driver_id"])
+		assert.Equal(suite.T(), document.ID.String(), notification.Data["document_id"])
+		assert.Equal(suite.T(), string(document.DocumentType), notification.Data["document_type"])
+		assert.Equal(suite.T(), document.ExpiryDate.Format("2006-01-02"), notification.Data["expiry_date"])
+		assert.NotEmpty(suite.T(), notification.Data["days_left"])
+	}
+
+	suite.T().Run("RepeatedRunSkipsNotifiedDocuments", func(t *testing.T) {
+		// Arrange
+		gateway := suite.env.StartPushGateway(t)
+
+		// Act - следующий ежечасный запуск
+		sent, err := suite.env.Notifications.NotifyExpiringDocuments(suite.env.Ctx, 30)
+
+		// Assert
+		require.NoError(t, err)
+		assert.Zero(t, sent)
+		assert.Empty(t, gateway.Requests(), "О сроке действия документа водитель уведомляется один раз")
+	})
+
+	suite.T().Run("RenewedDocumentNotifiedAgain", func(t *testing.T) {
+		// Arrange - права продлены, но новый срок тоже скоро истекает
+		gateway := suite.env.StartPushGateway(t)
+		license.ExpiryDate = license.ExpiryDate.AddDate(0, 0, 7)
+		require.NoError(t, suite.env.DocumentRepo.Update(suite.env.Ctx, license))
+
+		// Act
+		sent, err := suite.env.Notifications.NotifyExpiringDocuments(suite.env.Ctx, 30)
+
+		// Assert
+		require.NoError(t, err)
+		assert.Equal(t, 1, sent)
+		require.Len(t, gateway.RequestsFor(androidToken), 1)
+		assert.Equal(t, license.ExpiryDate.Format("2006-01-02"), gateway.RequestsFor(androidToken)[0].Data["expiry_date"])
+		assert.Empty(t, gateway.RequestsFor(iosToken))
+	})
+}
+
+// TestDocumentExpiryFailedDeliveryRetried тестирует, что ошибка отправки не прерывает обработку остальных документов,
+// а документ, уведомление о котором не доставлено, уведомляется при следующем запуске
+func (suite *PushNotificationsTestSuite) TestDocumentExpiryFailedDeliveryRetried() {
+	// Arrange - приложение на Android удалено, токен больше не действует
+	gateway := suite.env.StartPushGateway(suite.T())
+	now := time.Now()
+
+	androidToken := "fcm-expiring-" + uuid.New().String()
+	androidDriver := suite.createDriver(suite.T(), entities.PushPlatformFCM, androidToken)
+	suite.createDocument(androidDriver, entities.DocumentTypeDriverLicense, "77AA000004", now.AddDate(0, 0, 5), entities.VerificationStatusVerified)
+
+	iosToken := "apns-expiring-" + uuid.New().String()
+	iosDriver := suite.createDriver(suite.T(), entities.PushPlatformAPNS, iosToken)
+	suite.createDocument(iosDriver, entities.DocumentTypeInsurance, "INS-000002", now.AddDate(0, 0, 10), entities.VerificationStatusVerified)
+	gateway.InvalidateToken(androidToken)
+
+	// Act
+	sent, err := suite.env.Notifications.NotifyExpiringDocuments(suite.env.Ctx, 30)
+
+	// Assert
+	assert.ErrorIs(suite.T(), err, entities.ErrPushTokenInvalid)
+	assert.Equal(suite.T(), 1, sent)
+	assert.Len(suite.T(), gateway.RequestsFor(iosToken), 1, "Уведомление на iOS должно быть отправлено")
+	assert.Len(suite.T(), gateway.RequestsFor(androidToken), 1, "Недействительный токен не повторяется")
+
+	// Act - следующий запуск, шлюз снова принимает токен
+	retryGateway := suite.env.StartPushGateway(suite.T())
+	sent, err = suite.env.Notifications.NotifyExpiringDocuments(suite.env.Ctx, 30)
+
+	// Assert
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), 1, sent)
+	assert.Len(suite.T(), retryGateway.RequestsFor(androidToken), 1, "Недоставленное уведомление отправляется повторно")
+	assert.Empty(suite.T(), retryGateway.RequestsFor(iosToken), "Доставленное уведомление не повторяется")
+}
+
+// TestRetryOnGatewayFailure тестирует повторы отправки при сбоях шлюза
+func (suite *PushNotificationsTestSuite) TestRetryOnGatewayFailure() {
+	testCases := []struct {
+		name             string
+		platform         entities.PushPlatform
+		failures         int // -1 - шлюз отвечает ошибкой всегда
+		statusCode       int
+		expectedAttempts int
+		expectDelivered  bool
+		expectedErr      error
+	}{
+		{name: "RecoversAfter503", platform: entities.PushPlatformFCM, failures: 2, statusCode: http.StatusServiceUnavailable, expectedAttempts: 3, expectDelivered: true},
+		{name: "RecoversAfter429", platform: entities.PushPlatformAPNS, failures: 1, statusCode: http.StatusTooManyRequests, expectedAttempts: 2, expectDelivered: true},
+		{name: "GatewayDown", platform: entities.PushPlatformFCM, failures: -1, statusCode: http.StatusInternalServerError, expectedAttempts: helpers.TestPushMaxAttempts},
+		{name: "BadRequestNotRetried", platform: entities.PushPlatformAPNS, failures: -1, statusCode: http.StatusBadRequest, expectedAttempts: 1},
+		{name: "UnregisteredToken", platform: entities.PushPlatformAPNS, failures: -1, statusCode: http.StatusGone, expectedAttempts: 1, expectedErr: entities.ErrPushTokenInvalid},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			gateway := suite.env.StartPushGateway(t)
+			gateway.FailNext(tc.failures, tc.statusCode)
+
+			token := fmt.Sprintf("%s-retry-%s", tc.platform, uuid.New())
+			driverID := suite.createDriver(t, tc.platform, token)
+			orderID := uuid.New()
+
+			// Act
+			err := suite.env.Notifications.HandleOrderAssigned(suite.env.Ctx, driverID, orderID)
+
+			// Assert
+			requests := gateway.RequestsFor(token)
+			require.Len(t, requests, tc.expectedAttempts)
+
+			for i, request := range requests {
+				assert.Equal(t, orderID.String(), request.Data["order_id"], "Повтор должен отправлять то же уведомление")
+				if i > 0 {
+					assert.False(t, request.ReceivedAt.Before(requests[i-1].ReceivedAt))
+				}
+			}
+
+			if tc.expectDelivered {
+				require.NoError(t, err)
+				assert.Len(t, gateway.Delivered(), 1)
+				return
+			}
+
+			require.Error(t, err)
+			assert.Empty(t, gateway.Delivered())
+			if tc.expectedErr != nil {
+				assert.ErrorIs(t, err, tc.expectedErr)
+			}
+		})
+	}
+}
+
+// createDriver создает водителя с токеном устройства (пустая платформа - без токена)
+func (suite *PushNotificationsTestSuite) createDriver(t *testing.T, platform entities.PushPlatform, token string) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900888%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("push.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("PN%08d", suite.drivers)
+
+	if platform != "" {
+		require.NoError(t, driver.SetPushToken(platform, token))
+	}
+
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+	return createdDriver.ID
+}
+
+// createDocument сохраняет документ водителя с заданным сроком действия и статусом
+func (suite *PushNotificationsTestSuite) createDocument(driverID uuid.UUID, docType entities.DocumentType, number string, expiry time.Time, status entities.VerificationStatus) *entities.DriverDocument {
+	document := fixtures.CreateTestDocument(driverID, docType)
+	document.DocumentNumber = number
+	document.ExpiryDate = time.Date(expiry.Year(), expiry.Month(), expiry.Day(), 0, 0, 0, 0, time.UTC)
+	document.Status = status
+
+	require.NoError(suite.T(), suite.env.DocumentRepo.Create(suite.env.Ctx, document))
+	return document
+}
+
+// TestPushNotificationsTestSuite запускает тестовый suite
+func TestPushNotificationsTestSuite(t *testing.T) {
+	suite.Run(t, new(PushNotificationsTestSuite))
+}
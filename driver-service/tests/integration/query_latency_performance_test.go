@@ -0,0 +1,334 @@
+//go:build integration
+
+package integration
+
+import (
+	"math/rand"
+	"net/http"
+	"strconv"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры проверок задержки на большом наборе данных
+const (
+	latencySamples       = 50 // запросов на операцию
+	latencyWarmupSamples = 5  // запросов на прогрев перед замером
+	latencyNearbyRadius  = 3.0
+)
+
+// Запросы к БД, которые выполняют проверяемые операции; совпадают с запросами репозиториев
+const (
+	driverByIDQuery = `
+		SELECT * FROM drivers
+		WHERE id = $1 AND deleted_at IS NULL`
+	historyRangeQuery = `
+		SELECT * FROM driver_locations
+		WHERE driver_id = $1 AND recorded_at BETWEEN $2 AND $3
+		ORDER BY recorded_at ASC`
+	nearbyQuery = `
+		SELECT latest.*
+		FROM drivers d
+		CROSS JOIN LATERAL (
+			SELECT * FROM driver_locations
+			WHERE driver_id = d.id
+			ORDER BY recorded_at DESC
+			LIMIT 1
+		) latest
+		CROSS JOIN LATERAL (
+			SELECT 6371.0 * 2 * asin(least(1.0, sqrt(
+				power(sin(radians(latest.latitude - $2::float8) / 2), 2) +
+				cos(radians($2::float8)) * cos(radians(latest.latitude)) *
+				power(sin(radians(latest.longitude - $1::float8) / 2), 2)
+			))) AS km
+		) distance
+		WHERE distance.km <= $3 AND latest.recorded_at >= $5
+		ORDER BY distance.km, latest.driver_id
+		LIMIT $4`
+	driverStatsQuery = `
+		SELECT d.id AS driver_id, d.total_trips,
+			COALESCE(rs.average_rating, 0) AS average_rating,
+			COALESCE(rs.total_ratings, 0) AS total_ratings,
+			COALESCE(track.km, 0) AS distance_km
+		FROM drivers d
+		LEFT JOIN driver_rating_stats rs ON rs.driver_id = d.id
+		CROSS JOIN LATERAL (
+			SELECT SUM(6371.0 * 2 * asin(least(1.0, sqrt(
+				power(sin(radians(latitude - prev_latitude) / 2), 2) +
+				cos(radians(prev_latitude)) * cos(radians(latitude)) *
+				power(sin(radians(longitude - prev_longitude) / 2), 2)
+			)))) AS km
+			FROM (
+				SELECT latitude, longitude,
+					LAG(latitude) OVER w AS prev_latitude,
+					LAG(longitude) OVER w AS prev_longitude
+				FROM driver_locations
+				WHERE driver_id = d.id AND recorded_at BETWEEN $2 AND $3
+				WINDOW w AS (ORDER BY recorded_at)
+			) points
+			WHERE prev_latitude IS NOT NULL
+		) track
+		WHERE d.id = $1 AND d.deleted_at IS NULL`
+)
+
+// QueryLatencyPerformanceTestSuite проверяет, что основные запросы API укладываются в пороги задержки
+// на большом наборе данных с историей за месяцы (helpers.SeedLargeDataset, размер задается TEST_DATASET_SCALE
+// и TEST_DATASET_HISTORY_DAYS).
+// При превышении порога к упавшему тесту прикладываются планы запросов операции.
+type QueryLatencyPerformanceTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	dataset *helpers.SeededDataset
+	seed    int64
+}
+
+// SetupSuite выполняется один раз перед всеми тестами: набор данных только читается, поэтому
+// записывается один раз и между тестами не очищается
+func (suite *QueryLatencyPerformanceTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	// Поиск поблизости должен доходить до БД при каждом запросе
+	suite.env.SetFeature(suite.T(), features.GeoCache, false)
+
+	suite.seed = helpers.TestSeed(suite.T(), 1708)
+	suite.dataset = suite.env.DB.SeedLargeDataset(suite.T(), helpers.LoadLargeDataset(suite.T()), suite.seed)
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *QueryLatencyPerformanceTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом: текущий трек набора данных сдвигается к текущему времени
+func (suite *QueryLatencyPerformanceTestSuite) SetupTest() {
+	suite.env.DB.RefreshLargeDataset(suite.T(), suite.dataset)
+}
+
+// TestLocationHistoryLatency тестирует задержку истории местоположений водителя за период
+func (suite *QueryLatencyPerformanceTestSuite) TestLocationHistoryLatency() {
+	testCases := []struct {
+		name     string
+		from, to time.Time
+		expected int // точек в ответе; 0 - сверяется с количеством точек водителя в БД
+	}{
+		{
+			name:     "CurrentTrack",
+			from:     suite.dataset.From,
+			to:       suite.dataset.To,
+			expected: suite.dataset.LocationsPerDriver,
+		},
+		{
+			name:     "LastHour",
+			from:     suite.dataset.To.Add(-time.Hour),
+			to:       suite.dataset.To,
+			expected: int(time.Hour/suite.dataset.Interval) + 1,
+		},
+		{
+			// Неделя смен из середины истории: запрос выбирает диапазон из миллионов строк
+			name: "WeekOfHistory",
+			from: suite.dataset.HistoryFrom.AddDate(0, 0, suite.dataset.HistoryDays/2),
+			to:   suite.dataset.HistoryFrom.AddDate(0, 0, suite.dataset.HistoryDays/2+7),
+		},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			timings := helpers.NewRequestTimings()
+			api := helpers.NewAPITestHelper(suite.env.Router, t)
+			rng := rand.New(rand.NewSource(suite.seed))
+
+			// Act
+			var sampleDriver string
+			for i := 0; i < latencyWarmupSamples+latencySamples; i++ {
+				client := api
+				if i >= latencyWarmupSamples {
+					client = api.WithResponseInterceptor(timings.Record)
+				}
+
+				driverID := suite.dataset.DriverIDs[rng.Intn(len(suite.dataset.DriverIDs))]
+				sampleDriver = driverID.String()
+				page, response := client.GetLocationHistory(driverID, helpers.HistoryQuery{From: tc.from, To: tc.to})
+				require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+				expected := tc.expected
+				if expected == 0 {
+					expected = suite.env.DB.CountLocationsInRange(t, driverID, tc.from, tc.to)
+					require.Positive(t, expected, "В неделе истории должны быть смены")
+				}
+				require.Equal(t, expected, page.Count)
+			}
+
+			// Assert
+			suite.env.DB.AssertLatencyBudget(t, timings, helpers.LatencyBudget{
+				Operation: "GET /api/v1/drivers/{id}/locations/history",
+				P95:       200 * time.Millisecond,
+				Queries: []helpers.PlannedQuery{
+					{Name: "driver", Query: driverByIDQuery, Args: []interface{}{sampleDriver}},
+					{Name: "history range", Query: historyRangeQuery, Args: []interface{}{sampleDriver, tc.from, tc.to}},
+				},
+			})
+		})
+	}
+}
+
+// TestNearbySearchLatency тестирует задержку поиска водителей поблизости в центре области с водителями
+func (suite *QueryLatencyPerformanceTestSuite) TestNearbySearchLatency() {
+	// Arrange
+	timings := helpers.NewRequestTimings()
+	api := helpers.NewAPITestHelper(suite.env.Router, suite.T())
+	rng := rand.New(rand.NewSource(suite.seed))
+
+	// Act
+	var latitude, longitude float64
+	for i := 0; i < latencyWarmupSamples+latencySamples; i++ {
+		client := api
+		if i >= latencyWarmupSamples {
+			client = api.WithResponseInterceptor(timings.Record)
+		}
+
+		// Точки поиска в пределах нескольких километров от центра, где плотность водителей наибольшая
+		latitude = suite.dataset.Latitude + (rng.Float64()-0.5)*0.05
+		longitude = suite.dataset.Longitude + (rng.Float64()-0.5)*0.08
+		response := client.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    client.APIPath("/locations/nearby"),
+			QueryParams: map[string]string{
+				"latitude":  strconv.FormatFloat(latitude, 'f', -1, 64),
+				"longitude": strconv.FormatFloat(longitude, 'f', -1, 64),
+				"radius_km": strconv.FormatFloat(latencyNearbyRadius, 'f', -1, 64),
+			},
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	}
+
+	// Assert
+	suite.env.DB.AssertLatencyBudget(suite.T(), timings, helpers.LatencyBudget{
+		Operation: "GET /api/v1/locations/nearby",
+		P95:       500 * time.Millisecond,
+		Queries: []helpers.PlannedQuery{
+			{Name: "nearby", Query: nearbyQuery, Args: []interface{}{longitude, latitude, latencyNearbyRadius, 50,
+				time.Now().Add(-entities.LocationStaleAfter)}},
+			{Name: "driver", Query: driverByIDQuery, Args: []interface{}{suite.dataset.DriverIDs[0]}},
+		},
+	})
+}
+
+// TestNearbySearchCacheWarmVsCold тестирует, сколько экономит кэш поиска поблизости (geo_cache): задержка
+// одних и тех же запросов при пустом кэше (сбрасывается перед каждым запросом) и при прогретом.
+// Оба распределения выводятся в лог; прогретый кэш, который не быстрее холодного, означает обход кэша.
+//
+// Кэш поиска поблизости хранится в памяти процесса, а не в Redis (Redis хранит только сессии приложения
+// водителя), поэтому холодный кэш - сброшенный geo_cache, а замер не показывает задержку Redis.
+func (suite *QueryLatencyPerformanceTestSuite) TestNearbySearchCacheWarmVsCold() {
+	// Arrange
+	suite.env.SetFeature(suite.T(), features.GeoCache, true)
+	defer suite.env.SetFeature(suite.T(), features.GeoCache, false)
+
+	coldTimings, warmTimings := helpers.NewRequestTimings(), helpers.NewRequestTimings()
+	api := helpers.NewAPITestHelper(suite.env.Router, suite.T())
+	rng := rand.New(rand.NewSource(suite.seed))
+
+	// Разные точки в пределах нескольких километров от центра: у каждой своя запись кэша
+	points := make([]map[string]string, latencySamples)
+	for i := range points {
+		points[i] = map[string]string{
+			"latitude":  strconv.FormatFloat(suite.dataset.Latitude+(rng.Float64()-0.5)*0.05, 'f', 6, 64),
+			"longitude": strconv.FormatFloat(suite.dataset.Longitude+(rng.Float64()-0.5)*0.08, 'f', 6, 64),
+			"radius_km": strconv.FormatFloat(latencyNearbyRadius, 'f', -1, 64),
+		}
+	}
+	nearby := func(client *helpers.APITestHelper, point map[string]string) string {
+		response := client.MakeRequest(helpers.APIRequest{
+			Method:      http.MethodGet,
+			URL:         client.APIPath("/locations/nearby"),
+			QueryParams: point,
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+		return string(response.Body)
+	}
+
+	// Act - холодный кэш: каждый запрос идет в БД
+	for i := 0; i < latencyWarmupSamples; i++ {
+		nearby(api, points[i])
+	}
+	coldBodies := make([]string, len(points))
+	for i, point := range points {
+		suite.env.InvalidateGeoCache()
+		coldBodies[i] = nearby(api.WithResponseInterceptor(coldTimings.Record), point)
+	}
+
+	// Прогретый кэш: каждая точка запрошена один раз до замера
+	suite.env.InvalidateGeoCache()
+	for _, point := range points {
+		nearby(api, point)
+	}
+	cached := len(suite.env.GeoCacheEntries())
+	for i, point := range points {
+		body := nearby(api.WithResponseInterceptor(warmTimings.Record), point)
+		assert.Equal(suite.T(), coldBodies[i], body, "Прогретый кэш отдает тот же результат, что и БД")
+	}
+
+	// Assert
+	const operation = "GET /api/v1/locations/nearby"
+	cold := helpers.NewLatencyPercentiles(coldTimings.Samples(operation))
+	warm := helpers.NewLatencyPercentiles(warmTimings.Samples(operation))
+	suite.T().Logf("Cold cache over %d requests: p50 %v, p95 %v, p99 %v, max %v", cold.Samples, cold.P50, cold.P95, cold.P99, cold.Max)
+	suite.T().Logf("Warm cache over %d requests: p50 %v, p95 %v, p99 %v, max %v", warm.Samples, warm.P50, warm.P95, warm.P99, warm.Max)
+	if warm.P50 > 0 {
+		suite.T().Logf("Cache speedup p50: %.1fx, p95: %.1fx", float64(cold.P50)/float64(warm.P50), float64(cold.P95)/float64(warm.P95))
+	}
+
+	require.Equal(suite.T(), len(points), cold.Samples)
+	require.Equal(suite.T(), len(points), warm.Samples)
+	assert.Equal(suite.T(), len(points), cached, "Каждая точка закэширована при прогреве")
+	assert.Less(suite.T(), warm.P50, cold.P50, "Прогретый кэш не быстрее холодного: запросы идут в обход кэша")
+	assert.Less(suite.T(), warm.P95, cold.P50, "Медленные запросы при прогретом кэше: часть запросов идет в обход кэша")
+}
+
+// TestDriverStatsLatency тестирует задержку статистики водителя за весь период истории
+func (suite *QueryLatencyPerformanceTestSuite) TestDriverStatsLatency() {
+	// Arrange
+	timings := helpers.NewRequestTimings()
+	api := helpers.NewAPITestHelper(suite.env.Router, suite.T())
+	rng := rand.New(rand.NewSource(suite.seed))
+
+	// Act
+	var driverID uuid.UUID
+	for i := 0; i < latencyWarmupSamples+latencySamples; i++ {
+		client := api
+		if i >= latencyWarmupSamples {
+			client = api.WithResponseInterceptor(timings.Record)
+		}
+
+		driverID = suite.dataset.DriverIDs[rng.Intn(len(suite.dataset.DriverIDs))]
+		_, response := client.GetDriverStats(driverID, suite.dataset.HistoryFrom, suite.dataset.To)
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	}
+
+	// Assert
+	suite.env.DB.AssertLatencyBudget(suite.T(), timings, helpers.LatencyBudget{
+		Operation: "GET /api/v1/drivers/{id}/stats",
+		P95:       300 * time.Millisecond,
+		Queries: []helpers.PlannedQuery{
+			{Name: "stats", Query: driverStatsQuery, Args: []interface{}{driverID, suite.dataset.HistoryFrom, suite.dataset.To}},
+		},
+	})
+}
+
+// TestQueryLatencyPerformanceTestSuite запускает тестовый suite
+func TestQueryLatencyPerformanceTestSuite(t *testing.T) {
+	// Пропускаем performance тесты в быстром режиме
+	if testing.Short() {
+		t.Skip("Skipping performance tests in short mode")
+	}
+
+	suite.Run(t, new(QueryLatencyPerformanceTestSuite))
+}
@@ -0,0 +1,149 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"math/rand"
+	"strconv"
+	"sync"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// RatingConcurrencyTestSuite тестовый suite для конкурентного приема оценок водителя
+type RatingConcurrencyTestSuite struct {
+	suite.Suite
+	testDB       *helpers.TestDB
+	driverRepo   repositories.DriverRepository
+	events       *helpers.EventRecorder
+	ctx          context.Context
+	testDriverID uuid.UUID
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *RatingConcurrencyTestSuite) SetupSuite() {
+	suite.testDB = helpers.SetupTestDB(suite.T())
+	logger := helpers.CreateTestLogger(suite.T())
+	suite.ctx = context.Background()
+
+	suite.driverRepo = repositories.NewDriverRepository(suite.testDB.DB, logger)
+	suite.events = helpers.NewEventRecorder()
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *RatingConcurrencyTestSuite) TearDownSuite() {
+	suite.testDB.TeardownTestDB(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *RatingConcurrencyTestSuite) SetupTest() {
+	suite.testDB.CleanupTables(suite.T())
+	suite.events.Reset()
+
+	driver := fixtures.CreateTestDriverWithStatus(entities.StatusAvailable)
+	require.NoError(suite.T(), suite.driverRepo.Create(suite.ctx, driver))
+	suite.testDriverID = driver.ID
+}
+
+// TestConcurrentRatingIngestion тестирует, что статистика сходится к точному среднему при параллельных оценках
+func (suite *RatingConcurrencyTestSuite) TestConcurrentRatingIngestion() {
+	const (
+		ratingsCount = 60
+		publishers   = 10
+		consumers    = 8
+	)
+
+	// Arrange
+	rng := rand.New(rand.NewSource(helpers.TestSeed(suite.T(), 42)))
+	ratings := make([]int, ratingsCount)
+	expectedDistribution := make(map[string]int)
+	sum := 0
+	for i := range ratings {
+		ratings[i] = rng.Intn(5) + 1
+		expectedDistribution[strconv.Itoa(ratings[i])]++
+		sum += ratings[i]
+	}
+	expectedAverage := float64(sum) / float64(ratingsCount)
+
+	// Act
+	// Публикуем события customer.rated.driver параллельно несколькими отправителями
+	var publishWG sync.WaitGroup
+	for p := 0; p < publishers; p++ {
+		publishWG.Add(1)
+		go func(offset int) {
+			defer publishWG.Done()
+			for i := offset; i < ratingsCount; i += publishers {
+				rating := fixtures.CreateTestRating(suite.testDriverID, ratings[i])
+				_ = suite.events.PublishDriverEvent(suite.ctx, "customer.rated.driver", suite.testDriverID, rating)
+			}
+		}(p)
+	}
+	publishWG.Wait()
+
+	published := suite.events.EventsForDriver(suite.testDriverID, "customer.rated.driver")
+	require.Len(suite.T(), published, ratingsCount)
+
+	// Примечание: подписчика на customer.rated.driver в сервисе пока нет. Обработчики ниже делают то,
+	// что должен делать он: сохраняют оценку, а пересчет статистики выполняет триггер БД.
+	queue := make(chan *entities.DriverRating, len(published))
+	for _, event := range published {
+		queue <- event.Data.(*entities.DriverRating)
+	}
+	close(queue)
+
+	var consumeWG sync.WaitGroup
+	errs := make(chan error, len(published))
+	for c := 0; c < consumers; c++ {
+		consumeWG.Add(1)
+		go func() {
+			defer consumeWG.Done()
+			for rating := range queue {
+				if err := suite.testDB.InsertRating(suite.ctx, rating); err != nil {
+					errs <- err
+				}
+			}
+		}()
+	}
+	consumeWG.Wait()
+	close(errs)
+
+	for err := range errs {
+		suite.T().Errorf("Ошибка обработки оценки: %v", err)
+	}
+
+	// Assert
+	var storedCount int
+	err := suite.testDB.GetContext(suite.ctx, &storedCount,
+		"SELECT COUNT(*) FROM driver_ratings WHERE driver_id = $1", suite.testDriverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), ratingsCount, storedCount)
+
+	stats := suite.testDB.GetRatingStats(suite.T(), suite.testDriverID)
+	require.NotNil(suite.T(), stats)
+
+	// average_rating и current_rating хранятся как DECIMAL(3,2)
+	assert.Equal(suite.T(), ratingsCount, stats.TotalRatings, "Ни одна оценка не должна потеряться в статистике")
+	assert.InDelta(suite.T(), expectedAverage, stats.AverageRating, 0.005)
+	assert.Equal(suite.T(), expectedDistribution, stats.Distribution(suite.T()))
+
+	driver, err := suite.driverRepo.GetByID(suite.ctx, suite.testDriverID)
+	require.NoError(suite.T(), err)
+	assert.InDelta(suite.T(), expectedAverage, driver.CurrentRating, 0.005)
+	assert.InDelta(suite.T(), stats.AverageRating, driver.CurrentRating, 0.001,
+		"current_rating должен совпадать со статистикой")
+}
+
+// TestRatingConcurrencyTestSuite запускает тестовый suite
+func TestRatingConcurrencyTestSuite(t *testing.T) {
+	suite.Run(t, new(RatingConcurrencyTestSuite))
+}
@@ -0,0 +1,135 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// RatingStatsBackfillTestSuite тестовый suite для восстановления статистики оценок из исходных оценок
+type RatingStatsBackfillTestSuite struct {
+	suite.Suite
+	testDB     *helpers.TestDB
+	driverRepo repositories.DriverRepository
+	ctx        context.Context
+	driverIDs  []uuid.UUID
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *RatingStatsBackfillTestSuite) SetupSuite() {
+	suite.testDB = helpers.SetupTestDB(suite.T())
+	logger := helpers.CreateTestLogger(suite.T())
+	suite.ctx = context.Background()
+
+	suite.driverRepo = repositories.NewDriverRepository(suite.testDB.DB, logger)
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *RatingStatsBackfillTestSuite) TearDownSuite() {
+	suite.testDB.TeardownTestDB(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *RatingStatsBackfillTestSuite) SetupTest() {
+	suite.testDB.CleanupTables(suite.T())
+
+	suite.driverIDs = nil
+	ratingsByDriver := [][]int{
+		{5, 5, 4, 3, 5, 1},
+		{2, 3, 4},
+	}
+	for i, driver := range fixtures.CreateMultipleTestDrivers(len(ratingsByDriver)) {
+		driver.Status = entities.StatusAvailable
+		require.NoError(suite.T(), suite.driverRepo.Create(suite.ctx, driver))
+		suite.driverIDs = append(suite.driverIDs, driver.ID)
+
+		for _, rating := range ratingsByDriver[i] {
+			require.NoError(suite.T(), suite.testDB.InsertRating(suite.ctx, fixtures.CreateTestRating(driver.ID, rating)))
+		}
+	}
+}
+
+// TestBackfillRegeneratesDeletedStats тестирует восстановление удаленной строки статистики
+func (suite *RatingStatsBackfillTestSuite) TestBackfillRegeneratesDeletedStats() {
+	// Arrange
+	driftedID := suite.driverIDs[0]
+	untouchedID := suite.driverIDs[1]
+	for _, driverID := range suite.driverIDs {
+		helpers.AssertRatingStatsConsistent(suite.T(), suite.testDB, driverID)
+	}
+	untouchedBefore := suite.testDB.GetRatingStats(suite.T(), untouchedID)
+
+	// Имитируем расхождение: строка статистики потеряна, current_rating устарел
+	_, err := suite.testDB.ExecContext(suite.ctx, "DELETE FROM driver_rating_stats WHERE driver_id = $1", driftedID)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.driverRepo.UpdateRating(suite.ctx, driftedID, 1.0))
+	require.Nil(suite.T(), suite.testDB.GetRatingStats(suite.T(), driftedID))
+
+	// Act
+	suite.runBackfill()
+
+	// Assert
+	stats := suite.testDB.GetRatingStats(suite.T(), driftedID)
+	require.NotNil(suite.T(), stats, "Статистика должна быть восстановлена")
+	assert.Equal(suite.T(), 6, stats.TotalRatings)
+	assert.InDelta(suite.T(), 23.0/6.0, stats.AverageRating, 0.005)
+	assert.Equal(suite.T(), map[string]int{"1": 1, "3": 1, "4": 1, "5": 3}, stats.Distribution(suite.T()))
+
+	for _, driverID := range suite.driverIDs {
+		helpers.AssertRatingStatsConsistent(suite.T(), suite.testDB, driverID)
+	}
+
+	untouchedAfter := suite.testDB.GetRatingStats(suite.T(), untouchedID)
+	assert.Equal(suite.T(), untouchedBefore.TotalRatings, untouchedAfter.TotalRatings)
+	assert.InDelta(suite.T(), untouchedBefore.AverageRating, untouchedAfter.AverageRating, 0.001)
+}
+
+// TestBackfillFixesCorruptedStats тестирует исправление статистики, не совпадающей с исходными оценками
+func (suite *RatingStatsBackfillTestSuite) TestBackfillFixesCorruptedStats() {
+	// Arrange
+	driverID := suite.driverIDs[1]
+	_, err := suite.testDB.ExecContext(suite.ctx, `
+		UPDATE driver_rating_stats
+		SET average_rating = 4.9, total_ratings = 100, rating_distribution = '{"5": 100}'
+		WHERE driver_id = $1`, driverID)
+	require.NoError(suite.T(), err)
+
+	// Act
+	suite.runBackfill()
+	// Повторный запуск не должен ничего менять
+	suite.runBackfill()
+
+	// Assert
+	stats := suite.testDB.GetRatingStats(suite.T(), driverID)
+	require.NotNil(suite.T(), stats)
+	assert.Equal(suite.T(), 3, stats.TotalRatings)
+	assert.InDelta(suite.T(), 3.0, stats.AverageRating, 0.005)
+
+	helpers.AssertRatingStatsConsistent(suite.T(), suite.testDB, driverID)
+}
+
+// runBackfill пересчитывает статистику всех водителей, у которых есть оценки.
+// Примечание: отдельной задачи пересчета в сервисе пока нет, поэтому вызывается та же функция БД,
+// которую использует триггер trigger_driver_rating_stats_update. Когда задача появится, ее следует запускать здесь.
+func (suite *RatingStatsBackfillTestSuite) runBackfill() {
+	_, err := suite.testDB.ExecContext(suite.ctx, `
+		SELECT update_driver_rating_stats(driver_id)
+		FROM (SELECT DISTINCT driver_id FROM driver_ratings) AS rated`)
+	require.NoError(suite.T(), err)
+}
+
+// TestRatingStatsBackfillTestSuite запускает тестовый suite
+func TestRatingStatsBackfillTestSuite(t *testing.T) {
+	suite.Run(t, new(RatingStatsBackfillTestSuite))
+}
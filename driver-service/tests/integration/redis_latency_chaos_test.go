@@ -0,0 +1,304 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры нагрузки часа пик и деградации Redis
+const (
+	peakDrivers       = 60
+	peakLoadWorkers   = 8
+	peakLoadInterval  = 20 * time.Millisecond
+	peakReaderClients = 4 // клиенты, опрашивающие nearby и active без пауз
+	peakPhaseDuration = 2 * time.Second
+	redisSpikeLatency = 200 * time.Millisecond
+)
+
+// SLO чтения в режиме деградации Redis: задержка Redis не должна доходить до nearby и active,
+// а ни один запрос - приближаться к таймауту клиента
+const (
+	degradedNearbyP95  = 500 * time.Millisecond
+	degradedActiveP95  = 500 * time.Millisecond
+	degradedRequestMax = 2 * time.Second
+)
+
+// Операции чтения часа пик и фазы прогона
+const (
+	nearbyOperation   = "GET /api/v1/locations/nearby"
+	activeOperation   = "GET /api/v1/drivers/active"
+	peakBaselinePhase = "baseline"
+	redisLatencyPhase = "redis_latency"
+	peakRecoveryPhase = "recovery"
+)
+
+// RedisLatencyChaosTestSuite тестирует сервис в час пик (поток обновлений местоположений и непрерывные
+// запросы nearby и active), когда посреди прогона ответы Redis замедляются на redisSpikeLatency через
+// ChaosProxy: чтение укладывается в SLO режима деградации без ошибок и таймаутов, готовность сохраняется,
+// истечение сессий обрабатывается с задержкой, но не теряется. Без тестового Redis тесты пропускаются.
+type RedisLatencyChaosTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	chaos   *helpers.DependencyChaos
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *RedisLatencyChaosTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.chaos = suite.env.EnableHealthChecks(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *RedisLatencyChaosTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *RedisLatencyChaosTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.chaos.RestoreAll()
+}
+
+// TestPeakHoursWithRedisLatency тестирует час пик с задержкой Redis посреди прогона:
+// baseline, затем redisSpikeLatency на ответы Redis, затем восстановление
+func (suite *RedisLatencyChaosTestSuite) TestPeakHoursWithRedisLatency() {
+	if suite.chaos.Redis == nil {
+		suite.T().Skip("Тестовый Redis недоступен")
+	}
+
+	// Arrange
+	redis := suite.env.StartSessionExpiryListenerThrough(suite.T(), suite.chaos)
+	redis.DeleteKeys(helpers.TestSessionKeyPrefix + "*")
+
+	driverIDs := make([]uuid.UUID, 0, peakDrivers)
+	for i := 0; i < peakDrivers; i++ {
+		driverIDs = append(driverIDs, suite.createAvailableDriver(fixtures.RoutePoint{
+			Latitude:  55.7558 + float64(i%10)*0.002,
+			Longitude: 37.6173 + float64(i/10)*0.002,
+		}))
+	}
+	sessionDriver := suite.createAvailableDriver(fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173})
+
+	phases := newPeakPhases()
+	load := helpers.StartLocationLoad(suite.env.API, driverIDs, peakLoadWorkers, peakLoadInterval)
+	defer load.Stop()
+	readers := startPeakReaders(suite.env.API.WithResponseInterceptor(phases.Record), peakReaderClients)
+	defer readers.Stop()
+
+	// Act - baseline
+	time.Sleep(peakPhaseDuration)
+
+	// Act - задержка Redis посреди прогона
+	phases.Switch(redisLatencyPhase)
+	suite.chaos.SetLatency(suite.T(), "redis", redisSpikeLatency)
+	started := time.Now()
+
+	redis.SetSession(sessionDriver, sessionTTL)
+	expiry := helpers.AssertPropagatedWithin(suite.T(), "session expiry with redis latency", helpers.PropagationBudget{
+		Within:  sessionExpiryBudget + redisSpikeLatency,
+		Visible: suite.driverHasStatus(sessionDriver, entities.StatusOffline),
+	})
+	readiness := suite.env.API.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health/ready"})
+	time.Sleep(peakPhaseDuration - time.Since(started))
+
+	// Act - восстановление
+	suite.chaos.SetLatency(suite.T(), "redis", 0)
+	phases.Switch(peakRecoveryPhase)
+	time.Sleep(peakPhaseDuration)
+
+	failures := readers.Stop()
+	report := load.Stop()
+
+	// Assert
+	suite.T().Run("RedisLatencyInjected", func(t *testing.T) {
+		require.Equal(t, http.StatusOK, readiness.StatusCode, "Задержка Redis меньше таймаута проверки, сервис готов: %s", readiness.Body)
+
+		var body struct {
+			Dependencies map[string]struct {
+				Status    string  `json:"status"`
+				LatencyMS float64 `json:"latency_ms"`
+			} `json:"dependencies"`
+		}
+		require.NoError(t, json.Unmarshal(readiness.Body, &body))
+		assert.GreaterOrEqual(t, body.Dependencies["redis"].LatencyMS, float64(redisSpikeLatency.Milliseconds()),
+			"Проверка Redis идет через прокси с задержкой")
+		t.Logf("Session expiry with redis latency: %v", expiry)
+	})
+
+	suite.T().Run("NoErrorsOrTimeouts", func(t *testing.T) {
+		assert.Empty(t, failures, "Ошибки nearby и active в час пик")
+		assert.Empty(t, report.Failures, "Ошибки записи местоположений в час пик")
+		require.NotEmpty(t, report.Accepted)
+
+		for _, phase := range []string{peakBaselinePhase, redisLatencyPhase, peakRecoveryPhase} {
+			for _, operation := range []string{nearbyOperation, activeOperation} {
+				timings := phases.Timings(phase)
+				require.NotEmpty(t, timings.Samples(operation), "Нет запросов %s в фазе %s", operation, phase)
+				assert.LessOrEqual(t, timings.Percentile(operation, 100), degradedRequestMax,
+					"%s в фазе %s приблизился к таймауту", operation, phase)
+			}
+		}
+	})
+
+	suite.T().Run("DegradedModeSLO", func(t *testing.T) {
+		timings := phases.Timings(redisLatencyPhase)
+		suite.env.DB.AssertLatencyBudget(t, timings, helpers.LatencyBudget{Operation: nearbyOperation, P95: degradedNearbyP95})
+		suite.env.DB.AssertLatencyBudget(t, timings, helpers.LatencyBudget{Operation: activeOperation, P95: degradedActiveP95})
+
+		for _, operation := range []string{nearbyOperation, activeOperation} {
+			t.Logf("%s p95: baseline %v, redis latency %v, recovery %v", operation,
+				phases.Timings(peakBaselinePhase).Percentile(operation, 95),
+				timings.Percentile(operation, 95),
+				phases.Timings(peakRecoveryPhase).Percentile(operation, 95))
+		}
+	})
+}
+
+// createAvailableDriver создает водителя на линии с местоположением в point
+func (suite *RedisLatencyChaosTestSuite) createAvailableDriver(point fixtures.RoutePoint) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900743%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("peak.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("PK%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, entities.StatusAvailable))
+
+	location := entities.NewDriverLocation(created.ID, point.Latitude, point.Longitude, time.Now().Add(-time.Minute))
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+	return created.ID
+}
+
+// driverHasStatus возвращает проверку PropagationBudget, что водитель перешел в status
+func (suite *RedisLatencyChaosTestSuite) driverHasStatus(driverID uuid.UUID, status entities.Status) func(ctx context.Context) (bool, error) {
+	return func(ctx context.Context) (bool, error) {
+		driver, err := suite.env.DriverService.GetDriverByID(ctx, driverID)
+		if err != nil {
+			return false, err
+		}
+		return driver.Status == status, nil
+	}
+}
+
+// peakPhases длительности запросов по фазам прогона; Record подключается как ResponseInterceptor
+type peakPhases struct {
+	mu      sync.Mutex
+	current string
+	timings map[string]*helpers.RequestTimings
+}
+
+// newPeakPhases создает фазы прогона, начиная с baseline
+func newPeakPhases() *peakPhases {
+	return &peakPhases{
+		current: peakBaselinePhase,
+		timings: map[string]*helpers.RequestTimings{peakBaselinePhase: helpers.NewRequestTimings()},
+	}
+}
+
+// Switch начинает фазу phase: следующие запросы учитываются в ней
+func (p *peakPhases) Switch(phase string) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	p.current = phase
+	if _, ok := p.timings[phase]; !ok {
+		p.timings[phase] = helpers.NewRequestTimings()
+	}
+}
+
+// Record учитывает длительность запроса в текущей фазе
+func (p *peakPhases) Record(req *http.Request, resp *helpers.APIResponse, duration time.Duration) {
+	p.mu.Lock()
+	timings := p.timings[p.current]
+	p.mu.Unlock()
+	timings.Record(req, resp, duration)
+}
+
+// Timings возвращает длительности запросов фазы phase
+func (p *peakPhases) Timings(phase string) *helpers.RequestTimings {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if timings, ok := p.timings[phase]; ok {
+		return timings
+	}
+	return helpers.NewRequestTimings()
+}
+
+// peakReaders клиенты, непрерывно запрашивающие nearby и active до остановки
+type peakReaders struct {
+	stopped  atomic.Bool
+	wg       sync.WaitGroup
+	mu       sync.Mutex
+	failures []string
+}
+
+// startPeakReaders запускает count клиентов, чередующих nearby и active через api
+func startPeakReaders(api *helpers.APITestHelper, count int) *peakReaders {
+	readers := &peakReaders{}
+	for i := 0; i < count; i++ {
+		readers.wg.Add(1)
+		go readers.run(api)
+	}
+	return readers
+}
+
+// run чередует запросы nearby и active до остановки
+func (r *peakReaders) run(api *helpers.APITestHelper) {
+	defer r.wg.Done()
+
+	requests := []helpers.APIRequest{
+		{
+			Method: http.MethodGet,
+			URL:    api.APIPath("/locations/nearby"),
+			QueryParams: map[string]string{
+				"latitude":  "55.7558",
+				"longitude": "37.6173",
+				"radius_km": "5",
+			},
+		},
+		{Method: http.MethodGet, URL: api.APIPath("/drivers/active")},
+	}
+	for step := 0; !r.stopped.Load(); step++ {
+		request := requests[step%len(requests)]
+		response := api.MakeRequest(request)
+		if response.StatusCode != http.StatusOK {
+			r.mu.Lock()
+			r.failures = append(r.failures, fmt.Sprintf("%s %s -> %d: %s", request.Method, request.URL, response.StatusCode, response.Body))
+			r.mu.Unlock()
+		}
+	}
+}
+
+// Stop останавливает клиентов, дожидается запросов в полете и возвращает ошибочные ответы
+func (r *peakReaders) Stop() []string {
+	r.stopped.Store(true)
+	r.wg.Wait()
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	return append([]string(nil), r.failures...)
+}
+
+// TestRedisLatencyChaosTestSuite запускает тестовый suite
+func TestRedisLatencyChaosTestSuite(t *testing.T) {
+	suite.Run(t, new(RedisLatencyChaosTestSuite))
+}
@@ -0,0 +1,166 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Время жизни ключей сессий в тестах и бюджет реакции сервиса на истечение: Redis удаляет истекшие
+// ключи активным циклом раз в 100 мс, уведомление приходит с этой задержкой
+const (
+	sessionTTL          = 200 * time.Millisecond
+	sessionExpiryBudget = sessionTTL + time.Second
+)
+
+// SessionExpiryTestSuite тестирует реакцию сервиса на истечение ключей сессий приложения водителя
+// в Redis (keyspace notifications): активный водитель переводится в offline с событием driver.status.changed.
+// Без тестового Redis тесты пропускаются.
+type SessionExpiryTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	redis   *helpers.RedisHelper
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *SessionExpiryTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *SessionExpiryTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *SessionExpiryTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	suite.redis = suite.env.StartSessionExpiryListener(suite.T())
+	suite.redis.DeleteKeys(helpers.TestSessionKeyPrefix + "*")
+}
+
+// TestSessionExpiryMarksDriverOffline тестирует перевод в offline активного водителя, сессия которого истекла
+func (suite *SessionExpiryTestSuite) TestSessionExpiryMarksDriverOffline() {
+	for _, status := range []entities.Status{entities.StatusAvailable, entities.StatusOnShift} {
+		suite.T().Run(string(status), func(t *testing.T) {
+			// Arrange
+			driverID := suite.createDriver(t, status)
+
+			// Act
+			suite.redis.SetSession(driverID, sessionTTL)
+
+			// Assert
+			helpers.AssertPropagatedWithin(t, "session expiry", suite.offlineBudget(driverID))
+
+			events := suite.env.Events.EventsForDriver(driverID, "driver.status.changed")
+			require.Len(t, events, 1)
+			data := events[0].DataMap()
+			assert.Equal(t, string(status), data["old_status"])
+			assert.Equal(t, string(entities.StatusOffline), data["new_status"])
+			assert.Equal(t, "session_expired", data["changed_by"])
+		})
+	}
+
+	suite.T().Run("HeartbeatBringsDriverOnline", func(t *testing.T) {
+		// Arrange
+		driverID := suite.createDriver(t, entities.StatusAvailable)
+		suite.redis.SetSession(driverID, sessionTTL)
+		helpers.AssertPropagatedWithin(t, "session expiry", suite.offlineBudget(driverID))
+
+		// Act
+		_, response := suite.env.API.Heartbeat(driverID, nil)
+
+		// Assert
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+		assert.Equal(t, entities.StatusAvailable, suite.driverStatus(t, driverID))
+	})
+}
+
+// TestSessionKeysWithoutReaction тестирует, что водитель не переводится в offline, если сессия удалена
+// (выход из приложения), продлена до истечения или водитель не на линии. Уведомления обрабатываются
+// по порядку, поэтому после перевода в offline контрольного водителя, сессия которого истекает последней,
+// все предыдущие уведомления уже обработаны.
+func (suite *SessionExpiryTestSuite) TestSessionKeysWithoutReaction() {
+	// Arrange
+	inactive := suite.createDriver(suite.T(), entities.StatusInactive)
+	suite.redis.SetSession(inactive, sessionTTL)
+
+	loggedOut := suite.createDriver(suite.T(), entities.StatusAvailable)
+	suite.redis.SetSession(loggedOut, time.Hour)
+
+	renewed := suite.createDriver(suite.T(), entities.StatusAvailable)
+	suite.redis.SetSession(renewed, sessionTTL)
+
+	control := suite.createDriver(suite.T(), entities.StatusAvailable)
+
+	// Act
+	suite.redis.DeleteSession(loggedOut)
+	suite.redis.SetSession(renewed, time.Hour)
+	suite.redis.SetSession(control, 3*sessionTTL)
+
+	// Assert
+	helpers.AssertPropagatedWithin(suite.T(), "session expiry", suite.offlineBudget(control))
+
+	for driverID, expected := range map[uuid.UUID]entities.Status{
+		inactive:  entities.StatusInactive,
+		loggedOut: entities.StatusAvailable,
+		renewed:   entities.StatusAvailable,
+	} {
+		assert.Equal(suite.T(), expected, suite.driverStatus(suite.T(), driverID))
+		assert.Empty(suite.T(), suite.env.Events.EventsForDriver(driverID, "driver.status.changed"))
+	}
+}
+
+// offlineBudget бюджет перевода водителя в offline после истечения сессии
+func (suite *SessionExpiryTestSuite) offlineBudget(driverID uuid.UUID) helpers.PropagationBudget {
+	return helpers.PropagationBudget{
+		Within: sessionExpiryBudget,
+		Visible: func(ctx context.Context) (bool, error) {
+			driver, err := suite.env.DriverService.GetDriverByID(ctx, driverID)
+			if err != nil {
+				return false, err
+			}
+			return driver.Status == entities.StatusOffline, nil
+		},
+	}
+}
+
+// createDriver создает водителя с уникальными контактами в статусе status
+func (suite *SessionExpiryTestSuite) createDriver(t *testing.T, status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900333%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("session.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("SE%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+	require.NoError(t, suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	return created.ID
+}
+
+// driverStatus возвращает текущий статус водителя
+func (suite *SessionExpiryTestSuite) driverStatus(t *testing.T, driverID uuid.UUID) entities.Status {
+	driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, driverID)
+	require.NoError(t, err)
+	return driver.Status
+}
+
+// TestSessionExpiryTestSuite запускает тестовый suite
+func TestSessionExpiryTestSuite(t *testing.T) {
+	suite.Run(t, new(SessionExpiryTestSuite))
+}
@@ -0,0 +1,202 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/domain/services"
+	"driver-service/internal/repositories"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// maxShiftDuration максимальная длительность смены, после которой смена считается брошенной
+const maxShiftDuration = 12 * time.Hour
+
+// ShiftAutoCloseTestSuite тестовый suite для брошенных (не закрытых) смен
+type ShiftAutoCloseTestSuite struct {
+	suite.Suite
+	testDB        *helpers.TestDB
+	driverRepo    repositories.DriverRepository
+	driverService services.DriverService
+	jobService    services.JobService
+	events        *helpers.EventRecorder
+	ctx           context.Context
+	testDriverID  uuid.UUID
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *ShiftAutoCloseTestSuite) SetupSuite() {
+	suite.testDB = helpers.SetupTestDB(suite.T())
+	logger := helpers.CreateTestLogger(suite.T())
+	suite.ctx = context.Background()
+
+	suite.driverRepo = repositories.NewDriverRepository(suite.testDB.DB, logger)
+	documentRepo := repositories.NewDocumentRepository(suite.testDB.DB, logger)
+	suite.events = helpers.NewEventRecorder()
+
+	suite.driverService = services.NewDriverService(suite.driverRepo, documentRepo, suite.events, logger)
+	suite.jobService = services.NewJobService(suite.driverRepo, documentRepo,
+		repositories.NewLocationRepository(suite.testDB.DB, logger), repositories.NewShiftRepository(suite.testDB.DB, logger),
+		repositories.NewHeartbeatRepository(suite.testDB.DB, logger), repositories.NewConnectionRepository(suite.testDB.DB, logger),
+		suite.events, maxShiftDuration, helpers.TestLocationRetention, helpers.TestHeartbeatTimeout,
+		helpers.TestVerificationTimeout, logger)
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *ShiftAutoCloseTestSuite) TearDownSuite() {
+	suite.testDB.TeardownTestDB(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *ShiftAutoCloseTestSuite) SetupTest() {
+	suite.testDB.CleanupTables(suite.T())
+	suite.events.Reset()
+
+	driver, err := suite.driverService.CreateDriver(suite.ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.driverRepo.UpdateStatus(suite.ctx, driver.ID, entities.StatusOnShift))
+	suite.testDriverID = driver.ID
+}
+
+// TestOrphanShiftBlocksNewShift тестирует, что незакрытая смена не дает открыть новую
+func (suite *ShiftAutoCloseTestSuite) TestOrphanShiftBlocksNewShift() {
+	// Arrange
+	orphan := suite.openShift(time.Now().Add(-20*time.Hour), 5, 42.5, 3200)
+
+	// Act
+	next := fixtures.CreateTestShift(suite.testDriverID)
+	_, err := suite.testDB.NamedExecContext(suite.ctx, `
+		INSERT INTO driver_shifts (id, driver_id, start_time, status, metadata, created_at, updated_at)
+		VALUES (:id, :driver_id, :start_time, :status, :metadata, :created_at, :updated_at)`, next)
+
+	// Assert
+	require.Error(suite.T(), err, "Уникальный индекс должен запрещать вторую активную смену")
+	assert.Contains(suite.T(), err.Error(), "idx_driver_shifts_active")
+
+	stored := suite.testDB.GetShift(suite.T(), orphan.ID)
+	assert.True(suite.T(), stored.IsActive())
+}
+
+// TestOrphanShiftsDetection тестирует выборку смен, открытых дольше максимальной длительности
+func (suite *ShiftAutoCloseTestSuite) TestOrphanShiftsDetection() {
+	// Arrange
+	drivers := fixtures.CreateMultipleTestDrivers(2)
+	var otherDriverIDs []uuid.UUID
+	for _, driver := range drivers {
+		created, err := suite.driverService.CreateDriver(suite.ctx, driver)
+		require.NoError(suite.T(), err)
+		otherDriverIDs = append(otherDriverIDs, created.ID)
+	}
+
+	orphan := suite.openShift(time.Now().Add(-maxShiftDuration-time.Hour), 3, 25, 1800)
+	fresh := fixtures.CreateTestShift(otherDriverIDs[0])
+	fresh.StartTime = time.Now().Add(-time.Hour)
+	suite.testDB.InsertShift(suite.T(), fresh)
+
+	completed := fixtures.CreateCompletedShiftInZone(otherDriverIDs[1], time.UTC,
+		time.Now().Add(-30*time.Hour), time.Now().Add(-20*time.Hour), 8, 4000)
+	suite.testDB.InsertShift(suite.T(), completed)
+
+	// Act
+	var orphanIDs []uuid.UUID
+	err := suite.testDB.SelectContext(suite.ctx, &orphanIDs, `
+		SELECT id FROM driver_shifts
+		WHERE status = 'active' AND end_time IS NULL AND start_time < $1`,
+		time.Now().Add(-maxShiftDuration))
+
+	// Assert
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), []uuid.UUID{orphan.ID}, orphanIDs)
+}
+
+// TestAutoCloseOrphanShift тестирует автоматическое закрытие брошенной смены
+func (suite *ShiftAutoCloseTestSuite) TestAutoCloseOrphanShift() {
+	// Arrange
+	orphan := suite.openShift(time.Now().Add(-maxShiftDuration-2*time.Hour), 7, 63.4, 5100)
+
+	// Act
+	result, err := suite.jobService.RunJob(suite.ctx, services.JobAutoCloseShifts)
+
+	// Assert
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), 1, result.Affected)
+
+	stored := suite.testDB.GetShift(suite.T(), orphan.ID)
+	assert.Equal(suite.T(), entities.ShiftStatusCompleted, stored.Status)
+	require.NotNil(suite.T(), stored.EndTime)
+	assert.False(suite.T(), stored.EndTime.After(orphan.StartTime.Add(maxShiftDuration)),
+		"Смена должна закрываться не позже максимальной длительности")
+
+	// Итоги смены не должны теряться при закрытии
+	assert.Equal(suite.T(), orphan.TotalTrips, stored.TotalTrips)
+	assert.InDelta(suite.T(), orphan.TotalDistance, stored.TotalDistance, 0.01)
+	assert.InDelta(suite.T(), orphan.TotalEarnings, stored.TotalEarnings, 0.01)
+
+	driver, err := suite.driverService.GetDriverByID(suite.ctx, suite.testDriverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), entities.StatusAvailable, driver.Status, "Статус водителя должен сбрасываться")
+
+	events := suite.events.EventsForDriver(suite.testDriverID, "shift.auto_closed")
+	require.Len(suite.T(), events, 1)
+	assert.Equal(suite.T(), orphan.ID.String(), events[0].DataMap()["shift_id"])
+}
+
+// TestAutoCloseSuspendedShift тестирует автоматическое закрытие смены, брошенной на перерыве: перерыв закрывается
+// моментом окончания смены, водитель возвращается с перерыва в статус available
+func (suite *ShiftAutoCloseTestSuite) TestAutoCloseSuspendedShift() {
+	// Arrange
+	start := time.Now().Add(-maxShiftDuration - 2*time.Hour)
+	shift := fixtures.CreateTestShift(suite.testDriverID)
+	shift.StartTime = start
+	shift.Suspend()
+	// Перерыв начат за час до истечения максимальной длительности
+	shift.Metadata["paused_at"] = start.Add(maxShiftDuration - time.Hour).Format(time.RFC3339Nano)
+	suite.testDB.InsertShift(suite.T(), shift)
+	require.NoError(suite.T(), suite.driverRepo.UpdateStatus(suite.ctx, suite.testDriverID, entities.StatusInactive))
+
+	// Act
+	result, err := suite.jobService.RunJob(suite.ctx, services.JobAutoCloseShifts)
+
+	// Assert
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), 1, result.Affected)
+
+	stored := suite.testDB.GetShift(suite.T(), shift.ID)
+	assert.Equal(suite.T(), entities.ShiftStatusCompleted, stored.Status)
+	require.NotNil(suite.T(), stored.EndTime)
+	assert.WithinDuration(suite.T(), start.Add(maxShiftDuration), *stored.EndTime, time.Second)
+	assert.NotContains(suite.T(), stored.Metadata, "paused_at", "Перерыв закрывается вместе со сменой")
+	assert.InDelta(suite.T(), 60, stored.GetBreakDuration(), 1, "Перерыв длится до окончания смены")
+
+	driver, err := suite.driverService.GetDriverByID(suite.ctx, suite.testDriverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), entities.StatusAvailable, driver.Status, "Водитель возвращается с перерыва")
+	assert.Len(suite.T(), suite.events.EventsForDriver(suite.testDriverID, "shift.auto_closed"), 1)
+}
+
+// openShift создает активную смену тестового водителя с накопленными итогами
+func (suite *ShiftAutoCloseTestSuite) openShift(start time.Time, trips int, distance, earnings float64) *entities.DriverShift {
+	shift := fixtures.CreateTestShift(suite.testDriverID)
+	shift.StartTime = start
+	shift.TotalTrips = trips
+	shift.TotalDistance = distance
+	shift.TotalEarnings = earnings
+
+	suite.testDB.InsertShift(suite.T(), shift)
+	return shift
+}
+
+// TestShiftAutoCloseTestSuite запускает тестовый suite
+func TestShiftAutoCloseTestSuite(t *testing.T) {
+	suite.Run(t, new(ShiftAutoCloseTestSuite))
+}
@@ -0,0 +1,212 @@
+//go:build integration
+
+package integration
+
+import (
+	"errors"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// ShiftBreakTestSuite тестовый suite для перерывов (приостановки и возобновления) смены
+type ShiftBreakTestSuite struct {
+	suite.Suite
+	env            *helpers.TestEnvironment
+	testDriverID   uuid.UUID
+	driverPosition fixtures.RoutePoint
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *ShiftBreakTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.driverPosition = fixtures.RoutePoint{Latitude: 55.7558, Longitude: 37.6173}
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *ShiftBreakTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом: водитель с проверенным удостоверением на линии
+func (suite *ShiftBreakTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	// Поиск поблизости должен доходить до БД: кеш не успевает отразить перерыв
+	suite.env.SetFeature(suite.T(), features.GeoCache, false)
+
+	driver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, fixtures.CreateTestDriver())
+	require.NoError(suite.T(), err)
+	suite.testDriverID = driver.ID
+
+	license := fixtures.CreateTestDocument(driver.ID, entities.DocumentTypeDriverLicense)
+	require.NoError(suite.T(), suite.env.DocumentRepo.Create(suite.env.Ctx, license))
+	verifiedBy := "admin"
+	require.NoError(suite.T(), suite.env.DocumentRepo.UpdateStatus(suite.env.Ctx, license.ID,
+		entities.VerificationStatusVerified, &verifiedBy, nil))
+
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driver.ID, entities.StatusOnShift))
+
+	location := entities.NewDriverLocation(driver.ID, suite.driverPosition.Latitude, suite.driverPosition.Longitude, time.Now())
+	require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+}
+
+// TestBreakExcludedFromShiftMetrics тестирует, что время перерыва сохраняется в смене
+// и не учитывается в заработке в час
+func (suite *ShiftBreakTestSuite) TestBreakExcludedFromShiftMetrics() {
+	// Arrange
+	shift := fixtures.CreateTestShift(suite.testDriverID)
+	shift.StartTime = time.Now().Add(-4 * time.Hour)
+	shift.TotalTrips = 6
+	shift.TotalEarnings = 2700
+	suite.env.DB.InsertShift(suite.T(), shift)
+
+	// Act - перерыв, начатый час назад
+	shift.Suspend()
+	shift.Metadata["paused_at"] = time.Now().Add(-time.Hour).Format(time.RFC3339Nano)
+	suite.env.DB.UpdateShift(suite.T(), shift)
+
+	// Assert - текущий перерыв виден после чтения из БД
+	paused := suite.env.DB.GetShift(suite.T(), shift.ID)
+	assert.True(suite.T(), paused.IsPaused())
+	assert.False(suite.T(), paused.IsActive())
+	assert.Equal(suite.T(), int64(60), paused.GetBreakDuration())
+
+	// Act
+	paused.Resume()
+	suite.env.DB.UpdateShift(suite.T(), paused)
+
+	// Assert - завершенный перерыв сохраняется, заработок в час считается по трем рабочим часам
+	resumed := suite.env.DB.GetShift(suite.T(), shift.ID)
+	assert.True(suite.T(), resumed.IsActive())
+	assert.Equal(suite.T(), int64(60), resumed.GetBreakDuration())
+	assert.Equal(suite.T(), int64(180), resumed.GetWorkingDuration())
+	assert.InDelta(suite.T(), 900.0, resumed.GetEarningsPerHour(), 0.01, "Перерыв не должен учитываться в заработке в час")
+
+	response := resumed.ToResponse()
+	assert.Equal(suite.T(), int64(240), response.Duration)
+	assert.Equal(suite.T(), int64(60), response.BreakDuration)
+}
+
+// TestPausedDriverIsNotAssigned тестирует, что водитель на перерыве не получает заказы и не виден в поиске,
+// а после возобновления смены снова доступен
+func (suite *ShiftBreakTestSuite) TestPausedDriverIsNotAssigned() {
+	// Arrange
+	started, response := suite.env.API.StartShift(suite.testDriverID)
+	require.NotNil(suite.T(), started, string(response.Body))
+	assert.Equal(suite.T(), entities.ShiftStatusActive, started.Status)
+
+	order := helpers.OrderRequest{
+		OrderID:   uuid.New(),
+		Latitude:  suite.driverPosition.Latitude,
+		Longitude: suite.driverPosition.Longitude,
+	}
+	dispatcher := helpers.NewDispatcher(suite.env.API, suite.env.Events, dispatchSearchRadiusKm)
+
+	// Act
+	paused, response := suite.env.API.PauseShift(suite.testDriverID)
+
+	// Assert - водитель на перерыве недоступен для заказов
+	require.NotNil(suite.T(), paused, string(response.Body))
+	assert.Equal(suite.T(), entities.ShiftStatusSuspended, paused.Status)
+
+	assert.ErrorIs(suite.T(), suite.env.DriverService.ValidateDriverForOrder(suite.env.Ctx, suite.testDriverID),
+		entities.ErrDriverNotAvailable, "Водитель на перерыве не должен проходить проверку для заказа")
+
+	_, err := dispatcher.Dispatch(suite.env.Ctx, order)
+	assert.True(suite.T(), errors.Is(err, helpers.ErrNoDriverAvailable), "Заказ не должен назначаться водителю на перерыве: %v", err)
+	assert.Empty(suite.T(), suite.env.Events.EventsOfType("order.assigned"))
+
+	assert.NotContains(suite.T(), suite.nearbyDriverIDs(), suite.testDriverID, "Водитель на перерыве не должен быть в поиске поблизости")
+
+	// Act
+	resumed, response := suite.env.API.ResumeShift(suite.testDriverID)
+
+	// Assert - после возобновления водитель снова доступен
+	require.NotNil(suite.T(), resumed, string(response.Body))
+	assert.Equal(suite.T(), entities.ShiftStatusActive, resumed.Status)
+
+	assert.NoError(suite.T(), suite.env.DriverService.ValidateDriverForOrder(suite.env.Ctx, suite.testDriverID))
+	assert.Contains(suite.T(), suite.nearbyDriverIDs(), suite.testDriverID)
+
+	assignment, err := dispatcher.Dispatch(suite.env.Ctx, order)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), suite.testDriverID, assignment.DriverID)
+
+	// Время перерыва отражается в текущей смене и не входит в рабочее время
+	current, response := suite.env.API.GetCurrentShift(suite.testDriverID)
+	require.NotNil(suite.T(), current, string(response.Body))
+	assert.LessOrEqual(suite.T(), current.BreakDuration, current.Duration)
+}
+
+// TestShiftStateConflicts тестирует ответы API смен на действия, недопустимые в текущем состоянии смены
+func (suite *ShiftBreakTestSuite) TestShiftStateConflicts() {
+	// Arrange
+	_, response := suite.env.API.GetCurrentShift(suite.testDriverID)
+	require.Equal(suite.T(), http.StatusNotFound, response.StatusCode, string(response.Body))
+	suite.env.API.AssertErrorResponse(response, "SHIFT_NOT_FOUND")
+
+	_, response = suite.env.API.StartShift(suite.testDriverID)
+	require.Equal(suite.T(), http.StatusCreated, response.StatusCode, string(response.Body))
+
+	// Act
+	_, secondStart := suite.env.API.StartShift(suite.testDriverID)
+	_, resumeActive := suite.env.API.ResumeShift(suite.testDriverID)
+	_, firstPause := suite.env.API.PauseShift(suite.testDriverID)
+	_, secondPause := suite.env.API.PauseShift(suite.testDriverID)
+	ended, endResponse := suite.env.API.EndShift(suite.testDriverID)
+	_, pauseEnded := suite.env.API.PauseShift(suite.testDriverID)
+
+	// Assert
+	assert.Equal(suite.T(), http.StatusConflict, secondStart.StatusCode)
+	suite.env.API.AssertErrorResponse(secondStart, "SHIFT_EXISTS")
+	assert.Equal(suite.T(), http.StatusConflict, resumeActive.StatusCode)
+	suite.env.API.AssertErrorResponse(resumeActive, "INVALID_SHIFT_STATE")
+	assert.Equal(suite.T(), http.StatusOK, firstPause.StatusCode, string(firstPause.Body))
+	assert.Equal(suite.T(), http.StatusConflict, secondPause.StatusCode)
+	suite.env.API.AssertErrorResponse(secondPause, "INVALID_SHIFT_STATE")
+
+	// Смена, завершенная на перерыве, закрывает и перерыв
+	require.NotNil(suite.T(), ended, string(endResponse.Body))
+	assert.Equal(suite.T(), entities.ShiftStatusCompleted, ended.Status)
+	assert.NotNil(suite.T(), ended.EndTime)
+	assert.Equal(suite.T(), http.StatusNotFound, pauseEnded.StatusCode)
+	suite.env.API.AssertErrorResponse(pauseEnded, "SHIFT_NOT_FOUND")
+
+	driver, err := suite.env.DriverRepo.GetByID(suite.env.Ctx, suite.testDriverID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), entities.StatusAvailable, driver.Status)
+
+	var changedBy []string
+	for _, event := range suite.env.Events.EventsForDriver(suite.testDriverID, "driver.status.changed") {
+		changedBy = append(changedBy, event.DataMap()["changed_by"].(string))
+	}
+	assert.Equal(suite.T(), []string{"shift", "shift"}, changedBy, "Перерыв и окончание смены меняют статус водителя")
+}
+
+// nearbyDriverIDs возвращает водителей, найденных поблизости от тестового водителя
+func (suite *ShiftBreakTestSuite) nearbyDriverIDs() []uuid.UUID {
+	nearby, err := suite.env.LocationService.GetNearbyDrivers(suite.env.Ctx,
+		suite.driverPosition.Latitude, suite.driverPosition.Longitude, dispatchSearchRadiusKm, 100)
+	require.NoError(suite.T(), err)
+
+	driverIDs := make([]uuid.UUID, 0, len(nearby))
+	for _, location := range nearby {
+		driverIDs = append(driverIDs, location.DriverID)
+	}
+	return driverIDs
+}
+
+// TestShiftBreakTestSuite запускает тестовый suite
+func TestShiftBreakTestSuite(t *testing.T) {
+	suite.Run(t, new(ShiftBreakTestSuite))
+}
@@ -0,0 +1,178 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры восьмичасовой смены: три поездки по два часа с часовыми перерывами
+const (
+	shiftDayPingInterval = 30 * time.Second
+	shiftDayLeg          = 2 * time.Hour
+	shiftDayBreak        = time.Hour
+	shiftDayLegs         = 3
+)
+
+// ShiftDayScenarioTestSuite проигрывает полную смену водителя по часам сценария (helpers.NewScenarioClock):
+// с имитируемыми часами восемь часов езды укладываются в секунды, метки времени в запросах и событиях
+// остаются такими же, как при прогоне в реальном времени
+type ShiftDayScenarioTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *ShiftDayScenarioTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *ShiftDayScenarioTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *ShiftDayScenarioTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestEightHourShift тестирует историю, события и текущее местоположение после восьмичасовой смены
+func (suite *ShiftDayScenarioTestSuite) TestEightHourShift() {
+	// Arrange
+	span := shiftDayLegs*shiftDayLeg + (shiftDayLegs-1)*shiftDayBreak
+	clock := helpers.NewScenarioClock(suite.T(), span)
+	suite.env.Events.SetClock(clock)
+	started := time.Now()
+
+	driverID := suite.createDriver(entities.StatusOnShift)
+	outbound := fixtures.CreateCenterToAirportRoute(int(shiftDayLeg / shiftDayPingInterval))
+	inbound := make([]fixtures.RoutePoint, len(outbound))
+	for i, point := range outbound {
+		inbound[len(outbound)-1-i] = point
+	}
+
+	// Act
+	shiftStart := clock.Now()
+	var last fixtures.RoutePoint
+	for leg := 0; leg < shiftDayLegs; leg++ {
+		route := outbound
+		if leg%2 == 1 {
+			route = inbound
+		}
+
+		for i, point := range route {
+			if i > 0 {
+				clock.Sleep(shiftDayPingInterval)
+			}
+			suite.postLocation(driverID, point, clock.Now())
+			last = point
+		}
+
+		if leg < shiftDayLegs-1 {
+			clock.Sleep(shiftDayBreak)
+		}
+	}
+	shiftEnd := clock.Now()
+
+	// Assert
+	pointsPerLeg := len(outbound)
+	assert.Equal(suite.T(), span, shiftEnd.Sub(shiftStart))
+	if helpers.IsSimulated(clock) {
+		assert.Less(suite.T(), time.Since(started), time.Minute, "Смена с имитируемыми часами должна занимать секунды")
+	}
+
+	suite.T().Run("History", func(t *testing.T) {
+		page, response := suite.env.API.GetLocationHistory(driverID, helpers.HistoryQuery{
+			From: shiftStart.Add(-time.Second),
+			To:   shiftEnd.Add(time.Minute),
+		})
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+		require.Equal(t, shiftDayLegs*pointsPerLeg, page.Count)
+
+		helpers.AssertTrajectorySane(t, page.Locations, helpers.DefaultTrajectoryLimits())
+		assert.Equal(t, shiftDayLegs-1, countBreaks(page.Locations, shiftDayBreak))
+		assert.WithinDuration(t, shiftStart, page.Locations[0].RecordedAt, time.Second)
+		assert.WithinDuration(t, shiftEnd, page.Locations[len(page.Locations)-1].RecordedAt, time.Second)
+	})
+
+	suite.T().Run("Events", func(t *testing.T) {
+		events := suite.env.Events.EventsForDriver(driverID, "driver.location.updated")
+		require.Len(t, events, shiftDayLegs*pointsPerLeg)
+
+		// События записаны по часам сценария: от первой до последней точки прошла вся смена
+		assert.WithinDuration(t, events[0].Timestamp.Add(span), events[len(events)-1].Timestamp, time.Second)
+	})
+
+	suite.T().Run("CurrentLocation", func(t *testing.T) {
+		// Смена заканчивается в настоящем, поэтому последняя точка еще не устарела
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations/current", driverID)),
+		})
+		require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+		var location httpHandlers.LocationResponse
+		suite.env.API.UnmarshalResponse(response, &location)
+		assert.InDelta(t, last.Latitude, location.Latitude, 1e-6)
+		assert.InDelta(t, last.Longitude, location.Longitude, 1e-6)
+	})
+}
+
+// countBreaks считает промежутки между соседними точками не короче minGap
+func countBreaks(locations []*httpHandlers.LocationResponse, minGap time.Duration) int {
+	breaks := 0
+	for i := 1; i < len(locations); i++ {
+		if locations[i].RecordedAt.Sub(locations[i-1].RecordedAt) >= minGap {
+			breaks++
+		}
+	}
+	return breaks
+}
+
+// createDriver создает водителя в статусе status
+func (suite *ShiftDayScenarioTestSuite) createDriver(status entities.Status) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900888%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("shift.day.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("SD%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, created.ID, status))
+	return created.ID
+}
+
+// postLocation отправляет местоположение водителя через API с меткой времени по часам сценария
+func (suite *ShiftDayScenarioTestSuite) postLocation(driverID uuid.UUID, point fixtures.RoutePoint, at time.Time) {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body: map[string]interface{}{
+			"latitude":  point.Latitude,
+			"longitude": point.Longitude,
+			"timestamp": at.Unix(),
+		},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+}
+
+// TestShiftDayScenarioTestSuite запускает тестовый suite
+func TestShiftDayScenarioTestSuite(t *testing.T) {
+	suite.Run(t, new(ShiftDayScenarioTestSuite))
+}
@@ -0,0 +1,166 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"testing"
+	"time"
+	_ "time/tzdata"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/repositories"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// ShiftTimezoneTestSuite тестовый suite для смен, пересекающих переход на летнее время и разные часовые пояса
+type ShiftTimezoneTestSuite struct {
+	suite.Suite
+	testDB     *helpers.TestDB
+	driverRepo repositories.DriverRepository
+	ctx        context.Context
+	driverIDs  []uuid.UUID
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *ShiftTimezoneTestSuite) SetupSuite() {
+	suite.testDB = helpers.SetupTestDB(suite.T())
+	logger := helpers.CreateTestLogger(suite.T())
+
+	suite.driverRepo = repositories.NewDriverRepository(suite.testDB.DB, logger)
+	suite.ctx = context.Background()
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *ShiftTimezoneTestSuite) TearDownSuite() {
+	suite.testDB.TeardownTestDB(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *ShiftTimezoneTestSuite) SetupTest() {
+	suite.testDB.CleanupTables(suite.T())
+
+	suite.driverIDs = nil
+	for _, driver := range fixtures.CreateMultipleTestDrivers(2) {
+		require.NoError(suite.T(), suite.driverRepo.Create(suite.ctx, driver))
+		suite.driverIDs = append(suite.driverIDs, driver.ID)
+	}
+}
+
+// TestShiftDurationAcrossDST тестирует длительность смен, пересекающих переход на летнее/зимнее время
+func (suite *ShiftTimezoneTestSuite) TestShiftDurationAcrossDST() {
+	testCases := []struct {
+		name            string
+		timezone        string
+		start           [5]int // год, месяц, день, час, минута по местному времени
+		end             [5]int
+		expectedMinutes int64
+	}{
+		// 31 марта 2024 в Берлине часы переводятся вперед: 00:00-06:00 по местному времени - это 5 часов
+		{name: "Berlin spring forward", timezone: "Europe/Berlin", start: [5]int{2024, 3, 31, 0, 0}, end: [5]int{2024, 3, 31, 6, 0}, expectedMinutes: 5 * 60},
+		// 27 октября 2024 часы переводятся назад: тот же интервал длится 7 часов
+		{name: "Berlin fall back", timezone: "Europe/Berlin", start: [5]int{2024, 10, 27, 0, 0}, end: [5]int{2024, 10, 27, 6, 0}, expectedMinutes: 7 * 60},
+		{name: "New York spring forward", timezone: "America/New_York", start: [5]int{2024, 3, 10, 0, 0}, end: [5]int{2024, 3, 10, 6, 0}, expectedMinutes: 5 * 60},
+		// В Москве перехода нет
+		{name: "Moscow no DST", timezone: "Europe/Moscow", start: [5]int{2024, 3, 31, 0, 0}, end: [5]int{2024, 3, 31, 6, 0}, expectedMinutes: 6 * 60},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			location, err := time.LoadLocation(tc.timezone)
+			require.NoError(t, err)
+
+			start := time.Date(tc.start[0], time.Month(tc.start[1]), tc.start[2], tc.start[3], tc.start[4], 0, 0, location)
+			end := time.Date(tc.end[0], time.Month(tc.end[1]), tc.end[2], tc.end[3], tc.end[4], 0, 0, location)
+			shift := fixtures.CreateCompletedShiftInZone(suite.driverIDs[0], location, start, end, 3, 1500)
+
+			// Act
+			suite.testDB.InsertShift(t, shift)
+
+			// Assert
+			assert.Equal(t, tc.expectedMinutes, shift.GetDuration(), "Длительность по entity")
+
+			var dbMinutes int64
+			err = suite.testDB.GetContext(suite.ctx, &dbMinutes,
+				"SELECT calculate_shift_duration(start_time, end_time) FROM driver_shifts WHERE id = $1", shift.ID)
+			require.NoError(t, err)
+			assert.Equal(t, tc.expectedMinutes, dbMinutes, "Длительность по calculate_shift_duration")
+
+			// Момент времени должен сохраняться независимо от зоны, в которой он был задан
+			var stored struct {
+				StartTime time.Time `db:"start_time"`
+				EndTime   time.Time `db:"end_time"`
+			}
+			err = suite.testDB.GetContext(suite.ctx, &stored, "SELECT start_time, end_time FROM driver_shifts WHERE id = $1", shift.ID)
+			require.NoError(t, err)
+			assert.True(t, stored.StartTime.Equal(start), "start_time: %s != %s", stored.StartTime, start)
+			assert.True(t, stored.EndTime.Equal(end), "end_time: %s != %s", stored.EndTime, end)
+		})
+	}
+}
+
+// TestEarningsAggregatedByLocalDate тестирует группировку заработка по дате в зоне города, а не по дате UTC
+func (suite *ShiftTimezoneTestSuite) TestEarningsAggregatedByLocalDate() {
+	// Arrange
+	moscow, err := time.LoadLocation("Europe/Moscow")
+	require.NoError(suite.T(), err)
+	vladivostok, err := time.LoadLocation("Asia/Vladivostok")
+	require.NoError(suite.T(), err)
+
+	// Обе смены начинаются 1 июня по местному времени, но 31 мая по UTC
+	shifts := []*entities.DriverShift{
+		fixtures.CreateCompletedShiftInZone(suite.driverIDs[0], moscow,
+			time.Date(2024, 6, 1, 1, 0, 0, 0, moscow), time.Date(2024, 6, 1, 5, 0, 0, 0, moscow), 4, 2000),
+		fixtures.CreateCompletedShiftInZone(suite.driverIDs[1], vladivostok,
+			time.Date(2024, 6, 1, 2, 0, 0, 0, vladivostok), time.Date(2024, 6, 1, 8, 0, 0, 0, vladivostok), 6, 3500),
+	}
+
+	for _, shift := range shifts {
+		suite.testDB.InsertShift(suite.T(), shift)
+		require.NotEqual(suite.T(), shift.StartTime.UTC().Format("2006-01-02"), shift.StartTime.Format("2006-01-02"),
+			"Смена должна попадать на разные даты в UTC и по местному времени")
+	}
+
+	// Act
+	// Примечание: отчетов по заработку в сервисе пока нет. Запрос ниже - эталон группировки,
+	// который должен использовать будущий отчет: дата берется в часовом поясе города смены.
+	var rows []struct {
+		DriverID  uuid.UUID `db:"driver_id"`
+		LocalDate string    `db:"local_date"`
+		UTCDate   string    `db:"utc_date"`
+		Earnings  float64   `db:"earnings"`
+	}
+	err = suite.testDB.SelectContext(suite.ctx, &rows, `
+		SELECT driver_id,
+		       to_char((start_time AT TIME ZONE (metadata->>'timezone'))::date, 'YYYY-MM-DD') AS local_date,
+		       to_char((start_time AT TIME ZONE 'UTC')::date, 'YYYY-MM-DD') AS utc_date,
+		       SUM(total_earnings)::float8 AS earnings
+		FROM driver_shifts
+		GROUP BY driver_id, local_date, utc_date
+		ORDER BY driver_id`)
+	require.NoError(suite.T(), err)
+
+	// Assert
+	require.Len(suite.T(), rows, len(shifts))
+	expectedEarnings := map[uuid.UUID]float64{
+		shifts[0].DriverID: shifts[0].TotalEarnings,
+		shifts[1].DriverID: shifts[1].TotalEarnings,
+	}
+	for _, row := range rows {
+		assert.Equal(suite.T(), "2024-06-01", row.LocalDate, "Смена должна учитываться по местной дате")
+		assert.Equal(suite.T(), "2024-05-31", row.UTCDate, "Наивная группировка по UTC дает другую дату")
+		assert.InDelta(suite.T(), expectedEarnings[row.DriverID], row.Earnings, 0.001)
+	}
+}
+
+// TestShiftTimezoneTestSuite запускает тестовый suite
+func TestShiftTimezoneTestSuite(t *testing.T) {
+	suite.Run(t, new(ShiftTimezoneTestSuite))
+}
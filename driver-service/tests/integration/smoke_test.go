@@ -0,0 +1,196 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// SmokeTestSuite короткая проверка основных сценариев после деплоя (~60 секунд).
+// Запускается через ./scripts/run-tests.sh --mode smoke. Если задан SMOKE_BASE_URL,
+// запросы выполняются к развернутому сервису, иначе - к роутеру в памяти с тестовой БД.
+type SmokeTestSuite struct {
+	suite.Suite
+	env       *helpers.TestEnvironment // nil для развернутого сервиса
+	apiHelper *helpers.APITestHelper
+	slo       helpers.SmokeSLO
+	started   time.Time
+	span      *helpers.TestSpan
+	connStats *helpers.ConnectionStats // соединения к развернутому сервису; nil для роутера в памяти
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *SmokeTestSuite) SetupSuite() {
+	suite.slo = helpers.LoadSmokeSLO()
+	suite.started = time.Now()
+
+	if baseURL := os.Getenv("SMOKE_BASE_URL"); baseURL != "" {
+		suite.T().Logf("Smoke target: %s", baseURL)
+		suite.connStats = helpers.NewConnectionStats()
+		suite.apiHelper = helpers.NewRemoteAPITestHelper(baseURL, suite.T()).WithResponseInterceptor(suite.connStats.Record)
+		return
+	}
+
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.apiHelper = suite.env.API
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *SmokeTestSuite) TearDownSuite() {
+	if suite.env != nil {
+		suite.env.Close(suite.T())
+	}
+	if suite.connStats != nil {
+		// Отделяет установку соединений (DNS, TCP, TLS) от задержки сервиса при превышении SLO шагов
+		suite.connStats.Log(suite.T())
+	}
+
+	helpers.AssertWithinSLO(suite.T(), "smoke total", time.Since(suite.started), suite.slo.Total)
+}
+
+// TestSmoke проверяет health, создание водителя, обновление местоположения, поиск рядом и доставку события
+func (suite *SmokeTestSuite) TestSmoke() {
+	// Шаги и запросы smoke-проверки попадают в span теста (и в OpenTelemetry, если задан OTEL_EXPORTER_OTLP_ENDPOINT)
+	suite.span = helpers.StartTestSpan(suite.T(), nil)
+	suite.apiHelper = suite.apiHelper.WithSpan(suite.span)
+
+	var driverID uuid.UUID
+	lat, lon := 55.7558, 37.6173
+
+	suite.T().Log("Step 1: Health check")
+	suite.step("health", suite.slo.Health, func() {
+		response := suite.apiHelper.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health"})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+
+		var health map[string]interface{}
+		suite.apiHelper.UnmarshalResponse(response, &health)
+		assert.Equal(suite.T(), "healthy", health["status"])
+	})
+
+	suite.T().Log("Step 2: Create driver")
+	suite.step("create driver", suite.slo.CreateDriver, func() {
+		// Уникальные данные: на развернутом сервисе БД не очищается между запусками
+		suffix := time.Now().UnixNano() % 10000000
+
+		request := helpers.CreateDriverRequest()
+		request["phone"] = fmt.Sprintf("+7999%07d", suffix)
+		request["email"] = fmt.Sprintf("smoke-%d@example.com", suffix)
+		request["license_number"] = fmt.Sprintf("SMK%07d", suffix)
+		request["license_expiry"] = time.Now().AddDate(1, 0, 0).UTC().Format(time.RFC3339)
+
+		response := suite.apiHelper.MakeRequest(helpers.APIRequest{
+			Method: http.MethodPost,
+			URL:    suite.apiHelper.APIPath("/drivers"),
+			Body:   request,
+		})
+		require.Equal(suite.T(), http.StatusCreated, response.StatusCode, string(response.Body))
+
+		var driver httpHandlers.DriverResponse
+		suite.apiHelper.UnmarshalResponse(response, &driver)
+		driverID = driver.ID
+	})
+	suite.T().Cleanup(func() { suite.deleteDriver(driverID) })
+
+	if !suite.apiHelper.IsRemote() {
+		// Поиск рядом возвращает только активных водителей
+		require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driverID, entities.StatusAvailable))
+	}
+
+	suite.T().Log("Step 3: Update location")
+	eventsStarted := time.Now()
+	suite.step("update location", suite.slo.UpdateLocation, func() {
+		location := helpers.CreateLocationRequest()
+		location["latitude"] = lat
+		location["longitude"] = lon
+
+		response := suite.apiHelper.MakeRequest(helpers.APIRequest{
+			Method: http.MethodPost,
+			URL:    suite.apiHelper.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+			Body:   location,
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	})
+
+	suite.T().Log("Step 4: Nearby search")
+	suite.step("nearby search", suite.slo.NearbySearch, func() {
+		response := suite.apiHelper.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    suite.apiHelper.APIPath("/locations/nearby"),
+			QueryParams: map[string]string{
+				"latitude":  fmt.Sprintf("%f", lat),
+				"longitude": fmt.Sprintf("%f", lon),
+				"radius_km": "1",
+			},
+		})
+		require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+		if suite.apiHelper.IsRemote() {
+			// Водитель на развернутом сервисе остается в статусе registered и в выдачу не попадает
+			return
+		}
+
+		var nearby httpHandlers.NearbyDriversResponse
+		suite.apiHelper.UnmarshalResponse(response, &nearby)
+
+		found := false
+		for _, driver := range nearby.Drivers {
+			found = found || driver.DriverID == driverID
+		}
+		assert.True(suite.T(), found, "Водитель должен находиться поиском рядом")
+	})
+
+	suite.T().Log("Step 5: Event roundtrip")
+	if suite.apiHelper.IsRemote() {
+		// Примечание: доступа к шине событий развернутого сервиса из smoke-теста нет
+		suite.T().Log("Event roundtrip is not checked against a remote target")
+		return
+	}
+	helpers.Eventually(suite.T(), func(c *helpers.EventuallyT) {
+		assert.Len(c, suite.env.Events.EventsForDriver(driverID, "driver.registered"), 1)
+		assert.Len(c, suite.env.Events.EventsForDriver(driverID, "driver.location.updated"), 1)
+	}, suite.slo.EventRoundtrip, 50*time.Millisecond)
+
+	// Время доставки считается от запроса на обновление местоположения до публикации события
+	published := suite.env.Events.EventsForDriver(driverID, "driver.location.updated")[0].Timestamp
+	helpers.AssertWithinSLO(suite.T(), "event roundtrip", published.Sub(eventsStarted), suite.slo.EventRoundtrip)
+}
+
+// step выполняет шаг smoke-проверки и сверяет его длительность с порогом
+func (suite *SmokeTestSuite) step(name string, limit time.Duration, fn func()) {
+	start := time.Now()
+	suite.span.Step(name, fn)
+	helpers.AssertWithinSLO(suite.T(), name, time.Since(start), limit)
+}
+
+// deleteDriver удаляет созданного smoke-тестом водителя
+func (suite *SmokeTestSuite) deleteDriver(driverID uuid.UUID) {
+	if driverID == uuid.Nil {
+		return
+	}
+
+	response := suite.apiHelper.MakeRequest(helpers.APIRequest{
+		Method: http.MethodDelete,
+		URL:    suite.apiHelper.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+	})
+	if response.StatusCode >= http.StatusBadRequest {
+		suite.T().Logf("Failed to delete smoke driver %s: %d %s", driverID, response.StatusCode, string(response.Body))
+	}
+}
+
+// TestSmokeTestSuite запускает тестовый suite
+func TestSmokeTestSuite(t *testing.T) {
+	suite.Run(t, new(SmokeTestSuite))
+}
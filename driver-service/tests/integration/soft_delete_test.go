@@ -0,0 +1,357 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/features"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// softDeleteLocations количество точек истории местоположений удаляемого водителя
+const softDeleteLocations = 3
+
+// SoftDeleteTestSuite тестирует мягкое удаление водителя: удаленный водитель не виден ни в одном
+// способе чтения, его контакты можно зарегистрировать заново, а восстановление возвращает водителя
+// вместе с историей
+type SoftDeleteTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *SoftDeleteTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *SoftDeleteTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *SoftDeleteTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+	// Поиск поблизости должен видеть удаление и восстановление сразу, а не после истечения кэша
+	suite.env.SetFeature(suite.T(), features.GeoCache, false)
+}
+
+// TestDeletedDriverExcludedFromReads тестирует, что удаленный водитель не возвращается ни одним способом чтения
+func (suite *SoftDeleteTestSuite) TestDeletedDriverExcludedFromReads() {
+	// Arrange
+	deleted := suite.createActiveDriver()
+	kept := suite.createActiveDriver()
+	require.Contains(suite.T(), suite.nearbyDrivers(), deleted)
+
+	// Act
+	suite.deleteDriver(deleted)
+
+	// Assert
+	suite.T().Run("Get", func(t *testing.T) {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodGet,
+			URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s", deleted)),
+		})
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+		suite.env.API.AssertErrorResponse(response, "DRIVER_NOT_FOUND")
+	})
+
+	suite.T().Run("List", func(t *testing.T) {
+		list := suite.listDrivers(nil)
+		assert.NotContains(t, list.ids, deleted)
+		assert.Contains(t, list.ids, kept)
+		assert.Equal(t, 1, list.total, "Удаленный водитель не учитывается в total")
+
+		byStatus := suite.listDrivers(map[string]string{"status": string(entities.StatusAvailable)})
+		assert.Equal(t, []uuid.UUID{kept}, byStatus.ids)
+	})
+
+	suite.T().Run("Active", func(t *testing.T) {
+		active := suite.activeDrivers()
+		assert.NotContains(t, active, deleted)
+		assert.Contains(t, active, kept)
+	})
+
+	suite.T().Run("Nearby", func(t *testing.T) {
+		nearby := suite.nearbyDrivers()
+		assert.NotContains(t, nearby, deleted)
+		assert.Contains(t, nearby, kept)
+	})
+
+	suite.T().Run("Stats", func(t *testing.T) {
+		_, response := suite.env.API.GetLocationHistory(deleted, helpers.HistoryQuery{
+			From: time.Now().Add(-time.Hour),
+			To:   time.Now().Add(time.Minute),
+		})
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+		suite.env.API.AssertErrorResponse(response, "DRIVER_NOT_FOUND")
+
+		_, response = suite.env.API.GetDriverStats(deleted, time.Now().Add(-time.Hour), time.Now().Add(time.Minute))
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+		suite.env.API.AssertErrorResponse(response, "DRIVER_NOT_FOUND")
+	})
+
+	suite.T().Run("SecondDeleteNotFound", func(t *testing.T) {
+		response := suite.env.API.MakeRequest(helpers.APIRequest{
+			Method: http.MethodDelete,
+			URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s", deleted)),
+		})
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+	})
+}
+
+// TestReRegisterDeletedContacts тестирует регистрацию нового водителя с телефоном, email и ВУ удаленного
+func (suite *SoftDeleteTestSuite) TestReRegisterDeletedContacts() {
+	// Arrange
+	request := suite.driverRequest()
+	deleted := suite.registerDriver(request)
+
+	// Пока водитель не удален, контакты заняты
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+	suite.env.API.AssertStatusCode(response, http.StatusConflict)
+	suite.env.API.AssertErrorResponse(response, "DRIVER_EXISTS")
+
+	suite.deleteDriver(deleted)
+
+	// Act
+	registered := suite.registerDriver(request)
+
+	// Assert
+	assert.NotEqual(suite.T(), deleted, registered, "Повторная регистрация создает нового водителя")
+
+	driver, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, registered)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), request["phone"], driver.Phone)
+	assert.Equal(suite.T(), request["email"], driver.Email)
+	assert.Equal(suite.T(), request["license_number"], driver.LicenseNumber)
+	assert.Equal(suite.T(), []uuid.UUID{registered}, suite.listDrivers(nil).ids)
+}
+
+// TestRestoreDriver тестирует восстановление удаленного водителя через административный API
+func (suite *SoftDeleteTestSuite) TestRestoreDriver() {
+	// Arrange
+	driverID := suite.createActiveDriver()
+
+	document := fixtures.CreateTestDocument(driverID, entities.DocumentTypeDriverLicense)
+	require.NoError(suite.T(), suite.env.DocumentRepo.Create(suite.env.Ctx, document))
+	shift := fixtures.CreateTestShift(driverID)
+	suite.env.DB.InsertShift(suite.T(), shift)
+
+	suite.deleteDriver(driverID)
+
+	// Act
+	response := suite.restoreDriver(driverID)
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+	var restored httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &restored)
+	assert.Equal(suite.T(), driverID, restored.ID)
+	assert.Equal(suite.T(), entities.StatusAvailable, restored.Status, "Статус водителя сохраняется")
+
+	suite.T().Run("VisibleAgain", func(t *testing.T) {
+		assert.Contains(t, suite.listDrivers(nil).ids, driverID)
+		assert.Contains(t, suite.activeDrivers(), driverID)
+		assert.Contains(t, suite.nearbyDrivers(), driverID)
+	})
+
+	suite.T().Run("HistoryIntact", func(t *testing.T) {
+		history, response := suite.env.API.GetLocationHistory(driverID, helpers.HistoryQuery{
+			From: time.Now().Add(-time.Hour),
+			To:   time.Now().Add(time.Minute),
+		})
+		require.NotNil(t, history, string(response.Body))
+		assert.Len(t, history.Locations, softDeleteLocations)
+
+		documents, err := suite.env.DocumentRepo.GetByDriverID(suite.env.Ctx, driverID)
+		require.NoError(t, err)
+		require.Len(t, documents, 1)
+		assert.Equal(t, document.ID, documents[0].ID)
+
+		assert.Equal(t, shift.ID, suite.env.DB.GetShift(t, shift.ID).ID)
+	})
+
+	suite.T().Run("EventPublished", func(t *testing.T) {
+		events := suite.env.Events.EventsForDriver(driverID, "driver.restored")
+		require.Len(t, events, 1)
+		assert.Equal(t, string(entities.StatusAvailable), events[0].DataMap()["status"])
+	})
+
+	suite.T().Run("NotDeleted", func(t *testing.T) {
+		response := suite.restoreDriver(driverID)
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+		suite.env.API.AssertErrorResponse(response, "DRIVER_NOT_FOUND")
+	})
+
+	suite.T().Run("UnknownDriver", func(t *testing.T) {
+		response := suite.restoreDriver(uuid.New())
+		suite.env.API.AssertStatusCode(response, http.StatusNotFound)
+		suite.env.API.AssertErrorResponse(response, "DRIVER_NOT_FOUND")
+	})
+}
+
+// TestRestoreAfterReRegistration тестирует отказ в восстановлении, если контакты водителя заняты новой регистрацией
+func (suite *SoftDeleteTestSuite) TestRestoreAfterReRegistration() {
+	// Arrange
+	request := suite.driverRequest()
+	deleted := suite.registerDriver(request)
+	suite.deleteDriver(deleted)
+	registered := suite.registerDriver(request)
+
+	// Act
+	response := suite.restoreDriver(deleted)
+
+	// Assert
+	suite.env.API.AssertStatusCode(response, http.StatusConflict)
+	suite.env.API.AssertErrorResponse(response, "DRIVER_EXISTS")
+
+	_, err := suite.env.DriverService.GetDriverByID(suite.env.Ctx, deleted)
+	assert.ErrorIs(suite.T(), err, entities.ErrDriverNotFound, "Водитель остается удаленным")
+	assert.Equal(suite.T(), []uuid.UUID{registered}, suite.listDrivers(nil).ids)
+	assert.Empty(suite.T(), suite.env.Events.EventsOfType("driver.restored"))
+}
+
+// driverRequest возвращает запрос на регистрацию водителя с уникальными контактами
+func (suite *SoftDeleteTestSuite) driverRequest() map[string]interface{} {
+	suite.drivers++
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7900888%04d", suite.drivers)
+	request["email"] = fmt.Sprintf("deleted.driver%d@example.com", suite.drivers)
+	request["license_number"] = fmt.Sprintf("SD%08d", suite.drivers)
+	return request
+}
+
+// registerDriver регистрирует водителя через API и возвращает его ID
+func (suite *SoftDeleteTestSuite) registerDriver(request map[string]interface{}) uuid.UUID {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   request,
+	})
+	require.Equal(suite.T(), http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return driver.ID
+}
+
+// createActiveDriver регистрирует доступного водителя с историей местоположений в центре Москвы
+func (suite *SoftDeleteTestSuite) createActiveDriver() uuid.UUID {
+	driverID := suite.registerDriver(suite.driverRequest())
+	require.NoError(suite.T(), suite.env.DriverRepo.UpdateStatus(suite.env.Ctx, driverID, entities.StatusAvailable))
+
+	now := time.Now()
+	for i := softDeleteLocations - 1; i >= 0; i-- {
+		location := fixtures.CreateTestLocationWithCoords(driverID, 55.7558+float64(i)*0.001, 37.6173)
+		location.RecordedAt = now.Add(-time.Duration(i) * 10 * time.Second)
+		require.NoError(suite.T(), suite.env.LocationService.UpdateLocation(suite.env.Ctx, location))
+	}
+	return driverID
+}
+
+// deleteDriver удаляет водителя через API
+func (suite *SoftDeleteTestSuite) deleteDriver(driverID uuid.UUID) {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodDelete,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/drivers/%s", driverID)),
+	})
+	require.Equal(suite.T(), http.StatusNoContent, response.StatusCode, string(response.Body))
+}
+
+// restoreDriver восстанавливает водителя через административный API
+func (suite *SoftDeleteTestSuite) restoreDriver(driverID uuid.UUID) *helpers.APIResponse {
+	return suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath(fmt.Sprintf("/admin/drivers/%s/restore", driverID)),
+	})
+}
+
+// driverList ID и total одной страницы списка водителей
+type driverList struct {
+	ids   []uuid.UUID
+	total int
+}
+
+// listDrivers возвращает первую страницу GET /drivers
+func (suite *SoftDeleteTestSuite) listDrivers(query map[string]string) driverList {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method:      http.MethodGet,
+		URL:         suite.env.API.APIPath("/drivers"),
+		QueryParams: query,
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	var list httpHandlers.ListDriversResponse
+	suite.env.API.UnmarshalResponse(response, &list)
+
+	result := driverList{total: list.Total}
+	for _, driver := range list.Drivers {
+		result.ids = append(result.ids, driver.ID)
+	}
+	return result
+}
+
+// activeDrivers возвращает ID водителей из GET /drivers/active
+func (suite *SoftDeleteTestSuite) activeDrivers() []uuid.UUID {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath("/drivers/active"),
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	var active struct {
+		Drivers []*httpHandlers.DriverResponse `json:"drivers"`
+	}
+	suite.env.API.UnmarshalResponse(response, &active)
+
+	ids := make([]uuid.UUID, 0, len(active.Drivers))
+	for _, driver := range active.Drivers {
+		ids = append(ids, driver.ID)
+	}
+	return ids
+}
+
+// nearbyDrivers возвращает ID водителей из GET /locations/nearby вокруг центра Москвы
+func (suite *SoftDeleteTestSuite) nearbyDrivers() []uuid.UUID {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    suite.env.API.APIPath("/locations/nearby"),
+		QueryParams: map[string]string{
+			"latitude":  "55.7558",
+			"longitude": "37.6173",
+			"radius_km": "5",
+		},
+	})
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode, string(response.Body))
+
+	var nearby httpHandlers.NearbyDriversResponse
+	suite.env.API.UnmarshalResponse(response, &nearby)
+
+	ids := make([]uuid.UUID, 0, len(nearby.Drivers))
+	for _, driver := range nearby.Drivers {
+		ids = append(ids, driver.DriverID)
+	}
+	return ids
+}
+
+// TestSoftDeleteTestSuite запускает тестовый suite
+func TestSoftDeleteTestSuite(t *testing.T) {
+	suite.Run(t, new(SoftDeleteTestSuite))
+}
@@ -0,0 +1,143 @@
+//go:build integration
+
+package integration
+
+import (
+	"context"
+	"net/http"
+	"testing"
+	"time"
+
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/internal/repositories"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// startupRecoveryTimeout сколько ждать, пока сервис заметит появившуюся зависимость: с запасом на паузу
+// переподключения к Redis (1s) и TestStartupRetryInterval
+const startupRecoveryTimeout = 10 * time.Second
+
+// StartupOrderingTestSuite тестирует запуск сервиса раньше Postgres, NATS и Redis: пока зависимостей нет,
+// сервис жив, не готов и повторяет подключения, а после их появления становится готов без перезапуска.
+// Redis и NATS проверяются, если доступны тестовые экземпляры.
+type StartupOrderingTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	startup *helpers.LateStart
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *StartupOrderingTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.startup = suite.env.StartBeforeDependencies(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *StartupOrderingTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *StartupOrderingTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestServiceStartsBeforeDependencies тестирует, что сервис дожидается зависимостей, появляющихся по одной
+// в обратном порядке (Redis, NATS, Postgres), и становится готов после последней
+func (suite *StartupOrderingTestSuite) TestServiceStartsBeforeDependencies() {
+	chaos := suite.startup.Chaos
+	dependencies := sortedKeys(chaos.Proxies)
+
+	// Assert - зависимостей нет: сервис жив, не готов и повторяет подключения
+	assert.Equal(suite.T(), http.StatusOK, suite.get("/health/live").StatusCode, "Живость не зависит от зависимостей")
+	readiness := suite.readiness(http.StatusServiceUnavailable)
+	assert.Equal(suite.T(), httpHandlers.HealthStatusNotReady, readiness.Status)
+	for _, name := range dependencies {
+		assert.Equal(suite.T(), httpHandlers.DependencyStatusDown, readiness.Dependencies[name].Status, name)
+	}
+
+	helpers.Eventually(suite.T(), func(c *helpers.EventuallyT) {
+		for _, name := range dependencies {
+			assert.GreaterOrEqual(c, chaos.Proxies[name].Connections(), 2, "Сервис повторяет подключение к %s", name)
+		}
+	}, startupRecoveryTimeout, 50*time.Millisecond)
+	select {
+	case <-suite.startup.Connected():
+		suite.T().Fatal("Сервис не может подключиться к БД, пока она недоступна")
+	default:
+	}
+
+	// Act - Redis и NATS появляются раньше Postgres
+	for _, name := range []string{"redis", "nats"} {
+		if _, ok := chaos.Proxies[name]; !ok {
+			continue
+		}
+		chaos.Restore(suite.T(), name)
+
+		// Assert
+		select {
+		case <-suite.startup.Subscribed(suite.T(), name):
+		case <-time.After(startupRecoveryTimeout):
+			suite.T().Fatalf("Сервис не подписался на %s за %v после ее появления", name, startupRecoveryTimeout)
+		}
+		readiness := suite.readiness(http.StatusServiceUnavailable)
+		assert.Equal(suite.T(), httpHandlers.DependencyStatusUp, readiness.Dependencies[name].Status, name)
+		assert.Equal(suite.T(), httpHandlers.DependencyStatusDown, readiness.Dependencies["database"].Status,
+			"Без БД сервис не готов")
+	}
+
+	// Act - последним появляется Postgres
+	chaos.Restore(suite.T(), "database")
+
+	// Assert
+	select {
+	case <-suite.startup.Connected():
+	case <-time.After(startupRecoveryTimeout):
+		suite.T().Fatalf("Сервис не подключился к БД за %v после ее появления", startupRecoveryTimeout)
+	}
+	db := suite.startup.DB(suite.T())
+
+	readiness = suite.readiness(http.StatusOK)
+	assert.Equal(suite.T(), httpHandlers.HealthStatusReady, readiness.Status)
+	for _, name := range dependencies {
+		assert.Equal(suite.T(), httpHandlers.DependencyStatusUp, readiness.Dependencies[name].Status, name)
+	}
+
+	// Подключение, дождавшееся БД, обслуживает запросы наравне с остальными
+	created := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    suite.env.API.APIPath("/drivers"),
+		Body:   helpers.CreateDriverRequest(),
+	})
+	require.Equal(suite.T(), http.StatusCreated, created.StatusCode, string(created.Body))
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(created, &driver)
+
+	stored, err := repositories.NewDriverRepository(db, suite.env.Logger).GetByID(context.Background(), driver.ID)
+	require.NoError(suite.T(), err)
+	assert.Equal(suite.T(), driver.ID, stored.ID)
+}
+
+// readiness запрашивает /health/ready, ожидая status
+func (suite *StartupOrderingTestSuite) readiness(status int) httpHandlers.ReadinessResponse {
+	response := suite.get("/health/ready")
+	require.Equal(suite.T(), status, response.StatusCode, string(response.Body))
+
+	var readiness httpHandlers.ReadinessResponse
+	suite.env.API.UnmarshalResponse(response, &readiness)
+	return readiness
+}
+
+// get выполняет GET запрос к роутеру окружения
+func (suite *StartupOrderingTestSuite) get(url string) *helpers.APIResponse {
+	return suite.env.API.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: url})
+}
+
+// TestStartupOrderingTestSuite запускает тестовый suite
+func TestStartupOrderingTestSuite(t *testing.T) {
+	suite.Run(t, new(StartupOrderingTestSuite))
+}
@@ -0,0 +1,181 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"sync"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры нагрузки и смены клиентов потока
+const (
+	churnDrivers         = 5
+	churnStableClients   = 5
+	churnClients         = 300
+	churnWorkers         = 20
+	churnLoadWorkers     = 4
+	churnLoadInterval    = 20 * time.Millisecond
+	churnMaxHold         = 20 * time.Millisecond // клиент держит поток от 0 до churnMaxHold перед отключением
+	churnDeliveryTimeout = 10 * time.Second
+	churnRecoveryTimeout = 10 * time.Second
+)
+
+// churnArea область диспетчеров вокруг точек генератора нагрузки
+var churnArea = entities.GeoBounds{
+	SouthWest: entities.Location{Latitude: 55.70, Longitude: 37.50},
+	NorthEast: entities.Location{Latitude: 55.80, Longitude: 37.70},
+}
+
+// StreamChurnTestSuite проверяет поток позиций для диспетчеров при массовых подключениях и отключениях
+// клиентов под нагрузкой: открытые дескрипторы и горутины возвращаются к исходным значениям,
+// а постоянные клиенты получают все обновления без пропусков
+type StreamChurnTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	baseURL string
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *StreamChurnTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+	suite.baseURL = suite.env.StartHTTPServer(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *StreamChurnTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *StreamChurnTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestClientChurnUnderLoad тестирует сотни подключений и отключений клиентов потока во время нагрузки
+func (suite *StreamChurnTestSuite) TestClientChurnUnderLoad() {
+	// Arrange
+	driverIDs := make([]uuid.UUID, 0, churnDrivers)
+	for i := 1; i <= churnDrivers; i++ {
+		driverIDs = append(driverIDs, suite.createDriver(i))
+	}
+
+	// Прогревочное подключение: сетевые горутины и дескрипторы, создаваемые один раз, попадают в baseline
+	warmup, err := helpers.ConnectFeed(suite.baseURL, churnArea)
+	require.NoError(suite.T(), err)
+	warmup.Close()
+	helpers.WaitForCondition(suite.T(), func() bool {
+		return suite.env.DispatchFeed.Subscribers() == 0
+	}, churnRecoveryTimeout, "warmup subscription removed")
+	baseline := helpers.CaptureServiceHealth(suite.env.DB)
+
+	stable := make([]*helpers.FeedClient, 0, churnStableClients)
+	for i := 0; i < churnStableClients; i++ {
+		client, err := helpers.ConnectFeed(suite.baseURL, churnArea)
+		require.NoError(suite.T(), err)
+		stable = append(stable, client)
+	}
+	defer func() {
+		for _, client := range stable {
+			client.Close()
+		}
+	}()
+
+	load := helpers.StartLocationLoad(suite.env.API, driverIDs, churnLoadWorkers, churnLoadInterval)
+	defer load.Stop()
+
+	// Act
+	churnErrors := suite.churn()
+	report := load.Stop()
+
+	// Assert
+	suite.T().Run("ChurnClientsConnected", func(t *testing.T) {
+		assert.Empty(t, churnErrors, "Ошибки подключения клиентов потока")
+	})
+
+	suite.T().Run("StableStreamsUnaffected", func(t *testing.T) {
+		require.Empty(t, report.Failures, "Ошибки записи местоположений во время смены клиентов")
+		require.NotEmpty(t, report.Accepted)
+
+		for i, client := range stable {
+			client.WaitForLocations(t, report.Accepted, churnDeliveryTimeout)
+			assert.NoError(t, client.Err(), "Поток постоянного клиента %d оборвался", i)
+		}
+	})
+
+	suite.T().Run("NoLeaks", func(t *testing.T) {
+		for _, client := range stable {
+			client.Close()
+		}
+		stable = nil
+
+		helpers.WaitForCondition(t, func() bool {
+			return suite.env.DispatchFeed.Subscribers() == 0
+		}, churnRecoveryTimeout, "all feed subscriptions removed")
+		helpers.AssertServiceHealthRecovered(t, suite.env.DB, baseline, churnRecoveryTimeout)
+	})
+}
+
+// churn подключает и отключает churnClients клиентов потока в churnWorkers параллельных воркерах
+// и возвращает ошибки подключения
+func (suite *StreamChurnTestSuite) churn() []error {
+	var (
+		mu     sync.Mutex
+		errs   []error
+		wg     sync.WaitGroup
+		nextID = make(chan int, churnClients)
+	)
+	for i := 0; i < churnClients; i++ {
+		nextID <- i
+	}
+	close(nextID)
+
+	for worker := 0; worker < churnWorkers; worker++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+
+			for i := range nextID {
+				client, err := helpers.ConnectFeed(suite.baseURL, churnArea)
+				if err != nil {
+					mu.Lock()
+					errs = append(errs, fmt.Errorf("client %d: %w", i, err))
+					mu.Unlock()
+					continue
+				}
+
+				time.Sleep(time.Duration(i%3) * churnMaxHold / 2)
+				client.Close()
+			}
+		}()
+	}
+
+	wg.Wait()
+	return errs
+}
+
+// createDriver создает водителя с уникальными контактами через сервис
+func (suite *StreamChurnTestSuite) createDriver(n int) uuid.UUID {
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900555%04d", n)
+	driver.Email = fmt.Sprintf("churn.driver%d@example.com", n)
+	driver.LicenseNumber = fmt.Sprintf("CH%08d", n)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(suite.T(), err)
+	return created.ID
+}
+
+func TestStreamChurnTestSuite(t *testing.T) {
+	suite.Run(t, new(StreamChurnTestSuite))
+}
@@ -0,0 +1,87 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"path/filepath"
+	"testing"
+
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// TrafficReplayTestSuite воспроизводит анонимизированные записи продакшен-трафика (HAR или JSONL)
+// на тестовом стенде и сравнивает распределение кодов ответа с продакшеном. По умолчанию используются
+// образцы из tests/testdata/traffic, свою запись можно передать через TRAFFIC_CAPTURE.
+type TrafficReplayTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *TrafficReplayTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *TrafficReplayTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *TrafficReplayTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestStatusDistribution тестирует, что коды ответа стенда распределены так же, как в продакшене
+func (suite *TrafficReplayTestSuite) TestStatusDistribution() {
+	options := helpers.LoadReplayOptions(suite.T())
+	tolerance := helpers.LoadTrafficTolerance(suite.T())
+
+	for _, path := range helpers.TrafficCapturePaths(suite.T(), "traffic") {
+		suite.T().Run(filepath.Base(path), func(t *testing.T) {
+			// Arrange
+			env := suite.env.ForTest(t)
+			capture := helpers.LoadTrafficCapture(t, path)
+
+			// Анонимизированные ID водителей заменяются водителями, созданными при первом упоминании
+			drivers := make(map[string]string)
+			options.MapID = func(id string) string {
+				if mapped, ok := drivers[id]; ok {
+					return mapped
+				}
+				drivers[id] = suite.createDriver(t)
+				return drivers[id]
+			}
+
+			// Act
+			result := env.API.ReplayTraffic(capture, options)
+
+			// Assert
+			helpers.AssertStatusDistribution(t, result, tolerance)
+		})
+	}
+}
+
+// createDriver создает водителя для анонимизированного ID из записи
+func (suite *TrafficReplayTestSuite) createDriver(t *testing.T) string {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900666%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("replay.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("RP%08d", suite.drivers)
+
+	created, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+	return created.ID.String()
+}
+
+// TestTrafficReplayTestSuite запускает тестовый suite
+func TestTrafficReplayTestSuite(t *testing.T) {
+	suite.Run(t, new(TrafficReplayTestSuite))
+}
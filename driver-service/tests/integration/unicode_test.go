@@ -0,0 +1,257 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+	"unicode/utf8"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// UnicodeTestSuite тестовый suite для сохранения Unicode-данных водителей
+type UnicodeTestSuite struct {
+	suite.Suite
+	env *helpers.TestEnvironment
+}
+
+// unicodeNameCase набор имен для проверки
+type unicodeNameCase struct {
+	name       string
+	firstName  string
+	lastName   string
+	middleName string
+}
+
+// unicodeNameCases имена с разными классами символов Unicode
+var unicodeNameCases = []unicodeNameCase{
+	{name: "cyrillic with yo", firstName: "Фёдор", lastName: "Ёлкин", middleName: "Пётрович"},
+	{name: "emoji", firstName: "Иван 🚕", lastName: "Водитель 😀👍", middleName: "🇷🇺"},
+	{name: "arabic rtl", firstName: "محمد", lastName: "عبد الله", middleName: "بن علي"},
+	{name: "hebrew rtl", firstName: "דוד", lastName: "כהן"},
+	// Комбинируемые диакритические знаки (NFD) не должны нормализоваться в NFC
+	{name: "combining characters", firstName: "Jose\u0301", lastName: "Mu\u0308ller", middleName: "A\u030a"},
+	{name: "4-byte cjk extension", firstName: "𠜎𠜱", lastName: "𠝹𠱓𠱸", middleName: "𡃁"},
+	{name: "zero width joiner", firstName: "👨‍👩‍👧‍👦", lastName: "Семья"},
+	{name: "mixed scripts", firstName: "Ali Алі علي", lastName: "O'Brien-Петров"},
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *UnicodeTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *UnicodeTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *UnicodeTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestUnicodeNamesRoundTrip тестирует сохранение Unicode-имен без искажений
+func (suite *UnicodeTestSuite) TestUnicodeNamesRoundTrip() {
+	for i, tc := range unicodeNameCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			require.True(t, utf8.ValidString(tc.firstName+tc.lastName+tc.middleName))
+
+			// Act
+			created := suite.createDriver(t, i, tc)
+
+			// Assert
+			suite.assertNames(t, tc, created)
+
+			response := suite.env.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodGet,
+				URL:    fmt.Sprintf("/api/v1/drivers/%s", created.ID),
+			})
+			require.Equal(t, http.StatusOK, response.StatusCode)
+
+			var fetched httpHandlers.DriverResponse
+			suite.env.API.UnmarshalResponse(response, &fetched)
+			suite.assertNames(t, tc, &fetched)
+		})
+	}
+}
+
+// TestUnicodeNamesInList тестирует, что список и фильтрация возвращают имена без искажений
+func (suite *UnicodeTestSuite) TestUnicodeNamesInList() {
+	// Arrange
+	expected := make(map[uuid.UUID]unicodeNameCase)
+	for i, tc := range unicodeNameCases {
+		created := suite.createDriver(suite.T(), i, tc)
+		expected[created.ID] = tc
+	}
+
+	// Act
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    "/api/v1/drivers",
+		QueryParams: map[string]string{
+			"status": "registered",
+			"limit":  "100",
+		},
+	})
+
+	// Assert
+	require.Equal(suite.T(), http.StatusOK, response.StatusCode)
+
+	var list httpHandlers.ListDriversResponse
+	suite.env.API.UnmarshalResponse(response, &list)
+	require.Len(suite.T(), list.Drivers, len(unicodeNameCases))
+
+	for _, driver := range list.Drivers {
+		tc, ok := expected[driver.ID]
+		require.True(suite.T(), ok)
+		suite.assertNames(suite.T(), tc, driver)
+	}
+}
+
+// TestUnicodeFullTextSearch тестирует полнотекстовый индекс по именам на не-латинских строках
+func (suite *UnicodeTestSuite) TestUnicodeFullTextSearch() {
+	// Arrange
+	for i, tc := range unicodeNameCases {
+		suite.createDriver(suite.T(), i, tc)
+	}
+
+	// Примечание: поиска по имени в API пока нет, поэтому проверяем выражение индекса idx_drivers_names напрямую
+	testCases := []struct {
+		query    string
+		expected string
+	}{
+		{query: "Ёлкин", expected: "Ёлкин"},
+		{query: "محمد", expected: "عبد الله"},
+		{query: "Петров", expected: "O'Brien-Петров"},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.query, func(t *testing.T) {
+			// Act
+			var lastNames []string
+			err := suite.env.DB.SelectContext(suite.env.Ctx, &lastNames, `
+				SELECT last_name FROM drivers
+				WHERE to_tsvector('russian', first_name || ' ' || last_name || ' ' || COALESCE(middle_name, ''))
+				      @@ plainto_tsquery('russian', $1)`, tc.query)
+
+			// Assert
+			require.NoError(t, err)
+			assert.Equal(t, []string{tc.expected}, lastNames)
+		})
+	}
+}
+
+// TestUnicodeEventPayload тестирует, что событие регистрации содержит имя без искажений
+func (suite *UnicodeTestSuite) TestUnicodeEventPayload() {
+	for i, tc := range unicodeNameCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			created := suite.createDriver(t, i, tc)
+
+			// Assert
+			events := suite.env.Events.EventsForDriver(created.ID, "driver.registered")
+			require.Len(t, events, 1)
+
+			expectedName := tc.lastName + " " + tc.firstName
+			if tc.middleName != "" {
+				expectedName += " " + tc.middleName
+			}
+			assert.Equal(t, expectedName, events[0].DataMap()["name"])
+		})
+	}
+}
+
+// TestUnicodeNameLengthLimit тестирует, что длина имени считается в символах и не обрезается молча
+func (suite *UnicodeTestSuite) TestUnicodeNameLengthLimit() {
+	testCases := []struct {
+		name      string
+		firstName string
+	}{
+		// 100 символов по 4 байта - 400 байт, но укладывается в VARCHAR(100)
+		{name: "100 emoji", firstName: strings.Repeat("😀", 100)},
+		{name: "100 cjk extension", firstName: strings.Repeat("𠜎", 100)},
+		{name: "101 emoji", firstName: strings.Repeat("😀", 101)},
+	}
+
+	for i, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Act
+			request := suite.driverRequest(i, unicodeNameCase{firstName: tc.firstName, lastName: "Тест"})
+			response := suite.env.API.MakeRequest(helpers.APIRequest{
+				Method: http.MethodPost,
+				URL:    "/api/v1/drivers",
+				Body:   request,
+			})
+
+			// Assert
+			if utf8.RuneCountInString(tc.firstName) > entities.MaxNameLength {
+				// Слишком длинное имя отклоняется валидатором целиком, а не сохраняется частично
+				assert.Equal(t, http.StatusBadRequest, response.StatusCode, string(response.Body))
+				suite.env.API.AssertErrorResponse(response, "INVALID_DATA")
+				return
+			}
+
+			require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+			var driver httpHandlers.DriverResponse
+			suite.env.API.UnmarshalResponse(response, &driver)
+			assert.Equal(t, tc.firstName, driver.FirstName, "Имя не должно обрезаться")
+		})
+	}
+}
+
+// createDriver создает водителя с указанными именами через API
+func (suite *UnicodeTestSuite) createDriver(t *testing.T, index int, tc unicodeNameCase) *httpHandlers.DriverResponse {
+	response := suite.env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    "/api/v1/drivers",
+		Body:   suite.driverRequest(index, tc),
+	})
+	require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	suite.env.API.UnmarshalResponse(response, &driver)
+	return &driver
+}
+
+// driverRequest формирует запрос на создание водителя с уникальными контактами
+func (suite *UnicodeTestSuite) driverRequest(index int, tc unicodeNameCase) map[string]interface{} {
+	request := helpers.CreateDriverRequest()
+	request["phone"] = fmt.Sprintf("+7900555%04d", index)
+	request["email"] = fmt.Sprintf("unicode%d@example.com", index)
+	request["license_number"] = fmt.Sprintf("UNI%06d", index)
+	request["first_name"] = tc.firstName
+	request["last_name"] = tc.lastName
+	if tc.middleName != "" {
+		request["middle_name"] = tc.middleName
+	}
+	return request
+}
+
+// assertNames проверяет побайтовое совпадение имен
+func (suite *UnicodeTestSuite) assertNames(t *testing.T, tc unicodeNameCase, driver *httpHandlers.DriverResponse) {
+	assert.Equal(t, tc.firstName, driver.FirstName)
+	assert.Equal(t, tc.lastName, driver.LastName)
+	if tc.middleName == "" {
+		assert.Nil(t, driver.MiddleName)
+	} else {
+		require.NotNil(t, driver.MiddleName)
+		assert.Equal(t, tc.middleName, *driver.MiddleName)
+	}
+}
+
+// TestUnicodeTestSuite запускает тестовый suite
+func TestUnicodeTestSuite(t *testing.T) {
+	suite.Run(t, new(UnicodeTestSuite))
+}
@@ -0,0 +1,240 @@
+//go:build integration
+
+package integration
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"driver-service/internal/domain/entities"
+	"driver-service/internal/infrastructure/webhooks"
+	"driver-service/tests/fixtures"
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"github.com/stretchr/testify/suite"
+)
+
+// Параметры ожидания асинхронной доставки webhooks
+const (
+	webhookWaitTimeout  = 5 * time.Second
+	webhookPollInterval = 20 * time.Millisecond
+	webhookSecret       = "test-webhook-secret"
+	webhookStatusEvent  = "driver.status.changed"
+)
+
+// WebhookDeliveryTestSuite тестирует доставку событий партнерам через webhooks: подпись, повторы и dead letter
+type WebhookDeliveryTestSuite struct {
+	suite.Suite
+	env     *helpers.TestEnvironment
+	drivers int // счетчик водителей для уникальных телефонов в подтестах
+}
+
+// SetupSuite выполняется один раз перед всеми тестами
+func (suite *WebhookDeliveryTestSuite) SetupSuite() {
+	suite.env = helpers.NewTestEnvironment(suite.T())
+}
+
+// TearDownSuite выполняется один раз после всех тестов
+func (suite *WebhookDeliveryTestSuite) TearDownSuite() {
+	suite.env.Close(suite.T())
+}
+
+// SetupTest выполняется перед каждым тестом
+func (suite *WebhookDeliveryTestSuite) SetupTest() {
+	suite.env.Reset(suite.T())
+}
+
+// TestDeliveryWithValidSignature тестирует доставку изменения статуса подписанному адресату
+func (suite *WebhookDeliveryTestSuite) TestDeliveryWithValidSignature() {
+	// Arrange
+	receiver := suite.env.StartWebhookReceiver(suite.T(), webhookSecret, webhookStatusEvent)
+	otherReceiver := suite.env.StartWebhookReceiver(suite.T(), webhookSecret, "driver.blocked")
+
+	// Act
+	driverID := suite.changeDriverStatus(suite.T())
+
+	// Assert
+	helpers.Eventually(suite.T(), func(c *helpers.EventuallyT) {
+		assert.Len(c, receiver.Accepted(), 1)
+	}, webhookWaitTimeout, webhookPollInterval)
+
+	delivery := receiver.Accepted()[0]
+	assert.True(suite.T(), delivery.SignatureValid, "Подпись должна проверяться секретом адресата")
+	assert.Equal(suite.T(), webhooks.Sign(webhookSecret, delivery.Body), delivery.Signature)
+	assert.Equal(suite.T(), 1, delivery.Attempt)
+	assert.Equal(suite.T(), webhookStatusEvent, delivery.EventType)
+	assert.Equal(suite.T(), delivery.DeliveryID, delivery.Payload.DeliveryID.String())
+	assert.Equal(suite.T(), webhookStatusEvent, delivery.Payload.EventType)
+	assert.Equal(suite.T(), driverID, delivery.Payload.DriverID)
+	assert.Equal(suite.T(), string(entities.StatusRegistered), delivery.DataMap()["old_status"])
+	assert.Equal(suite.T(), string(entities.StatusPendingVerification), delivery.DataMap()["new_status"])
+
+	// Событие по-прежнему публикуется во внутреннюю шину, а адресат других событий его не получает
+	assert.Len(suite.T(), suite.env.Events.EventsForDriver(driverID, webhookStatusEvent), 1)
+	assert.Empty(suite.T(), otherReceiver.Deliveries())
+	assert.Empty(suite.T(), suite.env.DeadLettersFor(receiver.TargetID))
+}
+
+// TestSignatureValidation тестирует проверку HMAC подписи тела доставки
+func (suite *WebhookDeliveryTestSuite) TestSignatureValidation() {
+	body := []byte(`{"event_type":"driver.status.changed"}`)
+	signature := webhooks.Sign(webhookSecret, body)
+
+	testCases := []struct {
+		name      string
+		secret    string
+		body      []byte
+		signature string
+		valid     bool
+	}{
+		{name: "Valid", secret: webhookSecret, body: body, signature: signature, valid: true},
+		{name: "TamperedBody", secret: webhookSecret, body: []byte(`{"event_type":"driver.blocked"}`), signature: signature},
+		{name: "WrongSecret", secret: "other-secret", body: body, signature: signature},
+		{name: "MissingPrefix", secret: webhookSecret, body: body, signature: signature[len("sha256="):]},
+		{name: "Empty", secret: webhookSecret, body: body, signature: ""},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			assert.Equal(t, tc.valid, webhooks.VerifySignature(tc.secret, tc.body, tc.signature))
+		})
+	}
+
+	suite.T().Run("RejectedDeliveryIsNotRetried", func(t *testing.T) {
+		// Arrange - партнер сменил секрет, а адресат в сервисе остался со старым
+		receiver := suite.env.StartWebhookReceiver(t, webhookSecret, webhookStatusEvent)
+		receiver.RotateSecret("rotated-secret")
+
+		// Act
+		suite.changeDriverStatus(t)
+
+		// Assert - 401 не повторяется и сразу попадает в dead letter
+		helpers.Eventually(t, func(c *helpers.EventuallyT) {
+			assert.Len(c, suite.env.DeadLettersFor(receiver.TargetID), 1)
+		}, webhookWaitTimeout, webhookPollInterval)
+
+		deliveries := receiver.Deliveries()
+		require.Len(t, deliveries, 1)
+		assert.False(t, deliveries[0].SignatureValid)
+		assert.Equal(t, http.StatusUnauthorized, deliveries[0].StatusCode)
+
+		deadLetter := suite.env.DeadLettersFor(receiver.TargetID)[0]
+		assert.Equal(t, 1, deadLetter.Attempts)
+		assert.Equal(t, http.StatusUnauthorized, deadLetter.LastStatus)
+	})
+}
+
+// TestRetryWithBackoff тестирует повтор доставки после ответов 5xx с растущей паузой
+func (suite *WebhookDeliveryTestSuite) TestRetryWithBackoff() {
+	// Arrange
+	receiver := suite.env.StartWebhookReceiver(suite.T(), webhookSecret, webhookStatusEvent)
+	receiver.FailNext(2, http.StatusServiceUnavailable)
+
+	// Act
+	suite.changeDriverStatus(suite.T())
+
+	// Assert
+	helpers.Eventually(suite.T(), func(c *helpers.EventuallyT) {
+		assert.Len(c, receiver.Accepted(), 1)
+	}, webhookWaitTimeout, webhookPollInterval)
+
+	attempts := receiver.AttemptsFor(receiver.Accepted()[0].DeliveryID)
+	require.Len(suite.T(), attempts, 3, "Две неудачные попытки и одна успешная")
+
+	for i, attempt := range attempts {
+		assert.Equal(suite.T(), i+1, attempt.Attempt, "Номер попытки")
+		assert.True(suite.T(), attempt.SignatureValid, "Повтор должен быть подписан")
+		assert.Equal(suite.T(), attempts[0].Body, attempt.Body, "Повтор должен отправлять то же тело")
+	}
+	assert.Equal(suite.T(), http.StatusServiceUnavailable, attempts[0].StatusCode)
+	assert.Equal(suite.T(), http.StatusServiceUnavailable, attempts[1].StatusCode)
+	assert.Equal(suite.T(), http.StatusOK, attempts[2].StatusCode)
+
+	// Пауза удваивается: InitialBackoff перед второй попыткой и 2*InitialBackoff перед третьей
+	firstGap := attempts[1].ReceivedAt.Sub(attempts[0].ReceivedAt)
+	secondGap := attempts[2].ReceivedAt.Sub(attempts[1].ReceivedAt)
+	suite.T().Logf("Паузы между попытками: %v, %v", firstGap, secondGap)
+	assert.GreaterOrEqual(suite.T(), firstGap, helpers.TestWebhookConfig.InitialBackoff)
+	assert.GreaterOrEqual(suite.T(), secondGap, 2*helpers.TestWebhookConfig.InitialBackoff)
+
+	assert.Empty(suite.T(), suite.env.DeadLettersFor(receiver.TargetID))
+}
+
+// TestDeadLettering тестирует, что после исчерпания попыток или неповторяемой ошибки доставка попадает в dead letter
+func (suite *WebhookDeliveryTestSuite) TestDeadLettering() {
+	maxAttempts := helpers.TestWebhookConfig.MaxAttempts
+
+	testCases := []struct {
+		name             string
+		statusCode       int // 0 - получатель недоступен
+		expectedAttempts int
+	}{
+		{name: "InternalServerError", statusCode: http.StatusInternalServerError, expectedAttempts: maxAttempts},
+		{name: "BadGateway", statusCode: http.StatusBadGateway, expectedAttempts: maxAttempts},
+		{name: "TooManyRequests", statusCode: http.StatusTooManyRequests, expectedAttempts: maxAttempts},
+		{name: "Unreachable", statusCode: 0, expectedAttempts: maxAttempts},
+		{name: "BadRequest", statusCode: http.StatusBadRequest, expectedAttempts: 1},
+		{name: "Gone", statusCode: http.StatusGone, expectedAttempts: 1},
+	}
+
+	for _, tc := range testCases {
+		suite.T().Run(tc.name, func(t *testing.T) {
+			// Arrange
+			receiver := suite.env.StartWebhookReceiver(t, webhookSecret, webhookStatusEvent)
+			if tc.statusCode == 0 {
+				receiver.Close()
+			} else {
+				receiver.FailAlways(tc.statusCode)
+			}
+
+			// Act
+			driverID := suite.changeDriverStatus(t)
+
+			// Assert
+			helpers.Eventually(t, func(c *helpers.EventuallyT) {
+				assert.Len(c, suite.env.DeadLettersFor(receiver.TargetID), 1)
+			}, webhookWaitTimeout, webhookPollInterval)
+
+			deadLetter := suite.env.DeadLettersFor(receiver.TargetID)[0]
+			assert.Equal(t, tc.expectedAttempts, deadLetter.Attempts)
+			assert.Equal(t, tc.statusCode, deadLetter.LastStatus)
+			assert.Equal(t, driverID, deadLetter.Payload.DriverID)
+			assert.Equal(t, webhookStatusEvent, deadLetter.Payload.EventType)
+
+			if tc.statusCode == 0 {
+				assert.NotEmpty(t, deadLetter.LastError, "Сетевая ошибка должна сохраняться в dead letter")
+				return
+			}
+
+			attempts := receiver.AttemptsFor(deadLetter.Payload.DeliveryID.String())
+			assert.Len(t, attempts, tc.expectedAttempts, "Получатель должен увидеть все попытки")
+			assert.Len(t, receiver.Deliveries(), tc.expectedAttempts, "Других доставок быть не должно")
+		})
+	}
+}
+
+// changeDriverStatus создает водителя и переводит его на проверку документов, публикуя driver.status.changed
+func (suite *WebhookDeliveryTestSuite) changeDriverStatus(t *testing.T) uuid.UUID {
+	suite.drivers++
+	driver := fixtures.CreateTestDriver()
+	driver.Phone = fmt.Sprintf("+7900555%04d", suite.drivers)
+	driver.Email = fmt.Sprintf("webhook.driver%d@example.com", suite.drivers)
+	driver.LicenseNumber = fmt.Sprintf("WH%08d", suite.drivers)
+
+	createdDriver, err := suite.env.DriverService.CreateDriver(suite.env.Ctx, driver)
+	require.NoError(t, err)
+
+	err = suite.env.DriverService.ChangeDriverStatus(suite.env.Ctx, createdDriver.ID, entities.StatusPendingVerification)
+	require.NoError(t, err)
+	return createdDriver.ID
+}
+
+// TestWebhookDeliveryTestSuite запускает тестовый suite
+func TestWebhookDeliveryTestSuite(t *testing.T) {
+	suite.Run(t, new(WebhookDeliveryTestSuite))
+}
@@ -0,0 +1,101 @@
+//go:build integration
+
+package driverapp
+
+import (
+	"encoding/json"
+	"os"
+	"regexp"
+	"strconv"
+	"strings"
+	"testing"
+	"time"
+
+	"driver-service/tests/helpers"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/require"
+)
+
+// recordingsDir каталог записей трафика приложений в tests/testdata
+const recordingsDir = "driver_app"
+
+// Recording последовательность запросов приложения водителя, полученная из записанного трафика.
+// Токены и персональные данные из записи удаляются, идентификаторы заменяются подстановками:
+//
+//	{driver_id} - ID водителя, созданного набором
+//	{uuid}      - новый UUID для каждого запроса (например, X-Request-ID)
+//	"{now}", "{now-N}" - Unix-время запуска (минус N секунд) числом, а не строкой
+type Recording struct {
+	Platform     string            `json:"platform"`
+	AppVersion   string            `json:"app_version"`
+	RecordedFrom string            `json:"recorded_from"`
+	Headers      map[string]string `json:"headers"` // заголовки, которые приложение отправляет в каждом запросе
+	Steps        []RecordedStep    `json:"steps"`
+}
+
+// RecordedStep запрос приложения и поля ответа, которые приложение читает
+type RecordedStep struct {
+	Name           string            `json:"name"`
+	Description    string            `json:"description"`
+	Method         string            `json:"method"`
+	Path           string            `json:"path"`
+	Headers        map[string]string `json:"headers"`
+	Body           json.RawMessage   `json:"body"`
+	ExpectedStatus int               `json:"expected_status"`
+	// Reads JSONPath полей ответа и их JSON тип: без них клиент не разберет ответ
+	Reads map[string]string `json:"reads"`
+	// OptionalRoute маршрут может отсутствовать в сервисе (x-implemented: false в спецификации)
+	OptionalRoute bool `json:"optional_route"`
+}
+
+// nowPlaceholder подстановка времени в теле запроса: "{now}" или "{now-30}"
+var nowPlaceholder = regexp.MustCompile(`"\{now(?:-(\d+))?\}"`)
+
+// recordingPlatforms возвращает платформы, для которых есть записи (имена файлов без .json)
+func recordingPlatforms() []string {
+	files, _ := os.ReadDir(helpers.TestDataPath(recordingsDir))
+
+	var platforms []string
+	for _, file := range files {
+		if name := file.Name(); !file.IsDir() && strings.HasSuffix(name, ".json") {
+			platforms = append(platforms, strings.TrimSuffix(name, ".json"))
+		}
+	}
+	return platforms
+}
+
+// loadRecording загружает запись трафика платформы
+func loadRecording(t *testing.T, platform string) Recording {
+	var recording Recording
+	helpers.LoadTestData(t, recordingsDir+"/"+platform+".json", &recording)
+	require.NotEmpty(t, recording.Steps, "В записи %s нет запросов", platform)
+	return recording
+}
+
+// request собирает запрос шага с подстановками
+func (s RecordedStep) request(recording Recording, driverID uuid.UUID, now time.Time) helpers.APIRequest {
+	headers := make(map[string]string, len(recording.Headers)+len(s.Headers))
+	for key, value := range recording.Headers {
+		headers[key] = value
+	}
+	for key, value := range s.Headers {
+		headers[key] = value
+	}
+	for key, value := range headers {
+		headers[key] = strings.ReplaceAll(value, "{uuid}", uuid.NewString())
+	}
+
+	request := helpers.APIRequest{
+		Method:  s.Method,
+		URL:     strings.ReplaceAll(s.Path, "{driver_id}", driverID.String()),
+		Headers: headers,
+	}
+	if len(s.Body) > 0 {
+		request.Body = json.RawMessage(nowPlaceholder.ReplaceAllFunc(s.Body, func(match []byte) []byte {
+			seconds, _ := strconv.Atoi(string(nowPlaceholder.FindSubmatch(match)[1]))
+			return []byte(strconv.FormatInt(now.Add(-time.Duration(seconds)*time.Second).Unix(), 10))
+		}))
+	}
+	return request
+}
@@ -0,0 +1,37 @@
+//go:build integration
+
+// Package example пример внешнего тестового пакета
+package example
+
+import (
+	"net/http"
+	"testing"
+
+	"driver-service/tests/helpers"
+	"driver-service/tests/packs"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func init() {
+	packs.Register(healthSuite{})
+}
+
+// healthSuite проверяет, что сервис отвечает на health check
+type healthSuite struct{}
+
+// Name возвращает имя набора
+func (healthSuite) Name() string {
+	return "example/health"
+}
+
+// Run выполняет тесты набора
+func (healthSuite) Run(t *testing.T, env *helpers.TestEnvironment) {
+	response := env.API.MakeRequest(helpers.APIRequest{Method: http.MethodGet, URL: "/health"})
+	require.Equal(t, http.StatusOK, response.StatusCode)
+
+	var health map[string]interface{}
+	env.API.UnmarshalResponse(response, &health)
+	assert.Equal(t, "healthy", health["status"])
+}
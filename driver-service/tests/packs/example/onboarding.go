@@ -0,0 +1,87 @@
+//go:build integration
+
+package example
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+
+	"driver-service/internal/domain/entities"
+	httpHandlers "driver-service/internal/interfaces/http/handlers"
+	"driver-service/tests/helpers"
+	"driver-service/tests/packs"
+
+	"github.com/google/uuid"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// driverIDKey ключ env.Shared с ID водителя, созданного onboardingSuite
+const driverIDKey = "example.driver_id"
+
+func init() {
+	packs.Register(onboardingSuite{})
+	packs.Register(locationSuite{})
+}
+
+// onboardingSuite регистрирует водителя и выводит его на линию
+type onboardingSuite struct{}
+
+// Name возвращает имя набора
+func (onboardingSuite) Name() string {
+	return "example/onboarding"
+}
+
+// Run выполняет тесты набора
+func (onboardingSuite) Run(t *testing.T, env *helpers.TestEnvironment) {
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    env.API.APIPath("/drivers"),
+		Body:   helpers.CreateDriverRequest(),
+	})
+	require.Equal(t, http.StatusCreated, response.StatusCode, string(response.Body))
+
+	var driver httpHandlers.DriverResponse
+	env.API.UnmarshalResponse(response, &driver)
+	assert.Equal(t, entities.StatusRegistered, driver.Status)
+
+	require.NoError(t, env.DriverRepo.UpdateStatus(env.Ctx, driver.ID, entities.StatusAvailable))
+	env.Shared[driverIDKey] = driver.ID
+}
+
+// locationSuite проверяет отслеживание местоположения водителя, созданного onboardingSuite
+type locationSuite struct{}
+
+// Name возвращает имя набора
+func (locationSuite) Name() string {
+	return "example/location"
+}
+
+// DependsOn возвращает зависимости набора
+func (locationSuite) DependsOn() []string {
+	return []string{"example/onboarding"}
+}
+
+// Run выполняет тесты набора
+func (locationSuite) Run(t *testing.T, env *helpers.TestEnvironment) {
+	driverID, ok := env.Shared[driverIDKey].(uuid.UUID)
+	require.True(t, ok, "Нет водителя от example/onboarding")
+
+	response := env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodPost,
+		URL:    env.API.APIPath(fmt.Sprintf("/drivers/%s/locations", driverID)),
+		Body:   helpers.CreateLocationRequest(),
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode, string(response.Body))
+
+	response = env.API.MakeRequest(helpers.APIRequest{
+		Method: http.MethodGet,
+		URL:    env.API.APIPath(fmt.Sprintf("/drivers/%s/locations/current", driverID)),
+	})
+	require.Equal(t, http.StatusOK, response.StatusCode)
+
+	var location httpHandlers.LocationResponse
+	env.API.UnmarshalResponse(response, &location)
+	assert.Equal(t, driverID, location.DriverID)
+}
@@ -0,0 +1,74 @@
+//go:build integration
+
+// Package packs реестр внешних тестовых пакетов.
+//
+// Команды, которым нужны собственные проверки сервиса (например, региональные требования),
+// реализуют TestSuite в своем пакете и регистрируют его в init:
+//
+//	func init() {
+//		packs.Register(&complianceSuite{})
+//	}
+//
+// Пакет подключается blank-импортом в tests/integration/external_packs_test.go,
+// после чего TestExternalPacks выполняет его вместе со встроенными тестами.
+//
+// Набор может объявить зависимости, реализовав Dependent: он будет выполнен после них
+// в том же окружении и пропущен, если зависимость не прошла.
+package packs
+
+import (
+	"fmt"
+	"sort"
+	"sync"
+	"testing"
+
+	"driver-service/tests/helpers"
+)
+
+// TestSuite внешний набор тестов
+type TestSuite interface {
+	// Name уникальное имя набора, например "kz/compliance"
+	Name() string
+	// Run выполняет тесты набора. Окружение общее для всех наборов и очищается перед каждым из них.
+	Run(t *testing.T, env *helpers.TestEnvironment)
+}
+
+var (
+	mu     sync.Mutex
+	suites = make(map[string]TestSuite)
+)
+
+// Register регистрирует набор тестов. Повторная регистрация имени вызывает panic.
+func Register(suite TestSuite) {
+	mu.Lock()
+	defer mu.Unlock()
+
+	if suite == nil {
+		panic("packs: Register suite is nil")
+	}
+
+	name := suite.Name()
+	if name == "" {
+		panic("packs: Register suite with empty name")
+	}
+	if _, exists := suites[name]; exists {
+		panic(fmt.Sprintf("packs: Register called twice for suite %q", name))
+	}
+
+	suites[name] = suite
+}
+
+// Registered возвращает зарегистрированные наборы, отсортированные по имени
+func Registered() []TestSuite {
+	mu.Lock()
+	defer mu.Unlock()
+
+	result := make([]TestSuite, 0, len(suites))
+	for _, suite := range suites {
+		result = append(result, suite)
+	}
+	sort.Slice(result, func(i, j int) bool {
+		return result[i].Name() < result[j].Name()
+	})
+	return result
+}
@@ -0,0 +1,184 @@
+//go:build integration
+
+package packs
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"testing"
+
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/require"
+)
+
+// Dependent реализуется наборами, которым нужно состояние, созданное другими наборами
+// (например, сценарий поездки зависит от онбординга водителя)
+type Dependent interface {
+	// DependsOn имена наборов, которые должны успешно выполниться раньше
+	DependsOn() []string
+}
+
+// dependenciesOf возвращает зависимости набора (nil, если он не реализует Dependent)
+func dependenciesOf(suite TestSuite) []string {
+	if dependent, ok := suite.(Dependent); ok {
+		return dependent.DependsOn()
+	}
+	return nil
+}
+
+// Plan разбивает наборы на группы связанных зависимостями наборов и упорядочивает каждую группу так,
+// чтобы зависимости шли раньше зависимых. Группы и наборы без взаимных зависимостей упорядочены по имени.
+// Зависимости от незарегистрированных наборов не являются ошибкой: такие наборы будут пропущены при запуске.
+func Plan(suites []TestSuite) ([][]TestSuite, error) {
+	byName := make(map[string]TestSuite, len(suites))
+	var names []string
+	for _, suite := range suites {
+		byName[suite.Name()] = suite
+		names = append(names, suite.Name())
+	}
+	sort.Strings(names)
+
+	// Связи без учета направления для выделения групп
+	neighbours := make(map[string][]string)
+	for _, name := range names {
+		for _, dependency := range dependenciesOf(byName[name]) {
+			if _, ok := byName[dependency]; !ok {
+				continue
+			}
+			neighbours[name] = append(neighbours[name], dependency)
+			neighbours[dependency] = append(neighbours[dependency], name)
+		}
+	}
+
+	var groups [][]TestSuite
+	visited := make(map[string]bool)
+	for _, name := range names {
+		if visited[name] {
+			continue
+		}
+
+		var component []string
+		queue := []string{name}
+		visited[name] = true
+		for len(queue) > 0 {
+			current := queue[0]
+			queue = queue[1:]
+			component = append(component, current)
+
+			for _, next := range neighbours[current] {
+				if !visited[next] {
+					visited[next] = true
+					queue = append(queue, next)
+				}
+			}
+		}
+
+		ordered, err := topologicalOrder(component, byName)
+		if err != nil {
+			return nil, err
+		}
+		groups = append(groups, ordered)
+	}
+
+	return groups, nil
+}
+
+// topologicalOrder упорядочивает наборы группы алгоритмом Кана, выбирая среди готовых наборов первый по имени
+func topologicalOrder(component []string, byName map[string]TestSuite) ([]TestSuite, error) {
+	inGroup := make(map[string]bool, len(component))
+	for _, name := range component {
+		inGroup[name] = true
+	}
+
+	pending := make(map[string]int, len(component))
+	dependents := make(map[string][]string)
+	for _, name := range component {
+		for _, dependency := range dependenciesOf(byName[name]) {
+			if !inGroup[dependency] {
+				continue
+			}
+			pending[name]++
+			dependents[dependency] = append(dependents[dependency], name)
+		}
+	}
+
+	var ready []string
+	for _, name := range component {
+		if pending[name] == 0 {
+			ready = append(ready, name)
+		}
+	}
+
+	var ordered []TestSuite
+	for len(ready) > 0 {
+		sort.Strings(ready)
+		current := ready[0]
+		ready = ready[1:]
+		ordered = append(ordered, byName[current])
+
+		for _, dependent := range dependents[current] {
+			pending[dependent]--
+			if pending[dependent] == 0 {
+				ready = append(ready, dependent)
+			}
+		}
+	}
+
+	if len(ordered) != len(component) {
+		var cycle []string
+		for _, name := range component {
+			if pending[name] > 0 {
+				cycle = append(cycle, name)
+			}
+		}
+		sort.Strings(cycle)
+		return nil, fmt.Errorf("packs: dependency cycle between suites %s", strings.Join(cycle, ", "))
+	}
+
+	return ordered, nil
+}
+
+// RunAll выполняет зарегистрированные наборы в порядке зависимостей. Окружение очищается перед каждой группой
+// связанных наборов, поэтому зависимые наборы видят состояние, созданное их зависимостями. Набор пропускается
+// с указанием причины, если какая-либо его зависимость не зарегистрирована, упала или была пропущена.
+// Каждый набор выполняется в своем span (см. helpers.StartTestSpan): логи сервиса и запросы к API
+// прикладываются к результату упавшего набора, а при заданном TEST_ARTIFACTS_DIR сохраняются в файл.
+func RunAll(t *testing.T, env *helpers.TestEnvironment) {
+	groups, err := Plan(Registered())
+	require.NoError(t, err)
+
+	passed := make(map[string]bool)
+	for _, group := range groups {
+		env.Reset(t)
+
+		for _, suite := range group {
+			suite := suite
+			t.Run(suite.Name(), func(t *testing.T) {
+				defer func() {
+					passed[suite.Name()] = !t.Failed() && !t.Skipped()
+				}()
+
+				if reason := skipReason(suite, passed); reason != "" {
+					t.Skip(reason)
+				}
+				suite.Run(t, env.ForTest(t))
+			})
+		}
+	}
+}
+
+// skipReason возвращает причину пропуска набора или пустую строку, если все зависимости выполнены
+func skipReason(suite TestSuite, passed map[string]bool) string {
+	for _, dependency := range dependenciesOf(suite) {
+		ok, ran := passed[dependency]
+		switch {
+		case !ran:
+			return fmt.Sprintf("зависимость %q не зарегистрирована", dependency)
+		case !ok:
+			return fmt.Sprintf("зависимость %q не пройдена", dependency)
+		}
+	}
+	return ""
+}
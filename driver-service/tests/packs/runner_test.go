@@ -0,0 +1,95 @@
+//go:build integration
+
+package packs
+
+import (
+	"testing"
+
+	"driver-service/tests/helpers"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+// fakeSuite набор тестов для проверки планирования
+type fakeSuite struct {
+	name string
+	deps []string
+}
+
+func (s fakeSuite) Name() string { return s.name }
+
+func (s fakeSuite) DependsOn() []string { return s.deps }
+
+func (s fakeSuite) Run(t *testing.T, env *helpers.TestEnvironment) {}
+
+// planNames возвращает имена наборов по группам
+func planNames(t *testing.T, suites ...TestSuite) [][]string {
+	groups, err := Plan(suites)
+	require.NoError(t, err)
+
+	var names [][]string
+	for _, group := range groups {
+		var groupNames []string
+		for _, suite := range group {
+			groupNames = append(groupNames, suite.Name())
+		}
+		names = append(names, groupNames)
+	}
+	return names
+}
+
+func TestPlanOrdersDependenciesFirst(t *testing.T) {
+	names := planNames(t,
+		fakeSuite{name: "ride-lifecycle", deps: []string{"onboarding", "shift"}},
+		fakeSuite{name: "shift", deps: []string{"onboarding"}},
+		fakeSuite{name: "onboarding"},
+		fakeSuite{name: "health"},
+	)
+
+	assert.Equal(t, [][]string{
+		{"health"},
+		{"onboarding", "shift", "ride-lifecycle"},
+	}, names)
+}
+
+func TestPlanIgnoresUnregisteredDependencies(t *testing.T) {
+	names := planNames(t,
+		fakeSuite{name: "b", deps: []string{"missing"}},
+		fakeSuite{name: "a"},
+	)
+
+	assert.Equal(t, [][]string{{"a"}, {"b"}}, names)
+}
+
+func TestPlanDetectsCycles(t *testing.T) {
+	_, err := Plan([]TestSuite{
+		fakeSuite{name: "a", deps: []string{"c"}},
+		fakeSuite{name: "b", deps: []string{"a"}},
+		fakeSuite{name: "c", deps: []string{"b"}},
+		fakeSuite{name: "d"},
+	})
+
+	require.Error(t, err)
+	assert.Contains(t, err.Error(), "a, b, c")
+}
+
+func TestSkipReason(t *testing.T) {
+	suite := fakeSuite{name: "ride", deps: []string{"onboarding", "shift"}}
+
+	testCases := []struct {
+		name     string
+		passed   map[string]bool
+		expected string
+	}{
+		{name: "all passed", passed: map[string]bool{"onboarding": true, "shift": true}, expected: ""},
+		{name: "failed", passed: map[string]bool{"onboarding": true, "shift": false}, expected: `зависимость "shift" не пройдена`},
+		{name: "missing", passed: map[string]bool{"shift": true}, expected: `зависимость "onboarding" не зарегистрирована`},
+	}
+
+	for _, tc := range testCases {
+		t.Run(tc.name, func(t *testing.T) {
+			assert.Equal(t, tc.expected, skipReason(suite, tc.passed))
+		})
+	}
+}